@@ -7,6 +7,7 @@ pub mod ai_events;
 pub mod ai_render;
 pub mod ai_state; // Made public for integration tests
 pub mod context;
+pub mod offline;
 pub mod prompt;
 mod provider;
 pub mod render;
@@ -1,17 +1,19 @@
 //! AI event handling
 //!
-//! Handles keyboard events (Ctrl+A toggle, Esc close) and response channel polling.
+//! Handles keyboard events (Ctrl+A toggle, Esc close, follow-up input) and response
+//! channel polling.
 //!
 //! The AI request flow is triggered by jq execution results:
 //! - Query changes → jq executes → result available → cancel in-flight → debounce → AI request
 //! - Both success and error results trigger AI requests with appropriate context
 
-use ratatui::crossterm::event::KeyEvent;
+use ratatui::crossterm::event::{KeyCode, KeyEvent};
 use std::sync::mpsc::TryRecvError;
+use tui_textarea::Input;
 
 use super::ai_state::{AiResponse, AiState};
 use super::context::{ContextParams, QueryContext};
-use super::prompt::build_prompt;
+use super::prompt::build_configured_prompt;
 use super::selection::{apply::apply_suggestion, keybindings};
 use crate::autocomplete::AutocompleteState;
 use crate::input::InputState;
@@ -87,6 +89,24 @@ pub fn handle_suggestion_selection(
     false
 }
 
+/// Handle a key while a follow-up is being typed in the AI popup
+///
+/// Enter sends the follow-up with the conversation so far, Esc discards it,
+/// and other keys edit it.
+pub fn handle_follow_up_key(ai_state: &mut AiState, key: KeyEvent, query: &str) {
+    match key.code {
+        KeyCode::Esc => ai_state.cancel_follow_up(),
+        KeyCode::Enter => {
+            ai_state.submit_follow_up(query);
+        }
+        _ => {
+            if let Some(textarea) = ai_state.follow_up_input_mut() {
+                textarea.input(Input::from(key));
+            }
+        }
+    }
+}
+
 /// Poll the response channel for incoming AI responses
 ///
 /// This should be called in the main event loop to process streaming responses.
@@ -156,6 +176,7 @@ pub fn handle_execution_result(
 
     ai_state.cancel_in_flight_request();
     ai_state.clear_stale_response();
+    ai_state.clear_conversation();
     ai_state.set_last_query_hash(query);
 
     match query_result {
@@ -169,7 +190,7 @@ pub fn handle_execution_result(
                     params,
                     ai_state.max_context_length,
                 );
-                let prompt = build_prompt(&context);
+                let prompt = build_configured_prompt(&context, &ai_state.prompt_templates);
                 ai_state.send_request(prompt);
             }
         }
@@ -183,7 +204,7 @@ pub fn handle_execution_result(
                     params,
                     ai_state.max_context_length,
                 );
-                let prompt = build_prompt(&context);
+                let prompt = build_configured_prompt(&context, &ai_state.prompt_templates);
                 ai_state.send_request(prompt);
             }
         }
@@ -237,6 +258,15 @@ fn process_response(ai_state: &mut AiState, response: AiResponse) {
             }
             ai_state.complete_request();
         }
+        AiResponse::Fallback {
+            request_id,
+            provider,
+        } => {
+            if request_id < current_request_id {
+                return;
+            }
+            ai_state.fall_back_to(provider);
+        }
         AiResponse::Error(error_msg) => {
             ai_state.set_error(error_msg);
         }
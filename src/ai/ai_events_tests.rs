@@ -10,6 +10,8 @@ mod ai_flow_tests;
 mod application_tests;
 #[path = "ai_events_tests/debounce_tests.rs"]
 mod debounce_tests;
+#[path = "ai_events_tests/follow_up_tests.rs"]
+mod follow_up_tests;
 #[path = "ai_events_tests/property_tests.rs"]
 mod property_tests;
 #[path = "ai_events_tests/query_result_tests.rs"]
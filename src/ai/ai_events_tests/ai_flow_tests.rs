@@ -270,3 +270,69 @@ fn test_base_query_not_passed_on_success() {
         panic!("Expected Query request");
     }
 }
+
+/// Test: the worker falls back to another provider mid-request
+/// Validates the partial response is dropped and the popup follows the new provider
+#[test]
+fn test_fallback_switches_active_provider() {
+    let mut ai_state = AiState::new(true);
+    ai_state.visible = true;
+    let (tx, rx) = mpsc::channel();
+    ai_state.response_rx = Some(rx);
+    ai_state.providers = vec![
+        crate::ai::ai_state::AiProviderInfo {
+            name: "Anthropic".to_string(),
+            model: "claude".to_string(),
+        },
+        crate::ai::ai_state::AiProviderInfo {
+            name: "OpenAI".to_string(),
+            model: "gpt".to_string(),
+        },
+    ];
+
+    ai_state.start_request();
+    let request_id = ai_state.current_request_id();
+    tx.send(AiResponse::Chunk {
+        text: "partial".to_string(),
+        request_id,
+    })
+    .unwrap();
+    tx.send(AiResponse::Fallback {
+        request_id,
+        provider: 1,
+    })
+    .unwrap();
+    poll_response_channel(&mut ai_state);
+
+    assert!(ai_state.response.is_empty());
+    assert!(ai_state.loading);
+    assert_eq!(ai_state.active_provider_info(), ("OpenAI", "gpt"));
+
+    // A fallback from an older request is ignored
+    tx.send(AiResponse::Fallback {
+        request_id: request_id - 1,
+        provider: 0,
+    })
+    .unwrap();
+    poll_response_channel(&mut ai_state);
+    assert_eq!(ai_state.active_provider_info(), ("OpenAI", "gpt"));
+}
+
+/// Test: a configured prompt template replaces the built-in prompt
+#[test]
+fn test_flow_uses_configured_prompt_template() {
+    let mut ai_state = AiState::new(true);
+    ai_state.visible = true;
+    let (tx, rx) = mpsc::channel();
+    ai_state.request_tx = Some(tx);
+    ai_state.prompt_templates = crate::config::ai_types::AiPromptsConfig {
+        error: Some("Team rules. Fix {query}: {error}".to_string()),
+        ..Default::default()
+    };
+
+    let error_result: Result<String, String> = Err("compile error".to_string());
+    handle_execution_result(&mut ai_state, &error_result, ".a |", 4, empty_params());
+
+    let AiRequest::Query { prompt, .. } = rx.try_recv().unwrap();
+    assert_eq!(prompt, "Team rules. Fix .a |: compile error");
+}
@@ -0,0 +1,86 @@
+//! Tests for follow-ups typed in the AI popup
+
+use super::*;
+use crate::ai::context::ContextParams;
+use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+fn empty_params() -> ContextParams<'static> {
+    ContextParams {
+        input_schema: None,
+        base_query: None,
+        base_query_result: None,
+        is_empty_result: false,
+    }
+}
+
+fn key(code: KeyCode) -> KeyEvent {
+    KeyEvent::new(code, KeyModifiers::NONE)
+}
+
+/// State with one finished turn about `.users[]`
+fn answered_state() -> (AiState, mpsc::Receiver<AiRequest>) {
+    let mut ai_state = AiState::new(true);
+    ai_state.visible = true;
+    let (tx, rx) = mpsc::channel();
+    ai_state.request_tx = Some(tx);
+
+    handle_execution_result(
+        &mut ai_state,
+        &Ok("[]".to_string()),
+        ".users[]",
+        8,
+        empty_params(),
+    );
+    ai_state.append_chunk(r#"{"suggestions": []}"#);
+    ai_state.complete_request();
+    while rx.try_recv().is_ok() {}
+    (ai_state, rx)
+}
+
+#[test]
+fn test_typed_follow_up_is_sent_on_enter() {
+    let (mut ai_state, rx) = answered_state();
+    ai_state.start_follow_up();
+
+    for c in "sort it".chars() {
+        handle_follow_up_key(&mut ai_state, key(KeyCode::Char(c)), ".users[]");
+    }
+    handle_follow_up_key(&mut ai_state, key(KeyCode::Enter), ".users[]");
+
+    let AiRequest::Query {
+        prompt, history, ..
+    } = rx.try_recv().unwrap();
+    assert!(prompt.contains("sort it"));
+    assert_eq!(history.len(), 1);
+    assert!(ai_state.loading);
+}
+
+#[test]
+fn test_esc_discards_follow_up() {
+    let (mut ai_state, rx) = answered_state();
+    ai_state.start_follow_up();
+    handle_follow_up_key(&mut ai_state, key(KeyCode::Char('x')), ".users[]");
+
+    handle_follow_up_key(&mut ai_state, key(KeyCode::Esc), ".users[]");
+
+    assert!(!ai_state.is_composing());
+    assert!(rx.try_recv().is_err());
+    assert_eq!(ai_state.conversation.len(), 1);
+}
+
+#[test]
+fn test_query_change_starts_new_conversation() {
+    let (mut ai_state, rx) = answered_state();
+
+    handle_execution_result(
+        &mut ai_state,
+        &Ok("[]".to_string()),
+        ".users[].name",
+        13,
+        empty_params(),
+    );
+
+    let AiRequest::Query { history, .. } = rx.try_recv().unwrap();
+    assert!(history.is_empty());
+    assert!(ai_state.conversation.is_empty());
+}
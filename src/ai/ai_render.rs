@@ -14,6 +14,7 @@ use ratatui::{
 use super::ai_state::AiState;
 use crate::scroll::Scrollable;
 use crate::theme;
+use crate::widgets::text_width::{display_width, take_width};
 use crate::widgets::{popup, scrollbar};
 
 const HORIZONTAL_PADDING: u16 = 1;
@@ -240,6 +241,117 @@ fn render_suggestions_as_widgets(
     }
 }
 
+/// Rows taken above the content by the follow-ups asked so far, and below
+/// it by the follow-up being typed (each with a spacing line)
+fn conversation_rows(ai_state: &AiState) -> (u16, u16) {
+    let asked = ai_state.follow_ups().len() as u16;
+    let above = if asked > 0 { asked + 1 } else { 0 };
+    let below = if ai_state.is_composing() { 2 } else { 0 };
+    (above, below)
+}
+
+/// Split the follow-up rows off `area`, rendering them, and return the area
+/// left for the content
+fn render_conversation(ai_state: &mut AiState, frame: &mut Frame, area: Rect) -> Rect {
+    let (above, below) = conversation_rows(ai_state);
+    let above = above.min(area.height);
+    let below = below.min(area.height - above);
+
+    if above > 0 {
+        let lines: Vec<Line> = ai_state
+            .follow_ups()
+            .into_iter()
+            .map(|follow_up| {
+                Line::from(vec![
+                    Span::styled("› ", Style::default().fg(theme::ai::FOLLOW_UP_MARKER)),
+                    Span::styled(
+                        follow_up.to_string(),
+                        Style::default().fg(theme::ai::FOLLOW_UP_TEXT),
+                    ),
+                ])
+            })
+            .collect();
+        let header = Rect {
+            height: above,
+            ..area
+        };
+        frame.render_widget(Paragraph::new(lines), header);
+    }
+
+    if below > 0
+        && let Some(textarea) = ai_state.follow_up_input_mut()
+    {
+        let row = area.y + area.height - 1;
+        let prompt = "› ";
+        frame.render_widget(
+            Paragraph::new(Span::styled(
+                prompt,
+                Style::default()
+                    .fg(theme::ai::FOLLOW_UP_MARKER)
+                    .add_modifier(Modifier::BOLD),
+            )),
+            Rect {
+                y: row,
+                height: 1,
+                ..area
+            },
+        );
+        textarea.set_style(Style::default().fg(theme::ai::FOLLOW_UP_INPUT));
+        textarea.set_placeholder_text("Ask a follow-up, e.g. now also sort by name");
+        let prompt_width = prompt.chars().count() as u16;
+        frame.render_widget(
+            &*textarea,
+            Rect {
+                x: area.x + prompt_width,
+                y: row,
+                width: area.width.saturating_sub(prompt_width),
+                height: 1,
+            },
+        );
+    }
+
+    Rect {
+        y: area.y + above,
+        height: area.height - above - below,
+        ..area
+    }
+}
+
+/// Rows taken at the bottom by the session's usage estimate
+fn usage_rows(ai_state: &AiState) -> u16 {
+    if ai_state.usage.total_tokens() > 0 {
+        1
+    } else {
+        0
+    }
+}
+
+/// Render the session's token and cost estimate right-aligned on the last
+/// row of `area`, and return the area above it
+fn render_usage(ai_state: &AiState, frame: &mut Frame, area: Rect) -> Rect {
+    let rows = usage_rows(ai_state);
+    if rows == 0 || area.height <= rows {
+        return area;
+    }
+    let footer = Rect {
+        y: area.y + area.height - rows,
+        height: rows,
+        ..area
+    };
+    frame.render_widget(
+        Paragraph::new(Span::styled(
+            ai_state.usage.summary(),
+            Style::default().fg(theme::ai::USAGE),
+        ))
+        .alignment(ratatui::layout::Alignment::Right),
+        footer,
+    );
+    Rect {
+        height: area.height - rows,
+        ..area
+    }
+}
+
 /// Render the AI assistant popup
 ///
 /// Returns the popup area for region tracking.
@@ -256,10 +368,9 @@ pub fn render_popup(ai_state: &mut AiState, frame: &mut Frame, input_area: Rect)
     let frame_area = frame.area();
 
     // For suggestions, calculate height dynamically and position at bottom
-    let has_suggestions = !ai_state.suggestions.is_empty()
-        && ai_state.configured
-        && !ai_state.loading
-        && ai_state.error.is_none();
+    // Offline suggestions are shown without a provider too
+    let has_suggestions =
+        !ai_state.suggestions.is_empty() && !ai_state.loading && ai_state.error.is_none();
 
     let popup_area = if has_suggestions {
         // Pre-calculate content height for suggestions
@@ -268,8 +379,12 @@ pub fn render_popup(ai_state: &mut AiState, frame: &mut Frame, input_area: Rect)
             .width
             .saturating_sub(AUTOCOMPLETE_RESERVED_WIDTH)
             .saturating_sub(2 + HORIZONTAL_PADDING * 2);
-        let content_height =
-            calculate_suggestions_height(ai_state, max_content_width) + VERTICAL_PADDING * 2;
+        let (above, below) = conversation_rows(ai_state);
+        let content_height = calculate_suggestions_height(ai_state, max_content_width)
+            + above
+            + below
+            + usage_rows(ai_state)
+            + VERTICAL_PADDING * 2;
         let area = calculate_popup_area_with_height(frame_area, input_area, content_height)?;
         // Store the height for use during loading transitions
         ai_state.previous_popup_height = Some(area.height);
@@ -285,9 +400,12 @@ pub fn render_popup(ai_state: &mut AiState, frame: &mut Frame, input_area: Rect)
 
     popup::clear_area(frame, popup_area);
 
+    let (provider_name, model_name) = ai_state.active_provider_info();
+    let (provider_name, model_name) = (provider_name.to_string(), model_name.to_string());
+
     let title = Line::from(vec![
         Span::raw(" "),
-        Span::styled(&ai_state.provider_name, theme::ai::TITLE),
+        Span::styled(provider_name, theme::ai::TITLE),
         Span::raw(" "),
     ]);
 
@@ -321,13 +439,13 @@ pub fn render_popup(ai_state: &mut AiState, frame: &mut Frame, input_area: Rect)
     let max_model_width = (popup_area.width / 2)
         .saturating_sub(2)
         .saturating_sub(counter_width / 2);
-    let model_display = if ai_state.model_name.len() > max_model_width as usize {
+    let model_display = if display_width(&model_name) > max_model_width as usize {
         format!(
             "{}...",
-            &ai_state.model_name[..max_model_width.saturating_sub(3) as usize]
+            take_width(&model_name, max_model_width.saturating_sub(3) as usize)
         )
     } else {
-        ai_state.model_name.clone()
+        model_name
     };
 
     let model_name_title = Line::from(vec![
@@ -336,7 +454,19 @@ pub fn render_popup(ai_state: &mut AiState, frame: &mut Frame, input_area: Rect)
         Span::raw(" "),
     ]);
 
-    let hints = if !ai_state.suggestions.is_empty() {
+    let hints = if ai_state.is_composing() {
+        theme::border_hints::build_hints(&[("Enter", "Send"), ("Esc", "Cancel")], theme::ai::BORDER)
+    } else if ai_state.can_follow_up() && !ai_state.suggestions.is_empty() {
+        theme::border_hints::build_hints(
+            &[
+                ("Alt+1-5", "Apply"),
+                ("Alt+↑↓", "Select"),
+                ("Alt+A", "Follow Up"),
+                ("Ctrl+A", "Close"),
+            ],
+            theme::ai::BORDER,
+        )
+    } else if !ai_state.suggestions.is_empty() {
         theme::border_hints::build_hints(
             &[
                 ("Alt+1-5", "Apply"),
@@ -368,8 +498,10 @@ pub fn render_popup(ai_state: &mut AiState, frame: &mut Frame, input_area: Rect)
         // Get inner area with padding for better visual spacing
         let inner_area = block.inner(popup_area);
         let padded_area = popup::inset_rect(inner_area, HORIZONTAL_PADDING, VERTICAL_PADDING);
-        let max_width = padded_area.width;
-        render_suggestions_as_widgets(ai_state, frame, padded_area, max_width);
+        let usage_area = render_usage(ai_state, frame, padded_area);
+        let content_area = render_conversation(ai_state, frame, usage_area);
+        let max_width = content_area.width;
+        render_suggestions_as_widgets(ai_state, frame, content_area, max_width);
 
         // Render scrollbar on border (excluding corners), matching border color
         let scrollbar_area = Rect {
@@ -400,11 +532,13 @@ pub fn render_popup(ai_state: &mut AiState, frame: &mut Frame, input_area: Rect)
         // Get inner area with padding for better visual spacing
         let inner_area = block.inner(popup_area);
         let padded_area = popup::inset_rect(inner_area, HORIZONTAL_PADDING, VERTICAL_PADDING);
+        let usage_area = render_usage(ai_state, frame, padded_area);
+        let content_area = render_conversation(ai_state, frame, usage_area);
 
         // Use traditional content-based rendering for non-suggestion content
-        let content = build_content(ai_state, padded_area.width);
+        let content = build_content(ai_state, content_area.width);
         let popup_widget = Paragraph::new(content).wrap(Wrap { trim: false });
-        frame.render_widget(popup_widget, padded_area);
+        frame.render_widget(popup_widget, content_area);
     }
 
     Some(popup_area)
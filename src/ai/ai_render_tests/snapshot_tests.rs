@@ -532,3 +532,89 @@ fn snapshot_ai_popup_openai_compatible_provider() {
     let output = render_ai_popup_to_string(&mut state, 100, 30);
     assert_snapshot!(output);
 }
+
+#[test]
+fn snapshot_ai_popup_composing_follow_up() {
+    use crate::ai::ai_state::ConversationTurn;
+    use crate::ai::suggestion::{Suggestion, SuggestionType};
+
+    let mut state = AiState::new_with_config(
+        true,
+        true,
+        "Anthropic".to_string(),
+        "claude-3-5-sonnet-20241022".to_string(),
+        TEST_MAX_CONTEXT_LENGTH,
+    );
+    state.visible = true;
+    state.conversation = vec![
+        ConversationTurn {
+            prompt: "prompt".to_string(),
+            follow_up: None,
+            response: String::new(),
+        },
+        ConversationTurn {
+            prompt: "follow-up prompt".to_string(),
+            follow_up: Some("only active users".to_string()),
+            response: String::new(),
+        },
+    ];
+    state.suggestions = vec![Suggestion {
+        query: ".users[] | select(.active)".to_string(),
+        description: "Keep active users".to_string(),
+        suggestion_type: SuggestionType::Next,
+    }];
+    state.start_follow_up();
+    state
+        .follow_up_input_mut()
+        .unwrap()
+        .insert_str("now also sort by name");
+
+    let output = render_ai_popup_to_string(&mut state, 100, 30);
+    assert_snapshot!(output);
+}
+
+#[test]
+fn snapshot_ai_popup_with_session_usage() {
+    use crate::ai::ai_state::AiUsage;
+    use crate::config::ai_types::AiBudgetConfig;
+
+    let mut state = AiState::new_with_config(
+        true,
+        true,
+        "Anthropic".to_string(),
+        "claude-3-5-sonnet-20241022".to_string(),
+        TEST_MAX_CONTEXT_LENGTH,
+    );
+    state.visible = true;
+    state.response = "Try using `.foo[]` to iterate over the array.".to_string();
+    state.usage = AiUsage::with_budget(&AiBudgetConfig {
+        input_cost_per_million: 0.8,
+        output_cost_per_million: 4.0,
+        ..Default::default()
+    });
+    state.usage.input_tokens = 1_200;
+    state.usage.output_tokens = 150;
+
+    let output = render_ai_popup_to_string(&mut state, 100, 30);
+    assert_snapshot!(output);
+}
+
+#[test]
+fn snapshot_ai_popup_with_offline_suggestions() {
+    let mut state = AiState::new_with_config(
+        true,
+        false,
+        "AI".to_string(),
+        String::new(),
+        TEST_MAX_CONTEXT_LENGTH,
+    );
+    state.visible = true;
+    let result = serde_json::json!([
+        {"name": "api", "status": "up", "latency": 12},
+        {"name": "db", "status": "up", "latency": 40}
+    ]);
+    state.set_offline_suggestions(crate::ai::offline::suggest(".services", &result));
+
+    let output = render_ai_popup_to_string(&mut state, 100, 30);
+    assert_snapshot!(output);
+}
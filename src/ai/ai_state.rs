@@ -6,19 +6,28 @@
 use std::sync::mpsc::{Receiver, Sender};
 
 use super::selection::SelectionState;
+use crate::config::ai_types::AiPromptsConfig;
 use tokio_util::sync::CancellationToken;
+use tui_textarea::TextArea;
 
 // Re-export for backward compatibility
 #[allow(unused_imports)]
 pub use super::suggestion::{Suggestion, SuggestionType};
 
 // Module declarations
+#[path = "ai_state/conversation.rs"]
+mod conversation;
 #[path = "ai_state/lifecycle.rs"]
 pub(crate) mod lifecycle;
 #[path = "ai_state/response.rs"]
 mod response;
 #[path = "ai_state/suggestions.rs"]
 mod suggestions;
+#[path = "ai_state/usage.rs"]
+mod usage;
+
+pub use conversation::ConversationTurn;
+pub use usage::AiUsage;
 
 // Test module
 #[cfg(test)]
@@ -30,6 +39,11 @@ pub enum AiRequest {
     /// Query the AI with the given context
     Query {
         prompt: String,
+        /// Earlier turns of the conversation, oldest first
+        history: Vec<ConversationTurn>,
+        /// Provider to ask first, as an index into the worker's routes;
+        /// `None` starts with the configured provider
+        provider: Option<usize>,
         /// Unique ID for this request, used to filter stale responses
         request_id: u64,
         /// Cancellation token for aborting the request
@@ -51,6 +65,13 @@ pub enum AiResponse {
         /// Request ID this completion belongs to
         request_id: u64,
     },
+    /// The provider failed and the request moved on to the next route;
+    /// chunks received so far are discarded
+    Fallback {
+        request_id: u64,
+        /// Index of the provider now answering
+        provider: usize,
+    },
     /// An error occurred
     Error(String),
     /// The request was cancelled
@@ -61,6 +82,14 @@ pub enum AiResponse {
     },
 }
 
+/// A provider requests can be routed to, for display
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AiProviderInfo {
+    /// Display name (e.g., "Anthropic", "OpenAI-compatible")
+    pub name: String,
+    pub model: String,
+}
+
 /// AI Assistant state
 pub struct AiState {
     /// Whether the AI popup is visible
@@ -108,6 +137,21 @@ pub struct AiState {
     /// Previous popup height (when suggestions were last rendered)
     /// Used to maintain consistent size during loading transitions
     pub previous_popup_height: Option<u16>,
+    /// Completed turns of the conversation about the current query
+    pub conversation: Vec<ConversationTurn>,
+    /// Turn of the in-flight request; its response is filled in on completion
+    pub pending_turn: Option<ConversationTurn>,
+    /// Follow-up being typed, when the popup's input is open
+    pub follow_up_input: Option<TextArea<'static>>,
+    /// Providers the worker routes requests to: the configured provider,
+    /// then its fallbacks. Empty until the worker is running.
+    pub providers: Vec<AiProviderInfo>,
+    /// Index into `providers` of the one answering the current request
+    pub active_provider: usize,
+    /// Estimated tokens and cost of the session's requests
+    pub usage: AiUsage,
+    /// Custom prompts from the config, replacing the built-in ones
+    pub prompt_templates: AiPromptsConfig,
 }
 
 impl Default for AiState {
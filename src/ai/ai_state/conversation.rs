@@ -0,0 +1,81 @@
+//! AI conversation management
+//!
+//! Keeps the request/response turns sent for the current query and the
+//! follow-up being typed, so that suggestions can be refined in a chat.
+
+use tui_textarea::TextArea;
+
+use crate::ai::ai_state::AiState;
+use crate::ai::prompt::build_follow_up_prompt;
+
+/// One request/response exchange with the AI provider
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConversationTurn {
+    /// Prompt sent to the provider
+    pub prompt: String,
+    /// Follow-up typed by the user, when the turn was one
+    pub follow_up: Option<String>,
+    /// Complete response text
+    pub response: String,
+}
+
+impl AiState {
+    /// Whether a follow-up can be asked: a response has arrived and no
+    /// request is in flight
+    pub fn can_follow_up(&self) -> bool {
+        self.visible && !self.loading && self.error.is_none() && !self.conversation.is_empty()
+    }
+
+    /// Whether a follow-up is being typed
+    pub fn is_composing(&self) -> bool {
+        self.follow_up_input.is_some()
+    }
+
+    /// Start typing a follow-up
+    pub fn start_follow_up(&mut self) {
+        let mut textarea = TextArea::default();
+        textarea.set_cursor_line_style(ratatui::style::Style::default());
+        self.follow_up_input = Some(textarea);
+    }
+
+    /// Discard the follow-up being typed
+    pub fn cancel_follow_up(&mut self) {
+        self.follow_up_input = None;
+    }
+
+    pub fn follow_up_input_mut(&mut self) -> Option<&mut TextArea<'static>> {
+        self.follow_up_input.as_mut()
+    }
+
+    /// Send the typed follow-up with the conversation so far
+    ///
+    /// Returns false when nothing was typed or the request couldn't be sent.
+    pub fn submit_follow_up(&mut self, query: &str) -> bool {
+        let Some(textarea) = self.follow_up_input.take() else {
+            return false;
+        };
+        let follow_up = textarea.lines().join(" ").trim().to_string();
+        if follow_up.is_empty() {
+            return false;
+        }
+
+        let prompt = build_follow_up_prompt(&follow_up, query);
+        self.send_turn(prompt, Some(follow_up), None)
+    }
+
+    /// Follow-ups asked so far, including the one awaiting a response
+    pub fn follow_ups(&self) -> Vec<&str> {
+        self.conversation
+            .iter()
+            .chain(self.pending_turn.as_ref())
+            .filter_map(|turn| turn.follow_up.as_deref())
+            .collect()
+    }
+
+    /// Forget the conversation, e.g. when the query changes
+    pub fn clear_conversation(&mut self) {
+        self.conversation.clear();
+        self.pending_turn = None;
+        self.follow_up_input = None;
+    }
+}
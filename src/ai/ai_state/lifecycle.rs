@@ -4,7 +4,8 @@
 
 use super::super::selection::SelectionState;
 use super::super::suggestion::parse_suggestions;
-use crate::ai::ai_state::AiState;
+use crate::ai::ai_state::{AiState, AiUsage};
+use crate::config::ai_types::AiPromptsConfig;
 
 /// Default max context length for tests
 #[cfg(test)]
@@ -37,6 +38,13 @@ impl AiState {
             suggestions: Vec::new(),
             selection: SelectionState::new(),
             previous_popup_height: None,
+            conversation: Vec::new(),
+            pending_turn: None,
+            follow_up_input: None,
+            providers: Vec::new(),
+            active_provider: 0,
+            usage: AiUsage::default(),
+            prompt_templates: AiPromptsConfig::default(),
         }
     }
 
@@ -75,6 +83,13 @@ impl AiState {
             suggestions: Vec::new(),
             selection: SelectionState::new(),
             previous_popup_height: None,
+            conversation: Vec::new(),
+            pending_turn: None,
+            follow_up_input: None,
+            providers: Vec::new(),
+            active_provider: 0,
+            usage: AiUsage::default(),
+            prompt_templates: AiPromptsConfig::default(),
         }
     }
 
@@ -110,22 +125,28 @@ impl AiState {
 
     /// Mark the request as complete
     ///
-    /// Clears loading state, previous response, and in_flight_request_id.
+    /// Clears loading state, previous response, and in_flight_request_id,
+    /// and adds the finished turn to the conversation.
     pub fn complete_request(&mut self) {
         self.loading = false;
         self.previous_response = None;
         self.in_flight_request_id = None;
         self.suggestions = parse_suggestions(&self.response);
         self.selection.clear_layout();
+        if let Some(mut turn) = self.pending_turn.take() {
+            turn.response = self.response.clone();
+            self.conversation.push(turn);
+        }
     }
 
     /// Set an error state
     ///
-    /// Clears loading state and in_flight_request_id.
+    /// Clears loading state, in_flight_request_id and the pending turn.
     pub fn set_error(&mut self, error: String) {
         self.error = Some(error);
         self.loading = false;
         self.in_flight_request_id = None;
+        self.pending_turn = None;
     }
 
     /// Clear AI response and error when query becomes successful (test helper)
@@ -8,11 +8,13 @@ use std::sync::mpsc::{Receiver, Sender};
 
 use tokio_util::sync::CancellationToken;
 
-use crate::ai::ai_state::{AiRequest, AiResponse, AiState};
+use crate::ai::ai_state::usage::estimate_tokens;
+use crate::ai::ai_state::{AiRequest, AiResponse, AiState, ConversationTurn};
 
 impl AiState {
     /// Append a chunk to the current response
     pub fn append_chunk(&mut self, chunk: &str) {
+        self.usage.output_tokens += estimate_tokens(chunk);
         self.response.push_str(chunk);
     }
 
@@ -29,11 +31,28 @@ impl AiState {
     /// 3. Stores the token in current_cancel_token
     /// 4. Sends the request with the token
     pub fn send_request(&mut self, prompt: String) -> bool {
+        self.send_turn(prompt, None, None)
+    }
+
+    /// Send a turn of the conversation, with the earlier turns as history
+    ///
+    /// `follow_up` is the text the user typed when the turn is a follow-up,
+    /// and `provider` overrides which provider is asked first.
+    pub(super) fn send_turn(
+        &mut self,
+        prompt: String,
+        follow_up: Option<String>,
+        provider: Option<usize>,
+    ) -> bool {
         // Check if we have a channel first
         if self.request_tx.is_none() {
             return false;
         }
 
+        if !self.check_budget() {
+            return false;
+        }
+
         // Cancel any existing in-flight request first
         self.cancel_in_flight_request();
 
@@ -46,11 +65,22 @@ impl AiState {
         // Store the token so we can cancel it later
         self.current_cancel_token = Some(cancel_token.clone());
 
+        let history = self.conversation.clone();
+        self.active_provider = provider.unwrap_or(0);
+        self.pending_turn = Some(ConversationTurn {
+            prompt: prompt.clone(),
+            follow_up,
+            response: String::new(),
+        });
+        self.usage.input_tokens += self.request_input_tokens();
+
         // Now send the request
         if let Some(ref tx) = self.request_tx
             && tx
                 .send(AiRequest::Query {
                     prompt,
+                    history,
+                    provider,
                     request_id,
                     cancel_token,
                 })
@@ -60,9 +90,54 @@ impl AiState {
         }
         // If send failed, clear the token
         self.current_cancel_token = None;
+        self.pending_turn = None;
         false
     }
 
+    /// Ask the current request again, starting with the provider after the
+    /// one that answered it
+    ///
+    /// Returns the name of the provider asked, or None when there is no
+    /// other provider or nothing to ask again.
+    pub fn retry_with_next_provider(&mut self) -> Option<String> {
+        if self.providers.len() < 2 {
+            return None;
+        }
+        let turn = if self.pending_turn.is_some() {
+            self.pending_turn.take()
+        } else {
+            self.conversation.pop()
+        }?;
+
+        let next = (self.active_provider + 1) % self.providers.len();
+        if !self.send_turn(turn.prompt, turn.follow_up, Some(next)) {
+            return None;
+        }
+        Some(self.providers[next].name.clone())
+    }
+
+    /// Record that the worker moved the current request on to `provider`
+    /// and drop the text received from the failed one
+    pub fn fall_back_to(&mut self, provider: usize) {
+        self.active_provider = provider;
+        self.response.clear();
+        // The whole request is sent again to the next provider
+        self.usage.input_tokens += self.request_input_tokens();
+    }
+
+    /// Name and model of the provider answering, for the popup title
+    ///
+    /// Without a provider, suggestions come from the offline suggester.
+    pub fn active_provider_info(&self) -> (&str, &str) {
+        if !self.configured && !self.suggestions.is_empty() {
+            return ("Suggestions", "offline");
+        }
+        match self.providers.get(self.active_provider) {
+            Some(info) => (&info.name, &info.model),
+            None => (&self.provider_name, &self.model_name),
+        }
+    }
+
     /// Set the channel handles for communication with the worker thread
     pub fn set_channels(
         &mut self,
@@ -4,5 +4,13 @@
 //! Currently minimal as most suggestion logic is in lifecycle methods.
 
 use crate::ai::ai_state::AiState;
+use crate::ai::suggestion::Suggestion;
 
-impl AiState {}
+impl AiState {
+    /// Show suggestions made without a provider, replacing the current ones
+    pub fn set_offline_suggestions(&mut self, suggestions: Vec<Suggestion>) {
+        self.suggestions = suggestions;
+        self.selection.clear_selection();
+        self.selection.clear_layout();
+    }
+}
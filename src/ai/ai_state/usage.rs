@@ -0,0 +1,135 @@
+//! AI usage tracking
+//!
+//! Estimates the tokens and cost of the AI requests made in a session and
+//! stops further requests once the configured budget is used up.
+
+use crate::ai::ai_state::AiState;
+use crate::config::ai_types::AiBudgetConfig;
+
+/// Characters per token used for estimates; providers don't report usage
+/// while streaming
+const CHARS_PER_TOKEN: usize = 4;
+
+/// Estimate the tokens in a text
+pub fn estimate_tokens(text: &str) -> u64 {
+    text.chars().count().div_ceil(CHARS_PER_TOKEN) as u64
+}
+
+/// Estimated tokens used in the session, with the budget they count against
+#[derive(Debug, Clone, Default)]
+pub struct AiUsage {
+    /// Estimated tokens sent, including conversation history and fallbacks
+    pub input_tokens: u64,
+    /// Estimated tokens received
+    pub output_tokens: u64,
+    budget: AiBudgetConfig,
+    /// Whether the user has been told the budget is used up
+    notified: bool,
+}
+
+impl AiUsage {
+    pub fn with_budget(budget: &AiBudgetConfig) -> Self {
+        Self {
+            budget: budget.clone(),
+            ..Self::default()
+        }
+    }
+
+    pub fn total_tokens(&self) -> u64 {
+        self.input_tokens + self.output_tokens
+    }
+
+    /// Estimated cost, or None when no prices are configured
+    pub fn estimated_cost(&self) -> Option<f64> {
+        let budget = &self.budget;
+        if budget.input_cost_per_million <= 0.0 && budget.output_cost_per_million <= 0.0 {
+            return None;
+        }
+        Some(
+            (self.input_tokens as f64 * budget.input_cost_per_million
+                + self.output_tokens as f64 * budget.output_cost_per_million)
+                / 1_000_000.0,
+        )
+    }
+
+    /// Description of the limit that has been reached, if any
+    pub fn exceeded_limit(&self) -> Option<String> {
+        if let Some(max_tokens) = self.budget.max_tokens
+            && self.total_tokens() >= max_tokens
+        {
+            return Some(format!("{} tokens", format_tokens(max_tokens)));
+        }
+        if let (Some(max_cost), Some(cost)) = (self.budget.max_cost, self.estimated_cost())
+            && cost >= max_cost
+        {
+            return Some(format!("${:.2}", max_cost));
+        }
+        None
+    }
+
+    /// Running total for the popup footer, e.g. "~1.2k tokens · $0.0031"
+    pub fn summary(&self) -> String {
+        let tokens = format!("~{} tokens", format_tokens(self.total_tokens()));
+        match self.estimated_cost() {
+            Some(cost) => format!("{} · ${:.4}", tokens, cost),
+            None => tokens,
+        }
+    }
+
+    /// Take the one-time notice that the budget is used up
+    pub fn take_exceeded_notice(&mut self) -> Option<String> {
+        if self.notified {
+            return None;
+        }
+        let limit = self.exceeded_limit()?;
+        self.notified = true;
+        Some(format!(
+            "AI budget of {} reached; requests are paused for this session",
+            limit
+        ))
+    }
+}
+
+/// Format a token count compactly: 950, 1.2k, 3.4M
+fn format_tokens(tokens: u64) -> String {
+    if tokens >= 1_000_000 {
+        format!("{:.1}M", tokens as f64 / 1_000_000.0)
+    } else if tokens >= 1_000 {
+        format!("{:.1}k", tokens as f64 / 1_000.0)
+    } else {
+        tokens.to_string()
+    }
+}
+
+impl AiState {
+    /// Estimated input tokens of the in-flight request: the conversation
+    /// so far and the pending prompt
+    pub(super) fn request_input_tokens(&self) -> u64 {
+        self.conversation
+            .iter()
+            .chain(self.pending_turn.iter())
+            .map(|turn| estimate_tokens(&turn.prompt) + estimate_tokens(&turn.response))
+            .sum()
+    }
+
+    /// Refuse a request when the budget is used up, showing why in the popup
+    ///
+    /// Returns true when the request may be sent.
+    pub(super) fn check_budget(&mut self) -> bool {
+        match self.usage.exceeded_limit() {
+            Some(limit) => {
+                self.cancel_in_flight_request();
+                self.response.clear();
+                self.previous_response = None;
+                self.suggestions.clear();
+                self.set_error(format!(
+                    "Session budget of {} used ({}). Raise [ai.budget] in the config to continue.",
+                    limit,
+                    self.usage.summary()
+                ));
+                false
+            }
+            None => true,
+        }
+    }
+}
@@ -424,3 +424,266 @@ fn test_selection_persists_after_complete_request() {
     // Selection should persist (user may want to apply it)
     assert_eq!(state.selection.get_selected(), Some(0));
 }
+
+fn state_with_channel() -> (AiState, std::sync::mpsc::Receiver<super::AiRequest>) {
+    let mut state = AiState::new(true);
+    state.visible = true;
+    let (tx, rx) = std::sync::mpsc::channel();
+    state.request_tx = Some(tx);
+    (state, rx)
+}
+
+fn answer(state: &mut AiState, response: &str) {
+    state.append_chunk(response);
+    state.complete_request();
+}
+
+#[test]
+fn test_completed_request_is_added_to_conversation() {
+    let (mut state, _rx) = state_with_channel();
+
+    state.send_request("prompt".to_string());
+    answer(&mut state, "response");
+
+    assert_eq!(
+        state.conversation,
+        vec![super::ConversationTurn {
+            prompt: "prompt".to_string(),
+            follow_up: None,
+            response: "response".to_string(),
+        }]
+    );
+    assert!(state.pending_turn.is_none());
+}
+
+#[test]
+fn test_follow_up_sends_conversation_as_history() {
+    let (mut state, rx) = state_with_channel();
+    state.send_request("prompt".to_string());
+    answer(&mut state, "response");
+    rx.try_recv().unwrap();
+
+    state.start_follow_up();
+    state
+        .follow_up_input_mut()
+        .unwrap()
+        .insert_str("now also sort by name");
+    assert!(state.submit_follow_up(".users[]"));
+
+    let super::AiRequest::Query {
+        prompt, history, ..
+    } = rx.try_recv().unwrap();
+    assert!(prompt.contains("now also sort by name"));
+    assert_eq!(history, state.conversation);
+    assert!(!state.is_composing());
+    assert_eq!(state.follow_ups(), vec!["now also sort by name"]);
+}
+
+#[test]
+fn test_empty_follow_up_is_not_sent() {
+    let (mut state, rx) = state_with_channel();
+    state.send_request("prompt".to_string());
+    answer(&mut state, "response");
+    rx.try_recv().unwrap();
+
+    state.start_follow_up();
+    state.follow_up_input_mut().unwrap().insert_str("  ");
+
+    assert!(!state.submit_follow_up(".users[]"));
+    assert!(rx.try_recv().is_err());
+}
+
+#[test]
+fn test_follow_up_needs_a_finished_response() {
+    let (mut state, _rx) = state_with_channel();
+    assert!(!state.can_follow_up());
+
+    state.send_request("prompt".to_string());
+    assert!(!state.can_follow_up());
+
+    answer(&mut state, "response");
+    assert!(state.can_follow_up());
+}
+
+#[test]
+fn test_failed_request_is_not_added_to_conversation() {
+    let (mut state, _rx) = state_with_channel();
+
+    state.send_request("prompt".to_string());
+    state.set_error("API error".to_string());
+
+    assert!(state.conversation.is_empty());
+    assert!(state.pending_turn.is_none());
+}
+
+fn with_providers(state: &mut AiState) {
+    state.providers = vec![
+        super::AiProviderInfo {
+            name: "Anthropic".to_string(),
+            model: "claude".to_string(),
+        },
+        super::AiProviderInfo {
+            name: "OpenAI".to_string(),
+            model: "gpt".to_string(),
+        },
+    ];
+}
+
+#[test]
+fn test_retry_asks_next_provider_again() {
+    let (mut state, rx) = state_with_channel();
+    with_providers(&mut state);
+    state.send_request("prompt".to_string());
+    answer(&mut state, "response");
+    rx.try_recv().unwrap();
+
+    assert_eq!(state.retry_with_next_provider(), Some("OpenAI".to_string()));
+
+    let super::AiRequest::Query {
+        prompt,
+        history,
+        provider,
+        ..
+    } = rx.try_recv().unwrap();
+    assert_eq!(prompt, "prompt");
+    assert!(history.is_empty());
+    assert_eq!(provider, Some(1));
+    assert!(state.conversation.is_empty());
+    assert_eq!(state.active_provider_info(), ("OpenAI", "gpt"));
+
+    answer(&mut state, "other response");
+    assert_eq!(
+        state.retry_with_next_provider(),
+        Some("Anthropic".to_string())
+    );
+}
+
+#[test]
+fn test_retry_needs_another_provider() {
+    let (mut state, _rx) = state_with_channel();
+    state.send_request("prompt".to_string());
+    answer(&mut state, "response");
+
+    assert_eq!(state.retry_with_next_provider(), None);
+    assert_eq!(state.conversation.len(), 1);
+}
+
+#[test]
+fn test_fallback_discards_partial_response() {
+    let (mut state, _rx) = state_with_channel();
+    with_providers(&mut state);
+    state.send_request("prompt".to_string());
+    state.append_chunk("partial");
+
+    state.fall_back_to(1);
+
+    assert!(state.response.is_empty());
+    assert_eq!(state.active_provider_info(), ("OpenAI", "gpt"));
+}
+
+#[test]
+fn test_provider_info_without_routes_uses_configured_names() {
+    let state = AiState::new_with_config(
+        true,
+        true,
+        "Anthropic".to_string(),
+        "claude".to_string(),
+        TEST_MAX_CONTEXT_LENGTH,
+    );
+    assert_eq!(state.active_provider_info(), ("Anthropic", "claude"));
+}
+
+fn budget(max_tokens: Option<u64>, max_cost: Option<f64>) -> super::AiUsage {
+    super::AiUsage::with_budget(&crate::config::ai_types::AiBudgetConfig {
+        max_tokens,
+        max_cost,
+        input_cost_per_million: 1.0,
+        output_cost_per_million: 2.0,
+    })
+}
+
+#[test]
+fn test_usage_counts_prompt_history_and_response() {
+    let (mut state, _rx) = state_with_channel();
+
+    state.send_request("a".repeat(40));
+    assert_eq!(state.usage.input_tokens, 10);
+
+    answer(&mut state, &"b".repeat(20));
+    assert_eq!(state.usage.output_tokens, 5);
+
+    // The follow-up carries the first turn as history
+    state.start_follow_up();
+    state.follow_up_input_mut().unwrap().insert_str("more");
+    state.submit_follow_up(".");
+    let follow_up_prompt = state.pending_turn.as_ref().unwrap().prompt.clone();
+    assert_eq!(
+        state.usage.input_tokens,
+        10 + 10 + 5 + super::usage::estimate_tokens(&follow_up_prompt)
+    );
+}
+
+#[test]
+fn test_fallback_counts_request_again() {
+    let (mut state, _rx) = state_with_channel();
+    state.send_request("a".repeat(40));
+
+    state.fall_back_to(1);
+
+    assert_eq!(state.usage.input_tokens, 20);
+}
+
+#[test]
+fn test_usage_summary_includes_cost_when_priced() {
+    let mut usage = budget(None, None);
+    usage.input_tokens = 1_000_000;
+    usage.output_tokens = 500;
+    assert_eq!(usage.summary(), "~1.0M tokens · $1.0010");
+
+    let mut unpriced = super::AiUsage::default();
+    unpriced.input_tokens = 1_250;
+    assert_eq!(unpriced.estimated_cost(), None);
+    assert_eq!(unpriced.summary(), "~1.2k tokens");
+}
+
+#[test]
+fn test_requests_stop_when_token_budget_is_used() {
+    let (mut state, rx) = state_with_channel();
+    state.usage = budget(Some(15), None);
+
+    assert!(state.send_request("a".repeat(40)));
+    answer(&mut state, &"b".repeat(20));
+    rx.try_recv().unwrap();
+
+    assert!(!state.send_request("again".to_string()));
+    assert!(rx.try_recv().is_err());
+    assert!(!state.loading);
+    assert!(
+        state
+            .error
+            .as_ref()
+            .unwrap()
+            .contains("budget of 15 tokens")
+    );
+    assert!(state.response.is_empty());
+}
+
+#[test]
+fn test_requests_stop_when_cost_budget_is_used() {
+    let mut usage = budget(None, Some(0.5));
+    usage.input_tokens = 400_000;
+    assert_eq!(usage.exceeded_limit(), None);
+
+    usage.output_tokens = 50_000;
+    assert_eq!(usage.exceeded_limit(), Some("$0.50".to_string()));
+}
+
+#[test]
+fn test_budget_notice_is_given_once() {
+    let mut usage = budget(Some(10), None);
+    assert_eq!(usage.take_exceeded_notice(), None);
+
+    usage.output_tokens = 10;
+    assert!(usage.take_exceeded_notice().unwrap().contains("10 tokens"));
+    assert_eq!(usage.take_exceeded_notice(), None);
+}
@@ -115,8 +115,12 @@ pub fn truncate_json(json: &str, max_len: usize) -> String {
         return json.to_string();
     }
 
-    // Simple truncation with ellipsis indicator
-    let truncated = &json[..max_len];
+    // Simple truncation with ellipsis indicator, never inside a character
+    let mut end = max_len;
+    while !json.is_char_boundary(end) {
+        end -= 1;
+    }
+    let truncated = &json[..end];
     format!("{}... [truncated]", truncated)
 }
 
@@ -33,6 +33,13 @@ fn test_truncate_json_long() {
     assert!(truncated.ends_with("... [truncated]"));
 }
 
+#[test]
+fn test_truncate_json_inside_multibyte_character() {
+    let json = r#"{"name": "東京都"}"#;
+    let truncated = truncate_json(json, 14);
+    assert_eq!(truncated, r#"{"name": "東... [truncated]"#);
+}
+
 #[test]
 fn test_query_context_new() {
     let query = ".name".to_string();
@@ -0,0 +1,216 @@
+//! Offline query suggestions
+//!
+//! Proposes likely next queries from the shape of the current result, such
+//! as `map`, `select` and `group_by` templates filled in with real field
+//! names. Shown in the AI popup when no provider is configured.
+
+use serde_json::{Map, Value};
+
+use crate::ai::suggestion::{Suggestion, SuggestionType};
+use crate::query::language::{is_jq_identifier, jq_field, json_string};
+use crate::snippets::SnippetApplyMode;
+
+/// Suggestions shown at most, one per Alt+1-5 slot
+const MAX_SUGGESTIONS: usize = 5;
+
+/// Array elements inspected to learn the fields of an array of objects
+const SAMPLE_SIZE: usize = 50;
+
+/// Suggest next queries for `query`, whose result is `value`
+pub fn suggest(query: &str, value: &Value) -> Vec<Suggestion> {
+    let templates = match value {
+        Value::Array(items) if items.is_empty() => Vec::new(),
+        Value::Array(items) if items.iter().all(Value::is_object) => object_array(items),
+        Value::Array(items) if items.iter().all(Value::is_string) => string_array(),
+        Value::Array(items) if items.iter().all(Value::is_number) => number_array(),
+        Value::Array(_) => vec![(
+            "map(type) | unique".to_string(),
+            "List the types of the elements".to_string(),
+        )],
+        Value::Object(map) => object(map),
+        _ => Vec::new(),
+    };
+
+    templates
+        .into_iter()
+        .take(MAX_SUGGESTIONS)
+        .map(|(template, description)| Suggestion {
+            query: SnippetApplyMode::Append.combine(query, &template),
+            description,
+            suggestion_type: SuggestionType::Next,
+        })
+        .collect()
+}
+
+/// A field of an array of objects, with the first non-null value seen
+struct Field<'a> {
+    name: &'a str,
+    sample: &'a Value,
+    /// Whether a value was seen twice, making the field worth grouping by
+    repeats: bool,
+}
+
+/// Fields of the sampled objects in the order they first appear
+fn sample_fields(items: &[Value]) -> Vec<Field<'_>> {
+    let mut fields: Vec<Field> = Vec::new();
+    // Distinct values of each field, to spot repeats
+    let mut seen: Vec<Vec<&Value>> = Vec::new();
+    for item in items.iter().take(SAMPLE_SIZE).filter_map(Value::as_object) {
+        for (name, value) in item {
+            if value.is_null() {
+                continue;
+            }
+            match fields.iter().position(|field| field.name == name) {
+                Some(index) => {
+                    let values = &mut seen[index];
+                    if values.contains(&value) {
+                        fields[index].repeats = true;
+                    } else {
+                        values.push(value);
+                    }
+                }
+                None => {
+                    fields.push(Field {
+                        name,
+                        sample: value,
+                        repeats: false,
+                    });
+                    seen.push(vec![value]);
+                }
+            }
+        }
+    }
+    fields
+}
+
+fn object_array(items: &[Value]) -> Vec<(String, String)> {
+    let fields = sample_fields(items);
+    let text = fields.iter().find(|field| field.sample.is_string());
+    let number = fields.iter().find(|field| field.sample.is_number());
+    let flag = fields.iter().find(|field| field.sample.is_boolean());
+    let group = fields
+        .iter()
+        .find(|field| field.repeats && field.sample.is_string());
+
+    let mut templates = Vec::new();
+    if let Some(field) = text {
+        templates.push((
+            format!("map({})", jq_field(field.name)),
+            format!("List the {} of each object", field.name),
+        ));
+    }
+    if let Some(field) = flag {
+        templates.push((
+            format!("map(select({}))", jq_field(field.name)),
+            format!("Keep objects whose {} is true", field.name),
+        ));
+    } else if let Some(field) = text {
+        let literal = field.sample.to_string();
+        templates.push((
+            format!("map(select({} == {}))", jq_field(field.name), literal),
+            format!("Keep objects whose {} is {}", field.name, literal),
+        ));
+    }
+    if let Some(field) = group {
+        templates.push((
+            format!(
+                "group_by({path}) | map({{{key}: .[0]{path}, count: length}})",
+                path = jq_field(field.name),
+                key = label(field.name)
+            ),
+            format!("Count objects by {}", field.name),
+        ));
+    }
+    if let Some(field) = number {
+        templates.push((
+            format!("sort_by({}) | reverse", jq_field(field.name)),
+            format!("Sort by {}, largest first", field.name),
+        ));
+        templates.push((
+            format!("map({}) | add", jq_field(field.name)),
+            format!("Total of {}", field.name),
+        ));
+    } else if let Some(field) = text {
+        templates.push((
+            format!("sort_by({})", jq_field(field.name)),
+            format!("Sort by {}", field.name),
+        ));
+    }
+    if fields.len() > 2 {
+        let keep: Vec<String> = fields.iter().take(2).map(|field| key(field.name)).collect();
+        templates.push((
+            format!("map({{{}}})", keep.join(", ")),
+            format!("Keep only {} and {}", fields[0].name, fields[1].name),
+        ));
+    }
+    templates
+}
+
+fn string_array() -> Vec<(String, String)> {
+    vec![
+        (
+            "group_by(.) | map({value: .[0], count: length}) | sort_by(-.count)".to_string(),
+            "Count each distinct string, most common first".to_string(),
+        ),
+        ("unique".to_string(), "Remove duplicates".to_string()),
+        ("length".to_string(), "Count the strings".to_string()),
+    ]
+}
+
+fn number_array() -> Vec<(String, String)> {
+    vec![
+        ("add".to_string(), "Total of the numbers".to_string()),
+        (
+            "add / length".to_string(),
+            "Average of the numbers".to_string(),
+        ),
+        ("[min, max]".to_string(), "Smallest and largest".to_string()),
+        ("sort".to_string(), "Sort the numbers".to_string()),
+    ]
+}
+
+fn object(map: &Map<String, Value>) -> Vec<(String, String)> {
+    let mut templates = vec![("keys".to_string(), "List the keys".to_string())];
+    if let Some((name, _)) = map.iter().find(|(_, value)| value.is_array()) {
+        templates.push((
+            format!("{}[]", jq_field(name)),
+            format!("Iterate over {}", name),
+        ));
+    }
+    if map.len() > 1 && map.values().all(Value::is_object) {
+        templates.push((
+            "to_entries | map({key} + .value)".to_string(),
+            "Turn the entries into an array of objects".to_string(),
+        ));
+    }
+    if map.values().any(Value::is_null) {
+        templates.push((
+            "with_entries(select(.value != null))".to_string(),
+            "Drop null fields".to_string(),
+        ));
+    }
+    templates
+}
+
+/// Key for a field in an object construction: `name` or `"first name"`
+fn label(name: &str) -> String {
+    if is_jq_identifier(name) {
+        name.to_string()
+    } else {
+        json_string(name)
+    }
+}
+
+/// Field copied into an object construction, using the shorthand when it
+/// can: `name` or `"first name": .["first name"]`
+fn key(name: &str) -> String {
+    if is_jq_identifier(name) {
+        name.to_string()
+    } else {
+        format!("{}: {}", label(name), jq_field(name))
+    }
+}
+
+#[cfg(test)]
+#[path = "offline_tests.rs"]
+mod offline_tests;
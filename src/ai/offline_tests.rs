@@ -0,0 +1,126 @@
+//! Tests for offline query suggestions
+
+use super::*;
+use serde_json::json;
+
+fn queries(query: &str, value: Value) -> Vec<String> {
+    suggest(query, &value)
+        .into_iter()
+        .map(|suggestion| suggestion.query)
+        .collect()
+}
+
+#[test]
+fn test_array_of_objects_uses_real_field_names() {
+    let value = json!([
+        {"name": "api", "status": "up", "latency": 12},
+        {"name": "db", "status": "down", "latency": 40},
+        {"name": "cache", "status": "up", "latency": 3}
+    ]);
+
+    assert_eq!(
+        queries(".services", value),
+        vec![
+            ".services | map(.name)",
+            r#".services | map(select(.name == "api"))"#,
+            ".services | group_by(.status) | map({status: .[0].status, count: length})",
+            ".services | sort_by(.latency) | reverse",
+            ".services | map(.latency) | add",
+        ]
+    );
+}
+
+#[test]
+fn test_boolean_field_is_used_for_select() {
+    let value = json!([{"id": "a", "active": true}, {"id": "b", "active": false}]);
+
+    let suggestions = suggest(".", &value);
+
+    assert_eq!(suggestions[1].query, "map(select(.active))");
+    assert_eq!(
+        suggestions[1].description,
+        "Keep objects whose active is true"
+    );
+}
+
+#[test]
+fn test_unusual_field_names_are_quoted() {
+    let value = json!([
+        {"first name": "Ada", "last-name": "Lovelace", "x": 1},
+        {"first name": "Ada", "last-name": "Byron", "x": 2}
+    ]);
+
+    let queries = queries(".", value);
+
+    assert_eq!(queries[0], r#"map(.["first name"])"#);
+    assert!(
+        queries.contains(
+            &r#"group_by(.["first name"]) | map({"first name": .[0].["first name"], count: length})"#
+                .to_string()
+        )
+    );
+    assert!(!queries.iter().any(|query| query.starts_with("map({")));
+}
+
+#[test]
+fn test_keywords_and_non_ascii_names_are_quoted() {
+    let value = json!([{"if": 1, "ключ": "a"}]);
+
+    let queries = queries(".", value);
+
+    assert!(queries.contains(&r#"map(.["ключ"])"#.to_string()));
+    assert!(queries.contains(&r#"map(.["if"]) | add"#.to_string()));
+}
+
+#[test]
+fn test_projection_keeps_first_two_fields() {
+    let value = json!([{"id": "a", "name": "x", "note": null, "owner": "me"}]);
+
+    assert_eq!(queries(".", value).last().unwrap(), "map({id, name})");
+}
+
+#[test]
+fn test_array_of_scalars() {
+    assert_eq!(
+        queries(".tags", json!(["a", "b", "a"]))[1],
+        ".tags | unique"
+    );
+    assert_eq!(queries(".", json!([1, 2, 3]))[..2], ["add", "add / length"]);
+    assert_eq!(queries(".", json!([1, "a"])), vec!["map(type) | unique"]);
+}
+
+#[test]
+fn test_object_suggestions() {
+    let value = json!({"items": [1, 2], "meta": null});
+
+    assert_eq!(
+        queries(".", value),
+        vec!["keys", ".items[]", "with_entries(select(.value != null))"]
+    );
+}
+
+#[test]
+fn test_map_of_objects_becomes_array() {
+    let value = json!({"a": {"n": 1}, "b": {"n": 2}});
+
+    assert!(queries(".", value).contains(&"to_entries | map({key} + .value)".to_string()));
+}
+
+#[test]
+fn test_nothing_to_suggest_for_scalars_and_empty_arrays() {
+    assert!(suggest(".", &json!("text")).is_empty());
+    assert!(suggest(".", &json!(null)).is_empty());
+    assert!(suggest(".", &json!([])).is_empty());
+}
+
+#[test]
+fn test_suggestions_are_next_steps() {
+    let suggestions = suggest(".", &json!([1, 2]));
+
+    assert!(
+        suggestions
+            .iter()
+            .all(|suggestion| suggestion.suggestion_type == SuggestionType::Next)
+    );
+    assert!(suggestions.len() <= MAX_SUGGESTIONS);
+}
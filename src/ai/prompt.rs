@@ -4,6 +4,7 @@
 //! Generates different prompts for error troubleshooting vs success optimization.
 
 use super::context::QueryContext;
+use crate::config::ai_types::AiPromptsConfig;
 
 /// Build a prompt based on query context
 ///
@@ -17,6 +18,69 @@ pub fn build_prompt(context: &QueryContext) -> String {
     }
 }
 
+/// Build a prompt from the configured template for the context's kind,
+/// falling back to the built-in prompt
+pub fn build_configured_prompt(context: &QueryContext, prompts: &AiPromptsConfig) -> String {
+    let template = if context.is_success {
+        &prompts.success
+    } else {
+        &prompts.error
+    };
+    match template {
+        Some(template) => fill_template(template, context),
+        None => build_prompt(context),
+    }
+}
+
+/// Replace the placeholders of a custom prompt with the context
+///
+/// Placeholders are substituted in one pass, so braces in the inserted
+/// values are left alone, and unknown placeholders are kept as written.
+fn fill_template(template: &str, context: &QueryContext) -> String {
+    let mut prompt = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        prompt.push_str(&rest[..start]);
+        let tail = &rest[start..];
+        let value = tail
+            .find('}')
+            .and_then(|end| placeholder_value(&tail[1..end], context).map(|value| (value, end)));
+        match value {
+            Some((value, end)) => {
+                prompt.push_str(&value);
+                rest = &tail[end + 1..];
+            }
+            None => {
+                prompt.push('{');
+                rest = &tail[1..];
+            }
+        }
+    }
+    prompt.push_str(rest);
+    prompt
+}
+
+/// Value of a custom prompt placeholder, or None when the name is unknown
+fn placeholder_value(name: &str, context: &QueryContext) -> Option<String> {
+    let value = match name {
+        "query" => context.query.clone(),
+        "cursor" => context.cursor_pos.to_string(),
+        "sample" => context
+            .output_sample
+            .as_ref()
+            .or(context.base_query_result.as_ref())
+            .cloned()
+            .unwrap_or_default(),
+        "error" => context.error.clone().unwrap_or_default(),
+        "schema" => context.input_schema.clone().unwrap_or_default(),
+        "base_query" => context.base_query.clone().unwrap_or_default(),
+        "base_result" => context.base_query_result.clone().unwrap_or_default(),
+        "default" => build_prompt(context),
+        _ => return None,
+    };
+    Some(value)
+}
+
 /// Build a prompt for error troubleshooting
 ///
 /// Creates a prose prompt that includes the query, error message,
@@ -169,6 +233,32 @@ pub fn build_success_prompt(context: &QueryContext) -> String {
     prompt
 }
 
+/// Build a prompt for a follow-up typed in the AI popup
+///
+/// Earlier turns are sent as conversation history, so only the request
+/// and the query as it stands now are included.
+pub fn build_follow_up_prompt(follow_up: &str, query: &str) -> String {
+    let mut prompt = String::new();
+
+    prompt.push_str("## Follow-up Request\n");
+    prompt.push_str(&format!("{}\n\n", follow_up));
+
+    prompt.push_str("## Current Query\n");
+    prompt.push_str(&format!("```\n{}\n```\n\n", query));
+
+    prompt.push_str("## Response Format\n");
+    prompt.push_str(
+        "Refine your earlier suggestions to address the request. Return ONLY a raw JSON object with the same structure as before:\n",
+    );
+    prompt.push_str(r#"{"suggestions": [{"type": "next", "query": "jq_query", "details": "1 line description"}]}"#);
+    prompt.push_str("\n\n");
+    prompt.push_str("- query: valid jq syntax, single line\n");
+    prompt.push_str("- Provide 3-5 suggestions\n");
+    prompt.push_str("- IMPORTANT: Return raw JSON only, do NOT wrap in ```json code fences\n");
+
+    prompt
+}
+
 #[cfg(test)]
 #[path = "prompt_tests.rs"]
 mod prompt_tests;
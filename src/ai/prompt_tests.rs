@@ -286,3 +286,86 @@ fn test_base_query_result_truncation_in_context() {
     assert!(truncated.len() <= MAX_JSON_SAMPLE_LENGTH + 15);
     assert!(truncated.ends_with("... [truncated]"));
 }
+
+#[test]
+fn test_build_follow_up_prompt_includes_request_and_query() {
+    let prompt = build_follow_up_prompt("now also sort by name", ".users[]");
+
+    assert!(prompt.contains("## Follow-up Request\nnow also sort by name"));
+    assert!(prompt.contains("## Current Query\n```\n.users[]\n```"));
+    assert!(prompt.contains(r#"{"suggestions": ["#));
+}
+
+fn failed_query_context() -> QueryContext {
+    QueryContext {
+        query: ".name |".to_string(),
+        cursor_pos: 7,
+        output_sample: None,
+        error: Some("syntax error".to_string()),
+        is_success: false,
+        is_empty_result: false,
+        input_schema: Some("{\"name\": \"string\"}".to_string()),
+        base_query: Some(".name".to_string()),
+        base_query_result: Some("\"Ada\"".to_string()),
+    }
+}
+
+#[test]
+fn test_configured_prompt_fills_placeholders() {
+    let prompts = AiPromptsConfig {
+        error: Some(
+            "Fix `{query}` at {cursor}: {error}. Output was {sample} from {base_query}."
+                .to_string(),
+        ),
+        ..Default::default()
+    };
+
+    let prompt = build_configured_prompt(&failed_query_context(), &prompts);
+
+    assert_eq!(
+        prompt,
+        "Fix `.name |` at 7: syntax error. Output was \"Ada\" from .name."
+    );
+}
+
+#[test]
+fn test_configured_prompt_keeps_unknown_braces() {
+    let mut ctx = failed_query_context();
+    ctx.query = "{query}".to_string();
+    let prompts = AiPromptsConfig {
+        error: Some(r#"Reply as {"suggestions": []} for {query} {unknown}"#.to_string()),
+        ..Default::default()
+    };
+
+    let prompt = build_configured_prompt(&ctx, &prompts);
+
+    assert_eq!(
+        prompt,
+        r#"Reply as {"suggestions": []} for {query} {unknown}"#
+    );
+}
+
+#[test]
+fn test_configured_prompt_can_extend_default() {
+    let ctx = failed_query_context();
+    let prompts = AiPromptsConfig {
+        error: Some("{default}\nUse snake_case field names.".to_string()),
+        ..Default::default()
+    };
+
+    let prompt = build_configured_prompt(&ctx, &prompts);
+
+    assert!(prompt.starts_with(&build_error_prompt(&ctx)));
+    assert!(prompt.ends_with("Use snake_case field names."));
+}
+
+#[test]
+fn test_configured_prompt_uses_template_for_its_kind_only() {
+    let ctx = failed_query_context();
+    let prompts = AiPromptsConfig {
+        success: Some("only for {query}".to_string()),
+        ..Default::default()
+    };
+
+    assert_eq!(build_configured_prompt(&ctx, &prompts), build_prompt(&ctx));
+}
@@ -8,7 +8,7 @@ use std::sync::mpsc::Sender;
 use thiserror::Error;
 use tokio_util::sync::CancellationToken;
 
-use crate::ai::ai_state::AiResponse;
+use crate::ai::ai_state::{AiResponse, ConversationTurn};
 use crate::config::ai_types::{AiConfig, AiProviderType};
 
 mod async_anthropic;
@@ -55,6 +55,31 @@ pub enum AiError {
     Cancelled,
 }
 
+/// Author of a message in the conversation sent to a provider
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChatRole {
+    User,
+    Assistant,
+}
+
+/// Messages of a conversation, oldest first: each earlier turn's prompt and
+/// response, then `prompt`
+pub fn conversation_messages<'a>(
+    history: &'a [ConversationTurn],
+    prompt: &'a str,
+) -> Vec<(ChatRole, &'a str)> {
+    history
+        .iter()
+        .flat_map(|turn| {
+            [
+                (ChatRole::User, turn.prompt.as_str()),
+                (ChatRole::Assistant, turn.response.as_str()),
+            ]
+        })
+        .chain(std::iter::once((ChatRole::User, prompt)))
+        .collect()
+}
+
 /// Async AI provider implementations with cancellation support
 ///
 /// Uses async/await with tokio for non-blocking streaming and
@@ -88,6 +113,31 @@ impl AsyncAiProvider {
         }
     }
 
+    /// Model the provider sends requests to
+    pub fn model(&self) -> &str {
+        match self {
+            AsyncAiProvider::Anthropic(client) => client.model(),
+            AsyncAiProvider::Bedrock(client) => client.model(),
+            AsyncAiProvider::Openai(client) => client.model(),
+            AsyncAiProvider::Gemini(client) => client.model(),
+        }
+    }
+
+    /// Providers to try for a request, in order: the configured provider,
+    /// then each fallback whose section is complete
+    ///
+    /// Returns the configured provider's error when it can't be created.
+    pub fn routes_from_config(config: &AiConfig) -> Result<Vec<Self>, AiError> {
+        let mut routes = vec![Self::from_config(config)?];
+        for provider_type in config.fallback_providers() {
+            match Self::for_provider(config, provider_type) {
+                Ok(provider) => routes.push(provider),
+                Err(e) => log::warn!("Skipping AI fallback provider: {}", e),
+            }
+        }
+        Ok(routes)
+    }
+
     /// Create an async AI provider from configuration
     ///
     /// Returns an error if the configuration is invalid (e.g., missing API key)
@@ -116,6 +166,13 @@ impl AsyncAiProvider {
             });
         }
 
+        Self::for_provider(config, provider_type)
+    }
+
+    /// Create the async AI provider of `provider_type` from its config section
+    ///
+    /// Returns an error if that section is incomplete (e.g., missing API key)
+    pub fn for_provider(config: &AiConfig, provider_type: AiProviderType) -> Result<Self, AiError> {
         match provider_type {
             AiProviderType::Anthropic => {
                 let api_key = config
@@ -138,11 +195,14 @@ impl AsyncAiProvider {
                         message: "Missing model. Add 'model' in [ai.anthropic] section (e.g., 'claude-haiku-4-5-20251001'). See https://github.com/bellicose100xp/jiq#configuration for examples.".to_string(),
                     })?;
 
-                let provider = AsyncAiProvider::Anthropic(AsyncAnthropicClient::new(
-                    api_key.clone(),
-                    model.clone(),
-                    config.anthropic.max_tokens,
-                ));
+                let provider = AsyncAiProvider::Anthropic(
+                    AsyncAnthropicClient::new(
+                        api_key.clone(),
+                        model.clone(),
+                        config.anthropic.max_tokens,
+                    )
+                    .with_system_prompt(config.prompts.system.clone()),
+                );
 
                 // Use provider_name to avoid dead code warning
                 let _ = provider.provider_name();
@@ -169,11 +229,14 @@ impl AsyncAiProvider {
                         message: "Missing model. Add 'model' in [ai.bedrock] section (e.g., 'anthropic.claude-3-haiku-20240307-v1:0'). See https://github.com/bellicose100xp/jiq#configuration for examples.".to_string(),
                     })?;
 
-                let provider = AsyncAiProvider::Bedrock(AsyncBedrockClient::new(
-                    region.clone(),
-                    model.clone(),
-                    config.bedrock.profile.clone(),
-                ));
+                let provider = AsyncAiProvider::Bedrock(
+                    AsyncBedrockClient::new(
+                        region.clone(),
+                        model.clone(),
+                        config.bedrock.profile.clone(),
+                    )
+                    .with_system_prompt(config.prompts.system.clone()),
+                );
 
                 // Use provider_name to avoid dead code warning
                 let _ = provider.provider_name();
@@ -215,11 +278,10 @@ impl AsyncAiProvider {
                         message: "Missing model. Add 'model' in [ai.openai] section.".to_string(),
                     })?;
 
-                let provider = AsyncAiProvider::Openai(AsyncOpenAiClient::new(
-                    api_key,
-                    model.clone(),
-                    config.openai.base_url.clone(),
-                ));
+                let provider = AsyncAiProvider::Openai(
+                    AsyncOpenAiClient::new(api_key, model.clone(), config.openai.base_url.clone())
+                        .with_system_prompt(config.prompts.system.clone()),
+                );
 
                 // Use provider_name to avoid dead code warning
                 let _ = provider.provider_name();
@@ -247,8 +309,10 @@ impl AsyncAiProvider {
                         message: "Missing model. Add 'model' in [ai.gemini] section.".to_string(),
                     })?;
 
-                let provider =
-                    AsyncAiProvider::Gemini(AsyncGeminiClient::new(api_key.clone(), model.clone()));
+                let provider = AsyncAiProvider::Gemini(
+                    AsyncGeminiClient::new(api_key.clone(), model.clone())
+                        .with_system_prompt(config.prompts.system.clone()),
+                );
 
                 // Use provider_name to avoid dead code warning
                 let _ = provider.provider_name();
@@ -263,6 +327,7 @@ impl AsyncAiProvider {
     /// Can be cancelled via the CancellationToken.
     ///
     /// # Arguments
+    /// * `history` - Earlier turns of the conversation, oldest first
     /// * `prompt` - The prompt to send to the API
     /// * `request_id` - Unique ID for this request
     /// * `cancel_token` - Token to cancel the request
@@ -274,6 +339,7 @@ impl AsyncAiProvider {
     /// * `Err(AiError::*)` - Other errors
     pub async fn stream_with_cancel(
         &self,
+        history: &[ConversationTurn],
         prompt: &str,
         request_id: u64,
         cancel_token: CancellationToken,
@@ -282,22 +348,22 @@ impl AsyncAiProvider {
         match self {
             AsyncAiProvider::Anthropic(client) => {
                 client
-                    .stream_with_cancel(prompt, request_id, cancel_token, response_tx)
+                    .stream_with_cancel(history, prompt, request_id, cancel_token, response_tx)
                     .await
             }
             AsyncAiProvider::Bedrock(client) => {
                 client
-                    .stream_with_cancel(prompt, request_id, cancel_token, response_tx)
+                    .stream_with_cancel(history, prompt, request_id, cancel_token, response_tx)
                     .await
             }
             AsyncAiProvider::Openai(client) => {
                 client
-                    .stream_with_cancel(prompt, request_id, cancel_token, response_tx)
+                    .stream_with_cancel(history, prompt, request_id, cancel_token, response_tx)
                     .await
             }
             AsyncAiProvider::Gemini(client) => {
                 client
-                    .stream_with_cancel(prompt, request_id, cancel_token, response_tx)
+                    .stream_with_cancel(history, prompt, request_id, cancel_token, response_tx)
                     .await
             }
         }
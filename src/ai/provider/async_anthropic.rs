@@ -9,9 +9,9 @@ use futures::StreamExt;
 use reqwest::Client;
 use tokio_util::sync::CancellationToken;
 
-use super::AiError;
 use super::sse::{AnthropicEventParser, SseParser};
-use crate::ai::ai_state::AiResponse;
+use super::{AiError, ChatRole, conversation_messages};
+use crate::ai::ai_state::{AiResponse, ConversationTurn};
 
 /// Anthropic API endpoint
 const ANTHROPIC_API_URL: &str = "https://api.anthropic.com/v1/messages";
@@ -29,6 +29,7 @@ pub struct AsyncAnthropicClient {
     api_key: String,
     model: String,
     max_tokens: u32,
+    system_prompt: Option<String>,
 }
 
 impl AsyncAnthropicClient {
@@ -39,15 +40,55 @@ impl AsyncAnthropicClient {
             api_key,
             model,
             max_tokens,
+            system_prompt: None,
         }
     }
 
+    /// Instructions sent as the system prompt of every request
+    pub fn with_system_prompt(mut self, system_prompt: Option<String>) -> Self {
+        self.system_prompt = system_prompt;
+        self
+    }
+
+    /// Model requests are sent to
+    pub fn model(&self) -> &str {
+        &self.model
+    }
+
+    /// Build the request body for the Anthropic Messages API
+    ///
+    /// Includes the system prompt, when set, as the top-level `system` field.
+    fn build_request_body(&self, history: &[ConversationTurn], prompt: &str) -> serde_json::Value {
+        let messages: Vec<serde_json::Value> = conversation_messages(history, prompt)
+            .into_iter()
+            .map(|(role, content)| {
+                let role = match role {
+                    ChatRole::User => "user",
+                    ChatRole::Assistant => "assistant",
+                };
+                serde_json::json!({ "role": role, "content": content })
+            })
+            .collect();
+
+        let mut body = serde_json::json!({
+            "model": self.model,
+            "max_tokens": self.max_tokens,
+            "stream": true,
+            "messages": messages
+        });
+        if let Some(system_prompt) = &self.system_prompt {
+            body["system"] = serde_json::Value::from(system_prompt.as_str());
+        }
+        body
+    }
+
     /// Stream a response from the Anthropic API with cancellation support
     ///
     /// Uses `tokio::select!` to race the stream against the cancellation token.
     /// Sends chunks via the response channel as they arrive.
     ///
     /// # Arguments
+    /// * `history` - Earlier turns of the conversation, oldest first
     /// * `prompt` - The prompt to send to the API
     /// * `request_id` - Unique ID for this request
     /// * `cancel_token` - Token to cancel the request
@@ -59,6 +100,7 @@ impl AsyncAnthropicClient {
     /// * `Err(AiError::*)` - Other errors
     pub async fn stream_with_cancel(
         &self,
+        history: &[ConversationTurn],
         prompt: &str,
         request_id: u64,
         cancel_token: CancellationToken,
@@ -69,17 +111,7 @@ impl AsyncAnthropicClient {
             return Err(AiError::Cancelled);
         }
 
-        let request_body = serde_json::json!({
-            "model": self.model,
-            "max_tokens": self.max_tokens,
-            "stream": true,
-            "messages": [
-                {
-                    "role": "user",
-                    "content": prompt
-                }
-            ]
-        });
+        let request_body = self.build_request_body(history, prompt);
 
         let body = serde_json::to_string(&request_body).map_err(|e| AiError::Parse {
             provider: "Anthropic".to_string(),
@@ -144,6 +144,7 @@ proptest! {
         // Run the async function
         let result = rt.block_on(async {
             client.stream_with_cancel(
+                &[],
                 &prompt,
                 1,
                 cancel_token,
@@ -190,6 +191,7 @@ proptest! {
         // Run the async function
         let result = rt.block_on(async {
             client.stream_with_cancel(
+                &[],
                 &prompt,
                 request_id,
                 cancel_token,
@@ -275,3 +277,24 @@ fn test_anthropic_uses_shared_sse_parser() {
     assert_eq!(results3.len(), 1);
     assert_eq!(results3[0], "Final");
 }
+
+#[test]
+fn test_system_prompt_is_sent_as_system_field() {
+    let client = AsyncAnthropicClient::new(
+        "sk-ant-test".to_string(),
+        "claude-haiku-4-5".to_string(),
+        512,
+    );
+    assert!(
+        client
+            .build_request_body(&[], "prompt")
+            .get("system")
+            .is_none()
+    );
+
+    let client = client.with_system_prompt(Some("Answer in German".to_string()));
+    let body = client.build_request_body(&[], "prompt");
+
+    assert_eq!(body["system"], "Answer in German");
+    assert_eq!(body["messages"][0]["content"], "prompt");
+}
@@ -8,12 +8,12 @@ use std::sync::mpsc::Sender;
 
 use aws_config::BehaviorVersion;
 use aws_sdk_bedrockruntime::Client as BedrockRuntimeClient;
-use aws_sdk_bedrockruntime::types::{ContentBlock, ConversationRole, Message};
+use aws_sdk_bedrockruntime::types::{ContentBlock, ConversationRole, Message, SystemContentBlock};
 use futures::FutureExt;
 use tokio_util::sync::CancellationToken;
 
-use super::AiError;
-use crate::ai::ai_state::AiResponse;
+use super::{AiError, ChatRole, conversation_messages};
+use crate::ai::ai_state::{AiResponse, ConversationTurn};
 
 /// Async AWS Bedrock client with streaming support
 ///
@@ -24,6 +24,7 @@ pub struct AsyncBedrockClient {
     region: String,
     model: String,
     profile: Option<String>,
+    system_prompt: Option<String>,
 }
 
 impl AsyncBedrockClient {
@@ -38,9 +39,21 @@ impl AsyncBedrockClient {
             region,
             model,
             profile,
+            system_prompt: None,
         }
     }
 
+    /// Instructions sent as the system prompt of every request
+    pub fn with_system_prompt(mut self, system_prompt: Option<String>) -> Self {
+        self.system_prompt = system_prompt;
+        self
+    }
+
+    /// Model requests are sent to
+    pub fn model(&self) -> &str {
+        &self.model
+    }
+
     /// Build the AWS Bedrock client based on configuration
     ///
     /// Uses named profile credentials if profile is Some,
@@ -100,6 +113,7 @@ impl AsyncBedrockClient {
     /// Sends chunks via the response channel as they arrive.
     ///
     /// # Arguments
+    /// * `history` - Earlier turns of the conversation, oldest first
     /// * `prompt` - The prompt to send to the API
     /// * `request_id` - Unique ID for this request
     /// * `cancel_token` - Token to cancel the request
@@ -111,6 +125,7 @@ impl AsyncBedrockClient {
     /// * `Err(AiError::*)` - Other errors
     pub async fn stream_with_cancel(
         &self,
+        history: &[ConversationTurn],
         prompt: &str,
         request_id: u64,
         cancel_token: CancellationToken,
@@ -124,19 +139,33 @@ impl AsyncBedrockClient {
         // Build the client
         let client = self.build_client().await?;
 
-        // Create the message for the conversation
-        let message = Message::builder()
-            .role(ConversationRole::User)
-            .content(ContentBlock::Text(prompt.to_string()))
-            .build()
-            .map_err(|e| AiError::AwsSdk(format!("Failed to build message: {}", e)))?;
+        // Create the messages of the conversation
+        let messages = conversation_messages(history, prompt)
+            .into_iter()
+            .map(|(role, text)| {
+                let role = match role {
+                    ChatRole::User => ConversationRole::User,
+                    ChatRole::Assistant => ConversationRole::Assistant,
+                };
+                Message::builder()
+                    .role(role)
+                    .content(ContentBlock::Text(text.to_string()))
+                    .build()
+                    .map_err(|e| AiError::AwsSdk(format!("Failed to build message: {}", e)))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
 
         // Start the streaming conversation
         // Note: For inference profile ARNs, the region in the ARN should match the client region
         let mut stream_output = client
             .converse_stream()
             .model_id(&self.model)
-            .messages(message)
+            .set_messages(Some(messages))
+            .set_system(
+                self.system_prompt
+                    .clone()
+                    .map(|text| vec![SystemContentBlock::Text(text)]),
+            )
             .send()
             .await
             .map_err(|e| {
@@ -10,9 +10,9 @@ use reqwest::Client;
 use serde::Serialize;
 use tokio_util::sync::CancellationToken;
 
-use super::AiError;
 use super::sse::{GeminiEventParser, SseParser};
-use crate::ai::ai_state::AiResponse;
+use super::{AiError, ChatRole, conversation_messages};
+use crate::ai::ai_state::{AiResponse, ConversationTurn};
 
 /// Gemini API endpoint
 const GEMINI_API_URL: &str = "https://generativelanguage.googleapis.com/v1beta/models";
@@ -26,6 +26,7 @@ pub struct AsyncGeminiClient {
     client: Client,
     api_key: String,
     model: String,
+    system_prompt: Option<String>,
 }
 
 impl AsyncGeminiClient {
@@ -35,33 +36,45 @@ impl AsyncGeminiClient {
             client: Client::new(),
             api_key,
             model,
+            system_prompt: None,
         }
     }
 
+    /// Instructions sent as the system prompt of every request
+    pub fn with_system_prompt(mut self, system_prompt: Option<String>) -> Self {
+        self.system_prompt = system_prompt;
+        self
+    }
+
     /// Returns the stored API key (used in tests)
     #[cfg(test)]
     pub fn api_key(&self) -> &str {
         &self.api_key
     }
 
-    /// Returns the stored model (used in tests)
-    #[cfg(test)]
+    /// Model requests are sent to
     pub fn model(&self) -> &str {
         &self.model
     }
 
     /// Build the request body JSON for Gemini API
     ///
-    /// Creates a JSON request body with the contents array containing user role and parts.
+    /// Creates a JSON request body with a contents entry per message, `model` being the role of earlier responses.
     /// Gemini uses query parameters for streaming, not a body field.
+    /// The system prompt, when set, is sent as `systemInstruction`.
     ///
     /// # Arguments
+    /// * `history` - Earlier turns of the conversation, oldest first
     /// * `prompt` - The user prompt to send to the API
     ///
     /// # Returns
     /// * `Ok(String)` - Serialized JSON request body
     /// * `Err(AiError::Parse)` - If serialization fails
-    fn build_request_body(&self, prompt: &str) -> Result<String, AiError> {
+    fn build_request_body(
+        &self,
+        history: &[ConversationTurn],
+        prompt: &str,
+    ) -> Result<String, AiError> {
         #[derive(Serialize)]
         struct Part {
             text: String,
@@ -73,18 +86,35 @@ impl AsyncGeminiClient {
             parts: Vec<Part>,
         }
 
+        #[derive(Serialize)]
+        struct SystemInstruction {
+            parts: Vec<Part>,
+        }
+
         #[derive(Serialize)]
         struct RequestBody {
+            #[serde(rename = "systemInstruction", skip_serializing_if = "Option::is_none")]
+            system_instruction: Option<SystemInstruction>,
             contents: Vec<Content>,
         }
 
         let body = RequestBody {
-            contents: vec![Content {
-                role: "user".to_string(),
-                parts: vec![Part {
-                    text: prompt.to_string(),
-                }],
-            }],
+            system_instruction: self.system_prompt.as_ref().map(|text| SystemInstruction {
+                parts: vec![Part { text: text.clone() }],
+            }),
+            contents: conversation_messages(history, prompt)
+                .into_iter()
+                .map(|(role, text)| Content {
+                    role: match role {
+                        ChatRole::User => "user",
+                        ChatRole::Assistant => "model",
+                    }
+                    .to_string(),
+                    parts: vec![Part {
+                        text: text.to_string(),
+                    }],
+                })
+                .collect(),
         };
 
         serde_json::to_string(&body).map_err(|e| AiError::Parse {
@@ -109,6 +139,7 @@ impl AsyncGeminiClient {
     /// Sends chunks via the response channel as they arrive.
     ///
     /// # Arguments
+    /// * `history` - Earlier turns of the conversation, oldest first
     /// * `prompt` - The prompt to send to the API
     /// * `request_id` - Unique ID for this request
     /// * `cancel_token` - Token to cancel the request
@@ -120,6 +151,7 @@ impl AsyncGeminiClient {
     /// * `Err(AiError::*)` - Other errors
     pub async fn stream_with_cancel(
         &self,
+        history: &[ConversationTurn],
         prompt: &str,
         request_id: u64,
         cancel_token: CancellationToken,
@@ -131,7 +163,7 @@ impl AsyncGeminiClient {
         }
 
         // Build request body and URL
-        let body = self.build_request_body(prompt)?;
+        let body = self.build_request_body(history, prompt)?;
         let url = self.build_url();
 
         // Make the POST request to Gemini API
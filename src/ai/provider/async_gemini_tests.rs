@@ -1,6 +1,7 @@
 //! Tests for Async Gemini API client
 
 use super::*;
+use crate::ai::ai_state::ConversationTurn;
 use insta::assert_snapshot;
 use proptest::prelude::*;
 
@@ -89,7 +90,7 @@ proptest! {
         );
 
         // Build the request body
-        let result = client.build_request_body(&prompt);
+        let result = client.build_request_body(&[], &prompt);
 
         // Verify the request body was created successfully
         prop_assert!(result.is_ok(), "Request body should serialize successfully");
@@ -143,7 +144,7 @@ fn snapshot_request_body_format() {
     let client = AsyncGeminiClient::new("AIza-test123".to_string(), "gemini-2.0-flash".to_string());
 
     let body = client
-        .build_request_body("suggest jq filters for: extract user names")
+        .build_request_body(&[], "suggest jq filters for: extract user names")
         .expect("Request body should serialize successfully");
 
     // Parse and pretty-print for snapshot readability
@@ -183,7 +184,7 @@ async fn test_cancellation_before_response() {
     cancel_token.cancel();
 
     let result = client
-        .stream_with_cancel("test prompt", 1, cancel_token, tx)
+        .stream_with_cancel(&[], "test prompt", 1, cancel_token, tx)
         .await;
 
     // Should return Cancelled error
@@ -213,3 +214,43 @@ fn test_channel_disconnection() {
     // Should fail because receiver is dropped
     assert!(result.is_err(), "Send should fail when receiver is dropped");
 }
+
+#[test]
+fn test_request_body_includes_conversation_history() {
+    let client = AsyncGeminiClient::new("AIza-test123".to_string(), "gemini-2.0-flash".to_string());
+    let history = vec![ConversationTurn {
+        prompt: "suggest filters".to_string(),
+        follow_up: None,
+        response: "{\"suggestions\": []}".to_string(),
+    }];
+
+    let body = client
+        .build_request_body(&history, "now also sort by name")
+        .expect("Request body should serialize successfully");
+
+    let json: serde_json::Value = serde_json::from_str(&body).unwrap();
+    let roles: Vec<&str> = json["contents"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|message| message["role"].as_str().unwrap())
+        .collect();
+    assert_eq!(roles, vec!["user", "model", "user"]);
+    assert!(body.contains("now also sort by name"));
+}
+
+#[test]
+fn test_system_prompt_is_sent_as_system_instruction() {
+    let client = AsyncGeminiClient::new("AIza-test123".to_string(), "gemini-2.0-flash".to_string());
+    let body = client.build_request_body(&[], "suggest filters").unwrap();
+    assert!(!body.contains("systemInstruction"));
+
+    let client = client.with_system_prompt(Some("Answer in German".to_string()));
+    let body = client.build_request_body(&[], "suggest filters").unwrap();
+
+    let json: serde_json::Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(
+        json["systemInstruction"]["parts"][0]["text"],
+        "Answer in German"
+    );
+}
@@ -10,9 +10,9 @@ use reqwest::Client;
 use serde::Serialize;
 use tokio_util::sync::CancellationToken;
 
-use super::AiError;
 use super::sse::{OpenAiEventParser, SseParser};
-use crate::ai::ai_state::AiResponse;
+use super::{AiError, ChatRole, conversation_messages};
+use crate::ai::ai_state::{AiResponse, ConversationTurn};
 
 /// OpenAI API endpoint
 const OPENAI_API_URL: &str = "https://api.openai.com/v1/chat/completions";
@@ -27,6 +27,7 @@ pub struct AsyncOpenAiClient {
     api_key: String,
     model: String,
     api_url: String,
+    system_prompt: Option<String>,
 }
 
 impl AsyncOpenAiClient {
@@ -38,9 +39,21 @@ impl AsyncOpenAiClient {
             api_key,
             model,
             api_url,
+            system_prompt: None,
         }
     }
 
+    /// Instructions sent as the system prompt of every request
+    pub fn with_system_prompt(mut self, system_prompt: Option<String>) -> Self {
+        self.system_prompt = system_prompt;
+        self
+    }
+
+    /// Model requests are sent to
+    pub fn model(&self) -> &str {
+        &self.model
+    }
+
     /// Build the API URL from an optional base URL
     fn build_api_url(base_url: Option<String>) -> String {
         match base_url {
@@ -63,16 +76,22 @@ impl AsyncOpenAiClient {
 
     /// Build the request body JSON for OpenAI Chat Completions API
     ///
-    /// Creates a JSON request body with the model, messages array, and streaming enabled.
+    /// Creates a JSON request body with the model, the conversation's messages, and streaming enabled.
+    /// The system prompt, when set, is sent as the first message.
     /// Does not set max_tokens, allowing OpenAI to use its default.
     ///
     /// # Arguments
+    /// * `history` - Earlier turns of the conversation, oldest first
     /// * `prompt` - The user prompt to send to the API
     ///
     /// # Returns
     /// * `Ok(String)` - Serialized JSON request body
     /// * `Err(AiError::Parse)` - If serialization fails
-    fn build_request_body(&self, prompt: &str) -> Result<String, AiError> {
+    fn build_request_body(
+        &self,
+        history: &[ConversationTurn],
+        prompt: &str,
+    ) -> Result<String, AiError> {
         #[derive(Serialize)]
         struct Message {
             role: String,
@@ -86,12 +105,25 @@ impl AsyncOpenAiClient {
             stream: bool,
         }
 
+        let system = self.system_prompt.iter().map(|content| Message {
+            role: "system".to_string(),
+            content: content.clone(),
+        });
+        let conversation =
+            conversation_messages(history, prompt)
+                .into_iter()
+                .map(|(role, content)| Message {
+                    role: match role {
+                        ChatRole::User => "user",
+                        ChatRole::Assistant => "assistant",
+                    }
+                    .to_string(),
+                    content: content.to_string(),
+                });
+
         let body = RequestBody {
             model: self.model.clone(),
-            messages: vec![Message {
-                role: "user".to_string(),
-                content: prompt.to_string(),
-            }],
+            messages: system.chain(conversation).collect(),
             stream: true,
         };
 
@@ -107,6 +139,7 @@ impl AsyncOpenAiClient {
     /// Sends chunks via the response channel as they arrive.
     ///
     /// # Arguments
+    /// * `history` - Earlier turns of the conversation, oldest first
     /// * `prompt` - The prompt to send to the API
     /// * `request_id` - Unique ID for this request
     /// * `cancel_token` - Token to cancel the request
@@ -118,6 +151,7 @@ impl AsyncOpenAiClient {
     /// * `Err(AiError::*)` - Other errors
     pub async fn stream_with_cancel(
         &self,
+        history: &[ConversationTurn],
         prompt: &str,
         request_id: u64,
         cancel_token: CancellationToken,
@@ -129,7 +163,7 @@ impl AsyncOpenAiClient {
         }
 
         // Build request body
-        let body = self.build_request_body(prompt)?;
+        let body = self.build_request_body(history, prompt)?;
 
         // Make the POST request to OpenAI API
         let response = self
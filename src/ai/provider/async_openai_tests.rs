@@ -1,6 +1,7 @@
 //! Tests for Async OpenAI API client
 
 use super::*;
+use crate::ai::ai_state::ConversationTurn;
 use insta::assert_snapshot;
 use proptest::prelude::*;
 
@@ -103,7 +104,7 @@ proptest! {
         );
 
         // Build the request body
-        let result = client.build_request_body(&prompt);
+        let result = client.build_request_body(&[], &prompt);
 
         // Verify the request body was created successfully
         prop_assert!(result.is_ok(), "Request body should serialize successfully");
@@ -167,7 +168,7 @@ fn snapshot_request_body_format() {
     );
 
     let body = client
-        .build_request_body("suggest jq filters for: extract user names")
+        .build_request_body(&[], "suggest jq filters for: extract user names")
         .expect("Request body should serialize successfully");
 
     // Parse and pretty-print for snapshot readability
@@ -335,7 +336,7 @@ async fn test_cancellation_before_response() {
     cancel_token.cancel();
 
     let result = client
-        .stream_with_cancel("test prompt", 1, cancel_token, tx)
+        .stream_with_cancel(&[], "test prompt", 1, cancel_token, tx)
         .await;
 
     // Should return Cancelled error
@@ -581,3 +582,48 @@ proptest! {
         );
     }
 }
+
+#[test]
+fn test_request_body_includes_conversation_history() {
+    let client = AsyncOpenAiClient::new(
+        "sk-proj-test123".to_string(),
+        "gpt-4o-mini".to_string(),
+        None,
+    );
+    let history = vec![ConversationTurn {
+        prompt: "suggest filters".to_string(),
+        follow_up: None,
+        response: "{\"suggestions\": []}".to_string(),
+    }];
+
+    let body = client
+        .build_request_body(&history, "now also sort by name")
+        .expect("Request body should serialize successfully");
+
+    let json: serde_json::Value = serde_json::from_str(&body).unwrap();
+    let roles: Vec<&str> = json["messages"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|message| message["role"].as_str().unwrap())
+        .collect();
+    assert_eq!(roles, vec!["user", "assistant", "user"]);
+    assert!(body.contains("now also sort by name"));
+}
+
+#[test]
+fn test_system_prompt_is_first_message() {
+    let client = AsyncOpenAiClient::new(
+        "sk-proj-test123".to_string(),
+        "gpt-4o-mini".to_string(),
+        None,
+    )
+    .with_system_prompt(Some("Answer in German".to_string()));
+
+    let body = client.build_request_body(&[], "suggest filters").unwrap();
+
+    let json: serde_json::Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(json["messages"][0]["role"], "system");
+    assert_eq!(json["messages"][0]["content"], "Answer in German");
+    assert_eq!(json["messages"][1]["role"], "user");
+}
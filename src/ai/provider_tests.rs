@@ -8,6 +8,8 @@
 mod anthropic_tests;
 #[path = "provider_tests/bedrock_tests.rs"]
 mod bedrock_tests;
+#[path = "provider_tests/conversation_tests.rs"]
+mod conversation_tests;
 #[path = "provider_tests/error_tests.rs"]
 mod error_tests;
 #[path = "provider_tests/gemini_tests.rs"]
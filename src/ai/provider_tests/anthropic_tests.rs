@@ -8,6 +8,9 @@ fn test_async_from_config_missing_api_key() {
     let config = AiConfig {
         enabled: true,
         provider: Some(AiProviderType::Anthropic),
+        fallback: Vec::new(),
+        budget: Default::default(),
+        prompts: Default::default(),
         anthropic: AnthropicConfig {
             max_tokens: 512,
             api_key: None,
@@ -29,6 +32,9 @@ fn test_async_from_config_empty_api_key() {
     let config = AiConfig {
         enabled: true,
         provider: Some(AiProviderType::Anthropic),
+        fallback: Vec::new(),
+        budget: Default::default(),
+        prompts: Default::default(),
         anthropic: AnthropicConfig {
             max_tokens: 512,
             api_key: Some("".to_string()),
@@ -50,6 +56,9 @@ fn test_async_from_config_whitespace_api_key() {
     let config = AiConfig {
         enabled: true,
         provider: Some(AiProviderType::Anthropic),
+        fallback: Vec::new(),
+        budget: Default::default(),
+        prompts: Default::default(),
         anthropic: AnthropicConfig {
             max_tokens: 512,
             api_key: Some("   ".to_string()),
@@ -71,6 +80,9 @@ fn test_async_from_config_valid_api_key() {
     let config = AiConfig {
         enabled: true,
         provider: Some(AiProviderType::Anthropic),
+        fallback: Vec::new(),
+        budget: Default::default(),
+        prompts: Default::default(),
         anthropic: AnthropicConfig {
             max_tokens: 512,
             api_key: Some("sk-ant-test-key".to_string()),
@@ -91,6 +103,9 @@ fn test_async_from_config_disabled() {
     let config = AiConfig {
         enabled: false,
         provider: Some(AiProviderType::Anthropic),
+        fallback: Vec::new(),
+        budget: Default::default(),
+        prompts: Default::default(),
         anthropic: AnthropicConfig {
             max_tokens: 512,
             api_key: Some("sk-ant-test-key".to_string()),
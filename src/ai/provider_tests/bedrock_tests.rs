@@ -8,6 +8,9 @@ fn test_bedrock_missing_model_produces_error() {
     let config = AiConfig {
         enabled: true,
         provider: Some(AiProviderType::Bedrock),
+        fallback: Vec::new(),
+        budget: Default::default(),
+        prompts: Default::default(),
         anthropic: AnthropicConfig::default(),
         bedrock: BedrockConfig {
             region: Some("us-east-1".to_string()),
@@ -35,6 +38,9 @@ fn test_bedrock_empty_model_produces_error() {
     let config = AiConfig {
         enabled: true,
         provider: Some(AiProviderType::Bedrock),
+        fallback: Vec::new(),
+        budget: Default::default(),
+        prompts: Default::default(),
         anthropic: AnthropicConfig::default(),
         bedrock: BedrockConfig {
             region: Some("us-east-1".to_string()),
@@ -62,6 +68,9 @@ fn test_bedrock_whitespace_model_produces_error() {
     let config = AiConfig {
         enabled: true,
         provider: Some(AiProviderType::Bedrock),
+        fallback: Vec::new(),
+        budget: Default::default(),
+        prompts: Default::default(),
         anthropic: AnthropicConfig::default(),
         bedrock: BedrockConfig {
             region: Some("us-east-1".to_string()),
@@ -89,6 +98,9 @@ fn test_bedrock_missing_region_produces_error() {
     let config = AiConfig {
         enabled: true,
         provider: Some(AiProviderType::Bedrock),
+        fallback: Vec::new(),
+        budget: Default::default(),
+        prompts: Default::default(),
         anthropic: AnthropicConfig::default(),
         bedrock: BedrockConfig {
             region: None,
@@ -116,6 +128,9 @@ fn test_bedrock_empty_region_produces_error() {
     let config = AiConfig {
         enabled: true,
         provider: Some(AiProviderType::Bedrock),
+        fallback: Vec::new(),
+        budget: Default::default(),
+        prompts: Default::default(),
         anthropic: AnthropicConfig::default(),
         bedrock: BedrockConfig {
             region: Some("".to_string()),
@@ -143,6 +158,9 @@ fn test_bedrock_valid_config_creates_provider() {
     let config = AiConfig {
         enabled: true,
         provider: Some(AiProviderType::Bedrock),
+        fallback: Vec::new(),
+        budget: Default::default(),
+        prompts: Default::default(),
         anthropic: AnthropicConfig::default(),
         bedrock: BedrockConfig {
             region: Some("us-east-1".to_string()),
@@ -169,6 +187,9 @@ fn test_bedrock_valid_config_with_profile_creates_provider() {
     let config = AiConfig {
         enabled: true,
         provider: Some(AiProviderType::Bedrock),
+        fallback: Vec::new(),
+        budget: Default::default(),
+        prompts: Default::default(),
         anthropic: AnthropicConfig::default(),
         bedrock: BedrockConfig {
             region: Some("us-west-2".to_string()),
@@ -0,0 +1,35 @@
+//! Tests for the messages sent for a conversation
+
+use super::*;
+
+fn turn(prompt: &str, response: &str) -> ConversationTurn {
+    ConversationTurn {
+        prompt: prompt.to_string(),
+        follow_up: None,
+        response: response.to_string(),
+    }
+}
+
+#[test]
+fn test_messages_without_history_are_the_prompt() {
+    assert_eq!(
+        conversation_messages(&[], "prompt"),
+        vec![(ChatRole::User, "prompt")]
+    );
+}
+
+#[test]
+fn test_messages_alternate_turns_then_prompt() {
+    let history = vec![turn("first", "answer 1"), turn("second", "answer 2")];
+
+    assert_eq!(
+        conversation_messages(&history, "third"),
+        vec![
+            (ChatRole::User, "first"),
+            (ChatRole::Assistant, "answer 1"),
+            (ChatRole::User, "second"),
+            (ChatRole::Assistant, "answer 2"),
+            (ChatRole::User, "third"),
+        ]
+    );
+}
@@ -8,6 +8,9 @@ fn test_from_config_returns_error_when_provider_is_none() {
     let config = AiConfig {
         enabled: true,
         provider: None,
+        fallback: Vec::new(),
+        budget: Default::default(),
+        prompts: Default::default(),
         anthropic: AnthropicConfig {
             max_tokens: 512,
             api_key: Some("valid-key".to_string()),
@@ -46,6 +49,9 @@ fn test_from_config_error_when_provider_none_even_with_all_credentials() {
     let config = AiConfig {
         enabled: true,
         provider: None,
+        fallback: Vec::new(),
+        budget: Default::default(),
+        prompts: Default::default(),
         anthropic: AnthropicConfig {
             max_tokens: 512,
             api_key: Some("anthropic-key".to_string()),
@@ -163,6 +169,9 @@ fn test_provider_name_returns_correct_identifier() {
     let config = AiConfig {
         enabled: true,
         provider: Some(AiProviderType::Anthropic),
+        fallback: Vec::new(),
+        budget: Default::default(),
+        prompts: Default::default(),
         anthropic: AnthropicConfig {
             max_tokens: 512,
             api_key: Some("test-key".to_string()),
@@ -183,6 +192,9 @@ fn test_config_error_includes_correct_provider_for_missing_api_key() {
     let config = AiConfig {
         enabled: true,
         provider: Some(AiProviderType::Anthropic),
+        fallback: Vec::new(),
+        budget: Default::default(),
+        prompts: Default::default(),
         anthropic: AnthropicConfig {
             max_tokens: 512,
             api_key: None,
@@ -209,6 +221,9 @@ fn test_config_error_includes_correct_provider_for_disabled() {
     let config = AiConfig {
         enabled: false,
         provider: Some(AiProviderType::Anthropic),
+        fallback: Vec::new(),
+        budget: Default::default(),
+        prompts: Default::default(),
         anthropic: AnthropicConfig {
             max_tokens: 512,
             api_key: Some("valid-key".to_string()),
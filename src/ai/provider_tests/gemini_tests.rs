@@ -12,6 +12,9 @@ fn test_gemini_from_config_missing_api_key() {
     let config = AiConfig {
         enabled: true,
         provider: Some(AiProviderType::Gemini),
+        fallback: Vec::new(),
+        budget: Default::default(),
+        prompts: Default::default(),
         anthropic: AnthropicConfig::default(),
         bedrock: BedrockConfig::default(),
         openai: OpenAiConfig::default(),
@@ -37,6 +40,9 @@ fn test_gemini_from_config_missing_model() {
     let config = AiConfig {
         enabled: true,
         provider: Some(AiProviderType::Gemini),
+        fallback: Vec::new(),
+        budget: Default::default(),
+        prompts: Default::default(),
         anthropic: AnthropicConfig::default(),
         bedrock: BedrockConfig::default(),
         openai: OpenAiConfig::default(),
@@ -62,6 +68,9 @@ fn test_gemini_from_config_valid_creates_client() {
     let config = AiConfig {
         enabled: true,
         provider: Some(AiProviderType::Gemini),
+        fallback: Vec::new(),
+        budget: Default::default(),
+        prompts: Default::default(),
         anthropic: AnthropicConfig::default(),
         bedrock: BedrockConfig::default(),
         openai: OpenAiConfig::default(),
@@ -86,6 +95,9 @@ fn test_gemini_provider_name() {
     let config = AiConfig {
         enabled: true,
         provider: Some(AiProviderType::Gemini),
+        fallback: Vec::new(),
+        budget: Default::default(),
+        prompts: Default::default(),
         anthropic: AnthropicConfig::default(),
         bedrock: BedrockConfig::default(),
         openai: OpenAiConfig::default(),
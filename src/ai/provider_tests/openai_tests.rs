@@ -19,6 +19,9 @@ mod openai_error_snapshots {
         let config = AiConfig {
             enabled: true,
             provider: Some(AiProviderType::Openai),
+            fallback: Vec::new(),
+            budget: Default::default(),
+            prompts: Default::default(),
             anthropic: AnthropicConfig::default(),
             bedrock: BedrockConfig::default(),
             openai: OpenAiConfig {
@@ -41,6 +44,9 @@ mod openai_error_snapshots {
         let config = AiConfig {
             enabled: true,
             provider: Some(AiProviderType::Openai),
+            fallback: Vec::new(),
+            budget: Default::default(),
+            prompts: Default::default(),
             anthropic: AnthropicConfig::default(),
             bedrock: BedrockConfig::default(),
             openai: OpenAiConfig {
@@ -63,6 +69,9 @@ mod openai_error_snapshots {
         let config = AiConfig {
             enabled: true,
             provider: Some(AiProviderType::Openai),
+            fallback: Vec::new(),
+            budget: Default::default(),
+            prompts: Default::default(),
             anthropic: AnthropicConfig::default(),
             bedrock: BedrockConfig::default(),
             openai: OpenAiConfig {
@@ -85,6 +94,9 @@ mod openai_error_snapshots {
         let config = AiConfig {
             enabled: true,
             provider: Some(AiProviderType::Openai),
+            fallback: Vec::new(),
+            budget: Default::default(),
+            prompts: Default::default(),
             anthropic: AnthropicConfig::default(),
             bedrock: BedrockConfig::default(),
             openai: OpenAiConfig {
@@ -172,6 +184,9 @@ fn test_openai_provider_name_default() {
     let config = AiConfig {
         enabled: true,
         provider: Some(AiProviderType::Openai),
+        fallback: Vec::new(),
+        budget: Default::default(),
+        prompts: Default::default(),
         anthropic: AnthropicConfig::default(),
         bedrock: BedrockConfig::default(),
         openai: OpenAiConfig {
@@ -192,6 +207,9 @@ fn test_openai_provider_name_explicit_openai_url() {
     let config = AiConfig {
         enabled: true,
         provider: Some(AiProviderType::Openai),
+        fallback: Vec::new(),
+        budget: Default::default(),
+        prompts: Default::default(),
         anthropic: AnthropicConfig::default(),
         bedrock: BedrockConfig::default(),
         openai: OpenAiConfig {
@@ -212,6 +230,9 @@ fn test_openai_provider_name_custom_endpoint() {
     let config = AiConfig {
         enabled: true,
         provider: Some(AiProviderType::Openai),
+        fallback: Vec::new(),
+        budget: Default::default(),
+        prompts: Default::default(),
         anthropic: AnthropicConfig::default(),
         bedrock: BedrockConfig::default(),
         openai: OpenAiConfig {
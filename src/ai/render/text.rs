@@ -4,6 +4,8 @@
 
 #![allow(dead_code)]
 
+use crate::widgets::text_width::display_width;
+
 /// Wrap text to fit within a given width, breaking at word boundaries
 pub fn wrap_text(text: &str, max_width: usize) -> Vec<String> {
     if max_width == 0 {
@@ -23,7 +25,7 @@ pub fn wrap_text(text: &str, max_width: usize) -> Vec<String> {
         for word in paragraph.split_whitespace() {
             if current_line.is_empty() {
                 current_line = word.to_string();
-            } else if current_line.len() + 1 + word.len() <= max_width {
+            } else if display_width(&current_line) + 1 + display_width(word) <= max_width {
                 current_line.push(' ');
                 current_line.push_str(word);
             } else {
@@ -7,14 +7,14 @@
 //! Uses a tokio runtime for async HTTP streaming with cancellation support.
 //! Includes panic handling to prevent TUI corruption from AWS SDK panics.
 
-use std::panic::{self, AssertUnwindSafe};
 use std::sync::mpsc::{Receiver, Sender};
 
 use tokio_util::sync::CancellationToken;
 
-use super::ai_state::{AiRequest, AiResponse};
+use super::ai_state::{AiProviderInfo, AiRequest, AiResponse, ConversationTurn};
 use super::provider::{AiError, AsyncAiProvider};
 use crate::config::ai_types::AiConfig;
+use crate::crash;
 
 /// Spawn the AI worker thread
 ///
@@ -27,10 +27,13 @@ use crate::config::ai_types::AiConfig;
 /// AWS SDK credential loading) from corrupting the TUI.
 ///
 /// # Arguments
-/// * `config` - AI configuration (for creating the provider)
+/// * `config` - AI configuration (for creating the providers)
 /// * `request_rx` - Channel to receive requests from the main thread
 /// * `response_tx` - Channel to send responses to the main thread
 ///
+/// Returns the providers requests are routed to, in the order they are
+/// tried: the configured provider, then its fallbacks.
+///
 /// # Requirements
 /// - 4.1: WHEN the AI provider sends a streaming response THEN the AI_Popup
 ///   SHALL display text incrementally as chunks arrive
@@ -40,41 +43,21 @@ pub fn spawn_worker(
     config: &AiConfig,
     request_rx: Receiver<AiRequest>,
     response_tx: Sender<AiResponse>,
-) {
-    // Try to create the async provider from config
-    let provider_result = AsyncAiProvider::from_config(config);
+) -> Vec<AiProviderInfo> {
+    // Try to create the async providers from config
+    let providers_result = AsyncAiProvider::routes_from_config(config);
+    let routes = providers_result
+        .iter()
+        .flatten()
+        .map(|provider| AiProviderInfo {
+            name: provider.provider_name().to_string(),
+            model: provider.model().to_string(),
+        })
+        .collect();
 
     std::thread::spawn(move || {
-        // Set a custom panic hook for this thread to suppress output
-        // The default panic hook prints to stderr which corrupts the TUI
-        let response_tx_clone = response_tx.clone();
-        let prev_hook = panic::take_hook();
-        panic::set_hook(Box::new(move |panic_info| {
-            // Extract panic message
-            let panic_msg = if let Some(s) = panic_info.payload().downcast_ref::<&str>() {
-                s.to_string()
-            } else if let Some(s) = panic_info.payload().downcast_ref::<String>() {
-                s.clone()
-            } else {
-                "Unknown panic in AI worker".to_string()
-            };
-
-            // Log the panic instead of printing to stderr
-            log::error!(
-                "AI worker panic: {} at {:?}",
-                panic_msg,
-                panic_info.location()
-            );
-
-            // Try to send error to main thread
-            let _ = response_tx_clone.send(AiResponse::Error(format!(
-                "AI worker crashed: {}",
-                panic_msg
-            )));
-        }));
-
-        // Wrap the entire worker in catch_unwind to handle panics gracefully
-        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        let error_tx = response_tx.clone();
+        let result = crash::run_worker(|| {
             // Create a single-threaded tokio runtime for this worker thread
             let rt = tokio::runtime::Builder::new_current_thread()
                 .enable_all()
@@ -82,24 +65,19 @@ pub fn spawn_worker(
                 .expect("Failed to create tokio runtime");
 
             // Run the async worker loop on the runtime
-            rt.block_on(worker_loop(provider_result, request_rx, response_tx));
-        }));
-
-        // Restore the previous panic hook
-        panic::set_hook(prev_hook);
-
-        if let Err(e) = result {
-            // Extract panic message for logging
-            let panic_msg = if let Some(s) = e.downcast_ref::<&str>() {
-                s.to_string()
-            } else if let Some(s) = e.downcast_ref::<String>() {
-                s.clone()
-            } else {
-                "Unknown panic".to_string()
-            };
+            rt.block_on(worker_loop(providers_result, request_rx, response_tx));
+        });
+
+        if let Err(panic_msg) = result {
             log::error!("AI worker thread panicked: {}", panic_msg);
+            let _ = error_tx.send(AiResponse::Error(format!(
+                "AI worker crashed: {}",
+                panic_msg
+            )));
         }
     });
+
+    routes
 }
 
 /// Main async worker loop - processes requests until the channel is closed
@@ -111,12 +89,12 @@ pub fn spawn_worker(
 /// - 4.2: WHEN the worker thread is spawned THEN it SHALL create a tokio runtime
 ///   for async operations
 async fn worker_loop(
-    provider_result: Result<AsyncAiProvider, AiError>,
+    providers_result: Result<Vec<AsyncAiProvider>, AiError>,
     request_rx: Receiver<AiRequest>,
     response_tx: Sender<AiResponse>,
 ) {
-    // Check if provider was created successfully
-    let provider = provider_result.ok();
+    // No routes when the configured provider couldn't be created
+    let providers = providers_result.unwrap_or_default();
 
     // Process requests until the channel is closed
     // Using blocking recv() is fine here since we're in a dedicated thread
@@ -124,11 +102,21 @@ async fn worker_loop(
         match request {
             AiRequest::Query {
                 prompt,
+                history,
+                provider,
                 request_id,
                 cancel_token,
             } => {
-                handle_query_async(&provider, &prompt, request_id, cancel_token, &response_tx)
-                    .await;
+                handle_query_async(
+                    &providers,
+                    &history,
+                    &prompt,
+                    provider,
+                    request_id,
+                    cancel_token,
+                    &response_tx,
+                )
+                .await;
             }
         }
     }
@@ -137,15 +125,20 @@ async fn worker_loop(
 /// Handle a query request asynchronously
 ///
 /// Uses `tokio::select!` with biased mode to check cancellation first,
-/// then processes the async stream from the AI provider.
+/// then processes the async stream from the AI provider. When a provider
+/// fails (rate limit, network or API error), the request falls back to the
+/// next one, announced with `AiResponse::Fallback`; the last error is only
+/// reported once every provider has failed.
 ///
 /// # Requirements
 /// - 1.2: WHEN a cancel signal is received THEN the Worker_Thread SHALL abort
 ///   the HTTP request immediately
 /// - 3.2: WHEN a request is cancelled THEN the system SHALL send AiResponse::Cancelled
 async fn handle_query_async(
-    provider: &Option<AsyncAiProvider>,
+    providers: &[AsyncAiProvider],
+    history: &[ConversationTurn],
     prompt: &str,
+    first: Option<usize>,
     request_id: u64,
     cancel_token: CancellationToken,
     response_tx: &Sender<AiResponse>,
@@ -156,35 +149,62 @@ async fn handle_query_async(
         return;
     }
 
-    // Check if provider is available
-    let provider = match provider {
-        Some(p) => p,
-        None => {
-            let _ = response_tx.send(AiResponse::Error(
-                "AI not configured. Enable AI in your config file with 'enabled = true' and configure a provider. See https://github.com/bellicose100xp/jiq#configuration for setup instructions.".to_string(),
-            ));
-            return;
-        }
-    };
-
-    // Stream the response with cancellation support
-    // The async provider handles cancellation internally via tokio::select!
-    match provider
-        .stream_with_cancel(prompt, request_id, cancel_token, response_tx.clone())
-        .await
-    {
-        Ok(()) => {
-            // Stream completed successfully
-            let _ = response_tx.send(AiResponse::Complete { request_id });
-        }
-        Err(AiError::Cancelled) => {
-            // Request was cancelled - send Cancelled response
-            let _ = response_tx.send(AiResponse::Cancelled { request_id });
+    // Check if a provider is available
+    if providers.is_empty() {
+        let _ = response_tx.send(AiResponse::Error(
+            "AI not configured. Enable AI in your config file with 'enabled = true' and configure a provider. See https://github.com/bellicose100xp/jiq#configuration for setup instructions.".to_string(),
+        ));
+        return;
+    }
+
+    let mut last_error = None;
+    for (attempt, index) in route_order(providers.len(), first).into_iter().enumerate() {
+        if attempt > 0 {
+            let _ = response_tx.send(AiResponse::Fallback {
+                request_id,
+                provider: index,
+            });
         }
-        Err(e) => {
-            let _ = response_tx.send(AiResponse::Error(e.to_string()));
+
+        // Stream the response with cancellation support
+        // The async provider handles cancellation internally via tokio::select!
+        match providers[index]
+            .stream_with_cancel(
+                history,
+                prompt,
+                request_id,
+                cancel_token.clone(),
+                response_tx.clone(),
+            )
+            .await
+        {
+            Ok(()) => {
+                // Stream completed successfully
+                let _ = response_tx.send(AiResponse::Complete { request_id });
+                return;
+            }
+            Err(AiError::Cancelled) => {
+                // Request was cancelled - send Cancelled response
+                let _ = response_tx.send(AiResponse::Cancelled { request_id });
+                return;
+            }
+            Err(e) => {
+                log::warn!("AI provider {} failed: {}", index, e);
+                last_error = Some(e);
+            }
         }
     }
+
+    if let Some(e) = last_error {
+        let _ = response_tx.send(AiResponse::Error(e.to_string()));
+    }
+}
+
+/// Indices of the providers to try, starting with `first` (the configured
+/// provider when None) and wrapping around to the others in order
+fn route_order(count: usize, first: Option<usize>) -> Vec<usize> {
+    let first = first.filter(|&index| index < count).unwrap_or(0);
+    (0..count).map(|offset| (first + offset) % count).collect()
 }
 
 #[cfg(test)]
@@ -40,6 +40,8 @@ fn test_worker_handles_query_without_provider() {
     request_tx
         .send(AiRequest::Query {
             prompt: "test".to_string(),
+            history: Vec::new(),
+            provider: None,
             request_id: 1,
             cancel_token,
         })
@@ -83,6 +85,8 @@ fn test_worker_handles_pre_cancelled_request() {
     request_tx
         .send(AiRequest::Query {
             prompt: "test".to_string(),
+            history: Vec::new(),
+            provider: None,
             request_id: 1,
             cancel_token,
         })
@@ -162,8 +166,10 @@ fn test_cancel_signal_aborts_request() {
     cancel_token.cancel();
 
     run_async(handle_query_async(
-        &None,
+        &[],
+        &[],
         "test prompt",
+        None,
         request_id,
         cancel_token,
         &response_tx,
@@ -179,3 +185,83 @@ fn test_cancel_signal_aborts_request() {
         _ => panic!("Should have sent Cancelled response, got {:?}", response),
     }
 }
+
+#[test]
+fn test_route_order_starts_with_override() {
+    assert_eq!(route_order(3, None), vec![0, 1, 2]);
+    assert_eq!(route_order(3, Some(1)), vec![1, 2, 0]);
+    assert_eq!(route_order(3, Some(7)), vec![0, 1, 2]);
+    assert!(route_order(0, None).is_empty());
+}
+
+/// OpenAI-compatible provider whose endpoint refuses connections
+fn unreachable_provider(model: &str) -> AsyncAiProvider {
+    let config = AiConfig {
+        enabled: true,
+        provider: Some(crate::config::ai_types::AiProviderType::Openai),
+        openai: crate::config::ai_types::OpenAiConfig {
+            model: Some(model.to_string()),
+            base_url: Some("http://127.0.0.1:1/v1".to_string()),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    AsyncAiProvider::from_config(&config).unwrap()
+}
+
+#[test]
+fn test_failed_provider_falls_back_to_next() {
+    let (response_tx, response_rx) = mpsc::channel();
+    let providers = vec![
+        unreachable_provider("first"),
+        unreachable_provider("second"),
+    ];
+
+    run_async(handle_query_async(
+        &providers,
+        &[],
+        "test prompt",
+        None,
+        7,
+        CancellationToken::new(),
+        &response_tx,
+    ));
+
+    assert!(matches!(
+        response_rx.recv().unwrap(),
+        AiResponse::Fallback {
+            request_id: 7,
+            provider: 1
+        }
+    ));
+    assert!(matches!(response_rx.recv().unwrap(), AiResponse::Error(_)));
+    assert!(response_rx.try_recv().is_err());
+}
+
+#[test]
+fn test_routes_skip_incomplete_fallbacks() {
+    let config = AiConfig {
+        enabled: true,
+        provider: Some(crate::config::ai_types::AiProviderType::Gemini),
+        fallback: vec![
+            crate::config::ai_types::AiProviderType::Anthropic,
+            crate::config::ai_types::AiProviderType::Openai,
+        ],
+        gemini: crate::config::ai_types::GeminiConfig {
+            api_key: Some("key".to_string()),
+            model: Some("gemini-2.0-flash".to_string()),
+        },
+        openai: crate::config::ai_types::OpenAiConfig {
+            api_key: Some("key".to_string()),
+            model: Some("gpt-4o-mini".to_string()),
+            base_url: None,
+        },
+        ..Default::default()
+    };
+
+    let routes = AsyncAiProvider::routes_from_config(&config).unwrap();
+
+    let names: Vec<&str> = routes.iter().map(|p| p.provider_name()).collect();
+    assert_eq!(names, vec!["Gemini", "OpenAI"]);
+    assert_eq!(routes[1].model(), "gpt-4o-mini");
+}
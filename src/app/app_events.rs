@@ -6,15 +6,39 @@ use std::time::Duration;
 
 use super::app_state::{App, Focus};
 use crate::clipboard;
+use crate::config::KeyBindings;
 use crate::editor;
 use crate::editor::EditorMode;
 use crate::help::HelpTab;
 use crate::history;
+use crate::notification::NotificationType;
+use crate::permalink::{self, Permalink};
 use crate::results;
 use crate::snippets;
 
 mod global;
 
+/// Key typed with AltGr as the character it produced
+///
+/// Windows reports AltGr as Ctrl+Alt along with the character, e.g. `{` or
+/// `@` on German layouts, `€`, or `ą` on Polish ones. Such characters are
+/// typed rather than treated as shortcuts; Ctrl+Alt with an ASCII letter or
+/// digit stays a shortcut.
+fn normalize_altgr(key: KeyEvent) -> KeyEvent {
+    let altgr = KeyModifiers::CONTROL | KeyModifiers::ALT;
+    match key.code {
+        KeyCode::Char(c)
+            if key.modifiers.contains(altgr) && !c.is_ascii_alphanumeric() && !c.is_control() =>
+        {
+            KeyEvent {
+                modifiers: key.modifiers - altgr,
+                ..key
+            }
+        }
+        _ => key,
+    }
+}
+
 /// Determine the default help tab based on current app context
 ///
 /// Context-aware auto-selection:
@@ -66,7 +90,25 @@ fn handle_truly_global_keys(app: &mut App, key: KeyEvent) -> bool {
             true
         }
         KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
-            app.should_quit = true;
+            // A second Ctrl+C at the exit confirmation quits regardless
+            if app.exit_prompt_visible {
+                app.should_quit = true;
+            } else {
+                crate::exit_prompt::exit_prompt_events::request_quit(app);
+            }
+            true
+        }
+        KeyCode::Char('t') if key.modifiers.contains(KeyModifiers::ALT) => {
+            app.timing_overlay_visible = !app.timing_overlay_visible;
+            true
+        }
+        KeyCode::Char('r') if key.modifiers.contains(KeyModifiers::ALT) => {
+            app.redaction.toggle();
+            if app.redaction.enabled {
+                app.notification.show("Sensitive values hidden");
+            } else {
+                app.notification.show("Sensitive values shown");
+            }
             true
         }
         _ => false,
@@ -204,7 +246,7 @@ fn handle_popup_passthrough_keys(app: &mut App, key: KeyEvent) -> bool {
         && key.modifiers.contains(KeyModifiers::CONTROL)
         && app.history.is_visible()
     {
-        app.snippets.open();
+        crate::snippets::snippet_events::open_manager(app);
         app.autocomplete.hide();
         app.history.close();
         return true;
@@ -228,40 +270,148 @@ impl App {
             self.mark_dirty();
         }
 
+        let ai_error = self.ai.error.clone();
         if crate::ai::ai_events::poll_response_channel(&mut self.ai) {
             self.mark_dirty();
         }
+        // AI errors are shown in the AI popup; keep them in the message log too
+        if self.ai.error != ai_error
+            && let Some(error) = &self.ai.error
+        {
+            self.notification
+                .log(&format!("AI: {}", error), NotificationType::Error);
+        }
+        // Said once, as soon as the response that used up the budget arrives
+        if let Some(notice) = self.ai.usage.take_exceeded_notice() {
+            self.notification.show_warning(&notice);
+            self.mark_dirty();
+        }
+
+        if self.pipeline.poll_responses() {
+            self.mark_dirty();
+        }
+
+        if self.histogram.poll_responses() {
+            self.mark_dirty();
+        }
+
+        if crate::plugins::plugin_events::poll_plugin_task(self) {
+            self.mark_dirty();
+        }
+
+        if crate::autocomplete::suggestion_preview::update_preview(self) {
+            self.mark_dirty();
+        }
+
+        crate::recovery::autosave(self);
 
         // Check notification expiry
         if self.notification.clear_if_expired() {
             self.mark_dirty();
         }
 
-        if event::poll(EVENT_POLL_TIMEOUT)? {
+        if let Some(event) = self.next_event()? {
+            tracing::trace!(target: crate::logging::EVENT_LOOP_TARGET, ?event, "terminal event");
+            if let Some(recorder) = &mut self.recorder {
+                recorder.record(&event);
+            }
+            let focus = self.focus;
+            self.handle_event(event);
+            if self.focus != focus {
+                crate::screen_reader::announce_focus(self);
+            }
+        }
+        Ok(())
+    }
+
+    /// Wait for the next terminal event, or the next recorded one when
+    /// replaying
+    ///
+    /// While replaying, terminal input is ignored apart from resizes and
+    /// `Ctrl+C`, which stops the replay and hands control back.
+    fn next_event(&mut self) -> io::Result<Option<Event>> {
+        let Some(replayer) = &mut self.replayer else {
+            return Ok(if event::poll(EVENT_POLL_TIMEOUT)? {
+                Some(event::read()?)
+            } else {
+                None
+            });
+        };
+
+        let timeout = replayer
+            .time_to_next()
+            .map_or(EVENT_POLL_TIMEOUT, |t| t.min(EVENT_POLL_TIMEOUT));
+        if event::poll(timeout)? {
             match event::read()? {
-                Event::Key(key_event) if key_event.kind == KeyEventKind::Press => {
-                    self.handle_key_event(key_event);
-                    self.mark_dirty();
-                }
-                Event::Paste(text) => {
-                    self.handle_paste_event(text);
-                    self.mark_dirty();
-                }
-                Event::Resize(_, _) => {
-                    self.mark_dirty();
-                }
-                Event::Mouse(mouse_event) => {
-                    self.handle_mouse_event(mouse_event);
-                    self.mark_dirty();
+                Event::Key(key)
+                    if key.code == KeyCode::Char('c')
+                        && key.modifiers.contains(KeyModifiers::CONTROL) =>
+                {
+                    self.replayer = None;
+                    self.notification.show_warning("Replay stopped");
+                    return Ok(None);
                 }
+                event @ Event::Resize(_, _) => return Ok(Some(event)),
                 _ => {}
             }
         }
-        Ok(())
+
+        let event = replayer.next_due();
+        if replayer.is_finished() {
+            self.replayer = None;
+            self.notification.show("Replay finished");
+        }
+        Ok(event)
+    }
+
+    /// Handle one terminal (or replayed) event
+    pub fn handle_event(&mut self, event: Event) {
+        match event {
+            Event::Key(key_event) if key_event.kind == KeyEventKind::Press => {
+                self.handle_key_event(normalize_altgr(key_event));
+                self.mark_dirty();
+            }
+            Event::Paste(text) => {
+                self.handle_paste_event(text);
+                self.mark_dirty();
+            }
+            Event::Resize(_, _) => {
+                self.mark_dirty();
+            }
+            Event::Mouse(mouse_event) => {
+                self.handle_mouse_event(mouse_event);
+                self.mark_dirty();
+            }
+            _ => {}
+        }
     }
 
     fn handle_paste_event(&mut self, text: String) {
-        self.input.textarea.insert_str(&text);
+        // A pasted permalink replaces the query instead of being typed
+        if permalink::is_permalink(&text) {
+            match Permalink::decode(&text) {
+                Ok(permalink) => {
+                    self.open_permalink(permalink);
+                    self.notification.show("Opened shared query");
+                }
+                Err(e) => self.notification.show_warning(&e),
+            }
+            return;
+        }
+
+        // A pasted JSON document is offered as the input instead
+        if crate::paste::offer_json(self, &text) {
+            return;
+        }
+
+        self.insert_pasted_text(&text);
+    }
+
+    /// Type pasted `text` into the query, joined into one line, and run it
+    pub(crate) fn insert_pasted_text(&mut self, text: &str) {
+        self.input
+            .textarea
+            .insert_str(crate::paste::normalize_query(text));
 
         self.input
             .brace_tracker
@@ -287,6 +437,31 @@ impl App {
         }
 
         // STEP 2: Popup stack (topmost first) - each handles its own Esc
+        if self.exit_prompt_visible {
+            crate::exit_prompt::exit_prompt_events::handle_exit_prompt_key(self, key);
+            return;
+        }
+
+        if self.recovery.is_visible() {
+            crate::recovery::handle_recovery_key(self, key);
+            return;
+        }
+
+        if self.paste.is_visible() {
+            crate::paste::handle_paste_offer_key(self, key);
+            return;
+        }
+
+        if self.in_place.is_visible() {
+            crate::in_place::handle_in_place_key(self, key);
+            return;
+        }
+
+        if self.exit_menu.is_visible() {
+            crate::exit_prompt::exit_menu_events::handle_exit_menu_key(self, key);
+            return;
+        }
+
         if self.help.visible && handle_help_keys(self, key) {
             return;
         }
@@ -295,6 +470,91 @@ impl App {
             return;
         }
 
+        if self.command_line.is_visible() {
+            crate::command_line::command_line_events::handle_command_line_key(self, key);
+            return;
+        }
+
+        if self.message_log.is_visible() {
+            crate::notification::handle_message_log_key(self, key);
+            return;
+        }
+
+        if self.schema.is_visible() {
+            crate::schema::handle_schema_key(self, key);
+            return;
+        }
+
+        if self.lint.is_visible() {
+            crate::lint::handle_lint_key(self, key);
+            return;
+        }
+
+        if self.field_diff.is_visible() {
+            crate::stats::field_diff_events::handle_field_diff_key(self, key);
+            return;
+        }
+
+        if self.size_breakdown.is_visible() {
+            crate::stats::size_breakdown_events::handle_size_breakdown_key(self, key);
+            return;
+        }
+
+        if self.histogram.is_visible() {
+            crate::histogram::handle_histogram_key(self, key);
+            return;
+        }
+
+        if self.projection.is_visible() {
+            crate::projection::handle_projection_key(self, key);
+            return;
+        }
+
+        if self.filter_builder.is_visible() {
+            crate::filter_builder::handle_filter_builder_key(self, key);
+            return;
+        }
+
+        if self.value_edit.is_visible() {
+            crate::value_edit::handle_value_edit_key(self, key);
+            return;
+        }
+
+        if self.query_tabs.is_comparing() {
+            crate::query_tabs::handle_compare_key(self, key);
+            return;
+        }
+
+        if self.templates.is_visible() && crate::templates::handle_templates_key(self, key) {
+            return;
+        }
+
+        if self.pipeline.is_visible() {
+            crate::pipeline::pipeline_events::handle_pipeline_key(self, key);
+            return;
+        }
+
+        if self.variables.is_visible() {
+            crate::variables::variables_events::handle_variables_key(self, key);
+            return;
+        }
+
+        if self.bookmarks.is_visible() {
+            crate::bookmarks::bookmark_events::handle_bookmark_key(self, key);
+            return;
+        }
+
+        if self.key_finder.is_visible() {
+            crate::key_finder::handle_key_finder_key(self, key);
+            return;
+        }
+
+        if self.ai.is_composing() {
+            let query = self.input.query().to_string();
+            crate::ai::ai_events::handle_follow_up_key(&mut self.ai, key, &query);
+            return;
+        }
+
         // STEP 3: Keys that should pass through even when snippets/history are visible
         if (self.snippets.is_visible() || self.history.is_visible())
             && handle_popup_passthrough_keys(self, key)
@@ -312,6 +572,19 @@ impl App {
             return;
         }
 
+        // Emacs editing keys shadow the global keys they share while typing
+        if self.focus == Focus::InputField
+            && self.input.keybindings == KeyBindings::Emacs
+            && editor::emacs::handle_emacs_key(self, key)
+        {
+            return;
+        }
+
+        // Plugin commands come first so that they can replace built-in keys
+        if crate::plugins::plugin_events::handle_plugin_key(self, key) {
+            return;
+        }
+
         // STEP 3: Other global keys (when no popup is active)
         if global::handle_global_keys(self, key) {
             return;
@@ -325,7 +598,13 @@ impl App {
         // STEP 5: Focus-based routing
         match self.focus {
             Focus::InputField => self.handle_input_field_key(key),
-            Focus::ResultsPane => results::results_events::handle_results_pane_key(self, key),
+            Focus::ResultsPane => {
+                if !(self.reads_nodes()
+                    && crate::screen_reader::handle_screen_reader_key(self, key))
+                {
+                    results::results_events::handle_results_pane_key(self, key);
+                }
+            }
         }
     }
 
@@ -334,7 +613,12 @@ impl App {
             if self.autocomplete.is_visible() {
                 self.autocomplete.hide();
             }
-            self.input.editor_mode = EditorMode::Normal;
+            // A placeholder left by an inserted tooltip example would
+            // otherwise grow with Normal mode motions
+            self.input.textarea.cancel_selection();
+            if self.input.keybindings == KeyBindings::Vim {
+                self.input.editor_mode = EditorMode::Normal;
+            }
             return;
         }
 
@@ -358,6 +642,19 @@ impl App {
                     self.autocomplete.select_previous();
                     return;
                 }
+                KeyCode::Right | KeyCode::End
+                    if key.modifiers.is_empty() && crate::autocomplete::accept_ghost_text(self) =>
+                {
+                    return;
+                }
+                KeyCode::PageDown => {
+                    self.autocomplete.page_down();
+                    return;
+                }
+                KeyCode::PageUp => {
+                    self.autocomplete.page_up();
+                    return;
+                }
                 _ => {}
             }
         }
@@ -377,6 +674,7 @@ impl App {
                     self.input.textarea.delete_line_by_head();
                     self.input.textarea.delete_line_by_end();
                     editor::editor_events::execute_query(self);
+                    self.update_tooltip();
                 }
                 return;
             }
@@ -413,6 +711,7 @@ impl App {
         self.input.textarea.delete_line_by_end();
         self.input.textarea.insert_str(text);
         editor::editor_events::execute_query(self);
+        self.update_tooltip();
     }
 
     fn open_history_popup(&mut self) {
@@ -430,6 +729,25 @@ impl App {
         self.autocomplete.hide();
     }
 
+    /// Add the completed query to the timeline
+    ///
+    /// Errors and null results are left out, as are results already
+    /// outdated by further typing, so half-typed queries rarely end up in it.
+    fn record_timeline(&mut self) {
+        if self.debouncer.has_pending() {
+            return;
+        }
+        let Some(query_state) = &self.query else {
+            return;
+        };
+        if query_state.result.is_err() || query_state.is_empty_result {
+            return;
+        }
+        let query = self.input.textarea.lines()[0].clone();
+        self.timeline
+            .record(&query, query_state.language, query_state.snapshot());
+    }
+
     /// Poll for query responses and update state
     ///
     /// Checks for completed async queries and triggers AI updates when needed.
@@ -445,16 +763,23 @@ impl App {
         if let Some(completed_query) = completed_query {
             // Result changed - update stats once (not on every frame)
             self.update_stats();
+            self.record_timeline();
+            crate::screen_reader::announce_result(self);
 
             // State changed - trigger AI update if visible and query is not empty
-            if self.ai.visible && !completed_query.is_empty() {
+            if self.ai.visible && !self.ai.configured {
+                self.refresh_offline_suggestions();
+            } else if self.ai.visible && !completed_query.is_empty() {
                 let query_state = self.query.as_ref().unwrap();
                 let cursor_pos = self.input.textarea.cursor().1;
 
+                // Spooled results are not read back for the prompt, their sample is
+                // enough
                 let ai_result: Result<String, String> = match &query_state.result {
                     Ok(_) => query_state
                         .last_successful_result_unformatted
                         .as_ref()
+                        .or(query_state.last_successful_result_for_context.as_ref())
                         .map(|s| Ok(s.as_ref().clone()))
                         .unwrap_or_else(|| Ok(String::new())),
                     Err(e) => Err(e.clone()),
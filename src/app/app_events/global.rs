@@ -2,6 +2,7 @@ use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
 use super::super::app_state::{App, Focus, OutputMode};
 use crate::help::HelpTab;
+use crate::input::input_format::InputFormat;
 
 fn accept_autocomplete_suggestion(app: &mut App) -> bool {
     if app.focus == Focus::InputField && app.autocomplete.is_visible() {
@@ -16,6 +17,74 @@ fn accept_autocomplete_suggestion(app: &mut App) -> bool {
     false
 }
 
+/// Run a query still waiting on the debouncer, keep it in history and exit
+/// printing `mode`
+fn quit_with_output(app: &mut App, mode: OutputMode) {
+    if app.debouncer.has_pending() {
+        crate::editor::editor_events::execute_query(app);
+        app.debouncer.mark_executed();
+    }
+    if let Some(query) = &app.query
+        && query.result.is_ok()
+        && !app.query().is_empty()
+    {
+        let query_str = app.query().to_string();
+        app.history.add_entry(&query_str);
+    }
+    app.output_mode = Some(mode);
+    app.should_quit = true;
+}
+
+/// Exit printing the equivalent `jq` command, unless jq cannot read the
+/// input because it was converted to JSON when loaded
+fn quit_with_command(app: &mut App) {
+    let message = match app.input_info.as_ref().map(|info| info.format) {
+        None | Some(InputFormat::Json) => return quit_with_output(app, OutputMode::Command),
+        Some(InputFormat::Plugin) => {
+            "The input was decoded by a plugin: no jq command can read it".to_string()
+        }
+        Some(format) => format!(
+            "The input is {}, not JSON: no jq command can read it",
+            format.label()
+        ),
+    };
+    app.notification.show_warning(&message);
+}
+
+/// Show the previous or next query of the timeline
+///
+/// Its snapshot is shown when still available, otherwise the query re-runs.
+fn step_timeline(app: &mut App, forward: bool) {
+    let Some(query_state) = &mut app.query else {
+        return;
+    };
+    let entry = if forward {
+        app.timeline.forward()
+    } else {
+        app.timeline.back()
+    };
+    let Some(entry) = entry.cloned() else {
+        return;
+    };
+
+    query_state.cancel_in_flight();
+    query_state.language = entry.language;
+
+    match entry.snapshot {
+        Some(snapshot) => {
+            query_state.restore_snapshot(snapshot);
+            crate::editor::editor_events::replace_query(app, &entry.query);
+            app.debouncer.mark_executed();
+        }
+        None => crate::editor::editor_events::replace_query_and_execute(app, &entry.query),
+    }
+
+    if let Some((step, steps)) = app.timeline.position() {
+        app.notification
+            .show(&format!("Timeline {}/{}", step, steps));
+    }
+}
+
 pub fn handle_global_keys(app: &mut App, key: KeyEvent) -> bool {
     if let Some(query) = &mut app.query
         && crate::ai::ai_events::handle_suggestion_selection(
@@ -26,18 +95,19 @@ pub fn handle_global_keys(app: &mut App, key: KeyEvent) -> bool {
             &mut app.autocomplete,
         )
     {
+        app.update_tooltip();
         return true;
     }
 
     match key.code {
         KeyCode::Char('q') if !key.modifiers.contains(KeyModifiers::CONTROL) => match app.focus {
             Focus::ResultsPane => {
-                app.should_quit = true;
+                crate::exit_prompt::exit_prompt_events::request_quit(app);
                 true
             }
             Focus::InputField => {
                 if app.input.editor_mode == crate::editor::EditorMode::Normal {
-                    app.should_quit = true;
+                    crate::exit_prompt::exit_prompt_events::request_quit(app);
                     true
                 } else {
                     false
@@ -45,52 +115,24 @@ pub fn handle_global_keys(app: &mut App, key: KeyEvent) -> bool {
             }
         },
 
+        KeyCode::Char('q' | 'Q')
+            if key
+                .modifiers
+                .contains(KeyModifiers::CONTROL | KeyModifiers::SHIFT) =>
+        {
+            quit_with_command(app);
+            true
+        }
         KeyCode::Char('q') if key.modifiers.contains(KeyModifiers::CONTROL) => {
-            if app.debouncer.has_pending() {
-                crate::editor::editor_events::execute_query(app);
-                app.debouncer.mark_executed();
-            }
-            if let Some(query) = &app.query
-                && query.result.is_ok()
-                && !app.query().is_empty()
-            {
-                let query_str = app.query().to_string();
-                app.history.add_entry(&query_str);
-            }
-            app.output_mode = Some(OutputMode::Query);
-            app.should_quit = true;
+            quit_with_output(app, OutputMode::Query);
             true
         }
         KeyCode::Enter if key.modifiers.contains(KeyModifiers::SHIFT) => {
-            if app.debouncer.has_pending() {
-                crate::editor::editor_events::execute_query(app);
-                app.debouncer.mark_executed();
-            }
-            if let Some(query) = &app.query
-                && query.result.is_ok()
-                && !app.query().is_empty()
-            {
-                let query_str = app.query().to_string();
-                app.history.add_entry(&query_str);
-            }
-            app.output_mode = Some(OutputMode::Query);
-            app.should_quit = true;
+            quit_with_output(app, OutputMode::Query);
             true
         }
         KeyCode::Enter if key.modifiers.contains(KeyModifiers::ALT) => {
-            if app.debouncer.has_pending() {
-                crate::editor::editor_events::execute_query(app);
-                app.debouncer.mark_executed();
-            }
-            if let Some(query) = &app.query
-                && query.result.is_ok()
-                && !app.query().is_empty()
-            {
-                let query_str = app.query().to_string();
-                app.history.add_entry(&query_str);
-            }
-            app.output_mode = Some(OutputMode::Query);
-            app.should_quit = true;
+            quit_with_output(app, OutputMode::Query);
             true
         }
         KeyCode::Enter => {
@@ -98,19 +140,17 @@ pub fn handle_global_keys(app: &mut App, key: KeyEvent) -> bool {
                 return true;
             }
 
-            if app.debouncer.has_pending() {
-                crate::editor::editor_events::execute_query(app);
-                app.debouncer.mark_executed();
+            if app.exit_menu.enabled {
+                crate::exit_prompt::exit_menu_events::open_exit_menu(app);
+                return true;
             }
-            if let Some(query) = &app.query
-                && query.result.is_ok()
-                && !app.query().is_empty()
-            {
-                let query_str = app.query().to_string();
-                app.history.add_entry(&query_str);
+
+            if app.in_place_mode {
+                crate::in_place::open_in_place_preview(app);
+                return true;
             }
-            app.output_mode = Some(OutputMode::Results);
-            app.should_quit = true;
+
+            quit_with_output(app, OutputMode::Results);
             true
         }
 
@@ -168,13 +208,49 @@ pub fn handle_global_keys(app: &mut App, key: KeyEvent) -> bool {
             true
         }
 
-        KeyCode::Char('t') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+        KeyCode::Char('o') if key.modifiers.contains(KeyModifiers::CONTROL) => {
             crate::tooltip::tooltip_events::handle_tooltip_toggle(&mut app.tooltip);
             true
         }
 
+        KeyCode::Char('t') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            crate::query_tabs::new_tab(app);
+            true
+        }
+
+        // While typing, Ctrl+W deletes the word before the cursor
+        KeyCode::Char('w')
+            if key.modifiers.contains(KeyModifiers::CONTROL)
+                && (app.focus == Focus::ResultsPane
+                    || app.input.editor_mode == crate::editor::EditorMode::Normal) =>
+        {
+            crate::query_tabs::close_tab(app);
+            true
+        }
+
+        KeyCode::PageDown if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            crate::query_tabs::cycle_tab(app, true);
+            true
+        }
+        KeyCode::PageUp if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            crate::query_tabs::cycle_tab(app, false);
+            true
+        }
+
+        KeyCode::Char('e') if key.modifiers.contains(KeyModifiers::ALT) => {
+            crate::tooltip::tooltip_events::handle_next_example(app)
+        }
+
+        KeyCode::Char('i') if key.modifiers.contains(KeyModifiers::ALT) => {
+            crate::tooltip::tooltip_events::handle_insert_example(app)
+        }
+
         KeyCode::Char('f') if key.modifiers.contains(KeyModifiers::CONTROL) => {
-            crate::search::search_events::open_search(app);
+            if app.focus == Focus::ResultsPane {
+                crate::key_finder::open_key_finder(app);
+            } else {
+                crate::search::search_events::open_search(app);
+            }
             true
         }
 
@@ -197,13 +273,119 @@ pub fn handle_global_keys(app: &mut App, key: KeyEvent) -> bool {
             true
         }
 
+        KeyCode::Char('a')
+            if key.modifiers.contains(KeyModifiers::ALT) && app.ai.can_follow_up() =>
+        {
+            app.ai.start_follow_up();
+            app.autocomplete.hide();
+            true
+        }
+
+        KeyCode::Char('p') if key.modifiers.contains(KeyModifiers::ALT) && app.ai.visible => {
+            match app.ai.retry_with_next_provider() {
+                Some(provider) => app.notification.show(&format!("Asking {}", provider)),
+                None if app.ai.providers.len() < 2 => app
+                    .notification
+                    .show_warning("No fallback AI provider configured"),
+                None => {}
+            }
+            true
+        }
+
         KeyCode::Char('s') if key.modifiers.contains(KeyModifiers::CONTROL) => {
-            app.snippets.open();
+            crate::snippets::snippet_events::open_manager(app);
             app.autocomplete.hide();
             app.history.close();
             true
         }
 
+        KeyCode::Char('g') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            app.history.close();
+            crate::pipeline::pipeline_events::open_pipeline(app);
+            true
+        }
+
+        KeyCode::Char('v') if key.modifiers.contains(KeyModifiers::ALT) => {
+            app.variables.open();
+            app.autocomplete.hide();
+            app.history.close();
+            true
+        }
+
+        KeyCode::Char('m') if key.modifiers.contains(KeyModifiers::ALT) => {
+            app.message_log.open();
+            app.autocomplete.hide();
+            app.history.close();
+            true
+        }
+
+        KeyCode::Char('b') if key.modifiers.contains(KeyModifiers::ALT) => {
+            app.bookmarks.open();
+            app.autocomplete.hide();
+            app.history.close();
+            true
+        }
+
+        KeyCode::Char('s') if key.modifiers.contains(KeyModifiers::ALT) => {
+            crate::filter_builder::open_filter_builder(app);
+            true
+        }
+
+        KeyCode::Char('u') if key.modifiers.contains(KeyModifiers::ALT) => {
+            crate::input::breadcrumbs::go_up(app);
+            true
+        }
+
+        // Terminals without keyboard enhancements report Ctrl+/ as Ctrl+7
+        KeyCode::Char('/' | '7') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            crate::editor::editor_events::toggle_comment(app);
+            true
+        }
+
+        KeyCode::Char('f')
+            if key.modifiers.contains(KeyModifiers::ALT) && app.follower.is_some() =>
+        {
+            if let Some(follower) = &mut app.follower {
+                follower.toggle_pause();
+                app.follow_scroll_pending = !follower.is_paused();
+            }
+            true
+        }
+
+        KeyCode::Char(c @ '1'..='9') if key.modifiers.contains(KeyModifiers::ALT) => {
+            crate::snippets::snippet_events::apply_slot(app, c as u8 - b'0');
+            true
+        }
+
+        KeyCode::Left if key.modifiers.contains(KeyModifiers::ALT) => {
+            step_timeline(app, false);
+            true
+        }
+        KeyCode::Right if key.modifiers.contains(KeyModifiers::ALT) => {
+            step_timeline(app, true);
+            true
+        }
+
+        KeyCode::Char(':')
+            if app.focus == Focus::ResultsPane
+                || app.input.editor_mode == crate::editor::EditorMode::Normal =>
+        {
+            app.command_line.open();
+            app.autocomplete.hide();
+            app.history.close();
+            true
+        }
+
+        KeyCode::Char('l') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            if let Some(query) = &mut app.query {
+                query.language = query.language.next();
+                app.autocomplete.hide();
+                crate::editor::editor_events::execute_query(app);
+                app.debouncer.mark_executed();
+            }
+            true
+        }
+
         _ => false,
     }
 }
@@ -14,6 +14,8 @@ mod error_overlay_tests;
 mod global_key_tests;
 #[path = "global_tests/help_popup_tests.rs"]
 mod help_popup_tests;
+#[path = "global_tests/timeline_tests.rs"]
+mod timeline_tests;
 
 // Re-export common test utilities for use in submodules
 pub(crate) use crate::app::app_state::{App, Focus, OutputMode};
@@ -593,3 +593,40 @@ fn test_ctrl_a_toggles_off_no_request() {
         "Should NOT send AI request when hiding popup"
     );
 }
+
+#[test]
+fn test_alt_a_opens_follow_up_after_a_response() {
+    let mut app = app_with_query(".users");
+    app.input.editor_mode = EditorMode::Insert;
+    app.ai.visible = true;
+    app.ai.conversation = vec![crate::ai::ai_state::ConversationTurn {
+        prompt: "prompt".to_string(),
+        follow_up: None,
+        response: "response".to_string(),
+    }];
+
+    app.handle_key_event(key_with_mods(KeyCode::Char('a'), KeyModifiers::ALT));
+    app.handle_key_event(key(KeyCode::Char('x')));
+
+    assert!(app.ai.is_composing());
+    assert_eq!(app.query(), ".users");
+
+    app.handle_key_event(key(KeyCode::Esc));
+    assert!(!app.ai.is_composing());
+}
+
+#[test]
+fn test_ctrl_a_shows_offline_suggestions_without_provider() {
+    let mut app = app_with_query(".services");
+    crate::test_utils::test_helpers::wait_for_query_completion(&mut app, 2000);
+    app.input.editor_mode = EditorMode::Insert;
+    assert!(!app.ai.configured);
+
+    app.handle_key_event(key_with_mods(KeyCode::Char('a'), KeyModifiers::CONTROL));
+
+    assert_eq!(app.ai.suggestions[0].query, ".services | map(.name)");
+    assert_eq!(app.ai.active_provider_info(), ("Suggestions", "offline"));
+
+    app.handle_key_event(key_with_mods(KeyCode::Char('1'), KeyModifiers::ALT));
+    assert_eq!(app.query(), ".services | map(.name)");
+}
@@ -2,8 +2,12 @@
 //!
 //! Tests for quit commands, output modes, focus switching, and general key handling
 
+use std::path::Path;
+
 use super::*;
 use crate::history::HistoryState;
+use crate::input::input_format::InputFormat;
+use crate::input::input_info::InputInfo;
 
 // ========== Quit and Output Mode Tests ==========
 
@@ -137,6 +141,85 @@ fn test_ctrl_q_does_not_save_failed_query() {
     assert!(app.should_quit);
 }
 
+// ========== Ctrl+Shift+Q Tests ==========
+
+#[test]
+fn test_ctrl_shift_q_outputs_command_and_saves_successful_query() {
+    let mut app = app_with_query(".name");
+    let initial_count = app.history.total_count();
+
+    app.handle_key_event(key_with_mods(
+        KeyCode::Char('Q'),
+        KeyModifiers::CONTROL | KeyModifiers::SHIFT,
+    ));
+
+    assert_eq!(app.history.total_count(), initial_count + 1);
+    assert_eq!(app.output_mode, Some(OutputMode::Command));
+    assert!(app.should_quit);
+}
+
+#[test]
+fn test_ctrl_shift_lowercase_q_outputs_command() {
+    let mut app = app_with_query(".name");
+
+    app.handle_key_event(key_with_mods(
+        KeyCode::Char('q'),
+        KeyModifiers::CONTROL | KeyModifiers::SHIFT,
+    ));
+
+    assert_eq!(app.output_mode, Some(OutputMode::Command));
+    assert!(app.should_quit);
+}
+
+#[test]
+fn test_ctrl_shift_q_refuses_input_converted_from_another_format() {
+    let mut app = app_with_query(".name");
+    app.input_info = Some(InputInfo::new(
+        Some(Path::new("Cargo.toml")),
+        None,
+        InputFormat::Toml,
+        "{}",
+    ));
+
+    app.handle_key_event(key_with_mods(
+        KeyCode::Char('Q'),
+        KeyModifiers::CONTROL | KeyModifiers::SHIFT,
+    ));
+
+    assert!(!app.should_quit);
+    assert_eq!(app.output_mode, None);
+    assert_eq!(
+        app.notification.current_message(),
+        Some("The input is TOML, not JSON: no jq command can read it")
+    );
+}
+
+#[test]
+fn test_ctrl_shift_q_refuses_plugin_decoded_input() {
+    let mut app = app_with_query(".name");
+    app.input_info = Some(InputInfo::new(None, None, InputFormat::Plugin, "{}"));
+
+    app.handle_key_event(key_with_mods(
+        KeyCode::Char('Q'),
+        KeyModifiers::CONTROL | KeyModifiers::SHIFT,
+    ));
+
+    assert!(!app.should_quit);
+    assert_eq!(
+        app.notification.current_message(),
+        Some("The input was decoded by a plugin: no jq command can read it")
+    );
+}
+
+#[test]
+fn test_ctrl_q_without_shift_still_outputs_query() {
+    let mut app = app_with_query(".name");
+
+    app.handle_key_event(key_with_mods(KeyCode::Char('q'), KeyModifiers::CONTROL));
+
+    assert_eq!(app.output_mode, Some(OutputMode::Query));
+}
+
 // ========== Shift+Enter Tests ==========
 
 #[test]
@@ -536,7 +619,7 @@ fn test_backtab_round_trip_preserves_popup_state() {
     assert!(app.tooltip.enabled);
 }
 
-// ========== Tooltip Toggle Tests (Ctrl+T) ==========
+// ========== Tooltip Toggle Tests (Ctrl+O) ==========
 
 #[test]
 fn test_tooltip_initializes_enabled() {
@@ -545,63 +628,63 @@ fn test_tooltip_initializes_enabled() {
 }
 
 #[test]
-fn test_ctrl_t_toggles_tooltip_from_enabled() {
+fn test_ctrl_o_toggles_tooltip_from_enabled() {
     let mut app = app_with_query(".");
     assert!(app.tooltip.enabled);
 
-    app.handle_key_event(key_with_mods(KeyCode::Char('t'), KeyModifiers::CONTROL));
+    app.handle_key_event(key_with_mods(KeyCode::Char('o'), KeyModifiers::CONTROL));
     assert!(!app.tooltip.enabled);
 }
 
 #[test]
-fn test_ctrl_t_toggles_tooltip_from_disabled() {
+fn test_ctrl_o_toggles_tooltip_from_disabled() {
     let mut app = app_with_query(".");
     app.tooltip.toggle(); // disable first
     assert!(!app.tooltip.enabled);
 
-    app.handle_key_event(key_with_mods(KeyCode::Char('t'), KeyModifiers::CONTROL));
+    app.handle_key_event(key_with_mods(KeyCode::Char('o'), KeyModifiers::CONTROL));
     assert!(app.tooltip.enabled);
 }
 
 #[test]
-fn test_ctrl_t_works_in_insert_mode() {
+fn test_ctrl_o_works_in_insert_mode() {
     let mut app = app_with_query(".");
     app.input.editor_mode = EditorMode::Insert;
     app.focus = Focus::InputField;
     assert!(app.tooltip.enabled);
 
-    app.handle_key_event(key_with_mods(KeyCode::Char('t'), KeyModifiers::CONTROL));
+    app.handle_key_event(key_with_mods(KeyCode::Char('o'), KeyModifiers::CONTROL));
     assert!(!app.tooltip.enabled);
 }
 
 #[test]
-fn test_ctrl_t_works_in_normal_mode() {
+fn test_ctrl_o_works_in_normal_mode() {
     let mut app = app_with_query(".");
     app.input.editor_mode = EditorMode::Normal;
     app.focus = Focus::InputField;
     assert!(app.tooltip.enabled);
 
-    app.handle_key_event(key_with_mods(KeyCode::Char('t'), KeyModifiers::CONTROL));
+    app.handle_key_event(key_with_mods(KeyCode::Char('o'), KeyModifiers::CONTROL));
     assert!(!app.tooltip.enabled);
 }
 
 #[test]
-fn test_ctrl_t_works_when_results_pane_focused() {
+fn test_ctrl_o_works_when_results_pane_focused() {
     let mut app = app_with_query(".");
     app.focus = Focus::ResultsPane;
     assert!(app.tooltip.enabled);
 
-    app.handle_key_event(key_with_mods(KeyCode::Char('t'), KeyModifiers::CONTROL));
+    app.handle_key_event(key_with_mods(KeyCode::Char('o'), KeyModifiers::CONTROL));
     assert!(!app.tooltip.enabled);
 }
 
 #[test]
-fn test_ctrl_t_preserves_current_function() {
+fn test_ctrl_o_preserves_current_function() {
     let mut app = app_with_query("select(.x)");
     app.tooltip.set_current_function(Some("select".to_string()));
     assert!(app.tooltip.enabled);
 
-    app.handle_key_event(key_with_mods(KeyCode::Char('t'), KeyModifiers::CONTROL));
+    app.handle_key_event(key_with_mods(KeyCode::Char('o'), KeyModifiers::CONTROL));
 
     // Should toggle enabled but preserve current_function
     assert!(!app.tooltip.enabled);
@@ -609,13 +692,13 @@ fn test_ctrl_t_preserves_current_function() {
 }
 
 #[test]
-fn test_ctrl_t_round_trip() {
+fn test_ctrl_o_round_trip() {
     let mut app = app_with_query(".");
     let initial_enabled = app.tooltip.enabled;
 
     // Toggle twice should return to original state
-    app.handle_key_event(key_with_mods(KeyCode::Char('t'), KeyModifiers::CONTROL));
-    app.handle_key_event(key_with_mods(KeyCode::Char('t'), KeyModifiers::CONTROL));
+    app.handle_key_event(key_with_mods(KeyCode::Char('o'), KeyModifiers::CONTROL));
+    app.handle_key_event(key_with_mods(KeyCode::Char('o'), KeyModifiers::CONTROL));
 
     assert_eq!(app.tooltip.enabled, initial_enabled);
 }
@@ -798,11 +881,106 @@ fn test_ctrl_f_works_in_insert_mode() {
 }
 
 #[test]
-fn test_ctrl_f_works_in_results_pane() {
+fn test_ctrl_f_in_results_pane_opens_key_finder() {
     let mut app = app_with_query(".test");
     app.focus = Focus::ResultsPane;
 
     app.handle_key_event(key_with_mods(KeyCode::Char('f'), KeyModifiers::CONTROL));
 
-    assert!(app.search.is_visible());
+    assert!(app.key_finder.is_visible());
+    assert!(!app.search.is_visible());
+}
+
+// ========== Ctrl+L Query Language Tests ==========
+
+#[test]
+fn test_ctrl_l_cycles_query_language() {
+    use crate::query::language::QueryLanguage;
+
+    let mut app = app_with_query("");
+
+    app.handle_key_event(key_with_mods(KeyCode::Char('l'), KeyModifiers::CONTROL));
+    assert_eq!(
+        app.query.as_ref().unwrap().language,
+        QueryLanguage::JsonPath
+    );
+
+    app.handle_key_event(key_with_mods(KeyCode::Char('l'), KeyModifiers::CONTROL));
+    assert_eq!(
+        app.query.as_ref().unwrap().language,
+        QueryLanguage::JmesPath
+    );
+
+    app.handle_key_event(key_with_mods(KeyCode::Char('l'), KeyModifiers::CONTROL));
+    assert_eq!(app.query.as_ref().unwrap().language, QueryLanguage::Sql);
+
+    app.handle_key_event(key_with_mods(KeyCode::Char('l'), KeyModifiers::CONTROL));
+    assert_eq!(app.query.as_ref().unwrap().language, QueryLanguage::Jq);
+}
+
+#[test]
+fn test_ctrl_l_reevaluates_query_in_new_language() {
+    let mut app = app_with_query("$.name");
+    assert!(app.query.as_ref().unwrap().result.is_err());
+
+    app.handle_key_event(key_with_mods(KeyCode::Char('l'), KeyModifiers::CONTROL));
+    assert!(wait_for_query_completion(&mut app, 2000));
+
+    let result = app.query.as_ref().unwrap().result.as_ref().unwrap();
+    assert!(result.contains("test"));
+}
+
+#[test]
+fn test_translation_error_is_reported_with_position() {
+    let mut app = app_with_query("$.services[");
+    app.handle_key_event(key_with_mods(KeyCode::Char('l'), KeyModifiers::CONTROL));
+
+    let query_state = app.query.as_ref().unwrap();
+    assert!(
+        query_state
+            .result
+            .as_ref()
+            .unwrap_err()
+            .starts_with("JSONPath error")
+    );
+    let error = query_state.parsed_error.as_ref().unwrap();
+    assert_eq!(error.query, "$.services[");
+    assert_eq!(error.span, Some(10..11));
+}
+
+#[test]
+fn test_alt_f_toggles_follow_pause() {
+    let dir = tempfile::TempDir::new().unwrap();
+    let path = dir.path().join("events.jsonl");
+    std::fs::write(&path, "").unwrap();
+
+    let mut app = test_app(TEST_JSON);
+    app.follower = Some(crate::input::FileFollower::spawn(path, 0));
+
+    app.handle_key_event(key_with_mods(KeyCode::Char('f'), KeyModifiers::ALT));
+    assert!(app.follower.as_ref().unwrap().is_paused());
+
+    app.handle_key_event(key_with_mods(KeyCode::Char('f'), KeyModifiers::ALT));
+    assert!(!app.follower.as_ref().unwrap().is_paused());
+    assert!(app.follow_scroll_pending);
+}
+
+#[test]
+fn test_alt_f_ignored_when_not_following() {
+    let mut app = test_app(TEST_JSON);
+    app.handle_key_event(key_with_mods(KeyCode::Char('f'), KeyModifiers::ALT));
+    assert!(app.follower.is_none());
+}
+
+#[test]
+fn test_ctrl_slash_toggles_comment() {
+    let mut app = app_with_query(".name | length");
+    app.focus = Focus::ResultsPane;
+
+    app.handle_key_event(key_with_mods(KeyCode::Char('/'), KeyModifiers::CONTROL));
+    assert_eq!(app.query(), ".name # | length");
+    assert_eq!(app.focus, Focus::InputField);
+
+    app.handle_key_event(key_with_mods(KeyCode::Char('7'), KeyModifiers::CONTROL));
+    assert_eq!(app.query(), ".name | length");
 }
@@ -0,0 +1,104 @@
+//! Tests for stepping through the query timeline with Alt+Left/Alt+Right
+
+use super::*;
+use crate::query::language::QueryLanguage;
+
+fn alt(code: KeyCode) -> ratatui::crossterm::event::KeyEvent {
+    key_with_mods(code, KeyModifiers::ALT)
+}
+
+/// Type `query`, run it and record it like a completed query
+fn run_query(app: &mut App, query: &str) {
+    app.input.textarea.delete_line_by_head();
+    app.input.textarea.delete_line_by_end();
+    app.input.textarea.insert_str(query);
+    crate::editor::editor_events::execute_query(app);
+    assert!(wait_for_query_completion(app, 2000));
+    app.record_timeline();
+}
+
+fn result_text(app: &App) -> String {
+    app.query.as_ref().unwrap().result.clone().unwrap()
+}
+
+#[test]
+fn test_alt_left_restores_previous_query_and_result() {
+    let mut app = test_app(TEST_JSON);
+    run_query(&mut app, ".name");
+    run_query(&mut app, ".city");
+
+    app.handle_key_event(alt(KeyCode::Left));
+
+    assert_eq!(app.query(), ".name");
+    assert_eq!(result_text(&app).trim(), "\"test\"");
+    assert!(!app.query.as_ref().unwrap().is_pending());
+    assert_eq!(app.notification.current_message(), Some("Timeline 2/3"));
+}
+
+#[test]
+fn test_alt_right_steps_forward_again() {
+    let mut app = test_app(TEST_JSON);
+    run_query(&mut app, ".name");
+    run_query(&mut app, ".city");
+    app.handle_key_event(alt(KeyCode::Left));
+    app.handle_key_event(alt(KeyCode::Left));
+    assert_eq!(app.query(), "");
+
+    app.handle_key_event(alt(KeyCode::Right));
+    app.handle_key_event(alt(KeyCode::Right));
+
+    assert_eq!(app.query(), ".city");
+    assert_eq!(result_text(&app).trim(), "\"NYC\"");
+}
+
+#[test]
+fn test_errors_and_null_results_are_not_recorded() {
+    let mut app = test_app(TEST_JSON);
+    run_query(&mut app, ".name");
+    run_query(&mut app, ".nam");
+    run_query(&mut app, ".name |");
+
+    app.handle_key_event(alt(KeyCode::Left));
+
+    assert_eq!(app.query(), "");
+}
+
+#[test]
+fn test_stepping_back_restores_query_language() {
+    let mut app = test_app(TEST_JSON);
+    app.query.as_mut().unwrap().language = QueryLanguage::JsonPath;
+    run_query(&mut app, "$.age");
+    app.query.as_mut().unwrap().language = QueryLanguage::Jq;
+    run_query(&mut app, ".city");
+
+    app.handle_key_event(alt(KeyCode::Left));
+
+    assert_eq!(app.query(), "$.age");
+    assert_eq!(
+        app.query.as_ref().unwrap().language,
+        QueryLanguage::JsonPath
+    );
+    assert!(result_text(&app).contains("30"));
+}
+
+#[test]
+fn test_evicted_snapshot_reruns_query() {
+    let mut app = test_app(TEST_JSON);
+    run_query(&mut app, ".age");
+    run_query(&mut app, ".city");
+    app.timeline.clear_snapshots();
+
+    app.handle_key_event(alt(KeyCode::Left));
+    assert_eq!(app.query(), ".age");
+    assert!(wait_for_query_completion(&mut app, 2000));
+    assert_eq!(result_text(&app).trim(), "30");
+}
+
+#[test]
+fn test_alt_left_at_start_does_nothing() {
+    let mut app = test_app(TEST_JSON);
+    app.handle_key_event(alt(KeyCode::Left));
+
+    assert_eq!(app.query(), "");
+    assert_eq!(app.notification.current_message(), None);
+}
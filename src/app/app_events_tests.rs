@@ -56,6 +56,114 @@ fn test_paste_event_with_multiline_text() {
     assert!(app.query().contains(".name"));
 }
 
+#[test]
+fn test_paste_permalink_replaces_query() {
+    use crate::permalink::Permalink;
+    use crate::query::variables::{QueryVariable, VariableKind};
+
+    let mut app = test_app(r#"{"name": "Alice", "age": 30}"#);
+    app.input.textarea.insert_str(".age");
+    let link = Permalink {
+        query: ".[$field]".to_string(),
+        variables: vec![QueryVariable::new("field", "name", VariableKind::String)],
+        ..Permalink::default()
+    }
+    .encode();
+
+    app.handle_paste_event(format!("{}\n", link));
+
+    assert_eq!(app.query(), ".[$field]");
+    assert_eq!(app.variables.variables().len(), 1);
+    assert_eq!(
+        app.notification.current_message(),
+        Some("Opened shared query")
+    );
+    assert!(crate::test_utils::test_helpers::wait_for_query_completion(
+        &mut app, 2000
+    ));
+    let result = app.query.as_ref().unwrap().result.as_ref().unwrap();
+    assert!(result.contains("Alice"));
+}
+
+#[test]
+fn test_paste_invalid_permalink_keeps_query() {
+    let mut app = test_app(r#"{"name": "test"}"#);
+    app.input.textarea.insert_str(".name");
+
+    app.handle_paste_event("jiq://%%%".to_string());
+
+    assert_eq!(app.query(), ".name");
+    assert_eq!(
+        app.notification.current_message(),
+        Some("Invalid jiq:// link")
+    );
+}
+
+#[test]
+fn test_handle_event_dispatches_replayed_events() {
+    use ratatui::crossterm::event::{Event, KeyEvent};
+
+    let mut app = test_app(r#"{"name": "test"}"#);
+
+    app.handle_event(Event::Paste(".na".to_string()));
+    app.handle_event(Event::Key(KeyEvent::new(
+        KeyCode::Char('m'),
+        KeyModifiers::empty(),
+    )));
+    app.handle_event(Event::Key(KeyEvent::new(
+        KeyCode::Char('e'),
+        KeyModifiers::empty(),
+    )));
+
+    assert_eq!(app.query(), ".name");
+}
+
+#[test]
+fn test_multiline_paste_is_joined_into_one_line() {
+    use ratatui::crossterm::event::Event;
+
+    let mut app = test_app(r#"{"items": [{"name": "a"}]}"#);
+
+    app.handle_event(Event::Paste(
+        "# names of the items\n.items[]\n  | .name # just the name\n".to_string(),
+    ));
+
+    assert_eq!(app.query(), ".items[] | .name");
+    assert_eq!(app.input.textarea.lines().len(), 1);
+}
+
+#[test]
+fn test_altgr_characters_are_typed() {
+    use ratatui::crossterm::event::{Event, KeyEvent};
+
+    let mut app = test_app(r#"{"a": 1}"#);
+    app.input.editor_mode = EditorMode::Insert;
+    let altgr = KeyModifiers::CONTROL | KeyModifiers::ALT;
+
+    for c in ['[', '{', '}', ']', '@'] {
+        app.handle_event(Event::Key(KeyEvent::new(KeyCode::Char(c), altgr)));
+    }
+
+    assert_eq!(app.query(), "[{}]@");
+}
+
+#[test]
+fn test_normalize_altgr_keeps_shortcuts() {
+    use ratatui::crossterm::event::KeyEvent;
+
+    let altgr = KeyModifiers::CONTROL | KeyModifiers::ALT;
+    let shortcut = KeyEvent::new(KeyCode::Char('d'), altgr);
+    assert_eq!(super::normalize_altgr(shortcut), shortcut);
+    let ctrl = KeyEvent::new(KeyCode::Char('['), KeyModifiers::CONTROL);
+    assert_eq!(super::normalize_altgr(ctrl), ctrl);
+
+    let euro = super::normalize_altgr(KeyEvent::new(
+        KeyCode::Char('€'),
+        altgr | KeyModifiers::SHIFT,
+    ));
+    assert_eq!(euro.modifiers, KeyModifiers::SHIFT);
+}
+
 // Feature: performance, Property 1: Paste text insertion integrity
 // *For any* string pasted into the application, the input field content after
 // the paste operation should contain exactly that string at the cursor position.
@@ -138,7 +246,7 @@ fn test_ctrl_d_scrolls_results_from_input_field_insert_mode() {
     let content: String = (0..50).map(|i| format!("line{}\n", i)).collect();
     let query_state = app.query.as_mut().unwrap();
     query_state.result = Ok(content.clone());
-    query_state.last_successful_result = Some(Arc::new(content.clone()));
+    query_state.last_successful_result_unformatted = Some(Arc::new(content.clone()));
     query_state.cached_line_count = content.lines().count() as u32;
 
     let line_count = app.results_line_count_u32();
@@ -174,7 +282,7 @@ fn test_ctrl_d_scrolls_results_from_input_field_normal_mode() {
     let content: String = (0..50).map(|i| format!("line{}\n", i)).collect();
     let query_state = app.query.as_mut().unwrap();
     query_state.result = Ok(content.clone());
-    query_state.last_successful_result = Some(Arc::new(content.clone()));
+    query_state.last_successful_result_unformatted = Some(Arc::new(content.clone()));
     query_state.cached_line_count = content.lines().count() as u32;
 
     let line_count = app.results_line_count_u32();
@@ -212,7 +320,7 @@ fn test_ctrl_d_scrolls_results_from_input_field_operator_mode() {
     let content: String = (0..50).map(|i| format!("line{}\n", i)).collect();
     let query_state = app.query.as_mut().unwrap();
     query_state.result = Ok(content.clone());
-    query_state.last_successful_result = Some(Arc::new(content.clone()));
+    query_state.last_successful_result_unformatted = Some(Arc::new(content.clone()));
     query_state.cached_line_count = content.lines().count() as u32;
 
     let line_count = app.results_line_count_u32();
@@ -255,7 +363,7 @@ fn test_mouse_scroll_down_increases_offset() {
     let content: String = (0..50).map(|i| format!("line{}\n", i)).collect();
     let query_state = app.query.as_mut().unwrap();
     query_state.result = Ok(content.clone());
-    query_state.last_successful_result = Some(Arc::new(content.clone()));
+    query_state.last_successful_result_unformatted = Some(Arc::new(content.clone()));
     query_state.cached_line_count = content.lines().count() as u32;
 
     let line_count = app.results_line_count_u32();
@@ -296,7 +404,7 @@ fn test_mouse_scroll_down_multiple_times() {
     let content: String = (0..50).map(|i| format!("line{}\n", i)).collect();
     let query_state = app.query.as_mut().unwrap();
     query_state.result = Ok(content.clone());
-    query_state.last_successful_result = Some(Arc::new(content.clone()));
+    query_state.last_successful_result_unformatted = Some(Arc::new(content.clone()));
     query_state.cached_line_count = content.lines().count() as u32;
 
     let line_count = app.results_line_count_u32();
@@ -336,6 +444,7 @@ fn test_snippets_receives_keys_when_focus_is_results_pane() {
         name: "test".to_string(),
         query: ".test".to_string(),
         description: None,
+        input: Default::default(),
     }]);
     app.snippets.open();
     app.focus = Focus::ResultsPane;
@@ -361,11 +470,13 @@ fn test_snippets_navigation_works_when_focus_is_results_pane() {
             name: "first".to_string(),
             query: ".first".to_string(),
             description: None,
+            input: Default::default(),
         },
         Snippet {
             name: "second".to_string(),
             query: ".second".to_string(),
             description: None,
+            input: Default::default(),
         },
     ]);
     app.snippets.open();
@@ -411,6 +522,7 @@ fn test_global_keys_work_when_snippets_visible() {
         name: "test".to_string(),
         query: ".test".to_string(),
         description: None,
+        input: Default::default(),
     }]);
     app.snippets.open();
 
@@ -439,6 +551,7 @@ fn test_ctrl_c_quits_when_snippets_visible() {
         name: "test".to_string(),
         query: ".test".to_string(),
         description: None,
+        input: Default::default(),
     }]);
     app.snippets.open();
 
@@ -453,6 +566,45 @@ fn test_ctrl_c_quits_when_snippets_visible() {
     );
 }
 
+#[test]
+fn test_alt_t_toggles_timing_overlay() {
+    let mut app = app_with_query(".");
+
+    app.handle_key_event(key_with_mods(KeyCode::Char('t'), KeyModifiers::ALT));
+    assert!(app.timing_overlay_visible);
+
+    app.handle_key_event(key_with_mods(KeyCode::Char('t'), KeyModifiers::ALT));
+    assert!(!app.timing_overlay_visible);
+}
+
+#[test]
+fn test_alt_r_toggles_redacted_view() {
+    let mut app = app_with_query(".");
+    assert!(!app.redaction.enabled);
+
+    app.handle_key_event(key_with_mods(KeyCode::Char('r'), KeyModifiers::ALT));
+    assert!(app.redaction.enabled);
+    assert_eq!(
+        app.notification.current_message(),
+        Some("Sensitive values hidden")
+    );
+
+    app.handle_key_event(key_with_mods(KeyCode::Char('r'), KeyModifiers::ALT));
+    assert!(!app.redaction.enabled);
+}
+
+#[test]
+fn test_alt_t_toggles_timing_overlay_in_pipeline_view() {
+    let mut app = app_with_query(".a | .b");
+    app.handle_key_event(key_with_mods(KeyCode::Char('g'), KeyModifiers::CONTROL));
+    assert!(app.pipeline.is_visible());
+
+    app.handle_key_event(key_with_mods(KeyCode::Char('t'), KeyModifiers::ALT));
+
+    assert!(app.timing_overlay_visible);
+    assert!(app.pipeline.is_visible());
+}
+
 #[test]
 fn test_esc_closes_help_before_snippets() {
     use crate::snippets::Snippet;
@@ -463,6 +615,7 @@ fn test_esc_closes_help_before_snippets() {
         name: "test".to_string(),
         query: ".test".to_string(),
         description: None,
+        input: Default::default(),
     }]);
     app.snippets.open();
     app.help.visible = true;
@@ -11,35 +11,94 @@ impl App {
         self.frame_count = self.frame_count.wrapping_add(1);
         self.layout_regions.clear();
 
-        let overlay_visible = self.search.is_visible() || self.snippets.is_visible();
+        let overlay_visible = self.search.is_visible()
+            || self.snippets.is_visible()
+            || self.pipeline.is_visible()
+            || self.variables.is_visible();
 
-        let (results_area, input_area, help_area) = if overlay_visible {
-            let layout =
-                Layout::vertical([Constraint::Min(3), Constraint::Length(1)]).split(frame.area());
+        // The screen-reader layout has one announcement line instead of the
+        // status and help lines
+        let footer_lines = if self.screen_reader.is_enabled() {
+            1
+        } else {
+            2
+        };
+        // The path of the query gets a line under the input field
+        let crumbs = if overlay_visible || self.command_line.is_visible() {
+            Vec::new()
+        } else {
+            crate::input::breadcrumbs::visible_crumbs(self)
+        };
+        let crumb_lines = u16::from(!crumbs.is_empty());
+        // With several query tabs, a line above the input field lists them
+        let tab_lines = u16::from(self.query_tabs.has_tabs() && !self.screen_reader.is_enabled());
+        let (results_area, input_area, footer_area) = if overlay_visible {
+            let layout = Layout::vertical([Constraint::Min(3), Constraint::Length(footer_lines)])
+                .split(frame.area());
             (layout[0], None, layout[1])
         } else {
             let layout = Layout::vertical([
                 Constraint::Min(3),
+                Constraint::Length(tab_lines),
                 Constraint::Length(3),
-                Constraint::Length(1),
+                Constraint::Length(crumb_lines),
+                Constraint::Length(footer_lines),
             ])
             .split(frame.area());
-            (layout[0], Some(layout[1]), layout[2])
+            if tab_lines > 0 {
+                crate::query_tabs::render_tab_bar(self, frame, layout[1]);
+            }
+            if !crumbs.is_empty() {
+                crate::input::breadcrumbs::render_breadcrumbs(&crumbs, frame, layout[3]);
+                self.layout_regions.breadcrumbs = Some(layout[3]);
+            }
+            (layout[0], Some(layout[2]), layout[4])
         };
 
-        let (results_rect, search_rect) =
-            crate::results::results_render::render_pane(self, frame, results_area);
-        self.layout_regions.results_pane = Some(results_rect);
-        if let Some(search_rect) = search_rect {
-            self.layout_regions.search_bar = Some(search_rect);
+        // A numeric result gets a line above the pane for its sparkline
+        let mut sparkline_image = None;
+        let results_area = if self.sparkline.numbers().is_some()
+            && !self.screen_reader.is_enabled()
+            && results_area.height > 6
+        {
+            let [sparkline_area, pane_area] =
+                Layout::vertical([Constraint::Length(1), Constraint::Min(3)]).areas(results_area);
+            sparkline_image =
+                crate::results::sparkline::render_sparkline(self, frame, sparkline_area);
+            pane_area
+        } else {
+            results_area
+        };
+
+        if self.reads_nodes() {
+            crate::screen_reader::render_nodes(self, frame, results_area);
+            self.layout_regions.results_pane = Some(results_area);
+        } else {
+            let (results_rect, search_rect) =
+                crate::results::results_render::render_pane(self, frame, results_area);
+            self.layout_regions.results_pane = Some(results_rect);
+            if let Some(search_rect) = search_rect {
+                self.layout_regions.search_bar = Some(search_rect);
+            }
         }
 
         if let Some(input_area) = input_area {
-            let input_rect = crate::input::input_render::render_field(self, frame, input_area);
-            self.layout_regions.input_field = Some(input_rect);
+            if self.command_line.is_visible() {
+                crate::command_line::command_line_render::render_line(self, frame, input_area);
+            } else {
+                let input_rect = crate::input::input_render::render_field(self, frame, input_area);
+                self.layout_regions.input_field = Some(input_rect);
+            }
         }
 
-        crate::help::help_line_render::render_line(self, frame, help_area);
+        if self.screen_reader.is_enabled() {
+            crate::screen_reader::render_announcement(self, frame, footer_area);
+        } else {
+            let [status_area, help_area] =
+                Layout::vertical([Constraint::Length(1), Constraint::Length(1)]).areas(footer_area);
+            crate::layout::status_line::render_line(self, frame, status_area);
+            crate::help::help_line_render::render_line(self, frame, help_area);
+        }
 
         if let Some(input_area) = input_area {
             if self.ai.visible
@@ -60,6 +119,11 @@ impl App {
                     crate::autocomplete::autocomplete_render::render_popup(self, frame, input_area)
             {
                 self.layout_regions.autocomplete = Some(autocomplete_rect);
+                crate::autocomplete::autocomplete_render::render_preview(
+                    self,
+                    frame,
+                    autocomplete_rect,
+                );
             }
 
             if self.history.is_visible()
@@ -84,6 +148,34 @@ impl App {
             }
         }
 
+        if self.pipeline.is_visible() {
+            crate::pipeline::pipeline_render::render_popup(&self.pipeline, frame, results_area);
+        }
+
+        if self.variables.is_visible() {
+            crate::variables::variables_render::render_popup(
+                &mut self.variables,
+                frame,
+                results_area,
+            );
+        }
+
+        if self.bookmarks.is_visible() {
+            crate::bookmarks::bookmark_render::render_popup(
+                &mut self.bookmarks,
+                frame,
+                results_area,
+            );
+        }
+
+        if self.key_finder.is_visible() {
+            crate::key_finder::render_key_finder(&mut self.key_finder, frame, results_area);
+        }
+
+        if self.timing_overlay_visible {
+            crate::results::timing_overlay::render_overlay(self, frame, results_area);
+        }
+
         if self.error_overlay_visible
             && let Some(query) = &self.query
             && query.result.is_err()
@@ -93,12 +185,89 @@ impl App {
             self.layout_regions.error_overlay = Some(error_rect);
         }
 
+        if self.message_log.is_visible() {
+            crate::notification::render_message_log(self, frame);
+        }
+
+        if self.schema.is_visible() {
+            crate::schema::render_schema_popup(self, frame);
+        }
+
+        if self.lint.is_visible() {
+            crate::lint::render_lint_popup(self, frame);
+        }
+
+        if self.field_diff.is_visible() {
+            crate::stats::field_diff_render::render_field_diff(self, frame);
+        }
+
+        if self.size_breakdown.is_visible() {
+            crate::stats::size_breakdown_render::render_size_breakdown(self, frame);
+        }
+
+        if self.histogram.is_visible() {
+            crate::histogram::render_histogram(self, frame);
+        }
+
+        if self.projection.is_visible() {
+            crate::projection::render_projection(&self.projection, frame);
+        }
+
+        if self.filter_builder.is_visible() {
+            crate::filter_builder::render_filter_builder(&mut self.filter_builder, frame);
+        }
+
+        if self.value_edit.is_visible() {
+            crate::value_edit::render_value_edit(self, frame);
+        }
+
+        if self.query_tabs.is_comparing() {
+            crate::query_tabs::render_comparison(self, frame);
+        }
+
+        if self.templates.is_visible() {
+            crate::templates::render_templates(&self.templates, frame);
+        }
+
+        if self.exit_menu.is_visible() {
+            crate::exit_prompt::exit_menu_render::render_popup(self, frame);
+        }
+
+        if self.in_place.is_visible() {
+            crate::in_place::render_in_place_preview(self, frame);
+        }
+
+        if self.paste.is_visible() {
+            crate::paste::render_paste_offer(self, frame);
+        }
+
+        if self.recovery.is_visible() {
+            crate::recovery::render_recovery_prompt(self, frame);
+        }
+
+        if self.exit_prompt_visible {
+            crate::exit_prompt::exit_prompt_render::render_popup(self, frame);
+        }
+
         if self.help.visible
             && let Some(help_rect) = crate::help::help_popup_render::render_popup(self, frame)
         {
             self.layout_regions.help_popup = Some(help_rect);
         }
 
-        render_notification(frame, &mut self.notification);
+        // Notifications are read from the announcement line instead
+        if !self.screen_reader.is_enabled() {
+            render_notification(frame, &mut self.notification);
+        }
+
+        // Written after the frame, if nothing was drawn over it since
+        self.sparkline
+            .set_image(sparkline_image, frame.buffer_mut());
+
+        if self.no_color {
+            crate::widgets::monochrome::apply(frame.buffer_mut());
+        } else if self.screen_reader.is_enabled() {
+            crate::widgets::monochrome::ascii_borders(frame.buffer_mut());
+        }
     }
 }
@@ -28,6 +28,43 @@ fn snapshot_ui_with_query() {
     assert_snapshot!(output);
 }
 
+#[test]
+fn snapshot_ui_without_color() {
+    let json = r#"{"name": "Alice", "age": 30}"#;
+    let mut app = test_app(json);
+    app.no_color = true;
+    app.input.textarea.insert_str(".name");
+    app.query.as_mut().unwrap().execute(".name");
+    app.update_stats();
+
+    let output = render_to_string(&mut app, TEST_WIDTH, TEST_HEIGHT);
+    assert_snapshot!(output);
+}
+
+#[test]
+fn snapshot_ui_screen_reader() {
+    let json = r#"{"name": "Alice", "age": 30}"#;
+    let mut app = test_app(json);
+    app.screen_reader.enable();
+    app.update_stats();
+    crate::screen_reader::announce_result(&mut app);
+
+    let output = render_to_string(&mut app, TEST_WIDTH, TEST_HEIGHT);
+    assert_snapshot!(output);
+}
+
+#[test]
+fn snapshot_ui_with_numeric_result() {
+    let json = r#"{"prices": [4, 8, 15, 16, 23, 42]}"#;
+    let mut app = test_app(json);
+    app.input.textarea.insert_str(".prices[]");
+    app.query.as_mut().unwrap().execute(".prices[]");
+    app.update_stats();
+
+    let output = render_to_string(&mut app, TEST_WIDTH, TEST_HEIGHT);
+    assert_snapshot!(output);
+}
+
 #[test]
 fn snapshot_ui_with_array_data() {
     let json = r#"[{"name": "Alice"}, {"name": "Bob"}, {"name": "Charlie"}]"#;
@@ -144,6 +181,32 @@ fn snapshot_error_overlay() {
     assert_snapshot!(output);
 }
 
+#[test]
+fn snapshot_error_overlay_with_explanation() {
+    let json = r#"{"name": "Alice"}"#;
+    let mut app = test_app(json);
+
+    app.input.textarea.insert_str(".name.first");
+    app.query.as_mut().unwrap().execute(".name.first");
+    app.error_overlay_visible = true;
+
+    let output = render_to_string(&mut app, TEST_WIDTH, TEST_HEIGHT);
+    assert_snapshot!(output);
+}
+
+#[test]
+fn snapshot_results_pane_with_runtime_error_partial_output() {
+    let json = r#"[{"name": "Alice"}, {"name": "Bob"}, "Carol"]"#;
+    let mut app = test_app(json);
+
+    app.input.textarea.insert_str(".[] | .name");
+    app.query.as_mut().unwrap().execute(".[] | .name");
+    app.update_stats();
+
+    let output = render_to_string(&mut app, TEST_WIDTH, TEST_HEIGHT);
+    assert_snapshot!(output);
+}
+
 #[test]
 fn snapshot_results_pane_with_syntax_error_unfocused() {
     let json = r#"{"name": "Alice", "age": 30}"#;
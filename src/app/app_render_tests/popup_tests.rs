@@ -23,6 +23,25 @@ fn snapshot_history_popup() {
     assert_snapshot!(output);
 }
 
+#[test]
+fn snapshot_history_popup_with_marked_entries() {
+    let json = r#"{"test": true}"#;
+    let mut app = test_app(json);
+
+    app.history = HistoryState::empty();
+    app.history.add_entry_in_memory(".name");
+    app.history.add_entry_in_memory(".age");
+    app.history.add_entry_in_memory(".users[]");
+    app.history.open(None);
+    app.history.toggle_mark();
+    app.history.select_next();
+    app.history.select_next();
+    app.history.toggle_mark();
+
+    let output = render_to_string(&mut app, TEST_WIDTH, TEST_HEIGHT);
+    assert_snapshot!(output);
+}
+
 #[test]
 fn snapshot_history_popup_with_search() {
     let json = r#"{"test": true}"#;
@@ -199,6 +218,20 @@ fn snapshot_tooltip_dismiss_hint() {
     assert_snapshot!(output);
 }
 
+#[test]
+fn snapshot_tooltip_selected_example() {
+    let json = r#"{"name": "Alice", "age": 30}"#;
+    let mut app = test_app(json);
+
+    app.tooltip.enabled = true;
+    app.tooltip.set_current_function(Some("map".to_string()));
+    app.tooltip.select_next_example();
+    app.tooltip.select_next_example();
+
+    let output = render_to_string(&mut app, TOOLTIP_TEST_WIDTH, TOOLTIP_TEST_HEIGHT);
+    assert_snapshot!(output);
+}
+
 #[test]
 fn snapshot_tooltip_operator_alternative() {
     let json = r#"{"name": "Alice", "age": 30}"#;
@@ -97,3 +97,32 @@ fn snapshot_results_error_unfocused() {
     let output = render_to_string(&mut app, TEST_WIDTH, TEST_HEIGHT);
     assert_snapshot!(output);
 }
+
+#[test]
+fn snapshot_results_follow_paused() {
+    let dir = tempfile::TempDir::new().unwrap();
+    let path = dir.path().join("events.jsonl");
+    std::fs::write(&path, "").unwrap();
+
+    let mut app = test_app(r#"{"level": "info"}"#);
+    let mut follower = crate::input::FileFollower::spawn(path, 0);
+    follower.toggle_pause();
+    app.follower = Some(follower);
+    app.update_stats();
+
+    let output = render_to_string(&mut app, TEST_WIDTH, TEST_HEIGHT);
+    assert_snapshot!(output);
+}
+
+#[test]
+fn snapshot_results_redacted() {
+    let json = r#"{"user": "alice", "auth": {"password": "hunter2", "api_key": "k-123"}}"#;
+    let mut app = test_app(json);
+    app.input.textarea.insert_str(".");
+    app.query.as_mut().unwrap().execute(".");
+    app.update_stats();
+    app.redaction.enabled = true;
+
+    let output = render_to_string(&mut app, TEST_WIDTH, TEST_HEIGHT);
+    assert_snapshot!(output);
+}
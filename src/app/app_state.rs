@@ -1,18 +1,56 @@
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
 use crate::ai::AiState;
+use crate::autocomplete::suggestion_preview::SuggestionPreview;
+use crate::autocomplete::suggestion_ranking::{self, SuggestionRanking};
 use crate::autocomplete::{self, AutocompleteState};
-use crate::config::{ClipboardBackend, Config};
+use crate::bookmarks::BookmarkState;
+use crate::command_line::CommandLineState;
+use crate::config::{ClipboardBackend, Config, ConfigReload, ConfigWatcher};
+use crate::exit_prompt::exit_menu_state::ExitMenuState;
+use crate::external::ExternalRequest;
+use crate::filter_builder::FilterBuilderState;
 use crate::help::HelpPopupState;
+use crate::histogram::HistogramState;
 use crate::history::HistoryState;
-use crate::input::{FileLoader, InputState};
+use crate::in_place::InPlaceState;
+use crate::input::command_source::CommandSource;
+use crate::input::input_format::InputFormat;
+use crate::input::input_info::InputInfo;
+use crate::input::{FileFollower, FileLoader, InputState};
+use crate::key_finder::KeyFinderState;
 use crate::layout::LayoutRegions;
-use crate::notification::NotificationState;
+use crate::lint::LintState;
+use crate::notification::{MessageLogState, NotificationState};
+use crate::paste::PasteState;
+use crate::permalink::Permalink;
+use crate::pipeline::PipelineState;
+use crate::plugins::{PluginRegistry, PluginTask};
+use crate::projection::ProjectionState;
+use crate::query::library::JqLibrary;
+use crate::query::timeline::QueryTimeline;
 use crate::query::{Debouncer, QueryState};
+use crate::query_tabs::QueryTabsState;
+use crate::recording::{EventRecorder, EventReplayer};
+use crate::recovery::RecoveryState;
 use crate::results::cursor_state::CursorState;
+use crate::results::folding::FoldState;
+use crate::results::redaction::RedactionState;
+use crate::results::sparkline::SparklineState;
+use crate::results::sticky_header::StickyHeaderState;
+use crate::schema::SchemaState;
+use crate::screen_reader::ScreenReaderState;
 use crate::scroll::ScrollState;
 use crate::search::SearchState;
+use crate::session::{Session, SessionScroll};
 use crate::snippets::SnippetState;
-use crate::stats::{self, StatsState};
+use crate::stats::{self, FieldDiffState, SizeBreakdownState, StatsState};
+use crate::templates::TemplatesState;
 use crate::tooltip::{self, TooltipState};
+use crate::value_edit::ValueEditState;
+use crate::variables::VariablesState;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Focus {
@@ -20,32 +58,141 @@ pub enum Focus {
     ResultsPane,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum OutputMode {
     Results,
     Query,
+    /// The query followed by its results
+    Both,
+    /// Results written to a file instead of stdout
+    File(PathBuf),
+    /// Standalone `jq` command line reproducing the query
+    Command,
+    /// Previewed results written over the input file (`--in-place`)
+    InPlace(PathBuf, Arc<String>),
 }
 
 pub struct App {
     pub input: InputState,
     pub query: Option<QueryState>,
     pub file_loader: Option<FileLoader>,
+    /// Input file path, `None` when reading from stdin
+    pub input_path: Option<PathBuf>,
+    /// Command the input was read from (`jiq k8s`, `jiq aws`)
+    pub input_command: Option<CommandSource>,
+    /// Source, size and format of the loaded input for the status line
+    pub input_info: Option<InputInfo>,
+    /// Follow the input file for appended records once loaded (`--follow`)
+    pub follow: bool,
+    pub follower: Option<FileFollower>,
+    /// Scroll results to the bottom once appended records are shown
+    pub follow_scroll_pending: bool,
+    /// Name of the session resumed with `--session` or saved last
+    pub session_name: Option<String>,
+    /// Session to restore once the input has loaded
+    pub pending_session: Option<Session>,
+    /// Recovery file of the input and the offer to restore one left by an
+    /// unclean exit
+    pub recovery: RecoveryState,
+    /// JSON document pasted into the query, offered to load as the input
+    pub paste: PasteState,
+    /// Results position to restore once the session's query completes
+    pub session_scroll_pending: Option<SessionScroll>,
     pub focus: Focus,
     pub results_scroll: ScrollState,
     pub results_cursor: CursorState,
+    /// Closed objects and arrays in the results pane (`zc`, `zM`, `:fold`)
+    pub results_fold: FoldState,
     pub output_mode: Option<OutputMode>,
     pub should_quit: bool,
+    /// When this session started, for the exit confirmation
+    pub started_at: Instant,
+    /// Session age after which quitting asks first (`[exit]` config), if enabled
+    pub confirm_exit_after: Option<Duration>,
+    /// Query as last saved to a session or copied, so quitting doesn't ask
+    pub saved_query: Option<String>,
+    /// Exit confirmation shown after `q`/`Ctrl+C`
+    pub exit_prompt_visible: bool,
+    /// Choice of output shown by Enter, if enabled
+    pub exit_menu: ExitMenuState,
+    /// `--in-place`: Enter offers to write the results over the input file
+    pub in_place_mode: bool,
+    /// Diff shown before writing the results over the input file
+    pub in_place: InPlaceState,
     pub autocomplete: AutocompleteState,
+    /// How often suggestions were accepted for inputs of this shape
+    pub suggestion_ranking: SuggestionRanking,
+    /// Result preview of the selected autocomplete suggestion
+    pub suggestion_preview: SuggestionPreview,
     pub error_overlay_visible: bool,
+    /// Recent query timings shown over the results (Alt+T)
+    pub timing_overlay_visible: bool,
+    /// Masking of sensitive values in the results pane (Alt+R)
+    pub redaction: RedactionState,
+    /// Render without colors and with ASCII borders (`--no-color`, `NO_COLOR`)
+    pub no_color: bool,
+    /// Numbers of a numeric result, drawn as a sparkline above the results
+    pub sparkline: SparklineState,
+    /// Path of the container around the top of the scrolled results pane
+    pub sticky_header: StickyHeaderState,
+    /// Whether the path of the query is shown as crumbs under the input
+    pub breadcrumbs: bool,
+    /// Screen-reader layout and the node being read (`--screen-reader`)
+    pub screen_reader: ScreenReaderState,
     pub history: HistoryState,
     pub help: HelpPopupState,
     pub notification: NotificationState,
+    /// Past notifications popup (Alt+M)
+    pub message_log: MessageLogState,
+    /// Schema for `:validate` and the popup listing its violations
+    pub schema: SchemaState,
+    /// Issues found in the raw input by `:lint`
+    pub lint: LintState,
+    /// Comparison of the result's elements (`D` in the results pane)
+    pub field_diff: FieldDiffState,
+    /// Sizes of the result's top-level keys or elements (`S` in the results pane)
+    pub size_breakdown: SizeBreakdownState,
+    /// Counts of a field's values (`c` in the results pane)
+    pub histogram: HistogramState,
+    /// Column picker building a projection (`p` in the results pane)
+    pub projection: ProjectionState,
+    /// Form composing a `select(...)` clause (Alt+S)
+    pub filter_builder: FilterBuilderState,
+    /// Inline editor for a key or value of the result (`e` in the results pane)
+    pub value_edit: ValueEditState,
+    /// Starter queries for a recognised input (shown on load, `:templates`)
+    pub templates: TemplatesState,
     pub clipboard_backend: ClipboardBackend,
+    pub query_cache_size: usize,
+    /// jq module search paths and user library, applied once the input loads
+    pub jq_library: Arc<JqLibrary>,
     pub tooltip: TooltipState,
     pub stats: StatsState,
     pub debouncer: Debouncer,
+    /// Queries run this session, stepped through with Alt+Left/Alt+Right
+    pub timeline: QueryTimeline,
+    /// Other queries kept against the same input (Ctrl+T)
+    pub query_tabs: QueryTabsState,
     pub search: SearchState,
+    pub command_line: CommandLineState,
     pub snippets: SnippetState,
+    pub pipeline: PipelineState,
+    pub variables: VariablesState,
+    pub bookmarks: BookmarkState,
+    /// Sidebar finding keys and values in the input (`Ctrl+F` in the results pane)
+    pub key_finder: KeyFinderState,
+    /// Terminal events saved for `--record`
+    pub recorder: Option<EventRecorder>,
+    /// Recorded events played back in place of the terminal (`--replay`)
+    pub replayer: Option<EventReplayer>,
+    /// Plugins from the `[[plugins]]` config sections
+    pub plugins: PluginRegistry,
+    /// Plugin command running in the background
+    pub plugin_task: Option<PluginTask>,
+    /// Config file whose changes are applied while jiq runs
+    pub config_watcher: Option<ConfigWatcher>,
+    /// Editor or pager the event loop hands the terminal to next
+    pub pending_external: Option<ExternalRequest>,
     pub ai: AiState,
     pub saved_tooltip_visibility: bool,
     pub saved_ai_visibility_for_search: bool,
@@ -130,26 +277,92 @@ impl App {
             config.tooltip.auto_show
         };
 
+        let mut input = InputState::new();
+        input.auto_pairs = config.editor.auto_pairs;
+        input.keybindings = config.editor.keybindings;
+
+        let mut autocomplete = AutocompleteState::new();
+        autocomplete.validate_fields = config.autocomplete.validate_fields;
+
         Self {
-            input: InputState::new(),
+            input,
             query: None,
             file_loader: Some(loader),
+            input_path: None,
+            input_command: None,
+            input_info: None,
+            follow: false,
+            follower: None,
+            follow_scroll_pending: false,
+            session_name: None,
+            pending_session: None,
+            recovery: RecoveryState::new(config.exit.recover),
+            paste: PasteState::new(),
+            session_scroll_pending: None,
             focus: Focus::InputField,
             results_scroll: ScrollState::new(),
             results_cursor: CursorState::new(),
+            results_fold: FoldState::new(),
             output_mode: None,
             should_quit: false,
-            autocomplete: AutocompleteState::new(),
+            started_at: Instant::now(),
+            confirm_exit_after: config
+                .exit
+                .confirm
+                .then(|| Duration::from_secs(config.exit.confirm_after_secs)),
+            saved_query: None,
+            exit_prompt_visible: false,
+            exit_menu: ExitMenuState::new(config.exit.menu),
+            in_place_mode: false,
+            in_place: InPlaceState::default(),
+            autocomplete,
+            suggestion_ranking: SuggestionRanking::new(),
+            suggestion_preview: SuggestionPreview::new(),
             error_overlay_visible: false,
-            history: HistoryState::new(),
+            timing_overlay_visible: false,
+            redaction: RedactionState::new(&config.redaction),
+            no_color: config.display.no_color,
+            sparkline: SparklineState::new(
+                config.display.sparkline,
+                config.display.sparkline_images,
+            ),
+            sticky_header: StickyHeaderState::new(config.display.sticky_header),
+            breadcrumbs: config.display.breadcrumbs,
+            screen_reader: ScreenReaderState::new(config.display.screen_reader),
+            history: HistoryState::with_source(None, config.history.clone()),
             help: HelpPopupState::new(),
             notification: NotificationState::new(),
+            message_log: MessageLogState::new(),
+            schema: SchemaState::default(),
+            lint: LintState::default(),
+            field_diff: FieldDiffState::default(),
+            size_breakdown: SizeBreakdownState::default(),
+            histogram: HistogramState::new(),
+            projection: ProjectionState::new(),
+            filter_builder: FilterBuilderState::new(),
+            value_edit: ValueEditState::new(),
+            templates: TemplatesState::new(),
             clipboard_backend: config.clipboard.backend,
+            query_cache_size: config.query.cache_size,
+            jq_library: Arc::default(),
             tooltip: TooltipState::new(tooltip_enabled),
             stats: StatsState::default(),
             debouncer: Debouncer::new(),
+            timeline: QueryTimeline::new(),
+            query_tabs: QueryTabsState::new(),
             search: SearchState::new(),
+            command_line: CommandLineState::new(),
             snippets: SnippetState::new(),
+            pipeline: PipelineState::new(),
+            variables: VariablesState::new(),
+            bookmarks: BookmarkState::new(),
+            key_finder: KeyFinderState::new(),
+            recorder: None,
+            replayer: None,
+            plugins: PluginRegistry::default(),
+            plugin_task: None,
+            config_watcher: None,
+            pending_external: None,
             ai: ai_state,
             saved_tooltip_visibility: config.tooltip.auto_show,
             saved_ai_visibility_for_search: false,
@@ -172,24 +385,53 @@ impl App {
             self.mark_dirty();
             match result {
                 Ok(json_input) => {
-                    self.query = Some(QueryState::new(json_input.clone()));
-
-                    let schema_input = crate::json::extract_first_json_value(&json_input)
-                        .unwrap_or_else(|| json_input.clone());
-
-                    self.input_json_schema =
-                        crate::json::extract_json_schema_dynamic(&schema_input).map(|s| {
-                            crate::ai::context::prepare_schema_for_context(
-                                &s,
-                                self.ai.max_context_length,
-                            )
-                        });
+                    let format = self
+                        .file_loader
+                        .as_ref()
+                        .and_then(|loader| loader.format)
+                        .unwrap_or(InputFormat::Json);
+                    self.input_info = Some(InputInfo::new(
+                        self.input_path.as_deref(),
+                        self.input_command.as_ref(),
+                        format,
+                        &json_input,
+                    ));
+                    let mut query_state =
+                        QueryState::with_cache_size(json_input.clone(), self.query_cache_size);
+                    query_state.set_variables(self.variables.variables().to_vec());
+                    query_state.set_library(Arc::clone(&self.jq_library));
+                    self.timeline
+                        .record("", query_state.language, query_state.snapshot());
+                    self.load_bookmarks(&json_input);
+                    self.query = Some(query_state);
+                    crate::recovery::load_recovery(self, &json_input);
+                    self.load_input_schema(&json_input);
 
                     // Initialize stats for initial result
                     self.update_stats();
 
+                    // Resumed sessions bring their own query
+                    if self.templates.detect(&json_input).is_some()
+                        && self.pending_session.is_none()
+                        && !self.recovery.is_visible()
+                        && self.query().is_empty()
+                    {
+                        self.templates.open();
+                    }
+
                     self.file_loader = None;
 
+                    if let Some(session) = self.pending_session.take() {
+                        self.restore_session(session);
+                    }
+
+                    if self.follow
+                        && let Some(path) = &self.input_path
+                    {
+                        self.follower =
+                            Some(FileFollower::spawn(path.clone(), json_input.len() as u64));
+                    }
+
                     // Ensure AI works on launch with deferred file loading
                     if self.ai.visible && self.ai.enabled && self.ai.configured {
                         self.trigger_ai_request();
@@ -204,20 +446,252 @@ impl App {
         }
     }
 
+    /// Infer the schema of the input for autocomplete and the AI context
+    fn load_input_schema(&mut self, json_input: &str) {
+        let schema_input = crate::json::extract_first_json_value(json_input)
+            .unwrap_or_else(|| json_input.to_string());
+
+        let schema = crate::json::extract_json_schema_dynamic(&schema_input);
+        if let Some(schema) = &schema {
+            self.load_suggestion_ranking(schema);
+        }
+        self.input_json_schema = schema.map(|s| {
+            crate::ai::context::prepare_schema_for_context(&s, self.ai.max_context_length)
+        });
+    }
+
+    /// Replace the input with `json_input`, e.g. after editing it, and run
+    /// the query on it again
+    pub fn replace_input(&mut self, json_input: String) {
+        let format = self
+            .input_info
+            .as_ref()
+            .map_or(InputFormat::Json, |info| info.format);
+        self.input_info = Some(InputInfo::new(
+            self.input_path.as_deref(),
+            self.input_command.as_ref(),
+            format,
+            &json_input,
+        ));
+        let language = self.query.as_ref().map(|q| q.language).unwrap_or_default();
+        let mut query_state =
+            QueryState::with_cache_size(json_input.clone(), self.query_cache_size);
+        query_state.language = language;
+        query_state.set_variables(self.variables.variables().to_vec());
+        query_state.set_library(Arc::clone(&self.jq_library));
+        self.query = Some(query_state);
+        self.timeline.clear_snapshots();
+        self.query_tabs.clear_snapshots();
+        self.load_input_schema(&json_input);
+        crate::editor::editor_events::execute_query(self);
+        self.mark_dirty();
+    }
+
+    /// Load the bookmarks saved for the input document
+    fn load_bookmarks(&mut self, json_input: &str) {
+        let key = crate::bookmarks::bookmark_storage::document_key(
+            self.input_path.as_deref(),
+            self.input_command.as_ref(),
+            json_input,
+        );
+        if let Some(path) = crate::bookmarks::bookmark_storage::bookmarks_path(&key) {
+            self.bookmarks.load_from(path);
+        }
+    }
+
+    /// Apply the changes made to the config file since it was last read
+    pub fn poll_config_watcher(&mut self) {
+        let Some(reload) = self
+            .config_watcher
+            .as_mut()
+            .and_then(|w| w.poll(Instant::now()))
+        else {
+            return;
+        };
+        self.mark_dirty();
+        match reload {
+            ConfigReload::Loaded { previous, current } => {
+                self.apply_config_change(&previous, &current);
+                self.notification.show("Config reloaded");
+            }
+            ConfigReload::Invalid(warning) => {
+                self.notification
+                    .show_error(&format!("{}; keeping the current config", warning));
+            }
+        }
+    }
+
+    /// Apply the settings that differ between `previous` and `current`
+    ///
+    /// Only changed settings are applied, so a setting toggled with a key
+    /// (such as redaction or the tooltip) stays as it is unless the file
+    /// changes it too. AI, history, plugins, input format and `[exit]
+    /// recover` settings are read at startup only.
+    pub fn apply_config_change(&mut self, previous: &Config, current: &Config) {
+        if previous.editor.auto_pairs != current.editor.auto_pairs {
+            self.input.auto_pairs = current.editor.auto_pairs;
+        }
+        if previous.editor.keybindings != current.editor.keybindings {
+            self.input.keybindings = current.editor.keybindings;
+        }
+        if previous.autocomplete.validate_fields != current.autocomplete.validate_fields {
+            self.autocomplete.validate_fields = current.autocomplete.validate_fields;
+        }
+        if previous.tooltip.auto_show != current.tooltip.auto_show {
+            self.tooltip.enabled = current.tooltip.auto_show;
+        }
+        if previous.clipboard.backend != current.clipboard.backend {
+            self.clipboard_backend = current.clipboard.backend;
+        }
+        if previous.query.cache_size != current.query.cache_size {
+            self.query_cache_size = current.query.cache_size;
+        }
+        if previous.exit.confirm != current.exit.confirm
+            || previous.exit.confirm_after_secs != current.exit.confirm_after_secs
+        {
+            self.confirm_exit_after = current
+                .exit
+                .confirm
+                .then(|| Duration::from_secs(current.exit.confirm_after_secs));
+        }
+        if previous.exit.menu != current.exit.menu {
+            self.exit_menu.enabled = current.exit.menu;
+        }
+        if previous.redaction.enabled != current.redaction.enabled
+            || previous.redaction.fields != current.redaction.fields
+        {
+            self.redaction = RedactionState::new(&current.redaction);
+        }
+        if previous.display.no_color != current.display.no_color {
+            self.no_color = current.display.no_color;
+        }
+        if previous.display.sparkline != current.display.sparkline {
+            self.sparkline.set_enabled(current.display.sparkline);
+        }
+        if previous.display.sparkline_images != current.display.sparkline_images {
+            self.sparkline.set_images(current.display.sparkline_images);
+        }
+        if previous.display.sticky_header != current.display.sticky_header {
+            self.sticky_header
+                .set_enabled(current.display.sticky_header);
+        }
+        if previous.display.breadcrumbs != current.display.breadcrumbs {
+            self.breadcrumbs = current.display.breadcrumbs;
+        }
+        if previous.syntax != current.syntax {
+            crate::syntax_highlight::set_theme(crate::syntax_highlight::SyntaxTheme::from_config(
+                &current.syntax,
+            ));
+        }
+    }
+
+    /// Load the suggestion counts saved for inputs shaped like `schema`
+    fn load_suggestion_ranking(&mut self, schema: &str) {
+        let key = suggestion_ranking::shape_key(schema);
+        if let Some(path) = suggestion_ranking::ranking_path(&key) {
+            self.suggestion_ranking.load_from(path);
+        }
+    }
+
+    /// Restore the query of a saved session and run it
+    ///
+    /// The results position is restored by the renderer once the query
+    /// completes, since it depends on the size of the result.
+    pub(crate) fn restore_session(&mut self, session: Session) {
+        if let Some(query_state) = &mut self.query {
+            query_state.language = session.language;
+        }
+        self.input.textarea.delete_line_by_head();
+        self.input.textarea.delete_line_by_end();
+        self.input.textarea.insert_str(&session.query);
+        crate::editor::editor_events::execute_query(self);
+        self.session_scroll_pending = Some(session.scroll);
+    }
+
+    /// Replace the query with one shared through a `jiq://` link
+    ///
+    /// Variables in the link replace bindings of the same name. If the
+    /// input is still loading, the query runs once it has loaded.
+    pub fn open_permalink(&mut self, permalink: Permalink) {
+        if !permalink.variables.is_empty() {
+            let mut variables = self.variables.variables().to_vec();
+            variables.retain(|v| !permalink.variables.iter().any(|p| p.name == v.name));
+            variables.extend(permalink.variables.iter().cloned());
+            self.variables.set_variables(variables);
+            if let Some(query_state) = &mut self.query {
+                query_state.set_variables(self.variables.variables().to_vec());
+                self.timeline.clear_snapshots();
+                self.query_tabs.clear_snapshots();
+            }
+        }
+
+        let session = Session::from(permalink);
+        if self.query.is_some() {
+            self.restore_session(session);
+        } else {
+            self.pending_session = Some(session);
+        }
+    }
+
+    /// Poll the file follower and add appended records to the input
+    ///
+    /// The query is re-run (incrementally when possible) and the results
+    /// scroll to the bottom once it completes.
+    pub fn poll_follower(&mut self) {
+        let Some(update) = self.follower.as_mut().and_then(|f| f.poll()) else {
+            return;
+        };
+        self.mark_dirty();
+
+        if update.truncated {
+            self.notification
+                .show_warning("File truncated, following from the start");
+        }
+        if let Some(error) = update.error {
+            log::debug!("Skipped appended lines: {}", error);
+            self.notification
+                .show_error("Skipped appended lines that are not valid JSON");
+        }
+
+        if update.records.is_empty() {
+            return;
+        }
+        if let Some(info) = &mut self.input_info {
+            info.add_records(&update.records);
+        }
+        self.timeline.clear_snapshots();
+        self.query_tabs.clear_snapshots();
+        let query = self.query().to_string();
+        if let Some(query_state) = &mut self.query {
+            if !query_state.append_input(&update.records, &query) && !self.debouncer.has_pending() {
+                query_state.execute_async(&query);
+            }
+            self.follow_scroll_pending = true;
+        }
+    }
+
     pub fn should_quit(&self) -> bool {
         self.should_quit
     }
 
     pub fn output_mode(&self) -> Option<OutputMode> {
-        self.output_mode
+        self.output_mode.clone()
     }
 
     pub fn query(&self) -> &str {
         self.input.query()
     }
 
+    /// Rows the result takes up in the results pane, with folds closed
     pub fn results_line_count_u32(&self) -> u32 {
-        self.query.as_ref().map_or(0, |q| q.line_count())
+        self.query
+            .as_ref()
+            .map_or(0, |q| self.results_fold.row_count(q.line_count()))
+    }
+
+    /// Result line on the results cursor row, looking through folds
+    pub fn results_cursor_result_line(&self) -> usize {
+        self.results_fold.line_at(self.results_cursor.cursor_line())
     }
 
     pub fn update_autocomplete(&mut self) {
@@ -230,6 +704,14 @@ impl App {
 
     pub fn update_stats(&mut self) {
         stats::update_stats_from_app(self);
+        if let Some(query_state) = &self.query
+            && let Some(output) = query_state.last_successful_result_text()
+        {
+            self.sparkline.update(
+                &output,
+                query_state.last_successful_result_parsed.as_deref(),
+            );
+        }
     }
 
     pub fn insert_autocomplete_suggestion(
@@ -240,8 +722,11 @@ impl App {
     }
 
     /// Trigger an AI request for the current query context
+    ///
+    /// Without a provider, offline suggestions are shown instead.
     pub fn trigger_ai_request(&mut self) {
         if !self.ai.configured {
+            self.refresh_offline_suggestions();
             return;
         }
 
@@ -253,10 +738,13 @@ impl App {
         let query = self.input.query().to_string();
         let cursor_pos = self.input.textarea.cursor().1;
 
+        // Spooled results are not read back for the prompt, their sample is
+        // enough
         let ai_result: Result<String, String> = match &query_state.result {
             Ok(_) => query_state
                 .last_successful_result_unformatted
                 .as_ref()
+                .or(query_state.last_successful_result_for_context.as_ref())
                 .map(|s| Ok(s.as_ref().clone()))
                 .unwrap_or_else(|| Ok(String::new())),
             Err(e) => Err(e.clone()),
@@ -279,6 +767,24 @@ impl App {
         );
     }
 
+    /// Suggest next queries from the shape of the last successful result
+    pub fn refresh_offline_suggestions(&mut self) {
+        let suggestions = match &self.query {
+            Some(query_state) => match &query_state.last_successful_result_parsed {
+                Some(value) => crate::ai::offline::suggest(
+                    query_state
+                        .base_query_for_suggestions
+                        .as_deref()
+                        .unwrap_or("."),
+                    value,
+                ),
+                None => Vec::new(),
+            },
+            None => Vec::new(),
+        };
+        self.ai.set_offline_suggestions(suggestions);
+    }
+
     pub fn mark_dirty(&mut self) {
         self.needs_render = true;
     }
@@ -327,6 +833,13 @@ impl App {
         self.focus = Focus::ResultsPane;
     }
 
+    /// Whether the results pane reads the result one node at a time
+    ///
+    /// Search needs the pretty-printed lines, so it brings them back.
+    pub fn reads_nodes(&self) -> bool {
+        self.screen_reader.is_enabled() && !self.search.is_visible()
+    }
+
     /// Switch focus to the input field, restoring AI/tooltip visibility
     pub fn focus_input_field(&mut self) {
         if self.focus == Focus::InputField {
@@ -108,7 +108,7 @@ fn test_max_scroll_large_content() {
     let large_result: String = (0..70000).map(|i| format!("line {}\n", i)).collect();
     let query_state = app.query.as_mut().unwrap();
     query_state.result = Ok(large_result.clone());
-    query_state.last_successful_result = Some(Arc::new(large_result.clone()));
+    query_state.last_successful_result_unformatted = Some(Arc::new(large_result.clone()));
     query_state.cached_line_count = large_result.lines().count() as u32;
 
     let line_count = app.results_line_count_u32();
@@ -127,7 +127,7 @@ fn test_results_line_count_large_file() {
     let result: String = (0..65535).map(|_| "x\n").collect();
     let query_state = app.query.as_mut().unwrap();
     query_state.result = Ok(result.clone());
-    query_state.last_successful_result = Some(Arc::new(result.clone()));
+    query_state.last_successful_result_unformatted = Some(Arc::new(result.clone()));
     query_state.cached_line_count = result.lines().count() as u32;
 
     assert_eq!(app.results_line_count_u32(), 65535);
@@ -145,7 +145,7 @@ fn test_line_count_uses_last_result_on_error() {
     let valid_result: String = (0..50).map(|i| format!("line{}\n", i)).collect();
     let query_state = app.query.as_mut().unwrap();
     query_state.result = Ok(valid_result.clone());
-    query_state.last_successful_result = Some(Arc::new(valid_result.clone()));
+    query_state.last_successful_result_unformatted = Some(Arc::new(valid_result.clone()));
     query_state.cached_line_count = valid_result.lines().count() as u32;
 
     assert_eq!(app.results_line_count_u32(), 50);
@@ -164,7 +164,7 @@ fn test_line_count_with_error_no_cached_result() {
     let mut app = test_app(json);
 
     let query_state = app.query.as_mut().unwrap();
-    query_state.last_successful_result = None;
+    query_state.last_successful_result_unformatted = None;
     query_state.cached_line_count = 0;
     query_state.result = Err("error message".to_string());
 
@@ -527,6 +527,9 @@ fn test_new_with_bedrock_provider() {
         ai: AiConfig {
             enabled: true,
             provider: Some(AiProviderType::Bedrock),
+            fallback: Vec::new(),
+            budget: Default::default(),
+            prompts: Default::default(),
             bedrock: BedrockConfig {
                 region: Some("us-east-1".to_string()),
                 model: Some("anthropic.claude-3-sonnet".to_string()),
@@ -551,6 +554,9 @@ fn test_new_with_openai_provider() {
         ai: AiConfig {
             enabled: true,
             provider: Some(AiProviderType::Openai),
+            fallback: Vec::new(),
+            budget: Default::default(),
+            prompts: Default::default(),
             openai: OpenAiConfig {
                 api_key: Some("test-key".to_string()),
                 model: Some("gpt-4".to_string()),
@@ -575,6 +581,9 @@ fn test_new_with_gemini_provider() {
         ai: AiConfig {
             enabled: true,
             provider: Some(AiProviderType::Gemini),
+            fallback: Vec::new(),
+            budget: Default::default(),
+            prompts: Default::default(),
             gemini: GeminiConfig {
                 api_key: Some("test-key".to_string()),
                 model: Some("gemini-pro".to_string()),
@@ -623,9 +632,7 @@ fn test_trigger_ai_request_strips_ansi_from_success_output() {
 
     app.input.textarea.insert_str(".name");
     if let Some(query_state) = &mut app.query {
-        query_state.result = Ok("\x1b[0;32m\"test\"\x1b[0m\n".to_string());
-        query_state.last_successful_result =
-            Some(Arc::new("\x1b[0;32m\"test\"\x1b[0m\n".to_string()));
+        query_state.result = Ok("\"test\"\n".to_string());
         query_state.last_successful_result_unformatted = Some(Arc::new("\"test\"\n".to_string()));
         query_state.last_successful_result_for_context = Some(Arc::new(
             crate::ai::context::prepare_json_for_context("\"test\"\n", app.ai.max_context_length),
@@ -670,8 +677,6 @@ fn test_trigger_ai_request_strips_ansi_from_base_query_result() {
     app.input.textarea.insert_str(".invalid");
     if let Some(query_state) = &mut app.query {
         query_state.result = Err("field not found".to_string());
-        query_state.last_successful_result =
-            Some(Arc::new("\x1b[0;32m\"test\"\x1b[0m\n".to_string()));
         query_state.last_successful_result_unformatted = Some(Arc::new("\"test\"\n".to_string()));
         query_state.base_query_for_suggestions = Some(".name".to_string());
     }
@@ -717,8 +722,6 @@ fn test_trigger_ai_request_empty_result_uses_unformatted() {
     if let Some(query_state) = &mut app.query {
         query_state.result = Ok("null\n".to_string());
         query_state.is_empty_result = true;
-        query_state.last_successful_result =
-            Some(Arc::new("\x1b[0;32m\"previous\"\x1b[0m\n".to_string()));
         query_state.last_successful_result_unformatted =
             Some(Arc::new("\"previous\"\n".to_string()));
         query_state.last_successful_result_for_context =
@@ -748,3 +751,180 @@ fn test_trigger_ai_request_empty_result_uses_unformatted() {
 #[cfg(test)]
 #[path = "app_state_tests/dirty_flag_tests.rs"]
 mod dirty_flag_tests;
+
+/// App loaded from `path` with `--follow`, as main sets it up
+fn following_app(path: &std::path::Path, content: &str) -> App {
+    let loader = create_test_loader(content.to_string());
+    let mut app = App::new_with_loader(loader, &Config::default());
+    app.input_path = Some(path.to_path_buf());
+    app.follow = true;
+    app.poll_file_loader();
+    app
+}
+
+#[test]
+fn test_follow_starts_after_loading() {
+    let dir = tempfile::TempDir::new().unwrap();
+    let path = dir.path().join("events.jsonl");
+    std::fs::write(&path, "{\"n\": 1}\n").unwrap();
+
+    let app = following_app(&path, "{\"n\": 1}\n");
+    assert!(app.follower.is_some());
+
+    let app = test_app("{\"n\": 1}");
+    assert!(app.follower.is_none());
+}
+
+#[test]
+fn test_poll_follower_appends_records_and_scrolls() {
+    use std::io::Write;
+
+    let initial = "{\"n\": 1}\n";
+    let dir = tempfile::TempDir::new().unwrap();
+    let path = dir.path().join("events.jsonl");
+    std::fs::write(&path, initial).unwrap();
+    let mut app = following_app(&path, initial);
+    app.input.textarea.insert_str(".n");
+    app.query.as_mut().unwrap().execute(".n");
+
+    let mut file = std::fs::OpenOptions::new()
+        .append(true)
+        .open(&path)
+        .unwrap();
+    file.write_all(b"{\"n\": 2}\n").unwrap();
+
+    let start = std::time::Instant::now();
+    while !app.follow_scroll_pending && start.elapsed() < std::time::Duration::from_secs(2) {
+        app.poll_follower();
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    }
+    assert!(app.follow_scroll_pending);
+    assert!(crate::test_utils::test_helpers::wait_for_query_completion(
+        &mut app, 2000
+    ));
+
+    let query_state = app.query.as_ref().unwrap();
+    assert_eq!(
+        query_state
+            .last_successful_result_unformatted
+            .as_deref()
+            .unwrap(),
+        "1\n2\n"
+    );
+}
+
+#[test]
+fn test_pending_session_is_restored_after_loading() {
+    use crate::query::language::QueryLanguage;
+
+    let json = (0..100)
+        .map(|i| format!("{{\"n\": {}}}", i))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let mut app = App::new_with_loader(create_test_loader(json), &Config::default());
+    app.history = HistoryState::empty();
+    let scroll = SessionScroll {
+        offset: 20,
+        h_offset: 0,
+        cursor_line: 25,
+    };
+    app.pending_session = Some(Session {
+        query: "$.n".to_string(),
+        language: QueryLanguage::JsonPath,
+        scroll,
+        ..Session::default()
+    });
+
+    app.poll_file_loader();
+
+    assert!(app.pending_session.is_none());
+    assert_eq!(app.query(), "$.n");
+    assert_eq!(
+        app.query.as_ref().unwrap().language,
+        QueryLanguage::JsonPath
+    );
+    assert_eq!(app.session_scroll_pending, Some(scroll));
+
+    assert!(crate::test_utils::test_helpers::wait_for_query_completion(
+        &mut app, 2000
+    ));
+    crate::app::app_render_tests::render_to_string(&mut app, 80, 24);
+    assert_eq!(app.session_scroll_pending, None);
+    assert_eq!(app.results_scroll.offset, 20);
+    assert_eq!(app.results_cursor.cursor_line(), 25);
+}
+
+#[test]
+fn test_permalink_opened_while_loading_waits_for_input() {
+    use crate::permalink::Permalink;
+    use crate::query::variables::{QueryVariable, VariableKind};
+
+    let mut app = App::new_with_loader(
+        create_test_loader(r#"{"n": 1}"#.to_string()),
+        &Config::default(),
+    );
+    app.history = HistoryState::empty();
+    app.variables
+        .set_variables(vec![QueryVariable::new("x", "1", VariableKind::Json)]);
+
+    app.open_permalink(Permalink {
+        query: ".n + $x".to_string(),
+        variables: vec![QueryVariable::new("x", "2", VariableKind::Json)],
+        ..Permalink::default()
+    });
+    assert!(app.pending_session.is_some());
+    assert_eq!(app.variables.variables()[0].value, "2");
+
+    app.poll_file_loader();
+
+    assert_eq!(app.query(), ".n + $x");
+    assert!(crate::test_utils::test_helpers::wait_for_query_completion(
+        &mut app, 2000
+    ));
+    let result = app.query.as_ref().unwrap().result.as_ref().unwrap();
+    assert_eq!(result.trim(), "3");
+}
+
+fn parse_config(toml: &str) -> Config {
+    toml::from_str(toml).unwrap()
+}
+
+#[test]
+fn test_apply_config_change_applies_changed_settings() {
+    let mut app = test_app(r#"{"a": 1}"#);
+    let previous = Config::default();
+    let current = parse_config(
+        r#"
+[editor]
+auto_pairs = true
+
+[exit]
+confirm = true
+confirm_after_secs = 5
+
+[display]
+no_color = true
+breadcrumbs = false
+"#,
+    );
+
+    app.apply_config_change(&previous, &current);
+
+    assert!(app.input.auto_pairs);
+    assert_eq!(app.confirm_exit_after, Some(Duration::from_secs(5)));
+    assert!(app.no_color);
+    assert!(!app.breadcrumbs);
+}
+
+#[test]
+fn test_apply_config_change_keeps_unchanged_settings() {
+    let mut app = test_app(r#"{"a": 1}"#);
+    app.redaction.toggle();
+    let redacting = app.redaction.enabled;
+    let previous = Config::default();
+    let current = parse_config("[display]\nno_color = true\n");
+
+    app.apply_config_change(&previous, &current);
+
+    assert_eq!(app.redaction.enabled, redacting);
+}
@@ -30,6 +30,7 @@ pub fn handle_click(app: &mut App, region: Option<Region>, mouse: MouseEvent) {
         Some(Region::ResultsPane) => click_results_pane(app, mouse),
         Some(Region::InputField) => click_input_field(app, mouse),
         Some(Region::SearchBar) => click_search_bar(app),
+        Some(Region::Breadcrumbs) => crate::input::breadcrumbs::click_breadcrumb(app, mouse.column),
         Some(Region::AiWindow) => click_ai_window(app, mouse),
         Some(Region::SnippetList) => click_snippet_list(app, mouse),
         Some(Region::HelpPopup) => click_help_popup(app, mouse),
@@ -92,6 +93,7 @@ fn click_input_field(app: &mut App, mouse: MouseEvent) {
 
     // Set cursor to the calculated position
     app.input.set_cursor_column(target_col);
+    app.update_tooltip();
 }
 
 fn click_search_bar(app: &mut App) {
@@ -140,6 +142,7 @@ fn click_ai_window(app: &mut App, mouse: MouseEvent) {
             &mut app.autocomplete,
         );
         app.ai.selection.clear_selection();
+        app.update_tooltip();
     }
 }
 
@@ -147,6 +147,22 @@ fn test_click_input_field_positions_cursor() {
     );
 }
 
+#[test]
+fn test_click_input_field_updates_tooltip() {
+    let mut app = setup_app();
+    app.focus = Focus::InputField;
+    app.input.textarea.insert_str("map(.a) | keys");
+    app.input.scroll_offset = 0;
+    app.layout_regions.input_field = Some(ratatui::layout::Rect::new(0, 0, 30, 3));
+    app.update_tooltip();
+    assert_eq!(app.tooltip.current_function, Some("keys".to_string()));
+
+    // Click inside map's parentheses
+    handle_click(&mut app, Some(Region::InputField), create_mouse_event(6, 1));
+
+    assert_eq!(app.tooltip.current_function, Some("map".to_string()));
+}
+
 #[test]
 fn test_click_input_field_with_scroll_offset() {
     let mut app = setup_app();
@@ -327,11 +343,13 @@ fn test_click_snippet_list_selects_snippet() {
             name: "test1".to_string(),
             query: ".test1".to_string(),
             description: None,
+            input: Default::default(),
         },
         crate::snippets::Snippet {
             name: "test2".to_string(),
             query: ".test2".to_string(),
             description: None,
+            input: Default::default(),
         },
     ]);
     app.layout_regions.snippet_list = Some(ratatui::layout::Rect::new(0, 0, 50, 10));
@@ -352,6 +370,7 @@ fn test_click_snippet_list_on_border_is_ignored() {
         name: "test1".to_string(),
         query: ".test1".to_string(),
         description: None,
+        input: Default::default(),
     }]);
     app.layout_regions.snippet_list = Some(ratatui::layout::Rect::new(10, 5, 30, 10));
 
@@ -385,11 +404,13 @@ fn test_click_snippet_list_in_non_browse_mode() {
             name: "test1".to_string(),
             query: ".test1".to_string(),
             description: None,
+            input: Default::default(),
         },
         crate::snippets::Snippet {
             name: "test2".to_string(),
             query: ".test2".to_string(),
             description: None,
+            input: Default::default(),
         },
     ]);
     app.snippets.enter_create_mode(".test");
@@ -410,6 +431,7 @@ fn test_click_snippet_list_when_not_visible() {
         name: "test1".to_string(),
         query: ".test1".to_string(),
         description: None,
+        input: Default::default(),
     }]);
     app.layout_regions.snippet_list = Some(ratatui::layout::Rect::new(0, 0, 50, 10));
 
@@ -132,11 +132,13 @@ fn test_hover_snippet_list_updates_hovered_index() {
             name: "test1".to_string(),
             query: ".test1".to_string(),
             description: None,
+            input: Default::default(),
         },
         crate::snippets::Snippet {
             name: "test2".to_string(),
             query: ".test2".to_string(),
             description: None,
+            input: Default::default(),
         },
     ]);
     app.layout_regions.snippet_list = Some(Rect::new(0, 0, 50, 10));
@@ -155,6 +157,7 @@ fn test_hover_snippet_list_on_border_clears_hover() {
         name: "test1".to_string(),
         query: ".test1".to_string(),
         description: None,
+        input: Default::default(),
     }]);
     app.snippets.set_hovered(Some(0));
     app.layout_regions.snippet_list = Some(Rect::new(10, 5, 30, 10));
@@ -173,6 +176,7 @@ fn test_leaving_snippet_list_clears_hover() {
         name: "test1".to_string(),
         query: ".test1".to_string(),
         description: None,
+        input: Default::default(),
     }]);
     app.snippets.set_hovered(Some(0));
 
@@ -189,6 +193,7 @@ fn test_hover_snippet_list_when_not_visible() {
         name: "test1".to_string(),
         query: ".test1".to_string(),
         description: None,
+        input: Default::default(),
     }]);
     app.layout_regions.snippet_list = Some(Rect::new(0, 0, 50, 10));
 
@@ -206,6 +211,7 @@ fn test_hover_snippet_list_no_region() {
         name: "test1".to_string(),
         query: ".test1".to_string(),
         description: None,
+        input: Default::default(),
     }]);
     app.layout_regions.snippet_list = None;
 
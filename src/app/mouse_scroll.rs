@@ -28,6 +28,7 @@ pub fn handle_scroll(app: &mut App, region: Option<Region>, direction: ScrollDir
         Some(Region::InputField) => scroll_input(app, direction),
         // Non-scrollable regions: do nothing
         Some(Region::SearchBar)
+        | Some(Region::Breadcrumbs)
         | Some(Region::Tooltip)
         | Some(Region::ErrorOverlay)
         | Some(Region::SnippetPreview) => {}
@@ -117,21 +117,25 @@ fn test_scroll_snippet_list_down() {
             name: "s1".to_string(),
             query: ".s1".to_string(),
             description: None,
+            input: Default::default(),
         },
         Snippet {
             name: "s2".to_string(),
             query: ".s2".to_string(),
             description: None,
+            input: Default::default(),
         },
         Snippet {
             name: "s3".to_string(),
             query: ".s3".to_string(),
             description: None,
+            input: Default::default(),
         },
         Snippet {
             name: "s4".to_string(),
             query: ".s4".to_string(),
             description: None,
+            input: Default::default(),
         },
     ]);
     app.snippets.open();
@@ -157,11 +161,13 @@ fn test_scroll_snippet_list_up() {
             name: "s1".to_string(),
             query: ".s1".to_string(),
             description: None,
+            input: Default::default(),
         },
         Snippet {
             name: "s2".to_string(),
             query: ".s2".to_string(),
             description: None,
+            input: Default::default(),
         },
     ]);
     app.snippets.open();
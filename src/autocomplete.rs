@@ -2,12 +2,19 @@ pub mod autocomplete_render;
 pub mod autocomplete_state;
 mod brace_tracker;
 mod context;
+mod destructuring;
+mod entry_context;
+mod field_validation;
 pub mod insertion;
 pub mod jq_functions;
 pub mod json_navigator;
+pub mod path_language;
 pub mod path_parser;
 mod result_analyzer;
 mod scan_state;
+mod string_context;
+pub mod suggestion_preview;
+pub mod suggestion_ranking;
 mod variable_extractor;
 
 #[cfg(test)]
@@ -29,12 +36,13 @@ pub use autocomplete_state::{
     AutocompleteState, JsonFieldType, MAX_VISIBLE_SUGGESTIONS, Suggestion, SuggestionType,
     update_suggestions_from_app,
 };
-#[cfg(test)]
-pub use context::{EntryContext, detect_entry_context};
 pub use context::{SuggestionContext, analyze_context, get_suggestions};
-pub use insertion::insert_suggestion_from_app;
+#[cfg(test)]
+pub use entry_context::{EntryContext, detect_entry_context};
+pub use insertion::{accept_ghost_text, ghost_text_from_app, insert_suggestion_from_app};
 
 use crate::query::ResultType;
+use crate::query::library::LibraryFunction;
 use serde_json::Value;
 use std::collections::HashSet;
 use std::sync::Arc;
@@ -51,13 +59,16 @@ pub fn update_suggestions(
     original_json: Option<Arc<Value>>,
     all_field_names: Arc<HashSet<String>>,
     brace_tracker: &BraceTracker,
+    bound_variables: &[String],
+    library_functions: &[LibraryFunction],
 ) {
     if query.trim().len() < MIN_CHARS_FOR_AUTOCOMPLETE {
         autocomplete.hide();
         return;
     }
 
-    let suggestions = get_suggestions(
+    let cached_result = result_parsed.clone();
+    let mut suggestions = get_suggestions(
         query,
         cursor_pos,
         result_parsed,
@@ -66,5 +77,52 @@ pub fn update_suggestions(
         all_field_names,
         brace_tracker,
     );
+
+    // Variables from the variables panel and functions from the user library
+    // are defined outside the query text
+    if !bound_variables.is_empty() || !library_functions.is_empty() {
+        let before_cursor = &query[..cursor_pos.min(query.len())];
+        let (context, partial) = analyze_context(before_cursor, brace_tracker);
+        let extra: Vec<Suggestion> = match context {
+            SuggestionContext::VariableContext => bound_variables
+                .iter()
+                .filter(|name| name.contains(partial.as_str()))
+                .map(|name| Suggestion::new_with_type(name.clone(), SuggestionType::Variable, None))
+                .collect(),
+            SuggestionContext::FunctionContext if !partial.is_empty() => library_functions
+                .iter()
+                .filter(|f| f.name.to_lowercase().starts_with(&partial.to_lowercase()))
+                .map(|f| {
+                    Suggestion::new(f.name.clone(), SuggestionType::Function)
+                        .with_description("Defined in lib.jq")
+                        .with_signature(f.signature.clone())
+                        .with_needs_parens(f.has_params)
+                })
+                .collect(),
+            _ => Vec::new(),
+        };
+        if !extra.is_empty() {
+            for suggestion in extra {
+                if !suggestions.iter().any(|s| s.text == suggestion.text) {
+                    suggestions.push(suggestion);
+                }
+            }
+            if context == SuggestionContext::VariableContext {
+                suggestions.sort_by(|a, b| a.text.cmp(&b.text));
+            }
+        }
+    }
+
+    // The cached result only says which fields exist at the end of the query
+    if autocomplete.validate_fields
+        && let Some(result) = &cached_result
+        && query[cursor_pos.min(query.len())..].trim().is_empty()
+    {
+        let (context, _) = analyze_context(query, brace_tracker);
+        if context == SuggestionContext::FieldContext {
+            field_validation::retain_present_fields(&mut suggestions, result);
+        }
+    }
+
     autocomplete.update_suggestions(suggestions);
 }
@@ -1,14 +1,15 @@
 use ratatui::{
     Frame,
-    layout::Rect,
+    layout::{Alignment, Rect},
     style::Style,
     text::{Line, Span},
-    widgets::{Block, BorderType, Borders, List, ListItem},
+    widgets::{Block, BorderType, Borders, List, ListItem, Padding, Paragraph},
 };
 
 use crate::app::App;
 use crate::autocomplete::SuggestionType;
 use crate::autocomplete::autocomplete_state::MAX_VISIBLE_SUGGESTIONS;
+use crate::autocomplete::suggestion_preview::PreviewOutput;
 use crate::scroll::Scrollable;
 use crate::theme;
 use crate::widgets::{popup, scrollbar};
@@ -19,8 +20,14 @@ const POPUP_PADDING: u16 = 4;
 const POPUP_OFFSET_X: u16 = 2;
 const TYPE_LABEL_SPACING: usize = 1;
 const FIELD_PREFIX_LEN: usize = 2;
+const MAX_PREVIEW_WIDTH: u16 = 48;
+const MIN_PREVIEW_WIDTH: u16 = 16;
+const MIN_PREVIEW_HEIGHT: u16 = 8;
 
 fn get_type_label(suggestion: &crate::autocomplete::Suggestion) -> String {
+    if let Some(preview) = &suggestion.preview {
+        return preview.clone();
+    }
     match &suggestion.suggestion_type {
         SuggestionType::Field => {
             if let Some(field_type) = &suggestion.field_type {
@@ -141,12 +148,22 @@ pub fn render_popup(app: &App, frame: &mut Frame, input_area: Rect) -> Option<Re
 
     popup::clear_area(frame, popup_area);
 
-    let block = Block::default()
+    let mut block = Block::default()
         .borders(Borders::ALL)
         .border_type(BorderType::Rounded)
         .title(" Suggestions ")
         .border_style(Style::default().fg(theme::autocomplete::BORDER))
         .style(Style::default().bg(theme::autocomplete::BACKGROUND));
+    let hidden = app.autocomplete.hidden_count();
+    if hidden > 0 {
+        block = block.title_bottom(
+            Line::from(Span::styled(
+                format!(" ({} more) ", hidden),
+                Style::default().fg(theme::autocomplete::MORE),
+            ))
+            .alignment(Alignment::Right),
+        );
+    }
 
     let list = List::new(items).block(block);
     frame.render_widget(list, popup_area);
@@ -174,6 +191,67 @@ pub fn render_popup(app: &App, frame: &mut Frame, input_area: Rect) -> Option<Re
     Some(popup_area)
 }
 
+/// Render the preview of the selected suggestion to the right of the popup
+///
+/// Nothing is drawn until the preview has started running, or when there
+/// isn't room beside the popup.
+pub fn render_preview(app: &App, frame: &mut Frame, popup_area: Rect) -> Option<Rect> {
+    let output = app.suggestion_preview.output()?;
+
+    let available = frame.area().right().saturating_sub(popup_area.right());
+    let width = available.min(MAX_PREVIEW_WIDTH);
+    if width < MIN_PREVIEW_WIDTH {
+        return None;
+    }
+    // Bottom-aligned with the popup, growing upwards when the popup is short
+    let height = popup_area
+        .height
+        .max(MIN_PREVIEW_HEIGHT)
+        .min(popup_area.bottom());
+    let area = Rect {
+        x: popup_area.right(),
+        y: popup_area.bottom() - height,
+        width,
+        height,
+    };
+
+    let max_lines = area.height.saturating_sub(POPUP_BORDER_HEIGHT) as usize;
+    let lines: Vec<Line> = match output {
+        PreviewOutput::Pending => vec![Line::from(Span::styled(
+            "Running…",
+            Style::default().fg(theme::autocomplete::PREVIEW_PENDING),
+        ))],
+        PreviewOutput::Done(text) if text.lines.iter().all(|line| line.width() == 0) => {
+            vec![Line::from(Span::styled(
+                "(no output)",
+                Style::default().fg(theme::autocomplete::PREVIEW_PENDING),
+            ))]
+        }
+        PreviewOutput::Done(text) => text.lines.iter().take(max_lines).cloned().collect(),
+        PreviewOutput::Failed(error) => error
+            .lines()
+            .take(max_lines)
+            .map(|line| {
+                Line::from(Span::styled(
+                    line.to_string(),
+                    Style::default().fg(theme::autocomplete::PREVIEW_ERROR),
+                ))
+            })
+            .collect(),
+    };
+
+    popup::clear_area(frame, area);
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(" Preview ")
+        .padding(Padding::horizontal(1))
+        .border_style(Style::default().fg(theme::autocomplete::BORDER))
+        .style(Style::default().bg(theme::autocomplete::BACKGROUND));
+    frame.render_widget(Paragraph::new(lines).block(block), area);
+    Some(area)
+}
+
 #[cfg(test)]
 #[path = "autocomplete_render_tests.rs"]
 mod autocomplete_render_tests;
@@ -255,3 +255,103 @@ fn snapshot_autocomplete_scrollbar_at_bottom() {
     let output = render_autocomplete_scrollbar_test(19);
     assert_snapshot!(output);
 }
+
+#[test]
+fn snapshot_autocomplete_paged_down_shows_more_footer() {
+    let json = r#"{"name": "test"}"#;
+    let mut app = test_app(json);
+    let suggestions: Vec<Suggestion> = (0..33)
+        .map(|i| Suggestion::new(format!(".field{:02}", i), SuggestionType::Field))
+        .collect();
+    app.autocomplete.update_suggestions(suggestions);
+    app.autocomplete.page_down();
+    app.autocomplete.page_down();
+
+    let mut terminal = create_test_terminal(80, 20);
+    let input_area = Rect::new(0, 15, 80, 3);
+
+    terminal
+        .draw(|f| {
+            let _ = render_popup(&app, f, input_area);
+        })
+        .unwrap();
+
+    assert_snapshot!(terminal.backend().to_string());
+}
+
+#[test]
+fn snapshot_array_index_suggestions_show_previews() {
+    let json: serde_json::Value =
+        serde_json::from_str(r#"[{"id": 1, "name": "alpha"}, 2, 3, 4, 5, {"id": 6}]"#).unwrap();
+    let suggestions =
+        crate::autocomplete::result_analyzer::ResultAnalyzer::analyze_value(&json, true, false);
+
+    let json = r#"{"name": "test"}"#;
+    let mut app = test_app(json);
+    app.autocomplete.update_suggestions(suggestions);
+
+    let mut terminal = create_test_terminal(80, 12);
+    let input_area = Rect::new(0, 9, 80, 3);
+    terminal
+        .draw(|f| {
+            let _ = render_popup(&app, f, input_area);
+        })
+        .unwrap();
+
+    assert_snapshot!(terminal.backend().to_string());
+}
+
+fn render_with_preview(output: PreviewOutput) -> String {
+    let json = r#"{"name": "test"}"#;
+    let mut app = test_app(json);
+    app.autocomplete.update_suggestions(vec![
+        Suggestion::new(".name", SuggestionType::Field),
+        Suggestion::new(".tags", SuggestionType::Field),
+    ]);
+    app.suggestion_preview.set_output(".name", output);
+
+    let mut terminal = create_test_terminal(80, 12);
+    let input_area = Rect::new(0, 9, 80, 3);
+    terminal
+        .draw(|f| {
+            if let Some(popup_area) = render_popup(&app, f, input_area) {
+                let _ = render_preview(&app, f, popup_area);
+            }
+        })
+        .unwrap();
+
+    terminal.backend().to_string()
+}
+
+#[test]
+fn snapshot_preview_beside_popup() {
+    let output = PreviewOutput::Done(ratatui::text::Text::raw("\"test\""));
+    assert_snapshot!(render_with_preview(output));
+}
+
+#[test]
+fn snapshot_preview_error() {
+    let output = PreviewOutput::Failed("jq: error: Cannot index string".to_string());
+    assert_snapshot!(render_with_preview(output));
+}
+
+#[test]
+fn test_preview_pending_shows_running() {
+    let output = render_with_preview(PreviewOutput::Pending);
+    assert!(output.contains("Running…"));
+}
+
+#[test]
+fn test_preview_hidden_without_room() {
+    let json = r#"{"name": "test"}"#;
+    let mut app = test_app(json);
+    app.suggestion_preview
+        .set_output(".name", PreviewOutput::Done(ratatui::text::Text::raw("1")));
+
+    let mut terminal = create_test_terminal(40, 12);
+    terminal
+        .draw(|f| {
+            assert!(render_preview(&app, f, Rect::new(0, 2, 30, 6)).is_none());
+        })
+        .unwrap();
+}
@@ -1,7 +1,9 @@
 use std::fmt;
 
 use crate::app::App;
-use crate::autocomplete::update_suggestions;
+use crate::autocomplete::suggestion_ranking::SuggestionRanking;
+use crate::autocomplete::{path_language, update_suggestions};
+use crate::query::language::QueryLanguage;
 use crate::scroll::Scrollable;
 
 pub const MAX_VISIBLE_SUGGESTIONS: usize = 10;
@@ -18,10 +20,26 @@ pub fn update_suggestions_from_app(app: &mut App) {
 
     let query = app.input.query().to_string();
     let cursor_pos = app.input.textarea.cursor().1; // Column position
+
+    if query_state.language != QueryLanguage::Jq {
+        let before_cursor = &query[..path_language::byte_offset(&query, cursor_pos)];
+        let suggestions = path_language::get_suggestions(
+            query_state.language,
+            before_cursor,
+            query_state.last_successful_result_parsed.as_deref(),
+            &query_state.executor.all_field_names(),
+        );
+        app.autocomplete.update_suggestions(suggestions);
+        app.autocomplete.rank(&app.suggestion_ranking);
+        return;
+    }
+
     let result_parsed = query_state.last_successful_result_parsed.clone();
     let result_type = query_state.base_type_for_suggestions.clone();
     let original_json = query_state.executor.json_input_parsed();
     let all_field_names = query_state.executor.all_field_names();
+    let bound_variables = app.variables.names();
+    let library = query_state.library();
 
     update_suggestions(
         &mut app.autocomplete,
@@ -32,7 +50,10 @@ pub fn update_suggestions_from_app(app: &mut App) {
         original_json,
         all_field_names,
         &app.input.brace_tracker,
+        &bound_variables,
+        library.functions(),
     );
+    app.autocomplete.rank(&app.suggestion_ranking);
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -89,6 +110,8 @@ pub struct Suggestion {
     pub field_type: Option<JsonFieldType>,
     pub signature: Option<String>,
     pub needs_parens: bool,
+    /// Sample of the value the suggestion selects, shown instead of the type label
+    pub preview: Option<String>,
 }
 
 impl Suggestion {
@@ -100,6 +123,7 @@ impl Suggestion {
             field_type: None,
             signature: None,
             needs_parens: false,
+            preview: None,
         }
     }
 
@@ -115,6 +139,7 @@ impl Suggestion {
             field_type,
             signature: None,
             needs_parens: false,
+            preview: None,
         }
     }
 
@@ -123,6 +148,11 @@ impl Suggestion {
         self
     }
 
+    pub fn with_preview(mut self, preview: impl Into<String>) -> Self {
+        self.preview = Some(preview.into());
+        self
+    }
+
     pub fn with_signature(mut self, sig: impl Into<String>) -> Self {
         self.signature = Some(sig.into());
         self
@@ -140,6 +170,8 @@ pub struct AutocompleteState {
     selected_index: usize,
     scroll_offset: usize,
     is_visible: bool,
+    /// Drop field suggestions missing from the cached result
+    pub validate_fields: bool,
 }
 
 impl Default for AutocompleteState {
@@ -155,6 +187,7 @@ impl AutocompleteState {
             selected_index: 0,
             scroll_offset: 0,
             is_visible: false,
+            validate_fields: false,
         }
     }
 
@@ -165,6 +198,11 @@ impl AutocompleteState {
         self.is_visible = !self.suggestions.is_empty();
     }
 
+    /// Move suggestions accepted before to the top
+    pub fn rank(&mut self, ranking: &SuggestionRanking) {
+        ranking.rank(&mut self.suggestions);
+    }
+
     pub fn hide(&mut self) {
         self.is_visible = false;
         self.suggestions.clear();
@@ -186,6 +224,22 @@ impl AutocompleteState {
         }
     }
 
+    /// Move the selection down by a page, stopping at the last suggestion
+    pub fn page_down(&mut self) {
+        if self.suggestions.is_empty() {
+            return;
+        }
+        self.selected_index =
+            (self.selected_index + MAX_VISIBLE_SUGGESTIONS).min(self.suggestions.len() - 1);
+        self.adjust_scroll_to_selection();
+    }
+
+    /// Move the selection up by a page, stopping at the first suggestion
+    pub fn page_up(&mut self) {
+        self.selected_index = self.selected_index.saturating_sub(MAX_VISIBLE_SUGGESTIONS);
+        self.adjust_scroll_to_selection();
+    }
+
     fn adjust_scroll_to_selection(&mut self) {
         if self.selected_index >= self.scroll_offset + MAX_VISIBLE_SUGGESTIONS {
             self.scroll_offset = self.selected_index - MAX_VISIBLE_SUGGESTIONS + 1;
@@ -219,6 +273,13 @@ impl AutocompleteState {
         self.scroll_offset
     }
 
+    /// Number of suggestions outside the popup's window
+    pub fn hidden_count(&self) -> usize {
+        self.suggestions
+            .len()
+            .saturating_sub(MAX_VISIBLE_SUGGESTIONS)
+    }
+
     pub fn visible_suggestions(&self) -> impl Iterator<Item = (usize, &Suggestion)> {
         self.suggestions
             .iter()
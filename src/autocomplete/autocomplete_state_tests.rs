@@ -1,7 +1,11 @@
 //! Tests for autocomplete_state
 
 use super::*;
+use crate::app::Focus;
+use crate::editor::EditorMode;
 use crate::test_utils::test_helpers::test_app;
+use crate::test_utils::test_helpers::{app_with_query, key};
+use ratatui::crossterm::event::KeyCode;
 
 #[test]
 fn test_update_suggestions_from_app_when_query_none() {
@@ -167,6 +171,65 @@ fn test_scroll_adjusts_when_selection_moves_above_visible() {
     assert_eq!(state.scroll_offset(), 4);
 }
 
+#[test]
+fn test_page_down_moves_selection_a_page_and_scrolls() {
+    let mut state = AutocompleteState::new();
+    let suggestions: Vec<Suggestion> = (0..25)
+        .map(|i| Suggestion::new(format!("item{}", i), SuggestionType::Field))
+        .collect();
+    state.update_suggestions(suggestions);
+
+    state.page_down();
+    assert_eq!(state.selected_index(), 10);
+    assert_eq!(state.scroll_offset(), 1);
+
+    state.page_down();
+    state.page_down();
+    assert_eq!(state.selected_index(), 24, "stops at the last suggestion");
+    assert_eq!(state.scroll_offset(), 15);
+}
+
+#[test]
+fn test_page_up_moves_selection_a_page_and_scrolls() {
+    let mut state = AutocompleteState::new();
+    let suggestions: Vec<Suggestion> = (0..25)
+        .map(|i| Suggestion::new(format!("item{}", i), SuggestionType::Field))
+        .collect();
+    state.update_suggestions(suggestions);
+    state.selected_index = 24;
+    state.scroll_offset = 15;
+
+    state.page_up();
+    assert_eq!(state.selected_index(), 14);
+    assert_eq!(state.scroll_offset(), 14);
+
+    state.page_up();
+    state.page_up();
+    assert_eq!(state.selected_index(), 0, "stops at the first suggestion");
+    assert_eq!(state.scroll_offset(), 0);
+}
+
+#[test]
+fn test_page_down_on_empty() {
+    let mut state = AutocompleteState::new();
+    state.page_down();
+    assert_eq!(state.selected_index(), 0);
+}
+
+#[test]
+fn test_hidden_count() {
+    let mut state = AutocompleteState::new();
+    state.update_suggestions(
+        (0..23)
+            .map(|i| Suggestion::new(format!("item{}", i), SuggestionType::Field))
+            .collect(),
+    );
+    assert_eq!(state.hidden_count(), 13);
+
+    state.update_suggestions(vec![Suggestion::new("only", SuggestionType::Field)]);
+    assert_eq!(state.hidden_count(), 0);
+}
+
 #[test]
 fn test_visible_suggestions_returns_correct_window() {
     let mut state = AutocompleteState::new();
@@ -283,3 +346,83 @@ fn test_scrollable_content_fits_in_viewport() {
     state.scroll_view_down(5);
     assert_eq!(Scrollable::scroll_offset(&state), 0); // Can't scroll when content fits
 }
+
+#[test]
+fn test_update_suggestions_from_app_includes_library_functions() {
+    use crate::query::library::JqLibrary;
+    use std::sync::Arc;
+
+    let dir = tempfile::tempdir().unwrap();
+    let lib = dir.path().join("lib.jq");
+    std::fs::write(&lib, "def normalize_tags: .;\ndef greet($name): $name;").unwrap();
+
+    let mut app = test_app(r#"{"name": "test"}"#);
+    app.query
+        .as_mut()
+        .unwrap()
+        .set_library(Arc::new(JqLibrary::load(Vec::new(), Some(&lib))));
+    app.input.textarea.insert_str(".name | gre");
+
+    update_suggestions_from_app(&mut app);
+
+    let greet = app
+        .autocomplete
+        .suggestions()
+        .iter()
+        .find(|s| s.text == "greet")
+        .expect("library function suggested");
+    assert_eq!(greet.signature.as_deref(), Some("greet($name)"));
+    assert!(greet.needs_parens);
+}
+
+fn fallback_suggestions(validate_fields: bool) -> Vec<String> {
+    let query = "map(.";
+    let mut tracker = crate::autocomplete::BraceTracker::new();
+    tracker.rebuild(query);
+    let mut state = AutocompleteState::new();
+    state.validate_fields = validate_fields;
+    let field_names: std::collections::HashSet<String> =
+        ["id".to_string(), "name".to_string()].into();
+
+    crate::autocomplete::update_suggestions(
+        &mut state,
+        query,
+        query.len(),
+        Some(std::sync::Arc::new(serde_json::json!({"id": 1}))),
+        Some(crate::query::ResultType::Object),
+        None,
+        std::sync::Arc::new(field_names),
+        &tracker,
+        &[],
+        &[],
+    );
+    let mut texts: Vec<String> = state.suggestions().iter().map(|s| s.text.clone()).collect();
+    texts.sort();
+    texts
+}
+
+#[test]
+fn test_validate_fields_drops_fields_missing_from_cached_result() {
+    assert_eq!(fallback_suggestions(false), vec![".id", ".name"]);
+    assert_eq!(fallback_suggestions(true), vec![".id"]);
+}
+
+#[test]
+fn test_page_keys_page_through_autocomplete() {
+    let mut app = app_with_query(".");
+    app.input.editor_mode = EditorMode::Insert;
+    app.focus = Focus::InputField;
+
+    let suggestions = (0..25)
+        .map(|i| Suggestion::new(format!(".field{}", i), SuggestionType::Field))
+        .collect();
+    app.autocomplete.update_suggestions(suggestions);
+
+    app.handle_key_event(key(KeyCode::PageDown));
+    assert_eq!(app.autocomplete.selected_index(), 10);
+    assert_eq!(app.autocomplete.scroll_offset(), 1);
+
+    app.handle_key_event(key(KeyCode::PageUp));
+    assert_eq!(app.autocomplete.selected_index(), 0);
+    assert_eq!(app.autocomplete.scroll_offset(), 0);
+}
@@ -27,6 +27,9 @@ mod entry_context_tests;
 #[path = "context_tests/helper_tests.rs"]
 mod helper_tests;
 
+#[path = "context_tests/key_name_context_tests.rs"]
+mod key_name_context_tests;
+
 #[path = "context_tests/variable_context_tests.rs"]
 mod variable_context_tests;
 
@@ -35,3 +38,12 @@ mod nested_navigation_tests;
 
 #[path = "context_tests/edge_case_tests.rs"]
 mod edge_case_tests;
+
+#[path = "context_tests/format_context_tests.rs"]
+mod format_context_tests;
+
+#[path = "context_tests/interpolation_context_tests.rs"]
+mod interpolation_context_tests;
+
+#[path = "context_tests/destructuring_context_tests.rs"]
+mod destructuring_context_tests;
@@ -0,0 +1,69 @@
+use super::common::{empty_field_names, tracker_for};
+use crate::autocomplete::*;
+use crate::query::ResultType;
+use serde_json::{Value, json};
+use std::sync::Arc;
+
+fn context_of(query: &str) -> (SuggestionContext, String) {
+    analyze_context(query, &tracker_for(query))
+}
+
+fn suggestions_for(query: &str, result: Value, result_type: ResultType) -> Vec<String> {
+    let tracker = tracker_for(query);
+    get_suggestions(
+        query,
+        query.len(),
+        Some(Arc::new(result)),
+        Some(result_type),
+        None,
+        empty_field_names(),
+        &tracker,
+    )
+    .into_iter()
+    .map(|s| s.text)
+    .collect()
+}
+
+#[test]
+fn test_bracket_after_as_is_destructuring_context() {
+    assert_eq!(
+        context_of(".user as {"),
+        (SuggestionContext::DestructuringContext, "{".to_string())
+    );
+    assert_eq!(
+        context_of(".pair as[ "),
+        (SuggestionContext::DestructuringContext, "[ ".to_string())
+    );
+    assert_eq!(
+        context_of("reduce .[] as {"),
+        (SuggestionContext::DestructuringContext, "{".to_string())
+    );
+}
+
+#[test]
+fn test_other_brackets_are_not_destructuring_context() {
+    for query in [".user | {", ".has {", ".alias [", ".user as {name: $n, "] {
+        let (context, _) = context_of(query);
+        assert_ne!(
+            context,
+            SuggestionContext::DestructuringContext,
+            "query: {query}"
+        );
+    }
+}
+
+#[test]
+fn test_destructuring_suggests_pattern_of_result() {
+    assert_eq!(
+        suggestions_for(
+            ".user as {",
+            json!({"age": 30, "name": "alice"}),
+            ResultType::Object
+        ),
+        vec!["{age: $age, name: $name}", "{$age, $name}"]
+    );
+    assert_eq!(
+        suggestions_for(".pair as [", json!(["a", "b"]), ResultType::Array),
+        vec!["[$first, $second]"]
+    );
+}
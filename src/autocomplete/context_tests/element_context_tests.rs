@@ -20,7 +20,10 @@ fn test_suggestions_inside_map_returns_element_fields() {
         &tracker,
     );
 
-    let field_suggestions: Vec<_> = suggestions.iter().filter(|s| s.text != ".[]").collect();
+    let field_suggestions: Vec<_> = suggestions
+        .iter()
+        .filter(|s| s.suggestion_type == SuggestionType::Field)
+        .collect();
 
     assert!(
         !field_suggestions.is_empty(),
@@ -57,7 +60,10 @@ fn test_suggestions_inside_select_returns_element_fields() {
         &tracker,
     );
 
-    let field_suggestions: Vec<_> = suggestions.iter().filter(|s| s.text != ".[]").collect();
+    let field_suggestions: Vec<_> = suggestions
+        .iter()
+        .filter(|s| s.suggestion_type == SuggestionType::Field)
+        .collect();
 
     assert!(
         !field_suggestions.is_empty(),
@@ -89,7 +95,10 @@ fn test_suggestions_outside_function_returns_array_fields() {
         &tracker,
     );
 
-    let field_suggestions: Vec<_> = suggestions.iter().filter(|s| s.text != ".[]").collect();
+    let field_suggestions: Vec<_> = suggestions
+        .iter()
+        .filter(|s| s.suggestion_type == SuggestionType::Field)
+        .collect();
 
     assert!(
         !field_suggestions.is_empty(),
@@ -121,7 +130,10 @@ fn test_suggestions_inside_nested_element_functions() {
         &tracker,
     );
 
-    let field_suggestions: Vec<_> = suggestions.iter().filter(|s| s.text != ".[]").collect();
+    let field_suggestions: Vec<_> = suggestions
+        .iter()
+        .filter(|s| s.suggestion_type == SuggestionType::Field)
+        .collect();
 
     for suggestion in field_suggestions {
         assert!(
@@ -148,7 +160,10 @@ fn test_suggestions_inside_map_with_object_construction() {
         &tracker,
     );
 
-    let field_suggestions: Vec<_> = suggestions.iter().filter(|s| s.text != ".[]").collect();
+    let field_suggestions: Vec<_> = suggestions
+        .iter()
+        .filter(|s| s.suggestion_type == SuggestionType::Field)
+        .collect();
 
     for suggestion in field_suggestions {
         assert!(
@@ -202,7 +217,10 @@ fn test_suggestions_after_pipe_in_element_context() {
         &tracker,
     );
 
-    let field_suggestions: Vec<_> = suggestions.iter().filter(|s| s.text != ".[]").collect();
+    let field_suggestions: Vec<_> = suggestions
+        .iter()
+        .filter(|s| s.suggestion_type == SuggestionType::Field)
+        .collect();
 
     for suggestion in field_suggestions {
         assert!(
@@ -242,7 +260,10 @@ fn test_suggestions_all_element_functions() {
             &tracker,
         );
 
-        let field_suggestions: Vec<_> = suggestions.iter().filter(|s| s.text != ".[]").collect();
+        let field_suggestions: Vec<_> = suggestions
+            .iter()
+            .filter(|s| s.suggestion_type == SuggestionType::Field)
+            .collect();
 
         for suggestion in &field_suggestions {
             assert!(
@@ -274,7 +295,10 @@ fn test_suggestions_non_element_functions_have_brackets() {
             &tracker,
         );
 
-        let field_suggestions: Vec<_> = suggestions.iter().filter(|s| s.text != ".[]").collect();
+        let field_suggestions: Vec<_> = suggestions
+            .iter()
+            .filter(|s| s.suggestion_type == SuggestionType::Field)
+            .collect();
 
         for suggestion in &field_suggestions {
             assert!(
@@ -0,0 +1,76 @@
+use super::common::{empty_field_names, tracker_for};
+use crate::autocomplete::*;
+
+fn context_of(query: &str) -> (SuggestionContext, String) {
+    analyze_context(query, &tracker_for(query))
+}
+
+fn suggestions_for(query: &str) -> Vec<String> {
+    let tracker = tracker_for(query);
+    get_suggestions(
+        query,
+        query.len(),
+        None,
+        None,
+        None,
+        empty_field_names(),
+        &tracker,
+    )
+    .into_iter()
+    .map(|s| s.text)
+    .collect()
+}
+
+#[test]
+fn test_at_sign_is_format_context() {
+    assert_eq!(
+        context_of(".items[] | @"),
+        (SuggestionContext::FormatContext, "@".to_string())
+    );
+    assert_eq!(
+        context_of(".row|@cs"),
+        (SuggestionContext::FormatContext, "@cs".to_string())
+    );
+}
+
+#[test]
+fn test_at_sign_inside_string_is_not_format_context() {
+    let (context, _) = context_of(r#""mail me @"#);
+    assert_ne!(context, SuggestionContext::FormatContext);
+    assert!(suggestions_for(r#""mail me @"#).is_empty());
+}
+
+#[test]
+fn test_bare_at_sign_suggests_every_format() {
+    let suggestions = suggestions_for(". | @");
+    for format in ["@csv", "@tsv", "@json", "@base64", "@uri", "@sh"] {
+        assert!(
+            suggestions.contains(&format.to_string()),
+            "missing {format}"
+        );
+    }
+}
+
+#[test]
+fn test_format_partial_filters_by_prefix() {
+    assert_eq!(
+        suggestions_for(". | @base6"),
+        vec!["@base64".to_string(), "@base64d".to_string()]
+    );
+    assert_eq!(suggestions_for(". | @s"), vec!["@sh".to_string()]);
+}
+
+#[test]
+fn test_formats_have_descriptions() {
+    let query = ". | @";
+    let suggestions = get_suggestions(
+        query,
+        query.len(),
+        None,
+        None,
+        None,
+        empty_field_names(),
+        &tracker_for(query),
+    );
+    assert!(suggestions.iter().all(|s| s.description.is_some()));
+}
@@ -0,0 +1,86 @@
+use super::common::{empty_field_names, tracker_for};
+use crate::autocomplete::*;
+use crate::query::ResultType;
+use serde_json::{Value, json};
+use std::sync::Arc;
+
+fn context_of(query: &str) -> (SuggestionContext, String) {
+    analyze_context(query, &tracker_for(query))
+}
+
+fn suggestions_for(query: &str, result: Value, result_type: ResultType) -> Vec<String> {
+    let tracker = tracker_for(query);
+    get_suggestions(
+        query,
+        query.len(),
+        Some(Arc::new(result)),
+        Some(result_type),
+        None,
+        empty_field_names(),
+        &tracker,
+    )
+    .into_iter()
+    .map(|s| s.text)
+    .collect()
+}
+
+#[test]
+fn test_backslash_in_string_is_interpolation_context() {
+    assert_eq!(
+        context_of(r#".[] | "Hello \"#),
+        (SuggestionContext::InterpolationContext, String::new())
+    );
+    assert_eq!(
+        context_of(r#""Hello \("#),
+        (SuggestionContext::InterpolationContext, "(".to_string())
+    );
+    assert_eq!(
+        context_of(r#""Hello \(.na"#),
+        (SuggestionContext::InterpolationContext, "(.na".to_string())
+    );
+}
+
+#[test]
+fn test_other_escapes_are_not_interpolation_context() {
+    for query in [
+        r#""a\n"#,
+        r#""a\\"#,
+        r#""a\(.name) and "#,
+        r#""a\(.name | ascii"#,
+        r#"\"#,
+    ] {
+        let (context, _) = context_of(query);
+        assert_ne!(
+            context,
+            SuggestionContext::InterpolationContext,
+            "query: {query}"
+        );
+    }
+}
+
+#[test]
+fn test_interpolation_suggests_fields_of_result() {
+    let result = json!({"name": "alice", "age": 30});
+
+    let suggestions = suggestions_for(r#""Hi \"#, result.clone(), ResultType::Object);
+    assert!(suggestions.contains(&r"\(.name)".to_string()));
+    assert!(suggestions.contains(&r"\(.age)".to_string()));
+
+    let suggestions = suggestions_for(r#""Hi \(.na"#, result, ResultType::Object);
+    assert_eq!(suggestions, vec![r"\(.name)".to_string()]);
+}
+
+#[test]
+fn test_interpolation_without_result_suggests_nothing() {
+    let query = r#""Hi \("#;
+    let suggestions = get_suggestions(
+        query,
+        query.len(),
+        None,
+        None,
+        None,
+        empty_field_names(),
+        &tracker_for(query),
+    );
+    assert!(suggestions.is_empty());
+}
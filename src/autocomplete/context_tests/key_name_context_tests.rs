@@ -0,0 +1,164 @@
+use super::common::{empty_field_names, field_names_from, tracker_for};
+use crate::autocomplete::*;
+use crate::query::ResultType;
+use serde_json::{Value, json};
+use std::sync::Arc;
+
+fn context_of(query: &str) -> (SuggestionContext, String) {
+    analyze_context(query, &tracker_for(query))
+}
+
+fn suggestions_for(query: &str, result: Value, result_type: ResultType) -> Vec<String> {
+    let tracker = tracker_for(query);
+    get_suggestions(
+        query,
+        query.len(),
+        Some(Arc::new(result)),
+        Some(result_type),
+        None,
+        empty_field_names(),
+        &tracker,
+    )
+    .into_iter()
+    .map(|s| s.text)
+    .collect()
+}
+
+#[test]
+fn test_has_string_is_key_name_context() {
+    assert_eq!(
+        context_of(r#"has("na"#),
+        (SuggestionContext::KeyNameContext, "na".to_string())
+    );
+    assert_eq!(
+        context_of(r#".user | has( ""#),
+        (SuggestionContext::KeyNameContext, String::new())
+    );
+}
+
+#[test]
+fn test_bracket_string_is_key_name_context() {
+    assert_eq!(
+        context_of(r#".["na"#),
+        (SuggestionContext::KeyNameContext, "na".to_string())
+    );
+    assert_eq!(
+        context_of(r#"del(.user["na"#),
+        (SuggestionContext::KeyNameContext, "na".to_string())
+    );
+    assert_eq!(
+        context_of(r#".items[0]["na"#),
+        (SuggestionContext::KeyNameContext, "na".to_string())
+    );
+    assert_eq!(
+        context_of(r#"getpath(["na"#),
+        (SuggestionContext::KeyNameContext, "na".to_string())
+    );
+}
+
+#[test]
+fn test_entry_key_comparison_is_key_name_context() {
+    assert_eq!(
+        context_of(r#"with_entries(select(.key == "na"#),
+        (SuggestionContext::KeyNameContext, "na".to_string())
+    );
+    assert_eq!(
+        context_of(r#"to_entries | map(select(.key != ""#),
+        (SuggestionContext::KeyNameContext, String::new())
+    );
+}
+
+#[test]
+fn test_other_strings_are_not_key_name_context() {
+    for query in [
+        r#"["na"#,
+        r#"{"na"#,
+        r#"select(.name == "al"#,
+        r#"test("na"#,
+        r#"thas("na"#,
+        r#"has("a\(."#,
+        r#"has("name") | .na"#,
+    ] {
+        assert_ne!(
+            context_of(query).0,
+            SuggestionContext::KeyNameContext,
+            "{query}"
+        );
+    }
+}
+
+#[test]
+fn test_has_suggests_keys_of_object() {
+    let result = json!({"name": "alice", "age": 30, "first name": "a"});
+    let suggestions = suggestions_for(r#"has("na"#, result, ResultType::Object);
+
+    assert_eq!(suggestions.len(), 2);
+    assert!(suggestions.contains(&"name".to_string()));
+    assert!(suggestions.contains(&"first name".to_string()));
+}
+
+#[test]
+fn test_has_inside_select_suggests_element_keys() {
+    let result = json!([{"id": 1, "name": "alice"}, {"id": 2}]);
+    let suggestions = suggestions_for(r#"map(select(has(""#, result, ResultType::ArrayOfObjects);
+
+    assert_eq!(suggestions.len(), 2);
+    assert!(suggestions.contains(&"id".to_string()));
+    assert!(suggestions.contains(&"name".to_string()));
+}
+
+#[test]
+fn test_entry_key_comparison_suggests_keys() {
+    let result = json!({"name": "alice", "age": 30});
+    let suggestions = suggestions_for(
+        r#"with_entries(select(.key == "a"#,
+        result,
+        ResultType::Object,
+    );
+
+    assert_eq!(suggestions, vec!["name".to_string(), "age".to_string()]);
+}
+
+#[test]
+fn test_entry_key_comparison_after_to_entries_suggests_entry_keys() {
+    let result = json!([
+        {"key": "name", "value": "alice"},
+        {"key": "age", "value": 30}
+    ]);
+    let suggestions = suggestions_for(
+        r#"to_entries | map(select(.key == ""#,
+        result,
+        ResultType::ArrayOfObjects,
+    );
+
+    assert_eq!(suggestions, vec!["name".to_string(), "age".to_string()]);
+}
+
+#[test]
+fn test_key_name_falls_back_to_all_fields_for_scalar_result() {
+    let original = json!({"user": {"name": "alice"}});
+    let query = r#".user | has("na"#;
+    let tracker = tracker_for(query);
+
+    let suggestions = get_suggestions(
+        query,
+        query.len(),
+        Some(Arc::new(json!(false))),
+        Some(ResultType::Boolean),
+        None,
+        field_names_from(&original),
+        &tracker,
+    );
+
+    assert_eq!(suggestions.len(), 1);
+    assert_eq!(suggestions[0].text, "name");
+}
+
+#[test]
+fn test_del_and_pick_suggest_fields() {
+    let result = json!({"name": "alice", "age": 30});
+    for query in ["del(.na", "pick(.na"] {
+        let suggestions = suggestions_for(query, result.clone(), ResultType::Object);
+        assert_eq!(suggestions, vec![".name".to_string()], "{query}");
+    }
+}
@@ -309,7 +309,7 @@ proptest! {
 
         let field_suggestions: Vec<_> = suggestions
             .iter()
-            .filter(|s| s.text != ".[]" && s.text.len() > 1)
+            .filter(|s| s.suggestion_type == SuggestionType::Field)
             .collect();
 
         for suggestion in &field_suggestions {
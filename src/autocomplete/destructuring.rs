@@ -0,0 +1,161 @@
+//! Destructuring patterns for `as [` and `as {` bindings
+//!
+//! Right after `as {` the fields of the value being bound are offered as a
+//! ready-made pattern such as `{name: $name, age: $age}`, and after `as [`
+//! its first elements as `[$first, $second]`. The value is the last
+//! successful result, which is what the expression before `as` produced
+//! while the pattern was being typed.
+
+use serde_json::{Map, Value};
+
+use super::autocomplete_state::{Suggestion, SuggestionType};
+use super::context::SuggestionContext;
+use super::string_context::ends_with_word;
+use crate::query::language::is_jq_identifier;
+
+/// Most fields bound by a generated object pattern
+const MAX_FIELDS: usize = 10;
+
+const POSITIONS: [&str; 4] = ["first", "second", "third", "fourth"];
+
+/// Determines context from a destructuring pattern just opened after `as`.
+/// Returns DestructuringContext with the bracket and any spaces after it.
+///
+/// # Examples
+/// - `.user as {` → "{"
+/// - `.pair as [ ` → "[ "
+pub(super) fn context_from_destructuring(
+    before_cursor: &str,
+) -> Option<(SuggestionContext, String)> {
+    let trimmed = before_cursor.trim_end();
+    let open_pos = trimmed.len().checked_sub(1)?;
+    if !trimmed.ends_with(['[', '{']) {
+        return None;
+    }
+    if !ends_with_word(trimmed[..open_pos].trim_end(), "as") {
+        return None;
+    }
+    let partial = &before_cursor[open_pos..];
+    Some((SuggestionContext::DestructuringContext, partial.to_string()))
+}
+
+/// Patterns for the destructuring `partial` opens, over the last successful
+/// result
+pub(super) fn destructuring_suggestions(result: Option<&Value>, partial: &str) -> Vec<Suggestion> {
+    let open = partial.chars().next().unwrap_or('{');
+    result
+        .map(|value| destructuring_patterns(value, open))
+        .unwrap_or_default()
+}
+
+/// Patterns for a destructuring opened with `open` (`{` or `[`) over `value`
+pub fn destructuring_patterns(value: &Value, open: char) -> Vec<Suggestion> {
+    match (open, value) {
+        ('{', Value::Object(map)) => object_patterns(map),
+        // A stream of objects is cached as an array of them
+        ('{', Value::Array(items)) => match items.first() {
+            Some(Value::Object(map)) => object_patterns(map),
+            _ => Vec::new(),
+        },
+        ('[', Value::Array(items)) => array_patterns(items),
+        _ => Vec::new(),
+    }
+}
+
+fn object_patterns(map: &Map<String, Value>) -> Vec<Suggestion> {
+    if map.is_empty() {
+        return Vec::new();
+    }
+    let mut suggestions = vec![
+        Suggestion::new(object_pattern(map), SuggestionType::Pattern)
+            .with_description("Bind each field to a variable"),
+    ];
+    if map.keys().take(MAX_FIELDS).all(|key| is_jq_identifier(key)) {
+        let names: Vec<String> = map
+            .keys()
+            .take(MAX_FIELDS)
+            .map(|key| format!("${}", key))
+            .collect();
+        suggestions.push(
+            Suggestion::new(format!("{{{}}}", names.join(", ")), SuggestionType::Pattern)
+                .with_description("Bind each field to a variable of the same name"),
+        );
+    }
+    suggestions
+}
+
+/// `{name: $name, "first name": $first_name}` for the first fields of `map`
+fn object_pattern(map: &Map<String, Value>) -> String {
+    let mut used: Vec<String> = Vec::new();
+    let bindings: Vec<String> = map
+        .keys()
+        .take(MAX_FIELDS)
+        .map(|key| {
+            let name = unique_name(variable_name(key), &used);
+            used.push(name.clone());
+            let key = if is_jq_identifier(key) {
+                key.clone()
+            } else {
+                Value::String(key.clone()).to_string()
+            };
+            format!("{}: ${}", key, name)
+        })
+        .collect();
+    format!("{{{}}}", bindings.join(", "))
+}
+
+fn array_patterns(items: &[Value]) -> Vec<Suggestion> {
+    if items.is_empty() {
+        return Vec::new();
+    }
+    let names: Vec<String> = POSITIONS
+        .iter()
+        .take(items.len())
+        .map(|name| format!("${}", name))
+        .collect();
+    let mut suggestions = vec![
+        Suggestion::new(format!("[{}]", names.join(", ")), SuggestionType::Pattern)
+            .with_description("Bind the first elements to variables"),
+    ];
+    if let Some(Value::Object(map)) = items.first()
+        && !map.is_empty()
+    {
+        suggestions.push(
+            Suggestion::new(
+                format!("[{}]", object_pattern(map)),
+                SuggestionType::Pattern,
+            )
+            .with_description("Bind the fields of the first element"),
+        );
+    }
+    suggestions
+}
+
+/// A variable name for `key`: other characters become `_`, and a leading
+/// digit or a jq keyword gets a `_` in front
+fn variable_name(key: &str) -> String {
+    let name: String = key
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    if !is_jq_identifier(&name) {
+        format!("_{}", name)
+    } else {
+        name
+    }
+}
+
+/// `name`, or `name_2`, `name_3`, … when it is already taken
+fn unique_name(name: String, used: &[String]) -> String {
+    if !used.contains(&name) {
+        return name;
+    }
+    (2..)
+        .map(|n| format!("{}_{}", name, n))
+        .find(|candidate| !used.contains(candidate))
+        .expect("some numbered name is free")
+}
+
+#[cfg(test)]
+#[path = "destructuring_tests.rs"]
+mod destructuring_tests;
@@ -0,0 +1,85 @@
+//! Tests for destructuring patterns
+
+use super::*;
+use serde_json::json;
+
+fn texts(value: Value, open: char) -> Vec<String> {
+    destructuring_patterns(&value, open)
+        .into_iter()
+        .map(|s| s.text)
+        .collect()
+}
+
+#[test]
+fn test_object_pattern_binds_each_field() {
+    assert_eq!(
+        texts(json!({"age": 30, "name": "alice"}), '{'),
+        vec!["{age: $age, name: $name}", "{$age, $name}"]
+    );
+}
+
+#[test]
+fn test_object_pattern_quotes_other_keys() {
+    assert_eq!(
+        texts(json!({"first name": "a", "2fa": true}), '{'),
+        vec![r#"{"first name": $first_name, "2fa": $_2fa}"#]
+    );
+}
+
+#[test]
+fn test_object_pattern_quotes_keywords() {
+    assert_eq!(
+        texts(json!({"if": 1, "__loc__": 2}), '{'),
+        vec![r#"{"if": $_if, "__loc__": $___loc__}"#]
+    );
+}
+
+#[test]
+fn test_object_pattern_keeps_variable_names_unique() {
+    assert_eq!(
+        texts(json!({"a-b": 1, "a_b": 2}), '{'),
+        vec![r#"{"a-b": $a_b, a_b: $a_b_2}"#]
+    );
+}
+
+#[test]
+fn test_object_pattern_from_stream_of_objects() {
+    assert_eq!(
+        texts(json!([{"id": 1}, {"id": 2}]), '{'),
+        vec!["{id: $id}", "{$id}"]
+    );
+}
+
+#[test]
+fn test_object_pattern_limits_fields() {
+    let wide: serde_json::Map<String, Value> =
+        (0..20).map(|i| (format!("f{:02}", i), json!(i))).collect();
+    let pattern = &texts(Value::Object(wide), '{')[0];
+    assert_eq!(pattern.matches('$').count(), MAX_FIELDS);
+}
+
+#[test]
+fn test_array_pattern_binds_first_elements() {
+    assert_eq!(texts(json!([1, 2]), '['), vec!["[$first, $second]"]);
+    assert_eq!(
+        texts(json!([1, 2, 3, 4, 5, 6]), '['),
+        vec!["[$first, $second, $third, $fourth]"]
+    );
+}
+
+#[test]
+fn test_array_pattern_of_objects_offers_first_element_fields() {
+    assert_eq!(
+        texts(json!([{"name": "a"}, {"name": "b"}]), '['),
+        vec!["[$first, $second]", "[{name: $name}]"]
+    );
+}
+
+#[test]
+fn test_mismatched_shapes_have_no_pattern() {
+    assert!(texts(json!([1, 2]), '{').is_empty());
+    assert!(texts(json!({"a": 1}), '[').is_empty());
+    assert!(texts(json!("text"), '{').is_empty());
+    assert!(texts(json!([]), '[').is_empty());
+    assert!(texts(json!({}), '{').is_empty());
+}
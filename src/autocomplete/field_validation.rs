@@ -0,0 +1,70 @@
+//! Field suggestions checked against the cached result
+//!
+//! Field suggestions partly come from heuristics: the field names of the
+//! whole input, or the input navigated by the path typed so far. After a
+//! failed query or a change of result type they can name fields the result
+//! no longer has. With `validate_fields = true` under `[autocomplete]`, a
+//! field suggestion is only listed when its name is a key somewhere in the
+//! cached result. This walks the whole result, so it is off by default.
+
+use std::collections::HashSet;
+
+use serde_json::Value;
+
+use super::autocomplete_state::{Suggestion, SuggestionType};
+use super::path_parser::{PathSegment, parse_path};
+
+/// Drop field suggestions whose field is not a key anywhere in `result`
+pub fn retain_present_fields(suggestions: &mut Vec<Suggestion>, result: &Value) {
+    if !suggestions
+        .iter()
+        .any(|s| s.suggestion_type == SuggestionType::Field)
+    {
+        return;
+    }
+    let mut keys = HashSet::new();
+    collect_keys(result, &mut keys);
+    suggestions.retain(|suggestion| {
+        suggestion.suggestion_type != SuggestionType::Field
+            || field_name(&suggestion.text).is_none_or(|name| keys.contains(name.as_str()))
+    });
+}
+
+fn collect_keys<'a>(value: &'a Value, keys: &mut HashSet<&'a str>) {
+    match value {
+        Value::Object(map) => {
+            for (key, child) in map {
+                keys.insert(key);
+                collect_keys(child, keys);
+            }
+        }
+        Value::Array(items) => {
+            for item in items {
+                collect_keys(item, keys);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// The field a suggestion such as `.name`, `.[].name` or `name` accesses;
+/// None for suggestions that access no field, such as `.[]`
+fn field_name(text: &str) -> Option<String> {
+    let path = if text.starts_with('.') {
+        text.to_string()
+    } else {
+        format!(".{}", text)
+    };
+    let parsed = parse_path(&path);
+    if !parsed.partial.is_empty() {
+        return Some(parsed.partial);
+    }
+    match parsed.segments.last()? {
+        PathSegment::Field(name) | PathSegment::OptionalField(name) => Some(name.clone()),
+        PathSegment::ArrayIterator | PathSegment::ArrayIndex(_) => None,
+    }
+}
+
+#[cfg(test)]
+#[path = "field_validation_tests.rs"]
+mod field_validation_tests;
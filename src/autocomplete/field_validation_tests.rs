@@ -0,0 +1,56 @@
+//! Tests for field_validation
+
+use super::*;
+use serde_json::json;
+
+fn field(text: &str) -> Suggestion {
+    Suggestion::new(text, SuggestionType::Field)
+}
+
+fn texts(suggestions: &[Suggestion]) -> Vec<&str> {
+    suggestions.iter().map(|s| s.text.as_str()).collect()
+}
+
+#[test]
+fn test_field_name() {
+    assert_eq!(field_name(".name"), Some("name".to_string()));
+    assert_eq!(field_name("name"), Some("name".to_string()));
+    assert_eq!(field_name(".[].name"), Some("name".to_string()));
+    assert_eq!(field_name(".user.name?"), Some("name".to_string()));
+    assert_eq!(field_name(".[]"), None);
+    assert_eq!(field_name("[0]"), None);
+}
+
+#[test]
+fn test_missing_fields_are_dropped() {
+    let mut suggestions = vec![field(".id"), field(".name"), field(".email")];
+    retain_present_fields(&mut suggestions, &json!([{"id": 1, "name": "a"}]));
+    assert_eq!(texts(&suggestions), vec![".id", ".name"]);
+}
+
+#[test]
+fn test_nested_fields_are_kept() {
+    let mut suggestions = vec![field(".city"), field(".zip")];
+    retain_present_fields(
+        &mut suggestions,
+        &json!({"user": {"address": {"city": "Oslo"}}}),
+    );
+    assert_eq!(texts(&suggestions), vec![".city"]);
+}
+
+#[test]
+fn test_scalar_result_drops_every_field() {
+    let mut suggestions = vec![field(".id"), field(".[]")];
+    retain_present_fields(&mut suggestions, &json!(42));
+    assert_eq!(texts(&suggestions), vec![".[]"]);
+}
+
+#[test]
+fn test_other_suggestions_are_kept() {
+    let mut suggestions = vec![
+        Suggestion::new("map", SuggestionType::Function),
+        Suggestion::new(".[]", SuggestionType::Pattern),
+    ];
+    retain_present_fields(&mut suggestions, &json!(42));
+    assert_eq!(suggestions.len(), 2);
+}
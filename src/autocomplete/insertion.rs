@@ -5,10 +5,12 @@
 
 use tui_textarea::TextArea;
 
-use crate::app::App;
+use crate::app::{App, Focus};
 use crate::autocomplete::autocomplete_state::Suggestion;
-use crate::autocomplete::{SuggestionContext, analyze_context};
+use crate::autocomplete::{SuggestionContext, analyze_context, path_language};
+use crate::editor::EditorMode;
 use crate::query::QueryState;
+use crate::query::language::QueryLanguage;
 
 // Re-export sub-module functions
 pub use self::cursor::move_cursor_to_column;
@@ -51,6 +53,7 @@ pub fn insert_suggestion_from_app(app: &mut App, suggestion: &Suggestion) {
     };
 
     insert_suggestion(&mut app.input.textarea, query_state, suggestion);
+    app.suggestion_ranking.record(&suggestion.text);
 
     app.autocomplete.hide();
     app.results_scroll.reset();
@@ -62,11 +65,44 @@ pub fn insert_suggestion_from_app(app: &mut App, suggestion: &Suggestion) {
     query_state.execute_async(query);
 }
 
+/// Ghost text of the selected suggestion (the top-ranked one until the
+/// selection moves) while typing at the end of the query
+pub fn ghost_text_from_app(app: &App) -> Option<String> {
+    if app.focus != Focus::InputField || app.input.editor_mode != EditorMode::Insert {
+        return None;
+    }
+    let query_state = app.query.as_ref()?;
+    let suggestion = app.autocomplete.selected()?;
+    ghost_text(&app.input.textarea, query_state, suggestion)
+}
+
+/// Accept the suggestion shown as ghost text; returns false if there is none
+pub fn accept_ghost_text(app: &mut App) -> bool {
+    if ghost_text_from_app(app).is_none() {
+        return false;
+    }
+    let Some(suggestion) = app.autocomplete.selected().cloned() else {
+        return false;
+    };
+    insert_suggestion_from_app(app, &suggestion);
+    app.debouncer.mark_executed();
+    app.update_tooltip();
+    true
+}
+
+/// Whether `suggestion` iterates, indexes or slices an array: `[]`, `[0]`, `[-1]`, `[1:]`
+fn is_array_access(suggestion: &str) -> bool {
+    suggestion
+        .strip_prefix('[')
+        .and_then(|rest| rest.chars().next())
+        .is_some_and(|c| c == ']' || c == '-' || c == ':' || c.is_ascii_digit())
+}
+
 /// Check if trailing separator should be replaced to avoid duplicates
 fn should_replace_trailing_separator(char_before: Option<char>, suggestion: &str) -> bool {
     matches!(
         (char_before, suggestion),
-        (Some('.'), s) if s.starts_with('.') || s.starts_with("[]") || s.starts_with("{}")
+        (Some('.'), s) if s.starts_with('.') || is_array_access(s) || s.starts_with("{}")
     ) || matches!(
         (char_before, suggestion.chars().next()),
         (Some('['), Some('[')) | (Some('{'), Some('{'))
@@ -145,6 +181,68 @@ fn insert_variable_suggestion(
     );
 }
 
+/// Insert key name suggestion inside a string (e.g., `has("name"`)
+///
+/// Closes the string unless a closing quote already follows the cursor.
+fn insert_key_name_suggestion(
+    textarea: &mut TextArea<'_>,
+    query: &str,
+    cursor_pos: usize,
+    partial: &str,
+    suggestion: &Suggestion,
+) {
+    let replacement_start = cursor_pos.saturating_sub(partial.len());
+    let insert_text = if query[cursor_pos..].starts_with('"') {
+        suggestion.text.to_string()
+    } else {
+        format!("{}\"", suggestion.text)
+    };
+
+    replace_partial_at_cursor(textarea, query, cursor_pos, replacement_start, &insert_text);
+}
+
+/// Insert interpolation suggestion inside a string (e.g., `\(.name)`)
+///
+/// Replaces the backslash and what follows it, without doubling a closing
+/// paren that already follows the cursor.
+fn insert_interpolation_suggestion(
+    textarea: &mut TextArea<'_>,
+    query: &str,
+    cursor_pos: usize,
+    partial: &str,
+    suggestion: &Suggestion,
+) {
+    let replacement_start = cursor_pos.saturating_sub(partial.len() + 1);
+    let insert_text = if query[cursor_pos..].starts_with(')') {
+        suggestion.text.trim_end_matches(')')
+    } else {
+        suggestion.text.as_str()
+    };
+    replace_partial_at_cursor(textarea, query, cursor_pos, replacement_start, insert_text);
+}
+
+/// Insert destructuring pattern suggestion (e.g., `{name: $name}`)
+///
+/// Replaces the opening bracket already typed, without doubling a closing
+/// bracket that already follows the cursor.
+fn insert_destructuring_suggestion(
+    textarea: &mut TextArea<'_>,
+    query: &str,
+    cursor_pos: usize,
+    partial: &str,
+    suggestion: &Suggestion,
+) {
+    let replacement_start = cursor_pos.saturating_sub(partial.len());
+    let text = suggestion.text.as_str();
+    let insert_text = match text.chars().next_back() {
+        Some(close) if query[cursor_pos..].trim_start().starts_with(close) => {
+            &text[..text.len() - 1]
+        }
+        _ => text,
+    };
+    replace_partial_at_cursor(textarea, query, cursor_pos, replacement_start, insert_text);
+}
+
 /// Insert field suggestion (e.g., ".name", "[].price", "{}.key")
 fn insert_field_suggestion(
     textarea: &mut TextArea<'_>,
@@ -168,7 +266,7 @@ fn insert_field_suggestion(
         } else {
             cursor_pos
         }
-    } else if suggestion_text.starts_with("[]") || suggestion_text.starts_with("{}") {
+    } else if is_array_access(suggestion_text) || suggestion_text.starts_with("{}") {
         calculate_iteration_syntax_start(cursor_pos, partial.len(), before_cursor, base_query)
     } else if suggestion_text.starts_with('[')
         || suggestion_text.starts_with('{')
@@ -194,6 +292,47 @@ pub fn insert_suggestion(
     query_state: &mut QueryState,
     suggestion: &Suggestion,
 ) {
+    insert_into(textarea, query_state, suggestion);
+}
+
+/// Text accepting `suggestion` would add after the cursor
+///
+/// Only given when the cursor is at the end of the query and accepting the
+/// suggestion just appends to it, so it can be shown as ghost text.
+pub fn ghost_text(
+    textarea: &TextArea<'_>,
+    query_state: &QueryState,
+    suggestion: &Suggestion,
+) -> Option<String> {
+    let query = textarea.lines()[0].as_str();
+    if textarea.cursor().1 != query.chars().count() {
+        return None;
+    }
+
+    let completed = completed_query(textarea, query_state, suggestion);
+    match completed.strip_prefix(query) {
+        Some(ghost) if !ghost.is_empty() => Some(ghost.to_string()),
+        _ => None,
+    }
+}
+
+/// The query as it would read after accepting `suggestion`
+pub fn completed_query(
+    textarea: &TextArea<'_>,
+    query_state: &QueryState,
+    suggestion: &Suggestion,
+) -> String {
+    let mut completed = textarea.clone();
+    insert_into(&mut completed, query_state, suggestion);
+    completed.lines()[0].clone()
+}
+
+fn insert_into(textarea: &mut TextArea<'_>, query_state: &QueryState, suggestion: &Suggestion) {
+    if query_state.language != QueryLanguage::Jq {
+        path_language::insert_suggestion(textarea, query_state.language, suggestion);
+        return;
+    }
+
     let query = textarea.lines()[0].clone();
     let cursor_pos = textarea.cursor().1;
     let before_cursor = &query[..cursor_pos.min(query.len())];
@@ -225,5 +364,17 @@ pub fn insert_suggestion(
         SuggestionContext::VariableContext => {
             insert_variable_suggestion(textarea, &query, cursor_pos, &partial, suggestion);
         }
+        SuggestionContext::KeyNameContext => {
+            insert_key_name_suggestion(textarea, &query, cursor_pos, &partial, suggestion);
+        }
+        SuggestionContext::FormatContext => {
+            insert_function_suggestion(textarea, &query, cursor_pos, &partial, suggestion);
+        }
+        SuggestionContext::InterpolationContext => {
+            insert_interpolation_suggestion(textarea, &query, cursor_pos, &partial, suggestion);
+        }
+        SuggestionContext::DestructuringContext => {
+            insert_destructuring_suggestion(textarea, &query, cursor_pos, &partial, suggestion);
+        }
     }
 }
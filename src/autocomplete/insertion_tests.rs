@@ -3,12 +3,22 @@
 // Test submodules
 #[path = "insertion_tests/cursor_positioning_tests.rs"]
 mod cursor_positioning_tests;
+#[path = "insertion_tests/destructuring_insertion_tests.rs"]
+mod destructuring_insertion_tests;
 #[path = "insertion_tests/edge_case_tests.rs"]
 mod edge_case_tests;
 #[path = "insertion_tests/field_context_tests.rs"]
 mod field_context_tests;
+#[path = "insertion_tests/format_insertion_tests.rs"]
+mod format_insertion_tests;
 #[path = "insertion_tests/function_context_tests.rs"]
 mod function_context_tests;
+#[path = "insertion_tests/ghost_text_tests.rs"]
+mod ghost_text_tests;
+#[path = "insertion_tests/interpolation_insertion_tests.rs"]
+mod interpolation_insertion_tests;
+#[path = "insertion_tests/key_name_insertion_tests.rs"]
+mod key_name_insertion_tests;
 #[path = "insertion_tests/mid_query_insertion_tests.rs"]
 mod mid_query_insertion_tests;
 #[path = "insertion_tests/property_tests.rs"]
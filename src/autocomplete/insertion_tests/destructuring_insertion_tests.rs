@@ -0,0 +1,36 @@
+//! Destructuring pattern insertion tests
+
+use super::*;
+
+fn pattern(text: &str) -> Suggestion {
+    Suggestion::new(text, SuggestionType::Pattern)
+}
+
+#[test]
+fn test_pattern_replaces_opening_brace() {
+    let (mut textarea, mut query_state) = setup_insertion_test(".user as {");
+
+    insert_suggestion(
+        &mut textarea,
+        &mut query_state,
+        &pattern("{name: $name, age: $age}"),
+    );
+
+    assert_eq!(textarea.lines()[0], ".user as {name: $name, age: $age}");
+}
+
+#[test]
+fn test_pattern_keeps_existing_closing_bracket() {
+    let mut textarea = TextArea::default();
+    textarea.insert_str(".pair as [] | $first");
+    move_cursor_to_column(&mut textarea, 10);
+    let mut query_state = crate::query::QueryState::new(r#"{"test": true}"#.to_string());
+
+    insert_suggestion(
+        &mut textarea,
+        &mut query_state,
+        &pattern("[$first, $second]"),
+    );
+
+    assert_eq!(textarea.lines()[0], ".pair as [$first, $second] | $first");
+}
@@ -713,3 +713,27 @@ fn test_autocomplete_with_real_ecs_like_data() {
         non_null_lines.len()
     );
 }
+
+#[test]
+fn test_index_suggestion_replaces_trailing_dot() {
+    let json = r#"{"services": [{"name": "alice"}, {"name": "bob"}]}"#;
+    let mut app = test_app(json);
+
+    app.input.textarea.insert_str(".services");
+    app.query.as_mut().unwrap().execute(".services");
+    app.input.textarea.insert_str(".");
+
+    insert_suggestion_from_app(&mut app, &test_suggestion("[-1]"));
+
+    assert_eq!(app.input.query(), ".services[-1]");
+}
+
+#[test]
+fn test_slice_suggestion_at_root() {
+    let mut app = test_app("[1, 2, 3]");
+
+    app.input.textarea.insert_str(".");
+    insert_suggestion_from_app(&mut app, &test_suggestion(".[1:]"));
+
+    assert_eq!(app.input.query(), ".[1:]");
+}
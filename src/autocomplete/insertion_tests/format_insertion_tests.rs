@@ -0,0 +1,29 @@
+//! Format string insertion tests
+
+use super::*;
+
+#[test]
+fn test_format_replaces_partial_including_at_sign() {
+    let (mut textarea, mut query_state) = setup_insertion_test(".row | @cs");
+
+    insert_suggestion(
+        &mut textarea,
+        &mut query_state,
+        &Suggestion::new("@csv", SuggestionType::Function),
+    );
+
+    assert_eq!(textarea.lines()[0], ".row | @csv");
+}
+
+#[test]
+fn test_format_after_bare_at_sign() {
+    let (mut textarea, mut query_state) = setup_insertion_test(".cmd|@");
+
+    insert_suggestion(
+        &mut textarea,
+        &mut query_state,
+        &Suggestion::new("@sh", SuggestionType::Function),
+    );
+
+    assert_eq!(textarea.lines()[0], ".cmd|@sh");
+}
@@ -0,0 +1,100 @@
+//! Ghost text (inline completion preview) tests
+
+use super::*;
+use crate::app::Focus;
+use crate::editor::EditorMode;
+use crate::test_utils::test_helpers::key;
+use ratatui::crossterm::event::KeyCode;
+
+#[test]
+fn test_ghost_text_is_the_rest_of_the_completion() {
+    let (textarea, query_state) = setup_insertion_test(".te");
+    assert_eq!(
+        ghost_text(&textarea, &query_state, &test_suggestion("test")),
+        Some("st".to_string())
+    );
+}
+
+#[test]
+fn test_ghost_text_after_trailing_dot() {
+    let (textarea, query_state) = setup_insertion_test(".");
+    assert_eq!(
+        ghost_text(&textarea, &query_state, &test_suggestion(".test")),
+        Some("test".to_string())
+    );
+}
+
+#[test]
+fn test_no_ghost_text_when_completion_rewrites_the_query() {
+    let (textarea, query_state) = setup_insertion_test(".tst");
+    assert_eq!(
+        ghost_text(&textarea, &query_state, &test_suggestion("test")),
+        None
+    );
+}
+
+#[test]
+fn test_no_ghost_text_when_cursor_is_not_at_end() {
+    let (mut textarea, query_state) = setup_insertion_test(".te | .");
+    move_cursor_to_column(&mut textarea, 3);
+    assert_eq!(
+        ghost_text(&textarea, &query_state, &test_suggestion("test")),
+        None
+    );
+}
+
+#[test]
+fn test_no_ghost_text_when_already_complete() {
+    let (textarea, query_state) = setup_insertion_test(".test");
+    assert_eq!(
+        ghost_text(&textarea, &query_state, &test_suggestion("test")),
+        None
+    );
+}
+
+fn app_typing(query: &str) -> crate::app::App {
+    let mut app = test_app(r#"{"test": true}"#);
+    app.input.textarea.insert_str(query);
+    app.input.editor_mode = EditorMode::Insert;
+    app.focus = Focus::InputField;
+    app.autocomplete
+        .update_suggestions(vec![test_suggestion("test")]);
+    app
+}
+
+#[test]
+fn test_ghost_text_only_while_inserting_in_the_input_field() {
+    let mut app = app_typing(".te");
+    assert_eq!(ghost_text_from_app(&app), Some("st".to_string()));
+
+    app.input.editor_mode = EditorMode::Normal;
+    assert_eq!(ghost_text_from_app(&app), None);
+
+    app.input.editor_mode = EditorMode::Insert;
+    app.focus = Focus::ResultsPane;
+    assert_eq!(ghost_text_from_app(&app), None);
+}
+
+#[test]
+fn test_right_accepts_ghost_text() {
+    let mut app = app_typing(".te");
+    app.handle_key_event(key(KeyCode::Right));
+    assert_eq!(app.input.query(), ".test");
+    assert!(!app.autocomplete.is_visible());
+}
+
+#[test]
+fn test_end_accepts_ghost_text() {
+    let mut app = app_typing(".te");
+    app.handle_key_event(key(KeyCode::End));
+    assert_eq!(app.input.query(), ".test");
+}
+
+#[test]
+fn test_right_moves_cursor_without_ghost_text() {
+    let mut app = app_typing(".te");
+    move_cursor_to_column(&mut app.input.textarea, 1);
+    app.handle_key_event(key(KeyCode::Right));
+    assert_eq!(app.input.query(), ".te");
+    assert_eq!(app.input.textarea.cursor().1, 2);
+}
@@ -0,0 +1,46 @@
+//! String interpolation insertion tests
+
+use super::*;
+
+#[test]
+fn test_interpolation_replaces_backslash() {
+    let (mut textarea, mut query_state) = setup_insertion_test(r#""Hello \"#);
+
+    insert_suggestion(
+        &mut textarea,
+        &mut query_state,
+        &test_suggestion(r"\(.name)"),
+    );
+
+    assert_eq!(textarea.lines()[0], r#""Hello \(.name)"#);
+}
+
+#[test]
+fn test_interpolation_replaces_partial_field() {
+    let (mut textarea, mut query_state) = setup_insertion_test(r#".[] | "\(.na"#);
+
+    insert_suggestion(
+        &mut textarea,
+        &mut query_state,
+        &test_suggestion(r"\(.name)"),
+    );
+
+    assert_eq!(textarea.lines()[0], r#".[] | "\(.name)"#);
+}
+
+#[test]
+fn test_interpolation_keeps_existing_closing_paren() {
+    let mut textarea = TextArea::default();
+    textarea.insert_str(r#""Hi \(.n)""#);
+    move_cursor_to_column(&mut textarea, 8);
+    let mut query_state = crate::query::QueryState::new(r#"{"test": true}"#.to_string());
+
+    insert_suggestion(
+        &mut textarea,
+        &mut query_state,
+        &test_suggestion(r"\(.name)"),
+    );
+
+    assert_eq!(textarea.lines()[0], r#""Hi \(.name)""#);
+    assert_eq!(textarea.cursor().1, 11);
+}
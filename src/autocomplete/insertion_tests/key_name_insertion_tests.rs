@@ -0,0 +1,34 @@
+//! Key name insertion tests
+
+use super::*;
+
+#[test]
+fn test_key_name_replaces_partial_and_closes_string() {
+    let (mut textarea, mut query_state) = setup_insertion_test(r#".user | has("na"#);
+
+    insert_suggestion(&mut textarea, &mut query_state, &test_suggestion("name"));
+
+    assert_eq!(textarea.lines()[0], r#".user | has("name""#);
+}
+
+#[test]
+fn test_key_name_keeps_existing_closing_quote() {
+    let mut textarea = TextArea::default();
+    textarea.insert_str(r#"has("na")"#);
+    move_cursor_to_column(&mut textarea, 7);
+    let mut query_state = crate::query::QueryState::new(r#"{"test": true}"#.to_string());
+
+    insert_suggestion(&mut textarea, &mut query_state, &test_suggestion("name"));
+
+    assert_eq!(textarea.lines()[0], r#"has("name")"#);
+    assert_eq!(textarea.cursor().1, 9);
+}
+
+#[test]
+fn test_key_name_in_entry_comparison() {
+    let (mut textarea, mut query_state) = setup_insertion_test(r#"with_entries(select(.key == ""#);
+
+    insert_suggestion(&mut textarea, &mut query_state, &test_suggestion("age"));
+
+    assert_eq!(textarea.lines()[0], r#"with_entries(select(.key == "age""#);
+}
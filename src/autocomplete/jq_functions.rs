@@ -282,19 +282,6 @@ static JQ_BUILTINS: LazyLock<Vec<Suggestion>> = LazyLock::new(|| {
         );
     }
 
-    // Additional functions not in metadata (special syntax, keywords, etc.)
-    builtins.extend(vec![
-        // I/O and formatting (format strings, not functions)
-        Suggestion::new("@json", SuggestionType::Function)
-            .with_description("Format as JSON string"),
-        Suggestion::new("@uri", SuggestionType::Function).with_description("URL encode"),
-        Suggestion::new("@csv", SuggestionType::Function).with_description("Format as CSV"),
-        Suggestion::new("@tsv", SuggestionType::Function).with_description("Format as TSV"),
-        Suggestion::new("@html", SuggestionType::Function).with_description("HTML encode"),
-        Suggestion::new("@base64", SuggestionType::Function).with_description("Base64 encode"),
-        Suggestion::new("@base64d", SuggestionType::Function).with_description("Base64 decode"),
-    ]);
-
     // Date functions not in requirements list
     builtins.extend(vec![
         Suggestion::new("fromdateiso8601", SuggestionType::Function)
@@ -350,6 +337,33 @@ pub fn filter_builtins(prefix: &str) -> Vec<Suggestion> {
         .collect()
 }
 
+/// jq format strings with what they produce; suggested after `@`
+pub static JQ_FORMAT_STRINGS: &[(&str, &str)] = &[
+    ("@text", "Convert to a string, like tostring"),
+    ("@json", "Serialize as JSON text"),
+    ("@csv", "Array as a CSV row, strings quoted"),
+    ("@tsv", "Array as a TSV row, tabs escaped"),
+    ("@html", "Escape <, >, &, ' and \" for HTML"),
+    ("@uri", "Percent-encode for use in a URL"),
+    ("@sh", "Quote for use in a shell command"),
+    ("@base64", "Base64 encode"),
+    ("@base64d", "Base64 decode"),
+    ("@base32", "Base32 encode"),
+    ("@base32d", "Base32 decode"),
+];
+
+/// Filter format strings by prefix, which includes the `@`
+pub fn filter_formats(prefix: &str) -> Vec<Suggestion> {
+    let prefix_lower = prefix.to_lowercase();
+    JQ_FORMAT_STRINGS
+        .iter()
+        .filter(|(name, _)| name.starts_with(&prefix_lower))
+        .map(|(name, description)| {
+            Suggestion::new(*name, SuggestionType::Function).with_description(*description)
+        })
+        .collect()
+}
+
 /// Get all jq function metadata for testing
 #[cfg(test)]
 pub fn get_all_function_metadata() -> &'static [JqFunction] {
@@ -0,0 +1,130 @@
+//! Field completion for JSONPath, JMESPath and SQL queries
+//!
+//! These languages are completed by member name only. Candidates are the keys
+//! of the last successful result (or of its first element when it is an
+//! array), falling back to every field name in the input. SQL results only
+//! hold the selected columns, so SQL always completes from the input.
+
+use std::collections::{BTreeSet, HashSet};
+
+use serde_json::Value;
+use tui_textarea::TextArea;
+
+use crate::autocomplete::autocomplete_state::{Suggestion, SuggestionType};
+use crate::autocomplete::insertion::move_cursor_to_column;
+use crate::autocomplete::result_analyzer::ResultAnalyzer;
+use crate::query::language::{QueryLanguage, is_jq_identifier};
+
+/// Member name suggestions for the text before the cursor
+pub fn get_suggestions(
+    language: QueryLanguage,
+    before_cursor: &str,
+    result_parsed: Option<&Value>,
+    all_field_names: &HashSet<String>,
+) -> Vec<Suggestion> {
+    let start = partial_start(before_cursor);
+    let partial = &before_cursor[start..];
+    let after_dot = before_cursor[..start].ends_with('.');
+    if partial.starts_with(|c: char| c.is_ascii_digit()) {
+        return Vec::new();
+    }
+
+    // JMESPath and SQL fields can start anywhere (`a || b`, `WHERE age > ...`);
+    // JSONPath members always follow a dot
+    let wants_fields = match language {
+        QueryLanguage::Jq => false,
+        QueryLanguage::JsonPath => after_dot,
+        QueryLanguage::JmesPath | QueryLanguage::Sql => after_dot || !partial.is_empty(),
+    };
+    if !wants_fields {
+        return Vec::new();
+    }
+
+    let object = match result_parsed {
+        _ if language == QueryLanguage::Sql => None,
+        Some(Value::Array(items)) => items.first(),
+        other => other,
+    };
+    let mut suggestions: Vec<Suggestion> = match object {
+        Some(Value::Object(map)) if !map.is_empty() => map
+            .iter()
+            .map(|(key, value)| {
+                Suggestion::new_with_type(
+                    key.clone(),
+                    SuggestionType::Field,
+                    Some(ResultAnalyzer::detect_json_type(value)),
+                )
+            })
+            .collect(),
+        _ => all_field_names
+            .iter()
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .map(|key| Suggestion::new(key.clone(), SuggestionType::Field))
+            .collect(),
+    };
+
+    let partial_lower = partial.to_lowercase();
+    suggestions.retain(|s| s.text != partial && s.text.to_lowercase().contains(&partial_lower));
+    suggestions
+}
+
+/// Replace the partial member name before the cursor with `suggestion`
+///
+/// Names that are not plain identifiers, jq keywords included, are quoted the
+/// way each language requires: `"name"` in JMESPath and SQL, and `['name']` in JSONPath.
+pub fn insert_suggestion(
+    textarea: &mut TextArea<'_>,
+    language: QueryLanguage,
+    suggestion: &Suggestion,
+) {
+    let query = textarea.lines()[0].clone();
+    let cursor_pos = byte_offset(&query, textarea.cursor().1);
+    let mut start = partial_start(&query[..cursor_pos]);
+    let key = &suggestion.text;
+
+    let insert_text = if is_jq_identifier(key) {
+        key.clone()
+    } else {
+        match language {
+            QueryLanguage::JsonPath if query[..start].ends_with('.') => {
+                start -= 1;
+                format!("['{}']", key.replace('\\', "\\\\").replace('\'', "\\'"))
+            }
+            _ => serde_json::to_string(key).unwrap_or_else(|_| key.clone()),
+        }
+    };
+
+    let new_query = format!("{}{}{}", &query[..start], insert_text, &query[cursor_pos..]);
+    textarea.delete_line_by_head();
+    textarea.delete_line_by_end();
+    textarea.insert_str(&new_query);
+    let start_chars = query[..start].chars().count();
+    move_cursor_to_column(textarea, start_chars + insert_text.chars().count());
+}
+
+/// Byte offset of char column `column` in `query`
+///
+/// Textarea cursor columns count chars, but queries are sliced by bytes.
+pub fn byte_offset(query: &str, column: usize) -> usize {
+    query
+        .char_indices()
+        .nth(column)
+        .map(|(i, _)| i)
+        .unwrap_or(query.len())
+}
+
+/// Byte offset where the identifier ending at the cursor starts
+fn partial_start(before_cursor: &str) -> usize {
+    before_cursor
+        .char_indices()
+        .rev()
+        .take_while(|(_, c)| c.is_alphanumeric() || *c == '_')
+        .last()
+        .map(|(i, _)| i)
+        .unwrap_or(before_cursor.len())
+}
+
+#[cfg(test)]
+#[path = "path_language_tests.rs"]
+mod path_language_tests;
@@ -0,0 +1,145 @@
+//! Tests for JSONPath, JMESPath and SQL field completion
+
+use super::*;
+use serde_json::json;
+
+fn texts(suggestions: &[Suggestion]) -> Vec<&str> {
+    suggestions.iter().map(|s| s.text.as_str()).collect()
+}
+
+fn fields(names: &[&str]) -> HashSet<String> {
+    names.iter().map(|n| n.to_string()).collect()
+}
+
+#[test]
+fn test_jsonpath_suggests_keys_after_dot() {
+    let result = json!([{"name": "a", "age": 1}]);
+    let suggestions = get_suggestions(
+        QueryLanguage::JsonPath,
+        "$.people[*].",
+        Some(&result),
+        &fields(&[]),
+    );
+    assert_eq!(texts(&suggestions), vec!["name", "age"]);
+}
+
+#[test]
+fn test_sql_completes_from_input_fields() {
+    let result = json!([{"name": "a"}]);
+    let suggestions = get_suggestions(
+        QueryLanguage::Sql,
+        "SELECT name FROM . WHERE ag",
+        Some(&result),
+        &fields(&["age", "name", "stage"]),
+    );
+    assert_eq!(texts(&suggestions), vec!["age", "stage"]);
+}
+
+#[test]
+fn test_jsonpath_requires_dot() {
+    let result = json!({"name": "a"});
+    let suggestions = get_suggestions(QueryLanguage::JsonPath, "$[?", Some(&result), &fields(&[]));
+    assert!(suggestions.is_empty());
+}
+
+#[test]
+fn test_jmespath_suggests_bare_identifiers() {
+    let result = json!({"people": [], "owner": {}});
+    let suggestions = get_suggestions(QueryLanguage::JmesPath, "peo", Some(&result), &fields(&[]));
+    assert_eq!(texts(&suggestions), vec!["people"]);
+}
+
+#[test]
+fn test_falls_back_to_all_field_names() {
+    let result = json!([]);
+    let suggestions = get_suggestions(
+        QueryLanguage::JsonPath,
+        "$.store.bo",
+        Some(&result),
+        &fields(&["book", "bicycle", "color"]),
+    );
+    assert_eq!(texts(&suggestions), vec!["book"]);
+}
+
+#[test]
+fn test_exact_match_is_not_suggested() {
+    let result = json!({"name": "a"});
+    let suggestions = get_suggestions(QueryLanguage::JmesPath, "name", Some(&result), &fields(&[]));
+    assert!(suggestions.is_empty());
+}
+
+#[test]
+fn test_jq_gets_no_suggestions() {
+    let result = json!({"name": "a"});
+    let suggestions = get_suggestions(QueryLanguage::Jq, ".", Some(&result), &fields(&[]));
+    assert!(suggestions.is_empty());
+}
+
+fn insert(language: QueryLanguage, query: &str, key: &str) -> String {
+    let mut textarea = TextArea::default();
+    textarea.insert_str(query);
+    insert_suggestion(
+        &mut textarea,
+        language,
+        &Suggestion::new(key, SuggestionType::Field),
+    );
+    textarea.lines()[0].clone()
+}
+
+#[test]
+fn test_insert_replaces_partial() {
+    assert_eq!(
+        insert(QueryLanguage::JsonPath, "$.store.bo", "book"),
+        "$.store.book"
+    );
+    assert_eq!(
+        insert(QueryLanguage::JmesPath, "people[?ag", "age"),
+        "people[?age"
+    );
+}
+
+#[test]
+fn test_insert_quotes_unusual_names() {
+    assert_eq!(
+        insert(QueryLanguage::JsonPath, "$.headers.con", "content-type"),
+        "$.headers['content-type']"
+    );
+    assert_eq!(
+        insert(QueryLanguage::JmesPath, "headers.con", "content-type"),
+        r#"headers."content-type""#
+    );
+    assert_eq!(
+        insert(QueryLanguage::Sql, "SELECT en", "end"),
+        r#"SELECT "end""#
+    );
+}
+
+#[test]
+fn test_insert_after_non_ascii_text() {
+    assert_eq!(
+        insert(QueryLanguage::JsonPath, "$.名前.na", "name"),
+        "$.名前.name"
+    );
+    assert_eq!(
+        insert(QueryLanguage::JsonPath, "$.名前.con", "content-type"),
+        "$.名前['content-type']"
+    );
+}
+
+#[test]
+fn test_insert_places_cursor_after_non_ascii_text() {
+    let mut textarea = TextArea::default();
+    textarea.insert_str("$.名前.na");
+    insert_suggestion(
+        &mut textarea,
+        QueryLanguage::JsonPath,
+        &Suggestion::new("name", SuggestionType::Field),
+    );
+    assert_eq!(textarea.cursor(), (0, "$.名前.name".chars().count()));
+}
+
+#[test]
+fn test_byte_offset_counts_chars() {
+    assert_eq!(byte_offset("$.名前.na", 5), "$.名前.".len());
+    assert_eq!(byte_offset("$.名前.na", 99), "$.名前.na".len());
+}
@@ -3,6 +3,10 @@ use crate::query::ResultType;
 use serde_json::Value;
 use std::sync::Arc;
 
+mod array_access;
+
+use array_access::push_array_access_suggestions;
+
 pub struct ResultAnalyzer;
 
 #[inline]
@@ -76,13 +80,9 @@ impl ResultAnalyzer {
             Value::Array(arr) => {
                 let mut suggestions = Vec::new();
 
-                // Only suggest .[] when not suppressing array brackets
+                // Only suggest .[] and indexing when not suppressing array brackets
                 if !suppress_array_brackets {
-                    suggestions.push(Suggestion::new_with_type(
-                        format!("{}[]", prefix),
-                        SuggestionType::Pattern,
-                        None,
-                    ));
+                    push_array_access_suggestions(arr, prefix, &mut suggestions);
                 }
 
                 // If array contains objects, suggest their fields
@@ -116,6 +116,47 @@ impl ResultAnalyzer {
         }
     }
 
+    /// Key names of a JSON value, for completing a quoted key such as `has("na`
+    ///
+    /// Objects give their own keys and arrays the keys of their first object.
+    /// An array of `to_entries` output gives the `key` of each entry instead.
+    /// Keys are escaped so they can be inserted between double quotes.
+    pub fn analyze_key_names(value: &Value) -> Vec<Suggestion> {
+        let key_suggestion = |key: &str, val: &Value| {
+            let escaped = serde_json::to_string(key).unwrap_or_default();
+            Suggestion::new_with_type(
+                escaped[1..escaped.len() - 1].to_string(),
+                SuggestionType::Field,
+                Some(Self::detect_json_type(val)),
+            )
+        };
+
+        match value {
+            Value::Object(map) => map.iter().map(|(k, v)| key_suggestion(k, v)).collect(),
+            Value::Array(arr) if !arr.is_empty() && arr.iter().all(Self::is_entry) => arr
+                .iter()
+                .filter_map(|entry| Some((entry.get("key")?.as_str()?, entry.get("value")?)))
+                .map(|(k, v)| key_suggestion(k, v))
+                .collect(),
+            Value::Array(arr) => match arr.first() {
+                Some(Value::Object(map)) => map.iter().map(|(k, v)| key_suggestion(k, v)).collect(),
+                _ => Vec::new(),
+            },
+            _ => Vec::new(),
+        }
+    }
+
+    /// Whether a value looks like a `to_entries` element: `{"key": "...", "value": ...}`
+    fn is_entry(value: &Value) -> bool {
+        matches!(
+            value,
+            Value::Object(map)
+                if map.len() == 2
+                    && map.get("key").is_some_and(Value::is_string)
+                    && map.contains_key("value")
+        )
+    }
+
     /// Analyze pre-parsed JSON value for field suggestions
     ///
     /// Optimized path that avoids re-parsing on every keystroke.
@@ -150,13 +191,10 @@ impl ResultAnalyzer {
                 let prefix = dot_prefix(needs_leading_dot);
                 let mut suggestions = Vec::new();
 
-                // Only suggest .[] when not suppressing array brackets
+                // Only suggest .[] and indexing when not suppressing array brackets
                 if !suppress_array_brackets {
-                    suggestions.push(Suggestion::new_with_type(
-                        format!("{}[]", prefix),
-                        SuggestionType::Pattern,
-                        None,
-                    ));
+                    let elements = value.as_array().map(Vec::as_slice).unwrap_or_default();
+                    push_array_access_suggestions(elements, prefix, &mut suggestions);
                 }
 
                 if let Value::Array(arr) = value
@@ -208,17 +246,16 @@ impl ResultAnalyzer {
             }
             ResultType::Array => {
                 let prefix = dot_prefix(needs_leading_dot);
-                vec![Suggestion::new_with_type(
-                    format!("{}[]", prefix),
-                    SuggestionType::Pattern,
-                    None,
-                )]
+                let elements = value.as_array().map(Vec::as_slice).unwrap_or_default();
+                let mut suggestions = Vec::new();
+                push_array_access_suggestions(elements, prefix, &mut suggestions);
+                suggestions
             }
             _ => Vec::new(),
         }
     }
 
-    fn detect_json_type(value: &Value) -> JsonFieldType {
+    pub(crate) fn detect_json_type(value: &Value) -> JsonFieldType {
         match value {
             Value::Null => JsonFieldType::Null,
             Value::Bool(_) => JsonFieldType::Boolean,
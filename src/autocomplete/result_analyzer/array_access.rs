@@ -0,0 +1,103 @@
+//! Index and slice suggestions for arrays, previewing the elements they select
+
+use serde_json::Value;
+
+use super::ResultAnalyzer;
+use crate::autocomplete::autocomplete_state::{Suggestion, SuggestionType};
+
+/// Length of the `[0:n]` slice suggestion
+const SLICE_LENGTH: usize = 5;
+/// Characters of an element shown next to its index suggestion
+const PREVIEW_LENGTH: usize = 24;
+
+/// Writer keeping only the start of what is written, failing once full so
+/// serializing a large value stops early
+struct PreviewWriter(Vec<u8>);
+
+impl std::io::Write for PreviewWriter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        if self.0.len() > PREVIEW_LENGTH * 4 {
+            return Err(std::io::ErrorKind::WriteZero.into());
+        }
+        self.0.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Compact JSON of `value`, cut to `PREVIEW_LENGTH` characters
+fn preview_value(value: &Value) -> String {
+    let mut writer = PreviewWriter(Vec::new());
+    // Stopping early is expected for large values
+    let _ = serde_json::to_writer(&mut writer, value);
+    let json = String::from_utf8_lossy(&writer.0);
+
+    if json.chars().count() <= PREVIEW_LENGTH {
+        return json.into_owned();
+    }
+    let mut preview: String = json.chars().take(PREVIEW_LENGTH - 1).collect();
+    preview.push('…');
+    preview
+}
+
+/// Suggest iterating an array (`[]`) and, when it has elements, indexing
+/// and slicing it: `[0]`, `[-1]`, `[0:5]` and `[1:]`
+///
+/// Index suggestions preview the element they select.
+pub(super) fn push_array_access_suggestions(
+    arr: &[Value],
+    prefix: &str,
+    suggestions: &mut Vec<Suggestion>,
+) {
+    suggestions.push(Suggestion::new_with_type(
+        format!("{}[]", prefix),
+        SuggestionType::Pattern,
+        None,
+    ));
+
+    let (Some(first), Some(last)) = (arr.first(), arr.last()) else {
+        return;
+    };
+    suggestions.push(
+        Suggestion::new_with_type(
+            format!("{}[0]", prefix),
+            SuggestionType::Pattern,
+            Some(ResultAnalyzer::detect_json_type(first)),
+        )
+        .with_preview(preview_value(first)),
+    );
+    if arr.len() == 1 {
+        return;
+    }
+    suggestions.push(
+        Suggestion::new_with_type(
+            format!("{}[-1]", prefix),
+            SuggestionType::Pattern,
+            Some(ResultAnalyzer::detect_json_type(last)),
+        )
+        .with_preview(preview_value(last)),
+    );
+    if arr.len() > SLICE_LENGTH {
+        suggestions.push(
+            Suggestion::new(
+                format!("{}[0:{}]", prefix, SLICE_LENGTH),
+                SuggestionType::Pattern,
+            )
+            .with_preview(format!("first {} of {}", SLICE_LENGTH, arr.len())),
+        );
+    }
+    suggestions.push(
+        Suggestion::new(format!("{}[1:]", prefix), SuggestionType::Pattern).with_preview(format!(
+            "last {} of {}",
+            arr.len() - 1,
+            arr.len()
+        )),
+    );
+}
+
+#[cfg(test)]
+#[path = "array_access_tests.rs"]
+mod array_access_tests;
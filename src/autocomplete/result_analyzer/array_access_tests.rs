@@ -0,0 +1,69 @@
+//! Tests for array index and slice suggestions
+
+use crate::autocomplete::autocomplete_state::JsonFieldType;
+use crate::autocomplete::result_analyzer::ResultAnalyzer;
+use serde_json::Value;
+
+#[test]
+fn test_array_index_suggestions_preview_elements() {
+    let json: Value = serde_json::from_str(r#"[{"id": 1}, {"id": 2}]"#).unwrap();
+    let suggestions = ResultAnalyzer::analyze_value(&json, true, false);
+
+    let first = suggestions.iter().find(|s| s.text == ".[0]").unwrap();
+    assert_eq!(first.preview.as_deref(), Some(r#"{"id":1}"#));
+    assert_eq!(first.field_type, Some(JsonFieldType::Object));
+    let last = suggestions.iter().find(|s| s.text == ".[-1]").unwrap();
+    assert_eq!(last.preview.as_deref(), Some(r#"{"id":2}"#));
+}
+
+#[test]
+fn test_array_slice_suggested_for_long_arrays() {
+    let json: Value = serde_json::from_str("[1, 2, 3, 4, 5, 6, 7]").unwrap();
+    let suggestions = ResultAnalyzer::analyze_value(&json, false, false);
+
+    let texts: Vec<_> = suggestions.iter().map(|s| s.text.as_str()).collect();
+    assert_eq!(texts, vec!["[]", "[0]", "[-1]", "[0:5]", "[1:]"]);
+    let slice = suggestions.iter().find(|s| s.text == "[0:5]").unwrap();
+    assert_eq!(slice.preview.as_deref(), Some("first 5 of 7"));
+}
+
+#[test]
+fn test_single_element_array_only_suggests_first_index() {
+    let json: Value = serde_json::from_str(r#"["only"]"#).unwrap();
+    let suggestions = ResultAnalyzer::analyze_value(&json, true, false);
+
+    let texts: Vec<_> = suggestions.iter().map(|s| s.text.as_str()).collect();
+    assert_eq!(texts, vec![".[]", ".[0]"]);
+}
+
+#[test]
+fn test_empty_array_only_suggests_iteration() {
+    let json: Value = serde_json::from_str("[]").unwrap();
+    let suggestions = ResultAnalyzer::analyze_value(&json, true, false);
+
+    let texts: Vec<_> = suggestions.iter().map(|s| s.text.as_str()).collect();
+    assert_eq!(texts, vec![".[]"]);
+}
+
+#[test]
+fn test_index_preview_is_truncated() {
+    let json: Value =
+        serde_json::from_str(r#"[{"description": "a fairly long string value here"}]"#).unwrap();
+    let suggestions = ResultAnalyzer::analyze_value(&json, true, false);
+
+    let preview = suggestions
+        .iter()
+        .find(|s| s.text == ".[0]")
+        .and_then(|s| s.preview.clone())
+        .unwrap();
+    assert_eq!(preview.chars().count(), 24);
+    assert!(preview.ends_with('…'));
+}
+
+#[test]
+fn test_no_index_suggestions_when_brackets_suppressed() {
+    let json: Value = serde_json::from_str(r#"[{"id": 1}, {"id": 2}]"#).unwrap();
+    let suggestions = ResultAnalyzer::analyze_value(&json, true, true);
+
+    assert!(suggestions.iter().all(|s| !s.text.contains('[')));
+}
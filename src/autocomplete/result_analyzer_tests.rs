@@ -4,6 +4,9 @@ use super::*;
 use serde_json::Value;
 use std::sync::Arc;
 
+#[path = "result_analyzer_tests/key_name_tests.rs"]
+mod key_name_tests;
+
 /// Helper function to parse JSON string and wrap in Arc for testing
 ///
 /// For multi-line JSON (destructured objects), parses only the first complete object
@@ -372,7 +375,9 @@ fn test_array_with_nulls_in_result() {
 
     // Should suggest based on first element (null has no fields)
     assert!(suggestions.iter().any(|s| s.text == ".[]"));
-    assert_eq!(suggestions.len(), 1); // Only .[] since first element is null
+    // No fields since first element is null, only iteration and indexing
+    let texts: Vec<_> = suggestions.iter().map(|s| s.text.as_str()).collect();
+    assert_eq!(texts, vec![".[]", ".[0]", ".[-1]", ".[1:]"]);
 }
 
 #[test]
@@ -503,9 +508,9 @@ fn test_primitive_array_after_operator() {
     let suggestions =
         ResultAnalyzer::analyze_parsed_result(&parsed, ResultType::Array, true, false);
 
-    // Should only suggest .[]
-    assert_eq!(suggestions.len(), 1);
-    assert_eq!(suggestions[0].text, ".[]");
+    // Should only suggest iteration and indexing
+    let texts: Vec<_> = suggestions.iter().map(|s| s.text.as_str()).collect();
+    assert_eq!(texts, vec![".[]", ".[0]", ".[-1]", ".[1:]"]);
 }
 
 #[test]
@@ -516,9 +521,9 @@ fn test_primitive_array_after_continuation() {
     let suggestions =
         ResultAnalyzer::analyze_parsed_result(&parsed, ResultType::Array, false, false);
 
-    // Should only suggest [] (no leading dot)
-    assert_eq!(suggestions.len(), 1);
-    assert_eq!(suggestions[0].text, "[]");
+    // Should only suggest iteration and indexing (no leading dot)
+    let texts: Vec<_> = suggestions.iter().map(|s| s.text.as_str()).collect();
+    assert_eq!(texts, vec!["[]", "[0]", "[-1]", "[1:]"]);
 }
 
 #[test]
@@ -807,9 +812,9 @@ mod analyze_value_tests {
         let json: Value = serde_json::from_str("[1, 2, 3]").unwrap();
         let suggestions = ResultAnalyzer::analyze_value(&json, true, false);
 
-        // Only .[] for array of primitives
-        assert_eq!(suggestions.len(), 1);
-        assert_eq!(suggestions[0].text, ".[]");
+        // Only iteration and indexing for array of primitives
+        let texts: Vec<_> = suggestions.iter().map(|s| s.text.as_str()).collect();
+        assert_eq!(texts, vec![".[]", ".[0]", ".[-1]", ".[1:]"]);
     }
 
     #[test]
@@ -875,7 +880,8 @@ fn test_array_of_objects_with_nonsimple_field_names() {
     .unwrap();
     let suggestions = ResultAnalyzer::analyze_value(&json, true, false);
 
-    assert_eq!(suggestions.len(), 3); // .[], .[].1numeric_key, .[].simple_key
+    // .[], .[0], .[-1], .[1:], .[].1numeric_key, .[].simple_key
+    assert_eq!(suggestions.len(), 6);
     let suggestion_texts: Vec<_> = suggestions.iter().map(|s| s.text.as_str()).collect();
     assert!(suggestion_texts.contains(&".[]"));
     assert!(suggestion_texts.contains(&r#".[]."1numeric_key""#));
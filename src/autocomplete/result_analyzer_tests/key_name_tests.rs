@@ -0,0 +1,39 @@
+//! Tests for key name suggestions inside string literals
+
+use super::*;
+
+#[test]
+fn test_key_names_of_object_are_escaped() {
+    let json: Value = serde_json::from_str(r#"{"name": "a", "say \"hi\"": 1}"#).unwrap();
+    let suggestions = ResultAnalyzer::analyze_key_names(&json);
+
+    let texts: Vec<_> = suggestions.iter().map(|s| s.text.as_str()).collect();
+    assert_eq!(texts, vec!["name", r#"say \"hi\""#]);
+    assert_eq!(suggestions[0].field_type, Some(JsonFieldType::String));
+}
+
+#[test]
+fn test_key_names_of_array_use_first_object() {
+    let json: Value = serde_json::from_str(r#"[{"id": 1}, {"other": 2}]"#).unwrap();
+    let suggestions = ResultAnalyzer::analyze_key_names(&json);
+
+    let texts: Vec<_> = suggestions.iter().map(|s| s.text.as_str()).collect();
+    assert_eq!(texts, vec!["id"]);
+}
+
+#[test]
+fn test_key_names_of_entries_use_entry_keys() {
+    let json: Value =
+        serde_json::from_str(r#"[{"key": "b", "value": 1}, {"key": "a", "value": "x"}]"#).unwrap();
+    let suggestions = ResultAnalyzer::analyze_key_names(&json);
+
+    let texts: Vec<_> = suggestions.iter().map(|s| s.text.as_str()).collect();
+    assert_eq!(texts, vec!["b", "a"]);
+    assert_eq!(suggestions[1].field_type, Some(JsonFieldType::String));
+}
+
+#[test]
+fn test_key_names_of_scalar_are_empty() {
+    let json: Value = serde_json::from_str("42").unwrap();
+    assert!(ResultAnalyzer::analyze_key_names(&json).is_empty());
+}
@@ -0,0 +1,136 @@
+//! Completion contexts inside string literals
+//!
+//! A string typed where jq expects a key name, such as `has("na` or
+//! `.["na`, is completed with the keys of the last successful result, and
+//! an interpolation (`\(`) with its fields.
+
+use super::autocomplete_state::Suggestion;
+use super::context::{SuggestionContext, extract_partial_token};
+use super::entry_context::{EntryContext, detect_entry_context};
+use super::scan_state::ScanState;
+
+/// Determines context from a string literal that names a key of the input.
+/// Returns KeyNameContext with the text typed after the opening quote.
+///
+/// # Examples
+/// - `has("na` → "na"
+/// - `.["na` or `.user["na` → "na"
+/// - `getpath(["na` → "na"
+/// - `with_entries(select(.key == "na` → "na"
+pub(super) fn context_from_key_string(before_cursor: &str) -> Option<(SuggestionContext, String)> {
+    let quote_pos = find_unclosed_string_start(before_cursor)?;
+    let partial = &before_cursor[quote_pos + 1..];
+
+    // Escapes and interpolation make the key text unpredictable
+    if partial.contains('\\') {
+        return None;
+    }
+
+    let before_quote = before_cursor[..quote_pos].trim_end();
+    let names_key = if let Some(rest) = before_quote.strip_suffix('(') {
+        ends_with_word(rest.trim_end(), "has")
+    } else if let Some(rest) = before_quote.strip_suffix('[') {
+        ends_with_word(rest.trim_end(), "getpath(") || is_index_target(rest)
+    } else if let Some(rest) = before_quote
+        .strip_suffix("==")
+        .or_else(|| before_quote.strip_suffix("!="))
+    {
+        ends_with_word(rest.trim_end(), ".key")
+            && detect_entry_context(before_cursor, before_cursor.len()) != EntryContext::None
+    } else {
+        false
+    };
+
+    names_key.then(|| (SuggestionContext::KeyNameContext, partial.to_string()))
+}
+
+/// Find where the string literal the text ends inside starts, if any.
+pub(super) fn find_unclosed_string_start(text: &str) -> Option<usize> {
+    let mut state = ScanState::default();
+    let mut start = None;
+
+    for (pos, ch) in text.char_indices() {
+        let next = state.advance(ch);
+        if !state.is_in_string() && next.is_in_string() {
+            start = Some(pos);
+        }
+        state = next;
+    }
+    if state.is_in_string() { start } else { None }
+}
+
+/// Checks if a `[` right after `text` indexes a value (`.[`, `.user[`, `.[0][`)
+/// rather than building an array.
+fn is_index_target(text: &str) -> bool {
+    if text.ends_with([']', '?']) {
+        return true;
+    }
+    let chars: Vec<char> = text.chars().collect();
+    let (_, token) = extract_partial_token(&chars, chars.len());
+    token.starts_with('.') || token.starts_with('$')
+}
+
+/// Checks if text ends with `word` not preceded by an identifier character.
+pub(super) fn ends_with_word(text: &str, word: &str) -> bool {
+    text.strip_suffix(word).is_some_and(|rest| {
+        !rest
+            .chars()
+            .next_back()
+            .is_some_and(|c| c.is_alphanumeric() || c == '_')
+    })
+}
+
+/// Determines context from a string interpolation being typed.
+/// Returns InterpolationContext with the text typed after the backslash.
+///
+/// # Examples
+/// - `"Hello \` → ""
+/// - `"Hello \(` → "("
+/// - `"Hello \(.na` → "(.na"
+pub(super) fn context_from_interpolation(
+    before_cursor: &str,
+) -> Option<(SuggestionContext, String)> {
+    let quote_pos = find_unclosed_string_start(before_cursor)?;
+    let in_string = &before_cursor[quote_pos + 1..];
+
+    // The backslash must start an escape, not be escaped itself
+    let backslash = in_string.rfind('\\')?;
+    let escaping = in_string[..backslash]
+        .chars()
+        .rev()
+        .take_while(|&c| c == '\\')
+        .count();
+    if escaping % 2 == 1 {
+        return None;
+    }
+
+    let partial = &in_string[backslash + 1..];
+    let is_interpolation = match partial.strip_prefix('(') {
+        Some(rest) => {
+            let field = rest.strip_prefix('.').unwrap_or(rest);
+            (rest.is_empty() || rest.starts_with('.'))
+                && field.chars().all(|c| c.is_alphanumeric() || c == '_')
+        }
+        None => partial.is_empty(),
+    };
+
+    is_interpolation.then(|| (SuggestionContext::InterpolationContext, partial.to_string()))
+}
+
+/// Turn field suggestions such as `.name` into interpolations `\(.name)`
+pub(super) fn interpolation_suggestions(suggestions: Vec<Suggestion>) -> Vec<Suggestion> {
+    suggestions
+        .into_iter()
+        .filter(|s| s.text.starts_with('.'))
+        .map(|s| Suggestion {
+            text: format!("\\({})", s.text),
+            ..s
+        })
+        .collect()
+}
+
+/// The field name typed in an interpolation partial: "na" for "(.na"
+pub(super) fn interpolated_field_partial(partial: &str) -> &str {
+    let rest = partial.strip_prefix('(').unwrap_or(partial);
+    rest.strip_prefix('.').unwrap_or(rest)
+}
@@ -0,0 +1,261 @@
+//! Preview of the selected autocomplete suggestion
+//!
+//! While moving through the suggestion list, the query the selected
+//! suggestion would produce is run in the background and its output shown
+//! next to the popup. Runs are throttled: a suggestion has to stay selected
+//! for `PREVIEW_DELAY` before jq starts, and a newer selection cancels
+//! the run in flight.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::mpsc::{Receiver, Sender, channel};
+use std::time::{Duration, Instant};
+
+use ratatui::text::Text;
+use tokio_util::sync::CancellationToken;
+
+use super::insertion::completed_query;
+use crate::app::{App, Focus};
+use crate::editor::EditorMode;
+use crate::error::parser;
+use crate::query::QueryState;
+use crate::query::executor::JqExecutor;
+use crate::query::language::QueryLanguage;
+use crate::syntax_highlight::json::JsonHighlighter;
+
+/// How long a suggestion has to stay selected before its preview runs
+pub const PREVIEW_DELAY: Duration = Duration::from_millis(150);
+
+/// Output of a previewed program
+#[derive(Debug, Clone)]
+pub enum PreviewOutput {
+    Pending,
+    Done(Text<'static>),
+    Failed(String),
+}
+
+/// Finished preview run: the program and its output
+type PreviewResponse = (String, Result<String, String>);
+
+/// Preview of the result of accepting the selected suggestion
+///
+/// Outputs are cached by program for as long as the input they ran against
+/// stays the same, so moving back to a suggestion doesn't re-run jq.
+pub struct SuggestionPreview {
+    /// Program for the selected suggestion and when it was selected
+    requested: Option<(String, Instant)>,
+    /// Input the cached outputs were produced from
+    input: Option<Arc<String>>,
+    cache: HashMap<String, PreviewOutput>,
+    response_tx: Sender<PreviewResponse>,
+    response_rx: Receiver<PreviewResponse>,
+    cancel_token: CancellationToken,
+}
+
+impl Default for SuggestionPreview {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SuggestionPreview {
+    pub fn new() -> Self {
+        let (response_tx, response_rx) = channel();
+        Self {
+            requested: None,
+            input: None,
+            cache: HashMap::new(),
+            response_tx,
+            response_rx,
+            cancel_token: CancellationToken::new(),
+        }
+    }
+
+    /// Select `program` for preview against `input`
+    ///
+    /// Asking for the program already selected keeps its selection time, so
+    /// this can be called on every tick.
+    pub fn request(&mut self, program: String, input: Arc<String>) {
+        if !self
+            .input
+            .as_ref()
+            .is_some_and(|current| Arc::ptr_eq(current, &input))
+        {
+            self.cancel();
+            self.cache.clear();
+            self.input = Some(input);
+        }
+
+        if self.requested.as_ref().map(|(p, _)| p) != Some(&program) {
+            self.cancel();
+            self.requested = Some((program, Instant::now()));
+        }
+    }
+
+    /// Stop previewing, cancelling the run in flight
+    pub fn clear(&mut self) {
+        if self.requested.take().is_some() {
+            self.cancel();
+        }
+    }
+
+    fn cancel(&mut self) {
+        self.cancel_token.cancel();
+        self.cancel_token = CancellationToken::new();
+        self.cache
+            .retain(|_, output| !matches!(output, PreviewOutput::Pending));
+    }
+
+    /// Program of the selected suggestion, if any
+    pub fn program(&self) -> Option<&str> {
+        self.requested.as_ref().map(|(program, _)| program.as_str())
+    }
+
+    /// Output for the selected suggestion, once its run has started
+    pub fn output(&self) -> Option<&PreviewOutput> {
+        self.cache.get(self.program()?)
+    }
+
+    /// Select `program` with `output` already produced (test helper)
+    #[cfg(test)]
+    pub fn set_output(&mut self, program: &str, output: PreviewOutput) {
+        self.requested = Some((program.to_string(), Instant::now()));
+        self.cache.insert(program.to_string(), output);
+    }
+
+    /// Start the selected program once it has been selected for `PREVIEW_DELAY`
+    ///
+    /// Runs in a background thread; call `poll_responses` to collect the
+    /// output. Returns true if a run was started.
+    pub fn run_if_due(&mut self, query_state: &QueryState) -> bool {
+        let (Some((program, selected_at)), Some(input)) = (&self.requested, &self.input) else {
+            return false;
+        };
+        if selected_at.elapsed() < PREVIEW_DELAY || self.cache.contains_key(program) {
+            return false;
+        }
+
+        let program = program.clone();
+        let input = Arc::clone(input);
+        let variables = query_state.variables();
+        let library = query_state.library();
+        self.cache.insert(program.clone(), PreviewOutput::Pending);
+
+        let tx = self.response_tx.clone();
+        let cancel_token = self.cancel_token.clone();
+        std::thread::spawn(move || {
+            let result =
+                JqExecutor::execute_on_input(&input, &program, &variables, &library, &cancel_token)
+                    .map_err(|e| e.to_string());
+            if !cancel_token.is_cancelled() {
+                let _ = tx.send((program, result));
+            }
+        });
+        true
+    }
+
+    /// Store finished preview runs; returns true if any arrived
+    pub fn poll_responses(&mut self) -> bool {
+        let mut updated = false;
+        while let Ok((program, result)) = self.response_rx.try_recv() {
+            // Results from a cancelled run are no longer wanted
+            if !matches!(self.cache.get(&program), Some(PreviewOutput::Pending)) {
+                continue;
+            }
+            let output = match result {
+                Ok(output) => PreviewOutput::Done(JsonHighlighter::highlight(&output)),
+                Err(stderr) => {
+                    PreviewOutput::Failed(parser::parse(&stderr, &program).display_text())
+                }
+            };
+            self.cache.insert(program, output);
+            updated = true;
+        }
+        updated
+    }
+}
+
+/// Program and input that preview `completed`, the query after accepting
+/// a suggestion
+///
+/// When `completed` continues the base query the suggestions came from, only
+/// the continuation runs, against the cached base result. Otherwise the whole
+/// query runs against the original input.
+pub fn preview_target(query_state: &QueryState, completed: &str) -> (String, Arc<String>) {
+    if let (Some(base), Some(base_result)) = (
+        &query_state.base_query_for_suggestions,
+        &query_state.last_successful_result_unformatted,
+    ) && let Some(program) = completed
+        .strip_prefix(base.as_str())
+        .and_then(|rest| continuation_program(base, rest))
+    {
+        return (program, Arc::clone(base_result));
+    }
+    (
+        completed.to_string(),
+        query_state.executor.json_input_shared(),
+    )
+}
+
+/// Program applying `rest` to the output of `base`, when `base` + `rest`
+/// is the same as piping one into the other
+fn continuation_program(base: &str, rest: &str) -> Option<String> {
+    if let Some(after_pipe) = rest.trim_start().strip_prefix('|')
+        && !after_pipe.starts_with('=')
+    {
+        return Some(format!(".|{}", after_pipe));
+    }
+    // A path continued by more path: `.user` + `.name`, `.items` + `[0]`
+    let is_path = |text: &str| {
+        text.chars().all(|c| {
+            c.is_alphanumeric() || matches!(c, '_' | '.' | '[' | ']' | '"' | '-' | ':' | '?')
+        })
+    };
+    if !is_path(base) || !is_path(rest) {
+        return None;
+    }
+    match rest.chars().next()? {
+        '.' => Some(rest.to_string()),
+        '[' => Some(format!(".{}", rest)),
+        _ => None,
+    }
+}
+
+/// Keep the preview in step with the selected suggestion
+///
+/// Call on every tick. Returns true when the preview changed and needs
+/// redrawing.
+pub fn update_preview(app: &mut App) -> bool {
+    let selected = match &app.query {
+        Some(query_state)
+            if query_state.language == QueryLanguage::Jq
+                && app.autocomplete.is_visible()
+                && app.focus == Focus::InputField
+                && app.input.editor_mode == EditorMode::Insert =>
+        {
+            app.autocomplete.selected().map(|suggestion| {
+                let completed = completed_query(&app.input.textarea, query_state, suggestion);
+                preview_target(query_state, &completed)
+            })
+        }
+        _ => None,
+    };
+
+    let Some((program, input)) = selected else {
+        let had_preview = app.suggestion_preview.program().is_some();
+        app.suggestion_preview.clear();
+        return had_preview;
+    };
+
+    app.suggestion_preview.request(program, input);
+    let started = app
+        .query
+        .as_ref()
+        .is_some_and(|query_state| app.suggestion_preview.run_if_due(query_state));
+    let finished = app.suggestion_preview.poll_responses();
+    started || finished
+}
+
+#[cfg(test)]
+#[path = "suggestion_preview_tests.rs"]
+mod suggestion_preview_tests;
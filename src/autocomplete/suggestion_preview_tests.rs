@@ -0,0 +1,178 @@
+//! Tests for the selected suggestion preview
+
+use super::*;
+use crate::autocomplete::{Suggestion, SuggestionType};
+use crate::test_utils::test_helpers::test_app;
+
+fn query_state_with_base(base_query: &str, base_result: &str) -> QueryState {
+    let mut query_state = QueryState::new(r#"{"user": {"name": "alice"}}"#.to_string());
+    query_state.base_query_for_suggestions = Some(base_query.to_string());
+    query_state.last_successful_result_unformatted = Some(Arc::new(base_result.to_string()));
+    query_state
+}
+
+/// Backdate the selection so the preview is due
+fn make_due(preview: &mut SuggestionPreview) {
+    if let Some((_, selected_at)) = &mut preview.requested {
+        *selected_at -= PREVIEW_DELAY;
+    }
+}
+
+fn wait_for_output(preview: &mut SuggestionPreview) {
+    for _ in 0..200 {
+        if preview.poll_responses() {
+            return;
+        }
+        std::thread::sleep(Duration::from_millis(10));
+    }
+    panic!("preview did not finish");
+}
+
+#[test]
+fn test_continuation_of_path_runs_against_base_result() {
+    assert_eq!(
+        continuation_program(".user", ".name"),
+        Some(".name".to_string())
+    );
+    assert_eq!(
+        continuation_program(".items", "[0]"),
+        Some(".[0]".to_string())
+    );
+    assert_eq!(
+        continuation_program(".items", " | keys"),
+        Some(".| keys".to_string())
+    );
+}
+
+#[test]
+fn test_continuation_rejected_when_not_equivalent() {
+    // `.user` + `name` extends the field name rather than continuing the path
+    assert_eq!(continuation_program(".user", "name"), None);
+    // `.a, .b` + `.c` only applies `.c` to `.b`
+    assert_eq!(continuation_program(".a, .b", ".c"), None);
+    assert_eq!(continuation_program(".a", ".b, .c"), None);
+    assert_eq!(continuation_program(".a", " and .b"), None);
+    assert_eq!(continuation_program(".a", " |= 1"), None);
+}
+
+#[test]
+fn test_preview_target_uses_base_result() {
+    let query_state = query_state_with_base(".user", r#"{"name":"alice"}"#);
+
+    let (program, input) = preview_target(&query_state, ".user.name");
+
+    assert_eq!(program, ".name");
+    assert_eq!(input.as_str(), r#"{"name":"alice"}"#);
+}
+
+#[test]
+fn test_preview_target_falls_back_to_original_input() {
+    let query_state = query_state_with_base(".user", r#"{"name":"alice"}"#);
+
+    let (program, input) = preview_target(&query_state, ".other | keys");
+
+    assert_eq!(program, ".other | keys");
+    assert_eq!(input.as_str(), r#"{"user": {"name": "alice"}}"#);
+}
+
+#[test]
+fn test_preview_waits_for_delay() {
+    let query_state = query_state_with_base(".", "{}");
+    let mut preview = SuggestionPreview::new();
+
+    preview.request(".a".to_string(), Arc::new("{}".to_string()));
+
+    assert!(!preview.run_if_due(&query_state));
+    assert!(preview.output().is_none());
+}
+
+#[test]
+fn test_rerequesting_same_program_keeps_selection_time() {
+    let input = Arc::new("{}".to_string());
+    let mut preview = SuggestionPreview::new();
+    preview.request(".a".to_string(), Arc::clone(&input));
+    make_due(&mut preview);
+
+    preview.request(".a".to_string(), Arc::clone(&input));
+    assert!(preview.requested.as_ref().unwrap().1.elapsed() >= PREVIEW_DELAY);
+
+    preview.request(".b".to_string(), input);
+    assert!(preview.requested.as_ref().unwrap().1.elapsed() < PREVIEW_DELAY);
+}
+
+#[test]
+fn test_due_preview_runs_and_caches_output() {
+    let query_state = query_state_with_base(".", "{}");
+    let input = Arc::new(r#"{"a": 1}"#.to_string());
+    let mut preview = SuggestionPreview::new();
+    preview.request(".a".to_string(), Arc::clone(&input));
+    make_due(&mut preview);
+
+    assert!(preview.run_if_due(&query_state));
+    assert!(matches!(preview.output(), Some(PreviewOutput::Pending)));
+    wait_for_output(&mut preview);
+
+    match preview.output() {
+        Some(PreviewOutput::Done(text)) => assert!(text.to_string().contains('1')),
+        other => panic!("unexpected output {other:?}"),
+    }
+    // Already cached, so it isn't run again
+    assert!(!preview.run_if_due(&query_state));
+}
+
+#[test]
+fn test_failed_preview_keeps_error() {
+    let query_state = query_state_with_base(".", "{}");
+    let mut preview = SuggestionPreview::new();
+    preview.request(".a | map(".to_string(), Arc::new("{}".to_string()));
+    make_due(&mut preview);
+
+    assert!(preview.run_if_due(&query_state));
+    wait_for_output(&mut preview);
+
+    assert!(matches!(preview.output(), Some(PreviewOutput::Failed(_))));
+}
+
+#[test]
+fn test_new_input_clears_cache() {
+    let mut preview = SuggestionPreview::new();
+    preview.request(".a".to_string(), Arc::new("{}".to_string()));
+    preview.set_output(".a", PreviewOutput::Done(Text::raw("1")));
+
+    preview.request(".a".to_string(), Arc::new("{}".to_string()));
+
+    assert!(preview.output().is_none());
+}
+
+#[test]
+fn test_clear_drops_selection() {
+    let mut preview = SuggestionPreview::new();
+    preview.set_output(".a", PreviewOutput::Done(Text::raw("1")));
+
+    preview.clear();
+
+    assert!(preview.program().is_none());
+    assert!(preview.output().is_none());
+}
+
+#[test]
+fn test_update_preview_follows_selected_suggestion() {
+    let mut app = test_app(r#"{"user": {"name": "alice"}}"#);
+    app.input.textarea.insert_str(".us");
+    app.autocomplete
+        .update_suggestions(vec![Suggestion::new("user", SuggestionType::Field)]);
+
+    update_preview(&mut app);
+
+    assert_eq!(app.suggestion_preview.program(), Some(".user"));
+}
+
+#[test]
+fn test_update_preview_clears_when_autocomplete_hidden() {
+    let mut app = test_app(r#"{"user": {"name": "alice"}}"#);
+    app.suggestion_preview
+        .set_output(".user", PreviewOutput::Done(Text::raw("{}")));
+
+    assert!(update_preview(&mut app));
+    assert!(app.suggestion_preview.program().is_none());
+}
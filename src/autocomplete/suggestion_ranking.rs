@@ -0,0 +1,105 @@
+//! Suggestions ranked by how often they were accepted
+//!
+//! Every accepted suggestion is counted per input shape, so that documents
+//! with the same structure (e.g. responses of the same API) share counts
+//! while unrelated documents do not. Suggestions accepted before move to
+//! the top of the list, most accepted first; the rest keep their order.
+//! Counts are saved in `jiq/ranking/` under the data directory, next to
+//! the query history.
+
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use super::autocomplete_state::Suggestion;
+use crate::bookmarks::bookmark_storage::fnv1a;
+
+const RANKING_DIR: &str = "ranking";
+
+/// Most suggestions counted per input shape; the least accepted are dropped
+const MAX_COUNTED: usize = 200;
+
+/// Acceptance counts for the suggestions of one input shape
+#[derive(Debug, Default)]
+pub struct SuggestionRanking {
+    counts: HashMap<String, u32>,
+    /// File the counts are saved to; counts stay in memory without one
+    storage_path: Option<PathBuf>,
+}
+
+/// Key identifying documents of the same shape, from their JSON schema
+///
+/// A hash that stays the same across jiq versions and platforms.
+pub fn shape_key(schema: &str) -> String {
+    format!("{:016x}", fnv1a(&[b"shape:", schema.as_bytes()]))
+}
+
+pub fn ranking_path(key: &str) -> Option<PathBuf> {
+    crate::paths::state_dir().map(|dir| dir.join(RANKING_DIR).join(format!("{}.json", key)))
+}
+
+impl SuggestionRanking {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Load the counts saved in `path` and save changes back to it
+    pub fn load_from(&mut self, path: PathBuf) {
+        self.counts = load_counts(&path);
+        self.storage_path = Some(path);
+    }
+
+    pub fn count(&self, text: &str) -> u32 {
+        self.counts.get(text).copied().unwrap_or(0)
+    }
+
+    /// Count an accepted suggestion
+    pub fn record(&mut self, text: &str) {
+        *self.counts.entry(text.to_string()).or_insert(0) += 1;
+        if self.counts.len() > MAX_COUNTED
+            && let Some(least) = self
+                .counts
+                .iter()
+                .filter(|(counted, _)| counted.as_str() != text)
+                .min_by(|(a, a_count), (b, b_count)| a_count.cmp(b_count).then_with(|| b.cmp(a)))
+                .map(|(counted, _)| counted.clone())
+        {
+            self.counts.remove(&least);
+        }
+
+        if let Some(path) = &self.storage_path {
+            // Ranking is a convenience; failing to save it is not worth an error
+            let _ = save_counts(path, &self.counts);
+        }
+    }
+
+    /// Move accepted suggestions to the top, most accepted first
+    pub fn rank(&self, suggestions: &mut [Suggestion]) {
+        if self.counts.is_empty() {
+            return;
+        }
+        // Stable, so suggestions never accepted keep their order
+        suggestions.sort_by_key(|suggestion| Reverse(self.count(&suggestion.text)));
+    }
+}
+
+fn load_counts(path: &Path) -> HashMap<String, u32> {
+    fs::read_to_string(path)
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+fn save_counts(path: &Path, counts: &HashMap<String, u32>) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let content = serde_json::to_string(counts).map_err(io::Error::other)?;
+    fs::write(path, content)
+}
+
+#[cfg(test)]
+#[path = "suggestion_ranking_tests.rs"]
+mod suggestion_ranking_tests;
@@ -0,0 +1,112 @@
+//! Tests for suggestion_ranking
+
+use super::*;
+use crate::autocomplete::SuggestionType;
+use crate::test_utils::test_helpers::app_with_query;
+use tempfile::TempDir;
+
+fn texts(suggestions: &[Suggestion]) -> Vec<&str> {
+    suggestions.iter().map(|s| s.text.as_str()).collect()
+}
+
+fn fields(names: &[&str]) -> Vec<Suggestion> {
+    names
+        .iter()
+        .map(|name| Suggestion::new(*name, SuggestionType::Field))
+        .collect()
+}
+
+#[test]
+fn test_accepted_suggestions_move_to_top() {
+    let mut ranking = SuggestionRanking::new();
+    ranking.record("name");
+    ranking.record("zone");
+    ranking.record("zone");
+
+    let mut suggestions = fields(&["age", "city", "name", "zone"]);
+    ranking.rank(&mut suggestions);
+
+    assert_eq!(texts(&suggestions), ["zone", "name", "age", "city"]);
+}
+
+#[test]
+fn test_order_kept_without_counts() {
+    let ranking = SuggestionRanking::new();
+    let mut suggestions = fields(&["b", "a", "c"]);
+
+    ranking.rank(&mut suggestions);
+
+    assert_eq!(texts(&suggestions), ["b", "a", "c"]);
+}
+
+#[test]
+fn test_least_accepted_dropped_past_limit() {
+    let mut ranking = SuggestionRanking::new();
+    ranking.record("kept");
+    ranking.record("kept");
+    for i in 0..MAX_COUNTED {
+        ranking.record(&format!("field{:03}", i));
+    }
+
+    assert_eq!(ranking.counts.len(), MAX_COUNTED);
+    assert_eq!(ranking.count("kept"), 2);
+    assert_eq!(ranking.count(&format!("field{:03}", MAX_COUNTED - 2)), 0);
+    assert_eq!(ranking.count(&format!("field{:03}", MAX_COUNTED - 1)), 1);
+}
+
+#[test]
+fn test_counts_saved_and_loaded() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("ranking").join("shape.json");
+
+    let mut ranking = SuggestionRanking::new();
+    ranking.load_from(path.clone());
+    ranking.record("name");
+    ranking.record("name");
+
+    let mut loaded = SuggestionRanking::new();
+    loaded.load_from(path);
+    assert_eq!(loaded.count("name"), 2);
+}
+
+#[test]
+fn test_unreadable_file_starts_empty() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("shape.json");
+    fs::write(&path, "not json").unwrap();
+
+    let mut ranking = SuggestionRanking::new();
+    ranking.load_from(path);
+
+    assert_eq!(ranking.count("name"), 0);
+}
+
+#[test]
+fn test_shape_key_is_stable() {
+    let schema = r#"{"name":"string"}"#;
+    assert_eq!(shape_key(schema), shape_key(schema));
+    assert_eq!(shape_key(schema).len(), 16);
+    assert_ne!(shape_key(schema), shape_key(r#"{"age":"number"}"#));
+}
+
+#[test]
+fn test_accepting_a_suggestion_ranks_it_first() {
+    let mut app = app_with_query(".");
+    app.update_autocomplete();
+    let last = app
+        .autocomplete
+        .suggestions()
+        .last()
+        .expect("suggestions for .")
+        .clone();
+
+    app.insert_autocomplete_suggestion(&last);
+    assert_eq!(app.suggestion_ranking.count(&last.text), 1);
+
+    app.input.textarea.delete_line_by_head();
+    app.input.textarea.delete_line_by_end();
+    app.input.textarea.insert_str(".");
+    app.update_autocomplete();
+
+    assert_eq!(app.autocomplete.suggestions()[0].text, last.text);
+}
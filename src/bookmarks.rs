@@ -0,0 +1,7 @@
+pub mod bookmark_events;
+pub mod bookmark_render;
+mod bookmark_state;
+pub mod bookmark_storage;
+
+#[allow(unused_imports)]
+pub use bookmark_state::{Bookmark, BookmarkMode, BookmarkState};
@@ -0,0 +1,113 @@
+use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use tui_textarea::Input;
+
+use super::bookmark_state::BookmarkMode;
+use crate::app::App;
+use crate::editor::editor_events;
+use crate::results::line_path;
+
+pub fn handle_bookmark_key(app: &mut App, key: KeyEvent) {
+    match app.bookmarks.mode() {
+        BookmarkMode::Browse => handle_browse_mode(app, key),
+        BookmarkMode::Create { .. } => handle_create_mode(app, key),
+    }
+}
+
+fn is_toggle_key(key: &KeyEvent) -> bool {
+    key.code == KeyCode::Char('b') && key.modifiers.contains(KeyModifiers::ALT)
+}
+
+fn handle_browse_mode(app: &mut App, key: KeyEvent) {
+    if is_toggle_key(&key) {
+        app.bookmarks.close();
+        return;
+    }
+
+    match key.code {
+        KeyCode::Esc => app.bookmarks.close(),
+        KeyCode::Up | KeyCode::Char('k') => app.bookmarks.select_prev(),
+        KeyCode::Down | KeyCode::Char('j') => app.bookmarks.select_next(),
+        KeyCode::Enter => {
+            if let Some(path) = take_selected_path(app) {
+                editor_events::jump_to_path(app, &path);
+            }
+        }
+        KeyCode::Tab => {
+            if let Some(path) = take_selected_path(app) {
+                editor_events::insert_path(app, &path);
+            }
+        }
+        KeyCode::Char('d')
+            if key.modifiers.contains(KeyModifiers::CONTROL) && app.bookmarks.delete_selected() =>
+        {
+            persist(app);
+        }
+        _ => {}
+    }
+}
+
+fn handle_create_mode(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Esc => {
+            app.bookmarks.cancel_create();
+            app.bookmarks.close();
+        }
+        KeyCode::Enter => {
+            if let Some(path) = app.bookmarks.save_create() {
+                app.bookmarks.close();
+                if persist(app) {
+                    app.notification.show(&format!("Bookmarked {}", path));
+                }
+            }
+        }
+        _ => {
+            app.bookmarks.label_textarea_mut().input(Input::from(key));
+        }
+    }
+}
+
+/// Start a bookmark for the value on the results cursor line
+pub fn bookmark_cursor_line(app: &mut App) {
+    let Some(query_state) = &app.query else {
+        return;
+    };
+    let (Some(output), Some(base_query)) = (
+        query_state.last_successful_result_text(),
+        &query_state.base_query_for_suggestions,
+    ) else {
+        app.notification.show_warning("Nothing to bookmark");
+        return;
+    };
+
+    let line = app.results_cursor_result_line();
+    match line_path::path_at_line(&output, line) {
+        Some(at) => {
+            let path = line_path::query_path(base_query, &at);
+            app.bookmarks.start_create(path);
+        }
+        None => app.notification.show_warning("No JSON path on this line"),
+    }
+}
+
+/// Close the sidebar and take the selected bookmark's path
+fn take_selected_path(app: &mut App) -> Option<String> {
+    let path = app.bookmarks.selected_bookmark().map(|b| b.path.clone())?;
+    app.bookmarks.close();
+    Some(path)
+}
+
+/// Save the bookmarks, reporting failures; returns true on success
+fn persist(app: &mut App) -> bool {
+    match app.bookmarks.persist() {
+        Ok(()) => true,
+        Err(e) => {
+            app.notification
+                .show_error(&format!("Failed to save bookmarks: {}", e));
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "bookmark_events_tests.rs"]
+mod bookmark_events_tests;
@@ -0,0 +1,139 @@
+//! Tests for bookmarks sidebar key handling
+
+use crate::app::{App, Focus};
+use crate::bookmarks::{Bookmark, BookmarkMode};
+use crate::test_utils::test_helpers::{
+    app_with_query, key, key_with_mods, wait_for_query_completion,
+};
+use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+fn alt_b() -> KeyEvent {
+    key_with_mods(KeyCode::Char('b'), KeyModifiers::ALT)
+}
+
+fn type_text(app: &mut App, text: &str) {
+    for c in text.chars() {
+        app.handle_key_event(key(KeyCode::Char(c)));
+    }
+}
+
+fn result_text(app: &App) -> String {
+    app.query.as_ref().unwrap().result.clone().unwrap()
+}
+
+fn bookmark_line(app: &mut App, line: u32) {
+    app.focus = Focus::ResultsPane;
+    app.results_cursor.update_total_lines(100);
+    app.results_cursor.move_to_line(line);
+    app.handle_key_event(key(KeyCode::Char('m')));
+}
+
+fn app_with_bookmarks(paths: &[&str]) -> App {
+    let mut app = app_with_query(".");
+    for path in paths {
+        app.bookmarks.start_create(path.to_string());
+        app.bookmarks.save_create();
+    }
+    app.bookmarks.open();
+    app
+}
+
+#[test]
+fn test_m_bookmarks_cursor_line_with_label() {
+    let mut app = app_with_query(".");
+    bookmark_line(&mut app, 2);
+
+    assert_eq!(
+        app.bookmarks.mode(),
+        &BookmarkMode::Create {
+            path: ".age".to_string()
+        }
+    );
+    type_text(&mut app, "Age");
+    app.handle_key_event(key(KeyCode::Enter));
+
+    assert!(!app.bookmarks.is_visible());
+    assert_eq!(
+        app.bookmarks.bookmarks(),
+        &[Bookmark {
+            label: "Age".to_string(),
+            path: ".age".to_string(),
+        }]
+    );
+    assert_eq!(app.notification.current_message(), Some("Bookmarked .age"));
+}
+
+#[test]
+fn test_bookmark_path_includes_query_path() {
+    let mut app = app_with_query(".services");
+    bookmark_line(&mut app, 2);
+
+    assert_eq!(
+        app.bookmarks.mode(),
+        &BookmarkMode::Create {
+            path: ".services[0].name".to_string()
+        }
+    );
+}
+
+#[test]
+fn test_esc_cancels_bookmark() {
+    let mut app = app_with_query(".");
+    bookmark_line(&mut app, 1);
+    app.handle_key_event(key(KeyCode::Esc));
+
+    assert!(!app.bookmarks.is_visible());
+    assert!(app.bookmarks.bookmarks().is_empty());
+}
+
+#[test]
+fn test_alt_b_toggles_sidebar() {
+    let mut app = app_with_query(".");
+    app.handle_key_event(alt_b());
+    assert!(app.bookmarks.is_visible());
+
+    app.handle_key_event(alt_b());
+    assert!(!app.bookmarks.is_visible());
+
+    app.handle_key_event(alt_b());
+    app.handle_key_event(key(KeyCode::Esc));
+    assert!(!app.bookmarks.is_visible());
+}
+
+#[test]
+fn test_enter_jumps_to_bookmark() {
+    let mut app = app_with_bookmarks(&[".age", ".services[0].name"]);
+    app.handle_key_event(key(KeyCode::Enter));
+
+    assert!(!app.bookmarks.is_visible());
+    assert_eq!(app.query(), ".services[0].name");
+    assert!(wait_for_query_completion(&mut app, 5000));
+    assert!(result_text(&app).contains("svc1"));
+}
+
+#[test]
+fn test_tab_inserts_bookmark_into_query() {
+    let mut app = app_with_bookmarks(&[".city"]);
+    app.input.textarea.delete_line_by_head();
+    app.input.textarea.insert_str("[] | ");
+    app.input
+        .textarea
+        .move_cursor(tui_textarea::CursorMove::Head);
+    app.focus = Focus::ResultsPane;
+
+    app.handle_key_event(key(KeyCode::Tab));
+
+    assert!(!app.bookmarks.is_visible());
+    assert_eq!(app.focus, Focus::InputField);
+    assert_eq!(app.query(), ".city[] | ");
+}
+
+#[test]
+fn test_ctrl_d_deletes_selected_bookmark() {
+    let mut app = app_with_bookmarks(&[".age", ".city"]);
+    app.handle_key_event(key(KeyCode::Char('k')));
+    app.handle_key_event(key_with_mods(KeyCode::Char('d'), KeyModifiers::CONTROL));
+
+    assert_eq!(app.bookmarks.bookmarks().len(), 1);
+    assert_eq!(app.bookmarks.bookmarks()[0].path, ".city");
+}
@@ -0,0 +1,151 @@
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Paragraph},
+};
+
+use super::bookmark_state::{BookmarkMode, BookmarkState};
+use crate::theme;
+use crate::widgets::popup;
+
+const FIELD_HEIGHT: u16 = 3;
+const MIN_SIDEBAR_WIDTH: u16 = 56;
+
+fn build_browse_hints() -> Line<'static> {
+    theme::border_hints::build_hints(
+        &[
+            ("Enter", "Jump"),
+            ("Tab", "Insert"),
+            ("Ctrl+D", "Delete"),
+            ("Esc", "Close"),
+        ],
+        theme::bookmarks::BORDER,
+    )
+}
+
+fn build_create_hints() -> Line<'static> {
+    theme::border_hints::build_hints(
+        &[("Enter", "Save"), ("Esc", "Cancel")],
+        theme::bookmarks::FIELD_BORDER,
+    )
+}
+
+/// Area of the sidebar along the right edge of the results pane
+pub fn sidebar_area(results_area: Rect) -> Rect {
+    let width = (results_area.width * 2 / 5)
+        .max(MIN_SIDEBAR_WIDTH)
+        .min(results_area.width);
+    Rect {
+        x: results_area.right() - width,
+        width,
+        ..results_area
+    }
+}
+
+/// Render the bookmarks sidebar over the right part of the results pane
+pub fn render_popup(state: &mut BookmarkState, frame: &mut Frame, results_area: Rect) {
+    let area = sidebar_area(results_area);
+    popup::clear_area(frame, area);
+
+    let path = match state.mode() {
+        BookmarkMode::Browse => {
+            render_list(state, true, frame, area);
+            return;
+        }
+        BookmarkMode::Create { path } => path.clone(),
+    };
+
+    let layout =
+        Layout::vertical([Constraint::Min(0), Constraint::Length(FIELD_HEIGHT)]).split(area);
+    render_list(state, false, frame, layout[0]);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(format!(" Label for {} ", path))
+        .title_bottom(build_create_hints().alignment(Alignment::Center))
+        .border_style(Style::default().fg(theme::bookmarks::FIELD_BORDER))
+        .style(Style::default().bg(theme::bookmarks::BACKGROUND));
+    let textarea = state.label_textarea_mut();
+    textarea.set_block(block);
+    textarea.set_style(
+        Style::default()
+            .fg(theme::bookmarks::FIELD_TEXT)
+            .bg(theme::bookmarks::BACKGROUND),
+    );
+    textarea.set_cursor_style(Style::default().add_modifier(Modifier::REVERSED));
+    frame.render_widget(&*textarea, layout[1]);
+}
+
+fn render_list(state: &BookmarkState, show_hints: bool, frame: &mut Frame, area: Rect) {
+    if area.height == 0 {
+        return;
+    }
+
+    // Each bookmark takes two lines: its label and its path
+    let visible = (area.height.saturating_sub(2) / 2) as usize;
+    let first = (state.selected() + 1).saturating_sub(visible.max(1));
+
+    let lines: Vec<Line> = if state.bookmarks().is_empty() {
+        vec![
+            Line::from(Span::styled(
+                " No bookmarks yet",
+                Style::default().fg(theme::bookmarks::EMPTY),
+            )),
+            Line::from(Span::styled(
+                " Press m on a result line to add one",
+                Style::default().fg(theme::bookmarks::EMPTY),
+            )),
+        ]
+    } else {
+        state
+            .bookmarks()
+            .iter()
+            .enumerate()
+            .skip(first)
+            .take(visible)
+            .flat_map(|(index, bookmark)| {
+                let is_selected = show_hints && index == state.selected();
+                let indicator = if is_selected { "▸ " } else { "  " };
+                let mut label = Line::from(vec![
+                    Span::styled(
+                        indicator,
+                        Style::default().fg(theme::bookmarks::ITEM_SELECTED_INDICATOR),
+                    ),
+                    Span::styled(
+                        bookmark.label.clone(),
+                        Style::default().fg(theme::bookmarks::LABEL),
+                    ),
+                ]);
+                let mut path = Line::from(Span::styled(
+                    format!("    {}", bookmark.path),
+                    Style::default().fg(theme::bookmarks::PATH),
+                ));
+                if is_selected {
+                    let highlight = Style::default().bg(theme::bookmarks::ITEM_SELECTED_BG);
+                    label = label.style(highlight);
+                    path = path.style(highlight);
+                }
+                [label, path]
+            })
+            .collect()
+    };
+
+    let mut block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(format!(" Bookmarks ({}) ", state.bookmarks().len()))
+        .border_style(Style::default().fg(theme::bookmarks::BORDER))
+        .style(Style::default().bg(theme::bookmarks::BACKGROUND));
+    if show_hints {
+        block = block.title_bottom(build_browse_hints().alignment(Alignment::Center));
+    }
+
+    frame.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+#[cfg(test)]
+#[path = "bookmark_render_tests.rs"]
+mod bookmark_render_tests;
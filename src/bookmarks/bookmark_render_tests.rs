@@ -0,0 +1,61 @@
+//! Tests for bookmarks sidebar rendering
+
+use super::*;
+use insta::assert_snapshot;
+use ratatui::Terminal;
+use ratatui::backend::TestBackend;
+
+fn render_to_string(state: &mut BookmarkState, width: u16, height: u16) -> String {
+    let backend = TestBackend::new(width, height);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal.draw(|f| render_popup(state, f, f.area())).unwrap();
+    terminal.backend().to_string()
+}
+
+fn sample_state() -> BookmarkState {
+    let mut state = BookmarkState::new();
+    for (label, path) in [
+        ("First port", ".spec.ports[0]"),
+        ("Image", ".spec.containers[0].image"),
+    ] {
+        state.start_create(path.to_string());
+        state.label_textarea_mut().insert_str(label);
+        state.save_create();
+    }
+    state.open();
+    state
+}
+
+#[test]
+fn test_sidebar_takes_right_part_of_results() {
+    let area = sidebar_area(Rect::new(0, 0, 150, 20));
+    assert_eq!(area, Rect::new(90, 0, 60, 20));
+
+    let medium = sidebar_area(Rect::new(0, 0, 100, 20));
+    assert_eq!(medium, Rect::new(44, 0, 56, 20));
+
+    let narrow = sidebar_area(Rect::new(0, 0, 40, 20));
+    assert_eq!(narrow, Rect::new(0, 0, 40, 20));
+}
+
+#[test]
+fn snapshot_bookmarks_browse() {
+    let mut state = sample_state();
+    state.select_prev();
+    assert_snapshot!(render_to_string(&mut state, 90, 10));
+}
+
+#[test]
+fn snapshot_bookmarks_create() {
+    let mut state = sample_state();
+    state.start_create(".metadata.name".to_string());
+    state.label_textarea_mut().insert_str("Pod name");
+    assert_snapshot!(render_to_string(&mut state, 90, 12));
+}
+
+#[test]
+fn snapshot_bookmarks_empty() {
+    let mut state = BookmarkState::new();
+    state.open();
+    assert_snapshot!(render_to_string(&mut state, 90, 8));
+}
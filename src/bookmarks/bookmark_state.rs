@@ -0,0 +1,179 @@
+use std::io;
+use std::path::PathBuf;
+
+use ratatui::style::{Modifier, Style};
+use serde::{Deserialize, Serialize};
+use tui_textarea::TextArea;
+
+use super::bookmark_storage;
+
+/// Labelled jq path into the input document
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Bookmark {
+    pub label: String,
+    pub path: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum BookmarkMode {
+    #[default]
+    Browse,
+    /// Entering the label of a bookmark for `path`
+    Create { path: String },
+}
+
+fn create_label_textarea() -> TextArea<'static> {
+    let mut textarea = TextArea::default();
+    textarea.set_cursor_line_style(Style::default());
+    textarea.set_cursor_style(Style::default().add_modifier(Modifier::REVERSED));
+    textarea
+}
+
+/// Bookmarks sidebar: paths saved while exploring the current document
+pub struct BookmarkState {
+    visible: bool,
+    mode: BookmarkMode,
+    bookmarks: Vec<Bookmark>,
+    selected: usize,
+    label_textarea: TextArea<'static>,
+    /// File the bookmarks are saved to, `None` to keep them in memory
+    storage_path: Option<PathBuf>,
+}
+
+impl Default for BookmarkState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BookmarkState {
+    pub fn new() -> Self {
+        Self {
+            visible: false,
+            mode: BookmarkMode::Browse,
+            bookmarks: Vec::new(),
+            selected: 0,
+            label_textarea: create_label_textarea(),
+            storage_path: None,
+        }
+    }
+
+    /// Load the bookmarks saved in `path` and save changes back to it
+    pub fn load_from(&mut self, path: PathBuf) {
+        self.bookmarks = bookmark_storage::load_bookmarks_from_path(&path);
+        self.selected = 0;
+        self.storage_path = Some(path);
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    pub fn open(&mut self) {
+        self.visible = true;
+        self.mode = BookmarkMode::Browse;
+    }
+
+    pub fn close(&mut self) {
+        self.visible = false;
+        self.mode = BookmarkMode::Browse;
+    }
+
+    pub fn mode(&self) -> &BookmarkMode {
+        &self.mode
+    }
+
+    pub fn bookmarks(&self) -> &[Bookmark] {
+        &self.bookmarks
+    }
+
+    pub fn selected(&self) -> usize {
+        self.selected
+    }
+
+    pub fn selected_bookmark(&self) -> Option<&Bookmark> {
+        self.bookmarks.get(self.selected)
+    }
+
+    pub fn select_next(&mut self) {
+        if self.selected + 1 < self.bookmarks.len() {
+            self.selected += 1;
+        }
+    }
+
+    pub fn select_prev(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    /// Open the sidebar to label a new bookmark for `path`
+    pub fn start_create(&mut self, path: String) {
+        self.label_textarea = create_label_textarea();
+        if let Some(existing) = self.bookmarks.iter().find(|b| b.path == path) {
+            self.label_textarea.insert_str(&existing.label);
+        }
+        self.visible = true;
+        self.mode = BookmarkMode::Create { path };
+    }
+
+    pub fn cancel_create(&mut self) {
+        self.mode = BookmarkMode::Browse;
+    }
+
+    pub fn label_textarea_mut(&mut self) -> &mut TextArea<'static> {
+        &mut self.label_textarea
+    }
+
+    /// Store the bookmark being created and return its path
+    ///
+    /// An empty label falls back to the path. Bookmarking a path again
+    /// relabels the existing bookmark.
+    pub fn save_create(&mut self) -> Option<String> {
+        let BookmarkMode::Create { path } = std::mem::take(&mut self.mode) else {
+            return None;
+        };
+
+        let label = self.label_textarea.lines()[0].trim();
+        let label = if label.is_empty() {
+            path.clone()
+        } else {
+            label.to_string()
+        };
+
+        match self.bookmarks.iter().position(|b| b.path == path) {
+            Some(index) => {
+                self.bookmarks[index].label = label;
+                self.selected = index;
+            }
+            None => {
+                self.bookmarks.push(Bookmark {
+                    label,
+                    path: path.clone(),
+                });
+                self.selected = self.bookmarks.len() - 1;
+            }
+        }
+        Some(path)
+    }
+
+    /// Remove the selected bookmark; returns true if one was removed
+    pub fn delete_selected(&mut self) -> bool {
+        if self.selected >= self.bookmarks.len() {
+            return false;
+        }
+        self.bookmarks.remove(self.selected);
+        self.selected = self.selected.min(self.bookmarks.len().saturating_sub(1));
+        true
+    }
+
+    /// Save the bookmarks to the file they were loaded from, if any
+    pub fn persist(&self) -> io::Result<()> {
+        match &self.storage_path {
+            Some(path) => bookmark_storage::save_bookmarks_to_path(path, &self.bookmarks),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "bookmark_state_tests.rs"]
+mod bookmark_state_tests;
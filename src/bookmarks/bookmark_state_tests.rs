@@ -0,0 +1,119 @@
+//! Tests for bookmark state
+
+use super::*;
+use tempfile::TempDir;
+
+fn type_label(state: &mut BookmarkState, label: &str) {
+    state.label_textarea_mut().insert_str(label);
+}
+
+#[test]
+fn test_new_state_is_hidden_and_empty() {
+    let state = BookmarkState::new();
+    assert!(!state.is_visible());
+    assert!(state.bookmarks().is_empty());
+    assert_eq!(state.mode(), &BookmarkMode::Browse);
+}
+
+#[test]
+fn test_create_bookmark_with_label() {
+    let mut state = BookmarkState::new();
+    state.start_create(".spec.ports[0]".to_string());
+    assert!(state.is_visible());
+    type_label(&mut state, "First port");
+
+    assert_eq!(state.save_create().as_deref(), Some(".spec.ports[0]"));
+
+    assert_eq!(
+        state.bookmarks(),
+        &[Bookmark {
+            label: "First port".to_string(),
+            path: ".spec.ports[0]".to_string(),
+        }]
+    );
+    assert_eq!(state.mode(), &BookmarkMode::Browse);
+}
+
+#[test]
+fn test_empty_label_falls_back_to_path() {
+    let mut state = BookmarkState::new();
+    state.start_create(".name".to_string());
+    type_label(&mut state, "   ");
+    state.save_create();
+
+    assert_eq!(state.bookmarks()[0].label, ".name");
+}
+
+#[test]
+fn test_bookmarking_path_again_relabels_it() {
+    let mut state = BookmarkState::new();
+    state.start_create(".a".to_string());
+    type_label(&mut state, "old");
+    state.save_create();
+    state.start_create(".b".to_string());
+    state.save_create();
+
+    state.start_create(".a".to_string());
+    assert_eq!(state.label_textarea_mut().lines()[0], "old");
+    state.label_textarea_mut().delete_line_by_head();
+    type_label(&mut state, "new");
+    state.save_create();
+
+    assert_eq!(state.bookmarks().len(), 2);
+    assert_eq!(state.bookmarks()[0].label, "new");
+    assert_eq!(state.selected(), 0);
+}
+
+#[test]
+fn test_save_outside_create_mode_does_nothing() {
+    let mut state = BookmarkState::new();
+    assert_eq!(state.save_create(), None);
+    assert!(state.bookmarks().is_empty());
+}
+
+#[test]
+fn test_selection_and_delete() {
+    let mut state = BookmarkState::new();
+    for path in [".a", ".b", ".c"] {
+        state.start_create(path.to_string());
+        state.save_create();
+    }
+    assert_eq!(state.selected(), 2);
+
+    state.select_next();
+    assert_eq!(state.selected(), 2);
+    state.select_prev();
+    assert_eq!(state.selected_bookmark().unwrap().path, ".b");
+
+    assert!(state.delete_selected());
+    assert_eq!(state.bookmarks().len(), 2);
+    assert_eq!(state.selected_bookmark().unwrap().path, ".c");
+
+    assert!(state.delete_selected());
+    assert!(state.delete_selected());
+    assert!(!state.delete_selected());
+}
+
+#[test]
+fn test_persist_writes_to_loaded_path() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("doc.toml");
+
+    let mut state = BookmarkState::new();
+    state.load_from(path.clone());
+    state.start_create(".name".to_string());
+    state.save_create();
+    state.persist().unwrap();
+
+    let mut reloaded = BookmarkState::new();
+    reloaded.load_from(path);
+    assert_eq!(reloaded.bookmarks(), state.bookmarks());
+}
+
+#[test]
+fn test_persist_without_storage_path_keeps_bookmarks_in_memory() {
+    let mut state = BookmarkState::new();
+    state.start_create(".name".to_string());
+    state.save_create();
+    assert!(state.persist().is_ok());
+}
@@ -0,0 +1,96 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use super::bookmark_state::Bookmark;
+use crate::input::command_source::CommandSource;
+
+const BOOKMARKS_DIR: &str = "bookmarks";
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+#[derive(Deserialize, Serialize)]
+struct BookmarksFile {
+    #[serde(default)]
+    bookmarks: Vec<Bookmark>,
+}
+
+/// Key identifying the document bookmarks belong to
+///
+/// Files are identified by their absolute path so bookmarks survive edits,
+/// command input by its command line and stdin by its content. The key is
+/// a hash that stays the same across jiq versions and platforms.
+pub fn document_key(
+    input_path: Option<&Path>,
+    command: Option<&CommandSource>,
+    content: &str,
+) -> String {
+    let hash = match (input_path, command) {
+        (Some(path), _) => {
+            let path = fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+            fnv1a(&[b"file:", path.to_string_lossy().as_bytes()])
+        }
+        (None, Some(command)) => {
+            let command_line = format!("{} {}", command.program, command.args.join(" "));
+            fnv1a(&[b"command:", command_line.as_bytes()])
+        }
+        (None, None) => fnv1a(&[b"stdin:", content.as_bytes()]),
+    };
+    format!("{:016x}", hash)
+}
+
+pub(crate) fn fnv1a(parts: &[&[u8]]) -> u64 {
+    parts
+        .iter()
+        .flat_map(|part| part.iter())
+        .fold(FNV_OFFSET_BASIS, |hash, &byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(FNV_PRIME)
+        })
+}
+
+pub fn bookmarks_path(key: &str) -> Option<PathBuf> {
+    crate::paths::data_dir().map(|p| p.join(BOOKMARKS_DIR).join(format!("{}.toml", key)))
+}
+
+pub fn load_bookmarks_from_path(path: &Path) -> Vec<Bookmark> {
+    match fs::read_to_string(path) {
+        Ok(content) => parse_bookmarks_toml(&content),
+        Err(_) => Vec::new(),
+    }
+}
+
+pub fn parse_bookmarks_toml(content: &str) -> Vec<Bookmark> {
+    match toml::from_str::<BookmarksFile>(content) {
+        Ok(file) => file.bookmarks,
+        Err(_) => Vec::new(),
+    }
+}
+
+/// Write `bookmarks` to `path`, removing the file when there are none left
+pub fn save_bookmarks_to_path(path: &Path, bookmarks: &[Bookmark]) -> io::Result<()> {
+    if bookmarks.is_empty() {
+        return match fs::remove_file(path) {
+            Err(e) if e.kind() != io::ErrorKind::NotFound => Err(e),
+            _ => Ok(()),
+        };
+    }
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, serialize_bookmarks_toml(bookmarks))
+}
+
+pub fn serialize_bookmarks_toml(bookmarks: &[Bookmark]) -> String {
+    let file = BookmarksFile {
+        bookmarks: bookmarks.to_vec(),
+    };
+    toml::to_string_pretty(&file).unwrap_or_default()
+}
+
+#[cfg(test)]
+#[path = "bookmark_storage_tests.rs"]
+mod bookmark_storage_tests;
@@ -0,0 +1,105 @@
+//! Tests for bookmark storage
+
+use super::*;
+use tempfile::TempDir;
+
+fn bookmark(label: &str, path: &str) -> Bookmark {
+    Bookmark {
+        label: label.to_string(),
+        path: path.to_string(),
+    }
+}
+
+#[test]
+fn test_bookmarks_path_is_in_data_directory() {
+    let path = bookmarks_path("0123456789abcdef").unwrap();
+    assert!(path.starts_with(crate::paths::data_dir().unwrap()));
+    assert!(path.ends_with("bookmarks/0123456789abcdef.toml"));
+}
+
+#[test]
+fn test_document_key_is_stable_hex() {
+    let key = document_key(None, None, "{\"a\": 1}");
+    assert_eq!(key.len(), 16);
+    assert!(key.chars().all(|c| c.is_ascii_hexdigit()));
+    assert_eq!(key, document_key(None, None, "{\"a\": 1}"));
+}
+
+#[test]
+fn test_document_key_of_file_ignores_content() {
+    let path = Path::new("/data/cluster.json");
+    assert_eq!(
+        document_key(Some(path), None, "{}"),
+        document_key(Some(path), None, "{\"changed\": true}")
+    );
+    assert_ne!(
+        document_key(Some(path), None, "{}"),
+        document_key(Some(Path::new("/data/other.json")), None, "{}")
+    );
+}
+
+#[test]
+fn test_document_key_of_command_uses_command_line() {
+    let pods = CommandSource::kubectl("pods", &[]);
+    let nodes = CommandSource::kubectl("nodes", &[]);
+    assert_eq!(
+        document_key(None, Some(&pods), "{}"),
+        document_key(None, Some(&pods), "[]")
+    );
+    assert_ne!(
+        document_key(None, Some(&pods), "{}"),
+        document_key(None, Some(&nodes), "{}")
+    );
+}
+
+#[test]
+fn test_document_key_of_stdin_uses_content() {
+    assert_ne!(
+        document_key(None, None, "{\"a\": 1}"),
+        document_key(None, None, "{\"a\": 2}")
+    );
+}
+
+#[test]
+fn test_bookmarks_round_trip_through_toml() {
+    let bookmarks = vec![
+        bookmark("First port", ".spec.ports[0]"),
+        bookmark("Image", ".spec.containers[0].image"),
+    ];
+    let content = serialize_bookmarks_toml(&bookmarks);
+    assert_eq!(parse_bookmarks_toml(&content), bookmarks);
+}
+
+#[test]
+fn test_parse_invalid_toml_returns_no_bookmarks() {
+    assert!(parse_bookmarks_toml("bookmarks = ").is_empty());
+}
+
+#[test]
+fn test_save_and_load_from_path() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("bookmarks").join("key.toml");
+    let bookmarks = vec![bookmark("Name", ".name")];
+
+    save_bookmarks_to_path(&path, &bookmarks).unwrap();
+
+    assert_eq!(load_bookmarks_from_path(&path), bookmarks);
+}
+
+#[test]
+fn test_saving_no_bookmarks_removes_file() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("key.toml");
+    save_bookmarks_to_path(&path, &[bookmark("Name", ".name")]).unwrap();
+
+    save_bookmarks_to_path(&path, &[]).unwrap();
+
+    assert!(!path.exists());
+    save_bookmarks_to_path(&path, &[]).unwrap();
+}
+
+#[test]
+fn test_load_missing_file_returns_no_bookmarks() {
+    let dir = TempDir::new().unwrap();
+    assert!(load_bookmarks_from_path(&dir.path().join("missing.toml")).is_empty());
+}
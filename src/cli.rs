@@ -0,0 +1,398 @@
+//! Command line interface
+//!
+//! Argument definitions plus the `completions` and `man` subcommands, which
+//! generate their output from these definitions so new flags are picked up
+//! automatically.
+
+use std::ffi::OsStr;
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use clap::{ArgAction, CommandFactory, Parser, Subcommand};
+
+use crate::config::{Config, Override, Source, layers};
+use crate::history::HistoryFormat;
+use crate::input::input_format::{CsvOptions, InputDecoder, InputFormat, InputOptions, XmlOptions};
+use crate::plugins::PluginRegistry;
+use crate::query::variables::{QueryVariable, VariableKind};
+use crate::shell;
+
+/// Interactive JSON query tool
+#[derive(Parser, Debug)]
+#[command(
+    name = "jiq",
+    version,
+    about = "Interactive JSON query tool with real-time filtering using jq",
+    args_conflicts_with_subcommands = true
+)]
+pub struct Args {
+    /// Input JSON file (if not provided, reads from stdin)
+    pub input: Option<PathBuf>,
+
+    /// Terminate the final output with a NUL byte instead of a newline
+    #[arg(long)]
+    pub print0: bool,
+
+    /// Write the final output to this inherited file descriptor instead of stdout
+    #[arg(long, value_name = "FD")]
+    pub output_fd: Option<u32>,
+
+    /// Keep reading records appended to the input file, like tail -f
+    #[arg(short = 'f', long, requires = "input")]
+    pub follow: bool,
+
+    /// On Enter, show a diff and write the results back to the input file
+    #[arg(long, requires = "input", conflicts_with = "follow")]
+    pub in_place: bool,
+
+    /// Format of the input (default: from the file extension, else detected)
+    #[arg(long, value_enum, value_name = "FORMAT")]
+    pub input_format: Option<InputFormat>,
+
+    /// CSV field separator, e.g. ';' or 'tab' (default: ',', tab for .tsv)
+    #[arg(long, value_name = "CHAR", value_parser = parse_csv_char)]
+    pub csv_delimiter: Option<char>,
+
+    /// CSV quote character (default: '"')
+    #[arg(long, value_name = "CHAR", value_parser = parse_csv_char)]
+    pub csv_quote: Option<char>,
+
+    /// Convert CSV fields that look like numbers to JSON numbers
+    #[arg(long)]
+    pub csv_numbers: bool,
+
+    /// Format of the results printed on exit: json, toml or an output plugin
+    #[arg(long, value_name = "FORMAT", default_value = "json")]
+    pub output_format: OutputFormat,
+
+    /// Print the command line widget for a shell and exit
+    #[arg(long, value_name = "SHELL")]
+    pub shell_init: Option<shell::Shell>,
+
+    /// Add the queries in another tool's history FILE to jiq's history and exit
+    #[arg(long, value_name = "FILE")]
+    pub import_history: Option<PathBuf>,
+
+    /// Layout of the --import-history file
+    #[arg(
+        long = "format",
+        value_enum,
+        value_name = "FORMAT",
+        default_value = "plain",
+        requires = "import_history"
+    )]
+    pub import_format: HistoryFormat,
+
+    /// Bind $NAME to a string value in queries (like jq --arg); repeatable
+    #[arg(long = "arg", num_args = 2, value_names = ["NAME", "VALUE"], action = ArgAction::Append)]
+    pub string_args: Vec<String>,
+
+    /// Bind $NAME to a JSON value in queries (like jq --argjson); repeatable
+    #[arg(long = "argjson", num_args = 2, value_names = ["NAME", "JSON"], action = ArgAction::Append)]
+    pub json_args: Vec<String>,
+
+    /// Search DIR for jq modules (like jq -L); repeatable
+    #[arg(short = 'L', long = "library-path", value_name = "DIR", action = ArgAction::Append)]
+    pub library_paths: Vec<String>,
+
+    /// Resume a session saved with `:session save NAME`
+    #[arg(long, value_name = "NAME")]
+    pub session: Option<String>,
+
+    /// Open a query shared as a `jiq://` link with `:share`
+    #[arg(long, value_name = "LINK", conflicts_with = "session")]
+    pub open: Option<String>,
+
+    /// Save keystrokes and other terminal input with timestamps to FILE on exit
+    #[arg(long, value_name = "FILE")]
+    pub record: Option<PathBuf>,
+
+    /// Play back input saved with --record instead of reading the keyboard
+    #[arg(long, value_name = "FILE", conflicts_with = "record")]
+    pub replay: Option<PathBuf>,
+
+    /// JSON Schema to check the result against with `:validate`
+    #[arg(long, value_name = "FILE")]
+    pub schema: Option<PathBuf>,
+
+    /// Render without colors and with ASCII borders (also set by NO_COLOR)
+    #[arg(long)]
+    pub no_color: bool,
+
+    /// Screen-reader friendly layout: ASCII borders, one announcement line
+    /// and results read one node at a time
+    #[arg(long)]
+    pub screen_reader: bool,
+
+    /// Append debug logs to FILE (also set by JIQ_LOG_FILE)
+    #[arg(long, value_name = "FILE")]
+    pub log_file: Option<PathBuf>,
+
+    /// Log levels, e.g. 'debug' or 'info,jiq::query=trace' (also set by
+    /// JIQ_LOG; default: jiq=debug)
+    #[arg(long, value_name = "DIRECTIVES")]
+    pub log_level: Option<String>,
+
+    /// Set a config option, e.g. 'display.sparkline=false', over the config
+    /// file and JIQ_<SECTION>__<KEY> variables; repeatable
+    #[arg(long = "set", value_name = "KEY=VALUE", value_parser = layers::parse_set, action = ArgAction::Append)]
+    pub config_overrides: Vec<Override>,
+
+    /// Print the effective config with the source of each value and exit
+    #[arg(long)]
+    pub show_config: bool,
+
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+/// Format of the results printed on exit
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum OutputFormat {
+    /// jq's JSON output
+    #[default]
+    Json,
+    /// Convert each result object to TOML
+    Toml,
+    /// Convert the results with the output plugin of this name
+    Plugin(String),
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "json" => Ok(OutputFormat::Json),
+            "toml" => Ok(OutputFormat::Toml),
+            "" => Err("Output format must not be empty".to_string()),
+            name => Ok(OutputFormat::Plugin(name.to_string())),
+        }
+    }
+}
+
+#[derive(Subcommand, Debug, PartialEq, Eq)]
+pub enum Command {
+    /// Print a shell completion script
+    Completions {
+        #[arg(value_enum)]
+        shell: clap_complete::Shell,
+    },
+    /// Print the man page in roff format
+    Man,
+    /// Create, locate, check or edit the config file
+    Config {
+        #[command(subcommand)]
+        action: ConfigCommand,
+    },
+    /// Explore `kubectl get RESOURCE -o json`
+    #[command(name = "k8s")]
+    K8s {
+        /// Resource type, e.g. pods or deployments
+        resource: String,
+        /// Further kubectl arguments, e.g. -n kube-system
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+    /// Explore `aws SERVICE OPERATION --output json`
+    Aws {
+        /// Service, e.g. ec2
+        service: String,
+        /// Operation, e.g. describe-instances
+        operation: String,
+        /// Further aws arguments, e.g. --region eu-west-1
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+}
+
+/// What `jiq config` does
+#[derive(Subcommand, Debug, PartialEq, Eq)]
+pub enum ConfigCommand {
+    /// Write a commented config with every setting at its default
+    Init {
+        /// Replace an existing config
+        #[arg(long)]
+        force: bool,
+    },
+    /// Print where the config file is
+    Path,
+    /// Check the config file, reporting problems with their line and column
+    Validate,
+    /// Open the config file in $VISUAL or $EDITOR, creating it first if needed
+    Edit,
+}
+
+impl Args {
+    /// Variables given with `--arg` and `--argjson`
+    pub fn variables(&self) -> Result<Vec<QueryVariable>, String> {
+        let string_vars = self
+            .string_args
+            .chunks(2)
+            .map(|pair| (pair, VariableKind::String));
+        let json_vars = self
+            .json_args
+            .chunks(2)
+            .map(|pair| (pair, VariableKind::Json));
+
+        string_vars
+            .chain(json_vars)
+            .map(|(pair, kind)| {
+                let variable = QueryVariable::new(&pair[0], &pair[1], kind);
+                variable.validate()?;
+                Ok(variable)
+            })
+            .collect()
+    }
+
+    /// Whether to render without colors: `--no-color`, `[display] no_color`,
+    /// or a non-empty `NO_COLOR` (`no_color_env`), as https://no-color.org asks
+    pub fn no_color(&self, config: &Config, no_color_env: Option<&OsStr>) -> bool {
+        self.no_color || config.display.no_color || no_color_env.is_some_and(|v| !v.is_empty())
+    }
+
+    /// File to log to: `--log-file` or a non-empty `JIQ_LOG_FILE`
+    /// (`log_file_env`); `None` keeps logging off
+    pub fn log_file(&self, log_file_env: Option<&OsStr>) -> Option<PathBuf> {
+        self.log_file
+            .clone()
+            .or_else(|| log_file_env.filter(|v| !v.is_empty()).map(PathBuf::from))
+    }
+
+    /// Log level directives: `--log-level` or `JIQ_LOG` (`log_env`)
+    pub fn log_level(&self, log_env: Option<&OsStr>) -> Option<String> {
+        self.log_level
+            .clone()
+            .or_else(|| log_env.map(|v| v.to_string_lossy().into_owned()))
+    }
+
+    /// Config overrides given on the command line: the flags for config
+    /// options (`--no-color`, `--screen-reader`, `--csv-*`), then `--set`
+    pub fn config_overrides(&self) -> Vec<Override> {
+        let flag = |name: &str| Source::Flag(name.to_string());
+        let mut overrides = Vec::new();
+        if self.no_color {
+            overrides.push(Override::new(
+                "display.no_color",
+                "true",
+                flag("--no-color"),
+            ));
+        }
+        if self.screen_reader {
+            overrides.push(Override::new(
+                "display.screen_reader",
+                "true",
+                flag("--screen-reader"),
+            ));
+        }
+        if let Some(c) = self.csv_delimiter {
+            overrides.push(Override::new(
+                "csv.delimiter",
+                c.to_string(),
+                flag("--csv-delimiter"),
+            ));
+        }
+        if let Some(c) = self.csv_quote {
+            overrides.push(Override::new(
+                "csv.quote",
+                c.to_string(),
+                flag("--csv-quote"),
+            ));
+        }
+        if self.csv_numbers {
+            overrides.push(Override::new(
+                "csv.infer_numbers",
+                "true",
+                flag("--csv-numbers"),
+            ));
+        }
+        overrides.extend(self.config_overrides.iter().cloned());
+        overrides
+    }
+
+    /// Whether to use the screen-reader layout: `--screen-reader` or
+    /// `[display] screen_reader`
+    pub fn screen_reader(&self, config: &Config) -> bool {
+        self.screen_reader || config.display.screen_reader
+    }
+
+    /// Input reading options, with flags taking precedence over `[csv]` and
+    /// `[xml]` config
+    ///
+    /// An input plugin for the file's extension is used unless
+    /// `--input-format` is given. Following is only supported for JSON
+    /// (Lines) input, since appended records are not converted.
+    pub fn input_options(
+        &self,
+        config: &Config,
+        plugins: &PluginRegistry,
+    ) -> Result<InputOptions, String> {
+        let decoder = match (self.input_format, &self.input) {
+            (None, Some(path)) => plugins.decoder_for(path).map(InputDecoder),
+            _ => None,
+        };
+        let format = self
+            .input_format
+            .or_else(|| self.input.as_deref().and_then(InputFormat::from_path));
+        if self.follow && (decoder.is_some() || format.is_some_and(|f| f != InputFormat::Json)) {
+            return Err("--follow only works with JSON Lines input".to_string());
+        }
+
+        let csv = &config.csv;
+        let delimiter = self.csv_delimiter.or(csv.delimiter);
+        let quote = self.csv_quote.unwrap_or(csv.quote);
+        for c in delimiter.iter().chain([&quote]) {
+            if !c.is_ascii() {
+                return Err(format!("CSV characters must be ASCII, got '{}'", c));
+            }
+        }
+
+        if config.xml.text_key.is_empty() {
+            return Err("XML text_key must not be empty".to_string());
+        }
+
+        Ok(InputOptions {
+            format: self.input_format,
+            csv: CsvOptions {
+                delimiter: delimiter.map(|c| c as u8),
+                quote: quote as u8,
+                infer_numbers: self.csv_numbers || csv.infer_numbers,
+            },
+            xml: XmlOptions {
+                attribute_prefix: config.xml.attribute_prefix.clone(),
+                text_key: config.xml.text_key.clone(),
+            },
+            decoder,
+        })
+    }
+}
+
+/// Parse a single ASCII character, accepting `tab` and `\t` for a tab
+fn parse_csv_char(value: &str) -> Result<char, String> {
+    if value == "tab" || value == "\\t" {
+        return Ok('\t');
+    }
+    let mut chars = value.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) if c.is_ascii() => Ok(c),
+        _ => Err(format!(
+            "expected a single ASCII character, got '{}'",
+            value
+        )),
+    }
+}
+
+/// Write the completion script for `shell`
+pub fn write_completions(shell: clap_complete::Shell, out: &mut impl Write) {
+    clap_complete::generate(shell, &mut Args::command(), "jiq", out);
+}
+
+/// Write the man page
+pub fn write_man_page(out: &mut impl Write) -> io::Result<()> {
+    clap_mangen::Man::new(Args::command()).render(out)
+}
+
+#[cfg(test)]
+#[path = "cli_tests.rs"]
+mod cli_tests;
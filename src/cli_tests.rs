@@ -0,0 +1,469 @@
+//! Tests for cli
+
+use super::*;
+
+#[test]
+fn test_cli_definition_is_valid() {
+    Args::command().debug_assert();
+}
+
+#[test]
+fn test_parse_input_file() {
+    let args = Args::try_parse_from(["jiq", "data.json"]).unwrap();
+    assert_eq!(args.input, Some(PathBuf::from("data.json")));
+    assert_eq!(args.command, None);
+}
+
+#[test]
+fn test_parse_flags_with_input() {
+    let args = Args::try_parse_from(["jiq", "--print0", "--output-fd", "3", "data.json"]).unwrap();
+    assert!(args.print0);
+    assert_eq!(args.output_fd, Some(3));
+    assert_eq!(args.input, Some(PathBuf::from("data.json")));
+}
+
+#[test]
+fn test_log_file_sources() {
+    let args = Args::try_parse_from(["jiq"]).unwrap();
+    assert_eq!(args.log_file(None), None);
+    assert_eq!(args.log_file(Some(OsStr::new(""))), None);
+    assert_eq!(
+        args.log_file(Some(OsStr::new("/tmp/env.log"))),
+        Some(PathBuf::from("/tmp/env.log"))
+    );
+
+    let args = Args::try_parse_from(["jiq", "--log-file", "jiq.log"]).unwrap();
+    assert_eq!(
+        args.log_file(Some(OsStr::new("/tmp/env.log"))),
+        Some(PathBuf::from("jiq.log"))
+    );
+}
+
+#[test]
+fn test_log_level_sources() {
+    let args = Args::try_parse_from(["jiq"]).unwrap();
+    assert_eq!(args.log_level(None), None);
+    assert_eq!(
+        args.log_level(Some(OsStr::new("trace"))).as_deref(),
+        Some("trace")
+    );
+
+    let args = Args::try_parse_from(["jiq", "--log-level", "jiq::query=trace"]).unwrap();
+    assert_eq!(
+        args.log_level(Some(OsStr::new("trace"))).as_deref(),
+        Some("jiq::query=trace")
+    );
+}
+
+#[test]
+fn test_no_color_sources() {
+    let config = Config::default();
+    let args = Args::try_parse_from(["jiq"]).unwrap();
+    assert!(!args.no_color(&config, None));
+    assert!(!args.no_color(&config, Some(OsStr::new(""))));
+    assert!(args.no_color(&config, Some(OsStr::new("1"))));
+
+    let mut no_color_config = Config::default();
+    no_color_config.display.no_color = true;
+    assert!(args.no_color(&no_color_config, None));
+
+    let args = Args::try_parse_from(["jiq", "--no-color"]).unwrap();
+    assert!(args.no_color(&config, None));
+}
+
+#[test]
+fn test_screen_reader_sources() {
+    let config = Config::default();
+    let args = Args::try_parse_from(["jiq"]).unwrap();
+    assert!(!args.screen_reader(&config));
+
+    let mut screen_reader_config = Config::default();
+    screen_reader_config.display.screen_reader = true;
+    assert!(args.screen_reader(&screen_reader_config));
+
+    let args = Args::try_parse_from(["jiq", "--screen-reader"]).unwrap();
+    assert!(args.screen_reader(&config));
+}
+
+#[test]
+fn test_parse_variables() {
+    let args = Args::try_parse_from([
+        "jiq",
+        "--arg",
+        "env",
+        "prod",
+        "--argjson",
+        "limit",
+        "5",
+        "--arg",
+        "x",
+        "y",
+        "data.json",
+    ])
+    .unwrap();
+    assert_eq!(
+        args.variables().unwrap(),
+        vec![
+            QueryVariable::new("env", "prod", VariableKind::String),
+            QueryVariable::new("x", "y", VariableKind::String),
+            QueryVariable::new("limit", "5", VariableKind::Json),
+        ]
+    );
+    assert_eq!(args.input, Some(PathBuf::from("data.json")));
+}
+
+#[test]
+fn test_invalid_variables_are_rejected() {
+    let args = Args::try_parse_from(["jiq", "--argjson", "n", "not json"]).unwrap();
+    assert!(args.variables().is_err());
+
+    let args = Args::try_parse_from(["jiq", "--arg", "1x", "v"]).unwrap();
+    assert!(args.variables().is_err());
+
+    assert!(Args::try_parse_from(["jiq", "--arg", "only-name"]).is_err());
+}
+
+#[test]
+fn test_parse_library_paths() {
+    let args = Args::try_parse_from(["jiq", "-L", "~/.jq", "--library-path", "lib"]).unwrap();
+    assert_eq!(args.library_paths, vec!["~/.jq", "lib"]);
+}
+
+#[test]
+fn test_parse_output_format() {
+    let args = Args::try_parse_from(["jiq"]).unwrap();
+    assert_eq!(args.output_format, OutputFormat::Json);
+
+    let args = Args::try_parse_from(["jiq", "--output-format", "toml", "Cargo.toml"]).unwrap();
+    assert_eq!(args.output_format, OutputFormat::Toml);
+
+    let args = Args::try_parse_from(["jiq", "--output-format", "yaml"]).unwrap();
+    assert_eq!(args.output_format, OutputFormat::Plugin("yaml".to_string()));
+}
+
+#[test]
+fn test_input_options_from_flags_and_config() {
+    let config = crate::config::Config {
+        csv: crate::config::CsvConfig {
+            delimiter: Some(';'),
+            quote: '\'',
+            infer_numbers: true,
+        },
+        ..Default::default()
+    };
+
+    let args = Args::try_parse_from(["jiq", "data.txt"]).unwrap();
+    let options = args
+        .input_options(&config, &PluginRegistry::default())
+        .unwrap();
+    assert_eq!(options.format, None);
+    assert_eq!(options.csv.delimiter, Some(b';'));
+    assert_eq!(options.csv.quote, b'\'');
+    assert!(options.csv.infer_numbers);
+
+    let args = Args::try_parse_from([
+        "jiq",
+        "--input-format",
+        "csv",
+        "--csv-delimiter",
+        "tab",
+        "--csv-quote",
+        "\"",
+        "data.txt",
+    ])
+    .unwrap();
+    let options = args
+        .input_options(&config, &PluginRegistry::default())
+        .unwrap();
+    assert_eq!(options.format, Some(InputFormat::Csv));
+    assert_eq!(options.csv.delimiter, Some(b'\t'));
+    assert_eq!(options.csv.quote, b'"');
+}
+
+#[test]
+fn test_csv_characters_must_be_single_ascii() {
+    assert!(Args::try_parse_from(["jiq", "--csv-delimiter", ";;"]).is_err());
+    assert!(Args::try_parse_from(["jiq", "--csv-delimiter", "§"]).is_err());
+
+    let config = crate::config::Config {
+        csv: crate::config::CsvConfig {
+            delimiter: Some('§'),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let args = Args::try_parse_from(["jiq"]).unwrap();
+    assert!(
+        args.input_options(&config, &PluginRegistry::default())
+            .is_err()
+    );
+}
+
+#[test]
+fn test_input_options_use_xml_config() {
+    let args = Args::try_parse_from(["jiq", "pom.xml"]).unwrap();
+    let options = args
+        .input_options(&Default::default(), &PluginRegistry::default())
+        .unwrap();
+    assert_eq!(options.xml.attribute_prefix, "@");
+    assert_eq!(options.xml.text_key, "#text");
+
+    let mut config = crate::config::Config::default();
+    config.xml.attribute_prefix = "_".to_string();
+    config.xml.text_key = "value".to_string();
+    let options = args
+        .input_options(&config, &PluginRegistry::default())
+        .unwrap();
+    assert_eq!(options.xml.attribute_prefix, "_");
+    assert_eq!(options.xml.text_key, "value");
+
+    config.xml.text_key = String::new();
+    assert!(
+        args.input_options(&config, &PluginRegistry::default())
+            .is_err()
+    );
+}
+
+#[test]
+fn test_parse_completions_subcommand() {
+    let args = Args::try_parse_from(["jiq", "completions", "zsh"]).unwrap();
+    assert_eq!(
+        args.command,
+        Some(Command::Completions {
+            shell: clap_complete::Shell::Zsh
+        })
+    );
+    assert_eq!(args.input, None);
+}
+
+#[test]
+fn test_parse_completions_rejects_unknown_shell() {
+    assert!(Args::try_parse_from(["jiq", "completions", "tcsh"]).is_err());
+}
+
+#[test]
+fn test_parse_man_subcommand() {
+    let args = Args::try_parse_from(["jiq", "man"]).unwrap();
+    assert_eq!(args.command, Some(Command::Man));
+}
+
+#[test]
+fn test_parse_config_subcommands() {
+    let args = Args::try_parse_from(["jiq", "config", "init", "--force"]).unwrap();
+    assert_eq!(
+        args.command,
+        Some(Command::Config {
+            action: ConfigCommand::Init { force: true }
+        })
+    );
+    let args = Args::try_parse_from(["jiq", "config", "validate"]).unwrap();
+    assert_eq!(
+        args.command,
+        Some(Command::Config {
+            action: ConfigCommand::Validate
+        })
+    );
+    assert!(Args::try_parse_from(["jiq", "config"]).is_err());
+}
+
+#[test]
+fn test_parse_set_overrides() {
+    let args = Args::try_parse_from([
+        "jiq",
+        "--set",
+        "display.sparkline=false",
+        "--set",
+        "editor.keybindings=emacs",
+        "--show-config",
+    ])
+    .unwrap();
+
+    assert!(args.show_config);
+    let keys: Vec<&str> = args
+        .config_overrides
+        .iter()
+        .map(|o| o.key.as_str())
+        .collect();
+    assert_eq!(keys, ["display.sparkline", "editor.keybindings"]);
+    assert!(Args::try_parse_from(["jiq", "--set", "sparkline"]).is_err());
+}
+
+#[test]
+fn test_config_flags_become_overrides() {
+    let args = Args::try_parse_from([
+        "jiq",
+        "--no-color",
+        "--csv-delimiter",
+        "tab",
+        "--set",
+        "display.no_color=false",
+    ])
+    .unwrap();
+
+    assert_eq!(
+        args.config_overrides(),
+        vec![
+            Override::new(
+                "display.no_color",
+                "true",
+                Source::Flag("--no-color".to_string())
+            ),
+            Override::new(
+                "csv.delimiter",
+                "\t",
+                Source::Flag("--csv-delimiter".to_string())
+            ),
+            Override::new(
+                "display.no_color",
+                "false",
+                Source::Flag("--set".to_string())
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_parse_k8s_subcommand_with_kubectl_args() {
+    let args =
+        Args::try_parse_from(["jiq", "k8s", "pods", "-n", "kube-system", "-l", "app=web"]).unwrap();
+    assert_eq!(
+        args.command,
+        Some(Command::K8s {
+            resource: "pods".to_string(),
+            args: vec![
+                "-n".to_string(),
+                "kube-system".to_string(),
+                "-l".to_string(),
+                "app=web".to_string()
+            ],
+        })
+    );
+
+    assert!(Args::try_parse_from(["jiq", "k8s"]).is_err());
+}
+
+#[test]
+fn test_parse_aws_subcommand() {
+    let args = Args::try_parse_from([
+        "jiq",
+        "aws",
+        "ec2",
+        "describe-instances",
+        "--region",
+        "eu-west-1",
+    ])
+    .unwrap();
+    assert_eq!(
+        args.command,
+        Some(Command::Aws {
+            service: "ec2".to_string(),
+            operation: "describe-instances".to_string(),
+            args: vec!["--region".to_string(), "eu-west-1".to_string()],
+        })
+    );
+
+    assert!(Args::try_parse_from(["jiq", "aws", "ec2"]).is_err());
+}
+
+#[test]
+fn test_completions_include_flags() {
+    for shell in [
+        clap_complete::Shell::Bash,
+        clap_complete::Shell::Zsh,
+        clap_complete::Shell::Fish,
+    ] {
+        let mut out = Vec::new();
+        write_completions(shell, &mut out);
+        let script = String::from_utf8(out).unwrap();
+        assert!(script.contains("print0"), "{:?}", shell);
+        assert!(script.contains("shell-init"), "{:?}", shell);
+    }
+}
+
+#[test]
+fn test_man_page_documents_flags_and_subcommands() {
+    let mut out = Vec::new();
+    write_man_page(&mut out).unwrap();
+    let page = String::from_utf8(out).unwrap();
+    assert!(page.contains(".TH jiq"));
+    assert!(page.contains("output\\-fd"));
+    assert!(page.contains("completions"));
+}
+
+#[test]
+fn test_follow_requires_json_input_file() {
+    let args = Args::try_parse_from(["jiq", "-f", "events.jsonl"]).unwrap();
+    assert!(args.follow);
+    assert!(
+        args.input_options(&Default::default(), &PluginRegistry::default())
+            .is_ok()
+    );
+
+    assert!(Args::try_parse_from(["jiq", "--follow"]).is_err());
+
+    let args = Args::try_parse_from(["jiq", "--follow", "Cargo.toml"]).unwrap();
+    assert!(
+        args.input_options(&Default::default(), &PluginRegistry::default())
+            .is_err()
+    );
+}
+
+#[test]
+fn test_session_flag() {
+    let args = Args::try_parse_from(["jiq", "--session", "incident"]).unwrap();
+    assert_eq!(args.session.as_deref(), Some("incident"));
+    assert_eq!(args.input, None);
+
+    let args = Args::try_parse_from(["jiq", "--session", "incident", "other.json"]).unwrap();
+    assert_eq!(args.input, Some(PathBuf::from("other.json")));
+}
+
+#[test]
+fn test_parse_open() {
+    let args =
+        Args::try_parse_from(["jiq", "--open", "jiq://eyJxIjoiLmEifQ", "data.json"]).unwrap();
+    assert_eq!(args.open.as_deref(), Some("jiq://eyJxIjoiLmEifQ"));
+    assert_eq!(args.input, Some(PathBuf::from("data.json")));
+
+    assert!(Args::try_parse_from(["jiq", "--open", "jiq://x", "--session", "s"]).is_err());
+}
+
+#[test]
+fn test_parse_record_and_replay() {
+    let args = Args::try_parse_from(["jiq", "--record", "bug.json", "data.json"]).unwrap();
+    assert_eq!(args.record, Some(PathBuf::from("bug.json")));
+
+    let args = Args::try_parse_from(["jiq", "--replay", "bug.json", "data.json"]).unwrap();
+    assert_eq!(args.replay, Some(PathBuf::from("bug.json")));
+
+    assert!(Args::try_parse_from(["jiq", "--record", "a.json", "--replay", "b.json"]).is_err());
+}
+
+#[test]
+fn test_parse_schema() {
+    let args = Args::try_parse_from(["jiq", "--schema", "order.schema.json", "data.json"]).unwrap();
+    assert_eq!(args.schema, Some(PathBuf::from("order.schema.json")));
+    assert_eq!(args.input, Some(PathBuf::from("data.json")));
+}
+
+#[test]
+fn test_parse_import_history() {
+    let args = Args::try_parse_from(["jiq", "--import-history", ".zsh_history", "--format", "zsh"])
+        .unwrap();
+    assert_eq!(args.import_history, Some(PathBuf::from(".zsh_history")));
+    assert_eq!(args.import_format, HistoryFormat::Zsh);
+
+    let args = Args::try_parse_from(["jiq", "--import-history", "queries.txt"]).unwrap();
+    assert_eq!(args.import_format, HistoryFormat::Plain);
+
+    assert!(Args::try_parse_from(["jiq", "--format", "fx"]).is_err());
+}
+
+#[test]
+fn test_in_place_requires_input_file_and_no_follow() {
+    let args = Args::try_parse_from(["jiq", "--in-place", "config.json"]).unwrap();
+    assert!(args.in_place);
+
+    assert!(Args::try_parse_from(["jiq", "--in-place"]).is_err());
+    assert!(Args::try_parse_from(["jiq", "--in-place", "-f", "config.json"]).is_err());
+}
@@ -10,11 +10,44 @@ pub enum ClipboardError {
     WriteError,
 }
 
+/// Somewhere text can be copied to
+pub trait ClipboardTarget {
+    fn copy(&mut self, text: &str) -> ClipboardResult;
+}
+
+/// The operating system clipboard
+pub struct SystemClipboard;
+
+impl ClipboardTarget for SystemClipboard {
+    fn copy(&mut self, text: &str) -> ClipboardResult {
+        system::copy(text)
+    }
+}
+
+/// The terminal's clipboard, set with an OSC 52 escape sequence
+pub struct Osc52Clipboard;
+
+impl ClipboardTarget for Osc52Clipboard {
+    fn copy(&mut self, text: &str) -> ClipboardResult {
+        osc52::copy(text)
+    }
+}
+
 pub fn copy_to_clipboard(text: &str, backend: ClipboardBackend) -> ClipboardResult {
+    copy_with(text, backend, &mut SystemClipboard, &mut Osc52Clipboard)
+}
+
+/// Copy `text` as `backend` asks, to `system` or `osc52`
+pub fn copy_with(
+    text: &str,
+    backend: ClipboardBackend,
+    system: &mut impl ClipboardTarget,
+    osc52: &mut impl ClipboardTarget,
+) -> ClipboardResult {
     match backend {
-        ClipboardBackend::System => system::copy(text),
-        ClipboardBackend::Osc52 => osc52::copy(text),
-        ClipboardBackend::Auto => system::copy(text).or_else(|_| osc52::copy(text)),
+        ClipboardBackend::System => system.copy(text),
+        ClipboardBackend::Osc52 => osc52.copy(text),
+        ClipboardBackend::Auto => system.copy(text).or_else(|_| osc52.copy(text)),
     }
 }
 
@@ -31,3 +31,58 @@ fn test_copy_to_clipboard_unicode() {
     let result = copy_to_clipboard("日本語 🎉", ClipboardBackend::Osc52);
     assert!(result.is_ok());
 }
+
+/// Clipboard recording what is copied to it, or failing
+struct FakeClipboard {
+    available: bool,
+    copied: Vec<String>,
+}
+
+impl FakeClipboard {
+    fn new(available: bool) -> Self {
+        Self {
+            available,
+            copied: Vec::new(),
+        }
+    }
+}
+
+impl ClipboardTarget for FakeClipboard {
+    fn copy(&mut self, text: &str) -> ClipboardResult {
+        if !self.available {
+            return Err(ClipboardError::SystemUnavailable);
+        }
+        self.copied.push(text.to_string());
+        Ok(())
+    }
+}
+
+#[test]
+fn test_auto_prefers_system_clipboard() {
+    let (mut system, mut osc52) = (FakeClipboard::new(true), FakeClipboard::new(true));
+
+    copy_with(".a", ClipboardBackend::Auto, &mut system, &mut osc52).unwrap();
+
+    assert_eq!(system.copied, [".a"]);
+    assert!(osc52.copied.is_empty());
+}
+
+#[test]
+fn test_auto_falls_back_to_osc52() {
+    let (mut system, mut osc52) = (FakeClipboard::new(false), FakeClipboard::new(true));
+
+    copy_with(".a", ClipboardBackend::Auto, &mut system, &mut osc52).unwrap();
+
+    assert_eq!(osc52.copied, [".a"]);
+}
+
+#[test]
+fn test_explicit_backends_do_not_fall_back() {
+    let (mut system, mut osc52) = (FakeClipboard::new(false), FakeClipboard::new(true));
+
+    assert!(copy_with(".a", ClipboardBackend::System, &mut system, &mut osc52).is_err());
+    assert!(osc52.copied.is_empty());
+
+    copy_with(".b", ClipboardBackend::Osc52, &mut system, &mut osc52).unwrap();
+    assert_eq!(osc52.copied, [".b"]);
+}
@@ -24,13 +24,14 @@ fn copy_focused_content(app: &mut App, backend: ClipboardBackend) -> bool {
 }
 
 fn copy_query(app: &mut App, backend: ClipboardBackend) -> bool {
-    let query = app.query();
+    let query = app.query().to_string();
 
     if query.is_empty() {
         return false;
     }
 
-    if copy_to_clipboard(query, backend).is_ok() {
+    if copy_to_clipboard(&query, backend).is_ok() {
+        app.saved_query = Some(query);
         app.notification.show("Copied query!");
         true
     } else {
@@ -38,15 +39,15 @@ fn copy_query(app: &mut App, backend: ClipboardBackend) -> bool {
     }
 }
 
-fn copy_result(app: &mut App, backend: ClipboardBackend) -> bool {
+pub fn copy_result(app: &mut App, backend: ClipboardBackend) -> bool {
     // Only copy if query state is available
     let query_state = match &app.query {
         Some(q) => q,
         None => return false,
     };
 
-    // Copy what's displayed: last_successful_result_unformatted
-    let full_result = match &query_state.last_successful_result_unformatted {
+    // Copy what's displayed: the last successful result
+    let full_result = match query_state.last_successful_result_text() {
         Some(text) => text.as_ref().to_string(),
         None => return false,
     };
@@ -57,22 +58,16 @@ fn copy_result(app: &mut App, backend: ClipboardBackend) -> bool {
 
     let (result, notification) = if app.results_cursor.is_visual_mode() {
         let (start, end) = app.results_cursor.selection_range();
-        let lines: Vec<&str> = full_result.lines().collect();
-        let start_idx = start as usize;
-        let end_idx = (end as usize).min(lines.len().saturating_sub(1));
-
-        if start_idx < lines.len() {
-            let selected: String = lines[start_idx..=end_idx].join("\n");
-            let line_count = end_idx - start_idx + 1;
-            let notification = if line_count == 1 {
-                "Copied 1 line!".to_string()
-            } else {
-                format!("Copied {} lines!", line_count)
-            };
-            (selected, notification)
-        } else {
+        let (start_idx, end_idx) = app.results_fold.line_range(start, end);
+        let Some((selected, line_count)) = selected_lines(&full_result, start_idx, end_idx) else {
             return false;
-        }
+        };
+        let notification = if line_count == 1 {
+            "Copied 1 line!".to_string()
+        } else {
+            format!("Copied {} lines!", line_count)
+        };
+        (selected, notification)
     } else {
         (full_result, "Copied result!".to_string())
     };
@@ -82,6 +77,7 @@ fn copy_result(app: &mut App, backend: ClipboardBackend) -> bool {
     }
 
     if copy_to_clipboard(&result, backend).is_ok() {
+        app.saved_query = Some(app.query().to_string());
         app.notification.show(&notification);
         true
     } else {
@@ -89,6 +85,34 @@ fn copy_result(app: &mut App, backend: ClipboardBackend) -> bool {
     }
 }
 
+/// Lines `start..=end` of `result` and how many there are, with the
+/// indentation they share removed and without the comma ending the last
+/// one, so a selected object or array pastes as it would print on its own
+fn selected_lines(result: &str, start: usize, end: usize) -> Option<(String, usize)> {
+    let lines: Vec<&str> = result
+        .lines()
+        .skip(start)
+        .take(end.saturating_sub(start) + 1)
+        .collect();
+    if lines.is_empty() {
+        return None;
+    }
+    let indent = lines
+        .iter()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.len() - line.trim_start_matches(' ').len())
+        .min()
+        .unwrap_or(0);
+    let mut selected: Vec<&str> = lines
+        .iter()
+        .map(|line| line.get(indent..).unwrap_or(line.trim_start()))
+        .collect();
+    if let Some(last) = selected.last_mut() {
+        *last = last.strip_suffix(',').unwrap_or(last);
+    }
+    Some((selected.join("\n"), lines.len()))
+}
+
 #[cfg(test)]
 pub fn strip_ansi_codes(text: &str) -> String {
     let mut result = String::with_capacity(text.len());
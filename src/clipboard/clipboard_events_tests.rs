@@ -124,6 +124,7 @@ fn test_copy_result_rejects_empty_without_cache() {
     query_state.result = Ok(String::new());
     // Clear cache to test the no-cache scenario
     query_state.last_successful_result_unformatted = None;
+    query_state.last_successful_result_lines = None;
 
     let result = copy_result(&mut app, ClipboardBackend::Osc52);
     assert!(!result, "Empty result without cache should be rejected");
@@ -140,6 +141,7 @@ fn test_copy_result_rejects_ansi_only_without_cache() {
     query_state.result = Ok("\x1b[31m\x1b[0m".to_string());
     // Clear cache to test the no-cache scenario
     query_state.last_successful_result_unformatted = None;
+    query_state.last_successful_result_lines = None;
 
     let result = copy_result(&mut app, ClipboardBackend::Osc52);
     assert!(!result, "ANSI-only result without cache should be rejected");
@@ -156,6 +158,7 @@ fn test_copy_result_rejects_error_without_cache() {
     query_state.result = Err("some error".to_string());
     // Clear cache to test the no-cache scenario
     query_state.last_successful_result_unformatted = None;
+    query_state.last_successful_result_lines = None;
 
     let result = copy_result(&mut app, ClipboardBackend::Osc52);
     assert!(!result, "Error result without cache should be rejected");
@@ -312,3 +315,26 @@ fn test_copy_result_when_result_empty() {
 
     assert!(!result);
 }
+
+#[test]
+fn test_selected_lines_drop_shared_indent_and_trailing_comma() {
+    let result = "{\n  \"spec\": {\n    \"replicas\": 3\n  },\n  \"name\": \"api\"\n}";
+
+    assert_eq!(
+        selected_lines(result, 1, 3),
+        Some(("\"spec\": {\n  \"replicas\": 3\n}".to_string(), 3))
+    );
+    assert_eq!(
+        selected_lines(result, 4, 4),
+        Some(("\"name\": \"api\"".to_string(), 1))
+    );
+}
+
+#[test]
+fn test_selected_lines_past_the_end() {
+    assert_eq!(selected_lines("[\n  1\n]", 5, 6), None);
+    assert_eq!(
+        selected_lines("[\n  1\n]", 0, 9),
+        Some(("[\n  1\n]".to_string(), 3))
+    );
+}
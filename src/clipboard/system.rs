@@ -1,15 +1,38 @@
+use std::borrow::Cow;
+
 use arboard::Clipboard;
 
 use super::backend::{ClipboardError, ClipboardResult};
+use crate::paths::Platform;
 
+/// Copy to the operating system clipboard through arboard, which uses the
+/// Windows API on Windows, AppKit on macOS and X11 or Wayland elsewhere
 pub fn copy(text: &str) -> ClipboardResult {
     let mut clipboard = Clipboard::new().map_err(|_| ClipboardError::SystemUnavailable)?;
 
     clipboard
-        .set_text(text)
+        .set_text(native_line_endings(text, Platform::current()))
         .map_err(|_| ClipboardError::WriteError)
 }
 
+/// `text` with the line endings programs on `platform` paste correctly:
+/// `\r\n` on Windows, where some still show `\n` text on one line
+fn native_line_endings(text: &str, platform: Platform) -> Cow<'_, str> {
+    if platform != Platform::Windows || !text.contains('\n') {
+        return Cow::Borrowed(text);
+    }
+    let mut converted = String::with_capacity(text.len() + text.len() / 16);
+    let mut previous = None;
+    for c in text.chars() {
+        if c == '\n' && previous != Some('\r') {
+            converted.push('\r');
+        }
+        converted.push(c);
+        previous = Some(c);
+    }
+    Cow::Owned(converted)
+}
+
 #[cfg(test)]
 #[path = "system_tests.rs"]
 mod system_tests;
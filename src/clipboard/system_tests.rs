@@ -7,3 +7,18 @@ fn test_copy_returns_result() {
     let result = copy("test");
     assert!(result.is_ok() || matches!(result, Err(ClipboardError::SystemUnavailable)));
 }
+
+#[test]
+fn test_windows_line_endings() {
+    assert_eq!(
+        native_line_endings("{\n  \"a\": 1\r\n}\n", Platform::Windows),
+        "{\r\n  \"a\": 1\r\n}\r\n"
+    );
+    assert_eq!(native_line_endings("[1,2]", Platform::Windows), "[1,2]");
+}
+
+#[test]
+fn test_unix_line_endings_are_kept() {
+    assert_eq!(native_line_endings("a\nb", Platform::Unix), "a\nb");
+    assert_eq!(native_line_endings("a\nb", Platform::MacOs), "a\nb");
+}
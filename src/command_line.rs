@@ -0,0 +1,6 @@
+pub mod command_line_events;
+pub mod command_line_render;
+mod command_line_state;
+
+#[allow(unused_imports)]
+pub use command_line_state::{CommandLineState, LineCommand};
@@ -0,0 +1,95 @@
+use ratatui::crossterm::event::{KeyCode, KeyEvent};
+use tui_textarea::Input;
+
+use super::command_line_state::LineCommand;
+use crate::app::App;
+use crate::clipboard::backend::copy_to_clipboard;
+use crate::permalink::Permalink;
+use crate::session::{self, Session};
+
+pub fn handle_command_line_key(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Esc => app.command_line.close(),
+        KeyCode::Backspace if app.command_line.text().is_empty() => app.command_line.close(),
+        KeyCode::Enter => {
+            let text = app.command_line.text().to_string();
+            app.command_line.close();
+            match LineCommand::parse(&text) {
+                Ok(command) => run_command(app, command),
+                Err(e) => app.notification.show_warning(&e),
+            }
+        }
+        _ => {
+            app.command_line.textarea_mut().input(Input::from(key));
+        }
+    }
+}
+
+fn run_command(app: &mut App, command: LineCommand) {
+    match command {
+        LineCommand::SessionSave(name) => save_session(app, name),
+        LineCommand::Messages => app.message_log.open(),
+        LineCommand::Share { variables } => share_query(app, variables),
+        LineCommand::Validate(file) => crate::schema::validate_result(app, file.as_deref()),
+        LineCommand::Schema(file) => crate::schema::export_inferred_schema(app, file.as_deref()),
+        LineCommand::Lint => crate::lint::lint_input(app),
+        LineCommand::Diff => crate::stats::field_diff_events::open_field_diff(app),
+        LineCommand::Sizes => crate::stats::size_breakdown_events::open_size_breakdown(app),
+        LineCommand::Histogram(path) => crate::histogram::open_histogram(app, path.as_deref()),
+        LineCommand::Columns => crate::projection::open_projection(app),
+        LineCommand::Templates => crate::templates::open_templates(app),
+        LineCommand::Fold(depth) => crate::results::results_events::fold_to_depth(app, Some(depth)),
+        LineCommand::Unfold => crate::results::results_events::fold_to_depth(app, None),
+        LineCommand::Edit => crate::external::edit_input(app),
+        LineCommand::TabNew => crate::query_tabs::new_tab(app),
+        LineCommand::TabClose => crate::query_tabs::close_tab(app),
+        LineCommand::Tab(number) => crate::query_tabs::switch_tab(app, number),
+        LineCommand::Compare(old, Some(new)) => crate::query_tabs::compare_tabs(app, old, new),
+        LineCommand::Compare(tab, None) => {
+            let shown = app.query_tabs.active() + 1;
+            crate::query_tabs::compare_tabs(app, shown, tab)
+        }
+    }
+}
+
+/// Copy a `jiq://` link to the current query
+pub fn share_query(app: &mut App, include_variables: bool) {
+    if app.query().is_empty() {
+        app.notification
+            .show_warning("Nothing to share: the query is empty");
+        return;
+    }
+
+    let link = Permalink::capture(app, include_variables).encode();
+    if copy_to_clipboard(&link, app.clipboard_backend).is_ok() {
+        app.notification.show("Copied jiq:// link to the query");
+    } else {
+        app.notification.show_error("Cannot copy jiq:// link");
+    }
+}
+
+/// Save the current state as a named session
+///
+/// Without a name, the session given with `--session` or saved last is
+/// overwritten.
+pub fn save_session(app: &mut App, name: Option<String>) {
+    let Some(name) = name.or_else(|| app.session_name.clone()) else {
+        app.notification.show_warning("Usage: :session save NAME");
+        return;
+    };
+
+    match session::save_session(&name, &Session::capture(app)) {
+        Ok(_) => {
+            app.notification.show(&format!("Session '{}' saved", name));
+            app.session_name = Some(name);
+            app.saved_query = Some(app.query().to_string());
+        }
+        Err(e) => app
+            .notification
+            .show_error(&format!("Cannot save session: {}", e)),
+    }
+}
+
+#[cfg(test)]
+#[path = "command_line_events_tests.rs"]
+mod command_line_events_tests;
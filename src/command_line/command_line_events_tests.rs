@@ -0,0 +1,139 @@
+//! Tests for command line key handling
+
+use crate::app::{App, Focus};
+use crate::config::ClipboardBackend;
+use crate::editor::EditorMode;
+use crate::test_utils::test_helpers::{app_with_query, key};
+use ratatui::crossterm::event::KeyCode;
+
+fn type_text(app: &mut App, text: &str) {
+    for c in text.chars() {
+        app.handle_key_event(key(KeyCode::Char(c)));
+    }
+}
+
+#[test]
+fn test_colon_opens_command_line_in_normal_mode() {
+    let mut app = app_with_query(".");
+    app.input.editor_mode = EditorMode::Normal;
+
+    app.handle_key_event(key(KeyCode::Char(':')));
+
+    assert!(app.command_line.is_visible());
+    assert_eq!(app.query(), ".");
+}
+
+#[test]
+fn test_colon_opens_command_line_from_results_pane() {
+    let mut app = app_with_query(".");
+    app.focus = Focus::ResultsPane;
+
+    app.handle_key_event(key(KeyCode::Char(':')));
+
+    assert!(app.command_line.is_visible());
+}
+
+#[test]
+fn test_colon_is_typed_in_insert_mode() {
+    let mut app = app_with_query("");
+    app.input.editor_mode = EditorMode::Insert;
+
+    app.handle_key_event(key(KeyCode::Char(':')));
+
+    assert!(!app.command_line.is_visible());
+    assert_eq!(app.query(), ":");
+}
+
+#[test]
+fn test_typed_text_goes_to_command_line() {
+    let mut app = app_with_query(".name");
+    app.command_line.open();
+
+    type_text(&mut app, "session save");
+
+    assert_eq!(app.command_line.text(), "session save");
+    assert_eq!(app.query(), ".name");
+}
+
+#[test]
+fn test_esc_and_backspace_on_empty_close() {
+    let mut app = app_with_query(".");
+    app.command_line.open();
+    app.handle_key_event(key(KeyCode::Esc));
+    assert!(!app.command_line.is_visible());
+
+    app.command_line.open();
+    app.handle_key_event(key(KeyCode::Backspace));
+    assert!(!app.command_line.is_visible());
+}
+
+#[test]
+fn test_unknown_command_shows_warning() {
+    let mut app = app_with_query(".");
+    app.command_line.open();
+    type_text(&mut app, "frobnicate");
+    app.handle_key_event(key(KeyCode::Enter));
+
+    assert!(!app.command_line.is_visible());
+    assert!(!app.should_quit);
+    assert_eq!(
+        app.notification.current_message(),
+        Some("Unknown command: frobnicate")
+    );
+}
+
+#[test]
+fn test_session_save_without_name_needs_a_session() {
+    let mut app = app_with_query(".");
+    app.command_line.open();
+    type_text(&mut app, "session save");
+    app.handle_key_event(key(KeyCode::Enter));
+
+    assert_eq!(
+        app.notification.current_message(),
+        Some("Usage: :session save NAME")
+    );
+    assert_eq!(app.session_name, None);
+}
+
+#[test]
+fn test_session_save_rejects_invalid_name() {
+    let mut app = app_with_query(".");
+    app.command_line.open();
+    type_text(&mut app, "session save ../x");
+    app.handle_key_event(key(KeyCode::Enter));
+
+    assert!(
+        app.notification
+            .current_message()
+            .is_some_and(|m| m.starts_with("Cannot save session: Invalid session name"))
+    );
+    assert_eq!(app.session_name, None);
+}
+
+#[test]
+fn test_share_copies_link() {
+    let mut app = app_with_query(".name");
+    app.clipboard_backend = ClipboardBackend::Osc52;
+    app.command_line.open();
+    type_text(&mut app, "share");
+    app.handle_key_event(key(KeyCode::Enter));
+
+    assert_eq!(
+        app.notification.current_message(),
+        Some("Copied jiq:// link to the query")
+    );
+}
+
+#[test]
+fn test_share_needs_a_query() {
+    let mut app = app_with_query("");
+    app.command_line.open();
+    type_text(&mut app, "share vars");
+    app.handle_key_event(key(KeyCode::Enter));
+
+    assert_eq!(
+        app.notification.current_message(),
+        Some("Nothing to share: the query is empty")
+    );
+}
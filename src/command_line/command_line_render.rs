@@ -0,0 +1,55 @@
+use ratatui::{
+    Frame,
+    layout::{Alignment, Rect},
+    style::Style,
+    text::Span,
+    widgets::{Block, BorderType, Borders, Paragraph},
+};
+
+use crate::app::App;
+use crate::theme;
+
+/// Render the command line in place of the query input
+pub fn render_line(app: &mut App, frame: &mut Frame, area: Rect) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(" Command ")
+        .border_style(Style::default().fg(theme::command_line::BORDER))
+        .style(Style::default().bg(theme::command_line::BACKGROUND))
+        .title_bottom(
+            theme::border_hints::build_hints(
+                &[("Enter", "Run"), ("Esc", "Cancel")],
+                theme::command_line::HINTS,
+            )
+            .alignment(Alignment::Center),
+        );
+
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+    if inner.width < 2 {
+        return;
+    }
+
+    let prompt_area = Rect { width: 1, ..inner };
+    let text_area = Rect {
+        x: inner.x + 1,
+        width: inner.width - 1,
+        ..inner
+    };
+    frame.render_widget(
+        Paragraph::new(Span::styled(
+            ":",
+            Style::default().fg(theme::command_line::PROMPT),
+        )),
+        prompt_area,
+    );
+
+    let textarea = app.command_line.textarea_mut();
+    textarea.set_style(
+        Style::default()
+            .fg(theme::command_line::TEXT)
+            .bg(theme::command_line::BACKGROUND),
+    );
+    frame.render_widget(&*textarea, text_area);
+}
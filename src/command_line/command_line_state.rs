@@ -0,0 +1,167 @@
+use ratatui::style::Style;
+use tui_textarea::TextArea;
+
+use crate::theme;
+
+/// Command entered on the `:` command line
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LineCommand {
+    /// `:session save [NAME]`, saving under the current session name when
+    /// no name is given
+    SessionSave(Option<String>),
+    /// `:messages`, opening the log of past notifications
+    Messages,
+    /// `:share [vars]`, copying a `jiq://` link to the query, with the
+    /// variable bindings when `vars` is given
+    Share { variables: bool },
+    /// `:validate [FILE]`, checking the result against the `--schema` or
+    /// the given JSON Schema
+    Validate(Option<String>),
+    /// `:schema [FILE]`, writing a schema inferred from the result to FILE
+    /// or the clipboard
+    Schema(Option<String>),
+    /// `:lint`, checking the raw input for duplicate keys and other issues
+    Lint,
+    /// `:diff`, comparing the elements of the result
+    Diff,
+    /// `:sizes`, measuring the top-level keys or elements of the result
+    Sizes,
+    /// `:histogram [PATH]`, counting the values of PATH, or of the field on
+    /// the results cursor line
+    Histogram(Option<String>),
+    /// `:columns`, picking fields of the result to keep
+    Columns,
+    /// `:templates`, showing starter queries for a recognised input
+    Templates,
+    /// `:fold [DEPTH]`, closing the objects and arrays in the results pane
+    /// nested DEPTH or more levels deep (1 when not given)
+    Fold(usize),
+    /// `:unfold`, opening every fold in the results pane
+    Unfold,
+    /// `:edit`, opening the input in `$EDITOR` and loading it again
+    Edit,
+    /// `:tab new`, opening a query tab from the current query
+    TabNew,
+    /// `:tab close`, closing the shown query tab
+    TabClose,
+    /// `:tab N`, showing query tab N
+    Tab(usize),
+    /// `:compare A [B]`, diffing the results of query tabs A and B, or of
+    /// the shown tab and A
+    Compare(usize, Option<usize>),
+}
+
+impl LineCommand {
+    /// Parse the text typed after `:`
+    pub fn parse(input: &str) -> Result<Self, String> {
+        let words: Vec<&str> = input.split_whitespace().collect();
+        match words.as_slice() {
+            ["session", "save"] => Ok(LineCommand::SessionSave(None)),
+            ["session", "save", name] => Ok(LineCommand::SessionSave(Some(name.to_string()))),
+            ["session", ..] => Err("Usage: :session save [NAME]".to_string()),
+            ["messages"] => Ok(LineCommand::Messages),
+            ["share"] => Ok(LineCommand::Share { variables: false }),
+            ["share", "vars"] => Ok(LineCommand::Share { variables: true }),
+            ["share", ..] => Err("Usage: :share [vars]".to_string()),
+            ["validate"] => Ok(LineCommand::Validate(None)),
+            ["validate", file] => Ok(LineCommand::Validate(Some(file.to_string()))),
+            ["validate", ..] => Err("Usage: :validate [FILE]".to_string()),
+            ["schema"] => Ok(LineCommand::Schema(None)),
+            ["schema", file] => Ok(LineCommand::Schema(Some(file.to_string()))),
+            ["schema", ..] => Err("Usage: :schema [FILE]".to_string()),
+            ["lint"] => Ok(LineCommand::Lint),
+            ["diff"] => Ok(LineCommand::Diff),
+            ["sizes"] => Ok(LineCommand::Sizes),
+            ["histogram"] => Ok(LineCommand::Histogram(None)),
+            ["histogram", path @ ..] => Ok(LineCommand::Histogram(Some(path.join(" ")))),
+            ["columns"] => Ok(LineCommand::Columns),
+            ["templates"] => Ok(LineCommand::Templates),
+            ["fold"] => Ok(LineCommand::Fold(1)),
+            ["fold", depth] => depth
+                .parse()
+                .map(LineCommand::Fold)
+                .map_err(|_| "Usage: :fold [DEPTH]".to_string()),
+            ["fold", ..] => Err("Usage: :fold [DEPTH]".to_string()),
+            ["unfold"] => Ok(LineCommand::Unfold),
+            ["edit"] => Ok(LineCommand::Edit),
+            ["tab", "new"] => Ok(LineCommand::TabNew),
+            ["tab", "close"] => Ok(LineCommand::TabClose),
+            ["tab", number] => number
+                .parse()
+                .map(LineCommand::Tab)
+                .map_err(|_| "Usage: :tab new|close|N".to_string()),
+            ["tab", ..] => Err("Usage: :tab new|close|N".to_string()),
+            ["compare", tabs @ ..] if matches!(tabs.len(), 1 | 2) => {
+                let numbers: Result<Vec<usize>, _> = tabs.iter().map(|tab| tab.parse()).collect();
+                match numbers.as_deref() {
+                    Ok([tab]) => Ok(LineCommand::Compare(*tab, None)),
+                    Ok([old, new]) => Ok(LineCommand::Compare(*old, Some(*new))),
+                    _ => Err("Usage: :compare TAB [TAB]".to_string()),
+                }
+            }
+            ["compare", ..] => Err("Usage: :compare TAB [TAB]".to_string()),
+            [] => Err("No command given".to_string()),
+            [command, ..] => Err(format!("Unknown command: {}", command)),
+        }
+    }
+}
+
+fn create_textarea() -> TextArea<'static> {
+    let mut textarea = TextArea::default();
+    textarea.set_cursor_line_style(Style::default());
+    textarea.set_cursor_style(theme::palette::CURSOR);
+    textarea
+}
+
+/// Vim-style `:` command line, shown in place of the query input
+pub struct CommandLineState {
+    visible: bool,
+    textarea: TextArea<'static>,
+}
+
+impl Default for CommandLineState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CommandLineState {
+    pub fn new() -> Self {
+        Self {
+            visible: false,
+            textarea: create_textarea(),
+        }
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    pub fn open(&mut self) {
+        self.visible = true;
+        self.textarea = create_textarea();
+    }
+
+    /// Open with `text` already typed, ready to be completed
+    pub fn open_with(&mut self, text: &str) {
+        self.open();
+        self.textarea.insert_str(text);
+    }
+
+    pub fn close(&mut self) {
+        self.visible = false;
+        self.textarea = create_textarea();
+    }
+
+    pub fn text(&self) -> &str {
+        self.textarea.lines()[0].as_ref()
+    }
+
+    pub fn textarea_mut(&mut self) -> &mut TextArea<'static> {
+        &mut self.textarea
+    }
+}
+
+#[cfg(test)]
+#[path = "command_line_state_tests.rs"]
+mod command_line_state_tests;
@@ -0,0 +1,192 @@
+//! Tests for command_line_state
+
+use super::*;
+
+#[test]
+fn test_parse_session_save_with_name() {
+    assert_eq!(
+        LineCommand::parse("session save incident-42"),
+        Ok(LineCommand::SessionSave(Some("incident-42".to_string())))
+    );
+}
+
+#[test]
+fn test_parse_session_save_without_name() {
+    assert_eq!(
+        LineCommand::parse("  session   save "),
+        Ok(LineCommand::SessionSave(None))
+    );
+}
+
+#[test]
+fn test_parse_messages() {
+    assert_eq!(LineCommand::parse("messages"), Ok(LineCommand::Messages));
+}
+
+#[test]
+fn test_parse_share() {
+    assert_eq!(
+        LineCommand::parse("share"),
+        Ok(LineCommand::Share { variables: false })
+    );
+    assert_eq!(
+        LineCommand::parse("share vars"),
+        Ok(LineCommand::Share { variables: true })
+    );
+    assert_eq!(
+        LineCommand::parse("share all"),
+        Err("Usage: :share [vars]".to_string())
+    );
+}
+
+#[test]
+fn test_parse_validate() {
+    assert_eq!(
+        LineCommand::parse("validate"),
+        Ok(LineCommand::Validate(None))
+    );
+    assert_eq!(
+        LineCommand::parse("validate order.schema.json"),
+        Ok(LineCommand::Validate(Some("order.schema.json".to_string())))
+    );
+    assert_eq!(
+        LineCommand::parse("validate a.json b.json"),
+        Err("Usage: :validate [FILE]".to_string())
+    );
+}
+
+#[test]
+fn test_parse_schema() {
+    assert_eq!(LineCommand::parse("schema"), Ok(LineCommand::Schema(None)));
+    assert_eq!(
+        LineCommand::parse("schema out.json"),
+        Ok(LineCommand::Schema(Some("out.json".to_string())))
+    );
+    assert_eq!(
+        LineCommand::parse("schema a b"),
+        Err("Usage: :schema [FILE]".to_string())
+    );
+}
+
+#[test]
+fn test_parse_lint() {
+    assert_eq!(LineCommand::parse("lint"), Ok(LineCommand::Lint));
+    assert_eq!(
+        LineCommand::parse("lint x"),
+        Err("Unknown command: lint".to_string())
+    );
+}
+
+#[test]
+fn test_parse_diff() {
+    assert_eq!(LineCommand::parse("diff"), Ok(LineCommand::Diff));
+    assert_eq!(
+        LineCommand::parse("diff x"),
+        Err("Unknown command: diff".to_string())
+    );
+}
+
+#[test]
+fn test_parse_sizes() {
+    assert_eq!(LineCommand::parse("sizes"), Ok(LineCommand::Sizes));
+    assert_eq!(
+        LineCommand::parse("sizes x"),
+        Err("Unknown command: sizes".to_string())
+    );
+}
+
+#[test]
+fn test_parse_histogram() {
+    assert_eq!(
+        LineCommand::parse("histogram"),
+        Ok(LineCommand::Histogram(None))
+    );
+    assert_eq!(
+        LineCommand::parse(r#"histogram .[]["app name"]"#),
+        Ok(LineCommand::Histogram(Some(
+            r#".[]["app name"]"#.to_string()
+        )))
+    );
+}
+
+#[test]
+fn test_parse_columns() {
+    assert_eq!(LineCommand::parse("columns"), Ok(LineCommand::Columns));
+}
+
+#[test]
+fn test_parse_templates() {
+    assert_eq!(LineCommand::parse("templates"), Ok(LineCommand::Templates));
+}
+
+#[test]
+fn test_parse_fold() {
+    assert_eq!(LineCommand::parse("fold"), Ok(LineCommand::Fold(1)));
+    assert_eq!(LineCommand::parse("fold 3"), Ok(LineCommand::Fold(3)));
+    assert_eq!(
+        LineCommand::parse("fold deep"),
+        Err("Usage: :fold [DEPTH]".to_string())
+    );
+    assert_eq!(LineCommand::parse("unfold"), Ok(LineCommand::Unfold));
+}
+
+#[test]
+fn test_parse_edit() {
+    assert_eq!(LineCommand::parse("edit"), Ok(LineCommand::Edit));
+}
+
+#[test]
+fn test_parse_tab() {
+    assert_eq!(LineCommand::parse("tab new"), Ok(LineCommand::TabNew));
+    assert_eq!(LineCommand::parse("tab close"), Ok(LineCommand::TabClose));
+    assert_eq!(LineCommand::parse("tab 3"), Ok(LineCommand::Tab(3)));
+    assert_eq!(
+        LineCommand::parse("tab"),
+        Err("Usage: :tab new|close|N".to_string())
+    );
+    assert!(LineCommand::parse("tab x").is_err());
+}
+
+#[test]
+fn test_parse_compare() {
+    assert_eq!(
+        LineCommand::parse("compare 2"),
+        Ok(LineCommand::Compare(2, None))
+    );
+    assert_eq!(
+        LineCommand::parse("compare 1 3"),
+        Ok(LineCommand::Compare(1, Some(3)))
+    );
+    assert_eq!(
+        LineCommand::parse("compare"),
+        Err("Usage: :compare TAB [TAB]".to_string())
+    );
+    assert!(LineCommand::parse("compare a b").is_err());
+    assert!(LineCommand::parse("compare 1 2 3").is_err());
+}
+
+#[test]
+fn test_parse_rejects_unknown_commands() {
+    assert_eq!(
+        LineCommand::parse("write out.json"),
+        Err("Unknown command: write".to_string())
+    );
+    assert_eq!(
+        LineCommand::parse("session load x"),
+        Err("Usage: :session save [NAME]".to_string())
+    );
+    assert!(LineCommand::parse("").is_err());
+}
+
+#[test]
+fn test_open_starts_with_empty_text() {
+    let mut state = CommandLineState::new();
+    state.open();
+    state.textarea_mut().insert_str("session save a");
+    state.close();
+    assert!(!state.is_visible());
+
+    state.open();
+    assert!(state.is_visible());
+    assert_eq!(state.text(), "");
+}
@@ -2,78 +2,106 @@
 // This module handles loading and parsing configuration from ~/.config/jiq/config.toml
 
 pub mod ai_types;
+pub mod commands;
+pub mod layers;
 mod types;
+mod watcher;
 
 // AI types are used internally via Config struct
-pub use types::{ClipboardBackend, Config};
+pub use types::{
+    ClipboardBackend, Config, HistoryConfig, HistoryDedupe, KeyBindings, PluginConfig,
+    RedactionConfig, SyntaxConfig,
+};
 
 // Re-export for integration tests
 #[allow(unused_imports)]
 pub use ai_types::{AiConfig, AiProviderType, AnthropicConfig};
+pub use layers::{Override, Source};
 #[allow(unused_imports)]
-pub use types::TooltipConfig;
+pub use types::{
+    CsvConfig, EditorConfig, ExitConfig, QueryConfig, ThemeColor, TooltipConfig, XmlConfig,
+};
+pub use watcher::{ConfigReload, ConfigWatcher};
 
+use std::collections::BTreeMap;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 /// Result of loading configuration
 pub struct ConfigResult {
     pub config: Config,
+    /// Why the config file couldn't be used
     pub warning: Option<String>,
+    /// Source of each setting not at its default, by dotted key
+    pub sources: BTreeMap<String, Source>,
+    /// Overrides that couldn't be applied, with the reason
+    pub ignored: Vec<String>,
 }
 
-/// Loads configuration from ~/.config/jiq/config.toml
+/// Loads configuration from ~/.config/jiq/config.toml, then applies `overrides`
 /// Returns default configuration if file doesn't exist or on parse errors
-pub fn load_config() -> ConfigResult {
-    let config_path = get_config_path();
+pub fn load_config(overrides: &[Override]) -> ConfigResult {
+    load_config_from(&get_config_path(), overrides)
+}
+
+/// Loads configuration from `config_path`, see `load_config`
+pub fn load_config_from(config_path: &Path, overrides: &[Override]) -> ConfigResult {
+    let (file, warning) = read_config_file(config_path);
+    let resolved = layers::resolve(file, overrides);
+    ConfigResult {
+        config: resolved.config,
+        warning,
+        sources: resolved.sources,
+        ignored: resolved.ignored,
+    }
+}
 
-    // If file doesn't exist, return defaults silently
+/// Settings in the file at `config_path`, none when it is missing or
+/// invalid (with a warning)
+fn read_config_file(config_path: &Path) -> (toml::Table, Option<String>) {
+    // If file doesn't exist, use defaults silently
     if !config_path.exists() {
-        return ConfigResult {
-            config: Config::default(),
-            warning: None,
-        };
+        return (toml::Table::new(), None);
     }
 
     // Try to read the file
-    let contents = match fs::read_to_string(&config_path) {
+    let contents = match fs::read_to_string(config_path) {
         Ok(contents) => contents,
         Err(e) => {
-            #[cfg(debug_assertions)]
             log::error!("Failed to read config file {:?}: {}", config_path, e);
-            return ConfigResult {
-                config: Config::default(),
-                warning: Some(format!("Failed to read config: {}", e)),
-            };
+            return (
+                toml::Table::new(),
+                Some(format!("Failed to read config: {}", e)),
+            );
         }
     };
 
-    // Try to parse TOML
-    match toml::from_str::<Config>(&contents) {
-        Ok(config) => ConfigResult {
-            config,
-            warning: None,
-        },
+    // Try to parse TOML; parsing as a Config first reports wrong types
+    // with their position
+    match toml::from_str::<Config>(&contents).and_then(|_| toml::from_str(&contents)) {
+        Ok(table) => (table, None),
         Err(e) => {
-            #[cfg(debug_assertions)]
             log::error!("Failed to parse config file {:?}: {}", config_path, e);
-            ConfigResult {
-                config: Config::default(),
-                warning: Some(format!("Invalid config: {}", e)),
-            }
+            (toml::Table::new(), Some(format!("Invalid config: {}", e)))
         }
     }
 }
 
 /// Returns the path to the configuration file
 ///
-/// Always uses ~/.config/jiq/config.toml on all platforms for consistency.
-fn get_config_path() -> PathBuf {
-    dirs::home_dir()
-        .unwrap_or_else(|| PathBuf::from("."))
-        .join(".config")
-        .join("jiq")
-        .join("config.toml")
+/// `$XDG_CONFIG_HOME/jiq/config.toml`, by default `~/.config/jiq/config.toml`
+/// (`%APPDATA%\jiq\config.toml` on Windows), see `crate::paths`.
+pub fn get_config_path() -> PathBuf {
+    get_config_dir().join("config.toml")
+}
+
+/// Returns the path to the user jq library included in every query
+pub fn get_library_path() -> PathBuf {
+    get_config_dir().join(crate::query::library::LIBRARY_FILE)
+}
+
+fn get_config_dir() -> PathBuf {
+    crate::paths::config_dir().unwrap_or_else(|| PathBuf::from(".").join(".config").join("jiq"))
 }
 
 #[cfg(test)]
@@ -1,6 +1,6 @@
 // AI configuration type definitions
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 /// Test constant for max context length (matches default)
 #[cfg(test)]
@@ -19,7 +19,7 @@ fn default_max_context_length() -> u32 {
 }
 
 /// AI provider selection
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
 #[serde(rename_all = "lowercase")]
 pub enum AiProviderType {
     Anthropic,
@@ -29,7 +29,7 @@ pub enum AiProviderType {
 }
 
 /// Anthropic-specific configuration
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct AnthropicConfig {
     /// API key for Anthropic (required when AI is enabled)
     pub api_key: Option<String>,
@@ -51,7 +51,7 @@ impl Default for AnthropicConfig {
 }
 
 /// Bedrock provider configuration
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
 pub struct BedrockConfig {
     /// AWS region for Bedrock API calls (required)
     pub region: Option<String>,
@@ -62,7 +62,7 @@ pub struct BedrockConfig {
 }
 
 /// OpenAI-specific configuration
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
 pub struct OpenAiConfig {
     /// API key for OpenAI (required when AI is enabled with OpenAI provider)
     pub api_key: Option<String>,
@@ -73,7 +73,7 @@ pub struct OpenAiConfig {
 }
 
 /// Gemini-specific configuration
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
 pub struct GeminiConfig {
     /// API key for Gemini (required when AI is enabled with Gemini provider)
     pub api_key: Option<String>,
@@ -81,8 +81,42 @@ pub struct GeminiConfig {
     pub model: Option<String>,
 }
 
+/// Session budget for AI requests
+///
+/// Token counts are estimated from the text sent and received. Prices are
+/// per million tokens and only used for the cost estimate.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct AiBudgetConfig {
+    /// Estimated tokens a session may use before requests stop
+    pub max_tokens: Option<u64>,
+    /// Estimated cost a session may reach before requests stop
+    pub max_cost: Option<f64>,
+    /// Price per million input tokens
+    #[serde(default)]
+    pub input_cost_per_million: f64,
+    /// Price per million output tokens
+    #[serde(default)]
+    pub output_cost_per_million: f64,
+}
+
+/// Custom prompts for AI requests
+///
+/// `success` and `error` replace the built-in prompt for queries that ran
+/// and queries that failed. They may use the placeholders `{query}`,
+/// `{cursor}`, `{sample}`, `{error}`, `{schema}`, `{base_query}`,
+/// `{base_result}` and `{default}`, the built-in prompt.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct AiPromptsConfig {
+    /// Instructions sent as the system prompt of every request
+    pub system: Option<String>,
+    /// Prompt for a query that ran
+    pub success: Option<String>,
+    /// Prompt for a query that failed
+    pub error: Option<String>,
+}
+
 /// AI assistant configuration section
-#[derive(Debug, Clone, Default, Deserialize)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
 pub struct AiConfig {
     /// Whether AI features are enabled
     #[serde(default)]
@@ -90,9 +124,18 @@ pub struct AiConfig {
     /// Which AI provider to use (None when not configured)
     #[serde(default)]
     pub provider: Option<AiProviderType>,
+    /// Providers tried in order when `provider` fails, e.g. on rate limits
+    #[serde(default)]
+    pub fallback: Vec<AiProviderType>,
     /// Maximum character length for JSON context samples sent to AI
     #[serde(default = "default_max_context_length")]
     pub max_context_length: u32,
+    /// Token and cost limits for a session
+    #[serde(default)]
+    pub budget: AiBudgetConfig,
+    /// Custom system and request prompts
+    #[serde(default)]
+    pub prompts: AiPromptsConfig,
     /// Anthropic-specific configuration
     #[serde(default)]
     pub anthropic: AnthropicConfig,
@@ -107,6 +150,20 @@ pub struct AiConfig {
     pub gemini: GeminiConfig,
 }
 
+impl AiConfig {
+    /// Fallback providers in the order they are tried, without the primary
+    /// provider and repeats
+    pub fn fallback_providers(&self) -> Vec<AiProviderType> {
+        let mut providers: Vec<AiProviderType> = Vec::new();
+        for provider in &self.fallback {
+            if Some(*provider) != self.provider && !providers.contains(provider) {
+                providers.push(*provider);
+            }
+        }
+        providers
+    }
+}
+
 #[cfg(test)]
 #[path = "ai_types_tests.rs"]
 mod ai_types_tests;
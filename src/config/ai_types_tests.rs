@@ -244,3 +244,69 @@ model = "gpt-4o-mini"
     let config: Config = toml::from_str(toml).unwrap();
     assert_eq!(config.ai.openai.api_key, Some("   ".to_string()));
 }
+
+#[test]
+fn test_parse_fallback_providers() {
+    let toml = r#"
+[ai]
+enabled = true
+provider = "anthropic"
+fallback = ["openai", "anthropic", "gemini", "openai"]
+"#;
+    let config: Config = toml::from_str(toml).unwrap();
+    assert_eq!(
+        config.ai.fallback_providers(),
+        vec![AiProviderType::Openai, AiProviderType::Gemini]
+    );
+}
+
+#[test]
+fn test_no_fallback_by_default() {
+    let config: Config = toml::from_str("[ai]\nprovider = \"openai\"\n").unwrap();
+    assert!(config.ai.fallback_providers().is_empty());
+}
+
+#[test]
+fn test_parse_budget() {
+    let toml = r#"
+[ai]
+enabled = true
+
+[ai.budget]
+max_tokens = 200000
+max_cost = 0.5
+input_cost_per_million = 0.8
+output_cost_per_million = 4.0
+"#;
+    let config: Config = toml::from_str(toml).unwrap();
+    assert_eq!(config.ai.budget.max_tokens, Some(200_000));
+    assert_eq!(config.ai.budget.max_cost, Some(0.5));
+    assert_eq!(config.ai.budget.output_cost_per_million, 4.0);
+}
+
+#[test]
+fn test_no_budget_by_default() {
+    let config: Config = toml::from_str("[ai]\nenabled = true\n").unwrap();
+    assert!(config.ai.budget.max_tokens.is_none());
+    assert!(config.ai.budget.max_cost.is_none());
+    assert_eq!(config.ai.budget.input_cost_per_million, 0.0);
+}
+
+#[test]
+fn test_parse_prompts() {
+    let toml = r#"
+[ai.prompts]
+system = "Answer in German"
+error = "Fix {query}: {error}"
+"#;
+    let config: Config = toml::from_str(toml).unwrap();
+    assert_eq!(
+        config.ai.prompts.system,
+        Some("Answer in German".to_string())
+    );
+    assert_eq!(
+        config.ai.prompts.error,
+        Some("Fix {query}: {error}".to_string())
+    );
+    assert!(config.ai.prompts.success.is_none());
+}
@@ -0,0 +1,222 @@
+//! `jiq config init|path|validate|edit`
+//!
+//! Validation reports each problem with its line and column: TOML syntax
+//! errors and values of the wrong type fail, keys jiq doesn't know (usually
+//! typos, which would otherwise be ignored silently) are warned about.
+
+use std::fs;
+use std::io::{self, Write};
+use std::ops::Range;
+use std::path::Path;
+use std::process::Command;
+
+use super::Config;
+use crate::external::ExternalTool;
+
+/// Commented config with every setting at its default, written by `init`
+pub const DEFAULT_CONFIG: &str = include_str!("default_config.toml");
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+/// Something wrong in a config file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Problem {
+    pub severity: Severity,
+    /// Line and column, from 1, when known
+    pub position: Option<(usize, usize)>,
+    pub message: String,
+}
+
+impl Problem {
+    fn error(position: Option<(usize, usize)>, message: String) -> Self {
+        Self {
+            severity: Severity::Error,
+            position,
+            message,
+        }
+    }
+
+    fn warning(position: Option<(usize, usize)>, message: String) -> Self {
+        Self {
+            severity: Severity::Warning,
+            position,
+            message,
+        }
+    }
+}
+
+/// Problems in the config file `contents`, an empty list when it is valid
+pub fn validate(contents: &str) -> Vec<Problem> {
+    let mut unknown = Vec::new();
+    let result: Result<Config, _> = serde_ignored::deserialize(
+        toml::Deserializer::new(contents),
+        |path: serde_ignored::Path| unknown.push(path.to_string()),
+    );
+    match result {
+        Ok(_) => unknown
+            .iter()
+            .map(|key| {
+                Problem::warning(
+                    key_position(contents, key),
+                    format!("unknown key `{}`, ignored", key),
+                )
+            })
+            .collect(),
+        Err(e) => {
+            let position = e.span().map(|span| offset_position(contents, span));
+            vec![Problem::error(position, e.message().trim().to_string())]
+        }
+    }
+}
+
+/// Line and column, from 1, of the start of `span` in `contents`
+fn offset_position(contents: &str, span: Range<usize>) -> (usize, usize) {
+    let start = span.start.min(contents.len());
+    let before = &contents[..start];
+    let line = before.matches('\n').count() + 1;
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    let column = contents[line_start..start].chars().count() + 1;
+    (line, column)
+}
+
+/// Where the key or table at dotted `path` is set, e.g. `display.sparklines`
+///
+/// Array indices in the path (`plugins.0.nme`) are ignored, so the first
+/// matching key in an array of tables is found.
+fn key_position(contents: &str, path: &str) -> Option<(usize, usize)> {
+    let wanted: Vec<&str> = path
+        .split('.')
+        .filter(|segment| segment.parse::<usize>().is_err())
+        .collect();
+    let mut table: Vec<String> = Vec::new();
+    for (index, line) in contents.lines().enumerate() {
+        let trimmed = line.trim_start();
+        let indent = line.len() - trimmed.len();
+        if trimmed.starts_with('[') {
+            let name = trimmed
+                .trim_start_matches('[')
+                .split(']')
+                .next()
+                .unwrap_or_default();
+            table = name.split('.').map(|s| s.trim().to_string()).collect();
+            if table == wanted {
+                return Some((index + 1, indent + 1));
+            }
+        } else if let Some((key, _)) = trimmed.split_once('=')
+            && !trimmed.starts_with('#')
+        {
+            let key = key.trim().trim_matches('"');
+            let mut full: Vec<&str> = table.iter().map(String::as_str).collect();
+            full.extend(key.split('.').map(str::trim));
+            if full == wanted {
+                return Some((index + 1, indent + 1));
+            }
+        }
+    }
+    None
+}
+
+/// `problems` as `path:line:column: severity: message` lines, each followed
+/// by the offending line of `contents` and a caret under the column
+pub fn format_problems(path: &Path, contents: &str, problems: &[Problem]) -> String {
+    let mut out = String::new();
+    for problem in problems {
+        let severity = match problem.severity {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+        };
+        match problem.position {
+            Some((line, column)) => {
+                out.push_str(&format!(
+                    "{}:{}:{}: {}: {}\n",
+                    path.display(),
+                    line,
+                    column,
+                    severity,
+                    problem.message
+                ));
+                if let Some(source) = contents.lines().nth(line - 1) {
+                    out.push_str(&format!("  {}\n", source));
+                    out.push_str(&format!("  {}^\n", " ".repeat(column - 1)));
+                }
+            }
+            None => out.push_str(&format!(
+                "{}: {}: {}\n",
+                path.display(),
+                severity,
+                problem.message
+            )),
+        }
+    }
+    out
+}
+
+/// Write the default config to `path` unless a config exists there
+/// (`force` replaces it)
+pub fn init(path: &Path, force: bool) -> Result<(), String> {
+    if path.exists() && !force {
+        return Err(format!(
+            "{} already exists, use --force to replace it",
+            path.display()
+        ));
+    }
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|e| format!("Cannot create {}: {}", parent.display(), e))?;
+    }
+    fs::write(path, DEFAULT_CONFIG).map_err(|e| format!("Cannot write {}: {}", path.display(), e))
+}
+
+/// Check the config at `path`, writing its problems to `out`; true when it
+/// has no errors (a missing file is valid, as the defaults are used)
+pub fn check(path: &Path, out: &mut impl Write) -> io::Result<bool> {
+    if !path.exists() {
+        writeln!(
+            out,
+            "{} does not exist, the defaults are used",
+            path.display()
+        )?;
+        return Ok(true);
+    }
+    let contents = fs::read_to_string(path)?;
+    let problems = validate(&contents);
+    if problems.is_empty() {
+        writeln!(out, "{} is valid", path.display())?;
+        return Ok(true);
+    }
+    write!(out, "{}", format_problems(path, &contents, &problems))?;
+    Ok(problems.iter().all(|p| p.severity == Severity::Warning))
+}
+
+/// Open the config at `path` in `$VISUAL` or `$EDITOR`, writing the
+/// default config first if there is none, and check it once the editor exits
+pub fn edit(path: &Path, out: &mut impl Write) -> Result<bool, String> {
+    if !path.exists() {
+        init(path, false)?;
+    }
+    let command = ExternalTool::Editor.command_line(|name| std::env::var(name).ok());
+    let Some((program, args)) = command.split_first() else {
+        return Err("No editor set in $VISUAL or $EDITOR".to_string());
+    };
+    let status = Command::new(program)
+        .args(args)
+        .arg(path)
+        .status()
+        .map_err(|e| format!("Cannot run editor `{}`: {}", command.join(" "), e))?;
+    if !status.success() {
+        return Err(format!(
+            "Editor `{}` exited with {}",
+            command.join(" "),
+            status
+        ));
+    }
+    check(path, out).map_err(|e| format!("Cannot read {}: {}", path.display(), e))
+}
+
+#[cfg(test)]
+#[path = "commands_tests.rs"]
+mod commands_tests;
@@ -0,0 +1,150 @@
+//! Tests for config/commands
+
+use super::*;
+use std::path::PathBuf;
+use tempfile::TempDir;
+
+#[test]
+fn test_default_config_is_valid() {
+    assert!(validate(DEFAULT_CONFIG).is_empty());
+}
+
+#[test]
+fn test_default_config_parses_to_defaults() {
+    let config: Config = toml::from_str(DEFAULT_CONFIG).unwrap();
+    let defaults = Config::default();
+    assert_eq!(config.query.cache_size, defaults.query.cache_size);
+    assert_eq!(config.exit.recover, defaults.exit.recover);
+    assert_eq!(config.display.sparkline, defaults.display.sparkline);
+    assert!(!config.ai.enabled);
+}
+
+#[test]
+fn test_valid_config_has_no_problems() {
+    assert!(validate("[display]\nsparkline = false\n").is_empty());
+}
+
+#[test]
+fn test_syntax_error_position() {
+    let problems = validate("[display]\nsparkline = \n");
+
+    assert_eq!(problems.len(), 1);
+    assert_eq!(problems[0].severity, Severity::Error);
+    assert_eq!(problems[0].position.map(|(line, _)| line), Some(2));
+}
+
+#[test]
+fn test_wrong_type_position() {
+    let problems = validate("[query]\ncache_size = \"many\"\n");
+
+    assert_eq!(problems.len(), 1);
+    assert_eq!(problems[0].severity, Severity::Error);
+    assert_eq!(problems[0].position, Some((2, 14)));
+}
+
+#[test]
+fn test_unknown_key_warning() {
+    let problems = validate("[display]\nsparkline = true\n  sticky_heder = false\n");
+
+    assert_eq!(
+        problems,
+        vec![Problem::warning(
+            Some((3, 3)),
+            "unknown key `display.sticky_heder`, ignored".to_string()
+        )]
+    );
+}
+
+#[test]
+fn test_unknown_table_warning() {
+    let problems = validate("[dispaly]\nsparkline = true\n");
+
+    assert_eq!(problems.len(), 1);
+    assert_eq!(problems[0].position, Some((1, 1)));
+    assert!(problems[0].message.contains("`dispaly`"));
+}
+
+#[test]
+fn test_unknown_key_in_plugin() {
+    let contents = "[[plugins]]\nname = \"yaml\"\ncommand = [\"yq\"]\nnme = \"x\"\n";
+    let problems = validate(contents);
+
+    assert_eq!(problems.len(), 1);
+    assert_eq!(problems[0].position, Some((4, 1)));
+}
+
+#[test]
+fn test_format_problems_points_at_column() {
+    let contents = "[query]\ncache_size = \"many\"\n";
+    let problems = validate(contents);
+
+    let text = format_problems(Path::new("config.toml"), contents, &problems);
+
+    let lines: Vec<&str> = text.lines().collect();
+    assert!(lines[0].starts_with("config.toml:2:14: error: "));
+    assert_eq!(lines[1], "  cache_size = \"many\"");
+    assert_eq!(lines[2], "               ^");
+}
+
+#[test]
+fn test_format_problem_without_position() {
+    let problems = [Problem::warning(None, "odd".to_string())];
+    let text = format_problems(Path::new("c.toml"), "", &problems);
+    assert_eq!(text, "c.toml: warning: odd\n");
+}
+
+fn config_path(dir: &TempDir) -> PathBuf {
+    dir.path().join("jiq").join("config.toml")
+}
+
+#[test]
+fn test_init_writes_default_config() {
+    let dir = TempDir::new().unwrap();
+    let path = config_path(&dir);
+
+    init(&path, false).unwrap();
+
+    assert_eq!(fs::read_to_string(&path).unwrap(), DEFAULT_CONFIG);
+}
+
+#[test]
+fn test_init_keeps_existing_config() {
+    let dir = TempDir::new().unwrap();
+    let path = config_path(&dir);
+    init(&path, false).unwrap();
+    fs::write(&path, "[display]\n").unwrap();
+
+    let err = init(&path, false).unwrap_err();
+
+    assert!(err.contains("already exists"));
+    assert_eq!(fs::read_to_string(&path).unwrap(), "[display]\n");
+
+    init(&path, true).unwrap();
+    assert_eq!(fs::read_to_string(&path).unwrap(), DEFAULT_CONFIG);
+}
+
+#[test]
+fn test_check_missing_file_is_valid() {
+    let dir = TempDir::new().unwrap();
+    let mut out = Vec::new();
+
+    assert!(check(&config_path(&dir), &mut out).unwrap());
+    assert!(
+        String::from_utf8(out)
+            .unwrap()
+            .contains("defaults are used")
+    );
+}
+
+#[test]
+fn test_check_warnings_pass_errors_fail() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("config.toml");
+    let mut out = Vec::new();
+
+    fs::write(&path, "[display]\nsparklines = true\n").unwrap();
+    assert!(check(&path, &mut out).unwrap());
+
+    fs::write(&path, "[display]\nsparkline = 3\n").unwrap();
+    assert!(!check(&path, &mut out).unwrap());
+}
@@ -0,0 +1,290 @@
+//! Config resolved in layers: defaults < file < environment < command line
+//!
+//! Any setting can be given without a config file, as an environment
+//! variable `JIQ_<SECTION>__<KEY>` (`JIQ_DISPLAY__SPARKLINE=false`,
+//! `JIQ_AI__ANTHROPIC__API_KEY=...`) or with `--set section.key=value`.
+//! Values are read as TOML (`true`, `3`, `["a", "b"]`) and as a string
+//! otherwise, or when the setting expects one.
+
+use std::collections::BTreeMap;
+use std::ffi::OsString;
+use std::fmt;
+
+use super::Config;
+
+/// Prefix of environment variables overriding settings
+pub const ENV_PREFIX: &str = "JIQ_";
+
+/// Separates the section from the key in an environment variable name
+const ENV_SEPARATOR: &str = "__";
+
+/// Shown instead of secrets in `--show-config`
+const MASK: &str = "\"********\"";
+
+/// Where the value of a setting comes from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    Default,
+    File,
+    /// Environment variable of this name
+    Env(String),
+    /// Command line flag, e.g. `--set` or `--no-color`
+    Flag(String),
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Source::Default => write!(f, "default"),
+            Source::File => write!(f, "file"),
+            Source::Env(name) => write!(f, "env {}", name),
+            Source::Flag(flag) => write!(f, "{}", flag),
+        }
+    }
+}
+
+/// Setting given outside the config file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Override {
+    /// Dotted key, e.g. `display.sparkline`
+    pub key: String,
+    /// Value as written, see `parse_value`
+    pub value: String,
+    pub source: Source,
+}
+
+impl Override {
+    pub fn new(key: impl Into<String>, value: impl Into<String>, source: Source) -> Self {
+        Self {
+            key: key.into(),
+            value: value.into(),
+            source,
+        }
+    }
+
+    /// Where the override was given, for messages
+    fn origin(&self) -> String {
+        match &self.source {
+            Source::Env(name) => name.clone(),
+            source => format!("{} {}", source, self.key),
+        }
+    }
+}
+
+/// Overrides from the `JIQ_<SECTION>__<KEY>` variables among `vars`,
+/// ordered by key; other variables, like `JIQ_LOG`, are skipped
+pub fn env_overrides(vars: impl IntoIterator<Item = (OsString, OsString)>) -> Vec<Override> {
+    let mut overrides: Vec<Override> = vars
+        .into_iter()
+        .filter_map(|(name, value)| {
+            let name = name.into_string().ok()?;
+            let key = name.strip_prefix(ENV_PREFIX)?;
+            if !key.contains(ENV_SEPARATOR) {
+                return None;
+            }
+            let key = key
+                .split(ENV_SEPARATOR)
+                .map(str::to_lowercase)
+                .collect::<Vec<_>>()
+                .join(".");
+            Some(Override::new(
+                key,
+                value.into_string().ok()?,
+                Source::Env(name),
+            ))
+        })
+        .collect();
+    overrides.sort_by(|a, b| a.key.cmp(&b.key));
+    overrides
+}
+
+/// Parse the `KEY=VALUE` of `--set`
+pub fn parse_set(arg: &str) -> Result<Override, String> {
+    let Some((key, value)) = arg.split_once('=') else {
+        return Err(format!("expected KEY=VALUE, got '{}'", arg));
+    };
+    let key = key.trim();
+    if key.split('.').any(|segment| segment.trim().is_empty()) {
+        return Err(format!("invalid key '{}'", key));
+    }
+    Ok(Override::new(
+        key,
+        value.trim(),
+        Source::Flag("--set".to_string()),
+    ))
+}
+
+/// `text` as a TOML value, or as a string when it isn't one
+pub fn parse_value(text: &str) -> toml::Value {
+    format!("value = {}", text)
+        .parse::<toml::Table>()
+        .ok()
+        .and_then(|mut table| table.remove("value"))
+        .unwrap_or_else(|| toml::Value::String(text.to_string()))
+}
+
+/// Config resolved from its layers
+#[derive(Debug, Clone, Default)]
+pub struct Resolved {
+    pub config: Config,
+    /// Source of each setting not at its default, by dotted key
+    pub sources: BTreeMap<String, Source>,
+    /// Overrides that couldn't be applied, with the reason
+    pub ignored: Vec<String>,
+}
+
+/// Resolve the config file's `file` table with `overrides` applied on top
+/// in order; `file` is expected to be valid
+pub fn resolve(file: toml::Table, overrides: &[Override]) -> Resolved {
+    let mut sources = BTreeMap::new();
+    record_sources(&mut sources, None, &file, &Source::File);
+    let mut resolved = Resolved {
+        config: deserialize(file.clone()).map_or_else(|_| Config::default(), |(c, _)| c),
+        sources,
+        ignored: Vec::new(),
+    };
+
+    let mut table = file;
+    for o in overrides {
+        match apply(&table, o) {
+            Ok((applied, config, value)) => {
+                table = applied;
+                resolved.config = config;
+                record_value(&mut resolved.sources, &o.key, &value, &o.source);
+            }
+            Err(e) => resolved
+                .ignored
+                .push(format!("Ignoring {}: {}", o.origin(), e)),
+        }
+    }
+    resolved
+}
+
+/// `table` with the override set, the config it gives and the value used
+///
+/// A value read as something other than a string is tried as a string
+/// too, so `JIQ_AI__OPENAI__MODEL=4` still sets a model name.
+fn apply(table: &toml::Table, o: &Override) -> Result<(toml::Table, Config, toml::Value), String> {
+    let parsed = parse_value(&o.value);
+    let as_string = (!parsed.is_str()).then(|| toml::Value::String(o.value.clone()));
+    let mut first_error = None;
+    for value in std::iter::once(parsed).chain(as_string) {
+        let mut candidate = table.clone();
+        insert(&mut candidate, &o.key, value.clone())?;
+        match deserialize(candidate.clone()) {
+            Ok((_, unknown)) if unknown.iter().any(|k| overlaps(k, &o.key)) => {
+                return Err(format!("unknown key `{}`", o.key));
+            }
+            Ok((config, _)) => return Ok((candidate, config, value)),
+            Err(e) => {
+                first_error.get_or_insert(e);
+            }
+        }
+    }
+    Err(first_error.unwrap_or_default())
+}
+
+/// Whether dotted keys `a` and `b` are the same or one contains the other
+fn overlaps(a: &str, b: &str) -> bool {
+    a == b || a.starts_with(&format!("{}.", b)) || b.starts_with(&format!("{}.", a))
+}
+
+/// Set dotted `key` in `table`, creating the tables on the way
+fn insert(table: &mut toml::Table, key: &str, value: toml::Value) -> Result<(), String> {
+    let segments: Vec<&str> = key.split('.').map(str::trim).collect();
+    let (last, parents) = segments.split_last().expect("split yields a segment");
+    let mut current = table;
+    for (i, segment) in parents.iter().enumerate() {
+        let entry = current
+            .entry(segment.to_string())
+            .or_insert_with(|| toml::Value::Table(toml::Table::new()));
+        current = entry
+            .as_table_mut()
+            .ok_or_else(|| format!("`{}` is not a table", segments[..=i].join(".")))?;
+    }
+    current.insert(last.to_string(), value);
+    Ok(())
+}
+
+/// `table` as a config, with the dotted keys it doesn't know
+fn deserialize(table: toml::Table) -> Result<(Config, Vec<String>), String> {
+    let mut unknown = Vec::new();
+    let config = serde_ignored::deserialize(toml::Value::Table(table), |path| {
+        unknown.push(path.to_string())
+    })
+    .map_err(|e: toml::de::Error| {
+        e.to_string()
+            .lines()
+            .map(str::trim)
+            .collect::<Vec<_>>()
+            .join(" ")
+    })?;
+    Ok((config, unknown))
+}
+
+fn record_sources(
+    sources: &mut BTreeMap<String, Source>,
+    prefix: Option<&str>,
+    table: &toml::Table,
+    source: &Source,
+) {
+    for (key, value) in table {
+        let key = match prefix {
+            Some(prefix) => format!("{}.{}", prefix, key),
+            None => key.clone(),
+        };
+        record_value(sources, &key, value, source);
+    }
+}
+
+fn record_value(
+    sources: &mut BTreeMap<String, Source>,
+    key: &str,
+    value: &toml::Value,
+    source: &Source,
+) {
+    match value {
+        toml::Value::Table(table) => record_sources(sources, Some(key), table, source),
+        _ => {
+            sources.insert(key.to_string(), source.clone());
+        }
+    }
+}
+
+/// Every setting of `config` as a `key = value  # source` line, with
+/// secrets masked; unset optional settings are left out
+pub fn show(config: &Config, sources: &BTreeMap<String, Source>) -> Result<String, String> {
+    let value = toml::Value::try_from(config).map_err(|e| e.to_string())?;
+    let mut lines = Vec::new();
+    if let toml::Value::Table(table) = value {
+        flatten(None, &table, &mut lines);
+    }
+    let mut out = String::new();
+    for (key, value) in lines {
+        let source = sources.get(&key).unwrap_or(&Source::Default);
+        let value = if key.ends_with("api_key") {
+            MASK.to_string()
+        } else {
+            value
+        };
+        out.push_str(&format!("{} = {}  # {}\n", key, value, source));
+    }
+    Ok(out)
+}
+
+fn flatten(prefix: Option<&str>, table: &toml::Table, lines: &mut Vec<(String, String)>) {
+    for (key, value) in table {
+        let key = match prefix {
+            Some(prefix) => format!("{}.{}", prefix, key),
+            None => key.clone(),
+        };
+        match value {
+            toml::Value::Table(table) => flatten(Some(&key), table, lines),
+            value => lines.push((key, value.to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "layers_tests.rs"]
+mod layers_tests;
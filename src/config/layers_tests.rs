@@ -0,0 +1,186 @@
+//! Tests for config/layers
+
+use super::*;
+
+fn env(pairs: &[(&str, &str)]) -> Vec<Override> {
+    env_overrides(pairs.iter().map(|(k, v)| (k.into(), v.into())))
+}
+
+fn table(contents: &str) -> toml::Table {
+    contents.parse().unwrap()
+}
+
+#[test]
+fn test_env_overrides_map_to_dotted_keys() {
+    let overrides = env(&[
+        ("JIQ_DISPLAY__STICKY_HEADER", "false"),
+        ("JIQ_AI__ANTHROPIC__API_KEY", "sk"),
+    ]);
+
+    assert_eq!(
+        overrides,
+        vec![
+            Override::new(
+                "ai.anthropic.api_key",
+                "sk",
+                Source::Env("JIQ_AI__ANTHROPIC__API_KEY".to_string())
+            ),
+            Override::new(
+                "display.sticky_header",
+                "false",
+                Source::Env("JIQ_DISPLAY__STICKY_HEADER".to_string())
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_env_overrides_skip_other_variables() {
+    let overrides = env(&[
+        ("JIQ_LOG", "debug"),
+        ("JIQ_LOG_FILE", "/tmp/jiq.log"),
+        ("HOME", "/root"),
+        ("NOT_JIQ__X", "1"),
+    ]);
+    assert!(overrides.is_empty());
+}
+
+#[test]
+fn test_parse_value_types() {
+    assert_eq!(parse_value("false"), toml::Value::Boolean(false));
+    assert_eq!(parse_value("3"), toml::Value::Integer(3));
+    assert_eq!(
+        parse_value("[\"a\", \"b\"]"),
+        toml::Value::Array(vec!["a".into(), "b".into()])
+    );
+    assert_eq!(parse_value("\"quoted\""), toml::Value::from("quoted"));
+    assert_eq!(parse_value("osc52"), toml::Value::from("osc52"));
+    assert_eq!(parse_value("a\nb = 1"), toml::Value::from("a\nb = 1"));
+}
+
+#[test]
+fn test_parse_set() {
+    let o = parse_set("display.sparkline = false").unwrap();
+    assert_eq!(o.key, "display.sparkline");
+    assert_eq!(o.value, "false");
+    assert_eq!(o.source, Source::Flag("--set".to_string()));
+
+    assert!(parse_set("display.sparkline").is_err());
+    assert!(parse_set("=1").is_err());
+    assert!(parse_set("display..sparkline=1").is_err());
+}
+
+#[test]
+fn test_layers_take_precedence_in_order() {
+    let file = table("[display]\nsparkline = false\nbreadcrumbs = false\n");
+    let overrides = [
+        Override::new(
+            "display.sparkline",
+            "true",
+            Source::Env("JIQ_DISPLAY__SPARKLINE".to_string()),
+        ),
+        Override::new("display.breadcrumbs", "true", Source::Env("E".to_string())),
+        Override::new(
+            "display.breadcrumbs",
+            "false",
+            Source::Flag("--set".to_string()),
+        ),
+    ];
+
+    let resolved = resolve(file, &overrides);
+
+    assert!(resolved.config.display.sparkline);
+    assert!(!resolved.config.display.breadcrumbs);
+    assert!(resolved.config.display.sticky_header);
+    assert_eq!(
+        resolved.sources.get("display.sparkline"),
+        Some(&Source::Env("JIQ_DISPLAY__SPARKLINE".to_string()))
+    );
+    assert_eq!(
+        resolved.sources.get("display.breadcrumbs"),
+        Some(&Source::Flag("--set".to_string()))
+    );
+    assert_eq!(resolved.sources.get("display.sticky_header"), None);
+    assert!(resolved.ignored.is_empty());
+}
+
+#[test]
+fn test_value_retried_as_string() {
+    let overrides = [Override::new(
+        "ai.openai.model",
+        "4",
+        Source::Flag("--set".to_string()),
+    )];
+
+    let resolved = resolve(toml::Table::new(), &overrides);
+
+    assert_eq!(resolved.config.ai.openai.model.as_deref(), Some("4"));
+}
+
+#[test]
+fn test_invalid_override_is_ignored() {
+    let file = table("[query]\ncache_size = 5\n");
+    let overrides = [
+        Override::new(
+            "query.cache_size",
+            "many",
+            Source::Env("JIQ_QUERY__CACHE_SIZE".to_string()),
+        ),
+        Override::new(
+            "display.sparklines",
+            "false",
+            Source::Flag("--set".to_string()),
+        ),
+        Override::new("query.cache_size.x", "1", Source::Flag("--set".to_string())),
+    ];
+
+    let resolved = resolve(file, &overrides);
+
+    assert_eq!(resolved.config.query.cache_size, 5);
+    assert_eq!(resolved.ignored.len(), 3);
+    assert!(resolved.ignored[0].starts_with("Ignoring JIQ_QUERY__CACHE_SIZE: "));
+    assert_eq!(
+        resolved.ignored[1],
+        "Ignoring --set display.sparklines: unknown key `display.sparklines`"
+    );
+    assert!(resolved.ignored[2].contains("`query.cache_size` is not a table"));
+    assert_eq!(
+        resolved.sources.get("query.cache_size"),
+        Some(&Source::File)
+    );
+}
+
+#[test]
+fn test_show_lists_values_and_sources() {
+    let file = table("[display]\nsparkline = false\n");
+    let overrides = [Override::new(
+        "editor.keybindings",
+        "emacs",
+        Source::Flag("--set".to_string()),
+    )];
+
+    let resolved = resolve(file, &overrides);
+    let text = show(&resolved.config, &resolved.sources).unwrap();
+
+    let lines: Vec<&str> = text.lines().collect();
+    assert!(lines.contains(&"display.sparkline = false  # file"));
+    assert!(lines.contains(&"editor.keybindings = \"emacs\"  # --set"));
+    assert!(lines.contains(&"query.cache_size = 16  # default"));
+    assert!(lines.contains(&"exit.recover = true  # default"));
+    assert!(!text.contains("ai.anthropic.model"));
+}
+
+#[test]
+fn test_show_masks_secrets() {
+    let overrides = [Override::new(
+        "ai.anthropic.api_key",
+        "sk-secret",
+        Source::Env("JIQ_AI__ANTHROPIC__API_KEY".to_string()),
+    )];
+
+    let resolved = resolve(toml::Table::new(), &overrides);
+    let text = show(&resolved.config, &resolved.sources).unwrap();
+
+    assert!(!text.contains("sk-secret"));
+    assert!(text.contains("ai.anthropic.api_key = \"********\"  # env JIQ_AI__ANTHROPIC__API_KEY"));
+}
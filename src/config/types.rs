@@ -1,11 +1,14 @@
 // Configuration type definitions
 
-use serde::Deserialize;
+use std::str::FromStr;
+
+use ratatui::style::Color;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 use super::ai_types::AiConfig;
 
 /// Clipboard backend selection
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize, Default)]
 #[serde(rename_all = "lowercase")]
 pub enum ClipboardBackend {
     #[default]
@@ -15,7 +18,7 @@ pub enum ClipboardBackend {
 }
 
 /// Clipboard configuration section
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct ClipboardConfig {
     #[serde(default)]
     pub backend: ClipboardBackend,
@@ -30,7 +33,7 @@ impl Default for ClipboardConfig {
 }
 
 /// Tooltip configuration section
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct TooltipConfig {
     #[serde(default = "default_auto_show")]
     pub auto_show: bool,
@@ -46,8 +49,362 @@ impl Default for TooltipConfig {
     }
 }
 
+/// Query execution configuration section
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct QueryConfig {
+    /// Number of recent query results kept in memory (0 disables the cache)
+    #[serde(default = "default_cache_size")]
+    pub cache_size: usize,
+    /// Directories searched for jq modules (`include`/`import`), like jq's `-L`
+    #[serde(default)]
+    pub library_paths: Vec<String>,
+}
+
+fn default_cache_size() -> usize {
+    crate::query::result_cache::DEFAULT_CACHE_SIZE
+}
+
+impl Default for QueryConfig {
+    fn default() -> Self {
+        QueryConfig {
+            cache_size: default_cache_size(),
+            library_paths: Vec::new(),
+        }
+    }
+}
+
+/// Key bindings of the query input
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum KeyBindings {
+    /// Modal editing with Insert and Normal modes
+    #[default]
+    Vim,
+    /// Always inserting, with readline keys (`Ctrl+A`/`Ctrl+E`, `Alt+F`/`Alt+B`,
+    /// `Ctrl+K`/`Ctrl+U`/`Ctrl+W`)
+    Emacs,
+}
+
+/// Query editor configuration section
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+pub struct EditorConfig {
+    /// Automatically insert closing `)`, `]`, `}` and `"` when typing the opener
+    #[serde(default)]
+    pub auto_pairs: bool,
+    #[serde(default)]
+    pub keybindings: KeyBindings,
+}
+
+/// Autocomplete configuration section
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+pub struct AutocompleteConfig {
+    /// Check field suggestions against the cached result before listing them
+    #[serde(default)]
+    pub validate_fields: bool,
+}
+
+/// Which repeated queries the history keeps
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum HistoryDedupe {
+    /// Keep only the latest run of each query
+    #[default]
+    Global,
+    /// Drop a query only when it repeats the one before it
+    Consecutive,
+    /// Keep every run
+    None,
+}
+
+/// Query history configuration section
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct HistoryConfig {
+    #[serde(default)]
+    pub dedupe: HistoryDedupe,
+    /// Most entries kept; older ones are dropped
+    #[serde(default = "default_history_max_entries")]
+    pub max_entries: usize,
+    /// Size in KiB past which the older entries are moved to `history.1`,
+    /// keeping the newest entries; 0 never rotates
+    #[serde(default = "default_history_max_file_kb")]
+    pub max_file_kb: u64,
+    /// Create history files readable by their owner only (mode 600 on Unix)
+    #[serde(default)]
+    pub private: bool,
+    /// Mask the text of string literals before saving a query
+    #[serde(default)]
+    pub redact_strings: bool,
+}
+
+fn default_history_max_entries() -> usize {
+    1000
+}
+
+fn default_history_max_file_kb() -> u64 {
+    512
+}
+
+impl Default for HistoryConfig {
+    fn default() -> Self {
+        HistoryConfig {
+            dedupe: HistoryDedupe::default(),
+            max_entries: default_history_max_entries(),
+            max_file_kb: default_history_max_file_kb(),
+            private: false,
+            redact_strings: false,
+        }
+    }
+}
+
+/// Exit behaviour configuration section
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ExitConfig {
+    /// Ask before quitting with `q`/`Ctrl+C` when the query would be lost
+    #[serde(default)]
+    pub confirm: bool,
+    /// Only ask once the session has been open this many seconds
+    #[serde(default = "default_confirm_after_secs")]
+    pub confirm_after_secs: u64,
+    /// Ask how to leave on Enter: print results, query or both, write a
+    /// file or copy
+    #[serde(default)]
+    pub menu: bool,
+    /// Keep a recovery file of the query and offer to restore it after an
+    /// unclean exit
+    #[serde(default = "default_recover")]
+    pub recover: bool,
+}
+
+fn default_confirm_after_secs() -> u64 {
+    300
+}
+
+fn default_recover() -> bool {
+    true
+}
+
+impl Default for ExitConfig {
+    fn default() -> Self {
+        ExitConfig {
+            confirm: false,
+            confirm_after_secs: default_confirm_after_secs(),
+            menu: false,
+            recover: default_recover(),
+        }
+    }
+}
+
+/// CSV input configuration section
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct CsvConfig {
+    /// Field separator (default: `,`, or a tab for `.tsv` files)
+    #[serde(default)]
+    pub delimiter: Option<char>,
+    #[serde(default = "default_csv_quote")]
+    pub quote: char,
+    /// Convert fields that look like numbers to JSON numbers
+    #[serde(default)]
+    pub infer_numbers: bool,
+}
+
+fn default_csv_quote() -> char {
+    '"'
+}
+
+impl Default for CsvConfig {
+    fn default() -> Self {
+        CsvConfig {
+            delimiter: None,
+            quote: default_csv_quote(),
+            infer_numbers: false,
+        }
+    }
+}
+
+/// XML input configuration section
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct XmlConfig {
+    /// Prepended to attribute names in the converted JSON
+    #[serde(default = "default_xml_attribute_prefix")]
+    pub attribute_prefix: String,
+    /// Key holding element text when the element also has attributes or children
+    #[serde(default = "default_xml_text_key")]
+    pub text_key: String,
+}
+
+fn default_xml_attribute_prefix() -> String {
+    "@".to_string()
+}
+
+fn default_xml_text_key() -> String {
+    "#text".to_string()
+}
+
+impl Default for XmlConfig {
+    fn default() -> Self {
+        XmlConfig {
+            attribute_prefix: default_xml_attribute_prefix(),
+            text_key: default_xml_text_key(),
+        }
+    }
+}
+
+/// Redacted view configuration section
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct RedactionConfig {
+    /// Start with sensitive values masked
+    #[serde(default)]
+    pub enabled: bool,
+    /// Values of fields whose name contains one of these, ignoring case,
+    /// are masked
+    #[serde(default = "default_redaction_fields")]
+    pub fields: Vec<String>,
+}
+
+fn default_redaction_fields() -> Vec<String> {
+    ["password", "token", "secret", "key"]
+        .map(String::from)
+        .to_vec()
+}
+
+impl Default for RedactionConfig {
+    fn default() -> Self {
+        RedactionConfig {
+            enabled: false,
+            fields: default_redaction_fields(),
+        }
+    }
+}
+
+/// Display configuration section
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct DisplayConfig {
+    /// Render without colors and with ASCII borders, as with `--no-color`
+    #[serde(default)]
+    pub no_color: bool,
+    /// Screen-reader friendly layout, as with `--screen-reader`
+    #[serde(default)]
+    pub screen_reader: bool,
+    /// Sparkline of the distribution above numeric results
+    #[serde(default = "default_sparkline")]
+    pub sparkline: bool,
+    /// Sparkline drawn as an image in terminals with the kitty or iTerm2
+    /// graphics protocol
+    #[serde(default = "default_sparkline_images")]
+    pub sparkline_images: bool,
+    /// Path of the enclosing object or array pinned above scrolled results
+    #[serde(default = "default_sticky_header")]
+    pub sticky_header: bool,
+    /// Path of the query split into clickable crumbs under the input field
+    #[serde(default = "default_breadcrumbs")]
+    pub breadcrumbs: bool,
+}
+
+fn default_sparkline() -> bool {
+    true
+}
+
+fn default_sparkline_images() -> bool {
+    true
+}
+
+fn default_sticky_header() -> bool {
+    true
+}
+
+fn default_breadcrumbs() -> bool {
+    true
+}
+
+impl Default for DisplayConfig {
+    fn default() -> Self {
+        DisplayConfig {
+            no_color: false,
+            screen_reader: false,
+            sparkline: default_sparkline(),
+            sparkline_images: default_sparkline_images(),
+            sticky_header: default_sticky_header(),
+            breadcrumbs: default_breadcrumbs(),
+        }
+    }
+}
+
+/// Color written as `#rrggbb`, a name such as `yellow` or `lightblue`, or a
+/// 256-color palette index
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ThemeColor(pub Color);
+
+impl Serialize for ThemeColor {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.0.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ThemeColor {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let text = String::deserialize(deserializer)?;
+        Color::from_str(&text)
+            .map(ThemeColor)
+            .map_err(|_| serde::de::Error::custom(format!("invalid color `{}`", text)))
+    }
+}
+
+/// Colors of the jq syntax in queries, each replacing the theme's default
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize, Default)]
+pub struct SyntaxConfig {
+    /// `if`, `then`, `reduce`, `as`, `null` and other keywords
+    #[serde(default)]
+    pub keyword: Option<ThemeColor>,
+    /// Built-in functions such as `map` and `select`
+    #[serde(default)]
+    pub function: Option<ThemeColor>,
+    /// `$name` variables
+    #[serde(default)]
+    pub variable: Option<ThemeColor>,
+    /// Keys of constructed objects, `{name: ...}`
+    #[serde(default)]
+    pub object_key: Option<ThemeColor>,
+    /// Field accessors such as `.name`
+    #[serde(default)]
+    pub field: Option<ThemeColor>,
+    /// Format strings such as `@base64` and `@csv`
+    #[serde(default)]
+    pub format: Option<ThemeColor>,
+    #[serde(default)]
+    pub string: Option<ThemeColor>,
+    #[serde(default)]
+    pub number: Option<ThemeColor>,
+    /// Operators, brackets and punctuation
+    #[serde(default)]
+    pub operator: Option<ThemeColor>,
+    /// `#` comments
+    #[serde(default)]
+    pub comment: Option<ThemeColor>,
+    /// Colors of nested bracket pairs, outermost first and repeating; an
+    /// empty list colors brackets as operators
+    #[serde(default)]
+    pub brackets: Option<Vec<ThemeColor>>,
+}
+
+/// Plugin declared in a `[[plugins]]` config section
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct PluginConfig {
+    pub name: String,
+    /// Program and its arguments, run without a shell
+    pub command: Vec<String>,
+    /// Extensions of input files the plugin converts to JSON
+    #[serde(default)]
+    pub input_extensions: Vec<String>,
+    /// Offer the plugin as `--output-format NAME`
+    #[serde(default)]
+    pub output: bool,
+    /// Key running the plugin on the current results, e.g. `alt-o`
+    #[serde(default)]
+    pub key: Option<String>,
+}
+
 /// Root configuration structure
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
 pub struct Config {
     #[serde(default)]
     pub clipboard: ClipboardConfig,
@@ -55,6 +412,28 @@ pub struct Config {
     pub tooltip: TooltipConfig,
     #[serde(default)]
     pub ai: AiConfig,
+    #[serde(default)]
+    pub query: QueryConfig,
+    #[serde(default)]
+    pub editor: EditorConfig,
+    #[serde(default)]
+    pub autocomplete: AutocompleteConfig,
+    #[serde(default)]
+    pub history: HistoryConfig,
+    #[serde(default)]
+    pub exit: ExitConfig,
+    #[serde(default)]
+    pub csv: CsvConfig,
+    #[serde(default)]
+    pub xml: XmlConfig,
+    #[serde(default)]
+    pub redaction: RedactionConfig,
+    #[serde(default)]
+    pub display: DisplayConfig,
+    #[serde(default)]
+    pub syntax: SyntaxConfig,
+    #[serde(default)]
+    pub plugins: Vec<PluginConfig>,
 }
 
 #[cfg(test)]
@@ -92,3 +92,343 @@ fn test_missing_backend_field_uses_default() {
     let config: Config = toml::from_str(toml).unwrap();
     assert_eq!(config.clipboard.backend, ClipboardBackend::Auto);
 }
+
+#[test]
+fn test_query_config_default() {
+    let config = QueryConfig::default();
+    assert_eq!(
+        config.cache_size,
+        crate::query::result_cache::DEFAULT_CACHE_SIZE
+    );
+}
+
+#[test]
+fn test_parse_query_cache_size() {
+    let toml = r#"
+[query]
+cache_size = 4
+"#;
+    let config: Config = toml::from_str(toml).unwrap();
+    assert_eq!(config.query.cache_size, 4);
+}
+
+#[test]
+fn test_parse_query_cache_size_zero_disables_cache() {
+    let toml = r#"
+[query]
+cache_size = 0
+"#;
+    let config: Config = toml::from_str(toml).unwrap();
+    assert_eq!(config.query.cache_size, 0);
+}
+
+#[test]
+fn test_parse_query_library_paths() {
+    let toml = r#"
+[query]
+library_paths = ["~/.jq", "/opt/jq/modules"]
+"#;
+    let config: Config = toml::from_str(toml).unwrap();
+    assert_eq!(config.query.library_paths, vec!["~/.jq", "/opt/jq/modules"]);
+    assert_eq!(
+        config.query.cache_size,
+        crate::query::result_cache::DEFAULT_CACHE_SIZE
+    );
+}
+
+#[test]
+fn test_missing_query_section_uses_default() {
+    let toml = r#"
+[clipboard]
+backend = "auto"
+"#;
+    let config: Config = toml::from_str(toml).unwrap();
+    assert_eq!(
+        config.query.cache_size,
+        crate::query::result_cache::DEFAULT_CACHE_SIZE
+    );
+}
+
+#[test]
+fn test_editor_config_default_disables_auto_pairs() {
+    let config = EditorConfig::default();
+    assert!(!config.auto_pairs);
+}
+
+#[test]
+fn test_parse_editor_auto_pairs() {
+    let toml = r#"
+[editor]
+auto_pairs = true
+"#;
+    let config: Config = toml::from_str(toml).unwrap();
+    assert!(config.editor.auto_pairs);
+}
+
+#[test]
+fn test_parse_editor_keybindings() {
+    let toml = r#"
+[editor]
+keybindings = "emacs"
+"#;
+    let config: Config = toml::from_str(toml).unwrap();
+    assert_eq!(config.editor.keybindings, KeyBindings::Emacs);
+    assert_eq!(EditorConfig::default().keybindings, KeyBindings::Vim);
+}
+
+#[test]
+fn test_parse_display_no_color() {
+    let toml = r#"
+[display]
+no_color = true
+"#;
+    let config: Config = toml::from_str(toml).unwrap();
+    assert!(config.display.no_color);
+    assert!(!Config::default().display.no_color);
+}
+
+#[test]
+fn test_parse_display_screen_reader() {
+    let toml = r#"
+[display]
+screen_reader = true
+"#;
+    let config: Config = toml::from_str(toml).unwrap();
+    assert!(config.display.screen_reader);
+    assert!(!config.display.no_color);
+    assert!(!Config::default().display.screen_reader);
+}
+
+#[test]
+fn test_parse_display_sparkline() {
+    let toml = r#"
+[display]
+sparkline = false
+"#;
+    let config: Config = toml::from_str(toml).unwrap();
+    assert!(!config.display.sparkline);
+
+    let config: Config = toml::from_str("[display]\nno_color = true\n").unwrap();
+    assert!(config.display.sparkline);
+    assert!(Config::default().display.sparkline);
+}
+
+#[test]
+fn test_parse_display_breadcrumbs() {
+    let toml = r#"
+[display]
+breadcrumbs = false
+"#;
+    let config: Config = toml::from_str(toml).unwrap();
+    assert!(!config.display.breadcrumbs);
+    assert!(Config::default().display.breadcrumbs);
+}
+
+#[test]
+fn test_parse_autocomplete_validate_fields() {
+    let toml = r#"
+[autocomplete]
+validate_fields = true
+"#;
+    let config: Config = toml::from_str(toml).unwrap();
+    assert!(config.autocomplete.validate_fields);
+    assert!(!Config::default().autocomplete.validate_fields);
+}
+
+#[test]
+fn test_parse_history_section() {
+    let toml = r#"
+[history]
+dedupe = "consecutive"
+max_entries = 50
+max_file_kb = 0
+"#;
+    let config: Config = toml::from_str(toml).unwrap();
+    assert_eq!(config.history.dedupe, HistoryDedupe::Consecutive);
+    assert_eq!(config.history.max_entries, 50);
+    assert_eq!(config.history.max_file_kb, 0);
+
+    let config: Config = toml::from_str("[history]\ndedupe = \"none\"\n").unwrap();
+    assert_eq!(config.history.dedupe, HistoryDedupe::None);
+    assert_eq!(config.history.max_entries, 1000);
+
+    let defaults = Config::default().history;
+    assert_eq!(defaults.dedupe, HistoryDedupe::Global);
+    assert_eq!(defaults.max_file_kb, 512);
+    assert!(!defaults.private);
+    assert!(!defaults.redact_strings);
+}
+
+#[test]
+fn test_parse_history_privacy() {
+    let toml = r#"
+[history]
+private = true
+redact_strings = true
+"#;
+    let config: Config = toml::from_str(toml).unwrap();
+    assert!(config.history.private);
+    assert!(config.history.redact_strings);
+}
+
+#[test]
+fn test_missing_editor_section_uses_default() {
+    let toml = r#"
+[query]
+cache_size = 4
+"#;
+    let config: Config = toml::from_str(toml).unwrap();
+    assert!(!config.editor.auto_pairs);
+}
+
+#[test]
+fn test_parse_csv_section() {
+    let toml = r#"
+[csv]
+delimiter = ";"
+quote = "'"
+infer_numbers = true
+"#;
+    let config: Config = toml::from_str(toml).unwrap();
+    assert_eq!(config.csv.delimiter, Some(';'));
+    assert_eq!(config.csv.quote, '\'');
+    assert!(config.csv.infer_numbers);
+}
+
+#[test]
+fn test_missing_csv_section_uses_default() {
+    let config: Config = toml::from_str("").unwrap();
+    assert_eq!(config.csv.delimiter, None);
+    assert_eq!(config.csv.quote, '"');
+    assert!(!config.csv.infer_numbers);
+}
+
+#[test]
+fn test_parse_xml_section() {
+    let toml = r#"
+[xml]
+attribute_prefix = "-"
+text_key = "_text"
+"#;
+    let config: Config = toml::from_str(toml).unwrap();
+    assert_eq!(config.xml.attribute_prefix, "-");
+    assert_eq!(config.xml.text_key, "_text");
+}
+
+#[test]
+fn test_missing_xml_section_uses_default() {
+    let config: Config = toml::from_str("").unwrap();
+    assert_eq!(config.xml.attribute_prefix, "@");
+    assert_eq!(config.xml.text_key, "#text");
+}
+
+#[test]
+fn test_exit_config_default_does_not_confirm() {
+    let config = ExitConfig::default();
+    assert!(!config.confirm);
+    assert_eq!(config.confirm_after_secs, 300);
+    assert!(!config.menu);
+    assert!(config.recover);
+}
+
+#[test]
+fn test_parse_exit_config() {
+    let toml = r#"
+[exit]
+confirm = true
+confirm_after_secs = 60
+menu = true
+recover = false
+"#;
+    let config: Config = toml::from_str(toml).unwrap();
+    assert!(config.exit.confirm);
+    assert_eq!(config.exit.confirm_after_secs, 60);
+    assert!(config.exit.menu);
+    assert!(!config.exit.recover);
+}
+
+#[test]
+fn test_parse_plugins() {
+    let toml = r#"
+[[plugins]]
+name = "yaml"
+command = ["yq", "-o", "json"]
+input_extensions = ["yaml", "yml"]
+
+[[plugins]]
+name = "open-in-editor"
+command = ["sh", "-c", "code -"]
+key = "alt-o"
+"#;
+    let config: Config = toml::from_str(toml).unwrap();
+    assert_eq!(config.plugins.len(), 2);
+    assert_eq!(config.plugins[0].command, ["yq", "-o", "json"]);
+    assert_eq!(config.plugins[0].input_extensions, ["yaml", "yml"]);
+    assert!(!config.plugins[0].output);
+    assert_eq!(config.plugins[0].key, None);
+    assert_eq!(config.plugins[1].key.as_deref(), Some("alt-o"));
+}
+
+#[test]
+fn test_missing_plugins_section_is_empty() {
+    let config: Config = toml::from_str("").unwrap();
+    assert!(config.plugins.is_empty());
+}
+
+#[test]
+fn test_redaction_config_defaults() {
+    let config: Config = toml::from_str("").unwrap();
+    assert!(!config.redaction.enabled);
+    assert_eq!(
+        config.redaction.fields,
+        ["password", "token", "secret", "key"]
+    );
+}
+
+#[test]
+fn test_parse_redaction_config() {
+    let toml = r#"
+[redaction]
+enabled = true
+fields = ["ssn"]
+"#;
+    let config: Config = toml::from_str(toml).unwrap();
+    assert!(config.redaction.enabled);
+    assert_eq!(config.redaction.fields, ["ssn"]);
+}
+
+#[test]
+fn test_parse_syntax_colors() {
+    let toml = r##"
+[syntax]
+keyword = "#ff0000"
+comment = "darkgray"
+number = "244"
+"##;
+    let config: Config = toml::from_str(toml).unwrap();
+    assert_eq!(
+        config.syntax.keyword,
+        Some(ThemeColor(Color::Rgb(255, 0, 0)))
+    );
+    assert_eq!(config.syntax.comment, Some(ThemeColor(Color::DarkGray)));
+    assert_eq!(config.syntax.number, Some(ThemeColor(Color::Indexed(244))));
+    assert_eq!(config.syntax.string, None);
+}
+
+#[test]
+fn test_invalid_syntax_color_is_an_error() {
+    let err = toml::from_str::<Config>("[syntax]\nkeyword = \"pinkish\"\n").unwrap_err();
+    assert!(err.to_string().contains("invalid color `pinkish`"));
+}
+
+#[test]
+fn test_syntax_colors_serialize_as_strings() {
+    let config = SyntaxConfig {
+        keyword: Some(ThemeColor(Color::Rgb(255, 0, 0))),
+        ..SyntaxConfig::default()
+    };
+    assert_eq!(
+        toml::to_string(&config).unwrap().trim(),
+        "keyword = \"#FF0000\""
+    );
+}
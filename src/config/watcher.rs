@@ -0,0 +1,77 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant, SystemTime};
+
+use super::{Config, Override, load_config_from};
+
+/// How often the config file is checked for changes
+pub const CHECK_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Config file changed while jiq runs
+#[derive(Debug)]
+pub enum ConfigReload {
+    /// The file was read; `previous` is the config it replaces
+    Loaded {
+        previous: Box<Config>,
+        current: Box<Config>,
+    },
+    /// The file can't be used, the current config stays
+    Invalid(String),
+}
+
+/// Config file checked for changes so they apply without a restart
+#[derive(Debug)]
+pub struct ConfigWatcher {
+    path: PathBuf,
+    modified: Option<SystemTime>,
+    last_check: Instant,
+    config: Config,
+    /// Applied on top of the file on every reload
+    overrides: Vec<Override>,
+}
+
+impl ConfigWatcher {
+    /// Watch `path`, whose current contents were loaded as `config` with
+    /// `overrides` applied
+    pub fn new(path: PathBuf, config: Config, overrides: Vec<Override>) -> Self {
+        Self {
+            modified: modified(&path),
+            path,
+            last_check: Instant::now(),
+            config,
+            overrides,
+        }
+    }
+
+    /// Read the file again if it changed since it was last read, at most
+    /// every `CHECK_INTERVAL`
+    pub fn poll(&mut self, now: Instant) -> Option<ConfigReload> {
+        if now.duration_since(self.last_check) < CHECK_INTERVAL {
+            return None;
+        }
+        self.last_check = now;
+        let modified = modified(&self.path);
+        if modified == self.modified {
+            return None;
+        }
+        self.modified = modified;
+
+        let result = load_config_from(&self.path, &self.overrides);
+        if let Some(warning) = result.warning {
+            return Some(ConfigReload::Invalid(warning));
+        }
+        let previous = std::mem::replace(&mut self.config, result.config.clone());
+        Some(ConfigReload::Loaded {
+            previous: Box::new(previous),
+            current: Box::new(result.config),
+        })
+    }
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+#[cfg(test)]
+#[path = "watcher_tests.rs"]
+mod watcher_tests;
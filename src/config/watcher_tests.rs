@@ -0,0 +1,102 @@
+//! Tests for config/watcher
+
+use super::*;
+use tempfile::TempDir;
+
+/// Write `contents` with a modification time `secs` seconds in the future,
+/// so each write is seen as a change however fast the test runs
+fn write_config(path: &PathBuf, contents: &str, secs: u64) {
+    fs::write(path, contents).unwrap();
+    let file = fs::File::options().write(true).open(path).unwrap();
+    file.set_modified(SystemTime::now() + Duration::from_secs(secs))
+        .unwrap();
+}
+
+fn watcher(dir: &TempDir) -> (ConfigWatcher, PathBuf, Instant) {
+    let path = dir.path().join("config.toml");
+    write_config(&path, "[display]\nsparkline = true\n", 0);
+    let watcher = ConfigWatcher::new(path.clone(), Config::default(), Vec::new());
+    (watcher, path, Instant::now())
+}
+
+#[test]
+fn test_unchanged_file_is_not_reloaded() {
+    let dir = TempDir::new().unwrap();
+    let (mut watcher, _, start) = watcher(&dir);
+
+    assert!(watcher.poll(start + CHECK_INTERVAL).is_none());
+}
+
+#[test]
+fn test_changed_file_is_reloaded() {
+    let dir = TempDir::new().unwrap();
+    let (mut watcher, path, start) = watcher(&dir);
+
+    write_config(&path, "[display]\nsparkline = false\n", 10);
+
+    match watcher.poll(start + CHECK_INTERVAL) {
+        Some(ConfigReload::Loaded { previous, current }) => {
+            assert!(previous.display.sparkline);
+            assert!(!current.display.sparkline);
+        }
+        other => panic!("expected a reload, got {:?}", other),
+    }
+    assert!(watcher.poll(start + CHECK_INTERVAL * 2).is_none());
+}
+
+#[test]
+fn test_change_waits_for_interval() {
+    let dir = TempDir::new().unwrap();
+    let (mut watcher, path, start) = watcher(&dir);
+
+    write_config(&path, "[display]\nsparkline = false\n", 10);
+
+    assert!(watcher.poll(start).is_none());
+    assert!(watcher.poll(start + CHECK_INTERVAL).is_some());
+}
+
+#[test]
+fn test_invalid_file_keeps_config() {
+    let dir = TempDir::new().unwrap();
+    let (mut watcher, path, start) = watcher(&dir);
+
+    write_config(&path, "[display]\nsparkline = \n", 10);
+    assert!(matches!(
+        watcher.poll(start + CHECK_INTERVAL),
+        Some(ConfigReload::Invalid(_))
+    ));
+
+    write_config(&path, "[display]\nsparkline = false\n", 20);
+    match watcher.poll(start + CHECK_INTERVAL * 2) {
+        Some(ConfigReload::Loaded { previous, .. }) => assert!(previous.display.sparkline),
+        other => panic!("expected a reload, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_reload_keeps_overrides() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("config.toml");
+    write_config(&path, "", 0);
+    let overrides = vec![Override::new(
+        "display.breadcrumbs",
+        "false",
+        crate::config::Source::Flag("--set".to_string()),
+    )];
+    let mut watcher = ConfigWatcher::new(path.clone(), Config::default(), overrides);
+    let start = Instant::now();
+
+    write_config(
+        &path,
+        "[display]\nbreadcrumbs = true\nsparkline = false\n",
+        10,
+    );
+
+    match watcher.poll(start + CHECK_INTERVAL) {
+        Some(ConfigReload::Loaded { current, .. }) => {
+            assert!(!current.display.breadcrumbs);
+            assert!(!current.display.sparkline);
+        }
+        other => panic!("expected a reload, got {:?}", other),
+    }
+}
@@ -95,3 +95,43 @@ fn test_config_path_consistency() {
         path_str
     );
 }
+
+#[test]
+fn test_overrides_apply_on_top_of_file() {
+    let dir = tempfile::TempDir::new().unwrap();
+    let path = dir.path().join("config.toml");
+    fs::write(&path, "[display]\nsparkline = false\nbreadcrumbs = false\n").unwrap();
+    let overrides = [Override::new(
+        "display.sparkline",
+        "true",
+        Source::Env("JIQ_DISPLAY__SPARKLINE".to_string()),
+    )];
+
+    let result = load_config_from(&path, &overrides);
+
+    assert!(result.config.display.sparkline);
+    assert!(!result.config.display.breadcrumbs);
+    assert_eq!(
+        result.sources.get("display.breadcrumbs"),
+        Some(&Source::File)
+    );
+    assert!(result.warning.is_none());
+}
+
+#[test]
+fn test_overrides_apply_when_file_is_invalid() {
+    let dir = tempfile::TempDir::new().unwrap();
+    let path = dir.path().join("config.toml");
+    fs::write(&path, "[display]\nsparkline = \n").unwrap();
+    let overrides = [Override::new(
+        "editor.auto_pairs",
+        "true",
+        Source::Flag("--set".to_string()),
+    )];
+
+    let result = load_config_from(&path, &overrides);
+
+    assert!(result.warning.unwrap().starts_with("Invalid config: "));
+    assert!(result.config.display.sparkline);
+    assert!(result.config.editor.auto_pairs);
+}
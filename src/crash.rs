@@ -0,0 +1,308 @@
+//! Terminal restore and crash reports
+//!
+//! jiq takes the terminal into raw mode and the alternate screen. Whatever
+//! way it ends (a clean exit, an error returned from the event loop or a
+//! panic in the middle of a draw), the terminal is handed back exactly once.
+//! A panic also writes a report with the panic, a backtrace and a summary of
+//! the session (input, query, focus) to `crashes/` in the state directory
+//! (`~/.local/state/jiq/crashes/` on Linux), and says where. Panics on
+//! background threads only end that thread and are logged instead.
+
+use std::any::Any;
+use std::backtrace::Backtrace;
+use std::cell::Cell;
+use std::fmt::Write as _;
+use std::fs;
+use std::io::{self, stdout};
+use std::panic::{self, AssertUnwindSafe, PanicHookInfo};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use chrono::Local;
+use ratatui::crossterm::event::{
+    DisableBracketedPaste, DisableMouseCapture, KeyboardEnhancementFlags,
+    PopKeyboardEnhancementFlags, PushKeyboardEnhancementFlags,
+};
+use ratatui::crossterm::execute;
+use ratatui::crossterm::terminal::{
+    LeaveAlternateScreen, disable_raw_mode, supports_keyboard_enhancement,
+};
+
+use crate::app::{App, Focus};
+
+const CRASH_DIR: &str = "crashes";
+
+/// Whether jiq holds the terminal in raw mode and the alternate screen
+static TERMINAL_HELD: AtomicBool = AtomicBool::new(false);
+
+/// Whether the terminal was asked to report modifiers it drops by default
+static KEYS_DISAMBIGUATED: AtomicBool = AtomicBool::new(false);
+
+thread_local! {
+    /// Whether this thread runs the event loop, so a panic on it ends jiq
+    static UI_THREAD: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Summary of the session as of the last frame, for the crash report
+static CONTEXT: Mutex<Option<CrashContext>> = Mutex::new(None);
+
+/// What jiq was doing, kept up to date while it runs
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CrashContext {
+    /// File name, command or `stdin`, once the input has loaded
+    pub input: Option<String>,
+    pub query: String,
+    pub language: String,
+    pub focus: String,
+    /// Popup or mode taking the keys, if any
+    pub popup: Option<String>,
+}
+
+impl CrashContext {
+    pub fn capture(app: &App) -> Self {
+        Self {
+            input: app.input_info.as_ref().map(|info| info.source.clone()),
+            query: app.query().to_string(),
+            language: app
+                .query
+                .as_ref()
+                .map(|q| q.language.label())
+                .unwrap_or("jq")
+                .to_string(),
+            focus: match app.focus {
+                Focus::InputField => "input".to_string(),
+                Focus::ResultsPane => "results".to_string(),
+            },
+            popup: open_popup(app).map(str::to_string),
+        }
+    }
+}
+
+/// Name of the popup on top, for the few that take over the keys
+fn open_popup(app: &App) -> Option<&'static str> {
+    if app.exit_prompt_visible {
+        Some("exit prompt")
+    } else if app.recovery.is_visible() {
+        Some("recovery prompt")
+    } else if app.in_place.is_visible() {
+        Some("in-place preview")
+    } else if app.exit_menu.is_visible() {
+        Some("exit menu")
+    } else if app.help.visible {
+        Some("help")
+    } else if app.command_line.is_visible() {
+        Some("command line")
+    } else if app.autocomplete.is_visible() {
+        Some("autocomplete")
+    } else {
+        None
+    }
+}
+
+/// Remember the state of `app` for a crash report
+pub fn update_context(app: &App) {
+    let context = CrashContext::capture(app);
+    if let Ok(mut current) = CONTEXT.lock()
+        && current.as_ref() != Some(&context)
+    {
+        *current = Some(context);
+    }
+}
+
+/// Record that jiq has taken the terminal, so it is handed back on exit
+pub fn hold_terminal() {
+    TERMINAL_HELD.store(true, Ordering::SeqCst);
+}
+
+/// Ask terminals that support the kitty keyboard protocol to tell apart
+/// keys legacy terminals send alike, such as `Ctrl+Shift+Q` and `Ctrl+Q`
+///
+/// Must be called in raw mode and the alternate screen, as the terminal
+/// keeps its own settings for each screen.
+pub fn disambiguate_keys() {
+    if matches!(supports_keyboard_enhancement(), Ok(true))
+        && execute!(
+            stdout(),
+            PushKeyboardEnhancementFlags(KeyboardEnhancementFlags::DISAMBIGUATE_ESCAPE_CODES)
+        )
+        .is_ok()
+    {
+        KEYS_DISAMBIGUATED.store(true, Ordering::SeqCst);
+    }
+}
+
+/// Leave the alternate screen and raw mode if jiq still holds the terminal
+///
+/// Safe to call more than once: only the first call after `hold_terminal`
+/// writes anything, so output printed after a clean exit is not followed by
+/// stray escape sequences.
+pub fn release_terminal() -> io::Result<()> {
+    if !TERMINAL_HELD.swap(false, Ordering::SeqCst) {
+        return Ok(());
+    }
+    if KEYS_DISAMBIGUATED.swap(false, Ordering::SeqCst) {
+        let _ = execute!(stdout(), PopKeyboardEnhancementFlags);
+    }
+    let _ = execute!(
+        stdout(),
+        DisableMouseCapture,
+        DisableBracketedPaste,
+        LeaveAlternateScreen
+    );
+    disable_raw_mode()
+}
+
+/// Hands the terminal back when dropped, for returns that skip the normal
+/// exit path
+pub struct TerminalGuard;
+
+impl Drop for TerminalGuard {
+    fn drop(&mut self) {
+        let _ = release_terminal();
+    }
+}
+
+/// Restore the terminal and write a crash report before the panic is
+/// reported by the hook installed so far
+///
+/// Must be called on the thread running the event loop. Panics on any other
+/// thread are only logged: the TUI keeps running, and workers wrapped in
+/// `run_worker` recover from them.
+pub fn install_panic_hook() {
+    mark_ui_thread();
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        if !is_ui_thread() {
+            tracing::error!(
+                "background thread panic: {} at {}",
+                panic_message(info.payload()),
+                panic_location(info)
+            );
+            return;
+        }
+        let _ = release_terminal();
+        let context = CONTEXT.lock().ok().and_then(|c| c.clone());
+        let report = format_report(info, context.as_ref(), &Backtrace::force_capture());
+        match write_report(&report) {
+            Ok(path) => eprintln!("jiq crashed. A report was written to {}", path.display()),
+            Err(e) => eprintln!("jiq crashed and could not write a report: {}", e),
+        }
+        hook(info);
+    }));
+}
+
+/// Crash report for the panic `info`
+fn format_report(
+    info: &PanicHookInfo,
+    context: Option<&CrashContext>,
+    backtrace: &Backtrace,
+) -> String {
+    build_report(
+        &panic_message(info.payload()),
+        &panic_location(info),
+        context,
+        &backtrace.to_string(),
+    )
+}
+
+fn mark_ui_thread() {
+    UI_THREAD.with(|ui_thread| ui_thread.set(true));
+}
+
+fn is_ui_thread() -> bool {
+    UI_THREAD.with(Cell::get)
+}
+
+/// Run a background worker on the current thread, returning the message of
+/// the panic that ended it, if any
+pub fn run_worker(worker: impl FnOnce()) -> Result<(), String> {
+    panic::catch_unwind(AssertUnwindSafe(worker)).map_err(|payload| panic_message(payload.as_ref()))
+}
+
+/// Text of a panic payload
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|s| s.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "unknown panic".to_string())
+}
+
+fn panic_location(info: &PanicHookInfo) -> String {
+    info.location()
+        .map(|l| format!("{}:{}:{}", l.file(), l.line(), l.column()))
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+fn build_report(
+    message: &str,
+    location: &str,
+    context: Option<&CrashContext>,
+    backtrace: &str,
+) -> String {
+    let mut report = String::new();
+    let _ = writeln!(report, "jiq {} crash report", env!("CARGO_PKG_VERSION"));
+    let _ = writeln!(
+        report,
+        "Time: {}",
+        Local::now().format("%Y-%m-%d %H:%M:%S %z")
+    );
+    let _ = writeln!(
+        report,
+        "Platform: {} {}",
+        std::env::consts::OS,
+        std::env::consts::ARCH
+    );
+    let _ = writeln!(report);
+    let _ = writeln!(report, "Panic: {}", message);
+    let _ = writeln!(report, "Location: {}", location);
+    let _ = writeln!(report);
+    match context {
+        Some(context) => {
+            let input = context.input.as_deref().unwrap_or("(loading)");
+            let popup = context.popup.as_deref().unwrap_or("none");
+            let _ = writeln!(report, "Input: {}", input);
+            let _ = writeln!(report, "Language: {}", context.language);
+            let _ = writeln!(report, "Query: {}", context.query);
+            let _ = writeln!(report, "Focus: {}", context.focus);
+            let _ = writeln!(report, "Popup: {}", popup);
+        }
+        None => {
+            let _ = writeln!(
+                report,
+                "The panic happened before the first frame was drawn."
+            );
+        }
+    }
+    let _ = writeln!(report);
+    let _ = writeln!(report, "Backtrace:");
+    report.push_str(backtrace);
+    report
+}
+
+/// Directory crash reports are written to
+pub fn crash_dir() -> Option<PathBuf> {
+    crate::paths::state_dir().map(|p| p.join(CRASH_DIR))
+}
+
+fn write_report(report: &str) -> io::Result<PathBuf> {
+    let dir =
+        crash_dir().ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "no data directory"))?;
+    write_report_to(&dir, report)
+}
+
+/// Write `report` to a new file named after the current time in `dir`
+fn write_report_to(dir: &Path, report: &str) -> io::Result<PathBuf> {
+    fs::create_dir_all(dir)?;
+    let path = dir.join(format!(
+        "crash-{}.txt",
+        Local::now().format("%Y%m%d-%H%M%S%.3f")
+    ));
+    fs::write(&path, report)?;
+    Ok(path)
+}
+
+#[cfg(test)]
+#[path = "crash_tests.rs"]
+mod crash_tests;
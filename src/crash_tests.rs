@@ -0,0 +1,104 @@
+//! Tests for crash
+
+use super::*;
+use crate::test_utils::test_helpers::app_with_query;
+use tempfile::TempDir;
+
+fn context() -> CrashContext {
+    CrashContext {
+        input: Some("orders.json".to_string()),
+        query: ".orders[] | select(.total > 100)".to_string(),
+        language: "jq".to_string(),
+        focus: "input".to_string(),
+        popup: None,
+    }
+}
+
+#[test]
+fn test_capture_summarizes_app() {
+    let mut app = app_with_query(".name");
+    app.focus = Focus::ResultsPane;
+
+    let context = CrashContext::capture(&app);
+
+    assert_eq!(context.query, ".name");
+    assert_eq!(context.language, "jq");
+    assert_eq!(context.focus, "results");
+    assert_eq!(context.popup, None);
+}
+
+#[test]
+fn test_capture_names_open_popup() {
+    let mut app = app_with_query(".name");
+    app.help.visible = true;
+
+    assert_eq!(CrashContext::capture(&app).popup.as_deref(), Some("help"));
+}
+
+#[test]
+fn test_report_includes_panic_and_state() {
+    let report = build_report(
+        "index out of bounds",
+        "src/results/results_render.rs:42:9",
+        Some(&context()),
+        "0: jiq::main",
+    );
+
+    assert!(report.starts_with(&format!("jiq {} crash report", env!("CARGO_PKG_VERSION"))));
+    assert!(report.contains("Panic: index out of bounds"));
+    assert!(report.contains("Location: src/results/results_render.rs:42:9"));
+    assert!(report.contains("Input: orders.json"));
+    assert!(report.contains("Query: .orders[] | select(.total > 100)"));
+    assert!(report.contains("Focus: input"));
+    assert!(report.contains("Popup: none"));
+    assert!(report.ends_with("Backtrace:\n0: jiq::main"));
+}
+
+#[test]
+fn test_report_without_state() {
+    let report = build_report("boom", "src/main.rs:1:1", None, "");
+
+    assert!(report.contains("before the first frame was drawn"));
+    assert!(!report.contains("Query:"));
+}
+
+#[test]
+fn test_write_report_creates_directory() {
+    let dir = TempDir::new().unwrap();
+    let crashes = dir.path().join("crashes");
+
+    let path = write_report_to(&crashes, "report").unwrap();
+
+    assert!(path.starts_with(&crashes));
+    assert_eq!(fs::read_to_string(path).unwrap(), "report");
+}
+
+#[test]
+fn test_release_terminal_does_nothing_when_not_held() {
+    assert!(release_terminal().is_ok());
+}
+
+#[test]
+fn test_run_worker_returns_panic_message() {
+    let result = run_worker(|| panic!("worker failed"));
+
+    assert_eq!(result, Err("worker failed".to_string()));
+}
+
+#[test]
+fn test_run_worker_returns_ok_when_worker_ends() {
+    let mut ran = false;
+
+    let result = run_worker(|| ran = true);
+
+    assert_eq!(result, Ok(()));
+    assert!(ran);
+}
+
+#[test]
+fn test_only_the_marked_thread_is_the_ui_thread() {
+    mark_ui_thread();
+
+    assert!(is_ui_thread());
+    assert!(!std::thread::spawn(is_ui_thread).join().unwrap());
+}
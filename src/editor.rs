@@ -1,7 +1,13 @@
+pub mod auto_pairs;
 pub mod char_search;
+pub mod comment;
 pub mod editor_events;
+pub mod emacs;
+pub mod kill_ring;
 pub mod mode;
 pub mod text_objects;
+pub mod word_motion;
 
 pub use char_search::CharSearchState;
+pub use kill_ring::KillRing;
 pub use mode::EditorMode;
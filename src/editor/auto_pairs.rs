@@ -0,0 +1,151 @@
+//! Bracket and quote auto-pairing for insert mode
+//!
+//! When enabled via `[editor] auto_pairs`, typing an opener also inserts its
+//! closer, typing a closer directly before the same closer steps over it, and
+//! Backspace between an empty pair removes both characters.
+
+use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use tui_textarea::{CursorMove, TextArea};
+
+/// Closing character for an auto-paired opener
+fn closer_for(open: char) -> Option<char> {
+    match open {
+        '(' => Some(')'),
+        '[' => Some(']'),
+        '{' => Some('}'),
+        '"' => Some('"'),
+        _ => None,
+    }
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+/// Whether the cursor sits inside a string literal, given the text before it
+///
+/// Tracks `\(...)` interpolation so parens inside it are treated as code.
+fn in_string(before_cursor: &[char]) -> bool {
+    let mut in_str = false;
+    // Paren depth within each open interpolation
+    let mut interpolations: Vec<usize> = Vec::new();
+    let mut i = 0;
+
+    while i < before_cursor.len() {
+        let c = before_cursor[i];
+        if in_str {
+            match c {
+                '\\' if before_cursor.get(i + 1) == Some(&'(') => {
+                    interpolations.push(0);
+                    in_str = false;
+                    i += 1;
+                }
+                '\\' => i += 1,
+                '"' => in_str = false,
+                _ => {}
+            }
+        } else {
+            match c {
+                '"' => in_str = true,
+                '(' => {
+                    if let Some(depth) = interpolations.last_mut() {
+                        *depth += 1;
+                    }
+                }
+                ')' => match interpolations.last_mut() {
+                    Some(0) => {
+                        interpolations.pop();
+                        in_str = true;
+                    }
+                    Some(depth) => *depth -= 1,
+                    None => {}
+                },
+                _ => {}
+            }
+        }
+        i += 1;
+    }
+
+    in_str
+}
+
+/// Whether an opener typed before `next` should get an automatic closer
+///
+/// Pairs are only inserted at the end of the query or before whitespace or
+/// a closing delimiter, so wrapping existing text isn't disrupted.
+fn can_pair_before(next: Option<char>) -> bool {
+    match next {
+        None => true,
+        Some(c) => c.is_whitespace() || matches!(c, ')' | ']' | '}' | '|' | ',' | ';' | ':'),
+    }
+}
+
+/// Handle a key with auto-pairing
+///
+/// Returns `None` if the key should fall through to normal textarea input,
+/// otherwise whether the query text changed.
+pub fn handle_key(textarea: &mut TextArea<'_>, key: KeyEvent) -> Option<bool> {
+    if !(key.modifiers - KeyModifiers::SHIFT).is_empty() {
+        return None;
+    }
+
+    let (row, col) = textarea.cursor();
+    let chars: Vec<char> = textarea.lines().get(row)?.chars().collect();
+    let prev = col.checked_sub(1).and_then(|i| chars.get(i).copied());
+    let next = chars.get(col).copied();
+    let inside_string = in_string(&chars[..col.min(chars.len())]);
+
+    match key.code {
+        KeyCode::Backspace => {
+            let open = prev?;
+            if closer_for(open) != next || (open == '"' && !inside_string) {
+                return None;
+            }
+            textarea.delete_next_char();
+            textarea.delete_char();
+            Some(true)
+        }
+        KeyCode::Char('"') => {
+            if inside_string {
+                if next == Some('"') && prev != Some('\\') {
+                    textarea.move_cursor(CursorMove::Forward);
+                    return Some(false);
+                }
+                return None;
+            }
+            if prev.is_some_and(is_word_char) || !can_pair_before(next) {
+                return None;
+            }
+            insert_pair(textarea, '"', '"');
+            Some(true)
+        }
+        KeyCode::Char(c @ (')' | ']' | '}')) if !inside_string && next == Some(c) => {
+            textarea.move_cursor(CursorMove::Forward);
+            Some(false)
+        }
+        KeyCode::Char(c @ ('(' | '[' | '{')) => {
+            // Inside strings only `\(` interpolation is paired
+            let should_pair = if inside_string {
+                c == '(' && prev == Some('\\')
+            } else {
+                can_pair_before(next)
+            };
+            if !should_pair {
+                return None;
+            }
+            insert_pair(textarea, c, closer_for(c)?);
+            Some(true)
+        }
+        _ => None,
+    }
+}
+
+fn insert_pair(textarea: &mut TextArea<'_>, open: char, close: char) {
+    textarea.insert_char(open);
+    textarea.insert_char(close);
+    textarea.move_cursor(CursorMove::Back);
+}
+
+#[cfg(test)]
+#[path = "auto_pairs_tests.rs"]
+mod auto_pairs_tests;
@@ -0,0 +1,171 @@
+//! Tests for auto_pairs
+
+use super::*;
+use crate::editor::EditorMode;
+use crate::test_utils::test_helpers::{app_with_query, key};
+use ratatui::crossterm::event::KeyCode;
+
+fn textarea_at(text: &str, col: usize) -> TextArea<'static> {
+    let mut textarea = TextArea::from(vec![text.to_string()]);
+    textarea.move_cursor(CursorMove::Jump(0, col as u16));
+    textarea
+}
+
+fn char_key(c: char) -> KeyEvent {
+    KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE)
+}
+
+fn line<'a>(textarea: &'a TextArea<'_>) -> &'a str {
+    &textarea.lines()[0]
+}
+
+#[test]
+fn test_opener_inserts_pair_and_places_cursor_inside() {
+    for (open, expected) in [('(', "()"), ('[', "[]"), ('{', "{}"), ('"', "\"\"")] {
+        let mut textarea = textarea_at("", 0);
+        assert_eq!(handle_key(&mut textarea, char_key(open)), Some(true));
+        assert_eq!(line(&textarea), expected);
+        assert_eq!(textarea.cursor(), (0, 1));
+    }
+}
+
+#[test]
+fn test_opener_pairs_before_pipe() {
+    let mut textarea = textarea_at(".a | keys", 2);
+    assert_eq!(handle_key(&mut textarea, char_key('[')), Some(true));
+    assert_eq!(line(&textarea), ".a[] | keys");
+}
+
+#[test]
+fn test_opener_before_word_is_not_paired() {
+    let mut textarea = textarea_at(".a", 0);
+    assert_eq!(handle_key(&mut textarea, char_key('(')), None);
+}
+
+#[test]
+fn test_closer_steps_over_existing_closer() {
+    let mut textarea = textarea_at("select()", 7);
+    assert_eq!(handle_key(&mut textarea, char_key(')')), Some(false));
+    assert_eq!(line(&textarea), "select()");
+    assert_eq!(textarea.cursor(), (0, 8));
+}
+
+#[test]
+fn test_closer_without_matching_next_falls_through() {
+    let mut textarea = textarea_at("(.a", 3);
+    assert_eq!(handle_key(&mut textarea, char_key(')')), None);
+}
+
+#[test]
+fn test_quote_steps_over_closing_quote() {
+    let mut textarea = textarea_at(r#"."key""#, 5);
+    assert_eq!(handle_key(&mut textarea, char_key('"')), Some(false));
+    assert_eq!(textarea.cursor(), (0, 6));
+}
+
+#[test]
+fn test_escaped_quote_is_not_stepped_over() {
+    let mut textarea = textarea_at(r#""a\""#, 3);
+    assert_eq!(handle_key(&mut textarea, char_key('"')), None);
+}
+
+#[test]
+fn test_quote_after_word_is_not_paired() {
+    let mut textarea = textarea_at("abc", 3);
+    assert_eq!(handle_key(&mut textarea, char_key('"')), None);
+}
+
+#[test]
+fn test_brackets_inside_string_are_not_paired() {
+    let mut textarea = textarea_at(r#""a""#, 2);
+    assert_eq!(handle_key(&mut textarea, char_key('[')), None);
+}
+
+#[test]
+fn test_interpolation_paren_is_paired() {
+    let mut textarea = textarea_at(r#""\""#, 2);
+    assert_eq!(handle_key(&mut textarea, char_key('(')), Some(true));
+    assert_eq!(line(&textarea), r#""\()""#);
+}
+
+#[test]
+fn test_closer_inside_interpolation_steps_over() {
+    let mut textarea = textarea_at(r#""\(.a)""#, 5);
+    assert_eq!(handle_key(&mut textarea, char_key(')')), Some(false));
+    assert_eq!(textarea.cursor(), (0, 6));
+}
+
+#[test]
+fn test_backspace_deletes_empty_pair() {
+    for text in ["()", "[]", "{}", "\"\""] {
+        let mut textarea = textarea_at(text, 1);
+        let key = KeyEvent::new(KeyCode::Backspace, KeyModifiers::NONE);
+        assert_eq!(handle_key(&mut textarea, key), Some(true));
+        assert_eq!(line(&textarea), "");
+    }
+}
+
+#[test]
+fn test_backspace_between_separate_strings_falls_through() {
+    let mut textarea = textarea_at(r#""a""b""#, 3);
+    let key = KeyEvent::new(KeyCode::Backspace, KeyModifiers::NONE);
+    assert_eq!(handle_key(&mut textarea, key), None);
+}
+
+#[test]
+fn test_backspace_in_non_empty_pair_falls_through() {
+    let mut textarea = textarea_at("(a)", 2);
+    let key = KeyEvent::new(KeyCode::Backspace, KeyModifiers::NONE);
+    assert_eq!(handle_key(&mut textarea, key), None);
+}
+
+#[test]
+fn test_control_modified_keys_fall_through() {
+    let mut textarea = textarea_at("", 0);
+    let key = KeyEvent::new(KeyCode::Char('('), KeyModifiers::CONTROL);
+    assert_eq!(handle_key(&mut textarea, key), None);
+}
+
+#[test]
+fn test_shifted_opener_is_paired() {
+    let mut textarea = textarea_at("", 0);
+    let key = KeyEvent::new(KeyCode::Char('{'), KeyModifiers::SHIFT);
+    assert_eq!(handle_key(&mut textarea, key), Some(true));
+    assert_eq!(line(&textarea), "{}");
+}
+
+#[test]
+fn test_insert_mode_does_not_auto_pair_by_default() {
+    let mut app = app_with_query(".items");
+    app.input.editor_mode = EditorMode::Insert;
+
+    app.handle_key_event(key(KeyCode::Char('[')));
+
+    assert_eq!(app.query(), ".items[");
+}
+
+#[test]
+fn test_insert_mode_auto_pairs_when_enabled() {
+    let mut app = app_with_query(".items");
+    app.input.editor_mode = EditorMode::Insert;
+    app.input.auto_pairs = true;
+
+    app.handle_key_event(key(KeyCode::Char('[')));
+    assert_eq!(app.query(), ".items[]");
+    assert_eq!(app.input.textarea.cursor().1, 7);
+
+    app.handle_key_event(key(KeyCode::Char(']')));
+    assert_eq!(app.query(), ".items[]");
+    assert_eq!(app.input.textarea.cursor().1, 8);
+}
+
+#[test]
+fn test_insert_mode_auto_pair_schedules_execution() {
+    let mut app = app_with_query("");
+    app.input.editor_mode = EditorMode::Insert;
+    app.input.auto_pairs = true;
+
+    app.handle_key_event(key(KeyCode::Char('[')));
+
+    assert!(app.debouncer.has_pending());
+}
@@ -0,0 +1,88 @@
+//! Commenting out the end of the pipeline
+//!
+//! The query is a single line, so a `#` comment runs to its end. Toggling a
+//! comment on a pipeline stage disables it and every stage after it, showing
+//! the result of the stages before; toggling again removes the `#`.
+
+use tui_textarea::{CursorMove, TextArea};
+
+use crate::pipeline::{comment_offset, pipe_offsets};
+
+/// Text inserted to comment out the rest of the query
+const COMMENT: &str = "# ";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Edit {
+    /// Insert `COMMENT` at this character column
+    Insert(usize),
+    /// Remove this many characters from this character column
+    Remove(usize, usize),
+}
+
+/// Edit toggling the comment in `query` for the stage at character column
+/// `col`
+///
+/// A comment in the query is removed, with the space after its `#`.
+/// Otherwise one is inserted before the pipe ending the stage before the one
+/// at `col`, or at the start when `col` is in the first stage.
+fn toggle_edit(query: &str, col: usize) -> Option<Edit> {
+    let char_col = |byte: usize| query[..byte].chars().count();
+    if let Some(offset) = comment_offset(query) {
+        let len = if query[offset + 1..].starts_with(' ') {
+            2
+        } else {
+            1
+        };
+        return Some(Edit::Remove(char_col(offset), len));
+    }
+    if query.trim().is_empty() {
+        return None;
+    }
+    let byte = query
+        .char_indices()
+        .nth(col)
+        .map_or(query.len(), |(byte, _)| byte);
+    let pipe = pipe_offsets(query)
+        .into_iter()
+        .take_while(|pipe| *pipe < byte)
+        .last();
+    Some(Edit::Insert(pipe.map_or(0, char_col)))
+}
+
+/// Comment out the query from the stage at the cursor, or at the start of
+/// the selection, or remove the comment it has
+///
+/// Returns whether the query changed.
+pub fn toggle(textarea: &mut TextArea<'static>) -> bool {
+    let (_, cursor) = textarea.cursor();
+    let col = textarea
+        .selection_range()
+        .map_or(cursor, |((_, start), _)| start);
+    let Some(edit) = toggle_edit(&textarea.lines()[0], col) else {
+        return false;
+    };
+    textarea.cancel_selection();
+
+    let cursor = match edit {
+        Edit::Insert(at) => {
+            textarea.move_cursor(CursorMove::Jump(0, at as u16));
+            textarea.insert_str(COMMENT);
+            if cursor >= at {
+                cursor + COMMENT.len()
+            } else {
+                cursor
+            }
+        }
+        Edit::Remove(at, len) => {
+            textarea.move_cursor(CursorMove::Jump(0, at as u16));
+            textarea.delete_str(len);
+            cursor - cursor.saturating_sub(at).min(len)
+        }
+    };
+    textarea.move_cursor(CursorMove::Jump(0, cursor as u16));
+    true
+}
+
+#[cfg(test)]
+#[path = "comment_tests.rs"]
+mod comment_tests;
@@ -0,0 +1,103 @@
+//! Tests for comment
+
+use super::*;
+
+fn textarea_at(text: &str, col: usize) -> TextArea<'static> {
+    let mut textarea = TextArea::from(vec![text.to_string()]);
+    textarea.move_cursor(CursorMove::Jump(0, col as u16));
+    textarea
+}
+
+fn line<'a>(textarea: &'a TextArea<'_>) -> &'a str {
+    &textarea.lines()[0]
+}
+
+#[test]
+fn test_comments_out_stage_at_cursor_and_rest() {
+    let mut textarea = textarea_at(".a | .b | .c", 7);
+
+    assert!(toggle(&mut textarea));
+
+    assert_eq!(line(&textarea), ".a # | .b | .c");
+    assert_eq!(textarea.cursor(), (0, 9));
+}
+
+#[test]
+fn test_comments_out_whole_query_from_first_stage() {
+    let mut textarea = textarea_at(".a | .b", 1);
+
+    assert!(toggle(&mut textarea));
+
+    assert_eq!(line(&textarea), "# .a | .b");
+    assert_eq!(textarea.cursor(), (0, 3));
+}
+
+#[test]
+fn test_nested_pipes_belong_to_their_stage() {
+    let mut textarea = textarea_at(".a | map(.b | .c)", 15);
+
+    assert!(toggle(&mut textarea));
+
+    assert_eq!(line(&textarea), ".a # | map(.b | .c)");
+}
+
+#[test]
+fn test_toggle_removes_comment() {
+    let mut textarea = textarea_at(".a # | .b | .c", 9);
+
+    assert!(toggle(&mut textarea));
+
+    assert_eq!(line(&textarea), ".a | .b | .c");
+    assert_eq!(textarea.cursor(), (0, 7));
+}
+
+#[test]
+fn test_toggle_twice_restores_query() {
+    let mut textarea = textarea_at(".items[] | select(.id > 1) | .name", 14);
+
+    toggle(&mut textarea);
+    toggle(&mut textarea);
+
+    assert_eq!(line(&textarea), ".items[] | select(.id > 1) | .name");
+    assert_eq!(textarea.cursor(), (0, 14));
+}
+
+#[test]
+fn test_selection_start_picks_stage() {
+    let mut textarea = textarea_at(".a | .b | .c", 5);
+    textarea.start_selection();
+    textarea.move_cursor(CursorMove::End);
+
+    assert!(toggle(&mut textarea));
+
+    assert_eq!(line(&textarea), ".a # | .b | .c");
+    assert!(textarea.selection_range().is_none());
+}
+
+#[test]
+fn test_hash_in_string_is_not_a_comment() {
+    let mut textarea = textarea_at("\"#\" | .a", 7);
+
+    assert!(toggle(&mut textarea));
+
+    assert_eq!(line(&textarea), "\"#\" # | .a");
+}
+
+#[test]
+fn test_comment_without_space_is_removed() {
+    let mut textarea = textarea_at(".a #| .b", 0);
+
+    assert!(toggle(&mut textarea));
+
+    assert_eq!(line(&textarea), ".a | .b");
+    assert_eq!(textarea.cursor(), (0, 0));
+}
+
+#[test]
+fn test_empty_query_is_unchanged() {
+    let mut textarea = textarea_at("  ", 1);
+
+    assert!(!toggle(&mut textarea));
+
+    assert_eq!(line(&textarea), "  ");
+}
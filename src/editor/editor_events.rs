@@ -4,32 +4,115 @@ use tui_textarea::CursorMove;
 use crate::app::App;
 use crate::clipboard;
 use crate::editor::EditorMode;
+use crate::editor::auto_pairs;
 use crate::editor::char_search::{
     CharSearchState, SearchDirection, SearchType, execute_char_search,
 };
+use crate::editor::comment;
 use crate::editor::mode::TextObjectScope;
 use crate::editor::text_objects::{TextObjectTarget, execute_text_object};
+use crate::editor::word_motion::{WordMotion, move_word};
 use crate::help::HelpTab;
 
 pub fn handle_insert_mode_key(app: &mut App, key: KeyEvent) {
     app.input.reset_manual_scroll();
-    let content_changed = app.input.textarea.input(key);
+    if key.modifiers == KeyModifiers::CONTROL {
+        let motion = match key.code {
+            KeyCode::Left => Some(WordMotion::Back),
+            KeyCode::Right => Some(WordMotion::Forward),
+            _ => None,
+        };
+        if let Some(motion) = motion {
+            move_word(&mut app.input.textarea, motion);
+            app.update_autocomplete();
+            app.update_tooltip();
+            return;
+        }
+    }
+    let auto_paired = if app.input.auto_pairs {
+        auto_pairs::handle_key(&mut app.input.textarea, key)
+    } else {
+        None
+    };
+    let content_changed = auto_paired.unwrap_or_else(|| app.input.textarea.input(key));
 
     if content_changed {
-        app.history.reset_cycling();
-        app.debouncer.schedule_execution();
-        app.results_scroll.reset();
-        app.results_cursor.reset();
-        app.error_overlay_visible = false;
-        app.input
-            .brace_tracker
-            .rebuild(app.input.textarea.lines()[0].as_ref());
+        query_edited(app);
     }
 
     app.update_autocomplete();
     app.update_tooltip();
 }
 
+/// Schedule the query to run after typing changed it
+pub fn query_edited(app: &mut App) {
+    app.history.reset_cycling();
+    app.debouncer.schedule_execution();
+    app.results_scroll.reset();
+    app.results_cursor.reset();
+    app.error_overlay_visible = false;
+    app.input
+        .brace_tracker
+        .rebuild(app.input.textarea.lines()[0].as_ref());
+}
+
+/// Replace the query with `query` and run it straight away
+pub fn replace_query_and_execute(app: &mut App, query: &str) {
+    replace_query(app, query);
+    execute_query(app);
+    app.debouncer.mark_executed();
+}
+
+/// Replace the query with `query` without running it, for callers that
+/// restore a result they kept
+pub fn replace_query(app: &mut App, query: &str) {
+    app.input.textarea.delete_line_by_head();
+    app.input.textarea.delete_line_by_end();
+    insert_query_text(app, query);
+}
+
+/// Insert `text` at the query cursor and run the query straight away
+pub fn insert_and_execute(app: &mut App, text: &str) {
+    insert_query_text(app, text);
+    execute_query(app);
+    app.debouncer.mark_executed();
+}
+
+/// Replace the query with the jq `path` and run it
+pub fn jump_to_path(app: &mut App, path: &str) {
+    if let Some(query_state) = &mut app.query {
+        query_state.language = crate::query::language::QueryLanguage::Jq;
+    }
+    replace_query_and_execute(app, path);
+}
+
+/// Insert the jq `path` at the query cursor and run the query
+pub fn insert_path(app: &mut App, path: &str) {
+    app.focus = crate::app::Focus::InputField;
+    insert_and_execute(app, path);
+}
+
+fn insert_query_text(app: &mut App, text: &str) {
+    app.input.textarea.insert_str(text);
+    app.input
+        .brace_tracker
+        .rebuild(app.input.textarea.lines()[0].as_ref());
+    app.autocomplete.hide();
+    app.error_overlay_visible = false;
+    app.results_scroll.reset();
+    app.results_cursor.reset();
+    app.update_tooltip();
+}
+
+/// Comment out the pipeline from the stage at the cursor, or uncomment it
+pub fn toggle_comment(app: &mut App) {
+    if comment::toggle(&mut app.input.textarea) {
+        app.focus = crate::app::Focus::InputField;
+        app.autocomplete.hide();
+        query_edited(app);
+    }
+}
+
 pub fn handle_normal_mode_key(app: &mut App, key: KeyEvent) {
     app.input.reset_manual_scroll();
     match key.code {
@@ -57,13 +140,13 @@ pub fn handle_normal_mode_key(app: &mut App, key: KeyEvent) {
         }
 
         KeyCode::Char('w') => {
-            app.input.textarea.move_cursor(CursorMove::WordForward);
+            move_word(&mut app.input.textarea, WordMotion::Forward);
         }
         KeyCode::Char('b') => {
-            app.input.textarea.move_cursor(CursorMove::WordBack);
+            move_word(&mut app.input.textarea, WordMotion::Back);
         }
         KeyCode::Char('e') => {
-            app.input.textarea.move_cursor(CursorMove::WordEnd);
+            move_word(&mut app.input.textarea, WordMotion::End);
         }
 
         KeyCode::Char('i') => {
@@ -200,15 +283,15 @@ pub fn handle_operator_mode_key(app: &mut App, key: KeyEvent) {
 
     let motion_applied = match key.code {
         KeyCode::Char('w') => {
-            app.input.textarea.move_cursor(CursorMove::WordForward);
+            move_word(&mut app.input.textarea, WordMotion::Forward);
             true
         }
         KeyCode::Char('b') => {
-            app.input.textarea.move_cursor(CursorMove::WordBack);
+            move_word(&mut app.input.textarea, WordMotion::Back);
             true
         }
         KeyCode::Char('e') => {
-            app.input.textarea.move_cursor(CursorMove::WordEnd);
+            move_word(&mut app.input.textarea, WordMotion::End);
             app.input.textarea.move_cursor(CursorMove::Forward);
             true
         }
@@ -413,6 +496,36 @@ pub fn execute_query_with_auto_show(app: &mut App) {
     // AI update happens in poll_query_response() when result arrives
 }
 
+/// Run a query still waiting on the debouncer right away, without the worker
+pub fn run_pending_query(app: &mut App) {
+    if !app.debouncer.has_pending() {
+        return;
+    }
+    app.debouncer.mark_executed();
+    let Some(query_state) = &mut app.query else {
+        return;
+    };
+    let query = app.input.textarea.lines()[0].as_ref();
+    app.input.brace_tracker.rebuild(query);
+    query_state.execute_now(query);
+    app.results_scroll.reset();
+    app.results_cursor.reset();
+    app.error_overlay_visible = false;
+}
+
+/// Bring the result up to date with the query as typed before using it
+///
+/// Returns false, with a warning shown, while an earlier run of the query
+/// is still in flight, as the result shown is not its result yet.
+pub fn settle_query(app: &mut App) -> bool {
+    run_pending_query(app);
+    if app.query.as_ref().is_some_and(|query| query.is_pending()) {
+        app.notification.show_warning("The query is still running");
+        return false;
+    }
+    true
+}
+
 fn operator_char_search_from_key(key: KeyCode) -> Option<(SearchDirection, SearchType)> {
     match key {
         KeyCode::Char('f') => Some((SearchDirection::Forward, SearchType::Find)),
@@ -0,0 +1,72 @@
+//! Emacs key bindings for the query input
+//!
+//! Selected with `[editor] keybindings = "emacs"`. The input then never leaves
+//! Insert mode and these readline keys take precedence over the global keys
+//! sharing them (AI assistant, error overlay, search, bookmarks, copy, ...),
+//! which remain available from the results pane. Killed text goes to the
+//! input's kill ring.
+
+use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use tui_textarea::CursorMove;
+
+use crate::app::App;
+use crate::editor::editor_events::query_edited;
+use crate::editor::kill_ring::Kill;
+use crate::editor::word_motion::{WordMotion, move_word};
+
+/// Handle an emacs editing key; returns false for keys it leaves to others
+pub fn handle_emacs_key(app: &mut App, key: KeyEvent) -> bool {
+    let KeyCode::Char(c) = key.code else {
+        return false;
+    };
+    let textarea = &mut app.input.textarea;
+    let kill_ring = &mut app.input.kill_ring;
+
+    let changed = if key.modifiers.contains(KeyModifiers::CONTROL) {
+        match c {
+            'a' => move_cursor(textarea, CursorMove::Head),
+            'e' => move_cursor(textarea, CursorMove::End),
+            'b' => move_cursor(textarea, CursorMove::Back),
+            'f' => move_cursor(textarea, CursorMove::Forward),
+            'k' => kill_ring.kill(textarea, Kill::ToEnd),
+            'u' => kill_ring.kill(textarea, Kill::ToStart),
+            'w' => kill_ring.kill(textarea, Kill::WordBack),
+            'y' => kill_ring.yank(textarea),
+            'd' => textarea.delete_next_char(),
+            _ => return false,
+        }
+    } else if key.modifiers.contains(KeyModifiers::ALT) {
+        match c {
+            'b' => {
+                move_word(textarea, WordMotion::Back);
+                false
+            }
+            'f' => {
+                move_word(textarea, WordMotion::Forward);
+                false
+            }
+            'd' => kill_ring.kill(textarea, Kill::WordForward),
+            'y' => kill_ring.yank_pop(textarea),
+            _ => return false,
+        }
+    } else {
+        return false;
+    };
+
+    app.input.reset_manual_scroll();
+    if changed {
+        query_edited(app);
+    }
+    app.update_autocomplete();
+    app.update_tooltip();
+    true
+}
+
+fn move_cursor(textarea: &mut tui_textarea::TextArea<'static>, to: CursorMove) -> bool {
+    textarea.move_cursor(to);
+    false
+}
+
+#[cfg(test)]
+#[path = "emacs_tests.rs"]
+mod emacs_tests;
@@ -0,0 +1,153 @@
+//! Tests for emacs
+
+use super::*;
+use crate::config::KeyBindings;
+use crate::editor::EditorMode;
+use crate::test_utils::test_helpers::{app_with_query, key, key_with_mods};
+
+fn emacs_app(query: &str) -> App {
+    let mut app = app_with_query(query);
+    app.input.keybindings = KeyBindings::Emacs;
+    app
+}
+
+fn ctrl(c: char) -> KeyEvent {
+    key_with_mods(KeyCode::Char(c), KeyModifiers::CONTROL)
+}
+
+fn alt(c: char) -> KeyEvent {
+    key_with_mods(KeyCode::Char(c), KeyModifiers::ALT)
+}
+
+fn cursor(app: &App) -> usize {
+    app.input.textarea.cursor().1
+}
+
+#[test]
+fn test_ctrl_a_and_ctrl_e_move_to_line_ends() {
+    let mut app = emacs_app(".items[].name");
+
+    app.handle_key_event(ctrl('a'));
+    assert_eq!(cursor(&app), 0);
+    assert!(!app.ai.visible);
+
+    app.handle_key_event(ctrl('e'));
+    assert_eq!(cursor(&app), 13);
+    assert!(!app.error_overlay_visible);
+}
+
+#[test]
+fn test_ctrl_b_and_ctrl_f_move_by_character() {
+    let mut app = emacs_app(".name");
+
+    app.handle_key_event(ctrl('b'));
+    assert_eq!(cursor(&app), 4);
+    app.handle_key_event(ctrl('f'));
+    assert_eq!(cursor(&app), 5);
+    assert!(!app.search.is_visible());
+}
+
+#[test]
+fn test_alt_b_and_alt_f_move_by_word() {
+    let mut app = emacs_app("map(.name)");
+
+    app.handle_key_event(alt('b'));
+    assert!(cursor(&app) < 10);
+    assert!(!app.bookmarks.is_visible());
+
+    let before = cursor(&app);
+    app.handle_key_event(alt('f'));
+    assert!(cursor(&app) > before);
+}
+
+#[test]
+fn test_ctrl_k_deletes_to_end() {
+    let mut app = emacs_app(".name | length");
+    app.handle_key_event(ctrl('a'));
+    for _ in 0..5 {
+        app.handle_key_event(ctrl('f'));
+    }
+
+    app.handle_key_event(ctrl('k'));
+    assert_eq!(app.query(), ".name");
+}
+
+#[test]
+fn test_ctrl_u_deletes_to_start() {
+    let mut app = emacs_app(".name | length");
+    app.handle_key_event(ctrl('b'));
+
+    app.handle_key_event(ctrl('u'));
+    assert_eq!(app.query(), "h");
+}
+
+#[test]
+fn test_ctrl_w_deletes_previous_word() {
+    let mut app = emacs_app(".name | length");
+
+    app.handle_key_event(ctrl('w'));
+    assert_eq!(app.query(), ".name | ");
+}
+
+#[test]
+fn test_ctrl_d_deletes_next_character() {
+    let mut app = emacs_app(".name");
+    app.handle_key_event(ctrl('a'));
+
+    app.handle_key_event(ctrl('d'));
+    assert_eq!(app.query(), "name");
+}
+
+#[test]
+fn test_ctrl_y_yanks_killed_text() {
+    let mut app = emacs_app(".name | length");
+    app.handle_key_event(ctrl('w'));
+    app.handle_key_event(ctrl('a'));
+
+    app.handle_key_event(ctrl('y'));
+    assert_eq!(app.query(), "length.name | ");
+}
+
+#[test]
+fn test_alt_y_cycles_the_kill_ring() {
+    let mut app = emacs_app(".a | .b");
+    app.handle_key_event(ctrl('b'));
+    app.handle_key_event(ctrl('k'));
+    app.handle_key_event(ctrl('b'));
+    app.handle_key_event(ctrl('u'));
+    assert_eq!(app.query(), ".");
+
+    app.handle_key_event(ctrl('y'));
+    assert_eq!(app.query(), ".a | .");
+    app.handle_key_event(alt('y'));
+    assert_eq!(app.query(), "b.");
+}
+
+#[test]
+fn test_esc_stays_in_insert_mode() {
+    let mut app = emacs_app(".name");
+
+    app.handle_key_event(key(KeyCode::Esc));
+    assert_eq!(app.input.editor_mode, EditorMode::Insert);
+
+    app.handle_key_event(key(KeyCode::Char('x')));
+    assert_eq!(app.query(), ".namex");
+}
+
+#[test]
+fn test_other_keys_are_left_alone() {
+    let mut app = emacs_app(".name");
+
+    assert!(!handle_emacs_key(&mut app, key(KeyCode::Char('a'))));
+    assert!(!handle_emacs_key(&mut app, ctrl('r')));
+    assert!(!handle_emacs_key(&mut app, key(KeyCode::Left)));
+    assert_eq!(app.query(), ".name");
+}
+
+#[test]
+fn test_vim_profile_keeps_global_keys() {
+    let mut app = app_with_query(".name");
+
+    app.handle_key_event(ctrl('a'));
+    assert_eq!(cursor(&app), 5);
+}
@@ -0,0 +1,157 @@
+//! Kill ring of the emacs key bindings
+//!
+//! Text removed with `Ctrl+K`, `Ctrl+U`, `Ctrl+W` or `Alt+D` is kept, newest
+//! last, so `Ctrl+Y` can insert it again and `Alt+Y` right after a yank can
+//! swap it for older kills. As in readline, kills in a row make up one entry.
+
+use tui_textarea::{CursorMove, TextArea};
+
+/// Number of kills kept before the oldest is dropped
+const MAX_KILLS: usize = 16;
+
+/// Text a kill removes, relative to the cursor
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kill {
+    /// To the end of the line (`Ctrl+K`)
+    ToEnd,
+    /// To the start of the line (`Ctrl+U`)
+    ToStart,
+    /// The word before the cursor (`Ctrl+W`)
+    WordBack,
+    /// The word after the cursor (`Alt+D`)
+    WordForward,
+}
+
+impl Kill {
+    /// Whether the removed text comes after the cursor
+    fn is_forward(self) -> bool {
+        matches!(self, Kill::ToEnd | Kill::WordForward)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum EditKind {
+    Kill,
+    /// Kill at `index` inserted starting at column `start`
+    Yank {
+        start: usize,
+        index: usize,
+    },
+}
+
+/// The last kill or yank and the query it left
+#[derive(Debug, Clone)]
+struct LastEdit {
+    kind: EditKind,
+    line: String,
+    cursor: usize,
+}
+
+#[derive(Debug, Default)]
+pub struct KillRing {
+    kills: Vec<String>,
+    last: Option<LastEdit>,
+}
+
+impl KillRing {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Kills kept, newest last
+    #[cfg(test)]
+    pub fn kills(&self) -> &[String] {
+        &self.kills
+    }
+
+    /// Remove text from `textarea` and keep it; returns true if anything was removed
+    ///
+    /// Follows on from the previous kill when the query hasn't changed since,
+    /// adding the text to that entry instead of starting a new one.
+    pub fn kill(&mut self, textarea: &mut TextArea<'static>, kill: Kill) -> bool {
+        let continues = self.continues(textarea, |kind| *kind == EditKind::Kill);
+        let removed = match kill {
+            Kill::ToEnd => textarea.delete_line_by_end(),
+            Kill::ToStart => textarea.delete_line_by_head(),
+            Kill::WordBack => textarea.delete_word(),
+            Kill::WordForward => textarea.delete_next_word(),
+        };
+        if !removed {
+            return false;
+        }
+
+        let text = textarea.yank_text();
+        match self.kills.last_mut() {
+            Some(last) if continues && kill.is_forward() => last.push_str(&text),
+            Some(last) if continues => last.insert_str(0, &text),
+            _ => {
+                self.kills.push(text);
+                if self.kills.len() > MAX_KILLS {
+                    self.kills.remove(0);
+                }
+            }
+        }
+        self.remember(EditKind::Kill, textarea);
+        true
+    }
+
+    /// Insert the newest kill at the cursor; returns false when there is none
+    pub fn yank(&mut self, textarea: &mut TextArea<'static>) -> bool {
+        let Some(text) = self.kills.last() else {
+            return false;
+        };
+        let start = textarea.cursor().1;
+        textarea.insert_str(text);
+        let index = self.kills.len() - 1;
+        self.remember(EditKind::Yank { start, index }, textarea);
+        true
+    }
+
+    /// Replace the text just yanked with the kill before it, wrapping around
+    ///
+    /// Only works straight after `yank` or another `yank_pop`.
+    pub fn yank_pop(&mut self, textarea: &mut TextArea<'static>) -> bool {
+        if !self.continues(textarea, |kind| matches!(kind, EditKind::Yank { .. })) {
+            return false;
+        }
+        let Some(LastEdit {
+            kind: EditKind::Yank { start, index },
+            cursor,
+            ..
+        }) = self.last.take()
+        else {
+            return false;
+        };
+
+        textarea.move_cursor(CursorMove::Jump(0, start as u16));
+        textarea.delete_str(cursor - start);
+        let index = index.checked_sub(1).unwrap_or(self.kills.len() - 1);
+        textarea.insert_str(&self.kills[index]);
+        self.remember(EditKind::Yank { start, index }, textarea);
+        true
+    }
+
+    /// Whether the last edit was of `kind` and nothing changed the query since
+    fn continues(&self, textarea: &TextArea<'static>, kind: impl Fn(&EditKind) -> bool) -> bool {
+        self.last.as_ref().is_some_and(|last| {
+            kind(&last.kind)
+                && last.cursor == textarea.cursor().1
+                && textarea
+                    .lines()
+                    .first()
+                    .is_some_and(|line| *line == last.line)
+        })
+    }
+
+    fn remember(&mut self, kind: EditKind, textarea: &TextArea<'static>) {
+        self.last = Some(LastEdit {
+            kind,
+            line: textarea.lines().first().cloned().unwrap_or_default(),
+            cursor: textarea.cursor().1,
+        });
+    }
+}
+
+#[cfg(test)]
+#[path = "kill_ring_tests.rs"]
+mod kill_ring_tests;
@@ -0,0 +1,130 @@
+//! Tests for kill_ring
+
+use super::*;
+
+fn textarea(text: &str) -> TextArea<'static> {
+    let mut textarea = TextArea::from([text.to_string()]);
+    textarea.move_cursor(CursorMove::End);
+    textarea
+}
+
+fn line<'a>(textarea: &'a TextArea<'static>) -> &'a str {
+    &textarea.lines()[0]
+}
+
+#[test]
+fn test_kill_keeps_removed_text() {
+    let mut ring = KillRing::new();
+    let mut textarea = textarea(".items | length");
+
+    assert!(ring.kill(&mut textarea, Kill::WordBack));
+    assert_eq!(line(&textarea), ".items | ");
+    assert_eq!(ring.kills(), ["length"]);
+}
+
+#[test]
+fn test_kill_of_nothing_is_not_kept() {
+    let mut ring = KillRing::new();
+    let mut textarea = textarea(".items");
+
+    assert!(!ring.kill(&mut textarea, Kill::ToEnd));
+    assert!(ring.kills().is_empty());
+}
+
+#[test]
+fn test_kills_in_a_row_join() {
+    let mut ring = KillRing::new();
+    let mut textarea = textarea(".items | map(.name)");
+
+    ring.kill(&mut textarea, Kill::WordBack);
+    ring.kill(&mut textarea, Kill::WordBack);
+    assert_eq!(ring.kills().len(), 1);
+
+    textarea.move_cursor(CursorMove::Head);
+    ring.kill(&mut textarea, Kill::ToEnd);
+    assert_eq!(ring.kills().len(), 2);
+    assert_eq!(line(&textarea), "");
+}
+
+#[test]
+fn test_joined_backward_kills_keep_their_order() {
+    let mut ring = KillRing::new();
+    let mut textarea = textarea("a b c");
+
+    ring.kill(&mut textarea, Kill::WordBack);
+    ring.kill(&mut textarea, Kill::ToStart);
+    assert_eq!(ring.kills(), ["a b c"]);
+}
+
+#[test]
+fn test_typing_between_kills_starts_a_new_entry() {
+    let mut ring = KillRing::new();
+    let mut textarea = textarea(".a .b");
+
+    ring.kill(&mut textarea, Kill::WordBack);
+    textarea.insert_str("x");
+    textarea.delete_char();
+    textarea.move_cursor(CursorMove::Back);
+    ring.kill(&mut textarea, Kill::ToEnd);
+    assert_eq!(ring.kills().len(), 2);
+}
+
+#[test]
+fn test_yank_inserts_newest_kill() {
+    let mut ring = KillRing::new();
+    let mut textarea = textarea(".name");
+
+    assert!(!ring.yank(&mut textarea));
+
+    textarea.move_cursor(CursorMove::Head);
+    ring.kill(&mut textarea, Kill::ToEnd);
+    textarea.insert_str(".items[] | ");
+    assert!(ring.yank(&mut textarea));
+    assert_eq!(line(&textarea), ".items[] | .name");
+}
+
+#[test]
+fn test_yank_pop_cycles_older_kills() {
+    let mut ring = KillRing::new();
+    let mut textarea = textarea("first");
+    ring.kill(&mut textarea, Kill::ToStart);
+    textarea.insert_str("second");
+    textarea.move_cursor(CursorMove::Head);
+    ring.kill(&mut textarea, Kill::ToEnd);
+    textarea.insert_str("[");
+
+    ring.yank(&mut textarea);
+    assert_eq!(line(&textarea), "[second");
+    assert!(ring.yank_pop(&mut textarea));
+    assert_eq!(line(&textarea), "[first");
+    assert!(ring.yank_pop(&mut textarea));
+    assert_eq!(line(&textarea), "[second");
+    assert_eq!(textarea.cursor().1, 7);
+}
+
+#[test]
+fn test_yank_pop_needs_a_yank_first() {
+    let mut ring = KillRing::new();
+    let mut textarea = textarea("first");
+    ring.kill(&mut textarea, Kill::ToStart);
+
+    assert!(!ring.yank_pop(&mut textarea));
+
+    ring.yank(&mut textarea);
+    textarea.insert_str("!");
+    assert!(!ring.yank_pop(&mut textarea));
+    assert_eq!(line(&textarea), "first!");
+}
+
+#[test]
+fn test_oldest_kills_are_dropped() {
+    let mut ring = KillRing::new();
+    let mut textarea = textarea("");
+    for i in 0..MAX_KILLS + 2 {
+        textarea.insert_str(format!("k{}", i));
+        ring.kill(&mut textarea, Kill::ToStart);
+    }
+
+    assert_eq!(ring.kills().len(), MAX_KILLS);
+    assert_eq!(ring.kills()[0], "k2");
+}
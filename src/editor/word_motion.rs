@@ -0,0 +1,138 @@
+//! Word motions aware of jq paths
+//!
+//! A path like `.services[0].deploymentConfiguration` is three words, one per
+//! segment, so `w`, `b` and `e` step through it a segment at a time. Elsewhere
+//! words are runs of identifier characters or of other punctuation.
+
+use tui_textarea::{CursorMove, TextArea};
+
+/// Direction of a word motion
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WordMotion {
+    /// Start of the next word (`w`)
+    Forward,
+    /// Start of the word before the cursor (`b`)
+    Back,
+    /// Last character of the word ending after the cursor (`e`)
+    End,
+}
+
+/// Move the cursor of `textarea` by one word
+pub fn move_word(textarea: &mut TextArea<'static>, motion: WordMotion) {
+    let (row, col) = textarea.cursor();
+    let Some(line) = textarea.lines().get(row) else {
+        return;
+    };
+    let target = match motion {
+        WordMotion::Forward => next_word_start(line, col),
+        WordMotion::Back => previous_word_start(line, col),
+        WordMotion::End => word_end(line, col),
+    };
+    textarea.move_cursor(CursorMove::Jump(row as u16, target as u16));
+}
+
+/// Column of the next word's start, or the line end after the last word
+pub fn next_word_start(line: &str, col: usize) -> usize {
+    words(line)
+        .into_iter()
+        .map(|(start, _)| start)
+        .find(|&start| start > col)
+        .unwrap_or_else(|| line.chars().count())
+}
+
+/// Column of the start of the word before `col`, or 0
+pub fn previous_word_start(line: &str, col: usize) -> usize {
+    words(line)
+        .into_iter()
+        .map(|(start, _)| start)
+        .take_while(|&start| start < col)
+        .last()
+        .unwrap_or(0)
+}
+
+/// Column of the last character of the first word ending after `col`
+///
+/// Stays put when no word ends after `col`.
+pub fn word_end(line: &str, col: usize) -> usize {
+    words(line)
+        .into_iter()
+        .map(|(_, end)| end - 1)
+        .find(|&last| last > col)
+        .unwrap_or(col)
+}
+
+/// Words of `line` as ranges of char columns, end exclusive
+fn words(line: &str) -> Vec<(usize, usize)> {
+    let chars: Vec<char> = line.chars().collect();
+    let mut words = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let start = i;
+        let c = chars[i];
+        if c.is_whitespace() {
+            i += 1;
+            continue;
+        }
+
+        let next = chars.get(i + 1).copied();
+        if c == '.' && next.is_some_and(is_identifier) {
+            i = identifier_end(&chars, i + 1);
+        } else if c == '.' && next == Some('[') {
+            i = bracket_end(&chars, i + 1);
+        } else if c == '[' {
+            i = bracket_end(&chars, i);
+        } else if is_identifier(c) {
+            i = identifier_end(&chars, i);
+        } else {
+            i += 1;
+            while i < chars.len() && is_plain_punctuation(chars[i]) {
+                i += 1;
+            }
+        }
+        words.push((start, i));
+    }
+
+    words
+}
+
+fn is_identifier(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '$'
+}
+
+/// Punctuation that doesn't start a path segment
+fn is_plain_punctuation(c: char) -> bool {
+    !c.is_whitespace() && !is_identifier(c) && c != '.' && c != '['
+}
+
+fn identifier_end(chars: &[char], from: usize) -> usize {
+    let mut i = from;
+    while i < chars.len() && is_identifier(chars[i]) {
+        i += 1;
+    }
+    i
+}
+
+/// End of the `[...]` segment opening at `open`
+///
+/// Brackets holding whitespace or other brackets outside strings, like array
+/// constructions, are not segments; only the `[` is a word then.
+fn bracket_end(chars: &[char], open: usize) -> usize {
+    let mut in_string = false;
+    let mut i = open + 1;
+    while i < chars.len() {
+        match chars[i] {
+            '\\' if in_string => i += 1,
+            '"' => in_string = !in_string,
+            ']' if !in_string => return i + 1,
+            c if !in_string && (c.is_whitespace() || c == '[') => break,
+            _ => {}
+        }
+        i += 1;
+    }
+    open + 1
+}
+
+#[cfg(test)]
+#[path = "word_motion_tests.rs"]
+mod word_motion_tests;
@@ -0,0 +1,116 @@
+//! Tests for word_motion
+
+use super::*;
+use crate::editor::EditorMode;
+use crate::test_utils::test_helpers::{app_with_query, key, key_with_mods};
+use ratatui::crossterm::event::{KeyCode, KeyModifiers};
+use tui_textarea::CursorMove;
+
+const PATH: &str = ".services[0].deploymentConfiguration";
+
+#[test]
+fn test_path_segments_are_words() {
+    assert_eq!(words(PATH), vec![(0, 9), (9, 12), (12, 36)]);
+}
+
+#[test]
+fn test_words_of_a_pipeline() {
+    assert_eq!(
+        words(r#".items[] | select(.name == "a b")"#),
+        vec![
+            (0, 6),
+            (6, 8),
+            (9, 10),
+            (11, 17),
+            (17, 18),
+            (18, 23),
+            (24, 26),
+            (27, 28),
+            (28, 29),
+            (30, 31),
+            (31, 33),
+        ]
+    );
+}
+
+#[test]
+fn test_bracket_segments() {
+    assert_eq!(words(r#".["my key"].a"#), vec![(0, 11), (11, 13)]);
+    assert_eq!(words("$env.HOME"), vec![(0, 4), (4, 9)]);
+    assert_eq!(
+        words("[.a, .b]"),
+        vec![(0, 1), (1, 3), (3, 4), (5, 7), (7, 8)]
+    );
+}
+
+#[test]
+fn test_next_word_start() {
+    assert_eq!(next_word_start(PATH, 0), 9);
+    assert_eq!(next_word_start(PATH, 9), 12);
+    assert_eq!(next_word_start(PATH, 20), 36);
+    assert_eq!(next_word_start("", 0), 0);
+}
+
+#[test]
+fn test_previous_word_start() {
+    assert_eq!(previous_word_start(PATH, 36), 12);
+    assert_eq!(previous_word_start(PATH, 20), 12);
+    assert_eq!(previous_word_start(PATH, 12), 9);
+    assert_eq!(previous_word_start(PATH, 0), 0);
+}
+
+#[test]
+fn test_word_end() {
+    assert_eq!(word_end(PATH, 0), 8);
+    assert_eq!(word_end(PATH, 8), 11);
+    assert_eq!(word_end(PATH, 35), 35);
+}
+
+#[test]
+fn test_move_word_moves_textarea_cursor() {
+    let mut textarea = TextArea::from([PATH.to_string()]);
+
+    move_word(&mut textarea, WordMotion::Forward);
+    assert_eq!(textarea.cursor(), (0, 9));
+    move_word(&mut textarea, WordMotion::End);
+    assert_eq!(textarea.cursor(), (0, 11));
+    move_word(&mut textarea, WordMotion::Back);
+    assert_eq!(textarea.cursor(), (0, 9));
+}
+
+#[test]
+fn test_w_steps_through_path_segments() {
+    let mut app = app_with_query(".services[0].deploymentConfiguration");
+    app.input.textarea.move_cursor(CursorMove::Head);
+    app.input.editor_mode = EditorMode::Normal;
+
+    app.handle_key_event(key(KeyCode::Char('w')));
+    assert_eq!(app.input.textarea.cursor().1, 9);
+    app.handle_key_event(key(KeyCode::Char('w')));
+    assert_eq!(app.input.textarea.cursor().1, 12);
+}
+
+#[test]
+fn test_dw_deletes_one_path_segment() {
+    let mut app = app_with_query(".services[0].name");
+    app.input.textarea.move_cursor(CursorMove::Head);
+    app.input.editor_mode = EditorMode::Normal;
+
+    app.handle_key_event(key(KeyCode::Char('d')));
+    app.handle_key_event(key(KeyCode::Char('w')));
+    assert_eq!(app.query(), "[0].name");
+}
+
+#[test]
+fn test_ctrl_arrows_move_by_path_segment_in_insert_mode() {
+    let mut app = app_with_query(".services[0].name");
+    app.input.editor_mode = EditorMode::Insert;
+
+    app.handle_key_event(key_with_mods(KeyCode::Left, KeyModifiers::CONTROL));
+    assert_eq!(app.input.textarea.cursor().1, 12);
+    app.handle_key_event(key_with_mods(KeyCode::Left, KeyModifiers::CONTROL));
+    assert_eq!(app.input.textarea.cursor().1, 9);
+    app.handle_key_event(key_with_mods(KeyCode::Right, KeyModifiers::CONTROL));
+    assert_eq!(app.input.textarea.cursor().1, 12);
+    assert_eq!(app.query(), ".services[0].name");
+}
@@ -1,15 +1,38 @@
+pub mod explain;
+pub mod parser;
+
 use thiserror::Error;
 
 #[derive(Debug, Error, Clone, PartialEq)]
 pub enum JiqError {
-    #[error("jq binary not found in PATH.\n\nInstall jq from: https://jqlang.org/download/")]
+    #[error(
+        "jq binary not found in PATH.\n\nInstall jq from: https://jqlang.org/download/, or set JIQ_JQ to its path."
+    )]
     JqNotFound,
 
     #[error("Invalid JSON input: {0}")]
     InvalidJson(String),
 
+    #[error("Invalid TOML input: {0}")]
+    InvalidToml(String),
+
+    #[error("Invalid INI input: {0}")]
+    InvalidIni(String),
+
+    #[error("Invalid CSV input: {0}")]
+    InvalidCsv(String),
+
+    #[error("Invalid XML input: {0}")]
+    InvalidXml(String),
+
     #[error("IO error: {0}")]
     Io(String),
+
+    #[error("Command failed: {0}")]
+    CommandFailed(String),
+
+    #[error("Plugin failed: {0}")]
+    PluginFailed(String),
 }
 
 impl From<std::io::Error> for JiqError {
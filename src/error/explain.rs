@@ -0,0 +1,273 @@
+//! Plain-language explanations of jq errors
+//!
+//! jq's messages name the operation that failed and the types involved
+//! ("Cannot index string with string"), not the mistake in the query that
+//! usually causes it. Common messages are matched against a table of
+//! patterns, where `*` stands for any text, and explained with a likely fix
+//! and the manual sections of the functions involved.
+
+/// Base address of the jq manual, section anchors are appended
+const MANUAL_URL: &str = "https://jqlang.org/manual/";
+
+/// What a jq error means and how to fix it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Explanation {
+    /// What went wrong, in terms of the query
+    pub summary: &'static str,
+    /// Most likely way to fix it
+    pub fix: &'static str,
+    /// Functions and operators involved, with their manual section anchor
+    pub related: &'static [(&'static str, &'static str)],
+}
+
+impl Explanation {
+    /// Names of the related functions
+    pub fn related_names(&self) -> Vec<&'static str> {
+        self.related.iter().map(|(name, _)| *name).collect()
+    }
+
+    /// Manual section of the first related function
+    pub fn manual_url(&self) -> Option<String> {
+        self.related
+            .first()
+            .map(|(_, anchor)| format!("{MANUAL_URL}#{anchor}"))
+    }
+}
+
+const TYPE: (&str, &str) = ("type", "type");
+const SELECT: (&str, &str) = ("select", "select");
+const TOSTRING: (&str, &str) = ("tostring", "tostring");
+const TONUMBER: (&str, &str) = ("tonumber", "tonumber");
+const OPTIONAL: (&str, &str) = ("?", "error-suppression-optional-operator");
+const ALTERNATIVE: (&str, &str) = ("//", "alternative-operator");
+const FORMAT: (&str, &str) = ("@csv", "format-strings-and-escaping");
+
+const STRING_INPUT: Explanation = Explanation {
+    summary: "A string function ran on a value that is not a string.",
+    fix: "Convert the value with `tostring`, or skip others with `select(type == \"string\")`.",
+    related: &[TOSTRING, SELECT],
+};
+
+const ARITHMETIC: Explanation = Explanation {
+    summary: "An arithmetic operator got values of types it can't combine.",
+    fix: "Check the types with `type` and convert strings with `tonumber`.",
+    related: &[TYPE, TONUMBER],
+};
+
+const OBJECT_KEY: Explanation = Explanation {
+    summary: "An object was built with a key that is not a string.",
+    fix: "Convert the key with `tostring`: `{(.id | tostring): .name}`.",
+    related: &[("{}", "object-construction"), TOSTRING],
+};
+
+const NOT_A_NUMBER: Explanation = Explanation {
+    summary: "A string doesn't hold a valid number or JSON text, so it couldn't be converted.",
+    fix: "Fall back for such strings with `tonumber? // null`.",
+    related: &[TONUMBER, OPTIONAL, ALTERNATIVE],
+};
+
+const CSV_ROW: Explanation = Explanation {
+    summary: "`@csv` and `@tsv` format one array of strings, numbers, booleans and nulls per row.",
+    fix: "Build a row of plain values, e.g. `[.name, .age] | @csv`, with `tojson` for nested ones.",
+    related: &[FORMAT, ("tojson", "tojson-fromjson")],
+};
+
+/// Message patterns and their explanations, the first match wins
+const EXPLANATIONS: &[(&str, &Explanation)] = &[
+    (
+        "Cannot index string with *",
+        &Explanation {
+            summary: "A field or index was looked up on a string, not on an object or array.",
+            fix: "Check the data at this point with `type`; `.name?` skips values that can't be indexed.",
+            related: &[TYPE, SELECT, OPTIONAL],
+        },
+    ),
+    (
+        "Cannot index array with string*",
+        &Explanation {
+            summary: "A field was looked up on an array; fields belong to the objects inside it.",
+            fix: "Iterate over the array first: `.[].name` or `map(.name)`.",
+            related: &[
+                (".[]", "array-object-value-iterator"),
+                ("map", "map-map_values"),
+            ],
+        },
+    ),
+    (
+        "Cannot index object with number*",
+        &Explanation {
+            summary: "An object was indexed by position, as if it were an array.",
+            fix: "Look keys up by name with `.[\"key\"]`, or list them with `keys` or `to_entries`.",
+            related: &[
+                ("keys", "keys-keys_unsorted"),
+                ("to_entries", "to_entries-from_entries-with_entries"),
+            ],
+        },
+    ),
+    (
+        "Cannot index * with *",
+        &Explanation {
+            summary: "A value was indexed that has no fields or elements of that kind.",
+            fix: "Check the data at this point with `type`; `.name?` and `.[0]?` skip values that can't be indexed.",
+            related: &[TYPE, OPTIONAL],
+        },
+    ),
+    (
+        "Cannot iterate over null*",
+        &Explanation {
+            summary: "`.[]` ran on null, usually because the field before it is missing.",
+            fix: "Skip missing values with `.[]?`, or give a default: `(.items // [])[]`.",
+            related: &[OPTIONAL, ALTERNATIVE],
+        },
+    ),
+    (
+        "Cannot iterate over *",
+        &Explanation {
+            summary: "`.[]` ran on a value that is neither an array nor an object.",
+            fix: "Check the data at this point with `type`; `.[]?` skips values that can't be iterated.",
+            related: &[(".[]", "array-object-value-iterator"), TYPE, OPTIONAL],
+        },
+    ),
+    (
+        "* and * cannot be added",
+        &Explanation {
+            summary: "`+` got values it can't combine, such as a string and a number.",
+            fix: "Convert one side with `tostring` or `tonumber`, or interpolate: `\"\\(.a) \\(.b)\"`.",
+            related: &[("+", "addition"), TOSTRING, TONUMBER],
+        },
+    ),
+    (
+        "* cannot be divided because the divisor is zero",
+        &Explanation {
+            summary: "A number was divided by zero.",
+            fix: "Guard the division: `if .d == 0 then null else .n / .d end`.",
+            related: &[("if", "if-then-else-end")],
+        },
+    ),
+    ("* cannot be subtracted", &ARITHMETIC),
+    ("* cannot be multiplied", &ARITHMETIC),
+    ("* cannot be divided", &ARITHMETIC),
+    (
+        "$* is not defined",
+        &Explanation {
+            summary: "A variable is used that was never bound.",
+            fix: "Bind it with `... as $name | ...`, or pass it with `--arg name value`.",
+            related: &[("as", "variable-symbolic-binding-operator")],
+        },
+    ),
+    (
+        "* is not defined",
+        &Explanation {
+            summary: "No function has this name and number of arguments (the `/N` after the name).",
+            fix: "Check the spelling and the arguments, or define it first with `def name: ...;`.",
+            related: &[("def", "defining-functions")],
+        },
+    ),
+    (
+        "syntax error, unexpected INVALID_CHARACTER*",
+        &Explanation {
+            summary: "The query contains a character jq doesn't accept, often a single quote.",
+            fix: "Write strings in double quotes: `\"text\"`.",
+            related: &[],
+        },
+    ),
+    (
+        "syntax error, unexpected end of file*",
+        &Explanation {
+            summary: "The query ends in the middle of an expression.",
+            fix: "Close the open `(`, `[`, `{`, string or `if ... end`, or remove a trailing `|`.",
+            related: &[],
+        },
+    ),
+    (
+        "syntax error, unexpected $end*",
+        &Explanation {
+            summary: "The query ends in the middle of an expression.",
+            fix: "Close the open `(`, `[`, `{`, string or `if ... end`, or remove a trailing `|`.",
+            related: &[],
+        },
+    ),
+    (
+        "syntax error, unexpected *",
+        &Explanation {
+            summary: "jq couldn't make sense of the query at this point.",
+            fix: "Look for a missing `|`, `,` or `;`, or an unbalanced bracket near the underlined part.",
+            related: &[],
+        },
+    ),
+    ("Object keys must be strings*", &OBJECT_KEY),
+    ("Cannot use * as object key*", &OBJECT_KEY),
+    (
+        "Cannot check whether * has a *",
+        &Explanation {
+            summary: "`has` got a key of the wrong type: objects take strings, arrays numbers.",
+            fix: "Pass a string for objects, `has(\"name\")`, or a number for arrays, `has(0)`.",
+            related: &[("has", "has-key")],
+        },
+    ),
+    (
+        "* has no keys",
+        &Explanation {
+            summary: "`keys` ran on a value that is neither an object nor an array.",
+            fix: "Skip other values with `select(type == \"object\")`.",
+            related: &[("keys", "keys-keys_unsorted"), SELECT],
+        },
+    ),
+    (
+        "* has no length",
+        &Explanation {
+            summary: "`length` ran on a boolean, which has no length.",
+            fix: "Skip booleans with `select(type != \"boolean\")`.",
+            related: &[("length", "length"), SELECT],
+        },
+    ),
+    (
+        "* cannot be sorted, as it is not an array",
+        &Explanation {
+            summary: "`sort` or `sort_by` ran on a value that is not an array.",
+            fix: "Collect values into an array first, `[.[]] | sort`, or sort entries with `to_entries | sort_by(.key)`.",
+            related: &[
+                ("sort", "sort-sort_by"),
+                ("to_entries", "to_entries-from_entries-with_entries"),
+            ],
+        },
+    ),
+    ("* cannot be matched, as it is not a string", &STRING_INPUT),
+    ("* input must be a string", &STRING_INPUT),
+    ("* must be strings", &STRING_INPUT),
+    ("* requires string inputs", &STRING_INPUT),
+    ("* cannot be parsed as a number", &NOT_A_NUMBER),
+    ("Cannot parse '*", &NOT_A_NUMBER),
+    ("* is not valid in a csv row", &CSV_ROW),
+    ("* cannot be csv-formatted, only an array can be", &CSV_ROW),
+    ("* cannot be tsv-formatted, only an array can be", &CSV_ROW),
+];
+
+/// Explanation of the jq error `message`, if it is a known one
+pub fn explain(message: &str) -> Option<&'static Explanation> {
+    EXPLANATIONS
+        .iter()
+        .find(|(pattern, _)| matches(pattern, message))
+        .map(|(_, explanation)| *explanation)
+}
+
+/// Whether `message` starts like `pattern` and contains the rest of it in
+/// order, `*` matching any text
+fn matches(pattern: &str, message: &str) -> bool {
+    let mut parts = pattern.split('*');
+    let Some(rest) = parts.next().and_then(|first| message.strip_prefix(first)) else {
+        return false;
+    };
+    let mut rest = rest;
+    for part in parts {
+        match rest.find(part) {
+            Some(at) => rest = &rest[at + part.len()..],
+            None => return false,
+        }
+    }
+    true
+}
+
+#[cfg(test)]
+#[path = "explain_tests.rs"]
+mod explain_tests;
@@ -0,0 +1,131 @@
+//! Tests for error/explain
+
+use super::*;
+
+fn summary(message: &str) -> Option<&'static str> {
+    explain(message).map(|explanation| explanation.summary)
+}
+
+#[test]
+fn test_explains_string_indexing() {
+    let explanation = explain("Cannot index string with string \"name\"").unwrap();
+
+    assert!(explanation.summary.contains("on a string"));
+    assert!(explanation.fix.contains("type"));
+}
+
+#[test]
+fn test_specific_pattern_wins_over_general_one() {
+    assert_eq!(
+        summary("Cannot index array with string \"name\""),
+        Some("A field was looked up on an array; fields belong to the objects inside it.")
+    );
+    assert_eq!(
+        summary("Cannot index boolean with number"),
+        Some("A value was indexed that has no fields or elements of that kind.")
+    );
+}
+
+#[test]
+fn test_iterating_null_points_at_missing_field() {
+    assert!(
+        summary("Cannot iterate over null")
+            .unwrap()
+            .contains("missing")
+    );
+    assert!(
+        summary("Cannot iterate over number (1)")
+            .unwrap()
+            .contains("neither an array nor an object")
+    );
+}
+
+#[test]
+fn test_wildcards_match_types_and_values() {
+    assert!(summary("string (\"a\") and number (1) cannot be added").is_some());
+    assert_eq!(
+        explain("number (1) and number (0) cannot be divided because the divisor is zero")
+            .unwrap()
+            .related[0]
+            .0,
+        "if"
+    );
+}
+
+#[test]
+fn test_variables_and_functions_are_told_apart() {
+    assert!(
+        summary("$limit is not defined")
+            .unwrap()
+            .contains("variable")
+    );
+    assert!(
+        summary("mapp/1 is not defined")
+            .unwrap()
+            .contains("function")
+    );
+}
+
+#[test]
+fn test_syntax_errors() {
+    assert!(
+        summary("syntax error, unexpected INVALID_CHARACTER (Unix shell quoting issues?)")
+            .unwrap()
+            .contains("single quote")
+    );
+    assert_eq!(
+        summary("syntax error, unexpected end of file"),
+        summary("syntax error, unexpected $end")
+    );
+    assert!(summary("syntax error, unexpected '}'").is_some());
+}
+
+#[test]
+fn test_unknown_message_has_no_explanation() {
+    assert_eq!(explain("something went wrong"), None);
+    assert_eq!(explain(""), None);
+}
+
+#[test]
+fn test_pattern_start_is_anchored() {
+    assert!(!matches(
+        "Cannot index string with *",
+        "x Cannot index string with y"
+    ));
+    assert!(matches("* has no keys", "number (1) has no keys"));
+    assert!(!matches("* and * cannot be added", "cannot be added and"));
+}
+
+#[test]
+fn test_manual_url_points_to_first_related_section() {
+    let explanation = explain("Cannot index object with number").unwrap();
+
+    assert_eq!(explanation.related_names(), vec!["keys", "to_entries"]);
+    assert_eq!(
+        explanation.manual_url().as_deref(),
+        Some("https://jqlang.org/manual/#keys-keys_unsorted")
+    );
+}
+
+#[test]
+fn test_no_manual_url_without_related_functions() {
+    let explanation = explain("syntax error, unexpected '}'").unwrap();
+
+    assert_eq!(explanation.manual_url(), None);
+}
+
+#[test]
+fn test_every_pattern_is_reachable() {
+    for (index, (pattern, _)) in EXPLANATIONS.iter().enumerate() {
+        let sample = pattern.replace('*', "x");
+        let first = EXPLANATIONS
+            .iter()
+            .position(|(earlier, _)| matches(earlier, &sample))
+            .unwrap();
+        assert!(
+            first == index || EXPLANATIONS[first].1 == EXPLANATIONS[index].1,
+            "{pattern} is shadowed by {}",
+            EXPLANATIONS[first].0
+        );
+    }
+}
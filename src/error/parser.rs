@@ -0,0 +1,240 @@
+//! jq Error Parser
+//!
+//! Structures jq's stderr output into a message and, where possible, the
+//! location of the offending region in the query. jq 1.8+ reports a column
+//! (and a caret line); older versions only report the line, in which case the
+//! region is inferred from the message for common cases (unexpected end of
+//! query, undefined functions and variables). Errors jq reports while
+//! compiling the query are told apart from those raised by the input while it
+//! runs, which may come after some output.
+
+use std::ops::Range;
+
+/// Noise lines jq writes to stderr that carry no information about the error
+const NOISE_PREFIXES: &[&str] = &["Failed to set $JQ_COLORS"];
+
+/// When jq failed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorKind {
+    /// Compiling the query failed, nothing ran
+    Syntax,
+    /// The query failed on the input (`null` indexed, wrong types), possibly
+    /// after printing some results
+    Runtime,
+    /// jq couldn't run or the error isn't one of jq's
+    Other,
+}
+
+impl ErrorKind {
+    pub fn title(self) -> &'static str {
+        match self {
+            ErrorKind::Syntax => "Syntax Error",
+            ErrorKind::Runtime => "Runtime Error",
+            ErrorKind::Other => "Error",
+        }
+    }
+}
+
+/// Parsed jq error
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParsedJqError {
+    pub kind: ErrorKind,
+    /// Error message without the `jq: error:` prefix and location suffix
+    pub message: String,
+    /// 1-based line in the query, if reported
+    pub line: Option<usize>,
+    /// 1-based column in the query line, if reported (jq 1.8+)
+    pub column: Option<usize>,
+    /// Character range of the offending region in the query, if it could be located
+    pub span: Option<Range<usize>>,
+    /// Query that produced the error (the span refers to this text)
+    pub query: String,
+}
+
+impl ParsedJqError {
+    /// Text for the error overlay: message plus location when it adds information
+    pub fn display_text(&self) -> String {
+        let location = match (self.line, self.column) {
+            (Some(line), Some(column)) => Some(format!("at line {}, column {}", line, column)),
+            (Some(line), None) if self.query.contains('\n') => Some(format!("at line {}", line)),
+            _ => None,
+        };
+
+        match location {
+            Some(location) => format!("{}\n{}", self.message, location),
+            None => self.message.clone(),
+        }
+    }
+}
+
+/// Parse jq stderr output for `query`
+pub fn parse(stderr: &str, query: &str) -> ParsedJqError {
+    let lines: Vec<&str> = stderr.lines().filter(|line| !is_noise(line)).collect();
+
+    let Some(header_idx) = lines.iter().position(|l| l.starts_with("jq: error")) else {
+        return ParsedJqError {
+            kind: ErrorKind::Other,
+            message: lines.join("\n").trim().to_string(),
+            line: None,
+            column: None,
+            span: None,
+            query: query.to_string(),
+        };
+    };
+
+    // Runtime errors name the input they happened at: `jq: error (at <stdin>:3)`
+    let kind = if lines[header_idx].starts_with("jq: error (at ") {
+        ErrorKind::Runtime
+    } else {
+        ErrorKind::Syntax
+    };
+    let header = strip_error_prefix(lines[header_idx]);
+    let (message, line, column) = split_location(header);
+    let message = message
+        .trim_end()
+        .trim_end_matches("(Unix shell quoting issues?)")
+        .trim_end()
+        .to_string();
+
+    let span = match (line, column) {
+        (Some(line), Some(column)) => {
+            let caret_len = lines
+                .get(header_idx + 2)
+                .map(|l| l.trim().chars().take_while(|&c| c == '^').count())
+                .filter(|&n| n > 0)
+                .unwrap_or(1);
+            span_from_position(query, line, column, caret_len)
+        }
+        _ => infer_span(&message, query),
+    };
+
+    ParsedJqError {
+        kind,
+        message,
+        line,
+        column,
+        span,
+        query: query.to_string(),
+    }
+}
+
+fn is_noise(line: &str) -> bool {
+    let trimmed = line.trim();
+    NOISE_PREFIXES.iter().any(|p| trimmed.starts_with(p))
+        || (trimmed.starts_with("jq: ")
+            && (trimmed.ends_with("compile error") || trimmed.ends_with("compile errors")))
+}
+
+/// Strip `jq: error: ` or `jq: error (at <stdin>:0): `
+fn strip_error_prefix(header: &str) -> &str {
+    let rest = header.trim_start_matches("jq: error");
+    if let Some(rest) = rest.strip_prefix(": ") {
+        return rest;
+    }
+    if rest.starts_with(" (")
+        && let Some(end) = rest.find("): ")
+    {
+        return &rest[end + 3..];
+    }
+    rest.trim_start_matches(':').trim_start()
+}
+
+/// Split `msg at <top-level>, line 1, column 4:` into message, line and column
+fn split_location(header: &str) -> (&str, Option<usize>, Option<usize>) {
+    let Some(at_idx) = header.rfind(" at <") else {
+        return (header, None, None);
+    };
+
+    let location = &header[at_idx..];
+    let Some(line_idx) = location.find(", line ") else {
+        return (header, None, None);
+    };
+
+    let numbers = location[line_idx + ", line ".len()..].trim_end_matches(':');
+    let mut parts = numbers.split(", column ");
+    let line = parts.next().and_then(|s| s.trim().parse().ok());
+    let column = parts.next().and_then(|s| s.trim().parse().ok());
+
+    if line.is_none() {
+        return (header, None, None);
+    }
+    (&header[..at_idx], line, column)
+}
+
+/// Convert a 1-based line/column (byte-based, as reported by jq) to a char range
+fn span_from_position(query: &str, line: usize, column: usize, len: usize) -> Option<Range<usize>> {
+    let mut offset = 0;
+    for (idx, text) in query.split('\n').enumerate() {
+        if idx + 1 == line {
+            let col_chars = text
+                .char_indices()
+                .take_while(|(byte, _)| *byte < column.saturating_sub(1))
+                .count();
+            let line_chars = text.chars().count();
+            let start = offset + col_chars.min(line_chars.saturating_sub(1));
+            let end = (start + len).min(offset + line_chars.max(1));
+            return (start < end).then_some(start..end);
+        }
+        offset += text.chars().count() + 1;
+    }
+    None
+}
+
+/// Infer the offending region from the message when jq gives no column
+fn infer_span(message: &str, query: &str) -> Option<Range<usize>> {
+    if message.contains("unexpected $end") || message.contains("unexpected end of file") {
+        return last_token_span(query);
+    }
+
+    let name = message.strip_suffix(" is not defined")?;
+    if let Some(var) = name.strip_prefix('$') {
+        return find_identifier(query, var, Some('$'));
+    }
+    let function = name.split('/').next()?;
+    find_identifier(query, function, None)
+}
+
+/// Span of the last non-whitespace character
+fn last_token_span(query: &str) -> Option<Range<usize>> {
+    let chars: Vec<char> = query.chars().collect();
+    let last = chars.iter().rposition(|c| !c.is_whitespace())?;
+    Some(last..last + 1)
+}
+
+/// Find a standalone identifier (optionally with a required sigil before it)
+fn find_identifier(query: &str, name: &str, sigil: Option<char>) -> Option<Range<usize>> {
+    if name.is_empty() {
+        return None;
+    }
+
+    let chars: Vec<char> = query.chars().collect();
+    let target: Vec<char> = name.chars().collect();
+    let is_ident = |c: char| c.is_alphanumeric() || c == '_';
+
+    for start in 0..chars.len() {
+        if chars[start..].len() < target.len() || chars[start..start + target.len()] != target[..] {
+            continue;
+        }
+        let before = start.checked_sub(1).map(|i| chars[i]);
+        let after = chars.get(start + target.len()).copied();
+        if after.is_some_and(is_ident) {
+            continue;
+        }
+
+        match sigil {
+            Some(s) if before == Some(s) => return Some(start - 1..start + target.len()),
+            Some(_) => continue,
+            None => {
+                if before.is_some_and(|c| is_ident(c) || c == '.' || c == '$') {
+                    continue;
+                }
+                return Some(start..start + target.len());
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+#[path = "parser_tests.rs"]
+mod parser_tests;
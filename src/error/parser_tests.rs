@@ -0,0 +1,159 @@
+//! Tests for error/parser
+
+use super::*;
+
+#[test]
+fn test_parse_runtime_error() {
+    let stderr = "jq: error (at <stdin>:0): Cannot index number with string \"foo\"\n";
+    let parsed = parse(stderr, ".foo");
+
+    assert_eq!(parsed.kind, ErrorKind::Runtime);
+    assert_eq!(parsed.message, "Cannot index number with string \"foo\"");
+    assert_eq!(parsed.line, None);
+    assert_eq!(parsed.column, None);
+    assert_eq!(parsed.span, None);
+}
+
+#[test]
+fn test_parse_drops_jq_colors_warning() {
+    let stderr = "Failed to set $JQ_COLORS\njq: error (at <stdin>:1): Cannot iterate over null\n";
+    let parsed = parse(stderr, ".[]");
+
+    assert_eq!(parsed.message, "Cannot iterate over null");
+}
+
+#[test]
+fn test_parse_syntax_error_without_column() {
+    let stderr = "jq: error: syntax error, unexpected $end (Unix shell quoting issues?) at <top-level>, line 1:\n.a[\njq: 1 compile error\n";
+    let parsed = parse(stderr, ".a[");
+
+    assert_eq!(parsed.message, "syntax error, unexpected $end");
+    assert_eq!(parsed.line, Some(1));
+    assert_eq!(parsed.column, None);
+    // Unexpected end points at the last character
+    assert_eq!(parsed.span, Some(2..3));
+}
+
+#[test]
+fn test_parse_unexpected_end_of_file_ignores_trailing_whitespace() {
+    let stderr = "jq: error: syntax error, unexpected end of file (Unix shell quoting issues?) at <top-level>, line 1:\n.a | (1 +  \njq: 1 compile error\n";
+    let parsed = parse(stderr, ".a | (1 +  ");
+
+    assert_eq!(parsed.span, Some(8..9));
+}
+
+#[test]
+fn test_parse_syntax_error_with_column_and_carets() {
+    let stderr = "jq: error: $foo is not defined at <top-level>, line 1, column 5:\n    1 + $foo + 2\n        ^^^^\njq: 1 compile error\n";
+    let parsed = parse(stderr, "1 + $foo + 2");
+
+    assert_eq!(parsed.message, "$foo is not defined");
+    assert_eq!(parsed.line, Some(1));
+    assert_eq!(parsed.column, Some(5));
+    assert_eq!(parsed.span, Some(4..8));
+}
+
+#[test]
+fn test_parse_column_without_caret_line() {
+    let stderr = "jq: error: syntax error, unexpected ']' at <top-level>, line 1, column 4:\n";
+    let parsed = parse(stderr, ".a[]]");
+
+    assert_eq!(parsed.span, Some(3..4));
+}
+
+#[test]
+fn test_parse_column_on_second_line() {
+    let stderr = "jq: error: syntax error, unexpected ')' at <top-level>, line 2, column 3:\n";
+    let parsed = parse(stderr, ".a |\n.b)");
+
+    assert_eq!(parsed.span, Some(7..8));
+}
+
+#[test]
+fn test_parse_undefined_function_infers_span() {
+    let stderr =
+        "jq: error: foo/0 is not defined at <top-level>, line 1:\n.a | foo\njq: 1 compile error\n";
+    let parsed = parse(stderr, ".a | foo");
+
+    assert_eq!(parsed.message, "foo/0 is not defined");
+    assert_eq!(parsed.span, Some(5..8));
+}
+
+#[test]
+fn test_undefined_function_skips_field_access() {
+    let stderr = "jq: error: foo/0 is not defined at <top-level>, line 1:\n";
+    let parsed = parse(stderr, ".foo | foo");
+
+    assert_eq!(parsed.span, Some(7..10));
+}
+
+#[test]
+fn test_undefined_variable_infers_span() {
+    let stderr = "jq: error: $x is not defined at <top-level>, line 1:\n";
+    let parsed = parse(stderr, ".x | $xs | $x");
+
+    assert_eq!(parsed.span, Some(11..13));
+}
+
+#[test]
+fn test_unknown_syntax_error_has_no_span() {
+    let stderr = "jq: error: syntax error, unexpected INVALID_CHARACTER, expecting $end (Unix shell quoting issues?) at <top-level>, line 1:\n.a )\njq: 1 compile error\n";
+    let parsed = parse(stderr, ".a )");
+
+    assert_eq!(
+        parsed.message,
+        "syntax error, unexpected INVALID_CHARACTER, expecting $end"
+    );
+    assert_eq!(parsed.span, None);
+}
+
+#[test]
+fn test_parse_non_jq_error_passes_through() {
+    let parsed = parse("Query worker disconnected", ".a");
+
+    assert_eq!(parsed.message, "Query worker disconnected");
+    assert_eq!(parsed.span, None);
+}
+
+#[test]
+fn test_display_text_includes_column_when_known() {
+    let stderr = "jq: error: syntax error, unexpected ']' at <top-level>, line 1, column 4:\n";
+    let parsed = parse(stderr, ".a[]]");
+
+    assert_eq!(
+        parsed.display_text(),
+        "syntax error, unexpected ']'\nat line 1, column 4"
+    );
+}
+
+#[test]
+fn test_display_text_omits_line_for_single_line_query() {
+    let stderr = "jq: error: foo/0 is not defined at <top-level>, line 1:\n";
+    let parsed = parse(stderr, "foo");
+
+    assert_eq!(parsed.display_text(), "foo/0 is not defined");
+}
+
+#[test]
+fn test_display_text_includes_line_for_multi_line_query() {
+    let stderr = "jq: error: foo/0 is not defined at <top-level>, line 2:\n";
+    let parsed = parse(stderr, ".a |\nfoo");
+
+    assert_eq!(parsed.display_text(), "foo/0 is not defined\nat line 2");
+}
+
+#[test]
+fn test_compile_errors_are_syntax_errors() {
+    let stderr =
+        "jq: error: foo/0 is not defined at <top-level>, line 1:\nfoo\njq: 1 compile error\n";
+
+    assert_eq!(parse(stderr, "foo").kind, ErrorKind::Syntax);
+}
+
+#[test]
+fn test_non_jq_errors_are_other() {
+    assert_eq!(
+        parse("Failed to spawn jq: not found", ".").kind,
+        ErrorKind::Other
+    );
+}
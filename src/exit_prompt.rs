@@ -0,0 +1,5 @@
+pub mod exit_menu_events;
+pub mod exit_menu_render;
+pub mod exit_menu_state;
+pub mod exit_prompt_events;
+pub mod exit_prompt_render;
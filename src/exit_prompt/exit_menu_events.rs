@@ -0,0 +1,100 @@
+use std::path::PathBuf;
+
+use ratatui::crossterm::event::{KeyCode, KeyEvent};
+use tui_textarea::Input;
+
+use super::exit_menu_state::ExitAction;
+use crate::app::{App, OutputMode};
+
+/// Open the exit menu on the action used last time
+pub fn open_exit_menu(app: &mut App) {
+    crate::editor::editor_events::run_pending_query(app);
+    app.exit_menu.open();
+    app.autocomplete.hide();
+    app.history.close();
+}
+
+pub fn handle_exit_menu_key(app: &mut App, key: KeyEvent) {
+    if app.exit_menu.is_entering_path() {
+        handle_path_key(app, key);
+        return;
+    }
+
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('q') => app.exit_menu.close(),
+        KeyCode::Char('j') | KeyCode::Down => app.exit_menu.select_next(),
+        KeyCode::Char('k') | KeyCode::Up => app.exit_menu.select_previous(),
+        KeyCode::Char(c @ '1'..='9') => {
+            let index = c as usize - '1' as usize;
+            if let Some(action) = ExitAction::ALL.get(index) {
+                app.exit_menu.select(*action);
+                run_action(app, *action);
+            }
+        }
+        KeyCode::Enter => run_action(app, app.exit_menu.selected()),
+        _ => {}
+    }
+}
+
+fn handle_path_key(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Esc => app.exit_menu.cancel_path_entry(),
+        KeyCode::Enter => {
+            let path = app.exit_menu.path_text().trim().to_string();
+            if path.is_empty() {
+                app.notification.show_warning("Enter a file name");
+                return;
+            }
+            exit_with(
+                app,
+                ExitAction::File,
+                Some(OutputMode::File(PathBuf::from(path))),
+            );
+        }
+        _ => {
+            if let Some(textarea) = app.exit_menu.path_textarea_mut() {
+                textarea.input(Input::from(key));
+            }
+        }
+    }
+}
+
+fn run_action(app: &mut App, action: ExitAction) {
+    match action {
+        ExitAction::Results => exit_with(app, action, Some(OutputMode::Results)),
+        ExitAction::Query => exit_with(app, action, Some(OutputMode::Query)),
+        ExitAction::Both => exit_with(app, action, Some(OutputMode::Both)),
+        ExitAction::File => app.exit_menu.start_path_entry(),
+        ExitAction::InPlace => crate::in_place::open_in_place_preview(app),
+        ExitAction::Copy => {
+            if !crate::editor::editor_events::settle_query(app) {
+                return;
+            }
+            // Copied before quitting: there is no result to copy afterwards
+            if crate::clipboard::clipboard_events::copy_result(app, app.clipboard_backend) {
+                exit_with(app, action, None);
+            } else {
+                app.notification.show_warning("No result to copy");
+            }
+        }
+    }
+}
+
+/// Quit with `output_mode`, remembering `action` for next time
+fn exit_with(app: &mut App, action: ExitAction, output_mode: Option<OutputMode>) {
+    if let Some(query) = &app.query
+        && query.result.is_ok()
+        && !app.query().is_empty()
+    {
+        let query_str = app.query().to_string();
+        app.history.add_entry(&query_str);
+    }
+    app.exit_menu.close();
+    app.exit_menu.chosen = Some(action);
+    app.output_mode = output_mode;
+    app.should_quit = true;
+}
+
+#[cfg(test)]
+#[path = "exit_menu_events_tests.rs"]
+mod exit_menu_events_tests;
@@ -0,0 +1,173 @@
+//! Tests for exit_prompt/exit_menu_events
+
+use std::path::PathBuf;
+
+use crate::app::{App, OutputMode};
+use crate::config::ClipboardBackend;
+use crate::editor::EditorMode;
+use crate::exit_prompt::exit_menu_state::ExitAction;
+use crate::test_utils::test_helpers::{app_with_query, key};
+use ratatui::crossterm::event::KeyCode;
+
+fn menu_app(query: &str) -> App {
+    let mut app = app_with_query(query);
+    app.exit_menu.enabled = true;
+    app
+}
+
+fn type_text(app: &mut App, text: &str) {
+    for c in text.chars() {
+        app.handle_key_event(key(KeyCode::Char(c)));
+    }
+}
+
+#[test]
+fn test_enter_quits_with_results_when_menu_disabled() {
+    let mut app = app_with_query(".name");
+
+    app.handle_key_event(key(KeyCode::Enter));
+
+    assert!(!app.exit_menu.is_visible());
+    assert!(app.should_quit);
+    assert_eq!(app.output_mode, Some(OutputMode::Results));
+}
+
+#[test]
+fn test_enter_opens_menu_when_enabled() {
+    let mut app = menu_app(".name");
+
+    app.handle_key_event(key(KeyCode::Enter));
+
+    assert!(app.exit_menu.is_visible());
+    assert!(!app.should_quit);
+}
+
+#[test]
+fn test_enter_runs_selected_action() {
+    let mut app = menu_app(".name");
+    app.handle_key_event(key(KeyCode::Enter));
+
+    app.handle_key_event(key(KeyCode::Down));
+    app.handle_key_event(key(KeyCode::Down));
+    app.handle_key_event(key(KeyCode::Enter));
+
+    assert!(app.should_quit);
+    assert_eq!(app.output_mode, Some(OutputMode::Both));
+    assert_eq!(app.exit_menu.chosen, Some(ExitAction::Both));
+    assert!(!app.exit_menu.is_visible());
+}
+
+#[test]
+fn test_number_picks_action_directly() {
+    let mut app = menu_app(".name");
+    app.handle_key_event(key(KeyCode::Enter));
+
+    app.handle_key_event(key(KeyCode::Char('2')));
+
+    assert!(app.should_quit);
+    assert_eq!(app.output_mode, Some(OutputMode::Query));
+    assert_eq!(app.exit_menu.chosen, Some(ExitAction::Query));
+}
+
+#[test]
+fn test_menu_opens_on_last_action() {
+    let mut app = menu_app(".name");
+    app.exit_menu.select(ExitAction::Query);
+    app.handle_key_event(key(KeyCode::Enter));
+
+    app.handle_key_event(key(KeyCode::Enter));
+
+    assert_eq!(app.output_mode, Some(OutputMode::Query));
+}
+
+#[test]
+fn test_esc_closes_menu_without_quitting() {
+    let mut app = menu_app(".name");
+    app.handle_key_event(key(KeyCode::Enter));
+
+    app.handle_key_event(key(KeyCode::Esc));
+
+    assert!(!app.exit_menu.is_visible());
+    assert!(!app.should_quit);
+    assert_eq!(app.exit_menu.chosen, None);
+}
+
+#[test]
+fn test_write_to_file_asks_for_a_name() {
+    let mut app = menu_app(".name");
+    app.handle_key_event(key(KeyCode::Enter));
+    app.handle_key_event(key(KeyCode::Char('4')));
+    assert!(app.exit_menu.is_entering_path());
+    assert!(!app.should_quit);
+
+    // Keys go to the file name, not the menu
+    type_text(&mut app, "q1.json");
+    app.handle_key_event(key(KeyCode::Enter));
+
+    assert!(app.should_quit);
+    assert_eq!(
+        app.output_mode,
+        Some(OutputMode::File(PathBuf::from("q1.json")))
+    );
+    assert_eq!(app.exit_menu.chosen, Some(ExitAction::File));
+}
+
+#[test]
+fn test_write_to_file_needs_a_name() {
+    let mut app = menu_app(".name");
+    app.handle_key_event(key(KeyCode::Enter));
+    app.handle_key_event(key(KeyCode::Char('4')));
+
+    app.handle_key_event(key(KeyCode::Enter));
+    assert!(!app.should_quit);
+
+    app.handle_key_event(key(KeyCode::Esc));
+    assert!(!app.exit_menu.is_entering_path());
+    assert!(app.exit_menu.is_visible());
+}
+
+#[test]
+fn test_exit_adds_query_to_history() {
+    let mut app = menu_app(".name");
+    let initial_count = app.history.total_count();
+    app.handle_key_event(key(KeyCode::Enter));
+
+    app.handle_key_event(key(KeyCode::Char('1')));
+
+    assert_eq!(app.history.total_count(), initial_count + 1);
+}
+
+#[test]
+fn test_copy_uses_the_result_of_the_query_as_typed() {
+    let mut app = menu_app(".name");
+    app.clipboard_backend = ClipboardBackend::Osc52;
+    app.input.editor_mode = EditorMode::Insert;
+    type_text(&mut app, " | length");
+    assert!(app.debouncer.has_pending());
+    app.autocomplete.hide();
+    app.handle_key_event(key(KeyCode::Enter));
+
+    app.handle_key_event(key(KeyCode::Char('5')));
+
+    let query = app.query.as_ref().unwrap();
+    assert_eq!(query.result.as_deref(), Ok("4\n"));
+    assert!(app.should_quit);
+    assert_eq!(app.exit_menu.chosen, Some(ExitAction::Copy));
+}
+
+#[test]
+fn test_copy_refuses_while_the_query_runs() {
+    let mut app = menu_app(".name");
+    app.clipboard_backend = ClipboardBackend::Osc52;
+    app.handle_key_event(key(KeyCode::Enter));
+    app.query.as_mut().unwrap().execute_async(".age");
+
+    app.handle_key_event(key(KeyCode::Char('5')));
+
+    assert!(!app.should_quit);
+    assert!(app.exit_menu.is_visible());
+    assert_eq!(
+        app.notification.current_message(),
+        Some("The query is still running")
+    );
+}
@@ -0,0 +1,112 @@
+use ratatui::{
+    Frame,
+    layout::{Alignment, Rect},
+    style::Style,
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Padding, Paragraph},
+};
+
+use super::exit_menu_state::ExitAction;
+use crate::app::App;
+use crate::theme;
+use crate::widgets::popup;
+
+const MENU_WIDTH: u16 = 44;
+
+/// Render the exit menu in the middle of the screen
+pub fn render_popup(app: &App, frame: &mut Frame) -> Option<Rect> {
+    let frame_area = frame.area();
+    let width = MENU_WIDTH.min(frame_area.width.saturating_sub(2));
+    let entering_path = app.exit_menu.is_entering_path();
+    // Options plus, while typing a file name, a blank line and the name
+    let content_height = ExitAction::ALL.len() as u16 + if entering_path { 2 } else { 0 };
+    let height = (content_height + 2).min(frame_area.height);
+    if width < 20 || height < content_height + 2 {
+        return None;
+    }
+
+    let selected = app.exit_menu.selected();
+    let lines: Vec<Line> = ExitAction::ALL
+        .iter()
+        .enumerate()
+        .map(|(i, action)| {
+            let (indicator, style) = if *action == selected {
+                (
+                    Span::styled(
+                        "▸ ",
+                        Style::default()
+                            .fg(theme::exit_menu::ITEM_SELECTED_INDICATOR)
+                            .bg(theme::exit_menu::ITEM_SELECTED_BG),
+                    ),
+                    Style::default()
+                        .fg(theme::exit_menu::ITEM_SELECTED_FG)
+                        .bg(theme::exit_menu::ITEM_SELECTED_BG),
+                )
+            } else {
+                (Span::raw("  "), Style::default().fg(theme::exit_menu::TEXT))
+            };
+            Line::from(vec![
+                indicator,
+                Span::styled(format!("{} ", i + 1), style.fg(theme::exit_menu::KEY)),
+                Span::styled(action.label(), style),
+            ])
+            .style(style)
+        })
+        .collect();
+
+    let area = popup::centered_popup(frame_area, width, height);
+    popup::clear_area(frame, area);
+
+    let hints: &[(&str, &str)] = if entering_path {
+        &[("Enter", "Write"), ("Esc", "Back")]
+    } else {
+        &[("Enter", "Select"), ("Esc", "Cancel")]
+    };
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(" Exit ")
+        .title_bottom(
+            theme::border_hints::build_hints(hints, theme::exit_menu::HINTS)
+                .alignment(Alignment::Center),
+        )
+        .border_style(Style::default().fg(theme::exit_menu::BORDER))
+        .style(Style::default().bg(theme::exit_menu::BACKGROUND))
+        .padding(Padding::horizontal(1));
+    let inner = block.inner(area);
+    frame.render_widget(Paragraph::new(lines).block(block), area);
+
+    if let Some(textarea) = app.exit_menu.path_textarea() {
+        let prompt = "File: ";
+        let row = Rect {
+            y: inner.y + ExitAction::ALL.len() as u16 + 1,
+            height: 1,
+            ..inner
+        };
+        let prompt_width = (prompt.len() as u16).min(row.width);
+        frame.render_widget(
+            Paragraph::new(Span::styled(
+                prompt,
+                Style::default().fg(theme::exit_menu::PROMPT),
+            )),
+            Rect {
+                width: prompt_width,
+                ..row
+            },
+        );
+        frame.render_widget(
+            textarea,
+            Rect {
+                x: row.x + prompt_width,
+                width: row.width - prompt_width,
+                ..row
+            },
+        );
+    }
+
+    Some(area)
+}
+
+#[cfg(test)]
+#[path = "exit_menu_render_tests.rs"]
+mod exit_menu_render_tests;
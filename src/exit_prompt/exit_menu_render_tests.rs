@@ -0,0 +1,53 @@
+//! Tests for exit_prompt/exit_menu_render
+
+use super::*;
+use crate::test_utils::test_helpers::app_with_query;
+use insta::assert_snapshot;
+use ratatui::Terminal;
+use ratatui::backend::TestBackend;
+
+fn render_to_string(app: &App, width: u16, height: u16) -> String {
+    let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+    terminal
+        .draw(|f| {
+            render_popup(app, f);
+        })
+        .unwrap();
+    terminal.backend().to_string()
+}
+
+#[test]
+fn snapshot_exit_menu() {
+    let mut app = app_with_query(".name");
+    app.exit_menu.open();
+    app.exit_menu.select(ExitAction::Both);
+
+    let output = render_to_string(&app, 60, 12);
+    assert_snapshot!(output);
+}
+
+#[test]
+fn snapshot_exit_menu_file_name() {
+    let mut app = app_with_query(".name");
+    app.exit_menu.open();
+    app.exit_menu.select(ExitAction::File);
+    app.exit_menu.start_path_entry();
+    app.exit_menu
+        .path_textarea_mut()
+        .unwrap()
+        .insert_str("names.json");
+
+    let output = render_to_string(&app, 60, 12);
+    assert_snapshot!(output);
+}
+
+#[test]
+fn test_menu_skipped_when_area_too_small() {
+    let mut app = app_with_query(".name");
+    app.exit_menu.open();
+
+    let mut terminal = Terminal::new(TestBackend::new(60, 5)).unwrap();
+    terminal
+        .draw(|f| assert!(render_popup(&app, f).is_none()))
+        .unwrap();
+}
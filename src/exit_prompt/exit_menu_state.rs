@@ -0,0 +1,190 @@
+//! Exit menu
+//!
+//! With `menu` set in the `[exit]` config section, Enter asks how to leave
+//! instead of always printing the results. The action picked is remembered
+//! in jiq's data directory and preselected next time.
+
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use ratatui::style::Style;
+use tui_textarea::TextArea;
+
+use crate::theme;
+
+const LAST_ACTION_FILE: &str = "exit_action";
+
+/// Way of leaving jiq offered by the exit menu
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ExitAction {
+    #[default]
+    Results,
+    Query,
+    Both,
+    File,
+    Copy,
+    InPlace,
+}
+
+impl ExitAction {
+    pub const ALL: [ExitAction; 6] = [
+        ExitAction::Results,
+        ExitAction::Query,
+        ExitAction::Both,
+        ExitAction::File,
+        ExitAction::Copy,
+        ExitAction::InPlace,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            ExitAction::Results => "Print the results",
+            ExitAction::Query => "Print the query",
+            ExitAction::Both => "Print the query and the results",
+            ExitAction::File => "Write the results to a file",
+            ExitAction::Copy => "Copy the results to the clipboard",
+            ExitAction::InPlace => "Write the results back to the input",
+        }
+    }
+
+    /// Name stored in the last action file
+    pub fn name(self) -> &'static str {
+        match self {
+            ExitAction::Results => "results",
+            ExitAction::Query => "query",
+            ExitAction::Both => "both",
+            ExitAction::File => "file",
+            ExitAction::Copy => "copy",
+            ExitAction::InPlace => "in-place",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|action| action.name() == name)
+    }
+}
+
+fn create_textarea() -> TextArea<'static> {
+    let mut textarea = TextArea::default();
+    textarea.set_cursor_line_style(Style::default());
+    textarea.set_cursor_style(theme::palette::CURSOR);
+    textarea.set_style(
+        Style::default()
+            .fg(theme::exit_menu::TEXT)
+            .bg(theme::exit_menu::BACKGROUND),
+    );
+    textarea
+}
+
+/// Exit menu opened by Enter when enabled
+pub struct ExitMenuState {
+    /// Whether Enter opens the menu (`[exit]` config)
+    pub enabled: bool,
+    visible: bool,
+    selected: usize,
+    /// File name being typed for [`ExitAction::File`]
+    path: Option<TextArea<'static>>,
+    /// Action picked to leave, saved as the next default on exit
+    pub chosen: Option<ExitAction>,
+}
+
+impl ExitMenuState {
+    pub fn new(enabled: bool) -> Self {
+        Self {
+            enabled,
+            visible: false,
+            selected: 0,
+            path: None,
+            chosen: None,
+        }
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    pub fn open(&mut self) {
+        self.visible = true;
+        self.path = None;
+    }
+
+    pub fn close(&mut self) {
+        self.visible = false;
+        self.path = None;
+    }
+
+    /// Move the selection to `action`, e.g. the one used last time
+    pub fn select(&mut self, action: ExitAction) {
+        self.selected = ExitAction::ALL
+            .iter()
+            .position(|a| *a == action)
+            .unwrap_or(0);
+    }
+
+    pub fn selected(&self) -> ExitAction {
+        ExitAction::ALL[self.selected]
+    }
+
+    pub fn select_next(&mut self) {
+        self.selected = (self.selected + 1) % ExitAction::ALL.len();
+    }
+
+    pub fn select_previous(&mut self) {
+        self.selected = (self.selected + ExitAction::ALL.len() - 1) % ExitAction::ALL.len();
+    }
+
+    pub fn is_entering_path(&self) -> bool {
+        self.path.is_some()
+    }
+
+    pub fn start_path_entry(&mut self) {
+        self.path = Some(create_textarea());
+    }
+
+    pub fn cancel_path_entry(&mut self) {
+        self.path = None;
+    }
+
+    pub fn path_textarea(&self) -> Option<&TextArea<'static>> {
+        self.path.as_ref()
+    }
+
+    pub fn path_textarea_mut(&mut self) -> Option<&mut TextArea<'static>> {
+        self.path.as_mut()
+    }
+
+    pub fn path_text(&self) -> &str {
+        self.path
+            .as_ref()
+            .and_then(|textarea| textarea.lines().first())
+            .map_or("", |line| line.as_str())
+    }
+}
+
+fn last_action_path() -> Option<PathBuf> {
+    crate::paths::state_dir().map(|dir| dir.join(LAST_ACTION_FILE))
+}
+
+/// Action picked in the exit menu last time, if any
+pub fn load_last_action() -> Option<ExitAction> {
+    let content = fs::read_to_string(last_action_path()?).ok()?;
+    ExitAction::from_name(content.trim())
+}
+
+pub fn save_last_action(action: ExitAction) -> io::Result<()> {
+    let Some(path) = last_action_path() else {
+        return Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            "Could not determine data directory",
+        ));
+    };
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, action.name())
+}
+
+#[cfg(test)]
+#[path = "exit_menu_state_tests.rs"]
+mod exit_menu_state_tests;
@@ -0,0 +1,45 @@
+//! Tests for exit_prompt/exit_menu_state
+
+use super::*;
+
+#[test]
+fn test_action_names_round_trip() {
+    for action in ExitAction::ALL {
+        assert_eq!(ExitAction::from_name(action.name()), Some(action));
+    }
+    assert_eq!(ExitAction::from_name("bogus"), None);
+}
+
+#[test]
+fn test_selection_wraps_around() {
+    let mut menu = ExitMenuState::new(true);
+    assert_eq!(menu.selected(), ExitAction::Results);
+
+    menu.select_previous();
+    assert_eq!(menu.selected(), ExitAction::InPlace);
+
+    menu.select_next();
+    menu.select_next();
+    assert_eq!(menu.selected(), ExitAction::Query);
+}
+
+#[test]
+fn test_select_last_action() {
+    let mut menu = ExitMenuState::new(true);
+    menu.select(ExitAction::File);
+    assert_eq!(menu.selected(), ExitAction::File);
+}
+
+#[test]
+fn test_closing_discards_path_entry() {
+    let mut menu = ExitMenuState::new(true);
+    menu.open();
+    menu.start_path_entry();
+    menu.path_textarea_mut().unwrap().insert_str("out.json");
+    assert_eq!(menu.path_text(), "out.json");
+
+    menu.close();
+    menu.open();
+    assert!(!menu.is_entering_path());
+    assert_eq!(menu.path_text(), "");
+}
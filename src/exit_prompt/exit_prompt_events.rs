@@ -0,0 +1,84 @@
+//! Confirmation before quitting with an unsaved query
+//!
+//! With `confirm` set in the `[exit]` config section, `q` and `Ctrl+C` ask
+//! what to do with the query once the session has been open for a while,
+//! instead of quitting straight away.
+
+use ratatui::crossterm::event::{KeyCode, KeyEvent};
+
+use crate::app::{App, OutputMode};
+
+/// Whether quitting now would throw away a query worth keeping
+///
+/// Queries that were saved as a session or copied since they last changed
+/// don't need confirming, nor do empty and identity queries.
+pub fn should_confirm_exit(app: &App) -> bool {
+    let Some(confirm_after) = app.confirm_exit_after else {
+        return false;
+    };
+    let query = app.query().trim();
+    app.started_at.elapsed() >= confirm_after
+        && !query.is_empty()
+        && query != "."
+        && app.saved_query.as_deref() != Some(app.query())
+}
+
+/// Quit, or ask first when the query would be lost
+pub fn request_quit(app: &mut App) {
+    if should_confirm_exit(app) {
+        app.exit_prompt_visible = true;
+        app.autocomplete.hide();
+        app.history.close();
+    } else {
+        app.should_quit = true;
+    }
+}
+
+pub fn handle_exit_prompt_key(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Esc => app.exit_prompt_visible = false,
+        KeyCode::Enter | KeyCode::Char('o') => {
+            app.exit_prompt_visible = false;
+            if app.debouncer.has_pending() {
+                crate::editor::editor_events::execute_query(app);
+                app.debouncer.mark_executed();
+            }
+            if let Some(query) = &app.query
+                && query.result.is_ok()
+            {
+                let query_str = app.query().to_string();
+                app.history.add_entry(&query_str);
+            }
+            app.output_mode = Some(OutputMode::Results);
+            app.should_quit = true;
+        }
+        KeyCode::Char('c') => {
+            if !crate::editor::editor_events::settle_query(app) {
+                return;
+            }
+            // Stay open: a system clipboard may not outlive the process
+            crate::clipboard::clipboard_events::copy_result(app, app.clipboard_backend);
+        }
+        KeyCode::Char('s') => {
+            app.exit_prompt_visible = false;
+            match app.session_name.clone() {
+                Some(name) => {
+                    crate::command_line::command_line_events::save_session(app, Some(name));
+                    if app.saved_query.as_deref() == Some(app.query()) {
+                        app.should_quit = true;
+                    }
+                }
+                None => app.command_line.open_with("session save "),
+            }
+        }
+        KeyCode::Char('d') | KeyCode::Char('q') => {
+            app.exit_prompt_visible = false;
+            app.should_quit = true;
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+#[path = "exit_prompt_events_tests.rs"]
+mod exit_prompt_events_tests;
@@ -0,0 +1,171 @@
+//! Tests for exit_prompt/exit_prompt_events
+
+use std::time::Duration;
+
+use crate::app::{App, Focus, OutputMode};
+use crate::config::ClipboardBackend;
+use crate::editor::EditorMode;
+use crate::test_utils::test_helpers::{app_with_query, key, key_with_mods};
+use ratatui::crossterm::event::{KeyCode, KeyModifiers};
+
+/// App with exit confirmation enabled and already due
+fn confirming_app(query: &str) -> App {
+    let mut app = app_with_query(query);
+    app.confirm_exit_after = Some(Duration::ZERO);
+    app.focus = Focus::ResultsPane;
+    app
+}
+
+#[test]
+fn test_quit_without_confirmation_by_default() {
+    let mut app = app_with_query(".name");
+    app.focus = Focus::ResultsPane;
+
+    app.handle_key_event(key(KeyCode::Char('q')));
+
+    assert!(app.should_quit);
+    assert!(!app.exit_prompt_visible);
+}
+
+#[test]
+fn test_q_asks_before_losing_query() {
+    let mut app = confirming_app(".name");
+
+    app.handle_key_event(key(KeyCode::Char('q')));
+
+    assert!(app.exit_prompt_visible);
+    assert!(!app.should_quit);
+}
+
+#[test]
+fn test_ctrl_c_asks_and_second_ctrl_c_quits() {
+    let mut app = confirming_app(".name");
+    let ctrl_c = key_with_mods(KeyCode::Char('c'), KeyModifiers::CONTROL);
+
+    app.handle_key_event(ctrl_c);
+    assert!(app.exit_prompt_visible);
+    assert!(!app.should_quit);
+
+    app.handle_key_event(ctrl_c);
+    assert!(app.should_quit);
+    assert_eq!(app.output_mode, None);
+}
+
+#[test]
+fn test_no_confirmation_for_trivial_or_saved_queries() {
+    for query in ["", "."] {
+        let mut app = confirming_app(query);
+        app.handle_key_event(key(KeyCode::Char('q')));
+        assert!(app.should_quit, "{query:?}");
+    }
+
+    let mut app = confirming_app(".name");
+    app.saved_query = Some(".name".to_string());
+    app.handle_key_event(key(KeyCode::Char('q')));
+    assert!(app.should_quit);
+}
+
+#[test]
+fn test_no_confirmation_early_in_session() {
+    let mut app = confirming_app(".name");
+    app.confirm_exit_after = Some(Duration::from_secs(3600));
+
+    app.handle_key_event(key(KeyCode::Char('q')));
+
+    assert!(app.should_quit);
+}
+
+#[test]
+fn test_esc_keeps_editing() {
+    let mut app = confirming_app(".name");
+    app.handle_key_event(key(KeyCode::Char('q')));
+
+    app.handle_key_event(key(KeyCode::Esc));
+
+    assert!(!app.exit_prompt_visible);
+    assert!(!app.should_quit);
+}
+
+#[test]
+fn test_enter_outputs_result() {
+    let mut app = confirming_app(".name");
+    app.handle_key_event(key(KeyCode::Char('q')));
+
+    app.handle_key_event(key(KeyCode::Enter));
+
+    assert!(app.should_quit);
+    assert_eq!(app.output_mode, Some(OutputMode::Results));
+}
+
+#[test]
+fn test_d_discards() {
+    let mut app = confirming_app(".name");
+    app.handle_key_event(key(KeyCode::Char('q')));
+
+    app.handle_key_event(key(KeyCode::Char('d')));
+
+    assert!(app.should_quit);
+    assert_eq!(app.output_mode, None);
+}
+
+#[test]
+fn test_s_without_session_asks_for_a_name() {
+    let mut app = confirming_app(".name");
+    app.handle_key_event(key(KeyCode::Char('q')));
+
+    app.handle_key_event(key(KeyCode::Char('s')));
+
+    assert!(!app.exit_prompt_visible);
+    assert!(!app.should_quit);
+    assert!(app.command_line.is_visible());
+    assert_eq!(app.command_line.text(), "session save ");
+}
+
+#[test]
+fn test_other_keys_are_ignored() {
+    let mut app = confirming_app(".name");
+    app.handle_key_event(key(KeyCode::Char('q')));
+
+    app.handle_key_event(key(KeyCode::Char('x')));
+
+    assert!(app.exit_prompt_visible);
+    assert_eq!(app.query(), ".name");
+}
+
+#[test]
+fn test_c_copies_the_result_of_the_query_as_typed() {
+    let mut app = confirming_app(".name");
+    app.clipboard_backend = ClipboardBackend::Osc52;
+    app.focus = Focus::InputField;
+    app.input.editor_mode = EditorMode::Insert;
+    for c in " | length".chars() {
+        app.handle_key_event(key(KeyCode::Char(c)));
+    }
+    assert!(app.debouncer.has_pending());
+    app.focus = Focus::ResultsPane;
+    app.handle_key_event(key(KeyCode::Char('q')));
+
+    app.handle_key_event(key(KeyCode::Char('c')));
+
+    let query = app.query.as_ref().unwrap();
+    assert_eq!(query.result.as_deref(), Ok("4\n"));
+    assert_eq!(app.notification.current_message(), Some("Copied result!"));
+    assert_eq!(app.saved_query.as_deref(), Some(".name | length"));
+}
+
+#[test]
+fn test_c_refuses_while_the_query_runs() {
+    let mut app = confirming_app(".name");
+    app.clipboard_backend = ClipboardBackend::Osc52;
+    app.handle_key_event(key(KeyCode::Char('q')));
+    app.query.as_mut().unwrap().execute_async(".age");
+
+    app.handle_key_event(key(KeyCode::Char('c')));
+
+    assert_eq!(
+        app.notification.current_message(),
+        Some("The query is still running")
+    );
+    assert_eq!(app.saved_query, None);
+    assert!(app.exit_prompt_visible);
+}
@@ -0,0 +1,71 @@
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::Style,
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Padding, Paragraph},
+};
+
+use crate::app::App;
+use crate::theme;
+use crate::widgets::popup;
+use crate::widgets::text_width::truncate_to_width;
+
+const DIALOG_WIDTH: u16 = 52;
+
+const OPTIONS: &[(&str, &str)] = &[
+    ("Enter", "Output the result and exit"),
+    ("c", "Copy the result to the clipboard"),
+    ("s", "Save as a session"),
+    ("d", "Discard and exit"),
+    ("Esc", "Keep editing"),
+];
+
+/// Render the exit confirmation in the middle of the screen
+pub fn render_popup(app: &App, frame: &mut Frame) -> Option<Rect> {
+    let frame_area = frame.area();
+    let width = DIALOG_WIDTH.min(frame_area.width.saturating_sub(2));
+    let text_width = width.saturating_sub(4) as usize;
+
+    let key_style = Style::default().fg(theme::exit_prompt::KEY);
+    let text_style = Style::default().fg(theme::exit_prompt::TEXT);
+    let mut lines = vec![
+        Line::from(Span::styled(
+            "Quit and lose this query?",
+            theme::exit_prompt::QUESTION,
+        )),
+        Line::from(Span::styled(
+            truncate_to_width(app.query(), text_width),
+            Style::default().fg(theme::exit_prompt::QUERY),
+        )),
+        Line::default(),
+    ];
+    lines.extend(OPTIONS.iter().map(|(key, action)| {
+        Line::from(vec![
+            Span::styled(format!("{:<7}", key), key_style),
+            Span::styled(*action, text_style),
+        ])
+    }));
+
+    let height = (lines.len() as u16 + 2).min(frame_area.height);
+    if width < 20 || height < 5 {
+        return None;
+    }
+
+    let area = popup::centered_popup(frame_area, width, height);
+    popup::clear_area(frame, area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(" Quit ")
+        .border_style(Style::default().fg(theme::exit_prompt::BORDER))
+        .style(Style::default().bg(theme::exit_prompt::BACKGROUND))
+        .padding(Padding::horizontal(1));
+    frame.render_widget(Paragraph::new(lines).block(block), area);
+    Some(area)
+}
+
+#[cfg(test)]
+#[path = "exit_prompt_render_tests.rs"]
+mod exit_prompt_render_tests;
@@ -0,0 +1,32 @@
+//! Tests for exit_prompt/exit_prompt_render
+
+use super::*;
+use crate::test_utils::test_helpers::app_with_query;
+use insta::assert_snapshot;
+use ratatui::Terminal;
+use ratatui::backend::TestBackend;
+
+fn render_to_string(app: &App, width: u16, height: u16) -> String {
+    let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+    terminal
+        .draw(|f| {
+            render_popup(app, f);
+        })
+        .unwrap();
+    terminal.backend().to_string()
+}
+
+#[test]
+fn snapshot_exit_prompt() {
+    let app = app_with_query(".services[] | select(.status == \"degraded\") | {name, region}");
+
+    let output = render_to_string(&app, 80, 16);
+    assert_snapshot!(output);
+}
+
+#[test]
+fn test_truncate_long_query() {
+    assert_eq!(truncate_to_width(".a | .b", 10), ".a | .b");
+    assert_eq!(truncate_to_width(".abcdefgh", 5), ".abc…");
+    assert_eq!(truncate_to_width(".名前 | .年齢", 8), ".名前 |…");
+}
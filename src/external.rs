@@ -0,0 +1,227 @@
+//! External editor and pager
+//!
+//! `e` in the results pane opens the result in `$VISUAL` or `$EDITOR`, and
+//! `|` opens it in `$PAGER`. The result is written to a temporary file, the
+//! event loop leaves the alternate screen while the program runs and comes
+//! back to the same state once it exits.
+//!
+//! `E` or `:edit` opens the input instead and loads it again once the editor
+//! exits, keeping the query. A JSON input file is edited in place; any other
+//! input is edited as a temporary JSON copy, for this session only.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use crate::app::App;
+use crate::input::input_format::InputFormat;
+use crate::input::loader::validate_json_or_jsonl;
+
+/// Program the result is opened in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExternalTool {
+    Editor,
+    Pager,
+}
+
+impl ExternalTool {
+    fn name(self) -> &'static str {
+        match self {
+            ExternalTool::Editor => "editor",
+            ExternalTool::Pager => "pager",
+        }
+    }
+
+    /// Environment variables naming the program, in order of preference
+    fn variables(self) -> &'static [&'static str] {
+        match self {
+            ExternalTool::Editor => &["VISUAL", "EDITOR"],
+            ExternalTool::Pager => &["PAGER"],
+        }
+    }
+
+    fn fallback(self) -> &'static str {
+        match (self, cfg!(windows)) {
+            (ExternalTool::Editor, false) => "vi",
+            (ExternalTool::Editor, true) => "notepad",
+            (ExternalTool::Pager, false) => "less",
+            (ExternalTool::Pager, true) => "more",
+        }
+    }
+
+    /// Program and arguments from the environment, e.g. `code --wait`
+    pub fn command_line(self, env: impl Fn(&str) -> Option<String>) -> Vec<String> {
+        self.variables()
+            .iter()
+            .filter_map(|name| env(name))
+            .find(|value| !value.trim().is_empty())
+            .unwrap_or_else(|| self.fallback().to_string())
+            .split_whitespace()
+            .map(str::to_string)
+            .collect()
+    }
+}
+
+/// What the file handed to the program holds
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExternalTarget {
+    /// A temporary copy of the result, removed afterwards
+    Result,
+    /// The input, loaded again afterwards; `temporary` when it is a copy
+    /// to remove rather than the input file itself
+    Input { temporary: bool },
+}
+
+/// Program to run with the terminal handed over to it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExternalRequest {
+    pub tool: ExternalTool,
+    pub command: Vec<String>,
+    /// File the program opens
+    pub path: PathBuf,
+    pub target: ExternalTarget,
+}
+
+/// Write the current result to a temporary file and ask the event loop to
+/// open it in `tool`
+pub fn open_result(app: &mut App, tool: ExternalTool) {
+    let Some(result) = app
+        .query
+        .as_ref()
+        .and_then(|q| q.last_successful_result_text())
+        .filter(|result| !result.is_empty())
+    else {
+        app.notification.show_warning("No result to open yet");
+        return;
+    };
+
+    let Some(path) = write_temp_file(app, "result", &result) else {
+        return;
+    };
+    app.pending_external = Some(ExternalRequest {
+        tool,
+        command: tool.command_line(|name| std::env::var(name).ok()),
+        path,
+        target: ExternalTarget::Result,
+    });
+}
+
+/// Ask the event loop to open the input in the editor, to load it again
+/// once the editor exits
+pub fn edit_input(app: &mut App) {
+    if app.follow {
+        app.notification
+            .show_warning("The input cannot be edited while following it");
+        return;
+    }
+    let Some(json_input) = app
+        .query
+        .as_ref()
+        .map(|q| q.executor.json_input().to_string())
+    else {
+        app.notification.show_warning("The input is still loading");
+        return;
+    };
+
+    let is_json = app
+        .input_info
+        .as_ref()
+        .is_some_and(|info| info.format == InputFormat::Json);
+    let (path, temporary) = match &app.input_path {
+        Some(path) if is_json => (path.clone(), false),
+        _ => match write_temp_file(app, "input", &json_input) {
+            Some(path) => (path, true),
+            None => return,
+        },
+    };
+    app.pending_external = Some(ExternalRequest {
+        tool: ExternalTool::Editor,
+        command: ExternalTool::Editor.command_line(|name| std::env::var(name).ok()),
+        path,
+        target: ExternalTarget::Input { temporary },
+    });
+}
+
+/// Write `contents` to `jiq-NAME-PID.json` in the temporary directory
+fn write_temp_file(app: &mut App, name: &str, contents: &str) -> Option<PathBuf> {
+    let path = std::env::temp_dir().join(format!("jiq-{}-{}.json", name, std::process::id()));
+    match fs::write(&path, format!("{}\n", contents.trim_end())) {
+        Ok(()) => Some(path),
+        Err(e) => {
+            app.notification
+                .show_error(&format!("Cannot write {}: {}", path.display(), e));
+            None
+        }
+    }
+}
+
+/// Run the program on the request's file and wait for it to exit
+pub fn run(request: &ExternalRequest) -> io::Result<()> {
+    let Some((program, args)) = request.command.split_first() else {
+        return Err(io::Error::new(io::ErrorKind::InvalidInput, "empty command"));
+    };
+    let status = Command::new(program)
+        .args(args)
+        .arg(&request.path)
+        .status()?;
+    if status.success() {
+        Ok(())
+    } else {
+        Err(io::Error::other(format!("exited with {}", status)))
+    }
+}
+
+/// Clean up after the program exited, loading an edited input again and
+/// reporting a failure to start the program
+pub fn finish(app: &mut App, request: ExternalRequest, outcome: io::Result<()>) {
+    app.mark_dirty();
+    if let Err(e) = outcome {
+        app.notification.show_error(&format!(
+            "Cannot run {} `{}`: {}",
+            request.tool.name(),
+            request.command.join(" "),
+            e
+        ));
+    } else if let ExternalTarget::Input { temporary } = request.target {
+        reload_input(app, &request.path, temporary);
+    }
+    if request.target != (ExternalTarget::Input { temporary: false }) {
+        let _ = fs::remove_file(&request.path);
+    }
+}
+
+/// Load the input edited in `path` in place of the current one
+fn reload_input(app: &mut App, path: &Path, temporary: bool) {
+    let edited = match fs::read_to_string(path) {
+        Ok(edited) => edited,
+        Err(e) => {
+            app.notification
+                .show_error(&format!("Cannot read {}: {}", path.display(), e));
+            return;
+        }
+    };
+    let unchanged = app
+        .query
+        .as_ref()
+        .is_some_and(|q| q.executor.json_input().trim_end() == edited.trim_end());
+    if unchanged {
+        return;
+    }
+    if let Err(e) = validate_json_or_jsonl(&edited) {
+        app.notification
+            .show_error(&format!("Edited input not loaded: {}", e));
+        return;
+    }
+
+    app.replace_input(edited);
+    app.notification.show(if temporary {
+        "Reloaded the edited input, for this session only"
+    } else {
+        "Reloaded the edited input"
+    });
+}
+
+#[cfg(test)]
+#[path = "external_tests.rs"]
+mod external_tests;
@@ -0,0 +1,179 @@
+//! Tests for external
+
+use super::*;
+use crate::test_utils::test_helpers::test_app;
+
+fn env<'a>(vars: &'a [(&'a str, &'a str)]) -> impl Fn(&str) -> Option<String> + 'a {
+    move |name| {
+        vars.iter()
+            .find(|(key, _)| *key == name)
+            .map(|(_, value)| value.to_string())
+    }
+}
+
+#[test]
+fn test_editor_prefers_visual_over_editor() {
+    let vars = [("VISUAL", "code --wait"), ("EDITOR", "nano")];
+    assert_eq!(
+        ExternalTool::Editor.command_line(env(&vars)),
+        vec!["code", "--wait"]
+    );
+    assert_eq!(
+        ExternalTool::Editor.command_line(env(&[("EDITOR", "nano")])),
+        vec!["nano"]
+    );
+}
+
+#[test]
+fn test_blank_variables_fall_back_to_default_program() {
+    let vars = [("VISUAL", " "), ("PAGER", "")];
+    assert_eq!(
+        ExternalTool::Editor.command_line(env(&vars)),
+        vec![ExternalTool::Editor.fallback()]
+    );
+    assert_eq!(
+        ExternalTool::Pager.command_line(env(&vars)),
+        vec![ExternalTool::Pager.fallback()]
+    );
+}
+
+#[test]
+fn test_open_result_writes_file_and_finish_removes_it() {
+    let mut app = test_app(r#"{"name": "api"}"#);
+
+    open_result(&mut app, ExternalTool::Pager);
+    let request = app.pending_external.take().expect("request");
+    assert_eq!(request.tool, ExternalTool::Pager);
+    let written = fs::read_to_string(&request.path).unwrap();
+    assert!(written.contains(r#""name": "api""#));
+
+    let path = request.path.clone();
+    finish(&mut app, request, Ok(()));
+    assert!(!path.exists());
+    assert!(app.notification.current().is_none());
+}
+
+#[test]
+fn test_finish_reports_failure() {
+    let mut app = test_app("{}");
+    let request = ExternalRequest {
+        tool: ExternalTool::Editor,
+        command: vec!["no-such-editor".to_string()],
+        path: std::env::temp_dir().join("jiq-external-test-missing.json"),
+        target: ExternalTarget::Result,
+    };
+
+    finish(
+        &mut app,
+        request,
+        Err(io::Error::new(io::ErrorKind::NotFound, "not found")),
+    );
+
+    let message = app.notification.current().unwrap().message.clone();
+    assert!(message.contains("no-such-editor"), "{}", message);
+}
+
+#[test]
+fn test_open_result_without_result_warns() {
+    let mut app = test_app("{}");
+    app.query = None;
+
+    open_result(&mut app, ExternalTool::Editor);
+
+    assert!(app.pending_external.is_none());
+    assert!(app.notification.current().is_some());
+}
+
+#[test]
+fn test_run_reports_failing_program() {
+    let request = ExternalRequest {
+        tool: ExternalTool::Pager,
+        command: vec!["jiq-no-such-program".to_string()],
+        path: PathBuf::from("unused.json"),
+        target: ExternalTarget::Result,
+    };
+    assert!(run(&request).is_err());
+}
+
+fn edited_input_request(contents: &str) -> (tempfile::TempDir, ExternalRequest) {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("input.json");
+    fs::write(&path, contents).unwrap();
+    let request = ExternalRequest {
+        tool: ExternalTool::Editor,
+        command: vec!["vi".to_string()],
+        path,
+        target: ExternalTarget::Input { temporary: false },
+    };
+    (dir, request)
+}
+
+#[test]
+fn test_edited_input_replaces_input_and_keeps_query() {
+    let mut app = test_app(r#"{"name": "api"}"#);
+    app.input.textarea.insert_str(".name");
+    let (_dir, request) = edited_input_request(r#"{"name": "web"}"#);
+    let path = request.path.clone();
+
+    finish(&mut app, request, Ok(()));
+
+    let query = app.query.as_ref().unwrap();
+    assert_eq!(query.executor.json_input(), r#"{"name": "web"}"#);
+    assert_eq!(app.query(), ".name");
+    assert_eq!(
+        app.notification.current_message(),
+        Some("Reloaded the edited input")
+    );
+    // The input file itself is kept
+    assert!(path.exists());
+}
+
+#[test]
+fn test_invalid_edited_input_is_not_loaded() {
+    let mut app = test_app(r#"{"name": "api"}"#);
+    let (_dir, request) = edited_input_request(r#"{"name": "#);
+
+    finish(&mut app, request, Ok(()));
+
+    let query = app.query.as_ref().unwrap();
+    assert_eq!(query.executor.json_input(), r#"{"name": "api"}"#);
+    let message = app.notification.current_message().unwrap();
+    assert!(
+        message.starts_with("Edited input not loaded"),
+        "{}",
+        message
+    );
+}
+
+#[test]
+fn test_unchanged_input_is_not_reloaded() {
+    let mut app = test_app(r#"{"name": "api"}"#);
+    let (_dir, request) = edited_input_request("{\"name\": \"api\"}\n");
+
+    finish(&mut app, request, Ok(()));
+
+    assert!(app.notification.current().is_none());
+}
+
+#[test]
+fn test_edit_input_from_stdin_uses_temporary_copy() {
+    let mut app = test_app(r#"{"name": "api"}"#);
+
+    edit_input(&mut app);
+
+    let request = app.pending_external.take().expect("request");
+    assert_eq!(request.target, ExternalTarget::Input { temporary: true });
+    assert!(fs::read_to_string(&request.path).unwrap().contains("api"));
+    finish(&mut app, request.clone(), Ok(()));
+    assert!(!request.path.exists());
+}
+
+#[test]
+fn test_edit_input_refused_while_following() {
+    let mut app = test_app("{}");
+    app.follow = true;
+
+    edit_input(&mut app);
+
+    assert!(app.pending_external.is_none());
+}
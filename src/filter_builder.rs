@@ -0,0 +1,15 @@
+//! Filter builder
+//!
+//! `Alt+S` opens a form composing a `select(...)` clause from a field, an
+//! operator and a value, inserted at the query cursor. Fields and values
+//! seen in the result are suggested as you type, so a filter can be built
+//! without knowing jq's syntax.
+
+mod filter_builder_events;
+mod filter_builder_query;
+mod filter_builder_render;
+mod filter_builder_state;
+
+pub use filter_builder_events::{handle_filter_builder_key, open_filter_builder};
+pub use filter_builder_render::render_filter_builder;
+pub use filter_builder_state::FilterBuilderState;
@@ -0,0 +1,71 @@
+use ratatui::crossterm::event::{KeyCode, KeyEvent};
+use tui_textarea::Input;
+
+use super::filter_builder_query::ObservedFields;
+use super::filter_builder_state::BuilderField;
+use crate::app::{App, Focus};
+use crate::query::language::QueryLanguage;
+
+/// Open the filter builder, suggesting the fields of the last successful
+/// result
+pub fn open_filter_builder(app: &mut App) {
+    let Some(query_state) = &app.query else {
+        return;
+    };
+    if query_state.language != QueryLanguage::Jq {
+        app.notification
+            .show_warning("Filter builder is only available for jq queries");
+        return;
+    }
+    let observed = query_state
+        .last_successful_result_text()
+        .as_deref()
+        .map(|results| ObservedFields::from_results(results))
+        .unwrap_or_default();
+    app.filter_builder.open(observed);
+    app.autocomplete.hide();
+    app.history.close();
+}
+
+pub fn handle_filter_builder_key(app: &mut App, key: KeyEvent) {
+    let builder = &mut app.filter_builder;
+    match key.code {
+        KeyCode::Esc => builder.close(),
+        KeyCode::Enter => {
+            if !builder.accept_suggestion() {
+                let clause = builder.clause();
+                builder.close();
+                insert_clause(app, &clause);
+            }
+        }
+        KeyCode::Tab => {
+            builder.accept_suggestion();
+            builder.next_field();
+        }
+        KeyCode::BackTab => builder.prev_field(),
+        KeyCode::Down | KeyCode::Right if builder.field() == BuilderField::Operator => {
+            builder.next_operator()
+        }
+        KeyCode::Up | KeyCode::Left if builder.field() == BuilderField::Operator => {
+            builder.prev_operator()
+        }
+        KeyCode::Down => builder.select_next_suggestion(),
+        KeyCode::Up => builder.select_prev_suggestion(),
+        _ => {
+            if let Some(textarea) = builder.active_textarea_mut() {
+                textarea.input(Input::from(key));
+                builder.clear_suggestion();
+            }
+        }
+    }
+}
+
+/// Insert `clause` at the query cursor and run the query
+fn insert_clause(app: &mut App, clause: &str) {
+    app.focus = Focus::InputField;
+    crate::editor::editor_events::insert_and_execute(app, clause);
+}
+
+#[cfg(test)]
+#[path = "filter_builder_events_tests.rs"]
+mod filter_builder_events_tests;
@@ -0,0 +1,76 @@
+//! Tests for filter_builder_events
+
+use super::*;
+use crate::test_utils::test_helpers::{app_with_result, key, key_with_mods};
+use ratatui::crossterm::event::KeyModifiers;
+
+fn type_text(app: &mut App, text: &str) {
+    for c in text.chars() {
+        handle_filter_builder_key(app, key(KeyCode::Char(c)));
+    }
+}
+
+#[test]
+fn test_alt_s_opens_builder() {
+    let mut app = app_with_result(r#"[{"a": 1}]"#, ".");
+    app.handle_key_event(key_with_mods(KeyCode::Char('s'), KeyModifiers::ALT));
+    assert!(app.filter_builder.is_visible());
+}
+
+#[test]
+fn test_builds_and_inserts_clause_at_cursor() {
+    let mut app = app_with_result(r#"[{"status": "Running"}, {"status": "Failed"}]"#, ".[] | ");
+    open_filter_builder(&mut app);
+
+    // Pick the suggested path, keep `equals`, pick the second value
+    handle_filter_builder_key(&mut app, key(KeyCode::Down));
+    handle_filter_builder_key(&mut app, key(KeyCode::Tab));
+    handle_filter_builder_key(&mut app, key(KeyCode::Tab));
+    handle_filter_builder_key(&mut app, key(KeyCode::Down));
+    handle_filter_builder_key(&mut app, key(KeyCode::Down));
+    handle_filter_builder_key(&mut app, key(KeyCode::Enter));
+    assert!(app.filter_builder.is_visible());
+    assert_eq!(app.filter_builder.value(), r#""Failed""#);
+
+    handle_filter_builder_key(&mut app, key(KeyCode::Enter));
+    assert!(!app.filter_builder.is_visible());
+    assert_eq!(app.input.query(), r#".[] | select(.status == "Failed")"#);
+    assert_eq!(app.focus, Focus::InputField);
+}
+
+#[test]
+fn test_operator_field_cycles_with_arrows() {
+    let mut app = app_with_result(r#"[{"n": 1}]"#, ".");
+    open_filter_builder(&mut app);
+    handle_filter_builder_key(&mut app, key(KeyCode::Tab));
+
+    handle_filter_builder_key(&mut app, key(KeyCode::Right));
+    handle_filter_builder_key(&mut app, key(KeyCode::Down));
+    handle_filter_builder_key(&mut app, key(KeyCode::Left));
+    assert_eq!(
+        app.filter_builder.operator(),
+        crate::filter_builder::filter_builder_query::Operator::NotEqual
+    );
+}
+
+#[test]
+fn test_typing_fills_active_field() {
+    let mut app = app_with_result(r#"[{"n": 1}]"#, ".");
+    open_filter_builder(&mut app);
+    type_text(&mut app, ".n");
+    handle_filter_builder_key(&mut app, key(KeyCode::BackTab));
+    type_text(&mut app, "5");
+
+    assert_eq!(app.filter_builder.path(), ".n");
+    assert_eq!(app.filter_builder.value(), "5");
+}
+
+#[test]
+fn test_esc_closes_without_inserting() {
+    let mut app = app_with_result(r#"[{"n": 1}]"#, ".");
+    open_filter_builder(&mut app);
+    handle_filter_builder_key(&mut app, key(KeyCode::Esc));
+
+    assert!(!app.filter_builder.is_visible());
+    assert_eq!(app.input.query(), ".");
+}
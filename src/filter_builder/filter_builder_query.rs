@@ -0,0 +1,165 @@
+use std::collections::HashMap;
+
+use serde_json::Value;
+
+use crate::stats::field_diff::collect_leaves;
+
+/// Most elements of the result scanned for suggestions
+const MAX_ELEMENTS: usize = 500;
+
+/// Comparison applied to the field
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operator {
+    Equal,
+    NotEqual,
+    Less,
+    LessOrEqual,
+    Greater,
+    GreaterOrEqual,
+    Contains,
+    StartsWith,
+    EndsWith,
+    Matches,
+}
+
+impl Operator {
+    pub const ALL: [Operator; 10] = [
+        Operator::Equal,
+        Operator::NotEqual,
+        Operator::Less,
+        Operator::LessOrEqual,
+        Operator::Greater,
+        Operator::GreaterOrEqual,
+        Operator::Contains,
+        Operator::StartsWith,
+        Operator::EndsWith,
+        Operator::Matches,
+    ];
+
+    /// The jq operator or function
+    pub fn jq(self) -> &'static str {
+        match self {
+            Operator::Equal => "==",
+            Operator::NotEqual => "!=",
+            Operator::Less => "<",
+            Operator::LessOrEqual => "<=",
+            Operator::Greater => ">",
+            Operator::GreaterOrEqual => ">=",
+            Operator::Contains => "contains",
+            Operator::StartsWith => "startswith",
+            Operator::EndsWith => "endswith",
+            Operator::Matches => "test",
+        }
+    }
+
+    /// Plain-language name shown in the form
+    pub fn label(self) -> &'static str {
+        match self {
+            Operator::Equal => "equals",
+            Operator::NotEqual => "does not equal",
+            Operator::Less => "is less than",
+            Operator::LessOrEqual => "is at most",
+            Operator::Greater => "is greater than",
+            Operator::GreaterOrEqual => "is at least",
+            Operator::Contains => "contains",
+            Operator::StartsWith => "starts with",
+            Operator::EndsWith => "ends with",
+            Operator::Matches => "matches regex",
+        }
+    }
+
+    /// Whether the value is always a string, for the string functions
+    fn takes_string(self) -> bool {
+        matches!(
+            self,
+            Operator::StartsWith | Operator::EndsWith | Operator::Matches
+        )
+    }
+}
+
+/// The `select(...)` clause keeping elements whose `field` compares to
+/// `value`
+///
+/// A value that isn't JSON is taken as a string, so `Running` needs no
+/// quotes. An empty field compares the element itself.
+pub fn select_clause(field: &str, operator: Operator, value: &str) -> String {
+    let field = match field.trim() {
+        "" => ".",
+        field => field,
+    };
+    let value = value.trim();
+    let value = match serde_json::from_str::<Value>(value) {
+        Ok(Value::String(_)) => value.to_string(),
+        Ok(_) if !operator.takes_string() => value.to_string(),
+        _ => serde_json::to_string(value).unwrap_or_default(),
+    };
+    match operator {
+        Operator::Contains | Operator::StartsWith | Operator::EndsWith | Operator::Matches => {
+            format!("select({} | {}({}))", field, operator.jq(), value)
+        }
+        _ => format!("select({} {} {})", field, operator.jq(), value),
+    }
+}
+
+/// Fields of the result's elements with the values seen for each
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ObservedFields {
+    /// Paths of the scalars and arrays in the elements, in the order first
+    /// seen
+    pub fields: Vec<String>,
+    /// Compact JSON of each field's values, most frequent first
+    pub values: HashMap<String, Vec<String>>,
+}
+
+impl ObservedFields {
+    /// Fields of the objects in `results`, the elements of an array or the
+    /// values of a stream
+    ///
+    /// Results that aren't JSON give no suggestions.
+    pub fn from_results(results: &str) -> Self {
+        let Ok(values) = serde_json::Deserializer::from_str(results)
+            .into_iter::<Value>()
+            .collect::<Result<Vec<_>, _>>()
+        else {
+            return Self::default();
+        };
+        let elements: Vec<&Value> = match values.as_slice() {
+            [Value::Array(items)] => items.iter().collect(),
+            _ => values.iter().collect(),
+        };
+
+        let mut fields: Vec<String> = Vec::new();
+        let mut counts: HashMap<String, Vec<(String, usize)>> = HashMap::new();
+        for element in elements
+            .into_iter()
+            .filter(|element| element.is_object())
+            .take(MAX_ELEMENTS)
+        {
+            let mut leaves = Vec::new();
+            collect_leaves(element, String::new(), &mut leaves);
+            for (path, value) in leaves {
+                let seen = counts.entry(path.clone()).or_insert_with(|| {
+                    fields.push(path);
+                    Vec::new()
+                });
+                match seen.iter_mut().find(|(seen, _)| *seen == value) {
+                    Some((_, count)) => *count += 1,
+                    None => seen.push((value, 1)),
+                }
+            }
+        }
+
+        let values = counts
+            .into_iter()
+            .map(|(path, mut seen)| {
+                seen.sort_by_key(|(_, count)| std::cmp::Reverse(*count));
+                (path, seen.into_iter().map(|(value, _)| value).collect())
+            })
+            .collect();
+        Self { fields, values }
+    }
+}
+
+#[cfg(test)]
+#[path = "filter_builder_query_tests.rs"]
+mod filter_builder_query_tests;
@@ -0,0 +1,94 @@
+//! Tests for filter_builder_query
+
+use super::*;
+
+#[test]
+fn test_comparison_clause() {
+    assert_eq!(
+        select_clause(".replicas", Operator::Greater, "2"),
+        "select(.replicas > 2)"
+    );
+    assert_eq!(
+        select_clause(".enabled", Operator::Equal, "true"),
+        "select(.enabled == true)"
+    );
+}
+
+#[test]
+fn test_bare_words_are_strings() {
+    assert_eq!(
+        select_clause(".status", Operator::NotEqual, "Running"),
+        r#"select(.status != "Running")"#
+    );
+    assert_eq!(
+        select_clause(".status", Operator::Equal, r#""Running""#),
+        r#"select(.status == "Running")"#
+    );
+    assert_eq!(
+        select_clause(".name", Operator::Equal, "say \"hi\""),
+        r#"select(.name == "say \"hi\"")"#
+    );
+}
+
+#[test]
+fn test_function_clause() {
+    assert_eq!(
+        select_clause(".name", Operator::StartsWith, "api"),
+        r#"select(.name | startswith("api"))"#
+    );
+    assert_eq!(
+        select_clause(".tags", Operator::Contains, r#"["prod"]"#),
+        r#"select(.tags | contains(["prod"]))"#
+    );
+    assert_eq!(
+        select_clause(".version", Operator::Matches, "^1\\."),
+        r#"select(.version | test("^1\\."))"#
+    );
+}
+
+#[test]
+fn test_string_functions_quote_numbers() {
+    assert_eq!(
+        select_clause(".zip", Operator::StartsWith, "90"),
+        r#"select(.zip | startswith("90"))"#
+    );
+}
+
+#[test]
+fn test_empty_field_compares_element() {
+    assert_eq!(select_clause("  ", Operator::Equal, "1"), "select(. == 1)");
+}
+
+#[test]
+fn test_observed_fields_of_array() {
+    let observed = ObservedFields::from_results(
+        r#"[
+            {"name": "api", "spec": {"replicas": 3}},
+            {"name": "web", "spec": {"replicas": 3}},
+            {"name": "jobs", "spec": {"replicas": 1}, "app name": "x"}
+        ]"#,
+    );
+    assert_eq!(
+        observed.fields,
+        vec![".name", ".spec.replicas", r#".["app name"]"#]
+    );
+    assert_eq!(observed.values[".spec.replicas"], vec!["3", "1"]);
+    assert_eq!(
+        observed.values[".name"],
+        vec![r#""api""#, r#""web""#, r#""jobs""#]
+    );
+}
+
+#[test]
+fn test_observed_fields_of_stream() {
+    let observed = ObservedFields::from_results("{\"a\": 1}\n{\"a\": 2}\n");
+    assert_eq!(observed.fields, vec![".a"]);
+}
+
+#[test]
+fn test_no_suggestions_for_non_json() {
+    assert_eq!(
+        ObservedFields::from_results("abc"),
+        ObservedFields::default()
+    );
+}
@@ -0,0 +1,195 @@
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Paragraph},
+};
+use tui_textarea::TextArea;
+
+use super::filter_builder_query::Operator;
+use super::filter_builder_state::{BuilderField, FilterBuilderState, MAX_SUGGESTIONS};
+use crate::theme;
+use crate::widgets::popup;
+
+const FIELD_HEIGHT: u16 = 3;
+
+fn build_hints() -> Line<'static> {
+    theme::border_hints::build_hints(
+        &[
+            ("Tab", "Next"),
+            ("↑/↓", "Suggestions"),
+            ("Enter", "Insert"),
+            ("Esc", "Cancel"),
+        ],
+        theme::filter_builder::BORDER,
+    )
+}
+
+fn field_block(title: &str, is_active: bool) -> Block<'static> {
+    let border_color = if is_active {
+        theme::filter_builder::FIELD_ACTIVE_BORDER
+    } else {
+        theme::filter_builder::FIELD_INACTIVE_BORDER
+    };
+    Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(title.to_string())
+        .border_style(Style::default().fg(border_color))
+        .style(Style::default().bg(theme::filter_builder::BACKGROUND))
+}
+
+fn render_textarea(
+    textarea: &mut TextArea<'static>,
+    title: &str,
+    is_active: bool,
+    frame: &mut Frame,
+    area: Rect,
+) {
+    textarea.set_block(field_block(title, is_active));
+    textarea.set_style(
+        Style::default()
+            .fg(theme::filter_builder::FIELD_TEXT)
+            .bg(theme::filter_builder::BACKGROUND),
+    );
+    textarea.set_cursor_style(if is_active {
+        Style::default().add_modifier(Modifier::REVERSED)
+    } else {
+        Style::default()
+    });
+    frame.render_widget(&*textarea, area);
+}
+
+fn operator_line(selected: Operator, is_active: bool) -> Line<'static> {
+    let label = format!(" {} ", selected.label());
+    let mut spans = vec![Span::styled(
+        label,
+        Style::default()
+            .fg(theme::filter_builder::FIELD_TEXT)
+            .add_modifier(Modifier::BOLD),
+    )];
+    spans.push(Span::styled(
+        format!(" {}", selected.jq()),
+        Style::default().fg(theme::filter_builder::HINT),
+    ));
+    if is_active {
+        spans.push(Span::styled(
+            "   ←/→ to change",
+            Style::default().fg(theme::filter_builder::HINT),
+        ));
+    }
+    Line::from(spans)
+}
+
+fn suggestion_lines(state: &FilterBuilderState) -> Vec<Line<'static>> {
+    let suggestions = state.suggestions();
+    if suggestions.is_empty() {
+        let text = match state.field() {
+            BuilderField::Operator => " ",
+            _ => " No suggestions",
+        };
+        return vec![Line::from(Span::styled(
+            text,
+            Style::default().fg(theme::filter_builder::HINT),
+        ))];
+    }
+    suggestions
+        .into_iter()
+        .enumerate()
+        .map(|(index, suggestion)| {
+            let is_selected = state.suggestion() == Some(index);
+            let indicator = if is_selected { "▸ " } else { "  " };
+            let mut line = Line::from(vec![
+                Span::styled(
+                    indicator,
+                    Style::default().fg(theme::filter_builder::ITEM_SELECTED_INDICATOR),
+                ),
+                Span::styled(
+                    suggestion.to_string(),
+                    Style::default().fg(theme::filter_builder::SUGGESTION),
+                ),
+            ]);
+            if is_selected {
+                line = line.style(Style::default().bg(theme::filter_builder::ITEM_SELECTED_BG));
+            }
+            line
+        })
+        .collect()
+}
+
+/// Render the filter builder form with the clause it will insert
+pub fn render_filter_builder(state: &mut FilterBuilderState, frame: &mut Frame) -> Option<Rect> {
+    let frame_area = frame.area();
+    if !state.is_visible() || frame_area.width < 40 || frame_area.height < 16 {
+        return None;
+    }
+
+    let popup_width = ((frame_area.width as f32 * 0.6) as u16)
+        .clamp(60, 90)
+        .min(frame_area.width.saturating_sub(4));
+    let popup_height =
+        (FIELD_HEIGHT * 3 + MAX_SUGGESTIONS as u16 + 5).min(frame_area.height.saturating_sub(2));
+    let popup_area = popup::centered_popup(frame_area, popup_width, popup_height);
+    popup::clear_area(frame, popup_area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(Line::from(Span::styled(
+            " Filter builder ",
+            theme::filter_builder::TITLE,
+        )))
+        .title_bottom(build_hints().alignment(Alignment::Center))
+        .border_style(Style::default().fg(theme::filter_builder::BORDER))
+        .style(Style::default().bg(theme::filter_builder::BACKGROUND));
+    let content_area = block.inner(popup_area);
+    frame.render_widget(block, popup_area);
+
+    let layout = Layout::vertical([
+        Constraint::Length(FIELD_HEIGHT),
+        Constraint::Length(FIELD_HEIGHT),
+        Constraint::Length(FIELD_HEIGHT),
+        Constraint::Min(1),
+        Constraint::Length(1),
+    ])
+    .split(content_area);
+
+    let field = state.field();
+    render_textarea(
+        state.path_textarea_mut(),
+        " Field ",
+        field == BuilderField::Path,
+        frame,
+        layout[0],
+    );
+    frame.render_widget(
+        Paragraph::new(operator_line(
+            state.operator(),
+            field == BuilderField::Operator,
+        ))
+        .block(field_block(" Operator ", field == BuilderField::Operator)),
+        layout[1],
+    );
+    render_textarea(
+        state.value_textarea_mut(),
+        " Value ",
+        field == BuilderField::Value,
+        frame,
+        layout[2],
+    );
+    frame.render_widget(Paragraph::new(suggestion_lines(state)), layout[3]);
+    frame.render_widget(
+        Paragraph::new(Line::from(Span::styled(
+            format!(" {}", state.clause()),
+            theme::filter_builder::CLAUSE,
+        ))),
+        layout[4],
+    );
+
+    Some(popup_area)
+}
+
+#[cfg(test)]
+#[path = "filter_builder_render_tests.rs"]
+mod filter_builder_render_tests;
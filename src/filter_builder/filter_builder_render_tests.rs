@@ -0,0 +1,53 @@
+//! Tests for filter_builder_render
+
+use super::*;
+use crate::filter_builder::filter_builder_query::ObservedFields;
+use insta::assert_snapshot;
+use ratatui::Terminal;
+use ratatui::backend::TestBackend;
+
+fn render_to_string(state: &mut FilterBuilderState, width: u16, height: u16) -> String {
+    let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+    terminal
+        .draw(|f| {
+            render_filter_builder(state, f);
+        })
+        .unwrap();
+    terminal.backend().to_string()
+}
+
+fn open_state() -> FilterBuilderState {
+    let mut state = FilterBuilderState::new();
+    state.open(ObservedFields::from_results(
+        r#"[{"name": "api", "status": "Running"}, {"name": "web", "status": "Failed"}]"#,
+    ));
+    state
+}
+
+#[test]
+fn snapshot_filter_builder_path_suggestions() {
+    let mut state = open_state();
+    state.select_next_suggestion();
+    assert_snapshot!(render_to_string(&mut state, 80, 26));
+}
+
+#[test]
+fn snapshot_filter_builder_value_suggestions() {
+    let mut state = open_state();
+    state.path_textarea_mut().insert_str(".status");
+    state.next_field();
+    state.next_operator();
+    state.next_field();
+    assert_snapshot!(render_to_string(&mut state, 80, 26));
+}
+
+#[test]
+fn test_nothing_rendered_when_closed() {
+    let mut state = FilterBuilderState::new();
+    let mut terminal = Terminal::new(TestBackend::new(80, 26)).unwrap();
+    let mut area = Some(Rect::default());
+    terminal
+        .draw(|f| area = render_filter_builder(&mut state, f))
+        .unwrap();
+    assert_eq!(area, None);
+}
@@ -0,0 +1,218 @@
+use ratatui::style::{Modifier, Style};
+use tui_textarea::TextArea;
+
+use super::filter_builder_query::{ObservedFields, Operator, select_clause};
+
+/// Most suggestions listed under a field
+pub const MAX_SUGGESTIONS: usize = 8;
+
+/// Part of the form being filled in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BuilderField {
+    Path,
+    Operator,
+    Value,
+}
+
+fn create_textarea() -> TextArea<'static> {
+    let mut textarea = TextArea::default();
+    textarea.set_cursor_line_style(Style::default());
+    textarea.set_cursor_style(Style::default().add_modifier(Modifier::REVERSED));
+    textarea
+}
+
+fn text<'a>(textarea: &'a TextArea<'static>) -> &'a str {
+    textarea.lines()[0].as_str()
+}
+
+/// Form composing a `select(...)` clause (Alt+S)
+pub struct FilterBuilderState {
+    visible: bool,
+    field: BuilderField,
+    path_textarea: TextArea<'static>,
+    operator: usize,
+    value_textarea: TextArea<'static>,
+    observed: ObservedFields,
+    /// Highlighted suggestion of the path or value, if any
+    suggestion: Option<usize>,
+}
+
+impl Default for FilterBuilderState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FilterBuilderState {
+    pub fn new() -> Self {
+        Self {
+            visible: false,
+            field: BuilderField::Path,
+            path_textarea: create_textarea(),
+            operator: 0,
+            value_textarea: create_textarea(),
+            observed: ObservedFields::default(),
+            suggestion: None,
+        }
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    /// Show an empty form suggesting the fields and values of `observed`
+    pub fn open(&mut self, observed: ObservedFields) {
+        self.path_textarea = create_textarea();
+        self.value_textarea = create_textarea();
+        self.operator = 0;
+        self.field = BuilderField::Path;
+        self.observed = observed;
+        self.suggestion = None;
+        self.visible = true;
+    }
+
+    pub fn close(&mut self) {
+        self.visible = false;
+    }
+
+    pub fn field(&self) -> BuilderField {
+        self.field
+    }
+
+    pub fn operator(&self) -> Operator {
+        Operator::ALL[self.operator]
+    }
+
+    pub fn path(&self) -> &str {
+        text(&self.path_textarea)
+    }
+
+    pub fn value(&self) -> &str {
+        text(&self.value_textarea)
+    }
+
+    pub fn next_field(&mut self) {
+        self.field = match self.field {
+            BuilderField::Path => BuilderField::Operator,
+            BuilderField::Operator => BuilderField::Value,
+            BuilderField::Value => BuilderField::Path,
+        };
+        self.suggestion = None;
+    }
+
+    pub fn prev_field(&mut self) {
+        self.field = match self.field {
+            BuilderField::Path => BuilderField::Value,
+            BuilderField::Operator => BuilderField::Path,
+            BuilderField::Value => BuilderField::Operator,
+        };
+        self.suggestion = None;
+    }
+
+    pub fn next_operator(&mut self) {
+        self.operator = (self.operator + 1) % Operator::ALL.len();
+    }
+
+    pub fn prev_operator(&mut self) {
+        self.operator = (self.operator + Operator::ALL.len() - 1) % Operator::ALL.len();
+    }
+
+    /// Suggestions for the field being filled in, containing what was typed
+    ///
+    /// Paths come from the result's elements; values are those seen for the
+    /// chosen path, most frequent first.
+    pub fn suggestions(&self) -> Vec<&str> {
+        let (candidates, typed): (&[String], &str) = match self.field {
+            BuilderField::Path => (&self.observed.fields, self.path()),
+            BuilderField::Value => (
+                self.observed
+                    .values
+                    .get(self.path().trim())
+                    .map(Vec::as_slice)
+                    .unwrap_or_default(),
+                self.value(),
+            ),
+            BuilderField::Operator => return Vec::new(),
+        };
+        let typed = typed.trim();
+        let needle = typed.to_lowercase();
+        candidates
+            .iter()
+            .map(String::as_str)
+            .filter(|candidate| *candidate != typed)
+            .filter(|candidate| candidate.to_lowercase().contains(&needle))
+            .take(MAX_SUGGESTIONS)
+            .collect()
+    }
+
+    pub fn suggestion(&self) -> Option<usize> {
+        self.suggestion
+    }
+
+    pub fn select_next_suggestion(&mut self) {
+        let count = self.suggestions().len();
+        if count == 0 {
+            return;
+        }
+        self.suggestion = Some(match self.suggestion {
+            Some(index) if index + 1 < count => index + 1,
+            Some(index) => index,
+            None => 0,
+        });
+    }
+
+    pub fn select_prev_suggestion(&mut self) {
+        self.suggestion = match self.suggestion {
+            Some(0) | None => None,
+            Some(index) => Some(index - 1),
+        };
+    }
+
+    /// Fill the field with the highlighted suggestion; returns true if there
+    /// was one
+    pub fn accept_suggestion(&mut self) -> bool {
+        let Some(suggestion) = self
+            .suggestion
+            .and_then(|index| self.suggestions().get(index).map(|s| s.to_string()))
+        else {
+            return false;
+        };
+        if let Some(textarea) = self.active_textarea_mut() {
+            *textarea = create_textarea();
+            textarea.insert_str(&suggestion);
+        }
+        self.suggestion = None;
+        true
+    }
+
+    /// Textarea of the field being filled in; None for the operator
+    pub fn active_textarea_mut(&mut self) -> Option<&mut TextArea<'static>> {
+        match self.field {
+            BuilderField::Path => Some(&mut self.path_textarea),
+            BuilderField::Value => Some(&mut self.value_textarea),
+            BuilderField::Operator => None,
+        }
+    }
+
+    /// Typing changes what is suggested, so the highlight starts over
+    pub fn clear_suggestion(&mut self) {
+        self.suggestion = None;
+    }
+
+    pub fn path_textarea_mut(&mut self) -> &mut TextArea<'static> {
+        &mut self.path_textarea
+    }
+
+    pub fn value_textarea_mut(&mut self) -> &mut TextArea<'static> {
+        &mut self.value_textarea
+    }
+
+    /// The clause the form describes
+    pub fn clause(&self) -> String {
+        select_clause(self.path(), self.operator(), self.value())
+    }
+}
+
+#[cfg(test)]
+#[path = "filter_builder_state_tests.rs"]
+mod filter_builder_state_tests;
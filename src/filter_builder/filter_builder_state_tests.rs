@@ -0,0 +1,105 @@
+//! Tests for filter_builder_state
+
+use super::*;
+
+fn open_state() -> FilterBuilderState {
+    let mut state = FilterBuilderState::new();
+    state.open(ObservedFields::from_results(
+        r#"[
+            {"name": "api", "status": "Running", "spec": {"replicas": 3}},
+            {"name": "web", "status": "Running", "spec": {"replicas": 2}},
+            {"name": "jobs", "status": "Failed", "spec": {"replicas": 1}}
+        ]"#,
+    ));
+    state
+}
+
+#[test]
+fn test_open_starts_in_path() {
+    let state = open_state();
+    assert!(state.is_visible());
+    assert_eq!(state.field(), BuilderField::Path);
+    assert_eq!(state.operator(), Operator::Equal);
+    assert_eq!(
+        state.suggestions(),
+        vec![".name", ".status", ".spec.replicas"]
+    );
+}
+
+#[test]
+fn test_path_suggestions_match_typed_text() {
+    let mut state = open_state();
+    state.path_textarea_mut().insert_str("STA");
+    assert_eq!(state.suggestions(), vec![".status"]);
+
+    state.path_textarea_mut().delete_line_by_head();
+    state.path_textarea_mut().insert_str(".status");
+    assert!(state.suggestions().is_empty());
+}
+
+#[test]
+fn test_value_suggestions_follow_path() {
+    let mut state = open_state();
+    state.path_textarea_mut().insert_str(".status");
+    state.next_field();
+    state.next_field();
+    assert_eq!(state.field(), BuilderField::Value);
+    assert_eq!(state.suggestions(), vec![r#""Running""#, r#""Failed""#]);
+}
+
+#[test]
+fn test_accept_suggestion_fills_field() {
+    let mut state = open_state();
+    assert!(!state.accept_suggestion());
+
+    state.select_next_suggestion();
+    state.select_next_suggestion();
+    assert_eq!(state.suggestion(), Some(1));
+    assert!(state.accept_suggestion());
+    assert_eq!(state.path(), ".status");
+    assert_eq!(state.suggestion(), None);
+}
+
+#[test]
+fn test_suggestion_selection_is_clamped() {
+    let mut state = open_state();
+    state.select_prev_suggestion();
+    assert_eq!(state.suggestion(), None);
+    for _ in 0..5 {
+        state.select_next_suggestion();
+    }
+    assert_eq!(state.suggestion(), Some(2));
+    state.select_prev_suggestion();
+    assert_eq!(state.suggestion(), Some(1));
+}
+
+#[test]
+fn test_operators_cycle() {
+    let mut state = open_state();
+    state.prev_operator();
+    assert_eq!(state.operator(), Operator::Matches);
+    state.next_operator();
+    state.next_operator();
+    assert_eq!(state.operator(), Operator::NotEqual);
+}
+
+#[test]
+fn test_fields_cycle() {
+    let mut state = open_state();
+    state.prev_field();
+    assert_eq!(state.field(), BuilderField::Value);
+    state.next_field();
+    assert_eq!(state.field(), BuilderField::Path);
+}
+
+#[test]
+fn test_clause() {
+    let mut state = open_state();
+    state.path_textarea_mut().insert_str(".spec.replicas");
+    state.next_operator();
+    state.next_operator();
+    state.next_operator();
+    state.next_operator();
+    state.value_textarea_mut().insert_str("1");
+    assert_eq!(state.clause(), "select(.spec.replicas > 1)");
+}
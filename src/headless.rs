@@ -0,0 +1,189 @@
+//! Headless API
+//!
+//! [`Session`] runs queries and autocomplete the way the TUI does, without a
+//! terminal, so other tools and integration tests can embed jiq's engine:
+//!
+//! ```
+//! let mut session = jiq::Session::new(r#"{"services": [{"name": "api"}]}"#)?;
+//! session.set_query(".services[]");
+//! session.set_query(".services[].na");
+//! let fields: Vec<String> = session.suggestions().into_iter().map(|s| s.text).collect();
+//! assert!(fields.contains(&"name".to_string()));
+//!
+//! session.set_query(".services[].name");
+//! assert_eq!(session.result(), Ok("\"api\"\n"));
+//! # Ok::<(), jiq::error::JiqError>(())
+//! ```
+//!
+//! Queries run synchronously with the `jq` binary on `PATH`.
+
+use std::fs;
+use std::path::Path;
+use std::sync::Arc;
+
+use crate::autocomplete::{self, AutocompleteState, BraceTracker, Suggestion, path_language};
+use crate::error::JiqError;
+use crate::input::input_format::{self, InputFormat, InputOptions};
+use crate::query::QueryState;
+use crate::query::language::QueryLanguage;
+use crate::query::variables::QueryVariable;
+
+/// Input document with a query run against it
+pub struct Session {
+    state: QueryState,
+    format: InputFormat,
+    query: String,
+    variables: Vec<QueryVariable>,
+    /// Output read back from the spool file, for results too large to be
+    /// kept in the query state
+    spooled_output: Option<Arc<String>>,
+}
+
+impl Session {
+    /// Start a session on `input`, detecting its format from the content as
+    /// for stdin
+    pub fn new(input: impl Into<String>) -> Result<Self, JiqError> {
+        Self::from_input(input.into(), None, &InputOptions::default())
+    }
+
+    /// Start a session on the file at `path`, detecting its format from the
+    /// extension
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, JiqError> {
+        let path = path.as_ref();
+        let content = fs::read_to_string(path)?;
+        Self::from_input(content, Some(path), &InputOptions::default())
+    }
+
+    /// Start a session on `content` read from `path`, with the options of
+    /// `--input-format`, `--csv-*` and the `[xml]` config section
+    pub fn from_input(
+        content: String,
+        path: Option<&Path>,
+        options: &InputOptions,
+    ) -> Result<Self, JiqError> {
+        let (json, format) = input_format::convert(content, path, options)?;
+        let mut session = Self {
+            state: QueryState::new(json),
+            format,
+            query: String::new(),
+            variables: Vec::new(),
+            spooled_output: None,
+        };
+        session.run();
+        Ok(session)
+    }
+
+    /// Format the input was read as
+    pub fn input_format(&self) -> InputFormat {
+        self.format
+    }
+
+    /// Input as the JSON text queries run against
+    pub fn input(&self) -> &str {
+        self.state.executor.json_input()
+    }
+
+    pub fn query(&self) -> &str {
+        &self.query
+    }
+
+    /// Replace the query and run it
+    pub fn set_query(&mut self, query: impl Into<String>) {
+        self.query = query.into();
+        self.run();
+    }
+
+    pub fn language(&self) -> QueryLanguage {
+        self.state.language
+    }
+
+    /// Change the language the query is written in and run it again
+    pub fn set_language(&mut self, language: QueryLanguage) {
+        self.state.language = language;
+        self.run();
+    }
+
+    pub fn variables(&self) -> &[QueryVariable] {
+        &self.variables
+    }
+
+    /// Bind `$name` variables for the query and run it again
+    pub fn set_variables(&mut self, variables: Vec<QueryVariable>) {
+        self.state.set_variables(variables.clone());
+        self.variables = variables;
+        self.run();
+    }
+
+    /// Output of the query, or the error jq (or the translation to jq)
+    /// reported
+    ///
+    /// An empty query runs `.`, as in the TUI.
+    pub fn result(&self) -> Result<&str, &str> {
+        if let Some(output) = &self.spooled_output {
+            return Ok(output);
+        }
+        self.state.result.as_deref().map_err(String::as_str)
+    }
+
+    /// Autocomplete suggestions for the end of the query
+    pub fn suggestions(&self) -> Vec<Suggestion> {
+        self.suggestions_at(self.query.len())
+    }
+
+    /// Autocomplete suggestions for the cursor at byte offset `cursor`
+    ///
+    /// As in the TUI, fields are suggested from the last query that produced
+    /// a result, so suggestions for `.items[].na` come from `.items[]`'s
+    /// output once that has run.
+    pub fn suggestions_at(&self, cursor: usize) -> Vec<Suggestion> {
+        let mut cursor = cursor.min(self.query.len());
+        while !self.query.is_char_boundary(cursor) {
+            cursor -= 1;
+        }
+
+        if self.state.language != QueryLanguage::Jq {
+            return path_language::get_suggestions(
+                self.state.language,
+                &self.query[..cursor],
+                self.state.last_successful_result_parsed.as_deref(),
+                &self.state.executor.all_field_names(),
+            );
+        }
+
+        let mut brace_tracker = BraceTracker::new();
+        brace_tracker.rebuild(&self.query);
+        let bound_variables: Vec<String> = self
+            .variables()
+            .iter()
+            .map(|variable| format!("${}", variable.name))
+            .collect();
+        let library = self.state.library();
+        let mut autocomplete = AutocompleteState::new();
+        autocomplete::update_suggestions(
+            &mut autocomplete,
+            &self.query,
+            cursor,
+            self.state.last_successful_result_parsed.clone(),
+            self.state.base_type_for_suggestions.clone(),
+            self.state.executor.json_input_parsed(),
+            self.state.executor.all_field_names(),
+            &brace_tracker,
+            &bound_variables,
+            library.functions(),
+        );
+        autocomplete.suggestions().to_vec()
+    }
+
+    fn run(&mut self) {
+        self.state.execute(&self.query);
+        self.spooled_output = if self.state.result_is_spooled() {
+            self.state.last_successful_result_text()
+        } else {
+            None
+        };
+    }
+}
+
+#[cfg(test)]
+#[path = "headless_tests.rs"]
+mod headless_tests;
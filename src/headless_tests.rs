@@ -0,0 +1,136 @@
+//! Tests for headless
+
+use super::*;
+use crate::query::variables::VariableKind;
+use tempfile::TempDir;
+
+const JSON: &str = r#"{"services": [{"name": "api", "port": 8080}, {"name": "db", "port": 5432}]}"#;
+
+fn texts(suggestions: Vec<Suggestion>) -> Vec<String> {
+    suggestions.into_iter().map(|s| s.text).collect()
+}
+
+#[test]
+fn test_empty_query_returns_input() {
+    let session = Session::new(r#"{"a": 1}"#).unwrap();
+    assert_eq!(session.query(), "");
+    assert_eq!(session.result(), Ok("{\n  \"a\": 1\n}\n"));
+}
+
+#[test]
+fn test_set_query_runs_it() {
+    let mut session = Session::new(JSON).unwrap();
+    session.set_query(".services[].name");
+    assert_eq!(session.result(), Ok("\"api\"\n\"db\"\n"));
+}
+
+#[test]
+fn test_errors_are_reported() {
+    let mut session = Session::new(JSON).unwrap();
+    session.set_query(".services[");
+    assert!(session.result().is_err());
+}
+
+#[test]
+fn test_invalid_input_is_rejected() {
+    assert!(Session::new("{not json").is_err());
+}
+
+#[test]
+fn test_open_converts_by_extension() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("Cargo.toml");
+    std::fs::write(&path, "[package]\nname = \"jiq\"\n").unwrap();
+
+    let mut session = Session::open(&path).unwrap();
+    session.set_query(".package.name");
+
+    assert_eq!(session.input_format(), InputFormat::Toml);
+    assert_eq!(session.result(), Ok("\"jiq\"\n"));
+}
+
+#[test]
+fn test_variables_are_bound() {
+    let mut session = Session::new(JSON).unwrap();
+    session.set_query(".services[] | select(.port > $min) | .name");
+    assert!(session.result().is_err());
+
+    session.set_variables(vec![QueryVariable::new("min", "6000", VariableKind::Json)]);
+
+    assert_eq!(session.variables().len(), 1);
+    assert_eq!(session.result(), Ok("\"api\"\n"));
+}
+
+#[test]
+fn test_other_languages_are_translated() {
+    let mut session = Session::new(JSON).unwrap();
+    session.set_language(QueryLanguage::JsonPath);
+    session.set_query("$.services[*].port");
+
+    assert_eq!(session.language(), QueryLanguage::JsonPath);
+    let result = session.result().unwrap();
+    assert!(
+        result.contains("8080") && result.contains("5432"),
+        "{}",
+        result
+    );
+}
+
+#[test]
+fn test_field_suggestions_follow_last_result() {
+    let mut session = Session::new(JSON).unwrap();
+    session.set_query(".services[]");
+    session.set_query(".services[].po");
+
+    assert_eq!(texts(session.suggestions()), vec!["port"]);
+}
+
+#[test]
+fn test_function_suggestions() {
+    let mut session = Session::new(JSON).unwrap();
+    session.set_query(".services | leng");
+
+    assert!(texts(session.suggestions()).contains(&"length".to_string()));
+}
+
+#[test]
+fn test_variable_suggestions_include_bindings() {
+    let mut session = Session::new(JSON).unwrap();
+    session.set_variables(vec![QueryVariable::new("limit", "1", VariableKind::Json)]);
+    session.set_query(".services[$li");
+
+    assert!(texts(session.suggestions()).contains(&"$limit".to_string()));
+}
+
+#[test]
+fn test_suggestions_at_cursor_inside_query() {
+    let mut session = Session::new(JSON).unwrap();
+    session.set_query(".services[]");
+    session.set_query(".services[].na | length");
+
+    assert_eq!(texts(session.suggestions_at(14)), vec!["name"]);
+}
+
+#[test]
+fn test_cursor_is_clamped_to_query() {
+    let mut session = Session::new(r#"{"é": 1}"#).unwrap();
+    session.set_query(".\"é");
+
+    // Past the end, and inside the two bytes of "é"
+    session.suggestions_at(100);
+    session.suggestions_at(3);
+}
+
+#[test]
+fn test_spooled_result_is_read_back() {
+    let count = crate::query::result_spool::SPOOL_THRESHOLD_LINES + 1;
+    let mut session = Session::new("1").unwrap();
+    session.set_query(format!("range({})", count));
+
+    let result = session.result().unwrap();
+    assert_eq!(result.lines().count(), count);
+    assert!(result.ends_with("100000\n"));
+
+    session.set_query("1 + 1");
+    assert_eq!(session.result(), Ok("2\n"));
+}
@@ -20,12 +20,33 @@ pub const HELP_CATEGORIES: &[HelpCategory] = &[
                 ("F1 or ?", "Toggle this help"),
                 ("Ctrl+A", "Toggle AI assistant"),
                 ("Ctrl+S", "Open snippets manager"),
+                ("Alt+1-9", "Apply snippet in quick slot"),
                 ("Ctrl+C", "Quit without output"),
-                ("Enter", "Output filtered JSON and exit"),
+                ("Enter", "Output filtered JSON and exit (or exit menu)"),
                 ("Ctrl+Q", "Output query string only and exit"),
+                ("Ctrl+Shift+Q", "Output jq command line and exit"),
                 ("Shift+Tab", "Switch focus (Input / Results)"),
                 ("q", "Quit (in Normal mode or Results pane)"),
                 ("Ctrl+E", "Toggle error overlay"),
+                ("Ctrl+L", "Cycle query language (jq/JSONPath/JMESPath/SQL)"),
+                ("Ctrl+G", "Pipeline view (inspect each stage)"),
+                ("Alt+V", "Variables panel ($name bindings)"),
+                ("Alt+F", "Pause/resume following (--follow)"),
+                (
+                    ":",
+                    "Command line, e.g. :session save NAME, :share, :validate",
+                ),
+                ("Alt+←/→", "Step back/forward through queries run"),
+                ("Ctrl+T", "New query tab (:compare A B diffs two)"),
+                ("Ctrl+PgDn/PgUp", "Next/previous query tab"),
+                ("Ctrl+W", "Close query tab (in Normal mode or Results pane)"),
+                ("Alt+B", "Bookmarks sidebar"),
+                ("Alt+S", "Filter builder (insert a select clause)"),
+                ("Alt+U", "Cut the query back one crumb of its path"),
+                ("Ctrl+/", "Comment out the query from the cursor's stage"),
+                ("Alt+T", "Timing overlay (recent query times)"),
+                ("Alt+R", "Redacted view (mask passwords, tokens, ...)"),
+                ("Alt+M", "Message log (past notifications)"),
             ],
         }],
     },
@@ -48,7 +69,7 @@ pub const HELP_CATEGORIES: &[HelpCategory] = &[
                     ("i/a/I/A", "Enter Insert mode"),
                     ("h/l", "Move cursor left/right"),
                     ("0/^/$", "Jump to start/end of line"),
-                    ("w/b/e", "Word navigation"),
+                    ("w/b/e", "Word navigation (by path segment)"),
                     ("f/F/t/T", "Find/till char forward/backward"),
                     (";/,", "Repeat/reverse last char search"),
                     ("x/X", "Delete character"),
@@ -61,34 +82,98 @@ pub const HELP_CATEGORIES: &[HelpCategory] = &[
                     ("Ctrl+D/U", "Scroll results half page down/up"),
                 ],
             },
+            HelpSection {
+                title: Some("EMACS KEYS ([editor] keybindings)"),
+                entries: &[
+                    ("Ctrl+A/E", "Jump to start/end of line"),
+                    ("Ctrl+B/F", "Move cursor left/right"),
+                    ("Alt+B/F", "Word navigation"),
+                    ("Ctrl+K/U", "Delete to end/start of line"),
+                    ("Ctrl+W/Alt+D", "Delete word before/after cursor"),
+                    ("Ctrl+D", "Delete character"),
+                    ("Ctrl+Y/Alt+Y", "Yank killed text/cycle older kills"),
+                ],
+            },
             HelpSection {
                 title: Some("AUTOCOMPLETE"),
                 entries: &[
                     ("↑/↓", "Navigate suggestions"),
+                    ("PgUp/PgDn", "Page through suggestions"),
                     ("Tab", "Accept suggestion"),
+                    ("→/End", "Accept inline (ghost text) suggestion"),
                     ("Esc", "Dismiss"),
                 ],
             },
+            HelpSection {
+                title: Some("TOOLTIP"),
+                entries: &[
+                    ("Ctrl+O", "Toggle function tooltip"),
+                    ("Alt+E", "Select next example"),
+                    ("Alt+I", "Insert selected example"),
+                ],
+            },
         ],
     },
     // 3: Result tab
     HelpCategory {
         tab: HelpTab::Result,
-        sections: &[HelpSection {
-            title: None,
-            entries: &[
-                ("j/k/↑/↓", "Scroll line by line"),
-                ("J/K", "Scroll 10 lines"),
-                ("h/l/←/→", "Scroll column by column"),
-                ("H/L", "Scroll 10 columns"),
-                ("0/^", "Jump to left edge"),
-                ("$", "Jump to right edge"),
-                ("g/Home", "Jump to top"),
-                ("G/End", "Jump to bottom"),
-                ("Ctrl+D/U", "Half page down/up"),
-                ("PageDown/Up", "Half page down/up"),
-            ],
-        }],
+        sections: &[
+            HelpSection {
+                title: None,
+                entries: &[
+                    ("j/k/↑/↓", "Scroll line by line"),
+                    ("J/K", "Scroll 10 lines"),
+                    ("h/l/←/→", "Scroll column by column"),
+                    ("H/L", "Scroll 10 columns"),
+                    ("0/^", "Jump to left edge"),
+                    ("$", "Jump to right edge"),
+                    ("g/Home", "Jump to top"),
+                    ("G/End", "Jump to bottom"),
+                    ("Ctrl+D/U", "Half page down/up"),
+                    ("PageDown/Up", "Half page down/up"),
+                    ("m", "Bookmark the path of the cursor line"),
+                    ("Ctrl+F", "Find a key or value in the input"),
+                    ("D", "Compare fields across array elements"),
+                    ("S", "Size of each top-level key or element"),
+                    ("c", "Histogram of the cursor line's field"),
+                    ("p", "Pick columns to keep"),
+                    ("e", "Edit the value or key on the line"),
+                    ("O", "Open the result in $EDITOR"),
+                    ("|", "Open the result in $PAGER"),
+                    ("E", "Edit the input in $EDITOR and reload"),
+                ],
+            },
+            HelpSection {
+                title: Some("VISUAL SELECTION"),
+                entries: &[
+                    ("v/V", "Start or end a line selection"),
+                    ("j/k/↑/↓", "Extend the selection"),
+                    ("a", "Select the object or array around it"),
+                    ("o", "Go to the other end of the selection"),
+                    ("y", "Copy the selected lines"),
+                    ("Esc", "End the selection"),
+                ],
+            },
+            HelpSection {
+                title: Some("FOLDING"),
+                entries: &[
+                    ("za", "Toggle fold at cursor"),
+                    ("zo/zc", "Open/close fold at cursor"),
+                    ("zM/zR", "Close/open all folds"),
+                    ("zm/zr", "Close/open one more level"),
+                ],
+            },
+            HelpSection {
+                title: Some("SCREEN READER (--screen-reader)"),
+                entries: &[
+                    ("j/k/↑/↓", "Next/previous node"),
+                    ("h/l/←/→", "Parent/first child"),
+                    ("J/K", "Next/previous sibling"),
+                    ("g/G", "First/last node"),
+                    ("Space", "Repeat the current node"),
+                ],
+            },
+        ],
     },
     // 4: History tab
     HelpCategory {
@@ -99,6 +184,8 @@ pub const HELP_CATEGORIES: &[HelpCategory] = &[
                 ("↑/Ctrl+R", "Open history popup"),
                 ("↑/↓", "Navigate history entries"),
                 ("Type", "Fuzzy search filter"),
+                ("Space", "Mark entry (when not searching)"),
+                ("Ctrl+D", "Delete marked, matching or selected entries"),
                 ("Enter/Tab", "Select entry and close"),
                 ("Esc", "Close without selecting"),
             ],
@@ -114,6 +201,8 @@ pub const HELP_CATEGORIES: &[HelpCategory] = &[
                 ("Alt+1-5", "Apply AI suggestion (direct)"),
                 ("Alt+↑↓/j/k", "Navigate suggestions"),
                 ("Enter", "Apply selected suggestion"),
+                ("Alt+A", "Ask a follow-up about the suggestions"),
+                ("Alt+P", "Ask again with the next AI provider"),
             ],
         }],
     },
@@ -124,7 +213,7 @@ pub const HELP_CATEGORIES: &[HelpCategory] = &[
             HelpSection {
                 title: Some("SEARCH QUERY"),
                 entries: &[
-                    ("Ctrl+F", "Open search (from any pane)"),
+                    ("Ctrl+F", "Open search (from Input field)"),
                     ("/", "Open search (from Results pane)"),
                     ("Enter", "Confirm search"),
                     ("Esc", "Close search"),
@@ -152,13 +241,20 @@ pub const HELP_CATEGORIES: &[HelpCategory] = &[
                     ("↑/↓", "Navigate snippets"),
                     ("Type", "Filter snippets"),
                     ("Enter", "Apply selected snippet"),
+                    ("Tab", "Cycle apply mode (replace/append/wrap)"),
                     ("Ctrl+N", "Create new snippet"),
                     ("Ctrl+E", "Edit selected snippet"),
                     ("Ctrl+D", "Delete selected snippet"),
                     ("Ctrl+R", "Update snippet with current query"),
+                    ("Ctrl+T", "Cycle expected input (any/object/array)"),
+                    ("Alt+1-9", "Assign selected snippet to a quick slot"),
                     ("Esc", "Close snippets manager"),
                 ],
             },
+            HelpSection {
+                title: Some("QUICK SLOTS"),
+                entries: &[("Alt+1-9", "Apply the snippet in that slot")],
+            },
             HelpSection {
                 title: Some("CREATE/EDIT MODE"),
                 entries: &[
@@ -25,6 +25,12 @@ fn get_context_hints(app: &App) -> Vec<(&'static str, &'static str)> {
         }
     } else if app.snippets.is_visible() {
         hints!["F1/?" => "Help", "Esc" => "Close"]
+    } else if app.variables.is_visible() {
+        hints!["F1" => "Help", "Enter" => "Edit/Save", "Esc" => "Close"]
+    } else if app.bookmarks.is_visible() {
+        hints!["F1" => "Help", "Enter" => "Jump", "Tab" => "Insert", "Esc" => "Close"]
+    } else if app.pipeline.is_visible() {
+        hints!["F1" => "Help", "Space" => "Toggle Stage", "Enter" => "Apply", "Esc" => "Close"]
     } else if app.focus == Focus::InputField && app.input.editor_mode == EditorMode::Insert {
         hints!["F1" => "Help", "Shift+Tab" => "Navigate Results", "Ctrl+S" => "Snippets", "Ctrl+F" => "Search", "Ctrl+P/N" => "Cycle History", "Ctrl+R" => "History", "Ctrl+C" => "Quit"]
     } else if app.focus == Focus::ResultsPane {
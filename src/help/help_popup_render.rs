@@ -8,6 +8,7 @@ use ratatui::{
 
 use crate::app::App;
 use crate::help::{HelpSection, HelpTab, get_tab_content};
+use crate::snippets::Snippet;
 use crate::theme;
 use crate::widgets::{popup, scrollbar};
 
@@ -92,7 +93,13 @@ pub fn render_popup(app: &mut App, frame: &mut Frame) -> Option<Rect> {
 
     // Render content for active tab
     let content = get_tab_content(app.help.active_tab);
-    let lines = render_help_sections(content.sections, content_area.width);
+    let mut lines = render_help_sections(content.sections, content_area.width);
+    if app.help.active_tab == HelpTab::Snippet {
+        lines.extend(render_slot_assignments(
+            &app.snippets.slot_assignments(),
+            content_area.width,
+        ));
+    }
 
     // Update scroll bounds for current tab
     let content_height = lines.len() as u32;
@@ -164,9 +171,8 @@ fn render_tab_bar(active_tab: HelpTab, hovered_tab: Option<HelpTab>, _width: u16
     Line::from(spans)
 }
 
-fn render_help_sections(sections: &[HelpSection], width: u16) -> Vec<Line<'static>> {
-    let mut lines = Vec::new();
-
+/// Left padding centering the help entries in `width`
+fn left_padding(width: u16) -> String {
     // Calculate centering: key(15) + spacing(2) + desc(~40) = ~57 chars typical
     // We want to center this content in the available width
     let content_width = 57u16;
@@ -175,7 +181,12 @@ fn render_help_sections(sections: &[HelpSection], width: u16) -> Vec<Line<'stati
     } else {
         0
     };
-    let padding = " ".repeat(left_padding as usize);
+    " ".repeat(left_padding as usize)
+}
+
+fn render_help_sections(sections: &[HelpSection], width: u16) -> Vec<Line<'static>> {
+    let mut lines = Vec::new();
+    let padding = left_padding(width);
 
     for (section_idx, section) in sections.iter().enumerate() {
         // Add section header if present
@@ -202,6 +213,40 @@ fn render_help_sections(sections: &[HelpSection], width: u16) -> Vec<Line<'stati
     lines
 }
 
+/// Section listing the snippets assigned to quick slots, shown after the
+/// snippet tab's shortcuts
+fn render_slot_assignments(slots: &[(u8, &Snippet)], width: u16) -> Vec<Line<'static>> {
+    let padding = left_padding(width);
+    let mut lines = vec![
+        Line::from(""),
+        Line::from(Span::styled(
+            format!("{}── ASSIGNED SLOTS ──", padding),
+            theme::help::SECTION_HEADER,
+        )),
+    ];
+
+    if slots.is_empty() {
+        lines.push(Line::from(Span::styled(
+            format!(
+                "{}None yet; press Alt+1-9 on a snippet to assign one",
+                padding
+            ),
+            Style::default().fg(theme::help::DESCRIPTION),
+        )));
+    }
+    for (slot, snippet) in slots {
+        let key = format!("Alt+{}", slot);
+        lines.push(Line::from(vec![
+            Span::styled(format!("{}{:<15}", padding, key), theme::help::KEY),
+            Span::styled(
+                snippet.name.clone(),
+                Style::default().fg(theme::help::DESCRIPTION),
+            ),
+        ]));
+    }
+    lines
+}
+
 #[cfg(test)]
 #[path = "help_popup_render_tests.rs"]
 mod help_popup_render_tests;
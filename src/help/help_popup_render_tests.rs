@@ -65,3 +65,28 @@ fn test_render_tab_bar_hover_same_as_active() {
     let content = line.to_string();
     assert!(content.contains("[1:Global]"));
 }
+
+#[test]
+fn test_render_slot_assignments_lists_snippets() {
+    let snippet = Snippet {
+        name: "Names".to_string(),
+        query: ".[].name".to_string(),
+        description: None,
+        input: Default::default(),
+    };
+    let lines = render_slot_assignments(&[(2, &snippet)], TEST_WIDTH);
+
+    let line_strings: Vec<String> = lines.iter().map(|l| l.to_string()).collect();
+    assert!(line_strings.iter().any(|s| s.contains("ASSIGNED SLOTS")));
+    assert!(
+        line_strings
+            .iter()
+            .any(|s| s.contains("Alt+2") && s.contains("Names"))
+    );
+}
+
+#[test]
+fn test_render_slot_assignments_without_slots() {
+    let lines = render_slot_assignments(&[], TEST_WIDTH);
+    assert!(lines.last().unwrap().to_string().contains("None yet"));
+}
@@ -0,0 +1,15 @@
+//! Histogram of a field's values
+//!
+//! `c` in the results pane or `:histogram [PATH]` counts how often each value
+//! of a field occurs across the result, with jq's `group_by`, and shows the
+//! counts as bars, most frequent first. Saves writing the aggregation query
+//! to see how values are distributed.
+
+mod histogram_events;
+mod histogram_query;
+mod histogram_render;
+mod histogram_state;
+
+pub use histogram_events::{handle_histogram_key, open_histogram};
+pub use histogram_render::render_histogram;
+pub use histogram_state::HistogramState;
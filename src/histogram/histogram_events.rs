@@ -0,0 +1,53 @@
+use ratatui::crossterm::event::{KeyCode, KeyEvent};
+
+use super::histogram_query::field_for_path;
+use crate::app::App;
+use crate::results::line_path;
+use crate::scroll::handle_scroll_key;
+
+/// Count the values of `field` in the last successful result, for
+/// `:histogram [PATH]`
+///
+/// Without a field, the field of the value on the results cursor line is
+/// counted across every element, e.g. `.[].status` for `.[3].status`.
+pub fn open_histogram(app: &mut App, field: Option<&str>) {
+    let Some(query_state) = &app.query else {
+        return;
+    };
+    let Some(output) = query_state.last_successful_result_text() else {
+        app.notification.show_warning("No result to count yet");
+        return;
+    };
+
+    let field = match field {
+        Some(field) => field.to_string(),
+        None => {
+            let line = app.results_cursor_result_line();
+            match line_path::path_at_line(&output, line) {
+                Some(at) => field_for_path(&at.path),
+                None => {
+                    app.notification.show_warning("No JSON path on this line");
+                    return;
+                }
+            }
+        }
+    };
+
+    let input = output;
+    let variables = query_state.variables();
+    let library = query_state.library();
+    app.histogram.open(&field, input, variables, library);
+}
+
+pub fn handle_histogram_key(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('c') => app.histogram.close(),
+        _ => {
+            handle_scroll_key(&mut app.histogram.scroll, key);
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "histogram_events_tests.rs"]
+mod histogram_events_tests;
@@ -0,0 +1,46 @@
+//! Tests for histogram_events
+
+use super::*;
+use crate::app::Focus;
+use crate::test_utils::test_helpers::{app_with_result, key};
+
+#[test]
+fn test_open_uses_cursor_line_field() {
+    let mut app = app_with_result(r#"{"items": [{"a": 1}, {"a": 2}]}"#, ".items");
+    // Line 2 is `"a": 1` inside the first element
+    app.results_cursor.update_total_lines(8);
+    app.results_cursor.move_to_line(2);
+    open_histogram(&mut app, None);
+
+    assert!(app.histogram.is_visible());
+    assert_eq!(app.histogram.field(), Some(".[].a"));
+}
+
+#[test]
+fn test_open_with_field() {
+    let mut app = app_with_result(r#"[{"a": 1}, {"a": 2}]"#, ".");
+    open_histogram(&mut app, Some(".[].a"));
+
+    assert_eq!(app.histogram.field(), Some(".[].a"));
+}
+
+#[test]
+fn test_c_in_results_pane_toggles_popup() {
+    let mut app = app_with_result(r#"[{"a": 1}, {"a": 2}]"#, ".");
+    app.focus = Focus::ResultsPane;
+
+    app.handle_key_event(key(KeyCode::Char('c')));
+    assert!(app.histogram.is_visible());
+
+    app.handle_key_event(key(KeyCode::Char('c')));
+    assert!(!app.histogram.is_visible());
+}
+
+#[test]
+fn test_esc_closes_popup() {
+    let mut app = app_with_result(r#"[{"a": 1}, {"a": 2}]"#, ".");
+    open_histogram(&mut app, Some(".[].a"));
+
+    handle_histogram_key(&mut app, key(KeyCode::Esc));
+    assert!(!app.histogram.is_visible());
+}
@@ -0,0 +1,82 @@
+use serde_json::Value;
+
+/// A value of the field and how many times it occurs
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bucket {
+    /// Compact JSON of the value
+    pub value: String,
+    pub count: usize,
+}
+
+/// Field of every element matching the value at `path`, a result line path
+///
+/// Array indices become iterations, so the status of one item,
+/// `.items[3].status`, counts the status of every item: `.items[].status`.
+/// Indices are left alone inside quoted keys.
+pub fn field_for_path(path: &str) -> String {
+    let bytes = path.as_bytes();
+    let mut field = Vec::with_capacity(bytes.len());
+    let mut in_string = false;
+    let mut i = 0;
+    while i < bytes.len() {
+        let byte = bytes[i];
+        if in_string {
+            if byte == b'\\' && i + 1 < bytes.len() {
+                field.extend_from_slice(&bytes[i..i + 2]);
+                i += 2;
+                continue;
+            }
+            in_string = byte != b'"';
+        } else if byte == b'"' {
+            in_string = true;
+        } else if byte == b'[' {
+            let digits = bytes[i + 1..]
+                .iter()
+                .take_while(|b| b.is_ascii_digit())
+                .count();
+            if digits > 0 && bytes.get(i + 1 + digits) == Some(&b']') {
+                field.extend_from_slice(b"[]");
+                i += digits + 2;
+                continue;
+            }
+        }
+        field.push(byte);
+        i += 1;
+    }
+    // Only ASCII digits were removed, so the bytes are still UTF-8
+    String::from_utf8(field).unwrap_or_else(|_| path.to_string())
+}
+
+/// jq program counting the values of `field` across every output value of
+/// its input, most frequent first
+///
+/// The output is a single array of `[value, count]` pairs. Values with the
+/// same count stay in `group_by` order.
+pub fn histogram_program(field: &str) -> String {
+    format!(
+        "[., inputs | ({})] | group_by(.) | map([.[0], length]) | sort_by(-.[1])",
+        field
+    )
+}
+
+/// Buckets from the output of `histogram_program`
+///
+/// An empty result gives no output at all, which means no buckets.
+pub fn parse_buckets(output: &str) -> Result<Vec<Bucket>, String> {
+    if output.trim().is_empty() {
+        return Ok(Vec::new());
+    }
+    let pairs: Vec<(Value, usize)> =
+        serde_json::from_str(output).map_err(|e| format!("Unexpected jq output: {}", e))?;
+    Ok(pairs
+        .into_iter()
+        .map(|(value, count)| Bucket {
+            value: value.to_string(),
+            count,
+        })
+        .collect())
+}
+
+#[cfg(test)]
+#[path = "histogram_query_tests.rs"]
+mod histogram_query_tests;
@@ -0,0 +1,58 @@
+//! Tests for histogram_query
+
+use super::*;
+
+#[test]
+fn test_field_for_path_iterates_indices() {
+    assert_eq!(field_for_path(".[3].status"), ".[].status");
+    assert_eq!(
+        field_for_path(".items[12].spec.ports[0].port"),
+        ".items[].spec.ports[].port"
+    );
+    assert_eq!(field_for_path(".status"), ".status");
+    assert_eq!(field_for_path("."), ".");
+}
+
+#[test]
+fn test_field_for_path_keeps_quoted_keys() {
+    assert_eq!(field_for_path(r#".[1]["a[2]"]"#), r#".[]["a[2]"]"#);
+    assert_eq!(
+        field_for_path(r#".["say \"[1]\""][0]"#),
+        r#".["say \"[1]\""][]"#
+    );
+}
+
+#[test]
+fn test_histogram_program_wraps_field() {
+    assert_eq!(
+        histogram_program(".a, .b"),
+        "[., inputs | (.a, .b)] | group_by(.) | map([.[0], length]) | sort_by(-.[1])"
+    );
+}
+
+#[test]
+fn test_parse_buckets() {
+    assert_eq!(
+        parse_buckets(r#"[["Running",3],[null,1],[{"a":1},1]]"#),
+        Ok(vec![
+            Bucket {
+                value: r#""Running""#.to_string(),
+                count: 3
+            },
+            Bucket {
+                value: "null".to_string(),
+                count: 1
+            },
+            Bucket {
+                value: r#"{"a":1}"#.to_string(),
+                count: 1
+            },
+        ])
+    );
+}
+
+#[test]
+fn test_parse_buckets_empty_output() {
+    assert_eq!(parse_buckets("\n"), Ok(Vec::new()));
+    assert!(parse_buckets("3").is_err());
+}
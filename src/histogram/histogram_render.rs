@@ -0,0 +1,157 @@
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::Style,
+    text::{Line, Span, Text},
+    widgets::{Block, BorderType, Borders, Paragraph},
+};
+
+use super::histogram_query::Bucket;
+use super::histogram_state::HistogramOutput;
+use crate::app::App;
+use crate::theme;
+use crate::widgets::text_width::{display_width, truncate_to_width};
+use crate::widgets::{popup, scrollbar};
+
+/// Widest value column before values are cut off
+const MAX_VALUE_WIDTH: usize = 24;
+
+/// Space taken by a percentage, e.g. ` 66.7%`
+const PERCENT_WIDTH: usize = 7;
+
+fn build_hints() -> Line<'static> {
+    theme::border_hints::build_hints(
+        &[("j/k", "Scroll"), ("g/G", "Top/Bottom"), ("Esc", "Close")],
+        theme::histogram::BORDER,
+    )
+}
+
+/// One line per bucket: value, bar scaled to the most frequent value, count
+/// and share of all values
+fn bar_lines(buckets: &[Bucket], width: usize) -> Vec<Line<'static>> {
+    let total: usize = buckets.iter().map(|bucket| bucket.count).sum();
+    let max_count = buckets.iter().map(|bucket| bucket.count).max().unwrap_or(0);
+    let value_width = buckets
+        .iter()
+        .map(|bucket| display_width(&bucket.value))
+        .max()
+        .unwrap_or(0)
+        .min(MAX_VALUE_WIDTH);
+    let count_width = max_count.to_string().len();
+    let bar_width = width
+        .saturating_sub(value_width + count_width + PERCENT_WIDTH + 4)
+        .max(1);
+
+    buckets
+        .iter()
+        .map(|bucket| {
+            let value = truncate_to_width(&bucket.value, value_width);
+            let padding = value_width.saturating_sub(display_width(&value));
+            let bar = (bucket.count * bar_width / max_count.max(1)).max(1);
+            let percent = bucket.count as f64 * 100.0 / total.max(1) as f64;
+            Line::from(vec![
+                Span::raw(" "),
+                Span::styled(value, Style::default().fg(theme::histogram::VALUE)),
+                Span::raw(" ".repeat(padding + 1)),
+                Span::styled("█".repeat(bar), Style::default().fg(theme::histogram::BAR)),
+                Span::raw(" ".repeat(bar_width - bar + 1)),
+                Span::styled(
+                    format!("{:>width$}", bucket.count, width = count_width),
+                    theme::histogram::COUNT,
+                ),
+                Span::styled(
+                    format!("{:>width$.1}%", percent, width = PERCENT_WIDTH - 1),
+                    Style::default().fg(theme::histogram::PERCENT),
+                ),
+            ])
+        })
+        .collect()
+}
+
+/// Render the histogram popup for the counted field
+pub fn render_histogram(app: &mut App, frame: &mut Frame) -> Option<Rect> {
+    let frame_area = frame.area();
+    if frame_area.width < 30 || frame_area.height < 8 {
+        return None;
+    }
+    let field = app.histogram.field()?.to_string();
+
+    let popup_width = ((frame_area.width as f32 * 0.8) as u16)
+        .clamp(50, 100)
+        .min(frame_area.width.saturating_sub(4));
+    let popup_height = ((frame_area.height as f32 * 0.7) as u16)
+        .clamp(8, 30)
+        .min(frame_area.height.saturating_sub(2));
+    let popup_area = popup::centered_popup(frame_area, popup_width, popup_height);
+    popup::clear_area(frame, popup_area);
+
+    let title = match app.histogram.output() {
+        HistogramOutput::Done(buckets) => {
+            let total: usize = buckets.iter().map(|bucket| bucket.count).sum();
+            format!(
+                " {}: {} {}, {} distinct ",
+                field,
+                total,
+                if total == 1 { "value" } else { "values" },
+                buckets.len()
+            )
+        }
+        _ => format!(" {} ", field),
+    };
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(Line::from(Span::styled(title, theme::histogram::TITLE)))
+        .title_bottom(build_hints().centered())
+        .border_style(Style::default().fg(theme::histogram::BORDER))
+        .style(Style::default().bg(theme::histogram::BACKGROUND));
+    let content_area = block.inner(popup_area);
+    frame.render_widget(block, popup_area);
+
+    let note_style = Style::default().fg(theme::histogram::NOTE);
+    let lines = match app.histogram.output() {
+        HistogramOutput::Pending => vec![Line::from(Span::styled(" Counting…", note_style))],
+        HistogramOutput::Done(buckets) if buckets.is_empty() => {
+            vec![Line::from(Span::styled(" No values", note_style))]
+        }
+        HistogramOutput::Done(buckets) => bar_lines(buckets, content_area.width as usize),
+        HistogramOutput::Failed(message) => message
+            .lines()
+            .map(|line| {
+                Line::from(Span::styled(
+                    format!(" {}", line),
+                    Style::default().fg(theme::histogram::ERROR),
+                ))
+            })
+            .collect(),
+    };
+
+    let content_height = lines.len() as u32;
+    let scroll = &mut app.histogram.scroll;
+    scroll.update_bounds(content_height, content_area.height);
+    frame.render_widget(
+        Paragraph::new(Text::from(lines)).scroll((scroll.offset, 0)),
+        content_area,
+    );
+
+    let scrollbar_area = Rect {
+        x: popup_area.x,
+        y: popup_area.y.saturating_add(1),
+        width: popup_area.width,
+        height: popup_area.height.saturating_sub(2),
+    };
+    scrollbar::render_vertical_scrollbar_styled(
+        frame,
+        scrollbar_area,
+        content_height as usize,
+        scroll.viewport_height as usize,
+        scroll.offset as usize,
+        theme::histogram::BORDER,
+    );
+
+    Some(popup_area)
+}
+
+#[cfg(test)]
+#[path = "histogram_render_tests.rs"]
+mod histogram_render_tests;
@@ -0,0 +1,74 @@
+//! Tests for histogram_render
+
+use super::*;
+use crate::test_utils::test_helpers::test_app;
+use insta::assert_snapshot;
+use ratatui::Terminal;
+use ratatui::backend::TestBackend;
+
+fn render_to_string(app: &mut App, width: u16, height: u16) -> String {
+    let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+    terminal
+        .draw(|f| {
+            render_histogram(app, f);
+        })
+        .unwrap();
+    terminal.backend().to_string()
+}
+
+fn bucket(value: &str, count: usize) -> Bucket {
+    Bucket {
+        value: value.to_string(),
+        count,
+    }
+}
+
+#[test]
+fn snapshot_histogram() {
+    let mut app = test_app(r#"{"a": 1}"#);
+    app.histogram.set_output(
+        ".items[].status",
+        HistogramOutput::Done(vec![
+            bucket(r#""Running""#, 8),
+            bucket(r#""Pending""#, 3),
+            bucket(r#""CrashLoopBackOff""#, 1),
+        ]),
+    );
+
+    assert_snapshot!(render_to_string(&mut app, 80, 12));
+}
+
+#[test]
+fn snapshot_histogram_pending() {
+    let mut app = test_app(r#"{"a": 1}"#);
+    app.histogram
+        .set_output(".items[].status", HistogramOutput::Pending);
+
+    assert_snapshot!(render_to_string(&mut app, 80, 12));
+}
+
+#[test]
+fn test_bars_scale_to_most_frequent_value() {
+    let lines = bar_lines(&[bucket("1", 100), bucket("2", 50), bucket("3", 1)], 40);
+    let bar_len = |line: &Line| {
+        line.spans
+            .iter()
+            .find(|span| span.content.starts_with('█'))
+            .map(|span| span.content.chars().count())
+            .unwrap_or(0)
+    };
+    let full = bar_len(&lines[0]);
+    assert_eq!(bar_len(&lines[1]), full / 2);
+    assert_eq!(bar_len(&lines[2]), 1);
+}
+
+#[test]
+fn test_nothing_rendered_when_closed() {
+    let mut app = test_app(r#"{"a": 1}"#);
+    let mut terminal = Terminal::new(TestBackend::new(80, 20)).unwrap();
+    let mut area = Some(Rect::default());
+    terminal
+        .draw(|f| area = render_histogram(&mut app, f))
+        .unwrap();
+    assert_eq!(area, None);
+}
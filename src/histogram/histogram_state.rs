@@ -0,0 +1,145 @@
+use std::sync::Arc;
+use std::sync::mpsc::{Receiver, Sender, channel};
+
+use tokio_util::sync::CancellationToken;
+
+use super::histogram_query::{Bucket, histogram_program, parse_buckets};
+use crate::error::parser;
+use crate::query::executor::JqExecutor;
+use crate::query::library::JqLibrary;
+use crate::query::variables::QueryVariable;
+use crate::scroll::ScrollState;
+
+/// Counts of the field's values, once jq has produced them
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HistogramOutput {
+    Pending,
+    /// Most frequent value first
+    Done(Vec<Bucket>),
+    Failed(String),
+}
+
+/// Finished run: the program and its output or jq's error
+type HistogramResponse = (String, Result<String, String>);
+
+/// Histogram popup for one field (`c` or `:histogram`)
+pub struct HistogramState {
+    /// Field being counted; None while the popup is closed
+    field: Option<String>,
+    program: String,
+    output: HistogramOutput,
+    pub scroll: ScrollState,
+    response_tx: Sender<HistogramResponse>,
+    response_rx: Receiver<HistogramResponse>,
+    cancel_token: CancellationToken,
+}
+
+impl Default for HistogramState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HistogramState {
+    pub fn new() -> Self {
+        let (response_tx, response_rx) = channel();
+        Self {
+            field: None,
+            program: String::new(),
+            output: HistogramOutput::Pending,
+            scroll: ScrollState::new(),
+            response_tx,
+            response_rx,
+            cancel_token: CancellationToken::new(),
+        }
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.field.is_some()
+    }
+
+    pub fn field(&self) -> Option<&str> {
+        self.field.as_deref()
+    }
+
+    pub fn output(&self) -> &HistogramOutput {
+        &self.output
+    }
+
+    /// Show the popup for `field` and start counting its values in `input`
+    ///
+    /// Runs in a background thread; call `poll_responses` to collect the
+    /// counts. A run still going for another field is cancelled.
+    pub fn open(
+        &mut self,
+        field: &str,
+        input: Arc<String>,
+        variables: Arc<Vec<QueryVariable>>,
+        library: Arc<JqLibrary>,
+    ) {
+        self.cancel();
+        self.field = Some(field.to_string());
+        self.program = histogram_program(field);
+        self.output = HistogramOutput::Pending;
+        self.scroll = ScrollState::new();
+
+        let program = self.program.clone();
+        let tx = self.response_tx.clone();
+        let cancel_token = self.cancel_token.clone();
+        std::thread::spawn(move || {
+            let result =
+                JqExecutor::execute_on_input(&input, &program, &variables, &library, &cancel_token)
+                    .map_err(|e| e.to_string());
+            if !cancel_token.is_cancelled() {
+                let _ = tx.send((program, result));
+            }
+        });
+    }
+
+    /// Show `field` with `output` already produced (test helper)
+    #[cfg(test)]
+    pub fn set_output(&mut self, field: &str, output: HistogramOutput) {
+        self.field = Some(field.to_string());
+        self.program = histogram_program(field);
+        self.output = output;
+    }
+
+    pub fn close(&mut self) {
+        self.cancel();
+        self.field = None;
+    }
+
+    fn cancel(&mut self) {
+        self.cancel_token.cancel();
+        self.cancel_token = CancellationToken::new();
+    }
+
+    /// Store the finished run; returns true if it arrived
+    pub fn poll_responses(&mut self) -> bool {
+        let mut updated = false;
+        while let Ok((program, result)) = self.response_rx.try_recv() {
+            // Runs for a field no longer shown are not wanted
+            if !self.is_visible()
+                || program != self.program
+                || self.output != HistogramOutput::Pending
+            {
+                continue;
+            }
+            self.output = match result {
+                Ok(output) => match parse_buckets(&output) {
+                    Ok(buckets) => HistogramOutput::Done(buckets),
+                    Err(e) => HistogramOutput::Failed(e),
+                },
+                Err(stderr) => {
+                    HistogramOutput::Failed(parser::parse(&stderr, &program).display_text())
+                }
+            };
+            updated = true;
+        }
+        updated
+    }
+}
+
+#[cfg(test)]
+#[path = "histogram_state_tests.rs"]
+mod histogram_state_tests;
@@ -0,0 +1,89 @@
+//! Tests for histogram_state
+
+use super::*;
+use std::time::{Duration, Instant};
+
+fn wait_for_output(state: &mut HistogramState) {
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while Instant::now() < deadline {
+        state.poll_responses();
+        if *state.output() != HistogramOutput::Pending {
+            return;
+        }
+        std::thread::sleep(Duration::from_millis(10));
+    }
+    panic!("histogram timed out");
+}
+
+fn open(state: &mut HistogramState, field: &str, input: &str) {
+    state.open(
+        field,
+        Arc::new(input.to_string()),
+        Arc::default(),
+        Arc::default(),
+    );
+}
+
+fn bucket(value: &str, count: usize) -> Bucket {
+    Bucket {
+        value: value.to_string(),
+        count,
+    }
+}
+
+#[test]
+fn test_counts_values_most_frequent_first() {
+    let mut state = HistogramState::new();
+    open(
+        &mut state,
+        ".[].status",
+        r#"[{"status":"up"},{"status":"down"},{"status":"up"},{}]"#,
+    );
+    assert!(state.is_visible());
+    assert_eq!(state.field(), Some(".[].status"));
+    wait_for_output(&mut state);
+
+    assert_eq!(
+        *state.output(),
+        HistogramOutput::Done(vec![
+            bucket(r#""up""#, 2),
+            bucket("null", 1),
+            bucket(r#""down""#, 1)
+        ])
+    );
+}
+
+#[test]
+fn test_counts_across_a_stream() {
+    let mut state = HistogramState::new();
+    open(&mut state, ".n", "{\"n\":1}\n{\"n\":2}\n{\"n\":2}\n");
+    wait_for_output(&mut state);
+
+    assert_eq!(
+        *state.output(),
+        HistogramOutput::Done(vec![bucket("2", 2), bucket("1", 1)])
+    );
+}
+
+#[test]
+fn test_failing_field_reports_error() {
+    let mut state = HistogramState::new();
+    open(&mut state, ".[].a", r#"[1, 2]"#);
+    wait_for_output(&mut state);
+
+    match state.output() {
+        HistogramOutput::Failed(message) => assert!(message.contains("Cannot index")),
+        other => panic!("expected failure, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_close_drops_pending_run() {
+    let mut state = HistogramState::new();
+    open(&mut state, ".a", r#"{"a":1}"#);
+    state.close();
+    assert!(!state.is_visible());
+
+    std::thread::sleep(Duration::from_millis(200));
+    assert!(!state.poll_responses());
+}
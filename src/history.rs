@@ -1,7 +1,10 @@
 pub mod history_events;
+mod history_import;
 pub mod history_render;
 mod history_state;
+mod literal_redaction;
 mod matcher;
 pub mod storage;
 
+pub use history_import::{HistoryFormat, parse_history};
 pub use history_state::{HistoryState, MAX_VISIBLE_HISTORY};
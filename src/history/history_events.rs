@@ -1,4 +1,4 @@
-use ratatui::crossterm::event::{KeyCode, KeyEvent};
+use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use tui_textarea::Input;
 
 use crate::app::App;
@@ -24,6 +24,20 @@ pub fn handle_history_popup_key(app: &mut App, key: KeyEvent) {
             app.history.close();
         }
 
+        // Space separates search terms once a search is typed
+        KeyCode::Char(' ') if app.history.is_search_empty() => {
+            app.history.toggle_mark();
+        }
+
+        KeyCode::Char('d') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            let deleted = app.history.delete_entries();
+            if deleted > 0 {
+                let noun = if deleted == 1 { "entry" } else { "entries" };
+                app.notification
+                    .show(&format!("Deleted {} history {}", deleted, noun));
+            }
+        }
+
         _ => {
             let input = Input::from(key);
             if app.history.search_textarea_mut().input(input) {
@@ -46,6 +60,7 @@ fn replace_query_with(app: &mut App, text: &str) {
     app.results_scroll.reset();
     app.results_cursor.reset();
     app.error_overlay_visible = false;
+    app.update_tooltip();
 }
 
 #[cfg(test)]
@@ -81,6 +81,18 @@ fn test_history_popup_enter_selects() {
     assert_eq!(app.query(), ".selected_query");
 }
 
+#[test]
+fn test_history_popup_enter_updates_tooltip() {
+    let mut app = app_with_query("");
+    app.input.editor_mode = EditorMode::Insert;
+    app.history.add_entry_in_memory(".items | length");
+
+    app.handle_key_event(key_with_mods(KeyCode::Char('r'), KeyModifiers::CONTROL));
+    app.handle_key_event(key(KeyCode::Enter));
+
+    assert_eq!(app.tooltip.current_function, Some("length".to_string()));
+}
+
 #[test]
 fn test_history_popup_tab_selects() {
     let mut app = app_with_query("");
@@ -324,3 +336,39 @@ fn test_backspace_on_empty_search() {
     app.handle_key_event(key(KeyCode::Backspace));
     assert_eq!(app.history.search_query(), "");
 }
+
+#[test]
+fn test_space_marks_and_ctrl_d_deletes_marked_entries() {
+    let mut app = app_with_query("");
+    app.input.editor_mode = EditorMode::Insert;
+    app.history.add_entry_in_memory(".a");
+    app.history.add_entry_in_memory(".b");
+    app.history.add_entry_in_memory(".c");
+
+    app.handle_key_event(key_with_mods(KeyCode::Char('r'), KeyModifiers::CONTROL));
+    app.handle_key_event(key(KeyCode::Char(' ')));
+    app.handle_key_event(key(KeyCode::Up));
+    app.handle_key_event(key(KeyCode::Char(' ')));
+    assert_eq!(app.history.marked_count(), 2);
+    assert_eq!(app.history.search_query(), "");
+
+    app.handle_key_event(key_with_mods(KeyCode::Char('d'), KeyModifiers::CONTROL));
+
+    assert_eq!(app.history.total_count(), 1);
+    assert_eq!(app.history.selected_entry(), Some(".a"));
+    assert!(app.history.is_visible());
+}
+
+#[test]
+fn test_space_after_search_text_separates_terms() {
+    let mut app = app_with_query("");
+    app.input.editor_mode = EditorMode::Insert;
+    app.history.add_entry_in_memory(".test");
+
+    app.handle_key_event(key_with_mods(KeyCode::Char('r'), KeyModifiers::CONTROL));
+    app.handle_key_event(key(KeyCode::Char('t')));
+    app.handle_key_event(key(KeyCode::Char(' ')));
+
+    assert_eq!(app.history.search_query(), "t ");
+    assert_eq!(app.history.marked_count(), 0);
+}
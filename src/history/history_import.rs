@@ -0,0 +1,227 @@
+//! Reading queries out of other tools' history files for `--import-history`
+
+use clap::ValueEnum;
+
+/// Layout of a history file given to `--import-history`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum HistoryFormat {
+    /// One jq filter per line, oldest first (jq REPLs, hand-kept lists)
+    #[default]
+    Plain,
+    /// fx expressions, one per line; path expressions are kept and `@`
+    /// maps become `map(...)`
+    Fx,
+    /// zsh history; the filter of each `jq` command is kept
+    Zsh,
+}
+
+/// Queries found in `content`, newest first like jiq's own history
+pub fn parse_history(content: &str, format: HistoryFormat) -> Vec<String> {
+    let queries: Vec<String> = match format {
+        HistoryFormat::Plain => content
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(str::to_string)
+            .collect(),
+        HistoryFormat::Fx => content.lines().filter_map(fx_to_jq).collect(),
+        HistoryFormat::Zsh => zsh_commands(content)
+            .iter()
+            .flat_map(|command| jq_filters(command))
+            .collect(),
+    };
+    queries.into_iter().rev().collect()
+}
+
+/// The jq equivalent of an fx expression, when it has one
+///
+/// fx expressions are JavaScript; only the ones that read the same in jq
+/// are converted: paths such as `.items[0].name` and `@.name`, fx's map.
+fn fx_to_jq(line: &str) -> Option<String> {
+    let expr = line.trim();
+    if let Some(body) = expr.strip_prefix('@') {
+        return is_fx_path(body).then(|| format!("map({})", body));
+    }
+    is_fx_path(expr).then(|| expr.to_string())
+}
+
+/// Whether `expr` is a path of `.field`, `[index]`, `["key"]` and `?` steps
+fn is_fx_path(expr: &str) -> bool {
+    if !expr.starts_with('.') {
+        return false;
+    }
+    if expr == "." {
+        return true;
+    }
+
+    let chars: Vec<char> = expr.chars().collect();
+    let mut i = 0;
+    while i < chars.len() {
+        match chars[i] {
+            '.' => {
+                let start = i + 1;
+                i = start;
+                while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                    i += 1;
+                }
+                // `.` alone is only allowed right before `[`
+                if i == start && chars.get(i) != Some(&'[') {
+                    return false;
+                }
+            }
+            '[' => {
+                let Some(len) = chars[i..].iter().position(|&c| c == ']') else {
+                    return false;
+                };
+                let inner: String = chars[i + 1..i + len].iter().collect();
+                let is_index = !inner.is_empty()
+                    && inner
+                        .strip_prefix('-')
+                        .unwrap_or(&inner)
+                        .chars()
+                        .all(|c| c.is_ascii_digit());
+                let is_key = inner.len() >= 2
+                    && inner.starts_with('"')
+                    && inner.ends_with('"')
+                    && !inner[1..inner.len() - 1].contains('"');
+                if !(inner.is_empty() || is_index || is_key) {
+                    return false;
+                }
+                i += len + 1;
+            }
+            '?' => i += 1,
+            _ => return false,
+        }
+    }
+    true
+}
+
+/// Commands in a zsh history file, with the `: <time>:<elapsed>;` prefix of
+/// extended history removed and continuation lines joined
+fn zsh_commands(content: &str) -> Vec<String> {
+    let mut commands = Vec::new();
+    let mut current: Option<String> = None;
+
+    for line in content.lines() {
+        let line = match current.take() {
+            Some(mut command) => {
+                command.push('\n');
+                command.push_str(line);
+                command
+            }
+            None => strip_extended_prefix(line).to_string(),
+        };
+        match line.strip_suffix('\\') {
+            Some(head) => current = Some(head.to_string()),
+            None => commands.push(line),
+        }
+    }
+    commands.extend(current);
+    commands
+}
+
+fn strip_extended_prefix(line: &str) -> &str {
+    let Some(rest) = line.strip_prefix(": ") else {
+        return line;
+    };
+    match rest.split_once(';') {
+        Some((stamp, command))
+            if !stamp.is_empty() && stamp.chars().all(|c| c.is_ascii_digit() || c == ':') =>
+        {
+            command
+        }
+        _ => line,
+    }
+}
+
+/// Filters of the `jq` invocations in a shell command, e.g. `.name` in
+/// `curl -s URL | jq -r '.name'`
+fn jq_filters(command: &str) -> Vec<String> {
+    shell_words(command)
+        .split(|word| word.is_none())
+        .filter_map(|segment| {
+            let words: Vec<&str> = segment.iter().flatten().map(String::as_str).collect();
+            jq_filter(&words)
+        })
+        .collect()
+}
+
+/// Whether `program` runs jq: `jq`, a path to it, or `jq.exe` on Windows
+fn is_jq(program: &str) -> bool {
+    let name = program.rsplit(['/', '\\']).next().unwrap_or(program);
+    name == "jq" || name.eq_ignore_ascii_case("jq.exe")
+}
+
+/// The filter argument of one `jq ...` command
+fn jq_filter(words: &[&str]) -> Option<String> {
+    let (program, args) = words.split_first()?;
+    if !is_jq(program) {
+        return None;
+    }
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match *arg {
+            // The filter is read from a file
+            "-f" | "--from-file" => return None,
+            "--arg" | "--argjson" | "--slurpfile" | "--rawfile" => {
+                args.nth(1);
+            }
+            "-L" | "--indent" => {
+                args.next();
+            }
+            "--args" | "--jsonargs" => {}
+            option if option.starts_with('-') && option.len() > 1 => {}
+            filter => {
+                let filter = filter.split_whitespace().collect::<Vec<_>>().join(" ");
+                return (!filter.is_empty()).then_some(filter);
+            }
+        }
+    }
+    None
+}
+
+/// Words of a shell command with quotes removed; `None` marks a command
+/// separator such as `|`, `;` or `&&`
+fn shell_words(command: &str) -> Vec<Option<String>> {
+    let mut words = Vec::new();
+    let mut word: Option<String> = None;
+    let mut chars = command.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\'' => {
+                let text = word.get_or_insert_with(String::new);
+                text.extend(chars.by_ref().take_while(|&c| c != '\''));
+            }
+            '"' => {
+                let text = word.get_or_insert_with(String::new);
+                while let Some(c) = chars.next() {
+                    match c {
+                        '"' => break,
+                        '\\' if matches!(chars.peek(), Some('"' | '\\' | '$' | '`')) => {
+                            text.extend(chars.next());
+                        }
+                        _ => text.push(c),
+                    }
+                }
+            }
+            '\\' => word.get_or_insert_with(String::new).extend(chars.next()),
+            '|' | ';' | '&' => {
+                words.extend(word.take().map(Some));
+                words.push(None);
+                if chars.peek() == Some(&c) {
+                    chars.next();
+                }
+            }
+            c if c.is_whitespace() => words.extend(word.take().map(Some)),
+            c => word.get_or_insert_with(String::new).push(c),
+        }
+    }
+    words.extend(word.map(Some));
+    words
+}
+
+#[cfg(test)]
+#[path = "history_import_tests.rs"]
+mod history_import_tests;
@@ -0,0 +1,75 @@
+//! Tests for history/history_import
+
+use super::*;
+
+#[test]
+fn test_plain_lines_are_read_newest_first() {
+    let queries = parse_history(".a\n\n  .b | keys  \n", HistoryFormat::Plain);
+    assert_eq!(queries, vec![".b | keys", ".a"]);
+}
+
+#[test]
+fn test_fx_paths_are_kept_and_maps_converted() {
+    let content = ".items[0].name\n@.name\n.[\"odd key\"]?\nx => x.length\n.map(x => x.id)\n.\n";
+    let queries = parse_history(content, HistoryFormat::Fx);
+    assert_eq!(
+        queries,
+        vec![".", ".[\"odd key\"]?", "map(.name)", ".items[0].name"]
+    );
+}
+
+#[test]
+fn test_fx_rejects_non_paths() {
+    assert!(!is_fx_path(".a."));
+    assert!(!is_fx_path(".a[foo]"));
+    assert!(!is_fx_path("this.a"));
+    assert!(is_fx_path(".a[-1][]"));
+}
+
+#[test]
+fn test_zsh_extended_history_keeps_jq_filters() {
+    let content = "\
+: 1700000000:0;ls -la
+: 1700000001:0;curl -s https://example.com | jq -r '.items[] | .name'
+: 1700000002:3;jq --arg id 7 -c \".[] | select(.id == \\\"x\\\")\" data.json
+git status
+/usr/bin/jq . file.json && jq -f prog.jq file.json
+";
+    let queries = parse_history(content, HistoryFormat::Zsh);
+    assert_eq!(
+        queries,
+        vec![".", ".[] | select(.id == \"x\")", ".items[] | .name"]
+    );
+}
+
+#[test]
+fn test_zsh_multiline_commands_are_joined() {
+    let content = ": 1700000000:0;jq '.a \\\n| .b' f.json\n";
+    let queries = parse_history(content, HistoryFormat::Zsh);
+    assert_eq!(queries, vec![".a | .b"]);
+}
+
+#[test]
+fn test_zsh_command_without_filter_is_skipped() {
+    assert_eq!(
+        parse_history("jq\njq -r\n", HistoryFormat::Zsh),
+        Vec::<String>::new()
+    );
+}
+
+#[test]
+fn test_windows_jq_programs_are_recognized() {
+    assert!(is_jq("jq"));
+    assert!(is_jq("jq.exe"));
+    assert!(is_jq("JQ.EXE"));
+    assert!(is_jq(r"C:\ProgramData\chocolatey\bin\jq.exe"));
+    assert!(is_jq("/usr/bin/jq"));
+    assert!(!is_jq("jq-tool"));
+    assert!(!is_jq("gojq.exe"));
+
+    let content = "jq.exe -r '.name' data.json\n\"C:\\tools\\jq.exe\" .a data.json\n";
+    assert_eq!(
+        parse_history(content, HistoryFormat::Zsh),
+        vec![".a", ".name"]
+    );
+}
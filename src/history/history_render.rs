@@ -11,10 +11,18 @@ use crate::history::MAX_VISIBLE_HISTORY;
 use crate::scroll::Scrollable;
 use crate::syntax_highlight::JqHighlighter;
 use crate::theme;
+use crate::widgets::text_width::truncate_to_width;
 use crate::widgets::{popup, scrollbar};
 
 pub const HISTORY_SEARCH_HEIGHT: u16 = 3;
 
+fn build_hints() -> Line<'static> {
+    theme::border_hints::build_hints(
+        &[("Space", "Mark"), ("Ctrl+D", "Delete"), ("Enter", "Select")],
+        theme::history::BORDER,
+    )
+}
+
 /// Render the history popup
 ///
 /// Returns the popup area for region tracking.
@@ -44,11 +52,20 @@ pub fn render_popup(app: &mut App, frame: &mut Frame, input_area: Rect) -> Optio
     let list_area = layout[0];
     let search_area = layout[1];
 
-    let title = format!(
-        " History ({}/{}) ",
-        app.history.filtered_count(),
-        app.history.total_count()
-    );
+    let title = if app.history.marked_count() > 0 {
+        format!(
+            " History ({}/{}, {} marked) ",
+            app.history.filtered_count(),
+            app.history.total_count(),
+            app.history.marked_count()
+        )
+    } else {
+        format!(
+            " History ({}/{}) ",
+            app.history.filtered_count(),
+            app.history.total_count()
+        )
+    };
 
     let max_text_len = (list_area.width as usize).saturating_sub(6);
 
@@ -68,12 +85,7 @@ pub fn render_popup(app: &mut App, frame: &mut Frame, input_area: Rect) -> Optio
         list_items.push(ListItem::new(Line::from("")));
 
         for (display_idx, entry) in app.history.visible_entries() {
-            let display_text = if entry.chars().count() > max_text_len {
-                let truncated: String = entry.chars().take(max_text_len).collect();
-                format!("{}…", truncated)
-            } else {
-                entry.to_string()
-            };
+            let display_text = truncate_to_width(entry, max_text_len);
 
             let is_selected = display_idx == app.history.selected_index();
 
@@ -98,6 +110,12 @@ pub fn render_popup(app: &mut App, frame: &mut Frame, input_area: Rect) -> Optio
             };
 
             let mut spans = prefix;
+            if app.history.is_marked(entry) {
+                spans.push(Span::styled(
+                    "● ",
+                    Style::default().fg(theme::history::MARKED).bg(bg_color),
+                ));
+            }
 
             // Syntax highlighting for all items
             let highlighted = JqHighlighter::highlight(&display_text);
@@ -128,6 +146,7 @@ pub fn render_popup(app: &mut App, frame: &mut Frame, input_area: Rect) -> Optio
         .borders(Borders::ALL)
         .border_type(BorderType::Rounded)
         .title(title)
+        .title_bottom(build_hints().centered())
         .border_style(Style::default().fg(theme::history::BORDER))
         .style(Style::default().bg(theme::history::BACKGROUND));
 
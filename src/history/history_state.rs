@@ -1,8 +1,11 @@
+use std::collections::HashSet;
+
 use ratatui::style::{Modifier, Style};
 use tui_textarea::TextArea;
 
 use super::matcher::HistoryMatcher;
 use super::storage;
+use crate::config::HistoryConfig;
 use crate::scroll::Scrollable;
 
 pub const MAX_VISIBLE_HISTORY: usize = 15;
@@ -23,7 +26,14 @@ pub struct HistoryState {
     visible: bool,
     matcher: HistoryMatcher,
     persist_to_disk: bool,
+    /// Command source whose history this is (`jiq k8s pods`), `None` for
+    /// the shared history
+    source: Option<String>,
     cycling_index: Option<usize>,
+    /// Entries marked with Space for deleting together
+    marked: HashSet<String>,
+    /// Deduplication and size limits from `[history]`
+    policy: HistoryConfig,
 }
 
 impl Default for HistoryState {
@@ -34,7 +44,12 @@ impl Default for HistoryState {
 
 impl HistoryState {
     pub fn new() -> Self {
-        let entries = storage::load_history();
+        Self::with_source(None, HistoryConfig::default())
+    }
+
+    /// History kept separately for a command source, or the shared one
+    pub fn with_source(source: Option<String>, policy: HistoryConfig) -> Self {
+        let entries = storage::load_history(source.as_deref(), &policy);
         let filtered_indices = (0..entries.len()).collect();
 
         Self {
@@ -46,7 +61,10 @@ impl HistoryState {
             visible: false,
             matcher: HistoryMatcher::new(),
             persist_to_disk: true,
+            source,
             cycling_index: None,
+            marked: HashSet::new(),
+            policy,
         }
     }
 
@@ -61,7 +79,10 @@ impl HistoryState {
             visible: false,
             matcher: HistoryMatcher::new(),
             persist_to_disk: false,
+            source: None,
             cycling_index: None,
+            marked: HashSet::new(),
+            policy: HistoryConfig::default(),
         }
     }
 
@@ -96,6 +117,7 @@ impl HistoryState {
         self.selected_index = 0;
         self.scroll_offset = 0;
         self.filtered_indices = (0..self.entries.len()).collect();
+        self.marked.clear();
     }
 
     pub fn is_visible(&self) -> bool {
@@ -104,11 +126,7 @@ impl HistoryState {
 
     #[cfg(test)]
     pub fn search_query(&self) -> &str {
-        self.search_textarea
-            .lines()
-            .first()
-            .map(|s| s.as_str())
-            .unwrap_or("")
+        self.search_text()
     }
 
     pub fn search_textarea_mut(&mut self) -> &mut TextArea<'static> {
@@ -159,6 +177,61 @@ impl HistoryState {
             .map(String::as_str)
     }
 
+    /// Mark the selected entry for deleting, or unmark it
+    pub fn toggle_mark(&mut self) {
+        let Some(entry) = self.selected_entry().map(str::to_string) else {
+            return;
+        };
+        if !self.marked.remove(&entry) {
+            self.marked.insert(entry);
+        }
+    }
+
+    pub fn is_marked(&self, entry: &str) -> bool {
+        self.marked.contains(entry)
+    }
+
+    pub fn marked_count(&self) -> usize {
+        self.marked.len()
+    }
+
+    /// Delete the marked entries; without marks, every entry matching the
+    /// search, or the selected entry when there is no search. Returns how
+    /// many were deleted.
+    pub fn delete_entries(&mut self) -> usize {
+        let doomed: HashSet<String> = if !self.marked.is_empty() {
+            std::mem::take(&mut self.marked)
+        } else if !self.search_text().trim().is_empty() {
+            self.filtered_indices
+                .iter()
+                .filter_map(|&idx| self.entries.get(idx).cloned())
+                .collect()
+        } else {
+            self.selected_entry()
+                .map(str::to_string)
+                .into_iter()
+                .collect()
+        };
+        if doomed.is_empty() {
+            return 0;
+        }
+
+        if self.persist_to_disk
+            && let Err(e) = storage::remove_entries(&doomed, self.source.as_deref(), &self.policy)
+        {
+            eprintln!("Warning: Failed to save query history to disk: {}", e);
+        }
+
+        self.entries.retain(|entry| !doomed.contains(entry));
+        self.cycling_index = None;
+        self.update_filter();
+        self.selected_index = self
+            .selected_index
+            .min(self.filtered_indices.len().saturating_sub(1));
+        self.adjust_scroll_to_selection();
+        doomed.len()
+    }
+
     pub fn selected_index(&self) -> usize {
         self.selected_index
     }
@@ -189,33 +262,42 @@ impl HistoryState {
     }
 
     pub fn add_entry(&mut self, query: &str) {
-        if query.trim().is_empty() {
+        let query = &storage::prepare_entry(query, &self.policy);
+        if query.is_empty() {
             return;
         }
 
         // Only persist to disk if enabled (disabled for tests)
         if self.persist_to_disk
-            && let Err(e) = storage::add_entry(query)
+            && let Err(e) = storage::add_entry(query, self.source.as_deref(), &self.policy)
         {
             eprintln!("Warning: Failed to save query history to disk: {}", e);
             eprintln!("History will work for this session only.");
             // Continue with in-memory update despite save failure
         }
 
-        self.entries.retain(|e| e != query);
-        self.entries.insert(0, query.to_string());
+        storage::push_entry(&mut self.entries, query, self.policy.dedupe);
+        self.entries.truncate(self.policy.max_entries);
 
         self.filtered_indices = (0..self.entries.len()).collect();
     }
 
-    fn update_filter(&mut self) {
-        let query = self
-            .search_textarea
+    /// Whether the search box is empty
+    pub fn is_search_empty(&self) -> bool {
+        self.search_text().is_empty()
+    }
+
+    fn search_text(&self) -> &str {
+        self.search_textarea
             .lines()
             .first()
             .map(|s| s.as_str())
-            .unwrap_or("");
-        self.filtered_indices = self.matcher.filter(query, &self.entries);
+            .unwrap_or("")
+    }
+
+    fn update_filter(&mut self) {
+        let query = self.search_text().to_string();
+        self.filtered_indices = self.matcher.filter(&query, &self.entries);
     }
 
     pub fn cycle_previous(&mut self) -> Option<String> {
@@ -12,7 +12,10 @@ fn create_test_state(entries: Vec<&str>) -> HistoryState {
         visible: false,
         matcher: HistoryMatcher::new(),
         persist_to_disk: false,
+        source: None,
         cycling_index: None,
+        marked: HashSet::new(),
+        policy: HistoryConfig::default(),
     }
 }
 
@@ -360,7 +363,10 @@ fn create_scrollable_test_state(entry_count: usize) -> HistoryState {
         visible: false,
         matcher: HistoryMatcher::new(),
         persist_to_disk: false,
+        source: None,
         cycling_index: None,
+        marked: HashSet::new(),
+        policy: HistoryConfig::default(),
     }
 }
 
@@ -434,3 +440,87 @@ fn test_scrollable_content_fits_in_viewport() {
     state.scroll_view_down(5);
     assert_eq!(Scrollable::scroll_offset(&state), 0); // Can't scroll when content fits
 }
+
+#[test]
+fn test_toggle_mark_marks_and_unmarks_selected() {
+    let mut state = create_test_state(vec![".foo", ".bar", ".baz"]);
+    state.toggle_mark();
+    assert!(state.is_marked(".foo"));
+    assert_eq!(state.marked_count(), 1);
+
+    state.toggle_mark();
+    assert!(!state.is_marked(".foo"));
+    assert_eq!(state.marked_count(), 0);
+}
+
+#[test]
+fn test_delete_entries_removes_marked() {
+    let mut state = create_test_state(vec![".foo", ".bar", ".baz"]);
+    state.toggle_mark();
+    state.select_next();
+    state.select_next();
+    state.toggle_mark();
+
+    assert_eq!(state.delete_entries(), 2);
+    assert_eq!(state.total_count(), 1);
+    assert_eq!(state.selected_entry(), Some(".bar"));
+    assert_eq!(state.marked_count(), 0);
+}
+
+#[test]
+fn test_delete_entries_without_marks_removes_search_matches() {
+    let mut state = create_test_state(vec![".foo", ".bar", ".foo.x"]);
+    state.open(Some("foo"));
+
+    assert_eq!(state.delete_entries(), 2);
+    assert_eq!(state.total_count(), 1);
+    assert_eq!(state.filtered_count(), 0);
+}
+
+#[test]
+fn test_delete_entries_without_marks_or_search_removes_selected() {
+    let mut state = create_test_state(vec![".foo", ".bar", ".baz"]);
+    state.open(None);
+    state.select_next();
+
+    assert_eq!(state.delete_entries(), 1);
+    assert_eq!(state.total_count(), 2);
+    assert!(!state.entries.contains(&".bar".to_string()));
+    assert_eq!(state.selected_entry(), Some(".baz"));
+}
+
+#[test]
+fn test_close_clears_marks() {
+    let mut state = create_test_state(vec![".foo", ".bar", ".baz"]);
+    state.toggle_mark();
+    state.close();
+    assert_eq!(state.marked_count(), 0);
+}
+
+#[test]
+fn test_add_entry_follows_history_policy() {
+    let mut state = create_test_state(vec![".foo"]);
+    state.policy = HistoryConfig {
+        dedupe: crate::config::HistoryDedupe::Consecutive,
+        max_entries: 3,
+        max_file_kb: 0,
+        ..HistoryConfig::default()
+    };
+
+    state.add_entry(".bar");
+    state.add_entry(".bar");
+    state.add_entry(".foo");
+    state.add_entry(".baz");
+
+    assert_eq!(state.entries, vec![".baz", ".foo", ".bar"]);
+}
+
+#[test]
+fn test_add_entry_redacts_string_literals() {
+    let mut state = create_test_state(vec![]);
+    state.policy.redact_strings = true;
+
+    state.add_entry(r#"select(.name == "alice")"#);
+
+    assert_eq!(state.entries, vec![r#"select(.name == "***")"#]);
+}
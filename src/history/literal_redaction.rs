@@ -0,0 +1,74 @@
+//! Masking string literals in queries before they are saved
+//!
+//! With `redact_strings = true` under `[history]`, the text of every string
+//! literal is replaced by `***` before a query enters the history, so that
+//! identifiers typed into a filter (`select(.customer == "ACME-42")`) are not
+//! kept on disk. Interpolations inside strings are kept as they are.
+
+const MASK: &str = "***";
+
+/// `query` with the text of its string literals masked
+pub fn redact_string_literals(query: &str) -> String {
+    let mut redacted = String::with_capacity(query.len());
+    let mut in_string = false;
+    // Whether the current stretch of string text is already masked
+    let mut masked = false;
+    // Paren depth within each open interpolation
+    let mut interpolations: Vec<usize> = Vec::new();
+    let mut chars = query.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if in_string {
+            match c {
+                '\\' if chars.peek() == Some(&'(') => {
+                    chars.next();
+                    redacted.push_str("\\(");
+                    interpolations.push(0);
+                    in_string = false;
+                }
+                '"' => {
+                    redacted.push('"');
+                    in_string = false;
+                }
+                _ => {
+                    if c == '\\' {
+                        chars.next();
+                    }
+                    if !masked {
+                        redacted.push_str(MASK);
+                        masked = true;
+                    }
+                }
+            }
+            continue;
+        }
+
+        redacted.push(c);
+        match c {
+            '"' => {
+                in_string = true;
+                masked = false;
+            }
+            '(' => {
+                if let Some(depth) = interpolations.last_mut() {
+                    *depth += 1;
+                }
+            }
+            ')' => match interpolations.last_mut() {
+                Some(0) => {
+                    interpolations.pop();
+                    in_string = true;
+                    masked = false;
+                }
+                Some(depth) => *depth -= 1,
+                None => {}
+            },
+            _ => {}
+        }
+    }
+    redacted
+}
+
+#[cfg(test)]
+#[path = "literal_redaction_tests.rs"]
+mod literal_redaction_tests;
@@ -0,0 +1,41 @@
+//! Tests for history/literal_redaction
+
+use super::*;
+
+#[test]
+fn test_queries_without_strings_are_unchanged() {
+    assert_eq!(redact_string_literals(".items[] | .id"), ".items[] | .id");
+}
+
+#[test]
+fn test_string_literals_are_masked() {
+    assert_eq!(
+        redact_string_literals(r#"select(.customer == "ACME-42")"#),
+        r#"select(.customer == "***")"#
+    );
+    assert_eq!(
+        redact_string_literals(r#".["user id"], has("email")"#),
+        r#".["***"], has("***")"#
+    );
+}
+
+#[test]
+fn test_empty_strings_stay_empty() {
+    assert_eq!(redact_string_literals(r#".a // """#), r#".a // """#);
+}
+
+#[test]
+fn test_escapes_are_masked_with_the_text() {
+    assert_eq!(
+        redact_string_literals(r#"test("a\"b\\c")"#),
+        r#"test("***")"#
+    );
+}
+
+#[test]
+fn test_interpolations_are_kept() {
+    assert_eq!(
+        redact_string_literals(r#""id \(.id) of \(.owner | ascii("x")) done""#),
+        r#""***\(.id)***\(.owner | ascii("***"))***""#
+    );
+}
@@ -53,7 +53,7 @@ impl HistoryMatcher {
             })
             .collect();
 
-        scored.sort_by(|a, b| b.1.cmp(&a.1));
+        scored.sort_by_key(|&(_, score)| std::cmp::Reverse(score));
 
         scored.into_iter().map(|(idx, _)| idx).collect()
     }
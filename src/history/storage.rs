@@ -1,75 +1,261 @@
-use std::fs::{self, File};
+use std::collections::HashSet;
+use std::fs::{self, File, OpenOptions};
 use std::io::{self, BufRead, BufReader, Write};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+
+use super::literal_redaction::redact_string_literals;
+use crate::config::{HistoryConfig, HistoryDedupe};
 
-const MAX_HISTORY_ENTRIES: usize = 1000;
-const HISTORY_DIR: &str = "jiq";
 const HISTORY_FILE: &str = "history";
+/// Directory holding the histories of command sources (`jiq k8s`, `jiq aws`)
+const SOURCE_HISTORY_DIR: &str = "history.d";
 
-pub fn history_path() -> Option<PathBuf> {
-    dirs::data_dir().map(|p| p.join(HISTORY_DIR).join(HISTORY_FILE))
+/// Names of the shared history, its rotated archive and the directory of
+/// source histories, relative to the state directory
+pub fn state_entries() -> [PathBuf; 3] {
+    let history = PathBuf::from(HISTORY_FILE);
+    [
+        rotated_path(&history),
+        history,
+        PathBuf::from(SOURCE_HISTORY_DIR),
+    ]
 }
 
-pub fn load_history() -> Vec<String> {
-    let Some(path) = history_path() else {
-        return Vec::new();
-    };
+/// History file for `source`, or the shared history when `None`
+pub fn history_path(source: Option<&str>) -> Option<PathBuf> {
+    let dir = crate::paths::state_dir()?;
+    Some(match source {
+        Some(source) => dir.join(SOURCE_HISTORY_DIR).join(file_name(source)),
+        None => dir.join(HISTORY_FILE),
+    })
+}
 
-    let file = match File::open(&path) {
+/// Source name made safe to use as a file name
+fn file_name(source: &str) -> String {
+    source
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.') {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect::<String>()
+        .trim_start_matches('.')
+        .to_string()
+}
+
+pub fn load_history(source: Option<&str>, policy: &HistoryConfig) -> Vec<String> {
+    match history_path(source) {
+        Some(path) => load_history_from(&path, policy),
+        None => Vec::new(),
+    }
+}
+
+/// Load the history in `path`, compacting the file first when it breaks
+/// `policy` (written by an older version or under other settings)
+fn load_history_from(path: &Path, policy: &HistoryConfig) -> Vec<String> {
+    let file = match File::open(path) {
         Ok(f) => f,
         Err(_) => return Vec::new(),
     };
 
     let reader = BufReader::new(file);
-    reader
+    let entries: Vec<String> = reader
         .lines()
         .map_while(Result::ok)
         .filter(|line| !line.trim().is_empty())
-        .collect()
+        .collect();
+
+    if policy.private {
+        // Files created before `private` was set may be readable by others
+        let _ = restrict_permissions(path);
+    }
+
+    let compacted = apply_policy(&entries, policy);
+    if compacted == entries && !exceeds_size(&file_content(&compacted), policy) {
+        return entries;
+    }
+    // Migration is best effort; the compacted entries are used either way
+    save_history_to(path, &entries, policy).unwrap_or(compacted)
 }
 
-pub fn save_history(entries: &[String]) -> io::Result<()> {
-    let Some(path) = history_path() else {
+pub fn save_history(
+    entries: &[String],
+    source: Option<&str>,
+    policy: &HistoryConfig,
+) -> io::Result<()> {
+    let Some(path) = history_path(source) else {
         return Err(io::Error::new(
             io::ErrorKind::NotFound,
             "Could not determine history file path",
         ));
     };
+    save_history_to(&path, entries, policy).map(|_| ())
+}
 
+/// Write `entries` to `path` as `policy` allows, moving the older entries to
+/// `<path>.1` when the file grows past the size limit. Returns the entries
+/// written.
+fn save_history_to(
+    path: &Path,
+    entries: &[String],
+    policy: &HistoryConfig,
+) -> io::Result<Vec<String>> {
     if let Some(parent) = path.parent() {
         fs::create_dir_all(parent)?;
     }
 
-    let mut file = File::create(&path)?;
+    let mut kept = apply_policy(entries, policy);
+    let mut content = file_content(&kept);
+
+    if exceeds_size(&content, policy) {
+        // Keep the newest entries filling half the limit, so that the next
+        // few queries do not rotate again, and always the newest one
+        let budget = max_file_bytes(policy) / 2;
+        let mut size = 0;
+        let fitting = kept
+            .iter()
+            .take_while(|entry| {
+                size += entry.len() + 1;
+                size <= budget
+            })
+            .count()
+            .max(1);
+        let dropped = kept.split_off(fitting);
+        create_file(&rotated_path(path), policy)?.write_all(file_content(&dropped).as_bytes())?;
+        content = file_content(&kept);
+    }
 
-    let unique_entries = deduplicate(entries);
-    let trimmed = trim_to_max(&unique_entries);
+    create_file(path, policy)?.write_all(content.as_bytes())?;
+    Ok(kept)
+}
 
-    for entry in trimmed {
-        writeln!(file, "{}", entry)?;
+/// Create or truncate `path`, readable by its owner only under a private
+/// policy
+fn create_file(path: &Path, policy: &HistoryConfig) -> io::Result<File> {
+    let mut options = OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    if policy.private {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
     }
+    let file = options.open(path)?;
+    if policy.private {
+        // The mode only applies to new files
+        restrict_permissions(path)?;
+    }
+    Ok(file)
+}
 
+#[cfg(unix)]
+fn restrict_permissions(path: &Path) -> io::Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+    fs::set_permissions(path, fs::Permissions::from_mode(0o600))
+}
+
+#[cfg(not(unix))]
+fn restrict_permissions(_path: &Path) -> io::Result<()> {
     Ok(())
 }
 
 /// No file locking - last writer wins if multiple instances run simultaneously.
-pub fn add_entry(query: &str) -> io::Result<()> {
-    let query = query.trim();
+pub fn add_entry(query: &str, source: Option<&str>, policy: &HistoryConfig) -> io::Result<()> {
+    let query = prepare_entry(query, policy);
     if query.is_empty() {
         return Ok(());
     }
 
-    let mut entries = load_history();
+    let mut entries = load_history(source, policy);
+    push_entry(&mut entries, &query, policy.dedupe);
+    save_history(&entries, source, policy)
+}
+
+/// Delete `queries` from the history file, rewriting it without them.
+pub fn remove_entries(
+    queries: &HashSet<String>,
+    source: Option<&str>,
+    policy: &HistoryConfig,
+) -> io::Result<()> {
+    let mut entries = load_history(source, policy);
+    entries.retain(|e| !queries.contains(e));
+    save_history(&entries, source, policy)
+}
+
+/// Add `queries` (newest first) after the existing history, as entries
+/// older than any query run in jiq. Returns how many entries were added.
+pub fn import_entries(
+    queries: &[String],
+    source: Option<&str>,
+    policy: &HistoryConfig,
+) -> io::Result<usize> {
+    let Some(path) = history_path(source) else {
+        return Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            "Could not determine history file path",
+        ));
+    };
+    import_entries_to(&path, queries, policy)
+}
 
-    entries.retain(|e| e != query);
+fn import_entries_to(path: &Path, queries: &[String], policy: &HistoryConfig) -> io::Result<usize> {
+    let mut entries = load_history_from(path, policy);
+    let before = entries.len();
+    entries.extend(
+        queries
+            .iter()
+            .map(|query| prepare_entry(query, policy))
+            .filter(|query| !query.is_empty()),
+    );
+    let kept = save_history_to(path, &entries, policy)?;
+    Ok(kept.len().saturating_sub(before))
+}
+
+/// Put `query` first in `entries`, dropping repeats as `dedupe` says
+pub fn push_entry(entries: &mut Vec<String>, query: &str, dedupe: HistoryDedupe) {
+    match dedupe {
+        HistoryDedupe::Global => entries.retain(|e| e != query),
+        HistoryDedupe::Consecutive if entries.first().is_some_and(|e| e == query) => return,
+        HistoryDedupe::Consecutive | HistoryDedupe::None => {}
+    }
     entries.insert(0, query.to_string());
+}
+
+/// `query` as it is kept in the history: trimmed, and with its string
+/// literals masked when `policy` redacts them
+pub fn prepare_entry(query: &str, policy: &HistoryConfig) -> String {
+    let query = query.trim();
+    if policy.redact_strings {
+        redact_string_literals(query)
+    } else {
+        query.to_string()
+    }
+}
 
-    save_history(&entries)
+/// Entries left after redacting, deduplicating and capping them as
+/// `policy` says
+fn apply_policy(entries: &[String], policy: &HistoryConfig) -> Vec<String> {
+    let prepared: Vec<String> = if policy.redact_strings {
+        entries
+            .iter()
+            .map(|entry| prepare_entry(entry, policy))
+            .collect()
+    } else {
+        entries.to_vec()
+    };
+    let unique = match policy.dedupe {
+        HistoryDedupe::Global => deduplicate(&prepared),
+        HistoryDedupe::Consecutive => deduplicate_consecutive(&prepared),
+        HistoryDedupe::None => prepared,
+    };
+    trim_to_max(&unique, policy.max_entries)
 }
 
 /// Removes duplicate entries, keeping the first occurrence of each.
 fn deduplicate(entries: &[String]) -> Vec<String> {
-    let mut seen = std::collections::HashSet::new();
+    let mut seen = HashSet::new();
     entries
         .iter()
         .filter(|e| seen.insert(e.as_str()))
@@ -77,9 +263,35 @@ fn deduplicate(entries: &[String]) -> Vec<String> {
         .collect()
 }
 
+/// Removes entries repeating the one right before them.
+fn deduplicate_consecutive(entries: &[String]) -> Vec<String> {
+    let mut unique = entries.to_vec();
+    unique.dedup();
+    unique
+}
+
 /// Trims the entries to the maximum allowed size.
-fn trim_to_max(entries: &[String]) -> Vec<String> {
-    entries.iter().take(MAX_HISTORY_ENTRIES).cloned().collect()
+fn trim_to_max(entries: &[String], max_entries: usize) -> Vec<String> {
+    entries.iter().take(max_entries).cloned().collect()
+}
+
+fn file_content(entries: &[String]) -> String {
+    entries.iter().map(|entry| format!("{}\n", entry)).collect()
+}
+
+fn max_file_bytes(policy: &HistoryConfig) -> usize {
+    (policy.max_file_kb as usize).saturating_mul(1024)
+}
+
+fn exceeds_size(content: &str, policy: &HistoryConfig) -> bool {
+    policy.max_file_kb > 0 && content.len() > max_file_bytes(policy)
+}
+
+/// Where a rotated history file is kept: `history` → `history.1`
+fn rotated_path(path: &Path) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(".1");
+    path.with_file_name(name)
 }
 
 #[cfg(test)]
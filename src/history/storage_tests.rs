@@ -1,6 +1,20 @@
 //! Tests for history/storage
 
 use super::*;
+use tempfile::TempDir;
+
+fn entries(texts: &[&str]) -> Vec<String> {
+    texts.iter().map(|t| t.to_string()).collect()
+}
+
+fn policy(dedupe: HistoryDedupe, max_entries: usize, max_file_kb: u64) -> HistoryConfig {
+    HistoryConfig {
+        dedupe,
+        max_entries,
+        max_file_kb,
+        ..HistoryConfig::default()
+    }
+}
 
 #[test]
 fn test_deduplicate_keeps_first_occurrence() {
@@ -18,7 +32,187 @@ fn test_deduplicate_keeps_first_occurrence() {
 #[test]
 fn test_trim_to_max() {
     let entries: Vec<String> = (0..1500).map(|i| format!("entry{}", i)).collect();
-    let trimmed = trim_to_max(&entries);
-    assert_eq!(trimmed.len(), MAX_HISTORY_ENTRIES);
+    let trimmed = trim_to_max(&entries, 1000);
+    assert_eq!(trimmed.len(), 1000);
     assert_eq!(trimmed[0], "entry0");
 }
+
+#[test]
+fn test_source_histories_are_kept_apart() {
+    let shared = history_path(None).unwrap();
+    let pods = history_path(Some("k8s-pods")).unwrap();
+    assert!(shared.ends_with("jiq/history"));
+    assert!(pods.ends_with("jiq/history.d/k8s-pods"));
+}
+
+#[test]
+fn test_source_file_name_is_sanitized() {
+    assert_eq!(
+        file_name("aws-ec2-describe-instances"),
+        "aws-ec2-describe-instances"
+    );
+    assert_eq!(file_name("k8s-../secrets"), "k8s-.._secrets");
+    assert_eq!(file_name("../x"), "_x");
+    assert_eq!(file_name("k8s-pods.v1/x y"), "k8s-pods.v1_x_y");
+}
+
+#[test]
+fn test_push_entry_follows_dedupe_policy() {
+    let mut global = entries(&[".b", ".a"]);
+    push_entry(&mut global, ".a", HistoryDedupe::Global);
+    assert_eq!(global, entries(&[".a", ".b"]));
+
+    let mut consecutive = entries(&[".b", ".a"]);
+    push_entry(&mut consecutive, ".a", HistoryDedupe::Consecutive);
+    push_entry(&mut consecutive, ".a", HistoryDedupe::Consecutive);
+    assert_eq!(consecutive, entries(&[".a", ".b", ".a"]));
+
+    let mut none = entries(&[".a"]);
+    push_entry(&mut none, ".a", HistoryDedupe::None);
+    assert_eq!(none, entries(&[".a", ".a"]));
+}
+
+#[test]
+fn test_apply_policy_dedupes_and_caps() {
+    let all = entries(&[".a", ".a", ".b", ".a", ".c"]);
+    assert_eq!(
+        apply_policy(&all, &policy(HistoryDedupe::Global, 10, 0)),
+        entries(&[".a", ".b", ".c"])
+    );
+    assert_eq!(
+        apply_policy(&all, &policy(HistoryDedupe::Consecutive, 10, 0)),
+        entries(&[".a", ".b", ".a", ".c"])
+    );
+    assert_eq!(
+        apply_policy(&all, &policy(HistoryDedupe::None, 2, 0)),
+        entries(&[".a", ".a"])
+    );
+}
+
+#[test]
+fn test_save_rotates_oversized_file() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("history");
+    // 200 entries of 20 bytes each (with the newline) pass a 2 KiB limit
+    let all: Vec<String> = (0..200)
+        .map(|i| format!(".entry_number_{:05}", i))
+        .collect();
+
+    let kept = save_history_to(&path, &all, &policy(HistoryDedupe::Global, 1000, 2)).unwrap();
+
+    let rotated = fs::read_to_string(dir.path().join("history.1")).unwrap();
+    assert_eq!(kept.len(), 51);
+    assert_eq!(kept[0], ".entry_number_00000");
+    assert_eq!(fs::read_to_string(&path).unwrap(), file_content(&kept));
+    assert_eq!(rotated, file_content(&all[51..]));
+}
+
+#[test]
+fn test_rotation_keeps_newest_entry_past_the_budget() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("history");
+    let huge = format!(".\"{}\"", "x".repeat(3000));
+    let all = vec![huge.clone(), ".older".to_string()];
+
+    let kept = save_history_to(&path, &all, &policy(HistoryDedupe::Global, 1000, 2)).unwrap();
+
+    assert_eq!(kept, vec![huge]);
+    assert_eq!(fs::read_to_string(&path).unwrap(), file_content(&kept));
+    let rotated = fs::read_to_string(dir.path().join("history.1")).unwrap();
+    assert_eq!(rotated, ".older\n");
+}
+
+#[test]
+fn test_zero_size_limit_never_rotates() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("history");
+    let all: Vec<String> = (0..200)
+        .map(|i| format!(".entry_number_{:05}", i))
+        .collect();
+
+    save_history_to(&path, &all, &policy(HistoryDedupe::Global, 1000, 0)).unwrap();
+
+    assert!(!dir.path().join("history.1").exists());
+    assert_eq!(fs::read_to_string(&path).unwrap().lines().count(), 200);
+}
+
+#[test]
+fn test_load_migrates_file_breaking_policy() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("history");
+    fs::write(&path, ".a\n.b\n.a\n.c\n.d\n").unwrap();
+
+    let loaded = load_history_from(&path, &policy(HistoryDedupe::Global, 3, 0));
+
+    assert_eq!(loaded, entries(&[".a", ".b", ".c"]));
+    assert_eq!(fs::read_to_string(&path).unwrap(), ".a\n.b\n.c\n");
+}
+
+#[test]
+fn test_load_leaves_file_within_policy_alone() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("history");
+    fs::write(&path, ".a\n.a\n").unwrap();
+
+    let loaded = load_history_from(&path, &policy(HistoryDedupe::None, 10, 0));
+
+    assert_eq!(loaded, entries(&[".a", ".a"]));
+    assert_eq!(fs::read_to_string(&path).unwrap(), ".a\n.a\n");
+}
+
+#[test]
+fn test_redacting_policy_masks_new_and_existing_entries() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("history");
+    fs::write(&path, "select(.id == \"a\")\nselect(.id == \"b\")\n").unwrap();
+    let policy = HistoryConfig {
+        redact_strings: true,
+        ..HistoryConfig::default()
+    };
+
+    let loaded = load_history_from(&path, &policy);
+
+    assert_eq!(loaded, entries(&["select(.id == \"***\")"]));
+    assert_eq!(prepare_entry(" has(\"email\") ", &policy), "has(\"***\")");
+}
+
+#[cfg(unix)]
+#[test]
+fn test_private_policy_restricts_permissions() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("history");
+    fs::write(&path, ".a\n").unwrap();
+    fs::set_permissions(&path, fs::Permissions::from_mode(0o644)).unwrap();
+    let policy = HistoryConfig {
+        private: true,
+        ..HistoryConfig::default()
+    };
+
+    load_history_from(&path, &policy);
+    let mode = fs::metadata(&path).unwrap().permissions().mode();
+    assert_eq!(mode & 0o777, 0o600);
+
+    let new_path = dir.path().join("history.d").join("k8s");
+    save_history_to(&new_path, &entries(&[".b"]), &policy).unwrap();
+    let mode = fs::metadata(&new_path).unwrap().permissions().mode();
+    assert_eq!(mode & 0o777, 0o600);
+}
+
+#[test]
+fn test_import_appends_older_entries_without_duplicates() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("history");
+    fs::write(&path, ".a\n.b\n").unwrap();
+
+    let added = import_entries_to(
+        &path,
+        &entries(&[".c", ".a", " ", ".d"]),
+        &HistoryConfig::default(),
+    )
+    .unwrap();
+
+    assert_eq!(added, 2);
+    assert_eq!(fs::read_to_string(&path).unwrap(), ".a\n.b\n.c\n.d\n");
+}
@@ -0,0 +1,19 @@
+//! Writing the result back to the input file
+//!
+//! With `--in-place`, or the exit menu's "Write the results back to the
+//! input file", accepting the query shows a diff of the input file against
+//! the result. Confirming quits and replaces the file with the result
+//! through a temporary file and a rename, like `sponge`, so jiq works as an
+//! interactive editor for JSON config files.
+
+mod in_place_diff;
+mod in_place_events;
+mod in_place_file;
+mod in_place_render;
+mod in_place_state;
+
+pub use in_place_diff::{LineDiff, diff_lines};
+pub use in_place_events::{handle_in_place_key, open_in_place_preview};
+pub use in_place_file::write_atomically;
+pub use in_place_render::{diff_text, render_in_place_preview};
+pub use in_place_state::InPlaceState;
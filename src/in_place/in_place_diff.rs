@@ -0,0 +1,155 @@
+//! Line diff between the input file and the result about to replace it
+
+/// Unchanged lines shown around each change
+const CONTEXT: usize = 3;
+
+/// Largest old × new line product diffed line by line; past it the changed
+/// middle is shown as removed and then added
+const MAX_CELLS: usize = 4_000_000;
+
+/// One row of the preview
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DiffLine {
+    Same(String),
+    Removed(String),
+    Added(String),
+    /// Unchanged lines left out between changes
+    Skipped(usize),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LineDiff {
+    pub lines: Vec<DiffLine>,
+    pub removed: usize,
+    pub added: usize,
+}
+
+impl LineDiff {
+    pub fn is_empty(&self) -> bool {
+        self.removed == 0 && self.added == 0
+    }
+}
+
+/// Diff `old` and `new` by line, keeping a few unchanged lines around each
+/// change
+pub fn diff_lines(old: &str, new: &str) -> LineDiff {
+    let old: Vec<&str> = old.lines().collect();
+    let new: Vec<&str> = new.lines().collect();
+
+    let prefix = old.iter().zip(&new).take_while(|(a, b)| a == b).count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let old_middle = &old[prefix..old.len() - suffix];
+    let new_middle = &new[prefix..new.len() - suffix];
+
+    let mut full: Vec<DiffLine> = old[..prefix]
+        .iter()
+        .map(|line| DiffLine::Same(line.to_string()))
+        .collect();
+    full.extend(diff_middle(old_middle, new_middle));
+    full.extend(
+        old[old.len() - suffix..]
+            .iter()
+            .map(|line| DiffLine::Same(line.to_string())),
+    );
+
+    let removed = full
+        .iter()
+        .filter(|line| matches!(line, DiffLine::Removed(_)))
+        .count();
+    let added = full
+        .iter()
+        .filter(|line| matches!(line, DiffLine::Added(_)))
+        .count();
+    LineDiff {
+        lines: trim_context(full),
+        removed,
+        added,
+    }
+}
+
+/// Longest common subsequence of the changed middle, when small enough
+fn diff_middle(old: &[&str], new: &[&str]) -> Vec<DiffLine> {
+    if old.len().saturating_mul(new.len()) > MAX_CELLS {
+        return old
+            .iter()
+            .map(|line| DiffLine::Removed(line.to_string()))
+            .chain(new.iter().map(|line| DiffLine::Added(line.to_string())))
+            .collect();
+    }
+
+    // common[i][j]: length of the LCS of old[i..] and new[j..]
+    let width = new.len() + 1;
+    let mut common = vec![0u32; (old.len() + 1) * width];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            common[i * width + j] = if old[i] == new[j] {
+                common[(i + 1) * width + j + 1] + 1
+            } else {
+                common[(i + 1) * width + j].max(common[i * width + j + 1])
+            };
+        }
+    }
+
+    let mut lines = Vec::with_capacity(old.len() + new.len());
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            lines.push(DiffLine::Same(old[i].to_string()));
+            i += 1;
+            j += 1;
+        } else if i < old.len()
+            && (j == new.len() || common[(i + 1) * width + j] >= common[i * width + j + 1])
+        {
+            lines.push(DiffLine::Removed(old[i].to_string()));
+            i += 1;
+        } else {
+            lines.push(DiffLine::Added(new[j].to_string()));
+            j += 1;
+        }
+    }
+    lines
+}
+
+/// Replace runs of unchanged lines further than [`CONTEXT`] from a change
+/// with one [`DiffLine::Skipped`]
+fn trim_context(full: Vec<DiffLine>) -> Vec<DiffLine> {
+    let changed: Vec<usize> = full
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| !matches!(line, DiffLine::Same(_)))
+        .map(|(index, _)| index)
+        .collect();
+    let near_change = |index: usize| {
+        let next = changed.partition_point(|&changed| changed < index);
+        let after = changed.get(next).is_some_and(|&c| c - index <= CONTEXT);
+        let before = next > 0 && index - changed[next - 1] <= CONTEXT;
+        after || before
+    };
+
+    let mut lines = Vec::new();
+    let mut skipped = 0;
+    for (index, line) in full.into_iter().enumerate() {
+        if matches!(line, DiffLine::Same(_)) && !near_change(index) {
+            skipped += 1;
+            continue;
+        }
+        if skipped > 0 {
+            lines.push(DiffLine::Skipped(skipped));
+            skipped = 0;
+        }
+        lines.push(line);
+    }
+    if skipped > 0 {
+        lines.push(DiffLine::Skipped(skipped));
+    }
+    lines
+}
+
+#[cfg(test)]
+#[path = "in_place_diff_tests.rs"]
+mod in_place_diff_tests;
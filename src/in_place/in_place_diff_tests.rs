@@ -0,0 +1,72 @@
+//! Tests for in_place_diff
+
+use super::*;
+
+fn same(text: &str) -> DiffLine {
+    DiffLine::Same(text.to_string())
+}
+
+fn removed(text: &str) -> DiffLine {
+    DiffLine::Removed(text.to_string())
+}
+
+fn added(text: &str) -> DiffLine {
+    DiffLine::Added(text.to_string())
+}
+
+#[test]
+fn test_identical_texts_have_no_changes() {
+    let diff = diff_lines("{\n  \"a\": 1\n}", "{\n  \"a\": 1\n}");
+    assert!(diff.is_empty());
+    assert_eq!(diff.lines, vec![DiffLine::Skipped(3)]);
+}
+
+#[test]
+fn test_changed_line_shows_removed_then_added() {
+    let diff = diff_lines("{\n  \"a\": 1\n}", "{\n  \"a\": 2\n}");
+
+    assert_eq!(
+        diff.lines,
+        vec![
+            same("{"),
+            removed("  \"a\": 1"),
+            added("  \"a\": 2"),
+            same("}")
+        ]
+    );
+    assert_eq!((diff.removed, diff.added), (1, 1));
+}
+
+#[test]
+fn test_insertions_between_unchanged_lines() {
+    let old = "a\nb\nc\nd";
+    let new = "a\nb\nx\nc\ny\nd";
+
+    let diff = diff_lines(old, new);
+
+    assert_eq!(
+        diff.lines,
+        vec![
+            same("a"),
+            same("b"),
+            added("x"),
+            same("c"),
+            added("y"),
+            same("d")
+        ]
+    );
+    assert_eq!((diff.removed, diff.added), (0, 2));
+}
+
+#[test]
+fn test_unchanged_lines_far_from_changes_are_skipped() {
+    let old: Vec<String> = (0..20).map(|i| format!("line {}", i)).collect();
+    let mut new = old.clone();
+    new[10] = "changed".to_string();
+
+    let diff = diff_lines(&old.join("\n"), &new.join("\n"));
+
+    assert_eq!(diff.lines.first(), Some(&DiffLine::Skipped(7)));
+    assert_eq!(diff.lines.last(), Some(&DiffLine::Skipped(6)));
+    assert_eq!(diff.lines.len(), 1 + 3 + 2 + 3 + 1);
+}
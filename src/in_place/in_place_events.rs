@@ -0,0 +1,89 @@
+use ratatui::crossterm::event::{KeyCode, KeyEvent};
+
+use super::in_place_diff::diff_lines;
+use crate::app::{App, OutputMode};
+use crate::exit_prompt::exit_menu_state::ExitAction;
+use crate::input::input_format::InputFormat;
+use crate::scroll::handle_scroll_key;
+
+/// Show what writing the result back to the input file changes, to confirm
+/// before quitting
+pub fn open_in_place_preview(app: &mut App) {
+    let Some(path) = app.input_path.clone() else {
+        app.notification
+            .show_warning("Writing back needs an input file, not stdin");
+        return;
+    };
+    let is_json = app
+        .input_info
+        .as_ref()
+        .is_some_and(|info| info.format == InputFormat::Json);
+    if !is_json {
+        app.notification
+            .show_warning("Only JSON input files can be written back");
+        return;
+    }
+
+    if !crate::editor::editor_events::settle_query(app) {
+        return;
+    }
+    let Some(query) = &app.query else {
+        app.notification.show_warning("The input is still loading");
+        return;
+    };
+    // A null-only result keeps showing the last successful output, which is
+    // not what the query produces now
+    let result = match (&query.result, query.last_successful_result_text()) {
+        (Ok(_), Some(result)) if !query.is_empty_result && !result.trim().is_empty() => result,
+        _ => {
+            app.notification
+                .show_warning("The query has no result to write back");
+            return;
+        }
+    };
+
+    let diff = diff_lines(query.executor.json_input(), &result);
+    if diff.is_empty() {
+        app.notification
+            .show("The result is the same as the input file");
+        return;
+    }
+    app.exit_menu.close();
+    app.autocomplete.hide();
+    app.in_place.open(path, diff, result);
+}
+
+pub fn handle_in_place_key(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Char('y') | KeyCode::Enter => confirm(app),
+        KeyCode::Esc | KeyCode::Char('n') | KeyCode::Char('q') => app.in_place.close(),
+        _ => {
+            handle_scroll_key(&mut app.in_place.scroll, key);
+        }
+    }
+}
+
+/// Quit, writing the previewed result over the input file once the terminal
+/// is back
+fn confirm(app: &mut App) {
+    let Some((path, result)) = app.in_place.take() else {
+        return;
+    };
+    if let Some(query) = &app.query
+        && query.result.is_ok()
+        && !app.query().is_empty()
+    {
+        let query_str = app.query().to_string();
+        app.history.add_entry(&query_str);
+    }
+    // Preselected in the exit menu next time, where the menu is used
+    if app.exit_menu.enabled {
+        app.exit_menu.chosen = Some(ExitAction::InPlace);
+    }
+    app.output_mode = Some(OutputMode::InPlace(path, result));
+    app.should_quit = true;
+}
+
+#[cfg(test)]
+#[path = "in_place_events_tests.rs"]
+mod in_place_events_tests;
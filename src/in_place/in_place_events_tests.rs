@@ -0,0 +1,145 @@
+//! Tests for in_place_events
+
+use std::path::PathBuf;
+
+use super::*;
+use crate::editor::EditorMode;
+use crate::test_utils::test_helpers::{app_with_result, key, wait_for_query_completion};
+
+fn in_place_app(query: &str) -> App {
+    let mut app = app_with_result("{\n  \"name\": \"api\",\n  \"replicas\": 1\n}", query);
+    app.input_path = Some(PathBuf::from("config.json"));
+    app.in_place_mode = true;
+    app
+}
+
+#[test]
+fn test_enter_opens_preview_of_the_change() {
+    let mut app = in_place_app(".replicas = 3");
+
+    app.handle_key_event(key(KeyCode::Enter));
+
+    assert!(app.in_place.is_visible());
+    assert!(!app.should_quit);
+    let diff = app.in_place.diff().unwrap();
+    assert_eq!((diff.removed, diff.added), (1, 1));
+}
+
+#[test]
+fn test_confirm_quits_writing_back_to_the_input_file() {
+    let mut app = in_place_app(".replicas = 3");
+    app.handle_key_event(key(KeyCode::Enter));
+
+    app.handle_key_event(key(KeyCode::Char('y')));
+
+    assert!(app.should_quit);
+    let Some(OutputMode::InPlace(path, result)) = &app.output_mode else {
+        panic!("expected in-place output, got {:?}", app.output_mode);
+    };
+    assert_eq!(path, &PathBuf::from("config.json"));
+    assert_eq!(
+        result.as_str(),
+        "{\n  \"name\": \"api\",\n  \"replicas\": 3\n}\n"
+    );
+    assert_eq!(app.exit_menu.chosen, None);
+}
+
+#[test]
+fn test_esc_cancels_without_quitting() {
+    let mut app = in_place_app(".replicas = 3");
+    app.handle_key_event(key(KeyCode::Enter));
+
+    app.handle_key_event(key(KeyCode::Esc));
+
+    assert!(!app.in_place.is_visible());
+    assert!(!app.should_quit);
+}
+
+#[test]
+fn test_unchanged_result_is_not_offered() {
+    let mut app = in_place_app(".");
+
+    open_in_place_preview(&mut app);
+
+    assert!(!app.in_place.is_visible());
+    assert_eq!(
+        app.notification.current_message(),
+        Some("The result is the same as the input file")
+    );
+}
+
+#[test]
+fn test_stdin_input_cannot_be_written_back() {
+    let mut app = in_place_app(".replicas = 3");
+    app.input_path = None;
+
+    open_in_place_preview(&mut app);
+
+    assert!(!app.in_place.is_visible());
+}
+
+#[test]
+fn test_query_error_is_not_written_back() {
+    let mut app = in_place_app(".replicas = 3");
+    app.input.textarea.select_all();
+    app.input.textarea.cut();
+    app.input.textarea.insert_str(".[");
+    app.query.as_mut().unwrap().execute(".[");
+    assert!(wait_for_query_completion(&mut app, 2000));
+
+    open_in_place_preview(&mut app);
+
+    assert!(!app.in_place.is_visible());
+}
+
+#[test]
+fn test_null_result_is_not_written_back() {
+    let mut app = in_place_app(".replicas = 3");
+    app.input.textarea.select_all();
+    app.input.textarea.cut();
+    app.input.textarea.insert_str(".missing");
+    app.query.as_mut().unwrap().execute(".missing");
+    assert!(wait_for_query_completion(&mut app, 2000));
+    assert!(app.query.as_ref().unwrap().is_empty_result);
+
+    open_in_place_preview(&mut app);
+
+    assert!(!app.in_place.is_visible());
+    assert_eq!(
+        app.notification.current_message(),
+        Some("The query has no result to write back")
+    );
+}
+
+#[test]
+fn test_confirm_right_after_typing_writes_the_typed_query_result() {
+    let mut app = in_place_app(".replicas = 1");
+    app.input.editor_mode = EditorMode::Insert;
+    app.handle_key_event(key(KeyCode::Char('0')));
+    assert!(app.debouncer.has_pending());
+
+    app.handle_key_event(key(KeyCode::Enter));
+    app.handle_key_event(key(KeyCode::Char('y')));
+
+    let Some(OutputMode::InPlace(_, result)) = &app.output_mode else {
+        panic!("expected in-place output, got {:?}", app.output_mode);
+    };
+    assert_eq!(
+        result.as_str(),
+        "{\n  \"name\": \"api\",\n  \"replicas\": 10\n}\n"
+    );
+}
+
+#[test]
+fn test_query_still_running_is_not_written_back() {
+    let mut app = in_place_app(".replicas = 1");
+    app.query.as_mut().unwrap().execute_async(".replicas = 2");
+
+    open_in_place_preview(&mut app);
+
+    assert!(!app.in_place.is_visible());
+    assert_eq!(
+        app.notification.current_message(),
+        Some("The query is still running")
+    );
+}
@@ -0,0 +1,63 @@
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static NEXT_TEMP_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// Replace the contents of `path` with `contents`, followed by a newline
+///
+/// The new contents are written to a new temporary file next to the file
+/// `path` resolves to, synced, and then renamed over it, so the file is never
+/// left half written and a symlinked `path` stays a symlink. The original
+/// file's permissions are kept.
+pub fn write_atomically(path: &Path, contents: &str) -> io::Result<()> {
+    let target = fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+    let (temp, mut file) = create_temp_file(&target)?;
+    let written = file
+        .write_all(format!("{}\n", contents.trim_end()).as_bytes())
+        .and_then(|()| {
+            if let Ok(metadata) = fs::metadata(&target) {
+                file.set_permissions(metadata.permissions())?;
+            }
+            file.sync_all()
+        })
+        .and_then(|()| fs::rename(&temp, &target));
+    if written.is_err() {
+        let _ = fs::remove_file(&temp);
+    }
+    written
+}
+
+/// Create `dir/.name.{pid}-{n}.jiq-tmp` for `dir/name`, on the same file
+/// system for the rename
+///
+/// The file must not exist yet, so an existing file or symlink under that
+/// name is never written through. It starts out readable by its owner only.
+fn create_temp_file(target: &Path) -> io::Result<(PathBuf, File)> {
+    let name = target
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    loop {
+        let id = NEXT_TEMP_ID.fetch_add(1, Ordering::Relaxed);
+        let temp =
+            target.with_file_name(format!(".{}.{}-{}.jiq-tmp", name, std::process::id(), id));
+        let mut options = OpenOptions::new();
+        options.write(true).create_new(true);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+            options.mode(0o600);
+        }
+        match options.open(&temp) {
+            Ok(file) => return Ok((temp, file)),
+            Err(e) if e.kind() == io::ErrorKind::AlreadyExists => continue,
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "in_place_file_tests.rs"]
+mod in_place_file_tests;
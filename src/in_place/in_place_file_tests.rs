@@ -0,0 +1,77 @@
+//! Tests for in_place_file
+
+use super::*;
+
+#[test]
+fn test_write_atomically_replaces_file_and_leaves_no_temp_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("config.json");
+    fs::write(&path, "{\"a\": 1}\n").unwrap();
+
+    write_atomically(&path, "{\n  \"a\": 2\n}").unwrap();
+
+    assert_eq!(fs::read_to_string(&path).unwrap(), "{\n  \"a\": 2\n}\n");
+    let entries: Vec<_> = fs::read_dir(dir.path()).unwrap().collect();
+    assert_eq!(entries.len(), 1);
+}
+
+#[cfg(unix)]
+#[test]
+fn test_write_atomically_keeps_permissions() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("secret.json");
+    fs::write(&path, "{}").unwrap();
+    fs::set_permissions(&path, fs::Permissions::from_mode(0o600)).unwrap();
+
+    write_atomically(&path, "[]").unwrap();
+
+    let mode = fs::metadata(&path).unwrap().permissions().mode();
+    assert_eq!(mode & 0o777, 0o600);
+}
+
+#[test]
+fn test_write_into_missing_directory_fails() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("missing").join("config.json");
+
+    assert!(write_atomically(&path, "{}").is_err());
+}
+
+#[cfg(unix)]
+#[test]
+fn test_write_atomically_through_symlink_keeps_the_link() {
+    let dir = tempfile::tempdir().unwrap();
+    let real = dir.path().join("real.json");
+    let link = dir.path().join("link.json");
+    fs::write(&real, "{}").unwrap();
+    std::os::unix::fs::symlink(&real, &link).unwrap();
+
+    write_atomically(&link, "[]").unwrap();
+
+    assert!(
+        fs::symlink_metadata(&link)
+            .unwrap()
+            .file_type()
+            .is_symlink()
+    );
+    assert_eq!(fs::read_to_string(&real).unwrap(), "[]\n");
+}
+
+#[cfg(unix)]
+#[test]
+fn test_write_atomically_does_not_follow_a_planted_temp_symlink() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("config.json");
+    let victim = dir.path().join("victim");
+    fs::write(&path, "{}").unwrap();
+    fs::write(&victim, "untouched").unwrap();
+    // The name the temporary file used to have
+    std::os::unix::fs::symlink(&victim, dir.path().join(".config.json.jiq-tmp")).unwrap();
+
+    write_atomically(&path, "[]").unwrap();
+
+    assert_eq!(fs::read_to_string(&path).unwrap(), "[]\n");
+    assert_eq!(fs::read_to_string(&victim).unwrap(), "untouched");
+}
@@ -0,0 +1,109 @@
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::Style,
+    text::{Line, Span, Text},
+    widgets::{Block, BorderType, Borders, Paragraph},
+};
+
+use super::in_place_diff::{DiffLine, LineDiff};
+use crate::app::App;
+use crate::theme;
+use crate::widgets::text_width::truncate_to_width;
+use crate::widgets::{popup, scrollbar};
+
+fn build_hints() -> Line<'static> {
+    theme::border_hints::build_hints(
+        &[("y/Enter", "Write"), ("j/k", "Scroll"), ("Esc", "Cancel")],
+        theme::in_place::BORDER,
+    )
+}
+
+/// One line per diff row, `-` and `+` marking removed and added lines
+pub fn diff_text(diff: &LineDiff, width: usize) -> Vec<Line<'static>> {
+    let row = |marker: &str, text: &str, color| {
+        Line::from(Span::styled(
+            truncate_to_width(&format!("{} {}", marker, text), width),
+            Style::default().fg(color),
+        ))
+    };
+    diff.lines
+        .iter()
+        .map(|line| match line {
+            DiffLine::Same(text) => row(" ", text, theme::in_place::SAME),
+            DiffLine::Removed(text) => row("-", text, theme::in_place::REMOVED),
+            DiffLine::Added(text) => row("+", text, theme::in_place::ADDED),
+            DiffLine::Skipped(count) => row(
+                "⋯",
+                &format!("{} unchanged lines", count),
+                theme::in_place::NOTE,
+            ),
+        })
+        .collect()
+}
+
+/// Render the preview of the change written to the input file on confirm
+pub fn render_in_place_preview(app: &mut App, frame: &mut Frame) -> Option<Rect> {
+    let frame_area = frame.area();
+    if frame_area.width < 30 || frame_area.height < 8 {
+        return None;
+    }
+    let (path, diff) = app.in_place.path().zip(app.in_place.diff())?;
+
+    let popup_width = ((frame_area.width as f32 * 0.8) as u16)
+        .clamp(50, 120)
+        .min(frame_area.width.saturating_sub(4));
+    let popup_height = ((frame_area.height as f32 * 0.8) as u16)
+        .clamp(8, 40)
+        .min(frame_area.height.saturating_sub(2));
+    let popup_area = popup::centered_popup(frame_area, popup_width, popup_height);
+    popup::clear_area(frame, popup_area);
+
+    let name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let title = format!(
+        " Write back to {}? −{} +{} lines ",
+        name, diff.removed, diff.added
+    );
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(Line::from(Span::styled(title, theme::in_place::TITLE)))
+        .title_bottom(build_hints().centered())
+        .border_style(Style::default().fg(theme::in_place::BORDER))
+        .style(Style::default().bg(theme::in_place::BACKGROUND));
+    let content_area = block.inner(popup_area);
+    frame.render_widget(block, popup_area);
+
+    let lines = diff_text(diff, content_area.width as usize);
+    let content_height = lines.len() as u32;
+    let scroll = &mut app.in_place.scroll;
+    scroll.update_bounds(content_height, content_area.height);
+    frame.render_widget(
+        Paragraph::new(Text::from(lines)).scroll((scroll.offset, 0)),
+        content_area,
+    );
+
+    let scrollbar_area = Rect {
+        x: popup_area.x,
+        y: popup_area.y.saturating_add(1),
+        width: popup_area.width,
+        height: popup_area.height.saturating_sub(2),
+    };
+    scrollbar::render_vertical_scrollbar_styled(
+        frame,
+        scrollbar_area,
+        content_height as usize,
+        scroll.viewport_height as usize,
+        scroll.offset as usize,
+        theme::in_place::BORDER,
+    );
+
+    Some(popup_area)
+}
+
+#[cfg(test)]
+#[path = "in_place_render_tests.rs"]
+mod in_place_render_tests;
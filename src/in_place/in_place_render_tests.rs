@@ -0,0 +1,35 @@
+//! Tests for in_place_render
+
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use super::*;
+use crate::in_place::in_place_diff::diff_lines;
+use crate::test_utils::test_helpers::test_app;
+use insta::assert_snapshot;
+use ratatui::Terminal;
+use ratatui::backend::TestBackend;
+
+fn render_to_string(app: &mut App, width: u16, height: u16) -> String {
+    let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+    terminal
+        .draw(|f| {
+            render_in_place_preview(app, f);
+        })
+        .unwrap();
+    terminal.backend().to_string()
+}
+
+#[test]
+fn snapshot_in_place_preview() {
+    let mut app = test_app("{}");
+    let old = "{\n  \"name\": \"api\",\n  \"replicas\": 1,\n  \"port\": 80\n}";
+    let new = "{\n  \"name\": \"api\",\n  \"replicas\": 3,\n  \"port\": 80\n}";
+    app.in_place.open(
+        PathBuf::from("deploy/config.json"),
+        diff_lines(old, new),
+        Arc::new(new.to_string()),
+    );
+
+    assert_snapshot!(render_to_string(&mut app, 70, 14));
+}
@@ -0,0 +1,42 @@
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use super::in_place_diff::LineDiff;
+use crate::scroll::ScrollState;
+
+/// Preview of the change `--in-place` is about to make to the input file
+#[derive(Debug, Default)]
+pub struct InPlaceState {
+    /// File to write, the diff against it and the text written; None while
+    /// closed
+    preview: Option<(PathBuf, LineDiff, Arc<String>)>,
+    pub scroll: ScrollState,
+}
+
+impl InPlaceState {
+    pub fn is_visible(&self) -> bool {
+        self.preview.is_some()
+    }
+
+    pub fn path(&self) -> Option<&PathBuf> {
+        self.preview.as_ref().map(|(path, _, _)| path)
+    }
+
+    pub fn diff(&self) -> Option<&LineDiff> {
+        self.preview.as_ref().map(|(_, diff, _)| diff)
+    }
+
+    pub fn open(&mut self, path: PathBuf, diff: LineDiff, result: Arc<String>) {
+        self.preview = Some((path, diff, result));
+        self.scroll = ScrollState::new();
+    }
+
+    /// Close the preview, returning the file and the text to write to it
+    pub fn take(&mut self) -> Option<(PathBuf, Arc<String>)> {
+        self.preview.take().map(|(path, _, result)| (path, result))
+    }
+
+    pub fn close(&mut self) {
+        self.preview = None;
+    }
+}
@@ -1,7 +1,13 @@
+pub mod breadcrumbs;
+pub mod command_source;
+pub mod follower;
+pub mod input_format;
+pub mod input_info;
 pub mod input_render;
 mod input_state;
 pub mod loader;
 
+pub use follower::FileFollower;
 pub use input_state::InputState;
 pub use loader::FileLoader;
 
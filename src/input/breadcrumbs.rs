@@ -0,0 +1,231 @@
+//! Breadcrumb bar under the input field
+//!
+//! Splits the path the query starts with into crumbs, e.g. `.data.users[]`
+//! into `.data ▸ users[]`, to keep track of where a long drill-down is.
+//! Clicking a crumb, or `Alt+U` for the one before the last, cuts the query
+//! back to it. Turned off with `breadcrumbs = false` under `[display]`.
+
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::Paragraph,
+};
+
+use crate::app::{App, Focus};
+use crate::query::language::QueryLanguage;
+use crate::theme;
+
+/// Fewest crumbs worth a bar; a single one is just the query
+const MIN_CRUMBS: usize = 2;
+
+const SEPARATOR: &str = " ▸ ";
+
+/// One segment of the path the query starts with
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Crumb {
+    /// Segment as shown, e.g. `.data` for the first and `users[]` after it
+    pub label: String,
+    /// Byte offset in the query where the segment ends
+    pub end: usize,
+}
+
+/// Crumbs of the static path at the start of `query`
+///
+/// The path is made of `.field`, `."field"`, `[...]` with literal contents
+/// and `?`, and ends at anything else, such as a pipe or a function call.
+pub fn crumbs(query: &str) -> Vec<Crumb> {
+    let start = query.len() - query.trim_start().len();
+    let bytes = query.as_bytes();
+    let mut crumbs = Vec::new();
+    let mut crumb_start = start;
+    let mut i = start;
+
+    if bytes.get(i) != Some(&b'.') {
+        return crumbs;
+    }
+
+    while i < bytes.len() {
+        let segment_start = i;
+        match bytes[i] {
+            b'.' if i > crumb_start => {
+                push_crumb(query, crumb_start, i, &mut crumbs);
+                crumb_start = i;
+                continue;
+            }
+            b'.' => {
+                i += 1;
+                match bytes.get(i) {
+                    Some(b'"') => match string_end(query, i) {
+                        Some(end) => i = end,
+                        None => break,
+                    },
+                    Some(c) if c.is_ascii_alphabetic() || *c == b'_' => {
+                        while i < bytes.len()
+                            && (bytes[i].is_ascii_alphanumeric() || bytes[i] == b'_')
+                        {
+                            i += 1;
+                        }
+                    }
+                    Some(b'[') => {}
+                    _ => {
+                        i = segment_start;
+                        break;
+                    }
+                }
+            }
+            b'[' => match literal_bracket_end(query, i) {
+                Some(end) => i = end,
+                None => break,
+            },
+            b'?' => i += 1,
+            _ => break,
+        }
+    }
+    push_crumb(query, crumb_start, i, &mut crumbs);
+    crumbs
+}
+
+fn push_crumb(query: &str, start: usize, end: usize, crumbs: &mut Vec<Crumb>) {
+    let text = &query[start..end];
+    if text.is_empty() || text == "." {
+        return;
+    }
+    let label = if crumbs.is_empty() {
+        text
+    } else {
+        text.strip_prefix('.').unwrap_or(text)
+    };
+    crumbs.push(Crumb {
+        label: label.to_string(),
+        end,
+    });
+}
+
+/// End of the JSON string starting at `start`, past its closing quote
+fn string_end(query: &str, start: usize) -> Option<usize> {
+    let bytes = query.as_bytes();
+    let mut i = start + 1;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' => i += 2,
+            b'"' => return Some(i + 1),
+            _ => i += 1,
+        }
+    }
+    None
+}
+
+/// End of `[...]` at `start` when it only holds numbers, slices or a string
+fn literal_bracket_end(query: &str, start: usize) -> Option<usize> {
+    let bytes = query.as_bytes();
+    let mut i = start + 1;
+    while i < bytes.len() {
+        match bytes[i] {
+            b']' => return Some(i + 1),
+            b'"' => i = string_end(query, i)?,
+            b'0'..=b'9' | b':' | b'-' | b' ' => i += 1,
+            _ => return None,
+        }
+    }
+    None
+}
+
+/// Crumbs to show for the current query; empty when there is no bar
+pub fn visible_crumbs(app: &App) -> Vec<Crumb> {
+    let is_jq = app
+        .query
+        .as_ref()
+        .is_none_or(|query| query.language == QueryLanguage::Jq);
+    if !app.breadcrumbs || !is_jq || app.screen_reader.is_enabled() {
+        return Vec::new();
+    }
+    let crumbs = crumbs(app.query());
+    if crumbs.len() < MIN_CRUMBS {
+        return Vec::new();
+    }
+    crumbs
+}
+
+/// Columns each crumb takes in the bar, from its left edge
+fn crumb_columns(crumbs: &[Crumb]) -> Vec<(u16, u16)> {
+    let separator_width = SEPARATOR.chars().count() as u16;
+    let mut x = 1;
+    crumbs
+        .iter()
+        .map(|crumb| {
+            let width = crumb.label.chars().count() as u16;
+            let columns = (x, width);
+            x += width + separator_width;
+            columns
+        })
+        .collect()
+}
+
+/// Render the crumbs of the query in `area`
+pub fn render_breadcrumbs(crumbs: &[Crumb], frame: &mut Frame, area: Rect) {
+    let last = crumbs.len().saturating_sub(1);
+    let mut spans = vec![Span::raw(" ")];
+    for (index, crumb) in crumbs.iter().enumerate() {
+        if index > 0 {
+            spans.push(Span::styled(
+                SEPARATOR,
+                Style::default().fg(theme::breadcrumbs::SEPARATOR),
+            ));
+        }
+        let style = if index == last {
+            Style::default()
+                .fg(theme::breadcrumbs::CURRENT)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(theme::breadcrumbs::CRUMB)
+        };
+        spans.push(Span::styled(crumb.label.clone(), style));
+    }
+    frame.render_widget(Paragraph::new(Line::from(spans)), area);
+}
+
+/// Cut the query back to the crumb under column `x` of the bar
+pub fn click_breadcrumb(app: &mut App, x: u16) {
+    let Some(bar) = app.layout_regions.breadcrumbs else {
+        return;
+    };
+    let crumbs = visible_crumbs(app);
+    let column = x.saturating_sub(bar.x);
+    let clicked = crumb_columns(&crumbs)
+        .into_iter()
+        .position(|(start, width)| column >= start && column < start + width);
+    if let Some(index) = clicked {
+        truncate_query(app, crumbs[index].end);
+    }
+}
+
+/// Cut the query back to the crumb before where it ends (`Alt+U`)
+///
+/// A query going on past its path, e.g. with a pipe, is first cut back to
+/// the whole path.
+pub fn go_up(app: &mut App) {
+    let query_end = app.query().trim_end().len();
+    let target = crumbs(app.query())
+        .iter()
+        .rev()
+        .map(|crumb| crumb.end)
+        .find(|end| *end < query_end);
+    match target {
+        Some(end) => truncate_query(app, end),
+        None => app
+            .notification
+            .show_warning("Already at the top of the path"),
+    }
+}
+
+fn truncate_query(app: &mut App, end: usize) {
+    let query = app.query()[..end].to_string();
+    app.focus = Focus::InputField;
+    crate::editor::editor_events::replace_query_and_execute(app, &query);
+}
+
+#[cfg(test)]
+#[path = "breadcrumbs_tests.rs"]
+mod breadcrumbs_tests;
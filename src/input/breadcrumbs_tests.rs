@@ -0,0 +1,164 @@
+//! Tests for breadcrumbs
+
+use insta::assert_snapshot;
+use ratatui::Terminal;
+use ratatui::backend::TestBackend;
+use ratatui::crossterm::event::{KeyCode, KeyModifiers};
+
+use super::*;
+use crate::test_utils::test_helpers::{key_with_mods, test_app};
+
+const INPUT: &str = r#"{"data": {"users": [{"profile": {"name": "Ada"}}]}}"#;
+
+fn labels(query: &str) -> Vec<String> {
+    crumbs(query).into_iter().map(|crumb| crumb.label).collect()
+}
+
+fn app_with_query(query: &str) -> App {
+    let mut app = test_app(INPUT);
+    app.input.textarea.insert_str(query);
+    crate::editor::editor_events::execute_query(&mut app);
+    app
+}
+
+fn render(app: &mut App) -> String {
+    let mut terminal = Terminal::new(TestBackend::new(60, 14)).unwrap();
+    terminal.draw(|f| app.render(f)).unwrap();
+    terminal.backend().to_string()
+}
+
+#[test]
+fn test_crumbs_split_fields_and_iterators() {
+    assert_eq!(
+        labels(".data.users[].profile"),
+        vec![".data", "users[]", "profile"]
+    );
+}
+
+#[test]
+fn test_crumb_ends_cut_back_to_each_segment() {
+    let query = ".data.users[].profile";
+    let ends: Vec<&str> = crumbs(query)
+        .iter()
+        .map(|crumb| &query[..crumb.end])
+        .collect();
+    assert_eq!(
+        ends,
+        vec![".data", ".data.users[]", ".data.users[].profile"]
+    );
+}
+
+#[test]
+fn test_crumbs_keep_indexes_quoted_keys_and_optionals() {
+    assert_eq!(
+        labels(r#".items[0]."first name"?.tags[1:3]"#),
+        vec![".items[0]", r#""first name"?"#, "tags[1:3]"]
+    );
+    assert_eq!(labels(".[2].id"), vec![".[2]", "id"]);
+}
+
+#[test]
+fn test_crumbs_stop_at_the_first_non_path_part() {
+    assert_eq!(labels(".data.users | length"), vec![".data", "users"]);
+    assert_eq!(labels(".data.users[.id]"), vec![".data", "users"]);
+    assert_eq!(labels(".data."), vec![".data"]);
+}
+
+#[test]
+fn test_no_crumbs_for_queries_not_starting_with_a_path() {
+    assert!(crumbs("").is_empty());
+    assert!(crumbs(".").is_empty());
+    assert!(crumbs("..").is_empty());
+    assert!(crumbs("keys").is_empty());
+    assert_eq!(crumbs(r#".data."unterminated"#).len(), 1);
+}
+
+#[test]
+fn test_visible_crumbs_need_two_segments() {
+    assert!(visible_crumbs(&app_with_query(".data")).is_empty());
+    assert_eq!(visible_crumbs(&app_with_query(".data.users")).len(), 2);
+}
+
+#[test]
+fn test_visible_crumbs_off_in_config() {
+    let mut app = app_with_query(".data.users");
+    app.breadcrumbs = false;
+
+    assert!(visible_crumbs(&app).is_empty());
+}
+
+#[test]
+fn test_alt_u_cuts_back_one_crumb() {
+    let mut app = app_with_query(".data.users[].profile");
+
+    app.handle_key_event(key_with_mods(KeyCode::Char('u'), KeyModifiers::ALT));
+    assert_eq!(app.query(), ".data.users[]");
+
+    app.handle_key_event(key_with_mods(KeyCode::Char('u'), KeyModifiers::ALT));
+    assert_eq!(app.query(), ".data");
+}
+
+#[test]
+fn test_alt_u_first_drops_what_follows_the_path() {
+    let mut app = app_with_query(".data.users | length");
+
+    go_up(&mut app);
+
+    assert_eq!(app.query(), ".data.users");
+}
+
+#[test]
+fn test_alt_u_at_the_top_warns() {
+    let mut app = app_with_query(".data");
+
+    go_up(&mut app);
+
+    assert_eq!(app.query(), ".data");
+    assert!(
+        app.notification
+            .current_message()
+            .is_some_and(|message| message.contains("top of the path"))
+    );
+}
+
+#[test]
+fn test_click_on_a_crumb_cuts_the_query_back_to_it() {
+    let mut app = app_with_query(".data.users[].profile");
+    app.focus = Focus::ResultsPane;
+    app.layout_regions.breadcrumbs = Some(Rect::new(0, 10, 60, 1));
+
+    // " .data ▸ users[] ▸ profile": users[] starts at column 9
+    click_breadcrumb(&mut app, 10);
+
+    assert_eq!(app.query(), ".data.users[]");
+    assert_eq!(app.focus, Focus::InputField);
+}
+
+#[test]
+fn test_click_on_a_separator_does_nothing() {
+    let mut app = app_with_query(".data.users[].profile");
+    app.layout_regions.breadcrumbs = Some(Rect::new(0, 10, 60, 1));
+
+    click_breadcrumb(&mut app, 7);
+
+    assert_eq!(app.query(), ".data.users[].profile");
+}
+
+#[test]
+fn snapshot_breadcrumb_bar_under_input() {
+    let mut app = app_with_query(".data.users[].profile");
+
+    let output = render(&mut app);
+
+    assert!(app.layout_regions.breadcrumbs.is_some());
+    assert_snapshot!(output);
+}
+
+#[test]
+fn test_no_bar_for_a_single_crumb() {
+    let mut app = app_with_query(".data");
+
+    render(&mut app);
+
+    assert!(app.layout_regions.breadcrumbs.is_none());
+}
@@ -0,0 +1,108 @@
+//! Command Sources
+//!
+//! `jiq k8s` and `jiq aws` run kubectl or the AWS CLI with JSON output and
+//! open what they print, instead of piping it through stdin. Each source has
+//! its own query history, so queries written for pods are offered for pods.
+
+use std::io::ErrorKind;
+use std::process::{Command, Stdio};
+
+use serde::{Deserialize, Serialize};
+
+use super::loader::validate_json_or_jsonl;
+use crate::error::JiqError;
+use crate::shell;
+
+/// External command producing the JSON input
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CommandSource {
+    pub program: String,
+    pub args: Vec<String>,
+    /// Name of the query history kept for this source (e.g. `k8s-pods`)
+    pub history_key: String,
+}
+
+impl CommandSource {
+    /// `kubectl get RESOURCE -o json`, followed by `extra` arguments
+    pub fn kubectl(resource: &str, extra: &[String]) -> Self {
+        let mut args = vec![
+            "get".to_string(),
+            resource.to_string(),
+            "-o".to_string(),
+            "json".to_string(),
+        ];
+        args.extend_from_slice(extra);
+        Self {
+            program: "kubectl".to_string(),
+            args,
+            history_key: format!("k8s-{}", resource.to_ascii_lowercase()),
+        }
+    }
+
+    /// `aws SERVICE OPERATION --output json`, followed by `extra` arguments
+    pub fn aws(service: &str, operation: &str, extra: &[String]) -> Self {
+        let mut args = vec![
+            service.to_string(),
+            operation.to_string(),
+            "--output".to_string(),
+            "json".to_string(),
+        ];
+        args.extend_from_slice(extra);
+        Self {
+            program: "aws".to_string(),
+            args,
+            history_key: format!(
+                "aws-{}-{}",
+                service.to_ascii_lowercase(),
+                operation.to_ascii_lowercase()
+            ),
+        }
+    }
+
+    /// The command as it would be typed in a shell
+    pub fn command_line(&self) -> String {
+        std::iter::once(self.program.as_str())
+            .chain(self.args.iter().map(String::as_str))
+            .map(shell::quote)
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// Run the command and return its JSON output
+    ///
+    /// Fails when the program is missing, exits unsuccessfully (reporting
+    /// what it wrote to stderr) or prints something other than JSON.
+    pub fn run(&self) -> Result<String, JiqError> {
+        let output = Command::new(&self.program)
+            .args(&self.args)
+            .stdin(Stdio::null())
+            .output()
+            .map_err(|e| match e.kind() {
+                ErrorKind::NotFound => {
+                    JiqError::CommandFailed(format!("{} not found in PATH", self.program))
+                }
+                _ => JiqError::CommandFailed(format!("{}: {}", self.command_line(), e)),
+            })?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            let message = match stderr.trim() {
+                "" => output.status.to_string(),
+                stderr => stderr.to_string(),
+            };
+            return Err(JiqError::CommandFailed(format!(
+                "{}: {}",
+                self.command_line(),
+                message
+            )));
+        }
+
+        let stdout = String::from_utf8_lossy(&output.stdout).into_owned();
+        validate_json_or_jsonl(&stdout)?;
+        Ok(stdout)
+    }
+}
+
+#[cfg(test)]
+#[path = "command_source_tests.rs"]
+mod command_source_tests;
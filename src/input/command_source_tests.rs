@@ -0,0 +1,76 @@
+//! Tests for kubectl and AWS CLI input sources
+
+use super::*;
+
+fn shell_source(script: &str) -> CommandSource {
+    CommandSource {
+        program: "sh".to_string(),
+        args: vec!["-c".to_string(), script.to_string()],
+        history_key: "test".to_string(),
+    }
+}
+
+#[test]
+fn test_kubectl_command() {
+    let source = CommandSource::kubectl("Pods", &["-n".to_string(), "kube-system".to_string()]);
+    assert_eq!(
+        source.command_line(),
+        "kubectl get Pods -o json -n kube-system"
+    );
+    assert_eq!(source.history_key, "k8s-pods");
+}
+
+#[test]
+fn test_aws_command() {
+    let source = CommandSource::aws(
+        "ec2",
+        "describe-instances",
+        &[
+            "--filters".to_string(),
+            "Name=tag:env,Values=prod dev".to_string(),
+        ],
+    );
+    assert_eq!(
+        source.command_line(),
+        "aws ec2 describe-instances --output json --filters 'Name=tag:env,Values=prod dev'"
+    );
+    assert_eq!(source.history_key, "aws-ec2-describe-instances");
+}
+
+#[test]
+fn test_run_returns_json_output() {
+    let source = shell_source(r#"echo '{"items": []}'"#);
+    assert_eq!(source.run().unwrap().trim(), r#"{"items": []}"#);
+}
+
+#[test]
+fn test_run_reports_stderr_on_failure() {
+    let source = shell_source("echo 'error: the server could not be reached' >&2; exit 1");
+    let err = source.run().unwrap_err();
+    assert_eq!(
+        err,
+        JiqError::CommandFailed(format!(
+            "{}: error: the server could not be reached",
+            source.command_line()
+        ))
+    );
+}
+
+#[test]
+fn test_run_rejects_non_json_output() {
+    let source = shell_source("echo 'NAME READY STATUS'");
+    assert!(matches!(source.run(), Err(JiqError::InvalidJson(_))));
+}
+
+#[test]
+fn test_run_reports_missing_program() {
+    let source = CommandSource {
+        program: "jiq-test-missing-program".to_string(),
+        args: Vec::new(),
+        history_key: "test".to_string(),
+    };
+    assert_eq!(
+        source.run().unwrap_err(),
+        JiqError::CommandFailed("jiq-test-missing-program not found in PATH".to_string())
+    );
+}
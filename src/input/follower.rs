@@ -0,0 +1,180 @@
+//! File Follower Module
+//!
+//! Watches a growing JSON Lines file in a background thread, like `tail -f`,
+//! and hands newly written records to the UI thread. Only complete lines are
+//! read; a record still being written is picked up once its newline arrives.
+
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{Receiver, Sender, channel};
+use std::time::Duration;
+
+use super::loader::validate_json_or_jsonl;
+
+/// How often the file is checked for new data
+pub const POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Change to the followed file, sent by the background thread
+#[derive(Debug, Clone, PartialEq)]
+enum FollowEvent {
+    /// Newly completed lines holding one or more JSON values
+    Records(String),
+    /// The file shrank; reading restarts from its beginning
+    Truncated,
+    /// Newly completed lines were skipped because they are not valid JSON
+    Invalid(String),
+}
+
+/// What happened to the followed file since the last poll
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct FollowUpdate {
+    /// Records to append to the input, empty while paused
+    pub records: String,
+    /// The file was truncated (e.g. by log rotation)
+    pub truncated: bool,
+    /// Parse error of the last batch of lines that had to be skipped
+    pub error: Option<String>,
+}
+
+/// Follows a file in a background thread
+pub struct FileFollower {
+    rx: Receiver<FollowEvent>,
+    stop: Arc<AtomicBool>,
+    paused: bool,
+    /// Records received while paused, applied on resume
+    pending: String,
+}
+
+impl FileFollower {
+    /// Spawn a background thread following `path` from byte `offset`
+    ///
+    /// `offset` is the length of the content already loaded.
+    pub fn spawn(path: PathBuf, offset: u64) -> Self {
+        let (tx, rx) = channel();
+        let stop = Arc::new(AtomicBool::new(false));
+
+        let thread_stop = Arc::clone(&stop);
+        std::thread::spawn(move || follow_loop(&path, offset, &tx, &thread_stop));
+
+        Self {
+            rx,
+            stop,
+            paused: false,
+            pending: String::new(),
+        }
+    }
+
+    /// Collect changes since the last poll (non-blocking)
+    ///
+    /// Returns None when nothing changed.
+    pub fn poll(&mut self) -> Option<FollowUpdate> {
+        let mut update = FollowUpdate::default();
+        let mut changed = false;
+
+        while let Ok(event) = self.rx.try_recv() {
+            changed = true;
+            match event {
+                FollowEvent::Records(records) => self.pending.push_str(&records),
+                FollowEvent::Truncated => update.truncated = true,
+                FollowEvent::Invalid(error) => update.error = Some(error),
+            }
+        }
+
+        if !self.paused && !self.pending.is_empty() {
+            update.records = std::mem::take(&mut self.pending);
+            changed = true;
+        }
+
+        changed.then_some(update)
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Pause or resume applying new records
+    ///
+    /// Records keep being read while paused and are applied on resume.
+    pub fn toggle_pause(&mut self) {
+        self.paused = !self.paused;
+    }
+
+    /// Number of records held back while paused
+    pub fn pending_count(&self) -> usize {
+        self.pending
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .count()
+    }
+}
+
+impl Drop for FileFollower {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+    }
+}
+
+/// Background loop: check the file size and send whatever was appended
+fn follow_loop(path: &Path, mut offset: u64, tx: &Sender<FollowEvent>, stop: &AtomicBool) {
+    while !stop.load(Ordering::Relaxed) {
+        std::thread::sleep(POLL_INTERVAL);
+
+        // The file may be missing for a moment while it is being rotated
+        let Ok(len) = std::fs::metadata(path).map(|m| m.len()) else {
+            continue;
+        };
+        if len < offset {
+            offset = 0;
+            if tx.send(FollowEvent::Truncated).is_err() {
+                return;
+            }
+        }
+        if len == offset {
+            continue;
+        }
+
+        let Ok((lines, consumed)) = read_complete_lines(path, offset) else {
+            continue;
+        };
+        offset += consumed;
+        if lines.trim().is_empty() {
+            continue;
+        }
+
+        let event = match validate_json_or_jsonl(&lines) {
+            Ok(()) => FollowEvent::Records(lines),
+            Err(e) => FollowEvent::Invalid(e.to_string()),
+        };
+        if tx.send(event).is_err() {
+            return;
+        }
+    }
+}
+
+/// Read the complete lines after `offset`, leaving a trailing partial line
+///
+/// Returns the lines and the number of bytes they span.
+fn read_complete_lines(path: &Path, offset: u64) -> std::io::Result<(String, u64)> {
+    let mut file = File::open(path)?;
+    file.seek(SeekFrom::Start(offset))?;
+    let mut bytes = Vec::new();
+    file.read_to_end(&mut bytes)?;
+
+    let complete = bytes
+        .iter()
+        .rposition(|&b| b == b'\n')
+        .map_or(0, |pos| pos + 1);
+    bytes.truncate(complete);
+
+    Ok((
+        String::from_utf8_lossy(&bytes).into_owned(),
+        complete as u64,
+    ))
+}
+
+#[cfg(test)]
+#[path = "follower_tests.rs"]
+mod follower_tests;
@@ -0,0 +1,109 @@
+//! Tests for following a growing file
+
+use super::*;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::thread;
+use tempfile::TempDir;
+
+fn create_temp_file(content: &str) -> (TempDir, PathBuf) {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("events.jsonl");
+    fs::write(&file_path, content).unwrap();
+    (temp_dir, file_path)
+}
+
+fn append(path: &Path, content: &str) {
+    let mut file = OpenOptions::new().append(true).open(path).unwrap();
+    file.write_all(content.as_bytes()).unwrap();
+}
+
+/// Poll until an update arrives or about two seconds pass
+fn wait_for_update(follower: &mut FileFollower) -> Option<FollowUpdate> {
+    for _ in 0..200 {
+        if let Some(update) = follower.poll() {
+            return Some(update);
+        }
+        thread::sleep(Duration::from_millis(10));
+    }
+    None
+}
+
+#[test]
+fn test_appended_records_are_reported() {
+    let initial = "{\"n\": 1}\n";
+    let (_dir, path) = create_temp_file(initial);
+    let mut follower = FileFollower::spawn(path.clone(), initial.len() as u64);
+
+    append(&path, "{\"n\": 2}\n{\"n\": 3}\n");
+
+    let update = wait_for_update(&mut follower).expect("records should arrive");
+    assert_eq!(update.records, "{\"n\": 2}\n{\"n\": 3}\n");
+    assert!(!update.truncated);
+    assert_eq!(update.error, None);
+}
+
+#[test]
+fn test_partial_line_waits_for_newline() {
+    let (_dir, path) = create_temp_file("");
+    let mut follower = FileFollower::spawn(path.clone(), 0);
+
+    append(&path, "{\"n\": 1}\n{\"n\":");
+    let update = wait_for_update(&mut follower).expect("complete line should arrive");
+    assert_eq!(update.records, "{\"n\": 1}\n");
+
+    append(&path, " 2}\n");
+    let update = wait_for_update(&mut follower).expect("finished line should arrive");
+    assert_eq!(update.records, "{\"n\": 2}\n");
+}
+
+#[test]
+fn test_invalid_lines_are_skipped_with_error() {
+    let (_dir, path) = create_temp_file("");
+    let mut follower = FileFollower::spawn(path.clone(), 0);
+
+    append(&path, "not json\n");
+    let update = wait_for_update(&mut follower).expect("error should arrive");
+    assert!(update.records.is_empty());
+    assert!(update.error.is_some());
+}
+
+#[test]
+fn test_paused_follower_holds_records_until_resumed() {
+    let (_dir, path) = create_temp_file("");
+    let mut follower = FileFollower::spawn(path.clone(), 0);
+    follower.toggle_pause();
+    assert!(follower.is_paused());
+
+    append(&path, "1\n2\n");
+    let update = wait_for_update(&mut follower).expect("poll should report activity");
+    assert!(update.records.is_empty());
+    assert_eq!(follower.pending_count(), 2);
+
+    follower.toggle_pause();
+    let update = follower.poll().expect("held records should be released");
+    assert_eq!(update.records, "1\n2\n");
+    assert_eq!(follower.pending_count(), 0);
+}
+
+#[test]
+fn test_truncated_file_is_read_from_start() {
+    let initial = "1\n2\n3\n";
+    let (_dir, path) = create_temp_file(initial);
+    let mut follower = FileFollower::spawn(path.clone(), initial.len() as u64);
+
+    fs::write(&path, "4\n").unwrap();
+
+    // The truncation and the new content may arrive in separate polls
+    let mut truncated = false;
+    let mut records = String::new();
+    while let Some(update) = wait_for_update(&mut follower) {
+        truncated |= update.truncated;
+        records.push_str(&update.records);
+        if !records.is_empty() {
+            break;
+        }
+    }
+    assert!(truncated);
+    assert_eq!(records, "4\n");
+}
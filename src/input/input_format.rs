@@ -0,0 +1,575 @@
+//! Input Format Detection
+//!
+//! jq only reads JSON, so TOML, INI, CSV and XML inputs (Cargo.toml,
+//! systemd units, spreadsheet exports, pom.xml) are converted to JSON when
+//! loaded. The format is given with `--input-format` or taken from the file
+//! extension when it is a known one, otherwise sniffed from the content:
+//! JSON first, then XML for content starting with `<`, then TOML, then INI.
+//! CSV is never sniffed since almost any text parses as CSV. Input plugins
+//! registered for the file's extension take precedence over all of this.
+
+use std::fmt;
+use std::path::Path;
+use std::sync::Arc;
+
+use clap::ValueEnum;
+use serde::ser::{Error as _, Serialize, SerializeMap, SerializeSeq, Serializer};
+use serde_json::{Map, Value};
+
+use super::loader::validate_json_or_jsonl;
+use crate::error::JiqError;
+use crate::plugins::Plugin;
+
+/// Format of the loaded input
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum InputFormat {
+    Json,
+    Toml,
+    Ini,
+    /// Comma (or `--csv-delimiter`) separated values with a header row
+    Csv,
+    Xml,
+    /// Converted to JSON by an input plugin
+    #[value(skip)]
+    Plugin,
+}
+
+impl InputFormat {
+    pub fn label(self) -> &'static str {
+        match self {
+            InputFormat::Json => "JSON",
+            InputFormat::Toml => "TOML",
+            InputFormat::Ini => "INI",
+            InputFormat::Csv => "CSV",
+            InputFormat::Xml => "XML",
+            InputFormat::Plugin => "Plugin",
+        }
+    }
+
+    /// Format implied by a file extension, if it is a known one
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let extension = path.extension()?.to_str()?.to_ascii_lowercase();
+        match extension.as_str() {
+            "json" | "jsonl" | "ndjson" | "geojson" => Some(InputFormat::Json),
+            "toml" => Some(InputFormat::Toml),
+            // systemd units and desktop entries are INI files too
+            "ini" | "cfg" | "conf" | "service" | "socket" | "timer" | "mount" | "target"
+            | "path" | "desktop" => Some(InputFormat::Ini),
+            "csv" | "tsv" => Some(InputFormat::Csv),
+            "xml" | "xsd" | "xsl" | "xslt" | "svg" | "rss" | "atom" | "wsdl" | "plist"
+            | "csproj" => Some(InputFormat::Xml),
+            _ => None,
+        }
+    }
+}
+
+/// How CSV input is split into fields
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CsvOptions {
+    /// Field separator; defaults to a tab for `.tsv` files and a comma otherwise
+    pub delimiter: Option<u8>,
+    pub quote: u8,
+    /// Convert fields that look like numbers to JSON numbers
+    pub infer_numbers: bool,
+}
+
+impl Default for CsvOptions {
+    fn default() -> Self {
+        Self {
+            delimiter: None,
+            quote: b'"',
+            infer_numbers: false,
+        }
+    }
+}
+
+/// How XML elements map to JSON objects
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct XmlOptions {
+    /// Prepended to attribute names (`id="1"` becomes `"@id": "1"`)
+    pub attribute_prefix: String,
+    /// Key for an element's text when it also has attributes or children
+    pub text_key: String,
+}
+
+impl Default for XmlOptions {
+    fn default() -> Self {
+        Self {
+            attribute_prefix: "@".to_string(),
+            text_key: "#text".to_string(),
+        }
+    }
+}
+
+/// Options controlling how input is read
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct InputOptions {
+    /// Format to read, overriding detection
+    pub format: Option<InputFormat>,
+    pub csv: CsvOptions,
+    pub xml: XmlOptions,
+    /// Plugin converting the input to JSON, replacing the built-in formats
+    pub decoder: Option<InputDecoder>,
+}
+
+/// Input plugin chosen by file extension
+#[derive(Clone)]
+pub struct InputDecoder(pub Arc<dyn Plugin>);
+
+impl fmt::Debug for InputDecoder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("InputDecoder").field(&self.0.name()).finish()
+    }
+}
+
+impl PartialEq for InputDecoder {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.name() == other.0.name()
+    }
+}
+
+impl Eq for InputDecoder {}
+
+/// Convert loaded input to JSON (or JSONL) text for jq
+///
+/// Inputs that are already JSON are returned unchanged after validation.
+/// Also returns the format the input was read as.
+pub fn convert(
+    content: String,
+    path: Option<&Path>,
+    options: &InputOptions,
+) -> Result<(String, InputFormat), JiqError> {
+    if let Some(InputDecoder(plugin)) = &options.decoder {
+        let json = plugin
+            .decode_input(&content)
+            .map_err(JiqError::PluginFailed)?;
+        validate_json_or_jsonl(&json)?;
+        return Ok((json, InputFormat::Plugin));
+    }
+
+    let format = options
+        .format
+        .or_else(|| path.and_then(InputFormat::from_path));
+    let json = match format {
+        // Plugins are never given as a format, only found as a decoder
+        Some(InputFormat::Json | InputFormat::Plugin) => {
+            validate_json_or_jsonl(&content).map(|()| content)
+        }
+        Some(InputFormat::Toml) => toml_to_json(&content).map(render),
+        Some(InputFormat::Ini) => ini_to_json(&content).map(render),
+        Some(InputFormat::Csv) => {
+            let is_tsv = path
+                .and_then(|p| p.extension())
+                .is_some_and(|ext| ext.eq_ignore_ascii_case("tsv"));
+            let default_delimiter = if is_tsv { b'\t' } else { b',' };
+            let delimiter = options.csv.delimiter.unwrap_or(default_delimiter);
+            csv_to_json(&content, delimiter, &options.csv).map(render)
+        }
+        Some(InputFormat::Xml) => xml_to_json(&content, &options.xml).map(render),
+        None => return sniff(content, options),
+    };
+    json.map(|json| (json, format.unwrap_or(InputFormat::Json)))
+}
+
+/// Try each format in turn, reporting the JSON error if none fits
+fn sniff(content: String, options: &InputOptions) -> Result<(String, InputFormat), JiqError> {
+    let json_error = match validate_json_or_jsonl(&content) {
+        Ok(()) => return Ok((content, InputFormat::Json)),
+        Err(e) => e,
+    };
+    if content
+        .trim_start_matches('\u{feff}')
+        .trim_start()
+        .starts_with('<')
+    {
+        return xml_to_json(&content, &options.xml).map(|value| (render(value), InputFormat::Xml));
+    }
+    if looks_like_json(&content) {
+        return Err(json_error);
+    }
+    toml_to_json(&content)
+        .map(|value| (render(value), InputFormat::Toml))
+        .or_else(|_| ini_to_json(&content).map(|value| (render(value), InputFormat::Ini)))
+        .map_err(|_| json_error)
+}
+
+/// True if the first significant line opens a JSON value rather than a
+/// `[section]` header
+fn looks_like_json(content: &str) -> bool {
+    let Some(line) = content
+        .lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty() && !line.starts_with('#') && !line.starts_with(';'))
+    else {
+        return false;
+    };
+    match line.chars().next() {
+        Some('[') => !is_section_header(line),
+        Some(c) => !c.is_alphanumeric() && c != '_',
+        None => false,
+    }
+}
+
+fn is_section_header(line: &str) -> bool {
+    let inner = line.trim_start_matches('[').trim_end_matches(']');
+    line.ends_with(']')
+        && !inner.trim().is_empty()
+        && !inner.contains(['[', ']', '{', '}', ',', '"'])
+}
+
+fn render(value: Value) -> String {
+    serde_json::to_string_pretty(&value).unwrap_or_default()
+}
+
+fn toml_to_json(content: &str) -> Result<Value, JiqError> {
+    let table: toml::Table =
+        toml::from_str(content).map_err(|e| JiqError::InvalidToml(e.message().to_string()))?;
+    Ok(toml_value_to_json(toml::Value::Table(table)))
+}
+
+fn toml_value_to_json(value: toml::Value) -> Value {
+    match value {
+        toml::Value::String(s) => Value::String(s),
+        toml::Value::Integer(i) => Value::from(i),
+        toml::Value::Float(f) => serde_json::Number::from_f64(f)
+            .map(Value::Number)
+            .unwrap_or(Value::Null),
+        toml::Value::Boolean(b) => Value::Bool(b),
+        // JSON has no date type; keep the TOML text form
+        toml::Value::Datetime(d) => Value::String(d.to_string()),
+        toml::Value::Array(items) => {
+            Value::Array(items.into_iter().map(toml_value_to_json).collect())
+        }
+        toml::Value::Table(table) => Value::Object(
+            table
+                .into_iter()
+                .map(|(key, value)| (key, toml_value_to_json(value)))
+                .collect(),
+        ),
+    }
+}
+
+/// Parse a simple INI file into an object of sections
+///
+/// Keys before the first section stay at the top level. Values are kept as
+/// strings; a key repeated within a section (common in systemd units)
+/// becomes an array of its values.
+fn ini_to_json(content: &str) -> Result<Value, JiqError> {
+    let mut root = Map::new();
+    let mut section: Option<String> = None;
+    let mut entries = 0;
+
+    let mut lines = content.lines().enumerate();
+    while let Some((index, raw)) = lines.next() {
+        let error =
+            |message: &str| JiqError::InvalidIni(format!("line {}: {}", index + 1, message));
+
+        // A trailing backslash continues the value on the next line
+        let mut line = raw.trim().to_string();
+        while line.ends_with('\\') {
+            line.pop();
+            match lines.next() {
+                Some((_, next)) => line.push_str(next.trim()),
+                None => break,
+            }
+        }
+
+        if line.is_empty() || line.starts_with(';') || line.starts_with('#') {
+            continue;
+        }
+
+        if line.starts_with('[') {
+            if !is_section_header(&line) {
+                return Err(error("invalid section header"));
+            }
+            let name = line[1..line.len() - 1].trim().to_string();
+            match root
+                .entry(name.clone())
+                .or_insert_with(|| Value::Object(Map::new()))
+            {
+                Value::Object(_) => section = Some(name),
+                _ => return Err(error(&format!("section [{}] clashes with a key", name))),
+            }
+            entries += 1;
+            continue;
+        }
+
+        let Some(separator) = line.find(['=', ':']) else {
+            return Err(error("expected key = value"));
+        };
+        let key = line[..separator].trim();
+        if key.is_empty() {
+            return Err(error("missing key"));
+        }
+        let value = unquote(line[separator + 1..].trim());
+
+        let target = match &section {
+            Some(name) => match root.get_mut(name) {
+                Some(Value::Object(map)) => map,
+                _ => unreachable!("section entries are always objects"),
+            },
+            None => &mut root,
+        };
+        insert_repeated(target, key, Value::String(value));
+        entries += 1;
+    }
+
+    if entries == 0 {
+        return Err(JiqError::InvalidIni("Empty input".to_string()));
+    }
+    Ok(Value::Object(root))
+}
+
+fn unquote(value: &str) -> String {
+    for quote in ['"', '\''] {
+        if value.len() >= 2 && value.starts_with(quote) && value.ends_with(quote) {
+            return value[1..value.len() - 1].to_string();
+        }
+    }
+    value.to_string()
+}
+
+/// Insert `value`, turning a key that is already present into an array
+fn insert_repeated(map: &mut Map<String, Value>, key: &str, value: Value) {
+    match map.get_mut(key) {
+        Some(Value::Array(values)) => values.push(value),
+        Some(existing) => {
+            let first = existing.take();
+            *existing = Value::Array(vec![first, value]);
+        }
+        None => {
+            map.insert(key.to_string(), value);
+        }
+    }
+}
+
+/// Parse CSV into an array of objects keyed by the header row
+///
+/// Empty header cells are named `column_N`, repeated ones get a `_N`
+/// suffix. Short rows leave the missing columns `null`; extra cells are
+/// kept under `column_N` keys.
+fn csv_to_json(content: &str, delimiter: u8, options: &CsvOptions) -> Result<Value, JiqError> {
+    let mut reader = csv::ReaderBuilder::new()
+        .delimiter(delimiter)
+        .quote(options.quote)
+        .flexible(true)
+        .from_reader(content.as_bytes());
+    let csv_error = |e: csv::Error| JiqError::InvalidCsv(e.to_string());
+
+    let headers = csv_headers(reader.headers().map_err(csv_error)?);
+    if headers.is_empty() {
+        return Err(JiqError::InvalidCsv("Empty input".to_string()));
+    }
+
+    let mut rows = Vec::new();
+    for record in reader.records() {
+        let record = record.map_err(csv_error)?;
+        let mut row = Map::new();
+        for (index, header) in headers.iter().enumerate() {
+            let value = match record.get(index) {
+                Some(field) => csv_value(field, options.infer_numbers),
+                None => Value::Null,
+            };
+            row.insert(header.clone(), value);
+        }
+        for (index, field) in record.iter().enumerate().skip(headers.len()) {
+            row.insert(
+                format!("column_{}", index + 1),
+                csv_value(field, options.infer_numbers),
+            );
+        }
+        rows.push(Value::Object(row));
+    }
+    Ok(Value::Array(rows))
+}
+
+fn csv_headers(record: &csv::StringRecord) -> Vec<String> {
+    let mut headers: Vec<String> = Vec::with_capacity(record.len());
+    for (index, cell) in record.iter().enumerate() {
+        let base = match cell.trim() {
+            "" => format!("column_{}", index + 1),
+            name => name.to_string(),
+        };
+        let mut name = base.clone();
+        let mut suffix = 2;
+        while headers.contains(&name) {
+            name = format!("{}_{}", base, suffix);
+            suffix += 1;
+        }
+        headers.push(name);
+    }
+    headers
+}
+
+fn csv_value(field: &str, infer_numbers: bool) -> Value {
+    if infer_numbers && let Some(number) = parse_number(field) {
+        return Value::Number(number);
+    }
+    Value::String(field.to_string())
+}
+
+/// Parse a field as a number unless that would lose information
+///
+/// Values with leading zeros (`007`, zip codes) stay strings. Numbers
+/// written as JSON would write them keep their digits, so 64-bit IDs and
+/// `1.50` come through unchanged.
+fn parse_number(field: &str) -> Option<serde_json::Number> {
+    let digits = field.strip_prefix('-').unwrap_or(field);
+    if digits.len() > 1 && digits.starts_with('0') && !digits.starts_with("0.") {
+        return None;
+    }
+    if let Ok(number) = field.parse::<serde_json::Number>() {
+        return Some(number);
+    }
+    // f64 parsing also accepts "inf" and "NaN", which aren't numbers here
+    let is_numeric = field.chars().any(|c| c.is_ascii_digit())
+        && field
+            .chars()
+            .all(|c| c.is_ascii_digit() || matches!(c, '.' | '-' | '+' | 'e' | 'E'));
+    if !is_numeric {
+        return None;
+    }
+    field
+        .parse::<f64>()
+        .ok()
+        .filter(|f| f.is_finite())
+        .and_then(serde_json::Number::from_f64)
+}
+
+/// Convert an XML document to `{"root": {...}}`
+///
+/// Attributes become prefixed keys and child elements become keys named
+/// after their tag, with repeated tags collected into an array. An element
+/// with only text becomes a string; otherwise its text is stored under the
+/// text key. All values are strings.
+fn xml_to_json(content: &str, options: &XmlOptions) -> Result<Value, JiqError> {
+    let parsing = roxmltree::ParsingOptions {
+        allow_dtd: true,
+        ..roxmltree::ParsingOptions::default()
+    };
+    let document = roxmltree::Document::parse_with_options(content, parsing)
+        .map_err(|e| JiqError::InvalidXml(e.to_string()))?;
+    let root = document.root_element();
+
+    let mut object = Map::new();
+    object.insert(xml_name(root, root.tag_name()), xml_element(root, options));
+    Ok(Value::Object(object))
+}
+
+fn xml_element(node: roxmltree::Node, options: &XmlOptions) -> Value {
+    let mut object = Map::new();
+    for attribute in node.attributes() {
+        let name = match attribute.namespace().and_then(|ns| node.lookup_prefix(ns)) {
+            Some(prefix) => format!("{}:{}", prefix, attribute.name()),
+            None => attribute.name().to_string(),
+        };
+        object.insert(
+            format!("{}{}", options.attribute_prefix, name),
+            Value::String(attribute.value().to_string()),
+        );
+    }
+
+    let mut text = String::new();
+    for child in node.children() {
+        if child.is_element() {
+            let name = xml_name(child, child.tag_name());
+            insert_repeated(&mut object, &name, xml_element(child, options));
+        } else if child.is_text() {
+            text.push_str(child.text().unwrap_or_default());
+        }
+    }
+
+    let text = text.trim();
+    if object.is_empty() {
+        return Value::String(text.to_string());
+    }
+    if !text.is_empty() {
+        insert_repeated(
+            &mut object,
+            &options.text_key,
+            Value::String(text.to_string()),
+        );
+    }
+    Value::Object(object)
+}
+
+/// Element name as written, keeping a namespace prefix (`soap:Body`)
+fn xml_name(node: roxmltree::Node, name: roxmltree::ExpandedName) -> String {
+    match name.namespace().and_then(|ns| node.lookup_prefix(ns)) {
+        Some(prefix) if !prefix.is_empty() => format!("{}:{}", prefix, name.name()),
+        _ => name.name().to_string(),
+    }
+}
+
+/// Serialize jq output back to TOML
+///
+/// Each output value must be an object without nulls, since TOML has no
+/// null and no top-level arrays or scalars. Multiple values are separated
+/// by a blank line.
+pub fn json_to_toml(output: &str) -> Result<String, String> {
+    let mut documents = Vec::new();
+    for value in serde_json::Deserializer::from_str(output).into_iter::<Value>() {
+        let value = value.map_err(|e| e.to_string())?;
+        if !value.is_object() {
+            return Err("TOML output needs an object at the top level".to_string());
+        }
+        if contains_null(&value) {
+            return Err("null values can't be written as TOML".to_string());
+        }
+        let document = toml::to_string_pretty(&TomlValue(&value)).map_err(|e| e.to_string())?;
+        documents.push(document.trim_end().to_string());
+    }
+    Ok(documents.join("\n\n"))
+}
+
+/// A JSON value serialized with TOML's number types
+///
+/// serde_json keeps numbers as their JSON text, which other serializers
+/// would write as a table; integers become TOML integers and the rest
+/// floats.
+struct TomlValue<'a>(&'a Value);
+
+impl Serialize for TomlValue<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.0 {
+            Value::Null => serializer.serialize_unit(),
+            Value::Bool(b) => serializer.serialize_bool(*b),
+            Value::Number(n) => match (n.as_i64(), n.as_f64()) {
+                (Some(integer), _) => serializer.serialize_i64(integer),
+                (None, Some(float)) if n.is_f64() => serializer.serialize_f64(float),
+                _ => Err(S::Error::custom(format!(
+                    "{} doesn't fit in a TOML number",
+                    n
+                ))),
+            },
+            Value::String(s) => serializer.serialize_str(s),
+            Value::Array(items) => {
+                let mut seq = serializer.serialize_seq(Some(items.len()))?;
+                for item in items {
+                    seq.serialize_element(&TomlValue(item))?;
+                }
+                seq.end()
+            }
+            Value::Object(object) => {
+                let mut map = serializer.serialize_map(Some(object.len()))?;
+                for (key, value) in object {
+                    map.serialize_entry(key, &TomlValue(value))?;
+                }
+                map.end()
+            }
+        }
+    }
+}
+
+fn contains_null(value: &Value) -> bool {
+    match value {
+        Value::Null => true,
+        Value::Array(items) => items.iter().any(contains_null),
+        Value::Object(map) => map.values().any(contains_null),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+#[path = "input_format_tests.rs"]
+mod input_format_tests;
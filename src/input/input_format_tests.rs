@@ -0,0 +1,492 @@
+//! Tests for input format detection and conversion
+
+use super::*;
+use std::path::PathBuf;
+
+fn to_json(
+    content: String,
+    path: Option<&Path>,
+    options: &InputOptions,
+) -> Result<String, JiqError> {
+    convert(content, path, options).map(|(json, _)| json)
+}
+
+fn parse(text: &str) -> Value {
+    serde_json::from_str(text).unwrap()
+}
+
+#[test]
+fn test_format_from_extension() {
+    let format = |name: &str| InputFormat::from_path(&PathBuf::from(name));
+    assert_eq!(format("Cargo.toml"), Some(InputFormat::Toml));
+    assert_eq!(format("nginx.service"), Some(InputFormat::Ini));
+    assert_eq!(format("setup.CFG"), Some(InputFormat::Ini));
+    assert_eq!(format("data.json"), Some(InputFormat::Json));
+    assert_eq!(format("pom.xml"), Some(InputFormat::Xml));
+    assert_eq!(format("icon.svg"), Some(InputFormat::Xml));
+    assert_eq!(format("Cargo.lock"), None);
+    assert_eq!(format("README"), None);
+}
+
+#[test]
+fn test_json_is_returned_unchanged() {
+    let json = r#"{"a": 1}"#.to_string();
+    assert_eq!(
+        to_json(json.clone(), None, &InputOptions::default()).unwrap(),
+        json
+    );
+    assert_eq!(
+        to_json(
+            json.clone(),
+            Some(Path::new("x.json")),
+            &InputOptions::default()
+        )
+        .unwrap(),
+        json
+    );
+}
+
+#[test]
+fn test_toml_is_converted() {
+    let toml = r#"
+[package]
+name = "jiq"
+version = "3.20.3"
+edition = 2024
+published = 1979-05-27T07:32:00Z
+
+[[bin]]
+name = "jiq"
+
+[dependencies]
+serde = { version = "1.0", features = ["derive"] }
+"#;
+    let json = to_json(
+        toml.to_string(),
+        Some(Path::new("Cargo.toml")),
+        &InputOptions::default(),
+    )
+    .unwrap();
+    assert_eq!(
+        parse(&json),
+        parse(
+            r#"{
+                "package": {"name": "jiq", "version": "3.20.3", "edition": 2024,
+                            "published": "1979-05-27T07:32:00Z"},
+                "bin": [{"name": "jiq"}],
+                "dependencies": {"serde": {"version": "1.0", "features": ["derive"]}}
+            }"#
+        )
+    );
+}
+
+#[test]
+fn test_invalid_toml_reports_toml_error() {
+    let result = to_json(
+        "name = ".to_string(),
+        Some(Path::new("x.toml")),
+        &InputOptions::default(),
+    );
+    assert!(matches!(result, Err(JiqError::InvalidToml(_))));
+}
+
+#[test]
+fn test_ini_is_converted() {
+    let unit = r#"
+; systemd unit
+[Unit]
+Description=Example service
+After=network.target
+After=postgresql.service
+
+[Service]
+ExecStart=/usr/bin/example \
+    --port 8080
+Environment="MODE=prod"
+"#;
+    let json = to_json(
+        unit.to_string(),
+        Some(Path::new("example.service")),
+        &InputOptions::default(),
+    )
+    .unwrap();
+    assert_eq!(
+        parse(&json),
+        parse(
+            r#"{
+                "Unit": {"Description": "Example service",
+                         "After": ["network.target", "postgresql.service"]},
+                "Service": {"ExecStart": "/usr/bin/example --port 8080",
+                            "Environment": "MODE=prod"}
+            }"#
+        )
+    );
+}
+
+#[test]
+fn test_ini_keys_before_sections_stay_top_level() {
+    let json = to_json(
+        "name: root\n[core]\neditor = vim\n".to_string(),
+        Some(Path::new("x.ini")),
+        &InputOptions::default(),
+    )
+    .unwrap();
+    assert_eq!(
+        parse(&json),
+        parse(r#"{"name": "root", "core": {"editor": "vim"}}"#)
+    );
+}
+
+#[test]
+fn test_invalid_ini_reports_line() {
+    let result = to_json(
+        "[core]\njust some text\n".to_string(),
+        Some(Path::new("x.ini")),
+        &InputOptions::default(),
+    );
+    match result {
+        Err(JiqError::InvalidIni(message)) => assert!(message.starts_with("line 2")),
+        other => panic!("expected INI error, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_sniffs_toml_and_ini_without_extension() {
+    let json = to_json(
+        "[package]\nname = \"x\"\n".to_string(),
+        None,
+        &InputOptions::default(),
+    )
+    .unwrap();
+    assert_eq!(parse(&json), parse(r#"{"package": {"name": "x"}}"#));
+
+    let json = to_json(
+        "[Service]\nExecStart=/bin/true\n".to_string(),
+        None,
+        &InputOptions::default(),
+    )
+    .unwrap();
+    assert_eq!(
+        parse(&json),
+        parse(r#"{"Service": {"ExecStart": "/bin/true"}}"#)
+    );
+}
+
+#[test]
+fn test_convert_reports_format_read() {
+    let format = |content: &str, path: Option<&str>| {
+        convert(
+            content.to_string(),
+            path.map(Path::new),
+            &InputOptions::default(),
+        )
+        .unwrap()
+        .1
+    };
+    assert_eq!(format("{\"a\": 1}", None), InputFormat::Json);
+    assert_eq!(format("<a>1</a>", None), InputFormat::Xml);
+    assert_eq!(format("[package]\nname = \"x\"\n", None), InputFormat::Toml);
+    assert_eq!(
+        format("[Service]\nExecStart=/bin/true\n", None),
+        InputFormat::Ini
+    );
+    assert_eq!(format("a,b\n1,2\n", Some("data.csv")), InputFormat::Csv);
+}
+
+#[test]
+fn test_sniffing_keeps_json_errors_for_broken_json() {
+    for broken in [r#"{"a": 1"#, "[1, 2", r#""text"#, "just words"] {
+        let result = to_json(broken.to_string(), None, &InputOptions::default());
+        assert!(
+            matches!(result, Err(JiqError::InvalidJson(_))),
+            "{:?} gave {:?}",
+            broken,
+            result
+        );
+    }
+}
+
+#[test]
+fn test_json_to_toml() {
+    let toml = json_to_toml(r#"{"package": {"name": "jiq"}, "version": 1}"#).unwrap();
+    assert_eq!(toml, "version = 1\n\n[package]\nname = \"jiq\"");
+}
+
+#[test]
+fn test_json_to_toml_multiple_values() {
+    let toml = json_to_toml("{\"a\": 1}\n{\"a\": 2}").unwrap();
+    assert_eq!(toml, "a = 1\n\na = 2");
+}
+
+#[test]
+fn test_json_to_toml_keeps_key_order_and_number_types() {
+    let toml = json_to_toml(r#"{"zeta": 1.5, "alpha": "a", "id": 9007199254740993}"#).unwrap();
+    assert_eq!(toml, "zeta = 1.5\nalpha = \"a\"\nid = 9007199254740993");
+}
+
+#[test]
+fn test_json_to_toml_rejects_unrepresentable_values() {
+    assert!(json_to_toml("[1, 2]").is_err());
+    assert!(json_to_toml(r#"{"id": 18446744073709551617}"#).is_err());
+    assert!(json_to_toml("\"text\"").is_err());
+    assert!(json_to_toml(r#"{"a": null}"#).is_err());
+}
+
+fn csv_options(infer_numbers: bool) -> InputOptions {
+    InputOptions {
+        format: None,
+        csv: CsvOptions {
+            infer_numbers,
+            ..CsvOptions::default()
+        },
+        ..InputOptions::default()
+    }
+}
+
+#[test]
+fn test_csv_rows_become_objects() {
+    let csv = "name,age,zip\nAlice,30,02134\n\"Smith, Bob\",41.5,10001\n";
+    let json = to_json(
+        csv.to_string(),
+        Some(Path::new("people.csv")),
+        &csv_options(false),
+    )
+    .unwrap();
+    assert_eq!(
+        parse(&json),
+        parse(
+            r#"[
+                {"name": "Alice", "age": "30", "zip": "02134"},
+                {"name": "Smith, Bob", "age": "41.5", "zip": "10001"}
+            ]"#
+        )
+    );
+}
+
+#[test]
+fn test_csv_number_inference_keeps_leading_zeros() {
+    let csv = "name,age,zip,score\nAlice,30,02134,-1.5e3\nBob,,inf,NaN\n";
+    let json = to_json(
+        csv.to_string(),
+        Some(Path::new("people.csv")),
+        &csv_options(true),
+    )
+    .unwrap();
+    assert_eq!(
+        parse(&json),
+        parse(
+            r#"[
+                {"name": "Alice", "age": 30, "zip": "02134", "score": -1.5e3},
+                {"name": "Bob", "age": "", "zip": "inf", "score": "NaN"}
+            ]"#
+        )
+    );
+}
+
+#[test]
+fn test_toml_keeps_key_order() {
+    let json = to_json(
+        "zone = \"eu\"\nid = 7\n\n[server]\nport = 80\nhost = \"a\"\n".to_string(),
+        Some(Path::new("app.toml")),
+        &InputOptions::default(),
+    )
+    .unwrap();
+    let compact: String = json.split_whitespace().collect();
+    assert_eq!(
+        compact,
+        r#"{"zone":"eu","id":7,"server":{"port":80,"host":"a"}}"#
+    );
+}
+
+#[test]
+fn test_csv_keeps_column_order_and_large_ids() {
+    let csv = "zone,id,amount\neu,12345678901234567891,1.50\n";
+    let json = to_json(
+        csv.to_string(),
+        Some(Path::new("orders.csv")),
+        &csv_options(true),
+    )
+    .unwrap();
+    let compact: String = json.split_whitespace().collect();
+    assert_eq!(
+        compact,
+        r#"[{"zone":"eu","id":12345678901234567891,"amount":1.50}]"#
+    );
+}
+
+#[test]
+fn test_csv_headers_are_made_unique() {
+    let csv = "id,,id\n1,2,3,4\n5\n";
+    let json = to_json(
+        csv.to_string(),
+        Some(Path::new("x.csv")),
+        &csv_options(false),
+    )
+    .unwrap();
+    assert_eq!(
+        parse(&json),
+        parse(
+            r#"[
+                {"id": "1", "column_2": "2", "id_2": "3", "column_4": "4"},
+                {"id": "5", "column_2": null, "id_2": null}
+            ]"#
+        )
+    );
+}
+
+#[test]
+fn test_csv_delimiter_and_quote() {
+    let tsv = "a\tb\n1\t2\n";
+    let json = to_json(
+        tsv.to_string(),
+        Some(Path::new("x.tsv")),
+        &csv_options(false),
+    )
+    .unwrap();
+    assert_eq!(parse(&json), parse(r#"[{"a": "1", "b": "2"}]"#));
+
+    let options = InputOptions {
+        format: Some(InputFormat::Csv),
+        csv: CsvOptions {
+            delimiter: Some(b';'),
+            quote: b'\'',
+            infer_numbers: false,
+        },
+        ..InputOptions::default()
+    };
+    let json = to_json("a;b\n'x;y';z\n".to_string(), None, &options).unwrap();
+    assert_eq!(parse(&json), parse(r#"[{"a": "x;y", "b": "z"}]"#));
+}
+
+#[test]
+fn test_csv_is_not_sniffed() {
+    let result = to_json("a,b\n1,2\n".to_string(), None, &InputOptions::default());
+    assert!(matches!(result, Err(JiqError::InvalidJson(_))));
+}
+
+#[test]
+fn test_empty_csv_is_an_error() {
+    let result = to_json(String::new(), Some(Path::new("x.csv")), &csv_options(false));
+    assert!(matches!(result, Err(JiqError::InvalidCsv(_))));
+}
+
+#[test]
+fn test_xml_is_converted() {
+    let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<project xmlns="http://maven.apache.org/POM/4.0.0">
+  <!-- build settings -->
+  <artifactId>demo</artifactId>
+  <dependencies>
+    <dependency scope="test"><artifactId>junit</artifactId></dependency>
+    <dependency><artifactId>guava</artifactId></dependency>
+  </dependencies>
+  <empty/>
+</project>
+"#;
+    let json = to_json(
+        xml.to_string(),
+        Some(Path::new("pom.xml")),
+        &InputOptions::default(),
+    )
+    .unwrap();
+    assert_eq!(
+        parse(&json),
+        parse(
+            r#"{"project": {
+                "artifactId": "demo",
+                "dependencies": {"dependency": [
+                    {"@scope": "test", "artifactId": "junit"},
+                    {"artifactId": "guava"}
+                ]},
+                "empty": ""
+            }}"#
+        )
+    );
+}
+
+#[test]
+fn test_xml_text_next_to_attributes_uses_text_key() {
+    let xml = r#"<price currency="EUR">9.99</price>"#;
+    let json = to_json(xml.to_string(), None, &InputOptions::default()).unwrap();
+    assert_eq!(
+        parse(&json),
+        parse(r##"{"price": {"@currency": "EUR", "#text": "9.99"}}"##)
+    );
+
+    let options = InputOptions {
+        xml: XmlOptions {
+            attribute_prefix: "-".to_string(),
+            text_key: "value".to_string(),
+        },
+        ..InputOptions::default()
+    };
+    let json = to_json(xml.to_string(), None, &options).unwrap();
+    assert_eq!(
+        parse(&json),
+        parse(r#"{"price": {"-currency": "EUR", "value": "9.99"}}"#)
+    );
+}
+
+#[test]
+fn test_xml_keeps_namespace_prefixes() {
+    let xml = r#"<soap:Envelope xmlns:soap="urn:s" xmlns:x="urn:x">
+  <soap:Body x:id="1"><![CDATA[a < b]]></soap:Body>
+</soap:Envelope>"#;
+    let json = to_json(xml.to_string(), None, &InputOptions::default()).unwrap();
+    assert_eq!(
+        parse(&json),
+        parse(r##"{"soap:Envelope": {"soap:Body": {"@x:id": "1", "#text": "a < b"}}}"##)
+    );
+}
+
+#[test]
+fn test_invalid_xml_reports_xml_error() {
+    let result = to_json(
+        "<a><b></a>".to_string(),
+        Some(Path::new("x.xml")),
+        &InputOptions::default(),
+    );
+    assert!(matches!(result, Err(JiqError::InvalidXml(_))));
+
+    let result = to_json("<a><b></a>".to_string(), None, &InputOptions::default());
+    assert!(matches!(result, Err(JiqError::InvalidXml(_))));
+}
+
+#[test]
+fn test_plugin_decoder_is_used_before_format() {
+    use crate::config::PluginConfig;
+    use crate::plugins::ExecPlugin;
+    use std::sync::Arc;
+
+    let decoder = |script: &str| {
+        let plugin = ExecPlugin::from_config(&PluginConfig {
+            name: "upper".to_string(),
+            command: vec!["sh".to_string(), "-c".to_string(), script.to_string()],
+            input_extensions: vec!["up".to_string()],
+            output: false,
+            key: None,
+        })
+        .unwrap();
+        InputOptions {
+            decoder: Some(InputDecoder(Arc::new(plugin))),
+            ..InputOptions::default()
+        }
+    };
+
+    let options = decoder(r#"printf '{"v": "%s"}' "$(tr a-z A-Z)""#);
+    let (json, format) = convert("abc".to_string(), Some(Path::new("x.toml")), &options).unwrap();
+    assert_eq!(parse(&json), parse(r#"{"v": "ABC"}"#));
+    assert_eq!(format, InputFormat::Plugin);
+
+    let options = decoder("echo 'not json'");
+    assert!(matches!(
+        to_json("abc".to_string(), None, &options),
+        Err(JiqError::InvalidJson(_))
+    ));
+
+    let options = decoder("echo 'cannot decode' >&2; exit 1");
+    assert!(matches!(
+        to_json("abc".to_string(), None, &options),
+        Err(JiqError::PluginFailed(message)) if message == "upper: cannot decode"
+    ));
+}
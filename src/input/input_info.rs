@@ -0,0 +1,94 @@
+//! Input Metadata
+//!
+//! Facts about the loaded document shown in the status line: where it came
+//! from, how big it is, the format it was read as and how many JSON values
+//! it holds.
+
+use std::path::Path;
+
+use serde::de::IgnoredAny;
+
+use super::command_source::CommandSource;
+use super::input_format::InputFormat;
+
+/// Metadata of the loaded input
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputInfo {
+    /// File name, command or `stdin`
+    pub source: String,
+    /// Size in bytes
+    pub size: u64,
+    pub format: InputFormat,
+    /// Number of top-level JSON values, e.g. lines of JSON Lines input
+    pub documents: usize,
+}
+
+impl InputInfo {
+    /// Describe input loaded from `input_path` or `command` (stdin if neither)
+    ///
+    /// Files report their size on disk, other input the size of its JSON.
+    pub fn new(
+        input_path: Option<&Path>,
+        command: Option<&CommandSource>,
+        format: InputFormat,
+        json: &str,
+    ) -> Self {
+        let source = match (input_path, command) {
+            (Some(path), _) => path
+                .file_name()
+                .unwrap_or(path.as_os_str())
+                .to_string_lossy()
+                .into_owned(),
+            (None, Some(command)) => std::iter::once(command.program.as_str())
+                .chain(command.args.iter().take(2).map(String::as_str))
+                .collect::<Vec<_>>()
+                .join(" "),
+            (None, None) => "stdin".to_string(),
+        };
+        let size = input_path
+            .and_then(|path| std::fs::metadata(path).ok())
+            .map(|metadata| metadata.len())
+            .unwrap_or(json.len() as u64);
+
+        Self {
+            source,
+            size,
+            format,
+            documents: count_documents(json),
+        }
+    }
+
+    /// Account for records appended to a followed file
+    pub fn add_records(&mut self, records: &str) {
+        self.size += records.len() as u64;
+        self.documents += count_documents(records);
+    }
+}
+
+/// Number of top-level JSON values in `json`
+pub fn count_documents(json: &str) -> usize {
+    serde_json::Deserializer::from_str(json)
+        .into_iter::<IgnoredAny>()
+        .take_while(Result::is_ok)
+        .count()
+}
+
+/// Human readable size, e.g. `512 B` or `1.5 MB`
+pub fn format_size(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KB", "MB", "GB", "TB"];
+
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit + 1 < UNITS.len() {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", size, UNITS[unit])
+}
+
+#[cfg(test)]
+#[path = "input_info_tests.rs"]
+mod input_info_tests;
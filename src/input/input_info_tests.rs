@@ -0,0 +1,67 @@
+//! Tests for input metadata
+
+use super::*;
+use tempfile::TempDir;
+
+#[test]
+fn test_count_documents() {
+    assert_eq!(count_documents(r#"{"a": 1}"#), 1);
+    assert_eq!(count_documents("{\"a\": 1}\n{\"a\": 2}\n[3]\n"), 3);
+    assert_eq!(count_documents(""), 0);
+}
+
+#[test]
+fn test_count_documents_stops_at_invalid_json() {
+    assert_eq!(count_documents("1 2 {"), 2);
+}
+
+#[test]
+fn test_format_size() {
+    assert_eq!(format_size(0), "0 B");
+    assert_eq!(format_size(1023), "1023 B");
+    assert_eq!(format_size(1536), "1.5 KB");
+    assert_eq!(format_size(3 * 1024 * 1024), "3.0 MB");
+    assert_eq!(format_size(5 * 1024 * 1024 * 1024), "5.0 GB");
+}
+
+#[test]
+fn test_stdin_input() {
+    let info = InputInfo::new(None, None, InputFormat::Json, "{\"a\": 1}\n{\"a\": 2}");
+    assert_eq!(info.source, "stdin");
+    assert_eq!(info.size, 17);
+    assert_eq!(info.documents, 2);
+}
+
+#[test]
+fn test_file_input_reports_name_and_size_on_disk() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("Cargo.toml");
+    std::fs::write(&path, "name = \"x\"\n").unwrap();
+
+    let info = InputInfo::new(
+        Some(&path),
+        None,
+        InputFormat::Toml,
+        "{\n  \"name\": \"x\"\n}",
+    );
+
+    assert_eq!(info.source, "Cargo.toml");
+    assert_eq!(info.size, 11);
+    assert_eq!(info.format, InputFormat::Toml);
+    assert_eq!(info.documents, 1);
+}
+
+#[test]
+fn test_command_input_shows_command() {
+    let command = CommandSource::kubectl("pods", &["-A".to_string()]);
+    let info = InputInfo::new(None, Some(&command), InputFormat::Json, "{}");
+    assert_eq!(info.source, "kubectl get pods");
+}
+
+#[test]
+fn test_add_records() {
+    let mut info = InputInfo::new(None, None, InputFormat::Json, "{\"n\": 1}\n");
+    info.add_records("{\"n\": 2}\n{\"n\": 3}\n");
+    assert_eq!(info.documents, 3);
+    assert_eq!(info.size, 27);
+}
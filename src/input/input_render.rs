@@ -7,13 +7,18 @@ use ratatui::{
 };
 
 use crate::app::{App, Focus};
+use crate::config::KeyBindings;
 use crate::editor::EditorMode;
-use crate::syntax_highlight::JqHighlighter;
+use crate::query::language::QueryLanguage;
+use crate::query::lint;
 use crate::syntax_highlight::bracket_matcher::find_matching_bracket;
 use crate::syntax_highlight::overlay::{
-    extract_visible_spans, highlight_bracket_pairs, insert_cursor_into_spans,
+    append_ghost_text, extract_visible_spans, highlight_bracket_pairs, highlight_error_span,
+    highlight_selection, insert_cursor_into_spans,
 };
+use crate::syntax_highlight::{JqHighlighter, current_theme};
 use crate::theme;
+use crate::widgets::text_width::truncate_to_width;
 
 /// Render the input field
 ///
@@ -51,7 +56,9 @@ pub fn render_field(app: &mut App, frame: &mut Frame, area: Rect) -> Rect {
     };
 
     let mode_text = app.input.editor_mode.display();
-    let title_spans = match app.input.editor_mode {
+    let mut title_spans = match app.input.editor_mode {
+        // Emacs keys never leave Insert mode, so there's no mode to show
+        _ if app.input.keybindings == KeyBindings::Emacs => vec![Span::raw(" Query ")],
         EditorMode::Normal => {
             vec![
                 Span::raw(" Query ["),
@@ -68,6 +75,31 @@ pub fn render_field(app: &mut App, frame: &mut Frame, area: Rect) -> Rect {
         }
     };
 
+    let language = app.query.as_ref().map(|q| q.language).unwrap_or_default();
+    if language != QueryLanguage::Jq {
+        title_spans.push(Span::styled(
+            format!("[{}] ", language.label()),
+            Style::default().fg(theme::input::LANGUAGE_INDICATOR),
+        ));
+    }
+
+    // Lints only apply to jq syntax
+    let lint_warnings = if language == QueryLanguage::Jq {
+        lint::lint(app.query())
+    } else {
+        Vec::new()
+    };
+    if let Some(first) = lint_warnings.first() {
+        let more = match lint_warnings.len() - 1 {
+            0 => String::new(),
+            n => format!(" (+{} more)", n),
+        };
+        title_spans.push(Span::styled(
+            format!("⚠ {}{} ", first.message, more),
+            Style::default().fg(theme::input::SYNTAX_ERROR_WARNING),
+        ));
+    }
+
     let title = Line::from(title_spans);
 
     let mut block = Block::default()
@@ -80,9 +112,14 @@ pub fn render_field(app: &mut App, frame: &mut Frame, area: Rect) -> Rect {
     let has_tooltip_available = app.tooltip.current_function.is_some();
     let tooltip_active = app.tooltip.enabled;
     let ai_active = app.ai.visible;
+    // Ctrl+A moves to the line start with emacs keys while typing
+    let emacs_keys = app.input.keybindings == KeyBindings::Emacs;
 
-    if ai_active {
-        // AI active: show nothing
+    if ai_active || (emacs_keys && (tooltip_active || !has_tooltip_available)) {
+        // AI active, or its key taken and nothing else to hint: show nothing
+    } else if emacs_keys {
+        let tooltip_hint = theme::border_hints::build_hints(&[("Ctrl+O", "Tooltip")], border_color);
+        block = block.title_top(tooltip_hint.alignment(Alignment::Right));
     } else if tooltip_active {
         // Tooltip active: show only AI hint
         let ai_hint = theme::border_hints::build_hints(&[("Ctrl+A", "AI Assistant")], border_color);
@@ -90,7 +127,7 @@ pub fn render_field(app: &mut App, frame: &mut Frame, area: Rect) -> Rect {
     } else if has_tooltip_available {
         // Neither active, tooltip available: show both hints
         let combined = theme::border_hints::build_hints(
-            &[("Ctrl+T", "Tooltip"), ("Ctrl+A", "AI Assistant")],
+            &[("Ctrl+O", "Tooltip"), ("Ctrl+A", "AI Assistant")],
             border_color,
         );
         block = block.title_top(combined.alignment(Alignment::Right));
@@ -100,7 +137,27 @@ pub fn render_field(app: &mut App, frame: &mut Frame, area: Rect) -> Rect {
         block = block.title_top(ai_hint.alignment(Alignment::Right));
     }
 
-    if is_focused {
+    // SQL mode previews the jq it compiles to, so users can learn from it
+    let sql_preview = if language == QueryLanguage::Sql && !has_error && !app.query().is_empty() {
+        language.to_jq(app.query()).ok()
+    } else {
+        None
+    };
+
+    if let Some(filter) = sql_preview {
+        let max_width = area.width.saturating_sub(10) as usize;
+        let filter = truncate_to_width(&filter, max_width);
+        block = block.title_bottom(Line::from(vec![
+            Span::styled(
+                " jq: ",
+                Style::default().fg(theme::input::LANGUAGE_INDICATOR),
+            ),
+            Span::styled(
+                format!("{} ", filter),
+                Style::default().fg(theme::input::TRANSLATION_PREVIEW),
+            ),
+        ]));
+    } else if is_focused {
         if has_error {
             block = block.title_bottom(
                 theme::border_hints::build_hints(
@@ -132,6 +189,12 @@ pub fn render_field(app: &mut App, frame: &mut Frame, area: Rect) -> Rect {
         }
     }
 
+    // Lexing reuses the tokens of the query's unchanged start from the last frame
+    let highlighted_spans = is_focused.then(|| {
+        let tokens = app.input.lexer.tokens(&app.input.textarea.lines()[0]);
+        JqHighlighter::spans(tokens, &current_theme())
+    });
+
     let query = app.query();
     let cursor_col = app.input.textarea.cursor().1;
     let scroll_offset = app.input.scroll_offset;
@@ -145,31 +208,49 @@ pub fn render_field(app: &mut App, frame: &mut Frame, area: Rect) -> Rect {
         let paragraph = Paragraph::new(Line::from(final_spans)).block(block);
         frame.render_widget(paragraph, area);
     } else {
-        let highlighted_spans = JqHighlighter::highlight(query);
-
-        let spans_with_brackets =
+        let styled_spans = if let Some(highlighted_spans) = highlighted_spans {
             if let Some(bracket_positions) = find_matching_bracket(query, cursor_col) {
                 highlight_bracket_pairs(highlighted_spans, bracket_positions)
             } else {
                 highlighted_spans
-            };
+            }
+        } else {
+            vec![Span::styled(
+                query.to_string(),
+                Style::default().fg(theme::input::QUERY_UNFOCUSED),
+            )]
+        };
+
+        // Only highlight when the error belongs to the query currently shown
+        let styled_spans = match app.query.as_ref().and_then(|q| q.parsed_error.as_ref()) {
+            Some(error) if has_error && error.query == query => match error.span.clone() {
+                Some(span) => highlight_error_span(styled_spans, span),
+                None => styled_spans,
+            },
+            _ => styled_spans,
+        };
+
+        // Selected text, e.g. the placeholder of an inserted tooltip example
+        let styled_spans = match app.input.textarea.selection_range() {
+            Some(((_, start), (_, end))) if is_focused && start < end => {
+                highlight_selection(styled_spans, start..end)
+            }
+            _ => styled_spans,
+        };
+
+        // Preview of the selected suggestion, accepted with Right/End
+        let styled_spans = match crate::autocomplete::ghost_text_from_app(app) {
+            Some(ghost) => append_ghost_text(styled_spans, &ghost),
+            None => styled_spans,
+        };
 
-        let visible_spans =
-            extract_visible_spans(&spans_with_brackets, scroll_offset, viewport_width);
+        let visible_spans = extract_visible_spans(&styled_spans, scroll_offset, viewport_width);
 
         let final_spans = if is_focused {
             let cursor_in_viewport = cursor_col.saturating_sub(scroll_offset);
             insert_cursor_into_spans(visible_spans, cursor_in_viewport)
         } else {
             visible_spans
-                .into_iter()
-                .map(|span| {
-                    Span::styled(
-                        span.content,
-                        Style::default().fg(theme::input::QUERY_UNFOCUSED),
-                    )
-                })
-                .collect()
         };
 
         let paragraph = Paragraph::new(Line::from(final_spans)).block(block);
@@ -41,6 +41,19 @@ fn snapshot_query_focused_normal_mode() {
     assert_snapshot!(output);
 }
 
+#[test]
+fn snapshot_query_focused_emacs_keybindings() {
+    let json = r#"{"name": "Alice"}"#;
+    let mut app = test_app(json);
+    app.input.keybindings = crate::config::KeyBindings::Emacs;
+    app.input.textarea.insert_str(".name");
+    app.query.as_mut().unwrap().execute(".name");
+    app.focus = Focus::InputField;
+
+    let output = render_to_string(&mut app, TEST_WIDTH, TEST_HEIGHT);
+    assert_snapshot!(output);
+}
+
 #[test]
 fn snapshot_query_unfocused_insert_mode() {
     let json = r#"{"name": "Alice"}"#;
@@ -146,3 +159,139 @@ fn snapshot_query_unfocused_normal_mode_with_syntax_error() {
     let output = render_to_string(&mut app, TEST_WIDTH, TEST_HEIGHT);
     assert_snapshot!(output);
 }
+
+#[test]
+fn test_error_span_underlined_in_query() {
+    use ratatui::style::Modifier;
+
+    let json = r#"{"name": "Alice"}"#;
+    let mut app = test_app(json);
+    app.input.textarea.insert_str(".name | foo");
+    app.query.as_mut().unwrap().execute(".name | foo");
+    app.focus = Focus::ResultsPane;
+
+    let backend = TestBackend::new(TEST_WIDTH, TEST_HEIGHT);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal.draw(|f| app.render(f)).unwrap();
+    let buffer = terminal.backend().buffer();
+
+    // Query text starts at column 1 of the input row (inside the border),
+    // above the status and help lines
+    let input_row = TEST_HEIGHT - 4;
+    let underlined: String = (1..TEST_WIDTH - 1)
+        .map(|x| &buffer[(x, input_row)])
+        .filter(|cell| cell.modifier.contains(Modifier::UNDERLINED))
+        .map(|cell| cell.symbol())
+        .collect();
+
+    assert_eq!(underlined, "foo");
+}
+
+#[test]
+fn test_ghost_text_rendered_after_query() {
+    use crate::autocomplete::{Suggestion, SuggestionType};
+    use crate::editor::EditorMode;
+    use ratatui::style::Modifier;
+
+    let json = r#"{"name": "Alice"}"#;
+    let mut app = test_app(json);
+    app.input.textarea.insert_str(".na");
+    app.input.editor_mode = EditorMode::Insert;
+    app.focus = Focus::InputField;
+    app.autocomplete
+        .update_suggestions(vec![Suggestion::new("name", SuggestionType::Field)]);
+
+    let backend = TestBackend::new(TEST_WIDTH, TEST_HEIGHT);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal.draw(|f| app.render(f)).unwrap();
+    let buffer = terminal.backend().buffer();
+
+    let input_row = TEST_HEIGHT - 4;
+    let line: String = (1..6).map(|x| buffer[(x, input_row)].symbol()).collect();
+    assert_eq!(line, ".name");
+    let ghost: String = (1..TEST_WIDTH - 1)
+        .map(|x| &buffer[(x, input_row)])
+        .filter(|cell| cell.modifier.contains(Modifier::ITALIC))
+        .map(|cell| cell.symbol())
+        .collect();
+    assert_eq!(ghost, "me");
+}
+
+#[test]
+fn test_error_span_not_shown_for_stale_error() {
+    use ratatui::style::Modifier;
+
+    let json = r#"{"name": "Alice"}"#;
+    let mut app = test_app(json);
+    app.query.as_mut().unwrap().execute(".name | foo");
+    // User kept typing; error belongs to an older query
+    app.input.textarea.insert_str(".name | foob");
+    app.focus = Focus::ResultsPane;
+
+    let backend = TestBackend::new(TEST_WIDTH, TEST_HEIGHT);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal.draw(|f| app.render(f)).unwrap();
+    let buffer = terminal.backend().buffer();
+
+    let input_row = TEST_HEIGHT - 4;
+    assert!((1..TEST_WIDTH - 1).all(|x| {
+        !buffer[(x, input_row)]
+            .modifier
+            .contains(Modifier::UNDERLINED)
+    }));
+}
+
+#[test]
+fn snapshot_query_with_lint_warning() {
+    let json = r#"{"name": "Alice"}"#;
+    let mut app = test_app(json);
+    app.input.textarea.insert_str("select(.name = 1)");
+    app.focus = Focus::InputField;
+    app.input.editor_mode = EditorMode::Insert;
+
+    let output = render_to_string(&mut app, TEST_WIDTH, TEST_HEIGHT);
+    assert_snapshot!(output);
+}
+
+#[test]
+fn snapshot_query_with_multiple_lint_warnings() {
+    let json = r#"{"name": "Alice"}"#;
+    let mut app = test_app(json);
+    app.input.textarea.insert_str("map(.a | ");
+    app.focus = Focus::InputField;
+    app.input.editor_mode = EditorMode::Insert;
+
+    let output = render_to_string(&mut app, TEST_WIDTH, TEST_HEIGHT);
+    assert_snapshot!(output);
+}
+
+#[test]
+fn snapshot_query_in_jsonpath_mode() {
+    let json = r#"{"name": "Alice"}"#;
+    let mut app = test_app(json);
+    app.input.textarea.insert_str("$.name");
+    let query_state = app.query.as_mut().unwrap();
+    query_state.language = crate::query::language::QueryLanguage::JsonPath;
+    query_state.execute("$.name");
+    app.focus = Focus::InputField;
+    app.input.editor_mode = EditorMode::Insert;
+
+    let output = render_to_string(&mut app, TEST_WIDTH, TEST_HEIGHT);
+    assert_snapshot!(output);
+}
+
+#[test]
+fn snapshot_query_in_sql_mode_shows_jq_preview() {
+    let json = r#"[{"name": "Alice", "age": 34}]"#;
+    let mut app = test_app(json);
+    let query = "SELECT name FROM . WHERE age > 30";
+    app.input.textarea.insert_str(query);
+    let query_state = app.query.as_mut().unwrap();
+    query_state.language = crate::query::language::QueryLanguage::Sql;
+    query_state.execute(query);
+    app.focus = Focus::InputField;
+    app.input.editor_mode = EditorMode::Insert;
+
+    let output = render_to_string(&mut app, TEST_WIDTH, TEST_HEIGHT);
+    assert_snapshot!(output);
+}
@@ -5,7 +5,9 @@ use ratatui::{
 use tui_textarea::TextArea;
 
 use crate::autocomplete::BraceTracker;
-use crate::editor::{CharSearchState, EditorMode};
+use crate::config::KeyBindings;
+use crate::editor::{CharSearchState, EditorMode, KillRing};
+use crate::syntax_highlight::incremental::IncrementalLexer;
 use crate::theme;
 
 pub struct InputState {
@@ -15,6 +17,14 @@ pub struct InputState {
     pub brace_tracker: BraceTracker,
     pub last_char_search: Option<CharSearchState>,
     pub manual_scroll_active: bool,
+    /// Auto-insert closing brackets and quotes (`[editor] auto_pairs`)
+    pub auto_pairs: bool,
+    /// Modal vim editing or always-inserting emacs keys (`[editor] keybindings`)
+    pub keybindings: KeyBindings,
+    /// Text removed with the emacs kill keys, for `Ctrl+Y` and `Alt+Y`
+    pub kill_ring: KillRing,
+    /// Tokens of the query, kept between frames to highlight it
+    pub lexer: IncrementalLexer,
 }
 
 impl InputState {
@@ -37,6 +47,10 @@ impl InputState {
             brace_tracker: BraceTracker::new(),
             last_char_search: None,
             manual_scroll_active: false,
+            auto_pairs: false,
+            keybindings: KeyBindings::default(),
+            kill_ring: KillRing::new(),
+            lexer: IncrementalLexer::new(),
         }
     }
 
@@ -6,6 +6,8 @@
 use std::path::{Path, PathBuf};
 use std::sync::mpsc::{Receiver, channel};
 
+use super::command_source::CommandSource;
+use super::input_format::{self, InputFormat, InputOptions};
 use crate::error::JiqError;
 
 /// Represents the current state of file loading
@@ -19,7 +21,9 @@ pub enum LoadingState {
 /// Manages asynchronous file loading in a background thread
 pub struct FileLoader {
     pub state: LoadingState,
-    pub rx: Option<Receiver<Result<String, JiqError>>>,
+    pub rx: Option<Receiver<Result<(String, InputFormat), JiqError>>>,
+    /// Format the input was read as, known once loading completes
+    pub format: Option<InputFormat>,
 }
 
 impl FileLoader {
@@ -30,17 +34,25 @@ impl FileLoader {
     ///
     /// # Arguments
     /// * `path` - Path to the JSON file to load
+    #[allow(dead_code)]
     pub fn spawn_load(path: PathBuf) -> Self {
+        Self::spawn_load_with_options(path, InputOptions::default())
+    }
+
+    /// Spawn a background thread to load a file, converting it to JSON
+    /// according to `options`
+    pub fn spawn_load_with_options(path: PathBuf, options: InputOptions) -> Self {
         let (tx, rx) = channel();
 
         std::thread::spawn(move || {
-            let result = load_file_sync(&path);
+            let result = load_file_sync(&path, &options);
             let _ = tx.send(result);
         });
 
         Self {
             state: LoadingState::Loading,
             rx: Some(rx),
+            format: None,
         }
     }
 
@@ -48,17 +60,41 @@ impl FileLoader {
     ///
     /// Creates a background thread that reads from stdin, validates JSON,
     /// and sends the result back via a channel.
+    #[allow(dead_code)]
     pub fn spawn_load_stdin() -> Self {
+        Self::spawn_load_stdin_with_options(InputOptions::default())
+    }
+
+    /// Spawn a background thread to load from stdin, converting it to JSON
+    /// according to `options`
+    pub fn spawn_load_stdin_with_options(options: InputOptions) -> Self {
         let (tx, rx) = channel();
 
         std::thread::spawn(move || {
-            let result = load_stdin_sync();
+            let result = load_stdin_sync(&options);
             let _ = tx.send(result);
         });
 
         Self {
             state: LoadingState::Loading,
             rx: Some(rx),
+            format: None,
+        }
+    }
+
+    /// Spawn a background thread running `source` (kubectl, aws) and
+    /// loading its JSON output
+    pub fn spawn_load_command(source: CommandSource) -> Self {
+        let (tx, rx) = channel();
+
+        std::thread::spawn(move || {
+            let _ = tx.send(source.run().map(|json| (json, InputFormat::Json)));
+        });
+
+        Self {
+            state: LoadingState::Loading,
+            rx: Some(rx),
+            format: None,
         }
     }
 
@@ -71,6 +107,10 @@ impl FileLoader {
             match rx.try_recv() {
                 Ok(result) => {
                     self.rx = None;
+                    let result = result.map(|(json, format)| {
+                        self.format = Some(format);
+                        json
+                    });
                     self.state = match &result {
                         Ok(json) => LoadingState::Complete(json.clone()),
                         Err(e) => LoadingState::Error(e.clone()),
@@ -104,7 +144,7 @@ impl FileLoader {
 /// Validate that content is valid JSON or JSONL
 ///
 /// Uses StreamDeserializer to handle both single JSON values and JSONL (multiple values).
-fn validate_json_or_jsonl(content: &str) -> Result<(), JiqError> {
+pub(crate) fn validate_json_or_jsonl(content: &str) -> Result<(), JiqError> {
     let deserializer = serde_json::Deserializer::from_str(content).into_iter::<serde_json::Value>();
     let mut count = 0;
     for result in deserializer {
@@ -119,8 +159,9 @@ fn validate_json_or_jsonl(content: &str) -> Result<(), JiqError> {
 
 /// Synchronous file loading (runs in background thread)
 ///
-/// Reads the file from disk and validates that it contains valid JSON or JSONL.
-fn load_file_sync(path: &Path) -> Result<String, JiqError> {
+/// Reads the file from disk and converts it to JSON, validating JSON or JSONL
+/// input as-is.
+fn load_file_sync(path: &Path, options: &InputOptions) -> Result<(String, InputFormat), JiqError> {
     use std::fs::File;
     use std::io::Read;
 
@@ -128,15 +169,14 @@ fn load_file_sync(path: &Path) -> Result<String, JiqError> {
     let mut contents = String::new();
     file.read_to_string(&mut contents)?;
 
-    validate_json_or_jsonl(&contents)?;
-
-    Ok(contents)
+    input_format::convert(contents, Some(path), options)
 }
 
 /// Synchronous stdin loading (runs in background thread)
 ///
-/// Reads from stdin and validates that it contains valid JSON or JSONL.
-fn load_stdin_sync() -> Result<String, JiqError> {
+/// Reads from stdin and converts it to JSON, validating JSON or JSONL input
+/// as-is.
+fn load_stdin_sync(options: &InputOptions) -> Result<(String, InputFormat), JiqError> {
     use std::io::{self, IsTerminal, Read};
 
     if io::stdin().is_terminal() {
@@ -148,9 +188,7 @@ fn load_stdin_sync() -> Result<String, JiqError> {
     let mut buffer = String::new();
     io::stdin().read_to_string(&mut buffer)?;
 
-    validate_json_or_jsonl(&buffer)?;
-
-    Ok(buffer)
+    input_format::convert(buffer, None, options)
 }
 
 #[cfg(test)]
@@ -159,7 +159,7 @@ fn test_load_stdin_sync_detects_terminal() {
 
     // When stdin is a terminal (not piped), load_stdin_sync should error immediately
     if std::io::stdin().is_terminal() {
-        let result = load_stdin_sync();
+        let result = load_stdin_sync(&InputOptions::default());
         assert!(result.is_err(), "Should error when stdin is a terminal");
         match result.unwrap_err() {
             JiqError::Io(msg) => {
@@ -351,3 +351,36 @@ mod property_tests {
         }
     }
 }
+
+#[test]
+fn test_file_loader_converts_toml() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("Cargo.toml");
+    fs::write(&file_path, "[package]\nname = \"jiq\"\n").unwrap();
+
+    let mut loader = FileLoader::spawn_load(file_path);
+    let json = wait_for_completion(&mut loader, 100)
+        .expect("Loader should complete")
+        .expect("TOML should load");
+
+    let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+    assert_eq!(value["package"]["name"], "jiq");
+}
+
+#[test]
+fn test_file_loader_runs_command_source() {
+    let source = crate::input::command_source::CommandSource {
+        program: "sh".to_string(),
+        args: vec![
+            "-c".to_string(),
+            r#"echo '{"kind": "PodList"}'"#.to_string(),
+        ],
+        history_key: "k8s-pods".to_string(),
+    };
+
+    let mut loader = FileLoader::spawn_load_command(source);
+    let json = wait_for_completion(&mut loader, 200)
+        .expect("Loader should complete")
+        .expect("Command output should load");
+    assert_eq!(json.trim(), r#"{"kind": "PodList"}"#);
+}
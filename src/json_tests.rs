@@ -11,6 +11,13 @@ fn test_extract_json_schema_simple_object() {
     assert!(schema.contains(r#""age":"number""#));
 }
 
+#[test]
+fn test_extract_json_schema_keeps_key_order() {
+    let json = r#"{"zeta": 1, "alpha": "a"}"#;
+    let schema = extract_json_schema(json, 5).unwrap();
+    assert_eq!(schema, r#"{"zeta":"number","alpha":"string"}"#);
+}
+
 #[test]
 fn test_extract_json_schema_nested_object() {
     let json = r#"{"user": {"address": {"city": "NYC"}}}"#;
@@ -0,0 +1,15 @@
+//! Finding keys and values anywhere in the input
+//!
+//! `Ctrl+F` in the results pane opens a sidebar listing every path of the
+//! input whose key or value contains the typed text, with a preview of the
+//! value. Picking one jumps to it or inserts its path in the query, to find
+//! where a field lives in a large document without knowing its structure.
+
+mod key_finder_events;
+mod key_finder_render;
+mod key_finder_search;
+mod key_finder_state;
+
+pub use key_finder_events::{handle_key_finder_key, open_key_finder};
+pub use key_finder_render::render_key_finder;
+pub use key_finder_state::KeyFinderState;
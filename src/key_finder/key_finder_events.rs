@@ -0,0 +1,65 @@
+use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use serde_json::Value;
+use tui_textarea::Input;
+
+use crate::app::App;
+use crate::editor::editor_events;
+
+/// Open the sidebar on the documents of the input
+pub fn open_key_finder(app: &mut App) {
+    let Some(query_state) = &app.query else {
+        app.notification.show_warning("The input is still loading");
+        return;
+    };
+    let values = serde_json::Deserializer::from_str(query_state.executor.json_input())
+        .into_iter::<Value>()
+        .collect::<Result<Vec<_>, _>>();
+    match values {
+        Ok(values) => {
+            app.key_finder.open(values);
+            app.autocomplete.hide();
+        }
+        Err(e) => app
+            .notification
+            .show_warning(&format!("Input is not JSON: {}", e)),
+    }
+}
+
+pub fn handle_key_finder_key(app: &mut App, key: KeyEvent) {
+    let is_ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+    match key.code {
+        KeyCode::Esc => app.key_finder.close(),
+        KeyCode::Char('f') if is_ctrl => app.key_finder.close(),
+        KeyCode::Up => app.key_finder.select_prev(),
+        KeyCode::Char('p') if is_ctrl => app.key_finder.select_prev(),
+        KeyCode::Down => app.key_finder.select_next(),
+        KeyCode::Char('n') if is_ctrl => app.key_finder.select_next(),
+        KeyCode::Enter => {
+            if let Some(path) = take_selected_path(app) {
+                editor_events::jump_to_path(app, &path);
+            }
+        }
+        KeyCode::Tab => {
+            if let Some(path) = take_selected_path(app) {
+                editor_events::insert_path(app, &path);
+            }
+        }
+        _ => {
+            let finder = &mut app.key_finder;
+            if finder.textarea_mut().input(Input::from(key)) {
+                finder.search();
+            }
+        }
+    }
+}
+
+/// Close the sidebar and take the selected match's path
+fn take_selected_path(app: &mut App) -> Option<String> {
+    let path = app.key_finder.selected_match().map(|m| m.path.clone())?;
+    app.key_finder.close();
+    Some(path)
+}
+
+#[cfg(test)]
+#[path = "key_finder_events_tests.rs"]
+mod key_finder_events_tests;
@@ -0,0 +1,95 @@
+//! Tests for key_finder_events
+
+use super::*;
+use crate::app::Focus;
+use crate::test_utils::test_helpers::{key, key_with_mods, test_app};
+
+const INPUT: &str = r#"{"items": [{"metadata": {"name": "web"}}, {"metadata": {"name": "db"}}]}"#;
+
+fn app_in_results() -> App {
+    let mut app = test_app(INPUT);
+    app.focus = Focus::ResultsPane;
+    app
+}
+
+fn type_text(app: &mut App, text: &str) {
+    for c in text.chars() {
+        handle_key_finder_key(app, key(KeyCode::Char(c)));
+    }
+}
+
+#[test]
+fn test_ctrl_f_in_results_opens_finder() {
+    let mut app = app_in_results();
+
+    app.handle_key_event(key_with_mods(KeyCode::Char('f'), KeyModifiers::CONTROL));
+
+    assert!(app.key_finder.is_visible());
+    assert!(!app.search.is_visible());
+}
+
+#[test]
+fn test_ctrl_f_in_input_still_opens_search() {
+    let mut app = test_app(INPUT);
+    app.focus = Focus::InputField;
+
+    app.handle_key_event(key_with_mods(KeyCode::Char('f'), KeyModifiers::CONTROL));
+
+    assert!(!app.key_finder.is_visible());
+    assert!(app.search.is_visible());
+}
+
+#[test]
+fn test_typing_lists_matching_paths() {
+    let mut app = app_in_results();
+    open_key_finder(&mut app);
+
+    type_text(&mut app, "db");
+
+    let paths: Vec<&str> = app
+        .key_finder
+        .matches()
+        .iter()
+        .map(|m| m.path.as_str())
+        .collect();
+    assert_eq!(paths, [".items[1].metadata.name"]);
+}
+
+#[test]
+fn test_enter_replaces_query_with_path() {
+    let mut app = app_in_results();
+    app.input.textarea.insert_str(".items");
+    open_key_finder(&mut app);
+    type_text(&mut app, "name");
+    handle_key_finder_key(&mut app, key(KeyCode::Down));
+
+    handle_key_finder_key(&mut app, key(KeyCode::Enter));
+
+    assert!(!app.key_finder.is_visible());
+    assert_eq!(app.query(), ".items[1].metadata.name");
+}
+
+#[test]
+fn test_tab_inserts_path_at_cursor() {
+    let mut app = app_in_results();
+    app.input.textarea.insert_str("[");
+    open_key_finder(&mut app);
+    type_text(&mut app, "web");
+
+    handle_key_finder_key(&mut app, key(KeyCode::Tab));
+
+    assert_eq!(app.query(), "[.items[0].metadata.name");
+    assert_eq!(app.focus, Focus::InputField);
+}
+
+#[test]
+fn test_esc_closes_without_changing_query() {
+    let mut app = app_in_results();
+    open_key_finder(&mut app);
+    type_text(&mut app, "web");
+
+    handle_key_finder_key(&mut app, key(KeyCode::Esc));
+
+    assert!(!app.key_finder.is_visible());
+    assert_eq!(app.query(), "");
+}
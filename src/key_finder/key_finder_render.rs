@@ -0,0 +1,123 @@
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Paragraph},
+};
+
+use super::key_finder_state::KeyFinderState;
+use crate::bookmarks::bookmark_render::sidebar_area;
+use crate::theme;
+use crate::widgets::popup;
+use crate::widgets::text_width::truncate_to_width;
+
+const FIELD_HEIGHT: u16 = 3;
+
+fn build_hints() -> Line<'static> {
+    theme::border_hints::build_hints(
+        &[("Enter", "Jump"), ("Tab", "Insert"), ("Esc", "Close")],
+        theme::key_finder::BORDER,
+    )
+}
+
+/// Render the finder over the right part of the results pane
+pub fn render_key_finder(state: &mut KeyFinderState, frame: &mut Frame, results_area: Rect) {
+    let area = sidebar_area(results_area);
+    popup::clear_area(frame, area);
+
+    let [field_area, list_area] =
+        Layout::vertical([Constraint::Length(FIELD_HEIGHT), Constraint::Min(0)]).areas(area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(" Find key or value ")
+        .border_style(Style::default().fg(theme::key_finder::FIELD_BORDER))
+        .style(Style::default().bg(theme::key_finder::BACKGROUND));
+    let textarea = state.textarea_mut();
+    textarea.set_block(block);
+    textarea.set_style(
+        Style::default()
+            .fg(theme::key_finder::FIELD_TEXT)
+            .bg(theme::key_finder::BACKGROUND),
+    );
+    textarea.set_cursor_style(Style::default().add_modifier(Modifier::REVERSED));
+    frame.render_widget(&*textarea, field_area);
+
+    render_list(state, frame, list_area);
+}
+
+fn render_list(state: &KeyFinderState, frame: &mut Frame, area: Rect) {
+    if area.height == 0 {
+        return;
+    }
+
+    // Each match takes two lines: its path and a preview of its value
+    let visible = (area.height.saturating_sub(2) / 2) as usize;
+    let first = (state.selected() + 1).saturating_sub(visible.max(1));
+    let width = area.width.saturating_sub(2) as usize;
+
+    let lines: Vec<Line> = if state.matches().is_empty() {
+        let text = if state.query().trim().is_empty() {
+            " Type part of a key or value"
+        } else {
+            " No matches in the input"
+        };
+        vec![Line::from(Span::styled(
+            text,
+            Style::default().fg(theme::key_finder::EMPTY),
+        ))]
+    } else {
+        state
+            .matches()
+            .iter()
+            .enumerate()
+            .skip(first)
+            .take(visible)
+            .flat_map(|(index, found)| {
+                let is_selected = index == state.selected();
+                let indicator = if is_selected { "▸ " } else { "  " };
+                let mut path = Line::from(vec![
+                    Span::styled(
+                        indicator,
+                        Style::default().fg(theme::key_finder::ITEM_SELECTED_INDICATOR),
+                    ),
+                    Span::styled(
+                        truncate_to_width(&found.path, width.saturating_sub(2)),
+                        Style::default().fg(theme::key_finder::PATH),
+                    ),
+                ]);
+                let mut preview = Line::from(Span::styled(
+                    truncate_to_width(&format!("    {}", found.preview), width),
+                    Style::default().fg(theme::key_finder::PREVIEW),
+                ));
+                if is_selected {
+                    let highlight = Style::default().bg(theme::key_finder::ITEM_SELECTED_BG);
+                    path = path.style(highlight);
+                    preview = preview.style(highlight);
+                }
+                [path, preview]
+            })
+            .collect()
+    };
+
+    let count = if state.is_truncated() {
+        format!("{}+", state.matches().len())
+    } else {
+        state.matches().len().to_string()
+    };
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(format!(" Matches ({}) ", count))
+        .title_bottom(build_hints().alignment(Alignment::Center))
+        .border_style(Style::default().fg(theme::key_finder::BORDER))
+        .style(Style::default().bg(theme::key_finder::BACKGROUND));
+
+    frame.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+#[cfg(test)]
+#[path = "key_finder_render_tests.rs"]
+mod key_finder_render_tests;
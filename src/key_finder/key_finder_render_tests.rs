@@ -0,0 +1,41 @@
+//! Tests for key_finder_render
+
+use serde_json::json;
+
+use super::*;
+use insta::assert_snapshot;
+use ratatui::Terminal;
+use ratatui::backend::TestBackend;
+
+fn render_to_string(state: &mut KeyFinderState, width: u16, height: u16) -> String {
+    let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+    terminal
+        .draw(|f| {
+            let area = f.area();
+            render_key_finder(state, f, area);
+        })
+        .unwrap();
+    terminal.backend().to_string()
+}
+
+#[test]
+fn snapshot_key_finder_with_matches() {
+    let mut state = KeyFinderState::new();
+    state.open(vec![json!({
+        "name": "api",
+        "spec": {"containers": [{"name": "web", "image": "nginx"}]}
+    })]);
+    state.textarea_mut().insert_str("name");
+    state.search();
+    state.select_next();
+
+    assert_snapshot!(render_to_string(&mut state, 60, 12));
+}
+
+#[test]
+fn snapshot_key_finder_empty_search() {
+    let mut state = KeyFinderState::new();
+    state.open(vec![json!({"a": 1})]);
+
+    assert_snapshot!(render_to_string(&mut state, 60, 8));
+}
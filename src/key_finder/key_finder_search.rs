@@ -0,0 +1,126 @@
+use std::collections::HashSet;
+
+use serde_json::Value;
+
+use crate::query::language::jq_field;
+
+/// Most matches listed; the walk stops once there are this many
+pub const MAX_MATCHES: usize = 500;
+
+/// A key or value of the input containing the searched text
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyMatch {
+    /// jq path of the value, e.g. `.items[3].metadata.name`
+    pub path: String,
+    /// Short form of the value: scalars as JSON, containers by their size
+    pub preview: String,
+}
+
+/// Paths in `values` whose key or scalar value contains `needle`, ignoring
+/// case, in document order
+///
+/// The documents of a JSON Lines input share their paths, so each path is
+/// listed once. Returns the matches and whether the walk stopped at
+/// `MAX_MATCHES`.
+pub fn find_matches(values: &[Value], needle: &str) -> (Vec<KeyMatch>, bool) {
+    let needle = needle.trim().to_lowercase();
+    let mut finder = Finder {
+        needle,
+        matches: Vec::new(),
+        seen: HashSet::new(),
+    };
+    if finder.needle.is_empty() {
+        return (Vec::new(), false);
+    }
+
+    let mut path = String::new();
+    for value in values {
+        if !finder.walk(value, &mut path) {
+            return (finder.matches, true);
+        }
+    }
+    (finder.matches, false)
+}
+
+struct Finder {
+    needle: String,
+    matches: Vec<KeyMatch>,
+    seen: HashSet<String>,
+}
+
+impl Finder {
+    /// Visit `value` at `path` and its children; false once full
+    fn walk(&mut self, value: &Value, path: &mut String) -> bool {
+        match value {
+            Value::Object(map) => {
+                for (key, child) in map {
+                    let len = path.len();
+                    path.push_str(&jq_field(key));
+                    let key_matches = key.to_lowercase().contains(&self.needle);
+                    let full =
+                        (key_matches || self.value_matches(child)) && !self.push(path, child);
+                    if full || !self.walk(child, path) {
+                        return false;
+                    }
+                    path.truncate(len);
+                }
+            }
+            Value::Array(items) => {
+                for (index, child) in items.iter().enumerate() {
+                    let len = path.len();
+                    path.push_str(&format!("[{}]", index));
+                    if (self.value_matches(child) && !self.push(path, child))
+                        || !self.walk(child, path)
+                    {
+                        return false;
+                    }
+                    path.truncate(len);
+                }
+            }
+            _ => {}
+        }
+        true
+    }
+
+    fn value_matches(&self, value: &Value) -> bool {
+        match value {
+            Value::String(text) => text.to_lowercase().contains(&self.needle),
+            Value::Object(_) | Value::Array(_) => false,
+            scalar => scalar.to_string().contains(&self.needle),
+        }
+    }
+
+    /// Add the match at `path`; false once full
+    fn push(&mut self, path: &str, value: &Value) -> bool {
+        let path = if path.starts_with('[') {
+            format!(".{}", path)
+        } else {
+            path.to_string()
+        };
+        if self.seen.insert(path.clone()) {
+            self.matches.push(KeyMatch {
+                path,
+                preview: preview(value),
+            });
+        }
+        self.matches.len() < MAX_MATCHES
+    }
+}
+
+fn preview(value: &Value) -> String {
+    match value {
+        Value::Object(map) => match map.len() {
+            1 => "{1 key}".to_string(),
+            len => format!("{{{} keys}}", len),
+        },
+        Value::Array(items) => match items.len() {
+            1 => "[1 item]".to_string(),
+            len => format!("[{} items]", len),
+        },
+        scalar => scalar.to_string(),
+    }
+}
+
+#[cfg(test)]
+#[path = "key_finder_search_tests.rs"]
+mod key_finder_search_tests;
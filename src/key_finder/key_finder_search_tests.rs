@@ -0,0 +1,75 @@
+//! Tests for key_finder_search
+
+use serde_json::json;
+
+use super::*;
+
+fn paths(values: &[Value], needle: &str) -> Vec<String> {
+    find_matches(values, needle)
+        .0
+        .into_iter()
+        .map(|found| found.path)
+        .collect()
+}
+
+#[test]
+fn test_finds_keys_at_any_depth_in_document_order() {
+    let values = [json!({
+        "name": "api",
+        "spec": {"containers": [{"name": "web", "image": "nginx"}]},
+        "labels": {"app-name": "shop"}
+    })];
+
+    assert_eq!(
+        paths(&values, "name"),
+        [
+            ".name",
+            ".spec.containers[0].name",
+            ".labels.[\"app-name\"]"
+        ]
+    );
+}
+
+#[test]
+fn test_finds_scalar_values_ignoring_case() {
+    let values = [json!({"image": "NGINX:1.27", "tags": ["nginx", "web"], "port": 8080})];
+
+    assert_eq!(paths(&values, "nginx"), [".image", ".tags[0]"]);
+    assert_eq!(paths(&values, "808"), [".port"]);
+}
+
+#[test]
+fn test_top_level_array_paths_start_with_dot() {
+    let values = [json!([{"id": 1}, {"id": 2}])];
+    assert_eq!(paths(&values, "id"), [".[0].id", ".[1].id"]);
+}
+
+#[test]
+fn test_previews_describe_value() {
+    let values = [json!({"spec": {"a": 1, "b": 2}, "specs": [1], "special": "yes"})];
+    let (matches, truncated) = find_matches(&values, "spec");
+
+    let previews: Vec<&str> = matches.iter().map(|m| m.preview.as_str()).collect();
+    assert_eq!(previews, ["{2 keys}", "[1 item]", "\"yes\""]);
+    assert!(!truncated);
+}
+
+#[test]
+fn test_paths_shared_by_documents_are_listed_once() {
+    let values = [json!({"id": 1}), json!({"id": 2, "ids": []})];
+    assert_eq!(paths(&values, "id"), [".id", ".ids"]);
+}
+
+#[test]
+fn test_stops_at_max_matches() {
+    let items: Vec<Value> = (0..MAX_MATCHES + 10).map(|i| json!({"key": i})).collect();
+    let (matches, truncated) = find_matches(&[Value::Array(items)], "key");
+
+    assert_eq!(matches.len(), MAX_MATCHES);
+    assert!(truncated);
+}
+
+#[test]
+fn test_blank_search_has_no_matches() {
+    assert_eq!(find_matches(&[json!({"a": 1})], "  "), (Vec::new(), false));
+}
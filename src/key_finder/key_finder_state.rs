@@ -0,0 +1,108 @@
+use ratatui::style::{Modifier, Style};
+use serde_json::Value;
+use tui_textarea::TextArea;
+
+use super::key_finder_search::{KeyMatch, find_matches};
+
+fn create_textarea() -> TextArea<'static> {
+    let mut textarea = TextArea::default();
+    textarea.set_cursor_line_style(Style::default());
+    textarea.set_cursor_style(Style::default().add_modifier(Modifier::REVERSED));
+    textarea
+}
+
+/// Sidebar finding keys and values anywhere in the input (Ctrl+F in the
+/// results pane)
+pub struct KeyFinderState {
+    visible: bool,
+    textarea: TextArea<'static>,
+    /// Documents of the input, parsed when the sidebar opens
+    values: Vec<Value>,
+    matches: Vec<KeyMatch>,
+    /// Whether there are more matches than listed
+    truncated: bool,
+    selected: usize,
+}
+
+impl Default for KeyFinderState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl KeyFinderState {
+    pub fn new() -> Self {
+        Self {
+            visible: false,
+            textarea: create_textarea(),
+            values: Vec::new(),
+            matches: Vec::new(),
+            truncated: false,
+            selected: 0,
+        }
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    /// Show the sidebar searching `values`, keeping the last search
+    pub fn open(&mut self, values: Vec<Value>) {
+        self.values = values;
+        self.visible = true;
+        self.search();
+    }
+
+    /// Hide the sidebar, dropping the parsed input
+    pub fn close(&mut self) {
+        self.visible = false;
+        self.values = Vec::new();
+        self.matches = Vec::new();
+    }
+
+    pub fn query(&self) -> &str {
+        self.textarea.lines()[0].as_str()
+    }
+
+    pub fn textarea_mut(&mut self) -> &mut TextArea<'static> {
+        &mut self.textarea
+    }
+
+    /// List the matches of the text in the search field
+    pub fn search(&mut self) {
+        let (matches, truncated) = find_matches(&self.values, self.query());
+        self.matches = matches;
+        self.truncated = truncated;
+        self.selected = 0;
+    }
+
+    pub fn matches(&self) -> &[KeyMatch] {
+        &self.matches
+    }
+
+    pub fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+
+    pub fn selected(&self) -> usize {
+        self.selected
+    }
+
+    pub fn selected_match(&self) -> Option<&KeyMatch> {
+        self.matches.get(self.selected)
+    }
+
+    pub fn select_next(&mut self) {
+        if self.selected + 1 < self.matches.len() {
+            self.selected += 1;
+        }
+    }
+
+    pub fn select_prev(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+}
+
+#[cfg(test)]
+#[path = "key_finder_state_tests.rs"]
+mod key_finder_state_tests;
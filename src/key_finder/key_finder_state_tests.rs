@@ -0,0 +1,43 @@
+//! Tests for key_finder_state
+
+use serde_json::json;
+
+use super::*;
+
+fn state_searching(text: &str) -> KeyFinderState {
+    let mut state = KeyFinderState::new();
+    state.open(vec![json!({"name": "a", "names": ["b"], "other": 1})]);
+    state.textarea_mut().insert_str(text);
+    state.search();
+    state
+}
+
+#[test]
+fn test_search_lists_matches_and_selects_first() {
+    let state = state_searching("name");
+    assert_eq!(state.matches().len(), 2);
+    assert_eq!(state.selected_match().unwrap().path, ".name");
+}
+
+#[test]
+fn test_selection_stays_within_matches() {
+    let mut state = state_searching("name");
+    state.select_prev();
+    assert_eq!(state.selected(), 0);
+    state.select_next();
+    state.select_next();
+    assert_eq!(state.selected(), 1);
+    assert_eq!(state.selected_match().unwrap().path, ".names");
+}
+
+#[test]
+fn test_reopening_keeps_search_text() {
+    let mut state = state_searching("other");
+    state.close();
+    assert!(!state.is_visible());
+    assert!(state.matches().is_empty());
+
+    state.open(vec![json!({"other": 2})]);
+    assert_eq!(state.query(), "other");
+    assert_eq!(state.matches().len(), 1);
+}
@@ -3,9 +3,11 @@
 //! This module provides region tracking for position-aware mouse interactions.
 //! The `LayoutRegions` struct tracks where UI components are rendered, and
 //! `region_at()` determines which component is at a given screen position.
+//! It also renders the status line at the bottom of the screen.
 
 mod layout_hit_test;
 mod layout_regions;
+pub mod status_line;
 
 #[allow(unused_imports)]
 pub use layout_hit_test::region_at;
@@ -20,3 +22,7 @@ mod layout_regions_tests;
 #[cfg(test)]
 #[path = "layout/layout_hit_test_tests.rs"]
 mod layout_hit_test_tests;
+
+#[cfg(test)]
+#[path = "layout/status_line_tests.rs"]
+mod status_line_tests;
@@ -88,6 +88,13 @@ pub fn region_at(regions: &LayoutRegions, x: u16, y: u16) -> Option<Region> {
         return Some(Region::InputField);
     }
 
+    // Breadcrumb bar (under the input field)
+    if let Some(rect) = &regions.breadcrumbs
+        && contains(rect, x, y)
+    {
+        return Some(Region::Breadcrumbs);
+    }
+
     // Results pane (checked last as it's the largest base area)
     if let Some(rect) = &regions.results_pane
         && contains(rect, x, y)
@@ -12,6 +12,7 @@ pub enum Region {
     ResultsPane,
     InputField,
     SearchBar,
+    Breadcrumbs,
 
     // Popups
     AiWindow,
@@ -36,6 +37,7 @@ pub struct LayoutRegions {
     pub results_pane: Option<Rect>,
     pub input_field: Option<Rect>,
     pub search_bar: Option<Rect>,
+    pub breadcrumbs: Option<Rect>,
 
     // Popups (only populated when visible)
     pub ai_window: Option<Rect>,
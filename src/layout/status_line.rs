@@ -0,0 +1,116 @@
+//! Status line
+//!
+//! One line above the help line describing the input (source, size, format,
+//! number of documents) on the left and the last query (engine, result type
+//! and count, execution time) on the right.
+
+use ratatui::{
+    Frame,
+    layout::{Alignment, Rect},
+    style::Style,
+    text::{Line, Span},
+    widgets::Paragraph,
+};
+
+use crate::app::App;
+use crate::input::input_info::format_size;
+use crate::theme;
+
+const SEPARATOR: &str = " \u{2502} ";
+
+fn join_segments(segments: Vec<Span<'static>>) -> Vec<Span<'static>> {
+    let separator = Span::styled(
+        SEPARATOR,
+        Style::default().fg(theme::status_line::SEPARATOR),
+    );
+    let mut spans = Vec::with_capacity(segments.len() * 2 + 1);
+    spans.push(Span::raw(" "));
+    for (i, segment) in segments.into_iter().enumerate() {
+        if i > 0 {
+            spans.push(separator.clone());
+        }
+        spans.push(segment);
+    }
+    spans.push(Span::raw(" "));
+    spans
+}
+
+fn input_segments(app: &App) -> Vec<Span<'static>> {
+    let Some(info) = &app.input_info else {
+        return vec![Span::styled(
+            "Loading",
+            Style::default().fg(theme::status_line::TEXT),
+        )];
+    };
+
+    let documents = if info.documents == 1 {
+        "1 document".to_string()
+    } else {
+        format!("{} documents", info.documents)
+    };
+    vec![
+        Span::styled(
+            info.source.clone(),
+            Style::default().fg(theme::status_line::SOURCE),
+        ),
+        Span::styled(
+            format_size(info.size),
+            Style::default().fg(theme::status_line::TEXT),
+        ),
+        Span::styled(
+            info.format.label(),
+            Style::default().fg(theme::status_line::TEXT),
+        ),
+        Span::styled(documents, Style::default().fg(theme::status_line::TEXT)),
+    ]
+}
+
+fn query_segments(app: &App) -> Vec<Span<'static>> {
+    let Some(query_state) = &app.query else {
+        return Vec::new();
+    };
+    let text = Style::default().fg(theme::status_line::TEXT);
+
+    let mut segments = vec![Span::styled(query_state.language.label(), text)];
+    if query_state.is_pending() {
+        segments.push(Span::styled("Running", text));
+    } else if query_state.result.is_err() {
+        segments.push(Span::styled(
+            "Error",
+            Style::default().fg(theme::status_line::ERROR),
+        ));
+    } else if let Some(stats) = app.stats.display() {
+        segments.push(Span::styled(stats, text));
+    }
+    if let Some(ms) = query_state.cached_execution_time_ms {
+        segments.push(Span::styled(
+            crate::results::results_render::format_execution_time(ms),
+            text,
+        ));
+    }
+    segments
+}
+
+pub fn render_line(app: &App, frame: &mut Frame, area: Rect) {
+    let style = Style::default().bg(theme::status_line::BACKGROUND);
+    frame.render_widget(Paragraph::new("").style(style), area);
+
+    let right = Line::from(join_segments(query_segments(app)));
+    let right_width = (right.width() as u16).min(area.width);
+    frame.render_widget(
+        Paragraph::new(right)
+            .alignment(Alignment::Right)
+            .style(style),
+        area,
+    );
+
+    // The input description gives way to the query description on narrow terminals
+    let left_area = Rect {
+        width: area.width.saturating_sub(right_width),
+        ..area
+    };
+    frame.render_widget(
+        Paragraph::new(Line::from(join_segments(input_segments(app)))).style(style),
+        left_area,
+    );
+}
@@ -0,0 +1,77 @@
+//! Tests for the status line
+
+use super::status_line::render_line;
+use crate::app::App;
+use crate::input::input_format::InputFormat;
+use crate::input::input_info::InputInfo;
+use crate::test_utils::test_helpers::{app_with_query, test_app};
+use insta::assert_snapshot;
+use ratatui::Terminal;
+use ratatui::backend::TestBackend;
+
+fn render_status_line(app: &App, width: u16) -> String {
+    let backend = TestBackend::new(width, 1);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal.draw(|f| render_line(app, f, f.area())).unwrap();
+    terminal.backend().to_string()
+}
+
+fn cluster_info() -> InputInfo {
+    InputInfo {
+        source: "cluster.json".to_string(),
+        size: 3 * 1024 * 1024 / 2,
+        format: InputFormat::Json,
+        documents: 12,
+    }
+}
+
+#[test]
+fn test_loaded_input_is_described() {
+    let app = test_app("{\"a\": 1}\n{\"a\": 2}");
+    let info = app.input_info.as_ref().unwrap();
+    assert_eq!(info.source, "stdin");
+    assert_eq!(info.format, InputFormat::Json);
+    assert_eq!(info.documents, 2);
+}
+
+#[test]
+fn snapshot_status_line() {
+    let mut app = app_with_query(".services");
+    app.update_stats();
+    app.input_info = Some(cluster_info());
+    assert_snapshot!(render_status_line(&app, 100));
+}
+
+#[test]
+fn snapshot_status_line_with_execution_time() {
+    let mut app = app_with_query(".services");
+    app.update_stats();
+    app.input_info = Some(cluster_info());
+    if let Some(query_state) = &mut app.query {
+        query_state.cached_execution_time_ms = Some(1250);
+    }
+    assert_snapshot!(render_status_line(&app, 100));
+}
+
+#[test]
+fn snapshot_status_line_error() {
+    let mut app = app_with_query(".[");
+    app.input_info = Some(cluster_info());
+    assert_snapshot!(render_status_line(&app, 100));
+}
+
+#[test]
+fn snapshot_status_line_narrow_keeps_query_description() {
+    let mut app = app_with_query(".services");
+    app.update_stats();
+    app.input_info = Some(cluster_info());
+    assert_snapshot!(render_status_line(&app, 40));
+}
+
+#[test]
+fn snapshot_status_line_loading() {
+    let mut app = test_app("{}");
+    app.query = None;
+    app.input_info = None;
+    assert_snapshot!(render_status_line(&app, 60));
+}
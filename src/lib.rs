@@ -1,34 +1,72 @@
 //! jiq library - Interactive JSON query tool
 //!
 //! This library exposes the core functionality of jiq for testing purposes.
+//! [`Session`] runs queries and autocomplete without a terminal, for tools
+//! and integration tests that embed jiq's engine.
 
 pub mod ai;
 pub mod app;
 pub mod autocomplete;
+pub mod bookmarks;
+pub mod cli;
 pub mod clipboard;
+pub mod command_line;
 pub mod config;
+pub mod crash;
 pub mod editor;
 pub mod error;
+pub mod exit_prompt;
+pub mod external;
+pub mod filter_builder;
+pub mod headless;
 pub mod help;
+pub mod histogram;
 pub mod history;
+pub mod in_place;
 pub mod input;
 pub mod json;
+pub mod key_finder;
 pub mod layout;
+pub mod lint;
+pub mod logging;
 pub mod notification;
+pub mod paste;
+pub mod paths;
+pub mod permalink;
+pub mod pipeline;
+pub mod plugins;
+pub mod projection;
 pub mod query;
+pub mod query_tabs;
+pub mod recording;
+pub mod recovery;
 pub mod results;
+pub mod schema;
+pub mod screen_reader;
 pub mod scroll;
 pub mod search;
+pub mod session;
+pub mod shell;
 pub mod snippets;
 pub mod stats;
 pub mod syntax_highlight;
+pub mod templates;
 
 #[cfg(test)]
 pub mod test_utils;
 pub mod theme;
 pub mod tooltip;
+pub mod value_edit;
+pub mod variables;
 pub mod widgets;
 
 // Re-export commonly used types for convenience
 pub use app::{App, Focus, OutputMode};
 pub use config::Config;
+
+// Headless API for embedding jiq without a terminal
+pub use autocomplete::{Suggestion, SuggestionType};
+pub use headless::Session;
+pub use input::input_format::{InputFormat, InputOptions};
+pub use query::language::QueryLanguage;
+pub use query::variables::{QueryVariable, VariableKind};
@@ -0,0 +1,16 @@
+//! Input lint
+//!
+//! `:lint` scans the raw input for things JSON parsers tolerate or quietly
+//! change but downstream systems may choke on: duplicate keys, nesting too
+//! deep for common parsers, unpaired `\u` surrogate escapes and numbers a
+//! double can't hold exactly. Each is listed with its path and line.
+
+mod lint_events;
+mod lint_render;
+mod lint_scanner;
+mod lint_state;
+
+pub use lint_events::{handle_lint_key, lint_input};
+pub use lint_render::render_lint_popup;
+pub use lint_scanner::{LintIssue, LintReport, lint};
+pub use lint_state::LintState;
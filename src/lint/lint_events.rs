@@ -0,0 +1,35 @@
+use ratatui::crossterm::event::{KeyCode, KeyEvent};
+
+use super::lint;
+use crate::app::App;
+use crate::scroll::handle_scroll_key;
+
+/// Lint the raw input, for `:lint`
+///
+/// Clean input is reported in a notification, otherwise the issues are
+/// listed in a popup.
+pub fn lint_input(app: &mut App) {
+    let Some(query) = app.query.as_ref() else {
+        app.notification.show_warning("Input is still loading");
+        return;
+    };
+
+    match lint(query.executor.json_input()) {
+        Ok(report) if report.total == 0 => app.notification.show("No lint issues in the input"),
+        Ok(report) => app.lint.open(report),
+        Err(e) => app.notification.show_error(&e),
+    }
+}
+
+pub fn handle_lint_key(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('q') => app.lint.close(),
+        _ => {
+            handle_scroll_key(&mut app.lint.scroll, key);
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "lint_events_tests.rs"]
+mod lint_events_tests;
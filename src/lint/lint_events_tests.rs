@@ -0,0 +1,37 @@
+//! Tests for lint_events
+
+use super::*;
+use crate::test_utils::test_helpers::{key, test_app};
+
+#[test]
+fn test_clean_input_is_reported() {
+    let mut app = test_app(r#"{"a": 1}"#);
+    lint_input(&mut app);
+
+    assert_eq!(
+        app.notification.current_message(),
+        Some("No lint issues in the input")
+    );
+    assert!(!app.lint.is_visible());
+}
+
+#[test]
+fn test_issues_open_popup() {
+    let mut app = test_app(r#"{"id": 1, "id": 2, "big": 18446744073709551617}"#);
+    lint_input(&mut app);
+
+    let report = app.lint.report().unwrap();
+    assert_eq!(report.total, 2);
+    assert_eq!(report.issues[0].path, ".id");
+    assert_eq!(report.issues[1].path, ".big");
+}
+
+#[test]
+fn test_esc_closes_popup() {
+    let mut app = test_app(r#"{"id": 1, "id": 2}"#);
+    lint_input(&mut app);
+    assert!(app.lint.is_visible());
+
+    handle_lint_key(&mut app, key(KeyCode::Esc));
+    assert!(!app.lint.is_visible());
+}
@@ -0,0 +1,133 @@
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::Style,
+    text::{Line, Span, Text},
+    widgets::{Block, BorderType, Borders, Paragraph},
+};
+
+use super::{LintIssue, LintReport};
+use crate::ai::render::text::wrap_text;
+use crate::app::App;
+use crate::theme;
+use crate::widgets::{popup, scrollbar};
+
+/// Indentation of an issue's message under its path
+const MESSAGE_INDENT: usize = 3;
+
+fn build_hints() -> Line<'static> {
+    theme::border_hints::build_hints(
+        &[("j/k", "Scroll"), ("g/G", "Top/Bottom"), ("Esc", "Close")],
+        theme::lint::BORDER,
+    )
+}
+
+fn issue_lines(issue: &LintIssue, text_width: usize) -> Vec<Line<'static>> {
+    let mut header = vec![Span::raw(" ")];
+    if let Some(index) = issue.value_index {
+        header.push(Span::styled(
+            format!("#{} ", index + 1),
+            Style::default().fg(theme::lint::NOTE),
+        ));
+    }
+    header.push(Span::styled(issue.path.clone(), theme::lint::PATH));
+    header.push(Span::styled(
+        format!("  {}", issue.kind.label()),
+        theme::lint::KIND,
+    ));
+    header.push(Span::styled(
+        format!("  line {}", issue.line),
+        Style::default().fg(theme::lint::NOTE),
+    ));
+
+    let mut lines = vec![Line::from(header)];
+    lines.extend(
+        wrap_text(&issue.message, text_width)
+            .into_iter()
+            .map(|message| {
+                Line::from(vec![
+                    Span::raw(" ".repeat(MESSAGE_INDENT)),
+                    Span::styled(message, Style::default().fg(theme::lint::MESSAGE)),
+                ])
+            }),
+    );
+    lines
+}
+
+fn title(report: &LintReport) -> String {
+    let noun = if report.total == 1 { "issue" } else { "issues" };
+    if report.issues.len() < report.total {
+        format!(
+            " First {} of {} lint {} in the input ",
+            report.issues.len(),
+            report.total,
+            noun
+        )
+    } else {
+        format!(" {} lint {} in the input ", report.total, noun)
+    }
+}
+
+/// Render the popup listing the lint issues of the input
+pub fn render_lint_popup(app: &mut App, frame: &mut Frame) -> Option<Rect> {
+    let frame_area = frame.area();
+    if frame_area.width < 30 || frame_area.height < 8 {
+        return None;
+    }
+    let report = app.lint.report()?;
+
+    let popup_width = ((frame_area.width as f32 * 0.8) as u16)
+        .clamp(50, 100)
+        .min(frame_area.width.saturating_sub(4));
+    let popup_height = ((frame_area.height as f32 * 0.7) as u16)
+        .clamp(8, 30)
+        .min(frame_area.height.saturating_sub(2));
+    let popup_area = popup::centered_popup(frame_area, popup_width, popup_height);
+    popup::clear_area(frame, popup_area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(Line::from(Span::styled(title(report), theme::lint::TITLE)))
+        .title_bottom(build_hints().centered())
+        .border_style(Style::default().fg(theme::lint::BORDER))
+        .style(Style::default().bg(theme::lint::BACKGROUND));
+    let content_area = block.inner(popup_area);
+    frame.render_widget(block, popup_area);
+
+    let text_width = (content_area.width as usize).saturating_sub(MESSAGE_INDENT + 1);
+    let lines: Vec<Line> = report
+        .issues
+        .iter()
+        .flat_map(|issue| issue_lines(issue, text_width))
+        .collect();
+
+    let content_height = lines.len() as u32;
+    let scroll = &mut app.lint.scroll;
+    scroll.update_bounds(content_height, content_area.height);
+    frame.render_widget(
+        Paragraph::new(Text::from(lines)).scroll((scroll.offset, 0)),
+        content_area,
+    );
+
+    let scrollbar_area = Rect {
+        x: popup_area.x,
+        y: popup_area.y.saturating_add(1),
+        width: popup_area.width,
+        height: popup_area.height.saturating_sub(2),
+    };
+    scrollbar::render_vertical_scrollbar_styled(
+        frame,
+        scrollbar_area,
+        content_height as usize,
+        scroll.viewport_height as usize,
+        scroll.offset as usize,
+        theme::lint::BORDER,
+    );
+
+    Some(popup_area)
+}
+
+#[cfg(test)]
+#[path = "lint_render_tests.rs"]
+mod lint_render_tests;
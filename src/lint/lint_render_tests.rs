@@ -0,0 +1,56 @@
+//! Tests for lint_render
+
+use super::*;
+use crate::lint::lint;
+use crate::test_utils::test_helpers::test_app;
+use insta::assert_snapshot;
+use ratatui::Terminal;
+use ratatui::backend::TestBackend;
+
+fn render_to_string(app: &mut App, width: u16, height: u16) -> String {
+    let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+    terminal
+        .draw(|f| {
+            render_lint_popup(app, f);
+        })
+        .unwrap();
+    terminal.backend().to_string()
+}
+
+#[test]
+fn snapshot_lint_issues() {
+    let mut app = test_app(r#"{"a": 1}"#);
+    let report = lint(
+        "{\n  \"user\": {\"id\": 1, \"id\": 2},\n  \"balance\": 12345678901234567890,\n  \"note\": \"\\ud800\"\n}",
+    )
+    .unwrap();
+    app.lint.open(report);
+
+    assert_snapshot!(render_to_string(&mut app, 90, 20));
+}
+
+#[test]
+fn test_title_counts_issues() {
+    let report = lint(r#"{"a": 1, "a": 2}"#).unwrap();
+    assert_eq!(title(&report), " 1 lint issue in the input ");
+
+    let truncated = LintReport {
+        total: 1500,
+        ..report
+    };
+    assert_eq!(
+        title(&truncated),
+        " First 1 of 1500 lint issues in the input "
+    );
+}
+
+#[test]
+fn test_nothing_rendered_when_closed() {
+    let mut app = test_app(r#"{"a": 1}"#);
+    let mut terminal = Terminal::new(TestBackend::new(80, 20)).unwrap();
+    let mut area = Some(Rect::default());
+    terminal
+        .draw(|f| area = render_lint_popup(&mut app, f))
+        .unwrap();
+    assert_eq!(area, None);
+}
@@ -0,0 +1,458 @@
+use std::collections::HashSet;
+
+use crate::query::language::jq_field;
+
+/// Nesting deeper than this is reported; serde_json and many other parsers
+/// give up at 128 levels
+pub const MAX_DEPTH: usize = 100;
+
+/// Issues kept for the popup; later ones are only counted
+pub const MAX_ISSUES: usize = 1000;
+
+/// Integers beyond ±2^53 can't all be represented by a double
+const MAX_SAFE_INTEGER: u64 = 1 << 53;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LintKind {
+    DuplicateKey,
+    DeepNesting,
+    LoneSurrogate,
+    PrecisionLoss,
+}
+
+impl LintKind {
+    pub fn label(self) -> &'static str {
+        match self {
+            LintKind::DuplicateKey => "duplicate key",
+            LintKind::DeepNesting => "deep nesting",
+            LintKind::LoneSurrogate => "invalid escape",
+            LintKind::PrecisionLoss => "precision loss",
+        }
+    }
+}
+
+/// Something in the input that parsers accept differently or silently change
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LintIssue {
+    pub kind: LintKind,
+    /// Index of the input value, when the input holds several
+    pub value_index: Option<usize>,
+    /// jq path of the offending value within its input value
+    pub path: String,
+    /// 1-based line of the input
+    pub line: usize,
+    pub message: String,
+}
+
+/// Issues found in the input
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LintReport {
+    /// The first [`MAX_ISSUES`] issues in input order
+    pub issues: Vec<LintIssue>,
+    /// Issues found, including those not kept
+    pub total: usize,
+}
+
+/// Check the raw JSON text `input`, which may hold several values
+///
+/// The text is scanned rather than parsed into values, since parsing is
+/// exactly what drops duplicate keys and rounds numbers.
+pub fn lint(input: &str) -> Result<LintReport, String> {
+    let mut scanner = Scanner::new(input);
+    let mut values = 0;
+    loop {
+        scanner.skip_whitespace();
+        if scanner.pos >= scanner.bytes.len() {
+            break;
+        }
+        scanner.value_index = values;
+        scanner.value()?;
+        values += 1;
+    }
+
+    let mut report = scanner.report;
+    if values < 2 {
+        for issue in &mut report.issues {
+            issue.value_index = None;
+        }
+    }
+    Ok(report)
+}
+
+/// Container being scanned, with what's needed to build paths
+enum Frame {
+    Object {
+        keys: HashSet<String>,
+        key: Option<String>,
+    },
+    Array {
+        index: usize,
+    },
+}
+
+struct Scanner<'a> {
+    input: &'a str,
+    bytes: &'a [u8],
+    pos: usize,
+    line: usize,
+    stack: Vec<Frame>,
+    value_index: usize,
+    report: LintReport,
+}
+
+impl<'a> Scanner<'a> {
+    fn new(input: &'a str) -> Self {
+        Self {
+            input,
+            bytes: input.as_bytes(),
+            pos: 0,
+            line: 1,
+            stack: Vec::new(),
+            value_index: 0,
+            report: LintReport::default(),
+        }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.pos).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(byte) = self.peek() {
+            match byte {
+                b'\n' => self.line += 1,
+                b' ' | b'\t' | b'\r' => {}
+                _ => break,
+            }
+            self.pos += 1;
+        }
+    }
+
+    fn error(&self, expected: &str) -> String {
+        format!(
+            "Input is not JSON: expected {} at line {}",
+            expected, self.line
+        )
+    }
+
+    /// jq path of the value being scanned
+    fn path(&self) -> String {
+        let mut path = String::new();
+        for frame in &self.stack {
+            match frame {
+                Frame::Object { key: Some(key), .. } => path.push_str(&jq_field(key)),
+                Frame::Object { key: None, .. } => {}
+                Frame::Array { index } => path.push_str(&format!("[{}]", index)),
+            }
+        }
+
+        if path.is_empty() {
+            ".".to_string()
+        } else if path.starts_with('[') {
+            format!(".{}", path)
+        } else {
+            path
+        }
+    }
+
+    fn report(&mut self, kind: LintKind, message: String) {
+        self.report.total += 1;
+        if self.report.issues.len() < MAX_ISSUES {
+            let issue = LintIssue {
+                kind,
+                value_index: Some(self.value_index),
+                path: self.path(),
+                line: self.line,
+                message,
+            };
+            self.report.issues.push(issue);
+        }
+    }
+
+    /// Scan one value, containers included
+    ///
+    /// Containers are tracked on a stack instead of by recursion, so deeply
+    /// nested input can't overflow the call stack.
+    fn value(&mut self) -> Result<(), String> {
+        let base = self.stack.len();
+        loop {
+            self.skip_whitespace();
+            match self.peek() {
+                Some(b'{') => {
+                    self.pos += 1;
+                    self.open(Frame::Object {
+                        keys: HashSet::new(),
+                        key: None,
+                    });
+                    self.skip_whitespace();
+                    if self.peek() == Some(b'}') {
+                        self.pos += 1;
+                        self.stack.pop();
+                    } else {
+                        self.object_key()?;
+                        continue;
+                    }
+                }
+                Some(b'[') => {
+                    self.pos += 1;
+                    self.open(Frame::Array { index: 0 });
+                    self.skip_whitespace();
+                    if self.peek() == Some(b']') {
+                        self.pos += 1;
+                        self.stack.pop();
+                    } else {
+                        continue;
+                    }
+                }
+                Some(b'"') => {
+                    self.string(false)?;
+                }
+                Some(b'-' | b'0'..=b'9') => self.number(),
+                Some(b't' | b'f' | b'n') => self.literal()?,
+                _ => return Err(self.error("a value")),
+            }
+
+            // Close finished containers and move on to the next member
+            loop {
+                if self.stack.len() == base {
+                    return Ok(());
+                }
+                self.skip_whitespace();
+                let byte = self.peek();
+                match (self.stack.last_mut(), byte) {
+                    (Some(Frame::Array { index }), Some(b',')) => {
+                        *index += 1;
+                        self.pos += 1;
+                        break;
+                    }
+                    (Some(Frame::Object { .. }), Some(b',')) => {
+                        self.pos += 1;
+                        self.object_key()?;
+                        break;
+                    }
+                    (Some(Frame::Array { .. }), Some(b']'))
+                    | (Some(Frame::Object { .. }), Some(b'}')) => {
+                        self.pos += 1;
+                        self.stack.pop();
+                    }
+                    _ => return Err(self.error("',' or a closing bracket")),
+                }
+            }
+        }
+    }
+
+    /// Enter a container, reporting it when it is nested too deep
+    fn open(&mut self, frame: Frame) {
+        if self.stack.len() == MAX_DEPTH {
+            self.report(
+                LintKind::DeepNesting,
+                format!(
+                    "Nested more than {} levels deep; many parsers refuse input this deep",
+                    MAX_DEPTH
+                ),
+            );
+        }
+        self.stack.push(frame);
+    }
+
+    /// Scan `"key":` of an object member and check it wasn't seen before
+    fn object_key(&mut self) -> Result<(), String> {
+        self.skip_whitespace();
+        if self.peek() != Some(b'"') {
+            return Err(self.error("an object key"));
+        }
+        let key = self.string(true)?;
+        self.skip_whitespace();
+        if self.peek() != Some(b':') {
+            return Err(self.error("':'"));
+        }
+        self.pos += 1;
+
+        let Some(Frame::Object { keys, key: current }) = self.stack.last_mut() else {
+            unreachable!("object keys are only scanned inside objects");
+        };
+        let duplicate = !keys.insert(key.clone());
+        *current = Some(key);
+        if duplicate {
+            self.report(
+                LintKind::DuplicateKey,
+                "Key appears more than once in the object; jq keeps the last value, other parsers may keep the first or reject it".to_string(),
+            );
+        }
+        Ok(())
+    }
+
+    /// Scan a string, returning its decoded text when `keep` is set
+    fn string(&mut self, keep: bool) -> Result<String, String> {
+        self.pos += 1;
+        let mut text = String::new();
+        let mut run = self.pos;
+        loop {
+            match self.peek() {
+                Some(b'"') => {
+                    if keep {
+                        text.push_str(&self.input[run..self.pos]);
+                    }
+                    self.pos += 1;
+                    return Ok(text);
+                }
+                Some(b'\\') => {
+                    if keep {
+                        text.push_str(&self.input[run..self.pos]);
+                    }
+                    self.pos += 1;
+                    let decoded = self.escape()?;
+                    if keep {
+                        text.push(decoded);
+                    }
+                    run = self.pos;
+                }
+                Some(_) => self.pos += 1,
+                None => return Err(self.error("'\"'")),
+            }
+        }
+    }
+
+    /// Decode the escape after a backslash, reporting unpaired surrogates
+    fn escape(&mut self) -> Result<char, String> {
+        let byte = self.peek().ok_or_else(|| self.error("an escape"))?;
+        self.pos += 1;
+        let decoded = match byte {
+            b'"' => '"',
+            b'\\' => '\\',
+            b'/' => '/',
+            b'b' => '\u{8}',
+            b'f' => '\u{c}',
+            b'n' => '\n',
+            b'r' => '\r',
+            b't' => '\t',
+            b'u' => {
+                let code = self.hex4()?;
+                match code {
+                    0xD800..=0xDBFF => {
+                        let low = self.input[self.pos..]
+                            .strip_prefix("\\u")
+                            .and_then(|rest| rest.get(..4))
+                            .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                            .filter(|low| (0xDC00..=0xDFFF).contains(low));
+                        match low {
+                            Some(low) => {
+                                self.pos += 6;
+                                let combined = 0x10000 + ((code - 0xD800) << 10) + (low - 0xDC00);
+                                char::from_u32(combined).unwrap_or(char::REPLACEMENT_CHARACTER)
+                            }
+                            None => {
+                                self.lone_surrogate(code);
+                                char::REPLACEMENT_CHARACTER
+                            }
+                        }
+                    }
+                    0xDC00..=0xDFFF => {
+                        self.lone_surrogate(code);
+                        char::REPLACEMENT_CHARACTER
+                    }
+                    _ => char::from_u32(code).unwrap_or(char::REPLACEMENT_CHARACTER),
+                }
+            }
+            _ => return Err(self.error("a valid escape")),
+        };
+        Ok(decoded)
+    }
+
+    fn hex4(&mut self) -> Result<u32, String> {
+        let code = self
+            .input
+            .get(self.pos..self.pos + 4)
+            .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+            .ok_or_else(|| self.error("4 hex digits after \\u"))?;
+        self.pos += 4;
+        Ok(code)
+    }
+
+    fn lone_surrogate(&mut self, code: u32) {
+        self.report(
+            LintKind::LoneSurrogate,
+            format!(
+                "\\u{:04X} is half of a UTF-16 surrogate pair; it is replaced by U+FFFD or rejected",
+                code
+            ),
+        );
+    }
+
+    fn number(&mut self) {
+        let start = self.pos;
+        while let Some(b'0'..=b'9' | b'-' | b'+' | b'.' | b'e' | b'E') = self.peek() {
+            self.pos += 1;
+        }
+        let literal = &self.input[start..self.pos];
+        if let Some(message) = precision_loss(literal) {
+            self.report(LintKind::PrecisionLoss, message);
+        }
+    }
+
+    fn literal(&mut self) -> Result<(), String> {
+        for word in ["true", "false", "null"] {
+            if self.input[self.pos..].starts_with(word) {
+                self.pos += word.len();
+                return Ok(());
+            }
+        }
+        Err(self.error("a value"))
+    }
+}
+
+/// Why `literal` doesn't survive being read as a double, if it doesn't
+fn precision_loss(literal: &str) -> Option<String> {
+    let number: f64 = literal.parse().ok()?;
+    if !number.is_finite() {
+        return Some(format!("{} is out of range for a double", literal));
+    }
+    let is_integer = !literal.contains(['.', 'e', 'E']);
+    if is_integer && number.abs() < MAX_SAFE_INTEGER as f64 {
+        return None;
+    }
+    if decimal_digits(literal)? == decimal_digits(&format!("{:e}", number))? {
+        return None;
+    }
+    Some(format!(
+        "{} can't be represented exactly as a double and becomes {}",
+        literal,
+        format_double(number, is_integer)
+    ))
+}
+
+/// How a double is printed, as an integer when the literal was one
+fn format_double(number: f64, is_integer: bool) -> String {
+    if is_integer && number.abs() < 1e21 {
+        format!("{:.0}", number)
+    } else {
+        number.to_string()
+    }
+}
+
+/// Sign, significant digits and exponent of a decimal literal, so `1.50`
+/// and `15e-1` compare equal: `(false, "15", -1)`
+fn decimal_digits(literal: &str) -> Option<(bool, String, i64)> {
+    let (negative, unsigned) = match literal.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, literal),
+    };
+    let (mantissa, exponent) = match unsigned.split_once(['e', 'E']) {
+        Some((mantissa, exponent)) => (mantissa, exponent.parse::<i64>().ok()?),
+        None => (unsigned, 0),
+    };
+    let (whole, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+
+    let digits = format!("{}{}", whole, fraction);
+    let digits = digits.trim_start_matches('0');
+    let trimmed = digits.trim_end_matches('0');
+    if trimmed.is_empty() {
+        return Some((false, String::new(), 0));
+    }
+    let exponent = exponent - fraction.len() as i64 + (digits.len() - trimmed.len()) as i64;
+    Some((negative, trimmed.to_string(), exponent))
+}
+
+#[cfg(test)]
+#[path = "lint_scanner_tests.rs"]
+mod lint_scanner_tests;
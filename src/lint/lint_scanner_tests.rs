@@ -0,0 +1,133 @@
+//! Tests for lint_scanner
+
+use super::*;
+
+fn kinds(report: &LintReport) -> Vec<LintKind> {
+    report.issues.iter().map(|issue| issue.kind).collect()
+}
+
+#[test]
+fn test_clean_input_has_no_issues() {
+    let report = lint(r#"{"a": [1, 2.5, -0.1, 1e3, true, null], "b": {"c": "é\n"}}"#).unwrap();
+    assert_eq!(report, LintReport::default());
+}
+
+#[test]
+fn test_duplicate_key_reported_with_path_and_line() {
+    let report = lint("{\n  \"items\": [\n    {\"id\": 1,\n     \"id\": 2}\n  ]\n}").unwrap();
+
+    assert_eq!(
+        report.issues,
+        vec![LintIssue {
+            kind: LintKind::DuplicateKey,
+            value_index: None,
+            path: ".items[0].id".to_string(),
+            line: 4,
+            message: "Key appears more than once in the object; jq keeps the last value, other parsers may keep the first or reject it".to_string(),
+        }]
+    );
+    assert_eq!(report.total, 1);
+}
+
+#[test]
+fn test_escaped_duplicate_key_is_detected() {
+    let report = lint(r#"{"name": 1, "name": 2}"#).unwrap();
+    assert_eq!(kinds(&report), vec![LintKind::DuplicateKey]);
+}
+
+#[test]
+fn test_same_key_in_sibling_objects_is_fine() {
+    let report = lint(r#"[{"id": 1}, {"id": 2}]"#).unwrap();
+    assert!(report.issues.is_empty());
+}
+
+#[test]
+fn test_deep_nesting_reported_once_per_container() {
+    let depth = MAX_DEPTH + 5;
+    let input = format!("{}{}", "[".repeat(depth), "]".repeat(depth));
+    let report = lint(&input).unwrap();
+
+    assert_eq!(kinds(&report), vec![LintKind::DeepNesting]);
+    assert_eq!(
+        report.issues[0].path,
+        format!(".{}", "[0]".repeat(MAX_DEPTH))
+    );
+}
+
+#[test]
+fn test_very_deep_input_does_not_overflow() {
+    let depth = 200_000;
+    let input = format!("{}{}", "[".repeat(depth), "]".repeat(depth));
+    assert_eq!(lint(&input).unwrap().total, 1);
+}
+
+#[test]
+fn test_lone_surrogates_are_reported() {
+    let report = lint(r#"{"ok": "😀", "high": "\ud83d!", "low": "\ude00"}"#).unwrap();
+
+    assert_eq!(
+        kinds(&report),
+        vec![LintKind::LoneSurrogate, LintKind::LoneSurrogate]
+    );
+    assert_eq!(report.issues[0].path, ".high");
+    assert!(report.issues[0].message.starts_with("\\uD83D is half"));
+    assert_eq!(report.issues[1].path, ".low");
+}
+
+#[test]
+fn test_numbers_losing_precision() {
+    let report = lint(
+        r#"[9007199254740992, 9007199254740993, 12345678901234567890, 0.1, 1.50, 0.10000000000000000001, 1e400]"#,
+    )
+    .unwrap();
+
+    let paths: Vec<&str> = report
+        .issues
+        .iter()
+        .map(|issue| issue.path.as_str())
+        .collect();
+    assert_eq!(paths, vec![".[1]", ".[2]", ".[5]", ".[6]"]);
+    assert_eq!(
+        report.issues[0].message,
+        "9007199254740993 can't be represented exactly as a double and becomes 9007199254740992"
+    );
+    assert_eq!(
+        report.issues[3].message,
+        "1e400 is out of range for a double"
+    );
+}
+
+#[test]
+fn test_stream_values_are_numbered() {
+    let report = lint("{\"a\": 1}\n{\"a\": 1, \"a\": 2}\n").unwrap();
+    assert_eq!(report.issues[0].value_index, Some(1));
+    assert_eq!(report.issues[0].line, 2);
+}
+
+#[test]
+fn test_issues_beyond_limit_are_counted() {
+    let members: Vec<String> = (0..MAX_ISSUES + 5)
+        .map(|_| "\"k\": 1".to_string())
+        .collect();
+    let report = lint(&format!("{{\"k\": 0, {}}}", members.join(", "))).unwrap();
+
+    assert_eq!(report.issues.len(), MAX_ISSUES);
+    assert_eq!(report.total, MAX_ISSUES + 5);
+}
+
+#[test]
+fn test_malformed_input_is_an_error() {
+    assert_eq!(
+        lint("{\"a\" 1}"),
+        Err("Input is not JSON: expected ':' at line 1".to_string())
+    );
+    assert!(lint("[1, 2").is_err());
+    assert!(lint(r#""\x""#).is_err());
+}
+
+#[test]
+fn test_decimal_digits_normalizes() {
+    assert_eq!(decimal_digits("1.50"), decimal_digits("15e-1"));
+    assert_eq!(decimal_digits("-0.0"), Some((false, String::new(), 0)));
+    assert_eq!(decimal_digits("1200"), Some((false, "12".to_string(), 2)));
+}
@@ -0,0 +1,33 @@
+use super::LintReport;
+use crate::scroll::ScrollState;
+
+/// Popup listing the issues found by the last `:lint`
+#[derive(Debug, Default)]
+pub struct LintState {
+    report: Option<LintReport>,
+    pub scroll: ScrollState,
+}
+
+impl LintState {
+    pub fn is_visible(&self) -> bool {
+        self.report.is_some()
+    }
+
+    pub fn report(&self) -> Option<&LintReport> {
+        self.report.as_ref()
+    }
+
+    /// Show `report` from the top
+    pub fn open(&mut self, report: LintReport) {
+        self.report = Some(report);
+        self.scroll = ScrollState::new();
+    }
+
+    pub fn close(&mut self) {
+        self.report = None;
+    }
+}
+
+#[cfg(test)]
+#[path = "lint_state_tests.rs"]
+mod lint_state_tests;
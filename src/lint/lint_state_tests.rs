@@ -0,0 +1,21 @@
+//! Tests for lint_state
+
+use super::*;
+use crate::lint::lint;
+
+#[test]
+fn test_open_and_close() {
+    let mut state = LintState::default();
+    assert!(!state.is_visible());
+
+    let report = lint(r#"{"a": 1, "a": 2}"#).unwrap();
+    state.scroll.offset = 2;
+    state.open(report.clone());
+    assert!(state.is_visible());
+    assert_eq!(state.report(), Some(&report));
+    assert_eq!(state.scroll.offset, 0);
+
+    state.close();
+    assert!(!state.is_visible());
+    assert_eq!(state.report(), None);
+}
@@ -0,0 +1,67 @@
+//! Debug logging to a file
+//!
+//! Off unless `--log-file FILE` (or `JIQ_LOG_FILE`) names a file to append
+//! to, so release builds can log when diagnosing a user-reported issue.
+//! Levels are set with `--log-level` or `JIQ_LOG` using `RUST_LOG`-style
+//! directives, e.g. `debug` or `info,jiq::query=trace`; the default logs
+//! jiq's own debug messages only. Records from the `log` crate, used by jiq
+//! and some dependencies, are forwarded to the same file.
+//!
+//! Each terminal event and frame is traced under the `jiq::event_loop`
+//! target, only logged when asked for with `jiq::event_loop=trace`.
+
+use std::fs::{self, OpenOptions};
+use std::path::Path;
+use std::sync::Mutex;
+
+use tracing_subscriber::EnvFilter;
+
+/// Directives used when no level is given
+pub const DEFAULT_DIRECTIVES: &str = "jiq=debug";
+
+/// Target of the event loop traces
+pub const EVENT_LOOP_TARGET: &str = "jiq::event_loop";
+
+/// Parse `RUST_LOG`-style `directives`, the default ones when `None`
+pub fn parse_filter(directives: Option<&str>) -> Result<EnvFilter, String> {
+    let directives = directives
+        .map(str::trim)
+        .filter(|d| !d.is_empty())
+        .unwrap_or(DEFAULT_DIRECTIVES);
+    EnvFilter::builder()
+        .parse(directives)
+        .map_err(|e| format!("Invalid log level '{}': {}", directives, e))
+}
+
+/// Append log records passing `directives` to `path`
+pub fn init(path: &Path, directives: Option<&str>) -> Result<(), String> {
+    let filter = parse_filter(directives)?;
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        fs::create_dir_all(parent)
+            .map_err(|e| format!("Cannot create log directory {}: {}", parent.display(), e))?;
+    }
+    let file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .map_err(|e| format!("Cannot open log file {}: {}", path.display(), e))?;
+
+    tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_writer(Mutex::new(file))
+        .with_ansi(false)
+        .with_target(true)
+        .with_thread_names(true)
+        .try_init()
+        .map_err(|e| format!("Cannot start logging: {}", e))?;
+
+    tracing::info!(
+        version = env!("CARGO_PKG_VERSION"),
+        "=== jiq session started ==="
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+#[path = "logging_tests.rs"]
+mod logging_tests;
@@ -0,0 +1,35 @@
+//! Tests for logging
+
+use super::*;
+
+#[test]
+fn test_default_filter() {
+    let filter = parse_filter(None).unwrap();
+    assert_eq!(filter.to_string(), DEFAULT_DIRECTIVES);
+}
+
+#[test]
+fn test_blank_directives_use_default() {
+    let filter = parse_filter(Some("  ")).unwrap();
+    assert_eq!(filter.to_string(), DEFAULT_DIRECTIVES);
+}
+
+#[test]
+fn test_per_module_directives() {
+    let filter = parse_filter(Some("info,jiq::query=trace")).unwrap();
+    let text = filter.to_string();
+    assert!(text.contains("jiq::query=trace"));
+    assert!(text.contains("info"));
+}
+
+#[test]
+fn test_event_loop_directive() {
+    let directive = format!("{}=trace", EVENT_LOOP_TARGET);
+    assert!(parse_filter(Some(&directive)).is_ok());
+}
+
+#[test]
+fn test_invalid_directives_rejected() {
+    let err = parse_filter(Some("jiq=loud")).unwrap_err();
+    assert!(err.contains("Invalid log level 'jiq=loud'"));
+}
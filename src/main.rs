@@ -1,141 +1,372 @@
 use clap::Parser;
 use color_eyre::Result;
+use color_eyre::eyre::{WrapErr, eyre};
 use ratatui::DefaultTerminal;
-use ratatui::crossterm::event::{
-    DisableBracketedPaste, DisableMouseCapture, EnableBracketedPaste, EnableMouseCapture,
-};
+use ratatui::crossterm::event::{EnableBracketedPaste, EnableMouseCapture};
 use ratatui::crossterm::execute;
-use ratatui::crossterm::terminal::{
-    EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode,
-};
-use std::io::stdout;
-use std::path::PathBuf;
+use ratatui::crossterm::terminal::{EnterAlternateScreen, disable_raw_mode, enable_raw_mode};
+use std::io::{Write, stdout};
+use std::sync::Arc;
 
 mod ai;
 mod app;
 mod autocomplete;
+mod bookmarks;
+mod cli;
 mod clipboard;
+mod command_line;
 mod config;
+mod crash;
 mod editor;
 mod error;
+mod exit_prompt;
+mod external;
+mod filter_builder;
 mod help;
+mod histogram;
 mod history;
+mod in_place;
 mod input;
 mod json;
+mod key_finder;
 mod layout;
+mod lint;
+mod logging;
 mod notification;
+mod paste;
+mod paths;
+mod permalink;
+mod pipeline;
+mod plugins;
+mod projection;
 mod query;
+mod query_tabs;
+mod recording;
+mod recovery;
 mod results;
+mod schema;
+mod screen_reader;
 mod scroll;
 mod search;
+mod session;
+mod shell;
 mod snippets;
 mod stats;
 mod syntax_highlight;
+mod templates;
 #[cfg(test)]
 mod test_utils;
 pub mod theme;
 mod tooltip;
+mod value_edit;
+mod variables;
 mod widgets;
 
 use app::{App, OutputMode};
+use cli::{Args, Command, ConfigCommand, OutputFormat};
 use error::JiqError;
-use input::FileLoader;
+use history::HistoryState;
+use input::command_source::CommandSource;
+use input::{FileLoader, input_format};
 use query::executor::JqExecutor;
-
-/// Interactive JSON query tool
-#[derive(Parser, Debug)]
-#[command(
-    version,
-    about = "Interactive JSON query tool with real-time filtering using jq"
-)]
-struct Args {
-    /// Input JSON file (if not provided, reads from stdin)
-    input: Option<PathBuf>,
-}
+use query::library::JqLibrary;
 
 fn main() -> Result<()> {
-    // Writes to /tmp/jiq-debug.log at DEBUG level
-    #[cfg(debug_assertions)]
-    {
-        use std::io::Write;
-
-        let log_file = std::fs::OpenOptions::new()
-            .create(true)
-            .append(true)
-            .open("/tmp/jiq-debug.log")
-            .expect("Failed to open /tmp/jiq-debug.log");
-
-        env_logger::Builder::new()
-            .filter_level(log::LevelFilter::Debug)
-            .target(env_logger::Target::Pipe(Box::new(log_file)))
-            .format(|buf, record| {
-                use std::time::SystemTime;
-                let datetime: chrono::DateTime<chrono::Local> = SystemTime::now().into();
-                writeln!(
-                    buf,
-                    "[{}] [{}] {}",
-                    datetime.format("%Y-%m-%dT%H:%M:%S%.3f"),
-                    record.level(),
-                    record.args()
-                )
-            })
-            .init();
-
-        log::debug!("=== JIQ DEBUG SESSION STARTED ===");
+    color_eyre::install()?;
+
+    let mut args = Args::parse();
+
+    if let Some(path) = args.log_file(std::env::var_os("JIQ_LOG_FILE").as_deref()) {
+        let level = args.log_level(std::env::var_os("JIQ_LOG").as_deref());
+        logging::init(&path, level.as_deref()).map_err(|e| eyre!(e))?;
     }
 
-    color_eyre::install()?;
+    let mut input_command = match args.command {
+        Some(Command::Completions { shell }) => {
+            cli::write_completions(shell, &mut stdout());
+            return Ok(());
+        }
+        Some(Command::Man) => {
+            cli::write_man_page(&mut stdout())?;
+            return Ok(());
+        }
+        Some(Command::Config { ref action }) => {
+            paths::migrate();
+            if !run_config_command(action)? {
+                std::process::exit(1);
+            }
+            return Ok(());
+        }
+        Some(Command::K8s {
+            ref resource,
+            args: ref extra,
+        }) => Some(CommandSource::kubectl(resource, extra)),
+        Some(Command::Aws {
+            ref service,
+            ref operation,
+            args: ref extra,
+        }) => Some(CommandSource::aws(service, operation, extra)),
+        None => None,
+    };
+
+    if let Some(shell) = args.shell_init {
+        print!("{}", shell::init_script(shell));
+        return Ok(());
+    }
+
+    // Before anything is read, so files of earlier versions are found
+    paths::migrate();
 
     // Load config early to avoid defaults during app initialization
-    let config_result = config::load_config();
+    let mut config_overrides = config::layers::env_overrides(std::env::vars_os());
+    config_overrides.extend(args.config_overrides());
+    let config_result = config::load_config(&config_overrides);
 
-    let args = Args::parse();
+    if args.show_config {
+        show_config(&config_result)?;
+        return Ok(());
+    }
+
+    if let Some(path) = &args.import_history {
+        // zsh writes non-ASCII bytes in its own encoding; keep what decodes
+        let bytes = std::fs::read(path)
+            .wrap_err_with(|| format!("Failed to read history file {}", path.display()))?;
+        let queries = history::parse_history(&String::from_utf8_lossy(&bytes), args.import_format);
+        let added = history::storage::import_entries(&queries, None, &config_result.config.history)
+            .wrap_err("Failed to save query history")?;
+        println!(
+            "Imported {} new queries ({} found in {})",
+            added,
+            queries.len(),
+            path.display()
+        );
+        return Ok(());
+    }
 
     validate_jq_exists()?;
 
+    // The session's input is used unless another input is given. A shared
+    // link is opened like a session holding only the query and variables.
+    let session = match (&args.session, &args.open) {
+        (Some(name), _) => Some(session::load_session(name).map_err(|e| eyre!(e))?),
+        (None, Some(link)) => Some(
+            permalink::Permalink::decode(link)
+                .map(session::Session::from)
+                .map_err(|e| eyre!(e))?,
+        ),
+        (None, None) => None,
+    };
+    if let Some(session) = &session
+        && args.input.is_none()
+        && input_command.is_none()
+    {
+        args.input = session.input.clone();
+        input_command = session.command.clone();
+    }
+
+    // Variables given on the command line replace the session's
+    let cli_variables = args.variables().map_err(|e| eyre!(e))?;
+    let mut variables = session
+        .as_ref()
+        .map(|s| s.variables.clone())
+        .unwrap_or_default();
+    variables.retain(|v| !cli_variables.iter().any(|c| c.name == v.name));
+    variables.extend(cli_variables);
+    let plugins = plugins::PluginRegistry::from_config(&config_result.config.plugins)
+        .map_err(|e| eyre!(e))?;
+    if let OutputFormat::Plugin(name) = &args.output_format
+        && plugins.output_format(name).is_none()
+    {
+        return Err(eyre!(
+            "Unknown output format '{}': use json, toml or an output plugin",
+            name
+        ));
+    }
+    let input_options = args
+        .input_options(&config_result.config, &plugins)
+        .map_err(|e| eyre!(e))?;
+    let library_paths = [
+        config_result.config.query.library_paths.clone(),
+        args.library_paths.clone(),
+    ]
+    .concat();
+    let library = JqLibrary::load(library_paths, Some(&config::get_library_path()));
+
+    let replay = args
+        .replay
+        .as_deref()
+        .map(recording::Recording::load)
+        .transpose()
+        .map_err(|e| eyre!(e))?;
+    let schema = args
+        .schema
+        .as_deref()
+        .map(schema::SchemaValidator::load)
+        .transpose()
+        .map_err(|e| eyre!(e))?;
+
+    // Opened before the TUI starts so a bad descriptor fails fast
+    let mut output: Box<dyn Write> = match args.output_fd {
+        Some(fd) => Box::new(
+            shell::open_output_fd(fd)
+                .wrap_err_with(|| format!("Cannot write to output file descriptor {}", fd))?,
+        ),
+        None => Box::new(stdout()),
+    };
+
+    let screen_reader = args.screen_reader(&config_result.config);
+    let no_color = args.no_color(
+        &config_result.config,
+        std::env::var_os("NO_COLOR").as_deref(),
+    );
     let terminal = init_terminal()?;
+    // Hands the terminal back on any early return
+    let _terminal_guard = crash::TerminalGuard;
 
     // Deferred loading prevents blocking on large files/stdin
-    let loader = if let Some(path) = args.input {
-        FileLoader::spawn_load(path)
+    let loader = if let Some(source) = input_command.clone() {
+        FileLoader::spawn_load_command(source)
+    } else if let Some(path) = args.input.clone() {
+        FileLoader::spawn_load_with_options(path, input_options)
     } else {
-        FileLoader::spawn_load_stdin()
+        FileLoader::spawn_load_stdin_with_options(input_options)
     };
 
-    let app = App::new_with_loader(loader, &config_result.config);
-    let result = run(terminal, app, config_result);
+    syntax_highlight::set_theme(syntax_highlight::SyntaxTheme::from_config(
+        &config_result.config.syntax,
+    ));
+    let mut app = App::new_with_loader(loader, &config_result.config);
+    app.input_path = args.input;
+    if let Some(source) = &input_command {
+        app.history = HistoryState::with_source(
+            Some(source.history_key.clone()),
+            config_result.config.history.clone(),
+        );
+    }
+    app.input_command = input_command;
+    app.follow = args.follow;
+    app.in_place_mode = args.in_place;
+    app.variables.set_variables(variables);
+    app.session_name = args.session;
+    app.pending_session = session;
+    app.jq_library = Arc::new(library);
+    app.plugins = plugins;
+    app.schema.validator = schema;
+    app.no_color = no_color;
+    app.sparkline
+        .set_protocol(results::sparkline_image::GraphicsProtocol::detect(|name| {
+            std::env::var(name).ok()
+        }));
+    if screen_reader {
+        app.screen_reader.enable();
+    }
+    if let Some(action) = exit_prompt::exit_menu_state::load_last_action() {
+        app.exit_menu.select(action);
+    }
+    if args.in_place {
+        app.exit_menu
+            .select(exit_prompt::exit_menu_state::ExitAction::InPlace);
+    }
+    let size = terminal.size().ok().map(|size| (size.width, size.height));
+    if let Some(path) = args.record.clone() {
+        app.recorder = Some(recording::EventRecorder::new(path, size));
+    }
+    if let Some(replay) = replay {
+        // Mouse events hit different widgets in a terminal of another size
+        if let (Some((width, height)), Some(current)) = (replay.size, size)
+            && (width, height) != current
+        {
+            app.notification.show_warning(&format!(
+                "Recorded in a {}x{} terminal, mouse input may land elsewhere",
+                width, height
+            ));
+        }
+        app.replayer = Some(recording::EventReplayer::new(replay));
+    }
+    app.config_watcher = Some(config::ConfigWatcher::new(
+        config::get_config_path(),
+        config_result.config.clone(),
+        config_overrides,
+    ));
+    let result = run(terminal, &mut app, config_result);
 
     restore_terminal()?;
-    let app = result?;
+
+    // Saved even if jiq failed, as that is when a recording is most useful
+    if let (Some(recorder), Some(path)) = (&app.recorder, &args.record) {
+        recorder
+            .save()
+            .wrap_err_with(|| format!("Cannot write recording to {}", path.display()))?;
+    }
+    result?;
+
+    // A recovery file left behind means the last run did not get here
+    app.recovery.finish();
+
+    if let Some(action) = app.exit_menu.chosen
+        && let Err(e) = exit_prompt::exit_menu_state::save_last_action(action)
+    {
+        log::debug!("Cannot remember exit action: {}", e);
+    }
 
     // Output after terminal restore to prevent corruption
-    handle_output(&app)?;
+    let output_written = handle_output(&app, &mut output, args.print0, &args.output_format)?;
 
-    #[cfg(debug_assertions)]
-    log::debug!("=== JIQ DEBUG SESSION ENDED ===");
+    tracing::info!("=== jiq session ended ===");
 
+    if !output_written {
+        std::process::exit(1);
+    }
     Ok(())
 }
 
-/// Validate that jq binary exists in PATH
+/// Run `jiq config ACTION`; false when the config has errors
+fn run_config_command(action: &ConfigCommand) -> Result<bool> {
+    let path = config::get_config_path();
+    let mut out = stdout();
+    match action {
+        ConfigCommand::Init { force } => {
+            config::commands::init(&path, *force).map_err(|e| eyre!(e))?;
+            println!("Wrote {}", path.display());
+            Ok(true)
+        }
+        ConfigCommand::Path => {
+            println!("{}", path.display());
+            Ok(true)
+        }
+        ConfigCommand::Validate => config::commands::check(&path, &mut out)
+            .wrap_err_with(|| format!("Cannot read {}", path.display())),
+        ConfigCommand::Edit => config::commands::edit(&path, &mut out).map_err(|e| eyre!(e)),
+    }
+}
+
+/// Print the effective config for `--show-config`, its problems to stderr
+fn show_config(config_result: &config::ConfigResult) -> Result<()> {
+    let path = config::get_config_path();
+    if path.exists() {
+        println!("# {}", path.display());
+    }
+    print!(
+        "{}",
+        config::layers::show(&config_result.config, &config_result.sources)
+            .map_err(|e| eyre!(e))?
+    );
+    for warning in config_result.warning.iter().chain(&config_result.ignored) {
+        eprintln!("{}", warning);
+    }
+    Ok(())
+}
+
+/// Find the jq binary queries run with, see `query::jq_binary`
 fn validate_jq_exists() -> Result<(), JiqError> {
-    which::which("jq").map_err(|_| JiqError::JqNotFound)?;
+    let jq = query::jq_binary::find().ok_or(JiqError::JqNotFound)?;
+    query::jq_binary::set_program(jq);
     Ok(())
 }
 
 /// Initialize terminal with raw mode, alternate screen, and bracketed paste
 fn init_terminal() -> Result<DefaultTerminal> {
-    let hook = std::panic::take_hook();
-    std::panic::set_hook(Box::new(move |info| {
-        let _ = execute!(
-            stdout(),
-            DisableMouseCapture,
-            DisableBracketedPaste,
-            LeaveAlternateScreen
-        );
-        let _ = disable_raw_mode();
-        hook(info);
-    }));
+    crash::install_panic_hook();
 
     enable_raw_mode()?;
 
@@ -152,17 +383,13 @@ fn init_terminal() -> Result<DefaultTerminal> {
             return Err(e.into());
         }
     }
+    crash::hold_terminal();
+    crash::disambiguate_keys();
 
     match ratatui::Terminal::new(ratatui::backend::CrosstermBackend::new(stdout())) {
         Ok(terminal) => Ok(terminal),
         Err(e) => {
-            let _ = execute!(
-                stdout(),
-                DisableMouseCapture,
-                DisableBracketedPaste,
-                LeaveAlternateScreen
-            );
-            let _ = disable_raw_mode();
+            let _ = crash::release_terminal();
             Err(e.into())
         }
     }
@@ -170,27 +397,42 @@ fn init_terminal() -> Result<DefaultTerminal> {
 
 /// Restore terminal to normal state
 fn restore_terminal() -> Result<()> {
-    let _ = execute!(
+    crash::release_terminal()?;
+    Ok(())
+}
+
+/// Take the terminal back from an editor or pager and redraw everything
+fn resume_terminal(terminal: &mut DefaultTerminal) -> Result<()> {
+    enable_raw_mode()?;
+    execute!(
         stdout(),
-        DisableMouseCapture,
-        DisableBracketedPaste,
-        LeaveAlternateScreen
-    );
-    disable_raw_mode()?;
+        EnterAlternateScreen,
+        EnableBracketedPaste,
+        EnableMouseCapture
+    )?;
+    crash::hold_terminal();
+    crash::disambiguate_keys();
+    terminal.clear()?;
     Ok(())
 }
 
 fn run(
     mut terminal: DefaultTerminal,
-    mut app: App,
+    app: &mut App,
     config_result: config::ConfigResult,
-) -> Result<App> {
-    if let Some(warning) = config_result.warning {
-        app.notification.show_warning(&warning);
+) -> Result<()> {
+    let warnings: Vec<&str> = config_result
+        .warning
+        .iter()
+        .chain(&config_result.ignored)
+        .map(String::as_str)
+        .collect();
+    if !warnings.is_empty() {
+        app.notification.show_warning(&warnings.join("; "));
     }
 
     // Requirements 1.1, 1.3, 4.1
-    setup_ai_worker(&mut app, &config_result.config);
+    setup_ai_worker(app, &config_result.config);
 
     // Trigger initial request when AI popup visible on startup
     if app.ai.visible && app.ai.enabled && app.ai.configured {
@@ -200,20 +442,45 @@ fn run(
     loop {
         // Poll before render to load data from background thread
         app.poll_file_loader();
+        app.poll_follower();
+        app.poll_config_watcher();
 
         if app.should_render() {
+            // Taken before drawing, as a panic while drawing is the likeliest
+            crash::update_context(app);
+            let started = std::time::Instant::now();
             terminal.draw(|frame| app.render(frame))?;
+            if let Some(update) = app.sparkline.take_image_update() {
+                if update.needs_redraw() {
+                    terminal.clear()?;
+                    terminal.draw(|frame| app.render(frame))?;
+                }
+                update.write(terminal.backend_mut())?;
+            }
             app.clear_dirty();
+            tracing::trace!(
+                target: logging::EVENT_LOOP_TARGET,
+                elapsed_us = started.elapsed().as_micros() as u64,
+                "frame drawn"
+            );
         }
 
         app.handle_events()?;
 
+        if let Some(request) = app.pending_external.take() {
+            restore_terminal()?;
+            let outcome = external::run(&request);
+            resume_terminal(&mut terminal)?;
+            app.sparkline.forget_image();
+            external::finish(app, request, outcome);
+        }
+
         if app.should_quit() {
             break;
         }
     }
 
-    Ok(app)
+    Ok(())
 }
 
 /// Set up the AI worker thread and channels
@@ -231,35 +498,126 @@ fn setup_ai_worker(app: &mut App, config: &config::Config) {
     let (request_tx, request_rx) = std::sync::mpsc::channel();
     let (response_tx, response_rx) = std::sync::mpsc::channel();
     app.ai.set_channels(request_tx, response_rx);
+    app.ai.usage = ai::ai_state::AiUsage::with_budget(&config.ai.budget);
+    app.ai.prompt_templates = config.ai.prompts.clone();
 
     // Spawn the worker thread
-    ai::worker::spawn_worker(&config.ai, request_rx, response_tx);
+    app.ai.providers = ai::worker::spawn_worker(&config.ai, request_rx, response_tx);
+}
+
+/// Run the final query for output, reporting errors on stderr
+fn final_results(app: &App, format: &OutputFormat) -> Option<String> {
+    let query_state = app.query.as_ref()?;
+    let filter = match query_state.language.to_jq(app.query()) {
+        Ok(filter) => filter,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            return None;
+        }
+    };
+    let json_input = query_state.executor.json_input();
+    let mut executor = JqExecutor::new(json_input.to_string());
+    executor.set_variables(app.variables.variables().to_vec());
+    executor.set_library(Arc::clone(&app.jq_library));
+    let cancel_token = tokio_util::sync::CancellationToken::new();
+    match executor.execute_with_cancel(&filter, &cancel_token) {
+        Ok(result) => match format {
+            OutputFormat::Json => Some(result),
+            OutputFormat::Toml => match input_format::json_to_toml(&result) {
+                Ok(toml) => Some(toml),
+                Err(e) => {
+                    eprintln!("Error: cannot write results as TOML: {}", e);
+                    None
+                }
+            },
+            OutputFormat::Plugin(name) => {
+                let plugin = app.plugins.output_format(name)?;
+                match plugin.convert_output(&result) {
+                    Ok(output) => Some(output),
+                    Err(e) => {
+                        eprintln!("Error: plugin failed: {}", e);
+                        None
+                    }
+                }
+            }
+        },
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            None
+        }
+    }
 }
 
-/// Handle output after terminal is restored
-fn handle_output(app: &App) -> Result<()> {
+/// Handle output after terminal is restored; false when an error was reported instead
+fn handle_output(
+    app: &App,
+    output: &mut dyn Write,
+    print0: bool,
+    format: &OutputFormat,
+) -> Result<bool> {
     match app.output_mode() {
         Some(OutputMode::Results) => {
             // Execute final query and output results
-            // Only output if query is available
-            if let Some(query_state) = &app.query {
-                let json_input = query_state.executor.json_input();
-                let executor = JqExecutor::new(json_input.to_string());
-                let cancel_token = tokio_util::sync::CancellationToken::new();
-                match executor.execute_with_cancel(app.query(), &cancel_token) {
-                    Ok(result) => println!("{}", result),
-                    Err(e) => eprintln!("Error: {}", e),
-                }
-            }
+            let Some(result) = final_results(app, format) else {
+                return Ok(false);
+            };
+            shell::write_output(output, &result, print0)?;
         }
         Some(OutputMode::Query) => {
             // Output just the query string
-            println!("{}", app.query());
+            shell::write_output(output, app.query(), print0)?;
+        }
+        Some(OutputMode::Both) => {
+            shell::write_output(output, app.query(), print0)?;
+            let Some(result) = final_results(app, format) else {
+                return Ok(false);
+            };
+            shell::write_output(output, &result, print0)?;
+        }
+        Some(OutputMode::File(path)) => {
+            let Some(result) = final_results(app, format) else {
+                return Ok(false);
+            };
+            let mut file = std::fs::File::create(&path)
+                .wrap_err_with(|| format!("Cannot write {}", path.display()))?;
+            shell::write_output(&mut file, &result, false)?;
+            eprintln!("Results written to {}", path.display());
+        }
+        Some(OutputMode::InPlace(path, result)) => {
+            // The text shown in the preview, not a fresh run of the query
+            in_place::write_atomically(&path, &result)
+                .wrap_err_with(|| format!("Cannot write {}", path.display()))?;
+            eprintln!("Updated {}", path.display());
+        }
+        Some(OutputMode::Command) => {
+            // Output an equivalent jq invocation for scripts and docs,
+            // translating JSONPath/JMESPath queries into jq
+            let filter = match &app.query {
+                Some(query_state) => match query_state.language.to_jq(app.query()) {
+                    Ok(filter) => filter,
+                    Err(e) => {
+                        eprintln!("Error: {}", e);
+                        return Ok(false);
+                    }
+                },
+                None => app.query().to_string(),
+            };
+            let variables = app.variables.variables();
+            let mut command = shell::jq_command(
+                &filter,
+                variables,
+                &app.jq_library,
+                app.input_path.as_deref(),
+            );
+            if let Some(source) = &app.input_command {
+                command = format!("{} | {}", source.command_line(), command);
+            }
+            shell::write_output(output, &command, print0)?;
         }
         None => {
             // No output mode (exited with Ctrl+C or q)
         }
     }
 
-    Ok(())
+    Ok(true)
 }
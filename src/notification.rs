@@ -1,5 +1,11 @@
+mod message_log_events;
+mod message_log_render;
+mod message_log_state;
 mod notification_render;
 mod notification_state;
 
+pub use message_log_events::handle_message_log_key;
+pub use message_log_render::render_message_log;
+pub use message_log_state::MessageLogState;
 pub use notification_render::render_notification;
-pub use notification_state::NotificationState;
+pub use notification_state::{NotificationState, NotificationType};
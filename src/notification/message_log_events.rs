@@ -0,0 +1,26 @@
+use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use crate::app::App;
+use crate::scroll::handle_scroll_key;
+
+pub fn is_toggle_key(key: &KeyEvent) -> bool {
+    key.code == KeyCode::Char('m') && key.modifiers.contains(KeyModifiers::ALT)
+}
+
+pub fn handle_message_log_key(app: &mut App, key: KeyEvent) {
+    if is_toggle_key(&key) {
+        app.message_log.close();
+        return;
+    }
+
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('q') => app.message_log.close(),
+        _ => {
+            handle_scroll_key(&mut app.message_log.scroll, key);
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "message_log_events_tests.rs"]
+mod message_log_events_tests;
@@ -0,0 +1,79 @@
+//! Tests for notification/message_log_events
+
+use crate::app::App;
+use crate::test_utils::test_helpers::{app_with_query, key, key_with_mods};
+use ratatui::crossterm::event::{KeyCode, KeyModifiers};
+
+fn alt_m() -> ratatui::crossterm::event::KeyEvent {
+    key_with_mods(KeyCode::Char('m'), KeyModifiers::ALT)
+}
+
+fn app_with_scrollable_log() -> App {
+    let mut app = app_with_query(".");
+    app.message_log.open();
+    app.message_log.scroll.update_bounds(30, 10);
+    app
+}
+
+#[test]
+fn test_alt_m_opens_and_closes_message_log() {
+    let mut app = app_with_query(".");
+
+    app.handle_key_event(alt_m());
+    assert!(app.message_log.is_visible());
+
+    app.handle_key_event(alt_m());
+    assert!(!app.message_log.is_visible());
+}
+
+#[test]
+fn test_esc_and_q_close_message_log() {
+    let mut app = app_with_query(".");
+    for close_key in [KeyCode::Esc, KeyCode::Char('q')] {
+        app.message_log.open();
+        app.handle_key_event(key(close_key));
+        assert!(!app.message_log.is_visible());
+        assert!(!app.should_quit);
+    }
+}
+
+#[test]
+fn test_keys_scroll_message_log() {
+    let mut app = app_with_scrollable_log();
+    assert_eq!(app.message_log.scroll.offset, 20);
+
+    app.handle_key_event(key(KeyCode::Char('k')));
+    assert_eq!(app.message_log.scroll.offset, 19);
+
+    app.handle_key_event(key(KeyCode::Char('g')));
+    assert_eq!(app.message_log.scroll.offset, 0);
+
+    app.handle_key_event(key(KeyCode::Down));
+    assert_eq!(app.message_log.scroll.offset, 1);
+
+    app.handle_key_event(key(KeyCode::Char('G')));
+    assert_eq!(app.message_log.scroll.offset, 20);
+}
+
+#[test]
+fn test_keys_do_not_reach_query_while_open() {
+    let mut app = app_with_query(".");
+    app.message_log.open();
+
+    app.handle_key_event(key(KeyCode::Char('x')));
+
+    assert_eq!(app.query(), ".");
+}
+
+#[test]
+fn test_messages_command_opens_message_log() {
+    let mut app = app_with_query(".");
+    app.command_line.open();
+    for c in "messages".chars() {
+        app.handle_key_event(key(KeyCode::Char(c)));
+    }
+
+    app.handle_key_event(key(KeyCode::Enter));
+
+    assert!(app.message_log.is_visible());
+}
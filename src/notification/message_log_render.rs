@@ -0,0 +1,161 @@
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::Style,
+    text::{Line, Span, Text},
+    widgets::{Block, BorderType, Borders, Paragraph},
+};
+
+use super::notification_state::{LoggedMessage, NotificationType};
+use crate::app::App;
+use crate::theme;
+use crate::widgets::text_width::{display_width, take_width};
+use crate::widgets::{popup, scrollbar};
+
+/// Width of the ` 12:34:56 error ` prefix before each message
+const PREFIX_WIDTH: usize = 16;
+
+fn build_hints() -> Line<'static> {
+    theme::border_hints::build_hints(
+        &[("j/k", "Scroll"), ("g/G", "Top/Bottom"), ("Esc", "Close")],
+        theme::message_log::BORDER,
+    )
+}
+
+fn type_label(notification_type: NotificationType) -> Span<'static> {
+    let (label, color) = match notification_type {
+        NotificationType::Info => ("info", theme::message_log::INFO),
+        NotificationType::Warning => ("warn", theme::message_log::WARNING),
+        NotificationType::Error => ("error", theme::message_log::ERROR),
+    };
+    Span::styled(format!("{:<6}", label), Style::default().fg(color))
+}
+
+/// Split `text` into lines of at most `width` characters, breaking at spaces
+/// where possible
+fn wrap_message(text: &str, width: usize) -> Vec<String> {
+    let width = width.max(1);
+    let mut lines = Vec::new();
+    let mut current = String::new();
+
+    for mut word in text.split_whitespace() {
+        let current_width = display_width(&current);
+        if current_width > 0 && current_width + 1 + display_width(word) <= width {
+            current.push(' ');
+            current.push_str(word);
+            continue;
+        }
+        if current_width > 0 {
+            lines.push(std::mem::take(&mut current));
+        }
+        while display_width(word) > width {
+            // A character wider than the line still takes a line of its own
+            let mut head = take_width(word, width);
+            if head.is_empty() {
+                head = &word[..word.chars().next().map_or(0, char::len_utf8)];
+            }
+            lines.push(head.to_string());
+            word = &word[head.len()..];
+        }
+        current = word.to_string();
+    }
+
+    if !current.is_empty() || lines.is_empty() {
+        lines.push(current);
+    }
+    lines
+}
+
+fn message_lines(message: &LoggedMessage, text_width: usize) -> Vec<Line<'static>> {
+    let text_style = Style::default().fg(theme::message_log::TEXT);
+    let mut wrapped = wrap_message(&message.message, text_width).into_iter();
+
+    let mut lines = vec![Line::from(vec![
+        Span::styled(
+            format!(" {} ", message.time.format("%H:%M:%S")),
+            Style::default().fg(theme::message_log::TIME),
+        ),
+        type_label(message.notification_type),
+        Span::styled(wrapped.next().unwrap_or_default(), text_style),
+    ])];
+    lines.extend(wrapped.map(|continuation| {
+        Line::from(vec![
+            Span::raw(" ".repeat(PREFIX_WIDTH)),
+            Span::styled(continuation, text_style),
+        ])
+    }));
+    lines
+}
+
+/// Render the message log popup
+pub fn render_message_log(app: &mut App, frame: &mut Frame) -> Option<Rect> {
+    let frame_area = frame.area();
+    if frame_area.width < 30 || frame_area.height < 8 {
+        return None;
+    }
+
+    let popup_width = ((frame_area.width as f32 * 0.8) as u16)
+        .clamp(50, 100)
+        .min(frame_area.width.saturating_sub(4));
+    let popup_height = ((frame_area.height as f32 * 0.7) as u16)
+        .clamp(8, 30)
+        .min(frame_area.height.saturating_sub(2));
+    let popup_area = popup::centered_popup(frame_area, popup_width, popup_height);
+    popup::clear_area(frame, popup_area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(Line::from(Span::styled(
+            " Messages ",
+            theme::message_log::TITLE,
+        )))
+        .title_bottom(build_hints().centered())
+        .border_style(Style::default().fg(theme::message_log::BORDER))
+        .style(Style::default().bg(theme::message_log::BACKGROUND));
+    let content_area = block.inner(popup_area);
+    frame.render_widget(block, popup_area);
+
+    let text_width = (content_area.width as usize).saturating_sub(PREFIX_WIDTH + 1);
+    let messages = app.notification.messages();
+    let lines: Vec<Line> = if messages.is_empty() {
+        vec![Line::from(Span::styled(
+            " No messages yet",
+            Style::default().fg(theme::message_log::TIME),
+        ))]
+    } else {
+        messages
+            .iter()
+            .flat_map(|message| message_lines(message, text_width))
+            .collect()
+    };
+
+    let content_height = lines.len() as u32;
+    let scroll = &mut app.message_log.scroll;
+    scroll.update_bounds(content_height, content_area.height);
+    frame.render_widget(
+        Paragraph::new(Text::from(lines)).scroll((scroll.offset, 0)),
+        content_area,
+    );
+
+    let scrollbar_area = Rect {
+        x: popup_area.x,
+        y: popup_area.y.saturating_add(1),
+        width: popup_area.width,
+        height: popup_area.height.saturating_sub(2),
+    };
+    scrollbar::render_vertical_scrollbar_styled(
+        frame,
+        scrollbar_area,
+        content_height as usize,
+        scroll.viewport_height as usize,
+        scroll.offset as usize,
+        theme::message_log::BORDER,
+    );
+
+    Some(popup_area)
+}
+
+#[cfg(test)]
+#[path = "message_log_render_tests.rs"]
+mod message_log_render_tests;
@@ -0,0 +1,96 @@
+//! Tests for notification/message_log_render
+
+use super::*;
+use crate::test_utils::test_helpers::test_app;
+use chrono::TimeZone;
+use insta::assert_snapshot;
+use ratatui::Terminal;
+use ratatui::backend::TestBackend;
+
+fn render_to_string(app: &mut App, width: u16, height: u16) -> String {
+    let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+    terminal
+        .draw(|f| {
+            render_message_log(app, f);
+        })
+        .unwrap();
+    terminal.backend().to_string()
+}
+
+fn at(hour: u32, min: u32, sec: u32) -> chrono::DateTime<chrono::Local> {
+    chrono::Local
+        .with_ymd_and_hms(2025, 1, 1, hour, min, sec)
+        .unwrap()
+}
+
+#[test]
+fn test_wrap_message_breaks_at_spaces() {
+    assert_eq!(
+        wrap_message("unknown key 'colour' in [display]", 14),
+        vec!["unknown key", "'colour' in", "[display]"]
+    );
+}
+
+#[test]
+fn test_wrap_message_splits_long_words() {
+    assert_eq!(wrap_message("abcdefgh", 3), vec!["abc", "def", "gh"]);
+    assert_eq!(wrap_message("", 10), vec![""]);
+}
+
+#[test]
+fn test_wrap_message_counts_wide_characters_by_columns() {
+    assert_eq!(wrap_message("設定 ファイル", 8), vec!["設定", "ファイル"]);
+    assert_eq!(
+        wrap_message("設定ファイル", 5),
+        vec!["設定", "ファ", "イル"]
+    );
+    assert_eq!(wrap_message("設定", 1), vec!["設", "定"]);
+}
+
+#[test]
+fn snapshot_message_log() {
+    let mut app = test_app(r#"{"a": 1}"#);
+    app.notification.log_with_time(
+        "Invalid config: unknown field `colour` in [display], expected one of `theme`, `layout`",
+        NotificationType::Warning,
+        at(9, 14, 2),
+    );
+    app.notification
+        .log_with_time("Copied query!", NotificationType::Info, at(9, 15, 30));
+    app.notification.log_with_time(
+        "AI: request failed: 401 Unauthorized",
+        NotificationType::Error,
+        at(9, 16, 0),
+    );
+    app.message_log.open();
+
+    let output = render_to_string(&mut app, 80, 20);
+    assert_snapshot!(output);
+}
+
+#[test]
+fn snapshot_empty_message_log() {
+    let mut app = test_app(r#"{"a": 1}"#);
+    app.message_log.open();
+
+    let output = render_to_string(&mut app, 80, 20);
+    assert_snapshot!(output);
+}
+
+#[test]
+fn test_opens_scrolled_to_newest_message() {
+    let mut app = test_app(r#"{"a": 1}"#);
+    for i in 0..40 {
+        app.notification.log_with_time(
+            &format!("message {}", i),
+            NotificationType::Info,
+            at(10, 0, 0),
+        );
+    }
+    app.message_log.open();
+
+    let output = render_to_string(&mut app, 80, 20);
+
+    assert!(output.contains("message 39"));
+    assert!(!output.contains("message 0 "));
+}
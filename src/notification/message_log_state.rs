@@ -0,0 +1,45 @@
+use crate::scroll::ScrollState;
+
+/// Popup listing past notifications
+#[derive(Debug)]
+pub struct MessageLogState {
+    visible: bool,
+    pub scroll: ScrollState,
+}
+
+impl Default for MessageLogState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MessageLogState {
+    pub fn new() -> Self {
+        Self {
+            visible: false,
+            scroll: ScrollState::new(),
+        }
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    /// Open scrolled to the newest message
+    ///
+    /// The offset is clamped to the content once the popup is rendered.
+    pub fn open(&mut self) {
+        self.visible = true;
+        self.scroll = ScrollState::new();
+        self.scroll.offset = u16::MAX;
+        self.scroll.max_offset = u16::MAX;
+    }
+
+    pub fn close(&mut self) {
+        self.visible = false;
+    }
+}
+
+#[cfg(test)]
+#[path = "message_log_state_tests.rs"]
+mod message_log_state_tests;
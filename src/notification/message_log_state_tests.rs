@@ -0,0 +1,39 @@
+//! Tests for notification/message_log_state
+
+use super::*;
+
+#[test]
+fn test_open_and_close() {
+    let mut state = MessageLogState::new();
+    assert!(!state.is_visible());
+
+    state.open();
+    assert!(state.is_visible());
+
+    state.close();
+    assert!(!state.is_visible());
+}
+
+#[test]
+fn test_open_scrolls_to_newest_once_bounds_are_known() {
+    let mut state = MessageLogState::new();
+    state.open();
+
+    state.scroll.update_bounds(30, 10);
+
+    assert_eq!(state.scroll.offset, 20);
+}
+
+#[test]
+fn test_reopening_resets_scroll() {
+    let mut state = MessageLogState::new();
+    state.open();
+    state.scroll.update_bounds(30, 10);
+    state.scroll.jump_to_top();
+    state.close();
+
+    state.open();
+    state.scroll.update_bounds(30, 10);
+
+    assert_eq!(state.scroll.offset, 20);
+}
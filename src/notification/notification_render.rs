@@ -8,6 +8,7 @@ use ratatui::{
 
 use super::notification_state::NotificationState;
 use crate::widgets::popup;
+use crate::widgets::text_width::display_width;
 
 pub fn render_notification(frame: &mut Frame, notification: &mut NotificationState) {
     notification.clear_if_expired();
@@ -20,7 +21,7 @@ pub fn render_notification(frame: &mut Frame, notification: &mut NotificationSta
     let message = &notif.message;
     let style = &notif.style;
 
-    let content_width = message.len() as u16;
+    let content_width = display_width(message) as u16;
     let notification_width = content_width + 4;
     let notification_height = 3;
 
@@ -1,4 +1,6 @@
+use chrono::{DateTime, Local};
 use ratatui::style::Color;
+use std::collections::VecDeque;
 use std::time::{Duration, Instant};
 
 use crate::theme;
@@ -92,9 +94,22 @@ impl Notification {
     }
 }
 
+/// Maximum number of notifications kept in the message log
+pub const MESSAGE_LOG_LIMIT: usize = 200;
+
+/// A past notification, kept for the message log
+#[derive(Debug, Clone)]
+pub struct LoggedMessage {
+    pub message: String,
+    pub notification_type: NotificationType,
+    pub time: DateTime<Local>,
+}
+
 #[derive(Debug, Default)]
 pub struct NotificationState {
     pub current: Option<Notification>,
+    /// Every notification shown or logged, oldest first
+    log: VecDeque<LoggedMessage>,
 }
 
 impl NotificationState {
@@ -103,13 +118,52 @@ impl NotificationState {
     }
 
     pub fn show(&mut self, message: &str) {
+        self.log(message, NotificationType::Info);
         self.current = Some(Notification::new(message));
     }
 
     pub fn show_with_type(&mut self, message: &str, notification_type: NotificationType) {
+        self.log(message, notification_type);
         self.current = Some(Notification::with_type(message, notification_type));
     }
 
+    /// Record a message in the message log without showing it
+    pub fn log(&mut self, message: &str, notification_type: NotificationType) {
+        self.log_at(message, notification_type, Local::now());
+    }
+
+    fn log_at(
+        &mut self,
+        message: &str,
+        notification_type: NotificationType,
+        time: DateTime<Local>,
+    ) {
+        if self.log.len() == MESSAGE_LOG_LIMIT {
+            self.log.pop_front();
+        }
+        self.log.push_back(LoggedMessage {
+            message: message.to_string(),
+            notification_type,
+            time,
+        });
+    }
+
+    /// Record a message with a fixed time (test helper)
+    #[cfg(test)]
+    pub fn log_with_time(
+        &mut self,
+        message: &str,
+        notification_type: NotificationType,
+        time: DateTime<Local>,
+    ) {
+        self.log_at(message, notification_type, time);
+    }
+
+    /// Past notifications, oldest first
+    pub fn messages(&self) -> &VecDeque<LoggedMessage> {
+        &self.log
+    }
+
     pub fn show_warning(&mut self, message: &str) {
         self.show_with_type(message, NotificationType::Warning);
     }
@@ -103,3 +103,49 @@ fn test_error_notification_never_expires() {
     assert!(!state.clear_if_expired()); // Should not clear
     assert!(state.current().is_some());
 }
+
+#[test]
+fn test_shown_notifications_are_logged() {
+    let mut state = NotificationState::new();
+    state.show("Copied query!");
+    state.show_warning("Invalid config");
+    state.show_error("Failed to load file");
+
+    let logged: Vec<(&str, NotificationType)> = state
+        .messages()
+        .iter()
+        .map(|m| (m.message.as_str(), m.notification_type))
+        .collect();
+    assert_eq!(
+        logged,
+        vec![
+            ("Copied query!", NotificationType::Info),
+            ("Invalid config", NotificationType::Warning),
+            ("Failed to load file", NotificationType::Error),
+        ]
+    );
+}
+
+#[test]
+fn test_log_records_without_showing() {
+    let mut state = NotificationState::new();
+    state.log("AI: request failed", NotificationType::Error);
+
+    assert!(state.current().is_none());
+    assert_eq!(state.messages().len(), 1);
+}
+
+#[test]
+fn test_log_keeps_newest_messages() {
+    let mut state = NotificationState::new();
+    for i in 0..MESSAGE_LOG_LIMIT + 5 {
+        state.show(&format!("message {}", i));
+    }
+
+    assert_eq!(state.messages().len(), MESSAGE_LOG_LIMIT);
+    assert_eq!(state.messages().front().unwrap().message, "message 5");
+    assert_eq!(
+        state.messages().back().unwrap().message,
+        format!("message {}", MESSAGE_LOG_LIMIT + 4)
+    );
+}
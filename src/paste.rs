@@ -0,0 +1,17 @@
+//! Pasting into the query
+//!
+//! A large paste, or one spanning several lines, that parses as JSON or
+//! JSON Lines is not typed into the query. jiq asks whether to load it as
+//! the input document instead, or insert it after all. Other pastes
+//! spanning several lines are joined into a single-line query.
+
+mod paste_events;
+mod paste_query;
+mod paste_render;
+mod paste_state;
+
+pub use paste_events::{handle_paste_offer_key, offer_json};
+pub use paste_query::normalize_query;
+pub use paste_render::render_paste_offer;
+#[allow(unused_imports)]
+pub use paste_state::{MIN_JSON_LEN, PasteState, looks_like_json};
@@ -0,0 +1,52 @@
+use ratatui::crossterm::event::{KeyCode, KeyEvent};
+
+use super::paste_state::looks_like_json;
+use crate::app::App;
+use crate::input::input_info::format_size;
+
+/// Ask what to do with pasted `text` when it is a JSON document; false
+/// leaves it to be typed into the query
+pub fn offer_json(app: &mut App, text: &str) -> bool {
+    if app.query.is_none() || !looks_like_json(text) {
+        return false;
+    }
+    app.paste.set_offer(text.to_string());
+    true
+}
+
+pub fn handle_paste_offer_key(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Enter | KeyCode::Char('l') => load(app),
+        KeyCode::Char('i') => {
+            if let Some(text) = app.paste.take() {
+                app.insert_pasted_text(&text);
+            }
+        }
+        KeyCode::Esc | KeyCode::Char('n') => {
+            app.paste.take();
+            app.notification.show("Paste discarded");
+        }
+        _ => {}
+    }
+}
+
+/// Make the pasted document the input, in place of the file or command it
+/// was read from, keeping the query
+fn load(app: &mut App) {
+    let Some(text) = app.paste.take() else {
+        return;
+    };
+    let size = format_size(text.len() as u64);
+    app.input_path = None;
+    app.input_command = None;
+    app.input_info = None;
+    app.follower = None;
+    app.in_place_mode = false;
+    app.replace_input(text);
+    app.notification
+        .show(&format!("Loaded the pasted JSON ({}) as the input", size));
+}
+
+#[cfg(test)]
+#[path = "paste_events_tests.rs"]
+mod paste_events_tests;
@@ -0,0 +1,95 @@
+//! Tests for paste/paste_events
+
+use super::*;
+use crate::test_utils::test_helpers::{app_with_query, key};
+use ratatui::crossterm::event::Event;
+use std::path::PathBuf;
+
+const DOCUMENT: &str = "{\n  \"name\": \"pasted\",\n  \"tags\": [\"a\", \"b\"]\n}\n";
+
+fn app_offered(document: &str) -> App {
+    let mut app = app_with_query(".name");
+    app.input_path = Some(PathBuf::from("input.json"));
+    app.handle_event(Event::Paste(document.to_string()));
+    app
+}
+
+fn offering_app() -> App {
+    app_offered(DOCUMENT)
+}
+
+#[test]
+fn test_pasted_document_is_offered() {
+    let app = offering_app();
+
+    assert!(app.paste.is_visible());
+    assert_eq!(app.input.query(), ".name");
+}
+
+#[test]
+fn test_pasted_query_is_typed() {
+    let mut app = app_with_query("");
+
+    app.handle_event(Event::Paste(".items[]\n| .id".to_string()));
+
+    assert!(!app.paste.is_visible());
+    assert!(app.input.query().starts_with(".items[]"));
+}
+
+#[test]
+fn test_enter_loads_paste_as_input() {
+    let mut app = offering_app();
+
+    app.handle_key_event(key(KeyCode::Enter));
+
+    assert!(!app.paste.is_visible());
+    assert_eq!(app.query.as_ref().unwrap().executor.json_input(), DOCUMENT);
+    assert_eq!(app.input_path, None);
+    assert_eq!(app.input.query(), ".name");
+    let message = &app.notification.current().unwrap().message;
+    assert!(message.starts_with("Loaded the pasted JSON"));
+}
+
+#[test]
+fn test_i_inserts_paste_into_query() {
+    let document = format!("{{\"ids\": [{}]}}", vec!["12345"; 60].join(", "));
+    let mut app = app_offered(&document);
+    assert!(app.paste.is_visible());
+    let input_before = app
+        .query
+        .as_ref()
+        .unwrap()
+        .executor
+        .json_input()
+        .to_string();
+
+    app.handle_key_event(key(KeyCode::Char('i')));
+
+    assert!(!app.paste.is_visible());
+    assert!(app.input.query().ends_with(&document));
+    assert_eq!(
+        app.query.as_ref().unwrap().executor.json_input(),
+        input_before
+    );
+}
+
+#[test]
+fn test_esc_discards_paste() {
+    let mut app = offering_app();
+
+    app.handle_key_event(key(KeyCode::Esc));
+
+    assert!(!app.paste.is_visible());
+    assert_eq!(app.input.query(), ".name");
+    assert_eq!(app.input_path, Some(PathBuf::from("input.json")));
+}
+
+#[test]
+fn test_other_keys_keep_offer() {
+    let mut app = offering_app();
+
+    app.handle_key_event(key(KeyCode::Char('x')));
+
+    assert!(app.paste.is_visible());
+    assert_eq!(app.input.query(), ".name");
+}
@@ -0,0 +1,117 @@
+/// Join a jq program pasted over several lines into the single line the
+/// query editor holds, meaning the same
+///
+/// Comments are dropped, a line break or run of spaces between tokens
+/// becomes one space, and a line break inside a string literal becomes
+/// `\n`. Text without line breaks is returned as it is.
+pub fn normalize_query(text: &str) -> String {
+    if !text.contains(['\n', '\r']) {
+        return text.to_string();
+    }
+
+    let chars: Vec<char> = text.chars().collect();
+    let mut out = String::with_capacity(text.len());
+    // Paren depth inside each open `\(...)` interpolation
+    let mut interpolations: Vec<usize> = Vec::new();
+    let mut in_string = false;
+    let mut pending_space = false;
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        if in_string {
+            match c {
+                '\\' if chars.get(i + 1) == Some(&'(') => {
+                    out.push_str("\\(");
+                    interpolations.push(0);
+                    in_string = false;
+                    i += 2;
+                    continue;
+                }
+                '\\' => {
+                    out.push(c);
+                    if let Some(&next) = chars.get(i + 1) {
+                        out.push(next);
+                    }
+                    i += 2;
+                    continue;
+                }
+                '"' => in_string = false,
+                '\r' if chars.get(i + 1) == Some(&'\n') => {
+                    i += 1;
+                    continue;
+                }
+                '\n' | '\r' => {
+                    out.push_str("\\n");
+                    i += 1;
+                    continue;
+                }
+                _ => {}
+            }
+            out.push(c);
+            i += 1;
+            continue;
+        }
+
+        if c == '#' {
+            i = comment_end(&chars, i);
+            pending_space = true;
+            continue;
+        }
+        if c.is_whitespace() {
+            pending_space = true;
+            i += 1;
+            continue;
+        }
+        if pending_space && !out.is_empty() {
+            out.push(' ');
+        }
+        pending_space = false;
+
+        match c {
+            '"' => in_string = true,
+            '(' => {
+                if let Some(depth) = interpolations.last_mut() {
+                    *depth += 1;
+                }
+            }
+            ')' => match interpolations.last_mut() {
+                Some(0) => {
+                    interpolations.pop();
+                    in_string = true;
+                }
+                Some(depth) => *depth -= 1,
+                None => {}
+            },
+            _ => {}
+        }
+        out.push(c);
+        i += 1;
+    }
+    out
+}
+
+/// Index of the line break ending the comment starting at `start`; like
+/// jq, a line break after an odd number of backslashes continues it
+fn comment_end(chars: &[char], start: usize) -> usize {
+    let mut i = start;
+    while i < chars.len() {
+        if chars[i] == '\n' {
+            let backslashes = chars[start..i]
+                .iter()
+                .rev()
+                .skip_while(|c| **c == '\r')
+                .take_while(|c| **c == '\\')
+                .count();
+            if backslashes % 2 == 0 {
+                return i;
+            }
+        }
+        i += 1;
+    }
+    i
+}
+
+#[cfg(test)]
+#[path = "paste_query_tests.rs"]
+mod paste_query_tests;
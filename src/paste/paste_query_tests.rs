@@ -0,0 +1,58 @@
+//! Tests for paste/paste_query
+
+use super::*;
+
+#[test]
+fn test_single_line_is_unchanged() {
+    assert_eq!(normalize_query(".a  |  .b "), ".a  |  .b ");
+}
+
+#[test]
+fn test_lines_are_joined() {
+    let pasted = ".items[]\n  | select(.active)\n  | .name\n";
+    assert_eq!(
+        normalize_query(pasted),
+        ".items[] | select(.active) | .name"
+    );
+}
+
+#[test]
+fn test_comments_are_dropped() {
+    let pasted =
+        "# users who logged in\n.users[] # all of them\n| select(.last_login) # recently\n";
+    assert_eq!(normalize_query(pasted), ".users[] | select(.last_login)");
+}
+
+#[test]
+fn test_comment_continued_by_backslash() {
+    let pasted = ".a # note \\\n still the note\n| .b";
+    assert_eq!(normalize_query(pasted), ".a | .b");
+
+    let pasted = ".a # two backslashes \\\\\n| .b";
+    assert_eq!(normalize_query(pasted), ".a | .b");
+}
+
+#[test]
+fn test_strings_are_kept() {
+    let pasted = "\"a  # not a comment\"\n| \"tab\\tand \\\"quote\\\"\"";
+    assert_eq!(
+        normalize_query(pasted),
+        "\"a  # not a comment\" | \"tab\\tand \\\"quote\\\"\""
+    );
+}
+
+#[test]
+fn test_line_break_in_string_is_escaped() {
+    assert_eq!(normalize_query("\"two\nlines\"\r\n"), "\"two\\nlines\"");
+}
+
+#[test]
+fn test_interpolation_is_normalized() {
+    let pasted = "\"id: \\(.id\n  # the id\n  | tostring)\"";
+    assert_eq!(normalize_query(pasted), "\"id: \\(.id | tostring)\"");
+}
+
+#[test]
+fn test_crlf_line_breaks() {
+    assert_eq!(normalize_query(".a\r\n| .b\r\n"), ".a | .b");
+}
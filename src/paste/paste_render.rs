@@ -0,0 +1,75 @@
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::Style,
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Padding, Paragraph},
+};
+
+use crate::app::App;
+use crate::input::input_info::format_size;
+use crate::theme;
+use crate::widgets::popup;
+
+const DIALOG_WIDTH: u16 = 50;
+
+const OPTIONS: &[(&str, &str)] = &[
+    ("Enter", "Load it as the input"),
+    ("i", "Insert it into the query"),
+    ("Esc", "Discard it"),
+];
+
+/// Render the question of what to do with a pasted JSON document
+pub fn render_paste_offer(app: &App, frame: &mut Frame) -> Option<Rect> {
+    let text = app.paste.offer()?;
+    let frame_area = frame.area();
+    let width = DIALOG_WIDTH.min(frame_area.width.saturating_sub(2));
+
+    let key_style = Style::default().fg(theme::paste::KEY);
+    let text_style = Style::default().fg(theme::paste::TEXT);
+    let lines_pasted = text.trim_end().lines().count();
+    let mut lines = vec![
+        Line::from(Span::styled(
+            "The paste looks like a JSON document.",
+            theme::paste::QUESTION,
+        )),
+        Line::from(Span::styled(
+            format!(
+                "{}, {} line{}",
+                format_size(text.len() as u64),
+                lines_pasted,
+                if lines_pasted == 1 { "" } else { "s" }
+            ),
+            Style::default().fg(theme::paste::SIZE),
+        )),
+        Line::default(),
+    ];
+    lines.extend(OPTIONS.iter().map(|(key, action)| {
+        Line::from(vec![
+            Span::styled(format!("{:<7}", key), key_style),
+            Span::styled(*action, text_style),
+        ])
+    }));
+
+    let height = (lines.len() as u16 + 2).min(frame_area.height);
+    if width < 20 || height < 5 {
+        return None;
+    }
+
+    let area = popup::centered_popup(frame_area, width, height);
+    popup::clear_area(frame, area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(" Pasted JSON ")
+        .border_style(Style::default().fg(theme::paste::BORDER))
+        .style(Style::default().bg(theme::paste::BACKGROUND))
+        .padding(Padding::horizontal(1));
+    frame.render_widget(Paragraph::new(lines).block(block), area);
+    Some(area)
+}
+
+#[cfg(test)]
+#[path = "paste_render_tests.rs"]
+mod paste_render_tests;
@@ -0,0 +1,36 @@
+//! Tests for paste/paste_render
+
+use super::*;
+use crate::test_utils::test_helpers::app_with_query;
+use insta::assert_snapshot;
+use ratatui::Terminal;
+use ratatui::backend::TestBackend;
+
+fn render_to_string(app: &App, width: u16, height: u16) -> String {
+    let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+    terminal
+        .draw(|f| {
+            render_paste_offer(app, f);
+        })
+        .unwrap();
+    terminal.backend().to_string()
+}
+
+#[test]
+fn snapshot_paste_offer() {
+    let mut app = app_with_query("");
+    app.paste
+        .set_offer("{\n  \"name\": \"pasted\",\n  \"tags\": [\"a\", \"b\"]\n}\n".to_string());
+
+    let output = render_to_string(&app, 80, 12);
+    assert_snapshot!(output);
+}
+
+#[test]
+fn test_nothing_rendered_without_offer() {
+    let app = app_with_query("");
+    let mut terminal = Terminal::new(TestBackend::new(80, 12)).unwrap();
+    terminal
+        .draw(|f| assert!(render_paste_offer(&app, f).is_none()))
+        .unwrap();
+}
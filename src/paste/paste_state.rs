@@ -0,0 +1,54 @@
+use crate::input::loader::validate_json_or_jsonl;
+
+/// Shortest single-line paste checked for being a JSON document; shorter
+/// ones, like `{"a": 1}`, are more likely meant as query text
+pub const MIN_JSON_LEN: usize = 256;
+
+/// JSON document pasted into the query, until loaded, inserted or dropped
+#[derive(Debug, Default)]
+pub struct PasteState {
+    offer: Option<String>,
+}
+
+impl PasteState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether the prompt for a pasted document is shown
+    pub fn is_visible(&self) -> bool {
+        self.offer.is_some()
+    }
+
+    pub fn offer(&self) -> Option<&str> {
+        self.offer.as_deref()
+    }
+
+    /// Ask what to do with the pasted document `text`
+    pub fn set_offer(&mut self, text: String) {
+        self.offer = Some(text);
+    }
+
+    /// Take the pasted document to load or insert it
+    pub fn take(&mut self) -> Option<String> {
+        self.offer.take()
+    }
+}
+
+/// Whether pasted `text` is a JSON document rather than query text: an
+/// object or array, or several of them as JSON Lines, that is long or
+/// spans lines
+pub fn looks_like_json(text: &str) -> bool {
+    let trimmed = text.trim();
+    if !trimmed.starts_with(['{', '[']) {
+        return false;
+    }
+    if trimmed.len() < MIN_JSON_LEN && !trimmed.contains('\n') {
+        return false;
+    }
+    validate_json_or_jsonl(trimmed).is_ok()
+}
+
+#[cfg(test)]
+#[path = "paste_state_tests.rs"]
+mod paste_state_tests;
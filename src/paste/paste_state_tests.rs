@@ -0,0 +1,47 @@
+//! Tests for paste/paste_state
+
+use super::*;
+
+#[test]
+fn test_multiline_document_looks_like_json() {
+    assert!(looks_like_json("{\n  \"name\": \"jiq\"\n}\n"));
+    assert!(looks_like_json("[1,\n2]"));
+}
+
+#[test]
+fn test_json_lines_look_like_json() {
+    assert!(looks_like_json("{\"a\": 1}\n{\"a\": 2}\n"));
+}
+
+#[test]
+fn test_long_single_line_document_looks_like_json() {
+    let text = format!("{{\"items\": [{}]}}", vec!["1"; 200].join(","));
+    assert!(text.len() >= MIN_JSON_LEN);
+    assert!(looks_like_json(&text));
+}
+
+#[test]
+fn test_short_literal_is_query_text() {
+    assert!(!looks_like_json("{\"a\": 1}"));
+    assert!(!looks_like_json("[.a, .b]"));
+}
+
+#[test]
+fn test_queries_are_not_json() {
+    assert!(!looks_like_json(".items[]\n| select(.id > 1)"));
+    assert!(!looks_like_json("{\n  name: .name\n}"));
+    assert!(!looks_like_json("\"just a string\"\n"));
+}
+
+#[test]
+fn test_offer_lifecycle() {
+    let mut state = PasteState::new();
+    assert!(!state.is_visible());
+
+    state.set_offer("[1]".to_string());
+    assert!(state.is_visible());
+    assert_eq!(state.offer(), Some("[1]"));
+
+    assert_eq!(state.take(), Some("[1]".to_string()));
+    assert!(!state.is_visible());
+}
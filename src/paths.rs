@@ -0,0 +1,214 @@
+//! Where jiq keeps its files
+//!
+//! Files fall into three kinds, each with its own directory:
+//!
+//! - config, written by the user: `config.toml` and `lib.jq`
+//! - data, worth keeping and syncing: sessions, snippets and bookmarks
+//! - state, rebuilt by using jiq: history, recovery files, crash reports,
+//!   suggestion ranking and the last exit menu action
+//!
+//! On Linux and other Unix systems these are `$XDG_CONFIG_HOME/jiq`,
+//! `$XDG_DATA_HOME/jiq` and `$XDG_STATE_HOME/jiq`, defaulting to
+//! `~/.config/jiq`, `~/.local/share/jiq` and `~/.local/state/jiq`. macOS
+//! keeps config in `~/.config/jiq` and the rest in
+//! `~/Library/Application Support/jiq`. Windows uses `%APPDATA%\jiq` for
+//! config and data and `%LOCALAPPDATA%\jiq` for state.
+//!
+//! XDG variables set to an absolute path are honoured on macOS too. Files
+//! left where earlier versions kept them are moved on startup by `migrate`.
+
+use std::ffi::OsString;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+/// Name of jiq's directory inside each base directory
+const APP_DIR: &str = "jiq";
+
+/// Platform deciding the default directories
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Platform {
+    Unix,
+    MacOs,
+    Windows,
+}
+
+impl Platform {
+    pub fn current() -> Self {
+        if cfg!(windows) {
+            Platform::Windows
+        } else if cfg!(target_os = "macos") {
+            Platform::MacOs
+        } else {
+            Platform::Unix
+        }
+    }
+}
+
+/// jiq's directory for each kind of file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Dirs {
+    pub config: PathBuf,
+    pub data: PathBuf,
+    pub state: PathBuf,
+}
+
+impl Dirs {
+    /// Directories on `platform` for the user whose home is `home`, with
+    /// environment variables looked up by `env`
+    pub fn resolve(
+        platform: Platform,
+        home: &Path,
+        env: impl Fn(&str) -> Option<OsString>,
+    ) -> Self {
+        let var = |name: &str| env(name).map(PathBuf::from).filter(|p| p.is_absolute());
+        let (config, data, state) = match platform {
+            Platform::Unix => (
+                var("XDG_CONFIG_HOME").unwrap_or_else(|| home.join(".config")),
+                var("XDG_DATA_HOME").unwrap_or_else(|| home.join(".local").join("share")),
+                var("XDG_STATE_HOME").unwrap_or_else(|| home.join(".local").join("state")),
+            ),
+            Platform::MacOs => {
+                let support = home.join("Library").join("Application Support");
+                (
+                    var("XDG_CONFIG_HOME").unwrap_or_else(|| home.join(".config")),
+                    var("XDG_DATA_HOME").unwrap_or_else(|| support.clone()),
+                    var("XDG_STATE_HOME").unwrap_or(support),
+                )
+            }
+            Platform::Windows => {
+                let roaming =
+                    var("APPDATA").unwrap_or_else(|| home.join("AppData").join("Roaming"));
+                let local =
+                    var("LOCALAPPDATA").unwrap_or_else(|| home.join("AppData").join("Local"));
+                (roaming.clone(), roaming, local)
+            }
+        };
+        Self {
+            config: config.join(APP_DIR),
+            data: data.join(APP_DIR),
+            state: state.join(APP_DIR),
+        }
+    }
+
+    /// Where earlier versions kept each file, and where it goes now
+    ///
+    /// `old_config` is the `~/.config/jiq` config, snippets and bookmarks
+    /// were kept in on every platform, `old_data` the directory in the
+    /// platform's data directory everything else was kept in.
+    pub fn legacy_moves(&self, old_config: &Path, old_data: &Path) -> Vec<(PathBuf, PathBuf)> {
+        let config = ["config.toml", crate::query::library::LIBRARY_FILE]
+            .map(|name| (old_config.join(name), self.config.join(name)));
+        let data = [
+            (
+                old_config.join("snippets.toml"),
+                self.data.join("snippets.toml"),
+            ),
+            (old_config.join("bookmarks"), self.data.join("bookmarks")),
+            (old_data.join("sessions"), self.data.join("sessions")),
+        ];
+        let state = crate::history::storage::state_entries()
+            .into_iter()
+            .chain(["recovery", "crashes", "ranking", "exit_action"].map(PathBuf::from))
+            .map(|name| (old_data.join(&name), self.state.join(&name)));
+
+        config
+            .into_iter()
+            .chain(data)
+            .chain(state)
+            .filter(|(from, to)| from != to)
+            .collect()
+    }
+}
+
+static DIRS: OnceLock<Option<Dirs>> = OnceLock::new();
+
+/// This user's directories, `None` when the home directory is unknown
+pub fn dirs() -> Option<&'static Dirs> {
+    DIRS.get_or_init(|| {
+        dirs::home_dir()
+            .map(|home| Dirs::resolve(Platform::current(), &home, |name| std::env::var_os(name)))
+    })
+    .as_ref()
+}
+
+/// Directory of the config file and jq library
+pub fn config_dir() -> Option<PathBuf> {
+    dirs().map(|d| d.config.clone())
+}
+
+/// Directory of sessions, snippets and bookmarks
+pub fn data_dir() -> Option<PathBuf> {
+    dirs().map(|d| d.data.clone())
+}
+
+/// Directory of history, recovery files, crash reports and other state
+pub fn state_dir() -> Option<PathBuf> {
+    dirs().map(|d| d.state.clone())
+}
+
+/// Move files left where earlier versions kept them, returning the moves
+/// made; a file is only moved when nothing is at its new place yet
+pub fn migrate() -> Vec<(PathBuf, PathBuf)> {
+    let (Some(dirs), Some(home), Some(old_data)) = (dirs(), dirs::home_dir(), dirs::data_dir())
+    else {
+        return Vec::new();
+    };
+    let old_config = home.join(".config").join(APP_DIR);
+    migrate_files(&dirs.legacy_moves(&old_config, &old_data.join(APP_DIR)))
+}
+
+/// Move each existing `from` to its `to` that doesn't exist yet, returning
+/// the moves made; failures are logged and leave the file in place
+pub fn migrate_files(moves: &[(PathBuf, PathBuf)]) -> Vec<(PathBuf, PathBuf)> {
+    let mut moved = Vec::new();
+    for (from, to) in moves {
+        if !from.exists() || to.exists() {
+            continue;
+        }
+        match move_path(from, to) {
+            Ok(()) => {
+                tracing::info!(from = %from.display(), to = %to.display(), "moved to new location");
+                moved.push((from.clone(), to.clone()));
+            }
+            Err(e) => {
+                tracing::warn!(from = %from.display(), to = %to.display(), "cannot move: {}", e)
+            }
+        }
+    }
+    moved
+}
+
+/// Rename `from` to `to`, copying then removing it when they are on
+/// different file systems
+fn move_path(from: &Path, to: &Path) -> io::Result<()> {
+    if let Some(parent) = to.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    if fs::rename(from, to).is_ok() {
+        return Ok(());
+    }
+    copy_path(from, to)?;
+    if from.is_dir() {
+        fs::remove_dir_all(from)
+    } else {
+        fs::remove_file(from)
+    }
+}
+
+fn copy_path(from: &Path, to: &Path) -> io::Result<()> {
+    if !from.is_dir() {
+        return fs::copy(from, to).map(|_| ());
+    }
+    fs::create_dir_all(to)?;
+    for entry in fs::read_dir(from)? {
+        let entry = entry?;
+        copy_path(&entry.path(), &to.join(entry.file_name()))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+#[path = "paths_tests.rs"]
+mod paths_tests;
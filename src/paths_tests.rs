@@ -0,0 +1,161 @@
+//! Tests for paths
+
+use super::*;
+use std::collections::HashMap;
+use tempfile::TempDir;
+
+fn resolve(platform: Platform, vars: &[(&str, &str)]) -> Dirs {
+    let vars: HashMap<String, OsString> = vars
+        .iter()
+        .map(|(k, v)| (k.to_string(), OsString::from(v)))
+        .collect();
+    Dirs::resolve(platform, Path::new("/home/u"), |name| {
+        vars.get(name).cloned()
+    })
+}
+
+#[test]
+fn test_unix_defaults() {
+    let dirs = resolve(Platform::Unix, &[]);
+    assert_eq!(dirs.config, Path::new("/home/u/.config/jiq"));
+    assert_eq!(dirs.data, Path::new("/home/u/.local/share/jiq"));
+    assert_eq!(dirs.state, Path::new("/home/u/.local/state/jiq"));
+}
+
+#[test]
+fn test_unix_xdg_variables() {
+    let dirs = resolve(
+        Platform::Unix,
+        &[
+            ("XDG_CONFIG_HOME", "/xdg/config"),
+            ("XDG_DATA_HOME", "/xdg/data"),
+            ("XDG_STATE_HOME", "/xdg/state"),
+        ],
+    );
+    assert_eq!(dirs.config, Path::new("/xdg/config/jiq"));
+    assert_eq!(dirs.data, Path::new("/xdg/data/jiq"));
+    assert_eq!(dirs.state, Path::new("/xdg/state/jiq"));
+}
+
+#[test]
+fn test_relative_xdg_variables_are_ignored() {
+    let dirs = resolve(
+        Platform::Unix,
+        &[("XDG_CONFIG_HOME", "config"), ("XDG_STATE_HOME", "")],
+    );
+    assert_eq!(dirs, resolve(Platform::Unix, &[]));
+}
+
+#[test]
+fn test_macos_defaults() {
+    let dirs = resolve(Platform::MacOs, &[]);
+    assert_eq!(dirs.config, Path::new("/home/u/.config/jiq"));
+    assert_eq!(
+        dirs.data,
+        Path::new("/home/u/Library/Application Support/jiq")
+    );
+    assert_eq!(dirs.state, dirs.data);
+}
+
+#[test]
+fn test_windows_uses_app_data() {
+    let dirs = resolve(
+        Platform::Windows,
+        &[
+            ("APPDATA", "/users/u/roaming"),
+            ("LOCALAPPDATA", "/users/u/local"),
+            ("XDG_CONFIG_HOME", "/xdg/config"),
+        ],
+    );
+    assert_eq!(dirs.config, Path::new("/users/u/roaming/jiq"));
+    assert_eq!(dirs.data, Path::new("/users/u/roaming/jiq"));
+    assert_eq!(dirs.state, Path::new("/users/u/local/jiq"));
+}
+
+#[test]
+fn test_legacy_moves_skip_unchanged_locations() {
+    let dirs = resolve(Platform::Unix, &[]);
+    let moves = dirs.legacy_moves(
+        Path::new("/home/u/.config/jiq"),
+        Path::new("/home/u/.local/share/jiq"),
+    );
+
+    let find = |from: &str| {
+        moves
+            .iter()
+            .find(|(f, _)| f == Path::new(from))
+            .map(|(_, to)| to.clone())
+    };
+    assert_eq!(find("/home/u/.config/jiq/config.toml"), None);
+    assert_eq!(find("/home/u/.local/share/jiq/sessions"), None);
+    assert_eq!(
+        find("/home/u/.config/jiq/snippets.toml"),
+        Some(PathBuf::from("/home/u/.local/share/jiq/snippets.toml"))
+    );
+    assert_eq!(
+        find("/home/u/.local/share/jiq/history"),
+        Some(PathBuf::from("/home/u/.local/state/jiq/history"))
+    );
+    assert_eq!(
+        find("/home/u/.local/share/jiq/history.1"),
+        Some(PathBuf::from("/home/u/.local/state/jiq/history.1"))
+    );
+}
+
+#[test]
+fn test_migrate_moves_files_and_directories() {
+    let dir = TempDir::new().unwrap();
+    let old = dir.path().join("old");
+    let new = dir.path().join("new");
+    fs::create_dir_all(old.join("bookmarks")).unwrap();
+    fs::write(old.join("bookmarks").join("a.toml"), "x").unwrap();
+    fs::write(old.join("history"), "q\n").unwrap();
+    let moves = vec![
+        (old.join("bookmarks"), new.join("data").join("bookmarks")),
+        (old.join("history"), new.join("state").join("history")),
+        (old.join("missing"), new.join("missing")),
+    ];
+
+    let moved = migrate_files(&moves);
+
+    assert_eq!(moved, moves[..2]);
+    assert_eq!(
+        fs::read_to_string(new.join("data").join("bookmarks").join("a.toml")).unwrap(),
+        "x"
+    );
+    assert_eq!(
+        fs::read_to_string(new.join("state").join("history")).unwrap(),
+        "q\n"
+    );
+    assert!(!old.join("history").exists());
+}
+
+#[test]
+fn test_migrate_keeps_existing_files() {
+    let dir = TempDir::new().unwrap();
+    let from = dir.path().join("old_history");
+    let to = dir.path().join("history");
+    fs::write(&from, "old\n").unwrap();
+    fs::write(&to, "new\n").unwrap();
+
+    let moved = migrate_files(&[(from.clone(), to.clone())]);
+
+    assert!(moved.is_empty());
+    assert_eq!(fs::read_to_string(&to).unwrap(), "new\n");
+    assert!(from.exists());
+}
+
+#[test]
+fn test_copy_path_copies_directories() {
+    let dir = TempDir::new().unwrap();
+    let from = dir.path().join("from");
+    fs::create_dir_all(from.join("nested")).unwrap();
+    fs::write(from.join("nested").join("f"), "1").unwrap();
+
+    copy_path(&from, &dir.path().join("to")).unwrap();
+
+    assert_eq!(
+        fs::read_to_string(dir.path().join("to").join("nested").join("f")).unwrap(),
+        "1"
+    );
+}
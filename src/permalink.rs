@@ -0,0 +1,87 @@
+//! Query Permalinks
+//!
+//! A permalink packs a query, its language and optionally the variable
+//! bindings into a `jiq://` string that fits in a chat message. The data
+//! itself is never included, so colleagues can run a shared query on their
+//! own copy of the input. `:share` copies a link, and pasting one or passing
+//! it to `jiq --open` restores the query.
+
+use base64::{Engine as _, engine::general_purpose::URL_SAFE_NO_PAD};
+use serde::{Deserialize, Serialize};
+
+use crate::app::App;
+use crate::query::language::QueryLanguage;
+use crate::query::variables::QueryVariable;
+use crate::session::Session;
+
+pub const PREFIX: &str = "jiq://";
+
+/// Query shared through a `jiq://` link
+///
+/// Field names are kept to one letter as they end up in the link.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Permalink {
+    #[serde(rename = "q", default)]
+    pub query: String,
+    #[serde(rename = "l", default, skip_serializing_if = "is_default_language")]
+    pub language: QueryLanguage,
+    #[serde(rename = "v", default, skip_serializing_if = "Vec::is_empty")]
+    pub variables: Vec<QueryVariable>,
+}
+
+fn is_default_language(language: &QueryLanguage) -> bool {
+    *language == QueryLanguage::default()
+}
+
+impl Permalink {
+    /// Capture the current query of `app`, with its variable bindings if
+    /// `include_variables` is set
+    pub fn capture(app: &App, include_variables: bool) -> Self {
+        Self {
+            query: app.query().to_string(),
+            language: app.query.as_ref().map(|q| q.language).unwrap_or_default(),
+            variables: if include_variables {
+                app.variables.variables().to_vec()
+            } else {
+                Vec::new()
+            },
+        }
+    }
+
+    pub fn encode(&self) -> String {
+        let json = serde_json::to_string(self).unwrap_or_default();
+        format!("{}{}", PREFIX, URL_SAFE_NO_PAD.encode(json))
+    }
+
+    /// Parse a `jiq://` link, ignoring surrounding whitespace
+    pub fn decode(link: &str) -> Result<Self, String> {
+        let encoded = link
+            .trim()
+            .strip_prefix(PREFIX)
+            .ok_or_else(|| format!("Not a {} link", PREFIX))?;
+        let json = URL_SAFE_NO_PAD
+            .decode(encoded.trim_end_matches('='))
+            .map_err(|_| format!("Invalid {} link", PREFIX))?;
+        serde_json::from_slice(&json).map_err(|_| format!("Invalid {} link", PREFIX))
+    }
+}
+
+/// Whether pasted `text` is a permalink rather than part of a query
+pub fn is_permalink(text: &str) -> bool {
+    text.trim().starts_with(PREFIX)
+}
+
+impl From<Permalink> for Session {
+    fn from(permalink: Permalink) -> Self {
+        Self {
+            query: permalink.query,
+            language: permalink.language,
+            variables: permalink.variables,
+            ..Self::default()
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "permalink_tests.rs"]
+mod permalink_tests;
@@ -0,0 +1,119 @@
+//! Tests for permalink
+
+use super::*;
+use crate::query::variables::VariableKind;
+use crate::test_utils::test_helpers::app_with_query;
+
+fn sample_permalink() -> Permalink {
+    Permalink {
+        query: ".items[] | select(.status == $state)".to_string(),
+        language: QueryLanguage::Jq,
+        variables: vec![QueryVariable::new("state", "Running", VariableKind::String)],
+    }
+}
+
+#[test]
+fn test_permalink_round_trips() {
+    let permalink = sample_permalink();
+    let link = permalink.encode();
+
+    assert!(link.starts_with(PREFIX));
+    assert_eq!(Permalink::decode(&link).unwrap(), permalink);
+}
+
+#[test]
+fn test_link_is_url_safe() {
+    let permalink = Permalink {
+        query: "..|select(type == \"string\")?|.[0:1]".repeat(10),
+        ..Permalink::default()
+    };
+    let link = permalink.encode();
+    let encoded = link.strip_prefix(PREFIX).unwrap();
+
+    assert!(
+        encoded
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_'),
+        "{}",
+        encoded
+    );
+}
+
+#[test]
+fn test_defaults_are_left_out() {
+    let permalink = Permalink {
+        query: ".a".to_string(),
+        ..Permalink::default()
+    };
+    let link = permalink.encode();
+    let json = URL_SAFE_NO_PAD
+        .decode(link.strip_prefix(PREFIX).unwrap())
+        .unwrap();
+
+    assert_eq!(String::from_utf8(json).unwrap(), r#"{"q":".a"}"#);
+}
+
+#[test]
+fn test_language_is_kept() {
+    let permalink = Permalink {
+        query: "$.items[*]".to_string(),
+        language: QueryLanguage::JsonPath,
+        variables: Vec::new(),
+    };
+    let decoded = Permalink::decode(&permalink.encode()).unwrap();
+    assert_eq!(decoded.language, QueryLanguage::JsonPath);
+}
+
+#[test]
+fn test_decode_ignores_whitespace_and_padding() {
+    let link = format!("  {}==\n", sample_permalink().encode());
+    assert_eq!(Permalink::decode(&link).unwrap(), sample_permalink());
+}
+
+#[test]
+fn test_decode_rejects_other_text() {
+    assert_eq!(
+        Permalink::decode(".items[]"),
+        Err("Not a jiq:// link".to_string())
+    );
+    assert_eq!(
+        Permalink::decode("jiq://not base64!"),
+        Err("Invalid jiq:// link".to_string())
+    );
+    let not_json = format!("{}{}", PREFIX, URL_SAFE_NO_PAD.encode("nope"));
+    assert_eq!(
+        Permalink::decode(&not_json),
+        Err("Invalid jiq:// link".to_string())
+    );
+}
+
+#[test]
+fn test_is_permalink() {
+    assert!(is_permalink("jiq://eyJxIjoiLmEifQ"));
+    assert!(is_permalink("  jiq://eyJxIjoiLmEifQ\n"));
+    assert!(!is_permalink(".url | startswith(\"jiq://\")"));
+}
+
+#[test]
+fn test_capture_with_and_without_variables() {
+    let mut app = app_with_query(".name");
+    app.variables
+        .set_variables(vec![QueryVariable::new("n", "1", VariableKind::Json)]);
+
+    let without = Permalink::capture(&app, false);
+    assert_eq!(without.query, ".name");
+    assert!(without.variables.is_empty());
+
+    let with = Permalink::capture(&app, true);
+    assert_eq!(with.variables.len(), 1);
+    assert_eq!(with.variables[0].name, "n");
+}
+
+#[test]
+fn test_session_from_permalink_has_no_input() {
+    let session = Session::from(sample_permalink());
+    assert_eq!(session.query, sample_permalink().query);
+    assert_eq!(session.variables, sample_permalink().variables);
+    assert_eq!(session.input, None);
+    assert_eq!(session.command, None);
+}
@@ -0,0 +1,8 @@
+pub mod pipeline_events;
+pub mod pipeline_render;
+mod pipeline_splitter;
+mod pipeline_state;
+
+pub use pipeline_splitter::{comment_offset, pipe_offsets};
+#[allow(unused_imports)]
+pub use pipeline_state::{PipelineState, Stage, StageOutput};
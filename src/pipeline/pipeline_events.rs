@@ -0,0 +1,57 @@
+use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use crate::app::App;
+use crate::query::language::QueryLanguage;
+
+const OUTPUT_PAGE: u16 = 10;
+
+/// Open the pipeline view for the current query and start evaluating stages
+pub fn open_pipeline(app: &mut App) {
+    let Some(query_state) = &app.query else {
+        return;
+    };
+    if query_state.language != QueryLanguage::Jq {
+        app.notification
+            .show_warning("Pipeline view is only available for jq queries");
+        return;
+    }
+
+    let input = query_state.executor.json_input_shared();
+    let variables = query_state.variables();
+    let library = query_state.library();
+    let query = app.input.query().to_string();
+    app.autocomplete.hide();
+    app.pipeline.open(&query);
+    app.pipeline.evaluate(input, variables, library);
+}
+
+pub fn handle_pipeline_key(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('q') => app.pipeline.close(),
+        KeyCode::Char('g') if key.modifiers.contains(KeyModifiers::CONTROL) => app.pipeline.close(),
+        KeyCode::Up | KeyCode::Char('k') => app.pipeline.select_previous(),
+        KeyCode::Down | KeyCode::Char('j') => app.pipeline.select_next(),
+        KeyCode::PageDown => app.pipeline.scroll_output_down(OUTPUT_PAGE),
+        KeyCode::PageUp => app.pipeline.scroll_output_up(OUTPUT_PAGE),
+        KeyCode::Char(' ') => {
+            app.pipeline.toggle_selected();
+            if let Some(query_state) = &app.query {
+                app.pipeline.evaluate(
+                    query_state.executor.json_input_shared(),
+                    query_state.variables(),
+                    query_state.library(),
+                );
+            }
+        }
+        KeyCode::Enter => {
+            let query = app.pipeline.enabled_query();
+            app.pipeline.close();
+            crate::editor::editor_events::replace_query_and_execute(app, &query);
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+#[path = "pipeline_events_tests.rs"]
+mod pipeline_events_tests;
@@ -0,0 +1,93 @@
+//! Tests for pipeline view key handling
+
+use crate::query::language::QueryLanguage;
+use crate::test_utils::test_helpers::{app_with_query, key, key_with_mods};
+use ratatui::crossterm::event::{KeyCode, KeyModifiers};
+
+fn ctrl_g() -> ratatui::crossterm::event::KeyEvent {
+    key_with_mods(KeyCode::Char('g'), KeyModifiers::CONTROL)
+}
+
+#[test]
+fn test_ctrl_g_opens_and_closes_pipeline_view() {
+    let mut app = app_with_query(".services | .[] | .name");
+
+    app.handle_key_event(ctrl_g());
+    assert!(app.pipeline.is_visible());
+    assert_eq!(app.pipeline.stages().len(), 3);
+
+    app.handle_key_event(ctrl_g());
+    assert!(!app.pipeline.is_visible());
+}
+
+#[test]
+fn test_esc_closes_pipeline_view() {
+    let mut app = app_with_query(".a | .b");
+    app.handle_key_event(ctrl_g());
+
+    app.handle_key_event(key(KeyCode::Esc));
+    assert!(!app.pipeline.is_visible());
+    assert_eq!(app.input.query(), ".a | .b");
+}
+
+#[test]
+fn test_navigation_and_toggle() {
+    let mut app = app_with_query(".a | .b | .c");
+    app.handle_key_event(ctrl_g());
+    assert_eq!(app.pipeline.selected(), 2);
+
+    app.handle_key_event(key(KeyCode::Up));
+    app.handle_key_event(key(KeyCode::Char('k')));
+    assert_eq!(app.pipeline.selected(), 0);
+
+    app.handle_key_event(key(KeyCode::Char('j')));
+    app.handle_key_event(key(KeyCode::Char(' ')));
+    assert!(!app.pipeline.stages()[1].enabled);
+    assert_eq!(app.pipeline.enabled_query(), ".a | .c");
+}
+
+#[test]
+fn test_enter_applies_enabled_stages() {
+    let mut app = app_with_query(".services | .[0] | .name");
+    app.handle_key_event(ctrl_g());
+
+    app.handle_key_event(key(KeyCode::Char(' ')));
+    app.handle_key_event(key(KeyCode::Enter));
+
+    assert!(!app.pipeline.is_visible());
+    assert_eq!(app.input.query(), ".services | .[0]");
+}
+
+#[test]
+fn test_enter_refreshes_tooltip_for_applied_query() {
+    let mut app = app_with_query(".a | .b | length");
+    app.update_tooltip();
+    assert_eq!(app.tooltip.current_function, Some("length".to_string()));
+    app.handle_key_event(ctrl_g());
+
+    app.handle_key_event(key(KeyCode::Char(' ')));
+    app.handle_key_event(key(KeyCode::Enter));
+
+    assert_eq!(app.input.query(), ".a | .b");
+    assert_eq!(app.tooltip.current_function, None);
+}
+
+#[test]
+fn test_other_keys_do_not_edit_query() {
+    let mut app = app_with_query(".a");
+    app.handle_key_event(ctrl_g());
+
+    app.handle_key_event(key(KeyCode::Char('x')));
+    assert!(app.pipeline.is_visible());
+    assert_eq!(app.input.query(), ".a");
+}
+
+#[test]
+fn test_not_available_for_other_languages() {
+    let mut app = app_with_query("");
+    app.query.as_mut().unwrap().language = QueryLanguage::JsonPath;
+
+    app.handle_key_event(ctrl_g());
+    assert!(!app.pipeline.is_visible());
+    assert!(app.notification.current().is_some());
+}
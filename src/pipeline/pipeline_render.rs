@@ -0,0 +1,182 @@
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span, Text},
+    widgets::{Block, BorderType, Borders, Paragraph},
+};
+
+use super::pipeline_state::{PipelineState, StageOutput};
+use crate::theme;
+use crate::widgets::popup;
+use crate::widgets::text_width::{display_width, truncate_to_width};
+
+const MIN_OUTPUT_HEIGHT: u16 = 3;
+
+fn build_hints() -> Line<'static> {
+    theme::border_hints::build_hints(
+        &[
+            ("↑/↓", "Select"),
+            ("Space", "Toggle"),
+            ("Enter", "Apply"),
+            ("Esc", "Close"),
+        ],
+        theme::pipeline::BORDER,
+    )
+}
+
+/// Render the pipeline view over the results pane
+///
+/// The top block lists the stages; the bottom block shows the output of the
+/// selected stage.
+pub fn render_popup(state: &PipelineState, frame: &mut Frame, results_area: Rect) {
+    popup::clear_area(frame, results_area);
+
+    let wanted_list_height = state.stages().len() as u16 + 2;
+    let list_height = wanted_list_height
+        .min(results_area.height.saturating_sub(MIN_OUTPUT_HEIGHT))
+        .max(3.min(results_area.height));
+
+    let layout =
+        Layout::vertical([Constraint::Length(list_height), Constraint::Min(0)]).split(results_area);
+
+    render_stage_list(state, frame, layout[0]);
+    render_output(state, frame, layout[1]);
+}
+
+fn render_stage_list(state: &PipelineState, frame: &mut Frame, area: Rect) {
+    let visible = area.height.saturating_sub(2) as usize;
+    let first = (state.selected() + 1).saturating_sub(visible.max(1));
+    let inner_width = area.width.saturating_sub(2) as usize;
+
+    let lines: Vec<Line> = (first..state.stages().len())
+        .take(visible)
+        .map(|index| build_stage_line(state, index, inner_width))
+        .collect();
+
+    let title = format!(
+        " Pipeline ({} stage{}) ",
+        state.stages().len(),
+        if state.stages().len() == 1 { "" } else { "s" }
+    );
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(title)
+        .border_style(Style::default().fg(theme::pipeline::BORDER))
+        .style(Style::default().bg(theme::pipeline::BACKGROUND));
+
+    frame.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+fn build_stage_line(state: &PipelineState, index: usize, width: usize) -> Line<'static> {
+    let stage = &state.stages()[index];
+    let is_selected = index == state.selected();
+
+    let indicator = if is_selected { "▸ " } else { "  " };
+    let number = format!("{:>2}. ", index + 1);
+    let checkbox = if stage.enabled { "[x] " } else { "[ ] " };
+    let (summary, summary_color) = stage_summary(state, index);
+
+    let fixed = [indicator, number.as_str(), checkbox, summary.as_str()]
+        .iter()
+        .map(|part| display_width(part))
+        .sum::<usize>()
+        + 1;
+    let text_width = width.saturating_sub(fixed);
+    let text = truncate(&stage.text, text_width);
+    let padding = text_width.saturating_sub(display_width(&text));
+
+    let text_style = if stage.enabled {
+        Style::default().fg(theme::pipeline::STAGE_TEXT)
+    } else {
+        Style::default()
+            .fg(theme::pipeline::STAGE_DISABLED)
+            .add_modifier(Modifier::CROSSED_OUT)
+    };
+
+    let mut line = Line::from(vec![
+        Span::styled(
+            indicator,
+            Style::default().fg(theme::pipeline::ITEM_SELECTED_INDICATOR),
+        ),
+        Span::styled(number, Style::default().fg(theme::pipeline::STAGE_NUMBER)),
+        Span::styled(checkbox, text_style),
+        Span::styled(text, text_style),
+        Span::raw(" ".repeat(padding + 1)),
+        Span::styled(summary, Style::default().fg(summary_color)),
+    ]);
+    if is_selected {
+        line = line.style(Style::default().bg(theme::pipeline::ITEM_SELECTED_BG));
+    }
+    line
+}
+
+fn stage_summary(state: &PipelineState, index: usize) -> (String, ratatui::style::Color) {
+    if !state.stages()[index].enabled {
+        return ("skipped".to_string(), theme::pipeline::SUMMARY_PENDING);
+    }
+    match state.output(index) {
+        None | Some(StageOutput::Pending) => {
+            ("running…".to_string(), theme::pipeline::SUMMARY_PENDING)
+        }
+        Some(StageOutput::Failed(_)) => ("error".to_string(), theme::pipeline::ERROR),
+        Some(StageOutput::Done {
+            value_count: Some(0),
+            ..
+        }) => ("no output".to_string(), theme::pipeline::SUMMARY_EMPTY),
+        Some(StageOutput::Done {
+            value_count: Some(1),
+            ..
+        }) => ("1 value".to_string(), theme::pipeline::SUMMARY),
+        Some(StageOutput::Done {
+            value_count: Some(count),
+            ..
+        }) => (format!("{} values", count), theme::pipeline::SUMMARY),
+        Some(StageOutput::Done {
+            value_count: None, ..
+        }) => ("done".to_string(), theme::pipeline::SUMMARY),
+    }
+}
+
+fn render_output(state: &PipelineState, frame: &mut Frame, area: Rect) {
+    if area.height == 0 {
+        return;
+    }
+
+    let title = format!(" Stage {} output ", state.selected() + 1);
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(title)
+        .title_bottom(build_hints().alignment(Alignment::Center))
+        .border_style(Style::default().fg(theme::pipeline::BORDER))
+        .style(Style::default().bg(theme::pipeline::BACKGROUND));
+
+    let content = match state.output(state.selected()) {
+        Some(StageOutput::Done { rendered, .. }) => rendered.clone(),
+        Some(StageOutput::Failed(message)) => {
+            Text::styled(message.clone(), Style::default().fg(theme::pipeline::ERROR))
+        }
+        None | Some(StageOutput::Pending) => Text::styled(
+            "Running…",
+            Style::default().fg(theme::pipeline::SUMMARY_PENDING),
+        ),
+    };
+
+    let paragraph = Paragraph::new(content)
+        .block(block)
+        .scroll((state.output_scroll(), 0));
+    frame.render_widget(paragraph, area);
+}
+
+fn truncate(text: &str, max_len: usize) -> String {
+    // Stages can span lines in the query; keep the list one row per stage
+    let flat = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    truncate_to_width(&flat, max_len)
+}
+
+#[cfg(test)]
+#[path = "pipeline_render_tests.rs"]
+mod pipeline_render_tests;
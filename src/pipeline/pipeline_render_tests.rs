@@ -0,0 +1,64 @@
+//! Tests for pipeline view rendering
+
+use super::*;
+use insta::assert_snapshot;
+use ratatui::Terminal;
+use ratatui::backend::TestBackend;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+fn render_to_string(state: &PipelineState, width: u16, height: u16) -> String {
+    let backend = TestBackend::new(width, height);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal.draw(|f| render_popup(state, f, f.area())).unwrap();
+    terminal.backend().to_string()
+}
+
+fn evaluated_state(query: &str, input: &str) -> PipelineState {
+    let mut state = PipelineState::new();
+    state.open(query);
+    state.evaluate(Arc::new(input.to_string()), Arc::default(), Arc::default());
+
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while Instant::now() < deadline {
+        state.poll_responses();
+        let done = (0..state.stages().len())
+            .all(|i| !matches!(state.output(i), Some(StageOutput::Pending) | None));
+        if done {
+            return state;
+        }
+        std::thread::sleep(Duration::from_millis(10));
+    }
+    panic!("stage evaluation timed out");
+}
+
+#[test]
+fn snapshot_pipeline_view() {
+    let state = evaluated_state(".items | .[] | select(. > 1)", r#"{"items":[1,2,3]}"#);
+    assert_snapshot!(render_to_string(&state, 70, 14));
+}
+
+#[test]
+fn snapshot_pipeline_view_with_disabled_stage_and_error() {
+    let mut state = evaluated_state(".a | keys | .[0]", r#"{"a":{"b":1}}"#);
+    state.select_previous();
+    state.toggle_selected();
+    state.evaluate(
+        Arc::new(r#"{"a":{"b":1}}"#.to_string()),
+        Arc::default(),
+        Arc::default(),
+    );
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while matches!(state.output(2), Some(StageOutput::Pending)) && Instant::now() < deadline {
+        state.poll_responses();
+        std::thread::sleep(Duration::from_millis(10));
+    }
+    state.select_next();
+    assert_snapshot!(render_to_string(&state, 70, 12));
+}
+
+#[test]
+fn test_long_stage_is_truncated() {
+    assert_eq!(truncate(".a.b.c.d", 5), ".a.b…");
+    assert_eq!(truncate(".a\n  .b", 10), ".a .b");
+}
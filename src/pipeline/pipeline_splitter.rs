@@ -0,0 +1,174 @@
+//! Top-level pipe splitting
+//!
+//! Splits a jq query into pipeline stages at `|` operators that are not
+//! nested inside brackets, strings, `if ... end` or `def ...;` bodies.
+
+/// Constructs that hide pipes from the top level
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Open {
+    Paren,
+    Bracket,
+    Brace,
+    /// `\(...)` inside a string literal
+    Interpolation,
+    If,
+    Def,
+}
+
+/// Tokens reported at the top level of a query
+enum TopLevel<'a> {
+    /// Byte offset of a pipe operator
+    Pipe(usize),
+    /// Byte offset of the `#` starting a comment, at any depth
+    Comment(usize),
+    /// Keyword or function name (field and variable names excluded)
+    Word(&'a str),
+}
+
+/// Split `query` into trimmed pipeline stages, dropping empty ones
+///
+/// Examples:
+/// - `.a | .b[] | select(.x | . > 1)` → [`.a`, `.b[]`, `select(.x | . > 1)`]
+/// - `.a |= 1 | keys` → [`.a |= 1`, `keys`]
+pub fn split_stages(query: &str) -> Vec<String> {
+    let mut stages = Vec::new();
+    let mut start = 0;
+    scan_top_level(query, |token| {
+        if let TopLevel::Pipe(idx) = token {
+            stages.push(query[start..idx].trim().to_string());
+            start = idx + 1;
+        }
+    });
+    stages.push(query[start..].trim().to_string());
+    stages.retain(|stage| !stage.is_empty());
+    stages
+}
+
+/// Byte offsets of the top-level pipes in `query`
+pub fn pipe_offsets(query: &str) -> Vec<usize> {
+    let mut offsets = Vec::new();
+    scan_top_level(query, |token| {
+        if let TopLevel::Pipe(idx) = token {
+            offsets.push(idx);
+        }
+    });
+    offsets
+}
+
+/// Byte offset of the `#` starting the first comment in `query`
+pub fn comment_offset(query: &str) -> Option<usize> {
+    let mut offset = None;
+    scan_top_level(query, |token| {
+        if let TopLevel::Comment(idx) = token {
+            offset.get_or_insert(idx);
+        }
+    });
+    offset
+}
+
+/// Whether a stage ends in a binding (`E as $x`, `label $out`) whose body is
+/// the rest of the pipeline
+///
+/// Such a stage is not a complete program on its own; evaluating it as a
+/// prefix needs an explicit ` | .` body.
+pub fn binds_variables(stage: &str) -> bool {
+    let mut binds = false;
+    scan_top_level(stage, |token| {
+        if let TopLevel::Word("as" | "label") = token {
+            binds = true;
+        }
+    });
+    binds
+}
+
+fn scan_top_level<'a>(query: &'a str, mut on_token: impl FnMut(TopLevel<'a>)) {
+    let chars: Vec<(usize, char)> = query.char_indices().collect();
+    let mut stack: Vec<Open> = Vec::new();
+    let mut in_string = false;
+    let mut i = 0;
+
+    while i < chars.len() {
+        let (idx, c) = chars[i];
+        let next = chars.get(i + 1).map(|(_, c)| *c);
+
+        if in_string {
+            match c {
+                '\\' if next == Some('(') => {
+                    stack.push(Open::Interpolation);
+                    in_string = false;
+                    i += 2;
+                }
+                '\\' => i += 2,
+                '"' => {
+                    in_string = false;
+                    i += 1;
+                }
+                _ => i += 1,
+            }
+            continue;
+        }
+
+        match c {
+            '"' => in_string = true,
+            '#' => {
+                on_token(TopLevel::Comment(idx));
+                while i < chars.len() && chars[i].1 != '\n' {
+                    i += 1;
+                }
+                continue;
+            }
+            '(' => stack.push(Open::Paren),
+            '[' => stack.push(Open::Bracket),
+            '{' => stack.push(Open::Brace),
+            ')' if stack.last() == Some(&Open::Interpolation) => {
+                stack.pop();
+                in_string = true;
+            }
+            ')' => close(&mut stack, Open::Paren),
+            ']' => close(&mut stack, Open::Bracket),
+            '}' => close(&mut stack, Open::Brace),
+            ';' if stack.last() == Some(&Open::Def) => {
+                stack.pop();
+            }
+            '|' if stack.is_empty() && next != Some('=') => on_token(TopLevel::Pipe(idx)),
+            c if c.is_ascii_alphabetic() || c == '_' => {
+                let end = chars[i..]
+                    .iter()
+                    .position(|(_, c)| !(c.is_ascii_alphanumeric() || *c == '_'))
+                    .map(|n| i + n)
+                    .unwrap_or(chars.len());
+                let byte_end = chars.get(end).map(|(b, _)| *b).unwrap_or(query.len());
+                let word = &query[idx..byte_end];
+                let prev = i.checked_sub(1).map(|p| chars[p].1);
+
+                // `.end` and `$if` are names, not keywords
+                if !matches!(prev, Some('.' | '$')) {
+                    match word {
+                        "if" => stack.push(Open::If),
+                        "end" => close(&mut stack, Open::If),
+                        "def" => stack.push(Open::Def),
+                        _ => {}
+                    }
+                    if stack.is_empty() {
+                        on_token(TopLevel::Word(word));
+                    }
+                }
+                i = end;
+                continue;
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+}
+
+/// Pop up to and including the nearest `open`, discarding unbalanced keywords
+fn close(stack: &mut Vec<Open>, open: Open) {
+    if let Some(pos) = stack.iter().rposition(|o| *o == open) {
+        stack.truncate(pos);
+    }
+}
+
+#[cfg(test)]
+#[path = "pipeline_splitter_tests.rs"]
+mod pipeline_splitter_tests;
@@ -0,0 +1,89 @@
+//! Tests for pipeline splitting
+
+use super::*;
+
+#[test]
+fn test_splits_top_level_pipes() {
+    assert_eq!(
+        split_stages(".services | .[] | .name"),
+        vec![".services", ".[]", ".name"]
+    );
+}
+
+#[test]
+fn test_single_stage_and_empty_query() {
+    assert_eq!(split_stages(".a.b"), vec![".a.b"]);
+    assert!(split_stages("").is_empty());
+    assert_eq!(split_stages(".a | "), vec![".a"]);
+}
+
+#[test]
+fn test_nested_pipes_stay_in_their_stage() {
+    assert_eq!(
+        split_stages(".[] | select(.x | . > 1) | [.a | .b] | {k: (.c | length)}"),
+        vec![
+            ".[]",
+            "select(.x | . > 1)",
+            "[.a | .b]",
+            "{k: (.c | length)}"
+        ]
+    );
+}
+
+#[test]
+fn test_strings_and_interpolation() {
+    assert_eq!(
+        split_stages(r#".a | "x | y" | "\(.b | length) | z" | ."p|q""#),
+        vec![".a", r#""x | y""#, r#""\(.b | length) | z""#, r#"."p|q""#]
+    );
+}
+
+#[test]
+fn test_update_assignment_is_not_a_pipe() {
+    assert_eq!(split_stages(".a |= 1 | keys"), vec![".a |= 1", "keys"]);
+}
+
+#[test]
+fn test_if_and_def_bodies_are_not_split() {
+    assert_eq!(
+        split_stages(".[] | if .a then .b | .c else .d end | length"),
+        vec![".[]", "if .a then .b | .c else .d end", "length"]
+    );
+    assert_eq!(
+        split_stages("def f: .a | .b; .x | f"),
+        vec!["def f: .a | .b; .x", "f"]
+    );
+}
+
+#[test]
+fn test_field_named_like_keyword() {
+    assert_eq!(split_stages(".if | .end"), vec![".if", ".end"]);
+}
+
+#[test]
+fn test_comment_pipes_are_ignored() {
+    assert_eq!(split_stages(".a # x | y\n| .b"), vec![".a # x | y", ".b"]);
+}
+
+#[test]
+fn test_pipe_offsets() {
+    assert_eq!(pipe_offsets(".a | map(.b | .c) | .d"), vec![3, 18]);
+    assert_eq!(pipe_offsets(".a # | .b"), Vec::<usize>::new());
+}
+
+#[test]
+fn test_comment_offset() {
+    assert_eq!(comment_offset(".a # | .b"), Some(3));
+    assert_eq!(comment_offset("\"#\" | .a"), None);
+    assert_eq!(comment_offset("map(.a # x\n)"), Some(7));
+    assert_eq!(comment_offset(".a | .b"), None);
+}
+
+#[test]
+fn test_binds_variables() {
+    assert!(binds_variables(".[] as $x"));
+    assert!(binds_variables("label $out"));
+    assert!(!binds_variables(".as"));
+    assert!(!binds_variables("reduce_like($as)"));
+    assert!(!binds_variables("map(. as $x | $x)"));
+}
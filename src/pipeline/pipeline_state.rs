@@ -0,0 +1,301 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::mpsc::{Receiver, Sender, channel};
+use std::time::Instant;
+
+use ratatui::text::Text;
+use tokio_util::sync::CancellationToken;
+
+use super::pipeline_splitter::{binds_variables, split_stages};
+use crate::error::parser;
+use crate::query::executor::JqExecutor;
+use crate::query::library::JqLibrary;
+use crate::query::variables::QueryVariable;
+use crate::syntax_highlight::json::JsonHighlighter;
+
+/// One `|`-separated stage of the query
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Stage {
+    pub text: String,
+    pub enabled: bool,
+}
+
+/// Output of the query prefix ending at a stage
+#[derive(Debug, Clone)]
+pub enum StageOutput {
+    Pending,
+    Done {
+        rendered: Text<'static>,
+        /// Number of JSON values produced, when the output parses
+        value_count: Option<usize>,
+        /// Wall-clock time jq took to run the prefix
+        elapsed_ms: u64,
+    },
+    Failed(String),
+}
+
+/// Finished evaluation of a stage program: its output and run time in ms
+type StageResponse = (String, Result<String, String>, u64);
+
+/// Pipeline view: the query split into stages with each prefix's output
+///
+/// Outputs are cached by the jq program that produced them, so toggling a
+/// stage back on (or revisiting one) doesn't re-run jq.
+pub struct PipelineState {
+    visible: bool,
+    stages: Vec<Stage>,
+    selected: usize,
+    output_scroll: u16,
+    cache: HashMap<String, StageOutput>,
+    response_tx: Sender<StageResponse>,
+    response_rx: Receiver<StageResponse>,
+    cancel_token: CancellationToken,
+}
+
+impl Default for PipelineState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PipelineState {
+    pub fn new() -> Self {
+        let (response_tx, response_rx) = channel();
+        Self {
+            visible: false,
+            stages: Vec::new(),
+            selected: 0,
+            output_scroll: 0,
+            cache: HashMap::new(),
+            response_tx,
+            response_rx,
+            cancel_token: CancellationToken::new(),
+        }
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    /// Open the view for `query`, selecting its last stage
+    pub fn open(&mut self, query: &str) {
+        let mut stages: Vec<Stage> = split_stages(query)
+            .into_iter()
+            .map(|text| Stage {
+                text,
+                enabled: true,
+            })
+            .collect();
+        if stages.is_empty() {
+            stages.push(Stage {
+                text: ".".to_string(),
+                enabled: true,
+            });
+        }
+
+        self.selected = stages.len() - 1;
+        self.stages = stages;
+        self.output_scroll = 0;
+        self.cache.clear();
+        self.cancel_token = CancellationToken::new();
+        self.visible = true;
+    }
+
+    /// Close the view, cancelling stage evaluations still running
+    pub fn close(&mut self) {
+        self.cancel_token.cancel();
+        self.cache
+            .retain(|_, output| !matches!(output, StageOutput::Pending));
+        self.visible = false;
+    }
+
+    pub fn stages(&self) -> &[Stage] {
+        &self.stages
+    }
+
+    pub fn selected(&self) -> usize {
+        self.selected
+    }
+
+    pub fn output_scroll(&self) -> u16 {
+        self.output_scroll
+    }
+
+    pub fn select_next(&mut self) {
+        if self.selected + 1 < self.stages.len() {
+            self.selected += 1;
+            self.output_scroll = 0;
+        }
+    }
+
+    pub fn select_previous(&mut self) {
+        if self.selected > 0 {
+            self.selected -= 1;
+            self.output_scroll = 0;
+        }
+    }
+
+    pub fn scroll_output_down(&mut self, lines: u16) {
+        self.output_scroll = self.output_scroll.saturating_add(lines);
+    }
+
+    pub fn scroll_output_up(&mut self, lines: u16) {
+        self.output_scroll = self.output_scroll.saturating_sub(lines);
+    }
+
+    /// Enable or disable the selected stage
+    pub fn toggle_selected(&mut self) {
+        if let Some(stage) = self.stages.get_mut(self.selected) {
+            stage.enabled = !stage.enabled;
+        }
+    }
+
+    /// The query made of the enabled stages
+    pub fn enabled_query(&self) -> String {
+        self.enabled_prefix(self.stages.len())
+    }
+
+    /// jq program whose output is the data leaving stage `index`
+    ///
+    /// Disabled stages are left out, so a disabled stage shows the output of
+    /// the last enabled stage before it.
+    pub fn program(&self, index: usize) -> String {
+        let prefix = self.enabled_prefix(index + 1);
+        let last_enabled = self.stages[..=index.min(self.stages.len() - 1)]
+            .iter()
+            .rev()
+            .find(|stage| stage.enabled);
+        match last_enabled {
+            None => ".".to_string(),
+            Some(stage) if binds_variables(&stage.text) => format!("{} | .", prefix),
+            Some(_) => prefix,
+        }
+    }
+
+    fn enabled_prefix(&self, end: usize) -> String {
+        let enabled: Vec<&str> = self.stages[..end.min(self.stages.len())]
+            .iter()
+            .filter(|stage| stage.enabled)
+            .map(|stage| stage.text.as_str())
+            .collect();
+        if enabled.is_empty() {
+            ".".to_string()
+        } else {
+            enabled.join(" | ")
+        }
+    }
+
+    /// Cached output for stage `index`, if it has been evaluated
+    pub fn output(&self, index: usize) -> Option<&StageOutput> {
+        self.cache.get(&self.program(index))
+    }
+
+    /// Start evaluating every stage whose program isn't cached yet
+    ///
+    /// Runs in a background thread; call `poll_responses` to collect results.
+    pub fn evaluate(
+        &mut self,
+        input: Arc<String>,
+        variables: Arc<Vec<QueryVariable>>,
+        library: Arc<JqLibrary>,
+    ) {
+        let mut programs: Vec<String> = Vec::new();
+        for index in 0..self.stages.len() {
+            let program = self.program(index);
+            if !self.cache.contains_key(&program) && !programs.contains(&program) {
+                programs.push(program);
+            }
+        }
+        if programs.is_empty() {
+            return;
+        }
+
+        for program in &programs {
+            self.cache.insert(program.clone(), StageOutput::Pending);
+        }
+
+        let tx = self.response_tx.clone();
+        let cancel_token = self.cancel_token.clone();
+        std::thread::spawn(move || {
+            for program in programs {
+                let start = Instant::now();
+                let result = JqExecutor::execute_on_input(
+                    &input,
+                    &program,
+                    &variables,
+                    &library,
+                    &cancel_token,
+                )
+                .map_err(|e| e.to_string());
+                let elapsed_ms = start.elapsed().as_millis() as u64;
+                if cancel_token.is_cancelled() || tx.send((program, result, elapsed_ms)).is_err() {
+                    return;
+                }
+            }
+        });
+    }
+
+    /// Store finished stage evaluations; returns true if any arrived
+    pub fn poll_responses(&mut self) -> bool {
+        let mut updated = false;
+        while let Ok((program, result, elapsed_ms)) = self.response_rx.try_recv() {
+            // Results from a cancelled run are no longer wanted
+            if !matches!(self.cache.get(&program), Some(StageOutput::Pending)) {
+                continue;
+            }
+            let output = stage_output(&program, result, elapsed_ms);
+            self.cache.insert(program, output);
+            updated = true;
+        }
+        updated
+    }
+
+    /// Enabled stage adding the most run time, with that time in ms
+    ///
+    /// Each prefix runs from the input, so a stage's cost is its prefix's run
+    /// time minus that of the previous enabled prefix. Stages after a failing
+    /// one are not considered; None while enabled stages are still running.
+    pub fn slowest_stage(&self) -> Option<(usize, u64)> {
+        let mut previous_ms = 0;
+        let mut slowest: Option<(usize, u64)> = None;
+        for index in 0..self.stages.len() {
+            if !self.stages[index].enabled {
+                continue;
+            }
+            match self.output(index) {
+                Some(StageOutput::Done { elapsed_ms, .. }) => {
+                    let cost = elapsed_ms.saturating_sub(previous_ms);
+                    previous_ms = *elapsed_ms;
+                    if slowest.is_none_or(|(_, slowest_ms)| cost > slowest_ms) {
+                        slowest = Some((index, cost));
+                    }
+                }
+                Some(StageOutput::Failed(_)) => break,
+                None | Some(StageOutput::Pending) => return None,
+            }
+        }
+        slowest
+    }
+}
+
+fn stage_output(program: &str, result: Result<String, String>, elapsed_ms: u64) -> StageOutput {
+    match result {
+        Ok(output) => {
+            let value_count = serde_json::Deserializer::from_str(&output)
+                .into_iter::<serde_json::Value>()
+                .try_fold(0, |count, value| value.map(|_| count + 1))
+                .ok();
+            let rendered = JsonHighlighter::highlight(&output);
+            StageOutput::Done {
+                rendered,
+                value_count,
+                elapsed_ms,
+            }
+        }
+        Err(stderr) => StageOutput::Failed(parser::parse(&stderr, program).display_text()),
+    }
+}
+
+#[cfg(test)]
+#[path = "pipeline_state_tests.rs"]
+mod pipeline_state_tests;
@@ -0,0 +1,204 @@
+//! Tests for pipeline state
+
+use super::*;
+use std::time::{Duration, Instant};
+
+fn wait_for_outputs(state: &mut PipelineState) {
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while Instant::now() < deadline {
+        state.poll_responses();
+        let done = (0..state.stages().len())
+            .all(|i| !matches!(state.output(i), Some(StageOutput::Pending) | None));
+        if done {
+            return;
+        }
+        std::thread::sleep(Duration::from_millis(10));
+    }
+    panic!("stage evaluation timed out");
+}
+
+fn count(state: &PipelineState, index: usize) -> Option<usize> {
+    match state.output(index) {
+        Some(StageOutput::Done { value_count, .. }) => *value_count,
+        other => panic!("stage {} not done: {:?}", index, other),
+    }
+}
+
+#[test]
+fn test_open_splits_query_and_selects_last_stage() {
+    let mut state = PipelineState::new();
+    state.open(".a | .[] | .b");
+    assert!(state.is_visible());
+    assert_eq!(state.stages().len(), 3);
+    assert_eq!(state.selected(), 2);
+    assert!(state.stages().iter().all(|s| s.enabled));
+}
+
+#[test]
+fn test_empty_query_has_identity_stage() {
+    let mut state = PipelineState::new();
+    state.open("  ");
+    assert_eq!(state.stages()[0].text, ".");
+}
+
+#[test]
+fn test_selection_is_clamped() {
+    let mut state = PipelineState::new();
+    state.open(".a | .b");
+    state.select_next();
+    assert_eq!(state.selected(), 1);
+    state.select_previous();
+    state.select_previous();
+    assert_eq!(state.selected(), 0);
+}
+
+#[test]
+fn test_programs_skip_disabled_stages() {
+    let mut state = PipelineState::new();
+    state.open(".a | .[] | .b");
+    assert_eq!(state.program(0), ".a");
+    assert_eq!(state.program(2), ".a | .[] | .b");
+
+    state.select_previous();
+    state.toggle_selected();
+    assert_eq!(state.program(1), ".a");
+    assert_eq!(state.program(2), ".a | .b");
+    assert_eq!(state.enabled_query(), ".a | .b");
+}
+
+#[test]
+fn test_all_disabled_is_identity() {
+    let mut state = PipelineState::new();
+    state.open(".a");
+    state.toggle_selected();
+    assert_eq!(state.program(0), ".");
+    assert_eq!(state.enabled_query(), ".");
+}
+
+#[test]
+fn test_binding_stage_gets_identity_body() {
+    let mut state = PipelineState::new();
+    state.open(".x as $x | .items[] | $x");
+    assert_eq!(state.program(0), ".x as $x | .");
+    assert_eq!(state.program(1), ".x as $x | .items[]");
+}
+
+#[test]
+fn test_evaluate_runs_each_prefix() {
+    let mut state = PipelineState::new();
+    state.open(".items | .[] | select(. > 1)");
+    state.evaluate(
+        Arc::new(r#"{"items":[1,2,3]}"#.to_string()),
+        Arc::default(),
+        Arc::default(),
+    );
+    wait_for_outputs(&mut state);
+
+    assert_eq!(count(&state, 0), Some(1));
+    assert_eq!(count(&state, 1), Some(3));
+    assert_eq!(count(&state, 2), Some(2));
+}
+
+#[test]
+fn test_failing_stage_reports_error() {
+    let mut state = PipelineState::new();
+    state.open(".a | .[0]");
+    state.evaluate(
+        Arc::new(r#"{"a":{"b":1}}"#.to_string()),
+        Arc::default(),
+        Arc::default(),
+    );
+    wait_for_outputs(&mut state);
+
+    assert!(matches!(state.output(0), Some(StageOutput::Done { .. })));
+    match state.output(1) {
+        Some(StageOutput::Failed(message)) => assert!(message.contains("Cannot index")),
+        other => panic!("expected failure, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_toggled_programs_are_cached() {
+    let mut state = PipelineState::new();
+    state.open(".a | .b");
+    state.evaluate(
+        Arc::new(r#"{"a":{"b":1}}"#.to_string()),
+        Arc::default(),
+        Arc::default(),
+    );
+    wait_for_outputs(&mut state);
+
+    state.toggle_selected();
+    // ".a" is already cached, so nothing new is pending
+    state.evaluate(Arc::new(String::new()), Arc::default(), Arc::default());
+    assert!(matches!(state.output(1), Some(StageOutput::Done { .. })));
+}
+
+#[test]
+fn test_close_drops_pending_outputs() {
+    let mut state = PipelineState::new();
+    state.open(".a");
+    state.evaluate(Arc::new("{}".to_string()), Arc::default(), Arc::default());
+    state.close();
+    assert!(!state.is_visible());
+    assert!(state.output(0).is_none());
+}
+
+fn done_in(elapsed_ms: u64) -> StageOutput {
+    StageOutput::Done {
+        rendered: Text::default(),
+        value_count: Some(1),
+        elapsed_ms,
+    }
+}
+
+#[test]
+fn test_slowest_stage_uses_time_added_by_each_stage() {
+    let mut state = PipelineState::new();
+    state.open(".a | .b | .c");
+    state.cache.insert(".a".to_string(), done_in(5));
+    state.cache.insert(".a | .b".to_string(), done_in(45));
+    state.cache.insert(".a | .b | .c".to_string(), done_in(60));
+
+    assert_eq!(state.slowest_stage(), Some((1, 40)));
+}
+
+#[test]
+fn test_slowest_stage_waits_for_pending_stages() {
+    let mut state = PipelineState::new();
+    state.open(".a | .b");
+    state.cache.insert(".a".to_string(), done_in(5));
+    state
+        .cache
+        .insert(".a | .b".to_string(), StageOutput::Pending);
+
+    assert_eq!(state.slowest_stage(), None);
+}
+
+#[test]
+fn test_slowest_stage_skips_disabled_and_failed_stages() {
+    let mut state = PipelineState::new();
+    state.open(".a | .b | .c");
+    state.select_previous();
+    state.toggle_selected();
+    state.cache.insert(".a".to_string(), done_in(30));
+    state
+        .cache
+        .insert(".a | .c".to_string(), StageOutput::Failed("error".into()));
+
+    assert_eq!(state.slowest_stage(), Some((0, 30)));
+}
+
+#[test]
+fn test_evaluate_records_stage_times() {
+    let mut state = PipelineState::new();
+    state.open(".items | .[]");
+    state.evaluate(
+        Arc::new(r#"{"items":[1,2,3]}"#.to_string()),
+        Arc::default(),
+        Arc::default(),
+    );
+    wait_for_outputs(&mut state);
+
+    assert!(state.slowest_stage().is_some());
+}
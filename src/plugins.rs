@@ -0,0 +1,78 @@
+//! Plugins
+//!
+//! Plugins add organization-specific workflows without forking jiq. A
+//! plugin can decode input files jiq doesn't understand, add an output
+//! format for `--output-format`, and run a command on a key.
+//!
+//! Plugins declared in the `[[plugins]]` config section are executables
+//! ([`ExecPlugin`]) that read from stdin and write to stdout. Tools that
+//! embed jiq can implement [`Plugin`] directly and add it to a
+//! [`PluginRegistry`].
+
+mod exec_plugin;
+pub mod plugin_events;
+mod plugin_key;
+mod plugin_registry;
+mod plugin_task;
+
+use std::path::Path;
+
+use tokio_util::sync::CancellationToken;
+
+pub use exec_plugin::ExecPlugin;
+pub use plugin_key::PluginKey;
+pub use plugin_registry::PluginRegistry;
+pub use plugin_task::PluginTask;
+
+/// What a plugin command is run on
+#[derive(Debug, Clone, Copy)]
+pub struct CommandContext<'a> {
+    pub query: &'a str,
+    /// Output of the last successful query
+    pub results: &'a str,
+    /// Input file, `None` when the input came from stdin or a command
+    pub input_path: Option<&'a Path>,
+    /// Cancelled when the user stops the command
+    pub cancel: &'a CancellationToken,
+}
+
+/// Extension of jiq's input formats, output formats and commands
+///
+/// Every capability is optional; the defaults report that the plugin
+/// doesn't provide it.
+pub trait Plugin: Send + Sync {
+    fn name(&self) -> &str;
+
+    /// Extensions, without the dot, of files [`Plugin::decode_input`] reads
+    fn input_extensions(&self) -> &[String] {
+        &[]
+    }
+
+    /// Convert the content of an input file to JSON or JSON Lines
+    fn decode_input(&self, _content: &str) -> Result<String, String> {
+        Err(format!("{} does not decode input", self.name()))
+    }
+
+    /// Whether `--output-format NAME` uses [`Plugin::convert_output`]
+    fn is_output_format(&self) -> bool {
+        false
+    }
+
+    /// Convert the JSON results printed on exit
+    fn convert_output(&self, _results: &str) -> Result<String, String> {
+        Err(format!("{} is not an output format", self.name()))
+    }
+
+    /// Key running [`Plugin::run_command`]
+    fn key(&self) -> Option<PluginKey> {
+        None
+    }
+
+    /// Run the plugin's command, returning a message to show
+    ///
+    /// Runs on a worker thread; long-running commands should stop once
+    /// `context.cancel` is cancelled.
+    fn run_command(&self, _context: &CommandContext) -> Result<String, String> {
+        Err(format!("{} has no command", self.name()))
+    }
+}
@@ -0,0 +1,192 @@
+use std::io::{Read, Write};
+use std::process::{Command, Stdio};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+use tokio_util::sync::CancellationToken;
+
+use super::{CommandContext, Plugin, PluginKey};
+use crate::config::PluginConfig;
+
+/// Longest a command run on a key may take before it is killed
+pub const COMMAND_TIMEOUT: Duration = Duration::from_secs(30);
+
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Plugin declared in the `[[plugins]]` config section
+///
+/// The command gets the input, results or JSON on stdin and prints its
+/// answer to stdout. `JIQ_PLUGIN_ACTION` tells it what is asked of it:
+/// `decode`, `output` or `command`. Commands also get `JIQ_QUERY` and, for
+/// file input, `JIQ_INPUT_PATH`, and are killed after [`COMMAND_TIMEOUT`].
+#[derive(Debug, Clone)]
+pub struct ExecPlugin {
+    name: String,
+    command: Vec<String>,
+    input_extensions: Vec<String>,
+    output: bool,
+    key: Option<PluginKey>,
+}
+
+impl ExecPlugin {
+    pub fn from_config(config: &PluginConfig) -> Result<Self, String> {
+        if config.name.trim().is_empty() {
+            return Err("Plugin name must not be empty".to_string());
+        }
+        if config.command.is_empty() {
+            return Err(format!("Plugin '{}' has no command", config.name));
+        }
+        let key = config
+            .key
+            .as_deref()
+            .map(PluginKey::parse)
+            .transpose()
+            .map_err(|e| format!("Plugin '{}': {}", config.name, e))?;
+        Ok(Self {
+            name: config.name.clone(),
+            command: config.command.clone(),
+            input_extensions: config
+                .input_extensions
+                .iter()
+                .map(|ext| ext.trim_start_matches('.').to_string())
+                .collect(),
+            output: config.output,
+            key,
+        })
+    }
+
+    /// Run the command with `input` on stdin and return its stdout
+    ///
+    /// The command is killed when `cancel` is cancelled or once it has run
+    /// for longer than `timeout`.
+    fn run(
+        &self,
+        action: &str,
+        input: &str,
+        env: &[(&str, &str)],
+        cancel: &CancellationToken,
+        timeout: Option<Duration>,
+    ) -> Result<String, String> {
+        let (program, args) = self
+            .command
+            .split_first()
+            .ok_or_else(|| format!("{}: no command", self.name))?;
+        let mut child = Command::new(program)
+            .args(args)
+            .env("JIQ_PLUGIN_ACTION", action)
+            .envs(env.iter().copied())
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| format!("{}: cannot run {}: {}", self.name, program, e))?;
+
+        // Written and read from threads so a command printing before it has
+        // read all of its input can't deadlock on a full pipe
+        if let Some(mut stdin) = child.stdin.take() {
+            let input = input.to_string();
+            thread::spawn(move || {
+                let _ = stdin.write_all(input.as_bytes());
+            });
+        }
+        let stdout = child.stdout.take().map(read_in_background);
+        let stderr = child.stderr.take().map(read_in_background);
+
+        let started = Instant::now();
+        let status = loop {
+            if cancel.is_cancelled() {
+                let _ = child.kill();
+                let _ = child.wait();
+                return Err(format!("{}: cancelled", self.name));
+            }
+            if let Some(timeout) = timeout
+                && started.elapsed() >= timeout
+            {
+                let _ = child.kill();
+                let _ = child.wait();
+                return Err(format!(
+                    "{}: timed out after {}s",
+                    self.name,
+                    timeout.as_secs()
+                ));
+            }
+            match child.try_wait() {
+                Ok(Some(status)) => break status,
+                Ok(None) => thread::sleep(POLL_INTERVAL),
+                Err(e) => return Err(format!("{}: {}", self.name, e)),
+            }
+        };
+        let stdout = stdout
+            .and_then(|reader| reader.join().ok())
+            .unwrap_or_default();
+        let stderr = stderr
+            .and_then(|reader| reader.join().ok())
+            .unwrap_or_default();
+
+        if !status.success() {
+            let stderr = String::from_utf8_lossy(&stderr);
+            let stderr = stderr.trim();
+            return Err(if stderr.is_empty() {
+                format!("{}: exited with {}", self.name, status)
+            } else {
+                format!("{}: {}", self.name, stderr)
+            });
+        }
+        String::from_utf8(stdout).map_err(|_| format!("{}: output is not UTF-8", self.name))
+    }
+}
+
+fn read_in_background(mut pipe: impl Read + Send + 'static) -> JoinHandle<Vec<u8>> {
+    thread::spawn(move || {
+        let mut buffer = Vec::new();
+        let _ = pipe.read_to_end(&mut buffer);
+        buffer
+    })
+}
+
+impl Plugin for ExecPlugin {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn input_extensions(&self) -> &[String] {
+        &self.input_extensions
+    }
+
+    fn decode_input(&self, content: &str) -> Result<String, String> {
+        self.run("decode", content, &[], &CancellationToken::new(), None)
+    }
+
+    fn is_output_format(&self) -> bool {
+        self.output
+    }
+
+    fn convert_output(&self, results: &str) -> Result<String, String> {
+        self.run("output", results, &[], &CancellationToken::new(), None)
+    }
+
+    fn key(&self) -> Option<PluginKey> {
+        self.key
+    }
+
+    fn run_command(&self, context: &CommandContext) -> Result<String, String> {
+        let input_path = context
+            .input_path
+            .map(|path| path.to_string_lossy().into_owned());
+        let mut env = vec![("JIQ_QUERY", context.query)];
+        if let Some(path) = &input_path {
+            env.push(("JIQ_INPUT_PATH", path));
+        }
+        self.run(
+            "command",
+            context.results,
+            &env,
+            context.cancel,
+            Some(COMMAND_TIMEOUT),
+        )
+    }
+}
+
+#[cfg(test)]
+#[path = "exec_plugin_tests.rs"]
+mod exec_plugin_tests;
@@ -0,0 +1,149 @@
+//! Tests for exec_plugin
+
+use super::*;
+use std::path::Path;
+
+fn config(name: &str, script: &str) -> PluginConfig {
+    PluginConfig {
+        name: name.to_string(),
+        command: vec!["sh".to_string(), "-c".to_string(), script.to_string()],
+        input_extensions: Vec::new(),
+        output: false,
+        key: None,
+    }
+}
+
+fn plugin(script: &str) -> ExecPlugin {
+    ExecPlugin::from_config(&config("test", script)).unwrap()
+}
+
+#[test]
+fn test_from_config_validates() {
+    let mut empty_name = config(" ", "true");
+    assert!(ExecPlugin::from_config(&empty_name).is_err());
+
+    empty_name.name = "x".to_string();
+    empty_name.command.clear();
+    assert_eq!(
+        ExecPlugin::from_config(&empty_name).unwrap_err(),
+        "Plugin 'x' has no command"
+    );
+
+    let mut bad_key = config("x", "true");
+    bad_key.key = Some("o".to_string());
+    assert!(
+        ExecPlugin::from_config(&bad_key)
+            .unwrap_err()
+            .starts_with("Plugin 'x': Key 'o' needs ctrl or alt")
+    );
+}
+
+#[test]
+fn test_extensions_lose_leading_dot() {
+    let mut yaml = config("yaml", "cat");
+    yaml.input_extensions = vec![".yaml".to_string(), "yml".to_string()];
+    let plugin = ExecPlugin::from_config(&yaml).unwrap();
+    assert_eq!(plugin.input_extensions(), ["yaml", "yml"]);
+}
+
+#[test]
+fn test_decode_input_pipes_content() {
+    let plugin =
+        plugin(r#"read line; echo "{\"line\": \"$line\", \"action\": \"$JIQ_PLUGIN_ACTION\"}""#);
+    assert_eq!(
+        plugin.decode_input("hello\n").unwrap().trim(),
+        r#"{"line": "hello", "action": "decode"}"#
+    );
+}
+
+#[test]
+fn test_convert_output_pipes_results() {
+    let plugin = plugin("tr a-z A-Z");
+    assert_eq!(plugin.convert_output("\"abc\"\n").unwrap(), "\"ABC\"\n");
+}
+
+#[test]
+fn test_command_gets_query_and_path() {
+    let plugin = plugin(r#"echo "$JIQ_PLUGIN_ACTION $JIQ_QUERY $JIQ_INPUT_PATH $(wc -l)""#);
+    let context = CommandContext {
+        query: ".items[]",
+        results: "1\n2\n",
+        input_path: Some(Path::new("/data/in.json")),
+        cancel: &CancellationToken::new(),
+    };
+    assert_eq!(
+        plugin.run_command(&context).unwrap().trim(),
+        "command .items[] /data/in.json 2"
+    );
+}
+
+#[test]
+fn test_failure_reports_stderr() {
+    let plugin = plugin("echo 'bad input' >&2; exit 3");
+    assert_eq!(plugin.decode_input("").unwrap_err(), "test: bad input");
+
+    let silent = self::plugin("exit 1");
+    assert!(
+        silent
+            .convert_output("")
+            .unwrap_err()
+            .starts_with("test: exited with")
+    );
+}
+
+#[test]
+fn test_missing_program_is_an_error() {
+    let mut missing = config("gone", "");
+    missing.command = vec!["jiq-plugin-that-does-not-exist".to_string()];
+    let plugin = ExecPlugin::from_config(&missing).unwrap();
+    assert!(
+        plugin
+            .decode_input("")
+            .unwrap_err()
+            .starts_with("gone: cannot run jiq-plugin-that-does-not-exist")
+    );
+}
+
+#[test]
+fn test_large_input_does_not_deadlock() {
+    let plugin = plugin("cat");
+    let input = "x".repeat(1 << 20);
+    assert_eq!(plugin.convert_output(&input).unwrap().len(), input.len());
+}
+
+#[test]
+fn test_slow_command_times_out() {
+    let plugin = plugin("sleep 5");
+    let started = Instant::now();
+    let error = plugin
+        .run(
+            "command",
+            "",
+            &[],
+            &CancellationToken::new(),
+            Some(Duration::from_millis(100)),
+        )
+        .unwrap_err();
+    assert_eq!(error, "test: timed out after 0s");
+    assert!(started.elapsed() < Duration::from_secs(2));
+}
+
+#[test]
+fn test_cancelled_command_is_killed() {
+    let plugin = plugin("sleep 5");
+    let cancel = CancellationToken::new();
+    let canceller = {
+        let cancel = cancel.clone();
+        thread::spawn(move || {
+            thread::sleep(Duration::from_millis(50));
+            cancel.cancel();
+        })
+    };
+    let started = Instant::now();
+
+    let error = plugin.run("command", "", &[], &cancel, None).unwrap_err();
+
+    canceller.join().unwrap();
+    assert_eq!(error, "test: cancelled");
+    assert!(started.elapsed() < Duration::from_secs(2));
+}
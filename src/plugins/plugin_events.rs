@@ -0,0 +1,78 @@
+use ratatui::crossterm::event::{KeyCode, KeyEvent};
+
+use super::PluginTask;
+use crate::app::App;
+
+/// Run the plugin command bound to `key`, if any
+///
+/// The command runs in the background on the results of the last
+/// successful query, and its output is shown as a notification when it
+/// finishes. `Esc` cancels a running command; another command can't start
+/// until it is done.
+pub fn handle_plugin_key(app: &mut App, key: KeyEvent) -> bool {
+    if let Some(task) = &app.plugin_task
+        && key.code == KeyCode::Esc
+    {
+        task.cancel();
+        app.notification
+            .show_warning(&format!("{} cancelled", task.name()));
+        app.plugin_task = None;
+        return true;
+    }
+
+    let Some(plugin) = app.plugins.command_for_key(&key) else {
+        return false;
+    };
+    if let Some(task) = &app.plugin_task {
+        app.notification
+            .show_warning(&format!("{} is still running (Esc to cancel)", task.name()));
+        return true;
+    }
+
+    let results = app
+        .query
+        .as_ref()
+        .and_then(|q| q.last_successful_result_text())
+        .unwrap_or_default();
+    app.notification
+        .show(&format!("Running {} (Esc to cancel)", plugin.name()));
+    app.plugin_task = Some(PluginTask::spawn(
+        plugin,
+        app.query().to_string(),
+        results,
+        app.input_path.clone(),
+    ));
+    true
+}
+
+/// Show the outcome of the running plugin command once it has finished
+///
+/// Returns true when it finished.
+pub fn poll_plugin_task(app: &mut App) -> bool {
+    let Some(result) = app.plugin_task.as_ref().and_then(PluginTask::poll) else {
+        return false;
+    };
+    let name = app
+        .plugin_task
+        .take()
+        .map(|task| task.name().to_string())
+        .unwrap_or_default();
+    match result {
+        Ok(output) => {
+            let message = output.trim();
+            if message.is_empty() {
+                app.notification.show(&format!("{} done", name));
+            } else {
+                app.notification.show(message);
+            }
+        }
+        Err(e) => app
+            .notification
+            .show_error(&format!("Plugin failed: {}", e)),
+    }
+    true
+}
+
+#[cfg(test)]
+#[path = "plugin_events_tests.rs"]
+mod plugin_events_tests;
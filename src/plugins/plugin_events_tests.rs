@@ -0,0 +1,175 @@
+//! Tests for plugin_events
+
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use super::*;
+use crate::plugins::{CommandContext, Plugin, PluginKey};
+use crate::test_utils::test_helpers::{app_with_query, key, key_with_mods};
+use ratatui::crossterm::event::{KeyCode, KeyModifiers};
+
+/// Command plugin answering with `reply`, or with the query and the length
+/// of the results it was given when `reply` is `None`
+struct EchoPlugin {
+    reply: Option<Result<&'static str, &'static str>>,
+}
+
+/// Command plugin running until it is cancelled
+struct BlockingPlugin;
+
+impl Plugin for BlockingPlugin {
+    fn name(&self) -> &str {
+        "block"
+    }
+
+    fn key(&self) -> Option<PluginKey> {
+        PluginKey::parse("alt-b").ok()
+    }
+
+    fn run_command(&self, context: &CommandContext) -> Result<String, String> {
+        while !context.cancel.is_cancelled() {
+            std::thread::sleep(Duration::from_millis(5));
+        }
+        Err("cancelled".to_string())
+    }
+}
+
+impl Plugin for EchoPlugin {
+    fn name(&self) -> &str {
+        "echo"
+    }
+
+    fn key(&self) -> Option<PluginKey> {
+        PluginKey::parse("alt-e").ok()
+    }
+
+    fn run_command(&self, context: &CommandContext) -> Result<String, String> {
+        match self.reply {
+            Some(reply) => reply.map(String::from).map_err(String::from),
+            None => Ok(format!("{} {}", context.query, context.results.len())),
+        }
+    }
+}
+
+fn app_with_plugin(reply: Option<Result<&'static str, &'static str>>) -> App {
+    let mut app = app_with_query(".name");
+    app.plugins
+        .register(Arc::new(EchoPlugin { reply }))
+        .unwrap();
+    app.plugins.register(Arc::new(BlockingPlugin)).unwrap();
+    app
+}
+
+/// Poll the running plugin command until it has finished
+fn wait_for_plugin(app: &mut App) {
+    let started = Instant::now();
+    while !poll_plugin_task(app) {
+        assert!(
+            started.elapsed() < Duration::from_secs(2),
+            "plugin still running"
+        );
+        std::thread::sleep(Duration::from_millis(5));
+    }
+}
+
+fn alt_e() -> KeyEvent {
+    key_with_mods(KeyCode::Char('e'), KeyModifiers::ALT)
+}
+
+#[test]
+fn test_unbound_key_is_not_handled() {
+    let mut app = app_with_plugin(Some(Ok(" \n")));
+    assert!(!handle_plugin_key(&mut app, key(KeyCode::Char('e'))));
+    assert!(app.notification.current_message().is_none());
+}
+
+#[test]
+fn test_command_output_is_shown() {
+    let mut app = app_with_plugin(None);
+    assert!(crate::test_utils::test_helpers::wait_for_query_completion(
+        &mut app, 2000
+    ));
+    let results_len = app
+        .query
+        .as_ref()
+        .and_then(|q| q.last_successful_result_unformatted.clone())
+        .map_or(0, |results| results.len());
+
+    assert!(handle_plugin_key(&mut app, alt_e()));
+    wait_for_plugin(&mut app);
+    assert_eq!(
+        app.notification.current_message(),
+        Some(format!(".name {}", results_len).as_str())
+    );
+}
+
+#[test]
+fn test_empty_output_reports_done() {
+    let mut app = app_with_plugin(Some(Ok(" \n")));
+    assert!(handle_plugin_key(&mut app, alt_e()));
+    wait_for_plugin(&mut app);
+    assert_eq!(app.notification.current_message(), Some("echo done"));
+}
+
+#[test]
+fn test_failure_is_shown_as_error() {
+    let mut app = app_with_plugin(Some(Err("boom")));
+    assert!(handle_plugin_key(&mut app, alt_e()));
+    wait_for_plugin(&mut app);
+    assert_eq!(
+        app.notification.current_message(),
+        Some("Plugin failed: boom")
+    );
+}
+
+#[test]
+fn test_command_runs_in_the_background() {
+    let mut app = app_with_plugin(None);
+    let alt_b = key_with_mods(KeyCode::Char('b'), KeyModifiers::ALT);
+
+    assert!(handle_plugin_key(&mut app, alt_b));
+
+    assert!(app.plugin_task.is_some());
+    assert!(!poll_plugin_task(&mut app));
+    assert_eq!(
+        app.notification.current_message(),
+        Some("Running block (Esc to cancel)")
+    );
+}
+
+#[test]
+fn test_second_command_waits_for_the_running_one() {
+    let mut app = app_with_plugin(None);
+    handle_plugin_key(
+        &mut app,
+        key_with_mods(KeyCode::Char('b'), KeyModifiers::ALT),
+    );
+
+    assert!(handle_plugin_key(&mut app, alt_e()));
+
+    assert_eq!(app.plugin_task.as_ref().map(|t| t.name()), Some("block"));
+    assert_eq!(
+        app.notification.current_message(),
+        Some("block is still running (Esc to cancel)")
+    );
+}
+
+#[test]
+fn test_esc_cancels_running_command() {
+    let mut app = app_with_plugin(None);
+    handle_plugin_key(
+        &mut app,
+        key_with_mods(KeyCode::Char('b'), KeyModifiers::ALT),
+    );
+
+    assert!(handle_plugin_key(&mut app, key(KeyCode::Esc)));
+
+    assert!(app.plugin_task.is_none());
+    assert_eq!(app.notification.current_message(), Some("block cancelled"));
+}
+
+#[test]
+fn test_esc_is_not_handled_without_running_command() {
+    let mut app = app_with_plugin(None);
+    assert!(!handle_plugin_key(&mut app, key(KeyCode::Esc)));
+}
@@ -0,0 +1,59 @@
+use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+/// Key a plugin command is bound to, e.g. `alt-o`, `ctrl-alt-y` or `f5`
+///
+/// Keys need `ctrl` or `alt` unless they are function keys, so that a
+/// binding never takes away a character from the query input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PluginKey {
+    pub code: KeyCode,
+    pub modifiers: KeyModifiers,
+}
+
+impl PluginKey {
+    pub fn parse(spec: &str) -> Result<Self, String> {
+        let invalid = || format!("Invalid key '{}'", spec);
+        let mut parts: Vec<&str> = spec.split(['-', '+']).collect();
+        let key = parts.pop().filter(|k| !k.is_empty()).ok_or_else(invalid)?;
+
+        let mut modifiers = KeyModifiers::empty();
+        for part in parts {
+            modifiers |= match part.to_ascii_lowercase().as_str() {
+                "ctrl" => KeyModifiers::CONTROL,
+                "alt" => KeyModifiers::ALT,
+                _ => return Err(invalid()),
+            };
+        }
+
+        let lower = key.to_ascii_lowercase();
+        let mut chars = key.chars();
+        let code = match (chars.next(), chars.next()) {
+            (Some(c), None) => KeyCode::Char(c),
+            _ if lower == "enter" => KeyCode::Enter,
+            _ if lower == "tab" => KeyCode::Tab,
+            _ if lower == "space" => KeyCode::Char(' '),
+            _ => match lower.strip_prefix('f').and_then(|n| n.parse::<u8>().ok()) {
+                Some(n @ 1..=12) => KeyCode::F(n),
+                _ => return Err(invalid()),
+            },
+        };
+
+        if modifiers.is_empty() && !matches!(code, KeyCode::F(_)) {
+            return Err(format!(
+                "Key '{}' needs ctrl or alt, or use a function key",
+                spec
+            ));
+        }
+        Ok(Self { code, modifiers })
+    }
+
+    /// Whether `key` is this key; shift is ignored as it is part of
+    /// uppercase characters
+    pub fn matches(&self, key: &KeyEvent) -> bool {
+        key.code == self.code && key.modifiers.difference(KeyModifiers::SHIFT) == self.modifiers
+    }
+}
+
+#[cfg(test)]
+#[path = "plugin_key_tests.rs"]
+mod plugin_key_tests;
@@ -0,0 +1,66 @@
+//! Tests for plugin_key
+
+use super::*;
+
+fn key(code: KeyCode, modifiers: KeyModifiers) -> KeyEvent {
+    KeyEvent::new(code, modifiers)
+}
+
+#[test]
+fn test_parse_modifiers_and_keys() {
+    assert_eq!(
+        PluginKey::parse("alt-o"),
+        Ok(PluginKey {
+            code: KeyCode::Char('o'),
+            modifiers: KeyModifiers::ALT,
+        })
+    );
+    assert_eq!(
+        PluginKey::parse("Ctrl+Alt+Enter"),
+        Ok(PluginKey {
+            code: KeyCode::Enter,
+            modifiers: KeyModifiers::CONTROL | KeyModifiers::ALT,
+        })
+    );
+    assert_eq!(
+        PluginKey::parse("f5"),
+        Ok(PluginKey {
+            code: KeyCode::F(5),
+            modifiers: KeyModifiers::empty(),
+        })
+    );
+    assert_eq!(
+        PluginKey::parse("alt-space").unwrap().code,
+        KeyCode::Char(' ')
+    );
+}
+
+#[test]
+fn test_parse_rejects_invalid_keys() {
+    for spec in ["", "alt-", "meta-o", "alt-foo", "f13", "ctrl-f0"] {
+        assert!(PluginKey::parse(spec).is_err(), "{}", spec);
+    }
+}
+
+#[test]
+fn test_plain_characters_need_a_modifier() {
+    assert_eq!(
+        PluginKey::parse("x"),
+        Err("Key 'x' needs ctrl or alt, or use a function key".to_string())
+    );
+    assert!(PluginKey::parse("enter").is_err());
+}
+
+#[test]
+fn test_matches_ignores_shift() {
+    let alt_o = PluginKey::parse("alt-O").unwrap();
+    assert!(alt_o.matches(&key(
+        KeyCode::Char('O'),
+        KeyModifiers::ALT | KeyModifiers::SHIFT
+    )));
+    assert!(!alt_o.matches(&key(KeyCode::Char('o'), KeyModifiers::ALT)));
+    assert!(!alt_o.matches(&key(
+        KeyCode::Char('O'),
+        KeyModifiers::ALT | KeyModifiers::CONTROL
+    )));
+}
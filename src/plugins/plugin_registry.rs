@@ -0,0 +1,75 @@
+use std::path::Path;
+use std::sync::Arc;
+
+use ratatui::crossterm::event::KeyEvent;
+
+use super::{ExecPlugin, Plugin};
+use crate::config::PluginConfig;
+
+/// Plugins available to this run of jiq
+#[derive(Clone, Default)]
+pub struct PluginRegistry {
+    plugins: Vec<Arc<dyn Plugin>>,
+}
+
+impl PluginRegistry {
+    /// Registry of the plugins declared in the `[[plugins]]` config section
+    pub fn from_config(configs: &[PluginConfig]) -> Result<Self, String> {
+        let mut registry = Self::default();
+        for config in configs {
+            registry.register(Arc::new(ExecPlugin::from_config(config)?))?;
+        }
+        Ok(registry)
+    }
+
+    /// Add `plugin`, rejecting a name or key that is already taken
+    pub fn register(&mut self, plugin: Arc<dyn Plugin>) -> Result<(), String> {
+        if self.plugins.iter().any(|p| p.name() == plugin.name()) {
+            return Err(format!("Plugin '{}' is declared twice", plugin.name()));
+        }
+        if let Some(key) = plugin.key()
+            && let Some(other) = self.plugins.iter().find(|p| p.key() == Some(key))
+        {
+            return Err(format!(
+                "Plugins '{}' and '{}' use the same key",
+                other.name(),
+                plugin.name()
+            ));
+        }
+        self.plugins.push(plugin);
+        Ok(())
+    }
+
+    /// Plugin decoding files with the extension of `path`
+    pub fn decoder_for(&self, path: &Path) -> Option<Arc<dyn Plugin>> {
+        let extension = path.extension()?.to_str()?;
+        self.plugins
+            .iter()
+            .find(|p| {
+                p.input_extensions()
+                    .iter()
+                    .any(|ext| ext.eq_ignore_ascii_case(extension))
+            })
+            .cloned()
+    }
+
+    /// Plugin providing the output format `name`
+    pub fn output_format(&self, name: &str) -> Option<Arc<dyn Plugin>> {
+        self.plugins
+            .iter()
+            .find(|p| p.is_output_format() && p.name() == name)
+            .cloned()
+    }
+
+    /// Plugin whose command is bound to `key`
+    pub fn command_for_key(&self, key: &KeyEvent) -> Option<Arc<dyn Plugin>> {
+        self.plugins
+            .iter()
+            .find(|p| p.key().is_some_and(|k| k.matches(key)))
+            .cloned()
+    }
+}
+
+#[cfg(test)]
+#[path = "plugin_registry_tests.rs"]
+mod plugin_registry_tests;
@@ -0,0 +1,100 @@
+//! Tests for plugin_registry
+
+use super::*;
+use ratatui::crossterm::event::{KeyCode, KeyModifiers};
+
+fn config(name: &str) -> PluginConfig {
+    PluginConfig {
+        name: name.to_string(),
+        command: vec!["cat".to_string()],
+        input_extensions: Vec::new(),
+        output: false,
+        key: None,
+    }
+}
+
+fn registry() -> PluginRegistry {
+    PluginRegistry::from_config(&[
+        PluginConfig {
+            input_extensions: vec!["yaml".to_string(), "yml".to_string()],
+            output: true,
+            ..config("yaml")
+        },
+        PluginConfig {
+            key: Some("alt-o".to_string()),
+            ..config("open")
+        },
+    ])
+    .unwrap()
+}
+
+#[test]
+fn test_decoder_for_matches_extension() {
+    let registry = registry();
+    assert_eq!(
+        registry
+            .decoder_for(Path::new("deploy.YML"))
+            .map(|p| p.name().to_string()),
+        Some("yaml".to_string())
+    );
+    assert!(registry.decoder_for(Path::new("data.json")).is_none());
+    assert!(registry.decoder_for(Path::new("yaml")).is_none());
+}
+
+#[test]
+fn test_output_format_needs_output_flag() {
+    let registry = registry();
+    assert!(registry.output_format("yaml").is_some());
+    assert!(registry.output_format("open").is_none());
+    assert!(registry.output_format("csv").is_none());
+}
+
+#[test]
+fn test_command_for_key() {
+    let registry = registry();
+    let alt_o = KeyEvent::new(KeyCode::Char('o'), KeyModifiers::ALT);
+    let ctrl_o = KeyEvent::new(KeyCode::Char('o'), KeyModifiers::CONTROL);
+
+    assert_eq!(
+        registry
+            .command_for_key(&alt_o)
+            .map(|p| p.name().to_string()),
+        Some("open".to_string())
+    );
+    assert!(registry.command_for_key(&ctrl_o).is_none());
+    assert!(PluginRegistry::default().command_for_key(&alt_o).is_none());
+}
+
+#[test]
+fn test_duplicate_names_are_rejected() {
+    let result = PluginRegistry::from_config(&[config("yaml"), config("yaml")]);
+    assert_eq!(
+        result.err(),
+        Some("Plugin 'yaml' is declared twice".to_string())
+    );
+}
+
+#[test]
+fn test_duplicate_keys_are_rejected() {
+    let with_key = |name| PluginConfig {
+        key: Some("ctrl-alt-y".to_string()),
+        ..config(name)
+    };
+    let result = PluginRegistry::from_config(&[with_key("a"), with_key("b")]);
+    assert_eq!(
+        result.err(),
+        Some("Plugins 'a' and 'b' use the same key".to_string())
+    );
+}
+
+#[test]
+fn test_invalid_config_is_reported() {
+    let result = PluginRegistry::from_config(&[PluginConfig {
+        command: Vec::new(),
+        ..config("broken")
+    }]);
+    assert_eq!(
+        result.err(),
+        Some("Plugin 'broken' has no command".to_string())
+    );
+}
@@ -0,0 +1,66 @@
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::mpsc::{Receiver, TryRecvError, channel};
+
+use tokio_util::sync::CancellationToken;
+
+use super::{CommandContext, Plugin};
+
+/// Plugin command running on a worker thread
+///
+/// The UI keeps handling input while the command runs and polls the task
+/// for its outcome.
+pub struct PluginTask {
+    name: String,
+    cancel: CancellationToken,
+    rx: Receiver<Result<String, String>>,
+}
+
+impl PluginTask {
+    /// Run `plugin`'s command on `results` in a background thread
+    pub fn spawn(
+        plugin: Arc<dyn Plugin>,
+        query: String,
+        results: Arc<String>,
+        input_path: Option<PathBuf>,
+    ) -> Self {
+        let (tx, rx) = channel();
+        let cancel = CancellationToken::new();
+        let name = plugin.name().to_string();
+
+        let token = cancel.clone();
+        std::thread::spawn(move || {
+            let context = CommandContext {
+                query: &query,
+                results: &results,
+                input_path: input_path.as_deref(),
+                cancel: &token,
+            };
+            let _ = tx.send(plugin.run_command(&context));
+        });
+
+        Self { name, cancel, rx }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Ask the command to stop; its outcome is no longer reported
+    pub fn cancel(&self) {
+        self.cancel.cancel();
+    }
+
+    /// Outcome of the command once it has finished (non-blocking)
+    pub fn poll(&self) -> Option<Result<String, String>> {
+        match self.rx.try_recv() {
+            Ok(result) => Some(result),
+            Err(TryRecvError::Empty) => None,
+            Err(TryRecvError::Disconnected) => Some(Err(format!("{} stopped", self.name))),
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "plugin_task_tests.rs"]
+mod plugin_task_tests;
@@ -0,0 +1,63 @@
+//! Tests for plugin_task
+
+use std::time::{Duration, Instant};
+
+use super::*;
+
+struct ResultsLength;
+
+impl Plugin for ResultsLength {
+    fn name(&self) -> &str {
+        "length"
+    }
+
+    fn run_command(&self, context: &CommandContext) -> Result<String, String> {
+        Ok(format!("{} {}", context.query, context.results.len()))
+    }
+}
+
+struct Panicking;
+
+impl Plugin for Panicking {
+    fn name(&self) -> &str {
+        "panicking"
+    }
+
+    fn run_command(&self, _context: &CommandContext) -> Result<String, String> {
+        panic!("plugin bug")
+    }
+}
+
+fn wait(task: &PluginTask) -> Result<String, String> {
+    let started = Instant::now();
+    loop {
+        if let Some(result) = task.poll() {
+            return result;
+        }
+        assert!(
+            started.elapsed() < Duration::from_secs(2),
+            "task still running"
+        );
+        std::thread::sleep(Duration::from_millis(5));
+    }
+}
+
+#[test]
+fn test_task_reports_command_output() {
+    let task = PluginTask::spawn(
+        Arc::new(ResultsLength),
+        ".a".to_string(),
+        Arc::new("[1]\n".to_string()),
+        None,
+    );
+
+    assert_eq!(task.name(), "length");
+    assert_eq!(wait(&task), Ok(".a 4".to_string()));
+}
+
+#[test]
+fn test_task_reports_a_panicking_plugin() {
+    let task = PluginTask::spawn(Arc::new(Panicking), ".".to_string(), Arc::default(), None);
+
+    assert_eq!(wait(&task), Err("panicking stopped".to_string()));
+}
@@ -0,0 +1,15 @@
+//! Column picker
+//!
+//! `p` in the results pane or `:columns` lists the fields of an array of
+//! objects with checkboxes. Confirming replaces the query with one keeping
+//! only the checked fields, e.g. `.items | map({name, status})`, to build a
+//! projection of a wide object without typing every key.
+
+mod projection_events;
+mod projection_query;
+mod projection_render;
+mod projection_state;
+
+pub use projection_events::{handle_projection_key, open_projection};
+pub use projection_render::render_projection;
+pub use projection_state::ProjectionState;
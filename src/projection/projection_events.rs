@@ -0,0 +1,58 @@
+use ratatui::crossterm::event::{KeyCode, KeyEvent};
+
+use super::projection_query::Columns;
+use crate::app::App;
+use crate::query::language::QueryLanguage;
+
+/// List the fields of the last successful result in the column picker
+pub fn open_projection(app: &mut App) {
+    let Some(query_state) = &app.query else {
+        return;
+    };
+    if query_state.language != QueryLanguage::Jq {
+        app.notification
+            .show_warning("Column picker is only available for jq queries");
+        return;
+    }
+    let (Some(results), Some(base_query)) = (
+        query_state.last_successful_result_text(),
+        &query_state.base_query_for_suggestions,
+    ) else {
+        app.notification
+            .show_warning("No result to pick columns from yet");
+        return;
+    };
+
+    match Columns::from_results(&results) {
+        Ok(columns) => {
+            let base_query = base_query.clone();
+            app.projection.open(columns, &base_query);
+        }
+        Err(e) => app.notification.show_warning(&e),
+    }
+}
+
+pub fn handle_projection_key(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('q') => app.projection.close(),
+        KeyCode::Up | KeyCode::Char('k') => app.projection.select_prev(),
+        KeyCode::Down | KeyCode::Char('j') => app.projection.select_next(),
+        KeyCode::Char(' ') => app.projection.toggle_selected(),
+        KeyCode::Char('a') => app.projection.toggle_all(),
+        KeyCode::Tab => app.projection.toggle_shape(),
+        KeyCode::Enter => match app.projection.query() {
+            Some(query) => {
+                app.projection.close();
+                crate::editor::editor_events::replace_query_and_execute(app, &query);
+            }
+            None => app
+                .notification
+                .show_warning("Check at least one column with Space"),
+        },
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+#[path = "projection_events_tests.rs"]
+mod projection_events_tests;
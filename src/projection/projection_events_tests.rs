@@ -0,0 +1,73 @@
+//! Tests for projection_events
+
+use super::*;
+use crate::app::Focus;
+use crate::test_utils::test_helpers::{
+    app_with_result, assert_open_refused, key, wait_for_query_completion,
+};
+
+#[test]
+fn test_open_lists_result_fields() {
+    let mut app = app_with_result(r#"{"items": [{"a": 1, "b": 2}]}"#, ".items");
+    open_projection(&mut app);
+
+    assert!(app.projection.is_visible());
+    assert_eq!(app.projection.columns().len(), 2);
+}
+
+#[test]
+fn test_open_explains_unsuitable_result() {
+    let mut app = app_with_result(r#"{"items": [1, 2]}"#, ".items");
+
+    assert_open_refused(
+        &mut app,
+        open_projection,
+        |app| app.projection.is_visible(),
+        "Columns need an array or stream of objects",
+    );
+}
+
+#[test]
+fn test_enter_replaces_query_with_projection() {
+    let mut app = app_with_result(r#"{"items": [{"a": 1, "b": 2}]}"#, ".items");
+    app.focus = Focus::ResultsPane;
+    app.handle_key_event(key(KeyCode::Char('p')));
+    assert!(app.projection.is_visible());
+
+    app.handle_key_event(key(KeyCode::Char('j')));
+    app.handle_key_event(key(KeyCode::Char(' ')));
+    app.handle_key_event(key(KeyCode::Enter));
+
+    assert!(!app.projection.is_visible());
+    assert_eq!(app.input.query(), ".items | map({b})");
+    assert!(wait_for_query_completion(&mut app, 2000));
+    let result = app
+        .query
+        .as_ref()
+        .unwrap()
+        .last_successful_result_unformatted
+        .clone();
+    assert_eq!(
+        result.as_deref().map(|r| r.trim()),
+        Some("[\n  {\n    \"b\": 2\n  }\n]")
+    );
+}
+
+#[test]
+fn test_enter_without_checked_columns_keeps_popup() {
+    let mut app = app_with_result(r#"[{"a": 1}]"#, ".");
+    open_projection(&mut app);
+
+    handle_projection_key(&mut app, key(KeyCode::Enter));
+    assert!(app.projection.is_visible());
+    assert_eq!(app.input.query(), ".");
+}
+
+#[test]
+fn test_esc_closes_popup() {
+    let mut app = app_with_result(r#"[{"a": 1}]"#, ".");
+    open_projection(&mut app);
+
+    handle_projection_key(&mut app, key(KeyCode::Esc));
+    assert!(!app.projection.is_visible());
+}
@@ -0,0 +1,110 @@
+use serde_json::Value;
+
+use crate::query::language::jq_field;
+
+/// How the picked fields of each element are kept
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Shape {
+    /// An object with the picked keys: `{name, status}`
+    #[default]
+    Object,
+    /// An array of the picked values: `[.name, .status]`
+    Array,
+}
+
+impl Shape {
+    pub fn toggle(self) -> Self {
+        match self {
+            Shape::Object => Shape::Array,
+            Shape::Array => Shape::Object,
+        }
+    }
+}
+
+/// Fields of a result made of objects
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Columns {
+    /// Keys of the elements, in the order first seen
+    pub keys: Vec<String>,
+    /// Whether the elements are separate outputs rather than one array
+    pub stream: bool,
+}
+
+impl Columns {
+    /// Keys of the objects in `results`, the text jq printed
+    pub fn from_results(results: &str) -> Result<Self, String> {
+        let values = serde_json::Deserializer::from_str(results)
+            .into_iter::<Value>()
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| format!("Result is not JSON: {}", e))?;
+        let (elements, stream) = match values.as_slice() {
+            [Value::Array(items)] => (items.iter().collect::<Vec<_>>(), false),
+            _ => (values.iter().collect(), true),
+        };
+        if elements.is_empty() || !elements.iter().all(|element| element.is_object()) {
+            return Err("Columns need an array or stream of objects".to_string());
+        }
+
+        let mut keys: Vec<String> = Vec::new();
+        for element in elements.iter().filter_map(|element| element.as_object()) {
+            for key in element.keys() {
+                if !keys.contains(key) {
+                    keys.push(key.clone());
+                }
+            }
+        }
+        Ok(Self { keys, stream })
+    }
+}
+
+/// Filter keeping `keys` of each element, mapped over an array result
+pub fn projection(keys: &[&str], shape: Shape, stream: bool) -> String {
+    let element = match shape {
+        Shape::Object => format!(
+            "{{{}}}",
+            keys.iter()
+                .map(|key| object_member(key))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        Shape::Array => format!(
+            "[{}]",
+            keys.iter()
+                .map(|key| jq_field(key))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    };
+    if stream {
+        element
+    } else {
+        format!("map({})", element)
+    }
+}
+
+/// The query producing the projection: `filter` applied to `base_query`'s
+/// output
+pub fn projection_query(base_query: &str, filter: &str) -> String {
+    match base_query.trim() {
+        "" | "." => filter.to_string(),
+        base => format!("{} | {}", base, filter),
+    }
+}
+
+/// Member of an object construction copying `key`, `name` or
+/// `"app name": .["app name"]`
+fn object_member(key: &str) -> String {
+    let path = jq_field(key);
+    match path.strip_prefix('.') {
+        Some(identifier) if !identifier.starts_with('[') => identifier.to_string(),
+        _ => format!(
+            "{}: {}",
+            serde_json::to_string(key).unwrap_or_default(),
+            path
+        ),
+    }
+}
+
+#[cfg(test)]
+#[path = "projection_query_tests.rs"]
+mod projection_query_tests;
@@ -0,0 +1,50 @@
+//! Tests for projection_query
+
+use super::*;
+
+#[test]
+fn test_columns_of_an_array() {
+    let columns = Columns::from_results(r#"[{"b": 1}, {"b": 3, "a": 4}]"#).unwrap();
+    assert_eq!(columns.keys, vec!["b", "a"]);
+    assert!(!columns.stream);
+}
+
+#[test]
+fn test_columns_of_a_stream() {
+    let columns = Columns::from_results("{\"a\": 1}\n{\"b\": 2}\n").unwrap();
+    assert_eq!(columns.keys, vec!["a", "b"]);
+    assert!(columns.stream);
+}
+
+#[test]
+fn test_columns_need_objects() {
+    let error = Err("Columns need an array or stream of objects".to_string());
+    assert_eq!(Columns::from_results("[1, 2]"), error);
+    assert_eq!(Columns::from_results("[]"), error);
+    assert!(Columns::from_results("{").is_err());
+}
+
+#[test]
+fn test_object_projection() {
+    assert_eq!(
+        projection(&["name", "app name"], Shape::Object, false),
+        r#"map({name, "app name": .["app name"]})"#
+    );
+    assert_eq!(projection(&["name"], Shape::Object, true), "{name}");
+}
+
+#[test]
+fn test_array_projection() {
+    assert_eq!(
+        projection(&["name", "app name"], Shape::Array, false),
+        r#"map([.name, .["app name"]])"#
+    );
+    assert_eq!(projection(&["a", "b"], Shape::Array, true), "[.a, .b]");
+}
+
+#[test]
+fn test_projection_query_pipes_base() {
+    assert_eq!(projection_query(".items", "map({a})"), ".items | map({a})");
+    assert_eq!(projection_query(" . ", "map({a})"), "map({a})");
+    assert_eq!(projection_query("", "{a}"), "{a}");
+}
@@ -0,0 +1,109 @@
+use ratatui::{
+    Frame,
+    layout::{Constraint, Layout, Rect},
+    style::Style,
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Paragraph},
+};
+
+use super::projection_query::Shape;
+use super::projection_state::ProjectionState;
+use crate::theme;
+use crate::widgets::popup;
+
+fn build_hints() -> Line<'static> {
+    theme::border_hints::build_hints(
+        &[
+            ("Space", "Toggle"),
+            ("a", "All"),
+            ("Tab", "Object/Array"),
+            ("Enter", "Apply"),
+            ("Esc", "Close"),
+        ],
+        theme::projection::BORDER,
+    )
+}
+
+fn column_lines(state: &ProjectionState, height: usize) -> Vec<Line<'static>> {
+    let first = (state.selected() + 1).saturating_sub(height.max(1));
+    state
+        .columns()
+        .iter()
+        .enumerate()
+        .skip(first)
+        .take(height)
+        .map(|(index, column)| {
+            let is_selected = index == state.selected();
+            let indicator = if is_selected { "▸ " } else { "  " };
+            let checkbox = if column.checked { "[x] " } else { "[ ] " };
+            let mut line = Line::from(vec![
+                Span::styled(
+                    indicator,
+                    Style::default().fg(theme::projection::ITEM_SELECTED_INDICATOR),
+                ),
+                Span::styled(checkbox, Style::default().fg(theme::projection::CHECKBOX)),
+                Span::styled(
+                    column.key.clone(),
+                    Style::default().fg(theme::projection::KEY),
+                ),
+            ]);
+            if is_selected {
+                line = line.style(Style::default().bg(theme::projection::ITEM_SELECTED_BG));
+            }
+            line
+        })
+        .collect()
+}
+
+/// Render the column picker with the query it would produce
+pub fn render_projection(state: &ProjectionState, frame: &mut Frame) -> Option<Rect> {
+    let frame_area = frame.area();
+    if !state.is_visible() || frame_area.width < 30 || frame_area.height < 8 {
+        return None;
+    }
+
+    let popup_width = ((frame_area.width as f32 * 0.6) as u16)
+        .clamp(72, 96)
+        .min(frame_area.width.saturating_sub(4));
+    let list_height = state.columns().len() as u16;
+    let popup_height = (list_height + 4)
+        .min((frame_area.height as f32 * 0.7) as u16)
+        .max(8)
+        .min(frame_area.height.saturating_sub(2));
+    let popup_area = popup::centered_popup(frame_area, popup_width, popup_height);
+    popup::clear_area(frame, popup_area);
+
+    let shape = match state.shape() {
+        Shape::Object => "objects",
+        Shape::Array => "arrays",
+    };
+    let title = format!(" Pick columns ({}) ", shape);
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(Line::from(Span::styled(title, theme::projection::TITLE)))
+        .title_bottom(build_hints().centered())
+        .border_style(Style::default().fg(theme::projection::BORDER))
+        .style(Style::default().bg(theme::projection::BACKGROUND));
+    let content_area = block.inner(popup_area);
+    frame.render_widget(block, popup_area);
+
+    let layout = Layout::vertical([Constraint::Min(1), Constraint::Length(1)]).split(content_area);
+    let lines = column_lines(state, layout[0].height as usize);
+    frame.render_widget(Paragraph::new(lines), layout[0]);
+
+    let preview = match state.query() {
+        Some(query) => Span::styled(format!(" {}", query), theme::projection::QUERY),
+        None => Span::styled(
+            " Check columns with Space",
+            Style::default().fg(theme::projection::HINT),
+        ),
+    };
+    frame.render_widget(Paragraph::new(Line::from(preview)), layout[1]);
+
+    Some(popup_area)
+}
+
+#[cfg(test)]
+#[path = "projection_render_tests.rs"]
+mod projection_render_tests;
@@ -0,0 +1,70 @@
+//! Tests for projection_render
+
+use super::*;
+use crate::projection::projection_query::Columns;
+use insta::assert_snapshot;
+use ratatui::Terminal;
+use ratatui::backend::TestBackend;
+
+fn render_to_string(state: &ProjectionState, width: u16, height: u16) -> String {
+    let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+    terminal
+        .draw(|f| {
+            render_projection(state, f);
+        })
+        .unwrap();
+    terminal.backend().to_string()
+}
+
+fn open_state() -> ProjectionState {
+    let mut state = ProjectionState::new();
+    let columns = Columns::from_results(
+        r#"[{"name": "api", "image": "api:2", "replicas": 3, "app name": "shop"}]"#,
+    )
+    .unwrap();
+    state.open(columns, ".items");
+    state
+}
+
+#[test]
+fn snapshot_projection_unchecked() {
+    let state = open_state();
+    assert_snapshot!(render_to_string(&state, 80, 14));
+}
+
+#[test]
+fn snapshot_projection_with_checked_columns() {
+    let mut state = open_state();
+    state.toggle_selected();
+    state.select_next();
+    state.select_next();
+    state.select_next();
+    state.toggle_selected();
+    assert_snapshot!(render_to_string(&state, 80, 14));
+}
+
+#[test]
+fn test_selected_column_stays_visible() {
+    let mut state = ProjectionState::new();
+    let keys: Vec<String> = (0..30).map(|i| format!("\"k{}\": {}", i, i)).collect();
+    let columns = Columns::from_results(&format!("[{{{}}}]", keys.join(", "))).unwrap();
+    state.open(columns, ".");
+    for _ in 0..25 {
+        state.select_next();
+    }
+
+    let lines = column_lines(&state, 10);
+    assert_eq!(lines.len(), 10);
+    assert!(lines[9].to_string().starts_with("▸"));
+}
+
+#[test]
+fn test_nothing_rendered_when_closed() {
+    let state = ProjectionState::new();
+    let mut terminal = Terminal::new(TestBackend::new(80, 20)).unwrap();
+    let mut area = Some(Rect::default());
+    terminal
+        .draw(|f| area = render_projection(&state, f))
+        .unwrap();
+    assert_eq!(area, None);
+}
@@ -0,0 +1,111 @@
+use super::projection_query::{Columns, Shape, projection, projection_query};
+
+/// A field of the result and whether it is picked
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Column {
+    pub key: String,
+    pub checked: bool,
+}
+
+/// Column picker popup (`p` or `:columns`)
+#[derive(Debug, Default)]
+pub struct ProjectionState {
+    visible: bool,
+    columns: Vec<Column>,
+    selected: usize,
+    shape: Shape,
+    stream: bool,
+    /// Query whose result the columns come from
+    base_query: String,
+}
+
+impl ProjectionState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    /// Show the fields of `columns`, all unchecked, for the result of
+    /// `base_query`
+    pub fn open(&mut self, columns: Columns, base_query: &str) {
+        self.columns = columns
+            .keys
+            .into_iter()
+            .map(|key| Column {
+                key,
+                checked: false,
+            })
+            .collect();
+        self.stream = columns.stream;
+        self.base_query = base_query.to_string();
+        self.selected = 0;
+        self.visible = true;
+    }
+
+    pub fn close(&mut self) {
+        self.visible = false;
+    }
+
+    pub fn columns(&self) -> &[Column] {
+        &self.columns
+    }
+
+    pub fn selected(&self) -> usize {
+        self.selected
+    }
+
+    pub fn shape(&self) -> Shape {
+        self.shape
+    }
+
+    pub fn select_next(&mut self) {
+        if self.selected + 1 < self.columns.len() {
+            self.selected += 1;
+        }
+    }
+
+    pub fn select_prev(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    pub fn toggle_selected(&mut self) {
+        if let Some(column) = self.columns.get_mut(self.selected) {
+            column.checked = !column.checked;
+        }
+    }
+
+    /// Check every column, or uncheck them all when all are checked
+    pub fn toggle_all(&mut self) {
+        let check = !self.columns.iter().all(|column| column.checked);
+        for column in &mut self.columns {
+            column.checked = check;
+        }
+    }
+
+    /// Switch between keeping objects and arrays of the picked values
+    pub fn toggle_shape(&mut self) {
+        self.shape = self.shape.toggle();
+    }
+
+    /// Query keeping the checked columns, None while none are checked
+    pub fn query(&self) -> Option<String> {
+        let keys: Vec<&str> = self
+            .columns
+            .iter()
+            .filter(|column| column.checked)
+            .map(|column| column.key.as_str())
+            .collect();
+        if keys.is_empty() {
+            return None;
+        }
+        let filter = projection(&keys, self.shape, self.stream);
+        Some(projection_query(&self.base_query, &filter))
+    }
+}
+
+#[cfg(test)]
+#[path = "projection_state_tests.rs"]
+mod projection_state_tests;
@@ -0,0 +1,69 @@
+//! Tests for projection_state
+
+use super::*;
+
+fn open_state(results: &str, base_query: &str) -> ProjectionState {
+    let mut state = ProjectionState::new();
+    state.open(Columns::from_results(results).unwrap(), base_query);
+    state
+}
+
+#[test]
+fn test_open_lists_unchecked_columns() {
+    let state = open_state(r#"[{"a": 1, "b": 2}]"#, ".items");
+    assert!(state.is_visible());
+    assert_eq!(
+        state.columns(),
+        &[
+            Column {
+                key: "a".to_string(),
+                checked: false
+            },
+            Column {
+                key: "b".to_string(),
+                checked: false
+            }
+        ]
+    );
+    assert_eq!(state.query(), None);
+}
+
+#[test]
+fn test_selection_is_clamped() {
+    let mut state = open_state(r#"[{"a": 1, "b": 2}]"#, ".");
+    state.select_prev();
+    assert_eq!(state.selected(), 0);
+    state.select_next();
+    state.select_next();
+    assert_eq!(state.selected(), 1);
+}
+
+#[test]
+fn test_query_keeps_checked_columns() {
+    let mut state = open_state(r#"[{"a": 1, "b": 2, "c": 3}]"#, ".items");
+    state.toggle_selected();
+    state.select_next();
+    state.select_next();
+    state.toggle_selected();
+    assert_eq!(state.query(), Some(".items | map({a, c})".to_string()));
+
+    state.toggle_shape();
+    assert_eq!(state.query(), Some(".items | map([.a, .c])".to_string()));
+}
+
+#[test]
+fn test_query_for_a_stream() {
+    let mut state = open_state("{\"a\": 1}\n{\"b\": 2}\n", ".items[]");
+    state.toggle_all();
+    assert_eq!(state.query(), Some(".items[] | {a, b}".to_string()));
+}
+
+#[test]
+fn test_toggle_all() {
+    let mut state = open_state(r#"[{"a": 1, "b": 2}]"#, ".");
+    state.toggle_selected();
+    state.toggle_all();
+    assert!(state.columns().iter().all(|column| column.checked));
+    state.toggle_all();
+    assert!(state.columns().iter().all(|column| !column.checked));
+}
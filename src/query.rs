@@ -1,6 +1,16 @@
 pub mod debouncer;
 pub mod executor;
+pub mod incremental;
+pub mod jq_binary;
+pub mod language;
+pub mod library;
+pub mod lint;
 pub mod query_state;
+pub mod result_cache;
+pub mod result_lines;
+pub mod result_spool;
+pub mod timeline;
+pub mod variables;
 pub mod worker;
 
 // Re-export public types
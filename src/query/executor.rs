@@ -7,6 +7,8 @@ use std::time::Duration;
 use serde_json::Value;
 use tokio_util::sync::CancellationToken;
 
+use crate::query::library::JqLibrary;
+use crate::query::variables::{self, QueryVariable};
 use crate::query::worker::types::QueryError;
 
 /// Execute jq queries against JSON input
@@ -23,6 +25,10 @@ pub struct JqExecutor {
     /// All unique field names from the JSON, collected recursively.
     /// Cached for non-deterministic autocomplete fallback.
     all_field_names: OnceLock<Arc<HashSet<String>>>,
+    /// Variables bound for every query (`--arg`/`--argjson`)
+    variables: Arc<Vec<QueryVariable>>,
+    /// Module search paths and user library for every query
+    library: Arc<JqLibrary>,
 }
 
 impl JqExecutor {
@@ -32,6 +38,8 @@ impl JqExecutor {
             json_input: Arc::new(json_input),
             json_input_parsed: OnceLock::new(),
             all_field_names: OnceLock::new(),
+            variables: Arc::new(Vec::new()),
+            library: Arc::new(JqLibrary::default()),
         }
     }
 
@@ -40,6 +48,31 @@ impl JqExecutor {
         &self.json_input
     }
 
+    /// Get a shared handle to the JSON input for background evaluation
+    pub fn json_input_shared(&self) -> Arc<String> {
+        Arc::clone(&self.json_input)
+    }
+
+    /// Variables bound for every query
+    pub fn variables(&self) -> Arc<Vec<QueryVariable>> {
+        Arc::clone(&self.variables)
+    }
+
+    /// Replace the variables bound for every query
+    pub fn set_variables(&mut self, variables: Vec<QueryVariable>) {
+        self.variables = Arc::new(variables);
+    }
+
+    /// Module search paths and user library for every query
+    pub fn library(&self) -> Arc<JqLibrary> {
+        Arc::clone(&self.library)
+    }
+
+    /// Replace the module search paths and user library
+    pub fn set_library(&mut self, library: Arc<JqLibrary>) {
+        self.library = library;
+    }
+
     /// Get the parsed JSON input, lazily parsing on first access.
     ///
     /// Returns the original input JSON as a parsed Value, cached for repeated access.
@@ -97,39 +130,46 @@ impl JqExecutor {
     /// * `cancel_token` - Token for cancelling execution
     ///
     /// # Returns
-    /// * `Ok(String)` - Filtered JSON output with colors
-    /// * `Err(QueryError)` - Error or cancellation
+    /// * `Ok(String)` - Filtered JSON output, without colors
+    /// * `Err(QueryError)` - Error, with the output printed before it if
+    ///   any, or cancellation
     pub fn execute_with_cancel(
         &self,
         query: &str,
         cancel_token: &CancellationToken,
+    ) -> Result<String, QueryError> {
+        Self::execute_on_input(
+            &self.json_input,
+            query,
+            &self.variables,
+            &self.library,
+            cancel_token,
+        )
+    }
+
+    /// Execute a jq query against arbitrary input with cancellation support
+    ///
+    /// Used for incremental evaluation, where the remainder of a query runs
+    /// against the cached output of its prefix instead of the original input.
+    pub fn execute_on_input(
+        input: &Arc<String>,
+        query: &str,
+        variables: &[QueryVariable],
+        library: &JqLibrary,
+        cancel_token: &CancellationToken,
     ) -> Result<String, QueryError> {
         use std::io::Read;
         use std::sync::mpsc::channel;
 
         // Empty query defaults to identity filter
         let query = if query.trim().is_empty() { "." } else { query };
+        let program = library.program(query);
 
-        // Galaxy theme colors for jq output (using true color ANSI codes)
-        // Format: null:false:true:numbers:strings:arrays:objects:keys
-        // Each segment is an ANSI SGR code (38;2;R;G;B for true color)
-        let jq_colors = [
-            "38;2;130;133;158",  // null - muted gray
-            "38;2;224;108;117",  // false - soft red
-            "38;2;107;203;119",  // true - fresh green
-            "38;2;189;147;249",  // numbers - purple
-            "38;2;107;203;119",  // strings - fresh green
-            "1;38;2;0;217;255",  // arrays - bold electric cyan
-            "1;38;2;0;217;255",  // objects - bold electric cyan
-            "1;38;2;255;217;61", // keys - bold golden yellow
-        ]
-        .join(":");
-
-        // Spawn jq process with custom colors
-        let mut child = Command::new("jq")
-            .env("JQ_COLORS", jq_colors)
-            .arg("--color-output")
-            .arg(query)
+        // Spawn jq process; output stays uncolored and is highlighted by jiq
+        let mut child = Command::new(super::jq_binary::program())
+            .args(library.jq_args())
+            .args(variables::jq_args(variables))
+            .arg(program)
             .stdin(Stdio::piped())
             .stdout(Stdio::piped())
             .stderr(Stdio::piped())
@@ -139,7 +179,7 @@ impl JqExecutor {
         // Spawn thread to write JSON to stdin
         // This prevents deadlock if JSON is large (>64KB) and jq is slow to read
         // Arc::clone is O(1) - just increments reference count, no data copying
-        let json_input = Arc::clone(&self.json_input);
+        let json_input = Arc::clone(input);
         if let Some(stdin) = child.stdin.take() {
             std::thread::spawn(move || {
                 use std::io::Write;
@@ -201,11 +241,16 @@ impl JqExecutor {
             .map_err(|_| QueryError::OutputReadFailed("Failed to read stderr".to_string()))?;
 
         if status.success() {
-            Ok(String::from_utf8_lossy(&stdout_data).to_string())
+            return Ok(String::from_utf8_lossy(&stdout_data).to_string());
+        }
+        let message = library.query_error(String::from_utf8_lossy(&stderr_data).to_string());
+        if stdout_data.is_empty() {
+            Err(QueryError::ExecutionFailed(message))
         } else {
-            Err(QueryError::ExecutionFailed(
-                String::from_utf8_lossy(&stderr_data).to_string(),
-            ))
+            Err(QueryError::FailedWithOutput {
+                message,
+                output: String::from_utf8_lossy(&stdout_data).to_string(),
+            })
         }
     }
 }
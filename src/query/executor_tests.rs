@@ -1,6 +1,7 @@
 //! Tests for executor
 
 use super::*;
+use crate::query::variables::VariableKind;
 use tokio_util::sync::CancellationToken;
 
 #[test]
@@ -82,8 +83,8 @@ fn test_nested_field_access() {
 }
 
 #[test]
-fn test_color_output_flag_present() {
-    // This test verifies that ANSI color codes are present in output
+fn test_output_has_no_color_codes() {
+    // Results are highlighted by jiq, so jq must not add ANSI escape codes
     let json = r#"{"key": "value"}"#;
     let executor = JqExecutor::new(json.to_string());
     let cancel_token = CancellationToken::new();
@@ -91,8 +92,8 @@ fn test_color_output_flag_present() {
 
     assert!(result.is_ok());
     let output = result.unwrap();
-    // jq with --color-output produces ANSI escape codes
-    assert!(output.contains("\x1b[") || output.len() > json.len());
+    assert!(!output.contains('\x1b'));
+    assert!(output.contains(r#""key": "value""#));
 }
 
 #[test]
@@ -237,3 +238,106 @@ fn test_json_input_parsed_preserves_original_after_queries() {
         Some(2)
     );
 }
+
+#[test]
+fn test_execute_on_input_uses_given_input() {
+    let executor = JqExecutor::new(r#"{"name": "original"}"#.to_string());
+    let cancel_token = CancellationToken::new();
+    let input = Arc::new(r#"{"name": "other"}"#.to_string());
+
+    let result =
+        JqExecutor::execute_on_input(&input, ".name", &[], &JqLibrary::default(), &cancel_token)
+            .unwrap();
+    assert!(result.contains("other"));
+
+    // Executor's own input is untouched
+    let result = executor
+        .execute_with_cancel(".name", &cancel_token)
+        .unwrap();
+    assert!(result.contains("original"));
+}
+
+#[test]
+fn test_variables_are_passed_to_jq() {
+    let mut executor = JqExecutor::new(r#"{"items": [1, 2, 3]}"#.to_string());
+    executor.set_variables(vec![
+        QueryVariable::new("key", "count", VariableKind::String),
+        QueryVariable::new("min", "1", VariableKind::Json),
+    ]);
+    let cancel_token = CancellationToken::new();
+
+    let result = executor
+        .execute_with_cancel(
+            "{($key): [.items[] | select(. > $min)] | length}",
+            &cancel_token,
+        )
+        .unwrap();
+    assert!(result.contains(r#""count": 2"#), "{}", result);
+}
+
+#[test]
+fn test_undefined_variable_is_an_error() {
+    let executor = JqExecutor::new("{}".to_string());
+    let cancel_token = CancellationToken::new();
+
+    assert!(
+        executor
+            .execute_with_cancel("$missing", &cancel_token)
+            .is_err()
+    );
+}
+
+#[test]
+fn test_library_functions_and_modules_are_available() {
+    let dir = tempfile::tempdir().unwrap();
+    let modules = dir.path().join("modules");
+    std::fs::create_dir(&modules).unwrap();
+    std::fs::write(dir.path().join("lib.jq"), "def double: . * 2;").unwrap();
+    std::fs::write(modules.join("mylib.jq"), "def triple: . * 3;").unwrap();
+
+    let library = JqLibrary::load(
+        vec![modules.to_string_lossy().to_string()],
+        Some(&dir.path().join("lib.jq")),
+    );
+    let mut executor = JqExecutor::new("5".to_string());
+    executor.set_library(Arc::new(library));
+    let cancel_token = CancellationToken::new();
+
+    let result = executor
+        .execute_with_cancel(r#"include "mylib"; triple | double"#, &cancel_token)
+        .unwrap();
+    assert_eq!(result.trim(), "30");
+}
+
+#[test]
+fn test_library_errors_refer_to_query_line() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("lib.jq"), "def double: . * 2;").unwrap();
+    let mut executor = JqExecutor::new("{}".to_string());
+    executor.set_library(Arc::new(JqLibrary::load(
+        Vec::new(),
+        Some(&dir.path().join("lib.jq")),
+    )));
+    let cancel_token = CancellationToken::new();
+
+    let Err(QueryError::ExecutionFailed(message)) =
+        executor.execute_with_cancel("$missing", &cancel_token)
+    else {
+        panic!("expected an execution error");
+    };
+    assert!(message.contains("<top-level>, line 1"), "{}", message);
+}
+
+#[test]
+fn test_runtime_error_keeps_output_printed_before_it() {
+    let executor = JqExecutor::new(r#"[1, "a", 3]"#.to_string());
+    let cancel_token = CancellationToken::new();
+
+    let Err(QueryError::FailedWithOutput { message, output }) =
+        executor.execute_with_cancel(".[] | . + 1", &cancel_token)
+    else {
+        panic!("expected a failure with output");
+    };
+    assert_eq!(output, "2\n");
+    assert!(message.contains("cannot be added"), "{}", message);
+}
@@ -0,0 +1,286 @@
+//! Incremental Evaluation
+//!
+//! Detects when a query extends the previous successful query (e.g. `.services`
+//! → `.services[].name`) and derives the filter that, applied to the previous
+//! result, produces the same output as running the whole query on the input.
+//!
+//! The analysis is deliberately conservative: anything that could change the
+//! meaning of the prefix or depend on the original input (function definitions,
+//! variable bindings, `input`/`inputs`, comments) disables incremental evaluation.
+//!
+//! It also decides whether records appended to the input (when following a
+//! growing file) can be run through the query on their own.
+
+/// Keywords in the base query whose effect leaks into later pipeline stages
+const BASE_BLOCKING_KEYWORDS: &[&str] = &["def", "import", "include"];
+
+/// Keywords in the base query that bind variables visible to later stages
+const BASE_BINDING_KEYWORDS: &[&str] = &["as", "label"];
+
+/// Build the filter to run against `base`'s output so it is equivalent to `query`
+///
+/// Returns None when `query` does not extend `base` in a way that is safe to
+/// evaluate incrementally.
+///
+/// Examples:
+/// - (".services", ".services[].name") → ".[].name"
+/// - (".services", ".services | length") → "length"
+/// - (".a", ".a.b | keys") → ".b | keys"
+/// - (".a", ".ab") → None (not a token boundary)
+pub fn suffix_filter(base: &str, query: &str) -> Option<String> {
+    let base = base.trim_end();
+    if base.is_empty() || base == "." {
+        return None;
+    }
+
+    let suffix = query.strip_prefix(base)?;
+    if suffix.trim().is_empty() || !is_safe_base(base) {
+        return None;
+    }
+
+    let chain_len = path_chain_len(suffix);
+    let (chain, rest) = suffix.split_at(chain_len);
+
+    if !chain.is_empty() && !ends_with_path_chain(base) {
+        return None;
+    }
+
+    let chain_filter = if chain.starts_with('.') {
+        chain.to_string()
+    } else {
+        format!(".{}", chain)
+    };
+
+    let rest = rest.trim();
+    if rest.is_empty() {
+        return (!chain.is_empty()).then_some(chain_filter);
+    }
+
+    let piped = rest.strip_prefix('|')?;
+    if piped.starts_with('=') || piped.trim().is_empty() {
+        return None;
+    }
+    if !is_safe_suffix(piped, binds_variables(base)) {
+        return None;
+    }
+
+    if chain.is_empty() {
+        Some(piped.trim().to_string())
+    } else {
+        Some(format!("{} | {}", chain_filter, piped.trim()))
+    }
+}
+
+/// Whether `filter` handles each input value independently of the others
+///
+/// jq runs the filter once per input value, so unless it reads further values
+/// with `input`/`inputs`, running it on appended values alone produces exactly
+/// the output appended to the full result.
+pub fn is_per_input(filter: &str) -> bool {
+    !identifiers(filter).iter().any(|w| w.starts_with("input"))
+}
+
+/// Whether the base query can be safely used as a pipeline prefix
+fn is_safe_base(base: &str) -> bool {
+    if base.contains("\\(") {
+        return false;
+    }
+
+    let mut has_comment = false;
+    scan_outside_strings(base, |c| {
+        if c == '#' {
+            has_comment = true;
+        }
+    });
+
+    !has_comment
+        && !identifiers(base)
+            .iter()
+            .any(|w| BASE_BLOCKING_KEYWORDS.contains(&w.as_str()))
+}
+
+/// Whether the base query binds variables that a suffix could reference
+fn binds_variables(base: &str) -> bool {
+    identifiers(base)
+        .iter()
+        .any(|w| BASE_BINDING_KEYWORDS.contains(&w.as_str()))
+}
+
+/// Whether a piped suffix behaves the same on the prefix output as in the full query
+fn is_safe_suffix(suffix: &str, base_binds_variables: bool) -> bool {
+    if base_binds_variables && suffix.contains('$') {
+        return false;
+    }
+    is_per_input(suffix)
+}
+
+/// Length of the leading postfix path chain in `s`
+///
+/// A chain is a sequence of `.field`, `."field"`, `[]`, `[n]`, `[n:m]`,
+/// `["key"]` and `?` with no whitespace. Bracket contents must be literals
+/// so they don't depend on the input of the full query.
+fn path_chain_len(s: &str) -> usize {
+    let bytes = s.as_bytes();
+    let mut i = 0;
+
+    while i < bytes.len() {
+        match bytes[i] {
+            b'.' => {
+                let next = bytes.get(i + 1).copied();
+                match next {
+                    Some(b'"') => match string_end(s, i + 1) {
+                        Some(end) => i = end,
+                        None => break,
+                    },
+                    Some(c) if c.is_ascii_alphabetic() || c == b'_' => {
+                        i += 1;
+                        while i < bytes.len()
+                            && (bytes[i].is_ascii_alphanumeric() || bytes[i] == b'_')
+                        {
+                            i += 1;
+                        }
+                    }
+                    _ => break,
+                }
+            }
+            b'[' => match literal_bracket_end(s, i) {
+                Some(end) => i = end,
+                None => break,
+            },
+            b'?' if i > 0 => i += 1,
+            _ => break,
+        }
+    }
+
+    i
+}
+
+/// Whether `s` is `.` or a path chain starting at `.`, e.g. `.items[0].name`
+pub fn is_path_chain(s: &str) -> bool {
+    s == "." || (s.starts_with('.') && path_chain_len(s) == s.len())
+}
+
+/// Whether the last top-level pipe segment of `base` is a pure path chain
+fn ends_with_path_chain(base: &str) -> bool {
+    is_path_chain(last_pipe_segment(base).trim())
+}
+
+/// Text after the last `|` at bracket depth 0, outside strings
+fn last_pipe_segment(base: &str) -> &str {
+    let mut depth: i32 = 0;
+    let mut last_pipe = None;
+    let mut in_string = false;
+    let mut escaped = false;
+
+    for (idx, c) in base.char_indices() {
+        if in_string {
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == '"' {
+                in_string = false;
+            }
+            continue;
+        }
+        match c {
+            '"' => in_string = true,
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' | '}' => depth -= 1,
+            '|' if depth == 0 => last_pipe = Some(idx),
+            _ => {}
+        }
+    }
+
+    match last_pipe {
+        Some(idx) => &base[idx + 1..],
+        None => base,
+    }
+}
+
+/// Index just past the closing quote of the string starting at `start`
+fn string_end(s: &str, start: usize) -> Option<usize> {
+    let bytes = s.as_bytes();
+    let mut i = start + 1;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' => {
+                if bytes.get(i + 1) == Some(&b'(') {
+                    return None;
+                }
+                i += 2;
+            }
+            b'"' => return Some(i + 1),
+            _ => i += 1,
+        }
+    }
+    None
+}
+
+/// Index just past `]` for a bracket containing only literals (or nothing)
+fn literal_bracket_end(s: &str, start: usize) -> Option<usize> {
+    let bytes = s.as_bytes();
+    let mut i = start + 1;
+    while i < bytes.len() {
+        match bytes[i] {
+            b']' => return Some(i + 1),
+            b'"' => i = string_end(s, i)?,
+            c if c.is_ascii_digit() || matches!(c, b'-' | b':' | b' ') => i += 1,
+            _ => return None,
+        }
+    }
+    None
+}
+
+/// Call `f` for each character outside string literals
+fn scan_outside_strings(s: &str, mut f: impl FnMut(char)) {
+    let mut in_string = false;
+    let mut escaped = false;
+    for c in s.chars() {
+        if in_string {
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == '"' {
+                in_string = false;
+            }
+        } else if c == '"' {
+            in_string = true;
+        } else {
+            f(c);
+        }
+    }
+}
+
+/// Bare identifiers outside string literals (field names after `.` excluded)
+fn identifiers(s: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut current = String::new();
+    let mut prev = ' ';
+
+    scan_outside_strings(s, |c| {
+        if c.is_ascii_alphanumeric() || c == '_' {
+            if current.is_empty() && (prev == '.' || prev == '$') {
+                // Field access or variable name, not a keyword
+                current.push('\0');
+            }
+            current.push(c);
+        } else {
+            if !current.is_empty() && !current.starts_with('\0') {
+                words.push(std::mem::take(&mut current));
+            }
+            current.clear();
+        }
+        prev = c;
+    });
+    if !current.is_empty() && !current.starts_with('\0') {
+        words.push(current);
+    }
+
+    words
+}
+
+#[cfg(test)]
+#[path = "incremental_tests.rs"]
+mod incremental_tests;
@@ -0,0 +1,191 @@
+//! Tests for incremental
+
+use super::*;
+
+#[test]
+fn test_field_access_extension() {
+    assert_eq!(suffix_filter(".a", ".a.b"), Some(".b".to_string()));
+}
+
+#[test]
+fn test_iterator_extension() {
+    assert_eq!(
+        suffix_filter(".services", ".services[].name"),
+        Some(".[].name".to_string())
+    );
+}
+
+#[test]
+fn test_literal_index_and_slice_extension() {
+    assert_eq!(suffix_filter(".a", ".a[0]"), Some(".[0]".to_string()));
+    assert_eq!(suffix_filter(".a", ".a[-1]"), Some(".[-1]".to_string()));
+    assert_eq!(suffix_filter(".a", ".a[1:3]"), Some(".[1:3]".to_string()));
+    assert_eq!(
+        suffix_filter(".a", r#".a["key"]"#),
+        Some(r#".["key"]"#.to_string())
+    );
+}
+
+#[test]
+fn test_quoted_field_extension() {
+    assert_eq!(
+        suffix_filter(".a", r#".a."my-key""#),
+        Some(r#"."my-key""#.to_string())
+    );
+}
+
+#[test]
+fn test_optional_extension() {
+    assert_eq!(suffix_filter(".a", ".a[]?"), Some(".[]?".to_string()));
+}
+
+#[test]
+fn test_pipe_extension() {
+    assert_eq!(
+        suffix_filter(".services", ".services | length"),
+        Some("length".to_string())
+    );
+}
+
+#[test]
+fn test_chain_then_pipe_extension() {
+    assert_eq!(
+        suffix_filter(".a", ".a.b | keys"),
+        Some(".b | keys".to_string())
+    );
+}
+
+#[test]
+fn test_pipe_after_complex_base() {
+    assert_eq!(
+        suffix_filter(".a, .b", ".a, .b | tostring"),
+        Some("tostring".to_string())
+    );
+}
+
+#[test]
+fn test_chain_after_pipe_segment() {
+    assert_eq!(
+        suffix_filter(".a | .b", ".a | .b.c"),
+        Some(".c".to_string())
+    );
+}
+
+#[test]
+fn test_not_a_token_boundary() {
+    assert_eq!(suffix_filter(".a", ".ab"), None);
+    assert_eq!(suffix_filter(".a", ".a_b"), None);
+}
+
+#[test]
+fn test_not_an_extension() {
+    assert_eq!(suffix_filter(".a", ".b"), None);
+    assert_eq!(suffix_filter(".services", "[.services[]]"), None);
+}
+
+#[test]
+fn test_identical_or_whitespace_suffix() {
+    assert_eq!(suffix_filter(".a", ".a"), None);
+    assert_eq!(suffix_filter(".a", ".a   "), None);
+}
+
+#[test]
+fn test_identity_base_is_not_used() {
+    assert_eq!(suffix_filter(".", ".a"), None);
+    assert_eq!(suffix_filter("", ".a"), None);
+}
+
+#[test]
+fn test_chain_on_non_path_segment_rejected() {
+    assert_eq!(suffix_filter(".a, .b", ".a, .b.c"), None);
+    assert_eq!(suffix_filter(".a // .b", ".a // .b.c"), None);
+    assert_eq!(suffix_filter("try .a", "try .a.b"), None);
+}
+
+#[test]
+fn test_input_dependent_brackets_rejected() {
+    assert_eq!(suffix_filter(".a", ".a[.i]"), None);
+}
+
+#[test]
+fn test_other_operators_rejected() {
+    assert_eq!(suffix_filter(".a", ".a + 1"), None);
+    assert_eq!(suffix_filter(".a", ".a, .b"), None);
+    assert_eq!(suffix_filter(".a", ".a |= 1"), None);
+    assert_eq!(suffix_filter(".a", ".a // 1"), None);
+}
+
+#[test]
+fn test_incomplete_suffix_rejected() {
+    assert_eq!(suffix_filter(".a", ".a |"), None);
+    assert_eq!(suffix_filter(".a", ".a["), None);
+    assert_eq!(suffix_filter(".a", ".a."), None);
+}
+
+#[test]
+fn test_recursive_descent_and_dot_bracket_rejected() {
+    assert_eq!(suffix_filter(".a", ".a.."), None);
+    assert_eq!(suffix_filter(".a", ".a.[0]"), None);
+}
+
+#[test]
+fn test_base_with_definitions_rejected() {
+    assert_eq!(suffix_filter("def f: .; .a", "def f: .; .a | f"), None);
+}
+
+#[test]
+fn test_base_with_comment_rejected() {
+    assert_eq!(suffix_filter(".a # note", ".a # note | .b"), None);
+}
+
+#[test]
+fn test_hash_inside_string_is_not_comment() {
+    assert_eq!(
+        suffix_filter(r##".["#id"]"##, r##".["#id"] | length"##),
+        Some("length".to_string())
+    );
+}
+
+#[test]
+fn test_variables_from_base_bindings_rejected() {
+    assert_eq!(
+        suffix_filter(". as $root | .a", ". as $root | .a | $root"),
+        None
+    );
+}
+
+#[test]
+fn test_variables_defined_in_suffix_allowed() {
+    assert_eq!(
+        suffix_filter(".a", ".a | . as $x | $x"),
+        Some(". as $x | $x".to_string())
+    );
+}
+
+#[test]
+fn test_input_builtins_rejected() {
+    assert_eq!(suffix_filter(".a", ".a | input"), None);
+    assert_eq!(suffix_filter(".a", ".a | [inputs]"), None);
+}
+
+#[test]
+fn test_field_named_like_keyword_allowed() {
+    assert_eq!(
+        suffix_filter(".def", ".def | .input"),
+        Some(".input".to_string())
+    );
+}
+
+#[test]
+fn test_string_interpolation_base_rejected() {
+    assert_eq!(suffix_filter(r#""\(.a)""#, r#""\(.a)" | length"#), None);
+}
+
+#[test]
+fn test_per_input_filters() {
+    assert!(is_per_input("."));
+    assert!(is_per_input("select(.level == \"error\") | .msg"));
+    assert!(is_per_input(".input | \"inputs\""));
+    assert!(!is_per_input("[inputs]"));
+    assert!(!is_per_input(". as $first | input"));
+}
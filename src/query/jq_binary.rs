@@ -0,0 +1,104 @@
+//! Finding the jq program
+//!
+//! `JIQ_JQ` names it explicitly, as a path or a name looked up in `PATH`.
+//! Otherwise `jq` is looked up in `PATH` (with `PATHEXT`, so `jq.exe` is
+//! found on Windows). On Windows the names of the jq release downloads and
+//! the directories package managers install to are tried too, for when
+//! their directory isn't in `PATH`: scoop shims, Chocolatey's `bin` and
+//! winget links.
+
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use crate::paths::Platform;
+
+/// Environment variable naming the jq program
+pub const PROGRAM_VAR: &str = "JIQ_JQ";
+
+/// Names the jq release downloads for Windows use
+const WINDOWS_NAMES: [&str; 3] = ["jq.exe", "jq-win64.exe", "jq-windows-amd64.exe"];
+
+static PROGRAM: OnceLock<PathBuf> = OnceLock::new();
+
+/// Program queries run with, `jq` until `set_program` is called
+pub fn program() -> &'static Path {
+    PROGRAM.get().map_or(Path::new("jq"), PathBuf::as_path)
+}
+
+/// Run queries with `path`; only the first call has an effect
+pub fn set_program(path: PathBuf) {
+    let _ = PROGRAM.set(path);
+}
+
+/// Where jq is installed on this machine
+pub fn find() -> Option<PathBuf> {
+    locate(
+        Platform::current(),
+        |name| std::env::var_os(name),
+        |name| which::which(name).ok(),
+        Path::is_file,
+    )
+}
+
+/// Where jq is on `platform`, with environment variables looked up by
+/// `env`, programs searched in `PATH` by `in_path` and files checked by
+/// `is_file`
+pub fn locate(
+    platform: Platform,
+    env: impl Fn(&str) -> Option<OsString>,
+    in_path: impl Fn(&str) -> Option<PathBuf>,
+    is_file: impl Fn(&Path) -> bool,
+) -> Option<PathBuf> {
+    if let Some(program) = env(PROGRAM_VAR).filter(|v| !v.is_empty()) {
+        let program = PathBuf::from(program);
+        if program.components().count() == 1 {
+            return in_path(program.to_str()?);
+        }
+        return is_file(&program).then_some(program);
+    }
+    if let Some(path) = in_path("jq") {
+        return Some(path);
+    }
+    if platform != Platform::Windows {
+        return None;
+    }
+    WINDOWS_NAMES
+        .iter()
+        .find_map(|name| in_path(name))
+        .or_else(|| {
+            package_dirs(&env)
+                .iter()
+                .flat_map(|dir| WINDOWS_NAMES.map(|name| dir.join(name)))
+                .find(|path| is_file(path))
+        })
+}
+
+/// Directories scoop, Chocolatey and winget put programs in, from the
+/// variables that move them or their default places
+fn package_dirs(env: &impl Fn(&str) -> Option<OsString>) -> Vec<PathBuf> {
+    let var = |name: &str| env(name).filter(|v| !v.is_empty()).map(PathBuf::from);
+    let program_data = var("ProgramData").unwrap_or_else(|| PathBuf::from(r"C:\ProgramData"));
+    let mut dirs = Vec::new();
+    if let Some(scoop) = var("SCOOP").or_else(|| var("USERPROFILE").map(|p| p.join("scoop"))) {
+        dirs.push(scoop.join("shims"));
+    }
+    dirs.push(
+        var("SCOOP_GLOBAL")
+            .unwrap_or_else(|| program_data.join("scoop"))
+            .join("shims"),
+    );
+    dirs.push(
+        var("ChocolateyInstall")
+            .unwrap_or_else(|| program_data.join("chocolatey"))
+            .join("bin"),
+    );
+    if let Some(local) = var("LOCALAPPDATA") {
+        dirs.push(local.join("Microsoft").join("WinGet").join("Links"));
+    }
+    dirs
+}
+
+#[cfg(test)]
+#[path = "jq_binary_tests.rs"]
+mod jq_binary_tests;
@@ -0,0 +1,118 @@
+//! Tests for query/jq_binary
+
+use super::*;
+use std::collections::{HashMap, HashSet};
+
+struct Machine {
+    vars: HashMap<&'static str, &'static str>,
+    path: HashMap<&'static str, &'static str>,
+    files: HashSet<PathBuf>,
+}
+
+impl Machine {
+    fn new() -> Self {
+        Self {
+            vars: HashMap::new(),
+            path: HashMap::new(),
+            files: HashSet::new(),
+        }
+    }
+
+    fn locate(&self, platform: Platform) -> Option<PathBuf> {
+        locate(
+            platform,
+            |name| self.vars.get(name).map(OsString::from),
+            |name| self.path.get(name).map(PathBuf::from),
+            |path| self.files.contains(path),
+        )
+    }
+}
+
+#[test]
+fn test_finds_jq_in_path() {
+    let mut machine = Machine::new();
+    machine.path.insert("jq", "/usr/bin/jq");
+
+    assert_eq!(
+        machine.locate(Platform::Unix),
+        Some(PathBuf::from("/usr/bin/jq"))
+    );
+}
+
+#[test]
+fn test_missing_jq() {
+    assert_eq!(Machine::new().locate(Platform::Unix), None);
+    assert_eq!(Machine::new().locate(Platform::Windows), None);
+}
+
+#[test]
+fn test_program_variable_takes_precedence() {
+    let mut machine = Machine::new();
+    machine.path.insert("jq", "/usr/bin/jq");
+    machine.path.insert("gojq", "/usr/local/bin/gojq");
+    machine.vars.insert(PROGRAM_VAR, "gojq");
+
+    assert_eq!(
+        machine.locate(Platform::Unix),
+        Some(PathBuf::from("/usr/local/bin/gojq"))
+    );
+
+    machine.vars.insert(PROGRAM_VAR, "/opt/jq/bin/jq");
+    assert_eq!(machine.locate(Platform::Unix), None);
+    machine.files.insert(PathBuf::from("/opt/jq/bin/jq"));
+    assert_eq!(
+        machine.locate(Platform::Unix),
+        Some(PathBuf::from("/opt/jq/bin/jq"))
+    );
+}
+
+#[test]
+fn test_windows_release_name_in_path() {
+    let mut machine = Machine::new();
+    machine
+        .path
+        .insert("jq-win64.exe", r"C:\tools\jq-win64.exe");
+
+    assert_eq!(
+        machine.locate(Platform::Windows),
+        Some(PathBuf::from(r"C:\tools\jq-win64.exe"))
+    );
+    assert_eq!(machine.locate(Platform::Unix), None);
+}
+
+#[test]
+fn test_windows_scoop_shim() {
+    let mut machine = Machine::new();
+    machine.vars.insert("USERPROFILE", "/users/u");
+    let shim = Path::new("/users/u")
+        .join("scoop")
+        .join("shims")
+        .join("jq.exe");
+    machine.files.insert(shim.clone());
+
+    assert_eq!(machine.locate(Platform::Windows), Some(shim));
+}
+
+#[test]
+fn test_windows_chocolatey_bin() {
+    let mut machine = Machine::new();
+    machine.vars.insert("ChocolateyInstall", "/choco");
+    let bin = Path::new("/choco").join("bin").join("jq.exe");
+    machine.files.insert(bin.clone());
+
+    assert_eq!(machine.locate(Platform::Windows), Some(bin));
+}
+
+#[test]
+fn test_windows_winget_link() {
+    let mut machine = Machine::new();
+    machine.vars.insert("LOCALAPPDATA", "/local");
+    let link = Path::new("/local")
+        .join("Microsoft")
+        .join("WinGet")
+        .join("Links")
+        .join("jq.exe");
+    machine.files.insert(link.clone());
+
+    assert_eq!(machine.locate(Platform::Windows), Some(link));
+}
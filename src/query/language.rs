@@ -0,0 +1,118 @@
+//! Query Languages
+//!
+//! jiq always evaluates queries with jq. Alternative languages (JSONPath,
+//! JMESPath and an experimental SQL mode) are translated into an equivalent jq filter first, so they share
+//! the worker, result cache, results pane and history with native jq queries.
+
+pub mod jmespath;
+pub mod jsonpath;
+pub mod sql;
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// Language the query input is written in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum QueryLanguage {
+    #[default]
+    Jq,
+    JsonPath,
+    JmesPath,
+    Sql,
+}
+
+impl QueryLanguage {
+    /// Display name for the input title
+    pub fn label(self) -> &'static str {
+        match self {
+            QueryLanguage::Jq => "jq",
+            QueryLanguage::JsonPath => "JSONPath",
+            QueryLanguage::JmesPath => "JMESPath",
+            QueryLanguage::Sql => "SQL",
+        }
+    }
+
+    /// Next language in the Ctrl+L cycle
+    pub fn next(self) -> Self {
+        match self {
+            QueryLanguage::Jq => QueryLanguage::JsonPath,
+            QueryLanguage::JsonPath => QueryLanguage::JmesPath,
+            QueryLanguage::JmesPath => QueryLanguage::Sql,
+            QueryLanguage::Sql => QueryLanguage::Jq,
+        }
+    }
+
+    /// Translate `query` into a jq filter
+    ///
+    /// jq queries pass through unchanged, and an empty query is the identity
+    /// filter in every language.
+    pub fn to_jq(self, query: &str) -> Result<String, TranslateError> {
+        let translate = match self {
+            QueryLanguage::Jq => return Ok(query.to_string()),
+            QueryLanguage::JsonPath => jsonpath::to_jq,
+            QueryLanguage::JmesPath => jmespath::to_jq,
+            QueryLanguage::Sql => sql::to_jq,
+        };
+        if query.trim().is_empty() {
+            return Ok(".".to_string());
+        }
+        translate(query)
+    }
+}
+
+/// Error translating a query into jq
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TranslateError {
+    pub message: String,
+    /// Character offset in the query where the problem was detected
+    pub position: usize,
+}
+
+impl TranslateError {
+    pub fn new(message: impl Into<String>, position: usize) -> Self {
+        Self {
+            message: message.into(),
+            position,
+        }
+    }
+}
+
+impl fmt::Display for TranslateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at position {}", self.message, self.position + 1)
+    }
+}
+
+/// Quote a string as a JSON (and jq) string literal
+pub(crate) fn json_string(s: &str) -> String {
+    serde_json::to_string(s).unwrap_or_else(|_| format!("\"{}\"", s))
+}
+
+/// jq keywords, which older jq versions reject after a `.`
+const JQ_KEYWORDS: &[&str] = &[
+    "and", "or", "not", "if", "then", "elif", "else", "end", "as", "def", "reduce", "foreach",
+    "try", "catch", "label", "import", "include", "__loc__",
+];
+
+/// Whether `name` can be written as `.name` in jq
+pub(crate) fn is_jq_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+        && !JQ_KEYWORDS.contains(&name)
+}
+
+/// jq field access for `name`: `.name`, or `.["name"]` when quoting is needed
+pub(crate) fn jq_field(name: &str) -> String {
+    if is_jq_identifier(name) {
+        format!(".{}", name)
+    } else {
+        format!(".[{}]", json_string(name))
+    }
+}
+
+#[cfg(test)]
+#[path = "language_tests.rs"]
+mod language_tests;
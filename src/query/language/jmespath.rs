@@ -0,0 +1,419 @@
+//! JMESPath → jq translation
+//!
+//! Parses the full JMESPath grammar (projections, filters, multi-selects,
+//! pipes and the built-in function library) with a Pratt parser modelled on
+//! the reference implementation, then generates a jq filter that keeps
+//! JMESPath semantics: missing values are `null`, projections drop `null`
+//! results and conditions use JMESPath truthiness.
+
+mod codegen;
+mod lexer;
+
+use serde_json::Value;
+
+use super::TranslateError;
+use lexer::{Token, TokenKind};
+
+/// Translate a JMESPath expression into an equivalent jq filter
+pub fn to_jq(query: &str) -> Result<String, TranslateError> {
+    let tokens = lexer::tokenize(query)?;
+    let mut parser = Parser { tokens, index: 0 };
+    let ast = parser.expression(0)?;
+    if parser.kind() != &TokenKind::Eof {
+        return Err(parser.error("Unexpected token"));
+    }
+    codegen::generate(&ast)
+}
+
+/// JMESPath expression tree
+#[derive(Debug, Clone, PartialEq)]
+enum Ast {
+    Identity,
+    Field(String),
+    Index(i64),
+    Slice(Option<i64>, Option<i64>, Option<i64>),
+    Literal(Value),
+    /// `lhs.rhs` and `lhs[n]`
+    Subexpr(Box<Ast>, Box<Ast>),
+    /// `lhs | rhs`
+    Pipe(Box<Ast>, Box<Ast>),
+    /// List projection: evaluate `rhs` for each element of `lhs`
+    Projection(Box<Ast>, Box<Ast>),
+    /// Object projection (`lhs.*`): evaluate `rhs` for each value of `lhs`
+    ValueProjection(Box<Ast>, Box<Ast>),
+    /// `lhs[? condition] rhs`
+    FilterProjection(Box<Ast>, Box<Ast>, Box<Ast>),
+    Flatten(Box<Ast>),
+    Or(Box<Ast>, Box<Ast>),
+    And(Box<Ast>, Box<Ast>),
+    Not(Box<Ast>),
+    Comparison(Comparator, Box<Ast>, Box<Ast>),
+    MultiList(Vec<Ast>),
+    MultiHash(Vec<(String, Ast)>),
+    Function(String, Vec<Ast>, usize),
+    Expref(Box<Ast>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Comparator {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+impl Comparator {
+    fn jq_operator(self) -> &'static str {
+        match self {
+            Comparator::Eq => "==",
+            Comparator::Ne => "!=",
+            Comparator::Lt => "<",
+            Comparator::Le => "<=",
+            Comparator::Gt => ">",
+            Comparator::Ge => ">=",
+        }
+    }
+}
+
+/// Left binding power of a token, from the JMESPath reference grammar
+fn binding_power(kind: &TokenKind) -> u8 {
+    match kind {
+        TokenKind::Pipe => 1,
+        TokenKind::Or => 2,
+        TokenKind::And => 3,
+        TokenKind::Eq
+        | TokenKind::Ne
+        | TokenKind::Lt
+        | TokenKind::Le
+        | TokenKind::Gt
+        | TokenKind::Ge => 5,
+        TokenKind::Flatten => 9,
+        TokenKind::Star => 20,
+        TokenKind::Filter => 21,
+        TokenKind::Dot => 40,
+        TokenKind::Not => 45,
+        TokenKind::LBrace => 50,
+        TokenKind::LBracket => 55,
+        TokenKind::LParen => 60,
+        _ => 0,
+    }
+}
+
+/// Projections stop at tokens binding looser than this
+const PROJECTION_STOP: u8 = 10;
+
+struct Parser {
+    tokens: Vec<Token>,
+    index: usize,
+}
+
+impl Parser {
+    fn kind(&self) -> &TokenKind {
+        &self.tokens[self.index].kind
+    }
+
+    fn lookahead(&self, offset: usize) -> &TokenKind {
+        let index = (self.index + offset).min(self.tokens.len() - 1);
+        &self.tokens[index].kind
+    }
+
+    fn advance(&mut self) -> Token {
+        let token = self.tokens[self.index].clone();
+        if self.index < self.tokens.len() - 1 {
+            self.index += 1;
+        }
+        token
+    }
+
+    fn error(&self, message: &str) -> TranslateError {
+        TranslateError::new(message, self.tokens[self.index].position)
+    }
+
+    fn expect(&mut self, kind: TokenKind, what: &str) -> Result<(), TranslateError> {
+        if self.kind() == &kind {
+            self.advance();
+            Ok(())
+        } else {
+            Err(self.error(&format!("Expected {}", what)))
+        }
+    }
+
+    fn expression(&mut self, rbp: u8) -> Result<Ast, TranslateError> {
+        let token = self.advance();
+        let mut left = self.nud(token)?;
+        while rbp < binding_power(self.kind()) {
+            let token = self.advance();
+            left = self.led(token, left)?;
+        }
+        Ok(left)
+    }
+
+    /// Parse a token in prefix position
+    fn nud(&mut self, token: Token) -> Result<Ast, TranslateError> {
+        match token.kind {
+            TokenKind::Identifier(name) | TokenKind::QuotedIdentifier(name) => Ok(Ast::Field(name)),
+            TokenKind::Literal(value) => Ok(Ast::Literal(value)),
+            TokenKind::Current => Ok(Ast::Identity),
+            TokenKind::Star => {
+                let rhs = if self.kind() == &TokenKind::RBracket {
+                    Ast::Identity
+                } else {
+                    self.projection_rhs(binding_power(&TokenKind::Star))?
+                };
+                Ok(Ast::ValueProjection(Box::new(Ast::Identity), Box::new(rhs)))
+            }
+            TokenKind::Filter => self.filter(Ast::Identity),
+            TokenKind::LBrace => self.multi_select_hash(),
+            TokenKind::LParen => {
+                let inner = self.expression(0)?;
+                self.expect(TokenKind::RParen, "')'")?;
+                Ok(inner)
+            }
+            TokenKind::Flatten => {
+                let rhs = self.projection_rhs(binding_power(&TokenKind::Flatten))?;
+                Ok(Ast::Projection(
+                    Box::new(Ast::Flatten(Box::new(Ast::Identity))),
+                    Box::new(rhs),
+                ))
+            }
+            TokenKind::Not => {
+                let inner = self.expression(binding_power(&TokenKind::Not))?;
+                Ok(Ast::Not(Box::new(inner)))
+            }
+            TokenKind::LBracket => match (self.kind(), self.lookahead(1)) {
+                (TokenKind::Number(_) | TokenKind::Colon, _) => {
+                    let index = self.index_expression()?;
+                    self.project_if_slice(Ast::Identity, index)
+                }
+                (TokenKind::Star, TokenKind::RBracket) => {
+                    self.advance();
+                    self.advance();
+                    let rhs = self.projection_rhs(binding_power(&TokenKind::Star))?;
+                    Ok(Ast::Projection(Box::new(Ast::Identity), Box::new(rhs)))
+                }
+                _ => self.multi_select_list(),
+            },
+            TokenKind::Expref => {
+                let inner = self.expression(0)?;
+                Ok(Ast::Expref(Box::new(inner)))
+            }
+            _ => Err(TranslateError::new("Unexpected token", token.position)),
+        }
+    }
+
+    /// Parse a token in infix position
+    fn led(&mut self, token: Token, left: Ast) -> Result<Ast, TranslateError> {
+        let comparator = match token.kind {
+            TokenKind::Eq => Some(Comparator::Eq),
+            TokenKind::Ne => Some(Comparator::Ne),
+            TokenKind::Lt => Some(Comparator::Lt),
+            TokenKind::Le => Some(Comparator::Le),
+            TokenKind::Gt => Some(Comparator::Gt),
+            TokenKind::Ge => Some(Comparator::Ge),
+            _ => None,
+        };
+        if let Some(comparator) = comparator {
+            let right = self.expression(binding_power(&token.kind))?;
+            return Ok(Ast::Comparison(comparator, Box::new(left), Box::new(right)));
+        }
+
+        match token.kind {
+            TokenKind::Dot => {
+                if self.kind() == &TokenKind::Star {
+                    self.advance();
+                    let rhs = self.projection_rhs(binding_power(&TokenKind::Dot))?;
+                    Ok(Ast::ValueProjection(Box::new(left), Box::new(rhs)))
+                } else {
+                    let rhs = self.dot_rhs(binding_power(&TokenKind::Dot))?;
+                    Ok(Ast::Subexpr(Box::new(left), Box::new(rhs)))
+                }
+            }
+            TokenKind::Pipe => {
+                let right = self.expression(binding_power(&TokenKind::Pipe))?;
+                Ok(Ast::Pipe(Box::new(left), Box::new(right)))
+            }
+            TokenKind::Or => {
+                let right = self.expression(binding_power(&TokenKind::Or))?;
+                Ok(Ast::Or(Box::new(left), Box::new(right)))
+            }
+            TokenKind::And => {
+                let right = self.expression(binding_power(&TokenKind::And))?;
+                Ok(Ast::And(Box::new(left), Box::new(right)))
+            }
+            TokenKind::LParen => {
+                let Ast::Field(name) = left else {
+                    return Err(TranslateError::new(
+                        "Only functions can be called",
+                        token.position,
+                    ));
+                };
+                // The function name is the token before `(`
+                let name_position = self.tokens[self.index - 2].position;
+                let mut args = Vec::new();
+                while self.kind() != &TokenKind::RParen {
+                    args.push(self.expression(0)?);
+                    if self.kind() == &TokenKind::Comma {
+                        self.advance();
+                    } else if self.kind() != &TokenKind::RParen {
+                        return Err(self.error("Expected ',' or ')'"));
+                    }
+                }
+                self.advance();
+                Ok(Ast::Function(name, args, name_position))
+            }
+            TokenKind::Filter => self.filter(left),
+            TokenKind::Flatten => {
+                let rhs = self.projection_rhs(binding_power(&TokenKind::Flatten))?;
+                Ok(Ast::Projection(
+                    Box::new(Ast::Flatten(Box::new(left))),
+                    Box::new(rhs),
+                ))
+            }
+            TokenKind::LBracket => match self.kind() {
+                TokenKind::Number(_) | TokenKind::Colon => {
+                    let index = self.index_expression()?;
+                    self.project_if_slice(left, index)
+                }
+                _ => {
+                    self.expect(TokenKind::Star, "'*', a number or a slice")?;
+                    self.expect(TokenKind::RBracket, "']'")?;
+                    let rhs = self.projection_rhs(binding_power(&TokenKind::Star))?;
+                    Ok(Ast::Projection(Box::new(left), Box::new(rhs)))
+                }
+            },
+            _ => Err(TranslateError::new("Unexpected token", token.position)),
+        }
+    }
+
+    /// Rest of a filter projection after `[?`
+    fn filter(&mut self, left: Ast) -> Result<Ast, TranslateError> {
+        let condition = self.expression(0)?;
+        self.expect(TokenKind::RBracket, "']'")?;
+        let rhs = if self.kind() == &TokenKind::Flatten {
+            Ast::Identity
+        } else {
+            self.projection_rhs(binding_power(&TokenKind::Filter))?
+        };
+        Ok(Ast::FilterProjection(
+            Box::new(left),
+            Box::new(condition),
+            Box::new(rhs),
+        ))
+    }
+
+    /// Index or slice after `[`, consuming the closing `]`
+    fn index_expression(&mut self) -> Result<Ast, TranslateError> {
+        let is_slice = self.kind() == &TokenKind::Colon || self.lookahead(1) == &TokenKind::Colon;
+        if !is_slice {
+            let TokenKind::Number(index) = *self.kind() else {
+                return Err(self.error("Expected a number"));
+            };
+            self.advance();
+            self.expect(TokenKind::RBracket, "']'")?;
+            return Ok(Ast::Index(index));
+        }
+
+        let mut parts = [None; 3];
+        let mut part = 0;
+        loop {
+            match *self.kind() {
+                TokenKind::RBracket => break,
+                TokenKind::Colon if part < 2 => part += 1,
+                TokenKind::Number(n) => parts[part] = Some(n),
+                _ => return Err(self.error("Invalid slice")),
+            }
+            self.advance();
+        }
+        self.advance();
+        Ok(Ast::Slice(parts[0], parts[1], parts[2]))
+    }
+
+    fn project_if_slice(&mut self, left: Ast, index: Ast) -> Result<Ast, TranslateError> {
+        let is_slice = matches!(index, Ast::Slice(..));
+        let subexpr = Ast::Subexpr(Box::new(left), Box::new(index));
+        if is_slice {
+            let rhs = self.projection_rhs(binding_power(&TokenKind::Star))?;
+            Ok(Ast::Projection(Box::new(subexpr), Box::new(rhs)))
+        } else {
+            Ok(subexpr)
+        }
+    }
+
+    /// Expression applied to each element of a projection
+    fn projection_rhs(&mut self, rbp: u8) -> Result<Ast, TranslateError> {
+        match self.kind() {
+            kind if binding_power(kind) < PROJECTION_STOP => Ok(Ast::Identity),
+            TokenKind::LBracket | TokenKind::Filter => self.expression(rbp),
+            TokenKind::Dot => {
+                self.advance();
+                self.dot_rhs(rbp)
+            }
+            _ => Err(self.error("Unexpected token after projection")),
+        }
+    }
+
+    fn dot_rhs(&mut self, rbp: u8) -> Result<Ast, TranslateError> {
+        match self.kind() {
+            TokenKind::Identifier(_) | TokenKind::QuotedIdentifier(_) | TokenKind::Star => {
+                self.expression(rbp)
+            }
+            TokenKind::LBracket => {
+                self.advance();
+                self.multi_select_list()
+            }
+            TokenKind::LBrace => {
+                self.advance();
+                self.multi_select_hash()
+            }
+            _ => Err(self.error("Expected an identifier, '*', '[' or '{' after '.'")),
+        }
+    }
+
+    /// `[a, b]` after the opening bracket
+    fn multi_select_list(&mut self) -> Result<Ast, TranslateError> {
+        let mut items = Vec::new();
+        loop {
+            items.push(self.expression(0)?);
+            match self.kind() {
+                TokenKind::Comma => {
+                    self.advance();
+                }
+                TokenKind::RBracket => {
+                    self.advance();
+                    return Ok(Ast::MultiList(items));
+                }
+                _ => return Err(self.error("Expected ',' or ']'")),
+            }
+        }
+    }
+
+    /// `{key: expr, ...}` after the opening brace
+    fn multi_select_hash(&mut self) -> Result<Ast, TranslateError> {
+        let mut entries = Vec::new();
+        loop {
+            let key = match self.kind() {
+                TokenKind::Identifier(key) | TokenKind::QuotedIdentifier(key) => key.clone(),
+                _ => return Err(self.error("Expected a key")),
+            };
+            self.advance();
+            self.expect(TokenKind::Colon, "':'")?;
+            entries.push((key, self.expression(0)?));
+            match self.kind() {
+                TokenKind::Comma => {
+                    self.advance();
+                }
+                TokenKind::RBrace => {
+                    self.advance();
+                    return Ok(Ast::MultiHash(entries));
+                }
+                _ => return Err(self.error("Expected ',' or '}'")),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "jmespath_tests.rs"]
+mod jmespath_tests;
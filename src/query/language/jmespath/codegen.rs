@@ -0,0 +1,295 @@
+//! jq generation for parsed JMESPath expressions
+//!
+//! Every generated filter produces exactly one output for one input, which
+//! lets expressions be composed freely as jq arguments and bindings.
+
+use super::{Ast, Comparator};
+use crate::query::language::{TranslateError, is_jq_identifier, json_string};
+
+/// Generate the jq filter for a whole expression
+pub(super) fn generate(ast: &Ast) -> Result<String, TranslateError> {
+    gen_ast(ast)
+}
+
+fn gen_ast(ast: &Ast) -> Result<String, TranslateError> {
+    if !matches!(ast, Ast::Identity)
+        && let Some(path) = simple_path(ast)
+    {
+        return Ok(format!("(try {} catch null)", path));
+    }
+
+    Ok(match ast {
+        Ast::Identity => ".".to_string(),
+        Ast::Literal(value) => {
+            serde_json::to_string(value).unwrap_or_else(|_| "null".to_string())
+        }
+        Ast::Slice(start, end, step) => {
+            let slice = gen_slice(*start, *end, step.unwrap_or(1))?;
+            format!("(if type == \"array\" then {} else null end)", slice)
+        }
+        Ast::Subexpr(lhs, rhs) | Ast::Pipe(lhs, rhs) => pipe(gen_ast(lhs)?, gen_ast(rhs)?),
+        Ast::Projection(lhs, rhs) => pipe(
+            gen_ast(lhs)?,
+            format!(
+                "if type == \"array\" then [{}] else null end",
+                project_each(".[]", rhs)?
+            ),
+        ),
+        Ast::ValueProjection(lhs, rhs) => pipe(
+            gen_ast(lhs)?,
+            format!(
+                "if type == \"object\" then [{}] else null end",
+                project_each(".[]", rhs)?
+            ),
+        ),
+        Ast::FilterProjection(lhs, condition, rhs) => {
+            let each = format!(".[] | select({})", truthy(&gen_ast(condition)?));
+            pipe(
+                gen_ast(lhs)?,
+                format!(
+                    "if type == \"array\" then [{}] else null end",
+                    project_each(&each, rhs)?
+                ),
+            )
+        }
+        Ast::Flatten(inner) => pipe(
+            gen_ast(inner)?,
+            "if type == \"array\" then [.[] | if type == \"array\" then .[] else . end] else null end"
+                .to_string(),
+        ),
+        Ast::Or(lhs, rhs) => format!(
+            "(({}) as $l | if {} then $l else {} end)",
+            gen_ast(lhs)?,
+            truthy("$l"),
+            gen_ast(rhs)?
+        ),
+        Ast::And(lhs, rhs) => format!(
+            "(({}) as $l | if {} then {} else $l end)",
+            gen_ast(lhs)?,
+            truthy("$l"),
+            gen_ast(rhs)?
+        ),
+        Ast::Not(inner) => format!("({} | not)", truthy(&gen_ast(inner)?)),
+        Ast::Comparison(comparator, lhs, rhs) => {
+            gen_comparison(*comparator, &gen_ast(lhs)?, &gen_ast(rhs)?)
+        }
+        Ast::MultiList(items) => {
+            let items = items
+                .iter()
+                .map(|item| gen_ast(item).map(|s| format!("({})", s)))
+                .collect::<Result<Vec<_>, _>>()?;
+            format!("(if . == null then null else [{}] end)", items.join(", "))
+        }
+        Ast::MultiHash(entries) => {
+            let entries = entries
+                .iter()
+                .map(|(key, value)| {
+                    gen_ast(value).map(|s| format!("{}: ({})", json_string(key), s))
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            format!("(if . == null then null else {{{}}} end)", entries.join(", "))
+        }
+        Ast::Function(name, args, position) => gen_function(name, args, *position)?,
+        Ast::Expref(_) => {
+            return Err(TranslateError::new(
+                "Expression references ('&') are only valid as function arguments",
+                0,
+            ));
+        }
+        Ast::Field(_) | Ast::Index(_) => unreachable!("handled by simple_path"),
+    })
+}
+
+/// Field and index chains as a plain jq path, like `.a.b[0]`
+fn simple_path(ast: &Ast) -> Option<String> {
+    let path = path_suffix(ast)?;
+    Some(if path.is_empty() {
+        ".".to_string()
+    } else {
+        path
+    })
+}
+
+fn path_suffix(ast: &Ast) -> Option<String> {
+    match ast {
+        Ast::Identity => Some(String::new()),
+        Ast::Field(name) if is_jq_identifier(name) => Some(format!(".{}", name)),
+        Ast::Field(name) => Some(format!(".[{}]", json_string(name))),
+        Ast::Index(index) => Some(format!(".[{}]", index)),
+        Ast::Subexpr(lhs, rhs) => {
+            let lhs = path_suffix(lhs)?;
+            let rhs = path_suffix(rhs)?;
+            // jq 1.6 rejects `.a.[0]`, so bracket suffixes attach directly
+            match rhs.strip_prefix('.') {
+                Some(bracket) if bracket.starts_with('[') && !lhs.is_empty() => {
+                    Some(format!("{}{}", lhs, bracket))
+                }
+                _ => Some(format!("{}{}", lhs, rhs)),
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Compose two filters, dropping identity sides
+fn pipe(lhs: String, rhs: String) -> String {
+    match (lhs.as_str(), rhs.as_str()) {
+        (".", _) => rhs,
+        (_, ".") => lhs,
+        _ => format!("{} | {}", lhs, rhs),
+    }
+}
+
+/// Projection body: apply `rhs` to each element, dropping nulls
+fn project_each(each: &str, rhs: &Ast) -> Result<String, TranslateError> {
+    Ok(pipe(
+        pipe(each.to_string(), gen_ast(rhs)?),
+        "select(. != null)".to_string(),
+    ))
+}
+
+/// JMESPath truthiness: false, null and empty strings/arrays/objects are false
+fn truthy(value: &str) -> String {
+    format!("(({}) | IN(false, null, \"\", [], {{}}) | not)", value)
+}
+
+fn gen_slice(start: Option<i64>, end: Option<i64>, step: i64) -> Result<String, TranslateError> {
+    let bound = |b: Option<i64>| b.map(|v| v.to_string()).unwrap_or_default();
+    let slice = match (start, end) {
+        (None, None) => ".".to_string(),
+        _ => format!(".[{}:{}]", bound(start), bound(end)),
+    };
+
+    match step {
+        1 => Ok(slice),
+        0 => Err(TranslateError::new("Slice step cannot be 0", 0)),
+        s if s > 1 => Ok(pipe(slice, format!("[.[range(0; length; {})]]", s))),
+        s if start.is_none() && end.is_none() => {
+            Ok(format!("(reverse | [.[range(0; length; {})]])", -s))
+        }
+        _ => Err(TranslateError::new(
+            "Negative slice steps are only supported without start and end",
+            0,
+        )),
+    }
+}
+
+fn gen_comparison(comparator: Comparator, lhs: &str, rhs: &str) -> String {
+    let op = comparator.jq_operator();
+    match comparator {
+        Comparator::Eq | Comparator::Ne => format!("(({}) {} ({}))", lhs, op, rhs),
+        // Ordering is only defined between numbers
+        _ => format!(
+            "([({}), ({})] | if map(type) == [\"number\", \"number\"] then .[0] {} .[1] else null end)",
+            lhs, rhs, op
+        ),
+    }
+}
+
+/// Translate a call to a JMESPath built-in function
+fn gen_function(name: &str, args: &[Ast], position: usize) -> Result<String, TranslateError> {
+    let arity_error =
+        |expected: &str| TranslateError::new(format!("{}() takes {}", name, expected), position);
+    let value = |index: usize| -> Result<String, TranslateError> {
+        match &args[index] {
+            Ast::Expref(_) => Err(TranslateError::new(
+                format!("{}() does not take an expression reference", name),
+                position,
+            )),
+            arg => gen_ast(arg).map(|s| format!("({})", s)),
+        }
+    };
+    let expref = |index: usize| -> Result<String, TranslateError> {
+        match &args[index] {
+            Ast::Expref(inner) => gen_ast(inner),
+            _ => Err(TranslateError::new(
+                format!("{}() expects an expression reference like &field", name),
+                position,
+            )),
+        }
+    };
+    let unary = |body: &str| -> Result<String, TranslateError> {
+        if args.len() != 1 {
+            return Err(arity_error("1 argument"));
+        }
+        Ok(format!("({} | {})", value(0)?, body))
+    };
+    let binary = || -> Result<(String, String), TranslateError> {
+        if args.len() != 2 {
+            return Err(arity_error("2 arguments"));
+        }
+        Ok((value(0)?, value(1)?))
+    };
+    let by = |function: &str| -> Result<String, TranslateError> {
+        if args.len() != 2 {
+            return Err(arity_error("2 arguments"));
+        }
+        Ok(format!("({} | {}({}))", value(0)?, function, expref(1)?))
+    };
+    let variadic = || -> Result<Vec<String>, TranslateError> {
+        if args.is_empty() {
+            return Err(arity_error("at least 1 argument"));
+        }
+        (0..args.len()).map(value).collect()
+    };
+
+    match name {
+        "abs" => unary("if . < 0 then -. else . end"),
+        "avg" => unary("if length == 0 then null else add / length end"),
+        "ceil" => unary("ceil"),
+        "floor" => unary("floor"),
+        "keys" => unary("keys_unsorted"),
+        "length" => unary("length"),
+        "max" => unary("max"),
+        "min" => unary("min"),
+        "reverse" => {
+            unary("if type == \"string\" then explode | reverse | implode else reverse end")
+        }
+        "sort" => unary("sort"),
+        "sum" => unary("add // 0"),
+        "to_array" => unary("if type == \"array\" then . else [.] end"),
+        "to_number" => unary(
+            "if type == \"number\" then . elif type == \"string\" then (tonumber? // null) else null end",
+        ),
+        "to_string" => unary("if type == \"string\" then . else tojson end"),
+        "type" => unary("type"),
+        "values" => unary("[.[]]"),
+        "contains" => {
+            let (subject, search) = binary()?;
+            Ok(format!(
+                "({} as $v | {} | if type == \"string\" then ($v | type) == \"string\" and index($v) != null else any(.[]; . == $v) end)",
+                search, subject
+            ))
+        }
+        "ends_with" => {
+            let (subject, suffix) = binary()?;
+            Ok(format!("({} as $s | {} | endswith($s))", suffix, subject))
+        }
+        "starts_with" => {
+            let (subject, prefix) = binary()?;
+            Ok(format!("({} as $s | {} | startswith($s))", prefix, subject))
+        }
+        "join" => {
+            let (glue, list) = binary()?;
+            Ok(format!("({} as $g | {} | join($g))", glue, list))
+        }
+        "map" => {
+            if args.len() != 2 {
+                return Err(arity_error("2 arguments"));
+            }
+            Ok(format!("({} | map({}))", value(1)?, expref(0)?))
+        }
+        "max_by" => by("max_by"),
+        "min_by" => by("min_by"),
+        "sort_by" => by("sort_by"),
+        "merge" => Ok(format!("([{}] | add)", variadic()?.join(", "))),
+        "not_null" => Ok(format!(
+            "([{}] | map(select(. != null)) | .[0])",
+            variadic()?.join(", ")
+        )),
+        _ => Err(TranslateError::new(
+            format!("Unknown function '{}'", name),
+            position,
+        )),
+    }
+}
@@ -0,0 +1,172 @@
+//! JMESPath tokenizer
+
+use serde_json::Value;
+
+use crate::query::language::TranslateError;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TokenKind {
+    Identifier(String),
+    QuotedIdentifier(String),
+    Number(i64),
+    /// Backtick JSON literal or single-quoted raw string
+    Literal(Value),
+    Dot,
+    Star,
+    Flatten,
+    Filter,
+    LBracket,
+    RBracket,
+    LBrace,
+    RBrace,
+    LParen,
+    RParen,
+    Comma,
+    Colon,
+    Pipe,
+    Or,
+    And,
+    Not,
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Current,
+    Expref,
+    Eof,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Token {
+    pub kind: TokenKind,
+    /// Character offset of the token start
+    pub position: usize,
+}
+
+/// Split a JMESPath expression into tokens, ending with `Eof`
+pub fn tokenize(query: &str) -> Result<Vec<Token>, TranslateError> {
+    let chars: Vec<char> = query.chars().collect();
+    let mut tokens = Vec::new();
+    let mut pos = 0;
+
+    while pos < chars.len() {
+        let c = chars[pos];
+        let next = chars.get(pos + 1).copied();
+        let start = pos;
+
+        if c.is_whitespace() {
+            pos += 1;
+            continue;
+        }
+
+        let (kind, len) = match (c, next) {
+            ('.', _) => (TokenKind::Dot, 1),
+            ('*', _) => (TokenKind::Star, 1),
+            (']', _) => (TokenKind::RBracket, 1),
+            ('{', _) => (TokenKind::LBrace, 1),
+            ('}', _) => (TokenKind::RBrace, 1),
+            ('(', _) => (TokenKind::LParen, 1),
+            (')', _) => (TokenKind::RParen, 1),
+            (',', _) => (TokenKind::Comma, 1),
+            (':', _) => (TokenKind::Colon, 1),
+            ('@', _) => (TokenKind::Current, 1),
+            ('[', Some(']')) => (TokenKind::Flatten, 2),
+            ('[', Some('?')) => (TokenKind::Filter, 2),
+            ('[', _) => (TokenKind::LBracket, 1),
+            ('|', Some('|')) => (TokenKind::Or, 2),
+            ('|', _) => (TokenKind::Pipe, 1),
+            ('&', Some('&')) => (TokenKind::And, 2),
+            ('&', _) => (TokenKind::Expref, 1),
+            ('!', Some('=')) => (TokenKind::Ne, 2),
+            ('!', _) => (TokenKind::Not, 1),
+            ('=', Some('=')) => (TokenKind::Eq, 2),
+            ('<', Some('=')) => (TokenKind::Le, 2),
+            ('<', _) => (TokenKind::Lt, 1),
+            ('>', Some('=')) => (TokenKind::Ge, 2),
+            ('>', _) => (TokenKind::Gt, 1),
+            ('"', _) => {
+                let (text, end) = delimited(&chars, pos, '"')?;
+                let name: String = serde_json::from_str(&format!("\"{}\"", text))
+                    .map_err(|_| TranslateError::new("Invalid quoted identifier", start))?;
+                (TokenKind::QuotedIdentifier(name), end - pos)
+            }
+            ('\'', _) => {
+                let (text, end) = delimited(&chars, pos, '\'')?;
+                let raw = text.replace("\\'", "'");
+                (TokenKind::Literal(Value::String(raw)), end - pos)
+            }
+            ('`', _) => {
+                let (text, end) = delimited(&chars, pos, '`')?;
+                let value: Value = serde_json::from_str(text.replace("\\`", "`").trim())
+                    .map_err(|_| TranslateError::new("Invalid JSON literal", start))?;
+                (TokenKind::Literal(value), end - pos)
+            }
+            (c, _) if c == '-' || c.is_ascii_digit() => {
+                let mut end = pos + 1;
+                while chars.get(end).is_some_and(|c| c.is_ascii_digit()) {
+                    end += 1;
+                }
+                let text: String = chars[pos..end].iter().collect();
+                let number = text
+                    .parse()
+                    .map_err(|_| TranslateError::new("Invalid number", start))?;
+                (TokenKind::Number(number), end - pos)
+            }
+            (c, _) if c.is_ascii_alphabetic() || c == '_' => {
+                let mut end = pos + 1;
+                while chars
+                    .get(end)
+                    .is_some_and(|c| c.is_ascii_alphanumeric() || *c == '_')
+                {
+                    end += 1;
+                }
+                let name: String = chars[pos..end].iter().collect();
+                (TokenKind::Identifier(name), end - pos)
+            }
+            (c, _) => {
+                return Err(TranslateError::new(
+                    format!("Unexpected character '{}'", c),
+                    start,
+                ));
+            }
+        };
+
+        tokens.push(Token {
+            kind,
+            position: start,
+        });
+        pos += len;
+    }
+
+    tokens.push(Token {
+        kind: TokenKind::Eof,
+        position: chars.len(),
+    });
+    Ok(tokens)
+}
+
+/// Text between `delimiter` at `start` and its unescaped closing match
+///
+/// Returns the raw text (escapes untouched) and the offset after the closer.
+fn delimited(
+    chars: &[char],
+    start: usize,
+    delimiter: char,
+) -> Result<(String, usize), TranslateError> {
+    let mut pos = start + 1;
+    while pos < chars.len() {
+        match chars[pos] {
+            '\\' => pos += 2,
+            c if c == delimiter => {
+                return Ok((chars[start + 1..pos].iter().collect(), pos + 1));
+            }
+            _ => pos += 1,
+        }
+    }
+    Err(TranslateError::new(
+        format!("Unterminated {}", delimiter),
+        start,
+    ))
+}
@@ -0,0 +1,221 @@
+//! Tests for JMESPath translation
+
+use super::*;
+use serde_json::json;
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+const PEOPLE: &str = r#"{
+  "people": [
+    {"name": "Alice", "age": 34, "tags": ["admin", "dev"], "address": {"city": "Oslo"}},
+    {"name": "Bob", "age": 27, "tags": [], "address": null},
+    {"name": "Carol", "age": 41, "tags": ["dev"]}
+  ],
+  "owner": {"name": "Dana", "roles": {"a": 1, "b": 2}}
+}"#;
+
+/// Translate `expression` and evaluate it with jq against `input`
+fn eval(expression: &str, input: &str) -> Value {
+    let filter = to_jq(expression).unwrap_or_else(|e| panic!("{}: {}", expression, e));
+    let mut child = Command::new("jq")
+        .arg("-c")
+        .arg(&filter)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(input.as_bytes())
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+    assert!(
+        output.status.success(),
+        "{} -> {}: {}",
+        expression,
+        filter,
+        String::from_utf8_lossy(&output.stderr)
+    );
+    serde_json::from_slice(&output.stdout).unwrap()
+}
+
+#[test]
+fn test_field_and_index_chains() {
+    assert_eq!(eval("owner.name", PEOPLE), json!("Dana"));
+    assert_eq!(eval("people[0].name", PEOPLE), json!("Alice"));
+    assert_eq!(eval("people[-1].name", PEOPLE), json!("Carol"));
+    assert_eq!(eval("people[0].tags[1]", PEOPLE), json!("dev"));
+}
+
+#[test]
+fn test_missing_values_are_null() {
+    assert_eq!(eval("owner.missing.deeper", PEOPLE), json!(null));
+    assert_eq!(eval("people.name", PEOPLE), json!(null));
+    assert_eq!(eval("owner[0]", PEOPLE), json!(null));
+}
+
+#[test]
+fn test_quoted_identifiers() {
+    assert_eq!(
+        eval(
+            r#""content-type".value"#,
+            r#"{"content-type":{"value":"json"}}"#
+        ),
+        json!("json")
+    );
+    assert_eq!(eval(r#"a."if""#, r#"{"a":{"if":1}}"#), json!(1));
+}
+
+#[test]
+fn test_list_projection_drops_nulls() {
+    assert_eq!(eval("people[*].address.city", PEOPLE), json!(["Oslo"]));
+    assert_eq!(
+        eval("people[*].name", PEOPLE),
+        json!(["Alice", "Bob", "Carol"])
+    );
+}
+
+#[test]
+fn test_projection_stops_at_pipe() {
+    assert_eq!(eval("people[*].name | [0]", PEOPLE), json!("Alice"));
+    assert_eq!(eval("people[*].name[0]", PEOPLE), json!([]));
+}
+
+#[test]
+fn test_object_projection() {
+    assert_eq!(eval("owner.roles.*", PEOPLE), json!([1, 2]));
+    assert_eq!(eval("owner.*.a", PEOPLE), json!([1]));
+}
+
+#[test]
+fn test_flatten() {
+    assert_eq!(
+        eval("people[].tags[]", PEOPLE),
+        json!(["admin", "dev", "dev"])
+    );
+    assert_eq!(eval("@[]", "[[1,2],3,[4]]"), json!([1, 2, 3, 4]));
+}
+
+#[test]
+fn test_slices() {
+    assert_eq!(eval("[1:3]", "[0,1,2,3,4]"), json!([1, 2]));
+    assert_eq!(eval("[::2]", "[0,1,2,3,4]"), json!([0, 2, 4]));
+    assert_eq!(eval("[::-1]", "[0,1,2]"), json!([2, 1, 0]));
+    assert_eq!(eval("people[:2].name", PEOPLE), json!(["Alice", "Bob"]));
+}
+
+#[test]
+fn test_filter_projection() {
+    assert_eq!(
+        eval("people[?age > `30`].name", PEOPLE),
+        json!(["Alice", "Carol"])
+    );
+    assert_eq!(eval("people[?name == 'Bob'].age", PEOPLE), json!([27]));
+    assert_eq!(
+        eval("people[?tags].name", PEOPLE),
+        json!(["Alice", "Carol"])
+    );
+    assert_eq!(
+        eval("people[?!address].name", PEOPLE),
+        json!(["Bob", "Carol"])
+    );
+}
+
+#[test]
+fn test_filter_with_logical_operators() {
+    assert_eq!(
+        eval(
+            "people[?age > `30` && contains(tags, 'admin')].name",
+            PEOPLE
+        ),
+        json!(["Alice"])
+    );
+    assert_eq!(
+        eval("people[?age < `30` || name == 'Carol'].name", PEOPLE),
+        json!(["Bob", "Carol"])
+    );
+}
+
+#[test]
+fn test_ordering_comparison_of_non_numbers_is_null() {
+    assert_eq!(eval("a < b", r#"{"a":"x","b":"y"}"#), json!(null));
+    assert_eq!(eval("a < b", r#"{"a":1,"b":2}"#), json!(true));
+}
+
+#[test]
+fn test_or_and_return_operands() {
+    assert_eq!(eval("missing || owner.name", PEOPLE), json!("Dana"));
+    assert_eq!(eval("owner && owner.name", PEOPLE), json!("Dana"));
+    assert_eq!(eval("`[]` && owner.name", PEOPLE), json!([]));
+}
+
+#[test]
+fn test_multi_select() {
+    assert_eq!(eval("people[0].[name, age]", PEOPLE), json!(["Alice", 34]));
+    assert_eq!(
+        eval("people[*].{n: name, city: address.city}", PEOPLE),
+        json!([
+            {"n": "Alice", "city": "Oslo"},
+            {"n": "Bob", "city": null},
+            {"n": "Carol", "city": null}
+        ])
+    );
+    assert_eq!(eval("missing.{a: b}", PEOPLE), json!(null));
+}
+
+#[test]
+fn test_functions() {
+    assert_eq!(eval("length(people)", PEOPLE), json!(3));
+    assert_eq!(eval("max_by(people, &age).name", PEOPLE), json!("Carol"));
+    assert_eq!(
+        eval("sort_by(people, &age)[*].name", PEOPLE),
+        json!(["Bob", "Alice", "Carol"])
+    );
+    assert_eq!(eval("sum(people[*].age)", PEOPLE), json!(102));
+    assert_eq!(eval("avg(`[1, 2, 3]`)", "null"), json!(2));
+    assert_eq!(
+        eval("join(', ', people[*].name)", PEOPLE),
+        json!("Alice, Bob, Carol")
+    );
+    assert_eq!(eval("map(&age, people)", PEOPLE), json!([34, 27, 41]));
+    assert_eq!(eval("keys(owner.roles)", PEOPLE), json!(["a", "b"]));
+    assert_eq!(eval("starts_with(owner.name, 'Da')", PEOPLE), json!(true));
+    assert_eq!(eval("not_null(missing, owner.name)", PEOPLE), json!("Dana"));
+    assert_eq!(
+        eval("merge(`{\"a\": 1}`, `{\"b\": 2}`)", "null"),
+        json!({"a": 1, "b": 2})
+    );
+    assert_eq!(eval("to_string(`1`)", "null"), json!("1"));
+    assert_eq!(eval("to_number('12')", "null"), json!(12));
+    assert_eq!(eval("reverse('abc')", "null"), json!("cba"));
+    assert_eq!(eval("type(people)", PEOPLE), json!("array"));
+}
+
+#[test]
+fn test_current_node_and_pipes() {
+    assert_eq!(eval("owner | name", PEOPLE), json!("Dana"));
+    assert_eq!(eval("@", "[1]"), json!([1]));
+    assert_eq!(
+        eval("people[?length(@.tags) == `1`].name", PEOPLE),
+        json!(["Carol"])
+    );
+}
+
+#[test]
+fn test_errors_report_position() {
+    let err = to_jq("people[?age >").unwrap_err();
+    assert_eq!(err.position, 13);
+
+    let err = to_jq("nope(a)").unwrap_err();
+    assert_eq!(err.message, "Unknown function 'nope'");
+    assert_eq!(err.position, 0);
+
+    assert!(to_jq("length(a, b)").is_err());
+    assert!(to_jq("sort_by(people, age)").is_err());
+    assert!(to_jq("a.").is_err());
+    assert!(to_jq("`{bad json`").is_err());
+    assert!(to_jq("a b").is_err());
+}
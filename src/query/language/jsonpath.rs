@@ -0,0 +1,542 @@
+//! JSONPath → jq translation
+//!
+//! Supports the commonly used subset of JSONPath (RFC 9535):
+//! - Root `$`, child `.name` / `['name']`, wildcard `*`, descendants `..`
+//! - Array indices, slices `[start:end:step]` and unions `[0,2]` / `['a','b']`
+//! - Filters `[?(@.price < 10 && @.tags)]` with comparisons, `&&`, `||`, `!`
+//!   and the `length`, `count`, `match`, `search` and `value` functions
+//!
+//! The result is the list of matched nodes, so the generated filter collects
+//! its outputs into an array.
+
+use super::{TranslateError, jq_field, json_string};
+
+/// Translate a JSONPath expression into an equivalent jq filter
+pub fn to_jq(query: &str) -> Result<String, TranslateError> {
+    let mut parser = Parser::new(query);
+    parser.skip_whitespace();
+
+    match parser.peek() {
+        Some('$') => parser.pos += 1,
+        // Leniently accept paths that omit the root, like `.store.book`
+        Some('.') | Some('[') => {}
+        _ => return Err(parser.error("JSONPath must start with '$'")),
+    }
+
+    let steps = parser.parse_segments()?;
+    parser.skip_whitespace();
+    if parser.peek().is_some() {
+        return Err(parser.error("Unexpected character"));
+    }
+
+    let body = if steps.is_empty() {
+        ".".to_string()
+    } else {
+        steps.join(" | ")
+    };
+    let program = format!("[{}]", body);
+
+    if parser.uses_root {
+        Ok(format!(". as $__root | {}", program))
+    } else {
+        Ok(program)
+    }
+}
+
+/// Filter operand, tracked so it can be coerced to the right jq form
+enum Operand {
+    /// Node list from a relative or absolute path (a jq generator)
+    Nodes(String),
+    /// Single value (literals and value-returning functions)
+    Value(String),
+    /// Boolean (comparisons, logical operators, match/search)
+    Logical(String),
+}
+
+impl Operand {
+    /// jq expression producing the operand's value(s)
+    fn into_value(self) -> String {
+        match self {
+            Operand::Nodes(s) | Operand::Value(s) | Operand::Logical(s) => s,
+        }
+    }
+
+    /// jq expression producing a single boolean
+    ///
+    /// A bare path is an existence test.
+    fn into_logical(self) -> String {
+        match self {
+            Operand::Nodes(s) => format!("any({}; true)", s),
+            Operand::Value(s) => format!("any({}; .)", s),
+            Operand::Logical(s) => s,
+        }
+    }
+}
+
+struct Parser {
+    chars: Vec<char>,
+    pos: usize,
+    /// Whether a filter refers to the document root (`$`)
+    uses_root: bool,
+}
+
+impl Parser {
+    fn new(query: &str) -> Self {
+        Self {
+            chars: query.chars().collect(),
+            pos: 0,
+            uses_root: false,
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn peek_at(&self, offset: usize) -> Option<char> {
+        self.chars.get(self.pos + offset).copied()
+    }
+
+    fn error(&self, message: &str) -> TranslateError {
+        TranslateError::new(message, self.pos.min(self.chars.len().saturating_sub(1)))
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.pos += 1;
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), TranslateError> {
+        self.skip_whitespace();
+        if self.peek() == Some(expected) {
+            self.pos += 1;
+            Ok(())
+        } else {
+            Err(self.error(&format!("Expected '{}'", expected)))
+        }
+    }
+
+    /// Parse path segments, returning one jq pipeline stage per segment
+    fn parse_segments(&mut self) -> Result<Vec<String>, TranslateError> {
+        let mut steps = Vec::new();
+        loop {
+            match (self.peek(), self.peek_at(1)) {
+                (Some('.'), Some('.')) => {
+                    self.pos += 2;
+                    let selector = if self.peek() == Some('[') {
+                        self.parse_bracket()?
+                    } else {
+                        self.parse_dot_selector()?
+                    };
+                    steps.push(format!(".. | {}", selector));
+                }
+                (Some('.'), _) => {
+                    self.pos += 1;
+                    steps.push(self.parse_dot_selector()?);
+                }
+                (Some('['), _) => steps.push(self.parse_bracket()?),
+                _ => return Ok(steps),
+            }
+        }
+    }
+
+    /// Selector after `.` or `..`: a member name or `*`
+    fn parse_dot_selector(&mut self) -> Result<String, TranslateError> {
+        if self.peek() == Some('*') {
+            self.pos += 1;
+            return Ok(wildcard());
+        }
+        let name = self.parse_name();
+        if name.is_empty() {
+            return Err(self.error("Expected a member name or '*'"));
+        }
+        Ok(member(&name))
+    }
+
+    fn parse_name(&mut self) -> String {
+        let start = self.pos;
+        while self
+            .peek()
+            .is_some_and(|c| c.is_alphanumeric() || c == '_' || c == '-' || !c.is_ascii())
+        {
+            self.pos += 1;
+        }
+        self.chars[start..self.pos].iter().collect()
+    }
+
+    /// Bracketed selector list: `[...]`
+    fn parse_bracket(&mut self) -> Result<String, TranslateError> {
+        self.expect('[')?;
+        let mut selectors = vec![self.parse_selector()?];
+        loop {
+            self.skip_whitespace();
+            match self.peek() {
+                Some(',') => {
+                    self.pos += 1;
+                    selectors.push(self.parse_selector()?);
+                }
+                Some(']') => {
+                    self.pos += 1;
+                    break;
+                }
+                _ => return Err(self.error("Expected ',' or ']'")),
+            }
+        }
+
+        if selectors.len() == 1 {
+            Ok(selectors.remove(0))
+        } else {
+            let union: Vec<String> = selectors.iter().map(|s| format!("({})", s)).collect();
+            Ok(format!("({})", union.join(", ")))
+        }
+    }
+
+    fn parse_selector(&mut self) -> Result<String, TranslateError> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some('*') => {
+                self.pos += 1;
+                Ok(wildcard())
+            }
+            Some('\'') | Some('"') => {
+                let name = self.parse_string()?;
+                Ok(member(&name))
+            }
+            Some('?') => {
+                self.pos += 1;
+                self.skip_whitespace();
+                // Both `?expr` (RFC 9535) and `?(expr)` (original syntax)
+                let condition = self.parse_or()?.into_logical();
+                Ok(format!(".[]? | select({})", condition))
+            }
+            Some(c) if c == '-' || c == ':' || c.is_ascii_digit() => self.parse_index_or_slice(),
+            _ => Err(self.error("Expected a selector")),
+        }
+    }
+
+    fn parse_index_or_slice(&mut self) -> Result<String, TranslateError> {
+        let start = self.parse_optional_int()?;
+        self.skip_whitespace();
+        if self.peek() != Some(':') {
+            let index = start.ok_or_else(|| self.error("Expected an index"))?;
+            return Ok(index_selector(index));
+        }
+
+        self.pos += 1;
+        let end = self.parse_optional_int()?;
+        self.skip_whitespace();
+        let step = if self.peek() == Some(':') {
+            self.pos += 1;
+            self.parse_optional_int()?
+        } else {
+            None
+        };
+        slice_selector(start, end, step.unwrap_or(1)).ok_or_else(|| {
+            self.error("Negative slice steps are only supported without start and end")
+        })
+    }
+
+    fn parse_optional_int(&mut self) -> Result<Option<i64>, TranslateError> {
+        self.skip_whitespace();
+        let start = self.pos;
+        if self.peek() == Some('-') {
+            self.pos += 1;
+        }
+        while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+            self.pos += 1;
+        }
+        if self.pos == start {
+            return Ok(None);
+        }
+        let text: String = self.chars[start..self.pos].iter().collect();
+        text.parse()
+            .map(Some)
+            .map_err(|_| TranslateError::new("Invalid integer", start))
+    }
+
+    /// Quoted string with either quote style, returned unescaped
+    fn parse_string(&mut self) -> Result<String, TranslateError> {
+        let start = self.pos;
+        let quote = self.chars[self.pos];
+        self.pos += 1;
+        let mut value = String::new();
+        loop {
+            match self.peek() {
+                None => return Err(TranslateError::new("Unterminated string", start)),
+                Some(c) if c == quote => {
+                    self.pos += 1;
+                    return Ok(value);
+                }
+                Some('\\') => {
+                    let escaped = self
+                        .peek_at(1)
+                        .ok_or_else(|| TranslateError::new("Unterminated string", start))?;
+                    value.push(match escaped {
+                        'n' => '\n',
+                        't' => '\t',
+                        'r' => '\r',
+                        'b' => '\u{8}',
+                        'f' => '\u{c}',
+                        other => other,
+                    });
+                    self.pos += 2;
+                }
+                Some(c) => {
+                    value.push(c);
+                    self.pos += 1;
+                }
+            }
+        }
+    }
+
+    fn parse_or(&mut self) -> Result<Operand, TranslateError> {
+        let mut left = self.parse_and()?;
+        while self.consume("||") {
+            let right = self.parse_and()?;
+            left = Operand::Logical(format!(
+                "({} or {})",
+                left.into_logical(),
+                right.into_logical()
+            ));
+        }
+        Ok(left)
+    }
+
+    fn parse_and(&mut self) -> Result<Operand, TranslateError> {
+        let mut left = self.parse_unary()?;
+        while self.consume("&&") {
+            let right = self.parse_unary()?;
+            left = Operand::Logical(format!(
+                "({} and {})",
+                left.into_logical(),
+                right.into_logical()
+            ));
+        }
+        Ok(left)
+    }
+
+    fn parse_unary(&mut self) -> Result<Operand, TranslateError> {
+        self.skip_whitespace();
+        if self.peek() == Some('!') && self.peek_at(1) != Some('=') {
+            self.pos += 1;
+            let operand = self.parse_unary()?;
+            return Ok(Operand::Logical(format!(
+                "({} | not)",
+                operand.into_logical()
+            )));
+        }
+        self.parse_comparison()
+    }
+
+    fn parse_comparison(&mut self) -> Result<Operand, TranslateError> {
+        let left = self.parse_operand()?;
+        self.skip_whitespace();
+
+        let op = ["==", "!=", "<=", ">=", "<", ">"]
+            .into_iter()
+            .find(|op| self.consume(op));
+        let Some(op) = op else {
+            return Ok(left);
+        };
+
+        let right = self.parse_operand()?;
+        let (left, right) = (left.into_value(), right.into_value());
+        let comparison = if matches!(op, "==" | "!=") {
+            format!("$l {} $r", op)
+        } else {
+            // Ordering only applies between two numbers or two strings
+            format!(
+                "($l | type) == ($r | type) and ($l | type | IN(\"number\", \"string\")) and $l {} $r",
+                op
+            )
+        };
+        Ok(Operand::Logical(format!(
+            "any(({}) as $l | ({}) as $r | {}; .)",
+            left, right, comparison
+        )))
+    }
+
+    fn parse_operand(&mut self) -> Result<Operand, TranslateError> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some('(') => {
+                self.pos += 1;
+                let inner = self.parse_or()?;
+                self.expect(')')?;
+                Ok(inner)
+            }
+            Some('@') => {
+                self.pos += 1;
+                let steps = self.parse_segments()?;
+                Ok(Operand::Nodes(relative_path(".", &steps)))
+            }
+            Some('$') => {
+                self.pos += 1;
+                self.uses_root = true;
+                let steps = self.parse_segments()?;
+                Ok(Operand::Nodes(relative_path("$__root", &steps)))
+            }
+            Some('\'') | Some('"') => {
+                let value = self.parse_string()?;
+                Ok(Operand::Value(json_string(&value)))
+            }
+            Some(c) if c == '-' || c.is_ascii_digit() => self.parse_number(),
+            Some(c) if c.is_ascii_alphabetic() => self.parse_word(),
+            _ => Err(self.error("Expected a filter expression")),
+        }
+    }
+
+    fn parse_number(&mut self) -> Result<Operand, TranslateError> {
+        let start = self.pos;
+        if self.peek() == Some('-') {
+            self.pos += 1;
+        }
+        while self
+            .peek()
+            .is_some_and(|c| c.is_ascii_digit() || matches!(c, '.' | 'e' | 'E' | '+' | '-'))
+        {
+            self.pos += 1;
+        }
+        let text: String = self.chars[start..self.pos].iter().collect();
+        text.parse::<f64>()
+            .map(|_| Operand::Value(text))
+            .map_err(|_| TranslateError::new("Invalid number", start))
+    }
+
+    /// Literal keyword or function call
+    fn parse_word(&mut self) -> Result<Operand, TranslateError> {
+        let start = self.pos;
+        while self
+            .peek()
+            .is_some_and(|c| c.is_ascii_alphanumeric() || c == '_')
+        {
+            self.pos += 1;
+        }
+        let word: String = self.chars[start..self.pos].iter().collect();
+        match word.as_str() {
+            "true" | "false" | "null" => return Ok(Operand::Value(word)),
+            _ => {}
+        }
+
+        self.skip_whitespace();
+        if self.peek() != Some('(') {
+            return Err(TranslateError::new(
+                format!("Unknown literal '{}'", word),
+                start,
+            ));
+        }
+        self.pos += 1;
+        let mut args = Vec::new();
+        self.skip_whitespace();
+        if self.peek() != Some(')') {
+            args.push(self.parse_or()?);
+            while self.consume(",") {
+                args.push(self.parse_or()?);
+            }
+        }
+        self.expect(')')?;
+        function_call(&word, args).ok_or_else(|| {
+            TranslateError::new(
+                format!("Unknown function or wrong arguments: '{}'", word),
+                start,
+            )
+        })
+    }
+
+    /// Consume `token` (after optional whitespace) if it comes next
+    fn consume(&mut self, token: &str) -> bool {
+        self.skip_whitespace();
+        let matches = token
+            .chars()
+            .enumerate()
+            .all(|(i, c)| self.peek_at(i) == Some(c));
+        if matches {
+            self.pos += token.chars().count();
+        }
+        matches
+    }
+}
+
+/// Child member selector: nothing for non-objects or missing keys
+fn member(name: &str) -> String {
+    format!(
+        "objects | select(has({})) | {}",
+        json_string(name),
+        jq_field(name)
+    )
+}
+
+/// All children of objects and arrays
+fn wildcard() -> String {
+    ".[]?".to_string()
+}
+
+/// Array element selector: nothing when out of range
+fn index_selector(index: i64) -> String {
+    if index == -1 {
+        "arrays | .[-1:][]".to_string()
+    } else {
+        format!("arrays | .[{}:{}][]", index, index + 1)
+    }
+}
+
+/// Array slice selector, or `None` for unsupported negative steps
+fn slice_selector(start: Option<i64>, end: Option<i64>, step: i64) -> Option<String> {
+    let bound = |b: Option<i64>| b.map(|v| v.to_string()).unwrap_or_default();
+    let slice = match (start, end) {
+        (None, None) => String::new(),
+        _ => format!(" | .[{}:{}]", bound(start), bound(end)),
+    };
+
+    match step {
+        1 if slice.is_empty() => Some("arrays | .[]".to_string()),
+        1 => Some(format!("arrays{}[]", slice)),
+        0 => Some("empty".to_string()),
+        s if s > 1 => Some(format!("arrays{} | .[range(0; length; {})]", slice, s)),
+        s if start.is_none() && end.is_none() => {
+            Some(format!("arrays | reverse | .[range(0; length; {})]", -s))
+        }
+        _ => None,
+    }
+}
+
+/// Path expression evaluated from `base` (`.` for `@`, `$__root` for `$`)
+fn relative_path(base: &str, steps: &[String]) -> String {
+    if steps.is_empty() {
+        base.to_string()
+    } else {
+        format!("({} | {})", base, steps.join(" | "))
+    }
+}
+
+/// Translate an RFC 9535 function extension call
+fn function_call(name: &str, args: Vec<Operand>) -> Option<Operand> {
+    let mut args = args.into_iter().map(Operand::into_value);
+    let (first, second) = (args.next(), args.next());
+    if args.next().is_some() {
+        return None;
+    }
+
+    match (name, first, second) {
+        ("length", Some(value), None) => Some(Operand::Value(format!(
+            "(({}) | select(type | IN(\"string\", \"array\", \"object\")) | length)",
+            value
+        ))),
+        ("count", Some(nodes), None) => Some(Operand::Value(format!("([{}] | length)", nodes))),
+        ("value", Some(nodes), None) => Some(Operand::Value(format!("first({})", nodes))),
+        ("match", Some(value), Some(pattern)) => Some(Operand::Logical(format!(
+            "any(({}) as $p | ({}) | strings | test(\"^(?:\" + $p + \")$\"); .)",
+            pattern, value
+        ))),
+        ("search", Some(value), Some(pattern)) => Some(Operand::Logical(format!(
+            "any(({}) as $p | ({}) | strings | test($p); .)",
+            pattern, value
+        ))),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+#[path = "jsonpath_tests.rs"]
+mod jsonpath_tests;
@@ -0,0 +1,235 @@
+//! Tests for JSONPath translation
+
+use super::*;
+use serde_json::{Value, json};
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+const STORE: &str = r#"{
+  "store": {
+    "book": [
+      {"category": "reference", "author": "Nigel Rees", "title": "Sayings of the Century", "price": 8.95},
+      {"category": "fiction", "author": "Evelyn Waugh", "title": "Sword of Honour", "price": 12.99},
+      {"category": "fiction", "author": "Herman Melville", "title": "Moby Dick", "isbn": "0-553-21311-3", "price": 8.99},
+      {"category": "fiction", "author": "J. R. R. Tolkien", "title": "The Lord of the Rings", "isbn": "0-395-19395-8", "price": 22.99}
+    ],
+    "bicycle": {"color": "red", "price": 399}
+  },
+  "limit": 10
+}"#;
+
+/// Translate `path` and evaluate it with jq against `input`
+fn eval(path: &str, input: &str) -> Value {
+    let filter = to_jq(path).unwrap_or_else(|e| panic!("{}: {}", path, e));
+    let mut child = Command::new("jq")
+        .arg("-c")
+        .arg(&filter)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(input.as_bytes())
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+    assert!(
+        output.status.success(),
+        "{} -> {}: {}",
+        path,
+        filter,
+        String::from_utf8_lossy(&output.stderr)
+    );
+    serde_json::from_slice(&output.stdout).unwrap()
+}
+
+#[test]
+fn test_root_returns_whole_document() {
+    assert_eq!(eval("$", r#"{"a":1}"#), json!([{"a": 1}]));
+}
+
+#[test]
+fn test_child_members() {
+    assert_eq!(eval("$.store.bicycle.color", STORE), json!(["red"]));
+    assert_eq!(
+        eval("$['store']['bicycle']['color']", STORE),
+        json!(["red"])
+    );
+}
+
+#[test]
+fn test_missing_member_yields_nothing() {
+    assert_eq!(eval("$.store.missing", STORE), json!([]));
+    assert_eq!(eval("$.limit.missing", STORE), json!([]));
+}
+
+#[test]
+fn test_wildcard_over_array() {
+    assert_eq!(
+        eval("$.store.book[*].author", STORE),
+        json!([
+            "Nigel Rees",
+            "Evelyn Waugh",
+            "Herman Melville",
+            "J. R. R. Tolkien"
+        ])
+    );
+}
+
+#[test]
+fn test_descendant_member() {
+    assert_eq!(
+        eval("$..price", STORE),
+        json!([8.95, 12.99, 8.99, 22.99, 399])
+    );
+    assert_eq!(eval("$..*", r#"{"a":{"b":1}}"#), json!([{"b": 1}, 1]));
+}
+
+#[test]
+fn test_indices_and_negative_index() {
+    assert_eq!(
+        eval("$.store.book[0].title", STORE),
+        json!(["Sayings of the Century"])
+    );
+    assert_eq!(
+        eval("$.store.book[-1].title", STORE),
+        json!(["The Lord of the Rings"])
+    );
+    assert_eq!(eval("$.store.book[10]", STORE), json!([]));
+}
+
+#[test]
+fn test_slices() {
+    assert_eq!(eval("$[1:3]", "[0,1,2,3,4]"), json!([1, 2]));
+    assert_eq!(eval("$[:2]", "[0,1,2,3,4]"), json!([0, 1]));
+    assert_eq!(eval("$[-2:]", "[0,1,2,3,4]"), json!([3, 4]));
+    assert_eq!(eval("$[::2]", "[0,1,2,3,4]"), json!([0, 2, 4]));
+    assert_eq!(eval("$[::-1]", "[0,1,2]"), json!([2, 1, 0]));
+}
+
+#[test]
+fn test_unions() {
+    assert_eq!(eval("$[0,2]", "[\"a\",\"b\",\"c\"]"), json!(["a", "c"]));
+    assert_eq!(
+        eval("$.store.bicycle['color','price']", STORE),
+        json!(["red", 399])
+    );
+}
+
+#[test]
+fn test_filter_comparison() {
+    assert_eq!(
+        eval("$.store.book[?(@.price < 10)].title", STORE),
+        json!(["Sayings of the Century", "Moby Dick"])
+    );
+    assert_eq!(
+        eval("$.store.book[?@.category == 'reference'].author", STORE),
+        json!(["Nigel Rees"])
+    );
+}
+
+#[test]
+fn test_filter_existence_and_negation() {
+    assert_eq!(
+        eval("$.store.book[?(@.isbn)].title", STORE),
+        json!(["Moby Dick", "The Lord of the Rings"])
+    );
+    assert_eq!(
+        eval("$.store.book[?(!@.isbn)].title", STORE),
+        json!(["Sayings of the Century", "Sword of Honour"])
+    );
+}
+
+#[test]
+fn test_filter_logical_operators() {
+    assert_eq!(
+        eval(
+            "$.store.book[?(@.price > 10 && @.category == 'fiction')].title",
+            STORE
+        ),
+        json!(["Sword of Honour", "The Lord of the Rings"])
+    );
+    assert_eq!(
+        eval("$.store.book[?(@.price < 9 || @.price > 20)].price", STORE),
+        json!([8.95, 8.99, 22.99])
+    );
+}
+
+#[test]
+fn test_filter_against_root() {
+    assert_eq!(
+        eval("$.store.book[?(@.price < $.limit)].price", STORE),
+        json!([8.95, 8.99])
+    );
+}
+
+#[test]
+fn test_filter_ordering_ignores_mismatched_types() {
+    assert_eq!(
+        eval("$[?(@.a < 5)]", r#"[{"a":1},{"a":"x"},{"a":null},{}]"#),
+        json!([{"a": 1}])
+    );
+}
+
+#[test]
+fn test_filter_functions() {
+    assert_eq!(
+        eval("$.store.book[?(length(@.title) < 10)].title", STORE),
+        json!(["Moby Dick"])
+    );
+    assert_eq!(
+        eval("$.store.book[?match(@.author, 'J.*')].author", STORE),
+        json!(["J. R. R. Tolkien"])
+    );
+    assert_eq!(
+        eval("$.store.book[?search(@.title, 'Honour')].author", STORE),
+        json!(["Evelyn Waugh"])
+    );
+    assert_eq!(
+        eval("$[?count(@.*) > 1]", r#"[{"a":1},{"a":1,"b":2}]"#),
+        json!([{"a": 1, "b": 2}])
+    );
+}
+
+#[test]
+fn test_quoted_names_with_special_characters() {
+    assert_eq!(
+        eval(
+            "$['content-type', 'a b']",
+            r#"{"content-type":"json","a b":1}"#
+        ),
+        json!(["json", 1])
+    );
+    assert_eq!(eval("$.if", r#"{"if":true}"#), json!([true]));
+}
+
+#[test]
+fn test_path_without_root_is_accepted() {
+    assert_eq!(eval(".store.bicycle.color", STORE), json!(["red"]));
+}
+
+#[test]
+fn test_invalid_paths_report_position() {
+    let err = to_jq("store").unwrap_err();
+    assert_eq!(err.position, 0);
+
+    let err = to_jq("$.store[").unwrap_err();
+    assert_eq!(err.position, 7);
+
+    assert!(to_jq("$.a[?(@.b <)]").is_err());
+    assert!(to_jq("$[?(unknown(@))]").is_err());
+    assert!(to_jq("$['unterminated]").is_err());
+}
+
+#[test]
+fn test_root_binding_only_when_used() {
+    assert!(!to_jq("$.a").unwrap().contains("$__root"));
+    assert!(
+        to_jq("$.a[?(@.b == $.c)]")
+            .unwrap()
+            .starts_with(". as $__root |")
+    );
+}
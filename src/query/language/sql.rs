@@ -0,0 +1,567 @@
+//! SQL → jq translation
+//!
+//! Experimental mode for exploring arrays of objects with familiar SQL:
+//!
+//! ```text
+//! SELECT name, age FROM .users WHERE age > 30 ORDER BY age DESC LIMIT 5
+//! ```
+//!
+//! Supports `SELECT [DISTINCT]` with aliases and aggregates (`COUNT`, `SUM`,
+//! `AVG`, `MIN`, `MAX`), `FROM` a path into the input, `WHERE`, `GROUP BY`,
+//! `HAVING`, `ORDER BY` and `LIMIT`/`OFFSET`. The result is an array of rows.
+
+mod codegen;
+mod lexer;
+
+use super::TranslateError;
+use lexer::{Token, TokenKind};
+
+/// Translate a SQL query into an equivalent jq filter
+pub fn to_jq(query: &str) -> Result<String, TranslateError> {
+    let tokens = lexer::tokenize(query)?;
+    let mut parser = Parser {
+        tokens,
+        index: 0,
+        source: query.chars().collect(),
+    };
+    let select = parser.select()?;
+    if parser.kind() != &TokenKind::Eof {
+        return Err(parser.error("Unexpected token"));
+    }
+    codegen::generate(&select)
+}
+
+/// Words that cannot be used as bare column names
+const RESERVED: &[&str] = &[
+    "SELECT", "DISTINCT", "FROM", "WHERE", "GROUP", "BY", "HAVING", "ORDER", "ASC", "DESC",
+    "LIMIT", "OFFSET", "AS", "AND", "OR", "NOT", "LIKE", "IN", "IS", "NULL", "BETWEEN", "TRUE",
+    "FALSE",
+];
+
+#[derive(Debug, Clone, PartialEq)]
+struct Select {
+    distinct: bool,
+    /// Empty for `SELECT *`
+    items: Vec<SelectItem>,
+    /// Path segments of the `FROM` source (empty for `.`)
+    from: Vec<String>,
+    filter: Option<Expr>,
+    group_by: Vec<Expr>,
+    having: Option<Expr>,
+    order_by: Vec<(Expr, bool)>,
+    limit: Option<u64>,
+    offset: Option<u64>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct SelectItem {
+    expr: Expr,
+    /// Output key: the alias, column name or expression text
+    name: String,
+    /// Explicit `AS` alias, which ORDER BY may refer to
+    alias: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Expr {
+    Column(Vec<String>),
+    /// jq literal text
+    Literal(String),
+    Not(Box<Expr>),
+    Negate(Box<Expr>),
+    Binary(BinaryOp, Box<Expr>, Box<Expr>),
+    Like {
+        expr: Box<Expr>,
+        pattern: String,
+        negated: bool,
+    },
+    In {
+        expr: Box<Expr>,
+        list: Vec<Expr>,
+        negated: bool,
+    },
+    IsNull {
+        expr: Box<Expr>,
+        negated: bool,
+    },
+    Between {
+        expr: Box<Expr>,
+        low: Box<Expr>,
+        high: Box<Expr>,
+        negated: bool,
+    },
+    Function {
+        /// Upper-cased function name
+        name: String,
+        args: Vec<Expr>,
+        /// `COUNT(*)`
+        star: bool,
+        /// `COUNT(DISTINCT x)`
+        distinct: bool,
+        position: usize,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum BinaryOp {
+    And,
+    Or,
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+    Modulo,
+    Concat,
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    index: usize,
+    source: Vec<char>,
+}
+
+impl Parser {
+    fn kind(&self) -> &TokenKind {
+        &self.tokens[self.index].kind
+    }
+
+    fn token(&self) -> &Token {
+        &self.tokens[self.index]
+    }
+
+    fn advance(&mut self) -> Token {
+        let token = self.tokens[self.index].clone();
+        if self.index < self.tokens.len() - 1 {
+            self.index += 1;
+        }
+        token
+    }
+
+    fn error(&self, message: &str) -> TranslateError {
+        TranslateError::new(message, self.token().start)
+    }
+
+    /// Whether the current token is `keyword` (case-insensitive)
+    fn at_keyword(&self, keyword: &str) -> bool {
+        matches!(self.kind(), TokenKind::Word(w) if w.eq_ignore_ascii_case(keyword))
+    }
+
+    fn eat_keyword(&mut self, keyword: &str) -> bool {
+        let found = self.at_keyword(keyword);
+        if found {
+            self.advance();
+        }
+        found
+    }
+
+    fn expect_keyword(&mut self, keyword: &str) -> Result<(), TranslateError> {
+        if self.eat_keyword(keyword) {
+            Ok(())
+        } else {
+            Err(self.error(&format!("Expected {}", keyword)))
+        }
+    }
+
+    fn eat_symbol(&mut self, symbol: &str) -> bool {
+        let found = matches!(self.kind(), TokenKind::Symbol(s) if *s == symbol);
+        if found {
+            self.advance();
+        }
+        found
+    }
+
+    fn expect_symbol(&mut self, symbol: &str) -> Result<(), TranslateError> {
+        if self.eat_symbol(symbol) {
+            Ok(())
+        } else {
+            Err(self.error(&format!("Expected '{}'", symbol)))
+        }
+    }
+
+    fn select(&mut self) -> Result<Select, TranslateError> {
+        self.expect_keyword("SELECT")?;
+        let distinct = self.eat_keyword("DISTINCT");
+
+        let items = if self.eat_symbol("*") {
+            Vec::new()
+        } else {
+            let mut items = vec![self.select_item()?];
+            while self.eat_symbol(",") {
+                items.push(self.select_item()?);
+            }
+            items
+        };
+
+        let from = if self.eat_keyword("FROM") {
+            self.source_path()?
+        } else {
+            Vec::new()
+        };
+
+        let filter = if self.eat_keyword("WHERE") {
+            Some(self.expression()?)
+        } else {
+            None
+        };
+
+        let mut group_by = Vec::new();
+        if self.eat_keyword("GROUP") {
+            self.expect_keyword("BY")?;
+            group_by.push(self.expression()?);
+            while self.eat_symbol(",") {
+                group_by.push(self.expression()?);
+            }
+        }
+
+        let having = if self.eat_keyword("HAVING") {
+            Some(self.expression()?)
+        } else {
+            None
+        };
+
+        let mut order_by = Vec::new();
+        if self.eat_keyword("ORDER") {
+            self.expect_keyword("BY")?;
+            loop {
+                let expr = self.expression()?;
+                let descending = if self.eat_keyword("DESC") {
+                    true
+                } else {
+                    self.eat_keyword("ASC");
+                    false
+                };
+                order_by.push((expr, descending));
+                if !self.eat_symbol(",") {
+                    break;
+                }
+            }
+        }
+
+        let limit = if self.eat_keyword("LIMIT") {
+            Some(self.count()?)
+        } else {
+            None
+        };
+        let offset = if self.eat_keyword("OFFSET") {
+            Some(self.count()?)
+        } else {
+            None
+        };
+
+        Ok(Select {
+            distinct,
+            items,
+            from,
+            filter,
+            group_by,
+            having,
+            order_by,
+            limit,
+            offset,
+        })
+    }
+
+    fn select_item(&mut self) -> Result<SelectItem, TranslateError> {
+        let start = self.token().start;
+        let expr = self.expression()?;
+        let end = self.tokens[self.index.saturating_sub(1)].end;
+
+        let alias = if self.eat_keyword("AS") {
+            Some(self.identifier()?)
+        } else {
+            match self.kind() {
+                // Implicit alias: `SELECT count(*) total`
+                TokenKind::Word(word) if !is_reserved(word) => Some(self.identifier()?),
+                TokenKind::QuotedIdentifier(_) => Some(self.identifier()?),
+                _ => None,
+            }
+        };
+
+        let name = match (&alias, &expr) {
+            (Some(alias), _) => alias.clone(),
+            (None, Expr::Column(path)) => path.last().cloned().unwrap_or_default(),
+            (None, _) => self.source[start..end].iter().collect(),
+        };
+        Ok(SelectItem { expr, name, alias })
+    }
+
+    /// `FROM` source: `.`, `.items`, `items` or `.data.items`
+    fn source_path(&mut self) -> Result<Vec<String>, TranslateError> {
+        let leading_dot = self.eat_symbol(".");
+        let mut path = Vec::new();
+        let at_name = match self.kind() {
+            TokenKind::Word(word) => !is_reserved(word),
+            TokenKind::QuotedIdentifier(_) => true,
+            _ => false,
+        };
+        if !at_name {
+            return if leading_dot {
+                Ok(path)
+            } else {
+                Err(self.error("Expected a source path like '.' or '.items'"))
+            };
+        }
+        path.push(self.identifier()?);
+        while self.eat_symbol(".") {
+            path.push(self.identifier()?);
+        }
+        Ok(path)
+    }
+
+    fn identifier(&mut self) -> Result<String, TranslateError> {
+        match self.kind().clone() {
+            TokenKind::Word(word) if !is_reserved(&word) => {
+                self.advance();
+                Ok(word)
+            }
+            TokenKind::QuotedIdentifier(name) => {
+                self.advance();
+                Ok(name)
+            }
+            _ => Err(self.error("Expected an identifier")),
+        }
+    }
+
+    fn count(&mut self) -> Result<u64, TranslateError> {
+        match self.kind().clone() {
+            TokenKind::Number(text) => {
+                let value = text
+                    .parse()
+                    .map_err(|_| self.error("Expected a whole number"))?;
+                self.advance();
+                Ok(value)
+            }
+            _ => Err(self.error("Expected a number")),
+        }
+    }
+
+    fn expression(&mut self) -> Result<Expr, TranslateError> {
+        let mut left = self.and_expression()?;
+        while self.eat_keyword("OR") {
+            let right = self.and_expression()?;
+            left = Expr::Binary(BinaryOp::Or, Box::new(left), Box::new(right));
+        }
+        Ok(left)
+    }
+
+    fn and_expression(&mut self) -> Result<Expr, TranslateError> {
+        let mut left = self.not_expression()?;
+        while self.eat_keyword("AND") {
+            let right = self.not_expression()?;
+            left = Expr::Binary(BinaryOp::And, Box::new(left), Box::new(right));
+        }
+        Ok(left)
+    }
+
+    fn not_expression(&mut self) -> Result<Expr, TranslateError> {
+        if self.eat_keyword("NOT") {
+            return Ok(Expr::Not(Box::new(self.not_expression()?)));
+        }
+        self.predicate()
+    }
+
+    fn predicate(&mut self) -> Result<Expr, TranslateError> {
+        let left = self.concat()?;
+
+        let comparison = match self.kind() {
+            TokenKind::Symbol("=") => Some(BinaryOp::Eq),
+            TokenKind::Symbol("!=") | TokenKind::Symbol("<>") => Some(BinaryOp::Ne),
+            TokenKind::Symbol("<") => Some(BinaryOp::Lt),
+            TokenKind::Symbol("<=") => Some(BinaryOp::Le),
+            TokenKind::Symbol(">") => Some(BinaryOp::Gt),
+            TokenKind::Symbol(">=") => Some(BinaryOp::Ge),
+            _ => None,
+        };
+        if let Some(op) = comparison {
+            self.advance();
+            let right = self.concat()?;
+            return Ok(Expr::Binary(op, Box::new(left), Box::new(right)));
+        }
+
+        if self.eat_keyword("IS") {
+            let negated = self.eat_keyword("NOT");
+            self.expect_keyword("NULL")?;
+            return Ok(Expr::IsNull {
+                expr: Box::new(left),
+                negated,
+            });
+        }
+
+        let negated = self.eat_keyword("NOT");
+        if self.eat_keyword("LIKE") {
+            let TokenKind::String(pattern) = self.kind().clone() else {
+                return Err(self.error("LIKE requires a string pattern"));
+            };
+            self.advance();
+            return Ok(Expr::Like {
+                expr: Box::new(left),
+                pattern,
+                negated,
+            });
+        }
+        if self.eat_keyword("IN") {
+            self.expect_symbol("(")?;
+            let mut list = vec![self.concat()?];
+            while self.eat_symbol(",") {
+                list.push(self.concat()?);
+            }
+            self.expect_symbol(")")?;
+            return Ok(Expr::In {
+                expr: Box::new(left),
+                list,
+                negated,
+            });
+        }
+        if self.eat_keyword("BETWEEN") {
+            let low = self.concat()?;
+            self.expect_keyword("AND")?;
+            let high = self.concat()?;
+            return Ok(Expr::Between {
+                expr: Box::new(left),
+                low: Box::new(low),
+                high: Box::new(high),
+                negated,
+            });
+        }
+        if negated {
+            return Err(self.error("Expected LIKE, IN or BETWEEN after NOT"));
+        }
+        Ok(left)
+    }
+
+    fn concat(&mut self) -> Result<Expr, TranslateError> {
+        let mut left = self.additive()?;
+        while self.eat_symbol("||") {
+            let right = self.additive()?;
+            left = Expr::Binary(BinaryOp::Concat, Box::new(left), Box::new(right));
+        }
+        Ok(left)
+    }
+
+    fn additive(&mut self) -> Result<Expr, TranslateError> {
+        let mut left = self.term()?;
+        loop {
+            let op = match self.kind() {
+                TokenKind::Symbol("+") => BinaryOp::Add,
+                TokenKind::Symbol("-") => BinaryOp::Subtract,
+                _ => return Ok(left),
+            };
+            self.advance();
+            let right = self.term()?;
+            left = Expr::Binary(op, Box::new(left), Box::new(right));
+        }
+    }
+
+    fn term(&mut self) -> Result<Expr, TranslateError> {
+        let mut left = self.unary()?;
+        loop {
+            let op = match self.kind() {
+                TokenKind::Symbol("*") => BinaryOp::Multiply,
+                TokenKind::Symbol("/") => BinaryOp::Divide,
+                TokenKind::Symbol("%") => BinaryOp::Modulo,
+                _ => return Ok(left),
+            };
+            self.advance();
+            let right = self.unary()?;
+            left = Expr::Binary(op, Box::new(left), Box::new(right));
+        }
+    }
+
+    fn unary(&mut self) -> Result<Expr, TranslateError> {
+        if self.eat_symbol("-") {
+            return Ok(Expr::Negate(Box::new(self.unary()?)));
+        }
+        self.primary()
+    }
+
+    fn primary(&mut self) -> Result<Expr, TranslateError> {
+        let token = self.token().clone();
+        match token.kind {
+            TokenKind::Number(text) => {
+                self.advance();
+                Ok(Expr::Literal(text))
+            }
+            TokenKind::String(value) => {
+                self.advance();
+                Ok(Expr::Literal(super::json_string(&value)))
+            }
+            TokenKind::Symbol("(") => {
+                self.advance();
+                let inner = self.expression()?;
+                self.expect_symbol(")")?;
+                Ok(inner)
+            }
+            TokenKind::Word(word) if word.eq_ignore_ascii_case("NULL") => {
+                self.advance();
+                Ok(Expr::Literal("null".to_string()))
+            }
+            TokenKind::Word(word)
+                if word.eq_ignore_ascii_case("TRUE") || word.eq_ignore_ascii_case("FALSE") =>
+            {
+                self.advance();
+                Ok(Expr::Literal(word.to_ascii_lowercase()))
+            }
+            TokenKind::Word(word)
+                if !is_reserved(&word)
+                    && self.tokens[self.index + 1].kind == TokenKind::Symbol("(") =>
+            {
+                self.advance();
+                self.advance();
+                self.function(word.to_ascii_uppercase(), token.start)
+            }
+            TokenKind::Word(_) | TokenKind::QuotedIdentifier(_) => {
+                let mut path = vec![self.identifier()?];
+                while self.eat_symbol(".") {
+                    path.push(self.identifier()?);
+                }
+                Ok(Expr::Column(path))
+            }
+            _ => Err(self.error("Expected an expression")),
+        }
+    }
+
+    /// Function call arguments after `name(`
+    fn function(&mut self, name: String, position: usize) -> Result<Expr, TranslateError> {
+        if self.eat_symbol("*") {
+            self.expect_symbol(")")?;
+            return Ok(Expr::Function {
+                name,
+                args: Vec::new(),
+                star: true,
+                distinct: false,
+                position,
+            });
+        }
+
+        let distinct = self.eat_keyword("DISTINCT");
+        let mut args = Vec::new();
+        if !self.eat_symbol(")") {
+            args.push(self.expression()?);
+            while self.eat_symbol(",") {
+                args.push(self.expression()?);
+            }
+            self.expect_symbol(")")?;
+        }
+        Ok(Expr::Function {
+            name,
+            args,
+            star: false,
+            distinct,
+            position,
+        })
+    }
+}
+
+fn is_reserved(word: &str) -> bool {
+    RESERVED.iter().any(|r| r.eq_ignore_ascii_case(word))
+}
+
+#[cfg(test)]
+#[path = "sql_tests.rs"]
+mod sql_tests;
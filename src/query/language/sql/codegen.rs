@@ -0,0 +1,367 @@
+//! jq generation for parsed SQL queries
+//!
+//! The generated program collects the source rows into an array and applies
+//! one pipeline stage per clause, so it reads like the SQL it came from:
+//! `[rows] | map(select(WHERE)) | sort_by(ORDER BY) | map({SELECT}) | .[:LIMIT]`.
+
+use super::{BinaryOp, Expr, Select};
+use crate::query::language::{TranslateError, is_jq_identifier, json_string};
+
+/// Whether an expression is evaluated against one row or a group of rows
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Context {
+    Row,
+    Group,
+}
+
+const AGGREGATES: &[&str] = &["COUNT", "SUM", "AVG", "MIN", "MAX"];
+
+/// Generate the jq program for a whole query
+pub(super) fn generate(select: &Select) -> Result<String, TranslateError> {
+    let source = if select.from.is_empty() {
+        String::new()
+    } else {
+        format!("{} | ", jq_path(&select.from))
+    };
+    let mut stages = vec![format!(
+        "[{}if type == \"array\" then .[] else . end]",
+        source
+    )];
+
+    if let Some(filter) = &select.filter {
+        stages.push(format!("map(select({}))", expr(filter, Context::Row)?));
+    }
+
+    let grouped = !select.group_by.is_empty()
+        || select.having.is_some()
+        || select.items.iter().any(|item| has_aggregate(&item.expr))
+        || select.order_by.iter().any(|(e, _)| has_aggregate(e));
+    let context = if grouped {
+        Context::Group
+    } else {
+        Context::Row
+    };
+
+    if grouped {
+        if select.items.is_empty() {
+            return Err(TranslateError::new(
+                "SELECT * cannot be combined with GROUP BY or aggregates",
+                0,
+            ));
+        }
+        stages.push(match select.group_by.as_slice() {
+            [] => "[.]".to_string(),
+            [key] => format!("group_by({})", expr(key, Context::Row)?),
+            keys => {
+                let keys = keys
+                    .iter()
+                    .map(|key| expr(key, Context::Row))
+                    .collect::<Result<Vec<_>, _>>()?;
+                format!("group_by([{}])", keys.join(", "))
+            }
+        });
+        if let Some(having) = &select.having {
+            stages.push(format!("map(select({}))", expr(having, Context::Group)?));
+        }
+    }
+
+    // Stable sorts applied from the last key to the first give a multi-key
+    // sort; descending keys reverse around the sort to keep ties in order
+    for (key, descending) in select.order_by.iter().rev() {
+        let key = expr(resolve_order_key(select, key), context)?;
+        stages.push(if *descending {
+            format!("reverse | sort_by({}) | reverse", key)
+        } else {
+            format!("sort_by({})", key)
+        });
+    }
+
+    if !select.items.is_empty() {
+        let fields = select
+            .items
+            .iter()
+            .map(|item| {
+                expr(&item.expr, context)
+                    .map(|value| format!("{}: {}", json_string(&item.name), value))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        stages.push(format!("map({{{}}})", fields.join(", ")));
+    }
+
+    if select.distinct {
+        stages.push(
+            "reduce .[] as $row ([]; if any(.[]; . == $row) then . else . + [$row] end)"
+                .to_string(),
+        );
+    }
+
+    let offset = select.offset.unwrap_or(0);
+    match (select.limit, offset) {
+        (Some(limit), offset) => stages.push(format!(".[{}:{}]", offset, offset + limit)),
+        (None, 0) => {}
+        (None, offset) => stages.push(format!(".[{}:]", offset)),
+    }
+
+    Ok(stages.join(" | "))
+}
+
+/// ORDER BY may name a select alias or a 1-based column position
+fn resolve_order_key<'a>(select: &'a Select, key: &'a Expr) -> &'a Expr {
+    match key {
+        Expr::Column(path) if path.len() == 1 => select
+            .items
+            .iter()
+            .find(|item| item.alias.as_deref() == Some(path[0].as_str()))
+            .map(|item| &item.expr)
+            .unwrap_or(key),
+        Expr::Literal(text) => text
+            .parse::<usize>()
+            .ok()
+            .and_then(|n| n.checked_sub(1))
+            .and_then(|i| select.items.get(i))
+            .map(|item| &item.expr)
+            .unwrap_or(key),
+        _ => key,
+    }
+}
+
+fn has_aggregate(e: &Expr) -> bool {
+    match e {
+        Expr::Function { name, args, .. } => {
+            AGGREGATES.contains(&name.as_str()) || args.iter().any(has_aggregate)
+        }
+        Expr::Column(_) | Expr::Literal(_) => false,
+        Expr::Not(inner) | Expr::Negate(inner) => has_aggregate(inner),
+        Expr::Binary(_, lhs, rhs) => has_aggregate(lhs) || has_aggregate(rhs),
+        Expr::Like { expr, .. } | Expr::IsNull { expr, .. } => has_aggregate(expr),
+        Expr::In { expr, list, .. } => has_aggregate(expr) || list.iter().any(has_aggregate),
+        Expr::Between {
+            expr, low, high, ..
+        } => has_aggregate(expr) || has_aggregate(low) || has_aggregate(high),
+    }
+}
+
+/// jq path for a column or source path, like `.a.b` or `.["first name"]`
+fn jq_path(segments: &[String]) -> String {
+    let mut path = String::new();
+    for segment in segments {
+        if is_jq_identifier(segment) {
+            path.push('.');
+            path.push_str(segment);
+        } else {
+            if path.is_empty() {
+                path.push('.');
+            }
+            path.push_str(&format!("[{}]", json_string(segment)));
+        }
+    }
+    if path.is_empty() {
+        ".".to_string()
+    } else {
+        path
+    }
+}
+
+fn expr(e: &Expr, context: Context) -> Result<String, TranslateError> {
+    Ok(match e {
+        Expr::Column(path) => match context {
+            Context::Row => jq_path(path),
+            // Plain columns in grouped queries come from the group's first row
+            Context::Group => format!("(.[0] | {})", jq_path(path)),
+        },
+        Expr::Literal(text) => text.clone(),
+        Expr::Not(inner) => format!("({} | not)", expr(inner, context)?),
+        Expr::Negate(inner) => format!("(0 - {})", expr(inner, context)?),
+        Expr::Binary(op, lhs, rhs) => binary(*op, &expr(lhs, context)?, &expr(rhs, context)?),
+        Expr::Like {
+            expr: inner,
+            pattern,
+            negated,
+        } => {
+            let test = format!(
+                "({} | if type == \"string\" then test({}) else false end)",
+                expr(inner, context)?,
+                json_string(&like_regex(pattern))
+            );
+            if *negated {
+                format!("({} | not)", test)
+            } else {
+                test
+            }
+        }
+        Expr::In {
+            expr: inner,
+            list,
+            negated,
+        } => {
+            let list = list
+                .iter()
+                .map(|item| expr(item, context))
+                .collect::<Result<Vec<_>, _>>()?;
+            let found = format!("any({}; . == $v)", list.join(", "));
+            let found = if *negated {
+                format!("({} | not)", found)
+            } else {
+                found
+            };
+            format!(
+                "({} as $v | $v != null and {})",
+                expr(inner, context)?,
+                found
+            )
+        }
+        Expr::IsNull {
+            expr: inner,
+            negated,
+        } => format!(
+            "({} {} null)",
+            expr(inner, context)?,
+            if *negated { "!=" } else { "==" }
+        ),
+        Expr::Between {
+            expr: inner,
+            low,
+            high,
+            negated,
+        } => {
+            let check = if *negated {
+                "($v < $lo or $v > $hi)"
+            } else {
+                "$v >= $lo and $v <= $hi"
+            };
+            format!(
+                "({} as $v | {} as $lo | {} as $hi | $v != null and {})",
+                expr(inner, context)?,
+                expr(low, context)?,
+                expr(high, context)?,
+                check
+            )
+        }
+        Expr::Function {
+            name,
+            args,
+            star,
+            distinct,
+            position,
+        } => function(name, args, *star, *distinct, *position, context)?,
+    })
+}
+
+fn binary(op: BinaryOp, lhs: &str, rhs: &str) -> String {
+    let comparison = |operator: &str| {
+        // SQL comparisons with NULL are never true
+        format!(
+            "({} as $l | {} as $r | $l != null and $r != null and $l {} $r)",
+            lhs, rhs, operator
+        )
+    };
+    match op {
+        BinaryOp::And => format!("({} and {})", lhs, rhs),
+        BinaryOp::Or => format!("({} or {})", lhs, rhs),
+        BinaryOp::Eq => comparison("=="),
+        BinaryOp::Ne => comparison("!="),
+        BinaryOp::Lt => comparison("<"),
+        BinaryOp::Le => comparison("<="),
+        BinaryOp::Gt => comparison(">"),
+        BinaryOp::Ge => comparison(">="),
+        BinaryOp::Add => format!("({} + {})", lhs, rhs),
+        BinaryOp::Subtract => format!("({} - {})", lhs, rhs),
+        BinaryOp::Multiply => format!("({} * {})", lhs, rhs),
+        BinaryOp::Divide => format!("({} / {})", lhs, rhs),
+        BinaryOp::Modulo => format!("({} % {})", lhs, rhs),
+        BinaryOp::Concat => format!("(({} | tostring) + ({} | tostring))", lhs, rhs),
+    }
+}
+
+/// Anchored regex equivalent of a LIKE pattern (`%` any run, `_` one char)
+fn like_regex(pattern: &str) -> String {
+    let mut regex = String::from("^");
+    for c in pattern.chars() {
+        match c {
+            '%' => regex.push_str(".*"),
+            '_' => regex.push('.'),
+            c if "\\.^$|?*+()[]{}".contains(c) => {
+                regex.push('\\');
+                regex.push(c);
+            }
+            c => regex.push(c),
+        }
+    }
+    regex.push('$');
+    regex
+}
+
+fn function(
+    name: &str,
+    args: &[Expr],
+    star: bool,
+    distinct: bool,
+    position: usize,
+    context: Context,
+) -> Result<String, TranslateError> {
+    let arity = |expected: usize| {
+        if args.len() == expected && !star {
+            Ok(())
+        } else {
+            Err(TranslateError::new(
+                format!("{} takes {} argument(s)", name, expected),
+                position,
+            ))
+        }
+    };
+
+    if AGGREGATES.contains(&name) {
+        if context == Context::Row {
+            return Err(TranslateError::new(
+                format!("{} is only allowed in SELECT, HAVING and ORDER BY", name),
+                position,
+            ));
+        }
+        if name == "COUNT" && star {
+            return Ok("length".to_string());
+        }
+        arity(1)?;
+        let unique = if distinct { " | unique" } else { "" };
+        let values = format!(
+            "map({} | select(. != null)){}",
+            expr(&args[0], Context::Row)?,
+            unique
+        );
+        return Ok(match name {
+            "COUNT" => format!("({} | length)", values),
+            "SUM" => format!("({} | add)", values),
+            "AVG" => format!(
+                "({} | if length == 0 then null else add / length end)",
+                values
+            ),
+            "MIN" => format!("({} | min)", values),
+            _ => format!("({} | max)", values),
+        });
+    }
+
+    let single = |body: &str| -> Result<String, TranslateError> {
+        arity(1)?;
+        Ok(format!("({} | {})", expr(&args[0], context)?, body))
+    };
+    match name {
+        "LOWER" => single("if type == \"string\" then ascii_downcase else . end"),
+        "UPPER" => single("if type == \"string\" then ascii_upcase else . end"),
+        "LENGTH" => single("if . == null then null else length end"),
+        "ABS" => single("if type == \"number\" and . < 0 then 0 - . else . end"),
+        "ROUND" => single("if type == \"number\" then round else . end"),
+        "COALESCE" if !args.is_empty() && !star => {
+            let args = args
+                .iter()
+                .map(|arg| expr(arg, context))
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(format!(
+                "([{}] | map(select(. != null)) | .[0])",
+                args.join(", ")
+            ))
+        }
+        _ => Err(TranslateError::new(
+            format!("Unknown function {}", name),
+            position,
+        )),
+    }
+}
@@ -0,0 +1,124 @@
+//! SQL tokenizer
+
+use crate::query::language::TranslateError;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TokenKind {
+    /// Bare word; keywords are matched case-insensitively by the parser
+    Word(String),
+    /// `"double quoted"` identifier
+    QuotedIdentifier(String),
+    /// Number literal, kept as written
+    Number(String),
+    /// `'single quoted'` string literal, unescaped
+    String(String),
+    Symbol(&'static str),
+    Eof,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Token {
+    pub kind: TokenKind,
+    /// Character offset of the token start
+    pub start: usize,
+    /// Character offset just after the token
+    pub end: usize,
+}
+
+/// Multi-character symbols first so they win over their prefixes
+const SYMBOLS: &[&str] = &[
+    "<>", "!=", "<=", ">=", "||", ",", "(", ")", "*", "=", "<", ">", "+", "-", "/", "%", ".", "[",
+    "]",
+];
+
+/// Split a SQL query into tokens, ending with `Eof`
+pub fn tokenize(query: &str) -> Result<Vec<Token>, TranslateError> {
+    let chars: Vec<char> = query.chars().collect();
+    let mut tokens = Vec::new();
+    let mut pos = 0;
+
+    while pos < chars.len() {
+        let c = chars[pos];
+        let start = pos;
+
+        if c.is_whitespace() {
+            pos += 1;
+            continue;
+        }
+
+        let kind = if c.is_ascii_alphabetic() || c == '_' {
+            while chars
+                .get(pos)
+                .is_some_and(|c| c.is_ascii_alphanumeric() || *c == '_')
+            {
+                pos += 1;
+            }
+            TokenKind::Word(chars[start..pos].iter().collect())
+        } else if c.is_ascii_digit() {
+            while chars
+                .get(pos)
+                .is_some_and(|c| c.is_ascii_digit() || *c == '.')
+            {
+                pos += 1;
+            }
+            let text: String = chars[start..pos].iter().collect();
+            if text.parse::<f64>().is_err() {
+                return Err(TranslateError::new("Invalid number", start));
+            }
+            TokenKind::Number(text)
+        } else if c == '\'' || c == '"' {
+            // Quotes are escaped by doubling them: 'it''s'
+            let mut value = String::new();
+            pos += 1;
+            loop {
+                match chars.get(pos) {
+                    None => return Err(TranslateError::new("Unterminated string", start)),
+                    Some(&q) if q == c && chars.get(pos + 1) == Some(&c) => {
+                        value.push(c);
+                        pos += 2;
+                    }
+                    Some(&q) if q == c => {
+                        pos += 1;
+                        break;
+                    }
+                    Some(&other) => {
+                        value.push(other);
+                        pos += 1;
+                    }
+                }
+            }
+            if c == '\'' {
+                TokenKind::String(value)
+            } else {
+                TokenKind::QuotedIdentifier(value)
+            }
+        } else {
+            let symbol = SYMBOLS
+                .iter()
+                .find(|symbol| {
+                    symbol
+                        .chars()
+                        .enumerate()
+                        .all(|(i, s)| chars.get(pos + i) == Some(&s))
+                })
+                .ok_or_else(|| {
+                    TranslateError::new(format!("Unexpected character '{}'", c), start)
+                })?;
+            pos += symbol.chars().count();
+            TokenKind::Symbol(symbol)
+        };
+
+        tokens.push(Token {
+            kind,
+            start,
+            end: pos,
+        });
+    }
+
+    tokens.push(Token {
+        kind: TokenKind::Eof,
+        start: chars.len(),
+        end: chars.len(),
+    });
+    Ok(tokens)
+}
@@ -0,0 +1,232 @@
+//! Tests for SQL translation
+
+use super::*;
+use serde_json::{Value, json};
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+const PEOPLE: &str = r#"{
+  "people": [
+    {"name": "Alice", "age": 34, "dept": "eng", "email": "alice@example.com"},
+    {"name": "Bob", "age": 27, "dept": "ops"},
+    {"name": "Carol", "age": 41, "dept": "eng", "email": "carol@example.org"},
+    {"name": "Dave", "age": 30, "dept": "sales", "email": null},
+    {"name": "Eve", "dept": "ops"}
+  ]
+}"#;
+
+/// Translate `query` and evaluate it with jq against `input`
+fn eval(query: &str, input: &str) -> Value {
+    let filter = to_jq(query).unwrap_or_else(|e| panic!("{}: {}", query, e));
+    let mut child = Command::new("jq")
+        .arg("-c")
+        .arg(&filter)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(input.as_bytes())
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+    assert!(
+        output.status.success(),
+        "{} -> {}: {}",
+        query,
+        filter,
+        String::from_utf8_lossy(&output.stderr)
+    );
+    serde_json::from_slice(&output.stdout).unwrap()
+}
+
+#[test]
+fn test_select_where_order_by() {
+    assert_eq!(
+        eval(
+            "SELECT name, age FROM .people WHERE age > 30 ORDER BY age",
+            PEOPLE
+        ),
+        json!([{"name": "Alice", "age": 34}, {"name": "Carol", "age": 41}])
+    );
+}
+
+#[test]
+fn test_select_star_from_root_array() {
+    assert_eq!(
+        eval("SELECT * FROM . WHERE a = 2", r#"[{"a":1},{"a":2}]"#),
+        json!([{"a": 2}])
+    );
+    assert_eq!(
+        eval("select * where a = 1", r#"[{"a":1},{"a":2}]"#),
+        json!([{"a": 1}])
+    );
+}
+
+#[test]
+fn test_single_object_source_is_one_row() {
+    assert_eq!(
+        eval("SELECT a FROM .", r#"{"a":1,"b":2}"#),
+        json!([{"a": 1}])
+    );
+}
+
+#[test]
+fn test_order_by_desc_and_multiple_keys() {
+    assert_eq!(
+        eval("SELECT name FROM people ORDER BY dept DESC, name", PEOPLE),
+        json!([
+            {"name": "Dave"},
+            {"name": "Bob"},
+            {"name": "Eve"},
+            {"name": "Alice"},
+            {"name": "Carol"}
+        ])
+    );
+}
+
+#[test]
+fn test_aliases_expressions_and_order_by_alias() {
+    assert_eq!(
+        eval(
+            "SELECT name AS who, age + 1 next_age FROM .people WHERE age IS NOT NULL ORDER BY next_age DESC LIMIT 2",
+            PEOPLE
+        ),
+        json!([{"who": "Carol", "next_age": 42}, {"who": "Alice", "next_age": 35}])
+    );
+    assert_eq!(
+        eval("SELECT age * 2 FROM . ", r#"[{"age":3}]"#),
+        json!([{"age * 2": 6}])
+    );
+}
+
+#[test]
+fn test_null_comparisons_are_never_true() {
+    assert_eq!(
+        eval("SELECT name FROM .people WHERE age < 30", PEOPLE),
+        json!([{"name": "Bob"}])
+    );
+    assert_eq!(
+        eval("SELECT name FROM .people WHERE email IS NULL", PEOPLE),
+        json!([{"name": "Bob"}, {"name": "Dave"}, {"name": "Eve"}])
+    );
+}
+
+#[test]
+fn test_like_in_and_between() {
+    assert_eq!(
+        eval("SELECT name FROM .people WHERE email LIKE '%.org'", PEOPLE),
+        json!([{"name": "Carol"}])
+    );
+    assert_eq!(
+        eval("SELECT name FROM .people WHERE name NOT LIKE '_o%'", PEOPLE),
+        json!([{"name": "Alice"}, {"name": "Carol"}, {"name": "Dave"}, {"name": "Eve"}])
+    );
+    assert_eq!(
+        eval(
+            "SELECT name FROM .people WHERE dept IN ('ops', 'sales')",
+            PEOPLE
+        ),
+        json!([{"name": "Bob"}, {"name": "Dave"}, {"name": "Eve"}])
+    );
+    assert_eq!(
+        eval(
+            "SELECT name FROM .people WHERE age BETWEEN 28 AND 35",
+            PEOPLE
+        ),
+        json!([{"name": "Alice"}, {"name": "Dave"}])
+    );
+}
+
+#[test]
+fn test_group_by_with_aggregates() {
+    assert_eq!(
+        eval(
+            "SELECT dept, COUNT(*) AS n, MAX(age) oldest FROM .people GROUP BY dept HAVING COUNT(*) > 1 ORDER BY n DESC, dept",
+            PEOPLE
+        ),
+        json!([
+            {"dept": "eng", "n": 2, "oldest": 41},
+            {"dept": "ops", "n": 2, "oldest": 27}
+        ])
+    );
+}
+
+#[test]
+fn test_aggregates_without_group_by() {
+    assert_eq!(
+        eval(
+            "SELECT COUNT(*) total, COUNT(age) aged, AVG(age) avg_age, COUNT(DISTINCT dept) depts FROM .people",
+            PEOPLE
+        ),
+        json!([{"total": 5, "aged": 4, "avg_age": 33, "depts": 3}])
+    );
+    assert_eq!(
+        eval("SELECT COUNT(*) n, SUM(x) s FROM .", "[]"),
+        json!([{"n": 0, "s": null}])
+    );
+}
+
+#[test]
+fn test_distinct_keeps_first_occurrence_order() {
+    assert_eq!(
+        eval("SELECT DISTINCT dept FROM .people", PEOPLE),
+        json!([{"dept": "eng"}, {"dept": "ops"}, {"dept": "sales"}])
+    );
+}
+
+#[test]
+fn test_limit_and_offset() {
+    assert_eq!(
+        eval("SELECT name FROM .people LIMIT 2 OFFSET 1", PEOPLE),
+        json!([{"name": "Bob"}, {"name": "Carol"}])
+    );
+    assert_eq!(
+        eval("SELECT name FROM .people OFFSET 4", PEOPLE),
+        json!([{"name": "Eve"}])
+    );
+}
+
+#[test]
+fn test_scalar_functions_and_concat() {
+    assert_eq!(
+        eval(
+            "SELECT UPPER(name) AS n, name || '@' || dept AS tag, COALESCE(email, 'none') contact FROM .people WHERE name = 'Bob'",
+            PEOPLE
+        ),
+        json!([{"n": "BOB", "tag": "Bob@ops", "contact": "none"}])
+    );
+}
+
+#[test]
+fn test_nested_and_quoted_columns() {
+    assert_eq!(
+        eval(
+            r#"SELECT user.name, "first name" FROM .rows WHERE user.id = 1"#,
+            r#"{"rows":[{"user":{"id":1,"name":"x"},"first name":"y"},{"user":{"id":2}}]}"#
+        ),
+        json!([{"name": "x", "first name": "y"}])
+    );
+}
+
+#[test]
+fn test_invalid_queries_report_position() {
+    let err = to_jq("name FROM .").unwrap_err();
+    assert_eq!(err.position, 0);
+
+    let err = to_jq("SELECT name FROM . WHERE").unwrap_err();
+    assert_eq!(err.position, 24);
+
+    let err = to_jq("SELECT nope(name) FROM .").unwrap_err();
+    assert_eq!(err.position, 7);
+
+    let err = to_jq("SELECT name FROM . WHERE COUNT(*) > 1").unwrap_err();
+    assert_eq!(err.position, 25);
+
+    assert!(to_jq("SELECT 'unterminated").is_err());
+    assert!(to_jq("SELECT * FROM . GROUP BY a").is_err());
+    assert!(to_jq("SELECT a FROM . LIMIT x").is_err());
+}
@@ -0,0 +1,46 @@
+//! Tests for query languages
+
+use super::*;
+
+#[test]
+fn test_next_cycles_through_languages() {
+    assert_eq!(QueryLanguage::Jq.next(), QueryLanguage::JsonPath);
+    assert_eq!(QueryLanguage::JsonPath.next(), QueryLanguage::JmesPath);
+    assert_eq!(QueryLanguage::JmesPath.next(), QueryLanguage::Sql);
+    assert_eq!(QueryLanguage::Sql.next(), QueryLanguage::Jq);
+}
+
+#[test]
+fn test_labels() {
+    assert_eq!(QueryLanguage::Jq.label(), "jq");
+    assert_eq!(QueryLanguage::JsonPath.label(), "JSONPath");
+    assert_eq!(QueryLanguage::JmesPath.label(), "JMESPath");
+    assert_eq!(QueryLanguage::Sql.label(), "SQL");
+}
+
+#[test]
+fn test_jq_passes_through_unchanged() {
+    assert_eq!(QueryLanguage::Jq.to_jq(".a | keys").unwrap(), ".a | keys");
+    assert_eq!(QueryLanguage::Jq.to_jq("").unwrap(), "");
+}
+
+#[test]
+fn test_empty_query_is_identity_in_other_languages() {
+    assert_eq!(QueryLanguage::JsonPath.to_jq("  ").unwrap(), ".");
+    assert_eq!(QueryLanguage::JmesPath.to_jq("").unwrap(), ".");
+}
+
+#[test]
+fn test_translate_error_display_is_one_based() {
+    let err = TranslateError::new("Expected ']'", 4);
+    assert_eq!(err.to_string(), "Expected ']' at position 5");
+}
+
+#[test]
+fn test_jq_field_quotes_when_needed() {
+    assert_eq!(jq_field("name"), ".name");
+    assert_eq!(jq_field("_id2"), "._id2");
+    assert_eq!(jq_field("content-type"), r#".["content-type"]"#);
+    assert_eq!(jq_field("2fa"), r#".["2fa"]"#);
+    assert_eq!(jq_field("if"), r#".["if"]"#);
+}
@@ -0,0 +1,232 @@
+//! jq Module Library
+//!
+//! Module search paths (jq's `-L`) and the user library
+//! `~/.config/jiq/lib.jq`. When the library exists it is included ahead of
+//! every query, so its definitions are available without an `include`.
+//! The prelude sits on its own line; error locations are shifted back so
+//! they still refer to the query the user typed.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// File name of the user library, looked up in the jiq config directory
+pub const LIBRARY_FILE: &str = "lib.jq";
+
+/// Module name the user library is included as
+const LIBRARY_MODULE: &str = "lib";
+
+/// Number of lines the prelude adds in front of the query
+const PRELUDE_LINES: usize = 1;
+
+const TOP_LEVEL_LOCATION: &str = "at <top-level>, line ";
+
+/// A function defined in the user library
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LibraryFunction {
+    pub name: String,
+    /// Name with parameter list, e.g. `greet($name)`
+    pub signature: String,
+    pub has_params: bool,
+}
+
+/// Module search paths and user library passed to every jq invocation
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct JqLibrary {
+    search_paths: Vec<String>,
+    /// Directory holding `lib.jq`, if the library is included
+    library_dir: Option<PathBuf>,
+    functions: Vec<LibraryFunction>,
+}
+
+impl JqLibrary {
+    /// Build the library from search paths and an optional `lib.jq`
+    ///
+    /// A library file that doesn't exist or can't be read is not included.
+    pub fn load(search_paths: Vec<String>, library_file: Option<&Path>) -> Self {
+        let library = library_file.and_then(|path| {
+            let source = fs::read_to_string(path).ok()?;
+            let dir = path.parent()?.to_path_buf();
+            Some((dir, parse_definitions(&source)))
+        });
+        let (library_dir, functions) = match library {
+            Some((dir, functions)) => (Some(dir), functions),
+            None => (None, Vec::new()),
+        };
+
+        Self {
+            search_paths,
+            library_dir,
+            functions,
+        }
+    }
+
+    /// Functions defined in the user library
+    pub fn functions(&self) -> &[LibraryFunction] {
+        &self.functions
+    }
+
+    /// Command line arguments adding the module search paths
+    pub fn jq_args(&self) -> Vec<String> {
+        self.search_paths
+            .iter()
+            .flat_map(|path| ["-L".to_string(), path.clone()])
+            .collect()
+    }
+
+    /// Directive that includes the user library, if there is one
+    pub fn prelude(&self) -> Option<String> {
+        let dir = self.library_dir.as_ref()?;
+        let search = serde_json::to_string(&dir.to_string_lossy()).ok()?;
+        Some(format!(
+            "include \"{}\" {{search: {}}};",
+            LIBRARY_MODULE, search
+        ))
+    }
+
+    /// The program jq runs for `query`
+    pub fn program(&self, query: &str) -> String {
+        match self.prelude() {
+            Some(prelude) => format!("{}\n{}", prelude, query),
+            None => query.to_string(),
+        }
+    }
+
+    /// Rewrite jq's error output so locations refer to `query` rather than
+    /// the program returned by [`program`](Self::program)
+    pub fn query_error(&self, stderr: String) -> String {
+        if self.library_dir.is_none() {
+            return stderr;
+        }
+
+        let mut result = String::with_capacity(stderr.len());
+        let mut rest = stderr.as_str();
+        while let Some(idx) = rest.find(TOP_LEVEL_LOCATION) {
+            let (before, after) = rest.split_at(idx + TOP_LEVEL_LOCATION.len());
+            result.push_str(before);
+            let digits = after.chars().take_while(|c| c.is_ascii_digit()).count();
+            match after[..digits].parse::<usize>() {
+                Ok(line) => result.push_str(&line.saturating_sub(PRELUDE_LINES).max(1).to_string()),
+                Err(_) => result.push_str(&after[..digits]),
+            }
+            rest = &after[digits..];
+        }
+        result.push_str(rest);
+        result
+    }
+}
+
+/// Collect the top-level `def`s of a jq module
+///
+/// Comments and strings are skipped, and definitions nested inside another
+/// definition's body are left out since they aren't visible to queries.
+pub fn parse_definitions(source: &str) -> Vec<LibraryFunction> {
+    let chars: Vec<char> = source.chars().collect();
+    let mut functions = Vec::new();
+    // Bracket depth at which each enclosing definition's body ends
+    let mut open_defs: Vec<usize> = Vec::new();
+    let mut depth = 0usize;
+    let mut i = 0;
+
+    while i < chars.len() {
+        match chars[i] {
+            '#' => {
+                while i < chars.len() && chars[i] != '\n' {
+                    i += 1;
+                }
+            }
+            '"' => {
+                i += 1;
+                while i < chars.len() && chars[i] != '"' {
+                    if chars[i] == '\\' {
+                        i += 1;
+                    }
+                    i += 1;
+                }
+            }
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' | '}' => depth = depth.saturating_sub(1),
+            ';' if open_defs.last() == Some(&depth) => {
+                open_defs.pop();
+            }
+            c if is_ident_char(c) => {
+                let start = i;
+                while i < chars.len() && is_ident_char(chars[i]) {
+                    i += 1;
+                }
+                let word: String = chars[start..i].iter().collect();
+                let preceded_by_ident = start > 0 && matches!(chars[start - 1], '$' | '.');
+                if word == "def" && !preceded_by_ident {
+                    let (function, end) = parse_header(&chars, i);
+                    if let Some(function) = function
+                        && open_defs.is_empty()
+                    {
+                        functions.push(function);
+                    }
+                    open_defs.push(depth);
+                    i = end;
+                }
+                continue;
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+
+    functions
+}
+
+fn is_ident_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_'
+}
+
+/// Parse `name(params):` after a `def` keyword; returns the index past the `:`
+fn parse_header(chars: &[char], mut i: usize) -> (Option<LibraryFunction>, usize) {
+    while i < chars.len() && chars[i].is_whitespace() {
+        i += 1;
+    }
+    let start = i;
+    while i < chars.len() && (chars[i].is_ascii_alphanumeric() || chars[i] == '_') {
+        i += 1;
+    }
+    let name: String = chars[start..i].iter().collect();
+
+    while i < chars.len() && chars[i].is_whitespace() {
+        i += 1;
+    }
+    let mut params = None;
+    if chars.get(i) == Some(&'(') {
+        let params_start = i + 1;
+        while i < chars.len() && chars[i] != ')' {
+            i += 1;
+        }
+        let text: String = chars[params_start..i.min(chars.len())].iter().collect();
+        params = Some(
+            text.split(';')
+                .map(str::trim)
+                .collect::<Vec<_>>()
+                .join("; "),
+        );
+        i += 1;
+    }
+    while i < chars.len() && chars[i] != ':' {
+        i += 1;
+    }
+
+    if name.is_empty() {
+        return (None, i + 1);
+    }
+    let signature = match &params {
+        Some(params) => format!("{}({})", name, params),
+        None => name.clone(),
+    };
+    let function = LibraryFunction {
+        name,
+        signature,
+        has_params: params.is_some(),
+    };
+    (Some(function), i + 1)
+}
+
+#[cfg(test)]
+#[path = "library_tests.rs"]
+mod library_tests;
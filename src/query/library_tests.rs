@@ -0,0 +1,92 @@
+//! Tests for the jq module library
+
+use super::*;
+use std::io::Write;
+
+fn library_with_source(source: &str) -> (tempfile::TempDir, JqLibrary) {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join(LIBRARY_FILE);
+    fs::File::create(&path)
+        .unwrap()
+        .write_all(source.as_bytes())
+        .unwrap();
+    let library = JqLibrary::load(Vec::new(), Some(&path));
+    (dir, library)
+}
+
+#[test]
+fn test_parse_definitions() {
+    let functions = parse_definitions(
+        "# helpers\ndef double: . * 2;\ndef greet($name; f): \"hi \\($name)\";\n",
+    );
+    assert_eq!(
+        functions,
+        vec![
+            LibraryFunction {
+                name: "double".to_string(),
+                signature: "double".to_string(),
+                has_params: false,
+            },
+            LibraryFunction {
+                name: "greet".to_string(),
+                signature: "greet($name; f)".to_string(),
+                has_params: true,
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_parse_definitions_skips_nested_comments_and_strings() {
+    let source = r#"
+def outer:
+  def inner: 1;
+  reduce .[] as $x (0; . + inner);
+# def commented: 1;
+def after: "def quoted: 1;";
+"#;
+    let names: Vec<String> = parse_definitions(source)
+        .into_iter()
+        .map(|f| f.name)
+        .collect();
+    assert_eq!(names, vec!["outer", "after"]);
+}
+
+#[test]
+fn test_missing_library_file_is_not_included() {
+    let library = JqLibrary::load(Vec::new(), Some(Path::new("/nonexistent/jiq/lib.jq")));
+    assert_eq!(library.prelude(), None);
+    assert_eq!(library.program(".a"), ".a");
+}
+
+#[test]
+fn test_search_paths_become_jq_args() {
+    let library = JqLibrary::load(vec!["~/jq".to_string(), "/opt/jq".to_string()], None);
+    assert_eq!(library.jq_args(), vec!["-L", "~/jq", "-L", "/opt/jq"]);
+}
+
+#[test]
+fn test_program_includes_library() {
+    let (dir, library) = library_with_source("def double: . * 2;");
+    let program = library.program(".a | double");
+
+    let (prelude, query) = program.split_once('\n').unwrap();
+    assert!(prelude.starts_with("include \"lib\""));
+    assert!(prelude.contains(&dir.path().to_string_lossy().to_string()));
+    assert_eq!(query, ".a | double");
+    assert_eq!(library.functions().len(), 1);
+}
+
+#[test]
+fn test_query_error_locations_skip_prelude() {
+    let (_dir, library) = library_with_source("def double: . * 2;");
+    let stderr = "jq: error: $x is not defined at <top-level>, line 2, column 3:\n$x\n".to_string();
+    assert_eq!(
+        library.query_error(stderr),
+        "jq: error: $x is not defined at <top-level>, line 1, column 3:\n$x\n"
+    );
+
+    let library_error =
+        "jq: error: syntax error at /home/me/.config/jiq/lib.jq, line 4:".to_string();
+    assert_eq!(library.query_error(library_error.clone()), library_error);
+}
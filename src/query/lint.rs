@@ -0,0 +1,202 @@
+//! Query Linting
+//!
+//! Lightweight static checks for common jq mistakes, run on every keystroke so
+//! problems are reported before jq executes the query:
+//! - Unbalanced brackets/parens/braces
+//! - Unterminated strings
+//! - Assignment `=` used where `==` was meant inside `select()`
+//! - Trailing pipes
+
+use std::ops::Range;
+
+/// A problem found in the query
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LintWarning {
+    pub message: String,
+    /// Character range of the offending text
+    pub span: Range<usize>,
+}
+
+impl LintWarning {
+    fn new(message: impl Into<String>, span: Range<usize>) -> Self {
+        Self {
+            message: message.into(),
+            span,
+        }
+    }
+}
+
+/// Open bracket on the scan stack
+struct OpenBracket {
+    ch: char,
+    pos: usize,
+    /// Opening paren of a `select(...)` call
+    is_select: bool,
+    /// For string interpolation `\(...)`: start of the enclosing string
+    enclosing_string: Option<usize>,
+}
+
+/// Check a query for common mistakes, ordered by position
+pub fn lint(query: &str) -> Vec<LintWarning> {
+    let chars: Vec<char> = query.chars().collect();
+    let mut warnings = Vec::new();
+    let mut stack: Vec<OpenBracket> = Vec::new();
+    // Start of the string currently being scanned, if inside one
+    let mut string_start: Option<usize> = None;
+    // Last character outside strings and comments
+    let mut last_code: Option<usize> = None;
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+
+        if let Some(start) = string_start {
+            match c {
+                '\\' if chars.get(i + 1) == Some(&'(') => {
+                    stack.push(OpenBracket {
+                        ch: '(',
+                        pos: i + 1,
+                        is_select: false,
+                        enclosing_string: Some(start),
+                    });
+                    string_start = None;
+                    i += 2;
+                }
+                '\\' => i += 2,
+                '"' => {
+                    string_start = None;
+                    i += 1;
+                }
+                _ => i += 1,
+            }
+            continue;
+        }
+
+        if !c.is_whitespace() && c != '#' {
+            last_code = Some(i);
+        }
+        match c {
+            '"' => string_start = Some(i),
+            '#' => {
+                // Comment runs to end of line
+                while i < chars.len() && chars[i] != '\n' {
+                    i += 1;
+                }
+                continue;
+            }
+            '(' | '[' | '{' => stack.push(OpenBracket {
+                ch: c,
+                pos: i,
+                is_select: c == '(' && preceding_identifier(&chars, i) == "select",
+                enclosing_string: None,
+            }),
+            ')' | ']' | '}' => match stack.last() {
+                Some(open) if open.ch == matching_open(c) => {
+                    // Closing an interpolation resumes the enclosing string
+                    string_start = stack.pop().and_then(|open| open.enclosing_string);
+                }
+                Some(open) => {
+                    warnings.push(LintWarning::new(
+                        format!(
+                            "Mismatched '{}' (expected '{}')",
+                            c,
+                            matching_close(open.ch)
+                        ),
+                        i..i + 1,
+                    ));
+                    string_start = stack.pop().and_then(|open| open.enclosing_string);
+                }
+                None => warnings.push(LintWarning::new(format!("Unmatched '{}'", c), i..i + 1)),
+            },
+            '=' if is_bare_assignment(&chars, i) && stack.iter().any(|b| b.is_select) => {
+                warnings.push(LintWarning::new(
+                    "Use '==' to compare in select()",
+                    i..i + 1,
+                ));
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+
+    // Unclosed interpolations leave their enclosing string unterminated too
+    let unterminated_string = stack
+        .iter()
+        .find_map(|b| b.enclosing_string)
+        .or(string_start);
+    if let Some(start) = unterminated_string {
+        warnings.push(LintWarning::new("Unterminated string", start..chars.len()));
+    }
+
+    for open in stack.iter().filter(|b| b.enclosing_string.is_none()) {
+        warnings.push(LintWarning::new(
+            format!("Unclosed '{}'", open.ch),
+            open.pos..open.pos + 1,
+        ));
+    }
+
+    if unterminated_string.is_none()
+        && let Some(pos) = trailing_pipe(&chars, last_code)
+    {
+        warnings.push(LintWarning::new("Trailing pipe", pos..pos + 1));
+    }
+
+    warnings.sort_by_key(|w| w.span.start);
+    warnings
+}
+
+fn matching_open(close: char) -> char {
+    match close {
+        ')' => '(',
+        ']' => '[',
+        _ => '{',
+    }
+}
+
+fn matching_close(open: char) -> char {
+    match open {
+        '(' => ')',
+        '[' => ']',
+        _ => '}',
+    }
+}
+
+/// Identifier immediately before position `pos` (ignoring whitespace)
+fn preceding_identifier(chars: &[char], pos: usize) -> String {
+    let mut end = pos;
+    while end > 0 && chars[end - 1].is_whitespace() {
+        end -= 1;
+    }
+    let mut start = end;
+    while start > 0 && (chars[start - 1].is_alphanumeric() || chars[start - 1] == '_') {
+        start -= 1;
+    }
+    // Field access like `.select(` is not a function call
+    if start > 0 && matches!(chars[start - 1], '.' | '$') {
+        return String::new();
+    }
+    chars[start..end].iter().collect()
+}
+
+/// Whether `=` at `pos` is a plain assignment (not `==`, `!=`, `<=`, `|=`, `+=`, ...)
+fn is_bare_assignment(chars: &[char], pos: usize) -> bool {
+    let prev = pos.checked_sub(1).map(|p| chars[p]);
+    let next = chars.get(pos + 1).copied();
+    !matches!(
+        prev,
+        Some('=' | '!' | '<' | '>' | '|' | '+' | '-' | '*' | '/' | '%')
+    ) && next != Some('=')
+}
+
+/// Position of a `|` that ends the query (ignoring trailing whitespace)
+///
+/// `last_code` is the last character outside strings and comments, so a `|`
+/// ending a comment is not taken for a pipe.
+fn trailing_pipe(chars: &[char], last_code: Option<usize>) -> Option<usize> {
+    let last = chars.iter().rposition(|c| !c.is_whitespace())?;
+    (chars[last] == '|' && last_code == Some(last)).then_some(last)
+}
+
+#[cfg(test)]
+#[path = "lint_tests.rs"]
+mod lint_tests;
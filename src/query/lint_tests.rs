@@ -0,0 +1,156 @@
+//! Tests for lint
+
+use super::*;
+
+fn messages(query: &str) -> Vec<String> {
+    lint(query).into_iter().map(|w| w.message).collect()
+}
+
+#[test]
+fn test_valid_queries_have_no_warnings() {
+    for query in [
+        "",
+        ".",
+        ".services[].name",
+        "map(select(.age >= 18))",
+        r#"{name: .a, "b": [1, 2]}"#,
+        ".a |= 1",
+        r#"select(.name == "x")"#,
+        r#""\(.a) and \(.b)""#,
+        ".a // .b",
+        ".a # comment with ( and |",
+    ] {
+        assert!(lint(query).is_empty(), "unexpected warning for {query:?}");
+    }
+}
+
+#[test]
+fn test_unclosed_bracket() {
+    let warnings = lint("map(.a");
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].message, "Unclosed '('");
+    assert_eq!(warnings[0].span, 3..4);
+}
+
+#[test]
+fn test_multiple_unclosed_brackets_reported_in_order() {
+    assert_eq!(messages("{a: [1"), vec!["Unclosed '{'", "Unclosed '['"]);
+}
+
+#[test]
+fn test_unmatched_closing_bracket() {
+    let warnings = lint(".a)");
+    assert_eq!(warnings[0].message, "Unmatched ')'");
+    assert_eq!(warnings[0].span, 2..3);
+}
+
+#[test]
+fn test_mismatched_bracket() {
+    let warnings = lint("map(.a]");
+    assert_eq!(warnings[0].message, "Mismatched ']' (expected ')')");
+    assert_eq!(warnings[0].span, 6..7);
+}
+
+#[test]
+fn test_brackets_inside_strings_ignored() {
+    assert!(lint(r#"select(.a == "(")"#).is_empty());
+}
+
+#[test]
+fn test_unterminated_string() {
+    let warnings = lint(r#".a == "abc"#);
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].message, "Unterminated string");
+    assert_eq!(warnings[0].span, 6..10);
+}
+
+#[test]
+fn test_escaped_quote_does_not_end_string() {
+    assert_eq!(messages(r#""a\"b"#), vec!["Unterminated string"]);
+    assert!(lint(r#""a\"b""#).is_empty());
+}
+
+#[test]
+fn test_unclosed_interpolation() {
+    assert_eq!(messages(r#""\(.a"#), vec!["Unterminated string"]);
+}
+
+#[test]
+fn test_brackets_inside_interpolation_checked() {
+    assert_eq!(
+        messages(r#""\(.a])""#),
+        vec!["Mismatched ']' (expected ')')"]
+    );
+}
+
+#[test]
+fn test_assignment_in_select() {
+    let warnings = lint("select(.a = 1)");
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].message, "Use '==' to compare in select()");
+    assert_eq!(warnings[0].span, 10..11);
+}
+
+#[test]
+fn test_assignment_in_nested_select() {
+    assert_eq!(
+        messages(".[] | select((.a = 1) and .b)"),
+        vec!["Use '==' to compare in select()"]
+    );
+}
+
+#[test]
+fn test_comparison_operators_in_select_allowed() {
+    for query in [
+        "select(.a == 1)",
+        "select(.a != 1)",
+        "select(.a <= 1)",
+        "select(.a >= 1)",
+    ] {
+        assert!(lint(query).is_empty(), "unexpected warning for {query:?}");
+    }
+}
+
+#[test]
+fn test_update_assignment_in_select_allowed() {
+    assert!(lint("select(.a |= 1)").is_empty());
+    assert!(lint("select(.a += 1)").is_empty());
+    assert!(lint("select(.a //= 1)").is_empty());
+}
+
+#[test]
+fn test_assignment_outside_select_allowed() {
+    assert!(lint(".a = 1").is_empty());
+    assert!(lint("map(.a = 1)").is_empty());
+}
+
+#[test]
+fn test_select_field_is_not_select_call() {
+    assert!(lint(".select(.a = 1)").is_empty());
+}
+
+#[test]
+fn test_trailing_pipe() {
+    let warnings = lint(".a | ");
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].message, "Trailing pipe");
+    assert_eq!(warnings[0].span, 3..4);
+}
+
+#[test]
+fn test_pipe_inside_string_is_not_trailing() {
+    assert!(lint(r#".a + "|""#).is_empty());
+}
+
+#[test]
+fn test_trailing_pipe_after_string_with_hash() {
+    let warnings = lint(r#"."a#b" | "#);
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].message, "Trailing pipe");
+    assert_eq!(warnings[0].span, 7..8);
+}
+
+#[test]
+fn test_update_pipe_is_not_trailing() {
+    assert!(lint(".a |= .").is_empty());
+}
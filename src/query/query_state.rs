@@ -1,14 +1,19 @@
+use std::collections::VecDeque;
 use std::sync::Arc;
 use std::sync::mpsc::{Receiver, Sender, channel};
 use tokio_util::sync::CancellationToken;
 
-use ansi_to_tui::IntoText;
-use ratatui::text::{Line, Span, Text};
-
+use crate::error::parser::{self as error_parser, ErrorKind, ParsedJqError};
 use crate::query::executor::JqExecutor;
-use crate::query::worker::preprocess::{parse_and_detect_type, strip_ansi_codes};
-use crate::query::worker::types::RenderedLine;
-use crate::query::worker::{QueryRequest, QueryResponse, spawn_worker};
+use crate::query::incremental;
+use crate::query::language::QueryLanguage;
+use crate::query::library::JqLibrary;
+use crate::query::result_cache::{CachedResult, DEFAULT_CACHE_SIZE, ResultCache};
+use crate::query::result_lines::ResultLines;
+use crate::query::variables::QueryVariable;
+use crate::query::worker::preprocess::{parse_and_detect_type, preprocess_result};
+use crate::query::worker::types::{ProcessedResult, QueryError};
+use crate::query::worker::{PrefixInput, QueryRequest, QueryResponse, spawn_worker};
 use serde_json::Value;
 
 /// Type of result returned by a jq query
@@ -32,23 +37,34 @@ pub enum ResultType {
     Null,
 }
 
+/// Number of query runs kept for the timing overlay
+const MAX_RECENT_TIMINGS: usize = 10;
+
+/// Wall-clock time the worker spent on one query run
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QueryTiming {
+    pub query: String,
+    pub elapsed_ms: u64,
+}
+
 /// Query execution state
 pub struct QueryState {
     pub executor: JqExecutor,
+    /// Query output as printed by jq (uncolored)
+    /// Empty for spooled results, which are only kept in their spool file
     pub result: Result<String, String>,
-    /// Cached last successful result with ANSI colors (for rendering on error)
-    /// Uses Arc to make cloning cheap - autocomplete clones this on every keystroke!
-    pub last_successful_result: Option<Arc<String>>,
-    /// Unformatted result without ANSI codes (for autosuggestion analysis)
+    /// Last successful result (for rendering on error and autosuggestion analysis)
     /// Uses Arc to make cloning cheap - autocomplete clones this on every keystroke!
+    /// None for spooled results, see last_successful_result_text()
     pub last_successful_result_unformatted: Option<Arc<String>>,
     /// Parsed JSON value of last successful result (for autocomplete field extraction)
     /// Uses Arc to avoid re-parsing large files on every keystroke!
     /// This is THE critical optimization for large files.
+    /// None for spooled results
     pub last_successful_result_parsed: Option<Arc<Value>>,
-    /// Pre-rendered Text<'static> for display
-    /// Avoids expensive into_text() conversion in render loop (~10x/sec)
-    pub last_successful_result_rendered: Option<Text<'static>>,
+    /// Line index over the last successful result for display
+    /// Only the visible lines are highlighted, once per frame
+    pub last_successful_result_lines: Option<ResultLines>,
     /// Cached processed result for AI context (minified/truncated)
     /// Updated only when last_successful_result_unformatted changes
     pub last_successful_result_for_context: Option<Arc<String>>,
@@ -64,8 +80,23 @@ pub struct QueryState {
     pub(crate) cached_line_widths: Option<Arc<Vec<u16>>>,
     /// Cached execution time in milliseconds
     pub(crate) cached_execution_time_ms: Option<u64>,
+    /// Execution times of the latest worker runs, newest first
+    recent_timings: VecDeque<QueryTiming>,
     /// Whether current result is null/empty (valid query but no results)
     pub is_empty_result: bool,
+    /// Structured form of the current error (for overlay text and input highlighting)
+    pub parsed_error: Option<ParsedJqError>,
+    /// Whether the shown result is what the current query printed before
+    /// failing, rather than the last successful result
+    pub shows_partial_output: bool,
+    /// Language queries are written in; non-jq queries are translated before running
+    pub language: QueryLanguage,
+    /// Recently executed queries and their results (for instant undo/redo)
+    result_cache: ResultCache,
+    /// Query, as run by jq, whose output is the last successful result, when
+    /// that result was computed with the current variables and can seed
+    /// incremental evaluation
+    incremental_base_query: Option<String>,
 
     // Async execution support
     /// Channel to send query requests to worker
@@ -78,22 +109,35 @@ pub struct QueryState {
     in_flight_request_id: Option<u64>,
     /// Cancellation token for current request
     current_cancel_token: Option<CancellationToken>,
+    /// Raw query text of the in-flight request (cache key for its result)
+    in_flight_query: Option<String>,
+    /// The in-flight request runs the query on appended records only, and
+    /// its output extends the current result
+    in_flight_append: bool,
+    /// Query completed without the worker (cache hit or translation error)
+    /// not yet reported by poll_response()
+    pending_cached_completion: Option<String>,
 }
 
 impl QueryState {
     /// Create a new QueryState with the given JSON input
     ///
     /// Spawns a background worker thread for async query execution.
+    #[allow(dead_code)]
     pub fn new(json_input: String) -> Self {
+        Self::with_cache_size(json_input, DEFAULT_CACHE_SIZE)
+    }
+
+    /// Create a new QueryState keeping up to `cache_size` recent results in memory
+    ///
+    /// A size of 0 disables the result cache.
+    pub fn with_cache_size(json_input: String, cache_size: usize) -> Self {
         let executor = JqExecutor::new(json_input.clone());
         let cancel_token = CancellationToken::new();
         let result = executor
             .execute_with_cancel(".", &cancel_token)
             .map_err(|e| e.to_string());
-        let last_successful_result = result.as_ref().ok().map(|s| Arc::new(s.clone()));
-        let last_successful_result_unformatted = last_successful_result
-            .as_ref()
-            .map(|s| Arc::new(strip_ansi_codes(s)));
+        let last_successful_result_unformatted = result.as_ref().ok().map(|s| Arc::new(s.clone()));
 
         // Pre-process for AI context
         let last_successful_result_for_context =
@@ -116,14 +160,6 @@ impl QueryState {
                 })
                 .unwrap_or((None, None));
 
-        // Pre-render result to avoid expensive conversion in render loop
-        let last_successful_result_rendered = last_successful_result.clone().map(|s| {
-            s.as_bytes()
-                .to_vec()
-                .into_text()
-                .unwrap_or_else(|_| Text::raw(s.to_string()))
-        });
-
         // Cache line count, max width, and line widths for initial result
         let (cached_line_count, cached_max_line_width, cached_line_widths) =
             last_successful_result_unformatted
@@ -148,6 +184,25 @@ impl QueryState {
                 })
                 .unwrap_or((0, 0, None));
 
+        let last_successful_result_lines = last_successful_result_unformatted
+            .as_ref()
+            .map(|s| ResultLines::new(Arc::clone(s)));
+
+        // Spooled results are only kept in their spool file
+        let (result, last_successful_result_unformatted, last_successful_result_parsed) =
+            if last_successful_result_lines
+                .as_ref()
+                .is_some_and(ResultLines::is_spooled)
+            {
+                (Ok(String::new()), None, None)
+            } else {
+                (
+                    result,
+                    last_successful_result_unformatted,
+                    last_successful_result_parsed,
+                )
+            };
+
         let (request_tx, request_rx) = channel();
         let (response_tx, response_rx) = channel();
 
@@ -156,10 +211,9 @@ impl QueryState {
         Self {
             executor,
             result,
-            last_successful_result,
             last_successful_result_unformatted,
             last_successful_result_parsed,
-            last_successful_result_rendered,
+            last_successful_result_lines,
             last_successful_result_for_context,
             base_query_for_suggestions,
             base_type_for_suggestions,
@@ -167,35 +221,104 @@ impl QueryState {
             cached_max_line_width,
             cached_line_widths,
             cached_execution_time_ms: None,
+            recent_timings: VecDeque::with_capacity(MAX_RECENT_TIMINGS),
             is_empty_result: false,
+            parsed_error: None,
+            shows_partial_output: false,
+            language: QueryLanguage::default(),
+            result_cache: ResultCache::new(cache_size),
+            incremental_base_query: Some(".".to_string()),
             request_tx: Some(request_tx),
             response_rx: Some(response_rx),
             next_request_id: 1, // Reserve 0 for worker errors
             in_flight_request_id: None,
             current_cancel_token: None,
+            in_flight_query: None,
+            in_flight_append: false,
+            pending_cached_completion: None,
         }
     }
 
     /// Execute a query and update results
     /// Only caches non-null results for autosuggestions
     pub fn execute(&mut self, query: &str) {
+        let Some(query) = self.translate(query) else {
+            return;
+        };
+        let query = query.as_str();
         let cancel_token = CancellationToken::new();
-        self.result = self
-            .executor
-            .execute_with_cancel(query, &cancel_token)
-            .map_err(|e| e.to_string());
-        if let Ok(result) = &self.result {
-            self.update_successful_result(result.clone(), query);
+        let result = self.executor.execute_with_cancel(query, &cancel_token);
+        self.shows_partial_output = false;
+        if let Err(QueryError::FailedWithOutput { output, .. }) = &result
+            && let Ok(processed) = preprocess_result(output.clone(), query, &cancel_token)
+        {
+            self.show_partial_output(processed);
+        }
+        match result.map_err(|e| e.to_string()) {
+            Ok(output) => self.update_successful_result(output, query),
+            Err(message) => {
+                self.parsed_error = Some(error_parser::parse(&message, query));
+                self.result = Err(message);
+            }
         }
     }
 
-    /// Update cached results for autosuggestions
+    /// Translate `query` from the active language into jq
+    ///
+    /// On failure the translation error becomes the current result, located
+    /// in the original query text so the input field can highlight it.
+    fn translate(&mut self, query: &str) -> Option<String> {
+        match self.language.to_jq(query) {
+            Ok(translated) => Some(translated),
+            Err(err) => {
+                let message = format!("{} error: {}", self.language.label(), err);
+                self.parsed_error = Some(ParsedJqError {
+                    kind: ErrorKind::Syntax,
+                    message: message.clone(),
+                    line: None,
+                    column: None,
+                    span: Some(err.position..err.position + 1),
+                    query: query.to_string(),
+                });
+                self.result = Err(message);
+                self.is_empty_result = false;
+                self.shows_partial_output = false;
+                None
+            }
+        }
+    }
+
+    /// Show what a failing query printed before its error in place of the
+    /// last successful result
+    ///
+    /// The output is not a complete result, so it is neither cached nor used
+    /// as the base of incremental evaluation.
+    fn show_partial_output(&mut self, processed: ProcessedResult) {
+        self.shows_partial_output = true;
+        self.is_empty_result = false;
+        self.incremental_base_query = None;
+        self.last_successful_result_for_context =
+            Some(Arc::new(crate::ai::context::prepare_json_for_context(
+                &processed.unformatted,
+                crate::ai::context::MAX_JSON_SAMPLE_LENGTH,
+            )));
+        self.set_successful_result(processed.unformatted, processed.lines, processed.parsed);
+        self.cached_line_count = processed.line_count;
+        self.cached_max_line_width = processed.max_width;
+        self.cached_line_widths = Some(processed.line_widths);
+        self.cached_execution_time_ms = processed.execution_time_ms;
+        self.base_query_for_suggestions = Some(processed.query);
+        self.base_type_for_suggestions = Some(processed.result_type);
+    }
+
+    /// Show `output` as the result and update cached results for autosuggestions
     ///
     /// Only caches non-null results to avoid polluting suggestions with partial queries.
     fn update_successful_result(&mut self, output: String, query: &str) {
-        // Partial queries like ".s" return "null"; keep last meaningful result for suggestions
-        let unformatted = strip_ansi_codes(&output);
+        self.parsed_error = None;
+        self.shows_partial_output = false;
 
+        // Partial queries like ".s" return "null"; keep last meaningful result for suggestions
         // Compute line metrics and is_only_nulls in a single pass
         // is_only_nulls is true if all non-empty lines are "null" (including vacuous truth for empty output)
         let mut cached_line_count: u32 = 0;
@@ -203,7 +326,7 @@ impl QueryState {
         let mut widths: Vec<u16> = Vec::new();
         let mut is_only_nulls = true;
 
-        for line in unformatted.lines() {
+        for line in output.lines() {
             cached_line_count += 1;
             let width = line.len().min(u16::MAX as usize);
             widths.push(width as u16);
@@ -221,30 +344,24 @@ impl QueryState {
 
         self.is_empty_result = is_only_nulls;
 
-        if !is_only_nulls {
-            // Pre-render result BEFORE moving output into Arc
-            // This avoids expensive into_text() conversion in render loop
-            let rendered = output
-                .as_bytes()
-                .to_vec()
-                .into_text()
-                .unwrap_or_else(|_| Text::raw(output.clone()));
-
-            self.last_successful_result_rendered = Some(rendered);
-            self.last_successful_result = Some(Arc::new(output));
-            self.last_successful_result_unformatted = Some(Arc::new(unformatted.clone()));
+        if is_only_nulls {
+            self.result = Ok(output);
+        } else {
+            let output = Arc::new(output);
+            self.incremental_base_query = Some(query.to_string());
 
             // Pre-process for AI context (minified/truncated)
             self.last_successful_result_for_context =
                 Some(Arc::new(crate::ai::context::prepare_json_for_context(
-                    &unformatted,
+                    &output,
                     crate::ai::context::MAX_JSON_SAMPLE_LENGTH,
                 )));
 
             // Parse JSON and detect type in single pass (avoids duplicate parsing)
-            let (parsed, result_type) = parse_and_detect_type(&unformatted);
-            self.last_successful_result_parsed = parsed.map(Arc::new);
+            let (parsed, result_type) = parse_and_detect_type(&output);
             self.base_type_for_suggestions = Some(result_type);
+            let lines = ResultLines::new(Arc::clone(&output));
+            self.set_successful_result(output, lines, parsed.map(Arc::new));
 
             // Trim trailing whitespace/incomplete operators: ".services | ." → ".services"
             let base_query = Self::normalize_base_query(query);
@@ -253,7 +370,55 @@ impl QueryState {
             self.cached_line_count = cached_line_count;
             self.cached_max_line_width = cached_max_line_width;
             self.cached_line_widths = Some(Arc::new(widths));
+
+            self.cache_current_result(query);
+        }
+    }
+
+    /// Make `text` the last successful result and show it
+    ///
+    /// Spooled results are only kept in their spool file: the text, its
+    /// parsed value and the shown copy are dropped, leaving `result` empty.
+    fn set_successful_result(
+        &mut self,
+        text: Arc<String>,
+        lines: ResultLines,
+        parsed: Option<Arc<Value>>,
+    ) {
+        if lines.is_spooled() {
+            self.result = Ok(String::new());
+            self.last_successful_result_unformatted = None;
+            self.last_successful_result_parsed = None;
+        } else {
+            self.result = Ok(text.as_ref().clone());
+            self.last_successful_result_unformatted = Some(text);
+            self.last_successful_result_parsed = parsed;
         }
+        self.last_successful_result_lines = Some(lines);
+    }
+
+    /// Text of the last successful result, read back from the spool file
+    /// for spooled results
+    ///
+    /// For actions on the whole result, such as copying it; None if there
+    /// is no result or its spool file can't be read.
+    pub fn last_successful_result_text(&self) -> Option<Arc<String>> {
+        match &self.last_successful_result_unformatted {
+            Some(text) => Some(Arc::clone(text)),
+            None => self.last_successful_result_lines.as_ref()?.full_text(),
+        }
+    }
+
+    /// Whether the current result is only kept in its spool file, leaving
+    /// `result` empty
+    #[allow(dead_code)]
+    pub fn result_is_spooled(&self) -> bool {
+        matches!(&self.result, Ok(output) if output.is_empty())
+            && !self.is_empty_result
+            && self
+                .last_successful_result_lines
+                .as_ref()
+                .is_some_and(ResultLines::is_spooled)
     }
 
     /// Execute query asynchronously
@@ -262,10 +427,45 @@ impl QueryState {
     /// Call poll_response() in main event loop to get results.
     ///
     /// Automatically cancels any in-flight request before starting new one.
+    /// Queries found in the result cache are applied immediately without
+    /// involving the worker; poll_response() still reports their completion.
     pub fn execute_async(&mut self, query: &str) {
         // Cancel any existing request
         self.cancel_in_flight();
 
+        let Some(query) = self.translate(query) else {
+            // Report the translation error like a completed query
+            self.pending_cached_completion = Some(query.to_string());
+            return;
+        };
+        let query = query.as_str();
+
+        if self.apply_cached_result(query) {
+            return;
+        }
+
+        let prefix = self.prefix_input_for(query);
+        self.send_request(query, prefix, None);
+    }
+
+    /// Execute `query` on this thread in place of any in-flight request
+    ///
+    /// For when the result must match the query before going on; like a
+    /// cache hit, poll_response() still reports the completion.
+    pub fn execute_now(&mut self, query: &str) {
+        self.cancel_in_flight();
+        self.execute(query);
+        self.pending_cached_completion = Some(query.to_string());
+    }
+
+    /// Send `query` to the worker, evaluating it against `prefix` if given
+    /// and appending its output to `append_to` if given
+    fn send_request(
+        &mut self,
+        query: &str,
+        prefix: Option<PrefixInput>,
+        append_to: Option<ResultLines>,
+    ) {
         // Allocate new request ID
         let request_id = self.next_request_id;
         self.next_request_id = self.next_request_id.wrapping_add(1);
@@ -279,6 +479,7 @@ impl QueryState {
         let cancel_token = CancellationToken::new();
         self.current_cancel_token = Some(cancel_token.clone());
         self.in_flight_request_id = Some(request_id);
+        self.in_flight_query = Some(query.to_string());
 
         // Send request to worker
         if let Some(ref tx) = self.request_tx {
@@ -286,6 +487,10 @@ impl QueryState {
                 query: query.to_string(),
                 request_id,
                 cancel_token,
+                prefix,
+                append_to,
+                variables: self.executor.variables(),
+                library: self.executor.library(),
             };
 
             // If send fails, worker died - clear channels
@@ -295,6 +500,7 @@ impl QueryState {
                 self.response_rx = None;
                 self.in_flight_request_id = None;
                 self.current_cancel_token = None;
+                self.in_flight_query = None;
                 self.result = Err("Query worker disconnected".to_string());
             }
         } else {
@@ -308,6 +514,222 @@ impl QueryState {
             token.cancel();
         }
         self.in_flight_request_id = None;
+        self.in_flight_query = None;
+        self.in_flight_append = false;
+        self.pending_cached_completion = None;
+    }
+
+    /// Append records to the input, as when following a growing file
+    ///
+    /// The query worker is restarted on the extended input. When the current
+    /// result is up to date and `query` handles each input value on its own,
+    /// only the new records are run through it and the output is appended
+    /// to the result once poll_response() reports completion. Returns false
+    /// when the caller has to re-run the query instead.
+    pub fn append_input(&mut self, records: &str, query: &str) -> bool {
+        let up_to_date = !self.is_pending()
+            && self.result.is_ok()
+            && !self.is_empty_result
+            && self.last_successful_result_lines.is_some();
+        self.cancel_in_flight();
+
+        let mut json_input = self.executor.json_input().to_string();
+        if !json_input.is_empty() && !json_input.ends_with('\n') {
+            json_input.push('\n');
+        }
+        json_input.push_str(records);
+
+        let mut executor = JqExecutor::new(json_input.clone());
+        executor.set_variables(self.executor.variables().to_vec());
+        executor.set_library(self.executor.library());
+        self.executor = executor;
+        self.restart_worker(json_input);
+
+        // Cached results and the incremental base lack the new records
+        self.result_cache.clear();
+        self.incremental_base_query = None;
+
+        if !up_to_date {
+            return false;
+        }
+        let Ok(filter) = self.language.to_jq(query) else {
+            return false;
+        };
+        if !incremental::is_per_input(&filter) {
+            return false;
+        }
+
+        // Should the filter fail on the records, the worker's fallback runs
+        // the full query, which fails on the same records
+        let prefix = PrefixInput {
+            input: Arc::new(records.to_string()),
+            filter: filter.clone(),
+        };
+        // The worker appends the output to the result, so the spool file of
+        // a spooled result is extended without reading it back
+        let append_to = self.last_successful_result_lines.clone();
+        self.send_request(&filter, Some(prefix), append_to);
+        self.in_flight_append = self.in_flight_request_id.is_some();
+        true
+    }
+
+    /// Replace the query worker with one running against `json_input`
+    ///
+    /// The old worker exits once its request channel is dropped.
+    fn restart_worker(&mut self, json_input: String) {
+        let (request_tx, request_rx) = channel();
+        let (response_tx, response_rx) = channel();
+        spawn_worker(json_input, request_rx, response_tx);
+        self.request_tx = Some(request_tx);
+        self.response_rx = Some(response_rx);
+    }
+
+    /// Variables bound for every query (`--arg`/`--argjson`)
+    pub fn variables(&self) -> Arc<Vec<QueryVariable>> {
+        self.executor.variables()
+    }
+
+    /// Replace the bound variables
+    ///
+    /// Cached results and the incremental base were computed with the old
+    /// values, so they are dropped; the caller re-executes the query.
+    pub fn set_variables(&mut self, variables: Vec<QueryVariable>) {
+        if *self.executor.variables() == variables {
+            return;
+        }
+        self.cancel_in_flight();
+        self.executor.set_variables(variables);
+        self.result_cache.clear();
+        self.incremental_base_query = None;
+    }
+
+    /// Module search paths and user library for every query
+    pub fn library(&self) -> Arc<JqLibrary> {
+        self.executor.library()
+    }
+
+    /// Replace the module search paths and user library
+    ///
+    /// Like [`set_variables`](Self::set_variables), drops results computed
+    /// without them.
+    pub fn set_library(&mut self, library: Arc<JqLibrary>) {
+        if *self.executor.library() == *library {
+            return;
+        }
+        self.cancel_in_flight();
+        self.executor.set_library(library);
+        self.result_cache.clear();
+        self.incremental_base_query = None;
+    }
+
+    /// Restore a previously cached result for `query`, if present
+    ///
+    /// Returns true on cache hit.
+    fn apply_cached_result(&mut self, query: &str) -> bool {
+        let Some(cached) = self.result_cache.get(query).cloned() else {
+            return false;
+        };
+        self.restore_snapshot(cached);
+        self.incremental_base_query = Some(query.to_string());
+        true
+    }
+
+    /// Execution times of the latest queries run by the worker, newest first
+    ///
+    /// Cache hits and snapshots are not included as nothing was executed.
+    pub fn recent_timings(&self) -> impl Iterator<Item = &QueryTiming> {
+        self.recent_timings.iter()
+    }
+
+    pub(crate) fn record_timing(&mut self, query: String, elapsed_ms: u64) {
+        if self.recent_timings.len() == MAX_RECENT_TIMINGS {
+            self.recent_timings.pop_back();
+        }
+        self.recent_timings
+            .push_front(QueryTiming { query, elapsed_ms });
+    }
+
+    /// Show a previously taken snapshot as the current result
+    ///
+    /// Like a cache hit, poll_response() reports the completion.
+    pub fn restore_snapshot(&mut self, cached: CachedResult) {
+        self.result = Ok(cached.unformatted.as_ref().clone());
+        self.is_empty_result = false;
+        self.parsed_error = None;
+        self.shows_partial_output = false;
+        self.last_successful_result_unformatted = Some(cached.unformatted);
+        self.incremental_base_query = None;
+        self.last_successful_result_lines = Some(cached.lines);
+        self.last_successful_result_parsed = cached.parsed;
+        self.last_successful_result_for_context = cached.for_context;
+        self.cached_line_count = cached.line_count;
+        self.cached_max_line_width = cached.max_width;
+        self.cached_line_widths = cached.line_widths;
+        self.cached_execution_time_ms = cached.execution_time_ms;
+        self.base_query_for_suggestions = cached.base_query.clone();
+        self.base_type_for_suggestions = cached.result_type;
+
+        self.pending_cached_completion = Some(cached.base_query.unwrap_or_default());
+    }
+
+    /// Previous successful result to evaluate `query` against incrementally
+    ///
+    /// Only used when `query` extends the exact query that produced that
+    /// result and the result is smaller than the original input (otherwise
+    /// there is no gain).
+    fn prefix_input_for(&self, query: &str) -> Option<PrefixInput> {
+        let base_query = self.incremental_base_query.as_deref()?;
+        let base_output = self.last_successful_result_unformatted.as_ref()?;
+
+        if base_output.len() >= self.executor.json_input().len() {
+            return None;
+        }
+
+        incremental::suffix_filter(base_query, query).map(|filter| PrefixInput {
+            input: Arc::clone(base_output),
+            filter,
+        })
+    }
+
+    /// Results cached for the queries run so far
+    pub fn result_cache(&self) -> &ResultCache {
+        &self.result_cache
+    }
+
+    /// Use `cache` from now on, e.g. the one of the query tab being shown,
+    /// returning the cache used until now
+    pub fn replace_result_cache(&mut self, cache: ResultCache) -> ResultCache {
+        std::mem::replace(&mut self.result_cache, cache)
+    }
+
+    /// Store the current successful result in the cache under `query`
+    fn cache_current_result(&mut self, query: &str) {
+        if let Some(cached) = self.snapshot() {
+            self.result_cache.insert(query, cached);
+        }
+    }
+
+    /// Snapshot of the last successful result, restorable without jq
+    pub fn snapshot(&self) -> Option<CachedResult> {
+        let (Some(unformatted), Some(lines)) = (
+            &self.last_successful_result_unformatted,
+            &self.last_successful_result_lines,
+        ) else {
+            return None;
+        };
+
+        Some(CachedResult {
+            unformatted: Arc::clone(unformatted),
+            lines: lines.clone(),
+            parsed: self.last_successful_result_parsed.clone(),
+            for_context: self.last_successful_result_for_context.clone(),
+            line_count: self.cached_line_count,
+            max_width: self.cached_max_line_width,
+            line_widths: self.cached_line_widths.clone(),
+            result_type: self.base_type_for_suggestions.clone(),
+            base_query: self.base_query_for_suggestions.clone(),
+            execution_time_ms: self.cached_execution_time_ms,
+        })
     }
 
     /// Poll for query responses (non-blocking)
@@ -315,10 +737,13 @@ impl QueryState {
     /// Call this in main event loop to check for completed queries.
     /// Returns the query that produced the last completed result (for AI context), or None if no update.
     pub fn poll_response(&mut self) -> Option<String> {
-        let mut completed_query: Option<String> = None;
+        // Cache hits complete synchronously in execute_async()
+        let mut completed_query: Option<String> = self.pending_cached_completion.take();
 
         // Take the receiver temporarily to avoid borrow checker issues
-        let rx = self.response_rx.take()?;
+        let Some(rx) = self.response_rx.take() else {
+            return completed_query;
+        };
 
         // Process all available responses
         // Keep last completed query (if multiple responses, most recent wins)
@@ -341,6 +766,7 @@ impl QueryState {
                         self.result = Err("Query worker disconnected".to_string());
                         self.in_flight_request_id = None;
                         self.current_cancel_token = None;
+                        self.in_flight_query = None;
                         completed_query = Some(String::new());
                     }
                     // Don't put receiver back - it's disconnected
@@ -369,42 +795,63 @@ impl QueryState {
                     return None;
                 }
 
+                if std::mem::take(&mut self.in_flight_append) {
+                    self.in_flight_request_id = None;
+                    self.current_cancel_token = None;
+                    let raw_query = self.in_flight_query.take();
+                    self.append_processed(processed, raw_query.as_deref());
+                    return raw_query;
+                }
+
                 // Use precomputed is_only_nulls from worker thread
                 let is_only_nulls = processed.is_only_nulls;
 
+                if let Some(elapsed_ms) = processed.execution_time_ms {
+                    let query = self
+                        .in_flight_query
+                        .clone()
+                        .unwrap_or_else(|| processed.query.clone());
+                    self.record_timing(query, elapsed_ms);
+                }
+
                 self.is_empty_result = is_only_nulls;
+                self.parsed_error = None;
+                self.shows_partial_output = false;
 
                 // Clear in-flight tracking immediately
                 self.in_flight_request_id = None;
                 self.current_cancel_token = None;
+                let raw_query = self.in_flight_query.take();
 
                 // Only update cache if result is not null (same as sync path)
                 if !is_only_nulls {
-                    // Convert rendered lines to Text (fast - just allocations)
-                    let rendered = Self::rendered_lines_to_text(processed.rendered_lines);
-
                     // Update result and all caches
-                    self.result = Ok(processed.output.as_ref().clone());
-                    self.last_successful_result = Some(processed.output);
-                    self.last_successful_result_unformatted = Some(processed.unformatted.clone());
-                    self.last_successful_result_rendered = Some(rendered);
-                    self.last_successful_result_parsed = processed.parsed;
+                    self.incremental_base_query = raw_query.clone();
                     // Pre-process for AI context
                     self.last_successful_result_for_context =
                         Some(Arc::new(crate::ai::context::prepare_json_for_context(
                             &processed.unformatted,
                             crate::ai::context::MAX_JSON_SAMPLE_LENGTH,
                         )));
+                    self.set_successful_result(
+                        processed.unformatted,
+                        processed.lines,
+                        processed.parsed,
+                    );
                     self.cached_line_count = processed.line_count;
                     self.cached_max_line_width = processed.max_width;
                     self.cached_line_widths = Some(processed.line_widths);
                     self.cached_execution_time_ms = processed.execution_time_ms;
                     self.base_query_for_suggestions = Some(processed.query.clone());
                     self.base_type_for_suggestions = Some(processed.result_type);
+
+                    if let Some(raw_query) = raw_query {
+                        self.cache_current_result(&raw_query);
+                    }
                 } else {
                     // Null result - preserve ALL cache including rendered output
                     // Only update self.result so it shows as "null" in error state
-                    self.result = Ok(processed.output.as_ref().clone());
+                    self.result = Ok(processed.unformatted.as_ref().clone());
                 }
 
                 Some(processed.query)
@@ -419,25 +866,90 @@ impl QueryState {
                 if request_id == 0 || Some(request_id) == current_request_id {
                     self.in_flight_request_id = None;
                     self.current_cancel_token = None;
+                    self.in_flight_query = None;
+                    self.in_flight_append = false;
+                    self.parsed_error = Some(error_parser::parse(&message, &query));
                     self.result = Err(message);
                     self.is_empty_result = false;
+                    self.shows_partial_output = false;
                     // Return the query that produced this error for AI context
                     return Some(query);
                 }
 
                 None
             }
+            QueryResponse::PartialError {
+                processed,
+                message,
+                query,
+                request_id,
+            } => {
+                if Some(request_id) != current_request_id {
+                    return None;
+                }
+                self.in_flight_request_id = None;
+                self.current_cancel_token = None;
+                self.in_flight_query = None;
+                self.in_flight_append = false;
+                self.show_partial_output(processed);
+                self.parsed_error = Some(error_parser::parse(&message, &query));
+                self.result = Err(message);
+                Some(query)
+            }
             QueryResponse::Cancelled { request_id } => {
                 // Only clear in-flight if it matches
                 if Some(request_id) == current_request_id {
                     self.in_flight_request_id = None;
                     self.current_cancel_token = None;
+                    self.in_flight_query = None;
+                    self.in_flight_append = false;
                 }
                 None
             }
         }
     }
 
+    /// Extend the current result with the output for appended records
+    ///
+    /// The worker has already merged the output into `processed.lines`.
+    fn append_processed(&mut self, processed: ProcessedResult, raw_query: Option<&str>) {
+        if self.last_successful_result_lines.is_none() {
+            return;
+        }
+
+        if !processed.unformatted.is_empty() {
+            let mut widths = self
+                .cached_line_widths
+                .as_deref()
+                .cloned()
+                .unwrap_or_default();
+            widths.extend(processed.line_widths.iter());
+
+            self.cached_line_count += processed.line_count;
+            self.cached_max_line_width = self.cached_max_line_width.max(processed.max_width);
+            self.cached_line_widths = Some(Arc::new(widths));
+            if self.base_type_for_suggestions == Some(ResultType::Object) {
+                self.base_type_for_suggestions = Some(ResultType::DestructuredObjects);
+            }
+            // A spooled result keeps the context of its start
+            if let Some(merged) = processed.lines.text() {
+                self.last_successful_result_for_context =
+                    Some(Arc::new(crate::ai::context::prepare_json_for_context(
+                        merged,
+                        crate::ai::context::MAX_JSON_SAMPLE_LENGTH,
+                    )));
+            }
+            let merged = processed.lines.text().cloned().unwrap_or_default();
+            let parsed = self.last_successful_result_parsed.take();
+            self.set_successful_result(merged, processed.lines, parsed);
+        }
+
+        if let Some(raw_query) = raw_query {
+            self.incremental_base_query = Some(raw_query.to_string());
+            self.cache_current_result(raw_query);
+        }
+    }
+
     /// Check if a query is currently pending
     pub fn is_pending(&self) -> bool {
         self.in_flight_request_id.is_some()
@@ -476,26 +988,6 @@ impl QueryState {
         base
     }
 
-    /// Convert pre-rendered lines to Text for display
-    ///
-    /// Takes Vec<RenderedLine> from worker and converts to ratatui Text.
-    /// This is a simple allocation operation - much faster than ANSI parsing.
-    fn rendered_lines_to_text(lines: Vec<RenderedLine>) -> Text<'static> {
-        Text::from(
-            lines
-                .into_iter()
-                .map(|line| {
-                    Line::from(
-                        line.spans
-                            .into_iter()
-                            .map(|s| Span::styled(s.content, s.style))
-                            .collect::<Vec<_>>(),
-                    )
-                })
-                .collect::<Vec<_>>(),
-        )
-    }
-
     /// Get the total number of lines in the current results
     /// Note: Returns u32 to handle large files (>65K lines) correctly
     /// Always uses cached value computed when result changes
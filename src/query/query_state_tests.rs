@@ -1,11 +1,21 @@
 //! Tests for query_state
 
 use super::*;
-use crate::query::worker::preprocess::{parse_and_detect_type, strip_ansi_codes};
+use crate::query::worker::preprocess::parse_and_detect_type;
 
 // Submodules
+#[path = "query_state_tests/append_tests.rs"]
+mod append_tests;
 #[path = "query_state_tests/async_preprocessing_tests.rs"]
 mod async_preprocessing_tests;
+#[path = "query_state_tests/error_tests.rs"]
+mod error_tests;
+#[path = "query_state_tests/incremental_tests.rs"]
+mod incremental_tests;
+#[path = "query_state_tests/result_cache_tests.rs"]
+mod result_cache_tests;
+#[path = "query_state_tests/spool_tests.rs"]
+mod spool_tests;
 
 #[test]
 fn test_new_query_state() {
@@ -13,7 +23,7 @@ fn test_new_query_state() {
     let state = QueryState::new(json.to_string());
 
     assert!(state.result.is_ok());
-    assert!(state.last_successful_result.is_some());
+    assert!(state.last_successful_result_unformatted.is_some());
 }
 
 #[test]
@@ -23,7 +33,7 @@ fn test_execute_updates_result() {
 
     state.execute(".name");
     assert!(state.result.is_ok());
-    assert!(state.last_successful_result.is_some());
+    assert!(state.last_successful_result_unformatted.is_some());
 }
 
 #[test]
@@ -32,7 +42,7 @@ fn test_execute_caches_successful_result() {
     let mut state = QueryState::new(json.to_string());
 
     state.execute(".value");
-    let cached = state.last_successful_result.clone();
+    let cached = state.last_successful_result_unformatted.clone();
     assert!(cached.is_some());
 
     // Execute invalid query (syntax error)
@@ -40,7 +50,7 @@ fn test_execute_caches_successful_result() {
     assert!(state.result.is_err());
 
     // Last successful result should still be cached
-    assert_eq!(state.last_successful_result, cached);
+    assert_eq!(state.last_successful_result_unformatted, cached);
 }
 
 #[test]
@@ -50,7 +60,7 @@ fn test_line_count_with_ok_result() {
 
     let content: String = (0..50).map(|i| format!("line{}\n", i)).collect();
     state.result = Ok(content.clone());
-    state.last_successful_result = Some(Arc::new(content.clone()));
+    state.last_successful_result_unformatted = Some(Arc::new(content.clone()));
     state.cached_line_count = content.lines().count() as u32;
 
     assert_eq!(state.line_count(), 50);
@@ -63,7 +73,7 @@ fn test_line_count_uses_cached_on_error() {
 
     let valid_result: String = (0..30).map(|i| format!("line{}\n", i)).collect();
     state.result = Ok(valid_result.clone());
-    state.last_successful_result = Some(Arc::new(valid_result.clone()));
+    state.last_successful_result_unformatted = Some(Arc::new(valid_result.clone()));
     state.cached_line_count = valid_result.lines().count() as u32;
 
     // Now set an error
@@ -79,7 +89,7 @@ fn test_line_count_zero_on_error_without_cache() {
     let mut state = QueryState::new(json.to_string());
 
     state.result = Err("error".to_string());
-    state.last_successful_result = None;
+    state.last_successful_result_unformatted = None;
     state.cached_line_count = 0;
 
     assert_eq!(state.line_count(), 0);
@@ -91,78 +101,30 @@ fn test_null_results_dont_overwrite_cache() {
     let mut state = QueryState::new(json.to_string());
 
     // Initial state: should have cached the root object
-    let initial_cache = state.last_successful_result.clone();
-    let initial_unformatted = state.last_successful_result_unformatted.clone();
+    let initial_cache = state.last_successful_result_unformatted.clone();
     assert!(initial_cache.is_some());
-    assert!(initial_unformatted.is_some());
 
     // Execute a query that returns null (like typing partial field ".s")
     state.execute(".nonexistent");
     assert!(state.result.is_ok());
-    // jq returns "null" with ANSI codes, just check it contains "null"
-    assert!(state.result.as_ref().unwrap().contains("null"));
+    assert_eq!(state.result.as_ref().unwrap().trim(), "null");
 
-    // Both caches should NOT be updated - should still have the root object
-    assert_eq!(state.last_successful_result, initial_cache);
-    assert_eq!(
-        state.last_successful_result_unformatted,
-        initial_unformatted
-    );
+    // Cache should NOT be updated - should still have the root object
+    assert_eq!(state.last_successful_result_unformatted, initial_cache);
 
     // Execute a valid query that returns data
     state.execute(".name");
-    // jq returns with ANSI codes, just check it contains "test"
-    assert!(state.result.as_ref().unwrap().contains("test"));
+    assert_eq!(state.result.as_ref().unwrap().trim(), "\"test\"");
 
-    // Both caches should now be updated
-    assert_ne!(state.last_successful_result, initial_cache);
-    assert_ne!(
-        state.last_successful_result_unformatted,
-        initial_unformatted
-    );
+    // Cache should now be updated
+    assert_ne!(state.last_successful_result_unformatted, initial_cache);
     assert!(
         state
-            .last_successful_result
+            .last_successful_result_unformatted
             .as_ref()
             .unwrap()
             .contains("test")
     );
-
-    // Unformatted should not have ANSI codes
-    let unformatted = state.last_successful_result_unformatted.as_ref().unwrap();
-    assert!(!unformatted.contains("\x1b"));
-    assert!(unformatted.contains("test"));
-}
-
-#[test]
-fn test_strip_ansi_codes_simple() {
-    let input = "\x1b[0m{\x1b[1;39m\"name\"\x1b[0m: \x1b[0;32m\"test\"\x1b[0m}";
-    let output = strip_ansi_codes(input);
-    assert_eq!(output, r#"{"name": "test"}"#);
-    assert!(!output.contains("\x1b"));
-}
-
-#[test]
-fn test_strip_ansi_codes_complex() {
-    let input = "\x1b[1;39m{\x1b[0m\n  \x1b[0;34m\"key\"\x1b[0m: \x1b[0;32m\"value\"\x1b[0m\n\x1b[1;39m}\x1b[0m";
-    let output = strip_ansi_codes(input);
-    assert!(output.contains(r#""key""#));
-    assert!(output.contains(r#""value""#));
-    assert!(!output.contains("\x1b"));
-}
-
-#[test]
-fn test_strip_ansi_codes_no_codes() {
-    let input = r#"{"name": "plain"}"#;
-    let output = strip_ansi_codes(input);
-    assert_eq!(output, input);
-}
-
-#[test]
-fn test_strip_ansi_codes_null_with_color() {
-    let input = "\x1b[0;90mnull\x1b[0m";
-    let output = strip_ansi_codes(input);
-    assert_eq!(output, "null");
 }
 
 #[test]
@@ -173,11 +135,11 @@ fn test_unformatted_result_stored_on_execute() {
     // Execute a query
     state.execute(".name");
 
-    // Both formatted and unformatted should be cached
-    assert!(state.last_successful_result.is_some());
+    // Both the result and its line index should be cached
+    assert!(state.last_successful_result_lines.is_some());
     assert!(state.last_successful_result_unformatted.is_some());
 
-    // Unformatted should not contain ANSI codes
+    // jq output should not contain ANSI codes
     let unformatted = state.last_successful_result_unformatted.as_ref().unwrap();
     assert!(!unformatted.contains("\x1b"));
 }
@@ -544,6 +506,39 @@ fn test_poll_response_returns_completed_query() {
     );
 }
 
+#[test]
+fn test_worker_runs_record_recent_timings() {
+    let json = r#"{"name": "test", "value": 42}"#;
+    let mut state = QueryState::with_cache_size(json.to_string(), 0);
+    assert_eq!(state.recent_timings().count(), 0);
+
+    for query in [".name", ".value"] {
+        state.execute_async(query);
+        let timeout = std::time::Instant::now();
+        while state.is_pending() && timeout.elapsed() < std::time::Duration::from_secs(2) {
+            let _ = state.poll_response();
+            std::thread::sleep(std::time::Duration::from_millis(10));
+        }
+    }
+
+    let queries: Vec<&str> = state.recent_timings().map(|t| t.query.as_str()).collect();
+    assert_eq!(queries, vec![".value", ".name"], "newest run comes first");
+}
+
+#[test]
+fn test_recent_timings_are_capped() {
+    let mut state = QueryState::new("{}".to_string());
+    for i in 0..MAX_RECENT_TIMINGS + 3 {
+        state.record_timing(format!(".q{}", i), i as u64);
+    }
+
+    assert_eq!(state.recent_timings().count(), MAX_RECENT_TIMINGS);
+    assert_eq!(
+        state.recent_timings().next().map(|t| t.elapsed_ms),
+        Some((MAX_RECENT_TIMINGS + 2) as u64)
+    );
+}
+
 #[test]
 fn test_poll_response_returns_query_for_errors() {
     let json = r#"{"test": true}"#;
@@ -868,7 +863,7 @@ fn test_new_populates_rendered_cache() {
     let state = QueryState::new(json.to_string());
 
     assert!(
-        state.last_successful_result_rendered.is_some(),
+        state.last_successful_result_lines.is_some(),
         "new() should populate rendered cache"
     );
 }
@@ -881,7 +876,7 @@ fn test_successful_query_updates_rendered_cache() {
     state.execute(".name");
     assert!(state.result.is_ok(), "Query should succeed");
     assert!(
-        state.last_successful_result_rendered.is_some(),
+        state.last_successful_result_lines.is_some(),
         "Rendered cache should be populated after successful query"
     );
 }
@@ -892,7 +887,7 @@ fn test_error_query_preserves_rendered_cache() {
     let mut state = QueryState::new(json.to_string());
 
     state.execute(".value");
-    let cached_rendered = state.last_successful_result_rendered.clone();
+    let cached_rendered = state.last_successful_result_lines.clone();
     assert!(cached_rendered.is_some());
 
     // Execute invalid query (syntax error)
@@ -901,7 +896,7 @@ fn test_error_query_preserves_rendered_cache() {
 
     // Rendered cache should be unchanged
     assert_eq!(
-        state.last_successful_result_rendered.is_some(),
+        state.last_successful_result_lines.is_some(),
         cached_rendered.is_some(),
         "Rendered cache should be preserved on error"
     );
@@ -912,7 +907,7 @@ fn test_null_result_preserves_rendered_cache() {
     let json = r#"{"name": "test", "age": 30}"#;
     let mut state = QueryState::new(json.to_string());
 
-    let initial_rendered = state.last_successful_result_rendered.clone();
+    let initial_rendered = state.last_successful_result_lines.clone();
     assert!(initial_rendered.is_some());
 
     // Execute a query that returns null (partial field that doesn't exist)
@@ -922,7 +917,7 @@ fn test_null_result_preserves_rendered_cache() {
 
     // Rendered cache should NOT be updated
     assert_eq!(
-        state.last_successful_result_rendered.is_some(),
+        state.last_successful_result_lines.is_some(),
         initial_rendered.is_some(),
         "Rendered cache should be preserved for null-only results"
     );
@@ -935,7 +930,7 @@ fn test_line_count_always_uses_last_successful_result() {
 
     // Set up: successful result with known line count
     let multiline_result: String = (0..50).map(|i| format!("line{}\n", i)).collect();
-    state.last_successful_result = Some(Arc::new(multiline_result.clone()));
+    state.last_successful_result_unformatted = Some(Arc::new(multiline_result.clone()));
     state.cached_line_count = multiline_result.lines().count() as u32;
 
     // Set current result to something different
@@ -952,7 +947,7 @@ fn test_max_line_width_always_uses_last_successful_result() {
 
     // Set up: successful result with known max width
     let wide_result = "short\nthis_is_a_very_long_line_with_many_characters\nshort";
-    state.last_successful_result = Some(Arc::new(wide_result.to_string()));
+    state.last_successful_result_unformatted = Some(Arc::new(wide_result.to_string()));
     state.cached_max_line_width = wide_result.lines().map(|l| l.len()).max().unwrap_or(0) as u16;
 
     // Set current result to something different (short)
@@ -0,0 +1,107 @@
+//! Tests for appending records to the input while following a file
+
+use super::*;
+
+const RECORDS: &str =
+    "{\"level\": \"info\", \"msg\": \"start\"}\n{\"level\": \"error\", \"msg\": \"boom\"}\n";
+
+fn wait_for(state: &mut QueryState) {
+    let timeout = std::time::Instant::now();
+    while state.is_pending() && timeout.elapsed() < std::time::Duration::from_secs(2) {
+        let _ = state.poll_response();
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    }
+    assert!(!state.is_pending(), "query did not complete within timeout");
+}
+
+/// Unformatted output of running `query` on `input` from scratch
+fn full_result(input: &str, query: &str) -> String {
+    let mut state = QueryState::new(input.to_string());
+    state.execute(query);
+    state
+        .last_successful_result_unformatted
+        .unwrap()
+        .to_string()
+}
+
+#[test]
+fn test_appended_records_extend_result() {
+    let query = "select(.level == \"error\") | .msg";
+    let mut state = QueryState::new(RECORDS.to_string());
+    state.execute(query);
+
+    let appended = "{\"level\": \"error\", \"msg\": \"again\"}\n";
+    assert!(state.append_input(appended, query));
+    assert!(state.is_pending());
+    wait_for(&mut state);
+
+    let expected = full_result(&format!("{}{}", RECORDS, appended), query);
+    assert_eq!(
+        state.last_successful_result_unformatted.as_deref().unwrap(),
+        &expected
+    );
+    assert_eq!(state.line_count(), 2);
+    assert_eq!(
+        state.last_successful_result_lines.as_ref().unwrap().len(),
+        2
+    );
+    assert_eq!(
+        state.executor.json_input(),
+        format!("{}{}", RECORDS, appended)
+    );
+}
+
+#[test]
+fn test_appended_records_without_output_keep_result() {
+    let query = "select(.level == \"error\") | .msg";
+    let mut state = QueryState::new(RECORDS.to_string());
+    state.execute(query);
+    let before = state.last_successful_result_unformatted.clone();
+
+    assert!(state.append_input("{\"level\": \"info\"}\n", query));
+    wait_for(&mut state);
+
+    assert_eq!(state.last_successful_result_unformatted, before);
+    assert_eq!(state.line_count(), 1);
+}
+
+#[test]
+fn test_queries_reading_other_inputs_are_rerun() {
+    let query = "[., inputs] | length";
+    let mut state = QueryState::new(RECORDS.to_string());
+    state.execute(query);
+
+    assert!(!state.append_input("{\"level\": \"info\"}\n", query));
+    assert!(!state.is_pending());
+
+    state.execute_async(query);
+    wait_for(&mut state);
+    assert_eq!(
+        state.last_successful_result_unformatted.as_deref().unwrap(),
+        "3\n"
+    );
+}
+
+#[test]
+fn test_append_after_failed_query_requires_rerun() {
+    let mut state = QueryState::new(RECORDS.to_string());
+    state.execute(".msg |");
+
+    assert!(!state.append_input("{\"msg\": \"x\"}\n", ".msg |"));
+}
+
+#[test]
+fn test_single_object_result_becomes_destructured() {
+    let mut state = QueryState::new("{\"a\": 1}\n".to_string());
+    state.execute(".");
+    assert_eq!(state.base_type_for_suggestions, Some(ResultType::Object));
+
+    assert!(state.append_input("{\"a\": 2}\n", "."));
+    wait_for(&mut state);
+
+    assert_eq!(
+        state.base_type_for_suggestions,
+        Some(ResultType::DestructuredObjects)
+    );
+    assert_eq!(state.line_count(), 6);
+}
@@ -0,0 +1,113 @@
+//! Tests for structured error tracking
+
+use super::*;
+
+#[test]
+fn test_sync_error_is_parsed() {
+    let mut state = QueryState::new(r#"{"a": 1}"#.to_string());
+    state.execute(".a | foo");
+
+    let parsed = state.parsed_error.as_ref().expect("error should be parsed");
+    assert_eq!(parsed.query, ".a | foo");
+    assert!(parsed.message.contains("foo/0 is not defined"));
+    assert_eq!(parsed.span, Some(5..8));
+}
+
+#[test]
+fn test_success_clears_parsed_error() {
+    let mut state = QueryState::new(r#"{"a": 1}"#.to_string());
+    state.execute(".a | foo");
+    assert!(state.parsed_error.is_some());
+
+    state.execute(".a");
+    assert!(state.parsed_error.is_none());
+}
+
+#[test]
+fn test_async_error_is_parsed() {
+    let mut state = QueryState::new(r#"{"a": 1}"#.to_string());
+    state.execute_async(".a[");
+
+    let timeout = std::time::Instant::now();
+    while state.is_pending() && timeout.elapsed() < std::time::Duration::from_secs(2) {
+        let _ = state.poll_response();
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    }
+
+    let parsed = state.parsed_error.as_ref().expect("error should be parsed");
+    assert_eq!(parsed.query, ".a[");
+    assert!(parsed.message.starts_with("syntax error"));
+    assert!(parsed.span.is_some());
+}
+
+fn wait_for(state: &mut QueryState) {
+    let timeout = std::time::Instant::now();
+    while state.is_pending() && timeout.elapsed() < std::time::Duration::from_secs(2) {
+        let _ = state.poll_response();
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    }
+}
+
+#[test]
+fn test_runtime_error_shows_output_before_it() {
+    let mut state = QueryState::new(r#"[{"a": 1}, "x", {"a": 3}]"#.to_string());
+    state.execute(".[] | .a");
+
+    assert!(state.result.is_err());
+    assert!(state.shows_partial_output);
+    assert_eq!(
+        state.parsed_error.as_ref().unwrap().kind,
+        ErrorKind::Runtime
+    );
+    assert_eq!(
+        state.last_successful_result_unformatted.as_deref().unwrap(),
+        "1\n"
+    );
+}
+
+#[test]
+fn test_async_runtime_error_shows_output_before_it() {
+    let mut state = QueryState::new(r#"[{"a": 1}, {"a": 2}, "x"]"#.to_string());
+    state.execute_async(".[] | .a");
+    wait_for(&mut state);
+
+    assert!(state.result.is_err());
+    assert!(state.shows_partial_output);
+    assert_eq!(state.line_count(), 2);
+    assert_eq!(
+        state.last_successful_result_unformatted.as_deref().unwrap(),
+        "1\n2\n"
+    );
+}
+
+#[test]
+fn test_syntax_error_keeps_last_successful_result() {
+    let mut state = QueryState::new(r#"{"a": 1}"#.to_string());
+    state.execute(".a");
+    state.execute(".a[");
+
+    assert_eq!(state.parsed_error.as_ref().unwrap().kind, ErrorKind::Syntax);
+    assert!(!state.shows_partial_output);
+    assert_eq!(
+        state.last_successful_result_unformatted.as_deref().unwrap(),
+        "1\n"
+    );
+}
+
+#[test]
+fn test_partial_output_is_not_an_incremental_base() {
+    let mut state = QueryState::new(r#"[{"a": 1}, "x", {"a": 3}]"#.to_string());
+    state.execute(".[] | .a");
+
+    assert!(state.prefix_input_for(".[] | .a | tostring").is_none());
+}
+
+#[test]
+fn test_success_after_partial_output_clears_it() {
+    let mut state = QueryState::new(r#"[{"a": 1}, "x"]"#.to_string());
+    state.execute(".[] | .a");
+    state.execute(".[0]");
+
+    assert!(!state.shows_partial_output);
+    assert!(state.parsed_error.is_none());
+}
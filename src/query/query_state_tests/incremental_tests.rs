@@ -0,0 +1,95 @@
+//! Tests for incremental evaluation of queries extending the last result
+
+use super::*;
+
+const LARGE_JSON: &str = r#"{
+    "services": [{"name": "api"}, {"name": "web"}],
+    "padding": "this field makes the input larger than the .services result"
+}"#;
+
+#[test]
+fn test_prefix_input_used_when_query_extends_base() {
+    let mut state = QueryState::new(LARGE_JSON.to_string());
+    state.execute(".services");
+
+    let prefix = state
+        .prefix_input_for(".services[].name")
+        .expect("query extends base");
+    assert_eq!(prefix.filter, ".[].name");
+    assert_eq!(
+        prefix.input.as_str(),
+        state
+            .last_successful_result_unformatted
+            .as_ref()
+            .unwrap()
+            .as_str()
+    );
+}
+
+#[test]
+fn test_prefix_input_not_used_for_unrelated_query() {
+    let mut state = QueryState::new(LARGE_JSON.to_string());
+    state.execute(".services");
+
+    assert!(state.prefix_input_for(".padding").is_none());
+}
+
+#[test]
+fn test_prefix_input_not_used_when_result_not_smaller_than_input() {
+    let mut state = QueryState::new(LARGE_JSON.to_string());
+
+    // Initial result is the identity output, at least as large as the input
+    assert!(state.prefix_input_for(". | keys").is_none());
+
+    state.execute(".");
+    assert!(state.prefix_input_for(". | keys").is_none());
+}
+
+#[test]
+fn test_incremental_async_result_matches_full_execution() {
+    let mut state = QueryState::new(LARGE_JSON.to_string());
+    state.execute(".services");
+
+    state.execute_async(".services[].name");
+    let timeout = std::time::Instant::now();
+    while state.is_pending() && timeout.elapsed() < std::time::Duration::from_secs(2) {
+        let _ = state.poll_response();
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    }
+
+    let incremental = state.last_successful_result_unformatted.clone().unwrap();
+
+    let mut full = QueryState::new(LARGE_JSON.to_string());
+    full.execute(".services[].name");
+
+    assert_eq!(
+        incremental.as_str(),
+        full.last_successful_result_unformatted.unwrap().as_str()
+    );
+    assert_eq!(
+        state.base_query_for_suggestions,
+        Some(".services[].name".to_string())
+    );
+}
+
+#[test]
+fn test_prefix_input_keyed_on_the_query_that_ran() {
+    let json = format!(
+        r#"{{"services": [{{"name": "api"}}], "padding": "{}"}}"#,
+        "x".repeat(500)
+    );
+    let mut state = QueryState::new(json);
+    state.execute(".services | ..");
+
+    // The output is that of `..`, not of the `.services | .` it normalizes to
+    assert_eq!(
+        state.base_query_for_suggestions.as_deref(),
+        Some(".services | .")
+    );
+    assert!(state.prefix_input_for(".services | .[]").is_none());
+
+    let prefix = state
+        .prefix_input_for(".services | .. | strings")
+        .expect("query extends the query that ran");
+    assert_eq!(prefix.filter, "strings");
+}
@@ -0,0 +1,142 @@
+//! Tests for the query result cache integration
+
+use super::*;
+
+fn wait_for_completion(state: &mut QueryState) -> Option<String> {
+    let mut completed = None;
+    let timeout = std::time::Instant::now();
+    while timeout.elapsed() < std::time::Duration::from_secs(2) {
+        if let Some(query) = state.poll_response() {
+            completed = Some(query);
+        }
+        if !state.is_pending() && completed.is_some() {
+            break;
+        }
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    }
+    completed
+}
+
+#[test]
+fn test_async_result_is_cached() {
+    let json = r#"{"name": "test", "age": 30}"#;
+    let mut state = QueryState::new(json.to_string());
+
+    state.execute_async(".name");
+    wait_for_completion(&mut state);
+
+    assert!(state.result_cache.get(".name").is_some());
+}
+
+#[test]
+fn test_cache_hit_applies_result_without_worker() {
+    let json = r#"{"name": "test", "age": 30}"#;
+    let mut state = QueryState::new(json.to_string());
+
+    state.execute_async(".name");
+    wait_for_completion(&mut state);
+    state.execute_async(".age");
+    wait_for_completion(&mut state);
+
+    state.execute_async(".name");
+
+    // Served from cache: nothing in flight, result applied immediately
+    assert!(!state.is_pending());
+    assert!(state.result.as_ref().unwrap().contains("test"));
+    assert_eq!(
+        state
+            .last_successful_result_unformatted
+            .as_deref()
+            .map(|s| s.trim()),
+        Some("\"test\"")
+    );
+    assert_eq!(state.base_query_for_suggestions, Some(".name".to_string()));
+    assert_eq!(state.base_type_for_suggestions, Some(ResultType::String));
+}
+
+#[test]
+fn test_cache_hit_is_reported_by_poll_response() {
+    let json = r#"{"services": [{"name": "a"}]}"#;
+    let mut state = QueryState::new(json.to_string());
+
+    state.execute_async(".services");
+    wait_for_completion(&mut state);
+
+    state.execute_async(".services");
+    assert_eq!(state.poll_response(), Some(".services".to_string()));
+    assert_eq!(state.poll_response(), None);
+}
+
+#[test]
+fn test_null_results_are_not_cached() {
+    let json = r#"{"name": "test"}"#;
+    let mut state = QueryState::new(json.to_string());
+
+    state.execute_async(".missing");
+    wait_for_completion(&mut state);
+
+    assert!(state.result_cache.get(".missing").is_none());
+}
+
+#[test]
+fn test_errors_are_not_cached() {
+    let json = r#"{"name": "test"}"#;
+    let mut state = QueryState::new(json.to_string());
+
+    state.execute_async(".invalid syntax [");
+    wait_for_completion(&mut state);
+
+    assert!(state.result.is_err());
+    assert!(state.result_cache.is_empty());
+}
+
+#[test]
+fn test_cancelled_query_is_not_cached() {
+    let json = r#"{"name": "test", "age": 30}"#;
+    let mut state = QueryState::new(json.to_string());
+
+    state.execute_async(".name");
+    state.execute_async(".age");
+    wait_for_completion(&mut state);
+
+    assert!(state.result_cache.get(".name").is_none());
+    assert!(state.result_cache.get(".age").is_some());
+}
+
+#[test]
+fn test_sync_execute_populates_cache() {
+    let json = r#"{"name": "test"}"#;
+    let mut state = QueryState::new(json.to_string());
+
+    state.execute(".name");
+
+    assert!(state.result_cache.get(".name").is_some());
+}
+
+#[test]
+fn test_zero_cache_size_disables_cache() {
+    let json = r#"{"name": "test"}"#;
+    let mut state = QueryState::with_cache_size(json.to_string(), 0);
+
+    state.execute(".name");
+    state.execute_async(".name");
+
+    assert!(state.is_pending());
+}
+
+#[test]
+fn test_cache_hit_restores_line_metrics() {
+    let json = r#"{"items": [1, 2, 3], "name": "x"}"#;
+    let mut state = QueryState::new(json.to_string());
+
+    state.execute(".items");
+    let line_count = state.line_count();
+    let max_width = state.max_line_width();
+
+    state.execute(".name");
+    assert_ne!(state.line_count(), line_count);
+
+    state.execute_async(".items");
+    assert_eq!(state.line_count(), line_count);
+    assert_eq!(state.max_line_width(), max_width);
+}
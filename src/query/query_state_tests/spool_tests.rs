@@ -0,0 +1,105 @@
+//! Tests for spooling very large results
+
+use super::*;
+use crate::query::result_spool::SPOOL_THRESHOLD_LINES;
+
+fn wait_for(state: &mut QueryState) {
+    let timeout = std::time::Instant::now();
+    while state.is_pending() && timeout.elapsed() < std::time::Duration::from_secs(20) {
+        let _ = state.poll_response();
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    }
+    assert!(!state.is_pending(), "query did not complete within timeout");
+}
+
+/// Array pretty-printing to more lines than the spool threshold
+fn large_array() -> String {
+    let values: Vec<String> = (0..SPOOL_THRESHOLD_LINES).map(|i| i.to_string()).collect();
+    format!("[{}]", values.join(","))
+}
+
+fn result_line(state: &QueryState, index: usize) -> String {
+    state
+        .last_successful_result_lines
+        .as_ref()
+        .unwrap()
+        .line(index)
+        .unwrap()
+        .into_owned()
+}
+
+#[test]
+fn test_large_initial_result_is_spooled() {
+    let state = QueryState::new(large_array());
+
+    let lines = state.last_successful_result_lines.as_ref().unwrap();
+    assert!(lines.is_spooled());
+    assert_eq!(state.line_count() as usize, SPOOL_THRESHOLD_LINES + 2);
+    assert_eq!(result_line(&state, 1), "  0,");
+    assert_eq!(result_line(&state, SPOOL_THRESHOLD_LINES + 1), "]");
+
+    // Only the spool file keeps the text; copy and search read it back
+    assert!(state.last_successful_result_unformatted.is_none());
+    assert!(state.last_successful_result_parsed.is_none());
+    assert_eq!(state.result.as_deref(), Ok(""));
+    assert!(state.result_is_spooled());
+    assert!(
+        state
+            .last_successful_result_text()
+            .unwrap()
+            .contains("99999")
+    );
+}
+
+#[test]
+fn test_async_large_result_is_spooled() {
+    let mut state = QueryState::new(r#"{"n": 1}"#.to_string());
+
+    state.execute_async(&format!("range({})", SPOOL_THRESHOLD_LINES + 5));
+    wait_for(&mut state);
+
+    let lines = state.last_successful_result_lines.as_ref().unwrap();
+    assert!(lines.is_spooled());
+    assert_eq!(state.line_count() as usize, SPOOL_THRESHOLD_LINES + 5);
+    assert_eq!(result_line(&state, 50_000), "50000");
+    assert!(state.last_successful_result_unformatted.is_none());
+    assert!(state.result_is_spooled());
+    assert!(state.snapshot().is_none());
+}
+
+#[test]
+fn test_appended_records_extend_spooled_result() {
+    let records: String = (0..=SPOOL_THRESHOLD_LINES)
+        .map(|i| format!("{}\n", i))
+        .collect();
+    let mut state = QueryState::new(records);
+    assert!(state.result_is_spooled());
+
+    assert!(state.append_input("-1\n-2\n", "."));
+    wait_for(&mut state);
+
+    let lines = state.last_successful_result_lines.as_ref().unwrap();
+    assert!(lines.is_spooled());
+    assert_eq!(lines.len(), SPOOL_THRESHOLD_LINES + 3);
+    assert_eq!(result_line(&state, SPOOL_THRESHOLD_LINES + 2), "-2");
+    assert_eq!(state.line_count() as usize, SPOOL_THRESHOLD_LINES + 3);
+    assert!(state.last_successful_result_unformatted.is_none());
+    assert!(
+        state
+            .last_successful_result_text()
+            .unwrap()
+            .ends_with("100000\n-1\n-2\n")
+    );
+}
+
+#[test]
+fn test_small_results_are_not_spooled() {
+    let mut state = QueryState::new(r#"{"n": 1}"#.to_string());
+
+    state.execute_async(".n");
+    wait_for(&mut state);
+
+    let lines = state.last_successful_result_lines.as_ref().unwrap();
+    assert!(!lines.is_spooled());
+    assert_eq!(result_line(&state, 0), "1");
+}
@@ -0,0 +1,134 @@
+//! Result Cache
+//!
+//! Bounded LRU cache mapping query strings to fully processed results.
+//! Lets undo/redo and toggling between recently typed queries re-render
+//! instantly instead of re-invoking jq on a large document.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
+
+use serde_json::Value;
+
+use crate::query::query_state::ResultType;
+use crate::query::result_lines::ResultLines;
+
+/// Default number of query results kept in memory
+pub const DEFAULT_CACHE_SIZE: usize = 16;
+
+/// Snapshot of a successful query result, restorable without re-running jq
+#[derive(Debug, Clone)]
+pub struct CachedResult {
+    pub unformatted: Arc<String>,
+    pub lines: ResultLines,
+    pub parsed: Option<Arc<Value>>,
+    pub for_context: Option<Arc<String>>,
+    pub line_count: u32,
+    pub max_width: u16,
+    pub line_widths: Option<Arc<Vec<u16>>>,
+    pub result_type: Option<ResultType>,
+    pub base_query: Option<String>,
+    pub execution_time_ms: Option<u64>,
+}
+
+/// Least-recently-used cache of query results
+#[derive(Debug, Clone)]
+pub struct ResultCache {
+    capacity: usize,
+    entries: HashMap<String, CachedResult>,
+    /// Keys ordered from least to most recently used
+    order: VecDeque<String>,
+}
+
+impl ResultCache {
+    /// Create a cache holding at most `capacity` results (0 disables caching)
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: HashMap::new(),
+            order: VecDeque::new(),
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    #[allow(dead_code)]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    #[allow(dead_code)]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Whether `query` has a cached result, without marking it as used
+    #[allow(dead_code)]
+    pub fn contains(&self, query: &str) -> bool {
+        self.entries.contains_key(cache_key(query))
+    }
+
+    /// Look up a query, marking it as most recently used on hit
+    pub fn get(&mut self, query: &str) -> Option<&CachedResult> {
+        let key = cache_key(query);
+        if !self.entries.contains_key(key) {
+            return None;
+        }
+        self.touch(key);
+        self.entries.get(key)
+    }
+
+    /// Insert or replace a result, evicting the least recently used entry when full
+    pub fn insert(&mut self, query: &str, result: CachedResult) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        let key = cache_key(query);
+        if self.entries.insert(key.to_string(), result).is_some() {
+            self.touch(key);
+            return;
+        }
+
+        self.order.push_back(key.to_string());
+        while self.entries.len() > self.capacity {
+            match self.order.pop_front() {
+                Some(oldest) => {
+                    self.entries.remove(&oldest);
+                }
+                None => break,
+            }
+        }
+    }
+
+    /// Drop all cached results (e.g. when the input document is reloaded)
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.order.clear();
+    }
+
+    fn touch(&mut self, key: &str) {
+        if let Some(pos) = self.order.iter().position(|k| k == key)
+            && let Some(existing) = self.order.remove(pos)
+        {
+            self.order.push_back(existing);
+        }
+    }
+}
+
+impl Default for ResultCache {
+    fn default() -> Self {
+        Self::new(DEFAULT_CACHE_SIZE)
+    }
+}
+
+/// Empty queries execute as identity, so they share the "." entry
+fn cache_key(query: &str) -> &str {
+    if query.trim().is_empty() { "." } else { query }
+}
+
+#[cfg(test)]
+#[path = "result_cache_tests.rs"]
+mod result_cache_tests;
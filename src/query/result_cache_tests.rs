@@ -0,0 +1,107 @@
+//! Tests for result_cache
+
+use super::*;
+
+fn cached(output: &str) -> CachedResult {
+    CachedResult {
+        unformatted: Arc::new(output.to_string()),
+        lines: ResultLines::new(Arc::new(output.to_string())),
+        parsed: None,
+        for_context: None,
+        line_count: 1,
+        max_width: output.len() as u16,
+        line_widths: None,
+        result_type: Some(ResultType::String),
+        base_query: None,
+        execution_time_ms: None,
+    }
+}
+
+#[test]
+fn test_new_cache_is_empty() {
+    let cache = ResultCache::new(4);
+    assert!(cache.is_empty());
+    assert_eq!(cache.capacity(), 4);
+}
+
+#[test]
+fn test_default_uses_default_capacity() {
+    let cache = ResultCache::default();
+    assert_eq!(cache.capacity(), DEFAULT_CACHE_SIZE);
+}
+
+#[test]
+fn test_insert_and_get() {
+    let mut cache = ResultCache::new(4);
+    cache.insert(".a", cached("1"));
+
+    let hit = cache.get(".a").expect("entry should be cached");
+    assert_eq!(hit.unformatted.as_str(), "1");
+    assert!(cache.get(".b").is_none());
+}
+
+#[test]
+fn test_insert_replaces_existing_entry() {
+    let mut cache = ResultCache::new(4);
+    cache.insert(".a", cached("1"));
+    cache.insert(".a", cached("2"));
+
+    assert_eq!(cache.len(), 1);
+    assert_eq!(cache.get(".a").unwrap().unformatted.as_str(), "2");
+}
+
+#[test]
+fn test_evicts_least_recently_used() {
+    let mut cache = ResultCache::new(2);
+    cache.insert(".a", cached("1"));
+    cache.insert(".b", cached("2"));
+    cache.insert(".c", cached("3"));
+
+    assert_eq!(cache.len(), 2);
+    assert!(cache.get(".a").is_none());
+    assert!(cache.get(".b").is_some());
+    assert!(cache.get(".c").is_some());
+}
+
+#[test]
+fn test_get_refreshes_recency() {
+    let mut cache = ResultCache::new(2);
+    cache.insert(".a", cached("1"));
+    cache.insert(".b", cached("2"));
+
+    // Touch .a so .b becomes the eviction candidate
+    assert!(cache.get(".a").is_some());
+    cache.insert(".c", cached("3"));
+
+    assert!(cache.get(".a").is_some());
+    assert!(cache.get(".b").is_none());
+}
+
+#[test]
+fn test_zero_capacity_disables_caching() {
+    let mut cache = ResultCache::new(0);
+    cache.insert(".a", cached("1"));
+
+    assert!(cache.is_empty());
+    assert!(cache.get(".a").is_none());
+}
+
+#[test]
+fn test_clear_removes_all_entries() {
+    let mut cache = ResultCache::new(4);
+    cache.insert(".a", cached("1"));
+    cache.insert(".b", cached("2"));
+    cache.clear();
+
+    assert!(cache.is_empty());
+    assert!(cache.get(".a").is_none());
+}
+
+#[test]
+fn test_empty_query_shares_identity_entry() {
+    let mut cache = ResultCache::new(4);
+    cache.insert(".", cached("root"));
+
+    assert_eq!(cache.get("").unwrap().unformatted.as_str(), "root");
+    assert_eq!(cache.get("   ").unwrap().unformatted.as_str(), "root");
+}
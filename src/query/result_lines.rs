@@ -0,0 +1,180 @@
+//! Result Lines
+//!
+//! Query results are kept as the plain text jq printed, with the byte offset
+//! of each line. Rendering highlights only the part of the result in view,
+//! so drawing a frame costs the same for a result of ten lines or ten million.
+//! Very large results are read back from a spool file instead, see
+//! [`result_spool`](crate::query::result_spool).
+
+use std::borrow::Cow;
+use std::ops::Range;
+use std::sync::Arc;
+
+use ratatui::text::Line;
+
+use crate::query::result_spool::{ResultSpool, SPOOL_THRESHOLD_LINES};
+use crate::syntax_highlight::json::JsonHighlighter;
+
+/// Plain result text indexed by line
+///
+/// Cloning is cheap: the text and the index are shared.
+#[derive(Debug, Clone)]
+pub struct ResultLines {
+    source: Source,
+}
+
+#[derive(Debug, Clone)]
+enum Source {
+    Memory {
+        text: Arc<String>,
+        /// Byte offset of the start of each line
+        starts: Arc<Vec<usize>>,
+    },
+    /// Very large results, read back from a file a window at a time
+    Spooled(Arc<ResultSpool>),
+}
+
+impl Default for ResultLines {
+    fn default() -> Self {
+        Self::new(Arc::default())
+    }
+}
+
+impl ResultLines {
+    /// Index `text`, spooling it to a file when it has more than
+    /// [`SPOOL_THRESHOLD_LINES`] lines
+    ///
+    /// Falls back to the text in memory if the spool file can't be written.
+    pub fn new(text: Arc<String>) -> Self {
+        let bytes = text.as_bytes();
+        let mut starts = Vec::new();
+        if !bytes.is_empty() {
+            starts.push(0);
+        }
+        for newline in memchr::memchr_iter(b'\n', bytes) {
+            if newline + 1 < bytes.len() {
+                starts.push(newline + 1);
+            }
+        }
+        if starts.len() > SPOOL_THRESHOLD_LINES
+            && let Ok(spool) = ResultSpool::create(&text)
+        {
+            return Self {
+                source: Source::Spooled(Arc::new(spool)),
+            };
+        }
+        Self {
+            source: Source::Memory {
+                text,
+                starts: Arc::new(starts),
+            },
+        }
+    }
+
+    pub fn is_spooled(&self) -> bool {
+        matches!(self.source, Source::Spooled(_))
+    }
+
+    /// Result text, when it is kept in memory
+    pub fn text(&self) -> Option<&Arc<String>> {
+        match &self.source {
+            Source::Memory { text, .. } => Some(text),
+            Source::Spooled(_) => None,
+        }
+    }
+
+    /// The whole result text, read back from the spool file for spooled
+    /// results; None if that fails
+    pub fn full_text(&self) -> Option<Arc<String>> {
+        match &self.source {
+            Source::Memory { text, .. } => Some(Arc::clone(text)),
+            Source::Spooled(spool) => spool.read_all().ok().map(Arc::new),
+        }
+    }
+
+    /// This result followed by `more`, which starts a new line
+    ///
+    /// Spooled results are extended by appending `more` to the spool file
+    /// rather than writing the whole text again.
+    pub fn extended(&self, more: &str) -> Self {
+        if more.is_empty() {
+            return self.clone();
+        }
+        let text = match &self.source {
+            Source::Spooled(spool) => match spool.extended(more) {
+                Ok(spool) => {
+                    return Self {
+                        source: Source::Spooled(Arc::new(spool)),
+                    };
+                }
+                Err(e) => {
+                    log::warn!("Cannot extend spooled result: {}", e);
+                    match spool.read_all() {
+                        Ok(text) => Arc::new(text),
+                        Err(_) => return self.clone(),
+                    }
+                }
+            },
+            Source::Memory { text, .. } => Arc::clone(text),
+        };
+        let mut merged = String::with_capacity(text.len() + more.len() + 1);
+        merged.push_str(&text);
+        if !merged.is_empty() && !merged.ends_with('\n') {
+            merged.push('\n');
+        }
+        merged.push_str(more);
+        Self::new(Arc::new(merged))
+    }
+
+    /// Whether both index the same result, not just equal text
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        match (&self.source, &other.source) {
+            (Source::Memory { text, .. }, Source::Memory { text: other, .. }) => {
+                Arc::ptr_eq(text, other)
+            }
+            (Source::Spooled(spool), Source::Spooled(other)) => Arc::ptr_eq(spool, other),
+            _ => false,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        match &self.source {
+            Source::Memory { starts, .. } => starts.len(),
+            Source::Spooled(spool) => spool.line_count(),
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Text of line `index`, without its line ending
+    pub fn line(&self, index: usize) -> Option<Cow<'_, str>> {
+        let (text, starts) = match &self.source {
+            Source::Memory { text, starts } => (text, starts),
+            Source::Spooled(spool) => return spool.line(index).map(Cow::Owned),
+        };
+        let start = *starts.get(index)?;
+        let end = starts.get(index + 1).map_or(text.len(), |next| next - 1);
+        let line = &text[start..end];
+        Some(Cow::Borrowed(line.strip_suffix('\n').unwrap_or(line)))
+    }
+
+    /// Highlighted lines in `range`, clamped to the result
+    ///
+    /// Each line is cut after `max_width` characters, the last column the
+    /// view can show.
+    pub fn highlighted(&self, range: Range<usize>, max_width: usize) -> Vec<Line<'static>> {
+        let end = range.end.min(self.len());
+        let start = range.start.min(end);
+        (start..end)
+            .filter_map(|index| self.line(index))
+            .map(|line| JsonHighlighter::highlight_line(&line, max_width))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+#[path = "result_lines_tests.rs"]
+mod result_lines_tests;
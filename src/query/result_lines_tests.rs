@@ -0,0 +1,118 @@
+//! Tests for result_lines
+
+use super::*;
+
+fn lines(text: &str) -> ResultLines {
+    ResultLines::new(Arc::new(text.to_string()))
+}
+
+#[test]
+fn test_counts_lines_like_str_lines() {
+    for text in ["", "a", "a\n", "a\nb", "a\nb\n", "\n", "a\n\nb\n"] {
+        assert_eq!(lines(text).len(), text.lines().count(), "{:?}", text);
+    }
+}
+
+#[test]
+fn test_line_strips_line_ending() {
+    let result = lines("{\n  \"a\": 1\n}\n");
+    assert_eq!(result.line(0).as_deref(), Some("{"));
+    assert_eq!(result.line(1).as_deref(), Some("  \"a\": 1"));
+    assert_eq!(result.line(2).as_deref(), Some("}"));
+    assert_eq!(result.line(3), None);
+}
+
+#[test]
+fn test_empty_lines_are_kept() {
+    let result = lines("a\n\nb");
+    assert_eq!(result.line(1).as_deref(), Some(""));
+    assert_eq!(result.line(2).as_deref(), Some("b"));
+}
+
+#[test]
+fn test_highlighted_clamps_range() {
+    let result = lines("1\n2\n3\n");
+    assert_eq!(result.highlighted(1..10, usize::MAX).len(), 2);
+    assert!(result.highlighted(5..10, usize::MAX).is_empty());
+}
+
+#[test]
+fn test_highlighted_contains_plain_text() {
+    let result = lines("[\n  true\n]\n");
+    let rendered: Vec<String> = result
+        .highlighted(0..3, usize::MAX)
+        .iter()
+        .map(|line| {
+            line.spans
+                .iter()
+                .map(|span| span.content.as_ref())
+                .collect()
+        })
+        .collect();
+    assert_eq!(rendered, vec!["[", "  true", "]"]);
+}
+
+#[test]
+fn test_clone_shares_text() {
+    let result = lines("a\nb\n");
+    let copy = result.clone();
+    assert!(result.ptr_eq(&copy));
+    assert!(!result.ptr_eq(&lines("a\nb\n")));
+}
+
+#[test]
+fn test_highlighted_cuts_lines_at_max_width() {
+    let long_line = format!("[{}]\n", "1,".repeat(1_000));
+    let result = lines(&format!("[\n{}]\n", long_line));
+    let highlighted = result.highlighted(0..3, 50);
+    assert_eq!(highlighted[0].width(), 1);
+    assert_eq!(highlighted[1].width(), 50);
+}
+
+#[test]
+fn test_only_large_results_are_spooled() {
+    assert!(!lines("a\nb\n").is_spooled());
+
+    let text: String = (0..=SPOOL_THRESHOLD_LINES)
+        .map(|i| format!("{}\n", i))
+        .collect();
+    let result = lines(&text);
+    assert!(result.is_spooled());
+    assert_eq!(result.len(), SPOOL_THRESHOLD_LINES + 1);
+    assert_eq!(
+        result.line(SPOOL_THRESHOLD_LINES).as_deref(),
+        Some(SPOOL_THRESHOLD_LINES.to_string().as_str())
+    );
+    assert_eq!(result.highlighted(5..7, usize::MAX).len(), 2);
+    assert!(result.ptr_eq(&result.clone()));
+}
+
+#[test]
+fn test_extended_in_memory() {
+    let result = lines("a\nb").extended("c\n");
+    assert!(!result.is_spooled());
+    assert_eq!(result.text().map(|text| text.as_str()), Some("a\nb\nc\n"));
+    assert_eq!(result.len(), 3);
+
+    let unchanged = lines("a\n");
+    assert!(unchanged.extended("").ptr_eq(&unchanged));
+}
+
+#[test]
+fn test_spooled_results_keep_their_text_in_the_file() {
+    let text: String = (0..=SPOOL_THRESHOLD_LINES)
+        .map(|i| format!("{}\n", i))
+        .collect();
+    let result = lines(&text);
+    assert!(result.text().is_none());
+    assert_eq!(result.full_text().as_deref(), Some(&text));
+
+    let extended = result.extended("more\n");
+    assert!(extended.is_spooled());
+    assert_eq!(extended.len(), SPOOL_THRESHOLD_LINES + 2);
+    assert_eq!(
+        extended.line(SPOOL_THRESHOLD_LINES + 1).as_deref(),
+        Some("more")
+    );
+    assert_eq!(result.len(), SPOOL_THRESHOLD_LINES + 1);
+}
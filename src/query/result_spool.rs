@@ -0,0 +1,242 @@
+//! Spooled Results
+//!
+//! The results pane doesn't hold on to results with hundreds of thousands of
+//! lines. Above [`SPOOL_THRESHOLD_LINES`] the result text is written to a
+//! temporary spool file instead, and the lines around the viewport are read
+//! back from the file as the results are scrolled.
+
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Read, Seek, SeekFrom, Write};
+use std::ops::Range;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+/// Results with more lines than this are spooled to a file
+pub const SPOOL_THRESHOLD_LINES: usize = 100_000;
+
+/// Lines read from the file at once around the requested line
+const WINDOW_LINES: usize = 2_000;
+
+static NEXT_SPOOL_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// Lines read back from the spool file last
+#[derive(Debug, Default)]
+struct Window {
+    /// Index of the first line read
+    start: usize,
+    len: usize,
+    text: String,
+}
+
+#[derive(Debug)]
+struct Reader {
+    file: File,
+    window: Window,
+}
+
+/// Spool file shared by a result and the results extending it
+///
+/// The file is removed once the last of them is dropped.
+#[derive(Debug)]
+struct SpoolFile {
+    path: PathBuf,
+    /// Handle the result text is appended with
+    writer: Mutex<File>,
+}
+
+impl Drop for SpoolFile {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+/// Result text kept in a temporary file
+///
+/// The file is removed once no spool reads it anymore.
+#[derive(Debug)]
+pub struct ResultSpool {
+    reader: Mutex<Reader>,
+    /// Byte offset of the start of each line, followed by the end offset
+    line_offsets: Vec<u64>,
+    /// Whether the text ends with a line ending, so text appended to it
+    /// starts a new line
+    ends_with_newline: bool,
+    file: Arc<SpoolFile>,
+}
+
+impl ResultSpool {
+    /// Write `output` to a new spool file
+    pub fn create(output: &str) -> io::Result<Self> {
+        let (path, writer) = create_spool_file()?;
+        // From here on the spool file is removed when dropped
+        let file = Arc::new(SpoolFile {
+            path,
+            writer: Mutex::new(writer),
+        });
+        {
+            let mut writer = lock(&file.writer)?;
+            writer.write_all(output.as_bytes())?;
+            writer.flush()?;
+        }
+        Self::open(file, line_offsets(output), output.ends_with('\n'))
+    }
+
+    /// Spool of this result followed by `more`, which starts a new line
+    ///
+    /// `more` is appended to this spool's file, which stays valid as it only
+    /// reads the part before it. Should the file have been extended already,
+    /// by a result that was then dropped for this one, the text is copied to
+    /// a new file instead.
+    pub fn extended(&self, more: &str) -> io::Result<Self> {
+        if more.is_empty() {
+            let offsets = self.line_offsets.clone();
+            return Self::open(Arc::clone(&self.file), offsets, self.ends_with_newline);
+        }
+        let end = self.end_offset();
+        let separator = if end > 0 && !self.ends_with_newline {
+            "\n"
+        } else {
+            ""
+        };
+        let mut offsets = self.line_offsets[..self.line_offsets.len() - 1].to_vec();
+        let more_start = end + separator.len() as u64;
+        offsets.extend(
+            line_offsets(more)
+                .into_iter()
+                .map(|offset| offset + more_start),
+        );
+        let ends_with_newline = more.ends_with('\n');
+
+        let mut writer = lock(&self.file.writer)?;
+        if writer.metadata()?.len() == end {
+            writer.seek(SeekFrom::Start(end))?;
+            writer.write_all(separator.as_bytes())?;
+            writer.write_all(more.as_bytes())?;
+            writer.flush()?;
+            drop(writer);
+            return Self::open(Arc::clone(&self.file), offsets, ends_with_newline);
+        }
+        drop(writer);
+
+        let mut text = self.read_all()?;
+        text.push_str(separator);
+        text.push_str(more);
+        Self::create(&text)
+    }
+
+    fn open(
+        file: Arc<SpoolFile>,
+        line_offsets: Vec<u64>,
+        ends_with_newline: bool,
+    ) -> io::Result<Self> {
+        // A handle of its own, so reading doesn't move the writer's position
+        let reader = File::open(&file.path)?;
+        Ok(Self {
+            reader: Mutex::new(Reader {
+                file: reader,
+                window: Window::default(),
+            }),
+            line_offsets,
+            ends_with_newline,
+            file,
+        })
+    }
+
+    pub fn line_count(&self) -> usize {
+        self.line_offsets.len().saturating_sub(1)
+    }
+
+    /// Text of line `index`, without its line ending
+    ///
+    /// A line outside the window read last is read from the file along with
+    /// the lines around it, so scrolling mostly stays in memory. None past
+    /// the end and if the file can't be read.
+    pub fn line(&self, index: usize) -> Option<String> {
+        let total = self.line_count();
+        if index >= total {
+            return None;
+        }
+
+        let mut reader = self.reader.lock().ok()?;
+        let window = &reader.window;
+        if index < window.start || index >= window.start + window.len {
+            let len = WINDOW_LINES.min(total);
+            let start = index.saturating_sub(len / 2).min(total - len);
+            let text = self.read(&mut reader.file, start..start + len).ok()?;
+            reader.window = Window { start, len, text };
+        }
+
+        let window_offset = self.line_offsets[reader.window.start];
+        let from = (self.line_offsets[index] - window_offset) as usize;
+        let to = (self.line_offsets[index + 1] - window_offset) as usize;
+        let line = &reader.window.text[from..to];
+        Some(line.strip_suffix('\n').unwrap_or(line).to_string())
+    }
+
+    /// The whole result text, read back from the file
+    pub fn read_all(&self) -> io::Result<String> {
+        let mut reader = lock(&self.reader)?;
+        self.read(&mut reader.file, 0..self.line_count())
+    }
+
+    fn end_offset(&self) -> u64 {
+        self.line_offsets.last().copied().unwrap_or(0)
+    }
+
+    fn read(&self, file: &mut File, range: Range<usize>) -> io::Result<String> {
+        let from = self.line_offsets[range.start];
+        let to = self.line_offsets[range.end];
+        let mut bytes = vec![0; (to - from) as usize];
+        file.seek(SeekFrom::Start(from))?;
+        file.read_exact(&mut bytes)?;
+        String::from_utf8(bytes).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> io::Result<MutexGuard<'_, T>> {
+    mutex
+        .lock()
+        .map_err(|_| io::Error::other("spool file lock poisoned"))
+}
+
+/// Start of each line of `output`, followed by its length
+fn line_offsets(output: &str) -> Vec<u64> {
+    let bytes = output.as_bytes();
+    let mut offsets = Vec::new();
+    if !bytes.is_empty() {
+        offsets.push(0);
+    }
+    for newline in memchr::memchr_iter(b'\n', bytes) {
+        if newline + 1 < bytes.len() {
+            offsets.push(newline as u64 + 1);
+        }
+    }
+    offsets.push(bytes.len() as u64);
+    offsets
+}
+
+/// Create a new spool file in the temporary directory, readable by its owner
+/// only since results can hold anything from the input
+fn create_spool_file() -> io::Result<(PathBuf, File)> {
+    loop {
+        let id = NEXT_SPOOL_ID.fetch_add(1, Ordering::Relaxed);
+        let path = std::env::temp_dir().join(format!("jiq-{}-{}.spool", std::process::id(), id));
+        let mut options = OpenOptions::new();
+        options.read(true).write(true).create_new(true);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+            options.mode(0o600);
+        }
+        match options.open(&path) {
+            Ok(file) => return Ok((path, file)),
+            Err(e) if e.kind() == io::ErrorKind::AlreadyExists => continue,
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "result_spool_tests.rs"]
+mod result_spool_tests;
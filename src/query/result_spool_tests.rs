@@ -0,0 +1,132 @@
+//! Tests for result_spool
+
+use super::*;
+
+fn line_texts(spool: &ResultSpool, range: Range<usize>) -> Vec<String> {
+    range.map_while(|index| spool.line(index)).collect()
+}
+
+fn numbered_lines(count: usize) -> String {
+    (0..count).map(|i| format!("line {}\n", i)).collect()
+}
+
+#[test]
+fn test_spool_indexes_lines() {
+    let spool = ResultSpool::create("a\nbb\n\nccc\n").unwrap();
+    assert_eq!(spool.line_count(), 4);
+    assert_eq!(line_texts(&spool, 0..4), vec!["a", "bb", "", "ccc"]);
+
+    let spool = ResultSpool::create("a\nb").unwrap();
+    assert_eq!(spool.line_count(), 2);
+    assert_eq!(line_texts(&spool, 0..2), vec!["a", "b"]);
+
+    let spool = ResultSpool::create("").unwrap();
+    assert_eq!(spool.line_count(), 0);
+    assert_eq!(spool.line(0), None);
+}
+
+#[test]
+fn test_lines_are_read_in_windows_anywhere_in_the_result() {
+    let spool = ResultSpool::create(&numbered_lines(10_000)).unwrap();
+
+    assert_eq!(line_texts(&spool, 0..2), vec!["line 0", "line 1"]);
+    assert_eq!(
+        line_texts(&spool, 7_000..7_003),
+        vec!["line 7000", "line 7001", "line 7002"]
+    );
+    assert_eq!(
+        line_texts(&spool, 9_998..20_000),
+        vec!["line 9998", "line 9999"]
+    );
+    assert_eq!(spool.line(10_000), None);
+    assert_eq!(spool.line(3).as_deref(), Some("line 3"));
+}
+
+#[test]
+fn test_multibyte_text_is_read_back_whole() {
+    let spool = ResultSpool::create("\"héllo\"\n\"wörld\"\n").unwrap();
+
+    assert_eq!(line_texts(&spool, 0..2), vec!["\"héllo\"", "\"wörld\""]);
+}
+
+#[test]
+fn test_spool_file_removed_on_drop() {
+    let spool = ResultSpool::create("a\n").unwrap();
+    let path = spool.file.path.clone();
+    assert!(path.exists());
+
+    drop(spool);
+
+    assert!(!path.exists());
+}
+
+#[cfg(unix)]
+#[test]
+fn test_spool_file_readable_by_owner_only() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let spool = ResultSpool::create("a\n").unwrap();
+    let mode = fs::metadata(&spool.file.path).unwrap().permissions().mode();
+    assert_eq!(mode & 0o777, 0o600);
+}
+
+#[test]
+fn test_extended_appends_to_the_same_file() {
+    let spool = ResultSpool::create("a\nb\n").unwrap();
+    let extended = spool.extended("c\nd\n").unwrap();
+
+    assert!(Arc::ptr_eq(&spool.file, &extended.file));
+    assert_eq!(line_texts(&extended, 0..4), vec!["a", "b", "c", "d"]);
+    // The base still reads only its own lines
+    assert_eq!(spool.line_count(), 2);
+    assert_eq!(spool.read_all().unwrap(), "a\nb\n");
+    assert_eq!(extended.read_all().unwrap(), "a\nb\nc\nd\n");
+}
+
+#[test]
+fn test_extended_starts_a_new_line() {
+    let spool = ResultSpool::create("a\nb").unwrap();
+    let extended = spool.extended("c").unwrap();
+
+    assert_eq!(line_texts(&extended, 0..3), vec!["a", "b", "c"]);
+    assert_eq!(extended.read_all().unwrap(), "a\nb\nc");
+
+    let extended = extended.extended("d\n").unwrap();
+    assert_eq!(extended.read_all().unwrap(), "a\nb\nc\nd\n");
+}
+
+#[test]
+fn test_extended_copies_a_file_extended_already() {
+    let spool = ResultSpool::create("a\n").unwrap();
+    let first = spool.extended("b\n").unwrap();
+    drop(first);
+
+    let second = spool.extended("c\n").unwrap();
+
+    assert!(!Arc::ptr_eq(&spool.file, &second.file));
+    assert_eq!(line_texts(&second, 0..2), vec!["a", "c"]);
+    assert_eq!(spool.read_all().unwrap(), "a\n");
+}
+
+#[test]
+fn test_extended_with_nothing_keeps_the_lines() {
+    let spool = ResultSpool::create("a\n").unwrap();
+    let extended = spool.extended("").unwrap();
+
+    assert!(Arc::ptr_eq(&spool.file, &extended.file));
+    assert_eq!(line_texts(&extended, 0..2), vec!["a"]);
+}
+
+#[test]
+fn test_spool_file_kept_while_extended_spool_reads_it() {
+    let spool = ResultSpool::create("a\n").unwrap();
+    let extended = spool.extended("b\n").unwrap();
+    let path = spool.file.path.clone();
+
+    drop(spool);
+    assert!(path.exists());
+    assert_eq!(extended.line(1).as_deref(), Some("b"));
+
+    drop(extended);
+    assert!(!path.exists());
+}
@@ -0,0 +1,140 @@
+//! Query Timeline
+//!
+//! In-memory record of the queries run in this session, stepped through with
+//! Alt+Left/Alt+Right like browser history. Going back and running a new
+//! query starts a branch instead of discarding the later entries, so the
+//! timeline is a tree; stepping forward follows the branch entered last.
+//!
+//! Recent entries keep a snapshot of their result so stepping back shows it
+//! without re-running jq. Unlike the persistent history, nothing is saved.
+
+use crate::query::language::QueryLanguage;
+use crate::query::result_cache::CachedResult;
+
+/// Number of most recently recorded entries that keep their result snapshot
+pub const MAX_SNAPSHOTS: usize = 32;
+
+/// A query run during the session
+#[derive(Debug, Clone)]
+pub struct TimelineEntry {
+    pub query: String,
+    pub language: QueryLanguage,
+    /// Result at the time, `None` once evicted (the query is re-run instead)
+    pub snapshot: Option<CachedResult>,
+    parent: Option<usize>,
+    /// Child recorded last, followed when stepping forward
+    latest_child: Option<usize>,
+}
+
+/// Tree of the queries run this session
+#[derive(Debug, Default)]
+pub struct QueryTimeline {
+    entries: Vec<TimelineEntry>,
+    current: Option<usize>,
+}
+
+impl QueryTimeline {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[allow(dead_code)]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    #[allow(dead_code)]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn current(&self) -> Option<&TimelineEntry> {
+        self.current.map(|i| &self.entries[i])
+    }
+
+    /// Record a completed query after the current entry
+    ///
+    /// Re-running the current query only refreshes its snapshot. Returns
+    /// true when a new entry was added.
+    pub fn record(
+        &mut self,
+        query: &str,
+        language: QueryLanguage,
+        snapshot: Option<CachedResult>,
+    ) -> bool {
+        if let Some(current) = self.current {
+            let entry = &mut self.entries[current];
+            if entry.query == query && entry.language == language {
+                if snapshot.is_some() {
+                    entry.snapshot = snapshot;
+                }
+                return false;
+            }
+        }
+
+        let index = self.entries.len();
+        self.entries.push(TimelineEntry {
+            query: query.to_string(),
+            language,
+            snapshot,
+            parent: self.current,
+            latest_child: None,
+        });
+        if let Some(parent) = self.current {
+            self.entries[parent].latest_child = Some(index);
+        }
+        self.current = Some(index);
+
+        if let Some(evicted) = index.checked_sub(MAX_SNAPSHOTS) {
+            self.entries[evicted].snapshot = None;
+        }
+        true
+    }
+
+    /// Step back to the entry the current one was run after
+    pub fn back(&mut self) -> Option<&TimelineEntry> {
+        let parent = self.entries[self.current?].parent?;
+        self.current = Some(parent);
+        self.current()
+    }
+
+    /// Step forward along the branch entered last
+    pub fn forward(&mut self) -> Option<&TimelineEntry> {
+        let child = self.entries[self.current?].latest_child?;
+        self.current = Some(child);
+        self.current()
+    }
+
+    /// Position of the current entry on its branch as (step, steps), both
+    /// counted from 1, or None when the timeline is empty
+    pub fn position(&self) -> Option<(usize, usize)> {
+        let current = self.current?;
+
+        let mut step = 1;
+        let mut index = current;
+        while let Some(parent) = self.entries[index].parent {
+            step += 1;
+            index = parent;
+        }
+
+        let mut steps = step;
+        let mut index = current;
+        while let Some(child) = self.entries[index].latest_child {
+            steps += 1;
+            index = child;
+        }
+
+        Some((step, steps))
+    }
+
+    /// Drop all result snapshots, e.g. when the input changes
+    pub fn clear_snapshots(&mut self) {
+        for entry in &mut self.entries {
+            entry.snapshot = None;
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "timeline_tests.rs"]
+mod timeline_tests;
@@ -0,0 +1,126 @@
+//! Tests for query timeline
+
+use super::*;
+use crate::query::result_lines::ResultLines;
+use std::sync::Arc;
+
+fn snapshot(output: &str) -> Option<CachedResult> {
+    Some(CachedResult {
+        unformatted: Arc::new(output.to_string()),
+        lines: ResultLines::new(Arc::new(output.to_string())),
+        parsed: None,
+        for_context: None,
+        line_count: 1,
+        max_width: output.len() as u16,
+        line_widths: None,
+        result_type: None,
+        base_query: None,
+        execution_time_ms: None,
+    })
+}
+
+fn record(timeline: &mut QueryTimeline, query: &str) -> bool {
+    timeline.record(query, QueryLanguage::Jq, snapshot(query))
+}
+
+fn current_query(timeline: &QueryTimeline) -> Option<&str> {
+    timeline.current().map(|e| e.query.as_str())
+}
+
+#[test]
+fn test_new_timeline_is_empty() {
+    let mut timeline = QueryTimeline::new();
+    assert!(timeline.is_empty());
+    assert!(timeline.current().is_none());
+    assert!(timeline.back().is_none());
+    assert!(timeline.forward().is_none());
+    assert_eq!(timeline.position(), None);
+}
+
+#[test]
+fn test_back_and_forward_step_through_queries() {
+    let mut timeline = QueryTimeline::new();
+    record(&mut timeline, ".");
+    record(&mut timeline, ".items");
+    record(&mut timeline, ".items[0]");
+
+    assert_eq!(timeline.back().map(|e| e.query.as_str()), Some(".items"));
+    assert_eq!(timeline.back().map(|e| e.query.as_str()), Some("."));
+    assert!(timeline.back().is_none());
+    assert_eq!(current_query(&timeline), Some("."));
+
+    assert_eq!(timeline.forward().map(|e| e.query.as_str()), Some(".items"));
+    assert_eq!(
+        timeline.forward().map(|e| e.query.as_str()),
+        Some(".items[0]")
+    );
+    assert!(timeline.forward().is_none());
+}
+
+#[test]
+fn test_rerunning_current_query_is_not_recorded() {
+    let mut timeline = QueryTimeline::new();
+    assert!(record(&mut timeline, ".a"));
+    assert!(!record(&mut timeline, ".a"));
+    assert_eq!(timeline.len(), 1);
+
+    // The same text in another language is a different query
+    assert!(timeline.record(".a", QueryLanguage::JmesPath, None));
+    assert_eq!(timeline.len(), 2);
+}
+
+#[test]
+fn test_new_query_after_going_back_starts_a_branch() {
+    let mut timeline = QueryTimeline::new();
+    record(&mut timeline, ".");
+    record(&mut timeline, ".a");
+    record(&mut timeline, ".a.b");
+    timeline.back();
+    timeline.back();
+
+    record(&mut timeline, ".c");
+    assert_eq!(timeline.len(), 4);
+    assert_eq!(timeline.position(), Some((2, 2)));
+    assert_eq!(timeline.back().map(|e| e.query.as_str()), Some("."));
+
+    // Forward follows the newest branch
+    assert_eq!(timeline.forward().map(|e| e.query.as_str()), Some(".c"));
+}
+
+#[test]
+fn test_position_counts_steps_on_current_branch() {
+    let mut timeline = QueryTimeline::new();
+    for query in [".", ".a", ".a.b", ".a.b.c"] {
+        record(&mut timeline, query);
+    }
+    assert_eq!(timeline.position(), Some((4, 4)));
+
+    timeline.back();
+    timeline.back();
+    assert_eq!(timeline.position(), Some((2, 4)));
+}
+
+#[test]
+fn test_old_snapshots_are_evicted() {
+    let mut timeline = QueryTimeline::new();
+    for i in 0..=MAX_SNAPSHOTS {
+        record(&mut timeline, &format!(".[{}]", i));
+    }
+
+    assert!(timeline.entries[0].snapshot.is_none());
+    assert!(timeline.entries[1].snapshot.is_some());
+    assert!(timeline.current().unwrap().snapshot.is_some());
+}
+
+#[test]
+fn test_clear_snapshots_keeps_queries() {
+    let mut timeline = QueryTimeline::new();
+    record(&mut timeline, ".a");
+    record(&mut timeline, ".b");
+
+    timeline.clear_snapshots();
+
+    assert_eq!(timeline.len(), 2);
+    assert!(timeline.current().unwrap().snapshot.is_none());
+    assert_eq!(timeline.back().map(|e| e.query.as_str()), Some(".a"));
+}
@@ -0,0 +1,114 @@
+//! Query Variables
+//!
+//! Named values bound for every query run, the equivalent of jq's `--arg`
+//! and `--argjson` options. They are referenced in the query as `$name`, so
+//! values can change without editing the query text.
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// How a variable's value is passed to jq
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum VariableKind {
+    /// Bound as a string (`--arg`)
+    #[default]
+    String,
+    /// Parsed as JSON (`--argjson`)
+    Json,
+}
+
+impl VariableKind {
+    /// jq option that binds a variable of this kind
+    pub fn jq_option(self) -> &'static str {
+        match self {
+            VariableKind::String => "--arg",
+            VariableKind::Json => "--argjson",
+        }
+    }
+
+    pub fn toggle(self) -> Self {
+        match self {
+            VariableKind::String => VariableKind::Json,
+            VariableKind::Json => VariableKind::String,
+        }
+    }
+}
+
+impl fmt::Display for VariableKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VariableKind::String => write!(f, "string"),
+            VariableKind::Json => write!(f, "json"),
+        }
+    }
+}
+
+/// A named value available to queries as `$name`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QueryVariable {
+    /// Name without the leading `$`
+    pub name: String,
+    pub value: String,
+    #[serde(default)]
+    pub kind: VariableKind,
+}
+
+impl QueryVariable {
+    pub fn new(name: impl Into<String>, value: impl Into<String>, kind: VariableKind) -> Self {
+        Self {
+            name: name.into(),
+            value: value.into(),
+            kind,
+        }
+    }
+
+    /// Check that jq will accept this variable
+    pub fn validate(&self) -> Result<(), String> {
+        validate_name(&self.name)?;
+        if self.kind == VariableKind::Json
+            && let Err(e) = serde_json::from_str::<serde_json::Value>(&self.value)
+        {
+            return Err(format!("${} is not valid JSON: {}", self.name, e));
+        }
+        Ok(())
+    }
+}
+
+/// Check that `name` is a valid jq variable name (without the `$`)
+pub fn validate_name(name: &str) -> Result<(), String> {
+    let mut chars = name.chars();
+    let valid = match chars.next() {
+        Some(first) => {
+            (first.is_ascii_alphabetic() || first == '_')
+                && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+        }
+        None => false,
+    };
+    if valid {
+        Ok(())
+    } else if name.is_empty() {
+        Err("Variable name is empty".to_string())
+    } else {
+        Err(format!("Invalid variable name '{}'", name))
+    }
+}
+
+/// Command line arguments binding `variables` for a jq invocation
+pub fn jq_args(variables: &[QueryVariable]) -> Vec<String> {
+    variables
+        .iter()
+        .flat_map(|variable| {
+            [
+                variable.kind.jq_option().to_string(),
+                variable.name.clone(),
+                variable.value.clone(),
+            ]
+        })
+        .collect()
+}
+
+#[cfg(test)]
+#[path = "variables_tests.rs"]
+mod variables_tests;
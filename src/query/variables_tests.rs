@@ -0,0 +1,52 @@
+//! Tests for query variables
+
+use super::*;
+
+#[test]
+fn test_jq_args_per_kind() {
+    let variables = vec![
+        QueryVariable::new("env", "prod", VariableKind::String),
+        QueryVariable::new("limit", "3", VariableKind::Json),
+    ];
+    assert_eq!(
+        jq_args(&variables),
+        vec!["--arg", "env", "prod", "--argjson", "limit", "3"]
+    );
+    assert!(jq_args(&[]).is_empty());
+}
+
+#[test]
+fn test_validate_name() {
+    assert!(validate_name("env").is_ok());
+    assert!(validate_name("_x1").is_ok());
+    assert!(validate_name("").is_err());
+    assert!(validate_name("1x").is_err());
+    assert!(validate_name("a-b").is_err());
+    assert!(validate_name("$a").is_err());
+}
+
+#[test]
+fn test_validate_json_value() {
+    assert!(
+        QueryVariable::new("n", "[1, 2]", VariableKind::Json)
+            .validate()
+            .is_ok()
+    );
+    assert!(
+        QueryVariable::new("n", "nope", VariableKind::String)
+            .validate()
+            .is_ok()
+    );
+
+    let err = QueryVariable::new("n", "nope", VariableKind::Json)
+        .validate()
+        .unwrap_err();
+    assert!(err.starts_with("$n is not valid JSON"));
+}
+
+#[test]
+fn test_kind_toggle_and_display() {
+    assert_eq!(VariableKind::String.toggle(), VariableKind::Json);
+    assert_eq!(VariableKind::Json.toggle(), VariableKind::String);
+    assert_eq!(VariableKind::Json.to_string(), "json");
+}
@@ -32,11 +32,15 @@
 //!     query: ".foo".to_string(),
 //!     request_id: 1,
 //!     cancel_token,
+//!     prefix: None,
+//!     append_to: None,
+//!     variables: Default::default(),
+//!     library: Default::default(),
 //! }).unwrap();
 //!
 //! // Receive response
 //! match response_rx.recv().unwrap() {
-//!     QueryResponse::ProcessedSuccess { processed, .. } => println!("{}", processed.output),
+//!     QueryResponse::ProcessedSuccess { processed, .. } => println!("{}", processed.unformatted),
 //!     QueryResponse::Error { message, .. } => eprintln!("{}", message),
 //!     QueryResponse::Cancelled { .. } => println!("Cancelled"),
 //! }
@@ -48,4 +52,4 @@ pub mod types;
 
 // Re-exports for convenience
 pub use thread::spawn_worker;
-pub use types::{QueryRequest, QueryResponse};
+pub use types::{PrefixInput, QueryRequest, QueryResponse};
@@ -5,21 +5,18 @@
 
 use std::sync::Arc;
 
-use ansi_to_tui::IntoText;
-use memchr::memchr;
-use ratatui::text::Text;
 use serde_json::Value;
 use tokio_util::sync::CancellationToken;
 
-use super::types::{ProcessedResult, QueryError, RenderedLine, RenderedSpan};
+use super::types::{ProcessedResult, QueryError};
 use crate::query::query_state::ResultType;
+use crate::query::result_lines::ResultLines;
 
 /// Preprocess query result by performing all expensive operations
 ///
 /// This includes:
-/// - Stripping ANSI codes
 /// - Computing line metrics
-/// - Parsing ANSI to rendered lines
+/// - Indexing lines for rendering
 /// - Parsing JSON for autocomplete
 ///
 /// Checks cancellation token between operations to allow fast cancellation.
@@ -28,23 +25,18 @@ pub fn preprocess_result(
     query: &str,
     cancel_token: &CancellationToken,
 ) -> Result<ProcessedResult, QueryError> {
-    // Strip ANSI codes
-    if cancel_token.is_cancelled() {
-        return Err(QueryError::Cancelled);
-    }
-    let unformatted = strip_ansi_codes(&output);
-
     // Compute line metrics, widths, and is_only_nulls in a single pass
     if cancel_token.is_cancelled() {
         return Err(QueryError::Cancelled);
     }
-    let (line_count, max_width, line_widths, is_only_nulls) = compute_line_metrics(&unformatted);
+    let (line_count, max_width, line_widths, is_only_nulls) = compute_line_metrics(&output);
 
-    // Parse ANSI to RenderedLine
+    // Index lines so only the visible ones are highlighted when rendering
     if cancel_token.is_cancelled() {
         return Err(QueryError::Cancelled);
     }
-    let rendered_lines = parse_ansi_to_rendered_lines(&output, cancel_token)?;
+    let unformatted = Arc::new(output);
+    let lines = ResultLines::new(Arc::clone(&unformatted));
 
     // Parse JSON and detect type in single pass
     if cancel_token.is_cancelled() {
@@ -56,9 +48,8 @@ pub fn preprocess_result(
     let base_query = normalize_base_query(query);
 
     Ok(ProcessedResult {
-        output: Arc::new(output),
-        unformatted: Arc::new(unformatted),
-        rendered_lines,
+        unformatted,
+        lines,
         parsed,
         line_count,
         max_width,
@@ -102,103 +93,6 @@ fn compute_line_metrics(output: &str) -> (u32, u16, Arc<Vec<u16>>, bool) {
     )
 }
 
-/// Parse ANSI text into rendered lines
-///
-/// Converts ANSI escape sequences to styled spans for rendering.
-/// Checks cancellation every 10,000 lines for large files.
-fn parse_ansi_to_rendered_lines(
-    output: &str,
-    cancel_token: &CancellationToken,
-) -> Result<Vec<RenderedLine>, QueryError> {
-    // Check cancellation before starting expensive operation
-    if cancel_token.is_cancelled() {
-        return Err(QueryError::Cancelled);
-    }
-
-    // Parse ANSI codes to Text
-    let text: Text = output
-        .as_bytes()
-        .into_text()
-        .unwrap_or_else(|_| Text::raw(output.to_string()));
-
-    // Convert Text to Vec<RenderedLine>
-    let rendered_lines = text
-        .lines
-        .into_iter()
-        .enumerate()
-        .map(|(idx, line)| {
-            // Check cancellation every 10,000 lines
-            if idx % 10000 == 0 && cancel_token.is_cancelled() {
-                return Err(QueryError::Cancelled);
-            }
-
-            let spans = line
-                .spans
-                .into_iter()
-                .map(|span| RenderedSpan {
-                    content: span.content.to_string(),
-                    style: span.style,
-                })
-                .collect();
-
-            Ok(RenderedLine { spans })
-        })
-        .collect::<Result<Vec<_>, _>>()?;
-
-    Ok(rendered_lines)
-}
-
-/// Strip ANSI escape codes from a string using SIMD-accelerated scanning
-///
-/// jq outputs colored results with ANSI codes like:
-/// - `\x1b[0m` (reset)
-/// - `\x1b[1;39m` (bold)
-/// - `\x1b[0;32m` (green)
-///
-/// Uses memchr for fast byte-level scanning and bulk memory copies.
-pub fn strip_ansi_codes(s: &str) -> String {
-    let bytes = s.as_bytes();
-    let mut result = Vec::with_capacity(bytes.len());
-    let mut pos = 0;
-
-    // Find each escape sequence using SIMD-accelerated search
-    while let Some(esc_offset) = memchr(b'\x1b', &bytes[pos..]) {
-        let esc_pos = pos + esc_offset;
-
-        // Bulk copy everything before escape
-        result.extend_from_slice(&bytes[pos..esc_pos]);
-
-        // Skip escape sequence
-        pos = skip_csi_sequence(bytes, esc_pos);
-    }
-
-    // Copy remaining content after last escape
-    result.extend_from_slice(&bytes[pos..]);
-
-    // Safe: we only copied valid UTF-8 byte sequences from valid input
-    unsafe { String::from_utf8_unchecked(result) }
-}
-
-/// Skip a CSI (Control Sequence Introducer) sequence
-///
-/// CSI sequences have the format: ESC [ parameters m
-/// where parameters are numbers and semicolons
-fn skip_csi_sequence(bytes: &[u8], start: usize) -> usize {
-    let mut pos = start + 1; // Skip ESC
-
-    if pos < bytes.len() && bytes[pos] == b'[' {
-        pos += 1; // Skip '['
-        // Skip until 'm' (SGR terminator)
-        while pos < bytes.len() {
-            if bytes[pos] == b'm' {
-                return pos + 1;
-            }
-            pos += 1;
-        }
-    }
-    pos
-}
-
 /// Parse JSON and detect its type in a single pass
 ///
 /// Returns both the parsed first value and the result type, avoiding duplicate parsing.
@@ -1,9 +1,7 @@
 //! Tests for preprocessing functions
 
 use crate::query::query_state::ResultType;
-use crate::query::worker::preprocess::{
-    parse_and_detect_type, preprocess_result, strip_ansi_codes,
-};
+use crate::query::worker::preprocess::{parse_and_detect_type, preprocess_result};
 use crate::query::worker::types::QueryError;
 use tokio_util::sync::CancellationToken;
 
@@ -17,27 +15,21 @@ fn test_preprocess_result_basic() {
     assert!(result.is_ok());
 
     let processed = result.unwrap();
-    assert_eq!(processed.output.as_ref(), &output);
+    assert_eq!(processed.unformatted.as_ref(), &output);
     assert_eq!(processed.query, ".");
     assert_eq!(processed.result_type, ResultType::Object);
     assert!(processed.parsed.is_some());
-    assert!(!processed.rendered_lines.is_empty());
+    assert_eq!(processed.lines.len(), 1);
 }
 
 #[test]
-fn test_preprocess_result_strips_ansi() {
-    let output_with_ansi = "\x1b[0;32m\"test\"\x1b[0m".to_string();
+fn test_preprocess_result_keeps_output_uncolored() {
+    let output = "{\n  \"a\": 1\n}".to_string();
     let cancel_token = CancellationToken::new();
 
-    let result = preprocess_result(output_with_ansi, ".", &cancel_token);
-    assert!(result.is_ok());
-
-    let processed = result.unwrap();
-    assert_eq!(
-        processed.unformatted.as_ref(),
-        "\"test\"",
-        "Should strip ANSI codes"
-    );
+    let processed = preprocess_result(output.clone(), ".", &cancel_token).unwrap();
+    assert_eq!(processed.unformatted.as_ref(), &output);
+    assert_eq!(processed.lines.line(1).as_deref(), Some("  \"a\": 1"));
 }
 
 #[test]
@@ -185,31 +177,13 @@ fn test_preprocess_result_handles_invalid_json() {
 }
 
 #[test]
-fn test_rendered_lines_conversion() {
-    // Test that rendered lines are created correctly
-    let output_with_colors = "\x1b[0;32mtest\x1b[0m".to_string();
+fn test_preprocess_result_indexes_lines() {
+    let output = "[\n  1,\n  2\n]\n".to_string();
     let cancel_token = CancellationToken::new();
 
-    let result = preprocess_result(output_with_colors, ".", &cancel_token);
-    assert!(result.is_ok());
-
-    let processed = result.unwrap();
-    assert!(
-        !processed.rendered_lines.is_empty(),
-        "Should have rendered lines"
-    );
-
-    // Verify line structure
-    let first_line = &processed.rendered_lines[0];
-    assert!(!first_line.spans.is_empty(), "Should have spans");
-
-    // Content should be unformatted
-    let content: String = first_line
-        .spans
-        .iter()
-        .map(|s| s.content.as_str())
-        .collect();
-    assert!(content.contains("test"), "Should contain unformatted text");
+    let processed = preprocess_result(output, ".", &cancel_token).unwrap();
+    assert_eq!(processed.lines.len(), processed.line_count as usize);
+    assert_eq!(processed.lines.line(1).as_deref(), Some("  1,"));
 }
 
 #[test]
@@ -250,100 +224,6 @@ fn test_preprocess_max_width_clamped_to_u16_max() {
     );
 }
 
-// Unit tests for strip_ansi_codes function
-
-#[test]
-fn test_strip_ansi_codes_basic_sgr() {
-    let input = "\x1b[0;32mgreen text\x1b[0m";
-    let result = strip_ansi_codes(input);
-    assert_eq!(result, "green text", "Should strip SGR sequences");
-}
-
-#[test]
-fn test_strip_ansi_codes_multiple_sequences() {
-    let input = "\x1b[1;39mbold\x1b[0m normal \x1b[0;32mgreen\x1b[0m";
-    let result = strip_ansi_codes(input);
-    assert_eq!(result, "bold normal green", "Should strip all sequences");
-}
-
-#[test]
-fn test_strip_ansi_codes_typical_jq_output() {
-    let input = "\x1b[1;39m{\x1b[0m\n  \x1b[0;34m\"name\"\x1b[0m: \x1b[0;32m\"Alice\"\x1b[0m,\n  \x1b[0;34m\"age\"\x1b[0m: \x1b[0;33m30\x1b[0m\n\x1b[1;39m}\x1b[0m";
-    let result = strip_ansi_codes(input);
-    assert_eq!(
-        result, "{\n  \"name\": \"Alice\",\n  \"age\": 30\n}",
-        "Should strip typical jq colored output"
-    );
-}
-
-#[test]
-fn test_strip_ansi_codes_empty_string() {
-    let input = "";
-    let result = strip_ansi_codes(input);
-    assert_eq!(result, "", "Should handle empty string");
-}
-
-#[test]
-fn test_strip_ansi_codes_no_escapes() {
-    let input = "plain text without escapes";
-    let result = strip_ansi_codes(input);
-    assert_eq!(
-        result, "plain text without escapes",
-        "Should return identical content for plain text"
-    );
-}
-
-#[test]
-fn test_strip_ansi_codes_only_escape_sequences() {
-    let input = "\x1b[0m\x1b[1;39m\x1b[0;32m";
-    let result = strip_ansi_codes(input);
-    assert_eq!(result, "", "Should result in empty string");
-}
-
-#[test]
-fn test_strip_ansi_codes_malformed_no_bracket() {
-    let input = "\x1bX some text";
-    let result = strip_ansi_codes(input);
-    assert_eq!(
-        result, "X some text",
-        "Should handle escape without bracket"
-    );
-}
-
-#[test]
-fn test_strip_ansi_codes_malformed_no_terminator() {
-    let input = "\x1b[0;32 no closing bracket";
-    let result = strip_ansi_codes(input);
-    assert_eq!(
-        result, "",
-        "Should consume everything after unclosed escape sequence"
-    );
-}
-
-#[test]
-fn test_strip_ansi_codes_preserves_utf8() {
-    let input = "\x1b[0;32m你好世界\x1b[0m emoji: 🎉";
-    let result = strip_ansi_codes(input);
-    assert_eq!(
-        result, "你好世界 emoji: 🎉",
-        "Should preserve UTF-8 characters"
-    );
-}
-
-#[test]
-fn test_strip_ansi_codes_consecutive_escapes() {
-    let input = "text\x1b[0m\x1b[1;39m\x1b[0;32mmore text";
-    let result = strip_ansi_codes(input);
-    assert_eq!(result, "textmore text", "Should handle consecutive escapes");
-}
-
-#[test]
-fn test_strip_ansi_codes_escape_at_boundaries() {
-    let input = "\x1b[0;32mstart\x1b[0m middle \x1b[1;39mend\x1b[0m";
-    let result = strip_ansi_codes(input);
-    assert_eq!(result, "start middle end", "Should handle boundary escapes");
-}
-
 // Unit tests for parse_and_detect_type function
 
 #[test]
@@ -354,6 +234,15 @@ fn test_parse_and_detect_type_single_object() {
     assert_eq!(result_type, ResultType::Object);
 }
 
+#[test]
+fn test_parse_and_detect_type_keeps_key_order_and_large_numbers() {
+    let (parsed, _) = parse_and_detect_type(r#"{"zeta": 12345678901234567891, "alpha": 1.50}"#);
+    assert_eq!(
+        parsed.unwrap().to_string(),
+        r#"{"zeta":12345678901234567891,"alpha":1.50}"#
+    );
+}
+
 #[test]
 fn test_parse_and_detect_type_destructured_objects() {
     let input = "{\"a\": 1}\n{\"b\": 2}";
@@ -4,13 +4,17 @@
 //! Receives requests via channel, executes jq with cancellation support,
 //! and sends responses back to the main thread.
 
-use std::panic::{self, AssertUnwindSafe};
 use std::sync::mpsc::{Receiver, Sender};
 use std::time::Instant;
 
+use tokio_util::sync::CancellationToken;
+
 use super::preprocess::preprocess_result;
-use super::types::{QueryError, QueryRequest, QueryResponse};
+use super::types::{PrefixInput, QueryError, QueryRequest, QueryResponse};
+use crate::crash;
 use crate::query::executor::JqExecutor;
+use crate::query::library::JqLibrary;
+use crate::query::variables::QueryVariable;
 
 /// Spawn the query worker thread
 ///
@@ -19,7 +23,8 @@ use crate::query::executor::JqExecutor;
 /// 2. Executes jq queries with cancellation support
 /// 3. Sends responses back via the response channel
 ///
-/// Includes panic handling to prevent TUI corruption.
+/// A panic ends the worker with an error response instead of taking down
+/// the TUI.
 ///
 /// # Arguments
 /// * `json_input` - JSON input for query execution
@@ -31,50 +36,17 @@ pub fn spawn_worker(
     response_tx: Sender<QueryResponse>,
 ) {
     std::thread::spawn(move || {
-        // Set panic hook to prevent TUI corruption
-        let response_tx_clone = response_tx.clone();
-        let prev_hook = panic::take_hook();
-        panic::set_hook(Box::new(move |panic_info| {
-            let panic_msg = if let Some(s) = panic_info.payload().downcast_ref::<&str>() {
-                s.to_string()
-            } else if let Some(s) = panic_info.payload().downcast_ref::<String>() {
-                s.clone()
-            } else {
-                "Unknown panic in query worker".to_string()
-            };
-
-            log::error!(
-                "Query worker panic: {} at {:?}",
-                panic_msg,
-                panic_info.location()
-            );
-
-            // Try to send error to main thread
-            // Use request_id = 0 to indicate worker-level error
-            let _ = response_tx_clone.send(QueryResponse::Error {
+        let error_tx = response_tx.clone();
+        if let Err(panic_msg) =
+            crash::run_worker(|| worker_loop(&json_input, request_rx, response_tx))
+        {
+            log::error!("Query worker thread panicked: {}", panic_msg);
+            // request_id = 0 marks a worker-level error
+            let _ = error_tx.send(QueryResponse::Error {
                 message: format!("Query worker crashed: {}", panic_msg),
-                query: String::new(), // No specific query for worker-level errors
+                query: String::new(),
                 request_id: 0,
             });
-        }));
-
-        // Wrap worker in catch_unwind
-        let result = panic::catch_unwind(AssertUnwindSafe(|| {
-            worker_loop(&json_input, request_rx, response_tx);
-        }));
-
-        // Restore panic hook
-        panic::set_hook(prev_hook);
-
-        if let Err(e) = result {
-            let panic_msg = if let Some(s) = e.downcast_ref::<&str>() {
-                s.to_string()
-            } else if let Some(s) = e.downcast_ref::<String>() {
-                s.clone()
-            } else {
-                "Unknown panic".to_string()
-            };
-            log::error!("Query worker thread panicked: {}", panic_msg);
         }
     });
 }
@@ -113,11 +85,34 @@ fn handle_request(
     let query = request.query.clone();
     let start = Instant::now();
 
-    match executor.execute_with_cancel(&request.query, &request.cancel_token) {
+    let result = match &request.prefix {
+        Some(prefix) => execute_incremental(
+            executor,
+            prefix,
+            &request.query,
+            &request.variables,
+            &request.library,
+            &request.cancel_token,
+        ),
+        None => execute_full(
+            executor,
+            &request.query,
+            &request.variables,
+            &request.library,
+            &request.cancel_token,
+        ),
+    };
+
+    match result {
         Ok(output) => {
             // Preprocess result (expensive operations done in worker thread)
             match preprocess_result(output, &query, &request.cancel_token) {
                 Ok(mut processed) => {
+                    // Merged here rather than on the UI thread, as extending a
+                    // spooled result writes to its spool file
+                    if let Some(base) = &request.append_to {
+                        processed.lines = base.extended(&processed.unformatted);
+                    }
                     processed.execution_time_ms = Some(start.elapsed().as_millis() as u64);
                     let _ = response_tx.send(QueryResponse::ProcessedSuccess {
                         processed,
@@ -143,6 +138,23 @@ fn handle_request(
                 request_id: request.request_id,
             });
         }
+        Err(QueryError::FailedWithOutput { message, output }) => {
+            let response = match preprocess_result(output, &query, &request.cancel_token) {
+                Ok(mut processed) => {
+                    processed.execution_time_ms = Some(start.elapsed().as_millis() as u64);
+                    QueryResponse::PartialError {
+                        processed,
+                        message,
+                        query,
+                        request_id: request.request_id,
+                    }
+                }
+                Err(_) => QueryResponse::Cancelled {
+                    request_id: request.request_id,
+                },
+            };
+            let _ = response_tx.send(response);
+        }
         Err(e) => {
             let _ = response_tx.send(QueryResponse::Error {
                 message: e.to_string(),
@@ -153,6 +165,55 @@ fn handle_request(
     }
 }
 
+/// Execute the query against the original input
+fn execute_full(
+    executor: &JqExecutor,
+    query: &str,
+    variables: &[QueryVariable],
+    library: &JqLibrary,
+    cancel_token: &CancellationToken,
+) -> Result<String, QueryError> {
+    JqExecutor::execute_on_input(
+        &executor.json_input_shared(),
+        query,
+        variables,
+        library,
+        cancel_token,
+    )
+}
+
+/// Execute the query's remainder against its cached prefix output
+///
+/// Falls back to the full query on failure so error messages (and positions)
+/// refer to the query the user actually typed.
+fn execute_incremental(
+    executor: &JqExecutor,
+    prefix: &PrefixInput,
+    query: &str,
+    variables: &[QueryVariable],
+    library: &JqLibrary,
+    cancel_token: &CancellationToken,
+) -> Result<String, QueryError> {
+    match JqExecutor::execute_on_input(
+        &prefix.input,
+        &prefix.filter,
+        variables,
+        library,
+        cancel_token,
+    ) {
+        Err(QueryError::Cancelled) => Err(QueryError::Cancelled),
+        Err(e) => {
+            log::debug!(
+                "Incremental filter '{}' failed ({}), re-running full query",
+                prefix.filter,
+                e
+            );
+            execute_full(executor, query, variables, library, cancel_token)
+        }
+        ok => ok,
+    }
+}
+
 #[cfg(test)]
 #[path = "thread_tests.rs"]
 mod thread_tests;
@@ -21,6 +21,10 @@ fn test_worker_spawns_successfully() {
             query: ".".to_string(),
             request_id: 1,
             cancel_token,
+            prefix: None,
+            append_to: None,
+            variables: Default::default(),
+            library: Default::default(),
         })
         .unwrap();
 
@@ -49,6 +53,10 @@ fn test_worker_handles_invalid_query() {
             query: ".invalid syntax [".to_string(),
             request_id: 1,
             cancel_token,
+            prefix: None,
+            append_to: None,
+            variables: Default::default(),
+            library: Default::default(),
         })
         .unwrap();
 
@@ -79,6 +87,10 @@ fn test_worker_handles_pre_cancelled_request() {
             query: ".".to_string(),
             request_id: 1,
             cancel_token,
+            prefix: None,
+            append_to: None,
+            variables: Default::default(),
+            library: Default::default(),
         })
         .unwrap();
 
@@ -107,6 +119,10 @@ fn test_worker_sends_error_response_for_jq_failure() {
             query: ".invalid syntax [".to_string(),
             request_id: 1,
             cancel_token,
+            prefix: None,
+            append_to: None,
+            variables: Default::default(),
+            library: Default::default(),
         })
         .unwrap();
 
@@ -142,6 +158,10 @@ fn test_worker_handles_multiple_rapid_queries() {
                 query: format!(".{}", if i % 2 == 0 { "a" } else { "b" }),
                 request_id: i,
                 cancel_token,
+                prefix: None,
+                append_to: None,
+                variables: Default::default(),
+                library: Default::default(),
             })
             .unwrap();
     }
@@ -150,7 +170,9 @@ fn test_worker_handles_multiple_rapid_queries() {
     let mut received_count = 0;
     for _ in 0..5 {
         match response_rx.recv_timeout(std::time::Duration::from_secs(3)) {
-            Ok(QueryResponse::ProcessedSuccess { .. }) | Ok(QueryResponse::Error { .. }) => {
+            Ok(QueryResponse::ProcessedSuccess { .. })
+            | Ok(QueryResponse::Error { .. })
+            | Ok(QueryResponse::PartialError { .. }) => {
                 received_count += 1;
             }
             Ok(QueryResponse::Cancelled { .. }) => {
@@ -164,6 +186,42 @@ fn test_worker_handles_multiple_rapid_queries() {
     assert_eq!(received_count, 5, "Should receive all 5 responses");
 }
 
+#[test]
+fn test_worker_sends_output_printed_before_runtime_error() {
+    let json_input = r#"[{"a": 1}, "x"]"#.to_string();
+    let (request_tx, request_rx) = channel();
+    let (response_tx, response_rx) = channel();
+
+    spawn_worker(json_input, request_rx, response_tx);
+
+    request_tx
+        .send(QueryRequest {
+            query: ".[] | .a".to_string(),
+            request_id: 1,
+            cancel_token: CancellationToken::new(),
+            prefix: None,
+            append_to: None,
+            variables: Default::default(),
+            library: Default::default(),
+        })
+        .unwrap();
+
+    match response_rx.recv_timeout(std::time::Duration::from_secs(2)) {
+        Ok(QueryResponse::PartialError {
+            processed,
+            message,
+            request_id,
+            ..
+        }) => {
+            assert_eq!(request_id, 1);
+            assert_eq!(processed.unformatted.as_str(), "1\n");
+            assert!(message.contains("Cannot index string"), "{}", message);
+        }
+        Ok(other) => panic!("Expected PartialError, got {:?}", other),
+        Err(e) => panic!("Timeout waiting for response: {}", e),
+    }
+}
+
 #[test]
 fn test_worker_response_includes_original_query() {
     let json_input = r#"{"test": "value"}"#.to_string();
@@ -179,6 +237,10 @@ fn test_worker_response_includes_original_query() {
             query: original_query.to_string(),
             request_id: 42,
             cancel_token,
+            prefix: None,
+            append_to: None,
+            variables: Default::default(),
+            library: Default::default(),
         })
         .unwrap();
 
@@ -214,6 +276,10 @@ fn test_worker_error_response_includes_original_query() {
             query: original_query.to_string(),
             request_id: 99,
             cancel_token,
+            prefix: None,
+            append_to: None,
+            variables: Default::default(),
+            library: Default::default(),
         })
         .unwrap();
 
@@ -232,3 +298,70 @@ fn test_worker_error_response_includes_original_query() {
         Err(e) => panic!("Timeout: {}", e),
     }
 }
+
+#[test]
+fn test_worker_evaluates_prefix_incrementally() {
+    // Original input deliberately differs from the prefix output so the test
+    // can tell which one the filter ran against
+    let json_input = r#"{"services": [{"name": "full"}]}"#.to_string();
+    let (request_tx, request_rx) = channel();
+    let (response_tx, response_rx) = channel();
+
+    spawn_worker(json_input, request_rx, response_tx);
+
+    request_tx
+        .send(QueryRequest {
+            query: ".services[].name".to_string(),
+            request_id: 1,
+            cancel_token: CancellationToken::new(),
+            prefix: Some(PrefixInput {
+                input: std::sync::Arc::new(r#"[{"name": "incremental"}]"#.to_string()),
+                filter: ".[].name".to_string(),
+            }),
+            append_to: None,
+            variables: Default::default(),
+            library: Default::default(),
+        })
+        .unwrap();
+
+    match response_rx.recv_timeout(std::time::Duration::from_secs(2)) {
+        Ok(QueryResponse::ProcessedSuccess { processed, .. }) => {
+            assert!(processed.unformatted.contains("incremental"));
+            assert_eq!(processed.query, ".services[].name");
+        }
+        Ok(other) => panic!("Expected ProcessedSuccess, got {:?}", other),
+        Err(e) => panic!("Timeout waiting for response: {}", e),
+    }
+}
+
+#[test]
+fn test_worker_falls_back_to_full_query_when_prefix_filter_fails() {
+    let json_input = r#"{"services": [{"name": "full"}]}"#.to_string();
+    let (request_tx, request_rx) = channel();
+    let (response_tx, response_rx) = channel();
+
+    spawn_worker(json_input, request_rx, response_tx);
+
+    request_tx
+        .send(QueryRequest {
+            query: ".services[].name".to_string(),
+            request_id: 1,
+            cancel_token: CancellationToken::new(),
+            prefix: Some(PrefixInput {
+                input: std::sync::Arc::new("not json".to_string()),
+                filter: ".[].name".to_string(),
+            }),
+            append_to: None,
+            variables: Default::default(),
+            library: Default::default(),
+        })
+        .unwrap();
+
+    match response_rx.recv_timeout(std::time::Duration::from_secs(2)) {
+        Ok(QueryResponse::ProcessedSuccess { processed, .. }) => {
+            assert!(processed.unformatted.contains("full"));
+        }
+        Ok(other) => panic!("Expected ProcessedSuccess, got {:?}", other),
+        Err(e) => panic!("Timeout waiting for response: {}", e),
+    }
+}
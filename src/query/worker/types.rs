@@ -7,7 +7,10 @@ use std::sync::Arc;
 
 use tokio_util::sync::CancellationToken;
 
+use crate::query::library::JqLibrary;
 use crate::query::query_state::ResultType;
+use crate::query::result_lines::ResultLines;
+use crate::query::variables::QueryVariable;
 
 /// Request to execute a jq query
 #[derive(Debug)]
@@ -18,27 +21,37 @@ pub struct QueryRequest {
     pub request_id: u64,
     /// Token for cancelling this request
     pub cancel_token: CancellationToken,
+    /// Cached prefix result to evaluate incrementally against, if any
+    pub prefix: Option<PrefixInput>,
+    /// Current result the output extends, when the query runs on records
+    /// appended to the input; the response's lines are then the merged
+    /// result
+    pub append_to: Option<ResultLines>,
+    /// Variables bound for this query (`--arg`/`--argjson`)
+    pub variables: Arc<Vec<QueryVariable>>,
+    /// Module search paths and user library for this query
+    pub library: Arc<JqLibrary>,
 }
 
-/// Pre-rendered span with style (Send-safe)
+/// Cached output of a prefix query for incremental evaluation
+///
+/// Running `filter` on `input` produces the same output as running the full
+/// query on the original JSON input.
 #[derive(Debug, Clone)]
-pub struct RenderedSpan {
-    pub content: String,
-    pub style: ratatui::style::Style,
-}
-
-/// Pre-rendered line
-#[derive(Debug, Clone)]
-pub struct RenderedLine {
-    pub spans: Vec<RenderedSpan>,
+pub struct PrefixInput {
+    /// Unformatted output of the prefix query
+    pub input: Arc<String>,
+    /// Remainder of the query, rewritten to apply to `input`
+    pub filter: String,
 }
 
 /// Fully processed query result with all caches computed
 #[derive(Debug)]
 pub struct ProcessedResult {
-    pub output: Arc<String>,
+    /// Output as printed by jq, without colors
     pub unformatted: Arc<String>,
-    pub rendered_lines: Vec<RenderedLine>,
+    /// Line index over `unformatted` for rendering
+    pub lines: ResultLines,
     pub parsed: Option<Arc<serde_json::Value>>,
     pub line_count: u32,
     pub max_width: u16,
@@ -69,6 +82,17 @@ pub enum QueryResponse {
         /// Note: request_id = 0 indicates a worker-level error (applies immediately)
         request_id: u64,
     },
+    /// Query failed after printing some results
+    PartialError {
+        /// Output printed before the error, processed like a result
+        processed: ProcessedResult,
+        /// Error message from jq stderr
+        message: String,
+        /// The query that produced this error
+        query: String,
+        /// Request ID this response belongs to
+        request_id: u64,
+    },
     /// Query execution was cancelled
     Cancelled {
         /// Request ID that was cancelled
@@ -87,6 +111,8 @@ pub enum QueryError {
     Cancelled,
     /// jq returned non-zero exit code
     ExecutionFailed(String),
+    /// jq failed after printing some results, as runtime errors do
+    FailedWithOutput { message: String, output: String },
 }
 
 impl std::fmt::Display for QueryError {
@@ -96,6 +122,7 @@ impl std::fmt::Display for QueryError {
             QueryError::OutputReadFailed(e) => write!(f, "Failed to read jq output: {}", e),
             QueryError::Cancelled => write!(f, "Query execution cancelled"),
             QueryError::ExecutionFailed(e) => write!(f, "{}", e),
+            QueryError::FailedWithOutput { message, .. } => write!(f, "{}", message),
         }
     }
 }
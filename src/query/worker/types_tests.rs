@@ -22,6 +22,10 @@ fn test_query_request_creation() {
         query: ".foo".to_string(),
         request_id: 42,
         cancel_token: cancel_token.clone(),
+        prefix: None,
+        append_to: None,
+        variables: Default::default(),
+        library: Default::default(),
     };
 
     assert_eq!(request.query, ".foo");
@@ -33,9 +37,8 @@ fn test_query_request_creation() {
 fn test_query_response_variants() {
     // Test ProcessedSuccess variant
     let processed = ProcessedResult {
-        output: std::sync::Arc::new("result".to_string()),
         unformatted: std::sync::Arc::new("result".to_string()),
-        rendered_lines: vec![],
+        lines: Default::default(),
         parsed: None,
         line_count: 1,
         max_width: 6,
@@ -54,7 +57,7 @@ fn test_query_response_variants() {
             processed,
             request_id,
         } => {
-            assert_eq!(*processed.output, "result");
+            assert_eq!(*processed.unformatted, "result");
             assert_eq!(processed.query, ".foo");
             assert_eq!(request_id, 1);
         }
@@ -0,0 +1,17 @@
+//! Several queries against the same input
+//!
+//! `Ctrl+T` opens a tab starting from the current query, to explore the
+//! document from another angle without losing the first one. Each tab keeps
+//! its query, result and results position; switching back shows the kept
+//! result without re-running jq. `:compare` diffs the results of two tabs.
+
+mod query_tabs_events;
+mod query_tabs_render;
+mod query_tabs_state;
+
+pub use query_tabs_events::{
+    close_tab, compare_tabs, cycle_tab, handle_compare_key, new_tab, switch_tab,
+};
+pub use query_tabs_render::{render_comparison, render_tab_bar};
+#[allow(unused_imports)]
+pub use query_tabs_state::{MAX_TABS, QueryTab, QueryTabsState, TabComparison};
@@ -0,0 +1,175 @@
+use ratatui::crossterm::event::{KeyCode, KeyEvent};
+
+use super::query_tabs_state::{MAX_TABS, QueryTab, TabComparison};
+use crate::app::App;
+use crate::in_place::diff_lines;
+use crate::scroll::handle_scroll_key;
+use crate::session::SessionScroll;
+
+/// State of the shown tab, to keep while another one is shown
+fn capture(app: &App) -> QueryTab {
+    let query_state = app.query.as_ref();
+    let succeeded = query_state.is_some_and(|q| q.result.is_ok());
+    QueryTab {
+        query: app.query().to_string(),
+        language: query_state.map(|q| q.language).unwrap_or_default(),
+        snapshot: query_state.filter(|_| succeeded).and_then(|q| q.snapshot()),
+        cache: query_state
+            .map(|q| q.result_cache().clone())
+            .unwrap_or_default(),
+        scroll: SessionScroll {
+            offset: app.results_scroll.offset,
+            h_offset: app.results_scroll.h_offset,
+            cursor_line: app.results_cursor_result_line() as u32,
+        },
+    }
+}
+
+/// Show `tab` in the input field and results pane
+fn show(app: &mut App, tab: QueryTab) {
+    let Some(query_state) = &mut app.query else {
+        return;
+    };
+    query_state.cancel_in_flight();
+    query_state.language = tab.language;
+    query_state.replace_result_cache(tab.cache);
+
+    match tab.snapshot {
+        Some(snapshot) => {
+            query_state.restore_snapshot(snapshot);
+            crate::editor::editor_events::replace_query(app, &tab.query);
+            app.debouncer.mark_executed();
+        }
+        None => crate::editor::editor_events::replace_query_and_execute(app, &tab.query),
+    }
+    app.session_scroll_pending = Some(tab.scroll);
+}
+
+fn notify_position(app: &mut App) {
+    app.notification.show(&format!(
+        "Tab {}/{}",
+        app.query_tabs.active() + 1,
+        app.query_tabs.count()
+    ));
+}
+
+/// Open a new tab starting from the current query (Ctrl+T)
+pub fn new_tab(app: &mut App) {
+    if app.query.is_none() {
+        app.notification.show_warning("The input is still loading");
+        return;
+    }
+    let current = capture(app);
+    if !app.query_tabs.add(current) {
+        app.notification
+            .show_warning(&format!("At most {} tabs can be open", MAX_TABS));
+        return;
+    }
+    notify_position(app);
+}
+
+/// Show the tab numbered `number`, counting from 1
+pub fn switch_tab(app: &mut App, number: usize) {
+    if number == 0 || number > app.query_tabs.count() {
+        app.notification.show_warning(&format!("No tab {}", number));
+        return;
+    }
+    if app.query.is_none() {
+        return;
+    }
+    let current = capture(app);
+    if let Some(tab) = app.query_tabs.switch(current, number - 1) {
+        show(app, tab);
+    }
+    notify_position(app);
+}
+
+/// Show the next tab, or the previous one, wrapping around
+pub fn cycle_tab(app: &mut App, forward: bool) {
+    let count = app.query_tabs.count();
+    if count < 2 {
+        return;
+    }
+    let active = app.query_tabs.active();
+    let index = if forward {
+        (active + 1) % count
+    } else {
+        (active + count - 1) % count
+    };
+    switch_tab(app, index + 1);
+}
+
+/// Close the shown tab and show its neighbour
+pub fn close_tab(app: &mut App) {
+    match app.query_tabs.close_active() {
+        Some(tab) => {
+            show(app, tab);
+            notify_position(app);
+        }
+        None => app
+            .notification
+            .show_warning("The last tab can't be closed"),
+    }
+}
+
+/// Result text of the tab numbered `number`, counting from 1
+fn tab_result(app: &App, number: usize) -> Result<String, String> {
+    let index = number
+        .checked_sub(1)
+        .filter(|index| *index < app.query_tabs.count())
+        .ok_or_else(|| format!("No tab {}", number))?;
+    let result = if index == app.query_tabs.active() {
+        app.query
+            .as_ref()
+            .filter(|q| q.result.is_ok())
+            .and_then(|q| q.last_successful_result_text())
+            .map(|text| text.as_ref().clone())
+    } else {
+        app.query_tabs
+            .tab(index)
+            .and_then(|tab| tab.snapshot.as_ref())
+            .map(|snapshot| snapshot.unformatted.as_ref().clone())
+    };
+    result.ok_or_else(|| format!("Tab {} has no result, show it to run its query", number))
+}
+
+/// Show the diff between the results of tabs `old` and `new` (`:compare`)
+pub fn compare_tabs(app: &mut App, old: usize, new: usize) {
+    if old == new {
+        app.notification.show_warning("Pick two different tabs");
+        return;
+    }
+    let results = tab_result(app, old).and_then(|old| Ok((old, tab_result(app, new)?)));
+    let (old_result, new_result) = match results {
+        Ok(results) => results,
+        Err(message) => {
+            app.notification.show_warning(&message);
+            return;
+        }
+    };
+
+    let diff = diff_lines(&old_result, &new_result);
+    if diff.is_empty() {
+        app.notification
+            .show(&format!("Tabs {} and {} have the same result", old, new));
+        return;
+    }
+    app.autocomplete.hide();
+    app.query_tabs.open_comparison(TabComparison {
+        tabs: (old, new),
+        diff,
+    });
+}
+
+pub fn handle_compare_key(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('q') => app.query_tabs.close_comparison(),
+        _ => {
+            handle_scroll_key(&mut app.query_tabs.compare_scroll, key);
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "query_tabs_events_tests.rs"]
+mod query_tabs_events_tests;
@@ -0,0 +1,222 @@
+//! Tests for query_tabs_events
+
+use super::*;
+use crate::test_utils::test_helpers::{key, key_with_mods, test_app};
+use ratatui::crossterm::event::KeyModifiers;
+
+const INPUT: &str = r#"{"users": [{"name": "ada"}, {"name": "bob"}], "count": 2}"#;
+
+fn set_query(app: &mut App, query: &str) {
+    app.input.textarea.delete_line_by_head();
+    app.input.textarea.delete_line_by_end();
+    app.input.textarea.insert_str(query);
+    app.query.as_mut().unwrap().execute(query);
+}
+
+fn result(app: &App) -> String {
+    app.query.as_ref().unwrap().result.clone().unwrap()
+}
+
+fn app_with_two_tabs() -> App {
+    let mut app = test_app(INPUT);
+    set_query(&mut app, ".users[].name");
+    new_tab(&mut app);
+    set_query(&mut app, ".count");
+    app
+}
+
+#[test]
+fn test_ctrl_t_opens_a_tab_with_the_current_query() {
+    let mut app = test_app(INPUT);
+    set_query(&mut app, ".users");
+
+    app.handle_key_event(key_with_mods(KeyCode::Char('t'), KeyModifiers::CONTROL));
+
+    assert_eq!(app.query_tabs.count(), 2);
+    assert_eq!(app.query_tabs.active(), 1);
+    assert_eq!(app.query(), ".users");
+}
+
+#[test]
+fn test_switching_back_restores_query_and_result() {
+    let mut app = app_with_two_tabs();
+
+    switch_tab(&mut app, 1);
+
+    assert_eq!(app.query(), ".users[].name");
+    assert_eq!(result(&app), "\"ada\"\n\"bob\"\n");
+    assert_eq!(app.query_tabs.active(), 0);
+}
+
+#[test]
+fn test_switching_restores_the_results_position() {
+    let mut app = app_with_two_tabs();
+    switch_tab(&mut app, 1);
+    app.results_cursor.update_total_lines(2);
+    app.results_cursor.move_to_line(1);
+
+    switch_tab(&mut app, 2);
+    switch_tab(&mut app, 1);
+
+    assert_eq!(
+        app.session_scroll_pending.map(|scroll| scroll.cursor_line),
+        Some(1)
+    );
+}
+
+#[test]
+fn test_switching_to_a_missing_tab_warns() {
+    let mut app = app_with_two_tabs();
+
+    switch_tab(&mut app, 3);
+
+    assert_eq!(app.query_tabs.active(), 1);
+    assert_eq!(app.notification.current_message(), Some("No tab 3"));
+}
+
+#[test]
+fn test_ctrl_page_down_cycles_through_tabs() {
+    let mut app = app_with_two_tabs();
+
+    app.handle_key_event(key_with_mods(KeyCode::PageDown, KeyModifiers::CONTROL));
+    assert_eq!(app.query_tabs.active(), 0);
+
+    app.handle_key_event(key_with_mods(KeyCode::PageUp, KeyModifiers::CONTROL));
+    assert_eq!(app.query_tabs.active(), 1);
+}
+
+#[test]
+fn test_ctrl_w_closes_the_tab_and_shows_the_other() {
+    let mut app = app_with_two_tabs();
+    app.focus = crate::app::Focus::ResultsPane;
+
+    app.handle_key_event(key_with_mods(KeyCode::Char('w'), KeyModifiers::CONTROL));
+
+    assert_eq!(app.query_tabs.count(), 1);
+    assert_eq!(app.query(), ".users[].name");
+}
+
+#[test]
+fn test_ctrl_w_deletes_a_word_while_typing() {
+    let mut app = app_with_two_tabs();
+    app.input.editor_mode = crate::editor::EditorMode::Insert;
+
+    app.handle_key_event(key_with_mods(KeyCode::Char('w'), KeyModifiers::CONTROL));
+
+    assert_eq!(app.query_tabs.count(), 2);
+    assert_eq!(app.query(), ".");
+}
+
+#[test]
+fn test_last_tab_is_not_closed() {
+    let mut app = test_app(INPUT);
+
+    close_tab(&mut app);
+
+    assert_eq!(app.query_tabs.count(), 1);
+    assert!(
+        app.notification
+            .current_message()
+            .is_some_and(|message| message.contains("last tab"))
+    );
+}
+
+#[test]
+fn test_input_changes_drop_the_kept_results() {
+    let mut app = app_with_two_tabs();
+
+    assert!(app.query_tabs.tab(0).unwrap().snapshot.is_some());
+
+    app.query_tabs.clear_snapshots();
+
+    // The query is run again instead of showing the kept result
+    assert!(app.query_tabs.tab(0).unwrap().snapshot.is_none());
+    switch_tab(&mut app, 1);
+    assert_eq!(app.query(), ".users[].name");
+}
+
+#[test]
+fn test_each_tab_keeps_its_own_result_cache() {
+    let mut app = app_with_two_tabs();
+    let cache = |app: &App| app.query.as_ref().unwrap().result_cache().clone();
+
+    assert!(cache(&app).contains(".count"));
+
+    switch_tab(&mut app, 1);
+    assert!(cache(&app).contains(".users[].name"));
+    assert!(!cache(&app).contains(".count"));
+
+    switch_tab(&mut app, 2);
+    assert!(cache(&app).contains(".count"));
+}
+
+#[test]
+fn test_input_changes_drop_the_cached_results_of_tabs() {
+    let mut app = app_with_two_tabs();
+
+    app.query_tabs.clear_snapshots();
+
+    assert!(app.query_tabs.tab(0).unwrap().cache.is_empty());
+}
+
+#[test]
+fn test_compare_diffs_the_results_of_two_tabs() {
+    let mut app = app_with_two_tabs();
+
+    compare_tabs(&mut app, 1, 2);
+
+    let comparison = app.query_tabs.comparison().unwrap();
+    assert_eq!(comparison.tabs, (1, 2));
+    assert_eq!(comparison.diff.removed, 2);
+    assert_eq!(comparison.diff.added, 1);
+}
+
+#[test]
+fn test_compare_same_results_only_notifies() {
+    let mut app = test_app(INPUT);
+    set_query(&mut app, ".count");
+    new_tab(&mut app);
+
+    compare_tabs(&mut app, 1, 2);
+
+    assert!(!app.query_tabs.is_comparing());
+    assert_eq!(
+        app.notification.current_message(),
+        Some("Tabs 1 and 2 have the same result")
+    );
+}
+
+#[test]
+fn test_compare_with_a_failed_tab_warns() {
+    let mut app = app_with_two_tabs();
+    set_query(&mut app, ".count | error");
+
+    compare_tabs(&mut app, 1, 2);
+
+    assert!(!app.query_tabs.is_comparing());
+    assert!(
+        app.notification
+            .current_message()
+            .is_some_and(|message| message.contains("Tab 2 has no result"))
+    );
+}
+
+#[test]
+fn test_compare_command_with_one_tab_uses_the_shown_one() {
+    let mut app = app_with_two_tabs();
+    app.command_line.open_with("compare 1");
+
+    app.handle_key_event(key(KeyCode::Enter));
+
+    assert_eq!(app.query_tabs.comparison().unwrap().tabs, (2, 1));
+}
+
+#[test]
+fn test_esc_closes_the_comparison() {
+    let mut app = app_with_two_tabs();
+    compare_tabs(&mut app, 1, 2);
+
+    app.handle_key_event(key(KeyCode::Esc));
+
+    assert!(!app.query_tabs.is_comparing());
+}
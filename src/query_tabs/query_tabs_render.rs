@@ -0,0 +1,118 @@
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span, Text},
+    widgets::{Block, BorderType, Borders, Paragraph},
+};
+
+use crate::app::App;
+use crate::in_place::diff_text;
+use crate::theme;
+use crate::widgets::text_width::truncate_to_width;
+use crate::widgets::{popup, scrollbar};
+
+/// Widest a tab's query is shown in the tab bar
+const MAX_LABEL_WIDTH: usize = 24;
+
+fn build_hints() -> Line<'static> {
+    theme::border_hints::build_hints(
+        &[("j/k", "Scroll"), ("Esc", "Close")],
+        theme::query_tabs::BORDER,
+    )
+}
+
+/// Render the numbered queries of the tabs in `area`, the shown one
+/// highlighted
+pub fn render_tab_bar(app: &App, frame: &mut Frame, area: Rect) {
+    let active = app.query_tabs.active();
+    let mut spans = Vec::new();
+    for (index, query) in app.query_tabs.queries(app.query()).into_iter().enumerate() {
+        if index > 0 {
+            spans.push(Span::styled(
+                "│",
+                Style::default().fg(theme::query_tabs::SEPARATOR),
+            ));
+        }
+        let query = query.trim();
+        let label = if query.is_empty() { "." } else { query };
+        let text = format!(
+            " {} {} ",
+            index + 1,
+            truncate_to_width(label, MAX_LABEL_WIDTH)
+        );
+        let style = if index == active {
+            Style::default()
+                .fg(theme::query_tabs::ACTIVE)
+                .bg(theme::query_tabs::ACTIVE_BG)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(theme::query_tabs::INACTIVE)
+        };
+        spans.push(Span::styled(text, style));
+    }
+    frame.render_widget(Paragraph::new(Line::from(spans)), area);
+}
+
+/// Render the diff between the results of two tabs (`:compare`)
+pub fn render_comparison(app: &mut App, frame: &mut Frame) -> Option<Rect> {
+    let frame_area = frame.area();
+    if frame_area.width < 30 || frame_area.height < 8 {
+        return None;
+    }
+    let comparison = app.query_tabs.comparison()?;
+
+    let popup_width = ((frame_area.width as f32 * 0.8) as u16)
+        .clamp(50, 120)
+        .min(frame_area.width.saturating_sub(4));
+    let popup_height = ((frame_area.height as f32 * 0.8) as u16)
+        .clamp(8, 40)
+        .min(frame_area.height.saturating_sub(2));
+    let popup_area = popup::centered_popup(frame_area, popup_width, popup_height);
+    popup::clear_area(frame, popup_area);
+
+    let (old, new) = comparison.tabs;
+    let title = format!(
+        " Tab {} → tab {}: −{} +{} lines ",
+        old, new, comparison.diff.removed, comparison.diff.added
+    );
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(Line::from(Span::styled(title, theme::query_tabs::TITLE)))
+        .title_bottom(build_hints().centered())
+        .border_style(Style::default().fg(theme::query_tabs::BORDER))
+        .style(Style::default().bg(theme::query_tabs::BACKGROUND));
+    let content_area = block.inner(popup_area);
+    frame.render_widget(block, popup_area);
+
+    let lines = diff_text(&comparison.diff, content_area.width as usize);
+    let content_height = lines.len() as u32;
+    let scroll = &mut app.query_tabs.compare_scroll;
+    scroll.update_bounds(content_height, content_area.height);
+    frame.render_widget(
+        Paragraph::new(Text::from(lines)).scroll((scroll.offset, 0)),
+        content_area,
+    );
+
+    let scrollbar_area = Rect {
+        x: popup_area.x,
+        y: popup_area.y.saturating_add(1),
+        width: popup_area.width,
+        height: popup_area.height.saturating_sub(2),
+    };
+    scrollbar::render_vertical_scrollbar_styled(
+        frame,
+        scrollbar_area,
+        content_height as usize,
+        scroll.viewport_height as usize,
+        scroll.offset as usize,
+        theme::query_tabs::BORDER,
+    );
+
+    Some(popup_area)
+}
+
+#[cfg(test)]
+#[path = "query_tabs_render_tests.rs"]
+mod query_tabs_render_tests;
@@ -0,0 +1,61 @@
+//! Tests for query_tabs_render
+
+use insta::assert_snapshot;
+use ratatui::Terminal;
+use ratatui::backend::TestBackend;
+
+use super::*;
+use crate::in_place::diff_lines;
+use crate::query_tabs::TabComparison;
+use crate::test_utils::test_helpers::test_app;
+
+fn render_app(app: &mut App, width: u16, height: u16) -> String {
+    let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+    terminal.draw(|f| app.render(f)).unwrap();
+    terminal.backend().to_string()
+}
+
+fn app_with_tabs() -> App {
+    let mut app = test_app(r#"{"users": [{"name": "ada"}], "count": 1}"#);
+    app.input.textarea.insert_str(".users");
+    crate::query_tabs::new_tab(&mut app);
+    app.input.textarea.delete_line_by_head();
+    app.input.textarea.insert_str(".count");
+    crate::editor::editor_events::execute_query(&mut app);
+    app
+}
+
+#[test]
+fn snapshot_tab_bar_above_input() {
+    let mut app = app_with_tabs();
+
+    assert_snapshot!(render_app(&mut app, 60, 12));
+}
+
+#[test]
+fn test_no_tab_bar_with_a_single_tab() {
+    let mut app = test_app(r#"{"count": 1}"#);
+
+    let output = render_app(&mut app, 60, 12);
+
+    assert!(!output.contains(" 1 . "));
+}
+
+#[test]
+fn snapshot_comparison() {
+    let mut app = app_with_tabs();
+    let old = "{\n  \"name\": \"ada\",\n  \"age\": 36\n}";
+    let new = "{\n  \"name\": \"ada\",\n  \"age\": 37\n}";
+    app.query_tabs.open_comparison(TabComparison {
+        tabs: (1, 2),
+        diff: diff_lines(old, new),
+    });
+
+    let mut terminal = Terminal::new(TestBackend::new(70, 12)).unwrap();
+    terminal
+        .draw(|f| {
+            render_comparison(&mut app, f);
+        })
+        .unwrap();
+    assert_snapshot!(terminal.backend().to_string());
+}
@@ -0,0 +1,155 @@
+use crate::in_place::LineDiff;
+use crate::query::language::QueryLanguage;
+use crate::query::result_cache::{CachedResult, ResultCache};
+use crate::scroll::ScrollState;
+use crate::session::SessionScroll;
+
+/// Most tabs open at once, so each can be picked with `:tab N`
+pub const MAX_TABS: usize = 9;
+
+/// Query of a tab that is not shown, with its result and results position
+#[derive(Debug, Clone, Default)]
+pub struct QueryTab {
+    pub query: String,
+    pub language: QueryLanguage,
+    /// Result when the tab was left, `None` if the query failed or the input
+    /// changed since (the query is re-run instead)
+    pub snapshot: Option<CachedResult>,
+    /// Results of the queries run in the tab, so going back to one of them
+    /// doesn't run jq again
+    pub cache: ResultCache,
+    pub scroll: SessionScroll,
+}
+
+/// Diff between the results of two tabs (`:compare`)
+#[derive(Debug)]
+pub struct TabComparison {
+    /// Tab numbers, from 1, of the old and the new side
+    pub tabs: (usize, usize),
+    pub diff: LineDiff,
+}
+
+/// Independent queries against the same input, one shown at a time (Ctrl+T)
+#[derive(Debug)]
+pub struct QueryTabsState {
+    /// Every tab; the entry of the active tab is stale while it is shown, as
+    /// its query lives in the input field
+    tabs: Vec<QueryTab>,
+    active: usize,
+    comparison: Option<TabComparison>,
+    pub compare_scroll: ScrollState,
+}
+
+impl Default for QueryTabsState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl QueryTabsState {
+    pub fn new() -> Self {
+        Self {
+            tabs: vec![QueryTab::default()],
+            active: 0,
+            comparison: None,
+            compare_scroll: ScrollState::new(),
+        }
+    }
+
+    pub fn count(&self) -> usize {
+        self.tabs.len()
+    }
+
+    /// Index of the shown tab, from 0
+    pub fn active(&self) -> usize {
+        self.active
+    }
+
+    /// Whether there is more than the one tab, and so a tab bar
+    pub fn has_tabs(&self) -> bool {
+        self.tabs.len() > 1
+    }
+
+    /// Saved state of the tab at `index`; stale for the active tab
+    pub fn tab(&self, index: usize) -> Option<&QueryTab> {
+        self.tabs.get(index)
+    }
+
+    /// Queries of the tabs, the active one read from `current_query`
+    pub fn queries<'a>(&'a self, current_query: &'a str) -> Vec<&'a str> {
+        self.tabs
+            .iter()
+            .enumerate()
+            .map(|(index, tab)| {
+                if index == self.active {
+                    current_query
+                } else {
+                    tab.query.as_str()
+                }
+            })
+            .collect()
+    }
+
+    /// Save `current` as the active tab and open a copy of it after the
+    /// last tab; false when `MAX_TABS` are open
+    pub fn add(&mut self, current: QueryTab) -> bool {
+        if self.tabs.len() >= MAX_TABS {
+            return false;
+        }
+        self.tabs[self.active] = current.clone();
+        self.tabs.push(current);
+        self.active = self.tabs.len() - 1;
+        true
+    }
+
+    /// Save `current` as the active tab and make `index` active, returning
+    /// the tab to show; `None` for the active or a missing tab
+    pub fn switch(&mut self, current: QueryTab, index: usize) -> Option<QueryTab> {
+        if index == self.active || index >= self.tabs.len() {
+            return None;
+        }
+        self.tabs[self.active] = current;
+        self.active = index;
+        Some(self.tabs[index].clone())
+    }
+
+    /// Close the active tab, returning the neighbouring tab to show instead;
+    /// `None` for the last remaining tab
+    pub fn close_active(&mut self) -> Option<QueryTab> {
+        if self.tabs.len() < 2 {
+            return None;
+        }
+        self.tabs.remove(self.active);
+        self.active = self.active.min(self.tabs.len() - 1);
+        Some(self.tabs[self.active].clone())
+    }
+
+    /// Drop the results of the tabs, which no longer match the input
+    pub fn clear_snapshots(&mut self) {
+        for tab in &mut self.tabs {
+            tab.snapshot = None;
+            tab.cache.clear();
+        }
+    }
+
+    pub fn comparison(&self) -> Option<&TabComparison> {
+        self.comparison.as_ref()
+    }
+
+    pub fn is_comparing(&self) -> bool {
+        self.comparison.is_some()
+    }
+
+    pub fn open_comparison(&mut self, comparison: TabComparison) {
+        self.comparison = Some(comparison);
+        self.compare_scroll = ScrollState::new();
+    }
+
+    pub fn close_comparison(&mut self) {
+        self.comparison = None;
+    }
+}
+
+#[cfg(test)]
+#[path = "query_tabs_state_tests.rs"]
+mod query_tabs_state_tests;
@@ -0,0 +1,105 @@
+//! Tests for query_tabs_state
+
+use super::*;
+
+fn tab(query: &str) -> QueryTab {
+    QueryTab {
+        query: query.to_string(),
+        ..QueryTab::default()
+    }
+}
+
+#[test]
+fn test_starts_with_one_tab_and_no_bar() {
+    let tabs = QueryTabsState::new();
+
+    assert_eq!(tabs.count(), 1);
+    assert_eq!(tabs.active(), 0);
+    assert!(!tabs.has_tabs());
+}
+
+#[test]
+fn test_add_saves_the_current_tab_and_activates_a_copy() {
+    let mut tabs = QueryTabsState::new();
+
+    assert!(tabs.add(tab(".items")));
+
+    assert_eq!(tabs.count(), 2);
+    assert_eq!(tabs.active(), 1);
+    assert_eq!(tabs.tab(0).unwrap().query, ".items");
+    assert_eq!(tabs.tab(1).unwrap().query, ".items");
+}
+
+#[test]
+fn test_add_stops_at_max_tabs() {
+    let mut tabs = QueryTabsState::new();
+    for _ in 1..MAX_TABS {
+        assert!(tabs.add(tab(".")));
+    }
+
+    assert!(!tabs.add(tab(".")));
+    assert_eq!(tabs.count(), MAX_TABS);
+}
+
+#[test]
+fn test_switch_saves_the_current_tab_and_returns_the_target() {
+    let mut tabs = QueryTabsState::new();
+    tabs.add(tab(".a"));
+
+    let shown = tabs.switch(tab(".b"), 0).unwrap();
+
+    assert_eq!(shown.query, ".a");
+    assert_eq!(tabs.active(), 0);
+    assert_eq!(tabs.tab(1).unwrap().query, ".b");
+}
+
+#[test]
+fn test_switch_to_the_active_or_a_missing_tab_does_nothing() {
+    let mut tabs = QueryTabsState::new();
+    tabs.add(tab(".a"));
+
+    assert!(tabs.switch(tab(".b"), 1).is_none());
+    assert!(tabs.switch(tab(".b"), 5).is_none());
+    assert_eq!(tabs.active(), 1);
+}
+
+#[test]
+fn test_queries_read_the_active_tab_from_the_input() {
+    let mut tabs = QueryTabsState::new();
+    tabs.add(tab(".a"));
+
+    assert_eq!(tabs.queries(".a.b"), vec![".a", ".a.b"]);
+}
+
+#[test]
+fn test_close_active_shows_the_neighbour() {
+    let mut tabs = QueryTabsState::new();
+    tabs.add(tab(".a"));
+    tabs.add(tab(".b"));
+    tabs.switch(tab(".c"), 1);
+
+    let shown = tabs.close_active().unwrap();
+
+    assert_eq!(tabs.count(), 2);
+    assert_eq!(tabs.active(), 1);
+    assert_eq!(shown.query, ".c");
+}
+
+#[test]
+fn test_close_last_tab_moves_to_the_one_before() {
+    let mut tabs = QueryTabsState::new();
+    tabs.add(tab(".a"));
+
+    let shown = tabs.close_active().unwrap();
+
+    assert_eq!(tabs.active(), 0);
+    assert_eq!(shown.query, ".a");
+}
+
+#[test]
+fn test_last_remaining_tab_is_not_closed() {
+    let mut tabs = QueryTabsState::new();
+
+    assert!(tabs.close_active().is_none());
+    assert_eq!(tabs.count(), 1);
+}
@@ -0,0 +1,114 @@
+//! Input Recording
+//!
+//! `--record FILE` saves every terminal event with the time it arrived, and
+//! `--replay FILE` feeds them back to the event loop in place of the
+//! terminal. Replaying against the same input reproduces a bug report step
+//! by step, or drives a demo for recording a GIF.
+
+use std::collections::VecDeque;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use ratatui::crossterm::event::Event;
+use serde::{Deserialize, Serialize};
+
+/// Terminal event and when it arrived
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordedEvent {
+    /// Milliseconds since the recording started
+    pub at_ms: u64,
+    pub event: Event,
+}
+
+/// Contents of a recording file
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Recording {
+    /// Terminal size when recording started, as mouse events depend on it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub size: Option<(u16, u16)>,
+    #[serde(default)]
+    pub events: Vec<RecordedEvent>,
+}
+
+impl Recording {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let content = fs::read_to_string(path)
+            .map_err(|e| format!("Cannot read recording {}: {}", path.display(), e))?;
+        serde_json::from_str(&content)
+            .map_err(|e| format!("Invalid recording {}: {}", path.display(), e))
+    }
+}
+
+/// Collects events for `--record`, written out when jiq exits
+pub struct EventRecorder {
+    path: PathBuf,
+    started: Instant,
+    recording: Recording,
+}
+
+impl EventRecorder {
+    pub fn new(path: PathBuf, size: Option<(u16, u16)>) -> Self {
+        Self {
+            path,
+            started: Instant::now(),
+            recording: Recording {
+                size,
+                events: Vec::new(),
+            },
+        }
+    }
+
+    pub fn record(&mut self, event: &Event) {
+        self.recording.events.push(RecordedEvent {
+            at_ms: self.started.elapsed().as_millis() as u64,
+            event: event.clone(),
+        });
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        let json = serde_json::to_string_pretty(&self.recording).map_err(io::Error::other)?;
+        fs::write(&self.path, json)
+    }
+}
+
+/// Plays back a recording for `--replay` with its original timing
+pub struct EventReplayer {
+    started: Instant,
+    events: VecDeque<RecordedEvent>,
+}
+
+impl EventReplayer {
+    pub fn new(recording: Recording) -> Self {
+        Self {
+            started: Instant::now(),
+            events: recording.events.into(),
+        }
+    }
+
+    /// Next event if it is due, i.e. as much time has passed since the
+    /// replay started as had when it was recorded
+    pub fn next_due(&mut self) -> Option<Event> {
+        let elapsed = self.started.elapsed().as_millis() as u64;
+        if self.events.front()?.at_ms > elapsed {
+            return None;
+        }
+        self.events.pop_front().map(|recorded| recorded.event)
+    }
+
+    /// Time until the next event is due, zero if it already is
+    pub fn time_to_next(&self) -> Option<Duration> {
+        let next = self.events.front()?;
+        let due = self.started + Duration::from_millis(next.at_ms);
+        Some(due.saturating_duration_since(Instant::now()))
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.events.is_empty()
+    }
+}
+
+#[cfg(test)]
+#[path = "recording_tests.rs"]
+mod recording_tests;
@@ -0,0 +1,116 @@
+//! Tests for recording
+
+use super::*;
+use ratatui::crossterm::event::{
+    KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind,
+};
+use tempfile::TempDir;
+
+fn key_event(c: char) -> Event {
+    Event::Key(KeyEvent::new(KeyCode::Char(c), KeyModifiers::empty()))
+}
+
+fn recording(times: &[u64]) -> Recording {
+    Recording {
+        size: Some((120, 40)),
+        events: times
+            .iter()
+            .map(|&at_ms| RecordedEvent {
+                at_ms,
+                event: key_event('a'),
+            })
+            .collect(),
+    }
+}
+
+#[test]
+fn test_recording_round_trips_through_file() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("session.json");
+    let mut recorder = EventRecorder::new(path.clone(), Some((120, 40)));
+    let events = vec![
+        key_event('.'),
+        Event::Key(KeyEvent::new(KeyCode::Enter, KeyModifiers::SHIFT)),
+        Event::Paste(".items[]".to_string()),
+        Event::Mouse(MouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Left),
+            column: 3,
+            row: 7,
+            modifiers: KeyModifiers::empty(),
+        }),
+        Event::Resize(100, 30),
+    ];
+    for event in &events {
+        recorder.record(event);
+    }
+
+    recorder.save().unwrap();
+    let loaded = Recording::load(&path).unwrap();
+
+    assert_eq!(loaded, recorder.recording);
+    assert_eq!(loaded.size, Some((120, 40)));
+    let loaded_events: Vec<Event> = loaded.events.into_iter().map(|e| e.event).collect();
+    assert_eq!(loaded_events, events);
+}
+
+#[test]
+fn test_recorded_times_increase() {
+    let mut recorder = EventRecorder::new(PathBuf::from("unused.json"), None);
+    recorder.record(&key_event('a'));
+    std::thread::sleep(Duration::from_millis(20));
+    recorder.record(&key_event('b'));
+
+    let events = &recorder.recording.events;
+    assert!(events[1].at_ms >= events[0].at_ms + 20);
+}
+
+#[test]
+fn test_load_reports_invalid_files() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("broken.json");
+    fs::write(&path, "not json").unwrap();
+
+    assert!(
+        Recording::load(&path)
+            .unwrap_err()
+            .starts_with("Invalid recording")
+    );
+    assert!(
+        Recording::load(&dir.path().join("missing.json"))
+            .unwrap_err()
+            .starts_with("Cannot read recording")
+    );
+}
+
+#[test]
+fn test_replayer_waits_for_recorded_time() {
+    let mut replayer = EventReplayer::new(recording(&[0, 60_000]));
+
+    assert_eq!(replayer.next_due(), Some(key_event('a')));
+    assert_eq!(replayer.next_due(), None);
+    assert!(!replayer.is_finished());
+    assert!(replayer.time_to_next().unwrap() > Duration::from_secs(50));
+}
+
+#[test]
+fn test_replayer_plays_events_in_order() {
+    let mut replayer = EventReplayer::new(Recording {
+        size: None,
+        events: vec![
+            RecordedEvent {
+                at_ms: 0,
+                event: key_event('x'),
+            },
+            RecordedEvent {
+                at_ms: 0,
+                event: key_event('y'),
+            },
+        ],
+    });
+
+    assert_eq!(replayer.time_to_next(), Some(Duration::ZERO));
+    assert_eq!(replayer.next_due(), Some(key_event('x')));
+    assert_eq!(replayer.next_due(), Some(key_event('y')));
+    assert!(replayer.is_finished());
+    assert_eq!(replayer.time_to_next(), None);
+}
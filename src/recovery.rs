@@ -0,0 +1,15 @@
+//! Recovery of the query after an unclean exit
+//!
+//! While jiq runs, the query, variable bindings and results position are
+//! written every few seconds to a recovery file kept per input. A clean exit
+//! removes it; finding one on the next launch with the same input means the
+//! previous run crashed or was killed, and jiq offers to restore it.
+
+mod recovery_events;
+mod recovery_render;
+mod recovery_state;
+
+pub use recovery_events::{autosave, handle_recovery_key, load_recovery};
+pub use recovery_render::render_recovery_prompt;
+#[allow(unused_imports)]
+pub use recovery_state::{RecoveryState, SAVE_INTERVAL};
@@ -0,0 +1,75 @@
+use std::path::PathBuf;
+use std::time::Instant;
+
+use ratatui::crossterm::event::{KeyCode, KeyEvent};
+
+use crate::app::App;
+use crate::bookmarks::bookmark_storage::document_key;
+use crate::session::Session;
+
+const RECOVERY_DIR: &str = "recovery";
+
+/// Recovery file of the input identified by `key`
+pub fn recovery_path(key: &str) -> Option<PathBuf> {
+    crate::paths::state_dir().map(|p| p.join(RECOVERY_DIR).join(format!("{}.toml", key)))
+}
+
+/// Start keeping a recovery file for the loaded input, offering to restore
+/// the one a previous run on the same input left behind
+pub fn load_recovery(app: &mut App, json_input: &str) {
+    if !app.recovery.is_enabled() {
+        return;
+    }
+    let key = document_key(
+        app.input_path.as_deref(),
+        app.input_command.as_ref(),
+        json_input,
+    );
+    if let Some(path) = recovery_path(&key) {
+        let query = app.query().to_string();
+        let current_query = app.pending_session.is_none().then_some(query.as_str());
+        app.recovery.attach(path, current_query);
+    }
+}
+
+/// Bring the recovery file up to date every `SAVE_INTERVAL`
+pub fn autosave(app: &mut App) {
+    let now = Instant::now();
+    if app.query.is_none() || !app.recovery.is_due(now) {
+        return;
+    }
+    let session = Session::capture(app);
+    app.recovery.save(session, now);
+}
+
+pub fn handle_recovery_key(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Enter | KeyCode::Char('y') => restore(app),
+        KeyCode::Esc | KeyCode::Char('n') => {
+            app.recovery.decline();
+            app.notification.show("Recovered query discarded");
+        }
+        _ => {}
+    }
+}
+
+/// Bring back the query, variables and position of the offered session
+fn restore(app: &mut App) {
+    let Some(session) = app.recovery.accept() else {
+        return;
+    };
+    if !session.variables.is_empty() {
+        app.variables.set_variables(session.variables.clone());
+        if let Some(query_state) = &mut app.query {
+            query_state.set_variables(session.variables.clone());
+            app.timeline.clear_snapshots();
+            app.query_tabs.clear_snapshots();
+        }
+    }
+    app.restore_session(session);
+    app.notification.show("Query restored");
+}
+
+#[cfg(test)]
+#[path = "recovery_events_tests.rs"]
+mod recovery_events_tests;
@@ -0,0 +1,92 @@
+//! Tests for recovery/recovery_events
+
+use super::*;
+use crate::query::variables::{QueryVariable, VariableKind};
+use crate::recovery::RecoveryState;
+use crate::session::{self, SessionScroll};
+use crate::test_utils::test_helpers::{app_with_query, key};
+use tempfile::TempDir;
+
+/// App offering to restore `session` left in a recovery file in `dir`
+fn offering_app(dir: &TempDir, session: &Session) -> App {
+    let path = dir.path().join("input.toml");
+    session::save_session_to_path(&path, session).unwrap();
+    let mut app = app_with_query("");
+    app.recovery = RecoveryState::new(true);
+    app.recovery.attach(path, Some(""));
+    app
+}
+
+fn left_session() -> Session {
+    Session {
+        query: ".name | select(. == $who)".to_string(),
+        scroll: SessionScroll {
+            offset: 3,
+            h_offset: 0,
+            cursor_line: 4,
+        },
+        variables: vec![QueryVariable::new("who", "Alice", VariableKind::String)],
+        ..Session::default()
+    }
+}
+
+#[test]
+fn test_enter_restores_query_and_variables() {
+    let dir = TempDir::new().unwrap();
+    let mut app = offering_app(&dir, &left_session());
+
+    app.handle_key_event(key(KeyCode::Enter));
+
+    assert!(!app.recovery.is_visible());
+    assert_eq!(app.query(), ".name | select(. == $who)");
+    assert_eq!(app.variables.variables().len(), 1);
+    assert_eq!(app.session_scroll_pending, Some(left_session().scroll));
+    assert_eq!(app.notification.current_message(), Some("Query restored"));
+}
+
+#[test]
+fn test_esc_discards_recovered_query() {
+    let dir = TempDir::new().unwrap();
+    let mut app = offering_app(&dir, &left_session());
+
+    app.handle_key_event(key(KeyCode::Esc));
+
+    assert!(!app.recovery.is_visible());
+    assert_eq!(app.query(), "");
+    assert!(!dir.path().join("input.toml").exists());
+}
+
+#[test]
+fn test_other_keys_keep_prompt_open() {
+    let dir = TempDir::new().unwrap();
+    let mut app = offering_app(&dir, &left_session());
+
+    app.handle_key_event(key(KeyCode::Char('x')));
+
+    assert!(app.recovery.is_visible());
+    assert_eq!(app.query(), "");
+}
+
+#[test]
+fn test_autosave_writes_current_query() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("input.toml");
+    let mut app = app_with_query(".users[0]");
+    app.recovery = RecoveryState::new(true);
+    app.recovery.attach(path.clone(), Some(".users[0]"));
+
+    autosave(&mut app);
+
+    assert_eq!(
+        session::load_session_from_path(&path).unwrap().query,
+        ".users[0]"
+    );
+}
+
+#[test]
+fn test_recovery_path_is_per_input() {
+    let Some(path) = recovery_path("abc123") else {
+        return;
+    };
+    assert!(path.ends_with("jiq/recovery/abc123.toml"));
+}
@@ -0,0 +1,66 @@
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::Style,
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Padding, Paragraph},
+};
+
+use crate::app::App;
+use crate::theme;
+use crate::widgets::popup;
+use crate::widgets::text_width::truncate_to_width;
+
+const DIALOG_WIDTH: u16 = 56;
+
+const OPTIONS: &[(&str, &str)] = &[("Enter", "Restore the query"), ("Esc", "Discard it")];
+
+/// Render the offer to restore the query left by an unclean exit
+pub fn render_recovery_prompt(app: &App, frame: &mut Frame) -> Option<Rect> {
+    let session = app.recovery.offer()?;
+    let frame_area = frame.area();
+    let width = DIALOG_WIDTH.min(frame_area.width.saturating_sub(2));
+    let text_width = width.saturating_sub(4) as usize;
+
+    let key_style = Style::default().fg(theme::recovery::KEY);
+    let text_style = Style::default().fg(theme::recovery::TEXT);
+    let mut lines = vec![
+        Line::from(Span::styled(
+            "jiq did not exit cleanly last time on this input.",
+            theme::recovery::QUESTION,
+        )),
+        Line::from(Span::styled(
+            truncate_to_width(&session.query, text_width),
+            Style::default().fg(theme::recovery::QUERY),
+        )),
+        Line::default(),
+    ];
+    lines.extend(OPTIONS.iter().map(|(key, action)| {
+        Line::from(vec![
+            Span::styled(format!("{:<7}", key), key_style),
+            Span::styled(*action, text_style),
+        ])
+    }));
+
+    let height = (lines.len() as u16 + 2).min(frame_area.height);
+    if width < 20 || height < 5 {
+        return None;
+    }
+
+    let area = popup::centered_popup(frame_area, width, height);
+    popup::clear_area(frame, area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(" Restore query? ")
+        .border_style(Style::default().fg(theme::recovery::BORDER))
+        .style(Style::default().bg(theme::recovery::BACKGROUND))
+        .padding(Padding::horizontal(1));
+    frame.render_widget(Paragraph::new(lines).block(block), area);
+    Some(area)
+}
+
+#[cfg(test)]
+#[path = "recovery_render_tests.rs"]
+mod recovery_render_tests;
@@ -0,0 +1,46 @@
+//! Tests for recovery/recovery_render
+
+use super::*;
+use crate::recovery::RecoveryState;
+use crate::session::{self, Session};
+use crate::test_utils::test_helpers::app_with_query;
+use insta::assert_snapshot;
+use ratatui::Terminal;
+use ratatui::backend::TestBackend;
+use tempfile::TempDir;
+
+fn render_to_string(app: &App, width: u16, height: u16) -> String {
+    let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+    terminal
+        .draw(|f| {
+            render_recovery_prompt(app, f);
+        })
+        .unwrap();
+    terminal.backend().to_string()
+}
+
+#[test]
+fn snapshot_recovery_prompt() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("input.toml");
+    let session = Session {
+        query: ".items[] | select(.status == \"failed\") | .id".to_string(),
+        ..Session::default()
+    };
+    session::save_session_to_path(&path, &session).unwrap();
+    let mut app = app_with_query("");
+    app.recovery = RecoveryState::new(true);
+    app.recovery.attach(path, Some(""));
+
+    let output = render_to_string(&app, 80, 12);
+    assert_snapshot!(output);
+}
+
+#[test]
+fn test_nothing_rendered_without_offer() {
+    let app = app_with_query("");
+    let mut terminal = Terminal::new(TestBackend::new(80, 12)).unwrap();
+    terminal
+        .draw(|f| assert!(render_recovery_prompt(&app, f).is_none()))
+        .unwrap();
+}
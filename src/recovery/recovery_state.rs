@@ -0,0 +1,129 @@
+use std::fs;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use crate::session::{self, Session};
+
+/// How often the recovery file is brought up to date
+pub const SAVE_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Recovery file kept while jiq runs and the offer to restore one left
+/// behind by a run that did not exit cleanly
+#[derive(Debug)]
+pub struct RecoveryState {
+    enabled: bool,
+    /// Recovery file of the current input, once it has loaded
+    path: Option<PathBuf>,
+    /// Session last written, so unchanged state is not written again
+    saved: Option<Session>,
+    last_save: Option<Instant>,
+    /// Session found in the recovery file at startup, until restored or
+    /// declined
+    offer: Option<Session>,
+}
+
+impl RecoveryState {
+    pub fn new(enabled: bool) -> Self {
+        Self {
+            enabled,
+            path: None,
+            saved: None,
+            last_save: None,
+            offer: None,
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Whether the restore prompt is shown
+    pub fn is_visible(&self) -> bool {
+        self.offer.is_some()
+    }
+
+    pub fn offer(&self) -> Option<&Session> {
+        self.offer.as_ref()
+    }
+
+    /// Keep the state of the input in `path`, offering the session a
+    /// previous run left there unless it is just `current_query`; `None`
+    /// when the query was given explicitly (a resumed session) offers nothing
+    pub fn attach(&mut self, path: PathBuf, current_query: Option<&str>) {
+        if !self.enabled {
+            return;
+        }
+        if let Some(current_query) = current_query
+            && path.exists()
+        {
+            match session::load_session_from_path(&path) {
+                Ok(left) if !left.query.is_empty() && left.query != current_query => {
+                    self.offer = Some(left);
+                }
+                Ok(_) => {}
+                Err(e) => log::debug!("Ignored recovery file: {}", e),
+            }
+        }
+        self.path = Some(path);
+    }
+
+    /// Take the offered session to restore it
+    pub fn accept(&mut self) -> Option<Session> {
+        self.offer.take()
+    }
+
+    /// Drop the offered session along with its file
+    pub fn decline(&mut self) {
+        if self.offer.take().is_some() {
+            self.remove_file();
+        }
+    }
+
+    /// Whether it is time to bring the recovery file up to date
+    pub fn is_due(&self, now: Instant) -> bool {
+        self.enabled
+            && self.path.is_some()
+            && self.offer.is_none()
+            && self
+                .last_save
+                .is_none_or(|last| now.duration_since(last) >= SAVE_INTERVAL)
+    }
+
+    /// Write `session` to the recovery file if it changed since the last
+    /// write; an empty query leaves nothing worth recovering
+    pub fn save(&mut self, session: Session, now: Instant) {
+        self.last_save = Some(now);
+        if self.saved.as_ref() == Some(&session) {
+            return;
+        }
+        let Some(path) = &self.path else {
+            return;
+        };
+        if session.query.is_empty() {
+            self.remove_file();
+        } else if let Err(e) = session::save_session_to_path(path, &session) {
+            log::debug!("Cannot write recovery file {}: {}", path.display(), e);
+        }
+        self.saved = Some(session);
+    }
+
+    /// Remove the recovery file on a clean exit
+    pub fn finish(&mut self) {
+        if self.offer.is_none() {
+            self.remove_file();
+        }
+    }
+
+    fn remove_file(&self) {
+        if let Some(path) = &self.path
+            && path.exists()
+            && let Err(e) = fs::remove_file(path)
+        {
+            log::debug!("Cannot remove recovery file {}: {}", path.display(), e);
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "recovery_state_tests.rs"]
+mod recovery_state_tests;
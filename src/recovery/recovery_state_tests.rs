@@ -0,0 +1,169 @@
+//! Tests for recovery/recovery_state
+
+use super::*;
+use tempfile::TempDir;
+
+fn session(query: &str) -> Session {
+    Session {
+        query: query.to_string(),
+        ..Session::default()
+    }
+}
+
+fn recovery_file(dir: &TempDir) -> PathBuf {
+    dir.path().join("recovery").join("input.toml")
+}
+
+#[test]
+fn test_new_state_is_not_due_until_attached() {
+    let state = RecoveryState::new(true);
+    assert!(!state.is_visible());
+    assert!(!state.is_due(Instant::now()));
+}
+
+#[test]
+fn test_save_writes_recovery_file() {
+    let dir = TempDir::new().unwrap();
+    let path = recovery_file(&dir);
+    let mut state = RecoveryState::new(true);
+    state.attach(path.clone(), Some(""));
+
+    assert!(state.is_due(Instant::now()));
+    state.save(session(".users[0]"), Instant::now());
+
+    assert_eq!(
+        session::load_session_from_path(&path).unwrap().query,
+        ".users[0]"
+    );
+}
+
+#[test]
+fn test_not_due_again_within_interval() {
+    let dir = TempDir::new().unwrap();
+    let mut state = RecoveryState::new(true);
+    state.attach(recovery_file(&dir), Some(""));
+    let now = Instant::now();
+
+    state.save(session(".a"), now);
+
+    assert!(!state.is_due(now + Duration::from_secs(1)));
+    assert!(state.is_due(now + SAVE_INTERVAL));
+}
+
+#[test]
+fn test_save_with_empty_query_removes_file() {
+    let dir = TempDir::new().unwrap();
+    let path = recovery_file(&dir);
+    let mut state = RecoveryState::new(true);
+    state.attach(path.clone(), Some(""));
+
+    state.save(session(".a"), Instant::now());
+    state.save(session(""), Instant::now());
+
+    assert!(!path.exists());
+}
+
+#[test]
+fn test_attach_offers_leftover_session() {
+    let dir = TempDir::new().unwrap();
+    let path = recovery_file(&dir);
+    session::save_session_to_path(&path, &session(".items | length")).unwrap();
+
+    let mut state = RecoveryState::new(true);
+    state.attach(path, Some(""));
+
+    assert!(state.is_visible());
+    assert_eq!(state.offer().unwrap().query, ".items | length");
+    assert!(!state.is_due(Instant::now()));
+}
+
+#[test]
+fn test_attach_skips_leftover_matching_current_query() {
+    let dir = TempDir::new().unwrap();
+    let path = recovery_file(&dir);
+    session::save_session_to_path(&path, &session(".name")).unwrap();
+
+    let mut state = RecoveryState::new(true);
+    state.attach(path, Some(".name"));
+
+    assert!(!state.is_visible());
+}
+
+#[test]
+fn test_attach_without_query_tracks_file_but_offers_nothing() {
+    let dir = TempDir::new().unwrap();
+    let path = recovery_file(&dir);
+    session::save_session_to_path(&path, &session(".name")).unwrap();
+
+    let mut state = RecoveryState::new(true);
+    state.attach(path, None);
+
+    assert!(!state.is_visible());
+    assert!(state.is_due(Instant::now()));
+}
+
+#[test]
+fn test_disabled_state_ignores_leftover() {
+    let dir = TempDir::new().unwrap();
+    let path = recovery_file(&dir);
+    session::save_session_to_path(&path, &session(".name")).unwrap();
+
+    let mut state = RecoveryState::new(false);
+    state.attach(path, Some(""));
+
+    assert!(!state.is_visible());
+    assert!(!state.is_due(Instant::now()));
+}
+
+#[test]
+fn test_accept_takes_offer() {
+    let dir = TempDir::new().unwrap();
+    let path = recovery_file(&dir);
+    session::save_session_to_path(&path, &session(".a")).unwrap();
+    let mut state = RecoveryState::new(true);
+    state.attach(path, Some(""));
+
+    assert_eq!(state.accept().unwrap().query, ".a");
+    assert!(!state.is_visible());
+    assert!(state.is_due(Instant::now()));
+}
+
+#[test]
+fn test_decline_removes_file() {
+    let dir = TempDir::new().unwrap();
+    let path = recovery_file(&dir);
+    session::save_session_to_path(&path, &session(".a")).unwrap();
+    let mut state = RecoveryState::new(true);
+    state.attach(path.clone(), Some(""));
+
+    state.decline();
+
+    assert!(!state.is_visible());
+    assert!(!path.exists());
+}
+
+#[test]
+fn test_finish_removes_file() {
+    let dir = TempDir::new().unwrap();
+    let path = recovery_file(&dir);
+    let mut state = RecoveryState::new(true);
+    state.attach(path.clone(), Some(""));
+    state.save(session(".a"), Instant::now());
+
+    state.finish();
+
+    assert!(!path.exists());
+}
+
+#[test]
+fn test_finish_keeps_unanswered_offer() {
+    let dir = TempDir::new().unwrap();
+    let path = recovery_file(&dir);
+    session::save_session_to_path(&path, &session(".a")).unwrap();
+    let mut state = RecoveryState::new(true);
+    state.attach(path.clone(), Some(""));
+
+    state.finish();
+
+    assert!(path.exists());
+}
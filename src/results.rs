@@ -1,3 +1,10 @@
 pub mod cursor_state;
+pub mod folding;
+pub mod line_path;
+pub mod redaction;
 pub mod results_events;
 pub mod results_render;
+pub mod sparkline;
+pub mod sparkline_image;
+pub mod sticky_header;
+pub mod timing_overlay;
@@ -162,6 +162,21 @@ impl CursorState {
         self.mode = SelectionMode::Normal;
     }
 
+    /// Select lines `anchor` to `cursor`, leaving the cursor on `cursor`
+    pub fn select_lines(&mut self, anchor: u32, cursor: u32) {
+        let max_line = self.total_lines.saturating_sub(1);
+        self.mode = SelectionMode::Visual;
+        self.selection_anchor = anchor.min(max_line);
+        self.cursor_line = cursor.min(max_line);
+    }
+
+    /// Move the cursor to the other end of the selection, vim's `o`
+    pub fn swap_selection_ends(&mut self) {
+        if self.mode == SelectionMode::Visual {
+            std::mem::swap(&mut self.cursor_line, &mut self.selection_anchor);
+        }
+    }
+
     #[allow(dead_code)]
     pub fn toggle_visual_mode(&mut self) {
         match self.mode {
@@ -105,6 +105,21 @@ fn test_visual_mode_toggle() {
     assert!(!cursor.is_visual_mode());
 }
 
+#[test]
+fn test_select_lines_clamps_and_swaps_ends() {
+    let mut cursor = CursorState::new();
+    cursor.update_total_lines(10);
+
+    cursor.select_lines(2, 20);
+    assert!(cursor.is_visual_mode());
+    assert_eq!(cursor.selection_range(), (2, 9));
+    assert_eq!(cursor.cursor_line(), 9);
+
+    cursor.swap_selection_ends();
+    assert_eq!(cursor.cursor_line(), 2);
+    assert_eq!(cursor.selection_range(), (2, 9));
+}
+
 #[test]
 fn test_selection_range_normal_mode() {
     let mut cursor = CursorState::new();
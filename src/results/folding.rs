@@ -0,0 +1,301 @@
+//! Folding of the results pane
+//!
+//! jq pretty-prints an object or array spanning several lines with its
+//! opening bracket ending one line and its closing bracket starting a later
+//! one, so the foldable regions are found from the text alone. A closed
+//! region shows as its first line; the rows of the pane then no longer match
+//! the lines of the result. The cursor and scroll position count rows, and
+//! everything reading the result maps them back with [`FoldState::line_at`].
+
+use std::collections::BTreeSet;
+
+use crate::query::result_lines::ResultLines;
+
+/// An object or array spanning lines `start` to `end` of the result
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FoldRegion {
+    pub start: usize,
+    pub end: usize,
+    /// Containers around it: 0 for a top-level value
+    pub depth: usize,
+}
+
+#[derive(Debug, Default)]
+pub struct FoldState {
+    /// Result the regions were found in
+    lines: Option<ResultLines>,
+    line_count: usize,
+    /// Found on first use, sorted by start line
+    regions: Option<Vec<FoldRegion>>,
+    /// Regions this deep or deeper are closed, also in new results
+    level: Option<usize>,
+    /// Start lines of the closed regions
+    closed: BTreeSet<usize>,
+    /// Result line shown on each row, when anything is closed
+    rows: Option<Vec<usize>>,
+    /// `z` was pressed and the next key picks the fold command
+    pub pending: bool,
+}
+
+impl FoldState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Follow a new result: manual folds are dropped, a fold level is
+    /// applied again
+    pub fn sync(&mut self, lines: &ResultLines) {
+        if self
+            .lines
+            .as_ref()
+            .is_some_and(|synced| synced.ptr_eq(lines))
+        {
+            return;
+        }
+        self.lines = Some(lines.clone());
+        self.line_count = lines.len();
+        self.regions = None;
+        self.closed.clear();
+        self.rows = None;
+        if let Some(level) = self.level {
+            self.regions = Some(find_regions(lines));
+            self.close_from_depth(level);
+        }
+    }
+
+    /// Whether any region is closed
+    pub fn is_folded(&self) -> bool {
+        self.rows.is_some()
+    }
+
+    pub fn level(&self) -> Option<usize> {
+        self.level
+    }
+
+    /// Rows the result takes up in the pane
+    pub fn row_count(&self, line_count: u32) -> u32 {
+        match &self.rows {
+            Some(rows) => rows.len() as u32,
+            None => line_count,
+        }
+    }
+
+    /// Result line shown on `row`
+    pub fn line_at(&self, row: u32) -> usize {
+        match &self.rows {
+            Some(rows) => rows.get(row as usize).or(rows.last()).copied().unwrap_or(0),
+            None => row as usize,
+        }
+    }
+
+    /// Row showing result line `line`, or the closed region hiding it
+    pub fn row_of(&self, line: usize) -> u32 {
+        match &self.rows {
+            Some(rows) => rows
+                .partition_point(|&shown| shown <= line)
+                .saturating_sub(1) as u32,
+            None => line as u32,
+        }
+    }
+
+    /// Last line of the region closed at `line`, if one is
+    pub fn closed_end(&self, line: usize) -> Option<usize> {
+        if !self.closed.contains(&line) {
+            return None;
+        }
+        self.region_at(line).map(|region| region.end)
+    }
+
+    /// Result lines covered by rows `start..=end`, including the lines
+    /// inside a closed region on the last row
+    pub fn line_range(&self, start: u32, end: u32) -> (usize, usize) {
+        let last = self.line_at(end);
+        (self.line_at(start), self.closed_end(last).unwrap_or(last))
+    }
+
+    /// Close every region `depth` or more containers deep, here and in the
+    /// results that follow; `None` opens everything
+    pub fn set_level(&mut self, lines: &ResultLines, level: Option<usize>) {
+        self.sync(lines);
+        self.level = level;
+        match level {
+            Some(level) => {
+                self.find(lines);
+                self.close_from_depth(level);
+            }
+            None => {
+                self.closed.clear();
+                self.rows = None;
+            }
+        }
+    }
+
+    /// Close one more level of nesting (`zm`)
+    pub fn fold_more(&mut self, lines: &ResultLines) {
+        let deepest = self.find(lines).iter().map(|region| region.depth + 1).max();
+        let level = self.level.or(deepest).unwrap_or(0).saturating_sub(1);
+        self.set_level(lines, Some(level));
+    }
+
+    /// Open one more level of nesting (`zr`)
+    pub fn fold_less(&mut self, lines: &ResultLines) {
+        let Some(level) = self.level else {
+            return;
+        };
+        let deepest = self.find(lines).iter().map(|region| region.depth).max();
+        let level =
+            Some(level + 1).filter(|&level| deepest.is_some_and(|deepest| level <= deepest));
+        self.set_level(lines, level);
+    }
+
+    /// Open the region closed at `line`, or close the innermost region
+    /// around it (`za`); returns the line to put the cursor on
+    pub fn toggle(&mut self, lines: &ResultLines, line: usize) -> usize {
+        self.sync(lines);
+        if self.closed.contains(&line) {
+            self.open(lines, line)
+        } else {
+            self.close(lines, line)
+        }
+    }
+
+    /// Open the region closed at `line` (`zo`)
+    pub fn open(&mut self, lines: &ResultLines, line: usize) -> usize {
+        self.sync(lines);
+        if self.closed.remove(&line) {
+            self.rebuild_rows();
+        }
+        line
+    }
+
+    /// Close the innermost region around `line` (`zc`); returns its start
+    pub fn close(&mut self, lines: &ResultLines, line: usize) -> usize {
+        self.sync(lines);
+        let innermost = self
+            .find(lines)
+            .iter()
+            .filter(|region| region.start <= line && line <= region.end)
+            .map(|region| region.start)
+            .max();
+        match innermost {
+            Some(start) => {
+                self.closed.insert(start);
+                self.rebuild_rows();
+                start
+            }
+            None => line,
+        }
+    }
+
+    /// Open the closed regions hiding `line`, e.g. a search match; returns
+    /// whether any was
+    pub fn reveal(&mut self, line: usize) -> bool {
+        let hiding: Vec<usize> = self
+            .closed
+            .range(..line)
+            .copied()
+            .filter(|&start| {
+                self.region_at(start)
+                    .is_some_and(|region| line <= region.end)
+            })
+            .collect();
+        if hiding.is_empty() {
+            return false;
+        }
+        for start in hiding {
+            self.closed.remove(&start);
+        }
+        self.rebuild_rows();
+        true
+    }
+
+    /// Innermost region around lines `start..=end` that covers more than
+    /// them, to grow a selection to the next object or array
+    pub fn enclosing(
+        &mut self,
+        lines: &ResultLines,
+        start: usize,
+        end: usize,
+    ) -> Option<FoldRegion> {
+        self.sync(lines);
+        self.find(lines)
+            .iter()
+            .filter(|region| region.start <= start && end <= region.end)
+            .filter(|region| (region.start, region.end) != (start, end))
+            .max_by_key(|region| region.start)
+            .copied()
+    }
+
+    fn find(&mut self, lines: &ResultLines) -> &[FoldRegion] {
+        self.regions.get_or_insert_with(|| find_regions(lines))
+    }
+
+    fn region_at(&self, start: usize) -> Option<&FoldRegion> {
+        let regions = self.regions.as_ref()?;
+        let index = regions
+            .binary_search_by_key(&start, |region| region.start)
+            .ok()?;
+        regions.get(index)
+    }
+
+    fn close_from_depth(&mut self, level: usize) {
+        self.closed = self
+            .regions
+            .iter()
+            .flatten()
+            .filter(|region| region.depth >= level)
+            .map(|region| region.start)
+            .collect();
+        self.rebuild_rows();
+    }
+
+    fn rebuild_rows(&mut self) {
+        if self.closed.is_empty() {
+            self.rows = None;
+            return;
+        }
+        let mut rows = Vec::new();
+        let mut next = 0;
+        for &start in &self.closed {
+            // Closed inside a region that is already closed
+            if start < next {
+                continue;
+            }
+            let Some(end) = self.region_at(start).map(|region| region.end) else {
+                continue;
+            };
+            rows.extend(next..=start);
+            next = end + 1;
+        }
+        rows.extend(next..self.line_count);
+        self.rows = Some(rows);
+    }
+}
+
+/// Objects and arrays spanning more than one line, by start line
+pub fn find_regions(lines: &ResultLines) -> Vec<FoldRegion> {
+    let mut open = Vec::new();
+    let mut regions = Vec::new();
+    for index in 0..lines.len() {
+        let line = lines.line(index).unwrap_or_default();
+        let line = line.trim();
+        if line.starts_with(['}', ']'])
+            && let Some(start) = open.pop()
+        {
+            regions.push(FoldRegion {
+                start,
+                end: index,
+                depth: open.len(),
+            });
+        }
+        if line.ends_with(['{', '[']) {
+            open.push(index);
+        }
+    }
+    regions.sort_by_key(|region| region.start);
+    regions
+}
+
+#[cfg(test)]
+#[path = "folding_tests.rs"]
+mod folding_tests;
@@ -0,0 +1,183 @@
+//! Tests for folding
+
+use std::sync::Arc;
+
+use super::*;
+
+const DOCUMENT: &str = r#"{
+  "name": "api",
+  "spec": {
+    "ports": [
+      80,
+      443
+    ],
+    "labels": {}
+  },
+  "items": [
+    {
+      "id": 1
+    }
+  ]
+}"#;
+
+fn lines(text: &str) -> ResultLines {
+    ResultLines::new(Arc::new(text.to_string()))
+}
+
+fn shown(fold: &FoldState, lines: &ResultLines) -> Vec<usize> {
+    (0..fold.row_count(lines.len() as u32))
+        .map(|row| fold.line_at(row))
+        .collect()
+}
+
+#[test]
+fn test_find_regions() {
+    let regions = find_regions(&lines(DOCUMENT));
+    assert_eq!(
+        regions,
+        vec![
+            FoldRegion {
+                start: 0,
+                end: 14,
+                depth: 0
+            },
+            FoldRegion {
+                start: 2,
+                end: 8,
+                depth: 1
+            },
+            FoldRegion {
+                start: 3,
+                end: 6,
+                depth: 2
+            },
+            FoldRegion {
+                start: 9,
+                end: 13,
+                depth: 1
+            },
+            FoldRegion {
+                start: 10,
+                end: 12,
+                depth: 2
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_regions_of_each_output_in_a_stream() {
+    let regions = find_regions(&lines("{\n  \"a\": 1\n}\n[\n  2\n]\n3\n"));
+    assert_eq!(regions.len(), 2);
+    assert!(regions.iter().all(|region| region.depth == 0));
+}
+
+#[test]
+fn test_unfolded_rows_are_lines() {
+    let result = lines(DOCUMENT);
+    let mut fold = FoldState::new();
+    fold.sync(&result);
+
+    assert!(!fold.is_folded());
+    assert_eq!(fold.row_count(15), 15);
+    assert_eq!(fold.line_at(4), 4);
+    assert_eq!(fold.row_of(4), 4);
+}
+
+#[test]
+fn test_level_one_shows_top_level_keys() {
+    let result = lines(DOCUMENT);
+    let mut fold = FoldState::new();
+    fold.set_level(&result, Some(1));
+
+    assert_eq!(shown(&fold, &result), vec![0, 1, 2, 9, 14]);
+    assert_eq!(fold.closed_end(2), Some(8));
+    assert_eq!(fold.closed_end(1), None);
+}
+
+#[test]
+fn test_level_zero_closes_everything() {
+    let result = lines(DOCUMENT);
+    let mut fold = FoldState::new();
+    fold.set_level(&result, Some(0));
+
+    assert_eq!(shown(&fold, &result), vec![0]);
+    assert_eq!(fold.line_range(0, 0), (0, 14));
+}
+
+#[test]
+fn test_fold_more_and_less_step_one_level() {
+    let result = lines(DOCUMENT);
+    let mut fold = FoldState::new();
+
+    fold.fold_more(&result);
+    assert_eq!(fold.level(), Some(2));
+    fold.fold_more(&result);
+    assert_eq!(fold.level(), Some(1));
+
+    fold.fold_less(&result);
+    assert_eq!(fold.level(), Some(2));
+    fold.fold_less(&result);
+    assert_eq!(fold.level(), None);
+    assert!(!fold.is_folded());
+}
+
+#[test]
+fn test_row_of_hidden_line_is_its_fold() {
+    let result = lines(DOCUMENT);
+    let mut fold = FoldState::new();
+    fold.set_level(&result, Some(1));
+
+    assert_eq!(fold.row_of(5), 2);
+    assert_eq!(fold.row_of(9), 3);
+    assert_eq!(fold.row_of(14), 4);
+}
+
+#[test]
+fn test_toggle_closes_innermost_region_and_opens_it_again() {
+    let result = lines(DOCUMENT);
+    let mut fold = FoldState::new();
+
+    assert_eq!(fold.toggle(&result, 4), 3);
+    assert_eq!(
+        shown(&fold, &result),
+        vec![0, 1, 2, 3, 7, 8, 9, 10, 11, 12, 13, 14]
+    );
+
+    assert_eq!(fold.toggle(&result, 3), 3);
+    assert!(!fold.is_folded());
+}
+
+#[test]
+fn test_open_keeps_nested_folds() {
+    let result = lines(DOCUMENT);
+    let mut fold = FoldState::new();
+    fold.set_level(&result, Some(1));
+    fold.open(&result, 2);
+
+    assert_eq!(shown(&fold, &result), vec![0, 1, 2, 3, 7, 8, 9, 14]);
+}
+
+#[test]
+fn test_reveal_opens_folds_around_line() {
+    let result = lines(DOCUMENT);
+    let mut fold = FoldState::new();
+    fold.set_level(&result, Some(1));
+    fold.reveal(11);
+
+    assert_eq!(fold.row_of(11), fold.row_of(10) + 1);
+    assert_eq!(fold.closed_end(2), Some(8));
+}
+
+#[test]
+fn test_new_result_keeps_level_and_drops_manual_folds() {
+    let mut fold = FoldState::new();
+    fold.close(&lines(DOCUMENT), 4);
+    fold.sync(&lines(DOCUMENT));
+    assert!(!fold.is_folded());
+
+    fold.set_level(&lines(DOCUMENT), Some(1));
+    let next = lines("{\n  \"a\": [\n    1\n  ]\n}");
+    fold.sync(&next);
+    assert_eq!(shown(&fold, &next), vec![0, 1, 4]);
+}
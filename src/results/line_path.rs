@@ -0,0 +1,171 @@
+//! Path of the value on a result line
+//!
+//! Works on jq's pretty-printed output, one member or element per line, so
+//! the path can be found by tracking the open objects and arrays above the
+//! line instead of parsing the whole result.
+
+use crate::query::incremental::is_path_chain;
+use crate::query::language::jq_field;
+
+/// Location of a result line
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LinePath {
+    /// Index of the output value the line belongs to, when jq emits several
+    pub value_index: usize,
+    /// Path of the line's value within that output value, e.g. `.items[2].name`
+    pub path: String,
+}
+
+#[derive(Debug)]
+enum Container {
+    Object,
+    Array { next_index: usize },
+}
+
+#[derive(Debug)]
+struct Frame {
+    container: Container,
+    /// Path segment of the container within its parent
+    segment: String,
+}
+
+/// Find the path of the value on `line` of the pretty-printed `output`
+///
+/// A closing bracket belongs to the container it closes. Returns None for
+/// lines that are not part of pretty-printed JSON (e.g. compact or raw output).
+pub fn path_at_line(output: &str, line: usize) -> Option<LinePath> {
+    let mut stack: Vec<Frame> = Vec::new();
+    let mut value_index = 0;
+    let mut seen_value = false;
+
+    for (index, raw_line) in output.lines().enumerate().take(line + 1) {
+        let trimmed = raw_line.trim();
+        if trimmed.is_empty() {
+            continue;
+        }
+        let content = trimmed.strip_suffix(',').unwrap_or(trimmed);
+
+        if content == "}" || content == "]" {
+            if index == line {
+                return Some(LinePath {
+                    value_index,
+                    path: join(&stack, ""),
+                });
+            }
+            stack.pop()?;
+            continue;
+        }
+
+        let (segment, value) = match stack.last_mut() {
+            None => {
+                if seen_value {
+                    value_index += 1;
+                }
+                seen_value = true;
+                (String::new(), content)
+            }
+            Some(Frame {
+                container: Container::Array { next_index },
+                ..
+            }) => {
+                let segment = format!("[{}]", next_index);
+                *next_index += 1;
+                (segment, content)
+            }
+            Some(Frame {
+                container: Container::Object,
+                ..
+            }) => {
+                let (key, value) = split_member(content)?;
+                (jq_field(&key), value)
+            }
+        };
+
+        if index == line {
+            return Some(LinePath {
+                value_index,
+                path: join(&stack, &segment),
+            });
+        }
+
+        let container = match value {
+            "{" => Container::Object,
+            "[" => Container::Array { next_index: 0 },
+            _ => continue,
+        };
+        stack.push(Frame { container, segment });
+    }
+
+    None
+}
+
+/// Combine the query that produced a result with the path of one of its lines
+///
+/// The outcome is a jq expression selecting the line's value from the input.
+/// A path query is extended with the line path, picking the element of a
+/// single `[]` iteration the value came from; other queries are piped into it.
+pub fn query_path(base_query: &str, at: &LinePath) -> String {
+    let base = base_query.trim();
+    if base.is_empty() || base == "." {
+        return at.path.clone();
+    }
+    if !is_path_chain(base) {
+        return format!("{} | {}", base, at.path);
+    }
+
+    let base = match base.match_indices("[]").count() {
+        1 => base.replacen("[]", &format!("[{}]", at.value_index), 1),
+        _ => base.to_string(),
+    };
+    match at.path.as_str() {
+        "." => base,
+        path if path.starts_with(".[") => format!("{}{}", base, &path[1..]),
+        path => format!("{}{}", base, path),
+    }
+}
+
+/// Split an object member line into its key and the rest of the line
+pub(crate) fn split_member(content: &str) -> Option<(String, &str)> {
+    let bytes = content.as_bytes();
+    if bytes.first() != Some(&b'"') {
+        return None;
+    }
+
+    let mut i = 1;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' => i += 2,
+            b'"' => break,
+            _ => i += 1,
+        }
+    }
+    let key_end = i + 1;
+    if key_end > bytes.len() {
+        return None;
+    }
+
+    let key: String = serde_json::from_str(&content[..key_end]).ok()?;
+    let value = content[key_end..].strip_prefix(':')?.trim_start();
+    Some((key, value))
+}
+
+/// Path made of the open containers' segments followed by `last`
+fn join(stack: &[Frame], last: &str) -> String {
+    let path: String = stack
+        .iter()
+        .map(|frame| frame.segment.as_str())
+        .chain(std::iter::once(last))
+        .collect();
+
+    if path.is_empty() {
+        ".".to_string()
+    } else if path.starts_with('[') {
+        format!(".{}", path)
+    } else {
+        path
+    }
+}
+
+#[cfg(test)]
+#[path = "line_path_tests.rs"]
+mod line_path_tests;
@@ -0,0 +1,140 @@
+//! Tests for line_path
+
+use super::*;
+
+const OUTPUT: &str = r#"{
+  "name": "api",
+  "ports": [
+    80,
+    {
+      "target": 8080
+    }
+  ],
+  "labels": {
+    "app.kubernetes.io/name": "api"
+  },
+  "empty": {}
+}"#;
+
+fn path(output: &str, line: usize) -> Option<String> {
+    path_at_line(output, line).map(|at| at.path)
+}
+
+#[test]
+fn test_root_lines_are_the_whole_value() {
+    assert_eq!(path(OUTPUT, 0).as_deref(), Some("."));
+    assert_eq!(path(OUTPUT, 12).as_deref(), Some("."));
+}
+
+#[test]
+fn test_object_members() {
+    assert_eq!(path(OUTPUT, 1).as_deref(), Some(".name"));
+    assert_eq!(path(OUTPUT, 2).as_deref(), Some(".ports"));
+    assert_eq!(path(OUTPUT, 11).as_deref(), Some(".empty"));
+}
+
+#[test]
+fn test_array_elements_and_nested_members() {
+    assert_eq!(path(OUTPUT, 3).as_deref(), Some(".ports[0]"));
+    assert_eq!(path(OUTPUT, 4).as_deref(), Some(".ports[1]"));
+    assert_eq!(path(OUTPUT, 5).as_deref(), Some(".ports[1].target"));
+}
+
+#[test]
+fn test_closing_bracket_belongs_to_its_container() {
+    assert_eq!(path(OUTPUT, 6).as_deref(), Some(".ports[1]"));
+    assert_eq!(path(OUTPUT, 7).as_deref(), Some(".ports"));
+}
+
+#[test]
+fn test_keys_that_are_not_identifiers_are_quoted() {
+    assert_eq!(
+        path(OUTPUT, 9).as_deref(),
+        Some(".labels.[\"app.kubernetes.io/name\"]")
+    );
+}
+
+#[test]
+fn test_keyword_keys_are_quoted() {
+    let output = "{\n  \"if\": {\n    \"end\": 1\n  }\n}";
+    assert_eq!(path(output, 2).as_deref(), Some(".[\"if\"].[\"end\"]"));
+}
+
+#[test]
+fn test_top_level_array() {
+    let output = "[\n  {\n    \"id\": 1\n  },\n  {\n    \"id\": 2\n  }\n]";
+    assert_eq!(path(output, 5).as_deref(), Some(".[1].id"));
+}
+
+#[test]
+fn test_value_index_counts_output_values() {
+    let output = "{\n  \"id\": 1\n}\n{\n  \"id\": 2\n}";
+    assert_eq!(
+        path_at_line(output, 4),
+        Some(LinePath {
+            value_index: 1,
+            path: ".id".to_string()
+        })
+    );
+}
+
+#[test]
+fn test_lines_past_the_end_have_no_path() {
+    assert_eq!(path(OUTPUT, 100), None);
+}
+
+#[test]
+fn test_compact_output_values_are_whole_values() {
+    let output = "{\"a\":1}\n{\"a\":2}";
+    assert_eq!(path_at_line(output, 1), Some(at(1, ".")));
+}
+
+#[test]
+fn test_object_line_without_key_has_no_path() {
+    assert_eq!(path("{\n  \"a\"\n}", 1), None);
+    assert_eq!(path("{\n  1\n}", 1), None);
+}
+
+fn at(value_index: usize, path: &str) -> LinePath {
+    LinePath {
+        value_index,
+        path: path.to_string(),
+    }
+}
+
+#[test]
+fn test_query_path_on_identity() {
+    assert_eq!(query_path("", &at(0, ".name")), ".name");
+    assert_eq!(query_path(".", &at(0, ".ports[1]")), ".ports[1]");
+}
+
+#[test]
+fn test_query_path_extends_path_queries() {
+    assert_eq!(query_path(".spec", &at(0, ".name")), ".spec.name");
+    assert_eq!(query_path(".spec", &at(0, ".")), ".spec");
+    assert_eq!(query_path(".spec", &at(0, ".[2]")), ".spec[2]");
+}
+
+#[test]
+fn test_query_path_picks_iterated_element() {
+    assert_eq!(
+        query_path(".items[].metadata", &at(3, ".name")),
+        ".items[3].metadata.name"
+    );
+}
+
+#[test]
+fn test_query_path_keeps_ambiguous_iterations() {
+    assert_eq!(
+        query_path(".items[].ports[]", &at(3, ".")),
+        ".items[].ports[]"
+    );
+}
+
+#[test]
+fn test_query_path_pipes_other_queries() {
+    assert_eq!(
+        query_path("map(.spec)", &at(0, ".[0].name")),
+        "map(.spec) | .[0].name"
+    );
+}
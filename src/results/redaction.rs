@@ -0,0 +1,217 @@
+//! Redacted View
+//!
+//! Masks the values of fields whose name matches a configured pattern, such
+//! as `password` or `token`, in the results pane. Keys, brackets and nesting
+//! stay visible so the shape of the result can still be explored while the
+//! screen is shared.
+//!
+//! Only what is drawn changes: results printed on exit, copied or passed to
+//! plugins are the real ones.
+
+use std::ops::Range;
+
+use ratatui::text::Line;
+
+use crate::config::RedactionConfig;
+use crate::query::result_lines::ResultLines;
+use crate::syntax_highlight::json::{JsonHighlighter, is_followed_by_colon, string_end};
+
+/// Shown in place of a redacted value, whatever its type or length
+pub const MASK: &str = "\"••••••\"";
+
+pub struct RedactionState {
+    pub enabled: bool,
+    /// Lowercase patterns matched anywhere in a field name
+    patterns: Vec<String>,
+}
+
+impl RedactionState {
+    pub fn new(config: &RedactionConfig) -> Self {
+        Self {
+            enabled: config.enabled,
+            patterns: config
+                .fields
+                .iter()
+                .map(|pattern| pattern.to_lowercase())
+                .filter(|pattern| !pattern.is_empty())
+                .collect(),
+        }
+    }
+
+    pub fn toggle(&mut self) {
+        self.enabled = !self.enabled;
+    }
+
+    /// Whether values of the field `key` (as written in the JSON, without
+    /// quotes) are masked
+    pub fn is_sensitive(&self, key: &str) -> bool {
+        let key = key.to_lowercase();
+        self.patterns.iter().any(|pattern| key.contains(pattern))
+    }
+
+    /// Like [`ResultLines::highlighted`], with sensitive values masked
+    pub fn highlighted(
+        &self,
+        lines: &ResultLines,
+        range: Range<usize>,
+        max_width: usize,
+    ) -> Vec<Line<'static>> {
+        let end = range.end.min(lines.len());
+        let start = range.start.min(end);
+        let mut containers = self.containers_around(lines, start);
+        (start..end)
+            .filter_map(|index| lines.line(index))
+            .map(|line| {
+                let redacted = self.redact_line(&line, max_width, &mut containers);
+                JsonHighlighter::highlight_line(&redacted, max_width)
+            })
+            .collect()
+    }
+
+    /// Whether each object or array open at the start of line `index` is
+    /// masked, outermost first
+    ///
+    /// jq pretty-prints one member per line, indented one level deeper than
+    /// the line opening its container, so the containers are found by
+    /// walking up to each less indented line rather than parsing everything
+    /// above the view.
+    fn containers_around(&self, lines: &ResultLines, index: usize) -> Vec<bool> {
+        let Some(line) = lines.line(index) else {
+            return Vec::new();
+        };
+        let mut limit = indent_of(&line);
+        // A closing bracket is indented like the line opening its container
+        if line.trim_start().starts_with(['}', ']']) {
+            limit += 1;
+        }
+
+        // Whether the key of each container is sensitive, innermost first
+        let mut sensitive = Vec::new();
+        let mut i = index;
+        while limit > 0 && i > 0 {
+            i -= 1;
+            let Some(above) = lines.line(i) else {
+                break;
+            };
+            let indent = indent_of(&above);
+            if indent >= limit {
+                continue;
+            }
+            if !above.trim_end().ends_with(['{', '[']) {
+                // Not pretty-printed output
+                break;
+            }
+            sensitive.push(key_of(above.trim_start()).is_some_and(|key| self.is_sensitive(key)));
+            limit = indent;
+        }
+
+        let mut containers = Vec::with_capacity(sensitive.len());
+        let mut masked = false;
+        for key_is_sensitive in sensitive.into_iter().rev() {
+            masked = masked || key_is_sensitive;
+            containers.push(masked);
+        }
+        containers
+    }
+
+    /// Replace the sensitive values in the first `max_width` characters of
+    /// `line`
+    ///
+    /// `containers` tracks the objects and arrays opened and closed on the
+    /// line, including past `max_width`, for the lines that follow.
+    fn redact_line(&self, line: &str, max_width: usize, containers: &mut Vec<bool>) -> String {
+        let bytes = line.as_bytes();
+        let limit = line
+            .char_indices()
+            .nth(max_width)
+            .map_or(bytes.len(), |(index, _)| index);
+        let mut redacted = String::with_capacity(limit);
+        let mut copied = 0;
+        let mut mask_value = |start: usize, end: usize, redacted: &mut String| {
+            if start < limit {
+                redacted.push_str(&line[copied..start]);
+                redacted.push_str(MASK);
+                copied = end;
+            }
+        };
+
+        let mut key_is_sensitive = false;
+        let mut i = 0;
+        while i < bytes.len() {
+            let in_masked = key_is_sensitive || containers.last() == Some(&true);
+            match bytes[i] {
+                b'"' => {
+                    let end = string_end(bytes, i);
+                    if is_followed_by_colon(bytes, end) {
+                        key_is_sensitive = self.is_sensitive(key_text(&line[i..end]));
+                    } else {
+                        if in_masked {
+                            mask_value(i, end, &mut redacted);
+                        }
+                        key_is_sensitive = false;
+                    }
+                    i = end;
+                }
+                b'{' | b'[' => {
+                    containers.push(in_masked);
+                    key_is_sensitive = false;
+                    i += 1;
+                }
+                b'}' | b']' => {
+                    containers.pop();
+                    i += 1;
+                }
+                b',' | b':' => i += 1,
+                byte if byte.is_ascii_whitespace() => i += 1,
+                _ => {
+                    // Number, true, false or null
+                    let end = scalar_end(bytes, i);
+                    if in_masked {
+                        mask_value(i, end, &mut redacted);
+                    }
+                    key_is_sensitive = false;
+                    i = end;
+                }
+            }
+        }
+
+        if copied < limit {
+            redacted.push_str(&line[copied..limit]);
+        }
+        redacted
+    }
+}
+
+fn indent_of(line: &str) -> usize {
+    line.len() - line.trim_start().len()
+}
+
+/// Key of a line like `"name": {`, `None` for an array element
+fn key_of(trimmed: &str) -> Option<&str> {
+    if !trimmed.starts_with('"') {
+        return None;
+    }
+    let end = string_end(trimmed.as_bytes(), 0);
+    is_followed_by_colon(trimmed.as_bytes(), end).then(|| key_text(&trimmed[..end]))
+}
+
+/// Text of a quoted string token without its quotes
+fn key_text(token: &str) -> &str {
+    let inner = &token[1..];
+    inner.strip_suffix('"').unwrap_or(inner)
+}
+
+fn scalar_end(bytes: &[u8], start: usize) -> usize {
+    let mut i = start + 1;
+    while i < bytes.len() && !matches!(bytes[i], b',' | b'}' | b']' | b':' | b'"') {
+        if bytes[i].is_ascii_whitespace() {
+            break;
+        }
+        i += 1;
+    }
+    i
+}
+
+#[cfg(test)]
+#[path = "redaction_tests.rs"]
+mod redaction_tests;
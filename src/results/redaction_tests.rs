@@ -0,0 +1,157 @@
+//! Tests for redaction
+
+use super::*;
+use std::sync::Arc;
+
+fn state(fields: &[&str]) -> RedactionState {
+    RedactionState::new(&RedactionConfig {
+        enabled: true,
+        fields: fields.iter().map(|f| f.to_string()).collect(),
+    })
+}
+
+fn default_state() -> RedactionState {
+    RedactionState::new(&RedactionConfig {
+        enabled: true,
+        ..RedactionConfig::default()
+    })
+}
+
+fn lines(text: &str) -> ResultLines {
+    ResultLines::new(Arc::new(text.to_string()))
+}
+
+/// Redacted text of `range`, without styling
+fn redacted(state: &RedactionState, text: &str, range: Range<usize>) -> Vec<String> {
+    state
+        .highlighted(&lines(text), range, 1_000)
+        .into_iter()
+        .map(|line| line.to_string())
+        .collect()
+}
+
+const PRETTY: &str = r#"{
+  "user": "alice",
+  "password": "hunter2",
+  "api_token": 12345,
+  "credentials": {
+    "secret": "s3cr3t",
+    "region": "eu"
+  },
+  "secrets": [
+    "a",
+    {
+      "id": 1
+    }
+  ],
+  "count": 2
+}"#;
+
+#[test]
+fn test_default_fields() {
+    let state = default_state();
+    for key in [
+        "password",
+        "api_token",
+        "client_secret",
+        "SSH_KEY",
+        "apiKey",
+    ] {
+        assert!(state.is_sensitive(key), "{}", key);
+    }
+    assert!(!state.is_sensitive("user"));
+    assert!(!default_state().is_sensitive(""));
+}
+
+#[test]
+fn test_masks_values_of_matching_fields() {
+    let output = redacted(&default_state(), PRETTY, 0..16);
+    assert_eq!(output[1], r#"  "user": "alice","#);
+    assert_eq!(output[2], format!(r#"  "password": {},"#, MASK));
+    assert_eq!(output[3], format!(r#"  "api_token": {},"#, MASK));
+    assert_eq!(output[15], "}");
+}
+
+#[test]
+fn test_keeps_structure_of_masked_containers() {
+    let output = redacted(&default_state(), PRETTY, 0..16);
+    // Only "secret" matches inside credentials
+    assert_eq!(output[5], format!(r#"    "secret": {},"#, MASK));
+    assert_eq!(output[6], r#"    "region": "eu""#);
+    // Everything under "secrets" is masked, but keys and brackets remain
+    assert_eq!(output[8], r#"  "secrets": ["#);
+    assert_eq!(output[9], format!("    {},", MASK));
+    assert_eq!(output[11], format!(r#"      "id": {}"#, MASK));
+    assert_eq!(output[13], "  ],");
+    assert_eq!(output[14], r#"  "count": 2"#);
+}
+
+#[test]
+fn test_scrolled_view_knows_enclosing_fields() {
+    let state = default_state();
+    assert_eq!(
+        redacted(&state, PRETTY, 11..15),
+        vec![
+            format!(r#"      "id": {}"#, MASK),
+            "    }".to_string(),
+            "  ],".to_string(),
+            r#"  "count": 2"#.to_string(),
+        ]
+    );
+    // Starting on the line closing a masked container
+    assert_eq!(
+        redacted(&state, PRETTY, 12..15)[2],
+        r#"  "count": 2"#.to_string()
+    );
+    assert_eq!(
+        redacted(&state, PRETTY, 6..7),
+        vec![r#"    "region": "eu""#.to_string()]
+    );
+}
+
+#[test]
+fn test_compact_output() {
+    let text = r#"{"token":"abc","nested":{"key":[1,true,null]},"n":"x"}"#;
+    assert_eq!(
+        redacted(&default_state(), text, 0..1),
+        vec![format!(
+            r#"{{"token":{m},"nested":{{"key":[{m},{m},{m}]}},"n":"x"}}"#,
+            m = MASK
+        )]
+    );
+}
+
+#[test]
+fn test_custom_fields_and_escaped_strings() {
+    let state = state(&["SSN"]);
+    let text = r#"{"ssn": "1\"2", "password": "p"}"#;
+    assert_eq!(
+        redacted(&state, text, 0..1),
+        vec![format!(r#"{{"ssn": {}, "password": "p"}}"#, MASK)]
+    );
+}
+
+#[test]
+fn test_structure_past_visible_width_is_tracked() {
+    let state = default_state();
+    let text = format!(
+        "{{\n  \"{}\": {{\n    \"secret\": {{\n      \"v\": 1\n    }}\n  }}\n}}",
+        "x".repeat(50)
+    );
+    let output: Vec<String> = state
+        .highlighted(&lines(&text), 0..7, 30)
+        .into_iter()
+        .map(|line| line.to_string())
+        .collect();
+    assert_eq!(output[1], format!("  \"{}", "x".repeat(27)));
+    assert_eq!(output[3], format!("      \"v\": {}", MASK));
+    assert_eq!(output[4], "    }");
+}
+
+#[test]
+fn test_toggle() {
+    let mut state = RedactionState::new(&RedactionConfig::default());
+    assert!(!state.enabled);
+    state.toggle();
+    assert!(state.enabled);
+}
@@ -3,9 +3,15 @@ use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use crate::app::App;
 use crate::clipboard;
 use crate::editor::EditorMode;
+use crate::external::ExternalTool;
 use crate::help::HelpTab;
 
 pub fn handle_results_pane_key(app: &mut App, key: KeyEvent) {
+    if std::mem::take(&mut app.results_fold.pending) {
+        handle_fold_key(app, key);
+        return;
+    }
+
     if app.results_cursor.is_visual_mode() && handle_visual_mode_key(app, key) {
         return;
     }
@@ -49,6 +55,46 @@ pub fn handle_results_pane_key(app: &mut App, key: KeyEvent) {
             app.results_cursor.enter_visual_mode();
         }
 
+        KeyCode::Char('z') => {
+            app.results_fold.pending = true;
+        }
+
+        KeyCode::Char('m') => {
+            crate::bookmarks::bookmark_events::bookmark_cursor_line(app);
+        }
+
+        KeyCode::Char('D') => {
+            crate::stats::field_diff_events::open_field_diff(app);
+        }
+
+        KeyCode::Char('S') => {
+            crate::stats::size_breakdown_events::open_size_breakdown(app);
+        }
+
+        KeyCode::Char('c') => {
+            crate::histogram::open_histogram(app, None);
+        }
+
+        KeyCode::Char('p') => {
+            crate::projection::open_projection(app);
+        }
+
+        KeyCode::Char('e') => {
+            crate::value_edit::open_value_edit(app);
+        }
+
+        KeyCode::Char('O') => {
+            crate::external::open_result(app, ExternalTool::Editor);
+        }
+
+        KeyCode::Char('E') => {
+            crate::external::edit_input(app);
+        }
+
+        KeyCode::Char('|') => {
+            crate::external::open_result(app, ExternalTool::Pager);
+        }
+
         KeyCode::Up | KeyCode::Char('k') => {
             move_cursor_up(app, 1);
         }
@@ -134,6 +180,18 @@ fn handle_visual_mode_key(app: &mut App, key: KeyEvent) -> bool {
             true
         }
 
+        KeyCode::Char('a') => {
+            select_enclosing_node(app);
+            true
+        }
+
+        KeyCode::Char('o') => {
+            app.results_cursor.swap_selection_ends();
+            app.results_scroll
+                .ensure_cursor_visible(app.results_cursor.cursor_line());
+            true
+        }
+
         KeyCode::Char('$') => {
             let width = app.results_cursor.get_max_selected_line_width();
             let viewport_width = app.results_scroll.viewport_width;
@@ -145,6 +203,93 @@ fn handle_visual_mode_key(app: &mut App, key: KeyEvent) -> bool {
     }
 }
 
+/// Grow the selection to the object or array around it; pressed again, to
+/// the one around that
+fn select_enclosing_node(app: &mut App) {
+    let Some(lines) = app
+        .query
+        .as_ref()
+        .and_then(|q| q.last_successful_result_lines.clone())
+    else {
+        return;
+    };
+    let (start, end) = app.results_cursor.selection_range();
+    let (start, end) = app.results_fold.line_range(start, end);
+    let Some(region) = app.results_fold.enclosing(&lines, start, end) else {
+        return;
+    };
+    let fold = &app.results_fold;
+    app.results_cursor
+        .select_lines(fold.row_of(region.start), fold.row_of(region.end));
+    app.results_scroll
+        .ensure_cursor_visible(app.results_cursor.cursor_line());
+}
+
+/// Second key of a `z` fold command, vim-style
+fn handle_fold_key(app: &mut App, key: KeyEvent) {
+    let Some(lines) = app
+        .query
+        .as_ref()
+        .and_then(|q| q.last_successful_result_lines.clone())
+    else {
+        return;
+    };
+    let line = app.results_cursor_result_line();
+    let fold = &mut app.results_fold;
+    let cursor_line = match key.code {
+        KeyCode::Char('a') => fold.toggle(&lines, line),
+        KeyCode::Char('o') => fold.open(&lines, line),
+        KeyCode::Char('c') => fold.close(&lines, line),
+        KeyCode::Char('M') => {
+            fold.set_level(&lines, Some(0));
+            line
+        }
+        KeyCode::Char('R') => {
+            fold.set_level(&lines, None);
+            line
+        }
+        KeyCode::Char('m') => {
+            fold.fold_more(&lines);
+            line
+        }
+        KeyCode::Char('r') => {
+            fold.fold_less(&lines);
+            line
+        }
+        _ => return,
+    };
+    move_cursor_to_result_line(app, cursor_line);
+}
+
+/// Close everything `depth` or more containers deep (`:fold`), or open
+/// everything (`:unfold`)
+pub fn fold_to_depth(app: &mut App, depth: Option<usize>) {
+    let Some(lines) = app
+        .query
+        .as_ref()
+        .and_then(|q| q.last_successful_result_lines.clone())
+    else {
+        app.notification.show_warning("No result to fold yet");
+        return;
+    };
+    let line = app.results_cursor_result_line();
+    app.results_fold.set_level(&lines, depth);
+    move_cursor_to_result_line(app, line);
+}
+
+/// Put the cursor on the row showing result `line` after folds changed
+pub fn move_cursor_to_result_line(app: &mut App, line: usize) {
+    let rows = app.results_line_count_u32();
+    app.results_cursor.exit_visual_mode();
+    app.results_cursor.update_total_lines(rows);
+    app.results_cursor
+        .move_to_line(app.results_fold.row_of(line));
+    app.results_scroll
+        .update_bounds(rows, app.results_scroll.viewport_height);
+    app.results_scroll
+        .ensure_cursor_visible(app.results_cursor.cursor_line());
+}
+
 fn move_cursor_up(app: &mut App, lines: u32) {
     app.results_cursor.move_up(lines);
     app.results_scroll
@@ -12,7 +12,7 @@ fn setup_app_with_content(line_count: u32, viewport_height: u16) -> crate::app::
     let content: String = (0..line_count).map(|i| format!("line{}\n", i)).collect();
     let query_state = app.query.as_mut().unwrap();
     query_state.result = Ok(content.clone());
-    query_state.last_successful_result = Some(Arc::new(content.clone()));
+    query_state.last_successful_result_unformatted = Some(Arc::new(content.clone()));
     query_state.cached_line_count = line_count;
 
     app.results_scroll
@@ -233,7 +233,6 @@ fn app_with_wide_content() -> crate::app::App {
         .collect();
     let query_state = app.query.as_mut().unwrap();
     query_state.result = Ok(content.clone());
-    query_state.last_successful_result = Some(Arc::new(content.clone()));
     query_state.last_successful_result_unformatted = Some(Arc::new(content.clone()));
     query_state.cached_line_count = content.lines().count() as u32;
     query_state.cached_max_line_width = content.lines().map(|l| l.len()).max().unwrap_or(0) as u16;
@@ -534,3 +533,111 @@ fn test_tab_exits_visual_mode() {
     assert!(!app.results_cursor.is_visual_mode());
     assert_eq!(app.focus, Focus::InputField);
 }
+
+fn setup_app_with_document() -> crate::app::App {
+    let mut app = crate::test_utils::test_helpers::test_app(
+        r#"{"name": "api", "spec": {"replicas": 3, "ports": [80, 443]}, "tags": ["a", "b"]}"#,
+    );
+    app.focus = Focus::ResultsPane;
+    let line_count = app.results_line_count_u32();
+    app.results_scroll.update_bounds(line_count, 10);
+    app.results_cursor.update_total_lines(line_count);
+    app
+}
+
+#[test]
+fn test_z_capital_m_closes_everything_and_z_capital_r_opens_it() {
+    let mut app = setup_app_with_document();
+    assert_eq!(app.results_line_count_u32(), 14);
+
+    app.handle_key_event(key(KeyCode::Char('z')));
+    app.handle_key_event(key(KeyCode::Char('M')));
+    assert_eq!(app.results_line_count_u32(), 1);
+    assert_eq!(app.results_cursor.cursor_line(), 0);
+
+    app.handle_key_event(key(KeyCode::Char('z')));
+    app.handle_key_event(key(KeyCode::Char('R')));
+    assert_eq!(app.results_line_count_u32(), 14);
+}
+
+#[test]
+fn test_zc_closes_region_around_cursor_and_moves_to_its_start() {
+    let mut app = setup_app_with_document();
+    // Inside the "ports" array
+    app.results_cursor.move_to_line(5);
+
+    app.handle_key_event(key(KeyCode::Char('z')));
+    app.handle_key_event(key(KeyCode::Char('c')));
+
+    assert_eq!(app.results_cursor.cursor_line(), 4);
+    assert_eq!(app.results_line_count_u32(), 11);
+
+    app.handle_key_event(key(KeyCode::Char('z')));
+    app.handle_key_event(key(KeyCode::Char('o')));
+    assert_eq!(app.results_line_count_u32(), 14);
+}
+
+#[test]
+fn test_cursor_moves_over_closed_region() {
+    let mut app = setup_app_with_document();
+    app.results_cursor.move_to_line(2);
+    app.handle_key_event(key(KeyCode::Char('z')));
+    app.handle_key_event(key(KeyCode::Char('a')));
+
+    app.handle_key_event(key(KeyCode::Char('j')));
+
+    assert_eq!(app.results_cursor_result_line(), 9);
+}
+
+#[test]
+fn test_z_then_unknown_key_does_nothing() {
+    let mut app = setup_app_with_document();
+
+    app.handle_key_event(key(KeyCode::Char('z')));
+    app.handle_key_event(key(KeyCode::Char('j')));
+
+    assert_eq!(app.results_cursor.cursor_line(), 0);
+    assert!(!app.results_fold.pending);
+}
+
+#[test]
+fn test_visual_a_selects_enclosing_node_and_grows_on_repeat() {
+    let mut app = setup_app_with_document();
+    // "replicas": 3, inside "spec"
+    app.results_cursor.move_to_line(3);
+
+    app.handle_key_event(key(KeyCode::Char('v')));
+    app.handle_key_event(key(KeyCode::Char('a')));
+    assert_eq!(app.results_cursor.selection_range(), (2, 8));
+    assert_eq!(app.results_cursor.cursor_line(), 8);
+
+    app.handle_key_event(key(KeyCode::Char('a')));
+    assert_eq!(app.results_cursor.selection_range(), (0, 13));
+}
+
+#[test]
+fn test_visual_a_on_opening_line_selects_that_node() {
+    let mut app = setup_app_with_document();
+    // "ports": [
+    app.results_cursor.move_to_line(4);
+
+    app.handle_key_event(key(KeyCode::Char('v')));
+    app.handle_key_event(key(KeyCode::Char('a')));
+
+    assert_eq!(app.results_cursor.selection_range(), (4, 7));
+}
+
+#[test]
+fn test_visual_o_moves_cursor_to_other_end() {
+    let mut app = setup_app_with_content(20, 10);
+    app.results_cursor.move_to_line(5);
+
+    app.handle_key_event(key(KeyCode::Char('v')));
+    app.handle_key_event(key(KeyCode::Char('j')));
+    app.handle_key_event(key(KeyCode::Char('j')));
+    app.handle_key_event(key(KeyCode::Char('o')));
+    assert_eq!(app.results_cursor.cursor_line(), 5);
+
+    app.handle_key_event(key(KeyCode::Char('k')));
+    assert_eq!(app.results_cursor.selection_range(), (4, 7));
+}
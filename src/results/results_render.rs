@@ -6,10 +6,16 @@ use ratatui::{
     widgets::{Block, BorderType, Borders, Padding, Paragraph},
 };
 
+use crate::ai::render::text::wrap_text;
 use crate::app::App;
+use crate::error::explain::{self, Explanation};
+use crate::error::parser::ErrorKind;
+use crate::query::query_state::QueryState;
+use crate::query::result_lines::ResultLines;
 use crate::scroll::ScrollState;
 use crate::search::Match;
 use crate::search::search_render::SEARCH_BAR_HEIGHT;
+use crate::syntax_highlight::json::JsonHighlighter;
 use crate::theme;
 use crate::widgets::{popup, scrollbar};
 
@@ -34,6 +40,26 @@ fn build_search_hints() -> Line<'static> {
     )
 }
 
+/// Follow mode badge: following, or paused with the number of held records
+fn build_follow_badge(paused: bool, pending: usize) -> Line<'static> {
+    let (label, style) = if paused {
+        (
+            format!("  ⏸ Paused · {} new  ", pending),
+            theme::results::BADGE_FOLLOW_PAUSED,
+        )
+    } else {
+        (
+            "  ● Following  ".to_string(),
+            theme::results::BADGE_FOLLOWING,
+        )
+    };
+    Line::from(vec![
+        Span::raw(" "),
+        Span::styled(label, style),
+        Span::raw(" "),
+    ])
+}
+
 fn get_spinner(frame_count: u64) -> (char, Color) {
     let index = (frame_count / 8) as usize;
     let char_idx = index % SPINNER_CHARS.len();
@@ -50,15 +76,11 @@ fn format_position_indicator(scroll: &ScrollState, line_count: u32) -> String {
     }
     let start = scroll.offset as u32 + 1;
     let end = (scroll.offset as u32 + scroll.viewport_height as u32).min(line_count);
-    let percentage = if line_count > 0 {
-        (scroll.offset as u32 * 100) / line_count
-    } else {
-        0
-    };
+    let percentage = (scroll.offset as u32 * 100) / line_count;
     format!("L{}-{}/{} ({}%)", start, end, line_count, percentage)
 }
 
-fn format_execution_time(ms: u64) -> String {
+pub(crate) fn format_execution_time(ms: u64) -> String {
     if ms < 1000 {
         format!("{}ms", ms)
     } else {
@@ -66,7 +88,7 @@ fn format_execution_time(ms: u64) -> String {
     }
 }
 
-fn get_timing_color(ms: u64, border_color: Color) -> Color {
+pub(crate) fn get_timing_color(ms: u64, border_color: Color) -> Color {
     if ms < 200 {
         border_color
     } else if ms < 1000 {
@@ -127,12 +149,21 @@ pub fn render_pane(app: &mut App, frame: &mut Frame, area: Rect) -> (Rect, Optio
     let is_pending = query_state.is_pending();
     let stats_info = app.stats.display().unwrap_or_else(|| "Results".to_string());
 
+    if let Some(result_lines) = &query_state.last_successful_result_lines {
+        app.results_fold.sync(result_lines);
+    }
+
     // Calculate viewport dimensions and position indicator early for title
     let viewport_height = results_area.height.saturating_sub(2);
     let viewport_width = results_area.width.saturating_sub(2);
     let line_count = app.results_line_count_u32();
     app.results_scroll
         .update_bounds(line_count, viewport_height);
+    // Appended records are shown once the re-run query completes
+    if app.follow_scroll_pending && !is_pending {
+        app.results_scroll.jump_to_bottom();
+        app.follow_scroll_pending = false;
+    }
     if let Some(q) = &app.query {
         app.results_scroll
             .update_h_bounds(q.max_line_width(), viewport_width);
@@ -143,8 +174,26 @@ pub fn render_pane(app: &mut App, frame: &mut Frame, area: Rect) -> (Rect, Optio
     if let Some(q) = &app.query
         && let Some(widths) = &q.cached_line_widths
     {
-        app.results_cursor
-            .update_line_widths(std::sync::Arc::clone(widths));
+        let widths = if app.results_fold.is_folded() {
+            std::sync::Arc::new(
+                (0..line_count)
+                    .map(|row| {
+                        let line = app.results_fold.line_at(row);
+                        widths.get(line).copied().unwrap_or(0)
+                    })
+                    .collect(),
+            )
+        } else {
+            std::sync::Arc::clone(widths)
+        };
+        app.results_cursor.update_line_widths(widths);
+    }
+
+    // A resumed session's position is restored once its query completes
+    if !is_pending && let Some(scroll) = app.session_scroll_pending.take() {
+        app.results_scroll.offset = scroll.offset.min(app.results_scroll.max_offset);
+        app.results_scroll.h_offset = scroll.h_offset.min(app.results_scroll.max_h_offset);
+        app.results_cursor.move_to_line(scroll.cursor_line);
     }
 
     let position_indicator = format_position_indicator(&app.results_scroll, line_count);
@@ -162,11 +211,25 @@ pub fn render_pane(app: &mut App, frame: &mut Frame, area: Rect) -> (Rect, Optio
     };
 
     let (title, unfocused_border_color) = if query_state.result.is_err() {
-        // ERROR: Yellow text, yellow border (unfocused) - or search color when search visible
+        // ERROR: Yellow for syntax errors, red for the input failing the query
+        // (unfocused border) - or search color when search visible
+        let kind = error_kind(query_state);
+        let (badge, color, border) = match kind {
+            ErrorKind::Syntax => (
+                theme::results::BADGE_SYNTAX_ERROR,
+                theme::results::RESULT_WARNING,
+                theme::results::BORDER_WARNING,
+            ),
+            ErrorKind::Runtime | ErrorKind::Other => (
+                theme::results::BADGE_RUNTIME_ERROR,
+                theme::results::RESULT_ERROR,
+                theme::results::BORDER_ERROR,
+            ),
+        };
         let text_color = if search_visible {
             search_text_color
         } else {
-            theme::results::RESULT_WARNING
+            color
         };
         let mut spans = Vec::new();
         if is_pending {
@@ -177,17 +240,19 @@ pub fn render_pane(app: &mut App, frame: &mut Frame, area: Rect) -> (Rect, Optio
             ));
         }
         spans.push(Span::raw(" "));
-        spans.push(Span::styled(
-            "  ⚠ Syntax Error  ",
-            theme::results::BADGE_SYNTAX_ERROR,
-        ));
+        spans.push(Span::styled(format!("  ⚠ {}  ", kind.title()), badge));
         if !stats_info.is_empty() {
+            let shown = if query_state.shows_partial_output {
+                "Showing output before the error"
+            } else {
+                "Showing last successful result"
+            };
             spans.push(Span::styled(
-                format!(" {} | Showing last successful result ", stats_info),
+                format!(" {} | {} ", stats_info, shown),
                 Style::default().fg(text_color),
             ));
         }
-        (Line::from(spans), theme::results::BORDER_WARNING)
+        (Line::from(spans), border)
     } else if query_state.is_empty_result {
         // EMPTY: Gray text, gray border (unfocused) - or search color when search visible
         let text_color = if search_visible {
@@ -248,14 +313,26 @@ pub fn render_pane(app: &mut App, frame: &mut Frame, area: Rect) -> (Rect, Optio
     } else {
         unfocused_border_color
     };
-    let right_title: Option<Line<'_>> = if !position_indicator.is_empty() {
-        Some(Line::from(Span::styled(
+    let mut right_spans = Vec::new();
+    if app.redaction.enabled {
+        right_spans.push(Span::raw(" "));
+        right_spans.push(Span::styled("  Redacted  ", theme::results::BADGE_REDACTED));
+    }
+    if app.results_fold.is_folded() {
+        let badge = match app.results_fold.level() {
+            Some(level) => format!("  Fold {}  ", level),
+            None => "  Folded  ".to_string(),
+        };
+        right_spans.push(Span::raw(" "));
+        right_spans.push(Span::styled(badge, theme::results::BADGE_FOLDED));
+    }
+    if !position_indicator.is_empty() {
+        right_spans.push(Span::styled(
             format!(" {} ", position_indicator),
             Style::default().fg(right_title_color),
-        )))
-    } else {
-        None
-    };
+        ));
+    }
+    let right_title: Option<Line<'_>> = (!right_spans.is_empty()).then(|| Line::from(right_spans));
 
     // When search is confirmed (navigating), results pane is active (purple)
     // When search is not confirmed (editing), results pane is inactive (gray)
@@ -271,10 +348,12 @@ pub fn render_pane(app: &mut App, frame: &mut Frame, area: Rect) -> (Rect, Optio
         unfocused_border_color
     };
 
-    let is_stale = query_state.result.is_err() || query_state.is_empty_result;
+    // Output printed before a runtime error belongs to the current query
+    let is_stale = (query_state.result.is_err() && !query_state.shows_partial_output)
+        || query_state.is_empty_result;
 
-    // Always render from cached pre-rendered text
-    if let Some(rendered) = &query_state.last_successful_result_rendered {
+    // Always render from the last successful result
+    if let Some(result_lines) = &query_state.last_successful_result_lines {
         let mut block = Block::default()
             .borders(Borders::ALL)
             .border_type(BorderType::Rounded)
@@ -296,6 +375,9 @@ pub fn render_pane(app: &mut App, frame: &mut Frame, area: Rect) -> (Rect, Optio
                 Span::raw(" "),
             ]);
             block = block.title_bottom(match_count_badge.alignment(Alignment::Right));
+        } else if let Some(follower) = &app.follower {
+            let badge = build_follow_badge(follower.is_paused(), follower.pending_count());
+            block = block.title_bottom(badge.alignment(Alignment::Right));
         }
 
         // Add navigation hints when results pane is focused and search is not visible
@@ -314,22 +396,28 @@ pub fn render_pane(app: &mut App, frame: &mut Frame, area: Rect) -> (Rect, Optio
             block = block.title_bottom(timing_title.alignment(Alignment::Left));
         }
 
-        // Use cached pre-rendered text
-        // Optimization: Only clone visible viewport to avoid massive allocations
+        // Optimization: Only highlight the visible viewport
         let scroll_offset = app.results_scroll.offset as usize;
         let viewport_lines = viewport_height as usize;
 
-        // Slice to viewport range (with bounds checking)
-        let total_lines = rendered.lines.len();
-        let end_line = (scroll_offset + viewport_lines).min(total_lines);
-        let visible_lines = if scroll_offset < total_lines {
-            &rendered.lines[scroll_offset..end_line]
+        // Highlight only visible lines (50 lines instead of 100K+ for large files!),
+        // up to the right edge of the pane
+        let visible_width = app.results_scroll.h_offset as usize + results_area.width as usize;
+        let visible_range = scroll_offset..scroll_offset + viewport_lines;
+        let shown: Vec<usize> = visible_range
+            .clone()
+            .take_while(|&row| row < line_count as usize)
+            .map(|row| app.results_fold.line_at(row as u32))
+            .collect();
+        let visible_lines = if app.results_fold.is_folded() {
+            folded_lines(app, result_lines, &shown, visible_width)
+        } else if app.redaction.enabled {
+            app.redaction
+                .highlighted(result_lines, visible_range, visible_width)
         } else {
-            &[]
+            result_lines.highlighted(visible_range, visible_width)
         };
-
-        // Clone only visible lines (50 lines instead of 100K+ for large files!)
-        let viewport_text = Text::from(visible_lines.to_vec());
+        let viewport_text = Text::from(visible_lines);
 
         // Apply DIM effect for stale results
         let viewport_text = if is_stale {
@@ -340,12 +428,7 @@ pub fn render_pane(app: &mut App, frame: &mut Frame, area: Rect) -> (Rect, Optio
 
         // Apply search highlights only to visible viewport
         let final_text = if app.search.is_visible() && !app.search.matches().is_empty() {
-            apply_search_highlights(
-                viewport_text,
-                &app.search,
-                app.results_scroll.offset,
-                viewport_height,
-            )
+            apply_search_highlights(viewport_text, &app.search, &shown)
         } else {
             viewport_text
         };
@@ -365,6 +448,17 @@ pub fn render_pane(app: &mut App, frame: &mut Frame, area: Rect) -> (Rect, Optio
         frame.render_widget(content, results_area);
         render_scrollbar(frame, results_area, &app.results_scroll, line_count);
 
+        let top_line = shown.first().copied().unwrap_or(0);
+        if let Some(header) = app.sticky_header.header(result_lines, top_line) {
+            let inner = Rect {
+                x: results_area.x + 1,
+                y: results_area.y + 1,
+                width: results_area.width.saturating_sub(2),
+                height: viewport_height,
+            };
+            crate::results::sticky_header::render_sticky_header(frame, inner, header);
+        }
+
         if show_cursor {
             render_cursor_indicator(
                 frame,
@@ -463,29 +557,39 @@ pub fn render_error_overlay(app: &App, frame: &mut Frame, results_area: Rect) ->
         None => return None,
     };
 
-    if let Err(error) = &query_state.result {
+    if let Err(raw_error) = &query_state.result {
+        let error = match &query_state.parsed_error {
+            Some(parsed) if !parsed.message.is_empty() => parsed.display_text(),
+            _ => raw_error.clone(),
+        };
         let error_lines: Vec<&str> = error.lines().collect();
         let max_content_lines = 5;
-        let (display_error, truncated) = if error_lines.len() > max_content_lines {
+        let display_error = if error_lines.len() > max_content_lines {
             let truncated_lines = &error_lines[..max_content_lines];
             let mut display = truncated_lines.join("\n");
             display.push_str("\n... (error truncated)");
-            (display, true)
+            display
         } else {
-            (error.clone(), false)
+            error.clone()
         };
 
-        let content_lines = if truncated {
-            max_content_lines + 1
-        } else {
-            error_lines.len()
-        };
+        let overlay_with_margins = popup::inset_rect(results_area, 2, 0);
+        let mut lines: Vec<Line> = display_error.lines().map(Line::raw).collect();
+        let explanation = query_state
+            .parsed_error
+            .as_ref()
+            .and_then(|parsed| explain::explain(&parsed.message));
+        if let Some(explanation) = explanation {
+            // -2 for borders, -2 for left/right padding
+            let width = overlay_with_margins.width.saturating_sub(4) as usize;
+            lines.extend(explanation_lines(explanation, width));
+        }
+
         // +2 for borders, +2 for top/bottom padding
-        let overlay_height = (content_lines as u16 + 4).clamp(5, 9);
+        let overlay_height = (lines.len() as u16 + 4).clamp(5, 16);
 
         let overlay_y = results_area.bottom().saturating_sub(overlay_height + 1);
 
-        let overlay_with_margins = popup::inset_rect(results_area, 2, 0);
         let overlay_area = Rect {
             x: overlay_with_margins.x,
             y: overlay_y,
@@ -499,13 +603,13 @@ pub fn render_error_overlay(app: &App, frame: &mut Frame, results_area: Rect) ->
         let error_block = Block::default()
             .borders(Borders::ALL)
             .border_type(BorderType::Rounded)
-            .title(" Syntax Error ")
+            .title(format!(" {} ", error_kind(query_state).title()))
             .title_bottom(close_hint.alignment(Alignment::Center))
             .border_style(Style::default().fg(theme::results::BORDER_ERROR))
             .style(Style::default().bg(theme::results::BACKGROUND))
             .padding(Padding::new(1, 1, 1, 1));
 
-        let error_widget = Paragraph::new(display_error.as_str())
+        let error_widget = Paragraph::new(lines)
             .block(error_block)
             .style(Style::default().fg(theme::results::BORDER_ERROR));
 
@@ -515,6 +619,42 @@ pub fn render_error_overlay(app: &App, frame: &mut Frame, results_area: Rect) ->
     None
 }
 
+/// Kind of the current error; errors without a parsed form are shown as
+/// syntax errors
+fn error_kind(query_state: &QueryState) -> ErrorKind {
+    query_state
+        .parsed_error
+        .as_ref()
+        .map_or(ErrorKind::Syntax, |parsed| parsed.kind)
+}
+
+/// Lines explaining a jq error below its message, wrapped to `width`
+fn explanation_lines(explanation: &Explanation, width: usize) -> Vec<Line<'static>> {
+    let styled = |text: &str, color| {
+        wrap_text(text, width)
+            .into_iter()
+            .map(move |line| Line::styled(line, Style::default().fg(color)))
+    };
+    let mut lines = vec![Line::default()];
+    lines.extend(styled(explanation.summary, theme::results::EXPLANATION));
+    lines.extend(styled(
+        &format!("Fix: {}", explanation.fix),
+        theme::results::EXPLANATION_FIX,
+    ));
+    if let Some(url) = explanation.manual_url() {
+        let names = explanation.related_names().join(", ");
+        lines.extend(styled(
+            &format!("See: {names}"),
+            theme::results::EXPLANATION_LINK,
+        ));
+        lines.push(Line::styled(
+            url,
+            Style::default().fg(theme::results::EXPLANATION_LINK),
+        ));
+    }
+    lines
+}
+
 fn apply_dim_to_text(text: Text<'_>) -> Text<'static> {
     Text::from(
         text.lines
@@ -536,11 +676,48 @@ fn apply_dim_to_text(text: Text<'_>) -> Text<'static> {
     )
 }
 
+/// Lines of the result shown on `shown` rows, a closed object or array as
+/// its first line followed by its closing bracket and the lines it hides
+fn folded_lines(
+    app: &App,
+    result_lines: &ResultLines,
+    shown: &[usize],
+    max_width: usize,
+) -> Vec<Line<'static>> {
+    shown
+        .iter()
+        .map(|&index| {
+            let range = index..index + 1;
+            let highlighted = if app.redaction.enabled {
+                app.redaction.highlighted(result_lines, range, max_width)
+            } else {
+                result_lines.highlighted(range, max_width)
+            };
+            let mut line = highlighted.into_iter().next().unwrap_or_default();
+            if let Some(end) = app.results_fold.closed_end(index) {
+                let closing = result_lines.line(end).unwrap_or_default();
+                line.spans.push(Span::styled(
+                    "…",
+                    Style::default().fg(theme::results::FOLD_MARKER),
+                ));
+                line.spans
+                    .extend(JsonHighlighter::highlight_line(closing.trim_start(), max_width).spans);
+                line.spans.push(Span::styled(
+                    format!("  {} lines", end - index - 1),
+                    Style::default().fg(theme::results::FOLD_MARKER),
+                ));
+            }
+            line
+        })
+        .collect()
+}
+
+/// Highlight search matches, `shown` holding the result line of each line
+/// of `text`
 fn apply_search_highlights(
     text: Text<'_>,
     search_state: &crate::search::SearchState,
-    scroll_offset: u16,
-    viewport_height: u16,
+    shown: &[usize],
 ) -> Text<'static> {
     let matches = search_state.matches();
     let current_match_index = search_state.current_index();
@@ -561,14 +738,12 @@ fn apply_search_highlights(
         );
     }
 
-    let _ = viewport_height;
     let highlighted_lines: Vec<Line<'static>> = text
         .lines
         .into_iter()
         .enumerate()
         .map(|(line_idx, line)| {
-            // Adjust line_idx by scroll_offset to get absolute line number
-            let absolute_line = line_idx + scroll_offset as usize;
+            let absolute_line = shown.get(line_idx).copied().unwrap_or(line_idx);
             let line_matches: Vec<(usize, &Match)> =
                 search_state.matches_on_line(absolute_line as u32).collect();
 
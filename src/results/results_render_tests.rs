@@ -518,3 +518,100 @@ mod scrollbar_tests {
         );
     }
 }
+
+#[cfg(test)]
+mod long_line_tests {
+    use super::*;
+    use crate::test_utils::test_helpers::test_app;
+
+    fn long_value_app() -> App {
+        let value = format!("{}END", "x".repeat(20_000));
+        test_app(&format!(r#"{{"key": "{}"}}"#, value))
+    }
+
+    #[test]
+    fn test_long_line_is_cut_at_pane_edge() {
+        let mut app = long_value_app();
+        let output = render_to_string(&mut app, 80, 24);
+        assert!(output.contains(r#""key": "xxx"#));
+        assert!(!output.contains("END"));
+    }
+
+    #[test]
+    fn test_horizontal_scroll_shows_end_of_long_line() {
+        let mut app = long_value_app();
+        app.results_scroll.h_offset = 20_000 - 20;
+        let output = render_to_string(&mut app, 80, 24);
+        assert!(output.contains("xxxEND"));
+    }
+}
+
+#[cfg(test)]
+mod fold_tests {
+    use super::*;
+    use crate::app::Focus;
+    use crate::results::results_events::fold_to_depth;
+    use crate::test_utils::test_helpers::test_app;
+    use insta::assert_snapshot;
+
+    fn nested_app() -> App {
+        let mut app = test_app(
+            r#"{"name": "api", "spec": {"replicas": 3, "ports": [80, 443]}, "tags": ["a", "b"]}"#,
+        );
+        app.focus = Focus::ResultsPane;
+        app
+    }
+
+    #[test]
+    fn snapshot_results_folded_to_depth_one() {
+        let mut app = nested_app();
+        render_to_string(&mut app, 60, 12);
+        fold_to_depth(&mut app, Some(1));
+
+        assert_snapshot!(render_to_string(&mut app, 60, 12));
+    }
+
+    #[test]
+    fn test_unfold_shows_every_line() {
+        let mut app = nested_app();
+        render_to_string(&mut app, 60, 20);
+        fold_to_depth(&mut app, Some(0));
+        assert!(render_to_string(&mut app, 60, 20).contains("{…}  12 lines"));
+
+        fold_to_depth(&mut app, None);
+        let output = render_to_string(&mut app, 60, 20);
+        assert!(output.contains(r#""replicas": 3"#));
+        assert!(!output.contains("Folded"));
+    }
+}
+
+mod sticky_header_tests {
+    use super::*;
+    use crate::app::Focus;
+    use crate::test_utils::test_helpers::test_app;
+    use insta::assert_snapshot;
+
+    fn deep_app() -> App {
+        let mut app = test_app(
+            r#"{"items": [{"id": 1}, {"id": 2, "spec": {"replicas": 3, "ports": [80, 443, 8080, 8443]}}]}"#,
+        );
+        app.focus = Focus::ResultsPane;
+        app
+    }
+
+    #[test]
+    fn snapshot_sticky_header_when_scrolled() {
+        let mut app = deep_app();
+        render_to_string(&mut app, 60, 10);
+        app.results_scroll.offset = 9;
+
+        assert_snapshot!(render_to_string(&mut app, 60, 10));
+    }
+
+    #[test]
+    fn test_no_sticky_header_at_the_top() {
+        let mut app = deep_app();
+        let output = render_to_string(&mut app, 60, 10);
+        assert!(!output.contains(".items["));
+    }
+}
@@ -0,0 +1,250 @@
+//! Distribution sparkline for numeric results
+//!
+//! When the result is an array of numbers, or a stream of them such as
+//! `.[].price`, a line above the results pane shows how the values spread
+//! between the smallest and the largest as a row of block characters, with
+//! the count, minimum, mean and maximum next to it. Turned off with
+//! `sparkline = false` under `[display]`. Terminals with a graphics protocol
+//! get the row as an image, see [`sparkline_image`](super::sparkline_image).
+
+use ratatui::{
+    Frame,
+    buffer::Buffer,
+    layout::Rect,
+    style::Style,
+    text::{Line, Span},
+    widgets::Paragraph,
+};
+use serde_json::Value;
+
+use super::sparkline_image::{ChartImage, GraphicsProtocol, ImageUpdate};
+use crate::app::App;
+use crate::theme;
+
+/// Most numbers read from a stream of results
+const MAX_VALUES: usize = 100_000;
+
+/// Most bins drawn, however wide the terminal
+const MAX_BINS: usize = 48;
+
+const BLOCKS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// Numbers of the last result, when it is numeric
+#[derive(Debug, Default)]
+pub struct SparklineState {
+    enabled: bool,
+    /// Draw the sparkline as an image when the terminal can
+    images: bool,
+    /// Graphics protocol of the terminal, if it has one
+    protocol: Option<GraphicsProtocol>,
+    numbers: Option<Vec<f64>>,
+    /// Image the last frame left room for
+    wanted: Option<ChartImage>,
+    /// Image written to the terminal
+    shown: Option<ChartImage>,
+}
+
+impl SparklineState {
+    pub fn new(enabled: bool, images: bool) -> Self {
+        Self {
+            enabled,
+            images,
+            ..Self::default()
+        }
+    }
+
+    /// Turn the sparkline on or off; it shows from the next result
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+        if !enabled {
+            self.numbers = None;
+        }
+    }
+
+    /// Read the numbers of a new result; `first` is its already parsed first
+    /// value
+    pub fn update(&mut self, output: &str, first: Option<&Value>) {
+        if !self.enabled {
+            return;
+        }
+        self.numbers = numeric_values(output, first);
+    }
+
+    pub fn numbers(&self) -> Option<&[f64]> {
+        self.numbers.as_deref()
+    }
+
+    /// Draw images from the next frame on, with `sparkline_images`
+    pub fn set_images(&mut self, images: bool) {
+        self.images = images;
+    }
+
+    /// Graphics protocol of the terminal, as detected on startup
+    pub fn set_protocol(&mut self, protocol: Option<GraphicsProtocol>) {
+        self.protocol = protocol;
+    }
+
+    /// Protocol the sparkline is drawn with, None for block characters
+    pub fn protocol(&self) -> Option<GraphicsProtocol> {
+        self.protocol.filter(|_| self.images)
+    }
+
+    /// Record the image the frame in `buffer` left room for, unless
+    /// something was drawn over its cells after the sparkline
+    pub fn set_image(&mut self, image: Option<ChartImage>, buffer: &Buffer) {
+        self.wanted = image.filter(|image| image.is_uncovered(buffer));
+    }
+
+    /// Change to write to the terminal after the last frame, if the image it
+    /// wants is not the one on the screen
+    pub fn take_image_update(&mut self) -> Option<ImageUpdate> {
+        if self.wanted == self.shown {
+            return None;
+        }
+        let previous = std::mem::replace(&mut self.shown, self.wanted.clone());
+        Some(ImageUpdate {
+            previous,
+            next: self.wanted.clone(),
+        })
+    }
+
+    /// The screen was cleared, taking the image with it
+    pub fn forget_image(&mut self) {
+        self.shown = None;
+    }
+}
+
+/// Numbers of `output` when it is one array of numbers or a stream of
+/// numbers, at least two of them
+pub fn numeric_values(output: &str, first: Option<&Value>) -> Option<Vec<f64>> {
+    let numbers = match first {
+        Some(Value::Array(items)) => {
+            let numbers: Option<Vec<f64>> = items.iter().map(Value::as_f64).collect();
+            // A stream of arrays is not one distribution
+            let more = serde_json::Deserializer::from_str(output)
+                .into_iter::<serde::de::IgnoredAny>()
+                .nth(1)
+                .is_some();
+            numbers.filter(|_| !more)?
+        }
+        Some(Value::Number(_)) => {
+            let mut numbers = Vec::new();
+            for value in serde_json::Deserializer::from_str(output)
+                .into_iter::<Value>()
+                .take(MAX_VALUES)
+            {
+                numbers.push(value.ok()?.as_f64()?);
+            }
+            numbers
+        }
+        _ => return None,
+    };
+    (numbers.len() >= 2).then_some(numbers)
+}
+
+/// How many of `values` fall into each of `bins` equal ranges between the
+/// smallest and the largest
+pub fn bin_counts(values: &[f64], bins: usize) -> Vec<usize> {
+    let (min, max) = min_max(values);
+    if bins == 0 || min == max {
+        return vec![values.len()];
+    }
+    let mut counts = vec![0; bins];
+    let width = (max - min) / bins as f64;
+    for value in values {
+        let bin = (((value - min) / width) as usize).min(bins - 1);
+        counts[bin] += 1;
+    }
+    counts
+}
+
+/// One block per count, as tall as the count relative to the largest; empty
+/// bins are left blank
+pub fn blocks(counts: &[usize]) -> String {
+    let highest = counts.iter().copied().max().unwrap_or(0).max(1);
+    counts
+        .iter()
+        .map(|&count| {
+            if count == 0 {
+                ' '
+            } else {
+                let level = (count * BLOCKS.len()).div_ceil(highest);
+                BLOCKS[level.clamp(1, BLOCKS.len()) - 1]
+            }
+        })
+        .collect()
+}
+
+fn min_max(values: &[f64]) -> (f64, f64) {
+    values
+        .iter()
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), &value| {
+            (min.min(value), max.max(value))
+        })
+}
+
+/// Whole numbers without a fraction, others to two decimals
+fn format_number(value: f64) -> String {
+    if value.fract() == 0.0 && value.abs() < 1e15 {
+        format!("{}", value as i64)
+    } else {
+        let text = format!("{:.2}", value);
+        text.trim_end_matches('0').trim_end_matches('.').to_string()
+    }
+}
+
+/// Count, minimum, mean and maximum of `values`
+fn summary(values: &[f64]) -> String {
+    let (min, max) = min_max(values);
+    let mean = values.iter().sum::<f64>() / values.len() as f64;
+    format!(
+        "{} numbers  min {}  mean {}  max {}",
+        values.len(),
+        format_number(min),
+        format_number(mean),
+        format_number(max)
+    )
+}
+
+/// Draw the sparkline, returning the image to draw over its bins when the
+/// terminal has a graphics protocol
+pub fn render_sparkline(app: &App, frame: &mut Frame, area: Rect) -> Option<ChartImage> {
+    let numbers = app.sparkline.numbers()?;
+    let summary = summary(numbers);
+    let room = (area.width as usize).saturating_sub(summary.chars().count() + 3);
+    // About two values per bin, so that a few values do not leave it mostly empty
+    let bins = (numbers.len() / 2).clamp(2, MAX_BINS).min(room);
+    let counts = bin_counts(numbers, bins);
+
+    // The image goes over blanks left for it
+    let image = app
+        .sparkline
+        .protocol()
+        .filter(|_| !app.no_color)
+        .map(|protocol| ChartImage {
+            protocol,
+            area: Rect::new(area.x + 1, area.y, counts.len() as u16, 1).intersection(area),
+            counts: counts.clone(),
+            screen: frame.area(),
+        });
+    let chart = match &image {
+        Some(_) => " ".repeat(counts.len()),
+        None => blocks(&counts),
+    };
+
+    let line = Line::from(vec![
+        Span::raw(" "),
+        Span::styled(chart, Style::default().fg(theme::results::SPARKLINE)),
+        Span::raw("  "),
+        Span::styled(
+            summary,
+            Style::default().fg(theme::results::SPARKLINE_LABEL),
+        ),
+    ]);
+    frame.render_widget(Paragraph::new(line), area);
+    image
+}
+
+#[cfg(test)]
+#[path = "sparkline_tests.rs"]
+mod sparkline_tests;
@@ -0,0 +1,270 @@
+//! Sparkline Images
+//!
+//! Terminals speaking the kitty or iTerm2 graphics protocol get the numeric
+//! sparkline as an image, with bars of any height instead of eight block
+//! levels. The frame leaves the chart's cells blank and the image is written
+//! over them after the frame is drawn, and again only when the chart changes.
+//! Elsewhere, and with `sparkline_images = false`, the block characters stay.
+
+use std::io::{self, Write};
+
+use base64::{Engine as _, engine::general_purpose::STANDARD};
+use ratatui::buffer::Buffer;
+use ratatui::crossterm::cursor::{MoveTo, RestorePosition, SavePosition};
+use ratatui::crossterm::queue;
+use ratatui::layout::Rect;
+use ratatui::style::Color;
+
+use crate::theme;
+
+/// Pixels per bin, the image is scaled to one cell per bin
+const BIN_WIDTH: usize = 8;
+
+/// Pixels from the bottom of the row to the top of the highest bar
+const HEIGHT: usize = 32;
+
+/// Id of the chart image, so the next chart replaces it (kitty)
+const IMAGE_ID: u32 = 31_415;
+
+/// Most base64 bytes sent in one escape sequence (kitty)
+const CHUNK_LEN: usize = 4096;
+
+/// Below cells with a background color, so popups cover the chart (kitty)
+const Z_INDEX: i32 = -1_073_741_825;
+
+/// Graphics protocol of the terminal
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GraphicsProtocol {
+    Kitty,
+    Iterm2,
+}
+
+impl GraphicsProtocol {
+    /// Protocol of the terminal jiq runs in, from its environment variables
+    ///
+    /// None inside tmux and screen, which don't pass images on by default.
+    pub fn detect(env: impl Fn(&str) -> Option<String>) -> Option<Self> {
+        let term = env("TERM").unwrap_or_default();
+        if env("TMUX").is_some() || term.starts_with("screen") || term.starts_with("tmux") {
+            return None;
+        }
+        let program = env("TERM_PROGRAM").unwrap_or_default();
+        if env("KITTY_WINDOW_ID").is_some()
+            || term == "xterm-kitty"
+            || term == "xterm-ghostty"
+            || program == "ghostty"
+        {
+            Some(Self::Kitty)
+        } else if program == "iTerm.app"
+            || program == "WezTerm"
+            || env("LC_TERMINAL").as_deref() == Some("iTerm2")
+        {
+            Some(Self::Iterm2)
+        } else {
+            None
+        }
+    }
+}
+
+/// Chart image drawn over `area`, one cell per bin
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChartImage {
+    pub protocol: GraphicsProtocol,
+    pub area: Rect,
+    /// Values per bin, as for the block sparkline
+    pub counts: Vec<usize>,
+    /// Size of the screen, as resizing clears the image
+    pub screen: Rect,
+}
+
+impl ChartImage {
+    /// Whether the cells of the image still hold the blanks the sparkline
+    /// left for it, rather than something drawn over them
+    pub fn is_uncovered(&self, buffer: &Buffer) -> bool {
+        self.area.positions().all(|position| {
+            buffer.cell(position).is_some_and(|cell| {
+                cell.symbol() == " "
+                    && cell.fg == theme::results::SPARKLINE
+                    && cell.bg == Color::Reset
+            })
+        })
+    }
+
+    /// Escape sequence drawing the image at the cursor
+    fn escape(&self) -> String {
+        let (width, height, pixels) = pixels(&self.counts);
+        match self.protocol {
+            GraphicsProtocol::Kitty => {
+                let payload = STANDARD.encode(&pixels);
+                let chunks: Vec<&[u8]> = payload.as_bytes().chunks(CHUNK_LEN).collect();
+                let mut escape = String::new();
+                for (index, chunk) in chunks.iter().enumerate() {
+                    let more = u8::from(index + 1 < chunks.len());
+                    let chunk = std::str::from_utf8(chunk).unwrap_or_default();
+                    if index == 0 {
+                        escape.push_str(&format!(
+                            "\x1b_Ga=T,f=32,s={},v={},i={},c={},r={},z={},C=1,q=2,m={};{}\x1b\\",
+                            width,
+                            height,
+                            IMAGE_ID,
+                            self.area.width,
+                            self.area.height,
+                            Z_INDEX,
+                            more,
+                            chunk
+                        ));
+                    } else {
+                        escape.push_str(&format!("\x1b_Gm={};{}\x1b\\", more, chunk));
+                    }
+                }
+                escape
+            }
+            GraphicsProtocol::Iterm2 => {
+                let png = png(width, height, &pixels);
+                format!(
+                    "\x1b]1337;File=inline=1;size={};width={};height={};preserveAspectRatio=0:{}\x07",
+                    png.len(),
+                    self.area.width,
+                    self.area.height,
+                    STANDARD.encode(&png)
+                )
+            }
+        }
+    }
+}
+
+/// Change from the image on the screen to the one the last frame wants
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImageUpdate {
+    pub previous: Option<ChartImage>,
+    pub next: Option<ChartImage>,
+}
+
+impl ImageUpdate {
+    /// Whether the frame has to be drawn again first, to clear an iTerm2
+    /// image from the cells it was drawn into
+    pub fn needs_redraw(&self) -> bool {
+        self.previous
+            .as_ref()
+            .is_some_and(|image| image.protocol == GraphicsProtocol::Iterm2)
+    }
+
+    /// Remove the previous image and draw the next one, leaving the cursor
+    /// where it was
+    pub fn write(&self, out: &mut impl Write) -> io::Result<()> {
+        if let Some(previous) = &self.previous
+            && previous.protocol == GraphicsProtocol::Kitty
+        {
+            write!(out, "\x1b_Ga=d,d=I,i={},q=2\x1b\\", IMAGE_ID)?;
+        }
+        if let Some(next) = &self.next {
+            queue!(out, SavePosition, MoveTo(next.area.x, next.area.y))?;
+            out.write_all(next.escape().as_bytes())?;
+            queue!(out, RestorePosition)?;
+        }
+        out.flush()
+    }
+}
+
+/// Width, height and RGBA pixels of the bars for `counts` on a transparent
+/// background
+fn pixels(counts: &[usize]) -> (usize, usize, Vec<u8>) {
+    let width = counts.len() * BIN_WIDTH;
+    let mut pixels = vec![0; width * HEIGHT * 4];
+    let highest = counts.iter().copied().max().unwrap_or(0).max(1);
+    let color = match theme::results::SPARKLINE {
+        Color::Rgb(r, g, b) => [r, g, b, 255],
+        _ => [255, 255, 255, 255],
+    };
+    for (bin, &count) in counts.iter().enumerate() {
+        if count == 0 {
+            continue;
+        }
+        let bar = (count * HEIGHT).div_ceil(highest).max(1);
+        for y in HEIGHT - bar..HEIGHT {
+            // A pixel of space to the next bar
+            for x in bin * BIN_WIDTH..(bin + 1) * BIN_WIDTH - 1 {
+                let offset = (y * width + x) * 4;
+                pixels[offset..offset + 4].copy_from_slice(&color);
+            }
+        }
+    }
+    (width, HEIGHT, pixels)
+}
+
+/// RGBA pixels as a PNG, left uncompressed as charts take a few kilobytes
+fn png(width: usize, height: usize, pixels: &[u8]) -> Vec<u8> {
+    let mut scanlines = Vec::with_capacity(pixels.len() + height);
+    for row in pixels.chunks(width * 4) {
+        // No filter
+        scanlines.push(0);
+        scanlines.extend_from_slice(row);
+    }
+
+    let mut header = Vec::with_capacity(13);
+    header.extend_from_slice(&(width as u32).to_be_bytes());
+    header.extend_from_slice(&(height as u32).to_be_bytes());
+    // 8 bits per channel, RGBA, no interlacing
+    header.extend_from_slice(&[8, 6, 0, 0, 0]);
+
+    let mut png = b"\x89PNG\r\n\x1a\n".to_vec();
+    push_chunk(&mut png, b"IHDR", &header);
+    push_chunk(&mut png, b"IDAT", &zlib_stored(&scanlines));
+    push_chunk(&mut png, b"IEND", &[]);
+    png
+}
+
+fn push_chunk(png: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    png.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    let start = png.len();
+    png.extend_from_slice(kind);
+    png.extend_from_slice(data);
+    let crc = crc32(&png[start..]);
+    png.extend_from_slice(&crc.to_be_bytes());
+}
+
+/// `data` as a zlib stream of stored deflate blocks
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    let mut stream = vec![0x78, 0x01];
+    let mut blocks = data.chunks(u16::MAX as usize).peekable();
+    if blocks.peek().is_none() {
+        stream.extend_from_slice(&[1, 0, 0, 0xff, 0xff]);
+    }
+    while let Some(block) = blocks.next() {
+        stream.push(u8::from(blocks.peek().is_none()));
+        let len = block.len() as u16;
+        stream.extend_from_slice(&len.to_le_bytes());
+        stream.extend_from_slice(&(!len).to_le_bytes());
+        stream.extend_from_slice(block);
+    }
+    stream.extend_from_slice(&adler32(data).to_be_bytes());
+    stream
+}
+
+fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = u32::MAX;
+    for &byte in bytes {
+        crc ^= u32::from(byte);
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xEDB8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+fn adler32(bytes: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+    for &byte in bytes {
+        a = (a + u32::from(byte)) % 65_521;
+        b = (b + a) % 65_521;
+    }
+    (b << 16) | a
+}
+
+#[cfg(test)]
+#[path = "sparkline_image_tests.rs"]
+mod sparkline_image_tests;
@@ -0,0 +1,169 @@
+//! Tests for sparkline_image
+
+use super::*;
+
+fn env<'a>(vars: &'a [(&'a str, &'a str)]) -> impl Fn(&str) -> Option<String> + 'a {
+    move |name| {
+        vars.iter()
+            .find(|(key, _)| *key == name)
+            .map(|(_, value)| value.to_string())
+    }
+}
+
+fn image(protocol: GraphicsProtocol, counts: Vec<usize>) -> ChartImage {
+    ChartImage {
+        protocol,
+        area: Rect::new(3, 2, counts.len() as u16, 1),
+        counts,
+        screen: Rect::new(0, 0, 80, 24),
+    }
+}
+
+fn written(update: &ImageUpdate) -> String {
+    let mut out = Vec::new();
+    update.write(&mut out).unwrap();
+    String::from_utf8(out).unwrap()
+}
+
+#[test]
+fn test_detects_kitty() {
+    let detect = |vars| GraphicsProtocol::detect(env(vars));
+    assert_eq!(
+        detect(&[("TERM", "xterm-kitty")]),
+        Some(GraphicsProtocol::Kitty)
+    );
+    assert_eq!(
+        detect(&[("TERM", "xterm-256color"), ("KITTY_WINDOW_ID", "1")]),
+        Some(GraphicsProtocol::Kitty)
+    );
+    assert_eq!(
+        detect(&[("TERM_PROGRAM", "ghostty")]),
+        Some(GraphicsProtocol::Kitty)
+    );
+}
+
+#[test]
+fn test_detects_iterm2() {
+    let detect = |vars| GraphicsProtocol::detect(env(vars));
+    assert_eq!(
+        detect(&[("TERM_PROGRAM", "iTerm.app")]),
+        Some(GraphicsProtocol::Iterm2)
+    );
+    assert_eq!(
+        detect(&[("LC_TERMINAL", "iTerm2")]),
+        Some(GraphicsProtocol::Iterm2)
+    );
+    assert_eq!(
+        detect(&[("TERM_PROGRAM", "WezTerm")]),
+        Some(GraphicsProtocol::Iterm2)
+    );
+}
+
+#[test]
+fn test_no_protocol_elsewhere_or_in_multiplexers() {
+    let detect = |vars| GraphicsProtocol::detect(env(vars));
+    assert_eq!(detect(&[]), None);
+    assert_eq!(detect(&[("TERM", "xterm-256color")]), None);
+    assert_eq!(
+        detect(&[
+            ("TERM", "xterm-kitty"),
+            ("TMUX", "/tmp/tmux-1000/default,1,0")
+        ]),
+        None
+    );
+    assert_eq!(
+        detect(&[("TERM", "screen-256color"), ("TERM_PROGRAM", "iTerm.app")]),
+        None
+    );
+}
+
+#[test]
+fn test_bars_rise_from_the_bottom() {
+    let (width, height, pixels) = pixels(&[2, 0, 1]);
+    assert_eq!((width, height), (3 * BIN_WIDTH, HEIGHT));
+    let filled = |x: usize, y: usize| pixels[(y * width + x) * 4 + 3] == 255;
+
+    // Full height, then nothing, then half height
+    assert!(filled(0, 0) && filled(0, HEIGHT - 1));
+    assert!(!filled(BIN_WIDTH - 1, HEIGHT - 1));
+    assert!((0..HEIGHT).all(|y| !filled(BIN_WIDTH, y)));
+    assert!(!filled(2 * BIN_WIDTH, HEIGHT / 2 - 1));
+    assert!(filled(2 * BIN_WIDTH, HEIGHT / 2));
+}
+
+#[test]
+fn test_png_chunks_are_checksummed() {
+    let png = png(2, 1, &[255; 8]);
+    assert!(png.starts_with(b"\x89PNG\r\n\x1a\n"));
+    // IEND with its well-known CRC
+    assert!(png.ends_with(&[0, 0, 0, 0, b'I', b'E', b'N', b'D', 0xae, 0x42, 0x60, 0x82]));
+    assert_eq!(&png[12..16], b"IHDR");
+    assert_eq!(&png[16..24], &[0, 0, 0, 2, 0, 0, 0, 1]);
+}
+
+#[test]
+fn test_zlib_stream_of_stored_blocks() {
+    assert_eq!(adler32(b"Wikipedia"), 0x11e6_0398);
+    assert_eq!(crc32(b"123456789"), 0xcbf4_3926);
+
+    let stream = zlib_stored(b"abc");
+    assert_eq!(&stream[..2], &[0x78, 0x01]);
+    assert_eq!(&stream[2..7], &[1, 3, 0, 0xfc, 0xff]);
+    assert_eq!(&stream[7..10], b"abc");
+
+    let long = vec![7; u16::MAX as usize + 1];
+    let stream = zlib_stored(&long);
+    // A first block that is not the last, then the final byte
+    assert_eq!(stream[2], 0);
+    assert_eq!(stream[2 + 5 + u16::MAX as usize], 1);
+}
+
+#[test]
+fn test_kitty_image_is_placed_over_the_bins() {
+    let update = ImageUpdate {
+        previous: None,
+        next: Some(image(GraphicsProtocol::Kitty, vec![1, 2])),
+    };
+    let out = written(&update);
+
+    assert!(out.starts_with("\x1b7\x1b[3;4H\x1b_Ga=T,f=32,s=16,v=32,"));
+    assert!(out.contains(",c=2,r=1,"));
+    assert!(out.ends_with("\x1b\\\x1b8"));
+    assert!(!update.needs_redraw());
+}
+
+#[test]
+fn test_kitty_payload_is_sent_in_chunks() {
+    let escape = image(GraphicsProtocol::Kitty, vec![1; 40]).escape();
+    let chunks = escape.matches("\x1b_G").count();
+    assert!(chunks > 1);
+    assert_eq!(escape.matches("m=1;").count(), chunks - 1);
+    assert_eq!(escape.matches("m=0;").count(), 1);
+}
+
+#[test]
+fn test_kitty_image_is_deleted_when_replaced() {
+    let update = ImageUpdate {
+        previous: Some(image(GraphicsProtocol::Kitty, vec![1, 2])),
+        next: None,
+    };
+    assert_eq!(
+        written(&update),
+        format!("\x1b_Ga=d,d=I,i={},q=2\x1b\\", IMAGE_ID)
+    );
+}
+
+#[test]
+fn test_iterm2_image_is_an_inline_png() {
+    let update = ImageUpdate {
+        previous: Some(image(GraphicsProtocol::Iterm2, vec![1])),
+        next: Some(image(GraphicsProtocol::Iterm2, vec![1, 2])),
+    };
+    let out = written(&update);
+
+    assert!(out.contains("\x1b]1337;File=inline=1;size="));
+    assert!(out.contains(";width=2;height=1;preserveAspectRatio=0:iVBORw0KGgo"));
+    assert!(out.ends_with("\x07\x1b8"));
+    // Drawn into the cells, so the frame is drawn again to clear it
+    assert!(update.needs_redraw());
+}
@@ -0,0 +1,176 @@
+//! Tests for sparkline
+
+use super::*;
+use crate::test_utils::test_helpers::test_app;
+use insta::assert_snapshot;
+use ratatui::Terminal;
+use ratatui::backend::TestBackend;
+use serde_json::json;
+
+fn values(output: &str) -> Option<Vec<f64>> {
+    let first = serde_json::Deserializer::from_str(output)
+        .into_iter::<Value>()
+        .next()
+        .and_then(Result::ok);
+    numeric_values(output, first.as_ref())
+}
+
+#[test]
+fn test_disabling_drops_numbers() {
+    let first = json!([1, 2, 3]);
+    let mut state = SparklineState::new(true, true);
+    state.update("[1, 2, 3]", Some(&first));
+    assert!(state.numbers().is_some());
+
+    state.set_enabled(false);
+    assert!(state.numbers().is_none());
+    state.update("[1, 2, 3]", Some(&first));
+    assert!(state.numbers().is_none());
+}
+
+#[test]
+fn test_array_of_numbers() {
+    assert_eq!(values("[3, 1.5, -2]"), Some(vec![3.0, 1.5, -2.0]));
+}
+
+#[test]
+fn test_stream_of_numbers() {
+    assert_eq!(values("1\n2\n3\n"), Some(vec![1.0, 2.0, 3.0]));
+}
+
+#[test]
+fn test_non_numeric_results() {
+    assert_eq!(values(r#"[1, "2"]"#), None);
+    assert_eq!(values("1\n\"a\"\n"), None);
+    assert_eq!(values(r#"{"a": 1}"#), None);
+    assert_eq!(values("[1, 2]\n[3, 4]\n"), None);
+    assert_eq!(values("[]"), None);
+}
+
+#[test]
+fn test_single_number_is_not_a_distribution() {
+    assert_eq!(values("[1]"), None);
+    assert_eq!(values("42"), None);
+}
+
+#[test]
+fn test_bin_counts() {
+    assert_eq!(bin_counts(&[0.0, 1.0, 2.0, 9.0, 10.0], 5), [2, 1, 0, 0, 2]);
+    assert_eq!(bin_counts(&[4.0, 4.0, 4.0], 5), [3]);
+}
+
+#[test]
+fn test_blocks_scale_to_largest_bin() {
+    assert_eq!(blocks(&[8, 4, 1, 0]), "█▄▁ ");
+    assert_eq!(blocks(&[2]), "█");
+}
+
+#[test]
+fn test_format_number() {
+    assert_eq!(format_number(3.0), "3");
+    assert_eq!(format_number(-2.5), "-2.5");
+    assert_eq!(format_number(1.0 / 3.0), "0.33");
+}
+
+#[test]
+fn test_disabled_state_keeps_no_numbers() {
+    let mut state = SparklineState::new(false, true);
+    state.update("[1, 2]", Some(&json!([1, 2])));
+    assert!(state.numbers().is_none());
+
+    let mut state = SparklineState::new(true, true);
+    state.update("[1, 2]", Some(&json!([1, 2])));
+    assert_eq!(state.numbers(), Some(&[1.0, 2.0][..]));
+}
+
+#[test]
+fn snapshot_sparkline() {
+    let mut app = test_app("[1, 2, 2, 3, 3, 3, 4, 4, 5, 10]");
+    app.update_stats();
+
+    let (terminal, _) = render_with(&app);
+
+    assert_snapshot!(terminal.backend().to_string());
+}
+
+fn render_with(app: &App) -> (Terminal<TestBackend>, Option<ChartImage>) {
+    let mut terminal = Terminal::new(TestBackend::new(70, 1)).unwrap();
+    let mut image = None;
+    terminal
+        .draw(|f| image = render_sparkline(app, f, f.area()))
+        .unwrap();
+    (terminal, image)
+}
+
+#[test]
+fn test_graphics_protocol_leaves_blanks_for_the_image() {
+    let mut app = test_app("[1, 2, 2, 3, 3, 3, 4, 4, 5, 10]");
+    app.sparkline.set_protocol(Some(GraphicsProtocol::Kitty));
+    app.update_stats();
+
+    let (terminal, image) = render_with(&app);
+    let image = image.unwrap();
+    assert_eq!(image.protocol, GraphicsProtocol::Kitty);
+    assert_eq!(image.area, Rect::new(1, 0, 5, 1));
+    assert_eq!(image.counts, vec![3, 5, 1, 0, 1]);
+    let buffer = terminal.backend().buffer();
+    assert!(image.is_uncovered(buffer));
+    let row: String = (0..18).map(|x| buffer[(x, 0)].symbol()).collect();
+    assert_eq!(row, "        10 numbers");
+}
+
+#[test]
+fn test_blocks_without_protocol_or_images() {
+    let mut app = test_app("[1, 2, 3]");
+    app.update_stats();
+    assert!(render_with(&app).1.is_none());
+
+    app.sparkline.set_protocol(Some(GraphicsProtocol::Iterm2));
+    app.sparkline.set_images(false);
+    assert!(render_with(&app).1.is_none());
+
+    app.sparkline.set_images(true);
+    app.no_color = true;
+    assert!(render_with(&app).1.is_none());
+}
+
+#[test]
+fn test_image_update_only_when_the_chart_changes() {
+    let mut app = test_app("[1, 2, 2, 3]");
+    app.sparkline.set_protocol(Some(GraphicsProtocol::Kitty));
+    app.update_stats();
+    let (terminal, image) = render_with(&app);
+    let buffer = terminal.backend().buffer().clone();
+
+    let mut state = SparklineState::new(true, true);
+    state.set_image(image.clone(), &buffer);
+    let update = state.take_image_update().unwrap();
+    assert_eq!(update.previous, None);
+    assert_eq!(update.next, image);
+
+    state.set_image(image.clone(), &buffer);
+    assert_eq!(state.take_image_update(), None);
+
+    state.forget_image();
+    state.set_image(image.clone(), &buffer);
+    assert!(state.take_image_update().is_some());
+
+    state.set_image(None, &buffer);
+    let update = state.take_image_update().unwrap();
+    assert_eq!(update.previous, image);
+    assert_eq!(update.next, None);
+}
+
+#[test]
+fn test_covered_image_is_not_drawn() {
+    let mut app = test_app("[1, 2, 2, 3]");
+    app.sparkline.set_protocol(Some(GraphicsProtocol::Kitty));
+    app.update_stats();
+    let (terminal, image) = render_with(&app);
+    let mut buffer = terminal.backend().buffer().clone();
+    buffer[(2, 0)].set_symbol("│");
+
+    let mut state = SparklineState::new(true, true);
+    state.set_image(image, &buffer);
+    assert_eq!(state.take_image_update(), None);
+}
@@ -0,0 +1,200 @@
+//! Sticky context header in the results pane
+//!
+//! Once the line opening the object or array around the top of the pane has
+//! scrolled out of view, the first row of the pane shows the path of that
+//! container, e.g. `.items[42].spec`, like sticky scroll in an IDE. For a
+//! stream of outputs the path starts with the output's number. Turned off
+//! with `sticky_header = false` under `[display]`.
+
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::Style,
+    text::{Line, Span},
+    widgets::Paragraph,
+};
+
+use super::line_path::split_member;
+use crate::query::language::jq_field;
+use crate::query::result_lines::ResultLines;
+use crate::theme;
+use crate::widgets::text_width::{display_width, truncate_to_width};
+
+/// Line without an enclosing container
+const NONE: u32 = u32::MAX;
+
+/// Enclosing container of every line of a result, found in one pass
+///
+/// jq pretty-prints one member or element per line, so the container of a
+/// line is the last line above it opening an object or array that is still
+/// open. A closing bracket belongs to the container it closes.
+#[derive(Debug)]
+struct ContainerIndex {
+    /// Result the index was built for
+    lines: ResultLines,
+    /// Line opening the container around each line, or [`NONE`]
+    parents: Vec<u32>,
+    /// Position of each line among its container's members, or among the
+    /// outputs for a top-level line
+    positions: Vec<u32>,
+    outputs: u32,
+}
+
+impl ContainerIndex {
+    fn new(lines: &ResultLines) -> Self {
+        let mut parents = Vec::with_capacity(lines.len());
+        let mut positions = Vec::with_capacity(lines.len());
+        // Opening line and members seen so far of each open container
+        let mut open: Vec<(u32, u32)> = Vec::new();
+        let mut outputs = 0;
+
+        for index in 0..lines.len() {
+            let line = lines.line(index).unwrap_or_default();
+            let line = line.trim();
+            if line.starts_with(['}', ']'])
+                && let Some((start, _)) = open.pop()
+            {
+                parents.push(start);
+                positions.push(0);
+                continue;
+            }
+
+            let (parent, position) = match open.last_mut() {
+                Some((start, members)) => {
+                    *members += 1;
+                    (*start, *members - 1)
+                }
+                None if line.is_empty() => (NONE, 0),
+                None => {
+                    outputs += 1;
+                    (NONE, outputs - 1)
+                }
+            };
+            parents.push(parent);
+            positions.push(position);
+            if line.ends_with(['{', '[']) {
+                open.push((index as u32, 0));
+            }
+        }
+
+        Self {
+            lines: lines.clone(),
+            parents,
+            positions,
+            outputs,
+        }
+    }
+
+    /// Path of the container around `line`, None for a top-level line and
+    /// for the members of a single top-level value
+    fn context_of(&self, lines: &ResultLines, line: usize) -> Option<String> {
+        let mut container = *self.parents.get(line)?;
+        if container == NONE {
+            return None;
+        }
+
+        let mut segments = Vec::new();
+        let output = loop {
+            let parent = self.parents[container as usize];
+            if parent == NONE {
+                break self.positions[container as usize];
+            }
+            let opener = lines.line(parent as usize).unwrap_or_default();
+            let segment = if opener.trim_end().ends_with('[') {
+                format!("[{}]", self.positions[container as usize])
+            } else {
+                let member = lines.line(container as usize).unwrap_or_default();
+                jq_field(&split_member(member.trim())?.0)
+            };
+            segments.push(segment);
+            container = parent;
+        };
+
+        let path: String = segments.into_iter().rev().collect();
+        let path = match path.as_str() {
+            p if p.starts_with('[') => format!(".{}", p),
+            _ => path,
+        };
+        match (self.outputs, path.is_empty()) {
+            (0 | 1, true) => None,
+            (0 | 1, false) => Some(path),
+            (_, true) => Some(format!("#{}", output + 1)),
+            (_, false) => Some(format!("#{} {}", output + 1, path)),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct StickyHeaderState {
+    enabled: bool,
+    index: Option<ContainerIndex>,
+    /// Header last worked out, for the line it was asked for
+    cached: Option<(usize, Option<String>)>,
+}
+
+impl StickyHeaderState {
+    pub fn new(enabled: bool) -> Self {
+        Self {
+            enabled,
+            index: None,
+            cached: None,
+        }
+    }
+
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+        self.cached = None;
+    }
+
+    /// Header for the pane when `top_line` of the result is its first row,
+    /// None while the container around it is still in view
+    pub fn header(&mut self, lines: &ResultLines, top_line: usize) -> Option<&str> {
+        if !self.enabled || top_line == 0 {
+            return None;
+        }
+        let stale = self
+            .index
+            .as_ref()
+            .is_none_or(|index| !index.lines.ptr_eq(lines));
+        if stale {
+            self.index = Some(ContainerIndex::new(lines));
+            self.cached = None;
+        }
+        if self
+            .cached
+            .as_ref()
+            .is_none_or(|(line, _)| *line != top_line)
+        {
+            let header = self
+                .index
+                .as_ref()
+                .and_then(|index| index.context_of(lines, top_line));
+            self.cached = Some((top_line, header));
+        }
+        self.cached
+            .as_ref()
+            .and_then(|(_, header)| header.as_deref())
+    }
+}
+
+/// Draw `header` over the first row of the pane's content `area`
+pub fn render_sticky_header(frame: &mut Frame, area: Rect, header: &str) {
+    if area.width < 4 || area.height < 2 {
+        return;
+    }
+    let row = Rect { height: 1, ..area };
+    let width = row.width as usize;
+    let text = truncate_to_width(header, width.saturating_sub(2));
+    let padding = width.saturating_sub(display_width(&text) + 1);
+    let line = Line::from(vec![
+        Span::raw(" "),
+        Span::styled(text, Style::default().fg(theme::results::STICKY_HEADER)),
+        Span::raw(" ".repeat(padding)),
+    ])
+    .style(Style::default().bg(theme::results::STICKY_HEADER_BG));
+    frame.render_widget(Paragraph::new(line), row);
+}
+
+#[cfg(test)]
+#[path = "sticky_header_tests.rs"]
+mod sticky_header_tests;
@@ -0,0 +1,88 @@
+//! Tests for sticky_header
+
+use std::sync::Arc;
+
+use super::*;
+
+const DOCUMENT: &str = r#"{
+  "name": "api",
+  "items": [
+    {
+      "id": 1
+    },
+    {
+      "spec": {
+        "app name": "web",
+        "ports": [
+          80
+        ]
+      }
+    }
+  ]
+}"#;
+
+fn lines(text: &str) -> ResultLines {
+    ResultLines::new(Arc::new(text.to_string()))
+}
+
+fn header_at(text: &str, line: usize) -> Option<String> {
+    StickyHeaderState::new(true)
+        .header(&lines(text), line)
+        .map(str::to_string)
+}
+
+#[test]
+fn test_members_of_the_top_level_value_have_no_header() {
+    assert_eq!(header_at(DOCUMENT, 1), None);
+    assert_eq!(header_at(DOCUMENT, 2), None);
+    assert_eq!(header_at(DOCUMENT, 15), None);
+}
+
+#[test]
+fn test_header_is_the_path_of_the_enclosing_container() {
+    assert_eq!(header_at(DOCUMENT, 3).as_deref(), Some(".items"));
+    assert_eq!(header_at(DOCUMENT, 4).as_deref(), Some(".items[0]"));
+    assert_eq!(header_at(DOCUMENT, 8).as_deref(), Some(".items[1].spec"));
+    assert_eq!(
+        header_at(DOCUMENT, 10).as_deref(),
+        Some(".items[1].spec.ports")
+    );
+}
+
+#[test]
+fn test_closing_bracket_belongs_to_the_container_it_closes() {
+    assert_eq!(header_at(DOCUMENT, 5).as_deref(), Some(".items[0]"));
+    assert_eq!(header_at(DOCUMENT, 14).as_deref(), Some(".items"));
+}
+
+#[test]
+fn test_top_level_array_elements_are_indexed() {
+    let text = "[\n  {\n    \"id\": 1\n  },\n  {\n    \"id\": 2\n  }\n]";
+    assert_eq!(header_at(text, 5).as_deref(), Some(".[1]"));
+}
+
+#[test]
+fn test_stream_headers_start_with_the_output_number() {
+    let text = "{\n  \"a\": 1\n}\n{\n  \"b\": {\n    \"c\": 2\n  }\n}";
+    assert_eq!(header_at(text, 1).as_deref(), Some("#1"));
+    assert_eq!(header_at(text, 4).as_deref(), Some("#2"));
+    assert_eq!(header_at(text, 5).as_deref(), Some("#2 .b"));
+}
+
+#[test]
+fn test_first_line_and_disabled_state_have_no_header() {
+    assert_eq!(header_at(DOCUMENT, 0), None);
+    assert_eq!(
+        StickyHeaderState::new(false).header(&lines(DOCUMENT), 8),
+        None
+    );
+}
+
+#[test]
+fn test_new_result_rebuilds_the_index() {
+    let mut state = StickyHeaderState::new(true);
+    assert_eq!(state.header(&lines(DOCUMENT), 8), Some(".items[1].spec"));
+
+    let other = "{\n  \"tags\": [\n    \"a\"\n  ]\n}";
+    assert_eq!(state.header(&lines(other), 2), Some(".tags"));
+}
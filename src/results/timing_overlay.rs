@@ -0,0 +1,117 @@
+//! Timing overlay
+//!
+//! Small box in the top-right corner of the results pane listing how long
+//! the latest queries took. With the pipeline view open it also names the
+//! stage adding the most time.
+
+use ratatui::{
+    Frame,
+    layout::{Alignment, Rect},
+    style::Style,
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Paragraph},
+};
+
+use super::results_render::{format_execution_time, get_timing_color};
+use crate::app::App;
+use crate::theme;
+use crate::widgets::popup;
+use crate::widgets::text_width::truncate_to_width;
+
+const OVERLAY_WIDTH: u16 = 48;
+/// Width of the time column, e.g. `  12.5s `
+const TIME_WIDTH: usize = 8;
+
+fn build_hints() -> Line<'static> {
+    theme::border_hints::build_hints(&[("Alt+T", "Close")], theme::timing::BORDER)
+}
+
+fn time_span(ms: u64) -> Span<'static> {
+    Span::styled(
+        format!(
+            "{:>width$} ",
+            format_execution_time(ms),
+            width = TIME_WIDTH - 1
+        ),
+        Style::default().fg(get_timing_color(ms, theme::timing::TIME)),
+    )
+}
+
+fn timing_lines(app: &App, text_width: usize) -> Vec<Line<'static>> {
+    let label = Style::default().fg(theme::timing::LABEL);
+
+    let mut lines: Vec<Line> = match &app.query {
+        Some(query_state) if query_state.recent_timings().next().is_some() => query_state
+            .recent_timings()
+            .map(|timing| {
+                Line::from(vec![
+                    time_span(timing.elapsed_ms),
+                    Span::styled(
+                        truncate_to_width(&timing.query, text_width),
+                        Style::default().fg(theme::timing::QUERY),
+                    ),
+                ])
+            })
+            .collect(),
+        _ => vec![Line::from(Span::styled(" No queries run yet", label))],
+    };
+
+    if app.pipeline.is_visible() {
+        lines.push(Line::default());
+        let slowest = match app.pipeline.slowest_stage() {
+            Some((index, ms)) => {
+                let stage = &app.pipeline.stages()[index];
+                Line::from(vec![
+                    Span::styled(" Slowest stage ", label),
+                    Span::styled(
+                        format!("+{} ", format_execution_time(ms)),
+                        Style::default().fg(theme::timing::SLOWEST),
+                    ),
+                    Span::styled(
+                        truncate_to_width(
+                            &format!("{}. {}", index + 1, stage.text),
+                            text_width.saturating_sub(8),
+                        ),
+                        Style::default().fg(theme::timing::QUERY),
+                    ),
+                ])
+            }
+            None => Line::from(Span::styled(" Slowest stage: measuring…", label)),
+        };
+        lines.push(slowest);
+    }
+    lines
+}
+
+/// Render the timing overlay in the top-right corner of the results pane
+pub fn render_overlay(app: &App, frame: &mut Frame, results_area: Rect) -> Option<Rect> {
+    let width = OVERLAY_WIDTH.min(results_area.width);
+    let text_width = (width as usize).saturating_sub(2 + TIME_WIDTH);
+    let lines = timing_lines(app, text_width);
+    let height = (lines.len() as u16 + 2).min(results_area.height);
+    if width < 3 || height < 3 {
+        return None;
+    }
+
+    let area = Rect {
+        x: results_area.right() - width,
+        y: results_area.y,
+        width,
+        height,
+    };
+    popup::clear_area(frame, area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(" Timings ")
+        .title_bottom(build_hints().alignment(Alignment::Center))
+        .border_style(Style::default().fg(theme::timing::BORDER))
+        .style(Style::default().bg(theme::timing::BACKGROUND));
+    frame.render_widget(Paragraph::new(lines).block(block), area);
+    Some(area)
+}
+
+#[cfg(test)]
+#[path = "timing_overlay_tests.rs"]
+mod timing_overlay_tests;
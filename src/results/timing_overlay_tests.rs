@@ -0,0 +1,59 @@
+//! Tests for the timing overlay
+
+use super::render_overlay;
+use crate::app::App;
+use crate::test_utils::test_helpers::app_with_query;
+use insta::assert_snapshot;
+use ratatui::Terminal;
+use ratatui::backend::TestBackend;
+
+fn render(app: &App, width: u16, height: u16) -> String {
+    let backend = TestBackend::new(width, height);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal
+        .draw(|f| {
+            render_overlay(app, f, f.area());
+        })
+        .unwrap();
+    terminal.backend().to_string()
+}
+
+#[test]
+fn snapshot_timing_overlay_empty() {
+    let app = app_with_query(".");
+    assert_snapshot!(render(&app, 60, 6));
+}
+
+#[test]
+fn snapshot_timing_overlay_recent_timings() {
+    let mut app = app_with_query(".");
+    if let Some(query_state) = &mut app.query {
+        query_state.record_timing(".items[] | select(.price > 10)".to_string(), 12);
+        query_state.record_timing(
+            ".items | group_by(.category) | map(length)".to_string(),
+            640,
+        );
+        query_state.record_timing(".items | sort_by(.name) | .[] | .tags".to_string(), 2400);
+    }
+    assert_snapshot!(render(&app, 60, 8));
+}
+
+#[test]
+fn snapshot_timing_overlay_pipeline_measuring() {
+    let mut app = app_with_query(".");
+    app.pipeline.open(".items | .[] | .name");
+    if let Some(query_state) = &mut app.query {
+        query_state.record_timing(".items | .[] | .name".to_string(), 35);
+    }
+    assert_snapshot!(render(&app, 60, 8));
+}
+
+#[test]
+fn test_overlay_skipped_when_area_too_small() {
+    let app = app_with_query(".");
+    let backend = TestBackend::new(2, 2);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal
+        .draw(|f| assert!(render_overlay(&app, f, f.area()).is_none()))
+        .unwrap();
+}
@@ -0,0 +1,20 @@
+//! JSON Schema Validation
+//!
+//! `--schema FILE` loads a JSON Schema and `:validate` checks the current
+//! result against it, listing every violation with the path of the value
+//! that failed. Helps find out why a payload is rejected downstream.
+//!
+//! `:schema [FILE]` goes the other way, inferring a schema from the result
+//! to start one from.
+
+mod schema_events;
+mod schema_inference;
+mod schema_render;
+mod schema_state;
+mod schema_validator;
+
+pub use schema_events::{export_inferred_schema, handle_schema_key, validate_result};
+pub use schema_inference::infer_schema;
+pub use schema_render::render_schema_popup;
+pub use schema_state::SchemaState;
+pub use schema_validator::{SchemaValidator, Violation};
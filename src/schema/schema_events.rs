@@ -0,0 +1,104 @@
+use std::fs;
+use std::path::Path;
+
+use ratatui::crossterm::event::{KeyCode, KeyEvent};
+
+use super::{SchemaValidator, infer_schema};
+use crate::app::App;
+use crate::clipboard::backend::copy_to_clipboard;
+use crate::scroll::handle_scroll_key;
+
+/// Validate the last successful result, for `:validate [FILE]`
+///
+/// A schema file given here replaces the one from `--schema`. A result
+/// without violations is reported in a notification, otherwise the
+/// violations are listed in a popup.
+pub fn validate_result(app: &mut App, schema_path: Option<&str>) {
+    if let Some(path) = schema_path {
+        match SchemaValidator::load(Path::new(path)) {
+            Ok(validator) => app.schema.validator = Some(validator),
+            Err(e) => {
+                app.notification.show_error(&e);
+                return;
+            }
+        }
+    }
+
+    let Some(validator) = &app.schema.validator else {
+        app.notification
+            .show_error("No schema loaded: use :validate FILE or start jiq with --schema FILE");
+        return;
+    };
+    let Some(results) = app
+        .query
+        .as_ref()
+        .and_then(|q| q.last_successful_result_text())
+    else {
+        app.notification.show_error("Nothing to validate yet");
+        return;
+    };
+
+    match validator.validate(&results) {
+        Ok(violations) if violations.is_empty() => {
+            let message = format!("Result matches {}", validator.name());
+            app.notification.show(&message);
+        }
+        Ok(violations) => app.schema.open(violations),
+        Err(e) => app.notification.show_error(&e),
+    }
+}
+
+/// Infer a schema from the last successful result, for `:schema [FILE]`
+///
+/// The schema is written to `file` when given, otherwise copied to the
+/// clipboard.
+pub fn export_inferred_schema(app: &mut App, file: Option<&str>) {
+    let Some(results) = app
+        .query
+        .as_ref()
+        .and_then(|q| q.last_successful_result_text())
+    else {
+        app.notification
+            .show_error("Nothing to infer a schema from yet");
+        return;
+    };
+    let schema = match infer_schema(&results) {
+        Ok(schema) => schema,
+        Err(e) => {
+            app.notification.show_error(&e);
+            return;
+        }
+    };
+    let text = serde_json::to_string_pretty(&schema).unwrap_or_default() + "\n";
+
+    match file {
+        Some(file) => match fs::write(file, text) {
+            Ok(()) => app
+                .notification
+                .show(&format!("Schema written to {}", file)),
+            Err(e) => app
+                .notification
+                .show_error(&format!("Cannot write schema to {}: {}", file, e)),
+        },
+        None => {
+            if copy_to_clipboard(&text, app.clipboard_backend).is_ok() {
+                app.notification.show("Copied inferred schema");
+            } else {
+                app.notification.show_error("Cannot copy schema");
+            }
+        }
+    }
+}
+
+pub fn handle_schema_key(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('q') => app.schema.close(),
+        _ => {
+            handle_scroll_key(&mut app.schema.scroll, key);
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "schema_events_tests.rs"]
+mod schema_events_tests;
@@ -0,0 +1,147 @@
+//! Tests for schema_events
+
+use std::fs;
+
+use super::*;
+use crate::test_utils::test_helpers::{TEST_JSON, app_with_result, key};
+use tempfile::TempDir;
+
+fn write_schema(dir: &TempDir, schema: &str) -> String {
+    let path = dir.path().join("schema.json");
+    fs::write(&path, schema).unwrap();
+    path.to_string_lossy().into_owned()
+}
+
+#[test]
+fn test_validate_without_schema_is_an_error() {
+    let mut app = app_with_result(TEST_JSON, ".");
+    validate_result(&mut app, None);
+    assert!(
+        app.notification
+            .current_message()
+            .unwrap()
+            .starts_with("No schema loaded")
+    );
+    assert!(!app.schema.is_visible());
+}
+
+#[test]
+fn test_matching_result_is_reported() {
+    let dir = TempDir::new().unwrap();
+    let schema = write_schema(&dir, r#"{"type": "object"}"#);
+    let mut app = app_with_result(TEST_JSON, ".");
+
+    validate_result(&mut app, Some(&schema));
+
+    assert_eq!(
+        app.notification.current_message(),
+        Some("Result matches schema.json")
+    );
+    assert!(!app.schema.is_visible());
+    assert!(app.schema.validator.is_some());
+}
+
+#[test]
+fn test_violations_open_popup() {
+    let dir = TempDir::new().unwrap();
+    let schema = write_schema(&dir, r#"{"type": "object", "required": ["nope"]}"#);
+    let mut app = app_with_result(TEST_JSON, ".");
+
+    validate_result(&mut app, Some(&schema));
+
+    assert!(app.schema.is_visible());
+    assert_eq!(app.schema.violations().len(), 1);
+
+    // The loaded schema is kept for the next :validate
+    app.schema.close();
+    validate_result(&mut app, None);
+    assert!(app.schema.is_visible());
+}
+
+#[test]
+fn test_bad_schema_file_keeps_previous_schema() {
+    let dir = TempDir::new().unwrap();
+    let schema = write_schema(&dir, r#"{"type": "object"}"#);
+    let mut app = app_with_result(TEST_JSON, ".");
+    validate_result(&mut app, Some(&schema));
+
+    let missing = dir.path().join("missing.json");
+    validate_result(&mut app, Some(&missing.to_string_lossy()));
+
+    assert!(
+        app.notification
+            .current_message()
+            .unwrap()
+            .starts_with("Cannot read schema")
+    );
+    assert_eq!(app.schema.validator.as_ref().unwrap().name(), "schema.json");
+}
+
+#[test]
+fn test_keys_scroll_and_close() {
+    let mut app = app_with_result(TEST_JSON, ".");
+    app.schema.open(Vec::new());
+    app.schema.scroll.update_bounds(50, 10);
+
+    handle_schema_key(&mut app, key(KeyCode::Char('j')));
+    assert_eq!(app.schema.scroll.offset, 1);
+    handle_schema_key(&mut app, key(KeyCode::Char('G')));
+    assert_eq!(app.schema.scroll.offset, 40);
+    handle_schema_key(&mut app, key(KeyCode::Esc));
+    assert!(!app.schema.is_visible());
+}
+
+#[test]
+fn test_inferred_schema_is_written_to_file() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("inferred.json");
+    let mut app = app_with_result(TEST_JSON, ".");
+
+    export_inferred_schema(&mut app, Some(&path.to_string_lossy()));
+
+    let schema: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+    assert_eq!(schema["type"], "object");
+    assert!(
+        app.notification
+            .current_message()
+            .unwrap()
+            .starts_with("Schema written to")
+    );
+
+    // The written schema accepts the result it came from
+    validate_result(&mut app, Some(&path.to_string_lossy()));
+    assert_eq!(
+        app.notification.current_message(),
+        Some("Result matches inferred.json")
+    );
+}
+
+#[test]
+fn test_inferred_schema_is_copied() {
+    let mut app = app_with_result(TEST_JSON, ".");
+    app.clipboard_backend = crate::config::ClipboardBackend::Osc52;
+
+    export_inferred_schema(&mut app, None);
+
+    assert_eq!(
+        app.notification.current_message(),
+        Some("Copied inferred schema")
+    );
+}
+
+#[test]
+fn test_unwritable_schema_file_is_reported() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("missing").join("inferred.json");
+    let mut app = app_with_result(TEST_JSON, ".");
+
+    export_inferred_schema(&mut app, Some(&path.to_string_lossy()));
+
+    assert!(
+        app.notification
+            .current_message()
+            .unwrap()
+            .starts_with("Cannot write schema to")
+    );
+}
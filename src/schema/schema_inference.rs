@@ -0,0 +1,198 @@
+use std::collections::{BTreeSet, HashMap};
+
+use serde_json::{Map, Value, json};
+
+/// `$schema` of inferred schemas
+pub const DRAFT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// Most distinct values of a string field listed as an `enum`
+const MAX_ENUM_VALUES: usize = 8;
+
+/// Infer a JSON Schema that every value of `results`, the text jq printed,
+/// satisfies
+///
+/// Keys present in every object of a kind are `required`, and string
+/// fields that repeat a few distinct values get an `enum`.
+pub fn infer_schema(results: &str) -> Result<Value, String> {
+    let mut shape = Shape::default();
+    let mut samples = 0;
+    for value in serde_json::Deserializer::from_str(results).into_iter::<Value>() {
+        let value = value.map_err(|e| format!("Result is not JSON: {}", e))?;
+        shape.add(&value);
+        samples += 1;
+    }
+    if samples == 0 {
+        return Err("Nothing to infer a schema from".to_string());
+    }
+
+    let mut schema = Map::new();
+    schema.insert("$schema".to_string(), json!(DRAFT));
+    schema.extend(shape.to_schema());
+    Ok(Value::Object(schema))
+}
+
+/// What was seen at one place in the result
+#[derive(Debug, Default)]
+struct Shape {
+    null: bool,
+    boolean: bool,
+    integer: bool,
+    /// A number with a fraction or exponent
+    number: bool,
+    strings: Option<Strings>,
+    objects: Option<Objects>,
+    /// Elements of every array seen here
+    array_items: Option<Box<Shape>>,
+}
+
+#[derive(Debug, Default)]
+struct Strings {
+    count: usize,
+    /// Distinct values, `None` once there are too many for an enum
+    values: Option<BTreeSet<String>>,
+}
+
+#[derive(Debug, Default)]
+struct Objects {
+    count: usize,
+    /// Keys in the order first seen, with how many objects had them
+    properties: Vec<(String, usize, Shape)>,
+    index: HashMap<String, usize>,
+}
+
+impl Shape {
+    fn add(&mut self, value: &Value) {
+        match value {
+            Value::Null => self.null = true,
+            Value::Bool(_) => self.boolean = true,
+            // Integers too large for 64 bits are still integers
+            Value::Number(n) if !n.to_string().contains(['.', 'e', 'E']) => self.integer = true,
+            Value::Number(_) => self.number = true,
+            Value::String(s) => {
+                let strings = self.strings.get_or_insert_with(|| Strings {
+                    count: 0,
+                    values: Some(BTreeSet::new()),
+                });
+                strings.count += 1;
+                if let Some(values) = &mut strings.values {
+                    values.insert(s.clone());
+                    if values.len() > MAX_ENUM_VALUES {
+                        strings.values = None;
+                    }
+                }
+            }
+            Value::Array(items) => {
+                let shape = self.array_items.get_or_insert_default();
+                for item in items {
+                    shape.add(item);
+                }
+            }
+            Value::Object(map) => {
+                let objects = self.objects.get_or_insert_default();
+                objects.count += 1;
+                for (key, value) in map {
+                    let position = *objects.index.entry(key.clone()).or_insert_with(|| {
+                        objects.properties.push((key.clone(), 0, Shape::default()));
+                        objects.properties.len() - 1
+                    });
+                    let (_, count, shape) = &mut objects.properties[position];
+                    *count += 1;
+                    shape.add(value);
+                }
+            }
+        }
+    }
+
+    fn types(&self) -> Vec<&'static str> {
+        let mut types = Vec::new();
+        if self.objects.is_some() {
+            types.push("object");
+        }
+        if self.array_items.is_some() {
+            types.push("array");
+        }
+        if self.strings.is_some() {
+            types.push("string");
+        }
+        if self.number {
+            types.push("number");
+        } else if self.integer {
+            types.push("integer");
+        }
+        if self.boolean {
+            types.push("boolean");
+        }
+        if self.null {
+            types.push("null");
+        }
+        types
+    }
+
+    /// Schema keywords for this place, empty when nothing was seen
+    fn to_schema(&self) -> Map<String, Value> {
+        let mut schema = Map::new();
+        let types = self.types();
+        match types.as_slice() {
+            [] => return schema,
+            [single] => schema.insert("type".to_string(), json!(single)),
+            _ => schema.insert("type".to_string(), json!(types)),
+        };
+
+        if let Some(objects) = &self.objects {
+            let properties: Map<String, Value> = objects
+                .properties
+                .iter()
+                .map(|(key, _, shape)| (key.clone(), Value::Object(shape.to_schema())))
+                .collect();
+            let required: Vec<&str> = objects
+                .properties
+                .iter()
+                .filter(|(_, count, _)| *count == objects.count)
+                .map(|(key, _, _)| key.as_str())
+                .collect();
+            schema.insert("properties".to_string(), Value::Object(properties));
+            if !required.is_empty() {
+                schema.insert("required".to_string(), json!(required));
+            }
+        }
+
+        if let Some(items) = &self.array_items {
+            let items = items.to_schema();
+            if !items.is_empty() {
+                schema.insert("items".to_string(), Value::Object(items));
+            }
+        }
+
+        if let Some(values) = self.enum_values() {
+            schema.insert("enum".to_string(), Value::Array(values));
+        }
+        schema
+    }
+
+    /// Values of a string field repeating a few distinct values
+    ///
+    /// Each value has to have been seen at least twice on average, so a
+    /// field seen once or with unique values such as IDs isn't made an enum.
+    fn enum_values(&self) -> Option<Vec<Value>> {
+        let strings = self.strings.as_ref()?;
+        let values = strings.values.as_ref()?;
+        let only_strings = self.objects.is_none()
+            && self.array_items.is_none()
+            && !self.number
+            && !self.integer
+            && !self.boolean;
+        if !only_strings || strings.count < values.len() * 2 {
+            return None;
+        }
+
+        let mut values: Vec<Value> = values.iter().map(|value| json!(value)).collect();
+        if self.null {
+            values.push(Value::Null);
+        }
+        Some(values)
+    }
+}
+
+#[cfg(test)]
+#[path = "schema_inference_tests.rs"]
+mod schema_inference_tests;
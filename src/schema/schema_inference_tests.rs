@@ -0,0 +1,114 @@
+//! Tests for schema_inference
+
+use std::path::PathBuf;
+
+use super::*;
+use crate::schema::SchemaValidator;
+
+fn schema_without_draft(results: &str) -> Value {
+    let mut schema = infer_schema(results).unwrap();
+    assert_eq!(schema["$schema"], DRAFT);
+    schema.as_object_mut().unwrap().remove("$schema");
+    schema
+}
+
+#[test]
+fn test_scalars() {
+    assert_eq!(schema_without_draft("1"), json!({"type": "integer"}));
+    assert_eq!(schema_without_draft("1.5"), json!({"type": "number"}));
+    assert_eq!(schema_without_draft("1\n2.5"), json!({"type": "number"}));
+    assert_eq!(schema_without_draft("true"), json!({"type": "boolean"}));
+    assert_eq!(schema_without_draft("null"), json!({"type": "null"}));
+    assert_eq!(schema_without_draft("\"a\""), json!({"type": "string"}));
+    assert_eq!(
+        schema_without_draft("\"a\"\n1\nnull"),
+        json!({"type": ["string", "integer", "null"]})
+    );
+}
+
+#[test]
+fn test_objects_require_keys_in_every_sample() {
+    let results = r#"[
+        {"id": 1, "name": "a", "tags": ["x"]},
+        {"id": 2, "name": null},
+        {"id": 3, "email": "c@example.com", "tags": []}
+    ]"#;
+    assert_eq!(
+        schema_without_draft(results),
+        json!({
+            "type": "array",
+            "items": {
+                "type": "object",
+                "properties": {
+                    "id": {"type": "integer"},
+                    "name": {"type": ["string", "null"]},
+                    "tags": {"type": "array", "items": {"type": "string"}},
+                    "email": {"type": "string"}
+                },
+                "required": ["id"]
+            }
+        })
+    );
+}
+
+#[test]
+fn test_empty_arrays_have_no_items() {
+    assert_eq!(schema_without_draft("[]"), json!({"type": "array"}));
+    assert_eq!(
+        schema_without_draft(r#"{"a": {}}"#),
+        json!({
+            "type": "object",
+            "properties": {"a": {"type": "object", "properties": {}}},
+            "required": ["a"]
+        })
+    );
+}
+
+#[test]
+fn test_repeated_strings_become_enums() {
+    let results = r#"[
+        {"status": "Running", "id": "a1"},
+        {"status": "Pending", "id": "b2"},
+        {"status": "Running", "id": "c3"},
+        {"status": null, "id": "d4"},
+        {"status": "Pending", "id": "e5"}
+    ]"#;
+    let schema = schema_without_draft(results);
+    let properties = &schema["items"]["properties"];
+    assert_eq!(
+        properties["status"],
+        json!({"type": ["string", "null"], "enum": ["Pending", "Running", null]})
+    );
+    assert_eq!(properties["id"], json!({"type": "string"}));
+}
+
+#[test]
+fn test_many_distinct_strings_are_not_an_enum() {
+    let values: Vec<String> = (0..20).map(|i| format!("\"v{}\"", i % 10)).collect();
+    assert_eq!(
+        schema_without_draft(&values.join("\n")),
+        json!({"type": "string"})
+    );
+}
+
+#[test]
+fn test_inferred_schema_accepts_its_result() {
+    let results = r#"{"items": [{"n": 1, "kind": "a"}, {"n": 2.5, "kind": "a", "extra": [true, null]}]}
+{"items": []}"#;
+    let schema = infer_schema(results).unwrap();
+    let validator = SchemaValidator::new(PathBuf::from("inferred.json"), &schema).unwrap();
+    assert_eq!(validator.validate(results), Ok(Vec::new()));
+}
+
+#[test]
+fn test_errors() {
+    assert_eq!(
+        infer_schema("  \n"),
+        Err("Nothing to infer a schema from".to_string())
+    );
+    assert!(
+        infer_schema("plain text")
+            .unwrap_err()
+            .starts_with("Result is not JSON")
+    );
+}
@@ -0,0 +1,128 @@
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::Style,
+    text::{Line, Span, Text},
+    widgets::{Block, BorderType, Borders, Paragraph},
+};
+
+use super::Violation;
+use crate::ai::render::text::wrap_text;
+use crate::app::App;
+use crate::theme;
+use crate::widgets::{popup, scrollbar};
+
+/// Indentation of a violation's message under its path
+const MESSAGE_INDENT: usize = 3;
+
+fn build_hints() -> Line<'static> {
+    theme::border_hints::build_hints(
+        &[("j/k", "Scroll"), ("g/G", "Top/Bottom"), ("Esc", "Close")],
+        theme::schema::BORDER,
+    )
+}
+
+fn violation_lines(violation: &Violation, text_width: usize) -> Vec<Line<'static>> {
+    let mut path = vec![Span::raw(" ")];
+    if let Some(index) = violation.value_index {
+        path.push(Span::styled(
+            format!("#{} ", index + 1),
+            Style::default().fg(theme::schema::VALUE_INDEX),
+        ));
+    }
+    path.push(Span::styled(violation.path.clone(), theme::schema::PATH));
+
+    let mut lines = vec![Line::from(path)];
+    lines.extend(
+        wrap_text(&violation.message, text_width)
+            .into_iter()
+            .map(|message| {
+                Line::from(vec![
+                    Span::raw(" ".repeat(MESSAGE_INDENT)),
+                    Span::styled(message, Style::default().fg(theme::schema::MESSAGE)),
+                ])
+            }),
+    );
+    lines
+}
+
+/// Render the popup listing schema violations
+pub fn render_schema_popup(app: &mut App, frame: &mut Frame) -> Option<Rect> {
+    let frame_area = frame.area();
+    if frame_area.width < 30 || frame_area.height < 8 {
+        return None;
+    }
+
+    let popup_width = ((frame_area.width as f32 * 0.8) as u16)
+        .clamp(50, 100)
+        .min(frame_area.width.saturating_sub(4));
+    let popup_height = ((frame_area.height as f32 * 0.7) as u16)
+        .clamp(8, 30)
+        .min(frame_area.height.saturating_sub(2));
+    let popup_area = popup::centered_popup(frame_area, popup_width, popup_height);
+    popup::clear_area(frame, popup_area);
+
+    let count = app.schema.violations().len();
+    let schema_name = app
+        .schema
+        .validator
+        .as_ref()
+        .map(|validator| validator.name())
+        .unwrap_or_default();
+    let title = format!(
+        " {} {} against {} ",
+        count,
+        if count == 1 {
+            "violation"
+        } else {
+            "violations"
+        },
+        schema_name
+    );
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(Line::from(Span::styled(title, theme::schema::TITLE)))
+        .title_bottom(build_hints().centered())
+        .border_style(Style::default().fg(theme::schema::BORDER))
+        .style(Style::default().bg(theme::schema::BACKGROUND));
+    let content_area = block.inner(popup_area);
+    frame.render_widget(block, popup_area);
+
+    let text_width = (content_area.width as usize).saturating_sub(MESSAGE_INDENT + 1);
+    let lines: Vec<Line> = app
+        .schema
+        .violations()
+        .iter()
+        .flat_map(|violation| violation_lines(violation, text_width))
+        .collect();
+
+    let content_height = lines.len() as u32;
+    let scroll = &mut app.schema.scroll;
+    scroll.update_bounds(content_height, content_area.height);
+    frame.render_widget(
+        Paragraph::new(Text::from(lines)).scroll((scroll.offset, 0)),
+        content_area,
+    );
+
+    let scrollbar_area = Rect {
+        x: popup_area.x,
+        y: popup_area.y.saturating_add(1),
+        width: popup_area.width,
+        height: popup_area.height.saturating_sub(2),
+    };
+    scrollbar::render_vertical_scrollbar_styled(
+        frame,
+        scrollbar_area,
+        content_height as usize,
+        scroll.viewport_height as usize,
+        scroll.offset as usize,
+        theme::schema::BORDER,
+    );
+
+    Some(popup_area)
+}
+
+#[cfg(test)]
+#[path = "schema_render_tests.rs"]
+mod schema_render_tests;
@@ -0,0 +1,58 @@
+//! Tests for schema/schema_render
+
+use std::path::PathBuf;
+
+use super::*;
+use crate::schema::SchemaValidator;
+use crate::test_utils::test_helpers::test_app;
+use insta::assert_snapshot;
+use ratatui::Terminal;
+use ratatui::backend::TestBackend;
+
+fn render_to_string(app: &mut App, width: u16, height: u16) -> String {
+    let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+    terminal
+        .draw(|f| {
+            render_schema_popup(app, f);
+        })
+        .unwrap();
+    terminal.backend().to_string()
+}
+
+#[test]
+fn snapshot_schema_violations() {
+    let mut app = test_app(r#"{"a": 1}"#);
+    app.schema.validator = Some(
+        SchemaValidator::new(
+            PathBuf::from("/schemas/order.schema.json"),
+            &serde_json::json!({}),
+        )
+        .unwrap(),
+    );
+    app.schema.open(vec![
+        Violation {
+            value_index: None,
+            path: ".".to_string(),
+            message: "\"customer\" is a required property".to_string(),
+        },
+        Violation {
+            value_index: Some(1),
+            path: ".items[2].price".to_string(),
+            message: "\"12.50\" is not of types \"integer\", \"number\" and this message is long enough to wrap onto a second line".to_string(),
+        },
+    ]);
+
+    assert_snapshot!(render_to_string(&mut app, 80, 16));
+}
+
+#[test]
+fn test_tiny_terminal_renders_nothing() {
+    let mut app = test_app(r#"{"a": 1}"#);
+    app.schema.open(Vec::new());
+    let mut terminal = Terminal::new(TestBackend::new(20, 5)).unwrap();
+    let mut area = Some(Rect::default());
+    terminal
+        .draw(|f| area = render_schema_popup(&mut app, f))
+        .unwrap();
+    assert_eq!(area, None);
+}
@@ -0,0 +1,51 @@
+use super::{SchemaValidator, Violation};
+use crate::scroll::ScrollState;
+
+/// Schema given with `--schema` or `:validate FILE`, and the popup listing
+/// the violations of the last validation
+pub struct SchemaState {
+    pub validator: Option<SchemaValidator>,
+    visible: bool,
+    violations: Vec<Violation>,
+    pub scroll: ScrollState,
+}
+
+impl Default for SchemaState {
+    fn default() -> Self {
+        Self::new(None)
+    }
+}
+
+impl SchemaState {
+    pub fn new(validator: Option<SchemaValidator>) -> Self {
+        Self {
+            validator,
+            visible: false,
+            violations: Vec::new(),
+            scroll: ScrollState::new(),
+        }
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    pub fn violations(&self) -> &[Violation] {
+        &self.violations
+    }
+
+    /// Show `violations` from the top
+    pub fn open(&mut self, violations: Vec<Violation>) {
+        self.violations = violations;
+        self.visible = true;
+        self.scroll = ScrollState::new();
+    }
+
+    pub fn close(&mut self) {
+        self.visible = false;
+    }
+}
+
+#[cfg(test)]
+#[path = "schema_state_tests.rs"]
+mod schema_state_tests;
@@ -0,0 +1,34 @@
+//! Tests for schema_state
+
+use super::*;
+
+fn violation(path: &str) -> Violation {
+    Violation {
+        value_index: None,
+        path: path.to_string(),
+        message: "is not valid".to_string(),
+    }
+}
+
+#[test]
+fn test_new_state_is_hidden() {
+    let state = SchemaState::default();
+    assert!(!state.is_visible());
+    assert!(state.validator.is_none());
+    assert!(state.violations().is_empty());
+}
+
+#[test]
+fn test_open_replaces_violations_and_scrolls_to_top() {
+    let mut state = SchemaState::default();
+    state.open(vec![violation(".a")]);
+    state.scroll.offset = 3;
+
+    state.open(vec![violation(".b"), violation(".c")]);
+    assert!(state.is_visible());
+    assert_eq!(state.violations().len(), 2);
+    assert_eq!(state.scroll.offset, 0);
+
+    state.close();
+    assert!(!state.is_visible());
+}
@@ -0,0 +1,109 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use jsonschema::Validator;
+use serde_json::Value;
+
+use crate::query::language::jq_field;
+
+/// Value in the result that doesn't satisfy the schema
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Violation {
+    /// Index of the output value, when the query printed several
+    pub value_index: Option<usize>,
+    /// jq path of the failing value within its output value, e.g. `.items[2].id`
+    pub path: String,
+    pub message: String,
+}
+
+/// JSON Schema ready to validate results
+///
+/// `$ref`s to other files or URLs are not resolved.
+pub struct SchemaValidator {
+    path: PathBuf,
+    validator: Validator,
+}
+
+impl SchemaValidator {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let content = fs::read_to_string(path)
+            .map_err(|e| format!("Cannot read schema {}: {}", path.display(), e))?;
+        let schema: Value = serde_json::from_str(&content)
+            .map_err(|e| format!("Invalid schema {}: {}", path.display(), e))?;
+        Self::new(path.to_path_buf(), &schema)
+    }
+
+    /// Compile `schema`, read from `path`
+    pub fn new(path: PathBuf, schema: &Value) -> Result<Self, String> {
+        let validator = jsonschema::validator_for(schema)
+            .map_err(|e| format!("Invalid schema {}: {}", path.display(), e))?;
+        Ok(Self { path, validator })
+    }
+
+    /// Name of the schema file, for messages
+    pub fn name(&self) -> String {
+        self.path.file_name().map_or_else(
+            || self.path.display().to_string(),
+            |name| name.to_string_lossy().into_owned(),
+        )
+    }
+
+    /// Check each value of `results`, the text jq printed
+    pub fn validate(&self, results: &str) -> Result<Vec<Violation>, String> {
+        let values = serde_json::Deserializer::from_str(results)
+            .into_iter::<Value>()
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| format!("Result is not JSON: {}", e))?;
+        let several = values.len() > 1;
+
+        let mut violations = Vec::new();
+        for (index, value) in values.iter().enumerate() {
+            for error in self.validator.iter_errors(value) {
+                violations.push(Violation {
+                    value_index: several.then_some(index),
+                    path: jq_path(value, &error.instance_path.to_string()),
+                    message: error.to_string(),
+                });
+            }
+        }
+        Ok(violations)
+    }
+}
+
+/// jq path of the value at JSON Pointer `pointer` in `root`
+///
+/// The value tells whether a segment like `0` indexes an array or names a
+/// key.
+fn jq_path(root: &Value, pointer: &str) -> String {
+    let mut path = String::new();
+    let mut current = Some(root);
+    for segment in pointer.split('/').skip(1) {
+        let segment = segment.replace("~1", "/").replace("~0", "~");
+        let index = match current {
+            Some(Value::Array(_)) => segment.parse::<usize>().ok(),
+            _ => None,
+        };
+        match index {
+            Some(index) => {
+                path.push_str(&format!("[{}]", index));
+                current = current.and_then(|value| value.get(index));
+            }
+            None => {
+                path.push_str(&jq_field(&segment));
+                current = current.and_then(|value| value.get(&segment));
+            }
+        }
+    }
+
+    if path.is_empty() {
+        ".".to_string()
+    } else if path.starts_with('[') {
+        format!(".{}", path)
+    } else {
+        path
+    }
+}
+
+#[cfg(test)]
+#[path = "schema_validator_tests.rs"]
+mod schema_validator_tests;
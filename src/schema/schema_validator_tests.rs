@@ -0,0 +1,113 @@
+//! Tests for schema_validator
+
+use super::*;
+use serde_json::json;
+use tempfile::TempDir;
+
+fn validator(schema: Value) -> SchemaValidator {
+    SchemaValidator::new(PathBuf::from("schema.json"), &schema).unwrap()
+}
+
+fn item_schema() -> Value {
+    json!({
+        "type": "object",
+        "required": ["id", "tags"],
+        "properties": {
+            "id": {"type": "integer"},
+            "tags": {"type": "array", "items": {"type": "string"}},
+            "x-key": {"type": "string"}
+        }
+    })
+}
+
+#[test]
+fn test_valid_result_has_no_violations() {
+    let validator = validator(item_schema());
+    assert_eq!(
+        validator.validate(r#"{"id": 1, "tags": ["a"]}"#),
+        Ok(Vec::new())
+    );
+}
+
+#[test]
+fn test_violations_have_jq_paths() {
+    let validator = validator(item_schema());
+    let violations = validator
+        .validate(r#"{"id": "1", "tags": ["a", 2], "x-key": 3}"#)
+        .unwrap();
+    let mut paths: Vec<&str> = violations.iter().map(|v| v.path.as_str()).collect();
+    paths.sort();
+
+    assert_eq!(paths, [".[\"x-key\"]", ".id", ".tags[1]"]);
+    assert!(violations.iter().all(|v| v.value_index.is_none()));
+    let id = violations.iter().find(|v| v.path == ".id").unwrap();
+    assert!(id.message.contains("integer"), "{}", id.message);
+}
+
+#[test]
+fn test_missing_property_is_reported_on_its_object() {
+    let validator = validator(item_schema());
+    let violations = validator.validate(r#"{"id": 1}"#).unwrap();
+    assert_eq!(violations.len(), 1);
+    assert_eq!(violations[0].path, ".");
+    assert!(violations[0].message.contains("tags"));
+}
+
+#[test]
+fn test_numeric_keys_of_objects_stay_keys() {
+    let validator = validator(json!({
+        "type": "object",
+        "additionalProperties": {"type": "array", "items": {"type": "integer"}}
+    }));
+    let violations = validator.validate(r#"{"0": [1, "x"]}"#).unwrap();
+    assert_eq!(violations[0].path, ".[\"0\"][1]");
+}
+
+#[test]
+fn test_each_output_value_is_checked() {
+    let validator = validator(json!({"type": "integer"}));
+    let violations = validator.validate("1\n\"two\"\n3\nnull\n").unwrap();
+    let indexes: Vec<Option<usize>> = violations.iter().map(|v| v.value_index).collect();
+    assert_eq!(indexes, [Some(1), Some(3)]);
+}
+
+#[test]
+fn test_non_json_result_is_an_error() {
+    let validator = validator(json!({}));
+    assert!(
+        validator
+            .validate("plain text")
+            .unwrap_err()
+            .starts_with("Result is not JSON")
+    );
+}
+
+#[test]
+fn test_load_reports_bad_schemas() {
+    let dir = TempDir::new().unwrap();
+    let missing = dir.path().join("missing.json");
+    assert!(
+        SchemaValidator::load(&missing)
+            .err()
+            .unwrap()
+            .starts_with("Cannot read schema")
+    );
+
+    let not_json = dir.path().join("broken.json");
+    fs::write(&not_json, "{").unwrap();
+    assert!(
+        SchemaValidator::load(&not_json)
+            .err()
+            .unwrap()
+            .starts_with("Invalid schema")
+    );
+
+    let bad_type = dir.path().join("bad.json");
+    fs::write(&bad_type, r#"{"type": "nope"}"#).unwrap();
+    assert!(SchemaValidator::load(&bad_type).is_err());
+
+    let good = dir.path().join("good.json");
+    fs::write(&good, r#"{"type": "object"}"#).unwrap();
+    let validator = SchemaValidator::load(&good).unwrap();
+    assert_eq!(validator.name(), "good.json");
+}
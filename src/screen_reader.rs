@@ -0,0 +1,18 @@
+//! Screen-reader friendly layout
+//!
+//! `--screen-reader` (or `screen_reader = true` under `[display]`) draws
+//! borders with ASCII characters, replaces the status and help lines with a
+//! single announcement line describing the latest change, and shows the
+//! result one value ("node") at a time: its position, path and type, with
+//! keys to move to the next value, the parent or the first child. A screen
+//! reader following the terminal then reads short, meaningful lines instead
+//! of box drawing and pretty-printed JSON.
+
+mod screen_reader_events;
+mod screen_reader_nodes;
+mod screen_reader_render;
+mod screen_reader_state;
+
+pub use screen_reader_events::{announce_focus, announce_result, handle_screen_reader_key};
+pub use screen_reader_render::{render_announcement, render_nodes};
+pub use screen_reader_state::ScreenReaderState;
@@ -0,0 +1,96 @@
+use ratatui::crossterm::event::{KeyCode, KeyEvent};
+
+use super::screen_reader_state::NodeMove;
+use crate::app::{App, Focus};
+
+/// Announce the outcome of a finished query and list the result's nodes
+pub fn announce_result(app: &mut App) {
+    if !app.screen_reader.is_enabled() {
+        return;
+    }
+    let Some(query_state) = &app.query else {
+        return;
+    };
+
+    if let Err(error) = &query_state.result {
+        let first_line = error.lines().next().unwrap_or_default();
+        app.screen_reader.announce(format!("Error: {}", first_line));
+        return;
+    }
+    if query_state.is_empty_result {
+        app.screen_reader.announce("No results");
+        return;
+    }
+    let Some(output) = query_state.last_successful_result_text() else {
+        return;
+    };
+
+    app.screen_reader.load(&output);
+    let stats = app.stats.display().unwrap_or_else(|| "Result".to_string());
+    let nodes = app.screen_reader.nodes().len();
+    let count = match (nodes, app.screen_reader.is_truncated()) {
+        (_, true) => format!("first {} nodes", nodes),
+        (1, false) => "1 node".to_string(),
+        (n, false) => format!("{} nodes", n),
+    };
+    app.screen_reader.announce(format!("{}, {}", stats, count));
+}
+
+/// Announce the pane that just received focus
+pub fn announce_focus(app: &mut App) {
+    if !app.screen_reader.is_enabled() {
+        return;
+    }
+    let announcement = match app.focus {
+        Focus::InputField => "Query input".to_string(),
+        Focus::ResultsPane => match app.screen_reader.describe_current() {
+            Some(node) => format!("Results. {}", node),
+            None => "Results, nothing to read".to_string(),
+        },
+    };
+    app.screen_reader.announce(announcement);
+}
+
+/// Move between result nodes in the results pane
+///
+/// Returns false for keys the results pane handles as usual, such as `y`,
+/// `/` and Tab.
+pub fn handle_screen_reader_key(app: &mut App, key: KeyEvent) -> bool {
+    let node_move = match key.code {
+        KeyCode::Down | KeyCode::Char('j') => NodeMove::Next,
+        KeyCode::Up | KeyCode::Char('k') => NodeMove::Previous,
+        KeyCode::Left | KeyCode::Char('h') => NodeMove::Parent,
+        KeyCode::Right | KeyCode::Char('l') => NodeMove::FirstChild,
+        KeyCode::Char('J') => NodeMove::NextSibling,
+        KeyCode::Char('K') => NodeMove::PreviousSibling,
+        KeyCode::Home | KeyCode::Char('g') => NodeMove::First,
+        KeyCode::End | KeyCode::Char('G') => NodeMove::Last,
+        KeyCode::Char(' ') => {
+            announce_focus(app);
+            return true;
+        }
+        _ => return false,
+    };
+
+    if app.screen_reader.current().is_none() {
+        app.screen_reader.announce("Nothing to read");
+    } else if app.screen_reader.move_to(node_move) {
+        let node = app.screen_reader.describe_current().unwrap_or_default();
+        app.screen_reader.announce(node);
+    } else {
+        app.screen_reader.announce(match node_move {
+            NodeMove::Next => "Last node",
+            NodeMove::Previous => "First node",
+            NodeMove::NextSibling => "Last at this level",
+            NodeMove::PreviousSibling => "First at this level",
+            NodeMove::Parent => "Top level, no parent",
+            NodeMove::FirstChild => "No children",
+            NodeMove::First | NodeMove::Last => "Nothing to read",
+        });
+    }
+    true
+}
+
+#[cfg(test)]
+#[path = "screen_reader_events_tests.rs"]
+mod screen_reader_events_tests;
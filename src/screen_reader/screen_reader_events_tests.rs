@@ -0,0 +1,96 @@
+//! Tests for screen_reader_events
+
+use super::*;
+use crate::test_utils::test_helpers::{key, key_with_mods, screen_reader_app, test_app};
+use ratatui::crossterm::event::KeyModifiers;
+
+#[test]
+fn test_result_is_announced() {
+    let app = screen_reader_app(r#"{"a": [1, 2]}"#);
+
+    assert_eq!(app.screen_reader.announcement(), "Object, 4 nodes");
+    assert_eq!(app.screen_reader.nodes().len(), 4);
+}
+
+#[test]
+fn test_error_is_announced() {
+    let mut app = screen_reader_app(r#"{"a": 1}"#);
+    app.query.as_mut().unwrap().execute(".a |");
+
+    announce_result(&mut app);
+
+    assert!(app.screen_reader.announcement().starts_with("Error: "));
+}
+
+#[test]
+fn test_nothing_announced_when_disabled() {
+    let mut app = test_app(r#"{"a": 1}"#);
+
+    announce_result(&mut app);
+    announce_focus(&mut app);
+
+    assert_eq!(app.screen_reader.announcement(), "");
+    assert!(app.screen_reader.nodes().is_empty());
+}
+
+#[test]
+fn test_focus_is_announced() {
+    let mut app = screen_reader_app(r#"{"a": 1}"#);
+
+    app.focus = Focus::ResultsPane;
+    announce_focus(&mut app);
+    assert_eq!(
+        app.screen_reader.announcement(),
+        "Results. Node 1 of 2, .: object, 1 key: a"
+    );
+
+    app.focus = Focus::InputField;
+    announce_focus(&mut app);
+    assert_eq!(app.screen_reader.announcement(), "Query input");
+}
+
+#[test]
+fn test_navigation_keys_announce_nodes() {
+    let mut app = screen_reader_app(r#"{"a": [1, 2], "b": true}"#);
+
+    assert!(handle_screen_reader_key(&mut app, key(KeyCode::Char('l'))));
+    assert_eq!(
+        app.screen_reader.announcement(),
+        "Node 2 of 5, .a: array, 2 items"
+    );
+
+    assert!(handle_screen_reader_key(
+        &mut app,
+        key_with_mods(KeyCode::Char('J'), KeyModifiers::SHIFT)
+    ));
+    assert_eq!(
+        app.screen_reader.announcement(),
+        "Node 5 of 5, .b: boolean true"
+    );
+
+    assert!(handle_screen_reader_key(&mut app, key(KeyCode::Down)));
+    assert_eq!(app.screen_reader.announcement(), "Last node");
+
+    assert!(handle_screen_reader_key(&mut app, key(KeyCode::Char('h'))));
+    assert!(handle_screen_reader_key(&mut app, key(KeyCode::Char('h'))));
+    assert_eq!(app.screen_reader.announcement(), "Top level, no parent");
+}
+
+#[test]
+fn test_other_keys_pass_through() {
+    let mut app = screen_reader_app(r#"{"a": 1}"#);
+
+    assert!(!handle_screen_reader_key(&mut app, key(KeyCode::Char('y'))));
+    assert!(!handle_screen_reader_key(&mut app, key(KeyCode::Tab)));
+}
+
+#[test]
+fn test_results_pane_routes_keys_to_reader() {
+    let mut app = screen_reader_app(r#"{"a": 1}"#);
+    app.focus = Focus::ResultsPane;
+
+    app.handle_key_event(key(KeyCode::Char('j')));
+
+    assert_eq!(app.screen_reader.current().unwrap().path, ".a");
+    assert_eq!(app.results_cursor.cursor_line(), 0);
+}
@@ -0,0 +1,177 @@
+use serde_json::Value;
+
+use crate::query::language::jq_field;
+
+/// Most nodes listed for one result, so that huge results stay responsive
+pub const MAX_NODES: usize = 50_000;
+
+/// Most characters of a string read out in a node description
+const MAX_STRING_CHARS: usize = 200;
+
+/// Most keys listed in an object's description
+const MAX_LISTED_KEYS: usize = 8;
+
+/// One value of the result: an output value or anything nested in it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Node {
+    /// Index of the output value the node belongs to, when jq emits several
+    pub value_index: usize,
+    /// Path of the node within its output value, e.g. `.items[2].name`
+    pub path: String,
+    /// Nesting level, 0 for output values
+    pub depth: usize,
+    /// Index of the enclosing object or array's node
+    pub parent: Option<usize>,
+    pub previous_sibling: Option<usize>,
+    pub next_sibling: Option<usize>,
+    /// What the value is, e.g. `object, 3 keys: id, name, tags` or `string "web"`
+    pub description: String,
+}
+
+/// Nodes of jq's `output` in document order, each container followed by its
+/// contents
+///
+/// Returns whether the list was cut at [`MAX_NODES`]. Output that is not
+/// JSON (e.g. raw strings) ends the list.
+pub fn flatten(output: &str) -> (Vec<Node>, bool) {
+    let mut nodes = Vec::new();
+    let mut previous_root = None;
+    let values = serde_json::Deserializer::from_str(output).into_iter::<Value>();
+    for (value_index, value) in values.enumerate() {
+        let Ok(value) = value else {
+            break;
+        };
+        let mut walker = Walker {
+            nodes: &mut nodes,
+            value_index,
+        };
+        match walker.push(
+            &value,
+            ".".to_string(),
+            String::new(),
+            0,
+            None,
+            previous_root,
+        ) {
+            Some(index) => previous_root = Some(index),
+            None => return (nodes, true),
+        }
+    }
+    (nodes, false)
+}
+
+struct Walker<'a> {
+    nodes: &'a mut Vec<Node>,
+    value_index: usize,
+}
+
+impl Walker<'_> {
+    /// Add `value` and everything in it; None once the node limit is reached
+    ///
+    /// `prefix` is the path used to build the children's paths, which is
+    /// empty for an output value whose own path reads `.`.
+    fn push(
+        &mut self,
+        value: &Value,
+        path: String,
+        prefix: String,
+        depth: usize,
+        parent: Option<usize>,
+        previous_sibling: Option<usize>,
+    ) -> Option<usize> {
+        if self.nodes.len() >= MAX_NODES {
+            return None;
+        }
+        let index = self.nodes.len();
+        if let Some(previous) = previous_sibling {
+            self.nodes[previous].next_sibling = Some(index);
+        }
+        self.nodes.push(Node {
+            value_index: self.value_index,
+            path,
+            depth,
+            parent,
+            previous_sibling,
+            next_sibling: None,
+            description: describe(value),
+        });
+
+        let mut previous_child = None;
+        match value {
+            Value::Object(map) => {
+                for (key, child) in map {
+                    let path = format!("{}{}", prefix, jq_field(key));
+                    previous_child = Some(self.push(
+                        child,
+                        path.clone(),
+                        path,
+                        depth + 1,
+                        Some(index),
+                        previous_child,
+                    )?);
+                }
+            }
+            Value::Array(items) => {
+                for (position, child) in items.iter().enumerate() {
+                    let path = format!("{}[{}]", prefix, position);
+                    previous_child = Some(self.push(
+                        child,
+                        path.clone(),
+                        path,
+                        depth + 1,
+                        Some(index),
+                        previous_child,
+                    )?);
+                }
+            }
+            _ => {}
+        }
+        Some(index)
+    }
+}
+
+/// Type and content of `value` in words
+pub fn describe(value: &Value) -> String {
+    match value {
+        Value::Null => "null".to_string(),
+        Value::Bool(b) => format!("boolean {}", b),
+        Value::Number(n) => format!("number {}", n),
+        Value::String(s) => {
+            let count = s.chars().count();
+            if count > MAX_STRING_CHARS {
+                let cut: String = s.chars().take(MAX_STRING_CHARS).collect();
+                format!("string \"{}...\", {} characters", cut, count)
+            } else {
+                format!("string \"{}\"", s)
+            }
+        }
+        Value::Array(items) => match items.len() {
+            0 => "empty array".to_string(),
+            1 => "array, 1 item".to_string(),
+            n => format!("array, {} items", n),
+        },
+        Value::Object(map) => {
+            if map.is_empty() {
+                return "empty object".to_string();
+            }
+            let count = if map.len() == 1 {
+                "1 key".to_string()
+            } else {
+                format!("{} keys", map.len())
+            };
+            let mut keys: Vec<&str> = map
+                .keys()
+                .take(MAX_LISTED_KEYS)
+                .map(String::as_str)
+                .collect();
+            if map.len() > MAX_LISTED_KEYS {
+                keys.push("...");
+            }
+            format!("object, {}: {}", count, keys.join(", "))
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "screen_reader_nodes_tests.rs"]
+mod screen_reader_nodes_tests;
@@ -0,0 +1,97 @@
+//! Tests for screen_reader_nodes
+
+use super::*;
+use serde_json::json;
+
+fn paths(nodes: &[Node]) -> Vec<&str> {
+    nodes.iter().map(|node| node.path.as_str()).collect()
+}
+
+#[test]
+fn test_nodes_in_document_order() {
+    let (nodes, truncated) = flatten(r#"{"items": [{"name": "web"}, 2], "my key": null}"#);
+
+    assert!(!truncated);
+    assert_eq!(
+        paths(&nodes),
+        [
+            ".",
+            ".items",
+            ".items[0]",
+            ".items[0].name",
+            ".items[1]",
+            r#".["my key"]"#
+        ]
+    );
+    assert_eq!(
+        nodes.iter().map(|node| node.depth).collect::<Vec<_>>(),
+        [0, 1, 2, 3, 2, 1]
+    );
+}
+
+#[test]
+fn test_parents_and_siblings() {
+    let (nodes, _) = flatten(r#"{"a": [1, 2], "b": true}"#);
+
+    assert_eq!(nodes[1].parent, Some(0));
+    assert_eq!(nodes[2].parent, Some(1));
+    assert_eq!(nodes[1].next_sibling, Some(4));
+    assert_eq!(nodes[4].previous_sibling, Some(1));
+    assert_eq!(nodes[2].next_sibling, Some(3));
+    assert_eq!(nodes[3].next_sibling, None);
+    assert_eq!(nodes[0].parent, None);
+}
+
+#[test]
+fn test_several_outputs_are_siblings() {
+    let (nodes, _) = flatten("1\n{\"a\": 2}\n");
+
+    assert_eq!(paths(&nodes), [".", ".", ".a"]);
+    assert_eq!(nodes[1].value_index, 1);
+    assert_eq!(nodes[2].value_index, 1);
+    assert_eq!(nodes[0].next_sibling, Some(1));
+}
+
+#[test]
+fn test_non_json_output_ends_the_list() {
+    let (nodes, truncated) = flatten("1\nnot json\n2");
+
+    assert_eq!(nodes.len(), 1);
+    assert!(!truncated);
+}
+
+#[test]
+fn test_large_output_is_cut() {
+    let output = serde_json::to_string(&vec![0; MAX_NODES + 10]).unwrap();
+
+    let (nodes, truncated) = flatten(&output);
+
+    assert!(truncated);
+    assert_eq!(nodes.len(), MAX_NODES);
+}
+
+#[test]
+fn test_describe() {
+    assert_eq!(describe(&json!(null)), "null");
+    assert_eq!(describe(&json!(true)), "boolean true");
+    assert_eq!(describe(&json!(1.5)), "number 1.5");
+    assert_eq!(describe(&json!("web")), r#"string "web""#);
+    assert_eq!(describe(&json!([])), "empty array");
+    assert_eq!(describe(&json!([1])), "array, 1 item");
+    assert_eq!(describe(&json!([1, 2])), "array, 2 items");
+    assert_eq!(describe(&json!({})), "empty object");
+    assert_eq!(describe(&json!({"b": 1, "a": 2})), "object, 2 keys: b, a");
+}
+
+#[test]
+fn test_describe_shortens_long_values() {
+    let long = "x".repeat(MAX_STRING_CHARS + 1);
+    assert!(describe(&json!(long)).ends_with(r#"...", 201 characters"#));
+
+    let keys: serde_json::Map<String, Value> =
+        (0..10).map(|i| (format!("k{}", i), json!(i))).collect();
+    assert_eq!(
+        describe(&Value::Object(keys)),
+        "object, 10 keys: k0, k1, k2, k3, k4, k5, k6, k7, ..."
+    );
+}
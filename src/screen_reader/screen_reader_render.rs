@@ -0,0 +1,106 @@
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::Style,
+    text::{Line, Span},
+    widgets::{Paragraph, Wrap},
+};
+
+use crate::app::App;
+use crate::theme;
+
+const KEYS: &str = "j/k next/previous, h/l parent/first child, J/K next/previous sibling, g/G first/last, Space repeat";
+
+fn labelled(label: &str, value: String) -> Line<'static> {
+    Line::from(vec![
+        Span::styled(
+            format!("{}: ", label),
+            Style::default().fg(theme::screen_reader::LABEL),
+        ),
+        Span::styled(value, Style::default().fg(theme::screen_reader::TEXT)),
+    ])
+}
+
+fn plain(text: impl Into<String>) -> Line<'static> {
+    Line::from(Span::styled(
+        text.into(),
+        Style::default().fg(theme::screen_reader::TEXT),
+    ))
+}
+
+/// Lines of the results area: the current node's position, path and value
+fn node_lines(app: &App) -> Vec<Line<'static>> {
+    let Some(query_state) = &app.query else {
+        return vec![plain("Loading")];
+    };
+    if let Err(error) = &query_state.result {
+        return error.lines().map(|line| plain(line.to_string())).collect();
+    }
+    if query_state.is_empty_result {
+        return vec![plain("No results")];
+    }
+
+    let reader = &app.screen_reader;
+    let Some(node) = reader.current() else {
+        if query_state.is_pending() {
+            return vec![plain("Running")];
+        }
+        return vec![plain("Nothing to read, the result is not JSON")];
+    };
+
+    let total = if reader.is_truncated() {
+        format!("first {}", reader.nodes().len())
+    } else {
+        reader.nodes().len().to_string()
+    };
+    let mut lines = vec![plain(format!(
+        "Node {} of {}",
+        reader.selected() + 1,
+        total
+    ))];
+    if reader
+        .nodes()
+        .last()
+        .is_some_and(|last| last.value_index > 0)
+    {
+        lines.push(labelled("Output", (node.value_index + 1).to_string()));
+    }
+    lines.push(labelled("Path", node.path.clone()));
+    lines.push(labelled("Value", node.description.clone()));
+    lines.push(labelled("Depth", node.depth.to_string()));
+    lines.push(Line::default());
+    lines.push(labelled("Keys", KEYS.to_string()));
+    lines
+}
+
+/// Results area of the screen-reader layout: one node at a time, as plain
+/// text without borders
+pub fn render_nodes(app: &App, frame: &mut Frame, area: Rect) {
+    frame.render_widget(
+        Paragraph::new(node_lines(app)).wrap(Wrap { trim: false }),
+        area,
+    );
+}
+
+/// The one status line of the screen-reader layout: the current
+/// notification, otherwise the latest announcement
+pub fn render_announcement(app: &App, frame: &mut Frame, area: Rect) {
+    let text = app
+        .notification
+        .current()
+        .map_or(app.screen_reader.announcement(), |notification| {
+            notification.message.as_str()
+        });
+    frame.render_widget(
+        Paragraph::new(text.to_string()).style(
+            Style::default()
+                .fg(theme::screen_reader::TEXT)
+                .bg(theme::status_line::BACKGROUND),
+        ),
+        area,
+    );
+}
+
+#[cfg(test)]
+#[path = "screen_reader_render_tests.rs"]
+mod screen_reader_render_tests;
@@ -0,0 +1,55 @@
+//! Tests for screen_reader_render
+
+use super::*;
+use crate::test_utils::test_helpers::screen_reader_app;
+use insta::assert_snapshot;
+use ratatui::Terminal;
+use ratatui::backend::TestBackend;
+
+fn render_to_string(app: &App, width: u16, height: u16) -> String {
+    let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+    terminal
+        .draw(|f| {
+            let area = f.area();
+            let [nodes, announcement] = ratatui::layout::Layout::vertical([
+                ratatui::layout::Constraint::Min(1),
+                ratatui::layout::Constraint::Length(1),
+            ])
+            .areas(area);
+            render_nodes(app, f, nodes);
+            render_announcement(app, f, announcement);
+        })
+        .unwrap();
+    terminal.backend().to_string()
+}
+
+#[test]
+fn snapshot_node_view() {
+    let app = screen_reader_app(r#"{"items": [{"name": "web"}]}"#);
+
+    assert_snapshot!(render_to_string(&app, 60, 9));
+}
+
+#[test]
+fn test_error_lines_are_shown() {
+    let mut app = screen_reader_app(r#"{"a": 1}"#);
+    app.query.as_mut().unwrap().execute(".a |");
+
+    let text: Vec<String> = node_lines(&app)
+        .iter()
+        .map(|line| line.to_string())
+        .collect();
+
+    assert!(!text.is_empty());
+    assert!(!text[0].starts_with("Node"));
+}
+
+#[test]
+fn test_notification_takes_the_announcement_line() {
+    let mut app = screen_reader_app(r#"{"a": 1}"#);
+    app.notification.show("Copied query");
+
+    let output = render_to_string(&app, 40, 3);
+
+    assert!(output.lines().last().unwrap().contains("Copied query"));
+}
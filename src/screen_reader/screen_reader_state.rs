@@ -0,0 +1,157 @@
+use std::sync::Arc;
+
+use super::screen_reader_nodes::{self, Node};
+
+/// Where a navigation key moves in the node list
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NodeMove {
+    Next,
+    Previous,
+    Parent,
+    FirstChild,
+    NextSibling,
+    PreviousSibling,
+    First,
+    Last,
+}
+
+/// Screen-reader layout state: the result as a list of nodes, the node being
+/// read and the text of the announcement line
+#[derive(Debug, Default)]
+pub struct ScreenReaderState {
+    enabled: bool,
+    nodes: Vec<Node>,
+    /// Whether `nodes` stops at [`screen_reader_nodes::MAX_NODES`]
+    truncated: bool,
+    selected: usize,
+    /// Output the nodes were built from, to skip rebuilding for the same result
+    source: Option<Arc<String>>,
+    announcement: String,
+}
+
+impl ScreenReaderState {
+    pub fn new(enabled: bool) -> Self {
+        Self {
+            enabled,
+            ..Self::default()
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    pub fn enable(&mut self) {
+        self.enabled = true;
+    }
+
+    /// List the nodes of `output`, keeping the node at the same path when the
+    /// result changes
+    ///
+    /// Returns false when `output` is the result the nodes already describe.
+    pub fn load(&mut self, output: &Arc<String>) -> bool {
+        if self
+            .source
+            .as_ref()
+            .is_some_and(|source| Arc::ptr_eq(source, output))
+        {
+            return false;
+        }
+        let previous = self
+            .current()
+            .map(|node| (node.value_index, node.path.clone()));
+
+        let (nodes, truncated) = screen_reader_nodes::flatten(output);
+        self.nodes = nodes;
+        self.truncated = truncated;
+        self.source = Some(Arc::clone(output));
+        self.selected = previous
+            .and_then(|(value_index, path)| {
+                self.nodes
+                    .iter()
+                    .position(|node| node.value_index == value_index && node.path == path)
+            })
+            .unwrap_or(0);
+        true
+    }
+
+    pub fn nodes(&self) -> &[Node] {
+        &self.nodes
+    }
+
+    pub fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+
+    pub fn selected(&self) -> usize {
+        self.selected
+    }
+
+    pub fn current(&self) -> Option<&Node> {
+        self.nodes.get(self.selected)
+    }
+
+    /// Move to another node; false when there is none in that direction
+    pub fn move_to(&mut self, node_move: NodeMove) -> bool {
+        let Some(node) = self.current() else {
+            return false;
+        };
+        let target = match node_move {
+            NodeMove::Next => Some(self.selected + 1).filter(|&next| next < self.nodes.len()),
+            NodeMove::Previous => self.selected.checked_sub(1),
+            NodeMove::Parent => node.parent,
+            NodeMove::FirstChild => Some(self.selected + 1).filter(|&next| {
+                self.nodes
+                    .get(next)
+                    .is_some_and(|child| child.parent == Some(self.selected))
+            }),
+            NodeMove::NextSibling => node.next_sibling,
+            NodeMove::PreviousSibling => node.previous_sibling,
+            NodeMove::First => Some(0),
+            NodeMove::Last => Some(self.nodes.len() - 1),
+        };
+        match target {
+            Some(target) => {
+                self.selected = target;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Node position, path and value in one sentence, e.g.
+    /// `Node 3 of 12, .items[0].name: string "web"`
+    pub fn describe_current(&self) -> Option<String> {
+        let node = self.current()?;
+        let of = if self.truncated {
+            format!("{}+", self.nodes.len())
+        } else {
+            self.nodes.len().to_string()
+        };
+        let value = if self.nodes.last()?.value_index > 0 {
+            format!(", output {}", node.value_index + 1)
+        } else {
+            String::new()
+        };
+        Some(format!(
+            "Node {} of {}{}, {}: {}",
+            self.selected + 1,
+            of,
+            value,
+            node.path,
+            node.description
+        ))
+    }
+
+    pub fn announce(&mut self, message: impl Into<String>) {
+        self.announcement = message.into();
+    }
+
+    pub fn announcement(&self) -> &str {
+        &self.announcement
+    }
+}
+
+#[cfg(test)]
+#[path = "screen_reader_state_tests.rs"]
+mod screen_reader_state_tests;
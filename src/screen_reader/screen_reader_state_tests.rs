@@ -0,0 +1,84 @@
+//! Tests for screen_reader_state
+
+use super::*;
+
+fn loaded(output: &str) -> ScreenReaderState {
+    let mut state = ScreenReaderState::new(true);
+    state.load(&Arc::new(output.to_string()));
+    state
+}
+
+#[test]
+fn test_new_state() {
+    let state = ScreenReaderState::new(false);
+    assert!(!state.is_enabled());
+    assert!(state.current().is_none());
+    assert_eq!(state.announcement(), "");
+}
+
+#[test]
+fn test_moves() {
+    let mut state = loaded(r#"{"a": [1, 2], "b": true}"#);
+
+    assert!(state.move_to(NodeMove::FirstChild));
+    assert_eq!(state.current().unwrap().path, ".a");
+    assert!(state.move_to(NodeMove::NextSibling));
+    assert_eq!(state.current().unwrap().path, ".b");
+    assert!(!state.move_to(NodeMove::NextSibling));
+    assert!(!state.move_to(NodeMove::FirstChild));
+    assert!(state.move_to(NodeMove::PreviousSibling));
+    assert!(state.move_to(NodeMove::Next));
+    assert_eq!(state.current().unwrap().path, ".a[0]");
+    assert!(state.move_to(NodeMove::Parent));
+    assert_eq!(state.current().unwrap().path, ".a");
+    assert!(state.move_to(NodeMove::Last));
+    assert!(!state.move_to(NodeMove::Next));
+    assert!(state.move_to(NodeMove::First));
+    assert!(!state.move_to(NodeMove::Previous));
+    assert!(!state.move_to(NodeMove::Parent));
+}
+
+#[test]
+fn test_move_without_nodes() {
+    let mut state = ScreenReaderState::new(true);
+    assert!(!state.move_to(NodeMove::Last));
+}
+
+#[test]
+fn test_load_keeps_node_at_same_path() {
+    let mut state = loaded(r#"{"a": 1, "b": 2}"#);
+    state.move_to(NodeMove::Last);
+
+    state.load(&Arc::new(r#"{"b": 3, "c": 4}"#.to_string()));
+    assert_eq!(state.current().unwrap().path, ".b");
+
+    state.load(&Arc::new(r#"{"x": 1}"#.to_string()));
+    assert_eq!(state.selected(), 0);
+}
+
+#[test]
+fn test_load_skips_same_output() {
+    let output = Arc::new("[1]".to_string());
+    let mut state = ScreenReaderState::new(true);
+
+    assert!(state.load(&output));
+    assert!(!state.load(&output));
+    assert!(state.load(&Arc::new("[1]".to_string())));
+}
+
+#[test]
+fn test_describe_current() {
+    let mut state = loaded(r#"{"name": "web"}"#);
+    state.move_to(NodeMove::Next);
+    assert_eq!(
+        state.describe_current().unwrap(),
+        r#"Node 2 of 2, .name: string "web""#
+    );
+
+    let mut state = loaded("1\n2\n");
+    state.move_to(NodeMove::Next);
+    assert_eq!(
+        state.describe_current().unwrap(),
+        "Node 2 of 2, output 2, .: number 2"
+    );
+}
@@ -1,9 +1,15 @@
+mod scroll_keys;
 mod scroll_state;
 mod scroll_trait;
 
+pub use scroll_keys::handle_scroll_key;
 pub use scroll_state::ScrollState;
 pub use scroll_trait::Scrollable;
 
+#[cfg(test)]
+#[path = "scroll/scroll_keys_tests.rs"]
+mod scroll_keys_tests;
+
 #[cfg(test)]
 #[path = "scroll/scroll_state_tests.rs"]
 mod scroll_state_tests;
@@ -0,0 +1,23 @@
+use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use super::ScrollState;
+
+/// Scroll a popup's content by a vim-style or navigation key
+///
+/// Returns whether `key` was a scroll key, so popups can fall back to their
+/// own bindings for the others.
+pub fn handle_scroll_key(scroll: &mut ScrollState, key: KeyEvent) -> bool {
+    let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+    match key.code {
+        KeyCode::Char('j') | KeyCode::Down => scroll.scroll_down(1),
+        KeyCode::Char('k') | KeyCode::Up => scroll.scroll_up(1),
+        KeyCode::Char('d') if ctrl => scroll.page_down(),
+        KeyCode::Char('u') if ctrl => scroll.page_up(),
+        KeyCode::PageDown => scroll.page_down(),
+        KeyCode::PageUp => scroll.page_up(),
+        KeyCode::Char('g') | KeyCode::Home => scroll.jump_to_top(),
+        KeyCode::Char('G') | KeyCode::End => scroll.jump_to_bottom(),
+        _ => return false,
+    }
+    true
+}
@@ -0,0 +1,67 @@
+//! Tests for scroll_keys
+
+use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use super::*;
+
+fn scrolled(offset: u16) -> ScrollState {
+    let mut scroll = ScrollState::new();
+    scroll.update_bounds(100, 10);
+    scroll.offset = offset;
+    scroll
+}
+
+fn key(code: KeyCode, modifiers: KeyModifiers) -> KeyEvent {
+    KeyEvent::new(code, modifiers)
+}
+
+#[test]
+fn test_line_keys_scroll_by_one() {
+    let mut scroll = scrolled(5);
+
+    assert!(handle_scroll_key(
+        &mut scroll,
+        key(KeyCode::Char('j'), KeyModifiers::NONE)
+    ));
+    assert_eq!(scroll.offset, 6);
+    assert!(handle_scroll_key(
+        &mut scroll,
+        key(KeyCode::Up, KeyModifiers::NONE)
+    ));
+    assert_eq!(scroll.offset, 5);
+}
+
+#[test]
+fn test_ctrl_d_and_ctrl_u_page() {
+    let mut scroll = scrolled(20);
+
+    handle_scroll_key(&mut scroll, key(KeyCode::Char('d'), KeyModifiers::CONTROL));
+    assert!(scroll.offset > 20);
+    handle_scroll_key(&mut scroll, key(KeyCode::Char('u'), KeyModifiers::CONTROL));
+    assert_eq!(scroll.offset, 20);
+}
+
+#[test]
+fn test_jumps_to_ends() {
+    let mut scroll = scrolled(20);
+
+    handle_scroll_key(&mut scroll, key(KeyCode::Char('G'), KeyModifiers::SHIFT));
+    assert_eq!(scroll.offset, scroll.max_offset);
+    handle_scroll_key(&mut scroll, key(KeyCode::Home, KeyModifiers::NONE));
+    assert_eq!(scroll.offset, 0);
+}
+
+#[test]
+fn test_other_keys_are_left_to_the_popup() {
+    let mut scroll = scrolled(5);
+
+    assert!(!handle_scroll_key(
+        &mut scroll,
+        key(KeyCode::Char('d'), KeyModifiers::NONE)
+    ));
+    assert!(!handle_scroll_key(
+        &mut scroll,
+        key(KeyCode::Esc, KeyModifiers::NONE)
+    ));
+    assert_eq!(scroll.offset, 5);
+}
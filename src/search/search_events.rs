@@ -109,9 +109,9 @@ pub fn handle_search_key(app: &mut App, key: KeyEvent) -> bool {
 
             // Only update matches if query state is available
             if let Some(query_state) = &app.query
-                && let Some(content) = &query_state.last_successful_result_unformatted
+                && let Some(content) = query_state.last_successful_result_text()
             {
-                app.search.update_matches(content);
+                app.search.update_matches(&content);
             }
 
             if let Some(m) = app.search.current_match() {
@@ -10,11 +10,19 @@ pub(super) fn scroll_to_match(app: &mut App) {
         return;
     };
 
-    let target_line = current_match.line.min(u16::MAX as u32) as u16;
     let target_col = current_match.col;
     let match_len = current_match.len;
 
-    app.results_cursor.move_to_line(current_match.line);
+    // A match inside a fold opens it
+    if app.results_fold.reveal(current_match.line as usize) {
+        let rows = app.results_line_count_u32();
+        app.results_cursor.update_total_lines(rows);
+        app.results_scroll
+            .update_bounds(rows, app.results_scroll.viewport_height);
+    }
+    let target_row = app.results_fold.row_of(current_match.line as usize);
+    let target_line = target_row.min(u16::MAX as u32) as u16;
+    app.results_cursor.move_to_line(target_row);
 
     // Vertical scrolling - Neovim-style with scroll margin
     let viewport_height = app.results_scroll.viewport_height;
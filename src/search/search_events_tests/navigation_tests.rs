@@ -18,7 +18,6 @@ fn test_navigation_scrolls_to_match() {
         })
         .collect();
 
-    app.query.as_mut().unwrap().last_successful_result = Some(Arc::new(content.clone()));
     app.query
         .as_mut()
         .unwrap()
@@ -75,8 +74,10 @@ fn test_navigation_scrolls_to_match() {
 #[test]
 fn test_n_navigates_to_next_match() {
     let mut app = test_app(r#"{"name": "test"}"#);
-    app.query.as_mut().unwrap().last_successful_result =
-        Some(Arc::new("test\ntest\ntest".to_string()));
+    app.query
+        .as_mut()
+        .unwrap()
+        .last_successful_result_unformatted = Some(Arc::new("test\ntest\ntest".to_string()));
     open_search(&mut app);
 
     app.search.search_textarea_mut().insert_str("test");
@@ -99,8 +100,10 @@ fn test_n_navigates_to_next_match() {
 #[test]
 fn test_capital_n_navigates_to_prev_match() {
     let mut app = test_app(r#"{"name": "test"}"#);
-    app.query.as_mut().unwrap().last_successful_result =
-        Some(Arc::new("test\ntest\ntest".to_string()));
+    app.query
+        .as_mut()
+        .unwrap()
+        .last_successful_result_unformatted = Some(Arc::new("test\ntest\ntest".to_string()));
     open_search(&mut app);
 
     app.search.search_textarea_mut().insert_str("test");
@@ -117,7 +120,10 @@ fn test_capital_n_navigates_to_prev_match() {
 #[test]
 fn test_enter_navigates_to_next_match() {
     let mut app = test_app(r#"{"name": "test"}"#);
-    app.query.as_mut().unwrap().last_successful_result = Some(Arc::new("test\ntest".to_string()));
+    app.query
+        .as_mut()
+        .unwrap()
+        .last_successful_result_unformatted = Some(Arc::new("test\ntest".to_string()));
     open_search(&mut app);
 
     app.search.search_textarea_mut().insert_str("test");
@@ -134,7 +140,10 @@ fn test_enter_navigates_to_next_match() {
 #[test]
 fn test_shift_enter_navigates_to_prev_match() {
     let mut app = test_app(r#"{"name": "test"}"#);
-    app.query.as_mut().unwrap().last_successful_result = Some(Arc::new("test\ntest".to_string()));
+    app.query
+        .as_mut()
+        .unwrap()
+        .last_successful_result_unformatted = Some(Arc::new("test\ntest".to_string()));
     open_search(&mut app);
 
     app.search.search_textarea_mut().insert_str("test");
@@ -151,7 +160,6 @@ fn test_shift_enter_navigates_to_prev_match() {
 #[test]
 fn test_ctrl_f_reenters_edit_mode_when_confirmed() {
     let mut app = test_app(r#"{"name": "test"}"#);
-    app.query.as_mut().unwrap().last_successful_result = Some(Arc::new("test\ntest".to_string()));
     app.query
         .as_mut()
         .unwrap()
@@ -177,7 +185,6 @@ fn test_ctrl_f_reenters_edit_mode_when_confirmed() {
 #[test]
 fn test_slash_reenters_edit_mode_when_confirmed() {
     let mut app = test_app(r#"{"name": "test"}"#);
-    app.query.as_mut().unwrap().last_successful_result = Some(Arc::new("test\ntest".to_string()));
     app.query
         .as_mut()
         .unwrap()
@@ -200,7 +207,6 @@ fn test_slash_reenters_edit_mode_when_confirmed() {
 #[test]
 fn test_can_type_after_reenter_edit_mode() {
     let mut app = test_app(r#"{"name": "test"}"#);
-    app.query.as_mut().unwrap().last_successful_result = Some(Arc::new("test\ntest".to_string()));
     app.query
         .as_mut()
         .unwrap()
@@ -228,7 +234,6 @@ fn test_can_type_after_reenter_edit_mode() {
 fn test_tab_confirms_search_when_not_confirmed() {
     let mut app = test_app(r#"{"name": "test"}"#);
     let content = "test\ntest\ntest".to_string();
-    app.query.as_mut().unwrap().last_successful_result = Some(Arc::new(content.clone()));
     app.query
         .as_mut()
         .unwrap()
@@ -253,7 +258,6 @@ fn test_tab_unconfirms_search_when_confirmed() {
 
     let mut app = test_app(r#"{"name": "test"}"#);
     let content = "test\ntest\ntest".to_string();
-    app.query.as_mut().unwrap().last_successful_result = Some(Arc::new(content.clone()));
     app.query
         .as_mut()
         .unwrap()
@@ -279,7 +283,6 @@ fn test_tab_unconfirms_search_when_confirmed() {
 fn test_tab_cycles_between_edit_and_navigation_mode() {
     let mut app = test_app(r#"{"name": "test"}"#);
     let content = "test\ntest\ntest".to_string();
-    app.query.as_mut().unwrap().last_successful_result = Some(Arc::new(content.clone()));
     app.query
         .as_mut()
         .unwrap()
@@ -317,7 +320,6 @@ fn test_tab_scrolls_to_current_match_when_confirming() {
         })
         .collect();
 
-    app.query.as_mut().unwrap().last_successful_result = Some(Arc::new(content.clone()));
     app.query
         .as_mut()
         .unwrap()
@@ -348,7 +350,6 @@ fn app_with_confirmed_search() -> crate::app::App {
     let mut app = test_app(r#"{"name": "test"}"#);
 
     let content: String = (0..50).map(|i| format!("line {} test\n", i)).collect();
-    app.query.as_mut().unwrap().last_successful_result = Some(Arc::new(content.clone()));
     app.query
         .as_mut()
         .unwrap()
@@ -562,7 +563,6 @@ proptest! {
         let content: String = (0..content_lines)
             .map(|i| format!("line {} test\n", i))
             .collect();
-        app.query.as_mut().unwrap().last_successful_result = Some(Arc::new(content.clone()));
         app.query.as_mut().unwrap().last_successful_result_unformatted = Some(Arc::new(content.clone()));
         app.query.as_mut().unwrap().result = Ok(content.clone());
 
@@ -634,7 +634,6 @@ proptest! {
         let content: String = (0..20)
             .map(|i| format!("line {} test {}\n", i, "x".repeat(line_width as usize)))
             .collect();
-        app.query.as_mut().unwrap().last_successful_result = Some(Arc::new(content.clone()));
         app.query.as_mut().unwrap().last_successful_result_unformatted = Some(Arc::new(content.clone()));
         app.query.as_mut().unwrap().result = Ok(content.clone());
 
@@ -700,7 +699,6 @@ proptest! {
         let content: String = (0..content_lines)
             .map(|i| format!("line {} test\n", i))
             .collect();
-        app.query.as_mut().unwrap().last_successful_result = Some(Arc::new(content.clone()));
         app.query.as_mut().unwrap().last_successful_result_unformatted = Some(Arc::new(content.clone()));
         app.query.as_mut().unwrap().result = Ok(content.clone());
 
@@ -764,7 +762,6 @@ proptest! {
         let content: String = (0..content_lines)
             .map(|i| format!("line {} test\n", i))
             .collect();
-        app.query.as_mut().unwrap().last_successful_result = Some(Arc::new(content.clone()));
         app.query.as_mut().unwrap().last_successful_result_unformatted = Some(Arc::new(content.clone()));
         app.query.as_mut().unwrap().result = Ok(content.clone());
 
@@ -844,7 +841,7 @@ proptest! {
         let content: String = (0..num_matches)
             .map(|i| format!("line {} {}\n", i, query))
             .collect();
-        app.query.as_mut().unwrap().last_successful_result = Some(Arc::new(content.clone()));
+        app.query.as_mut().unwrap().last_successful_result_unformatted = Some(Arc::new(content.clone()));
         app.search.update_matches(&content);
 
         let matches_before: Vec<Match> = app.search.matches().to_vec();
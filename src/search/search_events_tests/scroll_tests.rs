@@ -190,3 +190,28 @@ proptest! {
         );
     }
 }
+
+#[test]
+fn test_scroll_to_match_opens_fold_hiding_it() {
+    let mut app = test_app(r#"{"spec": {"ports": [80, 443]}, "name": "api"}"#);
+    app.results_scroll.viewport_height = 20;
+    crate::results::results_events::fold_to_depth(&mut app, Some(1));
+    assert_eq!(app.results_line_count_u32(), 4);
+
+    app.search.open();
+    app.search.search_textarea_mut().insert_str("443");
+    let content = app
+        .query
+        .as_ref()
+        .unwrap()
+        .last_successful_result_unformatted
+        .clone()
+        .unwrap();
+    app.search.update_matches(&content);
+
+    scroll::scroll_to_match(&mut app);
+
+    assert_eq!(app.results_cursor_result_line(), 4);
+    assert_eq!(app.results_cursor.cursor_line(), 4);
+    assert_eq!(app.results_line_count_u32(), 9);
+}
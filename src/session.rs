@@ -0,0 +1,143 @@
+//! Named Sessions
+//!
+//! A session records where an investigation left off: the input, the query
+//! and its language, the results scroll position and the variable bindings.
+//! `:session save NAME` writes it to `sessions/NAME.toml` in jiq's data
+//! directory and `jiq --session NAME` picks up from there.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::app::App;
+use crate::input::command_source::CommandSource;
+use crate::query::language::QueryLanguage;
+use crate::query::variables::QueryVariable;
+
+const SESSIONS_DIR: &str = "sessions";
+
+/// Saved state of an investigation
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Session {
+    /// Input file, `None` when the input came from stdin or a command
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub input: Option<PathBuf>,
+    #[serde(default)]
+    pub query: String,
+    #[serde(default)]
+    pub language: QueryLanguage,
+    #[serde(default)]
+    pub scroll: SessionScroll,
+    /// Command the input was read from (`jiq k8s`, `jiq aws`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub command: Option<CommandSource>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub variables: Vec<QueryVariable>,
+}
+
+/// Position in the results pane
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SessionScroll {
+    #[serde(default)]
+    pub offset: u16,
+    #[serde(default)]
+    pub h_offset: u16,
+    #[serde(default)]
+    pub cursor_line: u32,
+}
+
+impl Session {
+    /// Capture the current state of `app`
+    ///
+    /// The input path is made absolute so the session can be resumed from
+    /// any directory.
+    pub fn capture(app: &App) -> Self {
+        Self {
+            input: app
+                .input_path
+                .as_ref()
+                .map(|path| fs::canonicalize(path).unwrap_or_else(|_| path.clone())),
+            query: app.query().to_string(),
+            language: app.query.as_ref().map(|q| q.language).unwrap_or_default(),
+            scroll: SessionScroll {
+                offset: app.results_scroll.offset,
+                h_offset: app.results_scroll.h_offset,
+                cursor_line: app.results_cursor_result_line() as u32,
+            },
+            command: app.input_command.clone(),
+            variables: app.variables.variables().to_vec(),
+        }
+    }
+
+    pub fn parse(content: &str) -> Result<Self, String> {
+        toml::from_str(content).map_err(|e| e.to_string())
+    }
+
+    pub fn to_toml(&self) -> String {
+        toml::to_string_pretty(self).unwrap_or_default()
+    }
+}
+
+/// Check that `name` can be used as a session file name
+pub fn validate_name(name: &str) -> Result<(), String> {
+    if name.is_empty() {
+        return Err("Session name must not be empty".to_string());
+    }
+    if name.starts_with('.')
+        || !name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+    {
+        return Err(format!(
+            "Invalid session name '{}': use letters, digits, '-', '_' and '.'",
+            name
+        ));
+    }
+    Ok(())
+}
+
+pub fn session_path(name: &str) -> Option<PathBuf> {
+    crate::paths::data_dir().map(|p| p.join(SESSIONS_DIR).join(format!("{}.toml", name)))
+}
+
+/// Load the session called `name`
+pub fn load_session(name: &str) -> Result<Session, String> {
+    validate_name(name)?;
+    let path = session_path(name).ok_or("Could not determine sessions directory")?;
+    if !path.exists() {
+        return Err(format!("Session '{}' not found", name));
+    }
+    load_session_from_path(&path)
+}
+
+pub fn load_session_from_path(path: &Path) -> Result<Session, String> {
+    let content =
+        fs::read_to_string(path).map_err(|e| format!("Cannot read {}: {}", path.display(), e))?;
+    Session::parse(&content).map_err(|e| format!("Invalid session {}: {}", path.display(), e))
+}
+
+/// Save `session` as `name`, replacing an existing session of that name
+pub fn save_session(name: &str, session: &Session) -> io::Result<PathBuf> {
+    validate_name(name).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+    let Some(path) = session_path(name) else {
+        return Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            "Could not determine sessions directory",
+        ));
+    };
+    save_session_to_path(&path, session)?;
+    Ok(path)
+}
+
+pub fn save_session_to_path(path: &Path, session: &Session) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, session.to_toml())
+}
+
+#[cfg(test)]
+#[path = "session_tests.rs"]
+mod session_tests;
@@ -0,0 +1,105 @@
+//! Tests for named sessions
+
+use super::*;
+use crate::query::variables::VariableKind;
+use crate::test_utils::test_helpers::app_with_query;
+use tempfile::TempDir;
+
+fn sample_session() -> Session {
+    Session {
+        input: Some(PathBuf::from("/data/cluster.json")),
+        query: ".items[] | select(.status == $state)".to_string(),
+        language: QueryLanguage::Jq,
+        scroll: SessionScroll {
+            offset: 40,
+            h_offset: 8,
+            cursor_line: 45,
+        },
+        command: None,
+        variables: vec![
+            QueryVariable::new("state", "Running", VariableKind::String),
+            QueryVariable::new("limit", "10", VariableKind::Json),
+        ],
+    }
+}
+
+#[test]
+fn test_session_round_trips_through_toml() {
+    let session = sample_session();
+    assert_eq!(Session::parse(&session.to_toml()).unwrap(), session);
+}
+
+#[test]
+fn test_command_session_round_trips_through_toml() {
+    let session = Session {
+        input: None,
+        query: ".items[].metadata.name".to_string(),
+        language: QueryLanguage::JmesPath,
+        command: Some(CommandSource::kubectl("pods", &["-A".to_string()])),
+        ..Session::default()
+    };
+    assert_eq!(Session::parse(&session.to_toml()).unwrap(), session);
+}
+
+#[test]
+fn test_parse_fills_missing_fields_with_defaults() {
+    let session = Session::parse("query = \".name\"\n").unwrap();
+    assert_eq!(session.query, ".name");
+    assert_eq!(session.input, None);
+    assert_eq!(session.language, QueryLanguage::Jq);
+    assert_eq!(session.scroll, SessionScroll::default());
+    assert!(session.variables.is_empty());
+}
+
+#[test]
+fn test_parse_rejects_invalid_toml() {
+    assert!(Session::parse("query = ").is_err());
+}
+
+#[test]
+fn test_save_and_load_from_path() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("sessions").join("incident.toml");
+    let session = sample_session();
+
+    save_session_to_path(&path, &session).unwrap();
+
+    assert_eq!(load_session_from_path(&path).unwrap(), session);
+}
+
+#[test]
+fn test_validate_name() {
+    assert!(validate_name("incident-42").is_ok());
+    assert!(validate_name("q3_report.v2").is_ok());
+    assert!(validate_name("").is_err());
+    assert!(validate_name("../escape").is_err());
+    assert!(validate_name(".hidden").is_err());
+    assert!(validate_name("two words").is_err());
+}
+
+#[test]
+fn test_session_path_uses_sessions_directory() {
+    let path = session_path("incident").unwrap();
+    assert!(path.ends_with("jiq/sessions/incident.toml"));
+}
+
+#[test]
+fn test_capture_records_query_scroll_and_variables() {
+    let mut app = app_with_query(".services");
+    app.results_scroll.offset = 3;
+    app.results_scroll.h_offset = 2;
+    app.variables.set_variables(vec![QueryVariable::new(
+        "env",
+        "prod",
+        VariableKind::String,
+    )]);
+
+    let session = Session::capture(&app);
+
+    assert_eq!(session.query, ".services");
+    assert_eq!(session.language, QueryLanguage::Jq);
+    assert_eq!(session.scroll.offset, 3);
+    assert_eq!(session.scroll.h_offset, 2);
+    assert_eq!(session.input, None);
+    assert_eq!(session.variables, app.variables.variables());
+}
@@ -0,0 +1,122 @@
+//! Shell integration
+//!
+//! Builds standalone `jq` command lines that reproduce the current query,
+//! quoted so they can be pasted into a POSIX shell or script unchanged, and
+//! provides the bash/zsh/fish widgets that insert jiq's output into the
+//! interactive command line.
+
+use std::fs::{File, OpenOptions};
+use std::io::{self, Write};
+use std::path::Path;
+
+use clap::ValueEnum;
+
+use crate::query::library::JqLibrary;
+use crate::query::variables::{self, QueryVariable};
+
+/// Shells with a command line widget
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Shell {
+    Bash,
+    Zsh,
+    Fish,
+}
+
+/// Widget script to be evaluated by the shell's startup file
+pub fn init_script(shell: Shell) -> &'static str {
+    match shell {
+        Shell::Bash => include_str!("shell/jiq.bash"),
+        Shell::Zsh => include_str!("shell/jiq.zsh"),
+        Shell::Fish => include_str!("shell/jiq.fish"),
+    }
+}
+
+/// Open an inherited file descriptor for writing the final output
+///
+/// Lets widgets capture the output on a separate descriptor (e.g.
+/// `jiq --output-fd 3 3>&1 1>/dev/tty`) while the TUI keeps the terminal.
+#[cfg(unix)]
+pub fn open_output_fd(fd: u32) -> io::Result<File> {
+    OpenOptions::new()
+        .write(true)
+        .open(format!("/dev/fd/{}", fd))
+}
+
+#[cfg(not(unix))]
+pub fn open_output_fd(_fd: u32) -> io::Result<File> {
+    Err(io::Error::new(
+        io::ErrorKind::Unsupported,
+        "--output-fd is only supported on Unix",
+    ))
+}
+
+/// Write the final output followed by a newline, or a NUL byte with `print0`
+pub fn write_output(
+    writer: &mut (impl Write + ?Sized),
+    output: &str,
+    print0: bool,
+) -> io::Result<()> {
+    writer.write_all(output.as_bytes())?;
+    writer.write_all(if print0 { b"\0" } else { b"\n" })?;
+    writer.flush()
+}
+
+/// Quote a string for a POSIX shell
+///
+/// Strings made only of safe characters are returned as-is; anything else is
+/// wrapped in single quotes, with embedded single quotes written as `'\''`.
+pub fn quote(s: &str) -> String {
+    let is_safe = !s.is_empty()
+        && s.chars().all(|c| {
+            c.is_ascii_alphanumeric()
+                || matches!(c, '_' | '-' | '.' | '/' | ',' | ':' | '@' | '+' | '=')
+        });
+    if is_safe {
+        return s.to_string();
+    }
+    format!("'{}'", s.replace('\'', r"'\''"))
+}
+
+/// Equivalent `jq` command line for a query, its variables, module library
+/// and input file
+///
+/// The query is always single-quoted so jq syntax is never shell-interpreted.
+/// Without an input path (stdin input) the command reads from stdin as well.
+/// The user library, if any, is included explicitly ahead of the query.
+/// Relative input paths starting with `-` get a `./` prefix so jq doesn't
+/// take them for options.
+pub fn jq_command(
+    query: &str,
+    variables: &[QueryVariable],
+    library: &JqLibrary,
+    input: Option<&Path>,
+) -> String {
+    let query = if query.trim().is_empty() { "." } else { query };
+    let query = match library.prelude() {
+        Some(prelude) => format!("{} {}", prelude, query),
+        None => query.to_string(),
+    };
+    let mut command = "jq".to_string();
+    for arg in library
+        .jq_args()
+        .into_iter()
+        .chain(variables::jq_args(variables))
+    {
+        command.push(' ');
+        command.push_str(&quote(&arg));
+    }
+    command.push_str(&format!(" '{}'", query.replace('\'', r"'\''")));
+    if let Some(path) = input {
+        let path = path.to_string_lossy();
+        command.push(' ');
+        if path.starts_with('-') {
+            command.push_str("./");
+        }
+        command.push_str(&quote(&path));
+    }
+    command
+}
+
+#[cfg(test)]
+#[path = "shell_tests.rs"]
+mod shell_tests;
@@ -0,0 +1,27 @@
+# jiq shell integration for bash
+#
+# Add to ~/.bashrc:
+#   eval "$(jiq --shell-init bash)"
+#
+# Alt+J opens jiq on the file path before the cursor and replaces it with
+# whatever jiq outputs on exit: results (Enter), the query (Ctrl+Q) or a full
+# jq command line (Ctrl+Shift+Q).
+
+__jiq_widget() {
+  local before="${READLINE_LINE:0:READLINE_POINT}"
+  local after="${READLINE_LINE:READLINE_POINT}"
+  local word="${before##*[[:space:]]}"
+  local file="${word/#\~/$HOME}"
+  [[ -n "$word" && -f "$file" ]] || return 0
+
+  local output
+  IFS= read -r -d '' output < <(jiq --print0 --output-fd 3 "$file" 3>&1 1>/dev/tty)
+  [[ -n "$output" ]] || return 0
+
+  before="${before%"$word"}"
+  READLINE_LINE="${before}${output}${after}"
+  READLINE_POINT=$((${#before} + ${#output}))
+}
+
+bind -m emacs-standard -x '"\ej": __jiq_widget'
+bind -m vi-insert -x '"\ej": __jiq_widget'
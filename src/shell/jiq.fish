@@ -0,0 +1,25 @@
+# jiq shell integration for fish
+#
+# Add to ~/.config/fish/config.fish:
+#   jiq --shell-init fish | source
+#
+# Alt+J opens jiq on the file path under the cursor and replaces it with
+# whatever jiq outputs on exit: results (Enter), the query (Ctrl+Q) or a full
+# jq command line (Ctrl+Shift+Q).
+
+function __jiq_widget
+    set -l token (commandline -t)
+    set -l file (string replace -r '^~' $HOME -- $token)
+    if test -z "$token"; or not test -f "$file"
+        return
+    end
+
+    set -l output (jiq --print0 --output-fd 3 $file 3>&1 1>/dev/tty | string split0)
+    if test -n "$output"
+        commandline -t -- $output
+    end
+    commandline -f repaint
+end
+
+bind \ej __jiq_widget
+bind -M insert \ej __jiq_widget
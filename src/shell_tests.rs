@@ -0,0 +1,168 @@
+//! Tests for shell command formatting
+
+use super::*;
+use crate::query::variables::VariableKind;
+use std::path::PathBuf;
+
+#[test]
+fn test_quote_leaves_safe_strings_unquoted() {
+    assert_eq!(quote("data.json"), "data.json");
+    assert_eq!(quote("./logs/app-2024_01.json"), "./logs/app-2024_01.json");
+}
+
+#[test]
+fn test_quote_wraps_strings_with_spaces() {
+    assert_eq!(quote("my data.json"), "'my data.json'");
+}
+
+#[test]
+fn test_quote_escapes_single_quotes() {
+    assert_eq!(quote("it's.json"), r"'it'\''s.json'");
+}
+
+#[test]
+fn test_quote_wraps_shell_metacharacters() {
+    assert_eq!(quote("$HOME/*.json"), "'$HOME/*.json'");
+    assert_eq!(quote("a;rm -rf b"), "'a;rm -rf b'");
+}
+
+#[test]
+fn test_quote_empty_string() {
+    assert_eq!(quote(""), "''");
+}
+
+#[test]
+fn test_jq_command_with_file() {
+    let path = PathBuf::from("file.json");
+    assert_eq!(
+        jq_command(".services[].name", &[], &JqLibrary::default(), Some(&path)),
+        "jq '.services[].name' file.json"
+    );
+}
+
+#[test]
+fn test_jq_command_from_stdin() {
+    assert_eq!(
+        jq_command(".a", &[], &JqLibrary::default(), None),
+        "jq '.a'"
+    );
+}
+
+#[test]
+fn test_jq_command_always_quotes_simple_query() {
+    assert_eq!(
+        jq_command("keys", &[], &JqLibrary::default(), None),
+        "jq 'keys'"
+    );
+}
+
+#[test]
+fn test_jq_command_escapes_single_quotes_in_query() {
+    assert_eq!(
+        jq_command(
+            r#".[] | select(.name == "O'Brien")"#,
+            &[],
+            &JqLibrary::default(),
+            None
+        ),
+        r#"jq '.[] | select(.name == "O'\''Brien")'"#
+    );
+}
+
+#[test]
+fn test_jq_command_keeps_variables_and_strings_literal() {
+    assert_eq!(
+        jq_command(r#". as $x | "\($x.a)""#, &[], &JqLibrary::default(), None),
+        r#"jq '. as $x | "\($x.a)"'"#
+    );
+}
+
+#[test]
+fn test_jq_command_empty_query_uses_identity() {
+    assert_eq!(jq_command("", &[], &JqLibrary::default(), None), "jq '.'");
+    assert_eq!(
+        jq_command("   ", &[], &JqLibrary::default(), None),
+        "jq '.'"
+    );
+}
+
+#[test]
+fn test_jq_command_quotes_path_with_spaces() {
+    let path = PathBuf::from("/tmp/my files/data.json");
+    assert_eq!(
+        jq_command(".", &[], &JqLibrary::default(), Some(&path)),
+        "jq '.' '/tmp/my files/data.json'"
+    );
+}
+
+#[test]
+fn test_jq_command_keeps_dash_path_from_being_an_option() {
+    let path = PathBuf::from("-data.json");
+    assert_eq!(
+        jq_command(".", &[], &JqLibrary::default(), Some(&path)),
+        "jq '.' ./-data.json"
+    );
+    let path = PathBuf::from("-my data.json");
+    assert_eq!(
+        jq_command(".", &[], &JqLibrary::default(), Some(&path)),
+        "jq '.' ./'-my data.json'"
+    );
+}
+
+#[test]
+fn test_write_output_terminates_with_newline() {
+    let mut buf = Vec::new();
+    write_output(&mut buf, ".a", false).unwrap();
+    assert_eq!(buf, b".a\n");
+}
+
+#[test]
+fn test_write_output_print0_terminates_with_nul() {
+    let mut buf = Vec::new();
+    write_output(&mut buf, "{\n  \"a\": 1\n}", true).unwrap();
+    assert_eq!(buf, b"{\n  \"a\": 1\n}\0");
+}
+
+#[test]
+fn test_init_scripts_bind_widget_and_use_fd_handoff() {
+    for shell in [Shell::Bash, Shell::Zsh, Shell::Fish] {
+        let script = init_script(shell);
+        assert!(script.contains("__jiq_widget"), "{:?}", shell);
+        assert!(script.contains("jiq --print0 --output-fd 3"), "{:?}", shell);
+        assert!(script.contains(r"\ej"), "{:?}", shell);
+    }
+}
+
+#[cfg(unix)]
+#[test]
+fn test_open_output_fd_rejects_closed_descriptor() {
+    assert!(open_output_fd(9999).is_err());
+}
+
+#[test]
+fn test_jq_command_includes_variables() {
+    let variables = vec![
+        QueryVariable::new("env", "prod eu", VariableKind::String),
+        QueryVariable::new("limit", "5", VariableKind::Json),
+    ];
+    assert_eq!(
+        jq_command(".[$env][:$limit]", &variables, &JqLibrary::default(), None),
+        "jq --arg env 'prod eu' --argjson limit 5 '.[$env][:$limit]'"
+    );
+}
+
+#[test]
+fn test_jq_command_includes_library() {
+    let dir = tempfile::tempdir().unwrap();
+    let lib = dir.path().join("lib.jq");
+    std::fs::write(&lib, "def double: . * 2;").unwrap();
+    let library = JqLibrary::load(vec!["~/.jq".to_string()], Some(&lib));
+
+    assert_eq!(
+        jq_command(".a | double", &[], &library, None),
+        format!(
+            r#"jq -L '~/.jq' 'include "lib" {{search: "{}"}}; .a | double'"#,
+            dir.path().display()
+        )
+    );
+}
@@ -1,8 +1,15 @@
+mod snippet_apply;
 pub mod snippet_events;
+mod snippet_input;
+mod snippet_list_render;
 mod snippet_matcher;
 pub mod snippet_render;
 mod snippet_state;
 pub mod snippet_storage;
 
+#[allow(unused_imports)]
+pub use snippet_apply::SnippetApplyMode;
+#[allow(unused_imports)]
+pub use snippet_input::SnippetInput;
 #[allow(unused_imports)]
 pub use snippet_state::{Snippet, SnippetMode, SnippetState};
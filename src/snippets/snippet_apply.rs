@@ -0,0 +1,64 @@
+/// Placeholder in a snippet's query standing for the current query in
+/// [`SnippetApplyMode::Wrap`]
+pub const CURRENT_PLACEHOLDER: &str = "<current>";
+
+/// How an applied snippet combines with the query being edited
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SnippetApplyMode {
+    /// The snippet replaces the query
+    #[default]
+    Replace,
+    /// The snippet is piped after the query: `<current> | snippet`
+    Append,
+    /// The query goes into the snippet, at `<current>` or as its argument:
+    /// `snippet(<current>)`
+    Wrap,
+}
+
+impl SnippetApplyMode {
+    /// The next mode in the `Tab` cycle
+    pub fn next(self) -> Self {
+        match self {
+            SnippetApplyMode::Replace => SnippetApplyMode::Append,
+            SnippetApplyMode::Append => SnippetApplyMode::Wrap,
+            SnippetApplyMode::Wrap => SnippetApplyMode::Replace,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            SnippetApplyMode::Replace => "replace",
+            SnippetApplyMode::Append => "append",
+            SnippetApplyMode::Wrap => "wrap",
+        }
+    }
+
+    /// Query resulting from applying `snippet` to `current` in this mode
+    pub fn combine(self, current: &str, snippet: &str) -> String {
+        let current = current.trim();
+        let snippet = snippet.trim();
+        match self {
+            SnippetApplyMode::Replace => snippet.to_string(),
+            SnippetApplyMode::Append => {
+                let current = current.trim_end_matches('|').trim_end();
+                if current.is_empty() || current == "." {
+                    snippet.to_string()
+                } else {
+                    format!("{} | {}", current, snippet)
+                }
+            }
+            SnippetApplyMode::Wrap => {
+                let current = if current.is_empty() { "." } else { current };
+                if snippet.contains(CURRENT_PLACEHOLDER) {
+                    snippet.replace(CURRENT_PLACEHOLDER, current)
+                } else {
+                    format!("{}({})", snippet, current)
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "snippet_apply_tests.rs"]
+mod snippet_apply_tests;
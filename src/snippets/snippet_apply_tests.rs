@@ -0,0 +1,61 @@
+//! Tests for snippets/snippet_apply
+
+use super::*;
+
+#[test]
+fn test_replace_ignores_current_query() {
+    assert_eq!(
+        SnippetApplyMode::Replace.combine(".items", "length"),
+        "length"
+    );
+}
+
+#[test]
+fn test_append_pipes_after_current_query() {
+    assert_eq!(
+        SnippetApplyMode::Append.combine(".items[]", "select(.active)"),
+        ".items[] | select(.active)"
+    );
+}
+
+#[test]
+fn test_append_drops_dangling_pipe() {
+    assert_eq!(
+        SnippetApplyMode::Append.combine(".items | ", "length"),
+        ".items | length"
+    );
+}
+
+#[test]
+fn test_append_to_empty_or_identity_query() {
+    assert_eq!(SnippetApplyMode::Append.combine("", "length"), "length");
+    assert_eq!(SnippetApplyMode::Append.combine(" . ", "length"), "length");
+}
+
+#[test]
+fn test_wrap_substitutes_placeholder() {
+    assert_eq!(
+        SnippetApplyMode::Wrap.combine(".items[]", "[<current>] | length"),
+        "[.items[]] | length"
+    );
+}
+
+#[test]
+fn test_wrap_without_placeholder_passes_query_as_argument() {
+    assert_eq!(
+        SnippetApplyMode::Wrap.combine(".age > 30", "select"),
+        "select(.age > 30)"
+    );
+}
+
+#[test]
+fn test_wrap_empty_query_uses_identity() {
+    assert_eq!(SnippetApplyMode::Wrap.combine("", "[<current>]"), "[.]");
+}
+
+#[test]
+fn test_modes_cycle() {
+    assert_eq!(SnippetApplyMode::Replace.next(), SnippetApplyMode::Append);
+    assert_eq!(SnippetApplyMode::Append.next(), SnippetApplyMode::Wrap);
+    assert_eq!(SnippetApplyMode::Wrap.next(), SnippetApplyMode::Replace);
+}
@@ -1,8 +1,10 @@
 use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use tui_textarea::Input;
 
+use super::snippet_input::mismatch_warning;
 use super::snippet_state::SnippetMode;
 use crate::app::App;
+use crate::query::ResultType;
 
 pub fn handle_snippet_popup_key(app: &mut App, key: KeyEvent) {
     match app.snippets.mode() {
@@ -31,11 +33,36 @@ fn handle_browse_mode(app: &mut App, key: KeyEvent) {
         }
         KeyCode::Enter => {
             if let Some(snippet) = app.snippets.selected_snippet() {
+                let warning =
+                    mismatch_warning(&snippet.name, snippet.input, app.snippets.result_type());
                 let query = snippet.query.clone();
                 apply_snippet(app, &query);
+                if let Some(warning) = warning {
+                    app.notification.show_warning(&warning);
+                }
             }
             app.snippets.close();
         }
+        KeyCode::Tab => {
+            let mode = app.snippets.cycle_apply_mode();
+            app.notification
+                .show(&format!("Apply mode: {}", mode.label()));
+        }
+        KeyCode::Char('t') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            match app.snippets.cycle_input() {
+                Ok(input) => app
+                    .notification
+                    .show(&format!("Snippet expects {}", input.with_article())),
+                Err(e) => app.notification.show_warning(&e),
+            }
+        }
+        KeyCode::Char(c @ '1'..='9') if key.modifiers.contains(KeyModifiers::ALT) => {
+            let slot = c as u8 - b'0';
+            match app.snippets.toggle_slot(slot) {
+                Ok(message) => app.notification.show(&message),
+                Err(e) => app.notification.show_warning(&e),
+            }
+        }
         KeyCode::Char('n') if key.modifiers.contains(KeyModifiers::CONTROL) => {
             let current_query = app.input.query().to_string();
             app.snippets.enter_create_mode(&current_query);
@@ -263,10 +290,48 @@ fn handle_confirm_update_mode(app: &mut App, key: KeyEvent) {
     }
 }
 
-fn apply_snippet(app: &mut App, query: &str) {
+/// Apply the snippet in quick slot `slot` (`Alt+1`..`Alt+9`) without
+/// opening the manager
+pub fn apply_slot(app: &mut App, slot: u8) {
+    let result_type = current_result_type(app);
+    let Some(snippet) = app.snippets.slot_snippet(slot) else {
+        app.notification
+            .show_warning(&format!("No snippet assigned to Alt+{}", slot));
+        return;
+    };
+    let warning = mismatch_warning(&snippet.name, snippet.input, result_type.as_ref());
+    let (name, query) = (snippet.name.clone(), snippet.query.clone());
+    apply_snippet(app, &query);
+    match warning {
+        Some(warning) => app.notification.show_warning(&warning),
+        None => app.notification.show(&format!("Applied '{}'", name)),
+    }
+}
+
+/// Open the snippet manager, listing the snippets suiting the current
+/// result first
+pub fn open_manager(app: &mut App) {
+    let result_type = current_result_type(app);
+    app.snippets.set_result_type(result_type);
+    app.snippets.open();
+}
+
+fn current_result_type(app: &App) -> Option<ResultType> {
+    app.query
+        .as_ref()
+        .and_then(|query| query.base_type_for_suggestions.clone())
+}
+
+/// Put `snippet` into the query box, combined with the current query
+/// according to the manager's apply mode, and run it
+fn apply_snippet(app: &mut App, snippet: &str) {
+    let query = app
+        .snippets
+        .apply_mode()
+        .combine(app.input.query(), snippet);
     app.input.textarea.delete_line_by_head();
     app.input.textarea.delete_line_by_end();
-    app.input.textarea.insert_str(query);
+    app.input.textarea.insert_str(&query);
 
     let query_text = app.input.textarea.lines()[0].as_ref();
     if let Some(query_state) = &mut app.query {
@@ -276,6 +341,7 @@ fn apply_snippet(app: &mut App, query: &str) {
     app.results_scroll.reset();
     app.results_cursor.reset();
     app.error_overlay_visible = false;
+    app.update_tooltip();
 }
 
 #[cfg(test)]
@@ -1,3 +1,7 @@
+#[path = "snippet_events_tests/applicability_tests.rs"]
+mod applicability_tests;
+#[path = "snippet_events_tests/apply_mode_tests.rs"]
+mod apply_mode_tests;
 #[path = "snippet_events_tests/apply_tests.rs"]
 mod apply_tests;
 #[path = "snippet_events_tests/create_tests.rs"]
@@ -18,3 +22,5 @@ mod rename_tests;
 mod replace_tests;
 #[path = "snippet_events_tests/search_tests.rs"]
 mod search_tests;
+#[path = "snippet_events_tests/slot_tests.rs"]
+mod slot_tests;
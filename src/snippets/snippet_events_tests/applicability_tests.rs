@@ -0,0 +1,64 @@
+use crate::editor::EditorMode;
+use crate::query::ResultType;
+use crate::snippets::{Snippet, SnippetInput};
+use crate::test_utils::test_helpers::{app_with_query, key, key_with_mods};
+use crossterm::event::{KeyCode, KeyModifiers};
+
+fn app_with_object_result() -> crate::app::App {
+    let mut app = app_with_query(".");
+    app.snippets.disable_persistence();
+    app.input.editor_mode = EditorMode::Insert;
+    if let Some(query) = &mut app.query {
+        query.base_type_for_suggestions = Some(ResultType::Object);
+    }
+    app
+}
+
+fn array_snippet() -> Snippet {
+    Snippet {
+        name: "First".to_string(),
+        query: ".[0]".to_string(),
+        description: None,
+        input: SnippetInput::Array,
+    }
+}
+
+#[test]
+fn test_applying_mismatched_snippet_warns_and_applies() {
+    let mut app = app_with_object_result();
+    app.handle_key_event(key_with_mods(KeyCode::Char('s'), KeyModifiers::CONTROL));
+    app.snippets.set_snippets(vec![array_snippet()]);
+
+    app.handle_key_event(key(KeyCode::Enter));
+
+    assert_eq!(app.input.query(), ".[0]");
+    assert_eq!(
+        app.notification.current_message(),
+        Some("'First' expects an array, but the result is an object")
+    );
+}
+
+#[test]
+fn test_ctrl_t_cycles_expected_input() {
+    let mut app = app_with_object_result();
+    app.handle_key_event(key_with_mods(KeyCode::Char('s'), KeyModifiers::CONTROL));
+    app.snippets.set_snippets(vec![array_snippet()]);
+
+    app.handle_key_event(key_with_mods(KeyCode::Char('t'), KeyModifiers::CONTROL));
+
+    assert_eq!(app.snippets.snippets()[0].input, SnippetInput::Any);
+    assert_eq!(
+        app.notification.current_message(),
+        Some("Snippet expects any input")
+    );
+    assert!(app.snippets.is_visible());
+}
+
+#[test]
+fn test_manager_opens_with_result_type() {
+    let mut app = app_with_object_result();
+
+    app.handle_key_event(key_with_mods(KeyCode::Char('s'), KeyModifiers::CONTROL));
+
+    assert_eq!(app.snippets.result_type(), Some(&ResultType::Object));
+}
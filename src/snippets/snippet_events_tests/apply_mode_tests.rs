@@ -0,0 +1,75 @@
+use crate::editor::EditorMode;
+use crate::snippets::{Snippet, SnippetApplyMode};
+use crate::test_utils::test_helpers::{app_with_query, key, key_with_mods};
+use crossterm::event::{KeyCode, KeyModifiers};
+
+fn app_with_snippet(current: &str, snippet_query: &str) -> crate::app::App {
+    let mut app = app_with_query(current);
+    app.snippets.disable_persistence();
+    app.input.editor_mode = EditorMode::Insert;
+    app.handle_key_event(key_with_mods(KeyCode::Char('s'), KeyModifiers::CONTROL));
+    app.snippets.set_snippets(vec![Snippet {
+        name: "Snippet".to_string(),
+        query: snippet_query.to_string(),
+        description: None,
+        input: Default::default(),
+    }]);
+    app
+}
+
+#[test]
+fn test_tab_cycles_apply_mode() {
+    let mut app = app_with_snippet(".items", "length");
+
+    app.handle_key_event(key(KeyCode::Tab));
+
+    assert_eq!(app.snippets.apply_mode(), SnippetApplyMode::Append);
+    assert_eq!(
+        app.notification.current_message(),
+        Some("Apply mode: append")
+    );
+    assert!(app.snippets.is_visible());
+    assert_eq!(app.snippets.search_textarea_mut().lines()[0], "");
+}
+
+#[test]
+fn test_enter_replaces_query_by_default() {
+    let mut app = app_with_snippet(".items", "length");
+
+    app.handle_key_event(key(KeyCode::Enter));
+
+    assert_eq!(app.input.query(), "length");
+}
+
+#[test]
+fn test_enter_appends_in_append_mode() {
+    let mut app = app_with_snippet(".items[]", "select(.active)");
+    app.handle_key_event(key(KeyCode::Tab));
+
+    app.handle_key_event(key(KeyCode::Enter));
+
+    assert_eq!(app.input.query(), ".items[] | select(.active)");
+    assert!(!app.snippets.is_visible());
+}
+
+#[test]
+fn test_enter_wraps_in_wrap_mode() {
+    let mut app = app_with_snippet(".items[]", "[<current>] | length");
+    app.handle_key_event(key(KeyCode::Tab));
+    app.handle_key_event(key(KeyCode::Tab));
+
+    app.handle_key_event(key(KeyCode::Enter));
+
+    assert_eq!(app.input.query(), "[.items[]] | length");
+}
+
+#[test]
+fn test_apply_mode_is_kept_after_closing() {
+    let mut app = app_with_snippet(".items", "length");
+    app.handle_key_event(key(KeyCode::Tab));
+    app.handle_key_event(key(KeyCode::Esc));
+
+    app.handle_key_event(key_with_mods(KeyCode::Char('s'), KeyModifiers::CONTROL));
+
+    assert_eq!(app.snippets.apply_mode(), SnippetApplyMode::Append);
+}
@@ -16,11 +16,13 @@ fn test_enter_applies_selected_snippet_and_closes_popup() {
             name: "test1".to_string(),
             query: ".foo".to_string(),
             description: None,
+            input: Default::default(),
         },
         Snippet {
             name: "test2".to_string(),
             query: ".bar".to_string(),
             description: None,
+            input: Default::default(),
         },
     ]);
 
@@ -42,11 +44,13 @@ fn test_enter_applies_snippet_after_navigation() {
             name: "test1".to_string(),
             query: ".foo".to_string(),
             description: None,
+            input: Default::default(),
         },
         Snippet {
             name: "test2".to_string(),
             query: ".bar".to_string(),
             description: None,
+            input: Default::default(),
         },
     ]);
 
@@ -70,6 +74,7 @@ fn test_enter_replaces_existing_query() {
         name: "test".to_string(),
         query: ".new_query".to_string(),
         description: None,
+        input: Default::default(),
     }]);
 
     app.handle_key_event(key(KeyCode::Enter));
@@ -89,6 +94,7 @@ fn test_enter_clears_error_overlay() {
         name: "test".to_string(),
         query: ".foo".to_string(),
         description: None,
+        input: Default::default(),
     }]);
 
     app.handle_key_event(key(KeyCode::Enter));
@@ -108,6 +114,7 @@ fn test_enter_resets_scroll_position() {
         name: "test".to_string(),
         query: ".foo".to_string(),
         description: None,
+        input: Default::default(),
     }]);
 
     app.handle_key_event(key(KeyCode::Enter));
@@ -142,6 +149,7 @@ fn test_enter_executes_query() {
         name: "keys query".to_string(),
         query: "keys".to_string(),
         description: Some("Get all keys".to_string()),
+        input: Default::default(),
     }]);
 
     app.handle_key_event(key(KeyCode::Enter));
@@ -258,6 +258,7 @@ fn test_duplicate_name_shows_error_notification() {
         name: "Existing".to_string(),
         query: ".foo".to_string(),
         description: None,
+        input: Default::default(),
     }]);
     app.handle_key_event(key_with_mods(KeyCode::Char('n'), KeyModifiers::CONTROL));
 
@@ -288,6 +289,7 @@ fn test_case_insensitive_duplicate_shows_notification() {
         name: "MySnippet".to_string(),
         query: ".foo".to_string(),
         description: None,
+        input: Default::default(),
     }]);
     app.handle_key_event(key_with_mods(KeyCode::Char('n'), KeyModifiers::CONTROL));
 
@@ -317,6 +319,7 @@ fn test_new_snippets_appear_at_top_of_list() {
         name: "Old".to_string(),
         query: ".old".to_string(),
         description: None,
+        input: Default::default(),
     }]);
     app.handle_key_event(key_with_mods(KeyCode::Char('n'), KeyModifiers::CONTROL));
 
@@ -14,6 +14,7 @@ fn test_d_key_enters_delete_mode() {
         name: "My Snippet".to_string(),
         query: ".test".to_string(),
         description: None,
+        input: Default::default(),
     }]);
     app.snippets.on_search_input_changed();
 
@@ -51,6 +52,7 @@ fn test_enter_confirms_delete() {
         name: "My Snippet".to_string(),
         query: ".test".to_string(),
         description: None,
+        input: Default::default(),
     }]);
     app.snippets.on_search_input_changed();
     app.handle_key_event(key_with_mods(KeyCode::Char('d'), KeyModifiers::CONTROL));
@@ -72,6 +74,7 @@ fn test_esc_cancels_delete() {
         name: "My Snippet".to_string(),
         query: ".test".to_string(),
         description: None,
+        input: Default::default(),
     }]);
     app.snippets.on_search_input_changed();
     app.handle_key_event(key_with_mods(KeyCode::Char('d'), KeyModifiers::CONTROL));
@@ -94,6 +97,7 @@ fn test_other_keys_ignored_in_confirm_delete_mode() {
         name: "My Snippet".to_string(),
         query: ".test".to_string(),
         description: None,
+        input: Default::default(),
     }]);
     app.snippets.on_search_input_changed();
     app.handle_key_event(key_with_mods(KeyCode::Char('d'), KeyModifiers::CONTROL));
@@ -123,11 +127,13 @@ fn test_delete_adjusts_selection_when_deleting_last() {
             name: "First".to_string(),
             query: ".first".to_string(),
             description: None,
+            input: Default::default(),
         },
         Snippet {
             name: "Second".to_string(),
             query: ".second".to_string(),
             description: None,
+            input: Default::default(),
         },
     ]);
     app.snippets.on_search_input_changed();
@@ -151,6 +157,7 @@ fn test_is_editing_false_in_confirm_delete_mode() {
         name: "My Snippet".to_string(),
         query: ".test".to_string(),
         description: None,
+        input: Default::default(),
     }]);
     app.snippets.on_search_input_changed();
 
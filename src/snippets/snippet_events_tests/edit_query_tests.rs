@@ -14,6 +14,7 @@ fn test_e_key_enters_edit_mode() {
         name: "My Snippet".to_string(),
         query: ".test | keys".to_string(),
         description: None,
+        input: Default::default(),
     }]);
     app.snippets.on_search_input_changed();
 
@@ -51,6 +52,7 @@ fn test_esc_in_edit_mode_cancels() {
         name: "My Snippet".to_string(),
         query: ".test".to_string(),
         description: None,
+        input: Default::default(),
     }]);
     app.snippets.on_search_input_changed();
     app.handle_key_event(key_with_mods(KeyCode::Char('e'), KeyModifiers::CONTROL));
@@ -72,6 +74,7 @@ fn test_tab_in_edit_name_mode_saves_and_navigates_to_query() {
         name: "Old".to_string(),
         query: ".old".to_string(),
         description: None,
+        input: Default::default(),
     }]);
     app.snippets.on_search_input_changed();
     app.handle_key_event(key_with_mods(KeyCode::Char('e'), KeyModifiers::CONTROL));
@@ -101,6 +104,7 @@ fn test_typing_in_edit_name_mode_updates_name() {
         name: "Old".to_string(),
         query: ".test".to_string(),
         description: None,
+        input: Default::default(),
     }]);
     app.snippets.on_search_input_changed();
     app.handle_key_event(key_with_mods(KeyCode::Char('e'), KeyModifiers::CONTROL));
@@ -126,6 +130,7 @@ fn test_enter_in_edit_name_mode_saves_and_exits() {
         name: "Old".to_string(),
         query: ".test".to_string(),
         description: None,
+        input: Default::default(),
     }]);
     app.snippets.on_search_input_changed();
     app.handle_key_event(key_with_mods(KeyCode::Char('e'), KeyModifiers::CONTROL));
@@ -153,6 +158,7 @@ fn test_edit_name_empty_shows_error() {
         name: "Old".to_string(),
         query: ".test".to_string(),
         description: None,
+        input: Default::default(),
     }]);
     app.snippets.on_search_input_changed();
     app.handle_key_event(key_with_mods(KeyCode::Char('e'), KeyModifiers::CONTROL));
@@ -179,6 +185,7 @@ fn test_is_editing_true_in_edit_mode() {
         name: "My Snippet".to_string(),
         query: ".test".to_string(),
         description: None,
+        input: Default::default(),
     }]);
     app.snippets.on_search_input_changed();
     assert!(!app.snippets.is_editing());
@@ -198,6 +205,7 @@ fn test_question_mark_blocked_in_edit_mode() {
         name: "My Snippet".to_string(),
         query: ".old".to_string(),
         description: None,
+        input: Default::default(),
     }]);
     app.snippets.on_search_input_changed();
     app.handle_key_event(key_with_mods(KeyCode::Char('e'), KeyModifiers::CONTROL));
@@ -219,6 +227,7 @@ fn test_full_edit_flow_name_query_description() {
         name: "OldName".to_string(),
         query: ".old".to_string(),
         description: Some("Old desc".to_string()),
+        input: Default::default(),
     }]);
     app.snippets.on_search_input_changed();
 
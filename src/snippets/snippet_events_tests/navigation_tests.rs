@@ -16,11 +16,13 @@ fn test_down_arrow_navigates_to_next_snippet() {
             name: "test1".to_string(),
             query: ".".to_string(),
             description: None,
+            input: Default::default(),
         },
         Snippet {
             name: "test2".to_string(),
             query: ".".to_string(),
             description: None,
+            input: Default::default(),
         },
     ]);
     assert_eq!(app.snippets.selected_index(), 0);
@@ -40,11 +42,13 @@ fn test_up_arrow_navigates_to_prev_snippet() {
             name: "test1".to_string(),
             query: ".".to_string(),
             description: None,
+            input: Default::default(),
         },
         Snippet {
             name: "test2".to_string(),
             query: ".".to_string(),
             description: None,
+            input: Default::default(),
         },
     ]);
 
@@ -90,11 +94,13 @@ fn test_navigation_stops_at_last_item() {
             name: "test1".to_string(),
             query: ".".to_string(),
             description: None,
+            input: Default::default(),
         },
         Snippet {
             name: "test2".to_string(),
             query: ".".to_string(),
             description: None,
+            input: Default::default(),
         },
     ]);
 
@@ -116,11 +122,13 @@ fn test_navigation_stops_at_first_item() {
             name: "test1".to_string(),
             query: ".".to_string(),
             description: None,
+            input: Default::default(),
         },
         Snippet {
             name: "test2".to_string(),
             query: ".".to_string(),
             description: None,
+            input: Default::default(),
         },
     ]);
     assert_eq!(app.snippets.selected_index(), 0);
@@ -14,6 +14,7 @@ fn test_e_key_enters_edit_name_mode() {
         name: "My Snippet".to_string(),
         query: ".test".to_string(),
         description: None,
+        input: Default::default(),
     }]);
     app.snippets.on_search_input_changed();
 
@@ -51,6 +52,7 @@ fn test_esc_in_edit_name_mode_cancels() {
         name: "My Snippet".to_string(),
         query: ".test".to_string(),
         description: None,
+        input: Default::default(),
     }]);
     app.snippets.on_search_input_changed();
     app.handle_key_event(key_with_mods(KeyCode::Char('e'), KeyModifiers::CONTROL));
@@ -72,6 +74,7 @@ fn test_typing_in_edit_name_mode_updates_name() {
         name: "Old".to_string(),
         query: ".test".to_string(),
         description: None,
+        input: Default::default(),
     }]);
     app.snippets.on_search_input_changed();
     app.handle_key_event(key_with_mods(KeyCode::Char('e'), KeyModifiers::CONTROL));
@@ -97,6 +100,7 @@ fn test_enter_in_edit_name_mode_saves_and_exits() {
         name: "Old".to_string(),
         query: ".test".to_string(),
         description: None,
+        input: Default::default(),
     }]);
     app.snippets.on_search_input_changed();
     app.handle_key_event(key_with_mods(KeyCode::Char('e'), KeyModifiers::CONTROL));
@@ -124,6 +128,7 @@ fn test_edit_name_empty_shows_error() {
         name: "Old".to_string(),
         query: ".test".to_string(),
         description: None,
+        input: Default::default(),
     }]);
     app.snippets.on_search_input_changed();
     app.handle_key_event(key_with_mods(KeyCode::Char('e'), KeyModifiers::CONTROL));
@@ -151,11 +156,13 @@ fn test_edit_name_duplicate_shows_error() {
             name: "First".to_string(),
             query: ".first".to_string(),
             description: None,
+            input: Default::default(),
         },
         Snippet {
             name: "Second".to_string(),
             query: ".second".to_string(),
             description: None,
+            input: Default::default(),
         },
     ]);
     app.snippets.on_search_input_changed();
@@ -189,6 +196,7 @@ fn test_edit_same_name_succeeds() {
         name: "My Snippet".to_string(),
         query: ".test".to_string(),
         description: None,
+        input: Default::default(),
     }]);
     app.snippets.on_search_input_changed();
     app.handle_key_event(key_with_mods(KeyCode::Char('e'), KeyModifiers::CONTROL));
@@ -210,6 +218,7 @@ fn test_is_editing_true_in_edit_name_mode() {
         name: "My Snippet".to_string(),
         query: ".test".to_string(),
         description: None,
+        input: Default::default(),
     }]);
     app.snippets.on_search_input_changed();
     assert!(!app.snippets.is_editing());
@@ -229,6 +238,7 @@ fn test_question_mark_blocked_in_edit_name_mode() {
         name: "Old".to_string(),
         query: ".test".to_string(),
         description: None,
+        input: Default::default(),
     }]);
     app.snippets.on_search_input_changed();
     app.handle_key_event(key_with_mods(KeyCode::Char('e'), KeyModifiers::CONTROL));
@@ -14,6 +14,7 @@ fn test_ctrl_r_enters_replace_mode() {
         name: "My Snippet".to_string(),
         query: ".old_query".to_string(),
         description: None,
+        input: Default::default(),
     }]);
     app.snippets.on_search_input_changed();
 
@@ -52,6 +53,7 @@ fn test_ctrl_r_with_identical_query_shows_warning() {
         name: "My Snippet".to_string(),
         query: ".same".to_string(),
         description: None,
+        input: Default::default(),
     }]);
     app.snippets.on_search_input_changed();
 
@@ -72,6 +74,7 @@ fn test_enter_confirms_replace() {
         name: "My Snippet".to_string(),
         query: ".old_query".to_string(),
         description: None,
+        input: Default::default(),
     }]);
     app.snippets.on_search_input_changed();
     app.handle_key_event(key_with_mods(KeyCode::Char('r'), KeyModifiers::CONTROL));
@@ -93,6 +96,7 @@ fn test_esc_cancels_replace() {
         name: "My Snippet".to_string(),
         query: ".old_query".to_string(),
         description: None,
+        input: Default::default(),
     }]);
     app.snippets.on_search_input_changed();
     app.handle_key_event(key_with_mods(KeyCode::Char('r'), KeyModifiers::CONTROL));
@@ -115,6 +119,7 @@ fn test_other_keys_ignored_in_confirm_replace_mode() {
         name: "My Snippet".to_string(),
         query: ".old_query".to_string(),
         description: None,
+        input: Default::default(),
     }]);
     app.snippets.on_search_input_changed();
     app.handle_key_event(key_with_mods(KeyCode::Char('r'), KeyModifiers::CONTROL));
@@ -143,6 +148,7 @@ fn test_replace_preserves_other_snippet_fields() {
         name: "My Snippet".to_string(),
         query: ".old_query".to_string(),
         description: Some("A description".to_string()),
+        input: Default::default(),
     }]);
     app.snippets.on_search_input_changed();
     app.handle_key_event(key_with_mods(KeyCode::Char('r'), KeyModifiers::CONTROL));
@@ -167,6 +173,7 @@ fn test_is_editing_false_in_confirm_replace_mode() {
         name: "My Snippet".to_string(),
         query: ".old_query".to_string(),
         description: None,
+        input: Default::default(),
     }]);
     app.snippets.on_search_input_changed();
 
@@ -15,16 +15,19 @@ fn test_typing_filters_snippets() {
             name: "flat array".to_string(),
             query: "flatten".to_string(),
             description: None,
+            input: Default::default(),
         },
         Snippet {
             name: "sort data".to_string(),
             query: "sort".to_string(),
             description: None,
+            input: Default::default(),
         },
         Snippet {
             name: "flat map".to_string(),
             query: "map(flatten)".to_string(),
             description: None,
+            input: Default::default(),
         },
     ]);
 
@@ -51,11 +54,13 @@ fn test_search_then_select_applies_filtered_snippet() {
             name: "Flatten arrays".to_string(),
             query: "flatten".to_string(),
             description: None,
+            input: Default::default(),
         },
         Snippet {
             name: "First data".to_string(),
             query: "first".to_string(),
             description: None,
+            input: Default::default(),
         },
     ]);
 
@@ -100,6 +105,7 @@ fn test_search_clears_when_popup_closes() {
         name: "test".to_string(),
         query: ".".to_string(),
         description: None,
+        input: Default::default(),
     }]);
 
     app.handle_key_event(key(KeyCode::Char('z')));
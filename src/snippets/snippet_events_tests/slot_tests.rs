@@ -0,0 +1,64 @@
+use crate::editor::EditorMode;
+use crate::snippets::Snippet;
+use crate::test_utils::test_helpers::{app_with_query, key_with_mods};
+use crossterm::event::{KeyCode, KeyModifiers};
+
+fn snippets() -> Vec<Snippet> {
+    vec![
+        Snippet {
+            name: "Names".to_string(),
+            query: ".[].name".to_string(),
+            description: None,
+            input: Default::default(),
+        },
+        Snippet {
+            name: "Count".to_string(),
+            query: "length".to_string(),
+            description: None,
+            input: Default::default(),
+        },
+    ]
+}
+
+#[test]
+fn test_alt_digit_in_manager_assigns_slot() {
+    let mut app = app_with_query("");
+    app.snippets.disable_persistence();
+    app.input.editor_mode = EditorMode::Insert;
+    app.handle_key_event(key_with_mods(KeyCode::Char('s'), KeyModifiers::CONTROL));
+    app.snippets.set_snippets(snippets());
+
+    app.handle_key_event(key_with_mods(KeyCode::Char('2'), KeyModifiers::ALT));
+
+    assert!(app.snippets.is_visible());
+    assert_eq!(app.snippets.slot_of("Names"), Some(2));
+    assert_eq!(app.input.query(), "");
+}
+
+#[test]
+fn test_alt_digit_outside_manager_applies_slot() {
+    let mut app = app_with_query(".old");
+    app.snippets.disable_persistence();
+    app.input.editor_mode = EditorMode::Insert;
+    app.handle_key_event(key_with_mods(KeyCode::Char('s'), KeyModifiers::CONTROL));
+    app.snippets.set_snippets(snippets());
+    app.snippets.set_selected_index(1);
+    app.handle_key_event(key_with_mods(KeyCode::Char('7'), KeyModifiers::ALT));
+    app.snippets.close();
+
+    app.handle_key_event(key_with_mods(KeyCode::Char('7'), KeyModifiers::ALT));
+
+    assert!(!app.snippets.is_visible());
+    assert_eq!(app.input.query(), "length");
+}
+
+#[test]
+fn test_alt_digit_on_empty_slot_keeps_query() {
+    let mut app = app_with_query(".old");
+    app.snippets.disable_persistence();
+    app.input.editor_mode = EditorMode::Insert;
+
+    app.handle_key_event(key_with_mods(KeyCode::Char('9'), KeyModifiers::ALT));
+
+    assert_eq!(app.input.query(), ".old");
+}
@@ -0,0 +1,98 @@
+use serde::{Deserialize, Serialize};
+
+use crate::query::ResultType;
+
+/// Kind of value a snippet is written for, set with `input = "array"` in
+/// `snippets.toml` or `Ctrl+T` in the snippet manager
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SnippetInput {
+    #[default]
+    Any,
+    Object,
+    Array,
+}
+
+impl SnippetInput {
+    pub fn is_any(&self) -> bool {
+        *self == SnippetInput::Any
+    }
+
+    /// Whether a snippet expecting this input suits a result of
+    /// `result_type`; anything goes while the type is unknown
+    pub fn matches(self, result_type: Option<&ResultType>) -> bool {
+        let Some(result_type) = result_type else {
+            return true;
+        };
+        match self {
+            SnippetInput::Any => true,
+            SnippetInput::Object => matches!(
+                result_type,
+                ResultType::Object | ResultType::DestructuredObjects
+            ),
+            SnippetInput::Array => {
+                matches!(result_type, ResultType::Array | ResultType::ArrayOfObjects)
+            }
+        }
+    }
+
+    /// The next kind in the `Ctrl+T` cycle
+    pub fn next(self) -> Self {
+        match self {
+            SnippetInput::Any => SnippetInput::Object,
+            SnippetInput::Object => SnippetInput::Array,
+            SnippetInput::Array => SnippetInput::Any,
+        }
+    }
+
+    /// Label shown after the snippet's name
+    pub fn label(self) -> &'static str {
+        match self {
+            SnippetInput::Any => "any",
+            SnippetInput::Object => "object",
+            SnippetInput::Array => "array",
+        }
+    }
+
+    /// `an object`, `an array`, for warnings
+    pub fn with_article(self) -> &'static str {
+        match self {
+            SnippetInput::Any => "any input",
+            SnippetInput::Object => "an object",
+            SnippetInput::Array => "an array",
+        }
+    }
+}
+
+/// Warning shown when applying the snippet `name`, written for `input`, to
+/// a result of another type
+pub fn mismatch_warning(
+    name: &str,
+    input: SnippetInput,
+    result_type: Option<&ResultType>,
+) -> Option<String> {
+    let result_type = result_type.filter(|t| !input.matches(Some(t)))?;
+    Some(format!(
+        "'{}' expects {}, but the result is {}",
+        name,
+        input.with_article(),
+        describe_result(result_type)
+    ))
+}
+
+/// How a result of `result_type` is described in warnings
+fn describe_result(result_type: &ResultType) -> &'static str {
+    match result_type {
+        ResultType::Object => "an object",
+        ResultType::DestructuredObjects => "a stream of objects",
+        ResultType::Array | ResultType::ArrayOfObjects => "an array",
+        ResultType::String => "a string",
+        ResultType::Number => "a number",
+        ResultType::Boolean => "a boolean",
+        ResultType::Null => "null",
+    }
+}
+
+#[cfg(test)]
+#[path = "snippet_input_tests.rs"]
+mod snippet_input_tests;
@@ -0,0 +1,44 @@
+//! Tests for snippets/snippet_input
+
+use super::*;
+
+#[test]
+fn test_any_matches_every_result() {
+    assert!(SnippetInput::Any.matches(Some(&ResultType::String)));
+    assert!(SnippetInput::Any.matches(None));
+}
+
+#[test]
+fn test_object_and_array_match_their_results() {
+    assert!(SnippetInput::Object.matches(Some(&ResultType::Object)));
+    assert!(SnippetInput::Object.matches(Some(&ResultType::DestructuredObjects)));
+    assert!(!SnippetInput::Object.matches(Some(&ResultType::ArrayOfObjects)));
+    assert!(SnippetInput::Array.matches(Some(&ResultType::Array)));
+    assert!(SnippetInput::Array.matches(Some(&ResultType::ArrayOfObjects)));
+    assert!(!SnippetInput::Array.matches(Some(&ResultType::Number)));
+}
+
+#[test]
+fn test_unknown_result_matches() {
+    assert!(SnippetInput::Array.matches(None));
+}
+
+#[test]
+fn test_next_cycles_through_kinds() {
+    assert_eq!(SnippetInput::Any.next(), SnippetInput::Object);
+    assert_eq!(SnippetInput::Object.next(), SnippetInput::Array);
+    assert_eq!(SnippetInput::Array.next(), SnippetInput::Any);
+}
+
+#[test]
+fn test_mismatch_warning() {
+    assert_eq!(
+        mismatch_warning("Names", SnippetInput::Array, Some(&ResultType::Object)).as_deref(),
+        Some("'Names' expects an array, but the result is an object")
+    );
+    assert_eq!(
+        mismatch_warning("Names", SnippetInput::Array, Some(&ResultType::Array)),
+        None
+    );
+    assert_eq!(mismatch_warning("Names", SnippetInput::Array, None), None);
+}
@@ -0,0 +1,168 @@
+use ratatui::{
+    style::{Modifier, Style},
+    text::{Line, Span},
+};
+
+use super::snippet_apply::SnippetApplyMode;
+use super::snippet_state::{Snippet, SnippetState};
+use crate::theme;
+use crate::widgets::text_width::{display_width, truncate_to_width};
+
+pub(super) fn build_list_content_from_visible(
+    state: &SnippetState,
+    area_width: u16,
+    hovered_index: Option<usize>,
+) -> Vec<Line<'static>> {
+    if state.filtered_count() == 0 {
+        let message = if state.snippets().is_empty() {
+            "   No snippets yet. Press Ctrl+N to create one."
+        } else {
+            "   No matches"
+        };
+        vec![Line::from(vec![Span::styled(
+            message,
+            Style::default().fg(theme::snippets::DESCRIPTION),
+        )])]
+    } else {
+        let selected_index = state.selected_index();
+        let max_width = area_width.saturating_sub(4) as usize;
+
+        state
+            .visible_snippets()
+            .map(|(i, s)| {
+                let is_selected = i == selected_index;
+                let is_hovered = hovered_index == Some(i) && !is_selected;
+
+                let (prefix, name_style, desc_style, bg_color) = if is_selected {
+                    (
+                        vec![Span::styled(
+                            " ▌ ",
+                            Style::default()
+                                .fg(theme::snippets::ITEM_SELECTED_INDICATOR)
+                                .bg(theme::snippets::ITEM_SELECTED_BG),
+                        )],
+                        Style::default()
+                            .fg(theme::snippets::FIELD_TEXT)
+                            .bg(theme::snippets::ITEM_SELECTED_BG)
+                            .add_modifier(Modifier::BOLD),
+                        Style::default()
+                            .fg(theme::snippets::DESCRIPTION)
+                            .bg(theme::snippets::ITEM_SELECTED_BG),
+                        Some(theme::snippets::ITEM_SELECTED_BG),
+                    )
+                } else if is_hovered {
+                    (
+                        vec![Span::styled(
+                            "   ",
+                            Style::default().bg(theme::snippets::ITEM_HOVERED_BG),
+                        )],
+                        Style::default()
+                            .fg(theme::snippets::FIELD_TEXT)
+                            .bg(theme::snippets::ITEM_HOVERED_BG),
+                        Style::default()
+                            .fg(theme::snippets::DESCRIPTION)
+                            .bg(theme::snippets::ITEM_HOVERED_BG),
+                        Some(theme::snippets::ITEM_HOVERED_BG),
+                    )
+                } else {
+                    (
+                        vec![Span::styled(
+                            "   ",
+                            Style::default().bg(theme::snippets::ITEM_NORMAL_BG),
+                        )],
+                        Style::default().fg(theme::snippets::FIELD_TEXT),
+                        Style::default().fg(theme::snippets::DESCRIPTION),
+                        None,
+                    )
+                };
+
+                // Dimmed when written for another kind of input than the result
+                let name_style = if state.is_applicable(s) {
+                    name_style
+                } else {
+                    name_style.fg(theme::snippets::DESCRIPTION)
+                };
+
+                let mut spans = prefix;
+                spans.push(Span::styled(s.name.clone(), name_style));
+
+                let badge = build_entry_badge(state, s);
+                if !badge.is_empty() {
+                    let badge_style = match bg_color {
+                        Some(bg) => Style::default().fg(theme::snippets::SLOT).bg(bg),
+                        None => Style::default().fg(theme::snippets::SLOT),
+                    };
+                    spans.push(Span::styled(badge.clone(), badge_style));
+                }
+
+                if let Some(desc) = &s.description {
+                    // 3 = width of prefix " ▌ " or "   "
+                    let name_len = 3 + display_width(&s.name) + display_width(&badge);
+                    let separator = " - ";
+                    let available = max_width.saturating_sub(name_len + separator.len());
+
+                    if available > 10 {
+                        let truncated_desc = truncate_to_width(desc, available);
+                        spans.push(Span::styled(
+                            format!("{}{}", separator, truncated_desc),
+                            desc_style,
+                        ));
+                    }
+                }
+
+                if let Some(bg) = bg_color {
+                    let current_len: usize = spans.iter().map(|s| display_width(&s.content)).sum();
+                    let padding_len = max_width.saturating_sub(current_len);
+                    if padding_len > 0 {
+                        spans.push(Span::styled(
+                            " ".repeat(padding_len),
+                            Style::default().bg(bg),
+                        ));
+                    }
+                }
+
+                Line::from(spans)
+            })
+            .collect()
+    }
+}
+
+pub(super) fn build_list_title(filtered_count: usize, total_count: usize) -> String {
+    if total_count == 0 {
+        " Snippets ".to_string()
+    } else if filtered_count == total_count {
+        format!(" Snippets ({}) ", total_count)
+    } else {
+        format!(" Snippets ({}/{}) ", filtered_count, total_count)
+    }
+}
+
+/// Badge naming the apply mode; none for the default, replace
+pub(super) fn build_apply_mode_badge(mode: SnippetApplyMode) -> Option<Line<'static>> {
+    (mode != SnippetApplyMode::Replace).then(|| {
+        Line::from(Span::styled(
+            format!(" Apply: {} ", mode.label()),
+            Style::default().fg(theme::snippets::APPLY_MODE),
+        ))
+        .alignment(ratatui::layout::Alignment::Right)
+    })
+}
+
+/// Quick slot, project and input badges shown after a snippet's name
+fn build_entry_badge(state: &SnippetState, snippet: &Snippet) -> String {
+    let mut badge = state
+        .slot_of(&snippet.name)
+        .map(|slot| format!(" [Alt+{}]", slot))
+        .unwrap_or_default();
+    if state.is_project_snippet(snippet) {
+        badge.push_str(" [project]");
+    }
+    if !snippet.input.is_any() {
+        badge.push_str(&format!(" ({})", snippet.input.label()));
+    }
+    badge
+}
+
+#[cfg(test)]
+#[path = "snippet_list_render_tests.rs"]
+mod snippet_list_render_tests;
@@ -0,0 +1,38 @@
+use super::*;
+use crate::snippets::SnippetInput;
+use crate::snippets::snippet_storage::SnippetSlots;
+
+fn snippet(name: &str, input: SnippetInput) -> Snippet {
+    Snippet {
+        name: name.to_string(),
+        query: ".".to_string(),
+        description: None,
+        input,
+    }
+}
+
+#[test]
+fn test_list_title_counts_matches() {
+    assert_eq!(build_list_title(0, 0), " Snippets ");
+    assert_eq!(build_list_title(3, 3), " Snippets (3) ");
+    assert_eq!(build_list_title(1, 3), " Snippets (1/3) ");
+}
+
+#[test]
+fn test_apply_mode_badge_only_for_combining_modes() {
+    assert!(build_apply_mode_badge(SnippetApplyMode::Replace).is_none());
+    let badge = build_apply_mode_badge(SnippetApplyMode::Wrap).unwrap();
+    assert_eq!(badge.to_string(), " Apply: wrap ");
+}
+
+#[test]
+fn test_entry_badge_lists_slot_and_input() {
+    let mut state = SnippetState::new_without_persistence();
+    let keys = snippet("Keys", SnippetInput::Object);
+    let count = snippet("Count", SnippetInput::Any);
+    state.set_snippets(vec![keys.clone(), count.clone()]);
+    state.set_slots(SnippetSlots::from([(2, "Keys".to_string())]));
+
+    assert_eq!(build_entry_badge(&state, &keys), " [Alt+2] (object)");
+    assert_eq!(build_entry_badge(&state, &count), "");
+}
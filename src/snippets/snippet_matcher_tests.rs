@@ -6,6 +6,7 @@ fn create_snippet(name: &str) -> Snippet {
         name: name.to_string(),
         query: ".".to_string(),
         description: None,
+        input: Default::default(),
     }
 }
 
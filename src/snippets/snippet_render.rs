@@ -6,10 +6,14 @@ use ratatui::{
     widgets::{Block, BorderType, Borders, Paragraph},
 };
 
+use super::snippet_list_render::{
+    build_apply_mode_badge, build_list_content_from_visible, build_list_title,
+};
 use super::snippet_state::{SnippetMode, SnippetState};
 use crate::ai::render::text::wrap_text;
 use crate::syntax_highlight::JqHighlighter;
 use crate::theme;
+use crate::widgets::text_width::truncate_to_width;
 use crate::widgets::{popup, scrollbar};
 
 const MIN_LIST_HEIGHT: u16 = 3;
@@ -151,15 +155,18 @@ fn render_minimal(
 
         let hints = build_browse_hints();
 
-        let popup = Paragraph::new(content).block(
-            Block::default()
-                .borders(Borders::ALL)
-                .border_type(BorderType::Rounded)
-                .title(title)
-                .title_bottom(hints.alignment(ratatui::layout::Alignment::Center))
-                .border_style(Style::default().fg(theme::snippets::BORDER))
-                .style(Style::default().bg(theme::snippets::BACKGROUND)),
-        );
+        let mut block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .title(title)
+            .title_bottom(hints.alignment(ratatui::layout::Alignment::Center))
+            .border_style(Style::default().fg(theme::snippets::BORDER))
+            .style(Style::default().bg(theme::snippets::BACKGROUND));
+        if let Some(badge) = build_apply_mode_badge(state.apply_mode()) {
+            block = block.title_top(badge);
+        }
+
+        let popup = Paragraph::new(content).block(block);
         frame.render_widget(popup, area);
         return (Some(area), None);
     }
@@ -212,13 +219,16 @@ fn render_list(
 
     let hints = build_browse_hints();
 
-    let block = Block::default()
+    let mut block = Block::default()
         .borders(Borders::ALL)
         .border_type(BorderType::Rounded)
         .title(title)
         .title_bottom(hints.alignment(ratatui::layout::Alignment::Center))
         .border_style(Style::default().fg(theme::snippets::BORDER))
         .style(Style::default().bg(theme::snippets::BACKGROUND));
+    if let Some(badge) = build_apply_mode_badge(state.apply_mode()) {
+        block = block.title_top(badge);
+    }
 
     let list = Paragraph::new(content).block(block);
     frame.render_widget(list, area);
@@ -270,122 +280,6 @@ fn render_preview(
     frame.render_widget(preview, area);
 }
 
-fn build_list_content_from_visible(
-    state: &SnippetState,
-    area_width: u16,
-    hovered_index: Option<usize>,
-) -> Vec<Line<'static>> {
-    if state.filtered_count() == 0 {
-        let message = if state.snippets().is_empty() {
-            "   No snippets yet. Press Ctrl+N to create one."
-        } else {
-            "   No matches"
-        };
-        vec![Line::from(vec![Span::styled(
-            message,
-            Style::default().fg(theme::snippets::DESCRIPTION),
-        )])]
-    } else {
-        let selected_index = state.selected_index();
-        let max_width = area_width.saturating_sub(4) as usize;
-
-        state
-            .visible_snippets()
-            .map(|(i, s)| {
-                let is_selected = i == selected_index;
-                let is_hovered = hovered_index == Some(i) && !is_selected;
-
-                let (prefix, name_style, desc_style, bg_color) = if is_selected {
-                    (
-                        vec![Span::styled(
-                            " ▌ ",
-                            Style::default()
-                                .fg(theme::snippets::ITEM_SELECTED_INDICATOR)
-                                .bg(theme::snippets::ITEM_SELECTED_BG),
-                        )],
-                        Style::default()
-                            .fg(theme::snippets::FIELD_TEXT)
-                            .bg(theme::snippets::ITEM_SELECTED_BG)
-                            .add_modifier(Modifier::BOLD),
-                        Style::default()
-                            .fg(theme::snippets::DESCRIPTION)
-                            .bg(theme::snippets::ITEM_SELECTED_BG),
-                        Some(theme::snippets::ITEM_SELECTED_BG),
-                    )
-                } else if is_hovered {
-                    (
-                        vec![Span::styled(
-                            "   ",
-                            Style::default().bg(theme::snippets::ITEM_HOVERED_BG),
-                        )],
-                        Style::default()
-                            .fg(theme::snippets::FIELD_TEXT)
-                            .bg(theme::snippets::ITEM_HOVERED_BG),
-                        Style::default()
-                            .fg(theme::snippets::DESCRIPTION)
-                            .bg(theme::snippets::ITEM_HOVERED_BG),
-                        Some(theme::snippets::ITEM_HOVERED_BG),
-                    )
-                } else {
-                    (
-                        vec![Span::styled(
-                            "   ",
-                            Style::default().bg(theme::snippets::ITEM_NORMAL_BG),
-                        )],
-                        Style::default().fg(theme::snippets::FIELD_TEXT),
-                        Style::default().fg(theme::snippets::DESCRIPTION),
-                        None,
-                    )
-                };
-
-                let mut spans = prefix;
-                spans.push(Span::styled(s.name.clone(), name_style));
-
-                if let Some(desc) = &s.description {
-                    let name_len = 3 + s.name.len(); // 3 = width of prefix " ▌ " or "   "
-                    let separator = " - ";
-                    let available = max_width.saturating_sub(name_len + separator.len());
-
-                    if available > 10 {
-                        let truncated_desc = if desc.len() > available {
-                            format!("{}…", &desc[..available.saturating_sub(1)])
-                        } else {
-                            desc.clone()
-                        };
-                        spans.push(Span::styled(
-                            format!("{}{}", separator, truncated_desc),
-                            desc_style,
-                        ));
-                    }
-                }
-
-                if let Some(bg) = bg_color {
-                    let current_len: usize = spans.iter().map(|s| s.content.len()).sum();
-                    let padding_len = max_width.saturating_sub(current_len);
-                    if padding_len > 0 {
-                        spans.push(Span::styled(
-                            " ".repeat(padding_len),
-                            Style::default().bg(bg),
-                        ));
-                    }
-                }
-
-                Line::from(spans)
-            })
-            .collect()
-    }
-}
-
-fn build_list_title(filtered_count: usize, total_count: usize) -> String {
-    if total_count == 0 {
-        " Snippets ".to_string()
-    } else if filtered_count == total_count {
-        format!(" Snippets ({}) ", total_count)
-    } else {
-        format!(" Snippets ({}/{}) ", filtered_count, total_count)
-    }
-}
-
 fn build_preview_content(
     snippet: &super::snippet_state::Snippet,
     max_width: usize,
@@ -914,11 +808,7 @@ fn render_confirm_delete_mode(state: &SnippetState, frame: &mut Frame, area: Rec
 
     let dialog_area = Rect::new(dialog_x, dialog_y, dialog_width, dialog_height);
 
-    let truncated_name = if snippet_name.len() > 30 {
-        format!("{}…", &snippet_name[..29])
-    } else {
-        snippet_name
-    };
+    let truncated_name = truncate_to_width(&snippet_name, 30);
 
     let content = vec![
         Line::from(""),
@@ -968,11 +858,7 @@ fn render_confirm_update_mode(state: &SnippetState, frame: &mut Frame, area: Rec
 
     let dialog_area = Rect::new(dialog_x, dialog_y, dialog_width, dialog_height);
 
-    let truncated_name = if snippet_name.len() > 40 {
-        format!("{}…", &snippet_name[..39])
-    } else {
-        snippet_name
-    };
+    let truncated_name = truncate_to_width(&snippet_name, 40);
 
     let mut content = vec![
         Line::from(""),
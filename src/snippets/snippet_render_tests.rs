@@ -5,6 +5,9 @@ use ratatui::Terminal;
 use ratatui::backend::TestBackend;
 use ratatui::layout::Rect;
 
+#[path = "snippet_render_tests/wide_char_tests.rs"]
+mod wide_char_tests;
+
 fn create_test_terminal(width: u16, height: u16) -> Terminal<TestBackend> {
     let backend = TestBackend::new(width, height);
     Terminal::new(backend).unwrap()
@@ -77,16 +80,19 @@ fn snapshot_snippet_popup_with_snippets() {
             name: "Select all keys".to_string(),
             query: "keys".to_string(),
             description: Some("Returns array of all keys".to_string()),
+            input: Default::default(),
         },
         Snippet {
             name: "Flatten arrays".to_string(),
             query: "flatten".to_string(),
             description: None,
+            input: Default::default(),
         },
         Snippet {
             name: "Filter by type".to_string(),
             query: ".[] | select(.type == \"error\")".to_string(),
             description: Some("Filter items by type".to_string()),
+            input: Default::default(),
         },
     ];
     let mut state = create_state_with_snippets(snippets);
@@ -106,6 +112,7 @@ fn snapshot_snippet_popup_with_single_snippet() {
         name: "Identity".to_string(),
         query: ".".to_string(),
         description: None,
+        input: Default::default(),
     }];
     let mut state = create_state_with_snippets(snippets);
     let results_area = Rect {
@@ -125,11 +132,13 @@ fn snapshot_snippet_popup_with_snippets_narrow() {
             name: "Select all keys".to_string(),
             query: "keys".to_string(),
             description: None,
+            input: Default::default(),
         },
         Snippet {
             name: "Flatten".to_string(),
             query: "flatten".to_string(),
             description: None,
+            input: Default::default(),
         },
     ];
     let mut state = create_state_with_snippets(snippets);
@@ -150,16 +159,19 @@ fn snapshot_snippet_popup_with_second_item_selected() {
             name: "Select all keys".to_string(),
             query: "keys".to_string(),
             description: Some("Returns array of all keys".to_string()),
+            input: Default::default(),
         },
         Snippet {
             name: "Flatten arrays".to_string(),
             query: "flatten".to_string(),
             description: None,
+            input: Default::default(),
         },
         Snippet {
             name: "Filter by type".to_string(),
             query: ".[] | select(.type == \"error\")".to_string(),
             description: Some("Filter items by type".to_string()),
+            input: Default::default(),
         },
     ];
     let mut state = create_state_with_snippets(snippets);
@@ -182,16 +194,19 @@ fn snapshot_snippet_popup_with_last_item_selected() {
             name: "Select all keys".to_string(),
             query: "keys".to_string(),
             description: None,
+            input: Default::default(),
         },
         Snippet {
             name: "Flatten arrays".to_string(),
             query: "flatten".to_string(),
             description: None,
+            input: Default::default(),
         },
         Snippet {
             name: "Filter by type".to_string(),
             query: ".[] | select(.type == \"error\")".to_string(),
             description: None,
+            input: Default::default(),
         },
     ];
     let mut state = create_state_with_snippets(snippets);
@@ -214,11 +229,13 @@ fn snapshot_preview_with_description() {
             name: "Select all keys".to_string(),
             query: "keys".to_string(),
             description: Some("Returns an array of all keys in the object".to_string()),
+            input: Default::default(),
         },
         Snippet {
             name: "Flatten arrays".to_string(),
             query: "flatten".to_string(),
             description: Some("Flattens nested arrays into a single array".to_string()),
+            input: Default::default(),
         },
     ];
     let mut state = create_state_with_snippets(snippets);
@@ -237,7 +254,7 @@ fn snapshot_preview_with_long_query_wrapping() {
     let snippets = vec![Snippet {
         name: "Complex filter".to_string(),
         query: ".data[] | select(.status == \"active\" and .type == \"premium\") | {id, name, email, created_at, metadata}".to_string(),
-        description: Some("Filters active premium users and extracts key fields".to_string()),
+        description: Some("Filters active premium users and extracts key fields".to_string()), input: Default::default(),
     }];
     let mut state = create_state_with_snippets(snippets);
     let results_area = Rect {
@@ -257,11 +274,13 @@ fn snapshot_very_short_height_falls_back_to_list_only() {
             name: "Keys".to_string(),
             query: "keys".to_string(),
             description: Some("Get keys".to_string()),
+            input: Default::default(),
         },
         Snippet {
             name: "Flatten".to_string(),
             query: "flatten".to_string(),
             description: None,
+            input: Default::default(),
         },
     ];
     let mut state = create_state_with_snippets(snippets);
@@ -281,6 +300,7 @@ fn snapshot_preview_without_description() {
         name: "Identity".to_string(),
         query: ".".to_string(),
         description: None,
+        input: Default::default(),
     }];
     let mut state = create_state_with_snippets(snippets);
     let results_area = Rect {
@@ -300,16 +320,19 @@ fn snapshot_filtered_results_with_search() {
             name: "Select all keys".to_string(),
             query: "keys".to_string(),
             description: Some("Returns array of all keys".to_string()),
+            input: Default::default(),
         },
         Snippet {
             name: "Flatten arrays".to_string(),
             query: "flatten".to_string(),
             description: None,
+            input: Default::default(),
         },
         Snippet {
             name: "Select items".to_string(),
             query: ".[]".to_string(),
             description: Some("Select all items".to_string()),
+            input: Default::default(),
         },
     ];
     let mut state = create_state_with_snippets(snippets);
@@ -332,11 +355,13 @@ fn snapshot_no_matches_message() {
             name: "Select keys".to_string(),
             query: "keys".to_string(),
             description: None,
+            input: Default::default(),
         },
         Snippet {
             name: "Flatten arrays".to_string(),
             query: "flatten".to_string(),
             description: None,
+            input: Default::default(),
         },
     ];
     let mut state = create_state_with_snippets(snippets);
@@ -526,6 +551,7 @@ fn snapshot_rename_mode_with_original_name() {
         name: "My Snippet".to_string(),
         query: ".test | keys".to_string(),
         description: None,
+        input: Default::default(),
     }]);
     state.enter_edit_mode();
 
@@ -546,6 +572,7 @@ fn snapshot_rename_mode_with_edited_name() {
         name: "Old Name".to_string(),
         query: ".test".to_string(),
         description: None,
+        input: Default::default(),
     }]);
     state.enter_edit_mode();
     state.name_textarea_mut().select_all();
@@ -569,6 +596,7 @@ fn snapshot_rename_mode_narrow_terminal() {
         name: "My Snippet".to_string(),
         query: ".test".to_string(),
         description: None,
+        input: Default::default(),
     }]);
     state.enter_edit_mode();
 
@@ -589,6 +617,7 @@ fn snapshot_rename_mode_small_height() {
         name: "My Snippet".to_string(),
         query: ".test".to_string(),
         description: None,
+        input: Default::default(),
     }]);
     state.enter_edit_mode();
 
@@ -609,6 +638,7 @@ fn snapshot_edit_query_mode_with_original_query() {
         name: "My Snippet".to_string(),
         query: ".test | keys".to_string(),
         description: None,
+        input: Default::default(),
     }]);
     state.enter_edit_mode();
 
@@ -629,6 +659,7 @@ fn snapshot_edit_query_mode_with_edited_query() {
         name: "My Snippet".to_string(),
         query: ".old".to_string(),
         description: None,
+        input: Default::default(),
     }]);
     state.enter_edit_mode();
     state.query_textarea_mut().select_all();
@@ -652,6 +683,7 @@ fn snapshot_edit_query_mode_narrow_terminal() {
         name: "My Snippet".to_string(),
         query: ".test".to_string(),
         description: None,
+        input: Default::default(),
     }]);
     state.enter_edit_mode();
 
@@ -672,6 +704,7 @@ fn snapshot_edit_query_mode_small_height() {
         name: "My Snippet".to_string(),
         query: ".test".to_string(),
         description: None,
+        input: Default::default(),
     }]);
     state.enter_edit_mode();
 
@@ -692,6 +725,7 @@ fn snapshot_confirm_delete_mode() {
         name: "My Snippet".to_string(),
         query: ".test | keys".to_string(),
         description: None,
+        input: Default::default(),
     }]);
     state.enter_delete_mode();
 
@@ -712,6 +746,7 @@ fn snapshot_confirm_delete_mode_narrow_terminal() {
         name: "My Snippet".to_string(),
         query: ".test".to_string(),
         description: None,
+        input: Default::default(),
     }]);
     state.enter_delete_mode();
 
@@ -732,6 +767,7 @@ fn snapshot_confirm_delete_mode_long_name() {
         name: "This is a very long snippet name that should be truncated".to_string(),
         query: ".test".to_string(),
         description: None,
+        input: Default::default(),
     }]);
     state.enter_delete_mode();
 
@@ -752,6 +788,7 @@ fn snapshot_confirm_delete_mode_small_area() {
         name: "Test".to_string(),
         query: ".test".to_string(),
         description: None,
+        input: Default::default(),
     }]);
     state.enter_delete_mode();
 
@@ -772,6 +809,7 @@ fn create_many_snippets(count: usize) -> Vec<Snippet> {
             name: format!("Snippet {:02}", i),
             query: format!(".query{:02}", i),
             description: None,
+            input: Default::default(),
         })
         .collect()
 }
@@ -832,3 +870,119 @@ fn snapshot_scrollbar_at_bottom() {
     let output = render_snippet_popup_to_string(&mut state, results_area, 80, 24);
     assert_snapshot!(output);
 }
+
+#[test]
+fn snapshot_browse_mode_with_quick_slots() {
+    let mut state = SnippetState::new_without_persistence();
+    state.set_snippets(vec![
+        Snippet {
+            name: "Names".to_string(),
+            query: ".[].name".to_string(),
+            description: Some("List every name".to_string()),
+            input: Default::default(),
+        },
+        Snippet {
+            name: "Count".to_string(),
+            query: "length".to_string(),
+            description: None,
+            input: Default::default(),
+        },
+    ]);
+    state.toggle_slot(1).unwrap();
+    state.set_selected_index(1);
+    state.toggle_slot(2).unwrap();
+
+    let results_area = Rect {
+        x: 0,
+        y: 0,
+        width: 80,
+        height: 20,
+    };
+    let output = render_snippet_popup_to_string(&mut state, results_area, 80, 24);
+    assert_snapshot!(output);
+}
+
+#[test]
+fn snapshot_browse_mode_with_input_types() {
+    let mut state = SnippetState::new_without_persistence();
+    state.set_snippets(vec![
+        Snippet {
+            name: "Keys".to_string(),
+            query: "keys".to_string(),
+            description: None,
+            input: crate::snippets::SnippetInput::Object,
+        },
+        Snippet {
+            name: "First".to_string(),
+            query: ".[0]".to_string(),
+            description: Some("First element".to_string()),
+            input: crate::snippets::SnippetInput::Array,
+        },
+    ]);
+    state.set_result_type(Some(crate::query::ResultType::Array));
+    state.open();
+
+    let results_area = Rect {
+        x: 0,
+        y: 0,
+        width: 80,
+        height: 20,
+    };
+    let output = render_snippet_popup_to_string(&mut state, results_area, 80, 24);
+    assert_snapshot!(output);
+}
+
+#[test]
+fn snapshot_browse_mode_with_project_snippet() {
+    let mut state = SnippetState::new_without_persistence();
+    state.set_snippets(vec![
+        Snippet {
+            name: "Deploys".to_string(),
+            query: ".deployments[]".to_string(),
+            description: Some("Deployments of this repo".to_string()),
+            input: Default::default(),
+        },
+        Snippet {
+            name: "Names".to_string(),
+            query: ".[].name".to_string(),
+            description: None,
+            input: Default::default(),
+        },
+    ]);
+    state.set_project(Some(super::super::snippet_storage::ProjectSnippets {
+        path: ".jiq/snippets.toml".into(),
+        names: ["Deploys".to_string()].into(),
+        shadowed: Vec::new(),
+    }));
+
+    let results_area = Rect {
+        x: 0,
+        y: 0,
+        width: 80,
+        height: 20,
+    };
+    let output = render_snippet_popup_to_string(&mut state, results_area, 80, 24);
+    assert_snapshot!(output);
+}
+
+#[test]
+fn snapshot_browse_mode_with_append_apply_mode() {
+    let mut state = SnippetState::new_without_persistence();
+    state.set_snippets(vec![Snippet {
+        name: "Active".to_string(),
+        query: "select(.active)".to_string(),
+        description: None,
+        input: Default::default(),
+    }]);
+    state.cycle_apply_mode();
+    state.open();
+
+    let results_area = Rect {
+        x: 0,
+        y: 0,
+        width: 80,
+        height: 20,
+    };
+    let output = render_snippet_popup_to_string(&mut state, results_area, 80, 24);
+    assert_snapshot!(output);
+}
@@ -0,0 +1,53 @@
+//! Rendering of names and descriptions with wide characters
+
+use super::*;
+
+#[test]
+fn snapshot_snippet_popup_with_wide_characters() {
+    let snippets = vec![
+        Snippet {
+            name: "名前を抽出".to_string(),
+            query: ".[] | .名前".to_string(),
+            description: Some(
+                "すべての項目から名前フィールドを取り出して一覧にします 🎉".to_string(),
+            ),
+            input: Default::default(),
+        },
+        Snippet {
+            name: "Count 🚀".to_string(),
+            query: "length".to_string(),
+            description: Some("Number of items".to_string()),
+            input: Default::default(),
+        },
+    ];
+    let mut state = create_state_with_snippets(snippets);
+    let results_area = Rect {
+        x: 0,
+        y: 0,
+        width: 60,
+        height: 20,
+    };
+    let output = render_snippet_popup_to_string(&mut state, results_area, 60, 24);
+    assert_snapshot!(output);
+}
+
+#[test]
+fn snapshot_confirm_delete_mode_long_wide_name() {
+    let mut state = SnippetState::new_without_persistence();
+    state.set_snippets(vec![Snippet {
+        name: "とても長いスニペットの名前はここで切り詰められます".to_string(),
+        query: ".test".to_string(),
+        description: None,
+        input: Default::default(),
+    }]);
+    state.enter_delete_mode();
+
+    let results_area = Rect {
+        x: 0,
+        y: 0,
+        width: 80,
+        height: 20,
+    };
+    let output = render_snippet_popup_to_string(&mut state, results_area, 80, 24);
+    assert_snapshot!(output);
+}
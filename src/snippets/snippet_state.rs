@@ -2,15 +2,25 @@ use ratatui::style::{Modifier, Style};
 use serde::{Deserialize, Serialize};
 use tui_textarea::TextArea;
 
+use super::snippet_apply::SnippetApplyMode;
+use super::snippet_input::SnippetInput;
 use super::snippet_matcher::SnippetMatcher;
+use super::snippet_storage::{self, ProjectSnippets, SnippetSlots};
+use crate::query::ResultType;
 use crate::scroll::Scrollable;
 
+mod applicability;
+mod slots;
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct Snippet {
     pub name: String,
     pub query: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+    /// Kind of value the query is written for
+    #[serde(default, skip_serializing_if = "SnippetInput::is_any")]
+    pub input: SnippetInput,
 }
 
 #[derive(Debug, Clone, Default, PartialEq, Eq)]
@@ -83,6 +93,15 @@ pub struct SnippetState {
     matcher: SnippetMatcher,
     persist_to_disk: bool,
     hovered_index: Option<usize>,
+    /// Snippets applied with `Alt+1`..`Alt+9` outside the manager
+    slots: SnippetSlots,
+    /// Type of the current result; snippets expecting another input are
+    /// listed last
+    result_type: Option<ResultType>,
+    /// Snippets from `.jiq/snippets.toml` in the working directory
+    project: Option<ProjectSnippets>,
+    /// How applied snippets combine with the query; kept between openings
+    apply_mode: SnippetApplyMode,
 }
 
 impl Default for SnippetState {
@@ -109,6 +128,10 @@ impl SnippetState {
             matcher: SnippetMatcher::new(),
             persist_to_disk: true,
             hovered_index: None,
+            slots: SnippetSlots::new(),
+            result_type: None,
+            project: None,
+            apply_mode: SnippetApplyMode::Replace,
         }
     }
 
@@ -130,13 +153,15 @@ impl SnippetState {
             matcher: SnippetMatcher::new(),
             persist_to_disk: false,
             hovered_index: None,
+            slots: SnippetSlots::new(),
+            result_type: None,
+            project: None,
+            apply_mode: SnippetApplyMode::Replace,
         }
     }
 
     pub fn open(&mut self) {
-        if self.persist_to_disk {
-            self.snippets = super::snippet_storage::load_snippets();
-        }
+        self.reload();
         self.search_textarea.select_all();
         self.search_textarea.cut();
         self.update_filter();
@@ -376,12 +401,13 @@ impl SnippetState {
             name,
             query: query.to_string(),
             description,
+            input: SnippetInput::Any,
         };
 
         self.snippets.insert(0, snippet);
 
         if self.persist_to_disk
-            && let Err(e) = super::snippet_storage::save_snippets(&self.snippets)
+            && let Err(e) = self.save()
         {
             self.snippets.remove(0);
             return Err(format!("Failed to save: {}", e));
@@ -425,12 +451,27 @@ impl SnippetState {
             .copied()
             .ok_or_else(|| "No snippet selected".to_string())?;
 
+        let original_slots = self.slots.clone();
+        let original_project = self.project.clone();
+        let current_name = self.snippets[snippet_idx].name.clone();
+        for name in self.slots.values_mut() {
+            if *name == current_name {
+                *name = new_name.clone();
+            }
+        }
+        if let Some(project) = &mut self.project
+            && project.names.remove(&current_name)
+        {
+            project.names.insert(new_name.clone());
+        }
         self.snippets[snippet_idx].name = new_name;
 
         if self.persist_to_disk
-            && let Err(e) = super::snippet_storage::save_snippets(&self.snippets)
+            && let Err(e) = self.save()
         {
             self.snippets[snippet_idx].name = original_name;
+            self.slots = original_slots;
+            self.project = original_project;
             return Err(format!("Failed to save: {}", e));
         }
 
@@ -509,7 +550,7 @@ impl SnippetState {
         self.snippets[snippet_idx].query = new_query;
 
         if self.persist_to_disk
-            && let Err(e) = super::snippet_storage::save_snippets(&self.snippets)
+            && let Err(e) = self.save()
         {
             self.snippets[snippet_idx].query = original_query;
             return Err(format!("Failed to save: {}", e));
@@ -540,7 +581,7 @@ impl SnippetState {
         self.snippets[snippet_idx].description = new_description;
 
         if self.persist_to_disk
-            && let Err(e) = super::snippet_storage::save_snippets(&self.snippets)
+            && let Err(e) = self.save()
         {
             self.snippets[snippet_idx].description = original_description;
             return Err(format!("Failed to save: {}", e));
@@ -577,11 +618,19 @@ impl SnippetState {
         }
 
         let removed_snippet = self.snippets.remove(snippet_idx);
+        let original_slots = self.slots.clone();
+        let original_project = self.project.clone();
+        self.slots.retain(|_, name| *name != snippet_name);
+        if let Some(project) = &mut self.project {
+            project.names.remove(&snippet_name);
+        }
 
         if self.persist_to_disk
-            && let Err(e) = super::snippet_storage::save_snippets(&self.snippets)
+            && let Err(e) = self.save()
         {
             self.snippets.insert(snippet_idx, removed_snippet);
+            self.slots = original_slots;
+            self.project = original_project;
             return Err(format!("Failed to save: {}", e));
         }
 
@@ -644,7 +693,7 @@ impl SnippetState {
         self.snippets[snippet_idx].query = new_query;
 
         if self.persist_to_disk
-            && let Err(e) = super::snippet_storage::save_snippets(&self.snippets)
+            && let Err(e) = self.save()
         {
             self.snippets[snippet_idx].query = original_query;
             return Err(format!("Failed to save: {}", e));
@@ -654,6 +703,36 @@ impl SnippetState {
         Ok(())
     }
 
+    pub fn apply_mode(&self) -> SnippetApplyMode {
+        self.apply_mode
+    }
+
+    /// Switch to the next apply mode: replace, append, wrap
+    pub fn cycle_apply_mode(&mut self) -> SnippetApplyMode {
+        self.apply_mode = self.apply_mode.next();
+        self.apply_mode
+    }
+
+    /// Whether `snippet` comes from the project's snippet file
+    pub fn is_project_snippet(&self, snippet: &Snippet) -> bool {
+        self.project
+            .as_ref()
+            .is_some_and(|project| project.names.contains(&snippet.name))
+    }
+
+    fn reload(&mut self) {
+        if self.persist_to_disk {
+            let store = snippet_storage::load_store();
+            self.snippets = store.snippets;
+            self.slots = store.slots;
+            self.project = store.project;
+        }
+    }
+
+    fn save(&self) -> std::io::Result<()> {
+        snippet_storage::save_store(&self.snippets, &self.slots, self.project.as_ref())
+    }
+
     pub fn snippets(&self) -> &[Snippet] {
         &self.snippets
     }
@@ -727,6 +806,11 @@ impl SnippetState {
             .map(|s| s.as_str())
             .unwrap_or("");
         self.filtered_indices = self.matcher.filter(query, &self.snippets);
+        // Stable, so matches keep their search ranking
+        let snippets = &self.snippets;
+        let result_type = self.result_type.as_ref();
+        self.filtered_indices
+            .sort_by_key(|&idx| !snippets[idx].input.matches(result_type));
     }
 
     fn adjust_scroll_to_selection(&mut self) {
@@ -760,6 +844,16 @@ impl SnippetState {
         self.scroll_offset = 0;
     }
 
+    #[cfg(test)]
+    pub fn set_project(&mut self, project: Option<ProjectSnippets>) {
+        self.project = project;
+    }
+
+    #[cfg(test)]
+    pub fn set_slots(&mut self, slots: SnippetSlots) {
+        self.slots = slots;
+    }
+
     #[cfg(test)]
     pub fn set_selected_index(&mut self, index: usize) {
         if index < self.filtered_indices.len() || self.filtered_indices.is_empty() {
@@ -0,0 +1,43 @@
+//! Which result types snippets suit, so the others can be listed last
+
+use super::{Snippet, SnippetState};
+use crate::query::ResultType;
+use crate::snippets::snippet_input::SnippetInput;
+
+impl SnippetState {
+    /// Type of the result snippets are offered for; call before `open`
+    pub fn set_result_type(&mut self, result_type: Option<ResultType>) {
+        self.result_type = result_type;
+    }
+
+    pub fn result_type(&self) -> Option<&ResultType> {
+        self.result_type.as_ref()
+    }
+
+    /// Whether `snippet` suits the current result
+    pub fn is_applicable(&self, snippet: &Snippet) -> bool {
+        snippet.input.matches(self.result_type.as_ref())
+    }
+
+    /// Cycle the input the selected snippet expects: any, object, array
+    pub fn cycle_input(&mut self) -> Result<SnippetInput, String> {
+        let snippet_idx = self
+            .filtered_indices
+            .get(self.selected_index)
+            .copied()
+            .ok_or_else(|| "No snippet selected".to_string())?;
+
+        let original_input = self.snippets[snippet_idx].input;
+        let input = original_input.next();
+        self.snippets[snippet_idx].input = input;
+
+        if self.persist_to_disk
+            && let Err(e) = self.save()
+        {
+            self.snippets[snippet_idx].input = original_input;
+            return Err(format!("Failed to save: {}", e));
+        }
+
+        Ok(input)
+    }
+}
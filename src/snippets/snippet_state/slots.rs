@@ -0,0 +1,63 @@
+//! Quick slots: snippets applied with `Alt+1`..`Alt+9` outside the manager
+
+use super::{Snippet, SnippetState};
+
+impl SnippetState {
+    /// Assign the selected snippet to quick slot `slot` (1-9), or free the
+    /// slot when it already holds it. Returns a message describing the change.
+    pub fn toggle_slot(&mut self, slot: u8) -> Result<String, String> {
+        let name = self
+            .selected_snippet()
+            .map(|s| s.name.clone())
+            .ok_or_else(|| "No snippet selected".to_string())?;
+
+        let original_slots = self.slots.clone();
+        let message = if self.slots.get(&slot) == Some(&name) {
+            self.slots.remove(&slot);
+            format!("Removed '{}' from Alt+{}", name, slot)
+        } else {
+            // A snippet has at most one slot
+            self.slots.retain(|_, assigned| *assigned != name);
+            self.slots.insert(slot, name.clone());
+            format!("Assigned '{}' to Alt+{}", name, slot)
+        };
+
+        if self.persist_to_disk
+            && let Err(e) = self.save()
+        {
+            self.slots = original_slots;
+            return Err(format!("Failed to save: {}", e));
+        }
+
+        Ok(message)
+    }
+
+    /// Quick slot holding the snippet named `name`
+    pub fn slot_of(&self, name: &str) -> Option<u8> {
+        self.slots
+            .iter()
+            .find(|(_, assigned)| *assigned == name)
+            .map(|(&slot, _)| slot)
+    }
+
+    /// Snippet assigned to quick slot `slot`, read afresh from disk since
+    /// snippets are otherwise only loaded when the manager opens
+    pub fn slot_snippet(&mut self, slot: u8) -> Option<&Snippet> {
+        self.reload();
+        let name = self.slots.get(&slot)?;
+        self.snippets.iter().find(|s| &s.name == name)
+    }
+
+    /// Quick slots and their snippets, for the help popup
+    pub fn slot_assignments(&self) -> Vec<(u8, &Snippet)> {
+        self.slots
+            .iter()
+            .filter_map(|(&slot, name)| {
+                self.snippets
+                    .iter()
+                    .find(|s| &s.name == name)
+                    .map(|s| (slot, s))
+            })
+            .collect()
+    }
+}
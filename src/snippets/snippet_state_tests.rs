@@ -1,5 +1,8 @@
+pub use super::super::snippet_storage::{ProjectSnippets, SnippetSlots};
 pub use super::{Snippet, SnippetMode, SnippetState};
 
+#[path = "snippet_state_tests/applicability_tests.rs"]
+mod applicability_tests;
 #[path = "snippet_state_tests/basic_tests.rs"]
 mod basic_tests;
 #[path = "snippet_state_tests/create_tests.rs"]
@@ -14,11 +17,15 @@ mod edit_query_tests;
 mod hover_tests;
 #[path = "snippet_state_tests/navigation_tests.rs"]
 mod navigation_tests;
+#[path = "snippet_state_tests/project_tests.rs"]
+mod project_tests;
 #[path = "snippet_state_tests/rename_tests.rs"]
 mod rename_tests;
 #[path = "snippet_state_tests/scrollable_tests.rs"]
 mod scrollable_tests;
 #[path = "snippet_state_tests/search_tests.rs"]
 mod search_tests;
+#[path = "snippet_state_tests/slot_tests.rs"]
+mod slot_tests;
 #[path = "snippet_state_tests/update_tests.rs"]
 mod update_tests;
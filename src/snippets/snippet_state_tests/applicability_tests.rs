@@ -0,0 +1,68 @@
+use super::*;
+use crate::query::ResultType;
+use crate::snippets::SnippetInput;
+
+fn snippet(name: &str, input: SnippetInput) -> Snippet {
+    Snippet {
+        name: name.to_string(),
+        query: ".".to_string(),
+        description: None,
+        input,
+    }
+}
+
+fn listed_names(state: &SnippetState) -> Vec<String> {
+    state
+        .visible_snippets()
+        .map(|(_, s)| s.name.clone())
+        .collect()
+}
+
+#[test]
+fn test_snippets_for_other_inputs_are_listed_last() {
+    let mut state = SnippetState::new_without_persistence();
+    state.set_snippets(vec![
+        snippet("Keys", SnippetInput::Object),
+        snippet("First", SnippetInput::Array),
+        snippet("Pretty", SnippetInput::Any),
+        snippet("Length", SnippetInput::Array),
+    ]);
+
+    state.set_result_type(Some(ResultType::Array));
+    state.open();
+
+    assert_eq!(
+        listed_names(&state),
+        vec!["First", "Pretty", "Length", "Keys"]
+    );
+    assert!(!state.is_applicable(&state.snippets()[0]));
+}
+
+#[test]
+fn test_order_is_kept_without_result_type() {
+    let mut state = SnippetState::new_without_persistence();
+    state.set_snippets(vec![
+        snippet("Keys", SnippetInput::Object),
+        snippet("First", SnippetInput::Array),
+    ]);
+
+    state.open();
+
+    assert_eq!(listed_names(&state), vec!["Keys", "First"]);
+}
+
+#[test]
+fn test_cycle_input_of_selected_snippet() {
+    let mut state = SnippetState::new_without_persistence();
+    state.set_snippets(vec![snippet("Keys", SnippetInput::Any)]);
+
+    assert_eq!(state.cycle_input(), Ok(SnippetInput::Object));
+    assert_eq!(state.cycle_input(), Ok(SnippetInput::Array));
+    assert_eq!(state.snippets()[0].input, SnippetInput::Array);
+}
+
+#[test]
+fn test_cycle_input_without_snippets_fails() {
+    let mut state = SnippetState::new_without_persistence();
+    assert!(state.cycle_input().is_err());
+}
@@ -80,6 +80,7 @@ fn test_save_new_snippet_duplicate_name_fails() {
         name: "Existing".to_string(),
         query: ".foo".to_string(),
         description: None,
+        input: Default::default(),
     }]);
 
     state.enter_create_mode(".bar");
@@ -130,6 +131,7 @@ fn test_filtered_indices_updated_after_save() {
         name: "First".to_string(),
         query: ".first".to_string(),
         description: None,
+        input: Default::default(),
     }]);
     assert_eq!(state.filtered_count(), 1);
 
@@ -183,6 +185,7 @@ fn test_save_new_snippet_case_insensitive_duplicate_uppercase() {
         name: "existing".to_string(),
         query: ".foo".to_string(),
         description: None,
+        input: Default::default(),
     }]);
 
     state.enter_create_mode(".bar");
@@ -201,6 +204,7 @@ fn test_save_new_snippet_case_insensitive_duplicate_mixedcase() {
         name: "MySnippet".to_string(),
         query: ".foo".to_string(),
         description: None,
+        input: Default::default(),
     }]);
 
     state.enter_create_mode(".bar");
@@ -218,6 +222,7 @@ fn test_save_new_snippet_case_insensitive_duplicate_titlecase() {
         name: "select keys".to_string(),
         query: ".foo".to_string(),
         description: None,
+        input: Default::default(),
     }]);
 
     state.enter_create_mode(".bar");
@@ -236,11 +241,13 @@ fn test_new_snippet_inserted_at_beginning() {
             name: "Old First".to_string(),
             query: ".first".to_string(),
             description: None,
+            input: Default::default(),
         },
         Snippet {
             name: "Old Second".to_string(),
             query: ".second".to_string(),
             description: None,
+            input: Default::default(),
         },
     ]);
 
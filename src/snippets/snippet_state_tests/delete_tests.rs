@@ -7,6 +7,7 @@ fn test_enter_delete_mode() {
         name: "My Snippet".to_string(),
         query: ".test".to_string(),
         description: None,
+        input: Default::default(),
     }]);
 
     state.enter_delete_mode();
@@ -33,6 +34,7 @@ fn test_cancel_delete() {
         name: "My Snippet".to_string(),
         query: ".test".to_string(),
         description: None,
+        input: Default::default(),
     }]);
     state.enter_delete_mode();
 
@@ -49,6 +51,7 @@ fn test_confirm_delete_success() {
         name: "My Snippet".to_string(),
         query: ".test".to_string(),
         description: None,
+        input: Default::default(),
     }]);
     state.enter_delete_mode();
 
@@ -66,6 +69,7 @@ fn test_confirm_delete_not_in_mode_fails() {
         name: "My Snippet".to_string(),
         query: ".test".to_string(),
         description: None,
+        input: Default::default(),
     }]);
 
     let result = state.confirm_delete();
@@ -87,16 +91,19 @@ fn test_delete_first_snippet() {
             name: "First".to_string(),
             query: ".first".to_string(),
             description: None,
+            input: Default::default(),
         },
         Snippet {
             name: "Second".to_string(),
             query: ".second".to_string(),
             description: None,
+            input: Default::default(),
         },
         Snippet {
             name: "Third".to_string(),
             query: ".third".to_string(),
             description: None,
+            input: Default::default(),
         },
     ]);
     state.set_selected_index(0);
@@ -118,16 +125,19 @@ fn test_delete_middle_snippet() {
             name: "First".to_string(),
             query: ".first".to_string(),
             description: None,
+            input: Default::default(),
         },
         Snippet {
             name: "Second".to_string(),
             query: ".second".to_string(),
             description: None,
+            input: Default::default(),
         },
         Snippet {
             name: "Third".to_string(),
             query: ".third".to_string(),
             description: None,
+            input: Default::default(),
         },
     ]);
     state.set_selected_index(1);
@@ -149,11 +159,13 @@ fn test_delete_last_snippet_adjusts_selection() {
             name: "First".to_string(),
             query: ".first".to_string(),
             description: None,
+            input: Default::default(),
         },
         Snippet {
             name: "Second".to_string(),
             query: ".second".to_string(),
             description: None,
+            input: Default::default(),
         },
     ]);
     state.set_selected_index(1);
@@ -173,6 +185,7 @@ fn test_delete_only_snippet() {
         name: "Only One".to_string(),
         query: ".only".to_string(),
         description: None,
+        input: Default::default(),
     }]);
     state.enter_delete_mode();
 
@@ -190,11 +203,13 @@ fn test_delete_updates_filtered_indices() {
             name: "First".to_string(),
             query: ".first".to_string(),
             description: None,
+            input: Default::default(),
         },
         Snippet {
             name: "Second".to_string(),
             query: ".second".to_string(),
             description: None,
+            input: Default::default(),
         },
     ]);
     state.enter_delete_mode();
@@ -211,6 +226,7 @@ fn test_is_editing_not_in_delete_mode() {
         name: "My Snippet".to_string(),
         query: ".test".to_string(),
         description: None,
+        input: Default::default(),
     }]);
 
     assert!(!state.is_editing());
@@ -225,6 +241,7 @@ fn test_close_resets_delete_mode() {
         name: "My Snippet".to_string(),
         query: ".test".to_string(),
         description: None,
+        input: Default::default(),
     }]);
     state.enter_delete_mode();
 
@@ -241,16 +258,19 @@ fn test_delete_with_search_filter_active() {
             name: "Alpha".to_string(),
             query: ".alpha".to_string(),
             description: None,
+            input: Default::default(),
         },
         Snippet {
             name: "Beta".to_string(),
             query: ".beta".to_string(),
             description: None,
+            input: Default::default(),
         },
         Snippet {
             name: "Gamma".to_string(),
             query: ".gamma".to_string(),
             description: None,
+            input: Default::default(),
         },
     ]);
     state.set_search_query("Beta");
@@ -12,6 +12,7 @@ fn test_enter_edit_query_via_next_field() {
         name: "My Snippet".to_string(),
         query: ".test | keys".to_string(),
         description: None,
+        input: Default::default(),
     }]);
 
     state.enter_edit_mode();
@@ -33,6 +34,7 @@ fn test_cancel_edit_in_query_mode() {
         name: "My Snippet".to_string(),
         query: ".test".to_string(),
         description: None,
+        input: Default::default(),
     }]);
     enter_edit_query_mode(&mut state);
 
@@ -49,6 +51,7 @@ fn test_update_snippet_query_success() {
         name: "My Snippet".to_string(),
         query: ".old".to_string(),
         description: None,
+        input: Default::default(),
     }]);
     enter_edit_query_mode(&mut state);
 
@@ -70,6 +73,7 @@ fn test_update_snippet_query_empty_fails() {
         name: "My Snippet".to_string(),
         query: ".test".to_string(),
         description: None,
+        input: Default::default(),
     }]);
     enter_edit_query_mode(&mut state);
 
@@ -89,6 +93,7 @@ fn test_update_snippet_query_whitespace_only_fails() {
         name: "My Snippet".to_string(),
         query: ".test".to_string(),
         description: None,
+        input: Default::default(),
     }]);
     enter_edit_query_mode(&mut state);
 
@@ -108,6 +113,7 @@ fn test_update_snippet_query_trims_whitespace() {
         name: "My Snippet".to_string(),
         query: ".old".to_string(),
         description: None,
+        input: Default::default(),
     }]);
     enter_edit_query_mode(&mut state);
 
@@ -128,16 +134,19 @@ fn test_edit_query_keeps_snippet_position() {
             name: "First".to_string(),
             query: ".first".to_string(),
             description: None,
+            input: Default::default(),
         },
         Snippet {
             name: "Second".to_string(),
             query: ".second".to_string(),
             description: None,
+            input: Default::default(),
         },
         Snippet {
             name: "Third".to_string(),
             query: ".third".to_string(),
             description: None,
+            input: Default::default(),
         },
     ]);
     state.set_selected_index(1);
@@ -161,6 +170,7 @@ fn test_edit_query_preserves_name_and_description() {
         name: "My Snippet".to_string(),
         query: ".old".to_string(),
         description: Some("My description".to_string()),
+        input: Default::default(),
     }]);
     enter_edit_query_mode(&mut state);
 
@@ -185,6 +195,7 @@ fn test_update_snippet_query_not_in_edit_mode_fails() {
         name: "My Snippet".to_string(),
         query: ".test".to_string(),
         description: None,
+        input: Default::default(),
     }]);
 
     let result = state.update_snippet_query();
@@ -199,6 +210,7 @@ fn test_is_editing_in_edit_query_mode() {
         name: "My Snippet".to_string(),
         query: ".test".to_string(),
         description: None,
+        input: Default::default(),
     }]);
 
     assert!(!state.is_editing());
@@ -213,6 +225,7 @@ fn test_close_resets_edit_query_mode() {
         name: "My Snippet".to_string(),
         query: ".test".to_string(),
         description: None,
+        input: Default::default(),
     }]);
     state.open();
     enter_edit_query_mode(&mut state);
@@ -230,6 +243,7 @@ fn test_edit_query_same_query_succeeds() {
         name: "My Snippet".to_string(),
         query: ".test".to_string(),
         description: None,
+        input: Default::default(),
     }]);
     enter_edit_query_mode(&mut state);
 
@@ -245,6 +259,7 @@ fn test_edit_query_populates_textarea() {
         name: "Complex Query".to_string(),
         query: ".data[] | select(.active) | {id, name}".to_string(),
         description: None,
+        input: Default::default(),
     }]);
 
     enter_edit_query_mode(&mut state);
@@ -262,6 +277,7 @@ fn test_edit_mode_field_cycling() {
         name: "Test".to_string(),
         query: ".test".to_string(),
         description: Some("Desc".to_string()),
+        input: Default::default(),
     }]);
 
     state.enter_edit_mode();
@@ -284,6 +300,7 @@ fn test_edit_mode_prev_field_cycling() {
         name: "Test".to_string(),
         query: ".test".to_string(),
         description: Some("Desc".to_string()),
+        input: Default::default(),
     }]);
 
     state.enter_edit_mode();
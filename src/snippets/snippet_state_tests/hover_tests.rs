@@ -7,16 +7,19 @@ fn create_test_state_with_snippets() -> SnippetState {
             name: "test1".to_string(),
             query: ".test1".to_string(),
             description: None,
+            input: Default::default(),
         },
         Snippet {
             name: "test2".to_string(),
             query: ".test2".to_string(),
             description: None,
+            input: Default::default(),
         },
         Snippet {
             name: "test3".to_string(),
             query: ".test3".to_string(),
             description: None,
+            input: Default::default(),
         },
     ]);
     state
@@ -14,11 +14,13 @@ fn test_selected_index_resets_on_open() {
             name: "test1".to_string(),
             query: ".".to_string(),
             description: None,
+            input: Default::default(),
         },
         Snippet {
             name: "test2".to_string(),
             query: ".".to_string(),
             description: None,
+            input: Default::default(),
         },
     ]);
     state.select_next();
@@ -36,16 +38,19 @@ fn test_select_next_increments_index() {
             name: "test1".to_string(),
             query: ".".to_string(),
             description: None,
+            input: Default::default(),
         },
         Snippet {
             name: "test2".to_string(),
             query: ".".to_string(),
             description: None,
+            input: Default::default(),
         },
         Snippet {
             name: "test3".to_string(),
             query: ".".to_string(),
             description: None,
+            input: Default::default(),
         },
     ]);
 
@@ -64,11 +69,13 @@ fn test_select_next_stops_at_last_item() {
             name: "test1".to_string(),
             query: ".".to_string(),
             description: None,
+            input: Default::default(),
         },
         Snippet {
             name: "test2".to_string(),
             query: ".".to_string(),
             description: None,
+            input: Default::default(),
         },
     ]);
 
@@ -90,16 +97,19 @@ fn test_select_prev_decrements_index() {
             name: "test1".to_string(),
             query: ".".to_string(),
             description: None,
+            input: Default::default(),
         },
         Snippet {
             name: "test2".to_string(),
             query: ".".to_string(),
             description: None,
+            input: Default::default(),
         },
         Snippet {
             name: "test3".to_string(),
             query: ".".to_string(),
             description: None,
+            input: Default::default(),
         },
     ]);
     state.select_next();
@@ -120,11 +130,13 @@ fn test_select_prev_stops_at_first_item() {
             name: "test1".to_string(),
             query: ".".to_string(),
             description: None,
+            input: Default::default(),
         },
         Snippet {
             name: "test2".to_string(),
             query: ".".to_string(),
             description: None,
+            input: Default::default(),
         },
     ]);
 
@@ -162,6 +174,7 @@ fn test_select_next_with_single_item() {
         name: "test".to_string(),
         query: ".".to_string(),
         description: None,
+        input: Default::default(),
     }]);
 
     assert_eq!(state.selected_index(), 0);
@@ -177,11 +190,13 @@ fn test_selected_snippet_returns_correct_snippet() {
             name: "first".to_string(),
             query: ".first".to_string(),
             description: None,
+            input: Default::default(),
         },
         Snippet {
             name: "second".to_string(),
             query: ".second".to_string(),
             description: Some("desc".to_string()),
+            input: Default::default(),
         },
     ];
     state.set_snippets(snippets);
@@ -210,11 +225,13 @@ fn test_set_snippets_resets_selected_index() {
             name: "test1".to_string(),
             query: ".".to_string(),
             description: None,
+            input: Default::default(),
         },
         Snippet {
             name: "test2".to_string(),
             query: ".".to_string(),
             description: None,
+            input: Default::default(),
         },
     ]);
     state.select_next();
@@ -224,6 +241,7 @@ fn test_set_snippets_resets_selected_index() {
         name: "new".to_string(),
         query: ".".to_string(),
         description: None,
+        input: Default::default(),
     }]);
     assert_eq!(state.selected_index(), 0);
 }
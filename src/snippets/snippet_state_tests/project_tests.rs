@@ -0,0 +1,64 @@
+use std::collections::HashSet;
+
+use super::*;
+
+fn state_with_project_snippet() -> SnippetState {
+    let mut state = SnippetState::new_without_persistence();
+    state.set_snippets(vec![
+        Snippet {
+            name: "Deploys".to_string(),
+            query: ".deployments[]".to_string(),
+            description: None,
+            input: Default::default(),
+        },
+        Snippet {
+            name: "Names".to_string(),
+            query: ".[].name".to_string(),
+            description: None,
+            input: Default::default(),
+        },
+    ]);
+    state.set_project(Some(ProjectSnippets {
+        path: ".jiq/snippets.toml".into(),
+        names: HashSet::from(["Deploys".to_string()]),
+        shadowed: Vec::new(),
+    }));
+    state
+}
+
+#[test]
+fn test_project_snippets_are_recognised() {
+    let state = state_with_project_snippet();
+
+    assert!(state.is_project_snippet(&state.snippets()[0]));
+    assert!(!state.is_project_snippet(&state.snippets()[1]));
+}
+
+#[test]
+fn test_renamed_project_snippet_stays_in_project() {
+    let mut state = state_with_project_snippet();
+
+    state.enter_edit_mode();
+    state.name_textarea_mut().select_all();
+    state.name_textarea_mut().cut();
+    state.name_textarea_mut().insert_str("Rollouts");
+    state.update_snippet_name().unwrap();
+
+    assert!(state.is_project_snippet(&state.snippets()[0]));
+}
+
+#[test]
+fn test_deleted_project_snippet_leaves_project() {
+    let mut state = state_with_project_snippet();
+
+    state.enter_delete_mode();
+    state.confirm_delete().unwrap();
+
+    let deploys = Snippet {
+        name: "Deploys".to_string(),
+        query: ".deployments[]".to_string(),
+        description: None,
+        input: Default::default(),
+    };
+    assert!(!state.is_project_snippet(&deploys));
+}
@@ -7,6 +7,7 @@ fn test_enter_edit_mode() {
         name: "My Snippet".to_string(),
         query: ".test".to_string(),
         description: None,
+        input: Default::default(),
     }]);
 
     state.enter_edit_mode();
@@ -35,6 +36,7 @@ fn test_cancel_edit() {
         name: "My Snippet".to_string(),
         query: ".test".to_string(),
         description: None,
+        input: Default::default(),
     }]);
     state.enter_edit_mode();
 
@@ -51,6 +53,7 @@ fn test_update_snippet_name_success() {
         name: "Old Name".to_string(),
         query: ".test".to_string(),
         description: None,
+        input: Default::default(),
     }]);
     state.enter_edit_mode();
 
@@ -72,6 +75,7 @@ fn test_update_snippet_name_empty_fails() {
         name: "My Snippet".to_string(),
         query: ".test".to_string(),
         description: None,
+        input: Default::default(),
     }]);
     state.enter_edit_mode();
 
@@ -91,6 +95,7 @@ fn test_update_snippet_name_whitespace_only_fails() {
         name: "My Snippet".to_string(),
         query: ".test".to_string(),
         description: None,
+        input: Default::default(),
     }]);
     state.enter_edit_mode();
 
@@ -110,6 +115,7 @@ fn test_update_snippet_name_trims_name() {
         name: "Old Name".to_string(),
         query: ".test".to_string(),
         description: None,
+        input: Default::default(),
     }]);
     state.enter_edit_mode();
 
@@ -130,11 +136,13 @@ fn test_update_snippet_name_duplicate_fails() {
             name: "First".to_string(),
             query: ".first".to_string(),
             description: None,
+            input: Default::default(),
         },
         Snippet {
             name: "Second".to_string(),
             query: ".second".to_string(),
             description: None,
+            input: Default::default(),
         },
     ]);
     state.enter_edit_mode();
@@ -157,11 +165,13 @@ fn test_update_snippet_name_case_insensitive_duplicate() {
             name: "First".to_string(),
             query: ".first".to_string(),
             description: None,
+            input: Default::default(),
         },
         Snippet {
             name: "Second".to_string(),
             query: ".second".to_string(),
             description: None,
+            input: Default::default(),
         },
     ]);
     state.enter_edit_mode();
@@ -182,6 +192,7 @@ fn test_update_snippet_name_same_name_allowed() {
         name: "My Snippet".to_string(),
         query: ".test".to_string(),
         description: None,
+        input: Default::default(),
     }]);
     state.enter_edit_mode();
 
@@ -197,6 +208,7 @@ fn test_update_snippet_name_same_name_different_case_allowed() {
         name: "my snippet".to_string(),
         query: ".test".to_string(),
         description: None,
+        input: Default::default(),
     }]);
     state.enter_edit_mode();
 
@@ -217,16 +229,19 @@ fn test_edit_name_keeps_snippet_position() {
             name: "First".to_string(),
             query: ".first".to_string(),
             description: None,
+            input: Default::default(),
         },
         Snippet {
             name: "Second".to_string(),
             query: ".second".to_string(),
             description: None,
+            input: Default::default(),
         },
         Snippet {
             name: "Third".to_string(),
             query: ".third".to_string(),
             description: None,
+            input: Default::default(),
         },
     ]);
     state.set_selected_index(1);
@@ -250,6 +265,7 @@ fn test_edit_name_preserves_query_and_description() {
         name: "Old Name".to_string(),
         query: ".complex | query".to_string(),
         description: Some("My description".to_string()),
+        input: Default::default(),
     }]);
     state.enter_edit_mode();
 
@@ -274,6 +290,7 @@ fn test_update_name_not_in_edit_mode_fails() {
         name: "My Snippet".to_string(),
         query: ".test".to_string(),
         description: None,
+        input: Default::default(),
     }]);
 
     let result = state.update_snippet_name();
@@ -288,6 +305,7 @@ fn test_is_editing_in_edit_mode() {
         name: "My Snippet".to_string(),
         query: ".test".to_string(),
         description: None,
+        input: Default::default(),
     }]);
 
     assert!(!state.is_editing());
@@ -302,6 +320,7 @@ fn test_close_resets_edit_mode() {
         name: "My Snippet".to_string(),
         query: ".test".to_string(),
         description: None,
+        input: Default::default(),
     }]);
     state.open();
     state.enter_edit_mode();
@@ -9,6 +9,7 @@ fn create_test_snippets(count: usize) -> Vec<Snippet> {
             name: format!("snippet{}", i),
             query: format!(".query{}", i),
             description: None,
+            input: Default::default(),
         })
         .collect()
 }
@@ -8,16 +8,19 @@ fn test_filtered_count_returns_all_when_no_search() {
             name: "test1".to_string(),
             query: ".".to_string(),
             description: None,
+            input: Default::default(),
         },
         Snippet {
             name: "test2".to_string(),
             query: ".".to_string(),
             description: None,
+            input: Default::default(),
         },
         Snippet {
             name: "test3".to_string(),
             query: ".".to_string(),
             description: None,
+            input: Default::default(),
         },
     ]);
     assert_eq!(state.filtered_count(), 3);
@@ -31,16 +34,19 @@ fn test_search_filters_snippets() {
             name: "Select keys".to_string(),
             query: "keys".to_string(),
             description: None,
+            input: Default::default(),
         },
         Snippet {
             name: "Flatten arrays".to_string(),
             query: "flatten".to_string(),
             description: None,
+            input: Default::default(),
         },
         Snippet {
             name: "Select items".to_string(),
             query: ".[]".to_string(),
             description: None,
+            input: Default::default(),
         },
     ]);
 
@@ -56,11 +62,13 @@ fn test_search_no_matches() {
             name: "Select keys".to_string(),
             query: "keys".to_string(),
             description: None,
+            input: Default::default(),
         },
         Snippet {
             name: "Flatten arrays".to_string(),
             query: "flatten".to_string(),
             description: None,
+            input: Default::default(),
         },
     ]);
 
@@ -77,11 +85,13 @@ fn test_search_clears_on_close() {
             name: "test1".to_string(),
             query: ".".to_string(),
             description: None,
+            input: Default::default(),
         },
         Snippet {
             name: "test2".to_string(),
             query: ".".to_string(),
             description: None,
+            input: Default::default(),
         },
     ]);
 
@@ -101,16 +111,19 @@ fn test_search_resets_selection() {
             name: "Select keys".to_string(),
             query: "keys".to_string(),
             description: None,
+            input: Default::default(),
         },
         Snippet {
             name: "Flatten arrays".to_string(),
             query: "flatten".to_string(),
             description: None,
+            input: Default::default(),
         },
         Snippet {
             name: "Select items".to_string(),
             query: ".[]".to_string(),
             description: None,
+            input: Default::default(),
         },
     ]);
 
@@ -130,11 +143,13 @@ fn test_on_search_input_changed_resets_selection() {
             name: "test1".to_string(),
             query: ".".to_string(),
             description: None,
+            input: Default::default(),
         },
         Snippet {
             name: "test2".to_string(),
             query: ".".to_string(),
             description: None,
+            input: Default::default(),
         },
     ]);
 
@@ -153,16 +168,19 @@ fn test_selected_snippet_uses_filtered_indices() {
             name: "Flatten arrays".to_string(),
             query: "flatten".to_string(),
             description: None,
+            input: Default::default(),
         },
         Snippet {
             name: "Select keys".to_string(),
             query: "keys".to_string(),
             description: None,
+            input: Default::default(),
         },
         Snippet {
             name: "Select items".to_string(),
             query: ".[]".to_string(),
             description: None,
+            input: Default::default(),
         },
     ]);
 
@@ -179,16 +197,19 @@ fn test_navigation_respects_filtered_list() {
             name: "Flatten arrays".to_string(),
             query: "flatten".to_string(),
             description: None,
+            input: Default::default(),
         },
         Snippet {
             name: "Select keys".to_string(),
             query: "keys".to_string(),
             description: None,
+            input: Default::default(),
         },
         Snippet {
             name: "Select items".to_string(),
             query: ".[]".to_string(),
             description: None,
+            input: Default::default(),
         },
     ]);
 
@@ -211,16 +232,19 @@ fn test_multi_term_search() {
             name: "Select all keys".to_string(),
             query: "keys".to_string(),
             description: None,
+            input: Default::default(),
         },
         Snippet {
             name: "Select items".to_string(),
             query: ".[]".to_string(),
             description: None,
+            input: Default::default(),
         },
         Snippet {
             name: "Get all values".to_string(),
             query: "values".to_string(),
             description: None,
+            input: Default::default(),
         },
     ]);
 
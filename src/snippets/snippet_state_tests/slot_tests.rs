@@ -0,0 +1,110 @@
+use super::*;
+
+fn state_with_snippets() -> SnippetState {
+    let mut state = SnippetState::new_without_persistence();
+    state.set_snippets(vec![
+        Snippet {
+            name: "Names".to_string(),
+            query: ".[].name".to_string(),
+            description: None,
+            input: Default::default(),
+        },
+        Snippet {
+            name: "Count".to_string(),
+            query: "length".to_string(),
+            description: None,
+            input: Default::default(),
+        },
+    ]);
+    state
+}
+
+#[test]
+fn test_toggle_slot_assigns_selected_snippet() {
+    let mut state = state_with_snippets();
+
+    let message = state.toggle_slot(3).unwrap();
+
+    assert_eq!(message, "Assigned 'Names' to Alt+3");
+    assert_eq!(state.slot_of("Names"), Some(3));
+    assert_eq!(
+        state.slot_snippet(3).map(|s| s.query.as_str()),
+        Some(".[].name")
+    );
+}
+
+#[test]
+fn test_toggle_slot_again_frees_it() {
+    let mut state = state_with_snippets();
+    state.toggle_slot(3).unwrap();
+
+    let message = state.toggle_slot(3).unwrap();
+
+    assert_eq!(message, "Removed 'Names' from Alt+3");
+    assert_eq!(state.slot_of("Names"), None);
+    assert!(state.slot_snippet(3).is_none());
+}
+
+#[test]
+fn test_snippet_moves_to_new_slot_and_replaces_previous_holder() {
+    let mut state = state_with_snippets();
+    state.toggle_slot(1).unwrap();
+    state.toggle_slot(2).unwrap();
+    assert_eq!(state.slot_of("Names"), Some(2));
+    assert!(state.slot_snippet(1).is_none());
+
+    state.set_selected_index(1);
+    state.toggle_slot(2).unwrap();
+
+    assert_eq!(state.slot_of("Names"), None);
+    assert_eq!(state.slot_of("Count"), Some(2));
+}
+
+#[test]
+fn test_toggle_slot_without_selection_fails() {
+    let mut state = SnippetState::new_without_persistence();
+    assert!(state.toggle_slot(1).is_err());
+}
+
+#[test]
+fn test_rename_keeps_slot() {
+    let mut state = state_with_snippets();
+    state.toggle_slot(4).unwrap();
+
+    state.enter_edit_mode();
+    state.name_textarea_mut().select_all();
+    state.name_textarea_mut().cut();
+    state.name_textarea_mut().insert_str("All names");
+    state.update_snippet_name().unwrap();
+
+    assert_eq!(state.slot_of("All names"), Some(4));
+}
+
+#[test]
+fn test_delete_frees_slot() {
+    let mut state = state_with_snippets();
+    state.toggle_slot(5).unwrap();
+
+    state.enter_delete_mode();
+    state.confirm_delete().unwrap();
+
+    assert!(state.slot_snippet(5).is_none());
+    assert!(state.slot_assignments().is_empty());
+}
+
+#[test]
+fn test_slot_assignments_skip_missing_snippets() {
+    let mut state = state_with_snippets();
+    state.set_slots(SnippetSlots::from([
+        (1, "Count".to_string()),
+        (2, "Gone".to_string()),
+    ]));
+
+    let assignments: Vec<_> = state
+        .slot_assignments()
+        .into_iter()
+        .map(|(slot, s)| (slot, s.name.as_str()))
+        .collect();
+
+    assert_eq!(assignments, vec![(1, "Count")]);
+}
@@ -7,6 +7,7 @@ fn test_enter_update_confirmation_success() {
         name: "My Snippet".to_string(),
         query: ".old".to_string(),
         description: None,
+        input: Default::default(),
     }]);
 
     let result = state.enter_update_confirmation(".new".to_string());
@@ -40,6 +41,7 @@ fn test_enter_update_confirmation_with_identical_query() {
         name: "My Snippet".to_string(),
         query: ".same".to_string(),
         description: None,
+        input: Default::default(),
     }]);
 
     let result = state.enter_update_confirmation(".same".to_string());
@@ -56,6 +58,7 @@ fn test_enter_update_confirmation_with_identical_query_trimmed() {
         name: "My Snippet".to_string(),
         query: ".same".to_string(),
         description: None,
+        input: Default::default(),
     }]);
 
     let result = state.enter_update_confirmation("  .same  ".to_string());
@@ -71,6 +74,7 @@ fn test_cancel_update() {
         name: "My Snippet".to_string(),
         query: ".old".to_string(),
         description: None,
+        input: Default::default(),
     }]);
     state.enter_update_confirmation(".new".to_string()).unwrap();
 
@@ -87,6 +91,7 @@ fn test_confirm_update_success() {
         name: "My Snippet".to_string(),
         query: ".old".to_string(),
         description: None,
+        input: Default::default(),
     }]);
     state.enter_update_confirmation(".new".to_string()).unwrap();
 
@@ -104,6 +109,7 @@ fn test_confirm_update_not_in_mode_fails() {
         name: "My Snippet".to_string(),
         query: ".old".to_string(),
         description: None,
+        input: Default::default(),
     }]);
 
     let result = state.confirm_update();
@@ -124,6 +130,7 @@ fn test_confirm_update_preserves_other_fields() {
         name: "My Snippet".to_string(),
         query: ".old".to_string(),
         description: Some("A description".to_string()),
+        input: Default::default(),
     }]);
     state.enter_update_confirmation(".new".to_string()).unwrap();
 
@@ -145,16 +152,19 @@ fn test_update_middle_snippet() {
             name: "First".to_string(),
             query: ".first".to_string(),
             description: None,
+            input: Default::default(),
         },
         Snippet {
             name: "Second".to_string(),
             query: ".second".to_string(),
             description: None,
+            input: Default::default(),
         },
         Snippet {
             name: "Third".to_string(),
             query: ".third".to_string(),
             description: None,
+            input: Default::default(),
         },
     ]);
     state.set_selected_index(1);
@@ -176,6 +186,7 @@ fn test_is_editing_not_in_update_mode() {
         name: "My Snippet".to_string(),
         query: ".test".to_string(),
         description: None,
+        input: Default::default(),
     }]);
 
     assert!(!state.is_editing());
@@ -190,6 +201,7 @@ fn test_close_resets_update_mode() {
         name: "My Snippet".to_string(),
         query: ".old".to_string(),
         description: None,
+        input: Default::default(),
     }]);
     state.enter_update_confirmation(".new".to_string()).unwrap();
 
@@ -206,16 +218,19 @@ fn test_update_with_search_filter_active() {
             name: "Alpha".to_string(),
             query: ".alpha".to_string(),
             description: None,
+            input: Default::default(),
         },
         Snippet {
             name: "Beta".to_string(),
             query: ".beta".to_string(),
             description: None,
+            input: Default::default(),
         },
         Snippet {
             name: "Gamma".to_string(),
             query: ".gamma".to_string(),
             description: None,
+            input: Default::default(),
         },
     ]);
     state.set_search_query("Beta");
@@ -236,6 +251,7 @@ fn test_update_long_query() {
         name: "Complex".to_string(),
         query: ".simple".to_string(),
         description: None,
+        input: Default::default(),
     }]);
     state
         .enter_update_confirmation(long_query.to_string())
@@ -254,11 +270,13 @@ fn test_update_does_not_affect_filtered_indices() {
             name: "First".to_string(),
             query: ".first".to_string(),
             description: None,
+            input: Default::default(),
         },
         Snippet {
             name: "Second".to_string(),
             query: ".second".to_string(),
             description: None,
+            input: Default::default(),
         },
     ]);
     state
@@ -1,44 +1,118 @@
+use std::collections::{BTreeMap, HashSet};
 use std::fs::{self, File};
 use std::io::{self, Read, Write};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use serde::{Deserialize, Serialize};
 
 use super::snippet_state::Snippet;
 
-const CONFIG_DIR: &str = "jiq";
 const SNIPPETS_FILE: &str = "snippets.toml";
+/// Directory in the working directory holding project snippets
+const PROJECT_DIR: &str = ".jiq";
+
+/// Quick slots `Alt+1`..`Alt+9` and the names of the snippets assigned to them
+pub type SnippetSlots = BTreeMap<u8, String>;
 
 #[derive(Deserialize, Serialize)]
 struct SnippetsFile {
     #[serde(default)]
     snippets: Vec<Snippet>,
+    /// Slot number (as a string, since TOML keys are) to snippet name
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    slots: BTreeMap<String, String>,
 }
 
 pub fn snippets_path() -> Option<PathBuf> {
-    dirs::home_dir().map(|p| p.join(".config").join(CONFIG_DIR).join(SNIPPETS_FILE))
+    crate::paths::data_dir().map(|p| p.join(SNIPPETS_FILE))
 }
 
-pub fn load_snippets() -> Vec<Snippet> {
-    let Some(path) = snippets_path() else {
-        return Vec::new();
-    };
+/// Snippets of `.jiq/snippets.toml` in the working directory, kept with
+/// the repository they belong to
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProjectSnippets {
+    pub path: PathBuf,
+    /// Names of the listed snippets that come from the project file
+    pub names: HashSet<String>,
+    /// Global snippets hidden by a project snippet of the same name, kept
+    /// so that saving the global file does not lose them
+    pub shadowed: Vec<Snippet>,
+}
+
+/// Global and project snippets merged, with the quick slot assignments
+#[derive(Debug, Clone, Default)]
+pub struct SnippetStore {
+    pub snippets: Vec<Snippet>,
+    pub slots: SnippetSlots,
+    pub project: Option<ProjectSnippets>,
+}
+
+/// Project snippet file in the working directory, when there is one
+pub fn project_snippets_path() -> Option<PathBuf> {
+    let path = std::env::current_dir()
+        .ok()?
+        .join(PROJECT_DIR)
+        .join(SNIPPETS_FILE);
+    path.is_file().then_some(path)
+}
 
-    load_snippets_from_path(&path)
+pub fn load_store() -> SnippetStore {
+    load_store_from(
+        snippets_path().as_deref(),
+        project_snippets_path().as_deref(),
+    )
 }
 
-pub fn load_snippets_from_path(path: &PathBuf) -> Vec<Snippet> {
-    let mut file = match File::open(path) {
-        Ok(f) => f,
-        Err(_) => return Vec::new(),
+/// Load the global snippets in `global` and the project snippets in
+/// `project`; project snippets come first and shadow global ones with the
+/// same name (ignoring case)
+fn load_store_from(global: Option<&Path>, project: Option<&Path>) -> SnippetStore {
+    let contents = global.map(read_contents).unwrap_or_default();
+    let mut snippets = parse_snippets_toml(&contents);
+    let slots = parse_slots_toml(&contents);
+
+    let Some(project_path) = project else {
+        return SnippetStore {
+            snippets,
+            slots,
+            project: None,
+        };
     };
 
-    let mut contents = String::new();
-    if file.read_to_string(&mut contents).is_err() {
-        return Vec::new();
+    let project_snippets = parse_snippets_toml(&read_contents(project_path));
+    let names: HashSet<String> = project_snippets.iter().map(|s| s.name.clone()).collect();
+    let lower_names: HashSet<String> = names.iter().map(|n| n.to_lowercase()).collect();
+    let (shadowed, global_snippets): (Vec<Snippet>, Vec<Snippet>) = snippets
+        .drain(..)
+        .partition(|s| lower_names.contains(&s.name.to_lowercase()));
+
+    SnippetStore {
+        snippets: project_snippets
+            .into_iter()
+            .chain(global_snippets)
+            .collect(),
+        slots,
+        project: Some(ProjectSnippets {
+            path: project_path.to_path_buf(),
+            names,
+            shadowed,
+        }),
     }
+}
 
-    parse_snippets_toml(&contents)
+#[cfg(test)]
+pub fn load_snippets_from_path(path: &Path) -> Vec<Snippet> {
+    parse_snippets_toml(&read_contents(path))
+}
+
+fn read_contents(path: &Path) -> String {
+    let mut contents = String::new();
+    if let Ok(mut file) = File::open(path)
+        && file.read_to_string(&mut contents).is_err()
+    {
+        contents.clear();
+    }
+    contents
 }
 
 pub fn parse_snippets_toml(content: &str) -> Vec<Snippet> {
@@ -48,28 +122,84 @@ pub fn parse_snippets_toml(content: &str) -> Vec<Snippet> {
     }
 }
 
-pub fn save_snippets(snippets: &[Snippet]) -> io::Result<()> {
+/// Slot assignments in a snippets file, ignoring slots outside 1-9
+pub fn parse_slots_toml(content: &str) -> SnippetSlots {
+    let Ok(snippets_file) = toml::from_str::<SnippetsFile>(content) else {
+        return SnippetSlots::new();
+    };
+    snippets_file
+        .slots
+        .into_iter()
+        .filter_map(|(slot, name)| {
+            let slot = slot.parse::<u8>().ok().filter(|n| (1..=9).contains(n))?;
+            Some((slot, name))
+        })
+        .collect()
+}
+
+/// Write `snippets` back to the files they came from: project snippets to
+/// the project file, the others and the slots to the global file
+pub fn save_store(
+    snippets: &[Snippet],
+    slots: &SnippetSlots,
+    project: Option<&ProjectSnippets>,
+) -> io::Result<()> {
     let Some(path) = snippets_path() else {
         return Err(io::Error::new(
             io::ErrorKind::NotFound,
             "Could not determine snippets file path",
         ));
     };
+    save_store_to(&path, snippets, slots, project)
+}
+
+fn save_store_to(
+    global: &Path,
+    snippets: &[Snippet],
+    slots: &SnippetSlots,
+    project: Option<&ProjectSnippets>,
+) -> io::Result<()> {
+    let Some(project) = project else {
+        return write_file(global, &serialize_snippets_file_toml(snippets, slots));
+    };
 
+    let (project_snippets, mut global_snippets): (Vec<Snippet>, Vec<Snippet>) = snippets
+        .iter()
+        .cloned()
+        .partition(|s| project.names.contains(&s.name));
+    global_snippets.extend(project.shadowed.iter().cloned());
+
+    write_file(
+        &project.path,
+        &serialize_snippets_file_toml(&project_snippets, &SnippetSlots::new()),
+    )?;
+    write_file(
+        global,
+        &serialize_snippets_file_toml(&global_snippets, slots),
+    )
+}
+
+fn write_file(path: &Path, content: &str) -> io::Result<()> {
     if let Some(parent) = path.parent() {
         fs::create_dir_all(parent)?;
     }
 
-    let content = serialize_snippets_toml(snippets);
-    let mut file = File::create(&path)?;
-    file.write_all(content.as_bytes())?;
-
-    Ok(())
+    let mut file = File::create(path)?;
+    file.write_all(content.as_bytes())
 }
 
+#[cfg(test)]
 pub fn serialize_snippets_toml(snippets: &[Snippet]) -> String {
+    serialize_snippets_file_toml(snippets, &SnippetSlots::new())
+}
+
+pub fn serialize_snippets_file_toml(snippets: &[Snippet], slots: &SnippetSlots) -> String {
     let file = SnippetsFile {
         snippets: snippets.to_vec(),
+        slots: slots
+            .iter()
+            .map(|(slot, name)| (slot.to_string(), name.clone()))
+            .collect(),
     };
     toml::to_string_pretty(&file).unwrap_or_default()
 }
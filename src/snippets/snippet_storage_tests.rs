@@ -5,13 +5,14 @@ use std::path::PathBuf;
 use tempfile::TempDir;
 
 use super::*;
+use crate::snippets::SnippetInput;
 
 #[test]
-fn test_snippets_path_returns_config_path() {
+fn test_snippets_path_is_in_data_directory() {
     let path = snippets_path();
     assert!(path.is_some());
     let path = path.unwrap();
-    assert!(path.to_string_lossy().contains(".config/jiq"));
+    assert!(path.starts_with(crate::paths::data_dir().unwrap()));
     assert!(path.to_string_lossy().ends_with("snippets.toml"));
 }
 
@@ -134,6 +135,7 @@ fn test_snippet_struct_serialization() {
         name: "Test".to_string(),
         query: ".foo".to_string(),
         description: Some("A test snippet".to_string()),
+        input: Default::default(),
     };
 
     let toml_str = toml::to_string(&snippet).unwrap();
@@ -148,6 +150,7 @@ fn test_snippet_struct_serialization_without_description() {
         name: "Test".to_string(),
         query: ".foo".to_string(),
         description: None,
+        input: Default::default(),
     };
 
     let toml_str = toml::to_string(&snippet).unwrap();
@@ -225,6 +228,7 @@ fn test_serialize_snippets_toml_single_snippet() {
         name: "Test".to_string(),
         query: ".test".to_string(),
         description: None,
+        input: Default::default(),
     }];
     let result = serialize_snippets_toml(&snippets);
     assert!(result.contains("[[snippets]]"));
@@ -239,6 +243,7 @@ fn test_serialize_snippets_toml_with_description() {
         name: "Test".to_string(),
         query: ".test".to_string(),
         description: Some("A test snippet".to_string()),
+        input: Default::default(),
     }];
     let result = serialize_snippets_toml(&snippets);
     assert!(result.contains("[[snippets]]"));
@@ -254,11 +259,13 @@ fn test_serialize_snippets_toml_multiple_snippets() {
             name: "First".to_string(),
             query: ".first".to_string(),
             description: None,
+            input: Default::default(),
         },
         Snippet {
             name: "Second".to_string(),
             query: ".second".to_string(),
             description: Some("Desc".to_string()),
+            input: Default::default(),
         },
     ];
     let result = serialize_snippets_toml(&snippets);
@@ -274,11 +281,13 @@ fn test_serialize_and_parse_roundtrip() {
             name: "First".to_string(),
             query: ".first | keys".to_string(),
             description: Some("Get keys from first".to_string()),
+            input: Default::default(),
         },
         Snippet {
             name: "Second".to_string(),
             query: ".[].value".to_string(),
             description: None,
+            input: Default::default(),
         },
     ];
 
@@ -303,6 +312,7 @@ fn test_save_snippets_creates_file() {
         name: "Test".to_string(),
         query: ".test".to_string(),
         description: None,
+        input: Default::default(),
     }];
 
     fs::create_dir_all(file_path.parent().unwrap()).unwrap();
@@ -320,6 +330,7 @@ fn test_serialize_snippets_toml_special_characters() {
         name: "Select errors".to_string(),
         query: ".[] | select(.type == \"error\")".to_string(),
         description: Some("Filter \"error\" types".to_string()),
+        input: Default::default(),
     }];
     let result = serialize_snippets_toml(&snippets);
 
@@ -331,3 +342,155 @@ fn test_serialize_snippets_toml_special_characters() {
         Some("Filter \"error\" types".to_string())
     );
 }
+
+#[test]
+fn test_slots_roundtrip_with_snippets() {
+    let snippets = vec![Snippet {
+        name: "Names".to_string(),
+        query: ".[].name".to_string(),
+        description: None,
+        input: Default::default(),
+    }];
+    let slots = SnippetSlots::from([(3, "Names".to_string())]);
+
+    let content = serialize_snippets_file_toml(&snippets, &slots);
+
+    assert!(content.contains("[slots]"));
+    assert_eq!(parse_snippets_toml(&content), snippets);
+    assert_eq!(parse_slots_toml(&content), slots);
+}
+
+#[test]
+fn test_parse_slots_ignores_slots_outside_range() {
+    let content = r#"
+[slots]
+0 = "Zero"
+1 = "One"
+10 = "Ten"
+x = "Other"
+"#;
+    let slots = parse_slots_toml(content);
+    assert_eq!(slots, SnippetSlots::from([(1, "One".to_string())]));
+}
+
+#[test]
+fn test_serialize_without_slots_has_no_slots_table() {
+    let content = serialize_snippets_toml(&[]);
+    assert!(!content.contains("slots"));
+}
+
+#[test]
+fn test_input_is_saved_only_when_set() {
+    let snippets = vec![
+        Snippet {
+            name: "First".to_string(),
+            query: ".[0]".to_string(),
+            description: None,
+            input: SnippetInput::Array,
+        },
+        Snippet {
+            name: "Any".to_string(),
+            query: ".".to_string(),
+            description: None,
+            input: SnippetInput::Any,
+        },
+    ];
+
+    let content = serialize_snippets_toml(&snippets);
+
+    assert_eq!(content.matches("input = ").count(), 1);
+    assert!(content.contains("input = \"array\""));
+    assert_eq!(parse_snippets_toml(&content), snippets);
+}
+
+fn named(name: &str, query: &str) -> Snippet {
+    Snippet {
+        name: name.to_string(),
+        query: query.to_string(),
+        description: None,
+        input: SnippetInput::Any,
+    }
+}
+
+fn write_snippets(path: &std::path::Path, snippets: &[Snippet]) {
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, serialize_snippets_toml(snippets)).unwrap();
+}
+
+#[test]
+fn test_project_snippets_come_first_and_shadow_global_ones() {
+    let temp_dir = TempDir::new().unwrap();
+    let global = temp_dir.path().join("global.toml");
+    let project = temp_dir.path().join(".jiq").join("snippets.toml");
+    write_snippets(
+        &global,
+        &[named("Names", ".[].name"), named("Count", "length")],
+    );
+    write_snippets(&project, &[named("count", ".items | length")]);
+
+    let store = load_store_from(Some(&global), Some(&project));
+
+    assert_eq!(
+        store.snippets,
+        vec![
+            named("count", ".items | length"),
+            named("Names", ".[].name")
+        ]
+    );
+    let project_snippets = store.project.unwrap();
+    assert!(project_snippets.names.contains("count"));
+    assert_eq!(project_snippets.shadowed, vec![named("Count", "length")]);
+}
+
+#[test]
+fn test_store_without_project_file() {
+    let temp_dir = TempDir::new().unwrap();
+    let global = temp_dir.path().join("global.toml");
+    write_snippets(&global, &[named("Names", ".[].name")]);
+
+    let store = load_store_from(Some(&global), None);
+
+    assert_eq!(store.snippets, vec![named("Names", ".[].name")]);
+    assert!(store.project.is_none());
+}
+
+#[test]
+fn test_save_store_writes_each_snippet_to_its_file() {
+    let temp_dir = TempDir::new().unwrap();
+    let global = temp_dir.path().join("global.toml");
+    let project = temp_dir.path().join(".jiq").join("snippets.toml");
+    write_snippets(
+        &global,
+        &[named("Names", ".[].name"), named("Count", "length")],
+    );
+    write_snippets(&project, &[named("Count", ".items | length")]);
+    let mut store = load_store_from(Some(&global), Some(&project));
+    store.snippets.push(named("Keys", "keys"));
+    store.slots.insert(1, "Count".to_string());
+
+    save_store_to(
+        &global,
+        &store.snippets,
+        &store.slots,
+        store.project.as_ref(),
+    )
+    .unwrap();
+
+    assert_eq!(
+        load_snippets_from_path(&project),
+        vec![named("Count", ".items | length")]
+    );
+    assert_eq!(
+        load_snippets_from_path(&global),
+        vec![
+            named("Names", ".[].name"),
+            named("Keys", "keys"),
+            named("Count", "length")
+        ]
+    );
+    let global_contents = fs::read_to_string(&global).unwrap();
+    assert_eq!(
+        parse_slots_toml(&global_contents),
+        SnippetSlots::from([(1, "Count".to_string())])
+    );
+}
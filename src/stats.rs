@@ -1,12 +1,24 @@
 //! Stats module for computing and displaying result statistics
 //!
 //! This module provides fast, character-based parsing to compute statistics
-//! about jq query results without full JSON parsing.
+//! about jq query results without full JSON parsing. The field differences
+//! view (`D`) parses the result to compare the elements of an array, and the
+//! size breakdown (`S`) to measure its top-level keys or elements.
 
+pub mod field_diff;
+pub mod field_diff_events;
+pub mod field_diff_render;
+mod field_diff_state;
 pub mod parser;
+pub mod size_breakdown;
+pub mod size_breakdown_events;
+pub mod size_breakdown_render;
+mod size_breakdown_state;
 mod stats_state;
 pub mod types;
 
 // Re-export public types
+pub use field_diff_state::FieldDiffState;
+pub use size_breakdown_state::SizeBreakdownState;
 pub use stats_state::StatsState;
 pub use stats_state::update_stats_from_app;
@@ -0,0 +1,223 @@
+//! Differences between array elements
+//!
+//! For a result that is an array of objects, or a stream of them, lists each
+//! field with whether it is the same in every element, differs in only a
+//! few, or varies. Spots the one record configured differently from the
+//! rest.
+
+use std::collections::HashMap;
+
+use serde_json::Value;
+
+use crate::query::language::jq_field;
+
+/// Most elements listed as differing from the rest of a field
+const MAX_OUTLIERS: usize = 3;
+
+/// How a field compares across elements
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Variation {
+    /// Every element has `value`
+    Constant { value: String },
+    /// All elements but `outliers` have `common`
+    Outliers {
+        common: String,
+        /// Index and value of each differing element, `None` when missing
+        outliers: Vec<(usize, Option<String>)>,
+    },
+    /// Values are spread out
+    Varies {
+        distinct: usize,
+        /// Elements without the field
+        missing: usize,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldDiff {
+    /// jq path of the field within an element, e.g. `.spec.replicas`
+    pub path: String,
+    pub variation: Variation,
+}
+
+/// Comparison of the elements of a result
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ElementDiff {
+    pub elements: usize,
+    /// Whether the elements are separate outputs rather than one array
+    pub stream: bool,
+    /// Fields differing in a few elements first, then varying, then constant
+    pub fields: Vec<FieldDiff>,
+}
+
+impl ElementDiff {
+    /// Compare the elements of `results`, the text jq printed
+    pub fn analyze(results: &str) -> Result<Self, String> {
+        let values = serde_json::Deserializer::from_str(results)
+            .into_iter::<Value>()
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| format!("Result is not JSON: {}", e))?;
+        let (elements, stream) = match values.as_slice() {
+            [Value::Array(items)] => (items.iter().collect::<Vec<_>>(), false),
+            _ => (values.iter().collect(), true),
+        };
+        if elements.len() < 2 || !elements.iter().all(|element| element.is_object()) {
+            return Err("Differences need an array or stream of at least 2 objects".to_string());
+        }
+
+        let mut fields: Vec<FieldValues> = Vec::new();
+        let mut index: HashMap<String, usize> = HashMap::new();
+        for (element_index, element) in elements.iter().enumerate() {
+            let mut leaves = Vec::new();
+            collect_leaves(element, String::new(), &mut leaves);
+            for (path, value) in leaves {
+                let position = *index.entry(path.clone()).or_insert_with(|| {
+                    fields.push(FieldValues::new(path));
+                    fields.len() - 1
+                });
+                fields[position].add(element_index, value);
+            }
+        }
+
+        let count = elements.len();
+        let mut fields: Vec<FieldDiff> = fields
+            .into_iter()
+            .map(|field| field.compare(count))
+            .collect();
+        fields.sort_by_key(|field| match field.variation {
+            Variation::Outliers { .. } => 0,
+            Variation::Varies { .. } => 1,
+            Variation::Constant { .. } => 2,
+        });
+        Ok(Self {
+            elements: count,
+            stream,
+            fields,
+        })
+    }
+
+    /// Label of element `index`: `[3]` in an array, `#4` in a stream
+    pub fn element_label(&self, index: usize) -> String {
+        if self.stream {
+            format!("#{}", index + 1)
+        } else {
+            format!("[{}]", index)
+        }
+    }
+}
+
+/// Paths and compact JSON of the scalars and arrays in `value`, descending
+/// into objects
+///
+/// Paths start with their first segment, `.name` or `.["app name"]`.
+pub(crate) fn collect_leaves(value: &Value, path: String, leaves: &mut Vec<(String, String)>) {
+    match value {
+        Value::Object(map) if !map.is_empty() || path.is_empty() => {
+            for (key, value) in map {
+                collect_leaves(value, format!("{}{}", path, jq_field(key)), leaves);
+            }
+        }
+        _ => leaves.push((path, value.to_string())),
+    }
+}
+
+/// Values of one field, with the elements that have each
+struct FieldValues {
+    path: String,
+    present: usize,
+    /// Each distinct value in the order first seen, with how many elements
+    /// have it and the first few of them
+    values: Vec<(String, usize, Vec<usize>)>,
+    index: HashMap<String, usize>,
+    /// Elements seen with the field, in order, to find those without it
+    elements: Vec<usize>,
+}
+
+impl FieldValues {
+    fn new(path: String) -> Self {
+        Self {
+            path,
+            present: 0,
+            values: Vec::new(),
+            index: HashMap::new(),
+            elements: Vec::new(),
+        }
+    }
+
+    fn add(&mut self, element: usize, value: String) {
+        self.present += 1;
+        self.elements.push(element);
+        let values = &mut self.values;
+        let position = *self.index.entry(value.clone()).or_insert_with(|| {
+            values.push((value, 0, Vec::new()));
+            values.len() - 1
+        });
+        let (_, count, elements) = &mut self.values[position];
+        *count += 1;
+        if elements.len() <= MAX_OUTLIERS {
+            elements.push(element);
+        }
+    }
+
+    fn compare(self, count: usize) -> FieldDiff {
+        let missing = count - self.present;
+        let variation = self.variation(count, missing);
+        FieldDiff {
+            path: if self.path.starts_with('[') {
+                format!(".{}", self.path)
+            } else {
+                self.path
+            },
+            variation,
+        }
+    }
+
+    fn variation(&self, count: usize, missing: usize) -> Variation {
+        let (common, common_count, _) = self
+            .values
+            .iter()
+            .max_by_key(|(_, count, _)| *count)
+            .expect("a field has at least one value");
+        if *common_count == count {
+            return Variation::Constant {
+                value: common.clone(),
+            };
+        }
+
+        // With two elements neither is the odd one out
+        let differing = count - common_count;
+        if count > 2 && differing <= MAX_OUTLIERS && differing * 2 < count {
+            let mut outliers: Vec<(usize, Option<String>)> = self
+                .values
+                .iter()
+                .filter(|(value, _, _)| value != common)
+                .flat_map(|(value, _, elements)| {
+                    elements
+                        .iter()
+                        .map(move |element| (*element, Some(value.clone())))
+                })
+                .collect();
+            if missing > 0 {
+                outliers.extend(
+                    (0..count)
+                        .filter(|element| self.elements.binary_search(element).is_err())
+                        .map(|element| (element, None)),
+                );
+            }
+            outliers.sort();
+            return Variation::Outliers {
+                common: common.clone(),
+                outliers,
+            };
+        }
+
+        Variation::Varies {
+            distinct: self.values.len(),
+            missing,
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "field_diff_tests.rs"]
+mod field_diff_tests;
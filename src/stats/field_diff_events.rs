@@ -0,0 +1,35 @@
+use ratatui::crossterm::event::{KeyCode, KeyEvent};
+
+use super::field_diff::ElementDiff;
+use crate::app::App;
+use crate::scroll::handle_scroll_key;
+
+/// Compare the elements of the last successful result and show the popup
+pub fn open_field_diff(app: &mut App) {
+    let Some(results) = app
+        .query
+        .as_ref()
+        .and_then(|q| q.last_successful_result_text())
+    else {
+        app.notification.show_warning("No result to compare yet");
+        return;
+    };
+
+    match ElementDiff::analyze(&results) {
+        Ok(diff) => app.field_diff.open(diff),
+        Err(e) => app.notification.show_warning(&e),
+    }
+}
+
+pub fn handle_field_diff_key(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('D') => app.field_diff.close(),
+        _ => {
+            handle_scroll_key(&mut app.field_diff.scroll, key);
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "field_diff_events_tests.rs"]
+mod field_diff_events_tests;
@@ -0,0 +1,48 @@
+//! Tests for field_diff_events
+
+use super::*;
+use crate::app::Focus;
+use crate::test_utils::test_helpers::{app_with_result, assert_open_refused, key};
+
+#[test]
+fn test_open_compares_result_elements() {
+    let mut app = app_with_result(r#"{"items": [{"a": 1}, {"a": 2}]}"#, ".items");
+    open_field_diff(&mut app);
+
+    assert!(app.field_diff.is_visible());
+    assert_eq!(app.field_diff.diff().unwrap().elements, 2);
+}
+
+#[test]
+fn test_open_explains_unsuitable_result() {
+    let mut app = app_with_result(r#"{"items": [{"a": 1}, {"a": 2}]}"#, ".");
+
+    assert_open_refused(
+        &mut app,
+        open_field_diff,
+        |app| app.field_diff.is_visible(),
+        "Differences need an array or stream of at least 2 objects",
+    );
+}
+
+#[test]
+fn test_d_in_results_pane_toggles_popup() {
+    let mut app = app_with_result(r#"[{"a": 1}, {"a": 2}, {"a": 1}]"#, ".");
+    app.focus = Focus::ResultsPane;
+
+    app.handle_key_event(key(KeyCode::Char('D')));
+    assert!(app.field_diff.is_visible());
+
+    app.handle_key_event(key(KeyCode::Char('j')));
+    app.handle_key_event(key(KeyCode::Char('D')));
+    assert!(!app.field_diff.is_visible());
+}
+
+#[test]
+fn test_esc_closes_popup() {
+    let mut app = app_with_result(r#"[{"a": 1}, {"a": 2}]"#, ".");
+    open_field_diff(&mut app);
+
+    handle_field_diff_key(&mut app, key(KeyCode::Esc));
+    assert!(!app.field_diff.is_visible());
+}
@@ -0,0 +1,190 @@
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::Style,
+    text::{Line, Span, Text},
+    widgets::{Block, BorderType, Borders, Paragraph},
+};
+
+use super::field_diff::{ElementDiff, FieldDiff, Variation};
+use crate::app::App;
+use crate::theme;
+use crate::widgets::text_width::{display_width, truncate_to_width};
+use crate::widgets::{popup, scrollbar};
+
+/// Widest path column before values are pushed right
+const MAX_PATH_WIDTH: usize = 32;
+
+/// Columns of a value shown before it is cut off
+const MAX_VALUE_WIDTH: usize = 40;
+
+fn build_hints() -> Line<'static> {
+    theme::border_hints::build_hints(
+        &[("j/k", "Scroll"), ("g/G", "Top/Bottom"), ("Esc", "Close")],
+        theme::field_diff::BORDER,
+    )
+}
+
+fn shorten(value: &str) -> String {
+    truncate_to_width(value, MAX_VALUE_WIDTH)
+}
+
+/// Spans describing how the field compares across elements
+fn describe(diff: &ElementDiff, variation: &Variation) -> Vec<Span<'static>> {
+    let value_style = Style::default().fg(theme::field_diff::VALUE);
+    let note_style = Style::default().fg(theme::field_diff::NOTE);
+    match variation {
+        Variation::Constant { value } => vec![Span::styled(shorten(value), value_style)],
+        Variation::Outliers { common, outliers } => {
+            let mut spans = vec![
+                Span::styled(shorten(common), value_style),
+                Span::styled(" except ", note_style),
+            ];
+            for (i, (element, value)) in outliers.iter().enumerate() {
+                if i > 0 {
+                    spans.push(Span::styled(", ", note_style));
+                }
+                spans.push(Span::styled(
+                    format!("{} ", diff.element_label(*element)),
+                    theme::field_diff::OUTLIER,
+                ));
+                spans.push(match value {
+                    Some(value) => Span::styled(shorten(value), value_style),
+                    None => Span::styled("missing", note_style),
+                });
+            }
+            spans
+        }
+        Variation::Varies { distinct, missing } => {
+            let mut text = format!("{} different values", distinct);
+            if *missing > 0 {
+                text.push_str(&format!(", missing from {}", missing));
+            }
+            vec![Span::styled(text, note_style)]
+        }
+    }
+}
+
+fn section_lines(
+    diff: &ElementDiff,
+    title: &str,
+    fields: &[&FieldDiff],
+    path_width: usize,
+) -> Vec<Line<'static>> {
+    if fields.is_empty() {
+        return Vec::new();
+    }
+
+    let mut lines = vec![Line::from(Span::styled(
+        format!(" {} ({})", title, fields.len()),
+        theme::field_diff::SECTION,
+    ))];
+    for field in fields {
+        let padding = path_width.saturating_sub(display_width(&field.path));
+        let mut spans = vec![
+            Span::raw("   "),
+            Span::styled(field.path.clone(), theme::field_diff::PATH),
+            Span::raw(" ".repeat(padding + 2)),
+        ];
+        spans.extend(describe(diff, &field.variation));
+        lines.push(Line::from(spans));
+    }
+    lines.push(Line::from(""));
+    lines
+}
+
+fn diff_lines(diff: &ElementDiff) -> Vec<Line<'static>> {
+    let path_width = diff
+        .fields
+        .iter()
+        .map(|field| display_width(&field.path))
+        .max()
+        .unwrap_or(0)
+        .min(MAX_PATH_WIDTH);
+    let with = |matches: fn(&Variation) -> bool| -> Vec<&FieldDiff> {
+        diff.fields
+            .iter()
+            .filter(|field| matches(&field.variation))
+            .collect()
+    };
+
+    let mut lines = Vec::new();
+    lines.extend(section_lines(
+        diff,
+        "Different in a few elements",
+        &with(|v| matches!(v, Variation::Outliers { .. })),
+        path_width,
+    ));
+    lines.extend(section_lines(
+        diff,
+        "Varies",
+        &with(|v| matches!(v, Variation::Varies { .. })),
+        path_width,
+    ));
+    lines.extend(section_lines(
+        diff,
+        "Same in every element",
+        &with(|v| matches!(v, Variation::Constant { .. })),
+        path_width,
+    ));
+    lines
+}
+
+/// Render the popup comparing the elements of the result
+pub fn render_field_diff(app: &mut App, frame: &mut Frame) -> Option<Rect> {
+    let frame_area = frame.area();
+    if frame_area.width < 30 || frame_area.height < 8 {
+        return None;
+    }
+    let diff = app.field_diff.diff()?;
+
+    let popup_width = ((frame_area.width as f32 * 0.8) as u16)
+        .clamp(50, 110)
+        .min(frame_area.width.saturating_sub(4));
+    let popup_height = ((frame_area.height as f32 * 0.7) as u16)
+        .clamp(8, 30)
+        .min(frame_area.height.saturating_sub(2));
+    let popup_area = popup::centered_popup(frame_area, popup_width, popup_height);
+    popup::clear_area(frame, popup_area);
+
+    let title = format!(" Differences between {} elements ", diff.elements);
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(Line::from(Span::styled(title, theme::field_diff::TITLE)))
+        .title_bottom(build_hints().centered())
+        .border_style(Style::default().fg(theme::field_diff::BORDER))
+        .style(Style::default().bg(theme::field_diff::BACKGROUND));
+    let content_area = block.inner(popup_area);
+    frame.render_widget(block, popup_area);
+
+    let lines = diff_lines(diff);
+    let content_height = lines.len() as u32;
+    let scroll = &mut app.field_diff.scroll;
+    scroll.update_bounds(content_height, content_area.height);
+    frame.render_widget(
+        Paragraph::new(Text::from(lines)).scroll((scroll.offset, 0)),
+        content_area,
+    );
+
+    let scrollbar_area = Rect {
+        x: popup_area.x,
+        y: popup_area.y.saturating_add(1),
+        width: popup_area.width,
+        height: popup_area.height.saturating_sub(2),
+    };
+    scrollbar::render_vertical_scrollbar_styled(
+        frame,
+        scrollbar_area,
+        content_height as usize,
+        scroll.viewport_height as usize,
+        scroll.offset as usize,
+        theme::field_diff::BORDER,
+    );
+
+    Some(popup_area)
+}
+
+#[cfg(test)]
+#[path = "field_diff_render_tests.rs"]
+mod field_diff_render_tests;
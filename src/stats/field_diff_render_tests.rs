@@ -0,0 +1,58 @@
+//! Tests for stats/field_diff_render
+
+use super::*;
+use crate::test_utils::test_helpers::test_app;
+use insta::assert_snapshot;
+use ratatui::Terminal;
+use ratatui::backend::TestBackend;
+
+fn render_to_string(app: &mut App, width: u16, height: u16) -> String {
+    let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+    terminal
+        .draw(|f| {
+            render_field_diff(app, f);
+        })
+        .unwrap();
+    terminal.backend().to_string()
+}
+
+#[test]
+fn snapshot_field_diff() {
+    let mut app = test_app(r#"{"a": 1}"#);
+    let diff = ElementDiff::analyze(
+        r#"[
+        {"name": "api", "kind": "Deployment", "replicas": 3, "team": "core"},
+        {"name": "web", "kind": "Deployment", "replicas": 3, "team": "core"},
+        {"name": "jobs", "kind": "Deployment", "replicas": 1},
+        {"name": "auth", "kind": "Deployment", "replicas": 3, "team": "core"}
+    ]"#,
+    )
+    .unwrap();
+    app.field_diff.open(diff);
+
+    assert_snapshot!(render_to_string(&mut app, 90, 18));
+}
+
+#[test]
+fn test_long_values_are_shortened() {
+    let long = "x".repeat(100);
+    let shortened = shorten(&long);
+    assert_eq!(display_width(&shortened), MAX_VALUE_WIDTH);
+    assert!(shortened.ends_with('…'));
+    assert_eq!(shorten("short"), "short");
+    assert_eq!(
+        display_width(&shorten(&"表".repeat(30))),
+        MAX_VALUE_WIDTH - 1
+    );
+}
+
+#[test]
+fn test_nothing_rendered_when_closed() {
+    let mut app = test_app(r#"{"a": 1}"#);
+    let mut terminal = Terminal::new(TestBackend::new(80, 20)).unwrap();
+    let mut area = Some(Rect::default());
+    terminal
+        .draw(|f| area = render_field_diff(&mut app, f))
+        .unwrap();
+    assert_eq!(area, None);
+}
@@ -0,0 +1,32 @@
+use super::field_diff::ElementDiff;
+use crate::scroll::ScrollState;
+
+/// Popup comparing the elements of the result (`D` or `:diff`)
+#[derive(Debug, Default)]
+pub struct FieldDiffState {
+    diff: Option<ElementDiff>,
+    pub scroll: ScrollState,
+}
+
+impl FieldDiffState {
+    pub fn is_visible(&self) -> bool {
+        self.diff.is_some()
+    }
+
+    pub fn diff(&self) -> Option<&ElementDiff> {
+        self.diff.as_ref()
+    }
+
+    pub fn open(&mut self, diff: ElementDiff) {
+        self.diff = Some(diff);
+        self.scroll = ScrollState::new();
+    }
+
+    pub fn close(&mut self) {
+        self.diff = None;
+    }
+}
+
+#[cfg(test)]
+#[path = "field_diff_state_tests.rs"]
+mod field_diff_state_tests;
@@ -0,0 +1,20 @@
+//! Tests for field_diff_state
+
+use super::*;
+
+#[test]
+fn test_open_and_close() {
+    let mut state = FieldDiffState::default();
+    assert!(!state.is_visible());
+
+    let diff = ElementDiff::analyze(r#"[{"a": 1}, {"a": 1}]"#).unwrap();
+    state.scroll.offset = 4;
+    state.open(diff.clone());
+    assert!(state.is_visible());
+    assert_eq!(state.diff(), Some(&diff));
+    assert_eq!(state.scroll.offset, 0);
+
+    state.close();
+    assert!(!state.is_visible());
+    assert_eq!(state.diff(), None);
+}
@@ -0,0 +1,150 @@
+//! Tests for field_diff
+
+use super::*;
+
+fn analyze(results: &str) -> ElementDiff {
+    ElementDiff::analyze(results).unwrap()
+}
+
+fn field<'a>(diff: &'a ElementDiff, path: &str) -> &'a Variation {
+    &diff
+        .fields
+        .iter()
+        .find(|field| field.path == path)
+        .unwrap_or_else(|| panic!("no field {}", path))
+        .variation
+}
+
+const DEPLOYMENTS: &str = r#"[
+    {"name": "api", "kind": "Deployment", "spec": {"replicas": 3, "image": "api:1"}},
+    {"name": "web", "kind": "Deployment", "spec": {"replicas": 3, "image": "web:1"}},
+    {"name": "jobs", "kind": "Deployment", "spec": {"replicas": 1, "image": "jobs:1"}},
+    {"name": "auth", "kind": "Deployment", "spec": {"replicas": 3, "image": "auth:1", "debug": true}},
+    {"name": "mail", "kind": "Deployment", "spec": {"replicas": 3, "image": "mail:1"}}
+]"#;
+
+#[test]
+fn test_constant_fields() {
+    let diff = analyze(DEPLOYMENTS);
+    assert_eq!(diff.elements, 5);
+    assert!(!diff.stream);
+    assert_eq!(
+        field(&diff, ".kind"),
+        &Variation::Constant {
+            value: "\"Deployment\"".to_string()
+        }
+    );
+}
+
+#[test]
+fn test_odd_elements_are_listed() {
+    let diff = analyze(DEPLOYMENTS);
+    assert_eq!(
+        field(&diff, ".spec.replicas"),
+        &Variation::Outliers {
+            common: "3".to_string(),
+            outliers: vec![(2, Some("1".to_string()))],
+        }
+    );
+    // A field only one element has is missing from the others, which
+    // are too many to be the odd ones out
+    assert_eq!(
+        field(&diff, ".spec.debug"),
+        &Variation::Varies {
+            distinct: 1,
+            missing: 4
+        }
+    );
+}
+
+#[test]
+fn test_missing_field_is_an_outlier() {
+    let diff = analyze(
+        r#"{"id": 1, "team": "a"}
+{"id": 2, "team": "a"}
+{"id": 3}
+{"id": 4, "team": "a"}"#,
+    );
+    assert!(diff.stream);
+    assert_eq!(
+        field(&diff, ".team"),
+        &Variation::Outliers {
+            common: "\"a\"".to_string(),
+            outliers: vec![(2, None)],
+        }
+    );
+    assert_eq!(diff.element_label(2), "#3");
+}
+
+#[test]
+fn test_varying_fields() {
+    let diff = analyze(DEPLOYMENTS);
+    assert_eq!(
+        field(&diff, ".name"),
+        &Variation::Varies {
+            distinct: 5,
+            missing: 0
+        }
+    );
+    assert_eq!(diff.element_label(2), "[2]");
+}
+
+#[test]
+fn test_two_elements_have_no_outliers() {
+    let diff = analyze(r#"[{"a": 1}, {"a": 2}]"#);
+    assert_eq!(
+        field(&diff, ".a"),
+        &Variation::Varies {
+            distinct: 2,
+            missing: 0
+        }
+    );
+}
+
+#[test]
+fn test_fields_are_ordered_by_variation() {
+    let diff = analyze(DEPLOYMENTS);
+    let kinds: Vec<u8> = diff
+        .fields
+        .iter()
+        .map(|field| match field.variation {
+            Variation::Outliers { .. } => 0,
+            Variation::Varies { .. } => 1,
+            Variation::Constant { .. } => 2,
+        })
+        .collect();
+    let mut sorted = kinds.clone();
+    sorted.sort();
+    assert_eq!(kinds, sorted);
+    assert_eq!(diff.fields[0].path, ".spec.replicas");
+}
+
+#[test]
+fn test_arrays_and_odd_keys_are_compared_whole() {
+    let diff = analyze(r#"[{"x-tags": [1, 2], "e": {}}, {"x-tags": [1, 2], "e": {}}]"#);
+    assert_eq!(
+        field(&diff, ".[\"x-tags\"]"),
+        &Variation::Constant {
+            value: "[1,2]".to_string()
+        }
+    );
+    assert_eq!(
+        field(&diff, ".e"),
+        &Variation::Constant {
+            value: "{}".to_string()
+        }
+    );
+}
+
+#[test]
+fn test_needs_several_objects() {
+    let error = "Differences need an array or stream of at least 2 objects".to_string();
+    assert_eq!(ElementDiff::analyze(r#"[{"a": 1}]"#), Err(error.clone()));
+    assert_eq!(ElementDiff::analyze("[1, 2, 3]"), Err(error.clone()));
+    assert_eq!(ElementDiff::analyze(r#"{"a": 1}"#), Err(error));
+    assert!(
+        ElementDiff::analyze("not json")
+            .unwrap_err()
+            .starts_with("Result is not JSON")
+    );
+}
@@ -0,0 +1,167 @@
+//! Size breakdown of a result
+//!
+//! Measures the compact serialized size and the number of JSON values under
+//! each top-level key of an object, each element of an array or each output
+//! of a stream, heaviest first. Finds which part of a large payload takes
+//! up the space.
+
+use std::io;
+
+use serde_json::Value;
+
+use crate::query::language::jq_field;
+
+/// Parts listed before the rest are summed up in one row
+pub const MAX_PARTS: usize = 500;
+
+/// What the parts of a result are
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PartKind {
+    Key,
+    Element,
+    Output,
+}
+
+impl PartKind {
+    /// Plural name, e.g. "keys"
+    pub fn plural(self) -> &'static str {
+        match self {
+            PartKind::Key => "keys",
+            PartKind::Element => "elements",
+            PartKind::Output => "outputs",
+        }
+    }
+}
+
+/// Size of one top-level key, element or output
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartSize {
+    /// `.name`, `.["app name"]`, `[3]` or `#4`
+    pub label: String,
+    /// Bytes of the value serialized compactly
+    pub bytes: u64,
+    /// JSON values in the part, counting the part itself
+    pub nodes: u64,
+}
+
+/// Sizes of the parts of a result
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SizeBreakdown {
+    pub kind: PartKind,
+    /// Parts in the result, including those not listed
+    pub count: usize,
+    /// Bytes of the whole result serialized compactly
+    pub total_bytes: u64,
+    pub total_nodes: u64,
+    /// The heaviest parts, at most [`MAX_PARTS`], largest first
+    pub parts: Vec<PartSize>,
+    /// Combined size of the parts not listed
+    pub rest_bytes: u64,
+    pub rest_nodes: u64,
+}
+
+impl SizeBreakdown {
+    /// Measure the parts of `results`, the text jq printed
+    pub fn analyze(results: &str) -> Result<Self, String> {
+        let values = serde_json::Deserializer::from_str(results)
+            .into_iter::<Value>()
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| format!("Result is not JSON: {}", e))?;
+
+        let (kind, mut parts) = match values.as_slice() {
+            [Value::Object(map)] => (
+                PartKind::Key,
+                map.iter()
+                    .map(|(key, value)| measure(jq_field(key), value))
+                    .collect::<Vec<_>>(),
+            ),
+            [Value::Array(items)] => (
+                PartKind::Element,
+                items
+                    .iter()
+                    .enumerate()
+                    .map(|(index, item)| measure(format!("[{}]", index), item))
+                    .collect(),
+            ),
+            [_, _, ..] => (
+                PartKind::Output,
+                values
+                    .iter()
+                    .enumerate()
+                    .map(|(index, value)| measure(format!("#{}", index + 1), value))
+                    .collect(),
+            ),
+            _ => return Err("Size breakdown needs an object, an array or a stream".to_string()),
+        };
+        if parts.is_empty() {
+            return Err(format!(
+                "Nothing to measure: the result has no {}",
+                kind.plural()
+            ));
+        }
+
+        let (total_bytes, total_nodes) = match kind {
+            PartKind::Output => (
+                parts.iter().map(|part| part.bytes).sum(),
+                parts.iter().map(|part| part.nodes).sum(),
+            ),
+            _ => (serialized_size(&values[0]), count_nodes(&values[0])),
+        };
+        let count = parts.len();
+        // Stable, so equal parts keep their document order
+        parts.sort_by_key(|part| std::cmp::Reverse(part.bytes));
+        let rest = parts.split_off(count.min(MAX_PARTS));
+
+        Ok(Self {
+            kind,
+            count,
+            total_bytes,
+            total_nodes,
+            parts,
+            rest_bytes: rest.iter().map(|part| part.bytes).sum(),
+            rest_nodes: rest.iter().map(|part| part.nodes).sum(),
+        })
+    }
+}
+
+fn measure(label: String, value: &Value) -> PartSize {
+    PartSize {
+        label,
+        bytes: serialized_size(value),
+        nodes: count_nodes(value),
+    }
+}
+
+/// Counts the bytes written to it without keeping them
+struct ByteCounter(u64);
+
+impl io::Write for ByteCounter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0 += buf.len() as u64;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Bytes of `value` serialized compactly, without building the string
+pub fn serialized_size(value: &Value) -> u64 {
+    let mut counter = ByteCounter(0);
+    serde_json::to_writer(&mut counter, value).expect("serializing a Value does not fail");
+    counter.0
+}
+
+/// JSON values in `value`, counting itself and everything nested in it
+pub fn count_nodes(value: &Value) -> u64 {
+    1 + match value {
+        Value::Array(items) => items.iter().map(count_nodes).sum(),
+        Value::Object(map) => map.values().map(count_nodes).sum(),
+        _ => 0,
+    }
+}
+
+#[cfg(test)]
+#[path = "size_breakdown_tests.rs"]
+mod size_breakdown_tests;
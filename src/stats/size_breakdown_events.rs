@@ -0,0 +1,35 @@
+use ratatui::crossterm::event::{KeyCode, KeyEvent};
+
+use super::size_breakdown::SizeBreakdown;
+use crate::app::App;
+use crate::scroll::handle_scroll_key;
+
+/// Measure the parts of the last successful result and show the popup
+pub fn open_size_breakdown(app: &mut App) {
+    let Some(results) = app
+        .query
+        .as_ref()
+        .and_then(|q| q.last_successful_result_text())
+    else {
+        app.notification.show_warning("No result to measure yet");
+        return;
+    };
+
+    match SizeBreakdown::analyze(&results) {
+        Ok(breakdown) => app.size_breakdown.open(breakdown),
+        Err(e) => app.notification.show_warning(&e),
+    }
+}
+
+pub fn handle_size_breakdown_key(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('S') => app.size_breakdown.close(),
+        _ => {
+            handle_scroll_key(&mut app.size_breakdown.scroll, key);
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "size_breakdown_events_tests.rs"]
+mod size_breakdown_events_tests;
@@ -0,0 +1,50 @@
+//! Tests for size_breakdown_events
+
+use super::*;
+use crate::app::Focus;
+use crate::test_utils::test_helpers::{app_with_result, assert_open_refused, key};
+
+#[test]
+fn test_open_measures_result() {
+    let mut app = app_with_result(r#"{"items": [1, 2], "name": "x"}"#, ".");
+    open_size_breakdown(&mut app);
+
+    assert!(app.size_breakdown.is_visible());
+    let breakdown = app.size_breakdown.breakdown().unwrap();
+    assert_eq!(breakdown.count, 2);
+    assert_eq!(breakdown.parts[0].label, ".items");
+}
+
+#[test]
+fn test_open_explains_unsuitable_result() {
+    let mut app = app_with_result(r#"{"name": "x"}"#, ".name");
+
+    assert_open_refused(
+        &mut app,
+        open_size_breakdown,
+        |app| app.size_breakdown.is_visible(),
+        "Size breakdown needs an object, an array or a stream",
+    );
+}
+
+#[test]
+fn test_s_in_results_pane_toggles_popup() {
+    let mut app = app_with_result(r#"[1, [2, 3], 4]"#, ".");
+    app.focus = Focus::ResultsPane;
+
+    app.handle_key_event(key(KeyCode::Char('S')));
+    assert!(app.size_breakdown.is_visible());
+
+    app.handle_key_event(key(KeyCode::Char('j')));
+    app.handle_key_event(key(KeyCode::Char('S')));
+    assert!(!app.size_breakdown.is_visible());
+}
+
+#[test]
+fn test_esc_closes_popup() {
+    let mut app = app_with_result(r#"{"a": 1}"#, ".");
+    open_size_breakdown(&mut app);
+
+    handle_size_breakdown_key(&mut app, key(KeyCode::Esc));
+    assert!(!app.size_breakdown.is_visible());
+}
@@ -0,0 +1,190 @@
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::Style,
+    text::{Line, Span, Text},
+    widgets::{Block, BorderType, Borders, Paragraph},
+};
+
+use super::size_breakdown::SizeBreakdown;
+use crate::app::App;
+use crate::input::input_info::format_size;
+use crate::theme;
+use crate::widgets::text_width::{display_width, truncate_to_width};
+use crate::widgets::{popup, scrollbar};
+
+/// Widest label column before labels are cut off
+const MAX_LABEL_WIDTH: usize = 28;
+
+/// Space taken by a size, e.g. ` 1023.9 KB`
+const SIZE_WIDTH: usize = 10;
+
+/// Space taken by a percentage, e.g. ` 66.7%`
+const PERCENT_WIDTH: usize = 7;
+
+/// Space taken by a node count, e.g. ` 12.3M nodes`
+const NODES_WIDTH: usize = 12;
+
+fn build_hints() -> Line<'static> {
+    theme::border_hints::build_hints(
+        &[("j/k", "Scroll"), ("g/G", "Top/Bottom"), ("Esc", "Close")],
+        theme::size_breakdown::BORDER,
+    )
+}
+
+/// Format a node count compactly: 950, 1.2k, 3.4M
+fn format_nodes(nodes: u64) -> String {
+    if nodes >= 1_000_000 {
+        format!("{:.1}M", nodes as f64 / 1_000_000.0)
+    } else if nodes >= 1_000 {
+        format!("{:.1}k", nodes as f64 / 1_000.0)
+    } else {
+        nodes.to_string()
+    }
+}
+
+/// Size, share of the whole and node count, right-aligned
+fn figures(bytes: u64, nodes: u64, total_bytes: u64) -> Vec<Span<'static>> {
+    let percent = bytes as f64 * 100.0 / total_bytes.max(1) as f64;
+    vec![
+        Span::styled(
+            format!("{:>width$}", format_size(bytes), width = SIZE_WIDTH),
+            theme::size_breakdown::SIZE,
+        ),
+        Span::styled(
+            format!("{:>width$.1}%", percent, width = PERCENT_WIDTH - 1),
+            Style::default().fg(theme::size_breakdown::NOTE),
+        ),
+        Span::styled(
+            format!(
+                "{:>width$} nodes",
+                format_nodes(nodes),
+                width = NODES_WIDTH - 6
+            ),
+            Style::default().fg(theme::size_breakdown::NOTE),
+        ),
+    ]
+}
+
+/// One line per part: label, bar scaled to the whole result, size, share
+/// and node count, then a line summing up the parts not listed
+fn part_lines(breakdown: &SizeBreakdown, width: usize) -> Vec<Line<'static>> {
+    let label_width = breakdown
+        .parts
+        .iter()
+        .map(|part| display_width(&part.label))
+        .max()
+        .unwrap_or(0)
+        .min(MAX_LABEL_WIDTH);
+    let bar_width = width
+        .saturating_sub(label_width + SIZE_WIDTH + PERCENT_WIDTH + NODES_WIDTH + 3)
+        .max(1);
+    let total = breakdown.total_bytes.max(1);
+
+    let mut lines: Vec<Line> = breakdown
+        .parts
+        .iter()
+        .map(|part| {
+            let label = truncate_to_width(&part.label, label_width);
+            let padding = label_width.saturating_sub(display_width(&label));
+            let bar = ((part.bytes * bar_width as u64 / total) as usize).clamp(1, bar_width);
+            let mut spans = vec![
+                Span::raw(" "),
+                Span::styled(label, theme::size_breakdown::LABEL),
+                Span::raw(" ".repeat(padding + 1)),
+                Span::styled(
+                    "█".repeat(bar),
+                    Style::default().fg(theme::size_breakdown::BAR),
+                ),
+                Span::raw(" ".repeat(bar_width - bar)),
+            ];
+            spans.extend(figures(part.bytes, part.nodes, breakdown.total_bytes));
+            Line::from(spans)
+        })
+        .collect();
+
+    let rest = breakdown.count - breakdown.parts.len();
+    if rest > 0 {
+        let text = format!(" … {} more {}", rest, breakdown.kind.plural());
+        let padding = (label_width + bar_width + 2).saturating_sub(display_width(&text));
+        let mut spans = vec![
+            Span::styled(text, Style::default().fg(theme::size_breakdown::NOTE)),
+            Span::raw(" ".repeat(padding)),
+        ];
+        spans.extend(figures(
+            breakdown.rest_bytes,
+            breakdown.rest_nodes,
+            breakdown.total_bytes,
+        ));
+        lines.push(Line::from(spans));
+    }
+    lines
+}
+
+/// Render the popup listing the sizes of the result's parts
+pub fn render_size_breakdown(app: &mut App, frame: &mut Frame) -> Option<Rect> {
+    let frame_area = frame.area();
+    if frame_area.width < 30 || frame_area.height < 8 {
+        return None;
+    }
+    let breakdown = app.size_breakdown.breakdown()?;
+
+    let popup_width = ((frame_area.width as f32 * 0.8) as u16)
+        .clamp(50, 100)
+        .min(frame_area.width.saturating_sub(4));
+    let popup_height = ((frame_area.height as f32 * 0.7) as u16)
+        .clamp(8, 30)
+        .min(frame_area.height.saturating_sub(2));
+    let popup_area = popup::centered_popup(frame_area, popup_width, popup_height);
+    popup::clear_area(frame, popup_area);
+
+    let title = format!(
+        " Size of {} {} · {} · {} nodes ",
+        breakdown.count,
+        breakdown.kind.plural(),
+        format_size(breakdown.total_bytes),
+        format_nodes(breakdown.total_nodes)
+    );
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(Line::from(Span::styled(
+            title,
+            theme::size_breakdown::TITLE,
+        )))
+        .title_bottom(build_hints().centered())
+        .border_style(Style::default().fg(theme::size_breakdown::BORDER))
+        .style(Style::default().bg(theme::size_breakdown::BACKGROUND));
+    let content_area = block.inner(popup_area);
+    frame.render_widget(block, popup_area);
+
+    let lines = part_lines(breakdown, content_area.width as usize);
+    let content_height = lines.len() as u32;
+    let scroll = &mut app.size_breakdown.scroll;
+    scroll.update_bounds(content_height, content_area.height);
+    frame.render_widget(
+        Paragraph::new(Text::from(lines)).scroll((scroll.offset, 0)),
+        content_area,
+    );
+
+    let scrollbar_area = Rect {
+        x: popup_area.x,
+        y: popup_area.y.saturating_add(1),
+        width: popup_area.width,
+        height: popup_area.height.saturating_sub(2),
+    };
+    scrollbar::render_vertical_scrollbar_styled(
+        frame,
+        scrollbar_area,
+        content_height as usize,
+        scroll.viewport_height as usize,
+        scroll.offset as usize,
+        theme::size_breakdown::BORDER,
+    );
+
+    Some(popup_area)
+}
+
+#[cfg(test)]
+#[path = "size_breakdown_render_tests.rs"]
+mod size_breakdown_render_tests;
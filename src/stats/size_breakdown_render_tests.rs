@@ -0,0 +1,63 @@
+//! Tests for stats/size_breakdown_render
+
+use super::*;
+use crate::stats::size_breakdown::MAX_PARTS;
+use crate::test_utils::test_helpers::test_app;
+use insta::assert_snapshot;
+use ratatui::Terminal;
+use ratatui::backend::TestBackend;
+
+fn render_to_string(app: &mut App, width: u16, height: u16) -> String {
+    let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+    terminal
+        .draw(|f| {
+            render_size_breakdown(app, f);
+        })
+        .unwrap();
+    terminal.backend().to_string()
+}
+
+#[test]
+fn snapshot_size_breakdown() {
+    let mut app = test_app(r#"{"a": 1}"#);
+    let events: Vec<String> = (0..40)
+        .map(|i| format!(r#"{{"id": {}, "message": "request handled"}}"#, i))
+        .collect();
+    let breakdown = SizeBreakdown::analyze(&format!(
+        r#"{{"events": [{}], "metadata": {{"region": "eu-west-1", "tags": ["a", "b"]}}, "version": 3}}"#,
+        events.join(",")
+    ))
+    .unwrap();
+    app.size_breakdown.open(breakdown);
+
+    assert_snapshot!(render_to_string(&mut app, 90, 14));
+}
+
+#[test]
+fn test_format_nodes() {
+    assert_eq!(format_nodes(950), "950");
+    assert_eq!(format_nodes(1_250), "1.2k");
+    assert_eq!(format_nodes(3_400_000), "3.4M");
+}
+
+#[test]
+fn test_parts_not_listed_are_summed_up() {
+    let items: Vec<String> = (0..MAX_PARTS + 3).map(|i| i.to_string()).collect();
+    let breakdown = SizeBreakdown::analyze(&format!("[{}]", items.join(","))).unwrap();
+    let lines = part_lines(&breakdown, 80);
+
+    assert_eq!(lines.len(), breakdown.parts.len() + 1);
+    let last = lines.last().unwrap().to_string();
+    assert!(last.contains("… 3 more elements"), "{}", last);
+}
+
+#[test]
+fn test_nothing_rendered_when_closed() {
+    let mut app = test_app(r#"{"a": 1}"#);
+    let mut terminal = Terminal::new(TestBackend::new(80, 20)).unwrap();
+    let mut area = Some(Rect::default());
+    terminal
+        .draw(|f| area = render_size_breakdown(&mut app, f))
+        .unwrap();
+    assert_eq!(area, None);
+}
@@ -0,0 +1,32 @@
+use super::size_breakdown::SizeBreakdown;
+use crate::scroll::ScrollState;
+
+/// Popup listing the sizes of the result's parts (`S` or `:sizes`)
+#[derive(Debug, Default)]
+pub struct SizeBreakdownState {
+    breakdown: Option<SizeBreakdown>,
+    pub scroll: ScrollState,
+}
+
+impl SizeBreakdownState {
+    pub fn is_visible(&self) -> bool {
+        self.breakdown.is_some()
+    }
+
+    pub fn breakdown(&self) -> Option<&SizeBreakdown> {
+        self.breakdown.as_ref()
+    }
+
+    pub fn open(&mut self, breakdown: SizeBreakdown) {
+        self.breakdown = Some(breakdown);
+        self.scroll = ScrollState::new();
+    }
+
+    pub fn close(&mut self) {
+        self.breakdown = None;
+    }
+}
+
+#[cfg(test)]
+#[path = "size_breakdown_state_tests.rs"]
+mod size_breakdown_state_tests;
@@ -0,0 +1,20 @@
+//! Tests for size_breakdown_state
+
+use super::*;
+
+#[test]
+fn test_open_and_close() {
+    let mut state = SizeBreakdownState::default();
+    assert!(!state.is_visible());
+
+    let breakdown = SizeBreakdown::analyze(r#"{"a": 1, "b": [2]}"#).unwrap();
+    state.scroll.offset = 4;
+    state.open(breakdown.clone());
+    assert!(state.is_visible());
+    assert_eq!(state.breakdown(), Some(&breakdown));
+    assert_eq!(state.scroll.offset, 0);
+
+    state.close();
+    assert!(!state.is_visible());
+    assert_eq!(state.breakdown(), None);
+}
@@ -0,0 +1,108 @@
+//! Tests for size_breakdown
+
+use super::*;
+
+#[test]
+fn test_object_keys_sorted_by_size() {
+    let breakdown =
+        SizeBreakdown::analyze(r#"{"meta": {"v": 1}, "items": [1, 2, 3, 4], "id": 7}"#).unwrap();
+
+    assert_eq!(breakdown.kind, PartKind::Key);
+    assert_eq!(breakdown.count, 3);
+    assert_eq!(
+        breakdown.parts,
+        vec![
+            PartSize {
+                label: ".items".to_string(),
+                bytes: 9,
+                nodes: 5,
+            },
+            PartSize {
+                label: ".meta".to_string(),
+                bytes: 7,
+                nodes: 2,
+            },
+            PartSize {
+                label: ".id".to_string(),
+                bytes: 1,
+                nodes: 1,
+            },
+        ]
+    );
+    assert_eq!(
+        breakdown.total_bytes,
+        r#"{"meta":{"v":1},"items":[1,2,3,4],"id":7}"#.len() as u64
+    );
+    assert_eq!(breakdown.total_nodes, 9);
+}
+
+#[test]
+fn test_array_elements_labelled_by_index() {
+    let breakdown = SizeBreakdown::analyze(r#"[{"a": 1}, {"a": "long value"}, 2]"#).unwrap();
+
+    assert_eq!(breakdown.kind, PartKind::Element);
+    let labels: Vec<&str> = breakdown
+        .parts
+        .iter()
+        .map(|part| part.label.as_str())
+        .collect();
+    assert_eq!(labels, vec!["[1]", "[0]", "[2]"]);
+}
+
+#[test]
+fn test_stream_outputs_labelled_by_position() {
+    let breakdown = SizeBreakdown::analyze("\"a\"\n\"abcd\"\n").unwrap();
+
+    assert_eq!(breakdown.kind, PartKind::Output);
+    assert_eq!(breakdown.parts[0].label, "#2");
+    assert_eq!(breakdown.total_bytes, 9);
+    assert_eq!(breakdown.total_nodes, 2);
+}
+
+#[test]
+fn test_keys_needing_quotes() {
+    let breakdown = SizeBreakdown::analyze(r#"{"app name": 1}"#).unwrap();
+    assert_eq!(breakdown.parts[0].label, r#".["app name"]"#);
+}
+
+#[test]
+fn test_size_is_compact_regardless_of_formatting() {
+    let pretty = SizeBreakdown::analyze("{\n  \"a\": [\n    1,\n    2\n  ]\n}").unwrap();
+    assert_eq!(pretty.parts[0].bytes, 5);
+    assert_eq!(pretty.total_bytes, 11);
+}
+
+#[test]
+fn test_parts_beyond_limit_are_summed() {
+    let items: Vec<String> = (0..MAX_PARTS + 10).map(|i| i.to_string()).collect();
+    let breakdown = SizeBreakdown::analyze(&format!("[{}]", items.join(","))).unwrap();
+
+    assert_eq!(breakdown.count, MAX_PARTS + 10);
+    assert_eq!(breakdown.parts.len(), MAX_PARTS);
+    assert_eq!(breakdown.rest_nodes, 10);
+    // The smallest elements are the one-digit numbers
+    assert_eq!(breakdown.rest_bytes, 10);
+}
+
+#[test]
+fn test_unsuitable_results() {
+    assert_eq!(
+        SizeBreakdown::analyze("42"),
+        Err("Size breakdown needs an object, an array or a stream".to_string())
+    );
+    assert_eq!(
+        SizeBreakdown::analyze("[]"),
+        Err("Nothing to measure: the result has no elements".to_string())
+    );
+    assert!(
+        SizeBreakdown::analyze("{oops")
+            .unwrap_err()
+            .starts_with("Result is not JSON")
+    );
+}
+
+#[test]
+fn test_count_nodes() {
+    let value: Value = serde_json::from_str(r#"{"a": [1, {"b": null}], "c": "x"}"#).unwrap();
+    assert_eq!(count_nodes(&value), 6);
+}
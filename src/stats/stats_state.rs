@@ -9,8 +9,8 @@ pub fn update_stats_from_app(app: &mut App) {
         None => return,
     };
 
-    if let Some(result) = &query_state.last_successful_result_unformatted {
-        app.stats.compute(result);
+    if let Some(result) = query_state.last_successful_result_text() {
+        app.stats.compute(&result);
     }
 }
 
@@ -1,86 +1,182 @@
 //! Syntax highlighting for jq query expressions.
 //!
-//! This module provides syntax highlighting for jq queries by tokenizing the input
-//! and applying color styles based on token types:
-//! - Keywords (if, then, else, etc.) → Yellow
-//! - Built-in functions (map, select, etc.) → Blue
-//! - Variables ($foo, $x, etc.) → Red
-//! - Object field names (in {name: value}) → Cyan
-//! - Numbers → Cyan
+//! Queries are split into tokens of a `TokenKind`, which the syntax theme
+//! maps to a style:
+//! - Keywords (if, then, else, etc.) → Pink
+//! - Built-in functions (map, select, etc.) → Cyan
+//! - Variables ($foo, $x, etc.) → Orange
+//! - Object keys (in {name: value}) → Cyan
+//! - Field accessors (.name) → Text color
+//! - Format strings (@base64, @csv, etc.) → Yellow
+//! - Numbers → Purple
 //! - Strings → Green
 //! - Operators (|, ==, +, etc.) → Magenta
+//! - Comments (# to end of line) → Muted gray
+//! - Brackets → Gold, orchid and azure by nesting depth; unclosed ones dimmed
+//!
+//! The `[syntax]` config section overrides the color of each kind.
 
 pub mod bracket_matcher;
+pub mod incremental;
+pub mod json;
 pub mod overlay;
+mod rainbow;
+mod syntax_theme;
 
-use ratatui::style::Style;
 use ratatui::text::Span;
 
-use crate::theme;
+#[allow(unused_imports)]
+pub use syntax_theme::{SyntaxTheme, current_theme, set_theme};
+
+/// Kind of a token of a jq query
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TokenKind {
+    Keyword,
+    Function,
+    Variable,
+    /// Key of a constructed object, `{name: ...}`
+    ObjectKey,
+    /// Field accessor, `.name`
+    Field,
+    /// Format string, `@base64`
+    Format,
+    String,
+    Number,
+    Operator,
+    Comment,
+    /// Whitespace, identity and names jq doesn't define
+    Plain,
+}
+
+impl TokenKind {
+    pub const ALL: [TokenKind; 11] = [
+        TokenKind::Keyword,
+        TokenKind::Function,
+        TokenKind::Variable,
+        TokenKind::ObjectKey,
+        TokenKind::Field,
+        TokenKind::Format,
+        TokenKind::String,
+        TokenKind::Number,
+        TokenKind::Operator,
+        TokenKind::Comment,
+        TokenKind::Plain,
+    ];
+}
+
+/// Piece of a jq query of one kind
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Token {
+    pub kind: TokenKind,
+    pub text: String,
+}
+
+impl Token {
+    fn new(kind: TokenKind, text: String) -> Self {
+        Self { kind, text }
+    }
+}
 
 pub struct JqHighlighter;
 
 impl JqHighlighter {
+    /// Spans of `text` styled by the syntax theme in use
     pub fn highlight(text: &str) -> Vec<Span<'static>> {
-        let mut spans = Vec::new();
+        Self::highlight_with(text, &current_theme())
+    }
+
+    /// Spans of `text` styled by `theme`
+    pub fn highlight_with(text: &str, theme: &SyntaxTheme) -> Vec<Span<'static>> {
+        Self::spans(&Self::tokenize(text), theme)
+    }
+
+    /// Spans of `tokens` styled by `theme`
+    pub fn spans(tokens: &[Token], theme: &SyntaxTheme) -> Vec<Span<'static>> {
+        let delimiters = rainbow::delimiter_styles(tokens, theme);
+        tokens
+            .iter()
+            .zip(delimiters)
+            .map(|(token, delimiter)| match (token.kind, delimiter) {
+                (_, Some(style)) => Span::styled(token.text.clone(), style),
+                (TokenKind::Plain, None) => Span::raw(token.text.clone()),
+                (kind, None) => Span::styled(token.text.clone(), theme.style(kind)),
+            })
+            .collect()
+    }
+
+    /// Split `text` into tokens, covering all of it
+    pub fn tokenize(text: &str) -> Vec<Token> {
         let chars: Vec<char> = text.chars().collect();
-        let mut i = 0;
-
-        while i < chars.len() {
-            if chars[i].is_whitespace() {
-                let (content, new_i) = parse_whitespace(&chars, i);
-                spans.push(Span::raw(content));
-                i = new_i;
-                continue;
-            }
-
-            if chars[i] == '"' {
-                let (content, new_i) = parse_string(&chars, i);
-                spans.push(Span::styled(
-                    content,
-                    Style::default().fg(theme::syntax::STRING),
-                ));
-                i = new_i;
-                continue;
-            }
-
-            if chars[i].is_ascii_digit()
-                || (chars[i] == '-' && i + 1 < chars.len() && chars[i + 1].is_ascii_digit())
-            {
-                let (content, new_i) = parse_number(&chars, i);
-                spans.push(Span::styled(
-                    content,
-                    Style::default().fg(theme::syntax::NUMBER),
-                ));
-                i = new_i;
-                continue;
-            }
-
-            if is_operator(chars[i]) {
-                let (content, new_i) = parse_operator(&chars, i);
-                spans.push(Span::styled(
-                    content,
-                    Style::default().fg(theme::syntax::OPERATOR),
-                ));
-                i = new_i;
-                continue;
-            }
-
-            if chars[i].is_alphabetic() || chars[i] == '_' || chars[i] == '.' || chars[i] == '$' {
-                let (word, new_i, starts_with_dot) = parse_identifier(&chars, i);
-                let is_object_field = !starts_with_dot && is_followed_by_colon(&chars, new_i);
-                let style = classify_word(&word, is_object_field);
-                spans.push(Span::styled(word, style));
-                i = new_i;
-                continue;
-            }
-
-            spans.push(Span::raw(chars[i].to_string()));
-            i += 1;
+        tokenize_from(&chars, 0)
+    }
+}
+
+/// Tokens of `chars` from index `start`, which must be where a token starts
+///
+/// No state carries over from one token to the next, so lexing can resume
+/// at any token boundary.
+fn tokenize_from(chars: &[char], start: usize) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut i = start;
+    while i < chars.len() {
+        if chars[i].is_whitespace() {
+            let (content, new_i) = parse_whitespace(chars, i);
+            tokens.push(Token::new(TokenKind::Plain, content));
+            i = new_i;
+            continue;
+        }
+
+        if chars[i] == '#' {
+            let (content, new_i) = parse_comment(chars, i);
+            tokens.push(Token::new(TokenKind::Comment, content));
+            i = new_i;
+            continue;
+        }
+
+        if chars[i] == '"' {
+            let (content, new_i) = parse_string(chars, i);
+            tokens.push(Token::new(TokenKind::String, content));
+            i = new_i;
+            continue;
+        }
+
+        if chars[i].is_ascii_digit()
+            || (chars[i] == '-' && i + 1 < chars.len() && chars[i + 1].is_ascii_digit())
+        {
+            let (content, new_i) = parse_number(chars, i);
+            tokens.push(Token::new(TokenKind::Number, content));
+            i = new_i;
+            continue;
+        }
+
+        if chars[i] == '@' && chars.get(i + 1).is_some_and(|c| c.is_alphabetic()) {
+            let (name, new_i, _) = parse_identifier(chars, i + 1);
+            tokens.push(Token::new(TokenKind::Format, format!("@{}", name)));
+            i = new_i;
+            continue;
+        }
+
+        if is_operator(chars[i]) {
+            let (content, new_i) = parse_operator(chars, i);
+            tokens.push(Token::new(TokenKind::Operator, content));
+            i = new_i;
+            continue;
         }
 
-        spans
+        if chars[i].is_alphabetic() || chars[i] == '_' || chars[i] == '.' || chars[i] == '$' {
+            let (word, new_i, starts_with_dot) = parse_identifier(chars, i);
+            let is_object_field = !starts_with_dot && is_followed_by_colon(chars, new_i);
+            let kind = classify_word(&word, is_object_field);
+            tokens.push(Token::new(kind, word));
+            i = new_i;
+            continue;
+        }
+
+        tokens.push(Token::new(TokenKind::Plain, chars[i].to_string()));
+        i += 1;
     }
+
+    tokens
 }
 
 /// Parses consecutive whitespace characters starting at position `i`.
@@ -126,6 +222,22 @@ fn parse_string(chars: &[char], start: usize) -> (String, usize) {
     (chars[start..i].iter().collect(), i)
 }
 
+/// Parses a comment from its `#` to the end of the line.
+///
+/// # Parameters
+/// - `chars`: Character array of the query text
+/// - `start`: Index of the `#` character
+///
+/// # Returns
+/// Tuple of (comment_text, end_index)
+fn parse_comment(chars: &[char], start: usize) -> (String, usize) {
+    let mut i = start;
+    while i < chars.len() && chars[i] != '\n' {
+        i += 1;
+    }
+    (chars[start..i].iter().collect(), i)
+}
+
 /// Parses a number (including negative and decimal).
 ///
 /// # Parameters
@@ -217,32 +329,35 @@ fn is_followed_by_colon(chars: &[char], pos: usize) -> bool {
     j < chars.len() && chars[j] == ':'
 }
 
-/// Determines the style for a word based on its classification.
+/// Determines the kind of a word.
 ///
 /// Classification order (important - checked in sequence):
-/// 1. Keywords (if, then, else, etc.) → Yellow
-/// 2. Built-in functions (map, select, etc.) → Blue
-/// 3. Variables (starts with $) → Red
-/// 4. Object field names (followed by :) → Cyan
-/// 5. Default (field accessors like .name) → No color
+/// 1. Keywords (if, then, else, etc.)
+/// 2. Built-in functions (map, select, etc.)
+/// 3. Variables (starts with $)
+/// 4. Object keys (followed by :)
+/// 5. Field accessors (starts with . and names a field)
+/// 6. Plain (identity, `..` and other names)
 ///
 /// # Parameters
 /// - `word`: The identifier text
 /// - `is_object_field`: Whether this identifier is followed by a colon
 ///
 /// # Returns
-/// Style with appropriate color applied
-fn classify_word(word: &str, is_object_field: bool) -> Style {
+/// Kind of the token
+fn classify_word(word: &str, is_object_field: bool) -> TokenKind {
     if is_keyword(word) {
-        Style::default().fg(theme::syntax::KEYWORD)
+        TokenKind::Keyword
     } else if is_builtin_function(word) {
-        Style::default().fg(theme::syntax::FUNCTION)
+        TokenKind::Function
     } else if is_variable(word) {
-        Style::default().fg(theme::syntax::VARIABLE)
+        TokenKind::Variable
     } else if is_object_field {
-        Style::default().fg(theme::syntax::FIELD)
+        TokenKind::ObjectKey
+    } else if word.starts_with('.') && word.chars().any(|c| c != '.') {
+        TokenKind::Field
     } else {
-        Style::default()
+        TokenKind::Plain
     }
 }
 
@@ -0,0 +1,87 @@
+//! Incremental lexing of a query being edited
+//!
+//! Typing changes the end of a query far more often than its start. The
+//! lexer keeps the tokens of the last text it was given and lexes a new text
+//! again only from shortly before its first changed character, so redrawing
+//! an unchanged query costs a comparison and appending to a long one costs
+//! only its last few tokens.
+
+use super::{Token, TokenKind, tokenize_from};
+
+/// Lexer reusing the tokens of the unchanged start of the text
+#[derive(Debug, Default)]
+pub struct IncrementalLexer {
+    chars: Vec<char>,
+    tokens: Vec<Token>,
+    /// Character index each token starts at
+    starts: Vec<usize>,
+    /// Character index the last call lexed from, `None` when nothing changed
+    resumed_at: Option<usize>,
+}
+
+impl IncrementalLexer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Tokens of `text`, covering all of it
+    pub fn tokens(&mut self, text: &str) -> &[Token] {
+        let chars: Vec<char> = text.chars().collect();
+        if chars == self.chars {
+            self.resumed_at = None;
+            return &self.tokens;
+        }
+
+        let changed = self
+            .chars
+            .iter()
+            .zip(&chars)
+            .take_while(|(old, new)| old == new)
+            .count();
+        let keep = self.reusable_tokens(changed);
+        let resume = self.starts.get(keep).copied().unwrap_or(0);
+        self.tokens.truncate(keep);
+        self.starts.truncate(keep);
+
+        let mut start = resume;
+        for token in tokenize_from(&chars, resume) {
+            self.starts.push(start);
+            start += token.text.chars().count();
+            self.tokens.push(token);
+        }
+        self.chars = chars;
+        self.resumed_at = Some(resume);
+        &self.tokens
+    }
+
+    /// Number of leading tokens still valid when the text changes from
+    /// character index `changed` on
+    ///
+    /// Tokens ending before the change are kept, except for the last one
+    /// that isn't whitespace: it may continue into the new text (`.na` to
+    /// `.name`) or change kind with what follows it (`name` before a `:`).
+    fn reusable_tokens(&self, changed: usize) -> usize {
+        let mut keep = (0..self.tokens.len())
+            .take_while(|&i| self.token_end(i) <= changed)
+            .count();
+        while keep > 0 && is_whitespace(&self.tokens[keep - 1]) {
+            keep -= 1;
+        }
+        keep.saturating_sub(1)
+    }
+
+    fn token_end(&self, index: usize) -> usize {
+        self.starts
+            .get(index + 1)
+            .copied()
+            .unwrap_or(self.chars.len())
+    }
+}
+
+fn is_whitespace(token: &Token) -> bool {
+    token.kind == TokenKind::Plain && token.text.chars().all(char::is_whitespace)
+}
+
+#[cfg(test)]
+#[path = "incremental_tests.rs"]
+mod incremental_tests;
@@ -0,0 +1,90 @@
+//! Tests for syntax_highlight/incremental
+
+use super::*;
+use crate::syntax_highlight::JqHighlighter;
+use proptest::prelude::*;
+
+#[test]
+fn test_first_text_is_lexed_in_full() {
+    let mut lexer = IncrementalLexer::new();
+
+    let tokens = lexer.tokens(".a | .b").to_vec();
+
+    assert_eq!(tokens, JqHighlighter::tokenize(".a | .b"));
+    assert_eq!(lexer.resumed_at, Some(0));
+}
+
+#[test]
+fn test_unchanged_text_is_not_lexed() {
+    let mut lexer = IncrementalLexer::new();
+    lexer.tokens(".items[] | .name");
+
+    lexer.tokens(".items[] | .name");
+
+    assert_eq!(lexer.resumed_at, None);
+}
+
+#[test]
+fn test_appending_relexes_last_token_only() {
+    let mut lexer = IncrementalLexer::new();
+    lexer.tokens(".items[] | select(.active) | .na");
+
+    let tokens = lexer.tokens(".items[] | select(.active) | .name").to_vec();
+
+    assert_eq!(lexer.resumed_at, Some(29));
+    assert_eq!(
+        tokens,
+        JqHighlighter::tokenize(".items[] | select(.active) | .name")
+    );
+}
+
+#[test]
+fn test_key_becomes_object_key_when_colon_follows() {
+    let mut lexer = IncrementalLexer::new();
+    lexer.tokens("{name ");
+
+    let tokens = lexer.tokens("{name :").to_vec();
+
+    assert_eq!(tokens[1].kind, TokenKind::ObjectKey);
+}
+
+#[test]
+fn test_edit_inside_string_relexes_from_string() {
+    let mut lexer = IncrementalLexer::new();
+    lexer.tokens(".a | \"x\" | .b");
+
+    let tokens = lexer.tokens(".a | \"x | .b").to_vec();
+
+    assert_eq!(tokens, JqHighlighter::tokenize(".a | \"x | .b"));
+}
+
+#[test]
+fn test_shrinking_text() {
+    let mut lexer = IncrementalLexer::new();
+    lexer.tokens(".a | length");
+
+    let tokens = lexer.tokens(".a | len").to_vec();
+
+    assert_eq!(tokens, JqHighlighter::tokenize(".a | len"));
+    assert!(lexer.tokens("").is_empty());
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(200))]
+
+    #[test]
+    fn prop_incremental_tokens_match_full_lexing(
+        first in r#"[.a-z$@#"\\|:(){}\[\] =<>\-0-9]{0,30}"#,
+        second in r#"[.a-z$@#"\\|:(){}\[\] =<>\-0-9]{0,30}"#,
+        keep in 0usize..30,
+    ) {
+        let keep = first.chars().count().min(keep);
+        let edited: String = first.chars().take(keep).chain(second.chars()).collect();
+        let mut lexer = IncrementalLexer::new();
+        lexer.tokens(&first);
+
+        let tokens = lexer.tokens(&edited).to_vec();
+
+        prop_assert_eq!(tokens, JqHighlighter::tokenize(&edited));
+    }
+}
@@ -0,0 +1,137 @@
+//! Syntax highlighting for JSON results.
+//!
+//! jq runs without `--color-output`, so results are kept as plain text and
+//! colored here when they are drawn. JSON tokens printed by jq never span
+//! lines, so each line can be highlighted on its own and only the lines on
+//! screen need to be.
+//!
+//! Minified output can put megabytes on a single line. Lines are only
+//! highlighted up to the last column that can be seen, so the cost of a
+//! frame doesn't depend on how long the lines are.
+
+use ratatui::style::Style;
+use ratatui::text::{Line, Span, Text};
+
+use crate::theme;
+
+/// Columns kept per line by [`JsonHighlighter::highlight`]
+///
+/// The views using it don't scroll horizontally, so anything past the width
+/// of a terminal is never shown.
+pub const MAX_UNSCROLLED_WIDTH: usize = 1_000;
+
+pub struct JsonHighlighter;
+
+impl JsonHighlighter {
+    /// Highlight every line of `text`, cut at [`MAX_UNSCROLLED_WIDTH`] columns
+    pub fn highlight(text: &str) -> Text<'static> {
+        Text::from(
+            text.lines()
+                .map(|line| Self::highlight_line(line, MAX_UNSCROLLED_WIDTH))
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    /// Highlight the first `max_width` characters of one line of jq output
+    ///
+    /// The rest of the line is dropped. A token cut off at `max_width` keeps
+    /// the style of the whole token. Anything that isn't a JSON token, such
+    /// as indentation, is left unstyled.
+    pub fn highlight_line(line: &str, max_width: usize) -> Line<'static> {
+        let bytes = line.as_bytes();
+        let limit = line
+            .char_indices()
+            .nth(max_width)
+            .map_or(bytes.len(), |(index, _)| index);
+        let mut spans = Vec::new();
+        let mut plain_start = 0;
+        let mut i = 0;
+
+        while i < limit {
+            let (end, style) = match bytes[i] {
+                b'"' => {
+                    let end = string_end(bytes, i);
+                    if is_followed_by_colon(bytes, end) {
+                        (end, theme::json::KEY)
+                    } else {
+                        (end, theme::json::STRING)
+                    }
+                }
+                b'{' | b'}' | b'[' | b']' | b',' | b':' => (i + 1, theme::json::PUNCTUATION),
+                b'-' | b'0'..=b'9' => (number_end(bytes, i), theme::json::NUMBER),
+                _ => match literal_at(bytes, i) {
+                    Some((len, style)) => (i + len, style),
+                    None => {
+                        i += 1;
+                        continue;
+                    }
+                },
+            };
+
+            // Tokens start and end on ASCII bytes and `limit` is a char
+            // boundary, so these slices are too
+            let end = end.min(limit);
+            if plain_start < i {
+                spans.push(Span::raw(line[plain_start..i].to_string()));
+            }
+            spans.push(Span::styled(line[i..end].to_string(), style));
+            i = end;
+            plain_start = end;
+        }
+
+        if plain_start < limit {
+            spans.push(Span::raw(line[plain_start..limit].to_string()));
+        }
+        Line::from(spans)
+    }
+}
+
+/// Index just past the closing quote of the string starting at `start`
+///
+/// Runs to the end of the line if the string isn't closed. Scans with memchr
+/// as a string cut off at the edge of the view may be megabytes long.
+pub(crate) fn string_end(bytes: &[u8], start: usize) -> usize {
+    let mut i = start + 1;
+    while let Some(offset) = memchr::memchr2(b'"', b'\\', &bytes[i.min(bytes.len())..]) {
+        let pos = i + offset;
+        if bytes[pos] == b'"' {
+            return pos + 1;
+        }
+        // Skip the escaped character
+        i = pos + 2;
+    }
+    bytes.len()
+}
+
+fn number_end(bytes: &[u8], start: usize) -> usize {
+    let mut i = start + 1;
+    while i < bytes.len() && matches!(bytes[i], b'0'..=b'9' | b'.' | b'e' | b'E' | b'+' | b'-') {
+        i += 1;
+    }
+    i
+}
+
+/// Whether the next non-whitespace byte at or after `i` is a colon
+pub(crate) fn is_followed_by_colon(bytes: &[u8], i: usize) -> bool {
+    bytes[i.min(bytes.len())..]
+        .iter()
+        .find(|b| !b.is_ascii_whitespace())
+        == Some(&b':')
+}
+
+/// Length and style of a `null`, `true` or `false` literal at `i`
+fn literal_at(bytes: &[u8], i: usize) -> Option<(usize, Style)> {
+    let rest = &bytes[i..];
+    [
+        ("null", theme::json::NULL),
+        ("true", theme::json::TRUE),
+        ("false", theme::json::FALSE),
+    ]
+    .into_iter()
+    .find(|(literal, _)| rest.starts_with(literal.as_bytes()))
+    .map(|(literal, style)| (literal.len(), style))
+}
+
+#[cfg(test)]
+#[path = "json_tests.rs"]
+mod json_tests;
@@ -0,0 +1,141 @@
+//! Tests for syntax_highlight/json
+
+use super::*;
+
+fn styled(line: &Line<'_>) -> Vec<(String, Style)> {
+    line.spans
+        .iter()
+        .map(|span| (span.content.to_string(), span.style))
+        .collect()
+}
+
+#[test]
+fn test_key_and_string_value() {
+    let line = JsonHighlighter::highlight_line(r#"  "name": "jiq","#, usize::MAX);
+    assert_eq!(
+        styled(&line),
+        vec![
+            ("  ".to_string(), Style::default()),
+            (r#""name""#.to_string(), theme::json::KEY),
+            (":".to_string(), theme::json::PUNCTUATION),
+            (" ".to_string(), Style::default()),
+            (r#""jiq""#.to_string(), theme::json::STRING),
+            (",".to_string(), theme::json::PUNCTUATION),
+        ]
+    );
+}
+
+#[test]
+fn test_scalars() {
+    let line = JsonHighlighter::highlight_line("[null,true,false,-1.5e+3]", usize::MAX);
+    let styles: Vec<Style> = styled(&line).into_iter().map(|(_, style)| style).collect();
+    assert_eq!(
+        styles,
+        vec![
+            theme::json::PUNCTUATION,
+            theme::json::NULL,
+            theme::json::PUNCTUATION,
+            theme::json::TRUE,
+            theme::json::PUNCTUATION,
+            theme::json::FALSE,
+            theme::json::PUNCTUATION,
+            theme::json::NUMBER,
+            theme::json::PUNCTUATION,
+        ]
+    );
+}
+
+#[test]
+fn test_escaped_quote_stays_in_string() {
+    let line = JsonHighlighter::highlight_line(r#""say \"hi\": ok""#, usize::MAX);
+    assert_eq!(
+        styled(&line),
+        vec![(r#""say \"hi\": ok""#.to_string(), theme::json::STRING)]
+    );
+}
+
+#[test]
+fn test_compact_object_keys() {
+    let line = JsonHighlighter::highlight_line(r#"{"a":1,"b":"c"}"#, usize::MAX);
+    let keys: Vec<String> = styled(&line)
+        .into_iter()
+        .filter(|(_, style)| *style == theme::json::KEY)
+        .map(|(content, _)| content)
+        .collect();
+    assert_eq!(keys, vec![r#""a""#, r#""b""#]);
+}
+
+#[test]
+fn test_unicode_is_preserved() {
+    let line = JsonHighlighter::highlight_line(r#"  "ключ": "значение ✓""#, usize::MAX);
+    let text: String = line
+        .spans
+        .iter()
+        .map(|span| span.content.as_ref())
+        .collect();
+    assert_eq!(text, r#"  "ключ": "значение ✓""#);
+}
+
+#[test]
+fn test_unterminated_string_runs_to_end_of_line() {
+    let line = JsonHighlighter::highlight_line(r#""abc"#, usize::MAX);
+    assert_eq!(
+        styled(&line),
+        vec![(r#""abc"#.to_string(), theme::json::STRING)]
+    );
+}
+
+#[test]
+fn test_highlight_splits_lines() {
+    let text = JsonHighlighter::highlight("{\n  \"a\": 1\n}\n");
+    assert_eq!(text.lines.len(), 3);
+}
+
+#[test]
+fn test_highlight_cuts_long_lines() {
+    let long_line = format!("[{}]", "1,".repeat(5_000));
+    let text = JsonHighlighter::highlight(&long_line);
+    assert_eq!(text.lines[0].width(), MAX_UNSCROLLED_WIDTH);
+}
+
+#[test]
+fn test_line_is_cut_at_max_width() {
+    let line = JsonHighlighter::highlight_line("[12345, true]", 4);
+    assert_eq!(
+        styled(&line),
+        vec![
+            ("[".to_string(), theme::json::PUNCTUATION),
+            ("123".to_string(), theme::json::NUMBER),
+        ]
+    );
+}
+
+#[test]
+fn test_key_cut_at_max_width_keeps_key_style() {
+    let line = JsonHighlighter::highlight_line(r#"  "a_very_long_key": 1"#, 6);
+    assert_eq!(
+        styled(&line),
+        vec![
+            ("  ".to_string(), Style::default()),
+            (r#""a_v"#.to_string(), theme::json::KEY),
+        ]
+    );
+}
+
+#[test]
+fn test_max_width_counts_characters() {
+    let line = JsonHighlighter::highlight_line(r#""é✓x""#, 3);
+    assert_eq!(
+        styled(&line),
+        vec![(r#""é✓"#.to_string(), theme::json::STRING)]
+    );
+}
+
+#[test]
+fn test_very_long_string_is_cut() {
+    let value = "x".repeat(1_000_000);
+    let line = format!(r#"{{"key": "{}", "n": 1}}"#, value);
+    let highlighted = JsonHighlighter::highlight_line(&line, 80);
+    assert_eq!(highlighted.width(), 80);
+    assert_eq!(highlighted.spans.last().unwrap().style, theme::json::STRING);
+}
@@ -4,8 +4,12 @@
 //! - Extracting the visible portion of styled spans when horizontally scrolled
 //! - Inserting a cursor indicator into styled spans
 //! - Highlighting matching bracket pairs with underline
+//! - Highlighting the query region jq reported an error for
+//! - Appending ghost text (a completion preview) after the query
 
-use ratatui::style::Modifier;
+use std::ops::Range;
+
+use ratatui::style::{Color, Modifier, Style};
 use ratatui::text::Span;
 
 use crate::theme;
@@ -156,10 +160,78 @@ pub fn highlight_bracket_pairs(
     apply_enhanced_modifiers_at_positions(
         spans,
         &[open_pos, close_pos],
+        theme::syntax::bracket_match::COLOR,
         Modifier::BOLD | Modifier::UNDERLINED,
     )
 }
 
+/// Highlights the region of the query that jq reported an error for.
+///
+/// Applies the error color with an underline to every character in `range`,
+/// preserving other style attributes.
+///
+/// # Parameters
+/// - `spans`: Styled text spans to process
+/// - `range`: Character range of the offending region
+///
+/// # Returns
+/// Vector of spans with the error region highlighted.
+pub fn highlight_error_span(spans: Vec<Span<'static>>, range: Range<usize>) -> Vec<Span<'static>> {
+    let positions: Vec<usize> = range.collect();
+    apply_enhanced_modifiers_at_positions(
+        spans,
+        &positions,
+        theme::syntax::error_span::COLOR,
+        Modifier::UNDERLINED,
+    )
+}
+
+/// Highlights the selected region of the query.
+///
+/// Used for the placeholder left selected by an inserted tooltip example, so
+/// it is clear that typing will replace it.
+///
+/// # Parameters
+/// - `spans`: Styled text spans to process
+/// - `range`: Character range of the selection
+///
+/// # Returns
+/// Vector of spans with the selection shown in reverse video.
+pub fn highlight_selection(spans: Vec<Span<'static>>, range: Range<usize>) -> Vec<Span<'static>> {
+    let positions: Vec<usize> = range.collect();
+    apply_enhanced_modifiers_at_positions(
+        spans,
+        &positions,
+        theme::input::SELECTION,
+        Modifier::REVERSED,
+    )
+}
+
+/// Appends ghost text after the query text.
+///
+/// The ghost text is dimmed and italic so it reads as a preview rather than
+/// part of the query. Apply it before `extract_visible_spans` so it scrolls
+/// with the query, and before `insert_cursor_into_spans` so a cursor at the
+/// end of the query sits on its first character.
+///
+/// # Parameters
+/// - `spans`: Styled query spans
+/// - `ghost`: Text accepting the completion would add
+///
+/// # Returns
+/// Vector of spans ending with the ghost text.
+pub fn append_ghost_text(mut spans: Vec<Span<'static>>, ghost: &str) -> Vec<Span<'static>> {
+    if !ghost.is_empty() {
+        spans.push(Span::styled(
+            ghost.to_string(),
+            Style::default()
+                .fg(theme::input::GHOST_TEXT)
+                .add_modifier(Modifier::ITALIC),
+        ));
+    }
+    spans
+}
+
 /// Applies modifiers to characters at specific positions while preserving existing style.
 ///
 /// This helper function splits spans as needed and adds the given modifiers
@@ -168,6 +240,7 @@ pub fn highlight_bracket_pairs(
 /// # Parameters
 /// - `spans`: Styled text spans to process
 /// - `positions`: Character positions where modifiers should be added
+/// - `color`: Foreground color applied at those positions
 /// - `modifiers`: The modifiers to add (e.g., Modifier::BOLD | Modifier::UNDERLINED)
 ///
 /// # Returns
@@ -175,6 +248,7 @@ pub fn highlight_bracket_pairs(
 fn apply_enhanced_modifiers_at_positions(
     spans: Vec<Span<'static>>,
     positions: &[usize],
+    color: Color,
     modifiers: Modifier,
 ) -> Vec<Span<'static>> {
     if positions.is_empty() {
@@ -217,10 +291,7 @@ fn apply_enhanced_modifiers_at_positions(
             }
 
             let char_at_pos = span_chars[pos_in_span].to_string();
-            let enhanced_style = span
-                .style
-                .fg(theme::syntax::bracket_match::COLOR)
-                .add_modifier(modifiers);
+            let enhanced_style = span.style.fg(color).add_modifier(modifiers);
             result.push(Span::styled(char_at_pos, enhanced_style));
 
             last_end = pos_in_span + 1;
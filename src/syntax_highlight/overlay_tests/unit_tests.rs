@@ -383,3 +383,105 @@ fn test_apply_modifier_at_positions_consecutive_positions() {
     assert!(result[2].style.add_modifier.contains(Modifier::UNDERLINED));
     assert_eq!(result[3].content, "d");
 }
+
+#[test]
+fn test_highlight_error_span_underlines_range() {
+    let spans = vec![Span::styled(".a | foo", Style::default().fg(Color::White))];
+
+    let result = highlight_error_span(spans, 5..8);
+
+    let underlined: String = result
+        .iter()
+        .filter(|s| s.style.add_modifier.contains(Modifier::UNDERLINED))
+        .map(|s| s.content.as_ref())
+        .collect();
+    assert_eq!(underlined, "foo");
+    assert!(
+        result
+            .iter()
+            .filter(|s| s.style.add_modifier.contains(Modifier::UNDERLINED))
+            .all(|s| s.style.fg == Some(theme::syntax::error_span::COLOR))
+    );
+}
+
+#[test]
+fn test_highlight_error_span_across_spans() {
+    let spans = vec![
+        Span::styled(".a", Style::default().fg(Color::Cyan)),
+        Span::raw(" "),
+        Span::styled("[", Style::default().fg(Color::Magenta)),
+    ];
+
+    let result = highlight_error_span(spans, 1..4);
+
+    let underlined: String = result
+        .iter()
+        .filter(|s| s.style.add_modifier.contains(Modifier::UNDERLINED))
+        .map(|s| s.content.as_ref())
+        .collect();
+    assert_eq!(underlined, "a [");
+}
+
+#[test]
+fn test_highlight_error_span_out_of_range_is_noop() {
+    let spans = vec![Span::styled(".a", Style::default())];
+
+    let result = highlight_error_span(spans.clone(), 10..12);
+
+    assert_eq!(result, spans);
+}
+
+#[test]
+fn test_highlight_selection_reverses_range() {
+    let spans = vec![Span::styled(
+        "map(.name)",
+        Style::default().fg(Color::White),
+    )];
+
+    let result = highlight_selection(spans, 4..9);
+
+    let selected: String = result
+        .iter()
+        .filter(|s| s.style.add_modifier.contains(Modifier::REVERSED))
+        .map(|s| s.content.as_ref())
+        .collect();
+    assert_eq!(selected, ".name");
+    assert!(
+        result
+            .iter()
+            .filter(|s| s.style.add_modifier.contains(Modifier::REVERSED))
+            .all(|s| s.style.fg == Some(theme::input::SELECTION))
+    );
+}
+
+#[test]
+fn test_append_ghost_text_adds_dimmed_span() {
+    let spans = vec![Span::styled(".na", Style::default().fg(Color::White))];
+
+    let result = append_ghost_text(spans, "me");
+
+    assert_eq!(result.len(), 2);
+    assert_eq!(result[1].content, "me");
+    assert_eq!(result[1].style.fg, Some(theme::input::GHOST_TEXT));
+    assert!(result[1].style.add_modifier.contains(Modifier::ITALIC));
+}
+
+#[test]
+fn test_append_empty_ghost_text_is_noop() {
+    let spans = vec![Span::raw(".name")];
+    assert_eq!(append_ghost_text(spans.clone(), ""), spans);
+}
+
+#[test]
+fn test_cursor_at_end_sits_on_ghost_text() {
+    let spans = append_ghost_text(vec![Span::raw(".na")], "me");
+
+    let result = insert_cursor_into_spans(spans, 3);
+
+    let cursor: Vec<&Span> = result
+        .iter()
+        .filter(|s| s.style.add_modifier.contains(Modifier::REVERSED))
+        .collect();
+    assert_eq!(cursor.len(), 1);
+    assert_eq!(cursor[0].content, "m");
+}
@@ -0,0 +1,85 @@
+//! Rainbow brackets
+//!
+//! Each `()`, `[]` and `{}` pair is colored by how deeply it is nested, so
+//! the partner of a bracket stands out in a long pipeline. `if` and `end`
+//! pair up as well, keeping their keyword color. An opener never closed is
+//! dimmed and a closer without an opener stands out, showing where a missing
+//! `)` or `end` breaks the query.
+
+use ratatui::style::Style;
+
+use super::{SyntaxTheme, Token, TokenKind};
+use crate::theme;
+
+/// What opens or closes a nested part of a query
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Delimiter {
+    Paren,
+    Bracket,
+    Brace,
+    If,
+}
+
+impl Delimiter {
+    /// Delimiter `token` is, with whether it opens
+    fn of(token: &Token) -> Option<(Delimiter, bool)> {
+        match (token.kind, token.text.as_str()) {
+            (TokenKind::Operator, "(") => Some((Delimiter::Paren, true)),
+            (TokenKind::Operator, ")") => Some((Delimiter::Paren, false)),
+            (TokenKind::Operator, "[") => Some((Delimiter::Bracket, true)),
+            (TokenKind::Operator, "]") => Some((Delimiter::Bracket, false)),
+            (TokenKind::Operator, "{") => Some((Delimiter::Brace, true)),
+            (TokenKind::Operator, "}") => Some((Delimiter::Brace, false)),
+            (TokenKind::Keyword, "if") => Some((Delimiter::If, true)),
+            (TokenKind::Keyword, "end") => Some((Delimiter::If, false)),
+            _ => None,
+        }
+    }
+}
+
+/// Style replacing the token kind's style for each of `tokens`
+///
+/// A closer pairs with the innermost open delimiter of its kind; openers
+/// nested in between were never closed.
+pub fn delimiter_styles(tokens: &[Token], theme: &SyntaxTheme) -> Vec<Option<Style>> {
+    let mut styles = vec![None; tokens.len()];
+    // Open delimiters, innermost last, with the index of their token
+    let mut open: Vec<(Delimiter, usize)> = Vec::new();
+
+    for (index, token) in tokens.iter().enumerate() {
+        let Some((delimiter, opens)) = Delimiter::of(token) else {
+            continue;
+        };
+        if opens {
+            open.push((delimiter, index));
+            continue;
+        }
+        let Some(pos) = open.iter().rposition(|(open, _)| *open == delimiter) else {
+            styles[index] = Some(theme::syntax::rainbow::UNMATCHED_CLOSER);
+            continue;
+        };
+        for (_, unclosed) in open.drain(pos + 1..) {
+            styles[unclosed] = Some(theme::syntax::rainbow::UNMATCHED_OPENER);
+        }
+        let Some((_, opener)) = open.pop() else {
+            continue;
+        };
+        if delimiter != Delimiter::If {
+            let depth = open
+                .iter()
+                .filter(|(open, _)| *open != Delimiter::If)
+                .count();
+            let style = theme.bracket_style(depth);
+            styles[opener] = style;
+            styles[index] = style;
+        }
+    }
+    for (_, unclosed) in open {
+        styles[unclosed] = Some(theme::syntax::rainbow::UNMATCHED_OPENER);
+    }
+    styles
+}
+
+#[cfg(test)]
+#[path = "rainbow_tests.rs"]
+mod rainbow_tests;
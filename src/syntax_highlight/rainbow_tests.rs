@@ -0,0 +1,147 @@
+//! Tests for syntax_highlight/rainbow
+
+use super::*;
+use crate::syntax_highlight::JqHighlighter;
+use crate::theme::syntax::rainbow::{COLORS, UNMATCHED_CLOSER, UNMATCHED_OPENER};
+
+/// Style given to each delimiter of `query`, by its text
+fn styles_of(query: &str) -> Vec<(String, Option<Style>)> {
+    let tokens = JqHighlighter::tokenize(query);
+    let styles = delimiter_styles(&tokens, &SyntaxTheme::default());
+    tokens
+        .into_iter()
+        .zip(styles)
+        .filter(|(token, _)| Delimiter::of(token).is_some())
+        .map(|(token, style)| (token.text, style))
+        .collect()
+}
+
+fn depth(depth: usize) -> Option<Style> {
+    Some(Style::new().fg(COLORS[depth % COLORS.len()]))
+}
+
+fn pairs(styles: &[(&str, Option<Style>)]) -> Vec<(String, Option<Style>)> {
+    styles
+        .iter()
+        .map(|(text, style)| (text.to_string(), *style))
+        .collect()
+}
+
+#[test]
+fn test_nested_pairs_are_colored_by_depth() {
+    assert_eq!(
+        styles_of("map({a: [.b]})"),
+        pairs(&[
+            ("(", depth(0)),
+            ("{", depth(1)),
+            ("[", depth(2)),
+            ("]", depth(2)),
+            ("}", depth(1)),
+            (")", depth(0)),
+        ])
+    );
+}
+
+#[test]
+fn test_colors_repeat_past_last_depth() {
+    let styles = styles_of("((((1))))");
+    assert_eq!(styles[3].1, depth(COLORS.len()));
+    assert_eq!(styles[3].1, depth(0));
+}
+
+#[test]
+fn test_sibling_pairs_share_color() {
+    assert_eq!(
+        styles_of("[.a] + [.b]"),
+        pairs(&[
+            ("[", depth(0)),
+            ("]", depth(0)),
+            ("[", depth(0)),
+            ("]", depth(0)),
+        ])
+    );
+}
+
+#[test]
+fn test_unclosed_opener_is_dimmed() {
+    assert_eq!(
+        styles_of("map(select(.a) | .b"),
+        pairs(&[
+            ("(", Some(UNMATCHED_OPENER)),
+            ("(", depth(1)),
+            (")", depth(1)),
+        ])
+    );
+}
+
+#[test]
+fn test_stray_closer_stands_out() {
+    assert_eq!(
+        styles_of(".a) | (.b)"),
+        pairs(&[
+            (")", Some(UNMATCHED_CLOSER)),
+            ("(", depth(0)),
+            (")", depth(0)),
+        ])
+    );
+}
+
+#[test]
+fn test_closer_skips_unclosed_openers_inside() {
+    assert_eq!(
+        styles_of("[(.a]"),
+        pairs(&[
+            ("[", depth(0)),
+            ("(", Some(UNMATCHED_OPENER)),
+            ("]", depth(0)),
+        ])
+    );
+}
+
+#[test]
+fn test_if_without_end_is_dimmed() {
+    assert_eq!(
+        styles_of("map(if .a then 1 else 2)"),
+        pairs(&[
+            ("(", depth(0)),
+            ("if", Some(UNMATCHED_OPENER)),
+            (")", depth(0)),
+        ])
+    );
+}
+
+#[test]
+fn test_matched_if_keeps_keyword_color_and_depth() {
+    assert_eq!(
+        styles_of("if .a then [1] else 2 end"),
+        pairs(&[
+            ("if", None),
+            ("[", depth(0)),
+            ("]", depth(0)),
+            ("end", None)
+        ])
+    );
+}
+
+#[test]
+fn test_brackets_in_strings_and_comments_are_ignored() {
+    assert_eq!(
+        styles_of("\"(\" | .a # ["),
+        Vec::<(String, Option<Style>)>::new()
+    );
+}
+
+#[test]
+fn test_no_bracket_colors_leaves_pairs_unstyled() {
+    let theme = SyntaxTheme::from_config(&crate::config::SyntaxConfig {
+        brackets: Some(Vec::new()),
+        ..Default::default()
+    });
+    let tokens = JqHighlighter::tokenize("(.a) | (");
+
+    let styles = delimiter_styles(&tokens, &theme);
+
+    assert_eq!(styles[0], None);
+    assert_eq!(styles[2], None);
+    assert_eq!(styles[6], Some(UNMATCHED_OPENER));
+}
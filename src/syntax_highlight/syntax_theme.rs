@@ -0,0 +1,115 @@
+//! Styles of jq token kinds
+//!
+//! Each kind defaults to its style in `theme::syntax`, and the `[syntax]`
+//! config section replaces the color of any of them. The theme in use is
+//! shared by every place a query is shown (input field, history, snippets)
+//! and replaced when the config is reloaded. Brackets are colored by their
+//! depth, cycling through the bracket colors.
+
+use std::sync::RwLock;
+
+use ratatui::style::{Color, Style};
+
+use super::TokenKind;
+use crate::config::SyntaxConfig;
+use crate::theme;
+
+/// Style of each token kind
+#[derive(Debug, Clone, PartialEq)]
+pub struct SyntaxTheme {
+    styles: [Style; TokenKind::ALL.len()],
+    /// Styles of bracket pairs by depth, none to style them as operators
+    brackets: Vec<Style>,
+}
+
+impl Default for SyntaxTheme {
+    fn default() -> Self {
+        Self {
+            styles: TokenKind::ALL.map(default_style),
+            brackets: bracket_styles(&theme::syntax::rainbow::COLORS),
+        }
+    }
+}
+
+impl SyntaxTheme {
+    /// Default styles with the colors set in `config`
+    pub fn from_config(config: &SyntaxConfig) -> Self {
+        let mut theme = Self::default();
+        for kind in TokenKind::ALL {
+            let color = match kind {
+                TokenKind::Keyword => config.keyword,
+                TokenKind::Function => config.function,
+                TokenKind::Variable => config.variable,
+                TokenKind::ObjectKey => config.object_key,
+                TokenKind::Field => config.field,
+                TokenKind::Format => config.format,
+                TokenKind::String => config.string,
+                TokenKind::Number => config.number,
+                TokenKind::Operator => config.operator,
+                TokenKind::Comment => config.comment,
+                TokenKind::Plain => None,
+            };
+            if let Some(color) = color {
+                theme.styles[kind as usize] = theme.styles[kind as usize].fg(color.0);
+            }
+        }
+        if let Some(colors) = &config.brackets {
+            let colors: Vec<_> = colors.iter().map(|color| color.0).collect();
+            theme.brackets = bracket_styles(&colors);
+        }
+        theme
+    }
+
+    pub fn style(&self, kind: TokenKind) -> Style {
+        self.styles[kind as usize]
+    }
+
+    /// Style of a bracket pair inside `depth` others, `None` when brackets
+    /// are styled as operators
+    pub fn bracket_style(&self, depth: usize) -> Option<Style> {
+        (!self.brackets.is_empty()).then(|| self.brackets[depth % self.brackets.len()])
+    }
+}
+
+fn bracket_styles(colors: &[Color]) -> Vec<Style> {
+    colors.iter().map(|&color| Style::new().fg(color)).collect()
+}
+
+fn default_style(kind: TokenKind) -> Style {
+    let fg = |color| Style::new().fg(color);
+    match kind {
+        TokenKind::Keyword => fg(theme::syntax::KEYWORD),
+        TokenKind::Function => fg(theme::syntax::FUNCTION),
+        TokenKind::Variable => fg(theme::syntax::VARIABLE),
+        TokenKind::ObjectKey => fg(theme::syntax::FIELD),
+        TokenKind::Field => theme::syntax::ACCESSOR,
+        TokenKind::Format => fg(theme::syntax::FORMAT),
+        TokenKind::String => fg(theme::syntax::STRING),
+        TokenKind::Number => fg(theme::syntax::NUMBER),
+        TokenKind::Operator => fg(theme::syntax::OPERATOR),
+        TokenKind::Comment => theme::syntax::COMMENT,
+        TokenKind::Plain => Style::new(),
+    }
+}
+
+static CURRENT: RwLock<Option<SyntaxTheme>> = RwLock::new(None);
+
+/// Theme queries are highlighted with
+pub fn current_theme() -> SyntaxTheme {
+    CURRENT
+        .read()
+        .ok()
+        .and_then(|theme| theme.clone())
+        .unwrap_or_default()
+}
+
+/// Highlight queries with `theme` from now on
+pub fn set_theme(theme: SyntaxTheme) {
+    if let Ok(mut current) = CURRENT.write() {
+        *current = Some(theme);
+    }
+}
+
+#[cfg(test)]
+#[path = "syntax_theme_tests.rs"]
+mod syntax_theme_tests;
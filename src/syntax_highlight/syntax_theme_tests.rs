@@ -0,0 +1,39 @@
+//! Tests for syntax_highlight/syntax_theme
+
+use super::*;
+use crate::config::ThemeColor;
+use ratatui::style::{Color, Modifier};
+
+#[test]
+fn test_default_styles_come_from_theme() {
+    let theme = SyntaxTheme::default();
+    assert_eq!(
+        theme.style(TokenKind::Keyword),
+        Style::new().fg(theme::syntax::KEYWORD)
+    );
+    assert_eq!(theme.style(TokenKind::Comment), theme::syntax::COMMENT);
+    assert_eq!(theme.style(TokenKind::Plain), Style::new());
+}
+
+#[test]
+fn test_config_replaces_colors() {
+    let config = SyntaxConfig {
+        keyword: Some(ThemeColor(Color::Yellow)),
+        comment: Some(ThemeColor(Color::Rgb(1, 2, 3))),
+        ..SyntaxConfig::default()
+    };
+
+    let theme = SyntaxTheme::from_config(&config);
+
+    assert_eq!(
+        theme.style(TokenKind::Keyword),
+        Style::new().fg(Color::Yellow)
+    );
+    let comment = theme.style(TokenKind::Comment);
+    assert_eq!(comment.fg, Some(Color::Rgb(1, 2, 3)));
+    assert!(comment.add_modifier.contains(Modifier::ITALIC));
+    assert_eq!(
+        theme.style(TokenKind::String),
+        SyntaxTheme::default().style(TokenKind::String)
+    );
+}
@@ -16,8 +16,8 @@ fn test_highlight_simple_field() {
 
 #[test]
 fn test_highlight_keyword() {
-    let spans = JqHighlighter::highlight("if");
-    assert_eq!(spans.len(), 1);
+    let spans = JqHighlighter::highlight("if . then 1 end");
+    assert_eq!(spans[0].content, "if");
     assert_eq!(spans[0].style.fg, Some(theme::syntax::KEYWORD));
 }
 
@@ -35,6 +35,21 @@ fn test_highlight_number() {
     assert_eq!(spans[0].style.fg, Some(theme::syntax::NUMBER));
 }
 
+#[test]
+fn test_highlight_comment() {
+    let spans = JqHighlighter::highlight(".a # | .b \"x\"");
+    let comment = spans.last().unwrap();
+    assert_eq!(comment.content, "# | .b \"x\"");
+    assert_eq!(comment.style, theme::syntax::COMMENT);
+}
+
+#[test]
+fn test_highlight_hash_in_string_is_not_comment() {
+    let spans = JqHighlighter::highlight(r#""a#b""#);
+    assert_eq!(spans.len(), 1);
+    assert_eq!(spans[0].style.fg, Some(theme::syntax::STRING));
+}
+
 #[test]
 fn test_highlight_function() {
     let spans = JqHighlighter::highlight("map");
@@ -242,3 +257,59 @@ fn test_object_field_with_whitespace_before_colon() {
     assert!(field_span.is_some());
     assert_eq!(field_span.unwrap().style.fg, Some(theme::syntax::FIELD));
 }
+
+fn kinds(text: &str) -> Vec<(TokenKind, String)> {
+    JqHighlighter::tokenize(text)
+        .into_iter()
+        .filter(|token| !token.text.trim().is_empty())
+        .map(|token| (token.kind, token.text))
+        .collect()
+}
+
+#[test]
+fn test_tokenize_covers_whole_text() {
+    let query = r#".items[] | {name: .name, id: $id} | @base64 # done"#;
+    let text: String = JqHighlighter::tokenize(query)
+        .into_iter()
+        .map(|token| token.text)
+        .collect();
+    assert_eq!(text, query);
+}
+
+#[test]
+fn test_tokenize_kinds() {
+    assert_eq!(
+        kinds(r#"{name: .user.name} | @csv "x" # c"#),
+        vec![
+            (TokenKind::Operator, "{".to_string()),
+            (TokenKind::ObjectKey, "name".to_string()),
+            (TokenKind::Operator, ":".to_string()),
+            (TokenKind::Field, ".user.name".to_string()),
+            (TokenKind::Operator, "}".to_string()),
+            (TokenKind::Operator, "|".to_string()),
+            (TokenKind::Format, "@csv".to_string()),
+            (TokenKind::String, "\"x\"".to_string()),
+            (TokenKind::Comment, "# c".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn test_identity_and_recursion_are_plain() {
+    assert_eq!(kinds("."), vec![(TokenKind::Plain, ".".to_string())]);
+    assert_eq!(kinds(".."), vec![(TokenKind::Plain, "..".to_string())]);
+}
+
+#[test]
+fn test_highlight_with_custom_theme() {
+    let config = crate::config::SyntaxConfig {
+        format: Some(crate::config::ThemeColor(Color::Red)),
+        ..Default::default()
+    };
+    let theme = SyntaxTheme::from_config(&config);
+
+    let spans = JqHighlighter::highlight_with("@text", &theme);
+
+    assert_eq!(spans.len(), 1);
+    assert_eq!(spans[0].style.fg, Some(Color::Red));
+}
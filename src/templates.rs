@@ -0,0 +1,16 @@
+//! Query templates for well-known documents
+//!
+//! When the input is recognised by its fingerprint (a Kubernetes List, the
+//! output of an AWS `describe-*` command, a GitHub API listing or an npm
+//! `package-lock.json`), a menu of starter queries tailored to it is offered
+//! on load. `1`-`9` run one; `:templates` brings the menu back.
+
+mod templates_data;
+mod templates_detect;
+mod templates_events;
+mod templates_render;
+mod templates_state;
+
+pub use templates_events::{handle_templates_key, open_templates};
+pub use templates_render::render_templates;
+pub use templates_state::TemplatesState;
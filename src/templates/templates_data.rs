@@ -0,0 +1,39 @@
+use serde::Deserialize;
+
+use super::templates_detect::DocumentShape;
+
+/// Starter queries bundled with jiq, keyed by `DocumentShape::id`
+const TEMPLATES: &str = include_str!("templates.toml");
+
+/// A starter query for a recognised document
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Template {
+    pub name: String,
+    pub query: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct ShapeTemplates {
+    id: String,
+    templates: Vec<Template>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TemplateData {
+    shapes: Vec<ShapeTemplates>,
+}
+
+/// The bundled templates for `shape`
+pub fn templates_for(shape: DocumentShape) -> Vec<Template> {
+    let data: TemplateData =
+        toml::from_str(TEMPLATES).unwrap_or(TemplateData { shapes: Vec::new() });
+    data.shapes
+        .into_iter()
+        .find(|shapes| shapes.id == shape.id())
+        .map(|shapes| shapes.templates)
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+#[path = "templates_data_tests.rs"]
+mod templates_data_tests;
@@ -0,0 +1,77 @@
+//! Tests for templates_data
+
+use std::sync::Arc;
+
+use tokio_util::sync::CancellationToken;
+
+use super::*;
+use crate::query::executor::JqExecutor;
+use crate::query::library::JqLibrary;
+use crate::templates::templates_detect::detect;
+
+const SHAPES: [DocumentShape; 5] = [
+    DocumentShape::KubernetesList,
+    DocumentShape::AwsEc2Instances,
+    DocumentShape::AwsDescribe,
+    DocumentShape::GitHubApi,
+    DocumentShape::PackageLock,
+];
+
+fn sample(shape: DocumentShape) -> &'static str {
+    match shape {
+        DocumentShape::KubernetesList => {
+            r#"{"apiVersion": "v1", "kind": "PodList", "items": [{"metadata": {"name": "web", "namespace": "default", "labels": {"app": "web"}}, "spec": {"containers": [{"image": "nginx"}]}, "status": {"phase": "Pending"}}]}"#
+        }
+        DocumentShape::AwsEc2Instances => {
+            r#"{"Reservations": [{"Instances": [{"InstanceId": "i-1", "InstanceType": "t3.micro", "State": {"Name": "running"}, "Tags": [{"Key": "Name", "Value": "web"}], "PrivateIpAddress": "10.0.0.1"}]}]}"#
+        }
+        DocumentShape::AwsDescribe => {
+            r#"{"Volumes": [{"VolumeId": "vol-1", "Tags": [{"Key": "Name", "Value": "data"}]}]}"#
+        }
+        DocumentShape::GitHubApi => {
+            r#"[{"node_id": "I_1", "url": "https://api.github.com/repos/a/b/issues/1", "html_url": "https://github.com/a/b/issues/1", "number": 1, "title": "Bug", "state": "open", "user": {"login": "octocat"}}]"#
+        }
+        DocumentShape::PackageLock => {
+            r#"{"name": "app", "lockfileVersion": 3, "packages": {"": {"dependencies": {"a": "^1"}}, "node_modules/a": {"version": "1.0.0"}, "node_modules/b/node_modules/a": {"version": "2.0.0", "dev": true}}}"#
+        }
+    }
+}
+
+#[test]
+fn test_every_shape_has_templates() {
+    for shape in SHAPES {
+        let templates = templates_for(shape);
+        assert!(
+            (1..=9).contains(&templates.len()),
+            "{} has {} templates",
+            shape.id(),
+            templates.len()
+        );
+    }
+}
+
+#[test]
+fn test_samples_are_recognised() {
+    for shape in SHAPES {
+        let value = serde_json::from_str(sample(shape)).unwrap();
+        assert_eq!(detect(&value), Some(shape));
+    }
+}
+
+#[test]
+fn test_templates_run_on_their_shape() {
+    let cancel_token = CancellationToken::new();
+    for shape in SHAPES {
+        let input = Arc::new(sample(shape).to_string());
+        for template in templates_for(shape) {
+            let result = JqExecutor::execute_on_input(
+                &input,
+                &template.query,
+                &[],
+                &JqLibrary::default(),
+                &cancel_token,
+            );
+            assert!(result.is_ok(), "{}: {:?}", template.query, result);
+        }
+    }
+}
@@ -0,0 +1,115 @@
+use serde_json::{Map, Value};
+
+/// Well-known document recognised from its fingerprint
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DocumentShape {
+    /// `kubectl get ... -o json`: a `*List` kind with `items`
+    KubernetesList,
+    /// `aws ec2 describe-instances`: instances grouped in `Reservations`
+    AwsEc2Instances,
+    /// Other AWS CLI `describe-*` / `list-*` output: PascalCase keys holding
+    /// an array of PascalCase objects
+    AwsDescribe,
+    /// GitHub REST API listing: an array of objects with a `node_id` and an
+    /// `https://api.github.com/` `url`
+    GitHubApi,
+    /// npm `package-lock.json` (lockfile version 2 or later)
+    PackageLock,
+}
+
+impl DocumentShape {
+    /// Key of the shape's templates in the bundled data
+    pub fn id(self) -> &'static str {
+        match self {
+            DocumentShape::KubernetesList => "kubernetes-list",
+            DocumentShape::AwsEc2Instances => "aws-ec2-instances",
+            DocumentShape::AwsDescribe => "aws-describe",
+            DocumentShape::GitHubApi => "github-api",
+            DocumentShape::PackageLock => "package-lock",
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            DocumentShape::KubernetesList => "Kubernetes list",
+            DocumentShape::AwsEc2Instances => "AWS EC2 instances",
+            DocumentShape::AwsDescribe => "AWS CLI output",
+            DocumentShape::GitHubApi => "GitHub API listing",
+            DocumentShape::PackageLock => "package-lock.json",
+        }
+    }
+}
+
+/// Recognise the first JSON value of `input`, if it is a well-known document
+pub fn detect_input(input: &str) -> Option<DocumentShape> {
+    let value = serde_json::Deserializer::from_str(input)
+        .into_iter::<Value>()
+        .next()?
+        .ok()?;
+    detect(&value)
+}
+
+/// Recognise a well-known document, most specific fingerprint first
+pub fn detect(value: &Value) -> Option<DocumentShape> {
+    if let Value::Array(elements) = value {
+        return elements
+            .first()
+            .and_then(Value::as_object)
+            .filter(|object| is_github_object(object))
+            .map(|_| DocumentShape::GitHubApi);
+    }
+
+    let object = value.as_object()?;
+    if is_kubernetes_list(object) {
+        Some(DocumentShape::KubernetesList)
+    } else if is_package_lock(object) {
+        Some(DocumentShape::PackageLock)
+    } else if object.get("Reservations").is_some_and(Value::is_array) {
+        Some(DocumentShape::AwsEc2Instances)
+    } else if is_aws_output(object) {
+        Some(DocumentShape::AwsDescribe)
+    } else {
+        None
+    }
+}
+
+fn is_kubernetes_list(object: &Map<String, Value>) -> bool {
+    object.get("apiVersion").is_some_and(Value::is_string)
+        && object
+            .get("kind")
+            .and_then(Value::as_str)
+            .is_some_and(|kind| kind.ends_with("List"))
+        && object.get("items").is_some_and(Value::is_array)
+}
+
+fn is_package_lock(object: &Map<String, Value>) -> bool {
+    object.get("lockfileVersion").is_some_and(Value::is_number)
+        && object.get("packages").is_some_and(Value::is_object)
+}
+
+fn is_aws_output(object: &Map<String, Value>) -> bool {
+    let has_resources = object.values().any(|value| {
+        value
+            .as_array()
+            .and_then(|elements| elements.first())
+            .and_then(Value::as_object)
+            .is_some_and(|element| !element.is_empty() && element.keys().all(|k| is_pascal_case(k)))
+    });
+    !object.is_empty() && object.keys().all(|k| is_pascal_case(k)) && has_resources
+}
+
+fn is_pascal_case(key: &str) -> bool {
+    key.chars().next().is_some_and(|c| c.is_ascii_uppercase())
+}
+
+fn is_github_object(object: &Map<String, Value>) -> bool {
+    object.get("node_id").is_some_and(Value::is_string)
+        && object
+            .get("url")
+            .and_then(Value::as_str)
+            .is_some_and(|url| url.starts_with("https://api.github.com/"))
+}
+
+#[cfg(test)]
+#[path = "templates_detect_tests.rs"]
+mod templates_detect_tests;
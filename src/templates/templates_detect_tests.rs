@@ -0,0 +1,55 @@
+//! Tests for templates_detect
+
+use super::*;
+use serde_json::json;
+
+#[test]
+fn test_detect_kubernetes_list() {
+    let list = json!({"apiVersion": "v1", "kind": "PodList", "items": []});
+    assert_eq!(detect(&list), Some(DocumentShape::KubernetesList));
+
+    let pod = json!({"apiVersion": "v1", "kind": "Pod", "metadata": {}});
+    assert_eq!(detect(&pod), None);
+}
+
+#[test]
+fn test_detect_aws_output() {
+    let instances = json!({"Reservations": [{"Instances": []}]});
+    assert_eq!(detect(&instances), Some(DocumentShape::AwsEc2Instances));
+
+    let volumes = json!({"Volumes": [{"VolumeId": "vol-1", "Size": 8}], "NextToken": "x"});
+    assert_eq!(detect(&volumes), Some(DocumentShape::AwsDescribe));
+
+    let camel = json!({"Volumes": [{"volumeId": "vol-1"}]});
+    assert_eq!(detect(&camel), None);
+}
+
+#[test]
+fn test_detect_github_api() {
+    let issue = json!({"node_id": "I_1", "url": "https://api.github.com/repos/a/b/issues/1"});
+    assert_eq!(
+        detect(&json!([issue.clone()])),
+        Some(DocumentShape::GitHubApi)
+    );
+    assert_eq!(detect(&issue), None);
+
+    let other = json!([{"node_id": "I_1", "url": "https://example.com"}]);
+    assert_eq!(detect(&other), None);
+}
+
+#[test]
+fn test_detect_package_lock() {
+    let lock = json!({"name": "app", "lockfileVersion": 3, "packages": {"": {}}});
+    assert_eq!(detect(&lock), Some(DocumentShape::PackageLock));
+
+    let package = json!({"name": "app", "dependencies": {}});
+    assert_eq!(detect(&package), None);
+}
+
+#[test]
+fn test_detect_input_uses_first_value() {
+    let input = "{\"apiVersion\": \"v1\", \"kind\": \"List\", \"items\": []}\n{\"a\": 1}";
+    assert_eq!(detect_input(input), Some(DocumentShape::KubernetesList));
+    assert_eq!(detect_input("[1, 2]"), None);
+    assert_eq!(detect_input("not json"), None);
+}
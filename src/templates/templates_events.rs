@@ -0,0 +1,58 @@
+use ratatui::crossterm::event::{KeyCode, KeyEvent};
+
+use crate::app::App;
+use crate::query::language::QueryLanguage;
+
+/// Show the templates for the loaded input, for `:templates`
+pub fn open_templates(app: &mut App) {
+    let Some(query_state) = &app.query else {
+        return;
+    };
+    if query_state.language != QueryLanguage::Jq {
+        app.notification
+            .show_warning("Query templates are only available for jq queries");
+        return;
+    }
+    if !app.templates.open() {
+        app.notification
+            .show_warning("No query templates for this input");
+    }
+}
+
+/// Handle a key while the templates menu is shown
+///
+/// Other keys close the menu and return false, so that typing a query right
+/// away isn't held up by it.
+pub fn handle_templates_key(app: &mut App, key: KeyEvent) -> bool {
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('q') => app.templates.close(),
+        KeyCode::Up | KeyCode::Char('k') => app.templates.select_prev(),
+        KeyCode::Down | KeyCode::Char('j') => app.templates.select_next(),
+        KeyCode::Enter => apply_template(app, app.templates.selected()),
+        KeyCode::Char(c @ '1'..='9') => {
+            let index = c as usize - '1' as usize;
+            if index < app.templates.templates().len() {
+                apply_template(app, index);
+            }
+        }
+        _ => {
+            app.templates.close();
+            return false;
+        }
+    }
+    true
+}
+
+/// Replace the query with template `index` and run it
+fn apply_template(app: &mut App, index: usize) {
+    let Some(template) = app.templates.templates().get(index) else {
+        return;
+    };
+    let query = template.query.clone();
+    app.templates.close();
+    crate::editor::editor_events::replace_query_and_execute(app, &query);
+}
+
+#[cfg(test)]
+#[path = "templates_events_tests.rs"]
+mod templates_events_tests;
@@ -0,0 +1,83 @@
+//! Tests for templates_events
+
+use super::*;
+use crate::test_utils::test_helpers::{key, test_app, wait_for_query_completion};
+
+const POD_LIST: &str =
+    r#"{"apiVersion": "v1", "kind": "PodList", "items": [{"metadata": {"name": "web"}}]}"#;
+
+#[test]
+fn test_menu_opens_when_input_is_recognised() {
+    let app = test_app(POD_LIST);
+    assert!(app.templates.is_visible());
+
+    let app = test_app(r#"{"name": "test"}"#);
+    assert!(!app.templates.is_visible());
+}
+
+#[test]
+fn test_number_key_runs_template() {
+    let mut app = test_app(POD_LIST);
+
+    app.handle_key_event(key(KeyCode::Char('1')));
+
+    assert!(!app.templates.is_visible());
+    assert_eq!(app.input.query(), ".items[].metadata.name");
+    assert!(wait_for_query_completion(&mut app, 2000));
+    let result = app
+        .query
+        .as_ref()
+        .unwrap()
+        .last_successful_result_unformatted
+        .clone()
+        .unwrap();
+    assert_eq!(result.trim(), "\"web\"");
+}
+
+#[test]
+fn test_enter_runs_selected_template() {
+    let mut app = test_app(POD_LIST);
+
+    app.handle_key_event(key(KeyCode::Char('j')));
+    app.handle_key_event(key(KeyCode::Enter));
+
+    let second = templates_for_test(&app, 1);
+    assert_eq!(app.input.query(), second);
+}
+
+#[test]
+fn test_other_keys_close_menu_and_reach_query() {
+    let mut app = test_app(POD_LIST);
+
+    app.handle_key_event(key(KeyCode::Char('.')));
+
+    assert!(!app.templates.is_visible());
+    assert_eq!(app.input.query(), ".");
+}
+
+#[test]
+fn test_command_reopens_menu() {
+    let mut app = test_app(POD_LIST);
+    app.handle_key_event(key(KeyCode::Esc));
+    assert!(!app.templates.is_visible());
+
+    open_templates(&mut app);
+    assert!(app.templates.is_visible());
+}
+
+#[test]
+fn test_command_explains_unrecognised_input() {
+    let mut app = test_app(r#"{"name": "test"}"#);
+
+    open_templates(&mut app);
+
+    assert!(!app.templates.is_visible());
+    assert_eq!(
+        app.notification.current_message(),
+        Some("No query templates for this input")
+    );
+}
+
+fn templates_for_test(app: &App, index: usize) -> String {
+    app.templates.templates()[index].query.clone()
+}
@@ -0,0 +1,109 @@
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::Style,
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Paragraph},
+};
+
+use super::templates_state::TemplatesState;
+use crate::theme;
+use crate::widgets::popup;
+use crate::widgets::text_width::{display_width, padding_for, truncate_to_width};
+
+/// Space taken by the indicator, number, gaps and right margin around the
+/// name and query columns
+const NAME_PREFIX_WIDTH: usize = 7;
+
+fn build_hints() -> Line<'static> {
+    theme::border_hints::build_hints(
+        &[
+            ("1-9", "Run"),
+            ("j/k", "Select"),
+            ("Enter", "Run selected"),
+            ("Esc", "Close"),
+        ],
+        theme::templates::BORDER,
+    )
+}
+
+fn template_lines(state: &TemplatesState, width: usize) -> Vec<Line<'static>> {
+    let name_width = state
+        .templates()
+        .iter()
+        .map(|template| display_width(&template.name))
+        .max()
+        .unwrap_or(0);
+    let query_width = width.saturating_sub(name_width + NAME_PREFIX_WIDTH);
+
+    state
+        .templates()
+        .iter()
+        .enumerate()
+        .map(|(index, template)| {
+            let is_selected = index == state.selected();
+            let indicator = if is_selected { "▸ " } else { "  " };
+
+            let mut line = Line::from(vec![
+                Span::styled(
+                    indicator,
+                    Style::default().fg(theme::templates::ITEM_SELECTED_INDICATOR),
+                ),
+                Span::styled(
+                    format!("{} ", index + 1),
+                    Style::default().fg(theme::templates::NUMBER),
+                ),
+                Span::styled(
+                    template.name.clone(),
+                    Style::default().fg(theme::templates::NAME),
+                ),
+                Span::raw(padding_for(&template.name, name_width + 2)),
+                Span::styled(
+                    truncate_to_width(&template.query, query_width),
+                    Style::default().fg(theme::templates::QUERY),
+                ),
+            ]);
+            if is_selected {
+                line = line.style(Style::default().bg(theme::templates::ITEM_SELECTED_BG));
+            }
+            line
+        })
+        .collect()
+}
+
+/// Render the starter queries for the recognised input
+pub fn render_templates(state: &TemplatesState, frame: &mut Frame) -> Option<Rect> {
+    let frame_area = frame.area();
+    let shape = state.shape()?;
+    if !state.is_visible() || frame_area.width < 30 || frame_area.height < 6 {
+        return None;
+    }
+
+    let popup_width = ((frame_area.width as f32 * 0.8) as u16)
+        .clamp(60, 110)
+        .min(frame_area.width.saturating_sub(4));
+    let popup_height =
+        (state.templates().len() as u16 + 2).min(frame_area.height.saturating_sub(2));
+    let popup_area = popup::centered_popup(frame_area, popup_width, popup_height);
+    popup::clear_area(frame, popup_area);
+
+    let title = format!(" Templates for {} ", shape.label());
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(Line::from(Span::styled(title, theme::templates::TITLE)))
+        .title_bottom(build_hints().centered())
+        .border_style(Style::default().fg(theme::templates::BORDER))
+        .style(Style::default().bg(theme::templates::BACKGROUND));
+    let content_area = block.inner(popup_area);
+    frame.render_widget(block, popup_area);
+
+    let lines = template_lines(state, content_area.width as usize);
+    frame.render_widget(Paragraph::new(lines), content_area);
+
+    Some(popup_area)
+}
+
+#[cfg(test)]
+#[path = "templates_render_tests.rs"]
+mod templates_render_tests;
@@ -0,0 +1,40 @@
+//! Tests for templates_render
+
+use super::*;
+use insta::assert_snapshot;
+use ratatui::Terminal;
+use ratatui::backend::TestBackend;
+
+fn render_to_string(state: &TemplatesState, width: u16, height: u16) -> String {
+    let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+    terminal
+        .draw(|f| {
+            render_templates(state, f);
+        })
+        .unwrap();
+    terminal.backend().to_string()
+}
+
+fn open_state() -> TemplatesState {
+    let mut state = TemplatesState::new();
+    state.detect(r#"{"apiVersion": "v1", "kind": "PodList", "items": []}"#);
+    state.open();
+    state
+}
+
+#[test]
+fn snapshot_templates_menu() {
+    let mut state = open_state();
+    state.select_next();
+    assert_snapshot!(render_to_string(&state, 100, 14));
+}
+
+#[test]
+fn test_closed_menu_is_not_rendered() {
+    let mut state = open_state();
+    state.close();
+    let mut terminal = Terminal::new(TestBackend::new(80, 14)).unwrap();
+    terminal
+        .draw(|f| assert!(render_templates(&state, f).is_none()))
+        .unwrap();
+}
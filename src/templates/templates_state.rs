@@ -0,0 +1,71 @@
+use super::templates_data::{Template, templates_for};
+use super::templates_detect::{DocumentShape, detect_input};
+
+/// Query templates menu (shown on load, or `:templates`)
+#[derive(Debug, Default)]
+pub struct TemplatesState {
+    visible: bool,
+    shape: Option<DocumentShape>,
+    templates: Vec<Template>,
+    selected: usize,
+}
+
+impl TemplatesState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    /// Recognise the loaded input and keep the templates for it
+    ///
+    /// Returns the recognised shape, if any.
+    pub fn detect(&mut self, input: &str) -> Option<DocumentShape> {
+        self.shape = detect_input(input);
+        self.templates = self.shape.map(templates_for).unwrap_or_default();
+        self.selected = 0;
+        self.shape
+    }
+
+    /// Show the menu; returns false when the input wasn't recognised
+    pub fn open(&mut self) -> bool {
+        if self.templates.is_empty() {
+            return false;
+        }
+        self.selected = 0;
+        self.visible = true;
+        true
+    }
+
+    pub fn close(&mut self) {
+        self.visible = false;
+    }
+
+    pub fn shape(&self) -> Option<DocumentShape> {
+        self.shape
+    }
+
+    pub fn templates(&self) -> &[Template] {
+        &self.templates
+    }
+
+    pub fn selected(&self) -> usize {
+        self.selected
+    }
+
+    pub fn select_next(&mut self) {
+        if self.selected + 1 < self.templates.len() {
+            self.selected += 1;
+        }
+    }
+
+    pub fn select_prev(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+}
+
+#[cfg(test)]
+#[path = "templates_state_tests.rs"]
+mod templates_state_tests;
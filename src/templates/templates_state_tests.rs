@@ -0,0 +1,43 @@
+//! Tests for templates_state
+
+use super::*;
+
+const POD_LIST: &str = r#"{"apiVersion": "v1", "kind": "PodList", "items": []}"#;
+
+#[test]
+fn test_detect_keeps_templates_of_the_shape() {
+    let mut state = TemplatesState::new();
+
+    assert_eq!(state.detect(POD_LIST), Some(DocumentShape::KubernetesList));
+    assert!(!state.templates().is_empty());
+    assert!(!state.is_visible());
+
+    assert_eq!(state.detect(r#"{"a": 1}"#), None);
+    assert!(state.templates().is_empty());
+}
+
+#[test]
+fn test_open_needs_a_recognised_input() {
+    let mut state = TemplatesState::new();
+    assert!(!state.open());
+
+    state.detect(POD_LIST);
+    assert!(state.open());
+    assert!(state.is_visible());
+    state.close();
+    assert!(!state.is_visible());
+}
+
+#[test]
+fn test_selection_stays_in_range() {
+    let mut state = TemplatesState::new();
+    state.detect(POD_LIST);
+    state.open();
+
+    state.select_prev();
+    assert_eq!(state.selected(), 0);
+    for _ in 0..20 {
+        state.select_next();
+    }
+    assert_eq!(state.selected(), state.templates().len() - 1);
+}
@@ -1,9 +1,12 @@
 #[cfg(test)]
 pub mod test_helpers {
     use crate::app::App;
+    use crate::autocomplete::suggestion_ranking::SuggestionRanking;
+    use crate::bookmarks::BookmarkState;
     use crate::config::Config;
     use crate::history::HistoryState;
     use crate::input::FileLoader;
+    use crate::recovery::RecoveryState;
     use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
     pub const TEST_JSON: &str = r#"{
@@ -23,10 +26,11 @@ pub mod test_helpers {
         use std::sync::mpsc::channel;
         let (tx, rx) = channel();
         // Send the result immediately so poll() will return it
-        let _ = tx.send(Ok(json));
+        let _ = tx.send(Ok((json, crate::input::input_format::InputFormat::Json)));
         FileLoader {
             state: LoadingState::Loading,
             rx: Some(rx),
+            format: None,
         }
     }
 
@@ -37,6 +41,12 @@ pub mod test_helpers {
         app.poll_file_loader();
         // Disable history persistence to avoid polluting real history file
         app.history = HistoryState::empty();
+        // Keep bookmarks in memory to avoid writing to the real config directory
+        app.bookmarks = BookmarkState::new();
+        // Count accepted suggestions in memory only
+        app.suggestion_ranking = SuggestionRanking::new();
+        // Keep no recovery file of the test queries
+        app.recovery = RecoveryState::new(false);
         app
     }
 
@@ -58,6 +68,37 @@ pub mod test_helpers {
         app
     }
 
+    /// Create an app whose query has finished running on `json`
+    pub fn app_with_result(json: &str, query: &str) -> App {
+        let mut app = test_app(json);
+        app.input.textarea.insert_str(query);
+        app.query.as_mut().unwrap().execute(query);
+        assert!(wait_for_query_completion(&mut app, 2000));
+        app
+    }
+
+    /// Create an app with the screen reader on and `json` announced
+    pub fn screen_reader_app(json: &str) -> App {
+        let mut app = test_app(json);
+        app.screen_reader.enable();
+        app.update_stats();
+        crate::screen_reader::announce_result(&mut app);
+        app
+    }
+
+    /// Assert that `open` leaves its popup closed and warns with `message`
+    pub fn assert_open_refused(
+        app: &mut App,
+        open: fn(&mut App),
+        is_visible: fn(&App) -> bool,
+        message: &str,
+    ) {
+        open(app);
+
+        assert!(!is_visible(app));
+        assert_eq!(app.notification.current_message(), Some(message));
+    }
+
     /// Wait for async query to complete by polling
     ///
     /// Polls query_state.poll_response() until query completes or timeout.
@@ -1,7 +1,8 @@
 //! Centralized theme configuration for all UI components.
 //!
 //! All colors and styles are defined here. When adding or modifying UI components:
-//! - Add new colors to the appropriate module
+//! - Add new colors to the appropriate module; per-feature modules live in
+//!   `src/theme/`
 //! - Use `theme::module::CONSTANT` in render files
 //! - Do NOT hardcode `Color::*` values directly in render files
 //!
@@ -64,13 +65,23 @@ pub mod input {
     pub const SYNTAX_ERROR_WARNING: Color = Color::Rgb(255, 217, 61);
     pub const TOOLTIP_HINT: Color = Color::Rgb(198, 120, 221); // Vibrant purple
     pub const UNFOCUSED_HINT: Color = Color::Rgb(90, 92, 119);
+    pub const LANGUAGE_INDICATOR: Color = Color::Rgb(198, 120, 221); // Vibrant purple
+    pub const TRANSLATION_PREVIEW: Color = Color::Rgb(150, 152, 175);
 
     // Unfocused query text
     pub const QUERY_UNFOCUSED: Color = Color::Rgb(90, 92, 119);
+    pub const GHOST_TEXT: Color = Color::Rgb(90, 92, 119);
+    pub const SELECTION: Color = Color::Rgb(255, 217, 61);
 
     pub const CURSOR: Style = Style::new().add_modifier(Modifier::REVERSED);
 }
 
+/// Breadcrumb bar styles
+pub mod breadcrumbs;
+
+/// Query tab bar and tab comparison styles
+pub mod query_tabs;
+
 /// Results pane styles
 pub mod results {
     use super::*;
@@ -82,6 +93,11 @@ pub mod results {
     pub const BORDER_ERROR: Color = Color::Rgb(224, 108, 117);
     pub const BACKGROUND: Color = Color::Rgb(26, 26, 46);
 
+    // Explanation below the message in the error overlay
+    pub const EXPLANATION: Color = Color::Rgb(236, 236, 244);
+    pub const EXPLANATION_FIX: Color = Color::Rgb(107, 203, 119);
+    pub const EXPLANATION_LINK: Color = Color::Rgb(130, 133, 158);
+
     // Search mode text colors (in title)
     pub const SEARCH_ACTIVE: Color = Color::Rgb(255, 107, 157); // Hot pink
     pub const SEARCH_INACTIVE: Color = Color::Rgb(90, 92, 119);
@@ -91,6 +107,10 @@ pub mod results {
     pub const TIMING_SLOW: Color = Color::Rgb(255, 217, 61);
     pub const TIMING_VERY_SLOW: Color = Color::Rgb(224, 108, 117);
 
+    // Distribution sparkline above numeric results
+    pub const SPARKLINE: Color = Color::Rgb(0, 217, 255);
+    pub const SPARKLINE_LABEL: Color = Color::Rgb(130, 133, 158);
+
     // Query state indicators
     pub const RESULT_OK: Color = Color::Rgb(107, 203, 119);
     pub const RESULT_WARNING: Color = Color::Rgb(255, 217, 61);
@@ -102,10 +122,39 @@ pub mod results {
         .fg(Color::Rgb(35, 30, 10)) // Deep dark yellow-tinted
         .bg(Color::Rgb(255, 217, 61)); // Golden yellow
 
+    pub const BADGE_RUNTIME_ERROR: Style = Style::new()
+        .fg(Color::Rgb(40, 15, 18)) // Deep dark red-tinted
+        .bg(Color::Rgb(224, 108, 117)); // Soft red
+
     pub const BADGE_EMPTY_RESULT: Style = Style::new()
         .fg(Color::Rgb(20, 25, 40)) // Deep dark blue-tinted
         .bg(Color::Rgb(130, 140, 170)); // Brighter steel blue
 
+    // Follow mode indicator (--follow)
+    pub const BADGE_FOLLOWING: Style = Style::new()
+        .fg(Color::Rgb(15, 35, 20)) // Deep dark green-tinted
+        .bg(Color::Rgb(107, 203, 119)); // Green
+    pub const BADGE_FOLLOW_PAUSED: Style = Style::new()
+        .fg(Color::Rgb(35, 30, 10)) // Deep dark yellow-tinted
+        .bg(Color::Rgb(255, 217, 61)); // Golden yellow
+
+    // Redacted view indicator (Alt+R)
+    pub const BADGE_REDACTED: Style = Style::new()
+        .fg(Color::Rgb(40, 15, 20)) // Deep dark red-tinted
+        .bg(Color::Rgb(224, 108, 117)); // Soft red
+
+    // Folded view indicator (zM, :fold)
+    pub const BADGE_FOLDED: Style = Style::new()
+        .fg(Color::Rgb(20, 25, 40)) // Deep dark blue-tinted
+        .bg(Color::Rgb(0, 217, 255)); // Electric cyan
+
+    // Closed object or array: `{…}` and the number of lines it hides
+    pub const FOLD_MARKER: Color = Color::Rgb(130, 133, 158);
+
+    // Path of the container around the top of the scrolled pane
+    pub const STICKY_HEADER: Color = Color::Rgb(0, 217, 255);
+    pub const STICKY_HEADER_BG: Color = Color::Rgb(36, 36, 58);
+
     // Search match highlighting
     pub const MATCH_HIGHLIGHT_BG: Color = Color::Rgb(85, 85, 115);
     pub const MATCH_HIGHLIGHT_FG: Color = Color::Rgb(236, 236, 244);
@@ -226,6 +275,9 @@ pub mod history {
     pub const ITEM_NORMAL_BG: Color = Color::Rgb(26, 26, 46);
     pub const ITEM_NORMAL_FG: Color = Color::Rgb(180, 182, 200);
 
+    // Entries marked for deleting
+    pub const MARKED: Color = Color::Rgb(255, 107, 107);
+
     // Empty state
     pub const NO_MATCHES: Color = Color::Rgb(90, 92, 119);
 
@@ -258,6 +310,10 @@ pub mod snippets {
     pub const DESCRIPTION: Color = Color::Rgb(90, 92, 119);
     pub const QUERY_PREVIEW: Color = Color::Rgb(255, 217, 61);
     pub const CATEGORY: Color = Color::Rgb(107, 203, 119);
+    // Quick slot badge (`[Alt+1]`) after a snippet name
+    pub const SLOT: Color = Color::Rgb(255, 217, 61);
+    // Apply mode badge (`Apply: append`) when snippets don't replace the query
+    pub const APPLY_MODE: Color = Color::Rgb(255, 217, 61);
 
     // Edit/Create mode
     pub const FIELD_ACTIVE_BORDER: Color = Color::Rgb(255, 217, 61);
@@ -277,6 +333,24 @@ pub mod snippets {
     pub const SEARCH_BG: Color = Color::Rgb(26, 26, 46);
 }
 
+/// Pipeline view styles
+pub mod pipeline;
+
+/// Variables panel styles
+pub mod variables;
+
+/// Bookmarks sidebar styles
+pub mod bookmarks;
+
+/// Key finder sidebar styles
+pub mod key_finder;
+
+/// Timing overlay styles
+pub mod timing;
+
+/// Command line styles (`:` commands)
+pub mod command_line;
+
 /// AI assistant styles
 pub mod ai {
     use super::*;
@@ -337,6 +411,14 @@ pub mod ai {
     pub const SUGGESTION_OPTIMIZE: Color = Color::Rgb(255, 217, 61);
     pub const SUGGESTION_NEXT: Color = Color::Rgb(107, 203, 119);
 
+    // Conversation: follow-ups asked so far and the one being typed
+    pub const FOLLOW_UP_MARKER: Color = Color::Rgb(0, 217, 255);
+    pub const FOLLOW_UP_TEXT: Color = Color::Rgb(130, 133, 158);
+    pub const FOLLOW_UP_INPUT: Color = Color::Rgb(236, 236, 244);
+
+    // Session token and cost estimate in the footer
+    pub const USAGE: Color = Color::Rgb(90, 92, 119);
+
     // Hints
     pub const HINT: Color = Color::Rgb(90, 92, 119);
 }
@@ -348,6 +430,7 @@ pub mod autocomplete {
     // Border and scrollbar
     pub const BORDER: Color = Color::Rgb(0, 217, 255);
     pub const SCROLLBAR: Color = Color::Rgb(0, 217, 255);
+    pub const MORE: Color = Color::Rgb(90, 92, 119);
     pub const BACKGROUND: Color = Color::Rgb(26, 26, 46);
 
     // List items
@@ -363,6 +446,10 @@ pub mod autocomplete {
     pub const TYPE_OPERATOR: Color = Color::Rgb(198, 120, 221);
     pub const TYPE_PATTERN: Color = Color::Rgb(107, 203, 119);
     pub const TYPE_VARIABLE: Color = Color::Rgb(224, 108, 117);
+
+    // Preview of the selected suggestion's result
+    pub const PREVIEW_PENDING: Color = Color::Rgb(90, 92, 119);
+    pub const PREVIEW_ERROR: Color = Color::Rgb(224, 108, 117);
 }
 
 /// Tooltip styles
@@ -380,6 +467,10 @@ pub mod tooltip {
     pub const DESCRIPTION: Color = Color::Rgb(236, 236, 244);
     pub const EXAMPLE: Color = Color::Rgb(0, 217, 255);
     pub const EXAMPLE_DESC: Color = Color::Rgb(130, 133, 158);
+    pub const EXAMPLE_SELECTED: Style = Style::new()
+        .fg(Color::Rgb(26, 26, 46))
+        .bg(Color::Rgb(0, 217, 255))
+        .add_modifier(Modifier::BOLD);
     pub const TIP: Color = Color::Rgb(255, 217, 61);
     pub const SEPARATOR: Color = Color::Rgb(90, 92, 119);
 }
@@ -422,6 +513,46 @@ pub mod help_line {
     pub const SEPARATOR: Color = Color::Rgb(90, 92, 119);
 }
 
+/// Status line styles (input and query description)
+pub mod status_line;
+
+/// Exit confirmation styles
+pub mod exit_prompt;
+
+pub mod paste;
+
+pub mod recovery;
+
+/// Exit menu styles
+pub mod exit_menu;
+
+/// Preview of the change `--in-place` makes to the input file
+pub mod in_place;
+
+/// Message log popup styles
+pub mod message_log;
+
+pub mod schema;
+
+pub mod lint;
+
+pub mod field_diff;
+
+pub mod size_breakdown;
+
+pub mod histogram;
+
+pub mod projection;
+
+pub mod filter_builder;
+
+pub mod value_edit;
+
+pub mod templates;
+
+/// Screen-reader layout styles
+pub mod screen_reader;
+
 /// Border hint utilities - for building styled keyboard shortcuts on borders
 pub mod border_hints {
     use super::*;
@@ -474,7 +605,31 @@ pub mod syntax {
     pub const NUMBER: Color = Color::Rgb(189, 147, 249); // Purple numbers
     pub const OPERATOR: Color = Color::Rgb(198, 120, 221); // Magenta operators
     pub const VARIABLE: Color = Color::Rgb(255, 184, 108); // Orange variables
-    pub const FIELD: Color = Color::Rgb(0, 217, 255); // Cyan fields
+    pub const FIELD: Color = Color::Rgb(0, 217, 255); // Cyan object keys
+    pub const ACCESSOR: Style = Style::new(); // Field accessors keep the text color
+    pub const FORMAT: Color = Color::Rgb(255, 217, 61); // Yellow format strings
+    pub const COMMENT: Style = Style::new()
+        .fg(Color::Rgb(110, 113, 140))
+        .add_modifier(Modifier::ITALIC); // Muted italic comments
+
+    /// Nested `()`, `[]` and `{}` pairs, colored by depth
+    pub mod rainbow {
+        use super::*;
+
+        pub const COLORS: [Color; 3] = [
+            Color::Rgb(255, 215, 0),   // Gold
+            Color::Rgb(218, 112, 214), // Orchid
+            Color::Rgb(23, 159, 255),  // Azure
+        ];
+        /// Opener never closed, where the query breaks
+        pub const UNMATCHED_OPENER: Style = Style::new()
+            .fg(Color::Rgb(90, 92, 119))
+            .add_modifier(Modifier::DIM);
+        /// Closer without an opener
+        pub const UNMATCHED_CLOSER: Style = Style::new()
+            .fg(Color::Rgb(224, 108, 117))
+            .add_modifier(Modifier::BOLD);
+    }
 
     /// Bracket pair matching style (color + bold + underlined)
     /// Applied to matching brackets when cursor is on a bracket
@@ -487,4 +642,14 @@ pub mod syntax {
             .add_modifier(Modifier::BOLD)
             .add_modifier(Modifier::UNDERLINED);
     }
+
+    /// Query region jq reported an error for (color + underlined)
+    pub mod error_span {
+        use super::*;
+
+        pub const COLOR: Color = Color::Rgb(224, 108, 117);
+    }
 }
+
+/// JSON result styles (results pane, pipeline view and suggestion preview)
+pub mod json;
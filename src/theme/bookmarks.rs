@@ -0,0 +1,15 @@
+use super::*;
+
+pub const BORDER: Color = Color::Rgb(107, 203, 119);
+pub const BACKGROUND: Color = Color::Rgb(26, 26, 46);
+
+// List items
+pub const LABEL: Color = Color::Rgb(107, 203, 119);
+pub const PATH: Color = Color::Rgb(90, 92, 119);
+pub const ITEM_SELECTED_BG: Color = Color::Rgb(45, 45, 72);
+pub const ITEM_SELECTED_INDICATOR: Color = Color::Rgb(107, 203, 119);
+pub const EMPTY: Color = Color::Rgb(90, 92, 119);
+
+// Label field
+pub const FIELD_BORDER: Color = Color::Rgb(255, 217, 61);
+pub const FIELD_TEXT: Color = Color::Rgb(236, 236, 244);
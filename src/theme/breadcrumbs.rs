@@ -0,0 +1,5 @@
+use super::*;
+
+pub const CRUMB: Color = Color::Rgb(150, 152, 175);
+pub const CURRENT: Color = Color::Rgb(0, 217, 255);
+pub const SEPARATOR: Color = Color::Rgb(90, 92, 119);
@@ -0,0 +1,7 @@
+use super::*;
+
+pub const BORDER: Color = Color::Rgb(0, 217, 255);
+pub const BACKGROUND: Color = Color::Rgb(26, 26, 46);
+pub const PROMPT: Color = Color::Rgb(0, 217, 255);
+pub const TEXT: Color = Color::Rgb(236, 236, 244);
+pub const HINTS: Color = Color::Rgb(90, 92, 119);
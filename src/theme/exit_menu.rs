@@ -0,0 +1,11 @@
+use super::*;
+
+pub const BORDER: Color = Color::Rgb(0, 217, 255);
+pub const BACKGROUND: Color = Color::Rgb(26, 26, 46);
+pub const KEY: Color = Color::Rgb(255, 217, 61);
+pub const TEXT: Color = Color::Rgb(180, 182, 200);
+pub const ITEM_SELECTED_BG: Color = Color::Rgb(45, 45, 72);
+pub const ITEM_SELECTED_FG: Color = Color::Rgb(236, 236, 244);
+pub const ITEM_SELECTED_INDICATOR: Color = Color::Rgb(0, 217, 255);
+pub const PROMPT: Color = Color::Rgb(0, 217, 255);
+pub const HINTS: Color = Color::Rgb(130, 133, 158);
@@ -0,0 +1,19 @@
+use super::*;
+
+pub const BORDER: Color = Color::Rgb(86, 182, 194);
+pub const BACKGROUND: Color = Color::Rgb(26, 26, 46);
+pub const TITLE: Style = Style::new()
+    .fg(Color::Rgb(236, 236, 244))
+    .add_modifier(Modifier::BOLD);
+
+pub const FIELD_ACTIVE_BORDER: Color = Color::Rgb(255, 217, 61);
+pub const FIELD_INACTIVE_BORDER: Color = Color::Rgb(90, 92, 119);
+pub const FIELD_TEXT: Color = Color::Rgb(236, 236, 244);
+pub const HINT: Color = Color::Rgb(90, 92, 119);
+
+pub const SUGGESTION: Color = Color::Rgb(236, 236, 244);
+pub const ITEM_SELECTED_BG: Color = Color::Rgb(45, 45, 72);
+pub const ITEM_SELECTED_INDICATOR: Color = Color::Rgb(86, 182, 194);
+pub const CLAUSE: Style = Style::new()
+    .fg(Color::Rgb(97, 175, 239))
+    .add_modifier(Modifier::BOLD);
@@ -0,0 +1,16 @@
+use super::*;
+
+pub const BORDER: Color = Color::Rgb(152, 195, 121);
+pub const BACKGROUND: Color = Color::Rgb(26, 26, 46);
+pub const TITLE: Style = Style::new()
+    .fg(Color::Rgb(236, 236, 244))
+    .add_modifier(Modifier::BOLD);
+
+pub const VALUE: Color = Color::Rgb(236, 236, 244);
+pub const BAR: Color = Color::Rgb(152, 195, 121);
+pub const COUNT: Style = Style::new()
+    .fg(Color::Rgb(236, 236, 244))
+    .add_modifier(Modifier::BOLD);
+pub const PERCENT: Color = Color::Rgb(130, 133, 158);
+pub const NOTE: Color = Color::Rgb(130, 133, 158);
+pub const ERROR: Color = Color::Rgb(224, 108, 117);
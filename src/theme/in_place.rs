@@ -0,0 +1,12 @@
+use super::*;
+
+pub const BORDER: Color = Color::Rgb(255, 184, 108);
+pub const BACKGROUND: Color = Color::Rgb(26, 26, 46);
+pub const TITLE: Style = Style::new()
+    .fg(Color::Rgb(236, 236, 244))
+    .add_modifier(Modifier::BOLD);
+
+pub const REMOVED: Color = Color::Rgb(224, 108, 117);
+pub const ADDED: Color = Color::Rgb(152, 195, 121);
+pub const SAME: Color = Color::Rgb(130, 133, 158);
+pub const NOTE: Color = Color::Rgb(90, 92, 119);
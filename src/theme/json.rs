@@ -0,0 +1,14 @@
+use super::*;
+
+pub const NULL: Style = Style::new().fg(Color::Rgb(130, 133, 158)); // Muted gray
+pub const FALSE: Style = Style::new().fg(Color::Rgb(224, 108, 117)); // Soft red
+pub const TRUE: Style = Style::new().fg(Color::Rgb(107, 203, 119)); // Fresh green
+pub const NUMBER: Style = Style::new().fg(Color::Rgb(189, 147, 249)); // Purple
+pub const STRING: Style = Style::new().fg(Color::Rgb(107, 203, 119)); // Fresh green
+pub const KEY: Style = Style::new()
+    .fg(Color::Rgb(255, 217, 61)) // Golden yellow
+    .add_modifier(Modifier::BOLD);
+/// Brackets, braces, commas and colons
+pub const PUNCTUATION: Style = Style::new()
+    .fg(Color::Rgb(0, 217, 255)) // Electric cyan
+    .add_modifier(Modifier::BOLD);
@@ -0,0 +1,15 @@
+use super::*;
+
+pub const BORDER: Color = Color::Rgb(97, 175, 239);
+pub const BACKGROUND: Color = Color::Rgb(26, 26, 46);
+
+// List items
+pub const PATH: Color = Color::Rgb(97, 175, 239);
+pub const PREVIEW: Color = Color::Rgb(90, 92, 119);
+pub const ITEM_SELECTED_BG: Color = Color::Rgb(45, 45, 72);
+pub const ITEM_SELECTED_INDICATOR: Color = Color::Rgb(97, 175, 239);
+pub const EMPTY: Color = Color::Rgb(90, 92, 119);
+
+// Search field
+pub const FIELD_BORDER: Color = Color::Rgb(255, 217, 61);
+pub const FIELD_TEXT: Color = Color::Rgb(236, 236, 244);
@@ -0,0 +1,14 @@
+use super::*;
+
+pub const BORDER: Color = Color::Rgb(255, 184, 108);
+pub const BACKGROUND: Color = Color::Rgb(26, 26, 46);
+pub const TITLE: Style = Style::new()
+    .fg(Color::Rgb(236, 236, 244))
+    .add_modifier(Modifier::BOLD);
+
+pub const PATH: Style = Style::new()
+    .fg(Color::Rgb(97, 175, 239))
+    .add_modifier(Modifier::BOLD);
+pub const KIND: Style = Style::new().fg(Color::Rgb(255, 184, 108));
+pub const NOTE: Color = Color::Rgb(130, 133, 158);
+pub const MESSAGE: Color = Color::Rgb(236, 236, 244);
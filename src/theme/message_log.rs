@@ -0,0 +1,13 @@
+use super::*;
+
+pub const BORDER: Color = Color::Rgb(130, 133, 158);
+pub const BACKGROUND: Color = Color::Rgb(26, 26, 46);
+pub const TITLE: Style = Style::new()
+    .fg(Color::Rgb(236, 236, 244))
+    .add_modifier(Modifier::BOLD);
+
+pub const TIME: Color = Color::Rgb(90, 92, 119);
+pub const TEXT: Color = Color::Rgb(236, 236, 244);
+pub const INFO: Color = Color::Rgb(130, 133, 158);
+pub const WARNING: Color = Color::Rgb(255, 217, 61);
+pub const ERROR: Color = Color::Rgb(224, 108, 117);
@@ -0,0 +1,10 @@
+use super::*;
+
+pub const BORDER: Color = Color::Rgb(0, 217, 255);
+pub const BACKGROUND: Color = Color::Rgb(26, 26, 46);
+pub const QUESTION: Style = Style::new()
+    .fg(Color::Rgb(236, 236, 244))
+    .add_modifier(Modifier::BOLD);
+pub const SIZE: Color = Color::Rgb(130, 133, 158);
+pub const KEY: Color = Color::Rgb(0, 217, 255);
+pub const TEXT: Color = Color::Rgb(236, 236, 244);
@@ -0,0 +1,17 @@
+use super::*;
+
+pub const BORDER: Color = Color::Rgb(199, 146, 234);
+pub const BACKGROUND: Color = Color::Rgb(26, 26, 46);
+
+// Stage list
+pub const STAGE_NUMBER: Color = Color::Rgb(90, 92, 119);
+pub const STAGE_TEXT: Color = Color::Rgb(236, 236, 244);
+pub const STAGE_DISABLED: Color = Color::Rgb(90, 92, 119);
+pub const ITEM_SELECTED_BG: Color = Color::Rgb(45, 45, 72);
+pub const ITEM_SELECTED_INDICATOR: Color = Color::Rgb(199, 146, 234);
+
+// Per-stage summary
+pub const SUMMARY: Color = Color::Rgb(107, 203, 119);
+pub const SUMMARY_EMPTY: Color = Color::Rgb(255, 217, 61);
+pub const SUMMARY_PENDING: Color = Color::Rgb(90, 92, 119);
+pub const ERROR: Color = Color::Rgb(224, 108, 117);
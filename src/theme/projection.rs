@@ -0,0 +1,16 @@
+use super::*;
+
+pub const BORDER: Color = Color::Rgb(198, 120, 221);
+pub const BACKGROUND: Color = Color::Rgb(26, 26, 46);
+pub const TITLE: Style = Style::new()
+    .fg(Color::Rgb(236, 236, 244))
+    .add_modifier(Modifier::BOLD);
+
+pub const KEY: Color = Color::Rgb(236, 236, 244);
+pub const CHECKBOX: Color = Color::Rgb(198, 120, 221);
+pub const ITEM_SELECTED_BG: Color = Color::Rgb(45, 45, 72);
+pub const ITEM_SELECTED_INDICATOR: Color = Color::Rgb(198, 120, 221);
+pub const QUERY: Style = Style::new()
+    .fg(Color::Rgb(97, 175, 239))
+    .add_modifier(Modifier::BOLD);
+pub const HINT: Color = Color::Rgb(90, 92, 119);
@@ -0,0 +1,13 @@
+use super::*;
+
+pub const ACTIVE: Color = Color::Rgb(0, 217, 255);
+pub const ACTIVE_BG: Color = Color::Rgb(45, 45, 72);
+pub const INACTIVE: Color = Color::Rgb(150, 152, 175);
+pub const SEPARATOR: Color = Color::Rgb(90, 92, 119);
+
+// Comparison popup
+pub const BORDER: Color = Color::Rgb(97, 175, 239);
+pub const BACKGROUND: Color = Color::Rgb(26, 26, 46);
+pub const TITLE: Style = Style::new()
+    .fg(Color::Rgb(97, 175, 239))
+    .add_modifier(Modifier::BOLD);
@@ -0,0 +1,4 @@
+use super::*;
+
+pub const LABEL: Color = Color::Rgb(130, 133, 158);
+pub const TEXT: Color = Color::Rgb(236, 236, 244);
@@ -0,0 +1,14 @@
+use super::*;
+
+pub const BORDER: Color = Color::Rgb(229, 192, 123);
+pub const BACKGROUND: Color = Color::Rgb(26, 26, 46);
+pub const TITLE: Style = Style::new()
+    .fg(Color::Rgb(236, 236, 244))
+    .add_modifier(Modifier::BOLD);
+
+pub const LABEL: Style = Style::new().fg(Color::Rgb(97, 175, 239));
+pub const BAR: Color = Color::Rgb(229, 192, 123);
+pub const SIZE: Style = Style::new()
+    .fg(Color::Rgb(236, 236, 244))
+    .add_modifier(Modifier::BOLD);
+pub const NOTE: Color = Color::Rgb(130, 133, 158);
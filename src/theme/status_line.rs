@@ -0,0 +1,7 @@
+use super::*;
+
+pub const BACKGROUND: Color = Color::Rgb(26, 26, 46);
+pub const SOURCE: Color = Color::Rgb(130, 133, 158);
+pub const TEXT: Color = Color::Rgb(90, 92, 119);
+pub const SEPARATOR: Color = Color::Rgb(60, 62, 88);
+pub const ERROR: Color = Color::Rgb(224, 108, 117);
@@ -0,0 +1,13 @@
+use super::*;
+
+pub const BORDER: Color = Color::Rgb(152, 195, 121);
+pub const BACKGROUND: Color = Color::Rgb(26, 26, 46);
+pub const TITLE: Style = Style::new()
+    .fg(Color::Rgb(236, 236, 244))
+    .add_modifier(Modifier::BOLD);
+
+pub const NUMBER: Color = Color::Rgb(255, 217, 61);
+pub const NAME: Color = Color::Rgb(236, 236, 244);
+pub const QUERY: Color = Color::Rgb(97, 175, 239);
+pub const ITEM_SELECTED_BG: Color = Color::Rgb(45, 45, 72);
+pub const ITEM_SELECTED_INDICATOR: Color = Color::Rgb(152, 195, 121);
@@ -0,0 +1,8 @@
+use super::*;
+
+pub const BORDER: Color = Color::Rgb(0, 217, 255);
+pub const BACKGROUND: Color = Color::Rgb(26, 26, 46);
+pub const TIME: Color = Color::Rgb(107, 203, 119);
+pub const QUERY: Color = Color::Rgb(236, 236, 244);
+pub const LABEL: Color = Color::Rgb(90, 92, 119);
+pub const SLOWEST: Color = Color::Rgb(255, 159, 67);
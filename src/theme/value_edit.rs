@@ -0,0 +1,11 @@
+use super::*;
+
+pub const BORDER: Color = Color::Rgb(255, 217, 61);
+pub const BACKGROUND: Color = Color::Rgb(26, 26, 46);
+pub const TITLE: Style = Style::new()
+    .fg(Color::Rgb(236, 236, 244))
+    .add_modifier(Modifier::BOLD);
+
+pub const TEXT: Color = Color::Rgb(236, 236, 244);
+pub const INACTIVE_TEXT: Color = Color::Rgb(130, 133, 158);
+pub const SEPARATOR: Color = Color::Rgb(90, 92, 119);
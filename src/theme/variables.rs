@@ -0,0 +1,17 @@
+use super::*;
+
+pub const BORDER: Color = Color::Rgb(255, 159, 67);
+pub const BACKGROUND: Color = Color::Rgb(26, 26, 46);
+
+// List items
+pub const NAME: Color = Color::Rgb(255, 159, 67);
+pub const VALUE: Color = Color::Rgb(236, 236, 244);
+pub const KIND: Color = Color::Rgb(90, 92, 119);
+pub const ITEM_SELECTED_BG: Color = Color::Rgb(45, 45, 72);
+pub const ITEM_SELECTED_INDICATOR: Color = Color::Rgb(255, 159, 67);
+pub const EMPTY: Color = Color::Rgb(90, 92, 119);
+
+// Edit form
+pub const FIELD_ACTIVE_BORDER: Color = Color::Rgb(255, 217, 61);
+pub const FIELD_INACTIVE_BORDER: Color = Color::Rgb(90, 92, 119);
+pub const FIELD_TEXT: Color = Color::Rgb(236, 236, 244);
@@ -1,10 +1,130 @@
+use std::ops::Range;
+
+use tui_textarea::TextArea;
+
 use super::tooltip_state::TooltipState;
+use crate::app::{App, Focus};
+use crate::autocomplete::insertion::move_cursor_to_column;
+use crate::editor::EditorMode;
 
 pub fn handle_tooltip_toggle(state: &mut TooltipState) -> bool {
     state.toggle();
     true
 }
 
+/// Select the next example of the visible tooltip (Alt+E)
+pub fn handle_next_example(app: &mut App) -> bool {
+    if app.focus != Focus::InputField || !app.tooltip.should_show() {
+        return false;
+    }
+    app.tooltip.select_next_example();
+    true
+}
+
+/// Insert the selected tooltip example into the query (Alt+I)
+///
+/// With the cursor on the name of the function the tooltip describes, the
+/// example replaces the name; otherwise it goes in at the cursor. The first
+/// field of the example is left selected as a placeholder, so typing
+/// replaces it with a field from the data.
+pub fn handle_insert_example(app: &mut App) -> bool {
+    if app.focus != Focus::InputField || !app.tooltip.should_show() {
+        return false;
+    }
+    let Some(code) = app.tooltip.selected_example_code() else {
+        return false;
+    };
+
+    let query: Vec<char> = app.input.query().chars().collect();
+    let cursor = app.input.textarea.cursor().1;
+    let replaced = app
+        .tooltip
+        .current_function
+        .as_deref()
+        .and_then(|func| {
+            word_at(&query, cursor)
+                .filter(|word| query[word.clone()].iter().copied().eq(func.chars()))
+        })
+        .unwrap_or(cursor..cursor);
+
+    insert_example(&mut app.input.textarea, &query, replaced, code);
+    app.input.editor_mode = EditorMode::Insert;
+    app.tooltip.selected_example = None;
+
+    app.autocomplete.hide();
+    app.history.reset_cycling();
+    app.results_scroll.reset();
+    app.results_cursor.reset();
+    app.error_overlay_visible = false;
+    app.input
+        .brace_tracker
+        .rebuild(app.input.textarea.lines()[0].as_ref());
+    app.debouncer.schedule_execution();
+    app.update_tooltip();
+    true
+}
+
+/// Replace `replaced` (character columns) of `query` with `code`, selecting
+/// the placeholder field of `code` or leaving the cursor after it
+fn insert_example(textarea: &mut TextArea<'_>, query: &[char], replaced: Range<usize>, code: &str) {
+    let new_query: String = query[..replaced.start]
+        .iter()
+        .copied()
+        .chain(code.chars())
+        .chain(query[replaced.end..].iter().copied())
+        .collect();
+
+    textarea.cancel_selection();
+    textarea.delete_line_by_head();
+    textarea.delete_line_by_end();
+    textarea.insert_str(&new_query);
+
+    match placeholder_range(code) {
+        Some(field) => {
+            move_cursor_to_column(textarea, replaced.start + field.start);
+            textarea.start_selection();
+            move_cursor_to_column(textarea, replaced.start + field.end);
+        }
+        None => move_cursor_to_column(textarea, replaced.start + code.chars().count()),
+    }
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+/// Character range of the word touching `cursor`, if any
+fn word_at(chars: &[char], cursor: usize) -> Option<Range<usize>> {
+    let cursor = cursor.min(chars.len());
+    let start = chars[..cursor]
+        .iter()
+        .rposition(|&c| !is_word_char(c))
+        .map_or(0, |i| i + 1);
+    let end = chars[cursor..]
+        .iter()
+        .position(|&c| !is_word_char(c))
+        .map_or(chars.len(), |i| cursor + i);
+    (start < end).then_some(start..end)
+}
+
+/// Character range of the first field access in `code`, e.g. `.age` in
+/// `select(.age > 18)`
+fn placeholder_range(code: &str) -> Option<Range<usize>> {
+    let chars: Vec<char> = code.chars().collect();
+    let start = (0..chars.len()).find(|&i| {
+        chars[i] == '.'
+            && chars
+                .get(i + 1)
+                .is_some_and(|&c| c.is_alphabetic() || c == '_')
+            && (i == 0 || !is_word_char(chars[i - 1]))
+    })?;
+    let end = chars[start + 1..]
+        .iter()
+        .position(|&c| !is_word_char(c))
+        .map_or(chars.len(), |i| start + 1 + i);
+    Some(start..end)
+}
+
 #[cfg(test)]
 #[path = "tooltip_events_tests.rs"]
 mod tooltip_events_tests;
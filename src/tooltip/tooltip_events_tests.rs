@@ -33,3 +33,91 @@ fn test_handle_tooltip_toggle_preserves_current_function() {
 
     assert_eq!(state.current_function, Some("select".to_string()));
 }
+
+mod example_insertion {
+    use crate::editor::EditorMode;
+    use crate::test_utils::test_helpers::{app_with_query, key, key_with_mods};
+    use crossterm::event::{KeyCode, KeyModifiers};
+    use tui_textarea::CursorMove;
+
+    fn alt(c: char) -> crossterm::event::KeyEvent {
+        key_with_mods(KeyCode::Char(c), KeyModifiers::ALT)
+    }
+
+    #[test]
+    fn test_alt_e_selects_examples_in_turn() {
+        let mut app = app_with_query("map");
+        app.update_tooltip();
+
+        app.handle_key_event(alt('e'));
+        assert_eq!(app.tooltip.selected_example, Some(0));
+
+        app.handle_key_event(alt('e'));
+        assert_eq!(app.tooltip.selected_example, Some(1));
+    }
+
+    #[test]
+    fn test_alt_e_without_tooltip_does_nothing() {
+        let mut app = app_with_query(".name");
+        app.update_tooltip();
+
+        app.handle_key_event(alt('e'));
+
+        assert_eq!(app.tooltip.selected_example, None);
+    }
+
+    #[test]
+    fn test_alt_i_replaces_function_name_and_selects_placeholder() {
+        let mut app = app_with_query(".items | map");
+        app.update_tooltip();
+
+        app.handle_key_event(alt('e'));
+        app.handle_key_event(alt('i'));
+
+        assert_eq!(app.input.query(), ".items | map(.name)");
+        assert_eq!(
+            app.input.textarea.selection_range(),
+            Some(((0, 13), (0, 18)))
+        );
+        assert_eq!(app.input.editor_mode, EditorMode::Insert);
+        assert_eq!(app.tooltip.selected_example, None);
+    }
+
+    #[test]
+    fn test_typing_replaces_placeholder() {
+        let mut app = app_with_query("map");
+        app.update_tooltip();
+
+        app.handle_key_event(alt('e'));
+        app.handle_key_event(alt('i'));
+        app.handle_key_event(key(KeyCode::Char('x')));
+
+        assert_eq!(app.input.query(), "map(x)");
+    }
+
+    #[test]
+    fn test_alt_i_inside_parens_inserts_at_cursor() {
+        let mut app = app_with_query("map()");
+        app.input.textarea.move_cursor(CursorMove::Back);
+        app.update_tooltip();
+
+        app.handle_key_event(alt('e'));
+        app.handle_key_event(alt('e'));
+        app.handle_key_event(alt('i'));
+
+        // Second example, `map(. + 1)`, has no field to select
+        assert_eq!(app.input.query(), "map(map(. + 1))");
+        assert_eq!(app.input.textarea.selection_range(), None);
+        assert_eq!(app.input.textarea.cursor(), (0, 14));
+    }
+
+    #[test]
+    fn test_alt_i_without_selected_example_does_nothing() {
+        let mut app = app_with_query("map");
+        app.update_tooltip();
+
+        app.handle_key_event(alt('i'));
+
+        assert_eq!(app.input.query(), "map");
+    }
+}
@@ -110,8 +110,22 @@ pub fn render_popup(app: &App, frame: &mut Frame, input_area: Rect) -> Option<Re
 
     let content_width = description_width.max(max_example_width).max(title_width);
 
-    let popup_width =
-        ((content_width as u16) + TOOLTIP_BORDER_WIDTH).clamp(TOOLTIP_MIN_WIDTH, TOOLTIP_MAX_WIDTH);
+    // Key hints for bottom-center of border; the popup is widened to fit them
+    let hints: &[(&'static str, &'static str)] = if app.tooltip.selected_example.is_some() {
+        &[
+            ("Alt+I", "Insert"),
+            ("Alt+E", "Next"),
+            ("Ctrl+O", "Dismiss"),
+        ]
+    } else {
+        &[("Alt+E", "Examples"), ("Ctrl+O", "Dismiss")]
+    };
+    let dismiss_hint = theme::border_hints::build_hints(hints, theme::tooltip::BORDER);
+    let hint_width = dismiss_hint.width() as u16 + 2; // left border + right border
+
+    let popup_width = ((content_width as u16) + TOOLTIP_BORDER_WIDTH)
+        .max(hint_width)
+        .clamp(TOOLTIP_MIN_WIDTH, TOOLTIP_MAX_WIDTH);
 
     // Calculate tip wrapping - available width for tip text
     let tip_available_width = (popup_width as usize).saturating_sub(6); // borders + padding + emoji
@@ -166,21 +180,25 @@ pub fn render_popup(app: &App, frame: &mut Frame, input_area: Rect) -> Option<Re
     lines.push(Line::from(""));
 
     // Examples with two-column layout: code │ description
-    for (code, desc) in &parsed_examples {
+    for (index, (code, desc)) in parsed_examples.iter().enumerate() {
+        // The example picked for insertion is marked and highlighted
+        let (marker, code_style) = if app.tooltip.selected_example == Some(index) {
+            ("▸ ", theme::tooltip::EXAMPLE_SELECTED)
+        } else {
+            ("  ", Style::default().fg(theme::tooltip::EXAMPLE))
+        };
         if desc.is_empty() {
             // No description, just show code
-            lines.push(Line::from(vec![Span::styled(
-                format!("  {}", code),
-                Style::default().fg(theme::tooltip::EXAMPLE),
-            )]));
+            lines.push(Line::from(vec![
+                Span::raw(marker),
+                Span::styled(code.to_string(), code_style),
+            ]));
         } else {
             // Two-column: code (padded) │ description
             let padded_code = format!("{:width$}", code, width = max_code_width);
             lines.push(Line::from(vec![
-                Span::styled(
-                    format!("  {}", padded_code),
-                    Style::default().fg(theme::tooltip::EXAMPLE),
-                ),
+                Span::raw(marker),
+                Span::styled(padded_code, code_style),
                 Span::styled(" │ ", Style::default().fg(theme::tooltip::SEPARATOR)),
                 Span::styled(*desc, Style::default().fg(theme::tooltip::EXAMPLE_DESC)),
             ]));
@@ -217,10 +235,6 @@ pub fn render_popup(app: &App, frame: &mut Frame, input_area: Rect) -> Option<Re
         Span::raw(" "),
     ]);
 
-    // Build dismiss hint for bottom-center of border
-    let dismiss_hint =
-        theme::border_hints::build_hints(&[("Ctrl+T", "Dismiss")], theme::tooltip::BORDER);
-
     // Create the popup widget with purple border
     // Title on top-left, dismiss hint on bottom-center, padding inside
     let popup_widget = Paragraph::new(text).block(
@@ -1,5 +1,16 @@
 use crate::app::App;
-use crate::tooltip::{detect_function_at_cursor, detect_operator_at_cursor};
+use crate::tooltip::tooltip_content::TooltipContent;
+use crate::tooltip::{
+    detect_function_at_cursor, detect_operator_at_cursor, get_operator_content, get_tooltip_content,
+};
+
+/// Code part of an example line such as `map(.name)   # extract field`
+pub fn example_code(example: &str) -> &str {
+    match example.find('#') {
+        Some(idx) => example[..idx].trim_end(),
+        None => example,
+    }
+}
 
 /// Update tooltip state based on cursor position. Functions take priority over operators.
 pub fn update_tooltip_from_app(app: &mut App) {
@@ -28,6 +39,8 @@ pub struct TooltipState {
     pub current_function: Option<String>,
     /// Currently detected operator (if any)
     pub current_operator: Option<String>,
+    /// Example picked with Alt+E, ready to insert with Alt+I
+    pub selected_example: Option<usize>,
 }
 
 impl TooltipState {
@@ -36,6 +49,7 @@ impl TooltipState {
             enabled: auto_show,
             current_function: None,
             current_operator: None,
+            selected_example: None,
         }
     }
 
@@ -44,13 +58,44 @@ impl TooltipState {
     }
 
     pub fn set_current_function(&mut self, func: Option<String>) {
+        if self.current_function != func {
+            self.selected_example = None;
+        }
         self.current_function = func;
     }
 
     pub fn set_current_operator(&mut self, op: Option<String>) {
+        if self.current_operator != op {
+            self.selected_example = None;
+        }
         self.current_operator = op;
     }
 
+    /// Content shown in the tooltip; functions take priority over operators
+    pub fn content(&self) -> Option<&'static TooltipContent> {
+        match (&self.current_function, &self.current_operator) {
+            (Some(func), _) => get_tooltip_content(func),
+            (None, Some(op)) => get_operator_content(op),
+            (None, None) => None,
+        }
+    }
+
+    /// Select the next example of the shown tooltip, wrapping to the first
+    pub fn select_next_example(&mut self) {
+        let count = self.content().map_or(0, |content| content.examples.len());
+        self.selected_example = match self.selected_example {
+            _ if count == 0 => None,
+            Some(index) => Some((index + 1) % count),
+            None => Some(0),
+        };
+    }
+
+    /// Code of the selected example, without its `# description`
+    pub fn selected_example_code(&self) -> Option<&'static str> {
+        let example = self.content()?.examples.get(self.selected_example?)?;
+        Some(example_code(example))
+    }
+
     pub fn should_show(&self) -> bool {
         self.enabled && (self.current_function.is_some() || self.current_operator.is_some())
     }
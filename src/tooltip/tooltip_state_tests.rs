@@ -528,6 +528,60 @@ fn test_update_tooltip_cursor_after_double_dot() {
     assert!(app.tooltip.should_show());
 }
 
+#[test]
+fn test_select_next_example_cycles_and_wraps() {
+    let mut state = TooltipState::new(true);
+    state.set_current_function(Some("map".to_string()));
+    assert_eq!(state.selected_example, None);
+
+    state.select_next_example();
+    assert_eq!(state.selected_example, Some(0));
+    assert_eq!(state.selected_example_code(), Some("map(.name)"));
+
+    for _ in 0..4 {
+        state.select_next_example();
+    }
+    assert_eq!(state.selected_example, Some(0));
+}
+
+#[test]
+fn test_select_next_example_without_content_selects_nothing() {
+    let mut state = TooltipState::new(true);
+    state.select_next_example();
+    assert_eq!(state.selected_example, None);
+    assert_eq!(state.selected_example_code(), None);
+}
+
+#[test]
+fn test_changing_function_clears_selected_example() {
+    let mut state = TooltipState::new(true);
+    state.set_current_function(Some("map".to_string()));
+    state.select_next_example();
+
+    state.set_current_function(Some("map".to_string()));
+    assert_eq!(state.selected_example, Some(0));
+
+    state.set_current_function(Some("select".to_string()));
+    assert_eq!(state.selected_example, None);
+}
+
+#[test]
+fn test_operator_examples_can_be_selected() {
+    let mut state = TooltipState::new(true);
+    state.set_current_operator(Some("//".to_string()));
+    state.select_next_example();
+    assert_eq!(
+        state.selected_example_code(),
+        Some(".name // \"anonymous\"")
+    );
+}
+
+#[test]
+fn test_example_code_strips_description() {
+    assert_eq!(example_code("map(.name)   # extract field"), "map(.name)");
+    assert_eq!(example_code("keys"), "keys");
+}
+
 // ========== Property Tests for update_tooltip_from_app ==========
 // **Feature: function-tooltip, Property 1: Tooltip visibility follows cursor on functions**
 // *For any* query string containing jq functions and any cursor position, when tooltip is enabled:
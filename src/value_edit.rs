@@ -0,0 +1,16 @@
+//! Editing values in the results
+//!
+//! `e` in the results pane opens a small editor over the cursor line, for
+//! the value on it and, for object members, the key. Instead of changing
+//! the result directly, the edit is turned into a jq update such as
+//! `.a.b = "new"` piped after the query, so the change is reproducible and
+//! can be undone by editing the query.
+
+mod value_edit_events;
+mod value_edit_query;
+mod value_edit_render;
+mod value_edit_state;
+
+pub use value_edit_events::{handle_value_edit_key, open_value_edit};
+pub use value_edit_render::render_value_edit;
+pub use value_edit_state::ValueEditState;
@@ -0,0 +1,89 @@
+use ratatui::crossterm::event::{KeyCode, KeyEvent};
+use tui_textarea::Input;
+
+use super::value_edit_query::{EditTarget, append_update, is_stream};
+use crate::app::App;
+use crate::query::language::QueryLanguage;
+use crate::session::SessionScroll;
+
+/// Edit the key or value on the results cursor line
+pub fn open_value_edit(app: &mut App) {
+    let Some(query_state) = &app.query else {
+        return;
+    };
+    if query_state.language != QueryLanguage::Jq {
+        app.notification
+            .show_warning("Editing values is only available for jq queries");
+        return;
+    }
+    let Some(output) = query_state.last_successful_result_text() else {
+        app.notification.show_warning("No result to edit yet");
+        return;
+    };
+    if is_stream(&output) {
+        app.notification
+            .show_warning("The query prints several values; collect them with [...] to edit one");
+        return;
+    }
+
+    let line = app.results_cursor_result_line();
+    let Some(target) = EditTarget::at_line(&output, line) else {
+        app.notification
+            .show_warning("Nothing to edit on this line");
+        return;
+    };
+    if app.redaction.enabled
+        && target
+            .key
+            .as_deref()
+            .is_some_and(|key| app.redaction.is_sensitive(key))
+    {
+        app.notification
+            .show_warning("Show masked values with Alt+R to edit them");
+        return;
+    }
+    app.value_edit.open(target);
+}
+
+pub fn handle_value_edit_key(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Esc => app.value_edit.close(),
+        KeyCode::Tab | KeyCode::BackTab => app.value_edit.toggle_field(),
+        KeyCode::Enter => match app.value_edit.update() {
+            Ok(Some(update)) => {
+                app.value_edit.close();
+                apply_update(app, &update);
+            }
+            Ok(None) => app.value_edit.close(),
+            Err(message) => app.notification.show_warning(&message),
+        },
+        _ => {
+            app.value_edit.active_textarea_mut().input(Input::from(key));
+        }
+    }
+}
+
+/// Pipe `update` after the query that produced the result and run it
+///
+/// The results cursor stays where it is, to go on editing nearby lines.
+fn apply_update(app: &mut App, update: &str) {
+    let Some(base_query) = app
+        .query
+        .as_ref()
+        .and_then(|query_state| query_state.base_query_for_suggestions.clone())
+    else {
+        return;
+    };
+    let query = append_update(&base_query, update);
+    let scroll = SessionScroll {
+        offset: app.results_scroll.offset,
+        h_offset: app.results_scroll.h_offset,
+        cursor_line: app.results_cursor_result_line() as u32,
+    };
+    crate::editor::editor_events::replace_query_and_execute(app, &query);
+    app.session_scroll_pending = Some(scroll);
+}
+
+#[cfg(test)]
+#[path = "value_edit_events_tests.rs"]
+mod value_edit_events_tests;
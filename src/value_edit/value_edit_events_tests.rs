@@ -0,0 +1,135 @@
+//! Tests for value_edit_events
+
+use super::*;
+use crate::app::Focus;
+use crate::test_utils::test_helpers::{key, test_app, wait_for_query_completion};
+
+const INPUT: &str = r#"{"spec": {"replicas": 1, "image": "api:1"}, "password": "hunter2"}"#;
+
+fn app_on_line(query: &str, line: u32) -> App {
+    let mut app = test_app(INPUT);
+    app.input.textarea.insert_str(query);
+    app.query.as_mut().unwrap().execute(query);
+    assert!(wait_for_query_completion(&mut app, 2000));
+    app.focus = Focus::ResultsPane;
+    app.results_cursor.update_total_lines(100);
+    app.results_cursor.move_to_line(line);
+    app
+}
+
+fn replace_field(app: &mut App, text: &str) {
+    app.value_edit.active_textarea_mut().delete_line_by_head();
+    for c in text.chars() {
+        handle_value_edit_key(app, key(KeyCode::Char(c)));
+    }
+}
+
+#[test]
+fn test_e_opens_editor_on_cursor_line() {
+    let mut app = app_on_line(".", 2);
+
+    app.handle_key_event(key(KeyCode::Char('e')));
+
+    assert!(app.value_edit.is_visible());
+    assert_eq!(app.value_edit.key(), "replicas");
+    assert_eq!(app.value_edit.value(), "1");
+}
+
+#[test]
+fn test_enter_appends_update_to_query_and_runs_it() {
+    let mut app = app_on_line(".", 2);
+    open_value_edit(&mut app);
+    replace_field(&mut app, "3");
+
+    handle_value_edit_key(&mut app, key(KeyCode::Enter));
+
+    assert!(!app.value_edit.is_visible());
+    assert_eq!(app.query(), ".spec.replicas = 3");
+    assert!(wait_for_query_completion(&mut app, 2000));
+    let result = app.query.as_ref().unwrap().result.as_ref().unwrap();
+    assert!(result.contains("\"replicas\": 3"));
+}
+
+#[test]
+fn test_update_keeps_results_cursor_line() {
+    let mut app = app_on_line(".", 2);
+    open_value_edit(&mut app);
+    replace_field(&mut app, "3");
+
+    handle_value_edit_key(&mut app, key(KeyCode::Enter));
+
+    let scroll = app.session_scroll_pending.expect("position kept");
+    assert_eq!(scroll.cursor_line, 2);
+}
+
+#[test]
+fn test_update_is_piped_after_query() {
+    let mut app = app_on_line(".spec", 2);
+    open_value_edit(&mut app);
+    replace_field(&mut app, "api:2");
+
+    handle_value_edit_key(&mut app, key(KeyCode::Enter));
+
+    assert_eq!(app.query(), ".spec | .image = \"api:2\"");
+}
+
+#[test]
+fn test_unchanged_edit_keeps_query() {
+    let mut app = app_on_line(".", 2);
+    open_value_edit(&mut app);
+
+    handle_value_edit_key(&mut app, key(KeyCode::Enter));
+
+    assert!(!app.value_edit.is_visible());
+    assert_eq!(app.query(), ".");
+}
+
+#[test]
+fn test_esc_cancels_edit() {
+    let mut app = app_on_line(".", 2);
+    open_value_edit(&mut app);
+    replace_field(&mut app, "3");
+
+    handle_value_edit_key(&mut app, key(KeyCode::Esc));
+
+    assert!(!app.value_edit.is_visible());
+    assert_eq!(app.query(), ".");
+}
+
+#[test]
+fn test_closing_line_warns() {
+    let mut app = app_on_line(".", 4);
+    open_value_edit(&mut app);
+
+    assert!(!app.value_edit.is_visible());
+    assert_eq!(
+        app.notification.current_message(),
+        Some("Nothing to edit on this line")
+    );
+}
+
+#[test]
+fn test_stream_result_warns() {
+    let mut app = app_on_line(".spec[]", 0);
+    open_value_edit(&mut app);
+
+    assert!(!app.value_edit.is_visible());
+    assert!(
+        app.notification
+            .current_message()
+            .is_some_and(|message| message.contains("several values"))
+    );
+}
+
+#[test]
+fn test_masked_value_is_not_opened() {
+    let mut app = app_on_line(".", 5);
+    app.redaction.enabled = true;
+    open_value_edit(&mut app);
+
+    assert!(!app.value_edit.is_visible());
+
+    app.redaction.enabled = false;
+    open_value_edit(&mut app);
+    assert_eq!(app.value_edit.value(), "\"hunter2\"");
+}
@@ -0,0 +1,118 @@
+use serde_json::Value;
+
+use crate::query::language::jq_field;
+use crate::results::line_path::{self, LinePath, split_member};
+
+/// What can be edited on a result line
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EditTarget {
+    /// Path of the line's value within the result, e.g. `.items[2].name`
+    pub path: String,
+    /// Key of the object member on the line; None for array elements and
+    /// top-level values
+    pub key: Option<String>,
+    /// JSON text of the value; None when the line opens an object or array
+    pub value: Option<String>,
+}
+
+impl EditTarget {
+    /// The member or element on `line` of the pretty-printed `output`
+    ///
+    /// None for lines closing an object or array and lines that are not
+    /// part of pretty-printed JSON.
+    pub fn at_line(output: &str, line: usize) -> Option<Self> {
+        let LinePath { path, .. } = line_path::path_at_line(output, line)?;
+        let trimmed = output.lines().nth(line)?.trim();
+        let content = trimmed.strip_suffix(',').unwrap_or(trimmed);
+        if content == "}" || content == "]" {
+            return None;
+        }
+
+        let (key, value) = match split_member(content) {
+            Some((key, value)) => (Some(key), value),
+            None => (None, content),
+        };
+        let value = (value != "{" && value != "[").then(|| value.to_string());
+        Some(Self { path, key, value })
+    }
+}
+
+/// Whether jq printed more than one value, where one path can't tell them
+/// apart
+pub fn is_stream(output: &str) -> bool {
+    let last_line = output.lines().count().saturating_sub(1);
+    line_path::path_at_line(output, last_line).is_some_and(|at| at.value_index > 0)
+}
+
+/// The jq value for what was typed in the value field
+///
+/// Valid JSON is kept as is, in compact form; anything else is taken as a
+/// string, so `hello` doesn't need quoting.
+pub fn value_literal(text: &str) -> String {
+    let text = text.trim();
+    match serde_json::from_str::<Value>(text) {
+        Ok(value) => value.to_string(),
+        Err(_) => Value::String(text.to_string()).to_string(),
+    }
+}
+
+/// The jq update making the edit, e.g. `.a.b = "new"`
+///
+/// `key` and `value` are the texts of the fields, for the parts of the line
+/// that can be edited. Returns None when nothing was changed.
+pub fn update_expression(
+    target: &EditTarget,
+    key: Option<&str>,
+    value: Option<&str>,
+) -> Result<Option<String>, String> {
+    let mut updates = Vec::new();
+
+    if let (Some(old), Some(new)) = (&target.value, value) {
+        let literal = value_literal(new);
+        if !is_same_value(old, &literal) {
+            updates.push(format!("{} = {}", target.path, literal));
+        }
+    }
+
+    if let (Some(old), Some(new)) = (&target.key, key) {
+        if new.is_empty() {
+            return Err("The key can't be empty".to_string());
+        }
+        if new != old {
+            updates.push(rename_expression(&target.path, old, new));
+        }
+    }
+
+    Ok((!updates.is_empty()).then(|| updates.join(" | ")))
+}
+
+/// Pipe `update` after `base_query`, the query that produced the result
+pub fn append_update(base_query: &str, update: &str) -> String {
+    match base_query.trim() {
+        "" | "." => update.to_string(),
+        base => format!("{} | {}", base, update),
+    }
+}
+
+/// Rename the member `old` at `path` to `new`, keeping the order of keys
+fn rename_expression(path: &str, old: &str, new: &str) -> String {
+    let rename = format!(
+        "with_entries(if .key == {} then .key = {} else . end)",
+        Value::String(old.to_string()),
+        Value::String(new.to_string())
+    );
+    let parent = path.strip_suffix(&jq_field(old)).unwrap_or_default();
+    match parent {
+        "" | "." => rename,
+        parent => format!("{} |= {}", parent, rename),
+    }
+}
+
+fn is_same_value(old: &str, literal: &str) -> bool {
+    let parse = |text| serde_json::from_str::<Value>(text).ok();
+    parse(old).is_some() && parse(old) == parse(literal)
+}
+
+#[cfg(test)]
+#[path = "value_edit_query_tests.rs"]
+mod value_edit_query_tests;
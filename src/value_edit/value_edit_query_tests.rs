@@ -0,0 +1,142 @@
+//! Tests for value_edit_query
+
+use super::*;
+
+const OUTPUT: &str = r#"{
+  "name": "api",
+  "spec": {
+    "replicas": 1,
+    "my key": true
+  },
+  "tags": [
+    "web"
+  ]
+}"#;
+
+fn target(line: usize) -> EditTarget {
+    EditTarget::at_line(OUTPUT, line).unwrap()
+}
+
+#[test]
+fn test_target_of_member_with_scalar_value() {
+    assert_eq!(
+        target(1),
+        EditTarget {
+            path: ".name".to_string(),
+            key: Some("name".to_string()),
+            value: Some("\"api\"".to_string()),
+        }
+    );
+}
+
+#[test]
+fn test_target_of_member_opening_object_has_no_value() {
+    let target = target(2);
+    assert_eq!(target.path, ".spec");
+    assert_eq!(target.key.as_deref(), Some("spec"));
+    assert_eq!(target.value, None);
+}
+
+#[test]
+fn test_target_of_array_element_has_no_key() {
+    let target = target(7);
+    assert_eq!(target.path, ".tags[0]");
+    assert_eq!(target.key, None);
+    assert_eq!(target.value.as_deref(), Some("\"web\""));
+}
+
+#[test]
+fn test_closing_lines_have_no_target() {
+    assert_eq!(EditTarget::at_line(OUTPUT, 5), None);
+    assert_eq!(EditTarget::at_line(OUTPUT, 9), None);
+}
+
+#[test]
+fn test_is_stream() {
+    assert!(!is_stream(OUTPUT));
+    assert!(is_stream("{\n  \"a\": 1\n}\n{\n  \"a\": 2\n}"));
+    assert!(is_stream("1\n2"));
+}
+
+#[test]
+fn test_value_literal_keeps_json_and_quotes_other_text() {
+    assert_eq!(value_literal("3"), "3");
+    assert_eq!(value_literal(" \"new\" "), "\"new\"");
+    assert_eq!(value_literal("{\"a\": [1, 2]}"), "{\"a\":[1,2]}");
+    assert_eq!(value_literal("null"), "null");
+    assert_eq!(value_literal("hello world"), "\"hello world\"");
+    assert_eq!(value_literal("say \"hi\""), "\"say \\\"hi\\\"\"");
+}
+
+#[test]
+fn test_value_update() {
+    assert_eq!(
+        update_expression(&target(3), Some("replicas"), Some("3")),
+        Ok(Some(".spec.replicas = 3".to_string()))
+    );
+    assert_eq!(
+        update_expression(&target(7), None, Some("app")),
+        Ok(Some(".tags[0] = \"app\"".to_string()))
+    );
+}
+
+#[test]
+fn test_unchanged_edit_has_no_update() {
+    assert_eq!(
+        update_expression(&target(1), Some("name"), Some("\"api\"")),
+        Ok(None)
+    );
+    // The same value written differently is no change either
+    assert_eq!(update_expression(&target(3), None, Some(" 1 ")), Ok(None));
+}
+
+#[test]
+fn test_rename_keeps_key_order() {
+    assert_eq!(
+        update_expression(&target(3), Some("count"), Some("1")),
+        Ok(Some(
+            ".spec |= with_entries(if .key == \"replicas\" then .key = \"count\" else . end)"
+                .to_string()
+        ))
+    );
+}
+
+#[test]
+fn test_rename_at_top_level_and_of_quoted_key() {
+    assert_eq!(
+        update_expression(&target(2), Some("config"), None),
+        Ok(Some(
+            "with_entries(if .key == \"spec\" then .key = \"config\" else . end)".to_string()
+        ))
+    );
+    assert_eq!(
+        update_expression(&target(4), Some("enabled"), Some("true")),
+        Ok(Some(
+            ".spec |= with_entries(if .key == \"my key\" then .key = \"enabled\" else . end)"
+                .to_string()
+        ))
+    );
+}
+
+#[test]
+fn test_value_is_set_before_renaming() {
+    assert_eq!(
+        update_expression(&target(1), Some("title"), Some("web")),
+        Ok(Some(
+            ".name = \"web\" | with_entries(if .key == \"name\" then .key = \"title\" else . end)"
+                .to_string()
+        ))
+    );
+}
+
+#[test]
+fn test_empty_key_is_refused() {
+    assert!(update_expression(&target(1), Some(""), Some("\"api\"")).is_err());
+}
+
+#[test]
+fn test_append_update() {
+    assert_eq!(append_update("", ".a = 1"), ".a = 1");
+    assert_eq!(append_update(" . ", ".a = 1"), ".a = 1");
+    assert_eq!(append_update(".items", ".[0] = 1"), ".items | .[0] = 1");
+}
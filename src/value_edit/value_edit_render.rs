@@ -0,0 +1,144 @@
+use ratatui::{
+    Frame,
+    layout::{Constraint, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Paragraph},
+};
+use tui_textarea::TextArea;
+
+use super::value_edit_state::EditField;
+use crate::app::App;
+use crate::theme;
+use crate::widgets::popup;
+use crate::widgets::text_width::display_width;
+
+const EDITOR_HEIGHT: u16 = 3;
+
+fn build_hints(has_both_fields: bool) -> Line<'static> {
+    let mut hints = Vec::new();
+    if has_both_fields {
+        hints.push(("Tab", "Key/Value"));
+    }
+    hints.extend([("Enter", "Apply"), ("Esc", "Cancel")]);
+    theme::border_hints::build_hints(&hints, theme::value_edit::BORDER)
+}
+
+fn render_textarea(
+    textarea: &mut TextArea<'static>,
+    is_active: bool,
+    frame: &mut Frame,
+    area: Rect,
+) {
+    let color = if is_active {
+        theme::value_edit::TEXT
+    } else {
+        theme::value_edit::INACTIVE_TEXT
+    };
+    textarea.set_style(Style::default().fg(color).bg(theme::value_edit::BACKGROUND));
+    textarea.set_cursor_style(if is_active {
+        Style::default().add_modifier(Modifier::REVERSED)
+    } else {
+        Style::default()
+    });
+    frame.render_widget(&*textarea, area);
+}
+
+/// Where the editor goes: over the results cursor line, its field on the
+/// line itself
+fn editor_area(app: &App) -> Option<Rect> {
+    let pane = app.layout_regions.results_pane?;
+    if pane.width < 24 || pane.height < EDITOR_HEIGHT {
+        return None;
+    }
+    let cursor_row = app
+        .results_cursor
+        .cursor_line()
+        .saturating_sub(app.results_scroll.offset as u32) as u16;
+    let y = (pane.y + cursor_row)
+        .min(pane.bottom().saturating_sub(EDITOR_HEIGHT))
+        .max(pane.y);
+    Some(Rect {
+        x: pane.x,
+        y,
+        width: pane.width,
+        height: EDITOR_HEIGHT,
+    })
+}
+
+/// Render the inline editor over the results cursor line
+pub fn render_value_edit(app: &mut App, frame: &mut Frame) -> Option<Rect> {
+    let area = editor_area(app)?;
+    let target = app.value_edit.target()?.clone();
+    popup::clear_area(frame, area);
+
+    let has_both_fields = target.key.is_some() && target.value.is_some();
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(Line::from(Span::styled(
+            format!(" Edit {} ", target.path),
+            theme::value_edit::TITLE,
+        )))
+        .title_bottom(build_hints(has_both_fields).right_aligned())
+        .border_style(Style::default().fg(theme::value_edit::BORDER))
+        .style(Style::default().bg(theme::value_edit::BACKGROUND));
+    let content_area = block.inner(area);
+    frame.render_widget(block, area);
+
+    let field = app.value_edit.field();
+    if target.key.is_none() {
+        render_textarea(
+            app.value_edit.value_textarea_mut(),
+            true,
+            frame,
+            content_area,
+        );
+        return Some(area);
+    }
+
+    // Room for the key as typed and the cursor after it
+    let key_width = (display_width(app.value_edit.key()) as u16 + 1).min(content_area.width / 2);
+    let [key_area, separator_area, value_area] = Layout::horizontal([
+        Constraint::Length(key_width),
+        Constraint::Length(2),
+        Constraint::Min(1),
+    ])
+    .areas(content_area);
+
+    render_textarea(
+        app.value_edit.key_textarea_mut(),
+        field == EditField::Key,
+        frame,
+        key_area,
+    );
+    frame.render_widget(
+        Paragraph::new(Span::styled(
+            ": ",
+            Style::default().fg(theme::value_edit::SEPARATOR),
+        )),
+        separator_area,
+    );
+    if target.value.is_some() {
+        render_textarea(
+            app.value_edit.value_textarea_mut(),
+            field == EditField::Value,
+            frame,
+            value_area,
+        );
+    } else {
+        frame.render_widget(
+            Paragraph::new(Span::styled(
+                "…",
+                Style::default().fg(theme::value_edit::INACTIVE_TEXT),
+            )),
+            value_area,
+        );
+    }
+
+    Some(area)
+}
+
+#[cfg(test)]
+#[path = "value_edit_render_tests.rs"]
+mod value_edit_render_tests;
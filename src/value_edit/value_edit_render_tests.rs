@@ -0,0 +1,74 @@
+//! Tests for value_edit_render
+
+use super::*;
+use crate::test_utils::test_helpers::test_app;
+use crate::value_edit::value_edit_query::EditTarget;
+use insta::assert_snapshot;
+use ratatui::Terminal;
+use ratatui::backend::TestBackend;
+
+fn render_to_string(app: &mut App, width: u16, height: u16) -> String {
+    let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+    terminal
+        .draw(|f| {
+            render_value_edit(app, f);
+        })
+        .unwrap();
+    terminal.backend().to_string()
+}
+
+fn app_editing(target: EditTarget) -> App {
+    let mut app = test_app(r#"{"a": 1}"#);
+    app.layout_regions.results_pane = Some(Rect::new(0, 0, 60, 8));
+    app.results_cursor.update_total_lines(10);
+    app.results_cursor.move_to_line(2);
+    app.value_edit.open(target);
+    app
+}
+
+#[test]
+fn snapshot_member_editor_over_cursor_line() {
+    let output = "{\n  \"spec\": {\n    \"replicas\": 1\n  }\n}";
+    let mut app = app_editing(EditTarget::at_line(output, 2).unwrap());
+
+    assert_snapshot!(render_to_string(&mut app, 60, 8));
+}
+
+#[test]
+fn snapshot_element_editor_has_only_value() {
+    let mut app = app_editing(EditTarget {
+        path: ".tags[0]".to_string(),
+        key: None,
+        value: Some("\"web\"".to_string()),
+    });
+
+    assert_snapshot!(render_to_string(&mut app, 60, 8));
+}
+
+#[test]
+fn test_editor_is_kept_inside_pane_at_bottom() {
+    let mut app = app_editing(EditTarget {
+        path: ".a".to_string(),
+        key: Some("a".to_string()),
+        value: Some("1".to_string()),
+    });
+    app.results_cursor.move_to_line(9);
+
+    let mut terminal = Terminal::new(TestBackend::new(60, 8)).unwrap();
+    let mut area = None;
+    terminal
+        .draw(|f| area = render_value_edit(&mut app, f))
+        .unwrap();
+    assert_eq!(area, Some(Rect::new(0, 5, 60, 3)));
+}
+
+#[test]
+fn snapshot_wide_key_keeps_its_columns() {
+    let mut app = app_editing(EditTarget {
+        path: ".名前".to_string(),
+        key: Some("名前".to_string()),
+        value: Some("\"ada\"".to_string()),
+    });
+
+    assert_snapshot!(render_to_string(&mut app, 60, 8));
+}
@@ -0,0 +1,130 @@
+use ratatui::style::{Modifier, Style};
+use tui_textarea::TextArea;
+
+use super::value_edit_query::{EditTarget, update_expression};
+
+/// Part of the line being edited
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EditField {
+    Key,
+    Value,
+}
+
+fn create_textarea(text: &str) -> TextArea<'static> {
+    let mut textarea = TextArea::new(vec![text.to_string()]);
+    textarea.set_cursor_line_style(Style::default());
+    textarea.set_cursor_style(Style::default().add_modifier(Modifier::REVERSED));
+    textarea.move_cursor(tui_textarea::CursorMove::End);
+    textarea
+}
+
+fn text<'a>(textarea: &'a TextArea<'static>) -> &'a str {
+    textarea.lines()[0].as_str()
+}
+
+/// Inline editor for the key or value on a results line (`e`)
+pub struct ValueEditState {
+    /// Line being edited; None while closed
+    target: Option<EditTarget>,
+    field: EditField,
+    key_textarea: TextArea<'static>,
+    value_textarea: TextArea<'static>,
+}
+
+impl Default for ValueEditState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ValueEditState {
+    pub fn new() -> Self {
+        Self {
+            target: None,
+            field: EditField::Value,
+            key_textarea: create_textarea(""),
+            value_textarea: create_textarea(""),
+        }
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.target.is_some()
+    }
+
+    /// Start editing `target`, on its value when it has one
+    pub fn open(&mut self, target: EditTarget) {
+        self.key_textarea = create_textarea(target.key.as_deref().unwrap_or_default());
+        self.value_textarea = create_textarea(target.value.as_deref().unwrap_or_default());
+        self.field = if target.value.is_some() {
+            EditField::Value
+        } else {
+            EditField::Key
+        };
+        self.target = Some(target);
+    }
+
+    pub fn close(&mut self) {
+        self.target = None;
+    }
+
+    pub fn target(&self) -> Option<&EditTarget> {
+        self.target.as_ref()
+    }
+
+    pub fn field(&self) -> EditField {
+        self.field
+    }
+
+    /// Switch between the key and the value, when the line has both
+    pub fn toggle_field(&mut self) {
+        let Some(target) = &self.target else {
+            return;
+        };
+        if target.key.is_some() && target.value.is_some() {
+            self.field = match self.field {
+                EditField::Key => EditField::Value,
+                EditField::Value => EditField::Key,
+            };
+        }
+    }
+
+    pub fn key(&self) -> &str {
+        text(&self.key_textarea)
+    }
+
+    pub fn value(&self) -> &str {
+        text(&self.value_textarea)
+    }
+
+    pub fn key_textarea_mut(&mut self) -> &mut TextArea<'static> {
+        &mut self.key_textarea
+    }
+
+    pub fn value_textarea_mut(&mut self) -> &mut TextArea<'static> {
+        &mut self.value_textarea
+    }
+
+    /// Textarea of the field being edited
+    pub fn active_textarea_mut(&mut self) -> &mut TextArea<'static> {
+        match self.field {
+            EditField::Key => &mut self.key_textarea,
+            EditField::Value => &mut self.value_textarea,
+        }
+    }
+
+    /// The jq update for the edit so far; None when nothing was changed
+    pub fn update(&self) -> Result<Option<String>, String> {
+        let Some(target) = &self.target else {
+            return Ok(None);
+        };
+        update_expression(
+            target,
+            target.key.as_ref().map(|_| self.key()),
+            target.value.as_ref().map(|_| self.value()),
+        )
+    }
+}
+
+#[cfg(test)]
+#[path = "value_edit_state_tests.rs"]
+mod value_edit_state_tests;
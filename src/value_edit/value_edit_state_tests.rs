@@ -0,0 +1,66 @@
+//! Tests for value_edit_state
+
+use super::*;
+
+fn member() -> EditTarget {
+    EditTarget {
+        path: ".name".to_string(),
+        key: Some("name".to_string()),
+        value: Some("\"api\"".to_string()),
+    }
+}
+
+#[test]
+fn test_open_fills_fields_and_starts_on_value() {
+    let mut state = ValueEditState::new();
+    state.open(member());
+
+    assert!(state.is_visible());
+    assert_eq!(state.key(), "name");
+    assert_eq!(state.value(), "\"api\"");
+    assert_eq!(state.field(), EditField::Value);
+    assert_eq!(state.update(), Ok(None));
+}
+
+#[test]
+fn test_container_member_starts_on_key_and_stays_there() {
+    let mut state = ValueEditState::new();
+    state.open(EditTarget {
+        path: ".spec".to_string(),
+        key: Some("spec".to_string()),
+        value: None,
+    });
+
+    assert_eq!(state.field(), EditField::Key);
+    state.toggle_field();
+    assert_eq!(state.field(), EditField::Key);
+}
+
+#[test]
+fn test_typing_in_active_field_changes_update() {
+    let mut state = ValueEditState::new();
+    state.open(member());
+    state.active_textarea_mut().delete_line_by_head();
+    state.active_textarea_mut().insert_str("web");
+
+    state.toggle_field();
+    assert_eq!(state.field(), EditField::Key);
+    state.active_textarea_mut().insert_str("s");
+
+    assert_eq!(
+        state.update(),
+        Ok(Some(
+            ".name = \"web\" | with_entries(if .key == \"name\" then .key = \"names\" else . end)"
+                .to_string()
+        ))
+    );
+}
+
+#[test]
+fn test_close_hides_editor() {
+    let mut state = ValueEditState::new();
+    state.open(member());
+    state.close();
+    assert!(!state.is_visible());
+    assert_eq!(state.update(), Ok(None));
+}
@@ -0,0 +1,6 @@
+pub mod variables_events;
+pub mod variables_render;
+mod variables_state;
+
+#[allow(unused_imports)]
+pub use variables_state::{VariableField, VariablesMode, VariablesState};
@@ -0,0 +1,84 @@
+use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use tui_textarea::Input;
+
+use super::variables_state::VariablesMode;
+use crate::app::App;
+
+pub fn handle_variables_key(app: &mut App, key: KeyEvent) {
+    match app.variables.mode() {
+        VariablesMode::Browse => handle_browse_mode(app, key),
+        VariablesMode::Edit { .. } => handle_edit_mode(app, key),
+    }
+}
+
+fn is_toggle_key(key: &KeyEvent) -> bool {
+    key.code == KeyCode::Char('v') && key.modifiers.contains(KeyModifiers::ALT)
+}
+
+fn handle_browse_mode(app: &mut App, key: KeyEvent) {
+    if is_toggle_key(&key) {
+        app.variables.close();
+        return;
+    }
+
+    match key.code {
+        KeyCode::Esc => app.variables.close(),
+        KeyCode::Up | KeyCode::Char('k') => app.variables.select_prev(),
+        KeyCode::Down | KeyCode::Char('j') => app.variables.select_next(),
+        KeyCode::Enter => app.variables.enter_edit_mode(),
+        KeyCode::Char('e') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            app.variables.enter_edit_mode()
+        }
+        KeyCode::Char('n') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            app.variables.enter_create_mode()
+        }
+        KeyCode::Char('d')
+            if key.modifiers.contains(KeyModifiers::CONTROL) && app.variables.delete_selected() =>
+        {
+            apply_variables(app);
+        }
+        KeyCode::Char('t') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            match app.variables.toggle_selected_kind() {
+                Ok(()) => apply_variables(app),
+                Err(e) => app.notification.show_warning(&e),
+            }
+        }
+        _ => {}
+    }
+}
+
+fn handle_edit_mode(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Esc => app.variables.cancel_edit(),
+        KeyCode::Enter => match app.variables.save_edit() {
+            Ok(()) => apply_variables(app),
+            Err(e) => app.notification.show_warning(&e),
+        },
+        KeyCode::Tab | KeyCode::BackTab => app.variables.next_field(),
+        KeyCode::Char('t') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            app.variables.toggle_edit_kind()
+        }
+        _ => {
+            if let Some(textarea) = app.variables.active_textarea_mut() {
+                textarea.input(Input::from(key));
+            }
+        }
+    }
+}
+
+/// Pass the panel's variables to the query and re-run it
+pub fn apply_variables(app: &mut App) {
+    let variables = app.variables.variables().to_vec();
+    let Some(query_state) = &mut app.query else {
+        return;
+    };
+    query_state.set_variables(variables);
+    app.timeline.clear_snapshots();
+    app.query_tabs.clear_snapshots();
+    crate::editor::editor_events::execute_query(app);
+    app.debouncer.mark_executed();
+}
+
+#[cfg(test)]
+#[path = "variables_events_tests.rs"]
+mod variables_events_tests;
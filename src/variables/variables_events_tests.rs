@@ -0,0 +1,163 @@
+//! Tests for variables panel key handling
+
+use crate::query::variables::{QueryVariable, VariableKind};
+use crate::test_utils::test_helpers::{
+    app_with_query, key, key_with_mods, wait_for_query_completion,
+};
+use crate::variables::VariablesMode;
+use ratatui::crossterm::event::{KeyCode, KeyModifiers};
+
+fn alt_v() -> ratatui::crossterm::event::KeyEvent {
+    key_with_mods(KeyCode::Char('v'), KeyModifiers::ALT)
+}
+
+fn ctrl(c: char) -> ratatui::crossterm::event::KeyEvent {
+    key_with_mods(KeyCode::Char(c), KeyModifiers::CONTROL)
+}
+
+fn type_text(app: &mut crate::app::App, text: &str) {
+    for c in text.chars() {
+        app.handle_key_event(key(KeyCode::Char(c)));
+    }
+}
+
+fn result_text(app: &crate::app::App) -> String {
+    app.query.as_ref().unwrap().result.clone().unwrap()
+}
+
+#[test]
+fn test_alt_v_opens_panel_in_create_mode() {
+    let mut app = app_with_query(".");
+    app.handle_key_event(alt_v());
+
+    assert!(app.variables.is_visible());
+    assert!(app.variables.is_editing());
+}
+
+#[test]
+fn test_defined_variable_is_used_by_query() {
+    let mut app = app_with_query("");
+    app.handle_key_event(alt_v());
+    type_text(&mut app, "field");
+    app.handle_key_event(key(KeyCode::Tab));
+    type_text(&mut app, "city");
+    app.handle_key_event(key(KeyCode::Enter));
+
+    assert_eq!(app.variables.mode(), VariablesMode::Browse);
+    app.handle_key_event(key(KeyCode::Esc));
+    assert!(!app.variables.is_visible());
+
+    type_text(&mut app, ".[$field]");
+    crate::editor::editor_events::execute_query(&mut app);
+    assert!(wait_for_query_completion(&mut app, 5000));
+    assert!(result_text(&app).contains("NYC"));
+}
+
+#[test]
+fn test_changing_value_reruns_query() {
+    let mut app = app_with_query("");
+    app.variables.set_variables(vec![QueryVariable::new(
+        "field",
+        "city",
+        VariableKind::String,
+    )]);
+    crate::variables::variables_events::apply_variables(&mut app);
+    type_text(&mut app, ".[$field]");
+    crate::editor::editor_events::execute_query(&mut app);
+    assert!(wait_for_query_completion(&mut app, 5000));
+    assert!(result_text(&app).contains("NYC"));
+
+    app.handle_key_event(alt_v());
+    app.handle_key_event(key(KeyCode::Enter));
+    for _ in 0.."city".len() {
+        app.handle_key_event(key(KeyCode::Backspace));
+    }
+    type_text(&mut app, "name");
+    app.handle_key_event(key(KeyCode::Enter));
+
+    assert!(wait_for_query_completion(&mut app, 5000));
+    assert!(result_text(&app).contains("test"));
+}
+
+#[test]
+fn test_incremental_base_is_not_reused_after_variable_change() {
+    let mut app = app_with_query("");
+    app.variables
+        .set_variables(vec![QueryVariable::new("n", "0", VariableKind::Json)]);
+    crate::variables::variables_events::apply_variables(&mut app);
+
+    type_text(&mut app, ".services[$n]");
+    crate::editor::editor_events::execute_query(&mut app);
+    assert!(wait_for_query_completion(&mut app, 5000));
+
+    // Now bind a different index: the extended query must not run against
+    // the result computed with the old value
+    app.variables
+        .set_variables(vec![QueryVariable::new("n", "1", VariableKind::Json)]);
+    crate::variables::variables_events::apply_variables(&mut app);
+    type_text(&mut app, ".name");
+    crate::editor::editor_events::execute_query(&mut app);
+    assert!(wait_for_query_completion(&mut app, 5000));
+    assert_eq!(result_text(&app).trim(), "null");
+}
+
+#[test]
+fn test_invalid_json_shows_warning() {
+    let mut app = app_with_query(".");
+    app.handle_key_event(alt_v());
+    type_text(&mut app, "n");
+    app.handle_key_event(key(KeyCode::Tab));
+    type_text(&mut app, "{oops");
+    app.handle_key_event(ctrl('t'));
+    app.handle_key_event(key(KeyCode::Enter));
+
+    assert!(app.variables.is_editing());
+    assert!(app.notification.current().is_some());
+    assert!(app.variables.variables().is_empty());
+}
+
+#[test]
+fn test_delete_variable() {
+    let mut app = app_with_query(".");
+    app.variables
+        .set_variables(vec![QueryVariable::new("a", "1", VariableKind::String)]);
+    app.handle_key_event(alt_v());
+    app.handle_key_event(ctrl('d'));
+
+    assert!(app.variables.variables().is_empty());
+    assert!(app.query.as_ref().unwrap().variables().is_empty());
+}
+
+#[test]
+fn test_browse_keys_do_not_edit_query() {
+    let mut app = app_with_query(".a");
+    app.variables
+        .set_variables(vec![QueryVariable::new("a", "1", VariableKind::String)]);
+    app.handle_key_event(alt_v());
+    app.handle_key_event(key(KeyCode::Char('x')));
+    assert_eq!(app.input.query(), ".a");
+
+    app.handle_key_event(alt_v());
+    assert!(!app.variables.is_visible());
+}
+
+#[test]
+fn test_bound_variables_are_autocompleted() {
+    let mut app = app_with_query("");
+    app.variables.set_variables(vec![QueryVariable::new(
+        "field",
+        "city",
+        VariableKind::String,
+    )]);
+
+    type_text(&mut app, ".[$fi");
+    app.update_autocomplete();
+
+    assert!(app.autocomplete.is_visible());
+    assert!(
+        app.autocomplete
+            .suggestions()
+            .iter()
+            .any(|s| s.text == "$field")
+    );
+}
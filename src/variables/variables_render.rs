@@ -0,0 +1,183 @@
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Layout, Rect},
+    style::Style,
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Paragraph},
+};
+use tui_textarea::TextArea;
+
+use super::variables_state::{VariableField, VariablesMode, VariablesState};
+use crate::theme;
+use crate::widgets::popup;
+
+const FIELD_HEIGHT: u16 = 3;
+
+fn build_browse_hints() -> Line<'static> {
+    theme::border_hints::build_hints(
+        &[
+            ("Enter", "Edit"),
+            ("Ctrl+N", "New"),
+            ("Ctrl+T", "Type"),
+            ("Ctrl+D", "Delete"),
+            ("Esc", "Close"),
+        ],
+        theme::variables::BORDER,
+    )
+}
+
+fn build_edit_hints() -> Line<'static> {
+    theme::border_hints::build_hints(
+        &[
+            ("Enter", "Save"),
+            ("Tab", "Next"),
+            ("Ctrl+T", "Type"),
+            ("Esc", "Cancel"),
+        ],
+        theme::variables::FIELD_ACTIVE_BORDER,
+    )
+}
+
+/// Render the variables panel over the results pane
+pub fn render_popup(state: &mut VariablesState, frame: &mut Frame, results_area: Rect) {
+    popup::clear_area(frame, results_area);
+
+    match state.mode() {
+        VariablesMode::Browse => render_list(state, true, frame, results_area),
+        VariablesMode::Edit { index, field } => {
+            let layout = Layout::vertical([
+                Constraint::Min(0),
+                Constraint::Length(FIELD_HEIGHT),
+                Constraint::Length(FIELD_HEIGHT),
+            ])
+            .split(results_area);
+
+            render_list(state, false, frame, layout[0]);
+
+            let verb = if index.is_some() { "Edit" } else { "New" };
+            let name_title = format!(" {} Variable - Name ", verb);
+            let value_title = format!(" Value ({}) ", state.edit_kind());
+            render_field(
+                state.name_textarea_mut(),
+                &name_title,
+                field == VariableField::Name,
+                None,
+                frame,
+                layout[1],
+            );
+            render_field(
+                state.value_textarea_mut(),
+                &value_title,
+                field == VariableField::Value,
+                Some(build_edit_hints()),
+                frame,
+                layout[2],
+            );
+        }
+    }
+}
+
+fn render_list(state: &VariablesState, show_hints: bool, frame: &mut Frame, area: Rect) {
+    if area.height == 0 {
+        return;
+    }
+
+    let visible = area.height.saturating_sub(2) as usize;
+    let first = (state.selected() + 1).saturating_sub(visible.max(1));
+
+    let lines: Vec<Line> = if state.variables().is_empty() {
+        vec![Line::from(Span::styled(
+            " No variables defined",
+            Style::default().fg(theme::variables::EMPTY),
+        ))]
+    } else {
+        state
+            .variables()
+            .iter()
+            .enumerate()
+            .skip(first)
+            .take(visible)
+            .map(|(index, variable)| {
+                let is_selected = show_hints && index == state.selected();
+                let indicator = if is_selected { "▸ " } else { "  " };
+                let mut line = Line::from(vec![
+                    Span::styled(
+                        indicator,
+                        Style::default().fg(theme::variables::ITEM_SELECTED_INDICATOR),
+                    ),
+                    Span::styled(
+                        format!("${}", variable.name),
+                        Style::default().fg(theme::variables::NAME),
+                    ),
+                    Span::styled(" = ", Style::default().fg(theme::variables::KIND)),
+                    Span::styled(
+                        variable.value.clone(),
+                        Style::default().fg(theme::variables::VALUE),
+                    ),
+                    Span::styled(
+                        format!("  ({})", variable.kind),
+                        Style::default().fg(theme::variables::KIND),
+                    ),
+                ]);
+                if is_selected {
+                    line = line.style(Style::default().bg(theme::variables::ITEM_SELECTED_BG));
+                }
+                line
+            })
+            .collect()
+    };
+
+    let mut block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(format!(" Variables ({}) ", state.variables().len()))
+        .border_style(Style::default().fg(theme::variables::BORDER))
+        .style(Style::default().bg(theme::variables::BACKGROUND));
+    if show_hints {
+        block = block.title_bottom(build_browse_hints().alignment(Alignment::Center));
+    }
+
+    frame.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+fn render_field(
+    textarea: &mut TextArea<'static>,
+    title: &str,
+    is_active: bool,
+    hints: Option<Line<'static>>,
+    frame: &mut Frame,
+    area: Rect,
+) {
+    let border_color = if is_active {
+        theme::variables::FIELD_ACTIVE_BORDER
+    } else {
+        theme::variables::FIELD_INACTIVE_BORDER
+    };
+
+    let mut block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(title.to_string())
+        .border_style(Style::default().fg(border_color))
+        .style(Style::default().bg(theme::variables::BACKGROUND));
+    if let Some(hints) = hints {
+        block = block.title_bottom(hints.alignment(Alignment::Center));
+    }
+
+    textarea.set_block(block);
+    textarea.set_style(
+        Style::default()
+            .fg(theme::variables::FIELD_TEXT)
+            .bg(theme::variables::BACKGROUND),
+    );
+    textarea.set_cursor_style(if is_active {
+        Style::default().add_modifier(ratatui::style::Modifier::REVERSED)
+    } else {
+        Style::default()
+    });
+    frame.render_widget(&*textarea, area);
+}
+
+#[cfg(test)]
+#[path = "variables_render_tests.rs"]
+mod variables_render_tests;
@@ -0,0 +1,45 @@
+//! Tests for variables panel rendering
+
+use super::*;
+use crate::query::variables::{QueryVariable, VariableKind};
+use insta::assert_snapshot;
+use ratatui::Terminal;
+use ratatui::backend::TestBackend;
+
+fn render_to_string(state: &mut VariablesState, width: u16, height: u16) -> String {
+    let backend = TestBackend::new(width, height);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal.draw(|f| render_popup(state, f, f.area())).unwrap();
+    terminal.backend().to_string()
+}
+
+fn sample_state() -> VariablesState {
+    let mut state = VariablesState::new();
+    state.set_variables(vec![
+        QueryVariable::new("env", "prod", VariableKind::String),
+        QueryVariable::new("limit", "10", VariableKind::Json),
+    ]);
+    state.open();
+    state
+}
+
+#[test]
+fn snapshot_variables_browse() {
+    let mut state = sample_state();
+    state.select_next();
+    assert_snapshot!(render_to_string(&mut state, 70, 8));
+}
+
+#[test]
+fn snapshot_variables_edit() {
+    let mut state = sample_state();
+    state.enter_edit_mode();
+    assert_snapshot!(render_to_string(&mut state, 70, 12));
+}
+
+#[test]
+fn snapshot_variables_empty_create() {
+    let mut state = VariablesState::new();
+    state.open();
+    assert_snapshot!(render_to_string(&mut state, 70, 10));
+}
@@ -0,0 +1,276 @@
+use ratatui::style::{Modifier, Style};
+use tui_textarea::TextArea;
+
+use crate::query::variables::{QueryVariable, VariableKind};
+
+/// Field focused in the variable form
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VariableField {
+    Name,
+    Value,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum VariablesMode {
+    #[default]
+    Browse,
+    /// Editing the variable at `index`, or a new one when `None`
+    Edit {
+        index: Option<usize>,
+        field: VariableField,
+    },
+}
+
+fn create_textarea() -> TextArea<'static> {
+    let mut textarea = TextArea::default();
+    textarea.set_cursor_line_style(Style::default());
+    textarea.set_cursor_style(Style::default().add_modifier(Modifier::REVERSED));
+    textarea
+}
+
+fn set_text(textarea: &mut TextArea<'static>, text: &str) {
+    *textarea = create_textarea();
+    textarea.insert_str(text);
+}
+
+/// Variables panel: the `$name` bindings passed to jq with every query
+pub struct VariablesState {
+    visible: bool,
+    mode: VariablesMode,
+    variables: Vec<QueryVariable>,
+    selected: usize,
+    name_textarea: TextArea<'static>,
+    value_textarea: TextArea<'static>,
+    edit_kind: VariableKind,
+}
+
+impl Default for VariablesState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl VariablesState {
+    pub fn new() -> Self {
+        Self {
+            visible: false,
+            mode: VariablesMode::Browse,
+            variables: Vec::new(),
+            selected: 0,
+            name_textarea: create_textarea(),
+            value_textarea: create_textarea(),
+            edit_kind: VariableKind::default(),
+        }
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    /// Open the panel, starting a new variable when there are none yet
+    pub fn open(&mut self) {
+        self.visible = true;
+        self.mode = VariablesMode::Browse;
+        if self.variables.is_empty() {
+            self.enter_create_mode();
+        }
+    }
+
+    pub fn close(&mut self) {
+        self.visible = false;
+        self.mode = VariablesMode::Browse;
+    }
+
+    pub fn mode(&self) -> VariablesMode {
+        self.mode
+    }
+
+    #[cfg(test)]
+    pub fn is_editing(&self) -> bool {
+        matches!(self.mode, VariablesMode::Edit { .. })
+    }
+
+    pub fn variables(&self) -> &[QueryVariable] {
+        &self.variables
+    }
+
+    /// Replace all variables (e.g. from `--arg` on the command line)
+    ///
+    /// Later definitions of a name replace earlier ones, as in jq.
+    pub fn set_variables(&mut self, variables: Vec<QueryVariable>) {
+        self.variables.clear();
+        for variable in variables {
+            match self.position(&variable.name) {
+                Some(index) => self.variables[index] = variable,
+                None => self.variables.push(variable),
+            }
+        }
+        self.selected = 0;
+    }
+
+    /// Variable names as referenced in queries (`$name`)
+    pub fn names(&self) -> Vec<String> {
+        self.variables
+            .iter()
+            .map(|variable| format!("${}", variable.name))
+            .collect()
+    }
+
+    pub fn selected(&self) -> usize {
+        self.selected
+    }
+
+    pub fn select_next(&mut self) {
+        if self.selected + 1 < self.variables.len() {
+            self.selected += 1;
+        }
+    }
+
+    pub fn select_prev(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    /// Switch the selected variable between string and JSON
+    ///
+    /// Returns an error (and keeps the kind) if the value is not valid JSON.
+    pub fn toggle_selected_kind(&mut self) -> Result<(), String> {
+        let Some(variable) = self.variables.get(self.selected) else {
+            return Ok(());
+        };
+        let toggled = QueryVariable {
+            kind: variable.kind.toggle(),
+            ..variable.clone()
+        };
+        toggled.validate()?;
+        self.variables[self.selected] = toggled;
+        Ok(())
+    }
+
+    /// Remove the selected variable; returns true if one was removed
+    pub fn delete_selected(&mut self) -> bool {
+        if self.selected >= self.variables.len() {
+            return false;
+        }
+        self.variables.remove(self.selected);
+        self.selected = self.selected.min(self.variables.len().saturating_sub(1));
+        true
+    }
+
+    pub fn enter_create_mode(&mut self) {
+        set_text(&mut self.name_textarea, "");
+        set_text(&mut self.value_textarea, "");
+        self.edit_kind = VariableKind::default();
+        self.mode = VariablesMode::Edit {
+            index: None,
+            field: VariableField::Name,
+        };
+    }
+
+    /// Edit the selected variable, starting in its value
+    pub fn enter_edit_mode(&mut self) {
+        let Some(variable) = self.variables.get(self.selected) else {
+            return;
+        };
+        set_text(&mut self.name_textarea, &variable.name);
+        set_text(&mut self.value_textarea, &variable.value);
+        self.edit_kind = variable.kind;
+        self.mode = VariablesMode::Edit {
+            index: Some(self.selected),
+            field: VariableField::Value,
+        };
+    }
+
+    pub fn cancel_edit(&mut self) {
+        self.mode = VariablesMode::Browse;
+        if self.variables.is_empty() {
+            self.close();
+        }
+    }
+
+    pub fn next_field(&mut self) {
+        if let VariablesMode::Edit { index, field } = self.mode {
+            let field = match field {
+                VariableField::Name => VariableField::Value,
+                VariableField::Value => VariableField::Name,
+            };
+            self.mode = VariablesMode::Edit { index, field };
+        }
+    }
+
+    pub fn edit_kind(&self) -> VariableKind {
+        self.edit_kind
+    }
+
+    pub fn toggle_edit_kind(&mut self) {
+        self.edit_kind = self.edit_kind.toggle();
+    }
+
+    #[cfg(test)]
+    pub fn value_textarea(&self) -> &TextArea<'static> {
+        &self.value_textarea
+    }
+
+    pub fn name_textarea_mut(&mut self) -> &mut TextArea<'static> {
+        &mut self.name_textarea
+    }
+
+    pub fn value_textarea_mut(&mut self) -> &mut TextArea<'static> {
+        &mut self.value_textarea
+    }
+
+    /// Textarea of the field being edited
+    pub fn active_textarea_mut(&mut self) -> Option<&mut TextArea<'static>> {
+        match self.mode {
+            VariablesMode::Edit {
+                field: VariableField::Name,
+                ..
+            } => Some(&mut self.name_textarea),
+            VariablesMode::Edit {
+                field: VariableField::Value,
+                ..
+            } => Some(&mut self.value_textarea),
+            VariablesMode::Browse => None,
+        }
+    }
+
+    /// Validate and store the variable being edited
+    pub fn save_edit(&mut self) -> Result<(), String> {
+        let VariablesMode::Edit { index, .. } = self.mode else {
+            return Ok(());
+        };
+
+        let name = self.name_textarea.lines()[0].trim().trim_start_matches('$');
+        let value = self.value_textarea.lines()[0].clone();
+        let variable = QueryVariable::new(name, value, self.edit_kind);
+        variable.validate()?;
+
+        if let Some(existing) = self.position(&variable.name)
+            && Some(existing) != index
+        {
+            return Err(format!("${} is already defined", variable.name));
+        }
+
+        match index {
+            Some(index) => {
+                self.variables[index] = variable;
+                self.selected = index;
+            }
+            None => {
+                self.variables.push(variable);
+                self.selected = self.variables.len() - 1;
+            }
+        }
+        self.mode = VariablesMode::Browse;
+        Ok(())
+    }
+
+    fn position(&self, name: &str) -> Option<usize> {
+        self.variables
+            .iter()
+            .position(|variable| variable.name == name)
+    }
+}
+
+#[cfg(test)]
+#[path = "variables_state_tests.rs"]
+mod variables_state_tests;
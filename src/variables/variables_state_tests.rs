@@ -0,0 +1,125 @@
+//! Tests for variables state
+
+use super::*;
+
+fn type_text(state: &mut VariablesState, text: &str) {
+    state.active_textarea_mut().unwrap().insert_str(text);
+}
+
+fn state_with(variables: &[(&str, &str)]) -> VariablesState {
+    let mut state = VariablesState::new();
+    state.set_variables(
+        variables
+            .iter()
+            .map(|(name, value)| QueryVariable::new(*name, *value, VariableKind::String))
+            .collect(),
+    );
+    state
+}
+
+#[test]
+fn test_open_without_variables_starts_creating() {
+    let mut state = VariablesState::new();
+    state.open();
+    assert!(state.is_visible());
+    assert_eq!(
+        state.mode(),
+        VariablesMode::Edit {
+            index: None,
+            field: VariableField::Name
+        }
+    );
+
+    state.cancel_edit();
+    assert!(!state.is_visible());
+}
+
+#[test]
+fn test_create_variable() {
+    let mut state = VariablesState::new();
+    state.open();
+    type_text(&mut state, "$env");
+    state.next_field();
+    type_text(&mut state, "prod");
+
+    state.save_edit().unwrap();
+    assert_eq!(state.mode(), VariablesMode::Browse);
+    assert_eq!(
+        state.variables(),
+        &[QueryVariable::new("env", "prod", VariableKind::String)]
+    );
+    assert_eq!(state.names(), vec!["$env"]);
+}
+
+#[test]
+fn test_create_json_variable_is_validated() {
+    let mut state = VariablesState::new();
+    state.open();
+    type_text(&mut state, "limit");
+    state.next_field();
+    type_text(&mut state, "five");
+    state.toggle_edit_kind();
+
+    assert!(state.save_edit().is_err());
+    assert!(state.is_editing());
+
+    let value = state.active_textarea_mut().unwrap();
+    value.select_all();
+    value.cut();
+    value.insert_str("5");
+    state.save_edit().unwrap();
+    assert_eq!(state.variables()[0].kind, VariableKind::Json);
+}
+
+#[test]
+fn test_duplicate_and_invalid_names_are_rejected() {
+    let mut state = state_with(&[("env", "prod")]);
+    state.enter_create_mode();
+    type_text(&mut state, "env");
+    assert!(state.save_edit().unwrap_err().contains("already defined"));
+
+    state.enter_create_mode();
+    type_text(&mut state, "my-var");
+    assert!(state.save_edit().is_err());
+}
+
+#[test]
+fn test_edit_selected_value() {
+    let mut state = state_with(&[("a", "1"), ("b", "2")]);
+    state.select_next();
+    state.enter_edit_mode();
+    assert_eq!(state.value_textarea().lines()[0], "2");
+
+    type_text(&mut state, "0");
+    state.save_edit().unwrap();
+    assert_eq!(state.variables()[1].value, "20");
+    assert_eq!(state.variables().len(), 2);
+}
+
+#[test]
+fn test_toggle_kind_requires_valid_json() {
+    let mut state = state_with(&[("n", "42"), ("s", "text")]);
+    state.toggle_selected_kind().unwrap();
+    assert_eq!(state.variables()[0].kind, VariableKind::Json);
+
+    state.select_next();
+    assert!(state.toggle_selected_kind().is_err());
+    assert_eq!(state.variables()[1].kind, VariableKind::String);
+}
+
+#[test]
+fn test_delete_selected() {
+    let mut state = state_with(&[("a", "1"), ("b", "2")]);
+    state.select_next();
+    assert!(state.delete_selected());
+    assert_eq!(state.selected(), 0);
+    assert!(state.delete_selected());
+    assert!(!state.delete_selected());
+}
+
+#[test]
+fn test_set_variables_keeps_last_definition() {
+    let state = state_with(&[("a", "1"), ("a", "2")]);
+    assert_eq!(state.variables().len(), 1);
+    assert_eq!(state.variables()[0].value, "2");
+}
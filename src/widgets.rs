@@ -1,2 +1,4 @@
+pub mod monochrome;
 pub mod popup;
 pub mod scrollbar;
+pub mod text_width;
@@ -0,0 +1,52 @@
+//! Monochrome rendering for `--no-color` and `NO_COLOR`
+//!
+//! Applied to the finished frame rather than to each widget: colors are
+//! dropped, highlighted backgrounds (selections, the results cursor, search
+//! matches) become reversed video so they stay visible, and box-drawing
+//! borders are redrawn with ASCII characters. The screen-reader layout uses
+//! the ASCII borders alone, as box drawing is read out character by character.
+
+use ratatui::buffer::Buffer;
+use ratatui::style::{Color, Modifier};
+
+use crate::theme;
+
+/// Strip colors and box drawing from every cell of `buffer`
+pub fn apply(buffer: &mut Buffer) {
+    for cell in buffer.content.iter_mut() {
+        if is_highlight(cell.bg) {
+            cell.modifier.toggle(Modifier::REVERSED);
+        }
+        cell.fg = Color::Reset;
+        cell.bg = Color::Reset;
+    }
+    ascii_borders(buffer);
+}
+
+/// Redraw box-drawing characters in `buffer` with `-`, `|` and `+`
+pub fn ascii_borders(buffer: &mut Buffer) {
+    for cell in buffer.content.iter_mut() {
+        if let Some(ascii) = cell.symbol().chars().next().and_then(ascii_border) {
+            cell.set_char(ascii);
+        }
+    }
+}
+
+/// Whether `bg` marks something out rather than filling a pane or popup
+fn is_highlight(bg: Color) -> bool {
+    !matches!(bg, Color::Reset | Color::Black) && bg != theme::palette::BG_DARK
+}
+
+/// ASCII stand-in for a box-drawing character
+fn ascii_border(c: char) -> Option<char> {
+    match c {
+        '─' | '━' | '═' | '┄' | '┈' | '╌' => Some('-'),
+        '│' | '┃' | '║' | '┆' | '┊' | '╎' => Some('|'),
+        '\u{2500}'..='\u{257F}' => Some('+'),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+#[path = "monochrome_tests.rs"]
+mod monochrome_tests;
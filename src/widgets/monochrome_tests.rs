@@ -0,0 +1,84 @@
+//! Tests for widgets/monochrome
+
+use super::*;
+use ratatui::layout::Rect;
+use ratatui::style::Style;
+use ratatui::widgets::{Block, BorderType, Borders, Widget};
+
+#[test]
+fn test_colors_are_dropped() {
+    let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 1));
+    buffer.set_string(
+        0,
+        0,
+        "ab",
+        Style::default()
+            .fg(Color::Red)
+            .bg(theme::palette::BG_DARK)
+            .add_modifier(Modifier::BOLD),
+    );
+
+    apply(&mut buffer);
+
+    let cell = &buffer[(0, 0)];
+    assert_eq!(cell.fg, Color::Reset);
+    assert_eq!(cell.bg, Color::Reset);
+    assert_eq!(cell.modifier, Modifier::BOLD);
+}
+
+#[test]
+fn test_highlighted_background_becomes_reversed() {
+    let mut buffer = Buffer::empty(Rect::new(0, 0, 2, 1));
+    buffer.set_string(
+        0,
+        0,
+        "a",
+        Style::default().bg(theme::results::CURSOR_LINE_BG),
+    );
+    buffer.set_string(1, 0, "b", Style::default().add_modifier(Modifier::REVERSED));
+
+    apply(&mut buffer);
+
+    assert!(buffer[(0, 0)].modifier.contains(Modifier::REVERSED));
+    assert!(buffer[(1, 0)].modifier.contains(Modifier::REVERSED));
+}
+
+#[test]
+fn test_borders_become_ascii() {
+    let area = Rect::new(0, 0, 4, 3);
+    let mut buffer = Buffer::empty(area);
+    Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .render(area, &mut buffer);
+
+    apply(&mut buffer);
+
+    assert_eq!(buffer, Buffer::with_lines(["+--+", "|  |", "+--+"]));
+}
+
+#[test]
+fn test_other_symbols_are_kept() {
+    let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 1));
+    buffer.set_string(0, 0, "▸é█", Style::default());
+
+    apply(&mut buffer);
+
+    assert_eq!(buffer, Buffer::with_lines(["▸é█"]));
+}
+
+#[test]
+fn test_ascii_borders_keep_colors() {
+    let area = Rect::new(0, 0, 3, 2);
+    let mut buffer = Buffer::empty(area);
+    Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan))
+        .render(area, &mut buffer);
+
+    ascii_borders(&mut buffer);
+
+    assert_eq!(buffer[(0, 0)].symbol(), "+");
+    assert_eq!(buffer[(1, 0)].symbol(), "-");
+    assert_eq!(buffer[(1, 0)].fg, Color::Cyan);
+}
@@ -0,0 +1,42 @@
+//! Terminal column width of text
+//!
+//! CJK characters and most emoji take two columns and combining marks none,
+//! so counting chars misaligns columns and slicing bytes can panic. These
+//! helpers measure and cut text by the columns it occupies on screen.
+
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+/// Columns `text` takes up in the terminal
+pub fn display_width(text: &str) -> usize {
+    text.width()
+}
+
+/// Longest prefix of `text` that fits in `width` columns
+pub fn take_width(text: &str, width: usize) -> &str {
+    let mut used = 0;
+    for (index, c) in text.char_indices() {
+        used += c.width().unwrap_or(0);
+        if used > width {
+            return &text[..index];
+        }
+    }
+    text
+}
+
+/// Cut `text` to at most `width` columns, ending it with `…` when cut
+pub fn truncate_to_width(text: &str, width: usize) -> String {
+    if display_width(text) <= width {
+        text.to_string()
+    } else {
+        format!("{}…", take_width(text, width.saturating_sub(1)))
+    }
+}
+
+/// Spaces needed after `text` to fill `width` columns
+pub fn padding_for(text: &str, width: usize) -> String {
+    " ".repeat(width.saturating_sub(display_width(text)))
+}
+
+#[cfg(test)]
+#[path = "text_width_tests.rs"]
+mod text_width_tests;
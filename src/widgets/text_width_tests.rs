@@ -0,0 +1,36 @@
+use super::*;
+
+#[test]
+fn test_display_width_counts_wide_characters_twice() {
+    assert_eq!(display_width("abc"), 3);
+    assert_eq!(display_width("日本語"), 6);
+    assert_eq!(display_width("ok 👍"), 5);
+    assert_eq!(display_width("e\u{301}"), 1);
+}
+
+#[test]
+fn test_take_width_stops_before_a_split_character() {
+    assert_eq!(take_width("日本語", 3), "日");
+    assert_eq!(take_width("日本語", 4), "日本");
+    assert_eq!(take_width("abc", 10), "abc");
+    assert_eq!(take_width("日本語", 0), "");
+}
+
+#[test]
+fn test_take_width_keeps_combining_marks_with_their_letter() {
+    assert_eq!(take_width("e\u{301}x", 1), "e\u{301}");
+}
+
+#[test]
+fn test_truncate_to_width() {
+    assert_eq!(truncate_to_width("short", 10), "short");
+    assert_eq!(truncate_to_width("名前が長いスニペット", 7), "名前が…");
+    assert_eq!(truncate_to_width("abcdef", 4), "abc…");
+    assert_eq!(display_width(&truncate_to_width("🎉🎉🎉🎉", 6)), 5);
+}
+
+#[test]
+fn test_padding_for() {
+    assert_eq!(padding_for("日本", 6), "  ");
+    assert_eq!(padding_for("toolong", 3), "");
+}
@@ -0,0 +1,49 @@
+//! Tests for the headless `jiq::Session` API, used as an embedding crate would
+
+use jiq::{InputFormat, QueryVariable, Session, SuggestionType, VariableKind};
+use std::path::PathBuf;
+
+fn fixture_path(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("fixtures")
+        .join(name)
+}
+
+#[test]
+fn test_query_fixture_file() {
+    let mut session = Session::open(fixture_path("simple.json")).unwrap();
+    assert_eq!(session.input_format(), InputFormat::Json);
+
+    session.set_query(".name");
+    assert_eq!(session.result(), Ok("\"Alice\"\n"));
+}
+
+#[test]
+fn test_query_json_lines() {
+    let mut session = Session::open(fixture_path("sample.jsonl")).unwrap();
+    session.set_query(r#"select(.name == $who) | .id"#);
+    session.set_variables(vec![QueryVariable::new("who", "Bob", VariableKind::String)]);
+
+    assert_eq!(session.result(), Ok("2\n"));
+}
+
+#[test]
+fn test_suggestions_for_fixture_fields() {
+    let mut session = Session::open(fixture_path("simple.json")).unwrap();
+    session.set_query(".ci");
+
+    let suggestions = session.suggestions();
+    assert!(
+        suggestions
+            .iter()
+            .any(|s| s.text == ".city" && s.suggestion_type == SuggestionType::Field),
+        "{:?}",
+        suggestions
+    );
+}
+
+#[test]
+fn test_missing_file_is_an_error() {
+    assert!(Session::open(fixture_path("does-not-exist.json")).is_err());
+}
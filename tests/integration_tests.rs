@@ -23,10 +23,11 @@ fn create_test_loader(json: String) -> jiq::input::FileLoader {
     use std::sync::mpsc::channel;
     let (tx, rx) = channel();
     // Send the result immediately so poll() will return it
-    let _ = tx.send(Ok(json));
+    let _ = tx.send(Ok((json, jiq::input::input_format::InputFormat::Json)));
     jiq::input::FileLoader {
         state: LoadingState::Loading,
         rx: Some(rx),
+        format: None,
     }
 }
 